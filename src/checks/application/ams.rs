@@ -3,10 +3,69 @@
 // Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
 // This file may not be copied, modified, or distributed except according to those terms.
 
-use crate::errors::application::ams::AmsParseError;
+use crate::errors::application::ams::{AdsError, AmsParseError};
 
 pub const AMS_HEADER_LEN: usize = 32;
 
+/// Préfixe AMS/TCP : commande u16 (0 pour un paquet AMS) puis longueur u32
+/// du paquet AMS qui suit.
+pub const AMS_TCP_HEADER_LEN: usize = 6;
+
+/// Bits de `state_flags` : réponse, commande ADS.
+pub const AMS_STATE_FLAG_RESPONSE: u16 = 0x0001;
+pub const AMS_STATE_FLAG_ADS_COMMAND: u16 = 0x0004;
+
+/// Commandes ADS (`cmd_id` du header AMS).
+pub const ADS_CMD_READ_DEVICE_INFO: u16 = 0x0001;
+pub const ADS_CMD_READ: u16 = 0x0002;
+pub const ADS_CMD_WRITE: u16 = 0x0003;
+pub const ADS_CMD_READ_STATE: u16 = 0x0004;
+pub const ADS_CMD_WRITE_CONTROL: u16 = 0x0005;
+pub const ADS_CMD_ADD_DEVICE_NOTIFICATION: u16 = 0x0006;
+pub const ADS_CMD_DELETE_DEVICE_NOTIFICATION: u16 = 0x0007;
+pub const ADS_CMD_DEVICE_NOTIFICATION: u16 = 0x0008;
+pub const ADS_CMD_READ_WRITE: u16 = 0x0009;
+
+/// Index groups du serveur de symboles TwinCAT.
+pub const ADS_IGRP_SYM_HANDLE_BY_NAME: u32 = 0xF003;
+pub const ADS_IGRP_SYM_VALUE_BY_NAME: u32 = 0xF004;
+pub const ADS_IGRP_SYM_VALUE_BY_HANDLE: u32 = 0xF005;
+pub const ADS_IGRP_SYM_RELEASE_HANDLE: u32 = 0xF006;
+pub const ADS_IGRP_SYM_INFO_BY_NAME_EX: u32 = 0xF009;
+pub const ADS_IGRP_SUMUP_READ: u32 = 0xF080;
+pub const ADS_IGRP_SUMUP_WRITE: u32 = 0xF081;
+pub const ADS_IGRP_SUMUP_READ_WRITE: u32 = 0xF082;
+
+/// Nom de périphérique de ReadDeviceInfo, complété par des octets nuls.
+pub const ADS_DEVICE_NAME_LEN: usize = 16;
+
+/// Handles de symboles retenus par `AdsSymbolTable` avant d'oublier le
+/// plus ancien.
+pub const ADS_MAX_SYMBOL_HANDLES: usize = 256;
+
+/// Demandes de handle en attente de réponse retenues par `AdsSymbolTable`.
+pub const ADS_MAX_PENDING_HANDLE_REQUESTS: usize = 64;
+
+/// Découverte et gestion des routes sur UDP 48899 : cookie `03 66 14 71`
+/// lu en little-endian.
+pub const ADS_DISCOVERY_MAGIC: u32 = 0x7114_6603;
+pub const ADS_DISCOVERY_HEADER_LEN: usize = 24;
+pub const ADS_DISCOVERY_SERVICE_SEARCH: u32 = 0x0000_0001;
+pub const ADS_DISCOVERY_SERVICE_ADD_ROUTE: u32 = 0x0000_0006;
+/// Bit des réponses dans le service id.
+pub const ADS_DISCOVERY_RESPONSE: u32 = 0x8000_0000;
+
+/// Tags des paquets de découverte.
+pub const ADS_DISCOVERY_TAG_STATUS: u16 = 0x0001;
+pub const ADS_DISCOVERY_TAG_PASSWORD: u16 = 0x0002;
+pub const ADS_DISCOVERY_TAG_TWINCAT_VERSION: u16 = 0x0003;
+pub const ADS_DISCOVERY_TAG_OS_VERSION: u16 = 0x0004;
+pub const ADS_DISCOVERY_TAG_HOST: u16 = 0x0005;
+pub const ADS_DISCOVERY_TAG_AMS_NET_ID: u16 = 0x0007;
+pub const ADS_DISCOVERY_TAG_ROUTE_NAME: u16 = 0x000C;
+pub const ADS_DISCOVERY_TAG_USER_NAME: u16 = 0x000D;
+pub const ADS_DISCOVERY_TAG_FINGERPRINT: u16 = 0x0012;
+
 pub fn validate_ams_header_length(len: usize) -> Result<(), AmsParseError> {
     if len < AMS_HEADER_LEN {
         return Err(AmsParseError::HeaderTooShort {
@@ -170,6 +229,251 @@ pub fn extract_invoke_id(payload: &[u8]) -> Result<u32, AmsParseError> {
     ]))
 }
 
+pub fn extract_ads_u8<'a>(buf: &'a [u8], field: &'static str) -> Result<(u8, &'a [u8]), AdsError> {
+    match buf {
+        [value, rest @ ..] => Ok((*value, rest)),
+        [] => Err(AdsError::Truncated { field }),
+    }
+}
+
+pub fn extract_ads_u16<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(u16, &'a [u8]), AdsError> {
+    match buf {
+        [low, high, rest @ ..] => Ok((u16::from_le_bytes([*low, *high]), rest)),
+        _ => Err(AdsError::Truncated { field }),
+    }
+}
+
+pub fn extract_ads_u32<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(u32, &'a [u8]), AdsError> {
+    let (bytes, rest) = extract_ads_bytes(buf, 4, field)?;
+    Ok((
+        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        rest,
+    ))
+}
+
+pub fn extract_ads_u64<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(u64, &'a [u8]), AdsError> {
+    let (bytes, rest) = extract_ads_bytes(buf, 8, field)?;
+    let mut value = [0u8; 8];
+    value.copy_from_slice(bytes);
+    Ok((u64::from_le_bytes(value), rest))
+}
+
+pub fn extract_ads_bytes<'a>(
+    buf: &'a [u8],
+    len: usize,
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), AdsError> {
+    if buf.len() < len {
+        return Err(AdsError::Truncated { field });
+    }
+    Ok(buf.split_at(len))
+}
+
+/// Données précédées de leur longueur u32, qui doivent finir exactement le
+/// message (Write, WriteControl, réponses Read et ReadWrite).
+pub fn extract_ads_data<'a>(buf: &'a [u8], field: &'static str) -> Result<&'a [u8], AdsError> {
+    let (length, rest) = extract_ads_u32(buf, field)?;
+    validate_ads_data_length(length, rest, field)?;
+    Ok(rest)
+}
+
+pub fn validate_ads_data_length(
+    declared: u32,
+    data: &[u8],
+    field: &'static str,
+) -> Result<(), AdsError> {
+    if data.len() != declared as usize {
+        return Err(AdsError::LengthMismatch {
+            field,
+            declared,
+            actual: data.len(),
+        });
+    }
+    Ok(())
+}
+
+pub fn validate_ads_no_trailing_data(rest: &[u8], field: &'static str) -> Result<(), AdsError> {
+    if !rest.is_empty() {
+        return Err(AdsError::TrailingData {
+            field,
+            length: rest.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Chaîne terminée par un octet nul (noms de symboles, nom de
+/// périphérique, tags de découverte) ; sans nul, tout le champ.
+pub fn extract_ads_c_string(bytes: &[u8]) -> &[u8] {
+    let end = bytes
+        .iter()
+        .position(|byte| *byte == 0)
+        .unwrap_or(bytes.len());
+    &bytes[..end]
+}
+
+/// Forme pointée usuelle d'un AMS Net ID, ex. `5.12.34.56.1.1`.
+pub fn format_ams_net_id(net_id: &[u8; 6]) -> String {
+    net_id
+        .iter()
+        .map(u8::to_string)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+pub fn ads_command_name(cmd_id: u16) -> &'static str {
+    match cmd_id {
+        ADS_CMD_READ_DEVICE_INFO => "ReadDeviceInfo",
+        ADS_CMD_READ => "Read",
+        ADS_CMD_WRITE => "Write",
+        ADS_CMD_READ_STATE => "ReadState",
+        ADS_CMD_WRITE_CONTROL => "WriteControl",
+        ADS_CMD_ADD_DEVICE_NOTIFICATION => "AddDeviceNotification",
+        ADS_CMD_DELETE_DEVICE_NOTIFICATION => "DeleteDeviceNotification",
+        ADS_CMD_DEVICE_NOTIFICATION => "DeviceNotification",
+        ADS_CMD_READ_WRITE => "ReadWrite",
+        _ => "Unknown",
+    }
+}
+
+/// Codes de retour ADS (champ `result` des réponses et `error_code` du
+/// header AMS).
+pub fn ads_return_code_name(code: u32) -> &'static str {
+    match code {
+        0x0000 => "No error",
+        0x0006 => "Target port not found",
+        0x0007 => "Target machine not found",
+        0x0700 => "General device error",
+        0x0701 => "Service not supported",
+        0x0702 => "Invalid index group",
+        0x0703 => "Invalid index offset",
+        0x0704 => "Reading or writing not permitted",
+        0x0705 => "Parameter size not correct",
+        0x0706 => "Invalid data values",
+        0x0707 => "Device not ready",
+        0x0708 => "Device busy",
+        0x0709 => "Invalid operating system context",
+        0x070A => "Insufficient memory",
+        0x070B => "Invalid parameter values",
+        0x070C => "Not found",
+        0x070D => "Syntax error",
+        0x070E => "Objects do not match",
+        0x070F => "Object already exists",
+        0x0710 => "Symbol not found",
+        0x0711 => "Invalid symbol version",
+        0x0712 => "Device in invalid state",
+        0x0713 => "Transmission mode not supported",
+        0x0714 => "Invalid notification handle",
+        0x0715 => "Notification client not registered",
+        0x0716 => "No further notification handle",
+        0x0717 => "Notification size too large",
+        0x0718 => "Device not initialized",
+        0x0719 => "Device timeout",
+        0x071A => "Interface query failed",
+        0x071B => "Wrong interface requested",
+        0x071C => "Invalid class ID",
+        0x071D => "Invalid object ID",
+        0x071E => "Request pending",
+        0x071F => "Request aborted",
+        0x0720 => "Signal warning",
+        0x0721 => "Invalid array index",
+        0x0722 => "Symbol not active",
+        0x0723 => "Access denied",
+        0x0724 => "Missing license",
+        0x0740 => "Client error",
+        0x0745 => "Client timeout",
+        _ => "Unknown",
+    }
+}
+
+pub fn ads_state_name(state: u16) -> &'static str {
+    match state {
+        0 => "Invalid",
+        1 => "Idle",
+        2 => "Reset",
+        3 => "Init",
+        4 => "Start",
+        5 => "Run",
+        6 => "Stop",
+        7 => "SaveConfig",
+        8 => "LoadConfig",
+        9 => "PowerFailure",
+        10 => "PowerGood",
+        11 => "Error",
+        12 => "Shutdown",
+        13 => "Suspend",
+        14 => "Resume",
+        15 => "Config",
+        16 => "Reconfig",
+        17 => "Stopping",
+        18 => "Incompatible",
+        19 => "Exception",
+        _ => "Unknown",
+    }
+}
+
+pub fn ads_index_group_name(index_group: u32) -> &'static str {
+    match index_group {
+        0x4020 => "PLC memory (%M)",
+        0x4021 => "PLC memory bit (%MX)",
+        0x4025 => "PLC memory size",
+        0xF000 => "Symbol table",
+        0xF001 => "Symbol name",
+        0xF002 => "Symbol value",
+        ADS_IGRP_SYM_HANDLE_BY_NAME => "Symbol handle by name",
+        ADS_IGRP_SYM_VALUE_BY_NAME => "Symbol value by name",
+        ADS_IGRP_SYM_VALUE_BY_HANDLE => "Symbol value by handle",
+        ADS_IGRP_SYM_RELEASE_HANDLE => "Release symbol handle",
+        0xF007 => "Symbol info by name",
+        0xF008 => "Symbol version",
+        ADS_IGRP_SYM_INFO_BY_NAME_EX => "Symbol info by name (extended)",
+        0xF00B => "Symbol upload",
+        0xF00C => "Symbol upload info",
+        0xF00E => "Data type upload",
+        0xF00F => "Symbol upload info 2",
+        0xF020 => "Input image (%I)",
+        0xF021 => "Input image bit (%IX)",
+        0xF030 => "Output image (%Q)",
+        0xF031 => "Output image bit (%QX)",
+        ADS_IGRP_SUMUP_READ => "Sum read",
+        ADS_IGRP_SUMUP_WRITE => "Sum write",
+        ADS_IGRP_SUMUP_READ_WRITE => "Sum read/write",
+        0xF100 => "Device data",
+        _ => "Unknown",
+    }
+}
+
+pub fn ads_transmission_mode_name(mode: u32) -> &'static str {
+    match mode {
+        0 => "None",
+        1 => "ClientCycle",
+        2 => "ClientOnChange",
+        3 => "ServerCycle",
+        4 => "ServerOnChange",
+        5 => "ServerCycle2",
+        6 => "ServerOnChange2",
+        7 => "Client1Request",
+        _ => "Unknown",
+    }
+}
+
+pub fn ads_discovery_service_name(service_id: u32) -> &'static str {
+    match service_id & !ADS_DISCOVERY_RESPONSE {
+        ADS_DISCOVERY_SERVICE_SEARCH => "Search",
+        ADS_DISCOVERY_SERVICE_ADD_ROUTE => "AddRoute",
+        _ => "Unknown",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -287,4 +591,41 @@ mod tests {
             })
         ));
     }
+
+    #[test]
+    fn test_extract_ads_data_checks_declared_length() {
+        assert_eq!(
+            extract_ads_data(&[0x02, 0, 0, 0, 0xAA, 0xBB], "data"),
+            Ok(&[0xAA, 0xBB][..])
+        );
+        assert_eq!(
+            extract_ads_data(&[0x03, 0, 0, 0, 0xAA, 0xBB], "data"),
+            Err(AdsError::LengthMismatch {
+                field: "data",
+                declared: 3,
+                actual: 2
+            })
+        );
+        assert_eq!(
+            extract_ads_u32(&[0x01, 0x02], "index group"),
+            Err(AdsError::Truncated {
+                field: "index group"
+            })
+        );
+    }
+
+    #[test]
+    fn test_ads_strings_and_names() {
+        assert_eq!(extract_ads_c_string(b"MAIN.bStart\0\0"), b"MAIN.bStart");
+        assert_eq!(extract_ads_c_string(b"PLC"), b"PLC");
+        assert_eq!(format_ams_net_id(&[5, 12, 34, 56, 1, 1]), "5.12.34.56.1.1");
+        assert_eq!(ads_command_name(ADS_CMD_READ_WRITE), "ReadWrite");
+        assert_eq!(ads_return_code_name(0x0710), "Symbol not found");
+        assert_eq!(ads_state_name(5), "Run");
+        assert_eq!(
+            ads_index_group_name(ADS_IGRP_SYM_HANDLE_BY_NAME),
+            "Symbol handle by name"
+        );
+        assert_eq!(ads_discovery_service_name(0x8000_0006), "AddRoute");
+    }
 }
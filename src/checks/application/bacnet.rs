@@ -0,0 +1,423 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Controles de taille et de coherence pour BACnet/IP (ASHRAE 135 annexe J).
+//!
+//! Le BVLC tient en 4 octets : le type `0x81`, la fonction et la longueur
+//! du datagramme entier sur 2 octets. Un datagramme UDP porte exactement un
+//! message : la longueur declaree doit donc egaler celle de la charge utile,
+//! ce qui rend la signature assez forte pour le port 47808.
+
+use crate::errors::application::bacnet::BacnetError;
+
+/// Type BVLC de BACnet/IP.
+pub const BACNET_BVLC_TYPE: u8 = 0x81;
+
+/// Type, fonction et longueur.
+pub const BACNET_BVLC_HEADER_LEN: usize = 4;
+
+/// Adresse B/IP (IPv4 et port) de l'emetteur d'un Forwarded-NPDU.
+pub const BACNET_BIP_ADDRESS_LEN: usize = 6;
+
+pub const BACNET_BVLC_RESULT: u8 = 0x00;
+pub const BACNET_BVLC_WRITE_BDT: u8 = 0x01;
+pub const BACNET_BVLC_READ_BDT: u8 = 0x02;
+pub const BACNET_BVLC_READ_BDT_ACK: u8 = 0x03;
+pub const BACNET_BVLC_FORWARDED_NPDU: u8 = 0x04;
+pub const BACNET_BVLC_REGISTER_FOREIGN_DEVICE: u8 = 0x05;
+pub const BACNET_BVLC_READ_FDT: u8 = 0x06;
+pub const BACNET_BVLC_READ_FDT_ACK: u8 = 0x07;
+pub const BACNET_BVLC_DELETE_FDT_ENTRY: u8 = 0x08;
+pub const BACNET_BVLC_DISTRIBUTE_BROADCAST: u8 = 0x09;
+pub const BACNET_BVLC_ORIGINAL_UNICAST_NPDU: u8 = 0x0A;
+pub const BACNET_BVLC_ORIGINAL_BROADCAST_NPDU: u8 = 0x0B;
+pub const BACNET_BVLC_SECURE_BVLL: u8 = 0x0C;
+
+/// Version du protocole reseau.
+pub const BACNET_NPDU_VERSION: u8 = 0x01;
+
+/// Bits de l'octet de controle NPCI.
+pub const BACNET_NPDU_NETWORK_MESSAGE: u8 = 0x80;
+pub const BACNET_NPDU_DESTINATION: u8 = 0x20;
+pub const BACNET_NPDU_SOURCE: u8 = 0x08;
+pub const BACNET_NPDU_EXPECTING_REPLY: u8 = 0x04;
+pub const BACNET_NPDU_PRIORITY_MASK: u8 = 0x03;
+
+/// Les messages reseau a partir de 0x80 sont proprietaires et suivis d'un
+/// identifiant de fabricant.
+pub const BACNET_NPDU_PROPRIETARY_MESSAGE: u8 = 0x80;
+
+/// Types d'APDU, dans le quartet haut du premier octet.
+pub const BACNET_APDU_CONFIRMED_REQUEST: u8 = 0;
+pub const BACNET_APDU_UNCONFIRMED_REQUEST: u8 = 1;
+pub const BACNET_APDU_SIMPLE_ACK: u8 = 2;
+pub const BACNET_APDU_COMPLEX_ACK: u8 = 3;
+pub const BACNET_APDU_SEGMENT_ACK: u8 = 4;
+pub const BACNET_APDU_ERROR: u8 = 5;
+pub const BACNET_APDU_REJECT: u8 = 6;
+pub const BACNET_APDU_ABORT: u8 = 7;
+
+/// Drapeaux du premier octet d'APDU : message segmente, segments a suivre,
+/// reponse segmentee acceptee ; NAK et SRV pour SegmentACK et Abort.
+pub const BACNET_APDU_SEGMENTED: u8 = 0x08;
+pub const BACNET_APDU_MORE_FOLLOWS: u8 = 0x04;
+pub const BACNET_APDU_SEGMENTED_RESPONSE_ACCEPTED: u8 = 0x02;
+pub const BACNET_APDU_NEGATIVE_ACK: u8 = 0x02;
+pub const BACNET_APDU_SERVER: u8 = 0x01;
+
+/// Services confirmes decodes.
+pub const BACNET_SERVICE_READ_PROPERTY: u8 = 12;
+pub const BACNET_SERVICE_WRITE_PROPERTY: u8 = 15;
+
+/// Services non confirmes decodes.
+pub const BACNET_SERVICE_I_AM: u8 = 0;
+pub const BACNET_SERVICE_WHO_IS: u8 = 8;
+
+/// Etiquettes applicatives (ASHRAE 135 clause 20.2.1.4).
+pub const BACNET_TAG_NULL: u8 = 0;
+pub const BACNET_TAG_BOOLEAN: u8 = 1;
+pub const BACNET_TAG_UNSIGNED: u8 = 2;
+pub const BACNET_TAG_SIGNED: u8 = 3;
+pub const BACNET_TAG_REAL: u8 = 4;
+pub const BACNET_TAG_DOUBLE: u8 = 5;
+pub const BACNET_TAG_OCTET_STRING: u8 = 6;
+pub const BACNET_TAG_CHARACTER_STRING: u8 = 7;
+pub const BACNET_TAG_BIT_STRING: u8 = 8;
+pub const BACNET_TAG_ENUMERATED: u8 = 9;
+pub const BACNET_TAG_DATE: u8 = 10;
+pub const BACNET_TAG_TIME: u8 = 11;
+pub const BACNET_TAG_OBJECT_IDENTIFIER: u8 = 12;
+
+/// Bit de classe (contexte) et valeurs LVT particulieres de l'octet
+/// d'etiquette.
+pub const BACNET_TAG_CLASS_CONTEXT: u8 = 0x08;
+pub const BACNET_TAG_LVT_MASK: u8 = 0x07;
+pub const BACNET_TAG_LVT_EXTENDED: u8 = 5;
+pub const BACNET_TAG_LVT_OPENING: u8 = 6;
+pub const BACNET_TAG_LVT_CLOSING: u8 = 7;
+pub const BACNET_TAG_NUMBER_EXTENDED: u8 = 15;
+
+/// Identifiant d'objet : type sur 10 bits, instance sur 22 bits.
+pub const BACNET_OBJECT_INSTANCE_BITS: u32 = 22;
+pub const BACNET_OBJECT_INSTANCE_MASK: u32 = 0x003F_FFFF;
+
+/// Verifie le BVLC en tete de `datagram` et retourne la longueur declaree.
+pub fn extract_bacnet_bvlc_len(datagram: &[u8]) -> Result<usize, BacnetError> {
+    if datagram.len() < BACNET_BVLC_HEADER_LEN {
+        return Err(BacnetError::InvalidLength {
+            expected: BACNET_BVLC_HEADER_LEN,
+            actual: datagram.len(),
+        });
+    }
+    if datagram[0] != BACNET_BVLC_TYPE {
+        return Err(BacnetError::InvalidBvlcType(datagram[0]));
+    }
+    if datagram[1] > BACNET_BVLC_SECURE_BVLL {
+        return Err(BacnetError::UnknownBvlcFunction(datagram[1]));
+    }
+    let declared = u16::from_be_bytes([datagram[2], datagram[3]]);
+    if usize::from(declared) != datagram.len() {
+        return Err(BacnetError::BvlcLengthMismatch {
+            declared,
+            actual: datagram.len(),
+        });
+    }
+    Ok(usize::from(declared))
+}
+
+pub fn extract_bacnet_bytes<'a>(
+    buf: &'a [u8],
+    len: usize,
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), BacnetError> {
+    if buf.len() < len {
+        return Err(BacnetError::Truncated { field });
+    }
+    Ok(buf.split_at(len))
+}
+
+/// Entier non signe big-endian de 1 a 4 octets.
+pub fn extract_bacnet_uint(bytes: &[u8], field: &'static str) -> Result<u32, BacnetError> {
+    if bytes.is_empty() || bytes.len() > 4 {
+        return Err(BacnetError::InvalidTagLength {
+            field,
+            length: bytes.len(),
+        });
+    }
+    Ok(bytes
+        .iter()
+        .fold(0u32, |value, &byte| (value << 8) | u32::from(byte)))
+}
+
+/// Entier signe en complement a deux de 1 a 4 octets.
+pub fn extract_bacnet_sint(bytes: &[u8], field: &'static str) -> Result<i32, BacnetError> {
+    let value = extract_bacnet_uint(bytes, field)?;
+    let shift = 32 - 8 * bytes.len() as u32;
+    Ok(((value << shift) as i32) >> shift)
+}
+
+/// Taille maximale d'APDU acceptee, codee sur 4 bits dans une requete
+/// confirmee.
+pub fn bacnet_max_apdu_len(code: u8) -> Option<u16> {
+    let len = match code {
+        0 => 50,
+        1 => 128,
+        2 => 206,
+        3 => 480,
+        4 => 1024,
+        5 => 1476,
+        _ => return None,
+    };
+    Some(len)
+}
+
+pub fn bacnet_bvlc_function_name(function: u8) -> &'static str {
+    match function {
+        BACNET_BVLC_RESULT => "BVLC-Result",
+        BACNET_BVLC_WRITE_BDT => "Write-Broadcast-Distribution-Table",
+        BACNET_BVLC_READ_BDT => "Read-Broadcast-Distribution-Table",
+        BACNET_BVLC_READ_BDT_ACK => "Read-Broadcast-Distribution-Table-Ack",
+        BACNET_BVLC_FORWARDED_NPDU => "Forwarded-NPDU",
+        BACNET_BVLC_REGISTER_FOREIGN_DEVICE => "Register-Foreign-Device",
+        BACNET_BVLC_READ_FDT => "Read-Foreign-Device-Table",
+        BACNET_BVLC_READ_FDT_ACK => "Read-Foreign-Device-Table-Ack",
+        BACNET_BVLC_DELETE_FDT_ENTRY => "Delete-Foreign-Device-Table-Entry",
+        BACNET_BVLC_DISTRIBUTE_BROADCAST => "Distribute-Broadcast-To-Network",
+        BACNET_BVLC_ORIGINAL_UNICAST_NPDU => "Original-Unicast-NPDU",
+        BACNET_BVLC_ORIGINAL_BROADCAST_NPDU => "Original-Broadcast-NPDU",
+        BACNET_BVLC_SECURE_BVLL => "Secure-BVLL",
+        _ => "Unknown",
+    }
+}
+
+pub fn bacnet_network_message_name(message_type: u8) -> &'static str {
+    match message_type {
+        0x00 => "Who-Is-Router-To-Network",
+        0x01 => "I-Am-Router-To-Network",
+        0x02 => "I-Could-Be-Router-To-Network",
+        0x03 => "Reject-Message-To-Network",
+        0x04 => "Router-Busy-To-Network",
+        0x05 => "Router-Available-To-Network",
+        0x06 => "Initialize-Routing-Table",
+        0x07 => "Initialize-Routing-Table-Ack",
+        0x08 => "Establish-Connection-To-Network",
+        0x09 => "Disconnect-Connection-To-Network",
+        0x12 => "What-Is-Network-Number",
+        0x13 => "Network-Number-Is",
+        BACNET_NPDU_PROPRIETARY_MESSAGE.. => "Proprietary",
+        _ => "Unknown",
+    }
+}
+
+pub fn bacnet_apdu_type_name(pdu_type: u8) -> &'static str {
+    match pdu_type {
+        BACNET_APDU_CONFIRMED_REQUEST => "Confirmed-Request",
+        BACNET_APDU_UNCONFIRMED_REQUEST => "Unconfirmed-Request",
+        BACNET_APDU_SIMPLE_ACK => "SimpleACK",
+        BACNET_APDU_COMPLEX_ACK => "ComplexACK",
+        BACNET_APDU_SEGMENT_ACK => "SegmentACK",
+        BACNET_APDU_ERROR => "Error",
+        BACNET_APDU_REJECT => "Reject",
+        BACNET_APDU_ABORT => "Abort",
+        _ => "Unknown",
+    }
+}
+
+pub fn bacnet_confirmed_service_name(service: u8) -> &'static str {
+    match service {
+        0 => "acknowledgeAlarm",
+        1 => "confirmedCOVNotification",
+        2 => "confirmedEventNotification",
+        3 => "getAlarmSummary",
+        4 => "getEnrollmentSummary",
+        5 => "subscribeCOV",
+        6 => "atomicReadFile",
+        7 => "atomicWriteFile",
+        8 => "addListElement",
+        9 => "removeListElement",
+        10 => "createObject",
+        11 => "deleteObject",
+        BACNET_SERVICE_READ_PROPERTY => "readProperty",
+        14 => "readPropertyMultiple",
+        BACNET_SERVICE_WRITE_PROPERTY => "writeProperty",
+        16 => "writePropertyMultiple",
+        17 => "deviceCommunicationControl",
+        18 => "confirmedPrivateTransfer",
+        19 => "confirmedTextMessage",
+        20 => "reinitializeDevice",
+        26 => "readRange",
+        28 => "subscribeCOVProperty",
+        29 => "getEventInformation",
+        _ => "Unknown",
+    }
+}
+
+pub fn bacnet_unconfirmed_service_name(service: u8) -> &'static str {
+    match service {
+        BACNET_SERVICE_I_AM => "i-Am",
+        1 => "i-Have",
+        2 => "unconfirmedCOVNotification",
+        3 => "unconfirmedEventNotification",
+        4 => "unconfirmedPrivateTransfer",
+        5 => "unconfirmedTextMessage",
+        6 => "timeSynchronization",
+        7 => "who-Has",
+        BACNET_SERVICE_WHO_IS => "who-Is",
+        9 => "utcTimeSynchronization",
+        10 => "writeGroup",
+        _ => "Unknown",
+    }
+}
+
+pub fn bacnet_object_type_name(object_type: u16) -> &'static str {
+    match object_type {
+        0 => "analog-input",
+        1 => "analog-output",
+        2 => "analog-value",
+        3 => "binary-input",
+        4 => "binary-output",
+        5 => "binary-value",
+        6 => "calendar",
+        7 => "command",
+        8 => "device",
+        9 => "event-enrollment",
+        10 => "file",
+        11 => "group",
+        12 => "loop",
+        13 => "multi-state-input",
+        14 => "multi-state-output",
+        15 => "notification-class",
+        16 => "program",
+        17 => "schedule",
+        18 => "averaging",
+        19 => "multi-state-value",
+        20 => "trend-log",
+        _ => "Unknown",
+    }
+}
+
+/// Noms des proprietes les plus courantes ; la liste complete compte
+/// plusieurs centaines d'entrees.
+pub fn bacnet_property_name(property: u32) -> &'static str {
+    match property {
+        8 => "all",
+        12 => "application-software-version",
+        28 => "description",
+        36 => "event-state",
+        44 => "firmware-revision",
+        62 => "max-apdu-length-accepted",
+        70 => "model-name",
+        75 => "object-identifier",
+        76 => "object-list",
+        77 => "object-name",
+        79 => "object-type",
+        81 => "out-of-service",
+        85 => "present-value",
+        87 => "priority-array",
+        103 => "reliability",
+        104 => "relinquish-default",
+        107 => "segmentation-supported",
+        111 => "status-flags",
+        112 => "system-status",
+        117 => "units",
+        120 => "vendor-identifier",
+        121 => "vendor-name",
+        _ => "Unknown",
+    }
+}
+
+pub fn bacnet_error_class_name(class: u32) -> &'static str {
+    match class {
+        0 => "device",
+        1 => "object",
+        2 => "property",
+        3 => "resources",
+        4 => "security",
+        5 => "services",
+        6 => "vt",
+        7 => "communication",
+        _ => "Unknown",
+    }
+}
+
+pub fn bacnet_reject_reason_name(reason: u8) -> &'static str {
+    match reason {
+        0 => "other",
+        1 => "buffer-overflow",
+        2 => "inconsistent-parameters",
+        3 => "invalid-parameter-data-type",
+        4 => "invalid-tag",
+        5 => "missing-required-parameter",
+        6 => "parameter-out-of-range",
+        7 => "too-many-arguments",
+        8 => "undefined-enumeration",
+        9 => "unrecognized-service",
+        _ => "Unknown",
+    }
+}
+
+pub fn bacnet_abort_reason_name(reason: u8) -> &'static str {
+    match reason {
+        0 => "other",
+        1 => "buffer-overflow",
+        2 => "invalid-apdu-in-this-state",
+        3 => "preempted-by-higher-priority-task",
+        4 => "segmentation-not-supported",
+        5 => "security-error",
+        6 => "insufficient-security",
+        7 => "window-size-out-of-range",
+        8 => "application-exceeded-reply-time",
+        9 => "out-of-resources",
+        10 => "tsm-timeout",
+        11 => "apdu-too-long",
+        _ => "Unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bvlc_length_must_match_datagram() {
+        assert_eq!(
+            extract_bacnet_bvlc_len(&[0x81, 0x0B, 0x00, 0x08, 0x01, 0x20, 0xFF, 0xFF]),
+            Ok(8)
+        );
+        assert_eq!(
+            extract_bacnet_bvlc_len(&[0x81, 0x0A, 0x00, 0x09, 0x01, 0x00]),
+            Err(BacnetError::BvlcLengthMismatch {
+                declared: 9,
+                actual: 6
+            })
+        );
+        assert_eq!(
+            extract_bacnet_bvlc_len(&[0x82, 0x0A, 0x00, 0x04]),
+            Err(BacnetError::InvalidBvlcType(0x82))
+        );
+        assert_eq!(
+            extract_bacnet_bvlc_len(&[0x81, 0x0D, 0x00, 0x04]),
+            Err(BacnetError::UnknownBvlcFunction(0x0D))
+        );
+    }
+
+    #[test]
+    fn integers_and_names() {
+        assert_eq!(extract_bacnet_uint(&[0x01, 0x00], "value"), Ok(256));
+        assert_eq!(extract_bacnet_sint(&[0xFF, 0x38], "value"), Ok(-200));
+        assert_eq!(
+            extract_bacnet_uint(&[0; 5], "value"),
+            Err(BacnetError::InvalidTagLength {
+                field: "value",
+                length: 5
+            })
+        );
+        assert_eq!(bacnet_max_apdu_len(5), Some(1476));
+        assert_eq!(bacnet_network_message_name(0x85), "Proprietary");
+        assert_eq!(bacnet_object_type_name(8), "device");
+        assert_eq!(bacnet_property_name(85), "present-value");
+        assert_eq!(bacnet_confirmed_service_name(12), "readProperty");
+    }
+}
@@ -0,0 +1,245 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Controles et extraction des elements BER/DER (X.690) partages par
+//! Kerberos, LDAP, les blobs de securite SMB, X.509 et MMS.
+//!
+//! Seule la forme definie des longueurs est admise : les protocoles qui
+//! s'appuient sur ce decodeur (DER pour Kerberos, sous-ensemble BER de
+//! RFC 4511 §5.1 pour LDAP) n'emploient jamais la forme indefinie.
+
+use crate::{
+    errors::application::ber::BerError,
+    parse::application::protocols::ber::{BerClass, BerElement},
+};
+
+pub const BER_CLASS_MASK: u8 = 0xC0;
+pub const BER_CONSTRUCTED: u8 = 0x20;
+/// Numero d'etiquette sur les octets suivants (numeros >= 31).
+pub const BER_HIGH_TAG: u8 = 0x1F;
+/// Longueur en forme longue : au plus 4 octets de longueur (4 Gio).
+pub const BER_MAX_LENGTH_OCTETS: usize = 4;
+
+pub const BER_TAG_BOOLEAN: u32 = 1;
+pub const BER_TAG_INTEGER: u32 = 2;
+pub const BER_TAG_BIT_STRING: u32 = 3;
+pub const BER_TAG_OCTET_STRING: u32 = 4;
+pub const BER_TAG_NULL: u32 = 5;
+pub const BER_TAG_OBJECT_IDENTIFIER: u32 = 6;
+pub const BER_TAG_ENUMERATED: u32 = 10;
+pub const BER_TAG_UTF8_STRING: u32 = 12;
+pub const BER_TAG_SEQUENCE: u32 = 16;
+pub const BER_TAG_SET: u32 = 17;
+pub const BER_TAG_IA5_STRING: u32 = 22;
+pub const BER_TAG_GENERALIZED_TIME: u32 = 24;
+pub const BER_TAG_GENERAL_STRING: u32 = 27;
+
+/// Lit l'element BER en tete de `data` et retourne ce qui le suit.
+pub fn extract_ber_element<'a>(
+    data: &'a [u8],
+    field: &'static str,
+) -> Result<(BerElement<'a>, &'a [u8]), BerError> {
+    let (&identifier, mut rest) = data.split_first().ok_or(BerError::Missing { field })?;
+    let mut number = u32::from(identifier & BER_HIGH_TAG);
+    if number == u32::from(BER_HIGH_TAG) {
+        number = 0;
+        loop {
+            let (&byte, next) = rest.split_first().ok_or(BerError::Truncated { field })?;
+            rest = next;
+            number = number
+                .checked_mul(128)
+                .map(|value| value | u32::from(byte & 0x7F))
+                .ok_or(BerError::TagOverflow { field })?;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+    }
+
+    let (&first, next) = rest.split_first().ok_or(BerError::Truncated { field })?;
+    rest = next;
+    let length = if first & 0x80 == 0 {
+        usize::from(first)
+    } else {
+        let width = usize::from(first & 0x7F);
+        // Forme indefinie (0x80), ou longueur au-dela de 4 octets.
+        if width == 0 || width > BER_MAX_LENGTH_OCTETS {
+            return Err(BerError::UnsupportedLength { field });
+        }
+        if rest.len() < width {
+            return Err(BerError::Truncated { field });
+        }
+        let (bytes, next) = rest.split_at(width);
+        rest = next;
+        bytes
+            .iter()
+            .fold(0usize, |value, &byte| (value << 8) | usize::from(byte))
+    };
+    if rest.len() < length {
+        return Err(BerError::Truncated { field });
+    }
+    let (value, rest) = rest.split_at(length);
+    Ok((
+        BerElement {
+            class: BerClass::from_identifier(identifier),
+            constructed: identifier & BER_CONSTRUCTED != 0,
+            number,
+            value,
+        },
+        rest,
+    ))
+}
+
+/// Verifie la classe et le numero d'etiquette d'un element.
+pub fn validate_ber_tag(
+    element: &BerElement<'_>,
+    class: BerClass,
+    number: u32,
+    field: &'static str,
+) -> Result<(), BerError> {
+    if element.class == class && element.number == number {
+        Ok(())
+    } else {
+        Err(BerError::UnexpectedTag {
+            field,
+            class: element.class.bits(),
+            number: element.number,
+        })
+    }
+}
+
+/// Entier signe BER de 1 a 8 octets, en complement a deux.
+pub fn extract_ber_integer(bytes: &[u8], field: &'static str) -> Result<i64, BerError> {
+    if bytes.is_empty() || bytes.len() > 8 {
+        return Err(BerError::InvalidValueLength {
+            field,
+            length: bytes.len(),
+        });
+    }
+    let sign = if bytes[0] & 0x80 != 0 { -1i64 } else { 0 };
+    Ok(bytes
+        .iter()
+        .fold(sign, |value, &byte| (value << 8) | i64::from(byte)))
+}
+
+/// Entier BER non signe sur 32 bits (nonces Kerberos, identifiants de
+/// message LDAP) ; l'octet nul de tete d'une valeur >= 2^31 est admis.
+pub fn extract_ber_unsigned(bytes: &[u8], field: &'static str) -> Result<u32, BerError> {
+    let value = extract_ber_integer(bytes, field)?;
+    u32::try_from(value).map_err(|_| BerError::InvalidValueLength {
+        field,
+        length: bytes.len(),
+    })
+}
+
+pub fn extract_ber_boolean(bytes: &[u8], field: &'static str) -> Result<bool, BerError> {
+    match bytes {
+        [value] => Ok(*value != 0),
+        _ => Err(BerError::InvalidValueLength {
+            field,
+            length: bytes.len(),
+        }),
+    }
+}
+
+/// BIT STRING : nombre de bits inutilises du dernier octet (0 a 7) puis
+/// les octets de bits.
+pub fn extract_ber_bit_string<'a>(
+    bytes: &'a [u8],
+    field: &'static str,
+) -> Result<(u8, &'a [u8]), BerError> {
+    match bytes.split_first() {
+        Some((&unused, bits)) if unused < 8 && (unused == 0 || !bits.is_empty()) => {
+            Ok((unused, bits))
+        }
+        _ => Err(BerError::InvalidBitString { field }),
+    }
+}
+
+/// Chaine texte BER (OCTET STRING LDAP, GeneralString Kerberos, UTF8String).
+pub fn extract_ber_string<'a>(bytes: &'a [u8], field: &'static str) -> Result<&'a str, BerError> {
+    std::str::from_utf8(bytes).map_err(|_| BerError::InvalidString { field })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_long_and_high_tag_forms() {
+        let (element, rest) =
+            extract_ber_element(&[0x02, 0x01, 0x05, 0xFF], "test").expect("INTEGER court");
+        assert!(element.is_universal(BER_TAG_INTEGER));
+        assert_eq!(element.value, [0x05]);
+        assert_eq!(rest, [0xFF]);
+
+        let mut long = vec![0x04, 0x81, 0x80];
+        long.extend([0xAB; 0x80]);
+        let (element, rest) = extract_ber_element(&long, "test").expect("forme longue");
+        assert_eq!(element.value.len(), 0x80);
+        assert!(rest.is_empty());
+
+        // [APPLICATION 30] construit, sur un octet ; [CONTEXT 200] sur deux.
+        let (element, _) = extract_ber_element(&[0x7E, 0x00], "test").expect("KRB-ERROR");
+        assert!(element.is_application(30));
+        assert!(element.constructed);
+        let (element, _) =
+            extract_ber_element(&[0x9F, 0x81, 0x48, 0x00], "test").expect("etiquette haute");
+        assert!(element.is_context(200));
+    }
+
+    #[test]
+    fn malformed_elements_are_rejected() {
+        assert_eq!(
+            extract_ber_element(&[], "test"),
+            Err(BerError::Missing { field: "test" })
+        );
+        assert_eq!(
+            extract_ber_element(&[0x30, 0x80, 0x00, 0x00], "test"),
+            Err(BerError::UnsupportedLength { field: "test" })
+        );
+        assert_eq!(
+            extract_ber_element(&[0x30, 0x85, 1, 0, 0, 0, 0], "test"),
+            Err(BerError::UnsupportedLength { field: "test" })
+        );
+        assert_eq!(
+            extract_ber_element(&[0x30, 0x03, 0x02, 0x01], "test"),
+            Err(BerError::Truncated { field: "test" })
+        );
+        assert_eq!(
+            extract_ber_element(&[0x1F, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F, 0x00], "test"),
+            Err(BerError::TagOverflow { field: "test" })
+        );
+    }
+
+    #[test]
+    fn primitive_values() {
+        assert_eq!(extract_ber_integer(&[0xFF, 0x7F], "test"), Ok(-129));
+        assert_eq!(
+            extract_ber_unsigned(&[0x00, 0xFF, 0xFF, 0xFF, 0xFF], "test"),
+            Ok(u32::MAX)
+        );
+        assert_eq!(
+            extract_ber_unsigned(&[0xFF], "test"),
+            Err(BerError::InvalidValueLength {
+                field: "test",
+                length: 1
+            })
+        );
+        assert_eq!(extract_ber_boolean(&[0xFF], "test"), Ok(true));
+        assert_eq!(
+            extract_ber_bit_string(&[0x00, 0x40, 0x81, 0x00, 0x10], "test"),
+            Ok((0, &[0x40, 0x81, 0x00, 0x10][..]))
+        );
+        assert_eq!(
+            extract_ber_bit_string(&[0x08, 0x00], "test"),
+            Err(BerError::InvalidBitString { field: "test" })
+        );
+        assert_eq!(
+            extract_ber_string(&[0xC3, 0x28], "test"),
+            Err(BerError::InvalidString { field: "test" })
+        );
+    }
+}
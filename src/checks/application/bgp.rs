@@ -0,0 +1,135 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Controles de taille et de coherence pour BGP-4 (RFC 4271).
+//!
+//! Le marqueur de 16 octets a 0xff, vestige de l'authentification de
+//! BGP-3, est la signature qui rend la sonde fiable : RFC 4271 §4.1 impose
+//! sa valeur, et aucun autre protocole courant sur TCP 179 ne la reproduit.
+
+use crate::errors::application::bgp::BgpError;
+
+/// Marqueur, longueur (u16) et type (u8).
+pub const BGP_HEADER_LENGTH: usize = 19;
+
+/// Taille du marqueur en tete de chaque message.
+pub const BGP_MARKER_LENGTH: usize = 16;
+
+/// Partie fixe d'un OPEN apres l'en-tete : version, My AS, Hold Time,
+/// BGP Identifier et longueur des parametres optionnels.
+pub const BGP_OPEN_FIXED_LENGTH: usize = 10;
+
+/// Type et longueur d'un parametre optionnel ou d'une capability.
+pub const BGP_TLV_HEADER_LENGTH: usize = 2;
+
+/// Verifie l'en-tete d'un message et retourne sa longueur declaree, qui
+/// doit couvrir l'en-tete et tenir dans les octets recus.
+pub fn extract_bgp_message_length(payload: &[u8]) -> Result<usize, BgpError> {
+    if payload.len() < BGP_HEADER_LENGTH {
+        return Err(BgpError::InvalidLength {
+            expected: BGP_HEADER_LENGTH,
+            actual: payload.len(),
+        });
+    }
+    if payload[..BGP_MARKER_LENGTH]
+        .iter()
+        .any(|&byte| byte != 0xff)
+    {
+        return Err(BgpError::InvalidMarker);
+    }
+    let length = u16::from_be_bytes([payload[16], payload[17]]);
+    if usize::from(length) < BGP_HEADER_LENGTH {
+        return Err(BgpError::InvalidMessageLength(length));
+    }
+    if usize::from(length) > payload.len() {
+        return Err(BgpError::TruncatedMessage {
+            declared: usize::from(length),
+            available: payload.len(),
+        });
+    }
+    Ok(usize::from(length))
+}
+
+/// Verifie qu'un corps de message contient sa partie fixe.
+pub fn validate_bgp_body_length(body: &[u8], expected: usize) -> Result<(), BgpError> {
+    if body.len() < expected {
+        return Err(BgpError::InvalidLength {
+            expected,
+            actual: body.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Extrait un champ dont la longueur est annoncee par l'emetteur.
+pub fn extract_bgp_field<'a>(
+    bytes: &'a [u8],
+    declared: usize,
+    field: &'static str,
+) -> Result<&'a [u8], BgpError> {
+    bytes.get(..declared).ok_or(BgpError::TruncatedField {
+        field,
+        declared,
+        available: bytes.len(),
+    })
+}
+
+/// Verifie qu'une longueur de prefixe tient dans la famille d'adresses et
+/// retourne le nombre d'octets de prefixe qui la suivent.
+pub fn extract_bgp_prefix_octets(length: u8, max_bits: u8) -> Result<usize, BgpError> {
+    if length > max_bits {
+        return Err(BgpError::InvalidPrefixLength { length, max_bits });
+    }
+    Ok(usize::from(length).div_ceil(8))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message_length_must_cover_header_and_fit() {
+        let mut keepalive = [0xff; 19];
+        keepalive[16] = 0;
+        keepalive[17] = 19;
+        keepalive[18] = 4;
+        assert_eq!(extract_bgp_message_length(&keepalive), Ok(19));
+
+        keepalive[17] = 18;
+        assert_eq!(
+            extract_bgp_message_length(&keepalive),
+            Err(BgpError::InvalidMessageLength(18))
+        );
+
+        keepalive[17] = 23;
+        assert_eq!(
+            extract_bgp_message_length(&keepalive),
+            Err(BgpError::TruncatedMessage {
+                declared: 23,
+                available: 19
+            })
+        );
+
+        keepalive[3] = 0;
+        assert_eq!(
+            extract_bgp_message_length(&keepalive),
+            Err(BgpError::InvalidMarker)
+        );
+    }
+
+    #[test]
+    fn prefix_octets_round_up_and_respect_family() {
+        assert_eq!(extract_bgp_prefix_octets(0, 32), Ok(0));
+        assert_eq!(extract_bgp_prefix_octets(17, 32), Ok(3));
+        assert_eq!(extract_bgp_prefix_octets(64, 128), Ok(8));
+        assert_eq!(
+            extract_bgp_prefix_octets(33, 32),
+            Err(BgpError::InvalidPrefixLength {
+                length: 33,
+                max_bits: 32
+            })
+        );
+    }
+}
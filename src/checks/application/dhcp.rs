@@ -3,7 +3,7 @@
 // Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
 // This file may not be copied, modified, or distributed except according to those terms.
 
-use crate::errors::application::dhcp::DhcpParseError;
+use crate::errors::application::dhcp::{DhcpOptionError, DhcpParseError};
 
 pub const DHCP_MIN_LEN: usize = 236;
 
@@ -198,6 +198,182 @@ pub fn extract_file(payload: &[u8]) -> Result<&[u8; 128], DhcpParseError> {
         })
 }
 
+/// Bourrage (0) et fin (255) de la zone options : un seul octet, sans
+/// longueur (RFC 2132 §3.1 et §3.2).
+pub const DHCP_OPTION_PAD: u8 = 0;
+pub const DHCP_OPTION_END: u8 = 255;
+
+/// Codes d'option interpretes (RFC 2132, RFC 3046, RFC 4702).
+pub const DHCP_OPTION_SUBNET_MASK: u8 = 1;
+pub const DHCP_OPTION_ROUTER: u8 = 3;
+pub const DHCP_OPTION_DNS_SERVERS: u8 = 6;
+pub const DHCP_OPTION_HOSTNAME: u8 = 12;
+pub const DHCP_OPTION_DOMAIN_NAME: u8 = 15;
+pub const DHCP_OPTION_REQUESTED_IP: u8 = 50;
+pub const DHCP_OPTION_LEASE_TIME: u8 = 51;
+pub const DHCP_OPTION_OVERLOAD: u8 = 52;
+pub const DHCP_OPTION_MESSAGE_TYPE: u8 = 53;
+pub const DHCP_OPTION_SERVER_ID: u8 = 54;
+pub const DHCP_OPTION_PARAMETER_REQUEST_LIST: u8 = 55;
+pub const DHCP_OPTION_RENEWAL_TIME: u8 = 58;
+pub const DHCP_OPTION_REBINDING_TIME: u8 = 59;
+pub const DHCP_OPTION_VENDOR_CLASS_ID: u8 = 60;
+pub const DHCP_OPTION_CLIENT_ID: u8 = 61;
+pub const DHCP_OPTION_CLIENT_FQDN: u8 = 81;
+pub const DHCP_OPTION_RELAY_AGENT_INFO: u8 = 82;
+
+/// Valeurs de l'option 52 : les champs file et/ou sname portent des
+/// options supplementaires (RFC 2132 §9.3).
+pub const DHCP_OVERLOAD_FILE: u8 = 1;
+pub const DHCP_OVERLOAD_SNAME: u8 = 2;
+
+/// Types de message de l'option 53 (RFC 2132 §9.6).
+pub const DHCP_DISCOVER: u8 = 1;
+pub const DHCP_OFFER: u8 = 2;
+pub const DHCP_REQUEST: u8 = 3;
+pub const DHCP_DECLINE: u8 = 4;
+pub const DHCP_ACK: u8 = 5;
+pub const DHCP_NAK: u8 = 6;
+pub const DHCP_RELEASE: u8 = 7;
+pub const DHCP_INFORM: u8 = 8;
+
+/// Drapeau E de l'option Client FQDN (RFC 4702 §2.1) : nom encode au
+/// format DNS plutot qu'en ASCII.
+pub const DHCP_FQDN_FLAG_ENCODED: u8 = 0x04;
+
+/// Nombre de baux suivis par un `DhcpLeaseTracker` avant d'oublier le
+/// client le plus ancien.
+pub const DHCP_MAX_TRACKED_LEASES: usize = 4096;
+
+/// Lit une option code-longueur-valeur en tete de `data` et retourne
+/// (code, valeur, reste). Le bourrage et la fin, sans longueur, sont a
+/// traiter par l'appelant.
+pub fn extract_dhcp_option(data: &[u8]) -> Result<(u8, &[u8], &[u8]), DhcpOptionError> {
+    let [code, rest @ ..] = data else {
+        return Err(DhcpOptionError::TruncatedOption {
+            code: 0,
+            expected: 1,
+            actual: 0,
+        });
+    };
+    let [length, rest @ ..] = rest else {
+        return Err(DhcpOptionError::TruncatedOption {
+            code: *code,
+            expected: 1,
+            actual: 0,
+        });
+    };
+    let length = usize::from(*length);
+    if rest.len() < length {
+        return Err(DhcpOptionError::TruncatedOption {
+            code: *code,
+            expected: length,
+            actual: rest.len(),
+        });
+    }
+    Ok((*code, &rest[..length], &rest[length..]))
+}
+
+/// Verifie que la valeur d'une option a exactement la taille attendue.
+pub fn validate_dhcp_option_length(
+    code: u8,
+    value: &[u8],
+    expected: usize,
+) -> Result<(), DhcpOptionError> {
+    if value.len() != expected {
+        return Err(DhcpOptionError::InvalidOptionLength {
+            code,
+            length: value.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Verifie qu'une liste d'adresses IPv4 est non vide et multiple de 4.
+pub fn validate_dhcp_address_list(code: u8, value: &[u8]) -> Result<(), DhcpOptionError> {
+    if value.is_empty() || !value.len().is_multiple_of(4) {
+        return Err(DhcpOptionError::InvalidOptionLength {
+            code,
+            length: value.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Nom du type de message DHCP (option 53).
+pub fn dhcp_message_type_name(message_type: u8) -> &'static str {
+    match message_type {
+        DHCP_DISCOVER => "DHCPDISCOVER",
+        DHCP_OFFER => "DHCPOFFER",
+        DHCP_REQUEST => "DHCPREQUEST",
+        DHCP_DECLINE => "DHCPDECLINE",
+        DHCP_ACK => "DHCPACK",
+        DHCP_NAK => "DHCPNAK",
+        DHCP_RELEASE => "DHCPRELEASE",
+        DHCP_INFORM => "DHCPINFORM",
+        9 => "DHCPFORCERENEW",
+        10 => "DHCPLEASEQUERY",
+        11 => "DHCPLEASEUNASSIGNED",
+        12 => "DHCPLEASEUNKNOWN",
+        13 => "DHCPLEASEACTIVE",
+        _ => "unknown",
+    }
+}
+
+/// Nom d'une option DHCP (registre IANA « BOOTP Vendor Extensions and
+/// DHCP Options »), pour les codes courants.
+pub fn dhcp_option_name(code: u8) -> &'static str {
+    match code {
+        DHCP_OPTION_PAD => "Pad",
+        DHCP_OPTION_SUBNET_MASK => "Subnet Mask",
+        2 => "Time Offset",
+        DHCP_OPTION_ROUTER => "Router",
+        DHCP_OPTION_DNS_SERVERS => "Domain Name Server",
+        DHCP_OPTION_HOSTNAME => "Host Name",
+        DHCP_OPTION_DOMAIN_NAME => "Domain Name",
+        28 => "Broadcast Address",
+        42 => "NTP Servers",
+        43 => "Vendor Specific Information",
+        44 => "NetBIOS Name Server",
+        DHCP_OPTION_REQUESTED_IP => "Requested IP Address",
+        DHCP_OPTION_LEASE_TIME => "IP Address Lease Time",
+        DHCP_OPTION_OVERLOAD => "Option Overload",
+        DHCP_OPTION_MESSAGE_TYPE => "DHCP Message Type",
+        DHCP_OPTION_SERVER_ID => "Server Identifier",
+        DHCP_OPTION_PARAMETER_REQUEST_LIST => "Parameter Request List",
+        56 => "Message",
+        57 => "Maximum DHCP Message Size",
+        DHCP_OPTION_RENEWAL_TIME => "Renewal Time Value",
+        DHCP_OPTION_REBINDING_TIME => "Rebinding Time Value",
+        DHCP_OPTION_VENDOR_CLASS_ID => "Vendor Class Identifier",
+        DHCP_OPTION_CLIENT_ID => "Client Identifier",
+        66 => "TFTP Server Name",
+        67 => "Bootfile Name",
+        DHCP_OPTION_CLIENT_FQDN => "Client FQDN",
+        DHCP_OPTION_RELAY_AGENT_INFO => "Relay Agent Information",
+        119 => "Domain Search",
+        121 => "Classless Static Route",
+        DHCP_OPTION_END => "End",
+        _ => "unknown",
+    }
+}
+
+/// Nom d'une sous-option de l'option 82 (RFC 3046, RFC 3527, RFC 3993).
+pub fn dhcp_relay_agent_suboption_name(code: u8) -> &'static str {
+    match code {
+        1 => "Agent Circuit ID",
+        2 => "Agent Remote ID",
+        4 => "DOCSIS Device Class",
+        5 => "Link Selection",
+        6 => "Subscriber ID",
+        9 => "Vendor-Specific Information",
+        11 => "Server Identifier Override",
+        12 => "Relay Agent Identifier",
+        151 => "Virtual Subnet Selection",
+        _ => "unknown",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -451,4 +627,52 @@ mod tests {
             })
         ));
     }
+
+    #[test]
+    fn test_extract_dhcp_option() {
+        // Message Type DISCOVER puis fin de zone.
+        let data = [0x35, 0x01, 0x01, 0xFF];
+        assert_eq!(extract_dhcp_option(&data), Ok((53, &[1][..], &[0xFF][..])));
+        assert_eq!(
+            extract_dhcp_option(&data[..2]),
+            Err(DhcpOptionError::TruncatedOption {
+                code: 53,
+                expected: 1,
+                actual: 0
+            })
+        );
+        assert_eq!(
+            extract_dhcp_option(&data[..1]),
+            Err(DhcpOptionError::TruncatedOption {
+                code: 53,
+                expected: 1,
+                actual: 0
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_dhcp_option_lengths() {
+        assert!(validate_dhcp_option_length(51, &[0, 0, 0x0E, 0x10], 4).is_ok());
+        assert_eq!(
+            validate_dhcp_option_length(53, &[], 1),
+            Err(DhcpOptionError::InvalidOptionLength {
+                code: 53,
+                length: 0
+            })
+        );
+        assert!(validate_dhcp_address_list(3, &[192, 168, 0, 1, 192, 168, 0, 2]).is_ok());
+        assert_eq!(
+            validate_dhcp_address_list(6, &[8, 8, 8]),
+            Err(DhcpOptionError::InvalidOptionLength { code: 6, length: 3 })
+        );
+    }
+
+    #[test]
+    fn test_dhcp_names() {
+        assert_eq!(dhcp_message_type_name(DHCP_ACK), "DHCPACK");
+        assert_eq!(dhcp_message_type_name(200), "unknown");
+        assert_eq!(dhcp_option_name(55), "Parameter Request List");
+        assert_eq!(dhcp_relay_agent_suboption_name(2), "Agent Remote ID");
+    }
 }
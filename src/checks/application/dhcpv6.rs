@@ -3,7 +3,7 @@
 // Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
 // This file may not be copied, modified, or distributed except according to those terms.
 
-use crate::errors::application::dhcpv6::Dhcpv6PacketParseError;
+use crate::errors::application::dhcpv6::{Dhcpv6OptionError, Dhcpv6PacketParseError};
 
 pub const DHCPV6_MIN_CLIENT_SERVER_LEN: usize = 4;
 
@@ -47,6 +47,88 @@ pub fn extract_transaction_id(payload: &[u8]) -> Result<u32, Dhcpv6PacketParseEr
     Ok(u32::from_be_bytes([0, payload[0], payload[1], payload[2]]))
 }
 
+/// Code et longueur, en tete de chaque option (RFC 8415 §21.1).
+pub const DHCPV6_OPTION_HEADER_LENGTH: usize = 4;
+
+/// Type, hop count, link-address et peer-address d'un message relais
+/// (RFC 8415 §9) : les options commencent ensuite.
+pub const DHCPV6_RELAY_HEADER_LENGTH: usize = 34;
+
+/// IAID, T1 et T2 en tete d'IA_NA (§21.4) et d'IA_PD (§21.21).
+pub const DHCPV6_IA_FIXED_LENGTH: usize = 12;
+
+/// IAID seul en tete d'IA_TA (§21.5).
+pub const DHCPV6_IA_TA_FIXED_LENGTH: usize = 4;
+
+/// Adresse, duree preferee et duree de validite d'IAADDR (§21.6).
+pub const DHCPV6_IAADDR_FIXED_LENGTH: usize = 24;
+
+/// Durees, longueur et prefixe d'IAPREFIX (§21.22).
+pub const DHCPV6_IAPREFIX_FIXED_LENGTH: usize = 25;
+
+/// Profondeur maximale d'options encapsulees (IA_NA → IAADDR → Status
+/// Code n'en demande que trois) : borne la recursion sur un message hostile.
+pub const MAX_DHCPV6_OPTION_DEPTH: usize = 4;
+
+/// Decoupe l'option qui commence a `offset` : renvoie son code et ses
+/// donnees, sans l'en-tete de quatre octets.
+pub fn extract_dhcpv6_option(
+    options: &[u8],
+    offset: usize,
+) -> Result<(u16, &[u8]), Dhcpv6OptionError> {
+    let rest = options.get(offset..).unwrap_or_default();
+    let code = match rest {
+        [high, low, ..] => u16::from_be_bytes([*high, *low]),
+        _ => 0,
+    };
+    if rest.len() < DHCPV6_OPTION_HEADER_LENGTH {
+        return Err(Dhcpv6OptionError::TruncatedOption {
+            code,
+            expected: DHCPV6_OPTION_HEADER_LENGTH,
+            actual: rest.len(),
+        });
+    }
+    let length = usize::from(u16::from_be_bytes([rest[2], rest[3]]));
+    let data = rest
+        .get(DHCPV6_OPTION_HEADER_LENGTH..DHCPV6_OPTION_HEADER_LENGTH + length)
+        .ok_or(Dhcpv6OptionError::TruncatedOption {
+            code,
+            expected: DHCPV6_OPTION_HEADER_LENGTH + length,
+            actual: rest.len(),
+        })?;
+    Ok((code, data))
+}
+
+/// Verifie que les donnees d'une option couvrent au moins sa partie fixe.
+pub fn validate_dhcpv6_option_min_length(
+    code: u16,
+    data: &[u8],
+    min: usize,
+) -> Result<(), Dhcpv6OptionError> {
+    if data.len() < min {
+        return Err(Dhcpv6OptionError::InvalidOptionLength {
+            code,
+            length: data.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Verifie qu'une option a taille fixe a exactement la taille prevue.
+pub fn validate_dhcpv6_option_length(
+    code: u16,
+    data: &[u8],
+    expected: usize,
+) -> Result<(), Dhcpv6OptionError> {
+    if data.len() != expected {
+        return Err(Dhcpv6OptionError::InvalidOptionLength {
+            code,
+            length: data.len(),
+        });
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,6 +174,28 @@ mod tests {
         assert_eq!(extract_transaction_id(&[0x12, 0x34, 0x56]), Ok(0x0012_3456));
     }
 
+    #[test]
+    fn test_extract_dhcpv6_option() {
+        let options = [0x00, 0x08, 0x00, 0x02, 0x00, 0x64, 0x00, 0x0e, 0x00, 0x00];
+        assert_eq!(
+            extract_dhcpv6_option(&options, 0),
+            Ok((8, &[0x00, 0x64][..]))
+        );
+        assert_eq!(extract_dhcpv6_option(&options, 6), Ok((14, &[][..])));
+    }
+
+    #[test]
+    fn test_extract_dhcpv6_option_truncated() {
+        assert_eq!(
+            extract_dhcpv6_option(&[0x00, 0x17, 0x00, 0x10, 0x20, 0x01], 0),
+            Err(Dhcpv6OptionError::TruncatedOption {
+                code: 23,
+                expected: 20,
+                actual: 6
+            })
+        );
+    }
+
     #[test]
     fn test_extract_transaction_id_too_short() {
         assert!(matches!(
@@ -0,0 +1,463 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Controles de taille et de coherence pour DNP3 (IEEE 1815).
+//!
+//! La trame de liaison commence par `05 64` et chaque bloc — l'en-tete de
+//! 8 octets, puis les donnees par tranches de 16 octets — est suivi de son
+//! CRC-16/DNP. Ces CRC rendent la sonde sure : un payload etranger a une
+//! chance sur 65536 de passer le seul CRC d'en-tete.
+
+use crate::errors::application::dnp3::Dnp3Error;
+
+/// Octets de debut de trame.
+pub const DNP3_START: u16 = 0x0564;
+
+/// Debut, longueur, controle, destination et source, sans le CRC.
+pub const DNP3_LINK_HEADER_LEN: usize = 8;
+
+/// Taille d'un CRC de bloc.
+pub const DNP3_CRC_LEN: usize = 2;
+
+/// Octets de donnees par bloc de la trame de liaison.
+pub const DNP3_BLOCK_LEN: usize = 16;
+
+/// Le champ longueur compte controle, destination et source (5 octets)
+/// puis les donnees utilisateur, sans les CRC.
+pub const DNP3_MIN_LENGTH_FIELD: u8 = 5;
+
+/// Bits du controle de liaison.
+pub const DNP3_LINK_DIR: u8 = 0x80;
+pub const DNP3_LINK_PRM: u8 = 0x40;
+pub const DNP3_LINK_FCB: u8 = 0x20;
+pub const DNP3_LINK_FCV: u8 = 0x10;
+
+/// Fonctions de liaison primaires portant des donnees utilisateur.
+pub const DNP3_LINK_CONFIRMED_USER_DATA: u8 = 0x03;
+pub const DNP3_LINK_UNCONFIRMED_USER_DATA: u8 = 0x04;
+
+/// Octet de transport : FIN, FIR et numero de sequence sur 6 bits.
+pub const DNP3_TRANSPORT_FIN: u8 = 0x80;
+pub const DNP3_TRANSPORT_FIR: u8 = 0x40;
+pub const DNP3_TRANSPORT_SEQUENCE_MASK: u8 = 0x3F;
+
+/// Taille maximale d'un fragment applicatif reassemble (IEEE 1815 §4.2.2
+/// recommande 2048 ; les implementations montent a 65535).
+pub const DNP3_MAX_FRAGMENT_LEN: usize = 65_535;
+
+/// Liaisons (source, destination) en cours de reassemblage retenues par
+/// `Dnp3TransportReassembler` avant d'oublier la plus ancienne.
+pub const DNP3_MAX_PENDING_FRAGMENTS: usize = 64;
+
+/// Controle applicatif : FIR, FIN, CON, UNS et sequence sur 4 bits.
+pub const DNP3_APP_FIR: u8 = 0x80;
+pub const DNP3_APP_FIN: u8 = 0x40;
+pub const DNP3_APP_CON: u8 = 0x20;
+pub const DNP3_APP_UNS: u8 = 0x10;
+pub const DNP3_APP_SEQUENCE_MASK: u8 = 0x0F;
+
+/// Codes fonction applicatifs.
+pub const DNP3_FC_CONFIRM: u8 = 0x00;
+pub const DNP3_FC_READ: u8 = 0x01;
+pub const DNP3_FC_WRITE: u8 = 0x02;
+pub const DNP3_FC_SELECT: u8 = 0x03;
+pub const DNP3_FC_OPERATE: u8 = 0x04;
+pub const DNP3_FC_DIRECT_OPERATE: u8 = 0x05;
+pub const DNP3_FC_DIRECT_OPERATE_NR: u8 = 0x06;
+pub const DNP3_FC_COLD_RESTART: u8 = 0x0D;
+pub const DNP3_FC_WARM_RESTART: u8 = 0x0E;
+pub const DNP3_FC_RESPONSE: u8 = 0x81;
+pub const DNP3_FC_UNSOLICITED_RESPONSE: u8 = 0x82;
+pub const DNP3_FC_AUTHENTICATE_RESPONSE: u8 = 0x83;
+
+/// Qualificateur : code de prefixe (bits 4-6) et code de plage (bits 0-3).
+pub const DNP3_QUALIFIER_PREFIX_SHIFT: u8 = 4;
+pub const DNP3_QUALIFIER_PREFIX_MASK: u8 = 0x07;
+pub const DNP3_QUALIFIER_RANGE_MASK: u8 = 0x0F;
+
+/// CRC-16/DNP : polynome 0x3D65 reflechi, resultat complemente, transmis
+/// en little-endian.
+pub fn dnp3_crc(bytes: &[u8]) -> u16 {
+    let mut crc: u16 = 0;
+    for byte in bytes {
+        crc ^= u16::from(*byte);
+        for _ in 0..8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ 0xA6BC
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+/// Verifie le CRC qui suit `block` dans `crc`.
+pub fn validate_dnp3_crc(block: &[u8], crc: &[u8], index: usize) -> Result<(), Dnp3Error> {
+    let computed = dnp3_crc(block);
+    let received = u16::from_le_bytes([crc[0], crc[1]]);
+    if computed != received {
+        return Err(Dnp3Error::CrcMismatch {
+            block: index,
+            computed,
+            received,
+        });
+    }
+    Ok(())
+}
+
+/// Taille sur le fil d'une trame dont le champ longueur vaut `length` :
+/// en-tete et son CRC, puis chaque bloc de donnees et son CRC.
+pub fn dnp3_frame_len(length: u8) -> usize {
+    let user_data = usize::from(length.saturating_sub(DNP3_MIN_LENGTH_FIELD));
+    DNP3_LINK_HEADER_LEN
+        + DNP3_CRC_LEN
+        + user_data
+        + user_data.div_ceil(DNP3_BLOCK_LEN) * DNP3_CRC_LEN
+}
+
+/// Verifie debut, champ longueur et CRC d'en-tete, et retourne la taille
+/// de la trame sur le fil.
+pub fn extract_dnp3_frame_len(stream: &[u8]) -> Result<usize, Dnp3Error> {
+    let header_len = DNP3_LINK_HEADER_LEN + DNP3_CRC_LEN;
+    if stream.len() < header_len {
+        return Err(Dnp3Error::InvalidLength {
+            expected: header_len,
+            actual: stream.len(),
+        });
+    }
+    let start = u16::from_be_bytes([stream[0], stream[1]]);
+    if start != DNP3_START {
+        return Err(Dnp3Error::InvalidStart(start));
+    }
+    if stream[2] < DNP3_MIN_LENGTH_FIELD {
+        return Err(Dnp3Error::InvalidFrameLength(stream[2]));
+    }
+    validate_dnp3_crc(
+        &stream[..DNP3_LINK_HEADER_LEN],
+        &stream[DNP3_LINK_HEADER_LEN..header_len],
+        0,
+    )?;
+    let frame_len = dnp3_frame_len(stream[2]);
+    if stream.len() < frame_len {
+        return Err(Dnp3Error::InvalidLength {
+            expected: frame_len,
+            actual: stream.len(),
+        });
+    }
+    Ok(frame_len)
+}
+
+pub fn extract_dnp3_bytes<'a>(
+    buf: &'a [u8],
+    len: usize,
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), Dnp3Error> {
+    if buf.len() < len {
+        return Err(Dnp3Error::Truncated { field });
+    }
+    Ok(buf.split_at(len))
+}
+
+/// Entier little-endian non signe de 1, 2 ou 4 octets (index, plages,
+/// compteurs et tailles des en-tetes d'objets).
+pub fn extract_dnp3_uint<'a>(
+    buf: &'a [u8],
+    width: usize,
+    field: &'static str,
+) -> Result<(u32, &'a [u8]), Dnp3Error> {
+    let (bytes, rest) = extract_dnp3_bytes(buf, width, field)?;
+    let value = bytes
+        .iter()
+        .rev()
+        .fold(0u32, |value, byte| (value << 8) | u32::from(*byte));
+    Ok((value, rest))
+}
+
+/// Largeur en octets d'un code de prefixe : index (1-3) ou taille (4-6)
+/// sur 1, 2 ou 4 octets.
+pub fn dnp3_prefix_width(prefix_code: u8) -> Option<usize> {
+    match prefix_code {
+        0 => Some(0),
+        1 | 4 => Some(1),
+        2 | 5 => Some(2),
+        3 | 6 => Some(4),
+        _ => None,
+    }
+}
+
+/// Taille d'un objet d'une variation a taille fixe ; `Some(0)` pour les
+/// variations sans donnees (donnees de classe). Les variations a bits
+/// packes (g1v1, g3v1, g10v1, g12v3, g80v1) sont traitees a part.
+pub fn dnp3_object_size(group: u8, variation: u8) -> Option<usize> {
+    let size = match (group, variation) {
+        // Entrees et sorties binaires, double-bit, avec flags.
+        (1 | 3 | 10, 2) | (2 | 4 | 11 | 13, 1) => 1,
+        (2 | 4 | 11 | 13, 2) => 7,
+        (2 | 4, 3) => 3,
+        // Bloc de commande (CROB) et Pattern Control Block.
+        (12, 1 | 2) => 11,
+        // Compteurs : 32 ou 16 bits, avec ou sans flags, avec ou sans temps.
+        (20..=23, 1) => 5,
+        (20..=23, 2) => 3,
+        (20, 5) | (21, 9) => 4,
+        (20, 6) | (21, 10) => 2,
+        (21..=23, 5) => 11,
+        (21..=23, 6) => 9,
+        // Entrees analogiques et evenements.
+        (30 | 32 | 40 | 41 | 42, 1) | (30 | 32 | 42, 5) | (40 | 41, 3) => 5,
+        (30 | 32 | 40 | 41 | 42, 2) => 3,
+        (30, 3) => 4,
+        (30, 4) => 2,
+        (30, 6) | (32, 6) | (40, 4) | (41, 4) | (42, 6) => 9,
+        (32, 3) | (32, 7) | (42, 3) | (42, 7) => 11,
+        (32, 4) | (42, 4) => 9,
+        (32, 8) | (42, 8) => 15,
+        // Bandes mortes.
+        (34, 1) => 2,
+        (34, 2 | 3) => 4,
+        // Temps et date, CTO, delai.
+        (50, 1 | 3) | (51, 1 | 2) => 6,
+        (50, 2) => 10,
+        (50, 4) => 11,
+        (52, 1 | 2) => 2,
+        // Donnees de classe : jamais de valeur.
+        (60, 1..=4) => 0,
+        // Chaines d'octets : la variation est la longueur.
+        (110 | 111, length) => usize::from(length),
+        _ => return None,
+    };
+    Some(size)
+}
+
+/// Variations dont chaque point occupe un bit (ou deux pour g3v1).
+pub fn dnp3_packed_bits(group: u8, variation: u8) -> Option<usize> {
+    match (group, variation) {
+        (1 | 10 | 80, 1) | (12, 3) => Some(1),
+        (3, 1) => Some(2),
+        _ => None,
+    }
+}
+
+/// Fonctions dont les en-tetes d'objets ne portent pas de valeurs : les
+/// lectures et demandes designent des points sans les decrire.
+pub fn dnp3_function_carries_values(function_code: u8) -> bool {
+    matches!(
+        function_code,
+        DNP3_FC_WRITE
+            | DNP3_FC_SELECT
+            | DNP3_FC_OPERATE
+            | DNP3_FC_DIRECT_OPERATE
+            | DNP3_FC_DIRECT_OPERATE_NR
+            | 0x0B
+            | 0x0C
+            | DNP3_FC_RESPONSE
+            | DNP3_FC_UNSOLICITED_RESPONSE
+    )
+}
+
+pub fn dnp3_link_function_name(control: u8) -> &'static str {
+    let function = control & 0x0F;
+    if control & DNP3_LINK_PRM != 0 {
+        match function {
+            0 => "RESET_LINK_STATES",
+            2 => "TEST_LINK_STATES",
+            DNP3_LINK_CONFIRMED_USER_DATA => "CONFIRMED_USER_DATA",
+            DNP3_LINK_UNCONFIRMED_USER_DATA => "UNCONFIRMED_USER_DATA",
+            9 => "REQUEST_LINK_STATUS",
+            _ => "Unknown",
+        }
+    } else {
+        match function {
+            0 => "ACK",
+            1 => "NACK",
+            11 => "LINK_STATUS",
+            15 => "NOT_SUPPORTED",
+            _ => "Unknown",
+        }
+    }
+}
+
+pub fn dnp3_function_name(function_code: u8) -> &'static str {
+    match function_code {
+        DNP3_FC_CONFIRM => "CONFIRM",
+        DNP3_FC_READ => "READ",
+        DNP3_FC_WRITE => "WRITE",
+        DNP3_FC_SELECT => "SELECT",
+        DNP3_FC_OPERATE => "OPERATE",
+        DNP3_FC_DIRECT_OPERATE => "DIRECT_OPERATE",
+        DNP3_FC_DIRECT_OPERATE_NR => "DIRECT_OPERATE_NR",
+        0x07 => "IMMED_FREEZE",
+        0x08 => "IMMED_FREEZE_NR",
+        0x09 => "FREEZE_CLEAR",
+        0x0A => "FREEZE_CLEAR_NR",
+        0x0B => "FREEZE_AT_TIME",
+        0x0C => "FREEZE_AT_TIME_NR",
+        DNP3_FC_COLD_RESTART => "COLD_RESTART",
+        DNP3_FC_WARM_RESTART => "WARM_RESTART",
+        0x0F => "INITIALIZE_DATA",
+        0x10 => "INITIALIZE_APPL",
+        0x11 => "START_APPL",
+        0x12 => "STOP_APPL",
+        0x13 => "SAVE_CONFIG",
+        0x14 => "ENABLE_UNSOLICITED",
+        0x15 => "DISABLE_UNSOLICITED",
+        0x16 => "ASSIGN_CLASS",
+        0x17 => "DELAY_MEASURE",
+        0x18 => "RECORD_CURRENT_TIME",
+        0x19 => "OPEN_FILE",
+        0x1A => "CLOSE_FILE",
+        0x1B => "DELETE_FILE",
+        0x1C => "GET_FILE_INFO",
+        0x1D => "AUTHENTICATE_FILE",
+        0x1E => "ABORT_FILE",
+        0x1F => "ACTIVATE_CONFIG",
+        0x20 => "AUTHENTICATE_REQ",
+        0x21 => "AUTH_REQ_NO_ACK",
+        DNP3_FC_RESPONSE => "RESPONSE",
+        DNP3_FC_UNSOLICITED_RESPONSE => "UNSOLICITED_RESPONSE",
+        DNP3_FC_AUTHENTICATE_RESPONSE => "AUTHENTICATE_RESPONSE",
+        _ => "Unknown",
+    }
+}
+
+/// Fonctions qui changent l'etat du poste : commandes, ecritures,
+/// redemarrages et arret d'applications.
+pub fn dnp3_function_is_control(function_code: u8) -> bool {
+    matches!(
+        function_code,
+        DNP3_FC_WRITE..=DNP3_FC_DIRECT_OPERATE_NR
+            | 0x09..=0x0C
+            | DNP3_FC_COLD_RESTART..=0x13
+            | 0x16
+            | 0x1B
+            | 0x1F
+    )
+}
+
+pub fn dnp3_group_name(group: u8) -> &'static str {
+    match group {
+        1 => "Binary Input",
+        2 => "Binary Input Event",
+        3 => "Double-bit Binary Input",
+        4 => "Double-bit Binary Input Event",
+        10 => "Binary Output",
+        11 => "Binary Output Event",
+        12 => "Binary Command",
+        13 => "Binary Command Event",
+        20 => "Counter",
+        21 => "Frozen Counter",
+        22 => "Counter Event",
+        23 => "Frozen Counter Event",
+        30 => "Analog Input",
+        32 => "Analog Input Event",
+        34 => "Analog Input Deadband",
+        40 => "Analog Output Status",
+        41 => "Analog Output",
+        42 => "Analog Output Event",
+        50 => "Time and Date",
+        51 => "Common Time of Occurrence",
+        52 => "Time Delay",
+        60 => "Class Data",
+        70 => "File Control",
+        80 => "Internal Indications",
+        110 => "Octet String",
+        111 => "Octet String Event",
+        120 => "Authentication",
+        _ => "Unknown",
+    }
+}
+
+/// Bits des Internal Indications (IIN1 en octet de poids faible).
+pub fn dnp3_iin_names(iin: u16) -> Vec<&'static str> {
+    const NAMES: [&str; 16] = [
+        "BROADCAST",
+        "CLASS_1_EVENTS",
+        "CLASS_2_EVENTS",
+        "CLASS_3_EVENTS",
+        "NEED_TIME",
+        "LOCAL_CONTROL",
+        "DEVICE_TROUBLE",
+        "DEVICE_RESTART",
+        "NO_FUNC_CODE_SUPPORT",
+        "OBJECT_UNKNOWN",
+        "PARAMETER_ERROR",
+        "EVENT_BUFFER_OVERFLOW",
+        "ALREADY_EXECUTING",
+        "CONFIG_CORRUPT",
+        "RESERVED_2",
+        "RESERVED_1",
+    ];
+    NAMES
+        .iter()
+        .enumerate()
+        .filter(|(bit, _)| iin & (1 << bit) != 0)
+        .map(|(_, name)| *name)
+        .collect()
+}
+
+/// Code de controle d'un CROB (g12v1).
+pub fn dnp3_control_code_name(control_code: u8) -> &'static str {
+    match control_code & 0x0F {
+        0 => "NUL",
+        1 => "PULSE_ON",
+        2 => "PULSE_OFF",
+        3 => "LATCH_ON",
+        4 => "LATCH_OFF",
+        _ => "Unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crc_matches_reference_check_value() {
+        // Valeur de controle du catalogue CRC pour "123456789".
+        assert_eq!(dnp3_crc(b"123456789"), 0xEA82);
+        let header = [0x05, 0x64, 0x05, 0xC0, 0x01, 0x00, 0x00, 0x00];
+        assert_eq!(validate_dnp3_crc(&header, &[0x91, 0xF8], 0), Ok(()));
+        assert_eq!(
+            validate_dnp3_crc(&header, &[0x91, 0xF9], 0),
+            Err(Dnp3Error::CrcMismatch {
+                block: 0,
+                computed: 0xF891,
+                received: 0xF991
+            })
+        );
+    }
+
+    #[test]
+    fn frame_len_counts_block_crcs() {
+        assert_eq!(dnp3_frame_len(5), 10);
+        // 16 octets de donnees : un bloc et son CRC.
+        assert_eq!(dnp3_frame_len(21), 28);
+        // 17 octets : deux blocs.
+        assert_eq!(dnp3_frame_len(22), 31);
+        assert_eq!(dnp3_frame_len(255), 292);
+    }
+
+    #[test]
+    fn object_sizes_and_names() {
+        assert_eq!(dnp3_object_size(30, 1), Some(5));
+        assert_eq!(dnp3_object_size(12, 1), Some(11));
+        assert_eq!(dnp3_object_size(110, 12), Some(12));
+        assert_eq!(dnp3_object_size(30, 0), None);
+        assert_eq!(dnp3_packed_bits(3, 1), Some(2));
+        assert_eq!(
+            extract_dnp3_uint(&[0x34, 0x12, 0xFF], 2, "index"),
+            Ok((0x1234, &[0xFF][..]))
+        );
+        assert_eq!(dnp3_function_name(DNP3_FC_DIRECT_OPERATE), "DIRECT_OPERATE");
+        assert!(dnp3_function_is_control(DNP3_FC_COLD_RESTART));
+        assert!(!dnp3_function_is_control(DNP3_FC_READ));
+        assert_eq!(
+            dnp3_iin_names(0x0082),
+            vec!["CLASS_1_EVENTS", "DEVICE_RESTART"]
+        );
+        assert_eq!(dnp3_link_function_name(0xC4), "UNCONFIRMED_USER_DATA");
+    }
+}
@@ -4,7 +4,7 @@
 // This file may not be copied, modified, or distributed except according to those terms.
 
 use crate::errors::application::dns::{
-    DnsFlagsError, DnsHeaderError, DnsPacketError, DnsQueryParseError,
+    DnsFlagsError, DnsHeaderError, DnsPacketError, DnsQueryParseError, DnsRDataError,
 };
 
 pub const DNS_MINIMUM_SIZE: usize = 12;
@@ -101,6 +101,91 @@ pub fn check_dns_label_bounds(
     Ok(())
 }
 
+// Tailles fixes des RDATA d'adresse (RFC 1035 §3.4.1, RFC 3596 §2.2).
+pub const DNS_A_RDATA_LEN: usize = 4;
+pub const DNS_AAAA_RDATA_LEN: usize = 16;
+
+// Codes d'option EDNS0 decodes (registre IANA "DNS EDNS0 Option Codes").
+pub const EDNS_OPTION_CLIENT_SUBNET: u16 = 8;
+pub const EDNS_OPTION_COOKIE: u16 = 10;
+pub const EDNS_OPTION_PADDING: u16 = 12;
+
+// Familles d'adresse de l'option Client Subnet (RFC 7871 §6, registre IANA
+// "Address Family Numbers").
+pub const EDNS_CLIENT_SUBNET_FAMILY_IPV4: u16 = 1;
+pub const EDNS_CLIENT_SUBNET_FAMILY_IPV6: u16 = 2;
+
+// Cookies DNS (RFC 7873 §4) : client de 8 octets, serveur de 8 a 32.
+pub const EDNS_CLIENT_COOKIE_LEN: usize = 8;
+pub const EDNS_SERVER_COOKIE_MIN_LEN: usize = 8;
+pub const EDNS_SERVER_COOKIE_MAX_LEN: usize = 32;
+
+// Cles SvcParam (RFC 9460 §14.3.2, RFC 9461 pour dohpath).
+pub const SVC_PARAM_MANDATORY: u16 = 0;
+pub const SVC_PARAM_ALPN: u16 = 1;
+pub const SVC_PARAM_NO_DEFAULT_ALPN: u16 = 2;
+pub const SVC_PARAM_PORT: u16 = 3;
+pub const SVC_PARAM_IPV4_HINT: u16 = 4;
+pub const SVC_PARAM_ECH: u16 = 5;
+pub const SVC_PARAM_IPV6_HINT: u16 = 6;
+pub const SVC_PARAM_DOH_PATH: u16 = 7;
+
+/// Taille maximale d'une fenetre de bitmap de types NSEC/NSEC3
+/// (RFC 4034 §4.1.2) : 256 types, 32 octets.
+pub const DNS_TYPE_BITMAP_MAX_WINDOW_LEN: usize = 32;
+
+/// Extrait un champ de RDATA dont la longueur est connue ou annoncee.
+pub fn extract_dns_rdata_field<'a>(
+    bytes: &'a [u8],
+    declared: usize,
+    field: &'static str,
+) -> Result<&'a [u8], DnsRDataError> {
+    bytes.get(..declared).ok_or(DnsRDataError::TruncatedField {
+        field,
+        declared,
+        available: bytes.len(),
+    })
+}
+
+/// Extrait une `<character-string>` (RFC 1035 §3.3) : un octet de longueur
+/// suivi d'autant d'octets. Retourne la chaine et les octets qui suivent.
+pub fn extract_dns_character_string<'a>(
+    bytes: &'a [u8],
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), DnsRDataError> {
+    let length = extract_dns_rdata_field(bytes, 1, field)?[0] as usize;
+    let content = extract_dns_rdata_field(&bytes[1..], length, field)?;
+    Ok((content, &bytes[1 + length..]))
+}
+
+/// Verifie qu'une RDATA a ete entierement consommee.
+pub fn validate_dns_rdata_no_trailing_data(
+    rest: &[u8],
+    field: &'static str,
+) -> Result<(), DnsRDataError> {
+    if !rest.is_empty() {
+        return Err(DnsRDataError::TrailingData {
+            field,
+            remaining: rest.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Valide une fenetre de bitmap de types (RFC 4034 §4.1.2) : numeros de
+/// fenetre strictement croissants, longueur de 1 a 32 octets.
+pub fn validate_type_bitmap_window(
+    previous: Option<u8>,
+    window: u8,
+    length: usize,
+) -> Result<(), DnsRDataError> {
+    let ordered = previous.is_none_or(|previous| window > previous);
+    if !ordered || length == 0 || length > DNS_TYPE_BITMAP_MAX_WINDOW_LEN {
+        return Err(DnsRDataError::InvalidTypeBitmap { window, length });
+    }
+    Ok(())
+}
+
 pub fn verify_dns_flags(flags: u16) -> Result<u16, DnsFlagsError> {
     let (qr, opcode, aa, tc, _rd, ra, z, rcode) = extract_dns_flags(flags);
 
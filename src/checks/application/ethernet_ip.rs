@@ -4,7 +4,7 @@
 // This file may not be copied, modified, or distributed except according to those terms.
 
 use crate::{
-    errors::application::ethernet_ip::EtherNetIpError,
+    errors::application::ethernet_ip::{CipError, EtherNetIpError},
     parse::application::protocols::ethernet_ip::EtherNetIpCommand,
 };
 
@@ -14,6 +14,62 @@ pub const COMMON_PACKET_FORMAT_MIN_LEN: usize = 8;
 /// Un item CPF pèse au minimum 4 octets : type_id 2 + length 2.
 pub const CPF_ITEM_HEADER_LEN: usize = 4;
 
+pub const CPF_ITEM_NULL_ADDRESS: u16 = 0x0000;
+pub const CPF_ITEM_LIST_IDENTITY: u16 = 0x000C;
+pub const CPF_ITEM_CONNECTED_ADDRESS: u16 = 0x00A1;
+pub const CPF_ITEM_CONNECTED_DATA: u16 = 0x00B1;
+pub const CPF_ITEM_UNCONNECTED_DATA: u16 = 0x00B2;
+pub const CPF_ITEM_SEQUENCED_ADDRESS: u16 = 0x8002;
+
+/// Port UDP des I/O implicites (classe 0/1).
+pub const ENIP_IO_UDP_PORT: u16 = 2222;
+
+/// Bit posé sur le code de service d'une réponse CIP.
+pub const CIP_SERVICE_REPLY: u8 = 0x80;
+
+pub const CIP_SERVICE_GET_ATTRIBUTES_ALL: u8 = 0x01;
+pub const CIP_SERVICE_SET_ATTRIBUTES_ALL: u8 = 0x02;
+pub const CIP_SERVICE_RESET: u8 = 0x05;
+pub const CIP_SERVICE_START: u8 = 0x06;
+pub const CIP_SERVICE_STOP: u8 = 0x07;
+pub const CIP_SERVICE_MULTIPLE_SERVICE_PACKET: u8 = 0x0A;
+pub const CIP_SERVICE_GET_ATTRIBUTE_SINGLE: u8 = 0x0E;
+pub const CIP_SERVICE_SET_ATTRIBUTE_SINGLE: u8 = 0x10;
+
+/// Services du Connection Manager (classe 0x06).
+pub const CIP_SERVICE_FORWARD_CLOSE: u8 = 0x4E;
+pub const CIP_SERVICE_UNCONNECTED_SEND: u8 = 0x52;
+pub const CIP_SERVICE_FORWARD_OPEN: u8 = 0x54;
+pub const CIP_SERVICE_LARGE_FORWARD_OPEN: u8 = 0x5B;
+
+/// Services Logix sur les tags (chemin symbolique ou classe Symbol 0x6B).
+/// 0x4E et 0x52 recouvrent Forward Close et Unconnected Send : seule la
+/// classe visée les distingue.
+pub const CIP_SERVICE_READ_TAG: u8 = 0x4C;
+pub const CIP_SERVICE_WRITE_TAG: u8 = 0x4D;
+pub const CIP_SERVICE_READ_MODIFY_WRITE_TAG: u8 = 0x4E;
+pub const CIP_SERVICE_READ_TAG_FRAGMENTED: u8 = 0x52;
+pub const CIP_SERVICE_WRITE_TAG_FRAGMENTED: u8 = 0x53;
+
+pub const CIP_CLASS_IDENTITY: u32 = 0x01;
+pub const CIP_CLASS_MESSAGE_ROUTER: u32 = 0x02;
+pub const CIP_CLASS_CONNECTION_MANAGER: u32 = 0x06;
+pub const CIP_CLASS_SYMBOL: u32 = 0x6B;
+
+/// Segment EPATH ANSI Extended Symbol : nom de tag Logix.
+pub const CIP_SEGMENT_SYMBOLIC: u8 = 0x91;
+/// Segment logique Electronic Key (format 4, 8 octets).
+pub const CIP_SEGMENT_ELECTRONIC_KEY: u8 = 0x34;
+/// Segment de données simple : longueur en mots puis données.
+pub const CIP_SEGMENT_SIMPLE_DATA: u8 = 0x80;
+
+/// Type Logix structure : suivi d'un handle de structure u16.
+pub const CIP_DATA_TYPE_STRUCTURE: u16 = 0x02A0;
+
+/// Connexions d'I/O implicites suivies au plus par un tracker avant
+/// d'évincer la moins récemment vue.
+pub const CIP_MAX_IO_CONNECTIONS: usize = 256;
+
 pub fn validate_min_length(packet: &[u8]) -> Result<(), EtherNetIpError> {
     if packet.len() < ENCAPSULATION_HEADER_LEN {
         return Err(EtherNetIpError::PacketTooShort {
@@ -269,6 +325,206 @@ pub fn extract_cpf_item(
     Ok((type_id, &data[item_header_end..item_end], item_end))
 }
 
+pub fn extract_cip_u8<'a>(buf: &'a [u8], field: &'static str) -> Result<(u8, &'a [u8]), CipError> {
+    match buf {
+        [value, rest @ ..] => Ok((*value, rest)),
+        [] => Err(CipError::Truncated { field }),
+    }
+}
+
+pub fn extract_cip_u16<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(u16, &'a [u8]), CipError> {
+    match buf {
+        [low, high, rest @ ..] => Ok((u16::from_le_bytes([*low, *high]), rest)),
+        _ => Err(CipError::Truncated { field }),
+    }
+}
+
+pub fn extract_cip_u32<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(u32, &'a [u8]), CipError> {
+    match buf {
+        [a, b, c, d, rest @ ..] => Ok((u32::from_le_bytes([*a, *b, *c, *d]), rest)),
+        _ => Err(CipError::Truncated { field }),
+    }
+}
+
+pub fn extract_cip_bytes<'a>(
+    buf: &'a [u8],
+    len: usize,
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), CipError> {
+    if buf.len() < len {
+        return Err(CipError::Truncated { field });
+    }
+    Ok(buf.split_at(len))
+}
+
+/// Chemin EPATH précédé de sa taille en mots de 16 bits (u8), forme des
+/// requêtes et des chemins de connexion.
+pub fn extract_cip_word_sized<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), CipError> {
+    let (words, rest) = extract_cip_u8(buf, field)?;
+    extract_cip_bytes(rest, usize::from(words) * 2, field)
+}
+
+pub fn validate_cip_no_trailing_data(rest: &[u8], field: &'static str) -> Result<(), CipError> {
+    if !rest.is_empty() {
+        return Err(CipError::TrailingData {
+            field,
+            length: rest.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Nom de tag : ASCII imprimable, sans quoi le segment n'est pas un
+/// symbole Logix.
+pub fn validate_cip_symbol(symbol: &[u8]) -> Result<&str, CipError> {
+    if !symbol
+        .iter()
+        .all(|byte| byte.is_ascii_graphic() || *byte == b' ')
+    {
+        return Err(CipError::InvalidSymbol);
+    }
+    std::str::from_utf8(symbol).map_err(|_| CipError::InvalidSymbol)
+}
+
+/// Nom d'un service CIP commun (Vol. 1, annexe A). Les services 0x4B-0x63
+/// sont propres à chaque classe : `class` lève l'ambiguïté pour le
+/// Connection Manager et les tags Logix.
+pub fn cip_service_name(service: u8, class: Option<u32>) -> &'static str {
+    match (service & !CIP_SERVICE_REPLY, class) {
+        (CIP_SERVICE_GET_ATTRIBUTES_ALL, _) => "Get Attributes All",
+        (CIP_SERVICE_SET_ATTRIBUTES_ALL, _) => "Set Attributes All",
+        (0x03, _) => "Get Attribute List",
+        (0x04, _) => "Set Attribute List",
+        (CIP_SERVICE_RESET, _) => "Reset",
+        (CIP_SERVICE_START, _) => "Start",
+        (CIP_SERVICE_STOP, _) => "Stop",
+        (0x08, _) => "Create",
+        (0x09, _) => "Delete",
+        (CIP_SERVICE_MULTIPLE_SERVICE_PACKET, _) => "Multiple Service Packet",
+        (0x0D, _) => "Apply Attributes",
+        (CIP_SERVICE_GET_ATTRIBUTE_SINGLE, _) => "Get Attribute Single",
+        (CIP_SERVICE_SET_ATTRIBUTE_SINGLE, _) => "Set Attribute Single",
+        (0x11, _) => "Find Next Object Instance",
+        (0x15, _) => "Restore",
+        (0x16, _) => "Save",
+        (0x17, _) => "No Operation",
+        (0x18, _) => "Get Member",
+        (0x19, _) => "Set Member",
+        (CIP_SERVICE_FORWARD_CLOSE, Some(CIP_CLASS_CONNECTION_MANAGER)) => "Forward Close",
+        (CIP_SERVICE_UNCONNECTED_SEND, Some(CIP_CLASS_CONNECTION_MANAGER)) => "Unconnected Send",
+        (CIP_SERVICE_FORWARD_OPEN, Some(CIP_CLASS_CONNECTION_MANAGER)) => "Forward Open",
+        (0x5A, Some(CIP_CLASS_CONNECTION_MANAGER)) => "Get Connection Owner",
+        (CIP_SERVICE_LARGE_FORWARD_OPEN, Some(CIP_CLASS_CONNECTION_MANAGER)) => {
+            "Large Forward Open"
+        }
+        (CIP_SERVICE_READ_TAG, None | Some(CIP_CLASS_SYMBOL)) => "Read Tag",
+        (CIP_SERVICE_WRITE_TAG, None | Some(CIP_CLASS_SYMBOL)) => "Write Tag",
+        (CIP_SERVICE_READ_MODIFY_WRITE_TAG, None | Some(CIP_CLASS_SYMBOL)) => {
+            "Read Modify Write Tag"
+        }
+        (CIP_SERVICE_READ_TAG_FRAGMENTED, None | Some(CIP_CLASS_SYMBOL)) => "Read Tag Fragmented",
+        (CIP_SERVICE_WRITE_TAG_FRAGMENTED, None | Some(CIP_CLASS_SYMBOL)) => "Write Tag Fragmented",
+        _ => "Unknown",
+    }
+}
+
+/// Nom d'un statut général CIP (Vol. 1, annexe B).
+pub fn cip_general_status_name(status: u8) -> &'static str {
+    match status {
+        0x00 => "Success",
+        0x01 => "Connection failure",
+        0x02 => "Resource unavailable",
+        0x03 => "Invalid parameter value",
+        0x04 => "Path segment error",
+        0x05 => "Path destination unknown",
+        0x06 => "Partial transfer",
+        0x07 => "Connection lost",
+        0x08 => "Service not supported",
+        0x09 => "Invalid attribute value",
+        0x0A => "Attribute list error",
+        0x0B => "Already in requested mode/state",
+        0x0C => "Object state conflict",
+        0x0D => "Object already exists",
+        0x0E => "Attribute not settable",
+        0x0F => "Privilege violation",
+        0x10 => "Device state conflict",
+        0x11 => "Reply data too large",
+        0x13 => "Not enough data",
+        0x14 => "Attribute not supported",
+        0x15 => "Too much data",
+        0x16 => "Object does not exist",
+        0x1E => "Embedded service error",
+        0x1F => "Vendor specific error",
+        0x20 => "Invalid parameter",
+        0x26 => "Path size invalid",
+        _ => "Unknown",
+    }
+}
+
+/// Nom d'une classe d'objet CIP courante.
+pub fn cip_class_name(class: u32) -> &'static str {
+    match class {
+        CIP_CLASS_IDENTITY => "Identity",
+        CIP_CLASS_MESSAGE_ROUTER => "Message Router",
+        0x04 => "Assembly",
+        0x05 => "Connection",
+        CIP_CLASS_CONNECTION_MANAGER => "Connection Manager",
+        0x37 => "File",
+        0x47 => "Device Level Ring",
+        0x48 => "QoS",
+        CIP_CLASS_SYMBOL => "Symbol",
+        0x6C => "Template",
+        0x8E => "Controller",
+        0xF4 => "Port",
+        0xF5 => "TCP/IP Interface",
+        0xF6 => "Ethernet Link",
+        _ => "Unknown",
+    }
+}
+
+/// Nom d'un type d'équipement de l'objet Identity (attribut 2).
+pub fn cip_device_type_name(device_type: u16) -> &'static str {
+    match device_type {
+        0x00 => "Generic Device",
+        0x02 => "AC Drive",
+        0x07 => "General Purpose Discrete I/O",
+        0x0C => "Communications Adapter",
+        0x0E => "Programmable Logic Controller",
+        0x18 => "Human-Machine Interface",
+        0x2B => "Generic Device (keyable)",
+        _ => "Unknown",
+    }
+}
+
+/// Nom d'un type de donnée atomique Logix.
+pub fn cip_data_type_name(data_type: u16) -> &'static str {
+    match data_type {
+        0x00C1 => "BOOL",
+        0x00C2 => "SINT",
+        0x00C3 => "INT",
+        0x00C4 => "DINT",
+        0x00C5 => "LINT",
+        0x00C6 => "USINT",
+        0x00C7 => "UINT",
+        0x00C8 => "UDINT",
+        0x00C9 => "ULINT",
+        0x00CA => "REAL",
+        0x00CB => "LREAL",
+        0x00D3 => "DWORD",
+        CIP_DATA_TYPE_STRUCTURE => "STRUCT",
+        _ => "Unknown",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -446,4 +702,48 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn test_extract_cip_word_sized() {
+        // Chemin de 2 mots : classe 0x02, instance 1.
+        let (path, rest) = extract_cip_word_sized(&[0x02, 0x20, 0x02, 0x24, 0x01, 0xFF], "path")
+            .expect("chemin complet");
+        assert_eq!(path, &[0x20, 0x02, 0x24, 0x01]);
+        assert_eq!(rest, &[0xFF]);
+
+        assert_eq!(
+            extract_cip_word_sized(&[0x02, 0x20, 0x02], "path"),
+            Err(CipError::Truncated { field: "path" })
+        );
+        assert_eq!(
+            validate_cip_no_trailing_data(&[0x00], "path"),
+            Err(CipError::TrailingData {
+                field: "path",
+                length: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_cip_symbol() {
+        assert_eq!(validate_cip_symbol(b"Program:Main"), Ok("Program:Main"));
+        assert_eq!(
+            validate_cip_symbol(&[0x41, 0x00]),
+            Err(CipError::InvalidSymbol)
+        );
+    }
+
+    #[test]
+    fn test_cip_names() {
+        assert_eq!(cip_service_name(0x54, Some(0x06)), "Forward Open");
+        assert_eq!(cip_service_name(0xD4, Some(0x06)), "Forward Open");
+        assert_eq!(cip_service_name(0x52, Some(0x06)), "Unconnected Send");
+        assert_eq!(cip_service_name(0x52, None), "Read Tag Fragmented");
+        // 0x4C sur la classe 0x8E n'est pas un Read Tag.
+        assert_eq!(cip_service_name(0x4C, Some(0x8E)), "Unknown");
+        assert_eq!(cip_general_status_name(0x05), "Path destination unknown");
+        assert_eq!(cip_class_name(0x06), "Connection Manager");
+        assert_eq!(cip_device_type_name(0x0C), "Communications Adapter");
+        assert_eq!(cip_data_type_name(0x00C4), "DINT");
+    }
 }
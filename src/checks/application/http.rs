@@ -3,7 +3,7 @@
 // Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
 // This file may not be copied, modified, or distributed except according to those terms.
 
-use crate::errors::application::http::HttpParseError;
+use crate::errors::application::http::{HttpMessageError, HttpParseError};
 
 /// Separator between the head (request line + headers) and the body.
 const HEAD_BODY_SEPARATOR: &str = "\r\n\r\n";
@@ -90,6 +90,90 @@ pub fn extract_header_line(line: &str) -> Result<(&str, &str), HttpParseError> {
     Ok((name, value))
 }
 
+/// Largest head (start line + header fields) buffered while waiting for the
+/// `CRLF CRLF` separator of a streamed message.
+pub const HTTP_MAX_HEAD_LEN: usize = 64 * 1024;
+
+/// Largest chunk-size line (extensions included) or trailer field line.
+pub const HTTP_MAX_CHUNK_LINE_LEN: usize = 4096;
+
+/// Returns the offset just past the first `CRLF CRLF` of a raw payload, i.e.
+/// where the body starts, or `None` while the head is incomplete.
+pub fn find_head_end(payload: &[u8]) -> Option<usize> {
+    payload
+        .windows(HEAD_BODY_SEPARATOR.len())
+        .position(|window| window == HEAD_BODY_SEPARATOR.as_bytes())
+        .map(|index| index + HEAD_BODY_SEPARATOR.len())
+}
+
+/// Requires the status code token of a status line: exactly three digits,
+/// from 100 to 599 (RFC 9110 §15).
+pub fn require_status_code(part: Option<&str>) -> Result<u16, HttpMessageError> {
+    let code = part.ok_or(HttpMessageError::MissingStatusCode)?;
+    let invalid = || HttpMessageError::InvalidStatusCode(code.to_string());
+    if code.len() != 3 || !code.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    match code.parse() {
+        Ok(status @ 100..=599) => Ok(status),
+        _ => Err(invalid()),
+    }
+}
+
+/// Parses a `Content-Length` value.
+///
+/// A list of identical values (`42, 42`), as left by a proxy merging
+/// duplicated fields, is accepted as that single value (RFC 9110 §8.6);
+/// differing values are refused, since the message boundary is then
+/// ambiguous.
+pub fn parse_content_length(value: &str) -> Result<usize, HttpMessageError> {
+    let mut length = None;
+    for part in value.split(',').map(str::trim) {
+        if part.is_empty() || !part.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(HttpMessageError::InvalidContentLength(value.to_string()));
+        }
+        let parsed: usize = part
+            .parse()
+            .map_err(|_| HttpMessageError::InvalidContentLength(value.to_string()))?;
+        match length {
+            Some(first) if first != parsed => {
+                return Err(HttpMessageError::ConflictingContentLength {
+                    first,
+                    second: parsed,
+                });
+            }
+            _ => length = Some(parsed),
+        }
+    }
+    length.ok_or_else(|| HttpMessageError::InvalidContentLength(value.to_string()))
+}
+
+/// Parses a chunk-size line, CRLF excluded (RFC 9112 §7.1): hexadecimal
+/// digits, optionally followed by `;` extensions, which are ignored.
+pub fn parse_chunk_size(line: &[u8]) -> Result<usize, HttpMessageError> {
+    let invalid = || HttpMessageError::InvalidChunkSize(String::from_utf8_lossy(line).into_owned());
+    let size = match line.iter().position(|&byte| byte == b';') {
+        Some(index) => line[..index].trim_ascii_end(),
+        None => line,
+    };
+    // `from_str_radix` tolererait un signe `+` : seuls des chiffres hexa.
+    if size.is_empty() || !size.iter().all(u8::is_ascii_hexdigit) {
+        return Err(invalid());
+    }
+    std::str::from_utf8(size)
+        .ok()
+        .and_then(|size| usize::from_str_radix(size, 16).ok())
+        .ok_or_else(invalid)
+}
+
+/// Checks the CRLF that closes the data of a chunk.
+pub fn validate_chunk_terminator(bytes: &[u8]) -> Result<(), HttpMessageError> {
+    if bytes != b"\r\n" {
+        return Err(HttpMessageError::MissingChunkTerminator);
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,4 +302,67 @@ mod tests {
             Err(HttpParseError::InvalidHeader)
         );
     }
+
+    #[test]
+    fn test_find_head_end() {
+        assert_eq!(find_head_end(b"HTTP/1.1 200 OK\r\n\r\nbody"), Some(19));
+        assert_eq!(find_head_end(b"HTTP/1.1 200 OK\r\n"), None);
+    }
+
+    #[test]
+    fn test_require_status_code() {
+        assert_eq!(require_status_code(Some("200")), Ok(200));
+        assert_eq!(require_status_code(Some("599")), Ok(599));
+        assert_eq!(
+            require_status_code(None),
+            Err(HttpMessageError::MissingStatusCode)
+        );
+        for code in ["099", "600", "20", "2000", "+20", "abc"] {
+            assert_eq!(
+                require_status_code(Some(code)),
+                Err(HttpMessageError::InvalidStatusCode(code.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_content_length() {
+        assert_eq!(parse_content_length("3308"), Ok(3308));
+        assert_eq!(parse_content_length("42, 42"), Ok(42));
+        assert_eq!(
+            parse_content_length("42, 43"),
+            Err(HttpMessageError::ConflictingContentLength {
+                first: 42,
+                second: 43
+            })
+        );
+        for value in ["", "-1", "+1", "0x10", "1 2", "99999999999999999999999"] {
+            assert_eq!(
+                parse_content_length(value),
+                Err(HttpMessageError::InvalidContentLength(value.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_chunk_size() {
+        assert_eq!(parse_chunk_size(b"219"), Ok(0x219));
+        assert_eq!(parse_chunk_size(b"1aF ; name=value"), Ok(0x1af));
+        assert_eq!(parse_chunk_size(b"0"), Ok(0));
+        for line in [&b""[..], b"+1", b"g", b";ext", b"ffffffffffffffffff"] {
+            assert!(matches!(
+                parse_chunk_size(line),
+                Err(HttpMessageError::InvalidChunkSize(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn test_validate_chunk_terminator() {
+        assert_eq!(validate_chunk_terminator(b"\r\n"), Ok(()));
+        assert_eq!(
+            validate_chunk_terminator(b"xy"),
+            Err(HttpMessageError::MissingChunkTerminator)
+        );
+    }
 }
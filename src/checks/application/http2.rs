@@ -0,0 +1,251 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use crate::errors::application::http2::{GrpcError, Http2Error};
+
+/// Client connection preface (RFC 9113 §3.4).
+pub const HTTP2_PREFACE: &[u8] = b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n";
+
+/// Taille de l'en-tete de trame (RFC 9113 §4.1).
+pub const HTTP2_FRAME_HEADER_LEN: usize = 9;
+
+/// SETTINGS_MAX_FRAME_SIZE initial ; le plafond annonçable est 2^24 - 1.
+pub const HTTP2_DEFAULT_MAX_FRAME_SIZE: usize = 16_384;
+pub const HTTP2_MAX_FRAME_SIZE_LIMIT: usize = (1 << 24) - 1;
+
+/// SETTINGS_HEADER_TABLE_SIZE initial (RFC 7541 §4.2).
+pub const HPACK_DEFAULT_TABLE_SIZE: usize = 4096;
+
+/// Plafond applique au SETTINGS_HEADER_TABLE_SIZE annonce : la valeur tient
+/// sur 32 bits, mais les implementations courantes ne depassent pas 64 Kio.
+pub const HPACK_MAX_TABLE_SIZE: usize = 64 * 1024;
+
+/// Types de trame (RFC 9113 §6).
+pub const HTTP2_FRAME_DATA: u8 = 0x0;
+pub const HTTP2_FRAME_HEADERS: u8 = 0x1;
+pub const HTTP2_FRAME_PRIORITY: u8 = 0x2;
+pub const HTTP2_FRAME_RST_STREAM: u8 = 0x3;
+pub const HTTP2_FRAME_SETTINGS: u8 = 0x4;
+pub const HTTP2_FRAME_PUSH_PROMISE: u8 = 0x5;
+pub const HTTP2_FRAME_PING: u8 = 0x6;
+pub const HTTP2_FRAME_GOAWAY: u8 = 0x7;
+pub const HTTP2_FRAME_WINDOW_UPDATE: u8 = 0x8;
+pub const HTTP2_FRAME_CONTINUATION: u8 = 0x9;
+
+/// Drapeaux de trame.
+pub const HTTP2_FLAG_END_STREAM: u8 = 0x1;
+pub const HTTP2_FLAG_ACK: u8 = 0x1;
+pub const HTTP2_FLAG_END_HEADERS: u8 = 0x4;
+pub const HTTP2_FLAG_PADDED: u8 = 0x8;
+pub const HTTP2_FLAG_PRIORITY: u8 = 0x20;
+
+/// Identifiants de parametres SETTINGS (RFC 9113 §6.5.2).
+pub const HTTP2_SETTINGS_HEADER_TABLE_SIZE: u16 = 0x1;
+pub const HTTP2_SETTINGS_MAX_FRAME_SIZE: u16 = 0x5;
+
+/// Plafond d'un bloc d'en-tetes reassemble (HEADERS + CONTINUATION), comme
+/// `HTTP_MAX_HEAD_LEN` pour une tete HTTP/1.x.
+pub const HTTP2_MAX_HEADER_BLOCK_LEN: usize = 64 * 1024;
+
+/// Prefixe d'un message gRPC : drapeau de compression puis longueur u32.
+pub const GRPC_MESSAGE_PREFIX_LEN: usize = 5;
+
+/// Taille maximale de message acceptee par defaut par les implementations
+/// gRPC : au-dela, le reassembleur refuse de mettre le message en tampon.
+pub const GRPC_DEFAULT_MAX_MESSAGE_LEN: usize = 4 * 1024 * 1024;
+
+/// Appels gRPC ouverts conserves par connexion avant de clore le plus
+/// ancien, bien au-dela du SETTINGS_MAX_CONCURRENT_STREAMS de 100 des
+/// implementations courantes.
+pub const GRPC_MAX_OPEN_CALLS: usize = 256;
+
+/// Whether `payload` starts with the client connection preface.
+pub fn is_http2_preface(payload: &[u8]) -> bool {
+    payload.starts_with(HTTP2_PREFACE)
+}
+
+/// Splits the 9-byte frame header into length, type, flags and stream id
+/// (reserved bit cleared). `None` when fewer than 9 bytes are available.
+pub fn extract_http2_frame_header(bytes: &[u8]) -> Option<(usize, u8, u8, u32)> {
+    let header = bytes.get(..HTTP2_FRAME_HEADER_LEN)?;
+    let length =
+        usize::from(header[0]) << 16 | usize::from(header[1]) << 8 | usize::from(header[2]);
+    let stream_id = u32::from_be_bytes([header[5], header[6], header[7], header[8]]) & 0x7fff_ffff;
+    Some((length, header[3], header[4], stream_id))
+}
+
+/// Refuses a frame longer than the receiver's SETTINGS_MAX_FRAME_SIZE.
+pub fn validate_http2_frame_length(length: usize, max: usize) -> Result<(), Http2Error> {
+    if length > max {
+        return Err(Http2Error::FrameTooLarge { length, max });
+    }
+    Ok(())
+}
+
+/// Checks the stream identifier a frame type requires: connection-level
+/// frames (SETTINGS, PING, GOAWAY) on stream 0, stream frames elsewhere.
+/// WINDOW_UPDATE is valid on both.
+pub fn validate_http2_stream_id(frame_type: u8, stream_id: u32) -> Result<(), Http2Error> {
+    let connection_level = matches!(
+        frame_type,
+        HTTP2_FRAME_SETTINGS | HTTP2_FRAME_PING | HTTP2_FRAME_GOAWAY
+    );
+    let stream_level = matches!(
+        frame_type,
+        HTTP2_FRAME_DATA
+            | HTTP2_FRAME_HEADERS
+            | HTTP2_FRAME_PRIORITY
+            | HTTP2_FRAME_RST_STREAM
+            | HTTP2_FRAME_PUSH_PROMISE
+            | HTTP2_FRAME_CONTINUATION
+    );
+    if (connection_level && stream_id != 0) || (stream_level && stream_id == 0) {
+        return Err(Http2Error::InvalidStreamId {
+            frame_type,
+            stream_id,
+        });
+    }
+    Ok(())
+}
+
+/// Requires a frame payload of exactly `expected` bytes.
+pub fn validate_http2_payload_length(
+    frame_type: u8,
+    payload: &[u8],
+    expected: usize,
+) -> Result<(), Http2Error> {
+    if payload.len() != expected {
+        return Err(Http2Error::InvalidFrameLength {
+            frame_type,
+            length: payload.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Removes the Pad Length byte and the trailing padding of a PADDED frame
+/// (RFC 9113 §6.1).
+pub fn strip_http2_padding(payload: &[u8], flags: u8) -> Result<&[u8], Http2Error> {
+    if flags & HTTP2_FLAG_PADDED == 0 {
+        return Ok(payload);
+    }
+    let (&pad_length, rest) = payload.split_first().ok_or(Http2Error::InvalidPadding)?;
+    let end = rest
+        .len()
+        .checked_sub(usize::from(pad_length))
+        .ok_or(Http2Error::InvalidPadding)?;
+    Ok(&rest[..end])
+}
+
+/// Whether a `content-type` value announces gRPC: `application/grpc`,
+/// optionally followed by `+proto`, `+json`... or parameters.
+pub fn is_grpc_content_type(value: &str) -> bool {
+    let value = value.trim().as_bytes();
+    value.len() >= 16
+        && value[..16].eq_ignore_ascii_case(b"application/grpc")
+        && matches!(value.get(16), None | Some(b'+' | b';'))
+}
+
+/// Splits the 5-byte prefix of a gRPC Length-Prefixed-Message into its
+/// compressed flag and length.
+pub fn extract_grpc_prefix(bytes: &[u8]) -> Result<(bool, usize), GrpcError> {
+    let prefix = bytes
+        .get(..GRPC_MESSAGE_PREFIX_LEN)
+        .ok_or(GrpcError::TruncatedPrefix {
+            available: bytes.len(),
+        })?;
+    let compressed = match prefix[0] {
+        0 => false,
+        1 => true,
+        flag => return Err(GrpcError::InvalidCompressedFlag(flag)),
+    };
+    let length = u32::from_be_bytes([prefix[1], prefix[2], prefix[3], prefix[4]]) as usize;
+    Ok((compressed, length))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_header_splits_and_clears_the_reserved_bit() {
+        // Synthetique : HEADERS de 0x000d octets, END_HEADERS, flux 1 avec
+        // le bit reserve positionne.
+        let header = [0x00, 0x00, 0x0d, 0x01, 0x04, 0x80, 0x00, 0x00, 0x01];
+        assert_eq!(
+            extract_http2_frame_header(&header),
+            Some((13, HTTP2_FRAME_HEADERS, HTTP2_FLAG_END_HEADERS, 1))
+        );
+        assert_eq!(extract_http2_frame_header(&header[..8]), None);
+    }
+
+    #[test]
+    fn stream_ids_follow_the_frame_type() {
+        assert!(validate_http2_stream_id(HTTP2_FRAME_SETTINGS, 0).is_ok());
+        assert!(validate_http2_stream_id(HTTP2_FRAME_WINDOW_UPDATE, 0).is_ok());
+        assert!(validate_http2_stream_id(HTTP2_FRAME_WINDOW_UPDATE, 3).is_ok());
+        assert_eq!(
+            validate_http2_stream_id(HTTP2_FRAME_PING, 1),
+            Err(Http2Error::InvalidStreamId {
+                frame_type: HTTP2_FRAME_PING,
+                stream_id: 1
+            })
+        );
+        assert!(validate_http2_stream_id(HTTP2_FRAME_DATA, 0).is_err());
+        // Type inconnu : ignore, quel que soit le flux.
+        assert!(validate_http2_stream_id(0x42, 0).is_ok());
+    }
+
+    #[test]
+    fn padding_is_stripped_or_rejected() {
+        assert_eq!(
+            strip_http2_padding(b"\x02abcPP", HTTP2_FLAG_PADDED),
+            Ok(&b"abc"[..])
+        );
+        assert_eq!(strip_http2_padding(b"abc", 0), Ok(&b"abc"[..]));
+        assert_eq!(
+            strip_http2_padding(b"\x05ab", HTTP2_FLAG_PADDED),
+            Err(Http2Error::InvalidPadding)
+        );
+        assert_eq!(
+            strip_http2_padding(b"", HTTP2_FLAG_PADDED),
+            Err(Http2Error::InvalidPadding)
+        );
+    }
+
+    #[test]
+    fn grpc_prefix_decodes() {
+        assert_eq!(extract_grpc_prefix(b"\x00\x00\x00\x00\x07"), Ok((false, 7)));
+        assert_eq!(
+            extract_grpc_prefix(b"\x01\x00\x01\x00\x00"),
+            Ok((true, 65_536))
+        );
+        assert_eq!(
+            extract_grpc_prefix(b"\x02\x00\x00\x00\x00"),
+            Err(GrpcError::InvalidCompressedFlag(2))
+        );
+        assert_eq!(
+            extract_grpc_prefix(b"\x00\x00"),
+            Err(GrpcError::TruncatedPrefix { available: 2 })
+        );
+    }
+
+    #[test]
+    fn grpc_content_types() {
+        assert!(is_grpc_content_type("application/grpc"));
+        assert!(is_grpc_content_type("application/grpc+proto"));
+        assert!(is_grpc_content_type("Application/gRPC; charset=utf-8"));
+        assert!(!is_grpc_content_type("application/grpc-web"));
+        assert!(!is_grpc_content_type("application/json"));
+    }
+
+    #[test]
+    fn preface_is_recognised() {
+        assert!(is_http2_preface(
+            b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n\x00\x00\x00\x04"
+        ));
+        assert!(!is_http2_preface(b"PRI * HTTP/2.0\r\n"));
+    }
+}
@@ -0,0 +1,139 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use crate::{checks::application::quic::QuicCursor, errors::application::http3::Http3Error};
+
+/// Types de trame (RFC 9114 §7.2).
+pub const HTTP3_FRAME_DATA: u64 = 0x00;
+pub const HTTP3_FRAME_HEADERS: u64 = 0x01;
+pub const HTTP3_FRAME_CANCEL_PUSH: u64 = 0x03;
+pub const HTTP3_FRAME_SETTINGS: u64 = 0x04;
+pub const HTTP3_FRAME_PUSH_PROMISE: u64 = 0x05;
+pub const HTTP3_FRAME_GOAWAY: u64 = 0x07;
+pub const HTTP3_FRAME_MAX_PUSH_ID: u64 = 0x0d;
+
+/// Types de trame HTTP/2 sans equivalent HTTP/3 : PRIORITY, PING,
+/// WINDOW_UPDATE et CONTINUATION (RFC 9114 §7.2.8).
+pub const HTTP3_RESERVED_HTTP2_FRAMES: [u64; 4] = [0x02, 0x06, 0x08, 0x09];
+
+/// Types de flux unidirectionnels (RFC 9114 §6.2, RFC 9204 §4.2).
+pub const HTTP3_STREAM_CONTROL: u64 = 0x00;
+pub const HTTP3_STREAM_PUSH: u64 = 0x01;
+pub const HTTP3_STREAM_QPACK_ENCODER: u64 = 0x02;
+pub const HTTP3_STREAM_QPACK_DECODER: u64 = 0x03;
+
+/// Identifiants de parametres SETTINGS (RFC 9114 §7.2.4.1, RFC 9204 §5).
+pub const HTTP3_SETTINGS_QPACK_MAX_TABLE_CAPACITY: u64 = 0x01;
+pub const HTTP3_SETTINGS_MAX_FIELD_SECTION_SIZE: u64 = 0x06;
+pub const HTTP3_SETTINGS_QPACK_BLOCKED_STREAMS: u64 = 0x07;
+
+/// Plafond d'une trame autre que DATA, mise en tampon jusqu'a sa fin : les
+/// sections d'en-tetes suivent le plafond de [`HTTP2_MAX_HEADER_BLOCK_LEN`](super::http2::HTTP2_MAX_HEADER_BLOCK_LEN).
+/// Les trames DATA ne sont jamais mises en tampon.
+pub const HTTP3_MAX_FRAME_LEN: usize = 64 * 1024;
+
+/// Plafond d'une instruction du flux encodeur QPACK en attente de ses
+/// derniers octets.
+pub const QPACK_MAX_INSTRUCTION_LEN: usize = 64 * 1024;
+
+/// Sections d'en-tetes bloquees gardees par sens, en attente des insertions
+/// du flux encodeur (borne independante de SETTINGS_QPACK_BLOCKED_STREAMS).
+pub const QPACK_MAX_BLOCKED_SECTIONS: usize = 64;
+
+/// Flux de requete ou de push suivis par sens avant d'abandonner le plus
+/// ancien ; borne aussi les identifiants de flux clos retenus pour ignorer
+/// les retransmissions tardives.
+pub const HTTP3_MAX_TRACKED_STREAMS: usize = 256;
+
+/// Plafond de la table dynamique QPACK : SETTINGS_QPACK_MAX_TABLE_CAPACITY
+/// est un varint de 62 bits, borne ici comme la table HPACK par
+/// [`HPACK_MAX_TABLE_SIZE`](super::http2::HPACK_MAX_TABLE_SIZE).
+pub const QPACK_MAX_TABLE_CAPACITY: usize = 64 * 1024;
+
+/// Whether `frame_type` is a reserved "grease" type of the form
+/// `0x1f * N + 0x21`, sent to exercise the ignore-unknown rule
+/// (RFC 9114 §7.2.8). The same form reserves stream types and settings.
+pub fn is_http3_grease(value: u64) -> bool {
+    value >= 0x21 && (value - 0x21).is_multiple_of(0x1f)
+}
+
+/// Refuses a frame type inherited from HTTP/2 without an HTTP/3 meaning.
+pub fn validate_http3_frame_type(frame_type: u64) -> Result<(), Http3Error> {
+    if HTTP3_RESERVED_HTTP2_FRAMES.contains(&frame_type) {
+        return Err(Http3Error::ReservedFrameType(frame_type));
+    }
+    Ok(())
+}
+
+/// Splits a frame header into type, payload length and header size (two
+/// varints). `None` when the header is not complete yet.
+pub fn extract_http3_frame_header(bytes: &[u8]) -> Option<(u64, u64, usize)> {
+    let mut cur = QuicCursor::new(bytes);
+    let frame_type = cur.read_varint().ok()?;
+    let length = cur.read_varint().ok()?;
+    Some((frame_type, length, bytes.len() - cur.remaining()))
+}
+
+/// Refuses a frame other than DATA longer than [`HTTP3_MAX_FRAME_LEN`].
+pub fn validate_http3_frame_length(frame_type: u64, length: u64) -> Result<(), Http3Error> {
+    if frame_type != HTTP3_FRAME_DATA && length > HTTP3_MAX_FRAME_LEN as u64 {
+        return Err(Http3Error::FrameTooLarge {
+            frame_type,
+            length,
+            max: HTTP3_MAX_FRAME_LEN,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn grease_values() {
+        assert!(is_http3_grease(0x21));
+        assert!(is_http3_grease(0x40));
+        assert!(is_http3_grease(0x1f * 1000 + 0x21));
+        assert!(!is_http3_grease(0x20));
+        assert!(!is_http3_grease(0x41));
+    }
+
+    #[test]
+    fn frame_header_splits_two_varints() {
+        // Type SETTINGS, longueur 0x4010 sur deux octets.
+        assert_eq!(
+            extract_http3_frame_header(&[0x04, 0x40, 0x10, 0xff]),
+            Some((HTTP3_FRAME_SETTINGS, 0x10, 3))
+        );
+        assert_eq!(extract_http3_frame_header(&[0x04, 0x40]), None);
+    }
+
+    #[test]
+    fn http2_frame_types_are_reserved() {
+        assert_eq!(
+            validate_http3_frame_type(0x09),
+            Err(Http3Error::ReservedFrameType(0x09))
+        );
+        assert_eq!(validate_http3_frame_type(HTTP3_FRAME_GOAWAY), Ok(()));
+    }
+
+    #[test]
+    fn only_data_frames_escape_the_length_limit() {
+        let too_long = HTTP3_MAX_FRAME_LEN as u64 + 1;
+        assert_eq!(
+            validate_http3_frame_length(HTTP3_FRAME_DATA, too_long),
+            Ok(())
+        );
+        assert_eq!(
+            validate_http3_frame_length(HTTP3_FRAME_HEADERS, too_long),
+            Err(Http3Error::FrameTooLarge {
+                frame_type: HTTP3_FRAME_HEADERS,
+                length: too_long,
+                max: HTTP3_MAX_FRAME_LEN,
+            })
+        );
+    }
+}
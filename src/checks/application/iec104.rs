@@ -0,0 +1,332 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Controles de taille et de coherence pour IEC 60870-5-104.
+//!
+//! L'APCI tient en 6 octets : `0x68`, la longueur de l'APDU (4 a 253) et
+//! quatre octets de controle dont les deux bits de poids faible donnent le
+//! format I, S ou U. Le seul octet de debut est une signature faible : la
+//! sonde exige aussi des octets de controle coherents avec le format et
+//! n'est sollicitee que sur le port 2404.
+
+use crate::errors::application::iec104::Iec104Error;
+
+/// Octet de debut d'APDU.
+pub const IEC104_START: u8 = 0x68;
+
+/// Debut, longueur et quatre octets de controle.
+pub const IEC104_APCI_LEN: usize = 6;
+
+/// Bornes du champ longueur : les quatre octets de controle seuls, jusqu'a
+/// un ASDU de 249 octets.
+pub const IEC104_MIN_APDU_LENGTH: u8 = 4;
+pub const IEC104_MAX_APDU_LENGTH: u8 = 253;
+
+/// Type, VSQ, cause de transmission sur 2 octets et adresse commune sur 2
+/// octets (parametres par defaut de la norme 104).
+pub const IEC104_ASDU_HEADER_LEN: usize = 6;
+
+/// Adresse d'objet d'information sur 3 octets.
+pub const IEC104_IOA_LEN: usize = 3;
+
+/// Bit SQ du VSQ : objets consecutifs a partir d'une seule adresse.
+pub const IEC104_VSQ_SEQUENCE: u8 = 0x80;
+pub const IEC104_VSQ_COUNT_MASK: u8 = 0x7F;
+
+/// Bits de l'octet de cause : test, confirmation negative, cause sur 6 bits.
+pub const IEC104_COT_TEST: u8 = 0x80;
+pub const IEC104_COT_NEGATIVE: u8 = 0x40;
+pub const IEC104_COT_CAUSE_MASK: u8 = 0x3F;
+
+/// Fonctions U (octet de controle 1).
+pub const IEC104_U_STARTDT_ACT: u8 = 0x07;
+pub const IEC104_U_STARTDT_CON: u8 = 0x0B;
+pub const IEC104_U_STOPDT_ACT: u8 = 0x13;
+pub const IEC104_U_STOPDT_CON: u8 = 0x23;
+pub const IEC104_U_TESTFR_ACT: u8 = 0x43;
+pub const IEC104_U_TESTFR_CON: u8 = 0x83;
+
+/// Temps binaire CP56Time2a (7 octets).
+pub const IEC104_CP56_LEN: usize = 7;
+
+/// Identifiants de type decodes en valeurs.
+pub const IEC104_M_SP_NA_1: u8 = 1;
+pub const IEC104_M_DP_NA_1: u8 = 3;
+pub const IEC104_M_ST_NA_1: u8 = 5;
+pub const IEC104_M_BO_NA_1: u8 = 7;
+pub const IEC104_M_ME_NA_1: u8 = 9;
+pub const IEC104_M_ME_NB_1: u8 = 11;
+pub const IEC104_M_ME_NC_1: u8 = 13;
+pub const IEC104_M_IT_NA_1: u8 = 15;
+pub const IEC104_M_ME_ND_1: u8 = 21;
+pub const IEC104_M_SP_TB_1: u8 = 30;
+pub const IEC104_M_DP_TB_1: u8 = 31;
+pub const IEC104_M_ST_TB_1: u8 = 32;
+pub const IEC104_M_BO_TB_1: u8 = 33;
+pub const IEC104_M_ME_TD_1: u8 = 34;
+pub const IEC104_M_ME_TE_1: u8 = 35;
+pub const IEC104_M_ME_TF_1: u8 = 36;
+pub const IEC104_M_IT_TB_1: u8 = 37;
+pub const IEC104_C_SC_NA_1: u8 = 45;
+pub const IEC104_C_DC_NA_1: u8 = 46;
+pub const IEC104_C_RC_NA_1: u8 = 47;
+pub const IEC104_C_SE_NA_1: u8 = 48;
+pub const IEC104_C_SE_NB_1: u8 = 49;
+pub const IEC104_C_SE_NC_1: u8 = 50;
+pub const IEC104_C_BO_NA_1: u8 = 51;
+pub const IEC104_C_SC_TA_1: u8 = 58;
+pub const IEC104_C_DC_TA_1: u8 = 59;
+pub const IEC104_C_RC_TA_1: u8 = 60;
+pub const IEC104_C_SE_TA_1: u8 = 61;
+pub const IEC104_C_SE_TB_1: u8 = 62;
+pub const IEC104_C_SE_TC_1: u8 = 63;
+pub const IEC104_C_BO_TA_1: u8 = 64;
+pub const IEC104_M_EI_NA_1: u8 = 70;
+pub const IEC104_C_IC_NA_1: u8 = 100;
+pub const IEC104_C_CI_NA_1: u8 = 101;
+pub const IEC104_C_RD_NA_1: u8 = 102;
+pub const IEC104_C_CS_NA_1: u8 = 103;
+pub const IEC104_C_RP_NA_1: u8 = 105;
+pub const IEC104_C_TS_TA_1: u8 = 107;
+pub const IEC104_F_SG_NA_1: u8 = 125;
+
+/// Verifie l'APCI en tete de `stream` et retourne la longueur de l'APDU
+/// sur le fil (debut et longueur compris).
+pub fn extract_iec104_apdu_len(stream: &[u8]) -> Result<usize, Iec104Error> {
+    if stream.len() < IEC104_APCI_LEN {
+        return Err(Iec104Error::InvalidLength {
+            expected: IEC104_APCI_LEN,
+            actual: stream.len(),
+        });
+    }
+    if stream[0] != IEC104_START {
+        return Err(Iec104Error::InvalidStart(stream[0]));
+    }
+    let length = stream[1];
+    if !(IEC104_MIN_APDU_LENGTH..=IEC104_MAX_APDU_LENGTH).contains(&length) {
+        return Err(Iec104Error::InvalidApduLength(length));
+    }
+    let apdu_len = usize::from(length) + 2;
+    if stream.len() < apdu_len {
+        return Err(Iec104Error::TruncatedApdu {
+            declared: apdu_len,
+            available: stream.len(),
+        });
+    }
+    Ok(apdu_len)
+}
+
+pub fn extract_iec104_bytes<'a>(
+    buf: &'a [u8],
+    len: usize,
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), Iec104Error> {
+    if buf.len() < len {
+        return Err(Iec104Error::Truncated { field });
+    }
+    Ok(buf.split_at(len))
+}
+
+/// Taille d'un element d'information (sans l'adresse) pour un type ;
+/// `None` pour un type inconnu ou de taille variable (segment de fichier).
+pub fn iec104_element_size(type_id: u8) -> Option<usize> {
+    let size = match type_id {
+        // Information de processus en surveillance.
+        1 | 3 => 1,
+        2 | 4 => 4,
+        5 | 21 => 2,
+        6 | 7 | 9 | 11 | 13 | 15 | 20 => 5,
+        8 | 14 | 16 | 30 | 31 => 8,
+        10 | 12 | 17 => 6,
+        18 | 19 => 7,
+        32 => 9,
+        33 | 36 | 37 => 12,
+        34 | 35 | 38 => 10,
+        39 | 40 => 11,
+        // Commandes.
+        45..=47 => 1,
+        48 | 49 => 3,
+        50 => 5,
+        51 => 4,
+        58..=60 => 8,
+        61 | 62 => 10,
+        63 => 12,
+        64 => 11,
+        // Systeme.
+        IEC104_M_EI_NA_1 | IEC104_C_IC_NA_1 | IEC104_C_CI_NA_1 | IEC104_C_RP_NA_1 => 1,
+        IEC104_C_RD_NA_1 => 0,
+        IEC104_C_CS_NA_1 => IEC104_CP56_LEN,
+        104 | 106 => 2,
+        IEC104_C_TS_TA_1 => 9,
+        // Parametres.
+        110 | 111 => 3,
+        112 => 5,
+        113 => 1,
+        // Transfert de fichiers.
+        120 => 6,
+        121 => 7,
+        122 | 124 => 4,
+        123 => 5,
+        126 => 13,
+        127 => 16,
+        _ => return None,
+    };
+    Some(size)
+}
+
+pub fn iec104_type_name(type_id: u8) -> &'static str {
+    match type_id {
+        1 => "M_SP_NA_1",
+        2 => "M_SP_TA_1",
+        3 => "M_DP_NA_1",
+        4 => "M_DP_TA_1",
+        5 => "M_ST_NA_1",
+        6 => "M_ST_TA_1",
+        7 => "M_BO_NA_1",
+        8 => "M_BO_TA_1",
+        9 => "M_ME_NA_1",
+        10 => "M_ME_TA_1",
+        11 => "M_ME_NB_1",
+        12 => "M_ME_TB_1",
+        13 => "M_ME_NC_1",
+        14 => "M_ME_TC_1",
+        15 => "M_IT_NA_1",
+        16 => "M_IT_TA_1",
+        17 => "M_EP_TA_1",
+        18 => "M_EP_TB_1",
+        19 => "M_EP_TC_1",
+        20 => "M_PS_NA_1",
+        21 => "M_ME_ND_1",
+        30 => "M_SP_TB_1",
+        31 => "M_DP_TB_1",
+        32 => "M_ST_TB_1",
+        33 => "M_BO_TB_1",
+        34 => "M_ME_TD_1",
+        35 => "M_ME_TE_1",
+        36 => "M_ME_TF_1",
+        37 => "M_IT_TB_1",
+        38 => "M_EP_TD_1",
+        39 => "M_EP_TE_1",
+        40 => "M_EP_TF_1",
+        45 => "C_SC_NA_1",
+        46 => "C_DC_NA_1",
+        47 => "C_RC_NA_1",
+        48 => "C_SE_NA_1",
+        49 => "C_SE_NB_1",
+        50 => "C_SE_NC_1",
+        51 => "C_BO_NA_1",
+        58 => "C_SC_TA_1",
+        59 => "C_DC_TA_1",
+        60 => "C_RC_TA_1",
+        61 => "C_SE_TA_1",
+        62 => "C_SE_TB_1",
+        63 => "C_SE_TC_1",
+        64 => "C_BO_TA_1",
+        70 => "M_EI_NA_1",
+        100 => "C_IC_NA_1",
+        101 => "C_CI_NA_1",
+        102 => "C_RD_NA_1",
+        103 => "C_CS_NA_1",
+        104 => "C_TS_NA_1",
+        105 => "C_RP_NA_1",
+        106 => "C_CD_NA_1",
+        107 => "C_TS_TA_1",
+        110 => "P_ME_NA_1",
+        111 => "P_ME_NB_1",
+        112 => "P_ME_NC_1",
+        113 => "P_AC_NA_1",
+        120 => "F_FR_NA_1",
+        121 => "F_SR_NA_1",
+        122 => "F_SC_NA_1",
+        123 => "F_LS_NA_1",
+        124 => "F_AF_NA_1",
+        125 => "F_SG_NA_1",
+        126 => "F_DR_TA_1",
+        127 => "F_SC_NB_1",
+        _ => "Unknown",
+    }
+}
+
+/// Types de commande et de parametre : ils agissent sur le poste.
+pub fn iec104_type_is_command(type_id: u8) -> bool {
+    matches!(type_id, 45..=64 | 103..=107 | 110..=113)
+}
+
+pub fn iec104_cause_name(cause: u8) -> &'static str {
+    match cause & IEC104_COT_CAUSE_MASK {
+        1 => "periodic",
+        2 => "background scan",
+        3 => "spontaneous",
+        4 => "initialized",
+        5 => "request",
+        6 => "activation",
+        7 => "activation confirmation",
+        8 => "deactivation",
+        9 => "deactivation confirmation",
+        10 => "activation termination",
+        11 => "return information remote",
+        12 => "return information local",
+        13 => "file transfer",
+        20 => "interrogated by station interrogation",
+        21..=36 => "interrogated by group interrogation",
+        37 => "requested by general counter request",
+        38..=41 => "requested by group counter request",
+        44 => "unknown type identification",
+        45 => "unknown cause of transmission",
+        46 => "unknown common address of ASDU",
+        47 => "unknown information object address",
+        _ => "Unknown",
+    }
+}
+
+pub fn iec104_u_function_name(function: u8) -> &'static str {
+    match function {
+        IEC104_U_STARTDT_ACT => "STARTDT act",
+        IEC104_U_STARTDT_CON => "STARTDT con",
+        IEC104_U_STOPDT_ACT => "STOPDT act",
+        IEC104_U_STOPDT_CON => "STOPDT con",
+        IEC104_U_TESTFR_ACT => "TESTFR act",
+        IEC104_U_TESTFR_CON => "TESTFR con",
+        _ => "Unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apdu_length_bounds() {
+        assert_eq!(extract_iec104_apdu_len(&[0x68, 0x04, 0x07, 0, 0, 0]), Ok(6));
+        assert_eq!(
+            extract_iec104_apdu_len(&[0x68, 0x03, 0x07, 0, 0, 0]),
+            Err(Iec104Error::InvalidApduLength(3))
+        );
+        assert_eq!(
+            extract_iec104_apdu_len(&[0x68, 0x0E, 0, 0, 0, 0, 0x64]),
+            Err(Iec104Error::TruncatedApdu {
+                declared: 16,
+                available: 7
+            })
+        );
+        assert_eq!(
+            extract_iec104_apdu_len(&[0x69, 0x04, 0x07, 0, 0, 0]),
+            Err(Iec104Error::InvalidStart(0x69))
+        );
+    }
+
+    #[test]
+    fn element_sizes_and_names() {
+        assert_eq!(iec104_element_size(IEC104_M_ME_NC_1), Some(5));
+        assert_eq!(iec104_element_size(IEC104_M_SP_TB_1), Some(8));
+        assert_eq!(iec104_element_size(IEC104_C_RD_NA_1), Some(0));
+        assert_eq!(iec104_element_size(IEC104_F_SG_NA_1), None);
+        assert_eq!(iec104_type_name(IEC104_C_IC_NA_1), "C_IC_NA_1");
+        assert!(iec104_type_is_command(IEC104_C_SC_NA_1));
+        assert!(!iec104_type_is_command(IEC104_M_SP_NA_1));
+        assert_eq!(iec104_cause_name(0x47), "activation confirmation");
+        assert_eq!(iec104_u_function_name(IEC104_U_TESTFR_CON), "TESTFR con");
+    }
+}
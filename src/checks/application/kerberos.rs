@@ -0,0 +1,306 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Controles et noms pour Kerberos V5 (RFC 4120).
+//!
+//! Sur UDP, un datagramme porte exactement un message DER. Sur TCP, chaque
+//! message est precede de sa longueur sur 4 octets big-endian dont le bit
+//! de poids fort est reserve (RFC 4120 §7.2.2) : un serveur qui recoit ce
+//! bit a 1 doit repondre KRB_ERR_FIELD_TOOLONG, un analyseur peut donc
+//! l'utiliser pour rejeter ce qui n'est pas du Kerberos.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+
+use crate::errors::application::kerberos::KerberosError;
+
+pub const KERBEROS_PORT: u16 = 88;
+/// Seule version du protocole en service (pvno).
+pub const KERBEROS_PVNO: i64 = 5;
+/// Prefixe de longueur des messages sur TCP.
+pub const KERBEROS_TCP_LENGTH_LEN: usize = 4;
+pub const KERBEROS_TCP_RESERVED_BIT: u32 = 0x8000_0000;
+
+/// Numeros d'etiquette APPLICATION des messages, egaux a leur msg-type.
+pub const KRB_AS_REQ: u32 = 10;
+pub const KRB_AS_REP: u32 = 11;
+pub const KRB_TGS_REQ: u32 = 12;
+pub const KRB_TGS_REP: u32 = 13;
+pub const KRB_AP_REQ: u32 = 14;
+pub const KRB_AP_REP: u32 = 15;
+pub const KRB_ERROR: u32 = 30;
+/// Ticket [APPLICATION 1] et EncKDCRepPart hors perimetre (chiffre).
+pub const KRB_TICKET: u32 = 1;
+
+/// OID du mecanisme GSS-API Kerberos V5 (RFC 4121) et de ses variantes.
+pub const OID_KERBEROS_V5: &[u8] = &[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x12, 0x01, 0x02, 0x02];
+/// OID Kerberos tronque historique de Windows 2000 (1.2.840.48018.1.2.2).
+pub const OID_MS_KERBEROS_V5: &[u8] = &[0x2A, 0x86, 0x48, 0x82, 0xF7, 0x12, 0x01, 0x02, 0x02];
+pub const OID_KERBEROS_V5_USER_TO_USER: &[u8] =
+    &[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x12, 0x01, 0x02, 0x02, 0x03];
+/// TOK_ID d'un jeton GSS-API Kerberos (RFC 4121 §4.1) : AP-REQ, AP-REP,
+/// KRB-ERROR.
+pub const KRB_TOKEN_AP_REQ: [u8; 2] = [0x01, 0x00];
+pub const KRB_TOKEN_AP_REP: [u8; 2] = [0x02, 0x00];
+pub const KRB_TOKEN_ERROR: [u8; 2] = [0x03, 0x00];
+
+/// Types de pre-authentification utiles a l'audit (IANA Kerberos PA-DATA).
+pub const PA_TGS_REQ: i64 = 1;
+pub const PA_ENC_TIMESTAMP: i64 = 2;
+pub const PA_ETYPE_INFO2: i64 = 19;
+pub const PA_PAC_REQUEST: i64 = 128;
+
+/// Types de chiffrement (RFC 3961, RFC 3962, RFC 4757, RFC 8009).
+pub const ETYPE_DES_CBC_CRC: i64 = 1;
+pub const ETYPE_DES_CBC_MD5: i64 = 3;
+pub const ETYPE_AES128_CTS_HMAC_SHA1_96: i64 = 17;
+pub const ETYPE_AES256_CTS_HMAC_SHA1_96: i64 = 18;
+pub const ETYPE_RC4_HMAC: i64 = 23;
+pub const ETYPE_RC4_HMAC_EXP: i64 = 24;
+
+/// Extrait un message Kerberos encadre sur TCP et retourne les octets
+/// qui le suivent.
+pub fn extract_kerberos_tcp_record(stream: &[u8]) -> Result<(&[u8], &[u8]), KerberosError> {
+    let Some((length, rest)) = stream.split_first_chunk::<KERBEROS_TCP_LENGTH_LEN>() else {
+        return Err(KerberosError::InvalidLength {
+            expected: KERBEROS_TCP_LENGTH_LEN,
+            actual: stream.len(),
+        });
+    };
+    let length = u32::from_be_bytes(*length);
+    if length & KERBEROS_TCP_RESERVED_BIT != 0 {
+        return Err(KerberosError::ReservedLengthBit);
+    }
+    let declared = length as usize;
+    if rest.len() < declared {
+        return Err(KerberosError::TruncatedRecord {
+            declared,
+            available: rest.len(),
+        });
+    }
+    Ok(rest.split_at(declared))
+}
+
+/// Verifie le pvno et la coherence du msg-type avec l'etiquette APPLICATION.
+pub fn validate_kerberos_header(tag: u32, pvno: i64, msg_type: i64) -> Result<(), KerberosError> {
+    if pvno != KERBEROS_PVNO {
+        return Err(KerberosError::InvalidVersion(pvno));
+    }
+    if msg_type != i64::from(tag) {
+        return Err(KerberosError::MessageTypeMismatch { tag, msg_type });
+    }
+    Ok(())
+}
+
+/// KerberosTime : GeneralizedTime UTC sans fraction, `YYYYMMDDHHMMSSZ`.
+pub fn extract_kerberos_time(
+    bytes: &[u8],
+    field: &'static str,
+) -> Result<DateTime<Utc>, KerberosError> {
+    std::str::from_utf8(bytes)
+        .ok()
+        .and_then(|text| NaiveDateTime::parse_from_str(text, "%Y%m%d%H%M%SZ").ok())
+        .map(|time| time.and_utc())
+        .ok_or(KerberosError::InvalidTime { field })
+}
+
+pub fn kerberos_message_type_name(message_type: u32) -> &'static str {
+    match message_type {
+        KRB_AS_REQ => "AS-REQ",
+        KRB_AS_REP => "AS-REP",
+        KRB_TGS_REQ => "TGS-REQ",
+        KRB_TGS_REP => "TGS-REP",
+        KRB_AP_REQ => "AP-REQ",
+        KRB_AP_REP => "AP-REP",
+        20 => "KRB-SAFE",
+        21 => "KRB-PRIV",
+        22 => "KRB-CRED",
+        KRB_ERROR => "KRB-ERROR",
+        _ => "Unknown",
+    }
+}
+
+pub fn kerberos_etype_name(etype: i64) -> &'static str {
+    match etype {
+        ETYPE_DES_CBC_CRC => "des-cbc-crc",
+        2 => "des-cbc-md4",
+        ETYPE_DES_CBC_MD5 => "des-cbc-md5",
+        16 => "des3-cbc-sha1",
+        ETYPE_AES128_CTS_HMAC_SHA1_96 => "aes128-cts-hmac-sha1-96",
+        ETYPE_AES256_CTS_HMAC_SHA1_96 => "aes256-cts-hmac-sha1-96",
+        19 => "aes128-cts-hmac-sha256-128",
+        20 => "aes256-cts-hmac-sha384-192",
+        ETYPE_RC4_HMAC => "rc4-hmac",
+        ETYPE_RC4_HMAC_EXP => "rc4-hmac-exp",
+        -128 => "rc4-hmac-old",
+        _ => "Unknown",
+    }
+}
+
+/// DES et RC4 : cles derivables hors ligne (kerberoasting, AS-REP
+/// roasting) ou cassables par force brute.
+pub fn is_weak_etype(etype: i64) -> bool {
+    matches!(
+        etype,
+        ETYPE_DES_CBC_CRC | 2 | ETYPE_DES_CBC_MD5 | ETYPE_RC4_HMAC | ETYPE_RC4_HMAC_EXP | -128
+    )
+}
+
+pub fn kerberos_padata_type_name(padata_type: i64) -> &'static str {
+    match padata_type {
+        PA_TGS_REQ => "PA-TGS-REQ",
+        PA_ENC_TIMESTAMP => "PA-ENC-TIMESTAMP",
+        3 => "PA-PW-SALT",
+        11 => "PA-ETYPE-INFO",
+        16 => "PA-PK-AS-REQ",
+        17 => "PA-PK-AS-REP",
+        PA_ETYPE_INFO2 => "PA-ETYPE-INFO2",
+        PA_PAC_REQUEST => "PA-PAC-REQUEST",
+        129 => "PA-FOR-USER",
+        133 => "PA-FX-COOKIE",
+        136 => "PA-FX-FAST",
+        137 => "PA-FX-ERROR",
+        138 => "PA-ENCRYPTED-CHALLENGE",
+        149 => "PA-REQ-ENC-PA-REP",
+        165 => "PA-SUPPORTED-ENCTYPES",
+        167 => "PA-PAC-OPTIONS",
+        _ => "Unknown",
+    }
+}
+
+/// Codes d'erreur KRB-ERROR (RFC 4120 §7.5.9), dont ceux qui trahissent
+/// l'enumeration de comptes et la pulverisation de mots de passe.
+pub fn kerberos_error_code_name(code: i64) -> &'static str {
+    match code {
+        0 => "KDC_ERR_NONE",
+        6 => "KDC_ERR_C_PRINCIPAL_UNKNOWN",
+        7 => "KDC_ERR_S_PRINCIPAL_UNKNOWN",
+        12 => "KDC_ERR_POLICY",
+        14 => "KDC_ERR_ETYPE_NOSUPP",
+        18 => "KDC_ERR_CLIENT_REVOKED",
+        23 => "KDC_ERR_KEY_EXPIRED",
+        24 => "KDC_ERR_PREAUTH_FAILED",
+        25 => "KDC_ERR_PREAUTH_REQUIRED",
+        31 => "KRB_AP_ERR_BAD_INTEGRITY",
+        32 => "KRB_AP_ERR_TKT_EXPIRED",
+        34 => "KRB_AP_ERR_REPEAT",
+        37 => "KRB_AP_ERR_SKEW",
+        41 => "KRB_AP_ERR_MODIFIED",
+        52 => "KRB_ERR_RESPONSE_TOO_BIG",
+        60 => "KRB_ERR_GENERIC",
+        68 => "KDC_ERR_WRONG_REALM",
+        _ => "Unknown",
+    }
+}
+
+pub fn kerberos_principal_type_name(name_type: i64) -> &'static str {
+    match name_type {
+        0 => "NT-UNKNOWN",
+        1 => "NT-PRINCIPAL",
+        2 => "NT-SRV-INST",
+        3 => "NT-SRV-HST",
+        4 => "NT-SRV-XHST",
+        5 => "NT-UID",
+        6 => "NT-X500-PRINCIPAL",
+        7 => "NT-SMTP-NAME",
+        10 => "NT-ENTERPRISE",
+        -128 => "NT-MS-PRINCIPAL",
+        _ => "Unknown",
+    }
+}
+
+/// Options KDC positionnees (RFC 4120 §5.4.1), bit 0 etant le poids fort.
+pub fn kdc_option_names(options: u32) -> Vec<&'static str> {
+    [
+        (1, "forwardable"),
+        (2, "forwarded"),
+        (3, "proxiable"),
+        (4, "proxy"),
+        (5, "allow-postdate"),
+        (6, "postdated"),
+        (8, "renewable"),
+        (14, "constrained-delegation"),
+        (15, "canonicalize"),
+        (16, "request-anonymous"),
+        (26, "disable-transited-check"),
+        (27, "renewable-ok"),
+        (28, "enc-tkt-in-skey"),
+        (30, "renew"),
+        (31, "validate"),
+    ]
+    .into_iter()
+    .filter(|(bit, _)| options & (0x8000_0000 >> bit) != 0)
+    .map(|(_, name)| name)
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tcp_records() {
+        let stream = [0x00, 0x00, 0x00, 0x02, 0x6A, 0x00, 0xFF];
+        assert_eq!(
+            extract_kerberos_tcp_record(&stream),
+            Ok((&[0x6A, 0x00][..], &[0xFF][..]))
+        );
+        assert_eq!(
+            extract_kerberos_tcp_record(&[0x80, 0x00, 0x00, 0x00]),
+            Err(KerberosError::ReservedLengthBit)
+        );
+        assert_eq!(
+            extract_kerberos_tcp_record(&[0x00, 0x00, 0x01, 0x00, 0x6A]),
+            Err(KerberosError::TruncatedRecord {
+                declared: 256,
+                available: 1
+            })
+        );
+        assert_eq!(
+            extract_kerberos_tcp_record(&[0x00, 0x00]),
+            Err(KerberosError::InvalidLength {
+                expected: 4,
+                actual: 2
+            })
+        );
+    }
+
+    #[test]
+    fn header_and_time() {
+        assert_eq!(validate_kerberos_header(KRB_AS_REQ, 5, 10), Ok(()));
+        assert_eq!(
+            validate_kerberos_header(KRB_AS_REQ, 4, 10),
+            Err(KerberosError::InvalidVersion(4))
+        );
+        assert_eq!(
+            validate_kerberos_header(KRB_AS_REQ, 5, 12),
+            Err(KerberosError::MessageTypeMismatch {
+                tag: 10,
+                msg_type: 12
+            })
+        );
+        let time = extract_kerberos_time(b"20370913024805Z", "till").expect("KerberosTime");
+        assert_eq!(time.to_rfc3339(), "2037-09-13T02:48:05+00:00");
+        assert_eq!(
+            extract_kerberos_time(b"2037", "till"),
+            Err(KerberosError::InvalidTime { field: "till" })
+        );
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(kerberos_message_type_name(KRB_TGS_REP), "TGS-REP");
+        assert_eq!(kerberos_etype_name(18), "aes256-cts-hmac-sha1-96");
+        assert!(is_weak_etype(ETYPE_RC4_HMAC));
+        assert!(!is_weak_etype(ETYPE_AES128_CTS_HMAC_SHA1_96));
+        assert_eq!(kerberos_padata_type_name(PA_PAC_REQUEST), "PA-PAC-REQUEST");
+        assert_eq!(kerberos_error_code_name(25), "KDC_ERR_PREAUTH_REQUIRED");
+        assert_eq!(kerberos_principal_type_name(2), "NT-SRV-INST");
+        // forwardable, renewable, canonicalize, renewable-ok.
+        assert_eq!(
+            kdc_option_names(0x4081_0010),
+            ["forwardable", "renewable", "canonicalize", "renewable-ok"]
+        );
+    }
+}
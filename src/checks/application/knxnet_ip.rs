@@ -0,0 +1,301 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Controles de taille et de coherence pour KNXnet/IP (ISO 22510).
+//!
+//! L'en-tete tient en 6 octets : sa longueur (toujours 6), la version
+//! `0x10`, le type de service et la longueur totale de la trame sur 2
+//! octets. Les structures du corps (HPAI, CRI, en-tete de connexion)
+//! commencent toutes par leur propre longueur.
+
+use crate::errors::application::knxnet_ip::KnxnetIpError;
+
+pub const KNXNET_IP_HEADER_LEN: u8 = 0x06;
+pub const KNXNET_IP_VERSION: u8 = 0x10;
+
+/// Adresse d'extremite : longueur, protocole, IPv4 et port.
+pub const KNXNET_IP_HPAI_LEN: u8 = 8;
+pub const KNXNET_IP_HPAI_UDP: u8 = 0x01;
+pub const KNXNET_IP_HPAI_TCP: u8 = 0x02;
+
+/// En-tete de connexion : longueur, canal, compteur de sequence, statut.
+pub const KNXNET_IP_CONNECTION_HEADER_LEN: u8 = 4;
+
+pub const KNXNET_IP_SEARCH_REQUEST: u16 = 0x0201;
+pub const KNXNET_IP_SEARCH_RESPONSE: u16 = 0x0202;
+pub const KNXNET_IP_DESCRIPTION_REQUEST: u16 = 0x0203;
+pub const KNXNET_IP_DESCRIPTION_RESPONSE: u16 = 0x0204;
+pub const KNXNET_IP_CONNECT_REQUEST: u16 = 0x0205;
+pub const KNXNET_IP_CONNECT_RESPONSE: u16 = 0x0206;
+pub const KNXNET_IP_CONNECTIONSTATE_REQUEST: u16 = 0x0207;
+pub const KNXNET_IP_CONNECTIONSTATE_RESPONSE: u16 = 0x0208;
+pub const KNXNET_IP_DISCONNECT_REQUEST: u16 = 0x0209;
+pub const KNXNET_IP_DISCONNECT_RESPONSE: u16 = 0x020A;
+pub const KNXNET_IP_DEVICE_CONFIGURATION_REQUEST: u16 = 0x0310;
+pub const KNXNET_IP_DEVICE_CONFIGURATION_ACK: u16 = 0x0311;
+pub const KNXNET_IP_TUNNELLING_REQUEST: u16 = 0x0420;
+pub const KNXNET_IP_TUNNELLING_ACK: u16 = 0x0421;
+pub const KNXNET_IP_ROUTING_INDICATION: u16 = 0x0530;
+pub const KNXNET_IP_ROUTING_LOST_MESSAGE: u16 = 0x0531;
+pub const KNXNET_IP_ROUTING_BUSY: u16 = 0x0532;
+pub const KNXNET_IP_SECURE_WRAPPER: u16 = 0x0950;
+
+/// Types de connexion du CRI/CRD.
+pub const KNXNET_IP_DEVICE_MGMT_CONNECTION: u8 = 0x03;
+pub const KNXNET_IP_TUNNEL_CONNECTION: u8 = 0x04;
+
+/// Bloc de description du peripherique (DIB DEVICE_INFO), de taille fixe.
+pub const KNXNET_IP_DIB_DEVICE_INFO: u8 = 0x01;
+pub const KNXNET_IP_DIB_DEVICE_INFO_LEN: u8 = 54;
+pub const KNXNET_IP_FRIENDLY_NAME_LEN: usize = 30;
+
+/// Codes de message cEMI de la couche liaison.
+pub const KNX_CEMI_L_DATA_REQ: u8 = 0x11;
+pub const KNX_CEMI_L_DATA_CON: u8 = 0x2E;
+pub const KNX_CEMI_L_DATA_IND: u8 = 0x29;
+
+/// Octets de controle, adresses et longueur du NPDU d'un L_Data.
+pub const KNX_CEMI_L_DATA_HEADER_LEN: usize = 7;
+
+/// Bit du second octet de controle : adresse de destination de groupe.
+pub const KNX_CEMI_GROUP_ADDRESS: u8 = 0x80;
+
+/// Deux bits de poids fort du TPCI : 00 donnees non numerotees, 01
+/// numerotees, 10 et 11 controle de connexion.
+pub const KNX_TPCI_CONTROL: u8 = 0x80;
+
+/// APCI sur 4 bits ; 0x3C0 annonce un APCI etendu sur 10 bits.
+pub const KNX_APCI_MASK: u16 = 0x03C0;
+pub const KNX_APCI_EXTENDED: u16 = 0x03C0;
+pub const KNX_APCI_GROUP_VALUE_READ: u16 = 0x0000;
+pub const KNX_APCI_GROUP_VALUE_RESPONSE: u16 = 0x0040;
+pub const KNX_APCI_GROUP_VALUE_WRITE: u16 = 0x0080;
+
+/// Valeur courte de groupe, portee par les 6 bits bas de l'APCI.
+pub const KNX_APCI_SHORT_DATA_MASK: u8 = 0x3F;
+
+/// Verifie l'en-tete en tete de `stream` et retourne la longueur totale de
+/// la trame.
+pub fn extract_knxnet_ip_frame_len(stream: &[u8]) -> Result<usize, KnxnetIpError> {
+    let header_len = usize::from(KNXNET_IP_HEADER_LEN);
+    if stream.len() < header_len {
+        return Err(KnxnetIpError::InvalidLength {
+            expected: header_len,
+            actual: stream.len(),
+        });
+    }
+    if stream[0] != KNXNET_IP_HEADER_LEN {
+        return Err(KnxnetIpError::InvalidHeaderLength(stream[0]));
+    }
+    if stream[1] != KNXNET_IP_VERSION {
+        return Err(KnxnetIpError::UnsupportedVersion(stream[1]));
+    }
+    let total = usize::from(u16::from_be_bytes([stream[4], stream[5]]));
+    if total < header_len {
+        return Err(KnxnetIpError::InvalidLength {
+            expected: header_len,
+            actual: total,
+        });
+    }
+    if stream.len() < total {
+        return Err(KnxnetIpError::TruncatedFrame {
+            declared: total,
+            available: stream.len(),
+        });
+    }
+    Ok(total)
+}
+
+pub fn extract_knxnet_ip_bytes<'a>(
+    buf: &'a [u8],
+    len: usize,
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), KnxnetIpError> {
+    if buf.len() < len {
+        return Err(KnxnetIpError::Truncated { field });
+    }
+    Ok(buf.split_at(len))
+}
+
+/// Structure prefixee par sa longueur (octet compris), d'au moins
+/// `min_len` octets.
+pub fn extract_knxnet_ip_structure<'a>(
+    buf: &'a [u8],
+    min_len: u8,
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), KnxnetIpError> {
+    let Some(&length) = buf.first() else {
+        return Err(KnxnetIpError::Truncated { field });
+    };
+    if length < min_len {
+        return Err(KnxnetIpError::InvalidStructureLength { field, length });
+    }
+    extract_knxnet_ip_bytes(buf, usize::from(length), field)
+}
+
+pub fn knxnet_ip_service_name(service_type: u16) -> &'static str {
+    match service_type {
+        KNXNET_IP_SEARCH_REQUEST => "SEARCH_REQUEST",
+        KNXNET_IP_SEARCH_RESPONSE => "SEARCH_RESPONSE",
+        KNXNET_IP_DESCRIPTION_REQUEST => "DESCRIPTION_REQUEST",
+        KNXNET_IP_DESCRIPTION_RESPONSE => "DESCRIPTION_RESPONSE",
+        KNXNET_IP_CONNECT_REQUEST => "CONNECT_REQUEST",
+        KNXNET_IP_CONNECT_RESPONSE => "CONNECT_RESPONSE",
+        KNXNET_IP_CONNECTIONSTATE_REQUEST => "CONNECTIONSTATE_REQUEST",
+        KNXNET_IP_CONNECTIONSTATE_RESPONSE => "CONNECTIONSTATE_RESPONSE",
+        KNXNET_IP_DISCONNECT_REQUEST => "DISCONNECT_REQUEST",
+        KNXNET_IP_DISCONNECT_RESPONSE => "DISCONNECT_RESPONSE",
+        0x020B => "SEARCH_REQUEST_EXTENDED",
+        0x020C => "SEARCH_RESPONSE_EXTENDED",
+        KNXNET_IP_DEVICE_CONFIGURATION_REQUEST => "DEVICE_CONFIGURATION_REQUEST",
+        KNXNET_IP_DEVICE_CONFIGURATION_ACK => "DEVICE_CONFIGURATION_ACK",
+        KNXNET_IP_TUNNELLING_REQUEST => "TUNNELLING_REQUEST",
+        KNXNET_IP_TUNNELLING_ACK => "TUNNELLING_ACK",
+        0x0422 => "TUNNELLING_FEATURE_GET",
+        0x0423 => "TUNNELLING_FEATURE_RESPONSE",
+        0x0424 => "TUNNELLING_FEATURE_SET",
+        0x0425 => "TUNNELLING_FEATURE_INFO",
+        KNXNET_IP_ROUTING_INDICATION => "ROUTING_INDICATION",
+        KNXNET_IP_ROUTING_LOST_MESSAGE => "ROUTING_LOST_MESSAGE",
+        KNXNET_IP_ROUTING_BUSY => "ROUTING_BUSY",
+        0x0533 => "ROUTING_SYSTEM_BROADCAST",
+        KNXNET_IP_SECURE_WRAPPER => "SECURE_WRAPPER",
+        0x0951 => "SECURE_SESSION_REQUEST",
+        0x0952 => "SECURE_SESSION_RESPONSE",
+        0x0953 => "SECURE_SESSION_AUTHENTICATE",
+        0x0954 => "SECURE_SESSION_STATUS",
+        0x0955 => "SECURE_TIMER_NOTIFY",
+        _ => "Unknown",
+    }
+}
+
+pub fn knxnet_ip_status_name(status: u8) -> &'static str {
+    match status {
+        0x00 => "E_NO_ERROR",
+        0x01 => "E_HOST_PROTOCOL_TYPE",
+        0x02 => "E_VERSION_NOT_SUPPORTED",
+        0x04 => "E_SEQUENCE_NUMBER",
+        0x21 => "E_CONNECTION_ID",
+        0x22 => "E_CONNECTION_TYPE",
+        0x23 => "E_CONNECTION_OPTION",
+        0x24 => "E_NO_MORE_CONNECTIONS",
+        0x25 => "E_NO_MORE_UNIQUE_CONNECTIONS",
+        0x26 => "E_DATA_CONNECTION",
+        0x27 => "E_KNX_CONNECTION",
+        0x28 => "E_AUTHORISATION_ERROR",
+        0x29 => "E_TUNNELLING_LAYER",
+        _ => "Unknown",
+    }
+}
+
+pub fn knx_cemi_message_name(message_code: u8) -> &'static str {
+    match message_code {
+        0x10 => "L_Raw.req",
+        KNX_CEMI_L_DATA_REQ => "L_Data.req",
+        0x13 => "L_Poll_Data.req",
+        0x25 => "L_Poll_Data.con",
+        0x2B => "L_Busmon.ind",
+        0x2D => "L_Raw.ind",
+        KNX_CEMI_L_DATA_IND => "L_Data.ind",
+        KNX_CEMI_L_DATA_CON => "L_Data.con",
+        0x2F => "L_Raw.con",
+        0xF0 => "M_Reset.ind",
+        0xF1 => "M_Reset.req",
+        0xF5 => "M_PropWrite.con",
+        0xF6 => "M_PropWrite.req",
+        0xF7 => "M_PropInfo.ind",
+        0xFB => "M_PropRead.con",
+        0xFC => "M_PropRead.req",
+        _ => "Unknown",
+    }
+}
+
+pub fn knx_priority_name(priority: u8) -> &'static str {
+    match priority & 0x03 {
+        0 => "system",
+        1 => "normal",
+        2 => "urgent",
+        _ => "low",
+    }
+}
+
+pub fn knx_apci_name(apci: u16) -> &'static str {
+    match apci & KNX_APCI_MASK {
+        KNX_APCI_GROUP_VALUE_READ => "GroupValueRead",
+        KNX_APCI_GROUP_VALUE_RESPONSE => "GroupValueResponse",
+        KNX_APCI_GROUP_VALUE_WRITE => "GroupValueWrite",
+        0x00C0 => "IndividualAddressWrite",
+        0x0100 => "IndividualAddressRead",
+        0x0140 => "IndividualAddressResponse",
+        0x0180 => "ADCRead",
+        0x01C0 => "ADCResponse",
+        0x0200 => "MemoryRead",
+        0x0240 => "MemoryResponse",
+        0x0280 => "MemoryWrite",
+        0x02C0 => "UserMessage",
+        0x0300 => "DeviceDescriptorRead",
+        0x0340 => "DeviceDescriptorResponse",
+        0x0380 => "Restart",
+        _ => match apci {
+            0x03D1 => "AuthorizeRequest",
+            0x03D2 => "AuthorizeResponse",
+            0x03D3 => "KeyWrite",
+            0x03D4 => "KeyResponse",
+            0x03D5 => "PropertyValueRead",
+            0x03D6 => "PropertyValueResponse",
+            0x03D7 => "PropertyValueWrite",
+            0x03D8 => "PropertyDescriptionRead",
+            0x03D9 => "PropertyDescriptionResponse",
+            0x03DC => "IndividualAddressSerialNumberRead",
+            0x03DD => "IndividualAddressSerialNumberResponse",
+            0x03DE => "IndividualAddressSerialNumberWrite",
+            _ => "Unknown",
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn frame_length_bounds() {
+        assert_eq!(
+            extract_knxnet_ip_frame_len(&[0x06, 0x10, 0x02, 0x01, 0x00, 0x06]),
+            Ok(6)
+        );
+        assert_eq!(
+            extract_knxnet_ip_frame_len(&[0x06, 0x10, 0x04, 0x20, 0x00, 0x15, 0x04]),
+            Err(KnxnetIpError::TruncatedFrame {
+                declared: 21,
+                available: 7
+            })
+        );
+        assert_eq!(
+            extract_knxnet_ip_frame_len(&[0x06, 0x20, 0x02, 0x01, 0x00, 0x06]),
+            Err(KnxnetIpError::UnsupportedVersion(0x20))
+        );
+        assert_eq!(
+            extract_knxnet_ip_structure(&[0x02, 0x01], KNXNET_IP_HPAI_LEN, "HPAI"),
+            Err(KnxnetIpError::InvalidStructureLength {
+                field: "HPAI",
+                length: 2
+            })
+        );
+    }
+
+    #[test]
+    fn service_and_apci_names() {
+        assert_eq!(
+            knxnet_ip_service_name(KNXNET_IP_TUNNELLING_REQUEST),
+            "TUNNELLING_REQUEST"
+        );
+        assert_eq!(knxnet_ip_status_name(0x24), "E_NO_MORE_CONNECTIONS");
+        assert_eq!(knx_cemi_message_name(KNX_CEMI_L_DATA_IND), "L_Data.ind");
+        assert_eq!(knx_apci_name(0x0081), "GroupValueWrite");
+        assert_eq!(knx_apci_name(0x03D5), "PropertyValueRead");
+        assert_eq!(knx_priority_name(3), "low");
+    }
+}
@@ -0,0 +1,204 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Constantes et noms pour LDAPv3 (RFC 4511) et CLDAP (MS-ADTS §6.3.3).
+//!
+//! Chaque message est une SEQUENCE BER { messageID, protocolOp,
+//! controls `[0]` optionnels } ; l'operation est une etiquette
+//! `[APPLICATION n]` a etiquetage implicite, ses champs suivent donc
+//! directement sans SEQUENCE intermediaire. Active Directory ajoute a
+//! l'authentification simple et SASL les variantes Sicily (NTLM brut).
+
+use crate::errors::application::ldap::LdapError;
+
+pub const LDAP_PORT: u16 = 389;
+/// Catalogue global Active Directory.
+pub const LDAP_GLOBAL_CATALOG_PORT: u16 = 3268;
+
+/// messageID : 0 .. 2^31 - 1 (RFC 4511 §4.1.1.1).
+pub const LDAP_MAX_MESSAGE_ID: i64 = 0x7FFF_FFFF;
+/// Profondeur maximale des filtres de recherche imbriques, pour borner la
+/// recursion sur un paquet forge.
+pub const LDAP_MAX_FILTER_DEPTH: usize = 32;
+
+/// Numeros `[APPLICATION n]` des operations.
+pub const LDAP_BIND_REQUEST: u32 = 0;
+pub const LDAP_BIND_RESPONSE: u32 = 1;
+pub const LDAP_UNBIND_REQUEST: u32 = 2;
+pub const LDAP_SEARCH_REQUEST: u32 = 3;
+pub const LDAP_SEARCH_RESULT_ENTRY: u32 = 4;
+pub const LDAP_SEARCH_RESULT_DONE: u32 = 5;
+pub const LDAP_MODIFY_RESPONSE: u32 = 7;
+pub const LDAP_ADD_RESPONSE: u32 = 9;
+pub const LDAP_DEL_RESPONSE: u32 = 11;
+pub const LDAP_MODIFY_DN_RESPONSE: u32 = 13;
+pub const LDAP_COMPARE_RESPONSE: u32 = 15;
+pub const LDAP_SEARCH_RESULT_REFERENCE: u32 = 19;
+pub const LDAP_EXTENDED_REQUEST: u32 = 23;
+pub const LDAP_EXTENDED_RESPONSE: u32 = 24;
+
+/// Choix d'authentification d'un BindRequest (contexte `[n]`).
+pub const LDAP_AUTH_SIMPLE: u32 = 0;
+pub const LDAP_AUTH_SASL: u32 = 3;
+/// Sicily (MS-ADTS §5.1.1.1) : decouverte des paquets, puis NEGOTIATE et
+/// AUTHENTICATE NTLMSSP sans enveloppe SASL.
+pub const LDAP_AUTH_SICILY_PACKAGE_DISCOVERY: u32 = 9;
+pub const LDAP_AUTH_SICILY_NEGOTIATE: u32 = 10;
+pub const LDAP_AUTH_SICILY_RESPONSE: u32 = 11;
+
+/// Choix du filtre de recherche (RFC 4511 §4.5.1.7).
+pub const LDAP_FILTER_AND: u32 = 0;
+pub const LDAP_FILTER_OR: u32 = 1;
+pub const LDAP_FILTER_NOT: u32 = 2;
+pub const LDAP_FILTER_EQUALITY_MATCH: u32 = 3;
+pub const LDAP_FILTER_SUBSTRINGS: u32 = 4;
+pub const LDAP_FILTER_GREATER_OR_EQUAL: u32 = 5;
+pub const LDAP_FILTER_LESS_OR_EQUAL: u32 = 6;
+pub const LDAP_FILTER_PRESENT: u32 = 7;
+pub const LDAP_FILTER_APPROX_MATCH: u32 = 8;
+pub const LDAP_FILTER_EXTENSIBLE_MATCH: u32 = 9;
+
+pub const LDAP_RESULT_SUCCESS: u32 = 0;
+pub const LDAP_RESULT_SASL_BIND_IN_PROGRESS: u32 = 14;
+
+pub const LDAP_STARTTLS_OID: &str = "1.3.6.1.4.1.1466.20037";
+
+/// Verifie qu'un messageID tient dans 0 .. 2^31 - 1.
+pub fn validate_ldap_message_id(message_id: i64) -> Result<u32, LdapError> {
+    if (0..=LDAP_MAX_MESSAGE_ID).contains(&message_id) {
+        Ok(message_id as u32)
+    } else {
+        Err(LdapError::InvalidMessageId(message_id))
+    }
+}
+
+pub fn ldap_operation_name(operation: u32) -> &'static str {
+    match operation {
+        LDAP_BIND_REQUEST => "bindRequest",
+        LDAP_BIND_RESPONSE => "bindResponse",
+        LDAP_UNBIND_REQUEST => "unbindRequest",
+        LDAP_SEARCH_REQUEST => "searchRequest",
+        LDAP_SEARCH_RESULT_ENTRY => "searchResEntry",
+        LDAP_SEARCH_RESULT_DONE => "searchResDone",
+        6 => "modifyRequest",
+        LDAP_MODIFY_RESPONSE => "modifyResponse",
+        8 => "addRequest",
+        LDAP_ADD_RESPONSE => "addResponse",
+        10 => "delRequest",
+        LDAP_DEL_RESPONSE => "delResponse",
+        12 => "modDNRequest",
+        LDAP_MODIFY_DN_RESPONSE => "modDNResponse",
+        14 => "compareRequest",
+        LDAP_COMPARE_RESPONSE => "compareResponse",
+        16 => "abandonRequest",
+        LDAP_SEARCH_RESULT_REFERENCE => "searchResRef",
+        LDAP_EXTENDED_REQUEST => "extendedReq",
+        LDAP_EXTENDED_RESPONSE => "extendedResp",
+        25 => "intermediateResponse",
+        _ => "Unknown",
+    }
+}
+
+pub fn ldap_result_code_name(code: u32) -> &'static str {
+    match code {
+        LDAP_RESULT_SUCCESS => "success",
+        1 => "operationsError",
+        2 => "protocolError",
+        3 => "timeLimitExceeded",
+        4 => "sizeLimitExceeded",
+        5 => "compareFalse",
+        6 => "compareTrue",
+        7 => "authMethodNotSupported",
+        8 => "strongerAuthRequired",
+        10 => "referral",
+        11 => "adminLimitExceeded",
+        12 => "unavailableCriticalExtension",
+        13 => "confidentialityRequired",
+        LDAP_RESULT_SASL_BIND_IN_PROGRESS => "saslBindInProgress",
+        16 => "noSuchAttribute",
+        17 => "undefinedAttributeType",
+        18 => "inappropriateMatching",
+        19 => "constraintViolation",
+        20 => "attributeOrValueExists",
+        21 => "invalidAttributeSyntax",
+        32 => "noSuchObject",
+        33 => "aliasProblem",
+        34 => "invalidDNSyntax",
+        36 => "aliasDereferencingProblem",
+        48 => "inappropriateAuthentication",
+        49 => "invalidCredentials",
+        50 => "insufficientAccessRights",
+        51 => "busy",
+        52 => "unavailable",
+        53 => "unwillingToPerform",
+        54 => "loopDetect",
+        64 => "namingViolation",
+        65 => "objectClassViolation",
+        66 => "notAllowedOnNonLeaf",
+        67 => "notAllowedOnRDN",
+        68 => "entryAlreadyExists",
+        69 => "objectClassModsProhibited",
+        71 => "affectsMultipleDSAs",
+        80 => "other",
+        _ => "Unknown",
+    }
+}
+
+pub fn ldap_scope_name(scope: u32) -> &'static str {
+    match scope {
+        0 => "baseObject",
+        1 => "singleLevel",
+        2 => "wholeSubtree",
+        _ => "Unknown",
+    }
+}
+
+/// Noms des operations etendues courantes.
+pub fn ldap_extended_operation_name(oid: &str) -> &'static str {
+    match oid {
+        LDAP_STARTTLS_OID => "StartTLS",
+        "1.3.6.1.4.1.4203.1.11.1" => "Password Modify",
+        "1.3.6.1.4.1.4203.1.11.3" => "Who am I?",
+        "1.3.6.1.1.8" => "Cancel",
+        "1.3.6.1.4.1.1466.20036" => "Notice of Disconnection",
+        "1.2.840.113556.1.4.1781" => "Fast Concurrent Bind",
+        _ => "Unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message_ids() {
+        assert_eq!(validate_ldap_message_id(0), Ok(0));
+        assert_eq!(
+            validate_ldap_message_id(LDAP_MAX_MESSAGE_ID),
+            Ok(0x7FFF_FFFF)
+        );
+        assert_eq!(
+            validate_ldap_message_id(-1),
+            Err(LdapError::InvalidMessageId(-1))
+        );
+        assert_eq!(
+            validate_ldap_message_id(1 << 31),
+            Err(LdapError::InvalidMessageId(1 << 31))
+        );
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(ldap_operation_name(LDAP_SEARCH_REQUEST), "searchRequest");
+        assert_eq!(ldap_operation_name(LDAP_EXTENDED_RESPONSE), "extendedResp");
+        assert_eq!(ldap_result_code_name(49), "invalidCredentials");
+        assert_eq!(
+            ldap_result_code_name(LDAP_RESULT_SASL_BIND_IN_PROGRESS),
+            "saslBindInProgress"
+        );
+        assert_eq!(ldap_scope_name(2), "wholeSubtree");
+        assert_eq!(ldap_extended_operation_name(LDAP_STARTTLS_OID), "StartTLS");
+    }
+}
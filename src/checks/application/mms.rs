@@ -0,0 +1,454 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Controles de taille et de coherence pour MMS (ISO 9506) et les couches
+//! OSI qui le portent sur TCP 102 : TPKT, COTP, session (ISO 8327),
+//! presentation (ISO 8823) et ACSE (ISO 8650).
+//!
+//! Presentation, ACSE et MMS sont encodes en BER et lus par le decodeur
+//! partage [`ber`](super::ber) : numeros d'etiquette longs admis (services
+//! fichiers au-dela de 30), forme indefinie refusee, absente des
+//! implementations IEC 61850.
+
+use crate::{
+    checks::application::ber::extract_ber_element,
+    errors::application::{ber::BerError, mms::MmsError},
+    parse::application::protocols::ber::BerElement,
+};
+
+/// Version, reserve et longueur du TPKT (RFC 1006).
+pub const MMS_TPKT_HEADER_LEN: usize = 4;
+pub const MMS_TPKT_VERSION: u8 = 3;
+
+/// SPDU de session : Give Tokens et Data Transfer, tous deux vides,
+/// precedent chaque PPDU de donnees.
+pub const MMS_SESSION_DATA_TRANSFER: [u8; 4] = [0x01, 0x00, 0x01, 0x00];
+pub const MMS_SPDU_DATA_TRANSFER: u8 = 0x01;
+pub const MMS_SPDU_FINISH: u8 = 0x09;
+pub const MMS_SPDU_DISCONNECT: u8 = 0x0A;
+pub const MMS_SPDU_REFUSE: u8 = 0x0C;
+pub const MMS_SPDU_CONNECT: u8 = 0x0D;
+pub const MMS_SPDU_ACCEPT: u8 = 0x0E;
+pub const MMS_SPDU_ABORT: u8 = 0x19;
+
+/// Parametres de session portant les donnees utilisateur.
+pub const MMS_SESSION_USER_DATA: u8 = 0xC1;
+pub const MMS_SESSION_EXTENDED_USER_DATA: u8 = 0xC2;
+
+/// Longueur de parametre de session sur 3 octets.
+pub const MMS_SESSION_LONG_LENGTH: u8 = 0xFF;
+
+/// Etiquettes universelles utilisees par la presentation et MMS.
+pub const MMS_TAG_BOOLEAN: u32 = 1;
+pub const MMS_TAG_INTEGER: u32 = 2;
+pub const MMS_TAG_OBJECT_IDENTIFIER: u32 = 6;
+pub const MMS_TAG_EXTERNAL: u32 = 8;
+pub const MMS_TAG_SEQUENCE: u32 = 16;
+pub const MMS_TAG_SET: u32 = 17;
+pub const MMS_TAG_VISIBLE_STRING: u32 = 26;
+
+/// PPDU de donnees (fully-encoded-data, APPLICATION 1) et CP/CPA (SET).
+pub const MMS_PRESENTATION_FULLY_ENCODED: u32 = 1;
+
+/// user-information `[30]` des APDU ACSE.
+pub const MMS_ACSE_USER_INFORMATION: u32 = 30;
+
+/// Choix du MMSpdu.
+pub const MMS_PDU_CONFIRMED_REQUEST: u32 = 0;
+pub const MMS_PDU_CONFIRMED_RESPONSE: u32 = 1;
+pub const MMS_PDU_CONFIRMED_ERROR: u32 = 2;
+pub const MMS_PDU_UNCONFIRMED: u32 = 3;
+pub const MMS_PDU_REJECT: u32 = 4;
+pub const MMS_PDU_CANCEL_REQUEST: u32 = 5;
+pub const MMS_PDU_CANCEL_RESPONSE: u32 = 6;
+pub const MMS_PDU_CANCEL_ERROR: u32 = 7;
+pub const MMS_PDU_INITIATE_REQUEST: u32 = 8;
+pub const MMS_PDU_INITIATE_RESPONSE: u32 = 9;
+pub const MMS_PDU_INITIATE_ERROR: u32 = 10;
+pub const MMS_PDU_CONCLUDE_REQUEST: u32 = 11;
+pub const MMS_PDU_CONCLUDE_RESPONSE: u32 = 12;
+pub const MMS_PDU_CONCLUDE_ERROR: u32 = 13;
+
+/// Services confirmes decodes.
+pub const MMS_SERVICE_GET_NAME_LIST: u32 = 1;
+pub const MMS_SERVICE_IDENTIFY: u32 = 2;
+pub const MMS_SERVICE_READ: u32 = 4;
+pub const MMS_SERVICE_WRITE: u32 = 5;
+
+/// Service non confirme des rapports IEC 61850.
+pub const MMS_SERVICE_INFORMATION_REPORT: u32 = 0;
+
+/// Structures et tableaux imbriques : bien au-dela des modeles IEC 61850.
+pub const MMS_MAX_NESTING_DEPTH: usize = 16;
+
+/// Verifie l'en-tete TPKT en tete de `stream` et retourne la TPDU COTP
+/// qu'il delimite, puis les octets qui suivent.
+pub fn extract_mms_tpkt(stream: &[u8]) -> Result<(&[u8], &[u8]), MmsError> {
+    if stream.len() < MMS_TPKT_HEADER_LEN {
+        return Err(MmsError::InvalidLength {
+            expected: MMS_TPKT_HEADER_LEN,
+            actual: stream.len(),
+        });
+    }
+    if stream[0] != MMS_TPKT_VERSION {
+        return Err(MmsError::InvalidTpktVersion(stream[0]));
+    }
+    let declared = usize::from(u16::from_be_bytes([stream[2], stream[3]]));
+    if declared < MMS_TPKT_HEADER_LEN {
+        return Err(MmsError::InvalidLength {
+            expected: MMS_TPKT_HEADER_LEN,
+            actual: declared,
+        });
+    }
+    if stream.len() < declared {
+        return Err(MmsError::TruncatedTpkt {
+            declared,
+            available: stream.len(),
+        });
+    }
+    let (frame, rest) = stream.split_at(declared);
+    Ok((&frame[MMS_TPKT_HEADER_LEN..], rest))
+}
+
+/// Longueur d'un SPDU ou d'un parametre de session : un octet, ou `0xFF`
+/// suivi de 2 octets.
+fn extract_session_length<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), MmsError> {
+    let (&first, rest) = buf.split_first().ok_or(MmsError::Truncated { field })?;
+    let (length, rest) = if first == MMS_SESSION_LONG_LENGTH {
+        let (wide, rest) = extract_mms_bytes(rest, 2, field)?;
+        (usize::from(u16::from_be_bytes([wide[0], wide[1]])), rest)
+    } else {
+        (usize::from(first), rest)
+    };
+    extract_mms_bytes(rest, length, field)
+}
+
+/// Lit le SPDU en tete des donnees de session et retourne son type et la
+/// PPDU qu'il porte.
+pub fn extract_mms_session_user_data(session: &[u8]) -> Result<(u8, &[u8]), MmsError> {
+    if let Some(presentation) = session.strip_prefix(&MMS_SESSION_DATA_TRANSFER[..]) {
+        return Ok((MMS_SPDU_DATA_TRANSFER, presentation));
+    }
+    let (&spdu_type, rest) = session
+        .split_first()
+        .ok_or(MmsError::Truncated { field: "SPDU" })?;
+    if !matches!(
+        spdu_type,
+        MMS_SPDU_FINISH
+            | MMS_SPDU_DISCONNECT
+            | MMS_SPDU_REFUSE
+            | MMS_SPDU_CONNECT
+            | MMS_SPDU_ACCEPT
+            | MMS_SPDU_ABORT
+    ) {
+        return Err(MmsError::UnsupportedSpdu(spdu_type));
+    }
+    let (mut parameters, _) = extract_session_length(rest, "SPDU")?;
+    while let Some((&code, rest)) = parameters.split_first() {
+        let (value, rest) = extract_session_length(rest, "session parameter")?;
+        if matches!(code, MMS_SESSION_USER_DATA | MMS_SESSION_EXTENDED_USER_DATA) {
+            return Ok((spdu_type, value));
+        }
+        parameters = rest;
+    }
+    Err(MmsError::MissingUserData("session SPDU"))
+}
+
+pub fn extract_mms_bytes<'a>(
+    buf: &'a [u8],
+    len: usize,
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), MmsError> {
+    if buf.len() < len {
+        return Err(MmsError::Truncated { field });
+    }
+    Ok(buf.split_at(len))
+}
+
+/// Lit un element BER en tete de `buf` et retourne ce qui le suit.
+pub fn extract_mms_tlv<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(BerElement<'a>, &'a [u8]), MmsError> {
+    extract_ber_element(buf, field).map_err(|error| match error {
+        BerError::Missing { .. } | BerError::Truncated { .. } => MmsError::Truncated { field },
+        _ => MmsError::UnsupportedLength { field },
+    })
+}
+
+/// Entier signe BER de 1 a 8 octets.
+pub fn extract_mms_integer(bytes: &[u8], field: &'static str) -> Result<i64, MmsError> {
+    if bytes.is_empty() || bytes.len() > 8 {
+        return Err(MmsError::InvalidValueLength {
+            field,
+            length: bytes.len(),
+        });
+    }
+    let sign = if bytes[0] & 0x80 != 0 { -1i64 } else { 0 };
+    Ok(bytes
+        .iter()
+        .fold(sign, |value, &byte| (value << 8) | i64::from(byte)))
+}
+
+/// Entier non signe BER sur 32 bits (identifiants d'invocation,
+/// compteurs) ; l'octet nul de tete d'une valeur >= 2^31 est admis.
+pub fn extract_mms_unsigned(bytes: &[u8], field: &'static str) -> Result<u32, MmsError> {
+    let value = extract_mms_integer(bytes, field)?;
+    u32::try_from(value).map_err(|_| MmsError::InvalidValueLength {
+        field,
+        length: bytes.len(),
+    })
+}
+
+/// Identifiant MMS (VisibleString).
+pub fn extract_mms_identifier<'a>(
+    bytes: &'a [u8],
+    field: &'static str,
+) -> Result<&'a str, MmsError> {
+    std::str::from_utf8(bytes)
+        .ok()
+        .filter(|text| text.is_ascii())
+        .ok_or(MmsError::InvalidIdentifier { field })
+}
+
+pub fn mms_spdu_name(spdu_type: u8) -> &'static str {
+    match spdu_type {
+        MMS_SPDU_DATA_TRANSFER => "DATA TRANSFER",
+        MMS_SPDU_FINISH => "FINISH",
+        MMS_SPDU_DISCONNECT => "DISCONNECT",
+        MMS_SPDU_REFUSE => "REFUSE",
+        MMS_SPDU_CONNECT => "CONNECT",
+        MMS_SPDU_ACCEPT => "ACCEPT",
+        MMS_SPDU_ABORT => "ABORT",
+        _ => "Unknown",
+    }
+}
+
+pub fn mms_acse_name(apdu: u32) -> &'static str {
+    match apdu {
+        0 => "AARQ",
+        1 => "AARE",
+        2 => "RLRQ",
+        3 => "RLRE",
+        4 => "ABRT",
+        _ => "Unknown",
+    }
+}
+
+pub fn mms_pdu_name(pdu_type: u32) -> &'static str {
+    match pdu_type {
+        MMS_PDU_CONFIRMED_REQUEST => "confirmed-RequestPDU",
+        MMS_PDU_CONFIRMED_RESPONSE => "confirmed-ResponsePDU",
+        MMS_PDU_CONFIRMED_ERROR => "confirmed-ErrorPDU",
+        MMS_PDU_UNCONFIRMED => "unconfirmed-PDU",
+        MMS_PDU_REJECT => "rejectPDU",
+        MMS_PDU_CANCEL_REQUEST => "cancel-RequestPDU",
+        MMS_PDU_CANCEL_RESPONSE => "cancel-ResponsePDU",
+        MMS_PDU_CANCEL_ERROR => "cancel-ErrorPDU",
+        MMS_PDU_INITIATE_REQUEST => "initiate-RequestPDU",
+        MMS_PDU_INITIATE_RESPONSE => "initiate-ResponsePDU",
+        MMS_PDU_INITIATE_ERROR => "initiate-ErrorPDU",
+        MMS_PDU_CONCLUDE_REQUEST => "conclude-RequestPDU",
+        MMS_PDU_CONCLUDE_RESPONSE => "conclude-ResponsePDU",
+        MMS_PDU_CONCLUDE_ERROR => "conclude-ErrorPDU",
+        _ => "Unknown",
+    }
+}
+
+pub fn mms_confirmed_service_name(service: u32) -> &'static str {
+    match service {
+        0 => "status",
+        MMS_SERVICE_GET_NAME_LIST => "getNameList",
+        MMS_SERVICE_IDENTIFY => "identify",
+        3 => "rename",
+        MMS_SERVICE_READ => "read",
+        MMS_SERVICE_WRITE => "write",
+        6 => "getVariableAccessAttributes",
+        7 => "defineNamedVariable",
+        8 => "defineScatteredAccess",
+        9 => "getScatteredAccessAttributes",
+        10 => "deleteVariableAccess",
+        11 => "defineNamedVariableList",
+        12 => "getNamedVariableListAttributes",
+        13 => "deleteNamedVariableList",
+        14 => "defineNamedType",
+        15 => "getNamedTypeAttributes",
+        16 => "deleteNamedType",
+        17 => "input",
+        18 => "output",
+        19 => "takeControl",
+        20 => "relinquishControl",
+        21 => "defineSemaphore",
+        22 => "deleteSemaphore",
+        23 => "reportSemaphoreStatus",
+        24 => "reportPoolSemaphoreStatus",
+        25 => "reportSemaphoreEntryStatus",
+        26 => "initiateDownloadSequence",
+        27 => "downloadSegment",
+        28 => "terminateDownloadSequence",
+        29 => "initiateUploadSequence",
+        30 => "uploadSegment",
+        31 => "terminateUploadSequence",
+        32 => "requestDomainDownload",
+        33 => "requestDomainUpload",
+        34 => "loadDomainContent",
+        35 => "storeDomainContent",
+        36 => "deleteDomain",
+        37 => "getDomainAttributes",
+        38 => "createProgramInvocation",
+        39 => "deleteProgramInvocation",
+        40 => "start",
+        41 => "stop",
+        42 => "resume",
+        43 => "reset",
+        44 => "kill",
+        45 => "getProgramInvocationAttributes",
+        46 => "obtainFile",
+        47 => "defineEventCondition",
+        48 => "deleteEventCondition",
+        49 => "getEventConditionAttributes",
+        50 => "reportEventConditionStatus",
+        51 => "alterEventConditionMonitoring",
+        52 => "triggerEvent",
+        53 => "defineEventAction",
+        54 => "deleteEventAction",
+        55 => "getEventActionAttributes",
+        56 => "reportEventActionStatus",
+        57 => "defineEventEnrollment",
+        58 => "deleteEventEnrollment",
+        59 => "alterEventEnrollment",
+        60 => "reportEventEnrollmentStatus",
+        61 => "getEventEnrollmentAttributes",
+        62 => "acknowledgeEventNotification",
+        63 => "getAlarmSummary",
+        64 => "getAlarmEnrollmentSummary",
+        65 => "readJournal",
+        66 => "writeJournal",
+        67 => "initializeJournal",
+        68 => "reportJournalStatus",
+        69 => "createJournal",
+        70 => "deleteJournal",
+        71 => "getCapabilityList",
+        72 => "fileOpen",
+        73 => "fileRead",
+        74 => "fileClose",
+        75 => "fileRename",
+        76 => "fileDelete",
+        77 => "fileDirectory",
+        _ => "Unknown",
+    }
+}
+
+pub fn mms_unconfirmed_service_name(service: u32) -> &'static str {
+    match service {
+        MMS_SERVICE_INFORMATION_REPORT => "informationReport",
+        1 => "unsolicitedStatus",
+        2 => "eventNotification",
+        _ => "Unknown",
+    }
+}
+
+pub fn mms_error_class_name(class: u32) -> &'static str {
+    match class {
+        0 => "vmd-state",
+        1 => "application-reference",
+        2 => "definition",
+        3 => "resource",
+        4 => "service",
+        5 => "service-preempt",
+        6 => "time-resolution",
+        7 => "access",
+        8 => "initiate",
+        9 => "conclude",
+        10 => "cancel",
+        11 => "file",
+        12 => "others",
+        _ => "Unknown",
+    }
+}
+
+pub fn mms_data_access_error_name(error: u32) -> &'static str {
+    match error {
+        0 => "object-invalidated",
+        1 => "hardware-fault",
+        2 => "temporarily-unavailable",
+        3 => "object-access-denied",
+        4 => "object-undefined",
+        5 => "invalid-address",
+        6 => "type-unsupported",
+        7 => "type-inconsistent",
+        8 => "object-attribute-inconsistent",
+        9 => "object-access-unsupported",
+        10 => "object-non-existent",
+        11 => "object-value-invalid",
+        _ => "Unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn read_ber_elements() {
+        // fileOpen [72] construit, etiquette longue.
+        let (tlv, rest) =
+            extract_mms_tlv(&[0xBF, 0x48, 0x01, 0xAA, 0x05], "service").expect("valid TLV");
+        assert!(tlv.constructed && tlv.is_context(72));
+        assert_eq!((tlv.value, rest), (&[0xAA][..], &[0x05][..]));
+
+        let mut long = vec![0x04, 0x81, 0x80];
+        long.extend_from_slice(&[0; 128]);
+        assert_eq!(
+            extract_mms_tlv(&long, "value")
+                .expect("valid TLV")
+                .0
+                .value
+                .len(),
+            128
+        );
+        assert_eq!(
+            extract_mms_tlv(&[0x30, 0x80, 0x00, 0x00], "value"),
+            Err(MmsError::UnsupportedLength { field: "value" })
+        );
+        assert_eq!(
+            extract_mms_tlv(&[0x02, 0x02, 0x01], "value"),
+            Err(MmsError::Truncated { field: "value" })
+        );
+        assert_eq!(extract_mms_integer(&[0xFF, 0x38], "value"), Ok(-200));
+        assert_eq!(
+            extract_mms_unsigned(&[0x00, 0x80, 0, 0, 0], "value"),
+            Ok(1 << 31)
+        );
+    }
+
+    #[test]
+    fn session_and_tpkt_framing() {
+        let (cotp, rest) = extract_mms_tpkt(&[0x03, 0x00, 0x00, 0x07, 0x02, 0xF0, 0x80, 0x03])
+            .expect("valid TPKT");
+        assert_eq!((cotp, rest), (&[0x02, 0xF0, 0x80][..], &[0x03][..]));
+
+        assert_eq!(
+            extract_mms_session_user_data(&[0x01, 0x00, 0x01, 0x00, 0x61]),
+            Ok((MMS_SPDU_DATA_TRANSFER, &[0x61][..]))
+        );
+        // CONNECT : version (0x16), puis donnees utilisateur (0xC1).
+        assert_eq!(
+            extract_mms_session_user_data(&[0x0D, 0x07, 0x16, 0x01, 0x02, 0xC1, 0x02, 0x31, 0x00]),
+            Ok((MMS_SPDU_CONNECT, &[0x31, 0x00][..]))
+        );
+        assert_eq!(
+            extract_mms_session_user_data(&[0x0D, 0x03, 0x16, 0x01, 0x02]),
+            Err(MmsError::MissingUserData("session SPDU"))
+        );
+        assert_eq!(
+            extract_mms_session_user_data(&[0x32, 0x01]),
+            Err(MmsError::UnsupportedSpdu(0x32))
+        );
+    }
+}
@@ -3,10 +3,37 @@
 // Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
 // This file may not be copied, modified, or distributed except according to those terms.
 
-use crate::errors::application::modbus_tcp::ModbusTcpError;
+use crate::errors::application::modbus_tcp::{ModbusDecodeError, ModbusTcpError};
 
 pub const MBAP_MIN_SIZE: usize = 7;
 
+pub const MODBUS_FC_READ_COILS: u8 = 0x01;
+pub const MODBUS_FC_READ_DISCRETE_INPUTS: u8 = 0x02;
+pub const MODBUS_FC_READ_HOLDING_REGISTERS: u8 = 0x03;
+pub const MODBUS_FC_READ_INPUT_REGISTERS: u8 = 0x04;
+pub const MODBUS_FC_WRITE_SINGLE_COIL: u8 = 0x05;
+pub const MODBUS_FC_WRITE_SINGLE_REGISTER: u8 = 0x06;
+pub const MODBUS_FC_WRITE_MULTIPLE_COILS: u8 = 0x0F;
+pub const MODBUS_FC_WRITE_MULTIPLE_REGISTERS: u8 = 0x10;
+pub const MODBUS_FC_MASK_WRITE_REGISTER: u8 = 0x16;
+pub const MODBUS_FC_READ_WRITE_MULTIPLE_REGISTERS: u8 = 0x17;
+pub const MODBUS_FC_ENCAPSULATED_INTERFACE: u8 = 0x2B;
+
+/// Bit posé sur le function code d'une réponse d'exception.
+pub const MODBUS_EXCEPTION_FLAG: u8 = 0x80;
+
+/// MEI type de Read Device Identification (function code 43).
+pub const MODBUS_MEI_READ_DEVICE_IDENTIFICATION: u8 = 0x0E;
+
+pub const MODBUS_COIL_ON: u16 = 0xFF00;
+pub const MODBUS_COIL_OFF: u16 = 0x0000;
+
+pub const MODBUS_MORE_FOLLOWS: u8 = 0xFF;
+
+/// Requêtes sans réponse conservées par connexion avant d'évincer la plus
+/// ancienne.
+pub const MODBUS_MAX_PENDING_TRANSACTIONS: usize = 64;
+
 pub fn validate_mbap_min_size(value: &[u8]) -> Result<(), ModbusTcpError> {
     if value.len() < MBAP_MIN_SIZE {
         return Err(ModbusTcpError::BufferTooSmall {
@@ -138,6 +165,154 @@ pub fn extract_function_code(pdu: &[u8]) -> Result<(u8, &[u8]), ModbusTcpError>
     Ok((pdu[0], &pdu[1..]))
 }
 
+pub fn extract_modbus_u8<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(u8, &'a [u8]), ModbusDecodeError> {
+    match buf {
+        [value, rest @ ..] => Ok((*value, rest)),
+        [] => Err(ModbusDecodeError::Truncated { field }),
+    }
+}
+
+pub fn extract_modbus_u16<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(u16, &'a [u8]), ModbusDecodeError> {
+    match buf {
+        [high, low, rest @ ..] => Ok((u16::from_be_bytes([*high, *low]), rest)),
+        _ => Err(ModbusDecodeError::Truncated { field }),
+    }
+}
+
+pub fn extract_modbus_bytes<'a>(
+    buf: &'a [u8],
+    len: usize,
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), ModbusDecodeError> {
+    if buf.len() < len {
+        return Err(ModbusDecodeError::Truncated { field });
+    }
+    Ok(buf.split_at(len))
+}
+
+/// Byte count u8 suivi d'autant d'octets, forme des réponses de lecture et
+/// des écritures multiples.
+pub fn extract_modbus_counted_bytes<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(u8, &'a [u8], &'a [u8]), ModbusDecodeError> {
+    let (byte_count, rest) = extract_modbus_u8(buf, field)?;
+    let (bytes, rest) = extract_modbus_bytes(rest, usize::from(byte_count), field)?;
+    Ok((byte_count, bytes, rest))
+}
+
+/// Valeur d'une bobine : 0xFF00 pour ON, 0x0000 pour OFF, toute autre
+/// valeur est illégale (§6.5).
+pub fn extract_modbus_coil_value(buf: &[u8]) -> Result<(bool, &[u8]), ModbusDecodeError> {
+    let (value, rest) = extract_modbus_u16(buf, "coil value")?;
+    match value {
+        MODBUS_COIL_ON => Ok((true, rest)),
+        MODBUS_COIL_OFF => Ok((false, rest)),
+        _ => Err(ModbusDecodeError::InvalidCoilValue(value)),
+    }
+}
+
+pub fn validate_modbus_no_trailing_data(
+    rest: &[u8],
+    field: &'static str,
+) -> Result<(), ModbusDecodeError> {
+    if !rest.is_empty() {
+        return Err(ModbusDecodeError::TrailingData {
+            field,
+            length: rest.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Octets nécessaires à `quantity` bits tassés, bit de poids faible en
+/// premier.
+pub fn modbus_bit_byte_count(quantity: u16) -> usize {
+    usize::from(quantity).div_ceil(8)
+}
+
+/// Vérifie le byte count d'une écriture multiple face à la quantité
+/// annoncée.
+pub fn validate_modbus_byte_count(declared: u8, expected: usize) -> Result<(), ModbusDecodeError> {
+    if usize::from(declared) != expected {
+        return Err(ModbusDecodeError::ByteCountMismatch { declared, expected });
+    }
+    Ok(())
+}
+
+/// Les registres font deux octets : un byte count impair est malformé.
+pub fn validate_modbus_register_byte_count(byte_count: u8) -> Result<(), ModbusDecodeError> {
+    if !byte_count.is_multiple_of(2) {
+        return Err(ModbusDecodeError::OddRegisterByteCount { byte_count });
+    }
+    Ok(())
+}
+
+/// Nom d'un function code public (Modbus Application Protocol v1.1b3, §5).
+/// Le bit d'exception est ignoré.
+pub fn modbus_function_name(function_code: u8) -> &'static str {
+    match function_code & !MODBUS_EXCEPTION_FLAG {
+        MODBUS_FC_READ_COILS => "Read Coils",
+        MODBUS_FC_READ_DISCRETE_INPUTS => "Read Discrete Inputs",
+        MODBUS_FC_READ_HOLDING_REGISTERS => "Read Holding Registers",
+        MODBUS_FC_READ_INPUT_REGISTERS => "Read Input Registers",
+        MODBUS_FC_WRITE_SINGLE_COIL => "Write Single Coil",
+        MODBUS_FC_WRITE_SINGLE_REGISTER => "Write Single Register",
+        0x07 => "Read Exception Status",
+        0x08 => "Diagnostics",
+        0x0B => "Get Comm Event Counter",
+        0x0C => "Get Comm Event Log",
+        MODBUS_FC_WRITE_MULTIPLE_COILS => "Write Multiple Coils",
+        MODBUS_FC_WRITE_MULTIPLE_REGISTERS => "Write Multiple Registers",
+        0x11 => "Report Server ID",
+        0x14 => "Read File Record",
+        0x15 => "Write File Record",
+        MODBUS_FC_MASK_WRITE_REGISTER => "Mask Write Register",
+        MODBUS_FC_READ_WRITE_MULTIPLE_REGISTERS => "Read/Write Multiple Registers",
+        0x18 => "Read FIFO Queue",
+        MODBUS_FC_ENCAPSULATED_INTERFACE => "Encapsulated Interface Transport",
+        _ => "Unknown",
+    }
+}
+
+/// Nom d'un code d'exception (§7).
+pub fn modbus_exception_code_name(exception_code: u8) -> &'static str {
+    match exception_code {
+        0x01 => "Illegal Function",
+        0x02 => "Illegal Data Address",
+        0x03 => "Illegal Data Value",
+        0x04 => "Server Device Failure",
+        0x05 => "Acknowledge",
+        0x06 => "Server Device Busy",
+        0x08 => "Memory Parity Error",
+        0x0A => "Gateway Path Unavailable",
+        0x0B => "Gateway Target Device Failed to Respond",
+        _ => "Unknown",
+    }
+}
+
+/// Nom d'un objet de Read Device Identification (§6.21) ; 0x07-0x7F sont
+/// réservés, 0x80-0xFF propres au fabricant.
+pub fn modbus_device_object_name(object_id: u8) -> &'static str {
+    match object_id {
+        0x00 => "VendorName",
+        0x01 => "ProductCode",
+        0x02 => "MajorMinorRevision",
+        0x03 => "VendorUrl",
+        0x04 => "ProductName",
+        0x05 => "ModelName",
+        0x06 => "UserApplicationName",
+        0x80..=0xFF => "Private",
+        _ => "Reserved",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -219,4 +394,63 @@ mod tests {
             })
         ));
     }
+
+    #[test]
+    fn test_extract_modbus_coil_value() {
+        assert_eq!(
+            extract_modbus_coil_value(&[0xFF, 0x00]),
+            Ok((true, &[][..]))
+        );
+        assert_eq!(
+            extract_modbus_coil_value(&[0x00, 0x00, 0x01]),
+            Ok((false, &[0x01][..]))
+        );
+        assert_eq!(
+            extract_modbus_coil_value(&[0x00, 0x01]),
+            Err(ModbusDecodeError::InvalidCoilValue(0x0001))
+        );
+        assert_eq!(
+            extract_modbus_coil_value(&[0xFF]),
+            Err(ModbusDecodeError::Truncated {
+                field: "coil value"
+            })
+        );
+    }
+
+    #[test]
+    fn test_modbus_byte_counts() {
+        assert_eq!(modbus_bit_byte_count(0), 0);
+        assert_eq!(modbus_bit_byte_count(8), 1);
+        assert_eq!(modbus_bit_byte_count(10), 2);
+        assert_eq!(validate_modbus_byte_count(2, 2), Ok(()));
+        assert_eq!(
+            validate_modbus_byte_count(1, 2),
+            Err(ModbusDecodeError::ByteCountMismatch {
+                declared: 1,
+                expected: 2
+            })
+        );
+        assert_eq!(
+            validate_modbus_register_byte_count(3),
+            Err(ModbusDecodeError::OddRegisterByteCount { byte_count: 3 })
+        );
+        // Byte count 2 annoncé, un seul octet présent.
+        assert_eq!(
+            extract_modbus_counted_bytes(&[0x02, 0x41], "register values"),
+            Err(ModbusDecodeError::Truncated {
+                field: "register values"
+            })
+        );
+    }
+
+    #[test]
+    fn test_modbus_names() {
+        assert_eq!(modbus_function_name(0x10), "Write Multiple Registers");
+        assert_eq!(modbus_function_name(0x83), "Read Holding Registers");
+        assert_eq!(modbus_function_name(0x7D), "Unknown");
+        assert_eq!(modbus_exception_code_name(0x02), "Illegal Data Address");
+        assert_eq!(modbus_device_object_name(0x01), "ProductCode");
+        assert_eq!(modbus_device_object_name(0x90), "Private");
+        assert_eq!(modbus_device_object_name(0x10), "Reserved");
+    }
 }
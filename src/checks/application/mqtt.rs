@@ -4,7 +4,10 @@
 // This file may not be copied, modified, or distributed except according to those terms.
 
 use crate::{
-    errors::application::mqtt::MqttError, parse::application::protocols::mqtt::MqttPacketType,
+    errors::application::mqtt::{MqttDecodeError, MqttError},
+    parse::application::protocols::mqtt::{
+        MqttPacketType, message::MqttVersion, properties::split_mqtt_properties,
+    },
 };
 
 pub const MQTT_MIN_HEADER_LEN: usize = 2;
@@ -374,8 +377,19 @@ fn validate_publish_vh(first_byte: u8, body: &[u8]) -> Result<usize, MqttError>
             available: body.len().saturating_sub(2),
         });
     }
-    validate_publish_topic(&body[2..2 + topic_len])?;
     let qos = (first_byte >> 1) & 0b11;
+    let topic = &body[2..vh_len];
+    // MQTT 5 : topic vide remplacé par un Topic Alias (§3.3.2.3.4). Les
+    // propriétés suivent le packet id éventuel.
+    let properties_start = if qos > 0 { vh_len + 2 } else { vh_len };
+    let aliased = topic.is_empty()
+        && body
+            .get(properties_start..)
+            .and_then(|rest| split_mqtt_properties(rest).ok())
+            .is_some_and(|(properties, _)| properties.topic_alias().is_some());
+    if !aliased {
+        validate_publish_topic(topic)?;
+    }
     if qos > 0 {
         read_packet_id(MqttPacketType::Publish, &body[vh_len..])?;
         vh_len += 2;
@@ -419,12 +433,15 @@ fn validate_subscription_vh(packet_type: MqttPacketType, body: &[u8]) -> Result<
     read_packet_id(packet_type, body)?;
     let with_qos = packet_type == MqttPacketType::Subscribe;
     // v3 : les entrées commencent après le packet id ; v5 : après un
-    // bloc de propriétés (toléré uniquement vide : 0x00).
+    // bloc de propriétés bien formé.
     let v3 = subscription_entries_fill_exactly(packet_type, body, 2, with_qos);
-    let v5_empty_props = body.len() > 2
-        && body[2] == 0x00
-        && subscription_entries_fill_exactly(packet_type, body, 3, with_qos);
-    if !v3 && !v5_empty_props {
+    let v5 = || {
+        split_mqtt_properties(&body[2..]).is_ok_and(|(_, entries)| {
+            let start = body.len() - entries.len();
+            subscription_entries_fill_exactly(packet_type, body, start, with_qos)
+        })
+    };
+    if !v3 && !v5() {
         return Err(MqttError::MalformedSubscriptionPayload { packet_type });
     }
     Ok(2)
@@ -443,16 +460,25 @@ fn validate_suback_vh(body: &[u8]) -> Result<usize, MqttError> {
     // v3 : chaque octet après le packet id est un code de retour.
     // (Un SUBACK v5 à propriétés vides passe aussi : 0x00 est un code
     // valide.)
-    if let Some(&bad) = body[2..]
-        .iter()
-        .find(|code| !SUBACK_REASON_CODES.contains(code))
-    {
-        return Err(MqttError::InvalidReasonCode {
-            packet_type: MqttPacketType::Suback,
-            code: bad,
-        });
+    let invalid = |codes: &[u8]| {
+        codes
+            .iter()
+            .copied()
+            .find(|code| !SUBACK_REASON_CODES.contains(code))
+    };
+    let Some(bad) = invalid(&body[2..]) else {
+        return Ok(2);
+    };
+    // v5 : bloc de propriétés puis au moins un reason code.
+    let v5 = split_mqtt_properties(&body[2..])
+        .is_ok_and(|(_, codes)| !codes.is_empty() && invalid(codes).is_none());
+    if v5 {
+        return Ok(2);
     }
-    Ok(2)
+    Err(MqttError::InvalidReasonCode {
+        packet_type: MqttPacketType::Suback,
+        code: bad,
+    })
 }
 
 /// Corps UNSUBACK : packet id non nul seul (v3.1.1), ou propriétés puis au
@@ -521,6 +547,225 @@ fn validate_disconnect_vh(body: &[u8]) -> Result<usize, MqttError> {
     }
 }
 
+/// Niveaux de protocole du CONNECT (MQTT 3.1.1 §3.1.2.2, MQTT 5 §3.1.2.2).
+pub const MQTT_LEVEL_3_1: u8 = 3;
+pub const MQTT_LEVEL_3_1_1: u8 = 4;
+pub const MQTT_LEVEL_5: u8 = 5;
+
+/// Bits des connect flags (§3.1.2.3) ; le Will QoS occupe les bits 3-4.
+pub const MQTT_CONNECT_CLEAN_START: u8 = 0x02;
+pub const MQTT_CONNECT_WILL: u8 = 0x04;
+pub const MQTT_CONNECT_WILL_QOS_MASK: u8 = 0x18;
+pub const MQTT_CONNECT_WILL_RETAIN: u8 = 0x20;
+pub const MQTT_CONNECT_PASSWORD: u8 = 0x40;
+pub const MQTT_CONNECT_USERNAME: u8 = 0x80;
+
+/// Identifiants de propriétés MQTT 5 (§2.2.2.2).
+pub const MQTT_PROP_PAYLOAD_FORMAT_INDICATOR: u8 = 0x01;
+pub const MQTT_PROP_MESSAGE_EXPIRY_INTERVAL: u8 = 0x02;
+pub const MQTT_PROP_CONTENT_TYPE: u8 = 0x03;
+pub const MQTT_PROP_RESPONSE_TOPIC: u8 = 0x08;
+pub const MQTT_PROP_CORRELATION_DATA: u8 = 0x09;
+pub const MQTT_PROP_SUBSCRIPTION_IDENTIFIER: u8 = 0x0B;
+pub const MQTT_PROP_SESSION_EXPIRY_INTERVAL: u8 = 0x11;
+pub const MQTT_PROP_ASSIGNED_CLIENT_IDENTIFIER: u8 = 0x12;
+pub const MQTT_PROP_SERVER_KEEP_ALIVE: u8 = 0x13;
+pub const MQTT_PROP_AUTHENTICATION_METHOD: u8 = 0x15;
+pub const MQTT_PROP_AUTHENTICATION_DATA: u8 = 0x16;
+pub const MQTT_PROP_REQUEST_PROBLEM_INFORMATION: u8 = 0x17;
+pub const MQTT_PROP_WILL_DELAY_INTERVAL: u8 = 0x18;
+pub const MQTT_PROP_REQUEST_RESPONSE_INFORMATION: u8 = 0x19;
+pub const MQTT_PROP_RESPONSE_INFORMATION: u8 = 0x1A;
+pub const MQTT_PROP_SERVER_REFERENCE: u8 = 0x1C;
+pub const MQTT_PROP_REASON_STRING: u8 = 0x1F;
+pub const MQTT_PROP_RECEIVE_MAXIMUM: u8 = 0x21;
+pub const MQTT_PROP_TOPIC_ALIAS_MAXIMUM: u8 = 0x22;
+pub const MQTT_PROP_TOPIC_ALIAS: u8 = 0x23;
+pub const MQTT_PROP_MAXIMUM_QOS: u8 = 0x24;
+pub const MQTT_PROP_RETAIN_AVAILABLE: u8 = 0x25;
+pub const MQTT_PROP_USER_PROPERTY: u8 = 0x26;
+pub const MQTT_PROP_MAXIMUM_PACKET_SIZE: u8 = 0x27;
+pub const MQTT_PROP_WILDCARD_SUBSCRIPTION_AVAILABLE: u8 = 0x28;
+pub const MQTT_PROP_SUBSCRIPTION_IDENTIFIER_AVAILABLE: u8 = 0x29;
+pub const MQTT_PROP_SHARED_SUBSCRIPTION_AVAILABLE: u8 = 0x2A;
+
+pub fn extract_mqtt_u8<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(u8, &'a [u8]), MqttDecodeError> {
+    match buf {
+        [value, rest @ ..] => Ok((*value, rest)),
+        [] => Err(MqttDecodeError::Truncated { field }),
+    }
+}
+
+pub fn extract_mqtt_u16<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(u16, &'a [u8]), MqttDecodeError> {
+    match buf {
+        [high, low, rest @ ..] => Ok((u16::from_be_bytes([*high, *low]), rest)),
+        _ => Err(MqttDecodeError::Truncated { field }),
+    }
+}
+
+pub fn extract_mqtt_u32<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(u32, &'a [u8]), MqttDecodeError> {
+    match buf {
+        [a, b, c, d, rest @ ..] => Ok((u32::from_be_bytes([*a, *b, *c, *d]), rest)),
+        _ => Err(MqttDecodeError::Truncated { field }),
+    }
+}
+
+/// Variable Byte Integer (§1.5.5), même encodage que le remaining length.
+pub fn extract_mqtt_varint<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(u32, &'a [u8]), MqttDecodeError> {
+    let (value, len) =
+        decode_remaining_length(buf).map_err(|_| MqttDecodeError::MalformedVarint { field })?;
+    Ok((value, &buf[len..]))
+}
+
+/// Binary Data (§1.5.6) : longueur u16 puis octets.
+pub fn extract_mqtt_binary<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), MqttDecodeError> {
+    let (len, rest) = extract_mqtt_u16(buf, field)?;
+    let len = usize::from(len);
+    if rest.len() < len {
+        return Err(MqttDecodeError::Truncated { field });
+    }
+    Ok(rest.split_at(len))
+}
+
+/// UTF-8 Encoded String (§1.5.4) : comme [`extract_mqtt_binary`], en UTF-8
+/// valide et sans U+0000.
+pub fn extract_mqtt_string<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(&'a str, &'a [u8]), MqttDecodeError> {
+    let (bytes, rest) = extract_mqtt_binary(buf, field)?;
+    let value = std::str::from_utf8(bytes)
+        .ok()
+        .filter(|value| !value.contains('\0'))
+        .ok_or(MqttDecodeError::InvalidUtf8 { field })?;
+    Ok((value, rest))
+}
+
+pub fn validate_mqtt_no_trailing_data(
+    rest: &[u8],
+    field: &'static str,
+) -> Result<(), MqttDecodeError> {
+    if !rest.is_empty() {
+        return Err(MqttDecodeError::TrailingData {
+            field,
+            length: rest.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Will QoS et Will Retain exigent le Will Flag ; Will QoS 3 est interdit
+/// (§3.1.2.6, §3.1.2.7).
+pub fn validate_mqtt_will_flags(connect_flags: u8) -> Result<(), MqttDecodeError> {
+    let qos = (connect_flags & MQTT_CONNECT_WILL_QOS_MASK) >> 3;
+    if qos == 3 {
+        return Err(MqttDecodeError::InvalidQos { qos });
+    }
+    let will_only = MQTT_CONNECT_WILL_QOS_MASK | MQTT_CONNECT_WILL_RETAIN;
+    if connect_flags & MQTT_CONNECT_WILL == 0 && connect_flags & will_only != 0 {
+        return Err(MqttDecodeError::InvalidWillFlags {
+            flags: connect_flags,
+        });
+    }
+    Ok(())
+}
+
+/// Options d'une souscription : QoS seul en 3.1.1 (bits 2-7 réservés) ;
+/// en MQTT 5, No Local, Retain As Published et Retain Handling 0-2, bits
+/// 6-7 réservés (§3.8.3.1).
+pub fn validate_mqtt_subscription_options(
+    version: MqttVersion,
+    options: u8,
+) -> Result<(), MqttDecodeError> {
+    let reserved = match version {
+        MqttVersion::V5 => 0xC0,
+        _ => 0xFC,
+    };
+    if options & reserved != 0 || options & 0x03 == 3 || (options >> 4) & 0x03 == 3 {
+        return Err(MqttDecodeError::InvalidSubscriptionOptions(options));
+    }
+    Ok(())
+}
+
+/// Nom d'un reason code MQTT 5 (§2.4). 0x00 vaut aussi « Normal
+/// disconnection » (DISCONNECT) et « Granted QoS 0 » (SUBACK).
+pub fn mqtt_reason_code_name(code: u8) -> &'static str {
+    match code {
+        0x00 => "Success",
+        0x01 => "Granted QoS 1",
+        0x02 => "Granted QoS 2",
+        0x04 => "Disconnect with Will Message",
+        0x10 => "No matching subscribers",
+        0x11 => "No subscription existed",
+        0x18 => "Continue authentication",
+        0x19 => "Re-authenticate",
+        0x80 => "Unspecified error",
+        0x81 => "Malformed Packet",
+        0x82 => "Protocol Error",
+        0x83 => "Implementation specific error",
+        0x84 => "Unsupported Protocol Version",
+        0x85 => "Client Identifier not valid",
+        0x86 => "Bad User Name or Password",
+        0x87 => "Not authorized",
+        0x88 => "Server unavailable",
+        0x89 => "Server busy",
+        0x8A => "Banned",
+        0x8B => "Server shutting down",
+        0x8C => "Bad authentication method",
+        0x8D => "Keep Alive timeout",
+        0x8E => "Session taken over",
+        0x8F => "Topic Filter invalid",
+        0x90 => "Topic Name invalid",
+        0x91 => "Packet Identifier in use",
+        0x92 => "Packet Identifier not found",
+        0x93 => "Receive Maximum exceeded",
+        0x94 => "Topic Alias invalid",
+        0x95 => "Packet too large",
+        0x96 => "Message rate too high",
+        0x97 => "Quota exceeded",
+        0x98 => "Administrative action",
+        0x99 => "Payload format invalid",
+        0x9A => "Retain not supported",
+        0x9B => "QoS not supported",
+        0x9C => "Use another server",
+        0x9D => "Server moved",
+        0x9E => "Shared Subscriptions not supported",
+        0x9F => "Connection rate exceeded",
+        0xA0 => "Maximum connect time",
+        0xA1 => "Subscription Identifiers not supported",
+        0xA2 => "Wildcard Subscriptions not supported",
+        _ => "Unknown",
+    }
+}
+
+/// Nom d'un code de retour CONNACK de MQTT 3.1/3.1.1 (§3.2.2.3).
+pub fn mqtt_connect_return_code_name(code: u8) -> &'static str {
+    match code {
+        0 => "Connection Accepted",
+        1 => "Unacceptable protocol version",
+        2 => "Identifier rejected",
+        3 => "Server unavailable",
+        4 => "Bad user name or password",
+        5 => "Not authorized",
+        _ => "Unknown",
+    }
+}
+
 /// Valide le corps (`remaining length` octets) selon le type de paquet et
 /// retourne la longueur du variable header.
 ///
@@ -938,4 +1183,87 @@ mod tests {
             Err(MqttError::MalformedSubscriptionPayload { .. })
         ));
     }
+
+    #[test]
+    fn test_extract_mqtt_fields() {
+        let buf = [0x00, 0x03, b'a', b'/', b'b', 0x80, 0x01, 0xFF];
+        let (topic, rest) = extract_mqtt_string(&buf, "topic").unwrap();
+        assert_eq!(topic, "a/b");
+        let (value, rest) = extract_mqtt_varint(rest, "varint").unwrap();
+        assert_eq!(value, 128);
+        assert!(matches!(
+            extract_mqtt_u16(rest, "packet id"),
+            Err(MqttDecodeError::Truncated { field: "packet id" })
+        ));
+        assert!(matches!(
+            validate_mqtt_no_trailing_data(rest, "payload"),
+            Err(MqttDecodeError::TrailingData { length: 1, .. })
+        ));
+        // U+0000 et UTF-8 invalide sont rejetés (§1.5.4).
+        for bad in [&[0x00, 0x01, 0x00][..], &[0x00, 0x01, 0xFF]] {
+            assert!(matches!(
+                extract_mqtt_string(bad, "topic"),
+                Err(MqttDecodeError::InvalidUtf8 { field: "topic" })
+            ));
+        }
+        assert!(matches!(
+            extract_mqtt_binary(&[0x00, 0x05, 0x01], "password"),
+            Err(MqttDecodeError::Truncated { field: "password" })
+        ));
+    }
+
+    #[test]
+    fn test_validate_mqtt_will_and_subscription_options() {
+        assert!(validate_mqtt_will_flags(0x2C).is_ok());
+        assert!(matches!(
+            validate_mqtt_will_flags(0x20),
+            Err(MqttDecodeError::InvalidWillFlags { flags: 0x20 })
+        ));
+        assert!(matches!(
+            validate_mqtt_will_flags(0x1C),
+            Err(MqttDecodeError::InvalidQos { qos: 3 })
+        ));
+
+        assert!(validate_mqtt_subscription_options(MqttVersion::V3_1_1, 0x01).is_ok());
+        assert!(validate_mqtt_subscription_options(MqttVersion::V3_1_1, 0x05).is_err());
+        // No Local + Retain As Published + Retain Handling 2.
+        assert!(validate_mqtt_subscription_options(MqttVersion::V5, 0x2E).is_ok());
+        for options in [0x03, 0x30, 0x40] {
+            assert!(matches!(
+                validate_mqtt_subscription_options(MqttVersion::V5, options),
+                Err(MqttDecodeError::InvalidSubscriptionOptions(o)) if o == options
+            ));
+        }
+    }
+
+    #[test]
+    fn test_mqtt_code_names() {
+        assert_eq!(mqtt_reason_code_name(0x87), "Not authorized");
+        assert_eq!(mqtt_reason_code_name(0x94), "Topic Alias invalid");
+        assert_eq!(mqtt_reason_code_name(0x7F), "Unknown");
+        assert_eq!(mqtt_connect_return_code_name(5), "Not authorized");
+        assert_eq!(mqtt_connect_return_code_name(6), "Unknown");
+    }
+
+    #[test]
+    fn test_variable_header_len_v5_forms() {
+        // SUBSCRIBE v5 : Subscription Identifier 11 avant les filtres.
+        let body = hex::decode("0002020b030003612f6225").unwrap();
+        assert_eq!(
+            variable_header_len(MqttPacketType::Subscribe, 0x82, &body).unwrap(),
+            2
+        );
+        // SUBACK v5 : Reason String « ok » puis Granted QoS 1 et 0x87.
+        let body = hex::decode("0002051f00026f6b0187").unwrap();
+        assert_eq!(
+            variable_header_len(MqttPacketType::Suback, 0x90, &body).unwrap(),
+            2
+        );
+        // PUBLISH v5 QoS 2 à topic vide remplacé par le Topic Alias 7.
+        let body = hex::decode("0000000a0323000732312e35").unwrap();
+        assert!(variable_header_len(MqttPacketType::Publish, 0x35, &body).is_ok());
+        // Sans Topic Alias, le topic vide reste refusé.
+        let body = hex::decode("0000000a00").unwrap();
+        assert!(variable_header_len(MqttPacketType::Publish, 0x35, &body).is_err());
+    }
 }
@@ -0,0 +1,273 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Controles des messages NTLMSSP (MS-NLMP §2.2) : signature, champs
+//! longueur / offset du payload, drapeaux de negociation et AV_PAIR.
+
+use crate::errors::application::ntlmssp::NtlmsspError;
+
+/// Jeton NTLMSSP : signature, puis type de message sur 4 octets.
+pub const NTLMSSP_SIGNATURE: [u8; 8] = *b"NTLMSSP\0";
+
+pub const NTLMSSP_NEGOTIATE: u32 = 1;
+pub const NTLMSSP_CHALLENGE: u32 = 2;
+pub const NTLMSSP_AUTHENTICATE: u32 = 3;
+
+/// Parties fixes, sans la structure VERSION optionnelle de 8 octets.
+pub const NTLMSSP_NEGOTIATE_MIN_LEN: usize = 16;
+pub const NTLMSSP_NEGOTIATE_FIXED_LEN: usize = 32;
+pub const NTLMSSP_CHALLENGE_FIXED_LEN: usize = 48;
+pub const NTLMSSP_AUTHENTICATE_FIXED_LEN: usize = 64;
+pub const NTLMSSP_VERSION_LEN: usize = 8;
+/// Champ de payload : longueur u16, longueur maximale u16, offset u32.
+pub const NTLMSSP_FIELD_LEN: usize = 8;
+/// NtChallengeResponse d'une authentification NTLMv1 (MS-NLMP §2.2.2.6).
+pub const NTLMV1_RESPONSE_LEN: usize = 24;
+
+pub const NTLMSSP_NEGOTIATE_UNICODE: u32 = 0x0000_0001;
+pub const NTLMSSP_NEGOTIATE_OEM: u32 = 0x0000_0002;
+pub const NTLMSSP_REQUEST_TARGET: u32 = 0x0000_0004;
+pub const NTLMSSP_NEGOTIATE_SIGN: u32 = 0x0000_0010;
+pub const NTLMSSP_NEGOTIATE_SEAL: u32 = 0x0000_0020;
+pub const NTLMSSP_NEGOTIATE_LM_KEY: u32 = 0x0000_0080;
+pub const NTLMSSP_NEGOTIATE_NTLM: u32 = 0x0000_0200;
+pub const NTLMSSP_ANONYMOUS: u32 = 0x0000_0800;
+pub const NTLMSSP_NEGOTIATE_OEM_DOMAIN_SUPPLIED: u32 = 0x0000_1000;
+pub const NTLMSSP_NEGOTIATE_OEM_WORKSTATION_SUPPLIED: u32 = 0x0000_2000;
+pub const NTLMSSP_NEGOTIATE_ALWAYS_SIGN: u32 = 0x0000_8000;
+pub const NTLMSSP_NEGOTIATE_EXTENDED_SESSIONSECURITY: u32 = 0x0008_0000;
+pub const NTLMSSP_NEGOTIATE_TARGET_INFO: u32 = 0x0080_0000;
+pub const NTLMSSP_NEGOTIATE_VERSION: u32 = 0x0200_0000;
+pub const NTLMSSP_NEGOTIATE_128: u32 = 0x2000_0000;
+pub const NTLMSSP_NEGOTIATE_KEY_EXCH: u32 = 0x4000_0000;
+pub const NTLMSSP_NEGOTIATE_56: u32 = 0x8000_0000;
+
+/// Identifiants AV_PAIR de la TargetInfo (MS-NLMP §2.2.2.1).
+pub const MSV_AV_EOL: u16 = 0x0000;
+pub const MSV_AV_NB_COMPUTER_NAME: u16 = 0x0001;
+pub const MSV_AV_NB_DOMAIN_NAME: u16 = 0x0002;
+pub const MSV_AV_DNS_COMPUTER_NAME: u16 = 0x0003;
+pub const MSV_AV_DNS_DOMAIN_NAME: u16 = 0x0004;
+pub const MSV_AV_DNS_TREE_NAME: u16 = 0x0005;
+pub const MSV_AV_FLAGS: u16 = 0x0006;
+pub const MSV_AV_TIMESTAMP: u16 = 0x0007;
+pub const MSV_AV_SINGLE_HOST: u16 = 0x0008;
+pub const MSV_AV_TARGET_NAME: u16 = 0x0009;
+pub const MSV_AV_CHANNEL_BINDINGS: u16 = 0x000A;
+pub const NTLMSSP_AV_PAIR_HEADER_LEN: usize = 4;
+
+pub fn validate_ntlmssp_length(message: &[u8], expected: usize) -> Result<(), NtlmsspError> {
+    if message.len() < expected {
+        return Err(NtlmsspError::InvalidLength {
+            expected,
+            actual: message.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Verifie la signature et retourne le type de message.
+pub fn extract_ntlmssp_message_type(message: &[u8]) -> Result<u32, NtlmsspError> {
+    let Some(rest) = message.strip_prefix(&NTLMSSP_SIGNATURE) else {
+        return Err(NtlmsspError::InvalidSignature);
+    };
+    let (message_type, _) = rest
+        .split_first_chunk::<4>()
+        .ok_or(NtlmsspError::InvalidLength {
+            expected: NTLMSSP_SIGNATURE.len() + 4,
+            actual: message.len(),
+        })?;
+    Ok(u32::from_le_bytes(*message_type))
+}
+
+/// Contenu d'un champ de payload dont le descripteur est a `at` ; vide
+/// quand la longueur est nulle, quel que soit l'offset.
+pub fn extract_ntlmssp_field<'a>(
+    message: &'a [u8],
+    at: usize,
+    field: &'static str,
+) -> Result<&'a [u8], NtlmsspError> {
+    let descriptor =
+        message
+            .get(at..at + NTLMSSP_FIELD_LEN)
+            .ok_or(NtlmsspError::InvalidLength {
+                expected: at + NTLMSSP_FIELD_LEN,
+                actual: message.len(),
+            })?;
+    let length = usize::from(u16::from_le_bytes([descriptor[0], descriptor[1]]));
+    let offset =
+        u32::from_le_bytes([descriptor[4], descriptor[5], descriptor[6], descriptor[7]]) as usize;
+    if length == 0 {
+        return Ok(&[]);
+    }
+    offset
+        .checked_add(length)
+        .and_then(|end| message.get(offset..end))
+        .ok_or(NtlmsspError::InvalidField {
+            field,
+            offset,
+            length,
+        })
+}
+
+/// Chaine du payload : UTF-16LE si NTLMSSP_NEGOTIATE_UNICODE a ete
+/// negocie, jeu OEM sinon (lu comme du Latin-1).
+pub fn extract_ntlmssp_string(
+    bytes: &[u8],
+    unicode: bool,
+    field: &'static str,
+) -> Result<String, NtlmsspError> {
+    if !unicode {
+        return Ok(bytes.iter().map(|&byte| char::from(byte)).collect());
+    }
+    if !bytes.len().is_multiple_of(2) {
+        return Err(NtlmsspError::InvalidUtf16 { field });
+    }
+    let units = bytes
+        .chunks_exact(2)
+        .map(|unit| u16::from_le_bytes([unit[0], unit[1]]));
+    char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .map_err(|_| NtlmsspError::InvalidUtf16 { field })
+}
+
+pub fn ntlmssp_message_type_name(message_type: u32) -> &'static str {
+    match message_type {
+        NTLMSSP_NEGOTIATE => "NEGOTIATE",
+        NTLMSSP_CHALLENGE => "CHALLENGE",
+        NTLMSSP_AUTHENTICATE => "AUTHENTICATE",
+        _ => "Unknown",
+    }
+}
+
+/// Noms des drapeaux de negociation, dans l'ordre des bits.
+pub fn ntlmssp_flag_names(flags: u32) -> Vec<&'static str> {
+    [
+        (NTLMSSP_NEGOTIATE_UNICODE, "UNICODE"),
+        (NTLMSSP_NEGOTIATE_OEM, "OEM"),
+        (NTLMSSP_REQUEST_TARGET, "REQUEST_TARGET"),
+        (NTLMSSP_NEGOTIATE_SIGN, "SIGN"),
+        (NTLMSSP_NEGOTIATE_SEAL, "SEAL"),
+        (NTLMSSP_NEGOTIATE_LM_KEY, "LM_KEY"),
+        (NTLMSSP_NEGOTIATE_NTLM, "NTLM"),
+        (NTLMSSP_ANONYMOUS, "ANONYMOUS"),
+        (NTLMSSP_NEGOTIATE_OEM_DOMAIN_SUPPLIED, "OEM_DOMAIN_SUPPLIED"),
+        (
+            NTLMSSP_NEGOTIATE_OEM_WORKSTATION_SUPPLIED,
+            "OEM_WORKSTATION_SUPPLIED",
+        ),
+        (NTLMSSP_NEGOTIATE_ALWAYS_SIGN, "ALWAYS_SIGN"),
+        (
+            NTLMSSP_NEGOTIATE_EXTENDED_SESSIONSECURITY,
+            "EXTENDED_SESSIONSECURITY",
+        ),
+        (NTLMSSP_NEGOTIATE_TARGET_INFO, "TARGET_INFO"),
+        (NTLMSSP_NEGOTIATE_VERSION, "VERSION"),
+        (NTLMSSP_NEGOTIATE_128, "128"),
+        (NTLMSSP_NEGOTIATE_KEY_EXCH, "KEY_EXCH"),
+        (NTLMSSP_NEGOTIATE_56, "56"),
+    ]
+    .into_iter()
+    .filter(|(bit, _)| flags & bit != 0)
+    .map(|(_, name)| name)
+    .collect()
+}
+
+pub fn ntlmssp_av_pair_name(id: u16) -> &'static str {
+    match id {
+        MSV_AV_EOL => "MsvAvEOL",
+        MSV_AV_NB_COMPUTER_NAME => "MsvAvNbComputerName",
+        MSV_AV_NB_DOMAIN_NAME => "MsvAvNbDomainName",
+        MSV_AV_DNS_COMPUTER_NAME => "MsvAvDnsComputerName",
+        MSV_AV_DNS_DOMAIN_NAME => "MsvAvDnsDomainName",
+        MSV_AV_DNS_TREE_NAME => "MsvAvDnsTreeName",
+        MSV_AV_FLAGS => "MsvAvFlags",
+        MSV_AV_TIMESTAMP => "MsvAvTimestamp",
+        MSV_AV_SINGLE_HOST => "MsvAvSingleHost",
+        MSV_AV_TARGET_NAME => "MsvAvTargetName",
+        MSV_AV_CHANNEL_BINDINGS => "MsvAvChannelBindings",
+        _ => "Unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signature_and_message_type() {
+        assert_eq!(
+            extract_ntlmssp_message_type(b"NTLMSSP\0\x02\x00\x00\x00"),
+            Ok(NTLMSSP_CHALLENGE)
+        );
+        assert_eq!(
+            extract_ntlmssp_message_type(b"NTLMSSX\0\x02\x00\x00\x00"),
+            Err(NtlmsspError::InvalidSignature)
+        );
+        assert_eq!(
+            extract_ntlmssp_message_type(b"NTLMSSP\0\x02"),
+            Err(NtlmsspError::InvalidLength {
+                expected: 12,
+                actual: 9
+            })
+        );
+    }
+
+    #[test]
+    fn payload_fields_are_bounded() {
+        // Descripteur a l'offset 0 : 4 octets a l'offset 8.
+        let message = [4, 0, 4, 0, 8, 0, 0, 0, b'u', 0, b's', 0];
+        assert_eq!(
+            extract_ntlmssp_field(&message, 0, "user"),
+            Ok(&message[8..12])
+        );
+        let message = [4, 0, 4, 0, 10, 0, 0, 0, b'u', 0, b's', 0];
+        assert_eq!(
+            extract_ntlmssp_field(&message, 0, "user"),
+            Err(NtlmsspError::InvalidField {
+                field: "user",
+                offset: 10,
+                length: 4
+            })
+        );
+        // Longueur nulle : l'offset n'est pas verifie.
+        let message = [0, 0, 0, 0, 0xFF, 0xFF, 0, 0];
+        assert_eq!(extract_ntlmssp_field(&message, 0, "user"), Ok(&[][..]));
+    }
+
+    #[test]
+    fn strings_and_names() {
+        assert_eq!(
+            extract_ntlmssp_string(&[b'D', 0, b'C', 0], true, "domain"),
+            Ok("DC".to_string())
+        );
+        assert_eq!(
+            extract_ntlmssp_string(b"WS01", false, "workstation"),
+            Ok("WS01".to_string())
+        );
+        assert_eq!(
+            extract_ntlmssp_string(&[b'D', 0, b'C'], true, "domain"),
+            Err(NtlmsspError::InvalidUtf16 { field: "domain" })
+        );
+        assert_eq!(ntlmssp_message_type_name(3), "AUTHENTICATE");
+        assert_eq!(
+            ntlmssp_flag_names(0xA208_8205),
+            [
+                "UNICODE",
+                "REQUEST_TARGET",
+                "NTLM",
+                "ALWAYS_SIGN",
+                "EXTENDED_SESSIONSECURITY",
+                "VERSION",
+                "128",
+                "56"
+            ]
+        );
+        assert_eq!(
+            ntlmssp_av_pair_name(MSV_AV_DNS_DOMAIN_NAME),
+            "MsvAvDnsDomainName"
+        );
+    }
+}
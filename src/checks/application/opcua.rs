@@ -4,12 +4,68 @@
 // This file may not be copied, modified, or distributed except according to those terms.
 
 use crate::{
-    errors::application::opcua::OpcuaParseError,
+    errors::application::opcua::{OpcuaDecodeError, OpcuaParseError},
     parse::application::protocols::opcua::{OpcuaChunkType, OpcuaMessageType},
 };
 
 pub const OPCUA_TCP_HEADER_LEN: usize = 8;
 
+/// Security policy of unsecured channels: the only one whose `MSG` chunks
+/// can be decoded without keys.
+pub const OPCUA_SECURITY_POLICY_NONE: &str = "http://opcfoundation.org/UA/SecurityPolicy#None";
+
+/// Nesting bound for Variant, DataValue and DiagnosticInfo values, which
+/// may contain themselves.
+pub const OPCUA_MAX_NESTING_DEPTH: usize = 8;
+
+/// Largest message the reassembler accepts, all chunks together.
+pub const OPCUA_MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+
+/// Multi-chunk messages reassembled at once before the oldest is dropped.
+pub const OPCUA_MAX_PENDING_MESSAGES: usize = 32;
+
+/// Channels remembered as secured (policy other than None) before the
+/// oldest is forgotten.
+pub const OPCUA_MAX_SECURED_CHANNELS: usize = 64;
+
+/// Numeric identifiers (namespace 0) of the `DefaultBinary` encodings that
+/// head each service body.
+pub const OPCUA_SERVICE_FAULT: u32 = 397;
+pub const OPCUA_GET_ENDPOINTS_REQUEST: u32 = 428;
+pub const OPCUA_GET_ENDPOINTS_RESPONSE: u32 = 431;
+pub const OPCUA_OPEN_SECURE_CHANNEL_REQUEST: u32 = 446;
+pub const OPCUA_OPEN_SECURE_CHANNEL_RESPONSE: u32 = 449;
+pub const OPCUA_CLOSE_SECURE_CHANNEL_REQUEST: u32 = 452;
+pub const OPCUA_CLOSE_SECURE_CHANNEL_RESPONSE: u32 = 455;
+pub const OPCUA_CREATE_SESSION_REQUEST: u32 = 461;
+pub const OPCUA_CREATE_SESSION_RESPONSE: u32 = 464;
+pub const OPCUA_ACTIVATE_SESSION_REQUEST: u32 = 467;
+pub const OPCUA_ACTIVATE_SESSION_RESPONSE: u32 = 470;
+pub const OPCUA_CLOSE_SESSION_REQUEST: u32 = 473;
+pub const OPCUA_CLOSE_SESSION_RESPONSE: u32 = 476;
+pub const OPCUA_BROWSE_REQUEST: u32 = 527;
+pub const OPCUA_BROWSE_RESPONSE: u32 = 530;
+pub const OPCUA_READ_REQUEST: u32 = 631;
+pub const OPCUA_READ_RESPONSE: u32 = 634;
+pub const OPCUA_WRITE_REQUEST: u32 = 673;
+pub const OPCUA_WRITE_RESPONSE: u32 = 676;
+pub const OPCUA_CALL_REQUEST: u32 = 712;
+pub const OPCUA_CALL_RESPONSE: u32 = 715;
+pub const OPCUA_CREATE_MONITORED_ITEMS_REQUEST: u32 = 751;
+pub const OPCUA_CREATE_MONITORED_ITEMS_RESPONSE: u32 = 754;
+pub const OPCUA_CREATE_SUBSCRIPTION_REQUEST: u32 = 787;
+pub const OPCUA_CREATE_SUBSCRIPTION_RESPONSE: u32 = 790;
+pub const OPCUA_PUBLISH_REQUEST: u32 = 826;
+pub const OPCUA_PUBLISH_RESPONSE: u32 = 829;
+
+/// `DefaultBinary` encodings of the ExtensionObjects decoded inside
+/// services: user identity tokens and data change notifications.
+pub const OPCUA_ANONYMOUS_IDENTITY_TOKEN: u32 = 321;
+pub const OPCUA_USER_NAME_IDENTITY_TOKEN: u32 = 324;
+pub const OPCUA_X509_IDENTITY_TOKEN: u32 = 327;
+pub const OPCUA_ISSUED_IDENTITY_TOKEN: u32 = 940;
+pub const OPCUA_DATA_CHANGE_NOTIFICATION: u32 = 811;
+
 pub fn validate_tcp_header_length(len: usize) -> Result<(), OpcuaParseError> {
     if len < OPCUA_TCP_HEADER_LEN {
         return Err(OpcuaParseError::PacketTooShort {
@@ -144,6 +200,252 @@ pub fn extract_ua_string(bytes: &[u8]) -> Result<(Option<&str>, usize), OpcuaPar
     Ok((Some(value), end))
 }
 
+/// Reads a byte and returns it with the rest of the buffer.
+pub fn extract_opcua_u8<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(u8, &'a [u8]), OpcuaDecodeError> {
+    match buf {
+        [value, rest @ ..] => Ok((*value, rest)),
+        [] => Err(OpcuaDecodeError::Truncated { field }),
+    }
+}
+
+/// Boolean: any non-zero byte is `true`.
+pub fn extract_opcua_bool<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(bool, &'a [u8]), OpcuaDecodeError> {
+    extract_opcua_u8(buf, field).map(|(value, rest)| (value != 0, rest))
+}
+
+pub fn extract_opcua_u16<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(u16, &'a [u8]), OpcuaDecodeError> {
+    extract_opcua_array::<2>(buf, field).map(|(bytes, rest)| (u16::from_le_bytes(bytes), rest))
+}
+
+pub fn extract_opcua_u32<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(u32, &'a [u8]), OpcuaDecodeError> {
+    extract_opcua_array::<4>(buf, field).map(|(bytes, rest)| (u32::from_le_bytes(bytes), rest))
+}
+
+pub fn extract_opcua_i32<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(i32, &'a [u8]), OpcuaDecodeError> {
+    extract_opcua_array::<4>(buf, field).map(|(bytes, rest)| (i32::from_le_bytes(bytes), rest))
+}
+
+pub fn extract_opcua_u64<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(u64, &'a [u8]), OpcuaDecodeError> {
+    extract_opcua_array::<8>(buf, field).map(|(bytes, rest)| (u64::from_le_bytes(bytes), rest))
+}
+
+pub fn extract_opcua_i64<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(i64, &'a [u8]), OpcuaDecodeError> {
+    extract_opcua_array::<8>(buf, field).map(|(bytes, rest)| (i64::from_le_bytes(bytes), rest))
+}
+
+pub fn extract_opcua_f64<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(f64, &'a [u8]), OpcuaDecodeError> {
+    extract_opcua_array::<8>(buf, field).map(|(bytes, rest)| (f64::from_le_bytes(bytes), rest))
+}
+
+/// Reads `N` bytes into an array.
+pub fn extract_opcua_array<'a, const N: usize>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<([u8; N], &'a [u8]), OpcuaDecodeError> {
+    let (bytes, rest) = extract_opcua_bytes(buf, N, field)?;
+    let mut array = [0u8; N];
+    array.copy_from_slice(bytes);
+    Ok((array, rest))
+}
+
+pub fn extract_opcua_bytes<'a>(
+    buf: &'a [u8],
+    len: usize,
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), OpcuaDecodeError> {
+    if buf.len() < len {
+        return Err(OpcuaDecodeError::Truncated { field });
+    }
+    Ok(buf.split_at(len))
+}
+
+/// Reads a signed 32-bit length prefix (String, ByteString, array) and
+/// returns `None` for `-1`, the null value.
+pub fn extract_opcua_length<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(Option<usize>, &'a [u8]), OpcuaDecodeError> {
+    let (length, rest) = extract_opcua_i32(buf, field)?;
+    match length {
+        -1 => Ok((None, rest)),
+        length if length < -1 => Err(OpcuaDecodeError::InvalidLength { field, length }),
+        length => Ok((Some(length as usize), rest)),
+    }
+}
+
+/// ByteString: length prefix then raw bytes, `None` when null.
+pub fn extract_opcua_byte_string<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(Option<&'a [u8]>, &'a [u8]), OpcuaDecodeError> {
+    let (length, rest) = extract_opcua_length(buf, field)?;
+    match length {
+        None => Ok((None, rest)),
+        Some(length) => {
+            let (bytes, rest) = extract_opcua_bytes(rest, length, field)?;
+            Ok((Some(bytes), rest))
+        }
+    }
+}
+
+/// String: a ByteString that must hold UTF-8.
+pub fn extract_opcua_string<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(Option<&'a str>, &'a [u8]), OpcuaDecodeError> {
+    let (bytes, rest) = extract_opcua_byte_string(buf, field)?;
+    let value = bytes
+        .map(core::str::from_utf8)
+        .transpose()
+        .map_err(|_| OpcuaDecodeError::InvalidUtf8 { field })?;
+    Ok((value, rest))
+}
+
+/// Rejects a value nested deeper than [`OPCUA_MAX_NESTING_DEPTH`].
+pub fn validate_opcua_depth(depth: usize) -> Result<(), OpcuaDecodeError> {
+    if depth > OPCUA_MAX_NESTING_DEPTH {
+        return Err(OpcuaDecodeError::NestingTooDeep);
+    }
+    Ok(())
+}
+
+/// Rejects a reassembled message larger than [`OPCUA_MAX_MESSAGE_SIZE`].
+pub fn validate_opcua_message_size(size: usize) -> Result<(), OpcuaDecodeError> {
+    if size > OPCUA_MAX_MESSAGE_SIZE {
+        return Err(OpcuaDecodeError::MessageTooLarge { size });
+    }
+    Ok(())
+}
+
+/// Service name from the numeric id of its `DefaultBinary` encoding.
+pub fn opcua_service_name(encoding_id: u32) -> &'static str {
+    match encoding_id {
+        OPCUA_SERVICE_FAULT => "ServiceFault",
+        OPCUA_GET_ENDPOINTS_REQUEST => "GetEndpointsRequest",
+        OPCUA_GET_ENDPOINTS_RESPONSE => "GetEndpointsResponse",
+        OPCUA_OPEN_SECURE_CHANNEL_REQUEST => "OpenSecureChannelRequest",
+        OPCUA_OPEN_SECURE_CHANNEL_RESPONSE => "OpenSecureChannelResponse",
+        OPCUA_CLOSE_SECURE_CHANNEL_REQUEST => "CloseSecureChannelRequest",
+        OPCUA_CLOSE_SECURE_CHANNEL_RESPONSE => "CloseSecureChannelResponse",
+        OPCUA_CREATE_SESSION_REQUEST => "CreateSessionRequest",
+        OPCUA_CREATE_SESSION_RESPONSE => "CreateSessionResponse",
+        OPCUA_ACTIVATE_SESSION_REQUEST => "ActivateSessionRequest",
+        OPCUA_ACTIVATE_SESSION_RESPONSE => "ActivateSessionResponse",
+        OPCUA_CLOSE_SESSION_REQUEST => "CloseSessionRequest",
+        OPCUA_CLOSE_SESSION_RESPONSE => "CloseSessionResponse",
+        OPCUA_BROWSE_REQUEST => "BrowseRequest",
+        OPCUA_BROWSE_RESPONSE => "BrowseResponse",
+        OPCUA_READ_REQUEST => "ReadRequest",
+        OPCUA_READ_RESPONSE => "ReadResponse",
+        OPCUA_WRITE_REQUEST => "WriteRequest",
+        OPCUA_WRITE_RESPONSE => "WriteResponse",
+        OPCUA_CALL_REQUEST => "CallRequest",
+        OPCUA_CALL_RESPONSE => "CallResponse",
+        OPCUA_CREATE_MONITORED_ITEMS_REQUEST => "CreateMonitoredItemsRequest",
+        OPCUA_CREATE_MONITORED_ITEMS_RESPONSE => "CreateMonitoredItemsResponse",
+        OPCUA_CREATE_SUBSCRIPTION_REQUEST => "CreateSubscriptionRequest",
+        OPCUA_CREATE_SUBSCRIPTION_RESPONSE => "CreateSubscriptionResponse",
+        OPCUA_PUBLISH_REQUEST => "PublishRequest",
+        OPCUA_PUBLISH_RESPONSE => "PublishResponse",
+        _ => "Unknown",
+    }
+}
+
+/// Symbolic name of the most common StatusCodes (Part 6, Annex A).
+pub fn opcua_status_code_name(status_code: u32) -> &'static str {
+    match status_code {
+        0x0000_0000 => "Good",
+        0x8001_0000 => "BadUnexpectedError",
+        0x8002_0000 => "BadInternalError",
+        0x8005_0000 => "BadCommunicationError",
+        0x8006_0000 => "BadEncodingError",
+        0x8007_0000 => "BadDecodingError",
+        0x800A_0000 => "BadTimeout",
+        0x800B_0000 => "BadServiceUnsupported",
+        0x8010_0000 => "BadTooManyOperations",
+        0x801F_0000 => "BadUserAccessDenied",
+        0x8020_0000 => "BadIdentityTokenInvalid",
+        0x8021_0000 => "BadIdentityTokenRejected",
+        0x8022_0000 => "BadSecureChannelIdInvalid",
+        0x8025_0000 => "BadSessionIdInvalid",
+        0x8026_0000 => "BadSessionClosed",
+        0x8027_0000 => "BadSessionNotActivated",
+        0x8028_0000 => "BadSubscriptionIdInvalid",
+        0x8033_0000 => "BadNodeIdInvalid",
+        0x8034_0000 => "BadNodeIdUnknown",
+        0x8035_0000 => "BadAttributeIdInvalid",
+        0x803A_0000 => "BadNotReadable",
+        0x803B_0000 => "BadNotWritable",
+        0x8074_0000 => "BadTypeMismatch",
+        0x8075_0000 => "BadMethodInvalid",
+        _ => "Unknown",
+    }
+}
+
+/// Node attribute name (Part 6, `AttributeIds`).
+pub fn opcua_attribute_name(attribute_id: u32) -> &'static str {
+    match attribute_id {
+        1 => "NodeId",
+        2 => "NodeClass",
+        3 => "BrowseName",
+        4 => "DisplayName",
+        5 => "Description",
+        6 => "WriteMask",
+        7 => "UserWriteMask",
+        8 => "IsAbstract",
+        9 => "Symmetric",
+        10 => "InverseName",
+        11 => "ContainsNoLoops",
+        12 => "EventNotifier",
+        13 => "Value",
+        14 => "DataType",
+        15 => "ValueRank",
+        16 => "ArrayDimensions",
+        17 => "AccessLevel",
+        18 => "UserAccessLevel",
+        19 => "MinimumSamplingInterval",
+        20 => "Historizing",
+        21 => "Executable",
+        22 => "UserExecutable",
+        _ => "Unknown",
+    }
+}
+
+/// `MessageSecurityMode` enumeration.
+pub fn opcua_security_mode_name(mode: u32) -> &'static str {
+    match mode {
+        0 => "Invalid",
+        1 => "None",
+        2 => "Sign",
+        3 => "SignAndEncrypt",
+        _ => "Unknown",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -372,4 +674,58 @@ mod tests {
         bytes.extend_from_slice(&[0xFF, 0xFE]);
         assert_eq!(extract_ua_string(&bytes), Err(OpcuaParseError::InvalidUtf8));
     }
+
+    #[test]
+    fn test_extract_opcua_string_and_length() {
+        let mut bytes = 3i32.to_le_bytes().to_vec();
+        bytes.extend_from_slice(b"opc");
+        bytes.push(0xAA);
+        assert_eq!(
+            extract_opcua_string(&bytes, "uri"),
+            Ok((Some("opc"), &[0xAA][..]))
+        );
+        assert_eq!(
+            extract_opcua_byte_string(&(-1i32).to_le_bytes(), "nonce"),
+            Ok((None, &[][..]))
+        );
+        assert_eq!(
+            extract_opcua_length(&(-2i32).to_le_bytes(), "array"),
+            Err(OpcuaDecodeError::InvalidLength {
+                field: "array",
+                length: -2
+            })
+        );
+        assert_eq!(
+            extract_opcua_string(&[0x02, 0, 0, 0, 0xFF, 0xFE], "uri"),
+            Err(OpcuaDecodeError::InvalidUtf8 { field: "uri" })
+        );
+        assert_eq!(
+            extract_opcua_u32(&[0x01, 0x02], "handle"),
+            Err(OpcuaDecodeError::Truncated { field: "handle" })
+        );
+    }
+
+    #[test]
+    fn test_opcua_limits() {
+        assert!(validate_opcua_depth(OPCUA_MAX_NESTING_DEPTH).is_ok());
+        assert_eq!(
+            validate_opcua_depth(OPCUA_MAX_NESTING_DEPTH + 1),
+            Err(OpcuaDecodeError::NestingTooDeep)
+        );
+        assert_eq!(
+            validate_opcua_message_size(OPCUA_MAX_MESSAGE_SIZE + 1),
+            Err(OpcuaDecodeError::MessageTooLarge {
+                size: OPCUA_MAX_MESSAGE_SIZE + 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_opcua_names() {
+        assert_eq!(opcua_service_name(631), "ReadRequest");
+        assert_eq!(opcua_service_name(1), "Unknown");
+        assert_eq!(opcua_status_code_name(0x8034_0000), "BadNodeIdUnknown");
+        assert_eq!(opcua_attribute_name(13), "Value");
+        assert_eq!(opcua_security_mode_name(3), "SignAndEncrypt");
+    }
 }
@@ -11,6 +11,96 @@ use crate::{
 /// QUIC v1 version number (RFC 9000).
 pub const QUIC_V1: u32 = 1;
 
+/// QUIC v2 version number (RFC 9369 §3.1).
+pub const QUIC_V2: u32 = 0x6b33_43cf;
+
+/// Sel de derivation des secrets Initial de QUIC v1 (RFC 9001 §5.2).
+pub const QUIC_V1_INITIAL_SALT: [u8; 20] = [
+    0x38, 0x76, 0x2c, 0xf7, 0xf5, 0x59, 0x34, 0xb3, 0x4d, 0x17, 0x9a, 0xe6, 0xa4, 0xc8, 0x0c, 0xad,
+    0xcc, 0xbb, 0x7f, 0x0a,
+];
+
+/// Sel de derivation des secrets Initial de QUIC v2 (RFC 9369 §3.3.1).
+pub const QUIC_V2_INITIAL_SALT: [u8; 20] = [
+    0x0d, 0xed, 0xe3, 0xde, 0xf7, 0x00, 0xa6, 0xdb, 0x81, 0x93, 0x81, 0xbe, 0x6e, 0x26, 0x9d, 0xcb,
+    0xf9, 0xbd, 0x2e, 0xd9,
+];
+
+/// Taille de l'echantillon de protection d'en-tete (RFC 9001 §5.4.2).
+pub const QUIC_HP_SAMPLE_LEN: usize = 16;
+
+/// L'echantillon commence 4 octets apres le debut du Packet Number, quelle
+/// que soit sa longueur reelle (RFC 9001 §5.4.2).
+pub const QUIC_HP_SAMPLE_OFFSET: usize = 4;
+
+/// Plafond du flux CRYPTO reassemble : un ClientHello, meme avec une
+/// key_share post-quantique, tient largement dans 64 Ko.
+pub const QUIC_MAX_CRYPTO_BUFFER: usize = 64 * 1024;
+
+/// Types de frames lus par [`parse_quic_frames`](crate::parse::application::protocols::quic::parse_quic_frames)
+/// (RFC 9000 §19).
+pub const QUIC_FRAME_PADDING: u64 = 0x00;
+pub const QUIC_FRAME_PING: u64 = 0x01;
+pub const QUIC_FRAME_ACK: u64 = 0x02;
+pub const QUIC_FRAME_ACK_ECN: u64 = 0x03;
+pub const QUIC_FRAME_CRYPTO: u64 = 0x06;
+
+/// Frames propres aux paquets 0-RTT et 1-RTT, lues par
+/// [`parse_quic_application_frames`](crate::parse::application::protocols::quic::stream::parse_quic_application_frames)
+/// (RFC 9000 §19, RFC 9221 §4).
+pub const QUIC_FRAME_RESET_STREAM: u64 = 0x04;
+pub const QUIC_FRAME_STOP_SENDING: u64 = 0x05;
+pub const QUIC_FRAME_NEW_TOKEN: u64 = 0x07;
+/// STREAM : types 0x08 a 0x0f, les trois bits de poids faible portant
+/// [`QUIC_STREAM_FLAG_OFF`], [`QUIC_STREAM_FLAG_LEN`] et
+/// [`QUIC_STREAM_FLAG_FIN`].
+pub const QUIC_FRAME_STREAM: u64 = 0x08;
+pub const QUIC_FRAME_STREAM_MAX: u64 = 0x0f;
+pub const QUIC_FRAME_MAX_DATA: u64 = 0x10;
+pub const QUIC_FRAME_MAX_STREAM_DATA: u64 = 0x11;
+pub const QUIC_FRAME_MAX_STREAMS_BIDI: u64 = 0x12;
+pub const QUIC_FRAME_MAX_STREAMS_UNI: u64 = 0x13;
+pub const QUIC_FRAME_DATA_BLOCKED: u64 = 0x14;
+pub const QUIC_FRAME_STREAM_DATA_BLOCKED: u64 = 0x15;
+pub const QUIC_FRAME_STREAMS_BLOCKED_BIDI: u64 = 0x16;
+pub const QUIC_FRAME_STREAMS_BLOCKED_UNI: u64 = 0x17;
+pub const QUIC_FRAME_NEW_CONNECTION_ID: u64 = 0x18;
+pub const QUIC_FRAME_RETIRE_CONNECTION_ID: u64 = 0x19;
+pub const QUIC_FRAME_PATH_CHALLENGE: u64 = 0x1a;
+pub const QUIC_FRAME_PATH_RESPONSE: u64 = 0x1b;
+pub const QUIC_FRAME_CONNECTION_CLOSE: u64 = 0x1c;
+pub const QUIC_FRAME_CONNECTION_CLOSE_APP: u64 = 0x1d;
+pub const QUIC_FRAME_HANDSHAKE_DONE: u64 = 0x1e;
+pub const QUIC_FRAME_DATAGRAM: u64 = 0x30;
+pub const QUIC_FRAME_DATAGRAM_LEN: u64 = 0x31;
+
+pub const QUIC_STREAM_FLAG_OFF: u64 = 0x04;
+pub const QUIC_STREAM_FLAG_LEN: u64 = 0x02;
+pub const QUIC_STREAM_FLAG_FIN: u64 = 0x01;
+
+/// Bits de poids faible d'un Stream ID (RFC 9000 §2.1) : initiateur
+/// (0 client, 1 serveur) et sens (0 bidirectionnel, 1 unidirectionnel).
+pub const QUIC_STREAM_ID_SERVER_INITIATED: u64 = 0x01;
+pub const QUIC_STREAM_ID_UNIDIRECTIONAL: u64 = 0x02;
+
+/// Offsets de flux et tailles finales sont bornes a 2^62 - 1 (RFC 9000
+/// §19.8), la plus grande valeur d'un varint.
+pub const QUIC_MAX_STREAM_OFFSET: u64 = (1 << 62) - 1;
+
+/// Plafond des octets hors ordre conserves par flux : la remise est
+/// immediate des que les donnees sont contigues, seuls les trous
+/// retiennent des donnees.
+pub const QUIC_MAX_STREAM_BUFFER: usize = 256 * 1024;
+
+/// Short Header (RFC 9000 §17.3.1) : Spin bit, puis Key Phase, proteges
+/// avec les bits reserves et la longueur du Packet Number.
+pub const QUIC_SHORT_HEADER_SPIN_BIT: u8 = 0x20;
+pub const QUIC_SHORT_HEADER_KEY_PHASE: u8 = 0x04;
+/// Bits masques par la protection d'en-tete : 4 pour un Long Header, 5
+/// pour un Short Header (RFC 9001 §5.4.1).
+pub const QUIC_LONG_HEADER_PROTECTED_BITS: u8 = 0x0f;
+pub const QUIC_SHORT_HEADER_PROTECTED_BITS: u8 = 0x1f;
+
 /// Taille du Retry Integrity Tag (RFC 9001 §5.8) : tag AEAD de 128 bits
 /// place a la fin de tout paquet Retry.
 pub const QUIC_RETRY_INTEGRITY_TAG_LEN: usize = 16;
@@ -148,15 +238,15 @@ pub fn extract_first_byte(b0: u8) -> Result<(QuicPacketType, u8), QuicError> {
     Ok((packet_type, pn_length))
 }
 
-/// Only QUIC v1 is accepted.
+/// Only QUIC v1 and v2 are accepted.
 pub fn validate_version(version: u32) -> Result<(), QuicError> {
-    if version != QUIC_V1 {
+    if version != QUIC_V1 && version != QUIC_V2 {
         return Err(QuicError::UnsupportedVersion(version));
     }
     Ok(())
 }
 
-/// Reads the 4-byte Version field and checks that it is QUIC v1.
+/// Reads the 4-byte Version field and checks that it is QUIC v1 or v2.
 ///
 /// Error order is preserved from the historical inline extraction:
 /// `Truncated` if fewer than 4 bytes remain, then `UnsupportedVersion`.
@@ -167,6 +257,23 @@ pub fn extract_version(cur: &mut QuicCursor<'_>) -> Result<u32, QuicError> {
     Ok(version)
 }
 
+/// Type de paquet selon la version : QUIC v2 permute les codes des Long
+/// Headers (RFC 9369 §3.2), `extract_first_byte` lisant ceux de v1.
+pub fn extract_packet_type(b0: u8, version: u32) -> QuicPacketType {
+    let lptype = (b0 >> 4) & 0b11;
+    match (version, lptype) {
+        (QUIC_V2, 0) => QuicPacketType::Retry,
+        (QUIC_V2, 1) => QuicPacketType::Initial,
+        (QUIC_V2, 2) => QuicPacketType::ZeroRtt,
+        (QUIC_V2, 3) => QuicPacketType::Handshake,
+        (_, 0) => QuicPacketType::Initial,
+        (_, 1) => QuicPacketType::ZeroRtt,
+        (_, 2) => QuicPacketType::Handshake,
+        (_, 3) => QuicPacketType::Retry,
+        (_, x) => QuicPacketType::Unknown(x),
+    }
+}
+
 /// The announced payload length must fit in the remaining bytes.
 pub fn validate_payload_available(available: usize, expected: usize) -> Result<(), QuicError> {
     if available < expected {
@@ -255,10 +362,54 @@ pub(crate) fn read_pn_and_payload<'a>(
     Ok(payload)
 }
 
+/// Reconstruit un Packet Number complet a partir de sa forme tronquee
+/// (RFC 9000 §A.3) : la valeur la plus proche du numero attendu, soit le
+/// plus grand numero deja authentifie plus un. Sans numero precedent, la
+/// valeur tronquee est prise telle quelle.
+pub fn decode_packet_number(largest_pn: Option<u64>, truncated_pn: u64, pn_length: u8) -> u64 {
+    let Some(largest_pn) = largest_pn else {
+        return truncated_pn;
+    };
+    let expected = largest_pn.saturating_add(1);
+    let window = 1u64 << (u32::from(pn_length.clamp(1, 4)) * 8);
+    let half_window = window / 2;
+    let candidate = (expected & !(window - 1)) | truncated_pn;
+
+    if candidate.saturating_add(half_window) <= expected
+        && candidate < (1u64 << 62).saturating_sub(window)
+    {
+        candidate + window
+    } else if candidate > expected.saturating_add(half_window) && candidate >= window {
+        candidate - window
+    } else {
+        candidate
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    // RFC 9000 §A.3 : exemple du texte, puis bords de fenetre.
+    #[test]
+    fn decodes_the_rfc_9000_packet_number_example() {
+        assert_eq!(
+            decode_packet_number(Some(0xa82f_30ea), 0x9b32, 2),
+            0xa82f_9b32
+        );
+    }
+
+    #[test]
+    fn packet_number_wraps_to_the_closest_value() {
+        // Attendu 0x100 : 0xff est plus proche que 0x1ff.
+        assert_eq!(decode_packet_number(Some(0xff), 0xff, 1), 0xff);
+        // Attendu 0x1fe : 0x01 tronque designe 0x201.
+        assert_eq!(decode_packet_number(Some(0x1fd), 0x01, 1), 0x201);
+        // Attendu 0x105 : 0xf0 tronque designe 0xf0, pas 0x1f0.
+        assert_eq!(decode_packet_number(Some(0x104), 0xf0, 1), 0xf0);
+        assert_eq!(decode_packet_number(None, 0x1234, 2), 0x1234);
+    }
+
     #[test]
     fn test_cursor_take_within_bounds() {
         let data = [1u8, 2, 3, 4];
@@ -405,6 +556,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_validate_version_accepts_v2() {
+        assert_eq!(validate_version(QUIC_V2), Ok(()));
+    }
+
+    #[test]
+    fn test_extract_packet_type_by_version() {
+        // 0xD0 : Long Packet Type 01, 0-RTT en v1 mais Initial en v2.
+        assert_eq!(extract_packet_type(0xD0, QUIC_V1), QuicPacketType::ZeroRtt);
+        assert_eq!(extract_packet_type(0xD0, QUIC_V2), QuicPacketType::Initial);
+        assert_eq!(extract_packet_type(0xC0, QUIC_V2), QuicPacketType::Retry);
+        assert_eq!(extract_packet_type(0xE0, QUIC_V2), QuicPacketType::ZeroRtt);
+        assert_eq!(
+            extract_packet_type(0xF0, QUIC_V2),
+            QuicPacketType::Handshake
+        );
+        assert_eq!(extract_packet_type(0xC0, QUIC_V1), QuicPacketType::Initial);
+    }
+
     #[test]
     fn test_validate_payload_available() {
         assert_eq!(validate_payload_available(10, 10), Ok(()));
@@ -0,0 +1,75 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Controles de taille et de coherence pour RIPv1/v2 (RFC 1058, RFC 2453)
+//! et RIPng (RFC 2080).
+//!
+//! Les deux familles partagent la meme forme : un en-tete de 4 octets puis
+//! des entrees de 20 octets. Un datagramme dont la taille n'est pas un
+//! multiple exact, ou dont une metrique depasse l'infini (16), n'est pas du
+//! RIP : c'est ce qui rend la sonde sure malgre un en-tete tres court.
+
+use crate::errors::application::rip::RipError;
+
+/// Commande, version et deux octets nuls (domaine de routage en RFC 1388).
+pub const RIP_HEADER_LENGTH: usize = 4;
+
+/// Entree de route, d'authentification (RIPv2) ou RTE RIPng.
+pub const RIP_ENTRY_LENGTH: usize = 20;
+
+/// Metrique « infinie » : route injoignable.
+pub const RIP_INFINITY_METRIC: u32 = 16;
+
+/// Verifie l'en-tete (commande 1 ou 2, version dans `versions`) et la
+/// taille des entrees, et retourne les octets des entrees.
+pub fn extract_rip_entries<'a>(payload: &'a [u8], versions: &[u8]) -> Result<&'a [u8], RipError> {
+    if payload.len() < RIP_HEADER_LENGTH {
+        return Err(RipError::InvalidLength {
+            expected: RIP_HEADER_LENGTH,
+            actual: payload.len(),
+        });
+    }
+    if !matches!(payload[0], 1 | 2) {
+        return Err(RipError::UnknownCommand(payload[0]));
+    }
+    if !versions.contains(&payload[1]) {
+        return Err(RipError::UnsupportedVersion(payload[1]));
+    }
+    let entries = &payload[RIP_HEADER_LENGTH..];
+    if !entries.len().is_multiple_of(RIP_ENTRY_LENGTH) {
+        return Err(RipError::InvalidEntriesLength(entries.len()));
+    }
+    Ok(entries)
+}
+
+/// Une metrique RIP va de 1 a 16 ; 0 n'apparait que dans une requete.
+pub fn validate_rip_metric(index: usize, metric: u32) -> Result<(), RipError> {
+    if metric > RIP_INFINITY_METRIC {
+        return Err(RipError::InvalidMetric { index, metric });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn entries_must_be_whole_and_header_known() {
+        assert_eq!(extract_rip_entries(&[2, 2, 0, 0], &[1, 2]), Ok(&[][..]));
+        assert_eq!(
+            extract_rip_entries(&[3, 2, 0, 0], &[1, 2]),
+            Err(RipError::UnknownCommand(3))
+        );
+        assert_eq!(
+            extract_rip_entries(&[2, 3, 0, 0], &[1, 2]),
+            Err(RipError::UnsupportedVersion(3))
+        );
+        assert_eq!(
+            extract_rip_entries(&[2, 2, 0, 0, 0], &[1, 2]),
+            Err(RipError::InvalidEntriesLength(1))
+        );
+    }
+}
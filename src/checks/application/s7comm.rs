@@ -5,8 +5,11 @@
 
 use crate::{
     errors::application::s7comm::S7CommParseError,
-    parse::application::protocols::s7comm::{
-        CotpHeader, S7Header, S7ParameterItem, S7ProtocolVersion, TpktHeader,
+    parse::application::protocols::{
+        bounded_capacity,
+        s7comm::{
+            CotpHeader, S7Header, S7ParameterItem, S7ProtocolVersion, TpktHeader, var::S7DataItem,
+        },
     },
 };
 
@@ -479,6 +482,347 @@ pub fn extract_parameter_item(
     }
 }
 
+/// ROSCTR values of the S7 header.
+pub const S7_ROSCTR_JOB: u8 = 0x01;
+pub const S7_ROSCTR_ACK: u8 = 0x02;
+pub const S7_ROSCTR_ACK_DATA: u8 = 0x03;
+pub const S7_ROSCTR_USERDATA: u8 = 0x07;
+
+/// Job and ACK-Data function codes (first parameter byte).
+pub const S7_FUNCTION_READ_VAR: u8 = 0x04;
+pub const S7_FUNCTION_WRITE_VAR: u8 = 0x05;
+pub const S7_FUNCTION_REQUEST_DOWNLOAD: u8 = 0x1a;
+pub const S7_FUNCTION_DOWNLOAD_BLOCK: u8 = 0x1b;
+pub const S7_FUNCTION_DOWNLOAD_ENDED: u8 = 0x1c;
+pub const S7_FUNCTION_START_UPLOAD: u8 = 0x1d;
+pub const S7_FUNCTION_UPLOAD: u8 = 0x1e;
+pub const S7_FUNCTION_END_UPLOAD: u8 = 0x1f;
+pub const S7_FUNCTION_PLC_CONTROL: u8 = 0x28;
+pub const S7_FUNCTION_PLC_STOP: u8 = 0x29;
+pub const S7_FUNCTION_SETUP_COMMUNICATION: u8 = 0xf0;
+
+/// Return code of a successful data item.
+pub const S7_RETURN_CODE_SUCCESS: u8 = 0xff;
+
+/// Data-item transport sizes whose length field counts bits, not bytes.
+pub const S7_DATA_TRANSPORT_SIZE_BIT: u8 = 0x03;
+pub const S7_DATA_TRANSPORT_SIZE_BYTE: u8 = 0x04;
+pub const S7_DATA_TRANSPORT_SIZE_INTEGER: u8 = 0x05;
+
+/// Bit 0 of the block transfer function status: more data follows.
+pub const S7_FUNCTION_STATUS_MORE_DATA: u8 = 0x01;
+
+/// Unanswered Read/Write Var JOBs kept by the access tracker.
+pub const S7_MAX_PENDING_VAR_JOBS: usize = 64;
+
+/// Fixed head of every Userdata parameter section.
+pub const S7_USERDATA_HEAD: [u8; 3] = [0x00, 0x01, 0x12];
+
+/// Userdata type (high nibble of the type/group byte).
+pub const S7_USERDATA_TYPE_PUSH: u8 = 0x0;
+pub const S7_USERDATA_TYPE_REQUEST: u8 = 0x4;
+pub const S7_USERDATA_TYPE_RESPONSE: u8 = 0x8;
+
+/// Userdata function group (low nibble of the type/group byte) and the
+/// CPU-functions subfunction that reads a system status list.
+pub const S7_USERDATA_GROUP_CPU: u8 = 0x4;
+pub const S7_USERDATA_SUBFUNCTION_READ_SZL: u8 = 0x01;
+
+/// Record lengths of the module (`xy11`) and component (`xy1C`)
+/// identification SZLs.
+pub const S7_SZL_MODULE_IDENTIFICATION_LENGTH: usize = 28;
+pub const S7_SZL_COMPONENT_IDENTIFICATION_LENGTH: usize = 34;
+
+/// Splits `length` bytes off the front of `data`.
+pub fn extract_s7_bytes<'a>(
+    data: &'a [u8],
+    length: usize,
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), S7CommParseError> {
+    if data.len() < length {
+        return Err(S7CommParseError::TruncatedField { field });
+    }
+
+    Ok(data.split_at(length))
+}
+
+pub fn extract_s7_u8<'a>(
+    data: &'a [u8],
+    field: &'static str,
+) -> Result<(u8, &'a [u8]), S7CommParseError> {
+    let (bytes, rest) = extract_s7_bytes(data, 1, field)?;
+    Ok((bytes[0], rest))
+}
+
+pub fn extract_s7_u16<'a>(
+    data: &'a [u8],
+    field: &'static str,
+) -> Result<(u16, &'a [u8]), S7CommParseError> {
+    let (bytes, rest) = extract_s7_bytes(data, 2, field)?;
+    Ok((u16::from_be_bytes([bytes[0], bytes[1]]), rest))
+}
+
+pub fn extract_s7_u32<'a>(
+    data: &'a [u8],
+    field: &'static str,
+) -> Result<(u32, &'a [u8]), S7CommParseError> {
+    let (bytes, rest) = extract_s7_bytes(data, 4, field)?;
+    Ok((
+        u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+        rest,
+    ))
+}
+
+/// Reads an ASCII string of `length` bytes.
+pub fn extract_s7_ascii<'a>(
+    data: &'a [u8],
+    length: usize,
+    field: &'static str,
+) -> Result<(&'a str, &'a [u8]), S7CommParseError> {
+    let (bytes, rest) = extract_s7_bytes(data, length, field)?;
+    if !bytes.is_ascii() {
+        return Err(S7CommParseError::InvalidAscii { field });
+    }
+    let text = std::str::from_utf8(bytes).map_err(|_| S7CommParseError::InvalidAscii { field })?;
+    Ok((text, rest))
+}
+
+/// Reads a string prefixed by its one-byte length (file names, PI services).
+pub fn extract_s7_prefixed_ascii<'a>(
+    data: &'a [u8],
+    field: &'static str,
+) -> Result<(&'a str, &'a [u8]), S7CommParseError> {
+    let (length, rest) = extract_s7_u8(data, field)?;
+    extract_s7_ascii(rest, usize::from(length), field)
+}
+
+pub fn validate_s7_parameter_consumed(rest: &[u8]) -> Result<(), S7CommParseError> {
+    validate_parameter_items_consumed(0, rest.len())
+}
+
+pub fn validate_s7_data_consumed(rest: &[u8]) -> Result<(), S7CommParseError> {
+    if !rest.is_empty() {
+        return Err(S7CommParseError::UnexpectedDataBytes {
+            remaining: rest.len(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Byte length of a data item value. BIT, BYTE/WORD/DWORD and INTEGER
+/// items count their length in bits, rounded up to whole bytes; the other
+/// transport sizes already count bytes.
+pub fn s7_data_item_byte_length(transport_size: u8, length: u16) -> usize {
+    let length = usize::from(length);
+    match transport_size {
+        S7_DATA_TRANSPORT_SIZE_BIT
+        | S7_DATA_TRANSPORT_SIZE_BYTE
+        | S7_DATA_TRANSPORT_SIZE_INTEGER => length.div_ceil(8),
+        _ => length,
+    }
+}
+
+/// Data item layout (Read Var ACK-Data results and Write Var JOB values):
+/// `+0` return code, `+1` transport size, `+2..+3` length, then the value.
+/// An odd-sized value is followed by a fill byte unless it is the last item.
+pub fn extract_s7_data_items(
+    data: &[u8],
+    count: usize,
+) -> Result<Vec<S7DataItem<'_>>, S7CommParseError> {
+    let mut items = Vec::with_capacity(bounded_capacity(count, data.len(), 4));
+    let mut rest = data;
+
+    for index in 0..count {
+        let [return_code, transport_size, high, low, tail @ ..] = rest else {
+            return Err(S7CommParseError::TruncatedDataItem { index });
+        };
+        let length = u16::from_be_bytes([*high, *low]);
+        let value_length = s7_data_item_byte_length(*transport_size, length);
+        if tail.len() < value_length {
+            return Err(S7CommParseError::TruncatedDataItem { index });
+        }
+        let (value, tail) = tail.split_at(value_length);
+        rest = tail;
+
+        if !value_length.is_multiple_of(2) && index + 1 < count {
+            let Some((_, tail)) = rest.split_first() else {
+                return Err(S7CommParseError::TruncatedDataItem { index });
+            };
+            rest = tail;
+        }
+
+        items.push(S7DataItem {
+            return_code: *return_code,
+            transport_size: *transport_size,
+            length,
+            data: value,
+        });
+    }
+
+    validate_s7_data_consumed(rest)?;
+
+    Ok(items)
+}
+
+pub fn validate_s7_userdata_head(head: [u8; 3]) -> Result<(), S7CommParseError> {
+    if head != S7_USERDATA_HEAD {
+        return Err(S7CommParseError::InvalidUserdataHead { head });
+    }
+
+    Ok(())
+}
+
+pub fn validate_s7_userdata_parameter_length(length: u8) -> Result<(), S7CommParseError> {
+    if !matches!(length, 4 | 8) {
+        return Err(S7CommParseError::InvalidUserdataParameterLength { length });
+    }
+
+    Ok(())
+}
+
+/// Parses the fixed-width ASCII decimal fields of block transfers
+/// (`"000500"`).
+pub fn parse_s7_ascii_decimal(digits: &[u8]) -> Option<u32> {
+    if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+        return None;
+    }
+    std::str::from_utf8(digits).ok()?.parse().ok()
+}
+
+/// Parses a two-character ASCII block type (`"0A"`) into its code.
+pub fn parse_s7_block_type(pair: &[u8]) -> Option<u8> {
+    let text = std::str::from_utf8(pair).ok()?;
+    if text.len() != 2 {
+        return None;
+    }
+    u8::from_str_radix(text, 16).ok()
+}
+
+/// Short memory-area name, as Wireshark displays it.
+pub fn s7_area_name(area: u8) -> &'static str {
+    match area {
+        0x03 => "SYSINFO",
+        0x05 => "SYSFLAGS",
+        0x06 => "ANAIN",
+        0x07 => "ANAOUT",
+        0x1c => "C",
+        0x1d => "T",
+        0x1e => "IEC C",
+        0x1f => "IEC T",
+        0x80 => "P",
+        0x81 => "I",
+        0x82 => "Q",
+        0x83 => "M",
+        0x84 => "DB",
+        0x85 => "DI",
+        0x86 => "L",
+        0x87 => "V",
+        _ => "Unknown",
+    }
+}
+
+/// Name of an S7ANY transport size (parameter item).
+pub fn s7_transport_size_name(transport_size: u8) -> &'static str {
+    match transport_size {
+        0x01 => "BIT",
+        0x02 => "BYTE",
+        0x03 => "CHAR",
+        0x04 => "WORD",
+        0x05 => "INT",
+        0x06 => "DWORD",
+        0x07 => "DINT",
+        0x08 => "REAL",
+        0x09 => "DATE",
+        0x0a => "TOD",
+        0x0b => "TIME",
+        0x0c => "S5TIME",
+        0x0f => "DATE_AND_TIME",
+        0x1c => "COUNTER",
+        0x1d => "TIMER",
+        0x1e => "IEC TIMER",
+        0x1f => "IEC COUNTER",
+        0x20 => "HS COUNTER",
+        _ => "Unknown",
+    }
+}
+
+/// Name of a Job/ACK-Data function code.
+pub fn s7_function_name(function: u8) -> &'static str {
+    match function {
+        0x00 => "CPU services",
+        S7_FUNCTION_READ_VAR => "Read Var",
+        S7_FUNCTION_WRITE_VAR => "Write Var",
+        S7_FUNCTION_REQUEST_DOWNLOAD => "Request download",
+        S7_FUNCTION_DOWNLOAD_BLOCK => "Download block",
+        S7_FUNCTION_DOWNLOAD_ENDED => "Download ended",
+        S7_FUNCTION_START_UPLOAD => "Start upload",
+        S7_FUNCTION_UPLOAD => "Upload",
+        S7_FUNCTION_END_UPLOAD => "End upload",
+        S7_FUNCTION_PLC_CONTROL => "PLC Control",
+        S7_FUNCTION_PLC_STOP => "PLC Stop",
+        S7_FUNCTION_SETUP_COMMUNICATION => "Setup communication",
+        _ => "Unknown",
+    }
+}
+
+/// Name of a data item return code.
+pub fn s7_return_code_name(code: u8) -> &'static str {
+    match code {
+        0x00 => "Reserved",
+        0x01 => "Hardware error",
+        0x03 => "Accessing the object not allowed",
+        0x05 => "Invalid address",
+        0x06 => "Data type not supported",
+        0x07 => "Data type inconsistent",
+        0x0a => "Object does not exist",
+        S7_RETURN_CODE_SUCCESS => "Success",
+        _ => "Unknown",
+    }
+}
+
+/// Name of a block type code (`0x0A` is a DB).
+pub fn s7_block_type_name(block_type: u8) -> &'static str {
+    match block_type {
+        0x08 => "OB",
+        0x0a => "DB",
+        0x0b => "SDB",
+        0x0c => "FC",
+        0x0d => "SFC",
+        0x0e => "FB",
+        0x0f => "SFB",
+        _ => "Unknown",
+    }
+}
+
+/// Name of a Userdata function group.
+pub fn s7_userdata_group_name(group: u8) -> &'static str {
+    match group {
+        0x0 => "Mode-transition",
+        0x1 => "Programmer commands",
+        0x2 => "Cyclic data",
+        0x3 => "Block functions",
+        S7_USERDATA_GROUP_CPU => "CPU functions",
+        0x5 => "Security",
+        0x6 => "PBC BSEND/BRECV",
+        0x7 => "Time functions",
+        0xf => "NC programming",
+        _ => "Unknown",
+    }
+}
+
+/// Description of a PI service started by PLC Control or PLC Stop.
+pub fn s7_pi_service_name(service: &str) -> &'static str {
+    match service {
+        "_INSE" => "Activate downloaded block",
+        "_DELE" => "Delete block",
+        "P_PROGRAM" => "Start/stop program",
+        "_GARB" => "Compress PLC memory",
+        "_MODU" => "Copy RAM to ROM",
+        _ => "Unknown",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1025,4 +1369,75 @@ mod tests {
             S7CommParseError::InvalidS7AnyLength { length: 11 }
         );
     }
+
+    #[test]
+    fn test_s7_data_item_byte_length_units() {
+        // BIT, BYTE/WORD/DWORD et INTEGER comptent en bits, arrondis.
+        assert_eq!(s7_data_item_byte_length(S7_DATA_TRANSPORT_SIZE_BIT, 1), 1);
+        assert_eq!(
+            s7_data_item_byte_length(S7_DATA_TRANSPORT_SIZE_BYTE, 0x0200),
+            64
+        );
+        assert_eq!(
+            s7_data_item_byte_length(S7_DATA_TRANSPORT_SIZE_INTEGER, 12),
+            2
+        );
+        // REAL et octet string comptent deja en octets.
+        assert_eq!(s7_data_item_byte_length(0x07, 4), 4);
+        assert_eq!(s7_data_item_byte_length(0x09, 5), 5);
+    }
+
+    #[test]
+    fn test_extract_s7_data_items_rules() {
+        // Deux bits : le premier est suivi de son octet de remplissage, pas
+        // le dernier.
+        let data = [0xff, 0x03, 0x00, 0x01, 0x01, 0x00, 0x0a, 0x00, 0x00, 0x00];
+        let items = extract_s7_data_items(&data, 2).unwrap();
+        assert_eq!(items[0].data, [0x01]);
+        assert_eq!(items[1].return_code, 0x0a);
+        assert!(items[1].data.is_empty());
+
+        assert_eq!(
+            extract_s7_data_items(&data[..5], 2).unwrap_err(),
+            S7CommParseError::TruncatedDataItem { index: 0 }
+        );
+        assert_eq!(
+            extract_s7_data_items(&data, 1).unwrap_err(),
+            S7CommParseError::UnexpectedDataBytes { remaining: 5 }
+        );
+    }
+
+    #[test]
+    fn test_s7_ascii_and_block_helpers() {
+        assert_eq!(parse_s7_ascii_decimal(b"000500"), Some(500));
+        assert_eq!(parse_s7_ascii_decimal(b"00 500"), None);
+        assert_eq!(parse_s7_ascii_decimal(b""), None);
+        assert_eq!(parse_s7_block_type(b"0A"), Some(0x0a));
+        assert_eq!(parse_s7_block_type(b"0G"), None);
+        assert_eq!(
+            extract_s7_prefixed_ascii(&[0x02, b'O', b'K', 0x00], "PI service").unwrap(),
+            ("OK", &[0x00][..])
+        );
+        assert_eq!(
+            extract_s7_prefixed_ascii(&[0x01, 0xc3], "PI service").unwrap_err(),
+            S7CommParseError::InvalidAscii {
+                field: "PI service"
+            }
+        );
+    }
+
+    #[test]
+    fn test_s7_names() {
+        assert_eq!(s7_area_name(0x84), "DB");
+        assert_eq!(s7_transport_size_name(0x02), "BYTE");
+        assert_eq!(s7_function_name(S7_FUNCTION_PLC_STOP), "PLC Stop");
+        assert_eq!(s7_return_code_name(0x05), "Invalid address");
+        assert_eq!(s7_block_type_name(0x0e), "FB");
+        assert_eq!(
+            s7_userdata_group_name(S7_USERDATA_GROUP_CPU),
+            "CPU functions"
+        );
+        assert_eq!(s7_pi_service_name("_INSE"), "Activate downloaded block");
+        assert_eq!(s7_pi_service_name("_XYZ"), "Unknown");
+    }
 }
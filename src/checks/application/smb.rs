@@ -0,0 +1,530 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Controles de taille et de coherence pour SMB1 (MS-CIFS) et SMB2/SMB3
+//! (MS-SMB2).
+//!
+//! Un message SMB2 commence par un en-tete fixe de 64 octets, suivi d'un
+//! corps dont les deux premiers octets annoncent la taille de la partie
+//! fixe (`StructureSize`, impaire quand un tampon variable suit). Les
+//! tampons variables sont references par un offset compte depuis le debut
+//! de l'en-tete SMB2, jamais depuis le corps.
+//!
+//! Les blobs de securite de NEGOTIATE et SESSION_SETUP sont des jetons
+//! GSS-API : SPNEGO (RFC 4178) enveloppant NTLMSSP ou Kerberos. Seul le
+//! sous-ensemble DER necessaire a leur identification est lu ici.
+
+use crate::{
+    checks::application::ber::{BER_HIGH_TAG, extract_ber_element},
+    errors::application::smb::SmbError,
+};
+
+pub const SMB1_PROTOCOL_ID: [u8; 4] = *b"\xffSMB";
+pub const SMB2_PROTOCOL_ID: [u8; 4] = *b"\xfeSMB";
+/// En-tete de chiffrement SMB3 (TRANSFORM_HEADER).
+pub const SMB2_TRANSFORM_PROTOCOL_ID: [u8; 4] = *b"\xfdSMB";
+/// En-tete de compression SMB 3.1.1 (COMPRESSION_TRANSFORM_HEADER).
+pub const SMB2_COMPRESSION_PROTOCOL_ID: [u8; 4] = *b"\xfcSMB";
+
+pub const SMB1_HEADER_LEN: usize = 32;
+pub const SMB2_HEADER_LEN: usize = 64;
+pub const SMB2_TRANSFORM_HEADER_LEN: usize = 52;
+pub const SMB2_COMPRESSION_HEADER_LEN: usize = 16;
+
+pub const SMB1_COM_NEGOTIATE: u8 = 0x72;
+pub const SMB1_COM_SESSION_SETUP_ANDX: u8 = 0x73;
+pub const SMB1_COM_TREE_CONNECT_ANDX: u8 = 0x75;
+/// Bit FLAGS du serveur vers le client.
+pub const SMB1_FLAGS_REPLY: u8 = 0x80;
+/// Prefixe de chaque dialecte d'un NEGOTIATE SMB1.
+pub const SMB1_DIALECT_BUFFER_FORMAT: u8 = 0x02;
+
+pub const SMB2_NEGOTIATE: u16 = 0x0000;
+pub const SMB2_SESSION_SETUP: u16 = 0x0001;
+pub const SMB2_LOGOFF: u16 = 0x0002;
+pub const SMB2_TREE_CONNECT: u16 = 0x0003;
+pub const SMB2_TREE_DISCONNECT: u16 = 0x0004;
+pub const SMB2_CREATE: u16 = 0x0005;
+pub const SMB2_CLOSE: u16 = 0x0006;
+pub const SMB2_FLUSH: u16 = 0x0007;
+pub const SMB2_READ: u16 = 0x0008;
+pub const SMB2_WRITE: u16 = 0x0009;
+pub const SMB2_LOCK: u16 = 0x000A;
+pub const SMB2_IOCTL: u16 = 0x000B;
+pub const SMB2_CANCEL: u16 = 0x000C;
+pub const SMB2_ECHO: u16 = 0x000D;
+pub const SMB2_QUERY_DIRECTORY: u16 = 0x000E;
+pub const SMB2_CHANGE_NOTIFY: u16 = 0x000F;
+pub const SMB2_QUERY_INFO: u16 = 0x0010;
+pub const SMB2_SET_INFO: u16 = 0x0011;
+pub const SMB2_OPLOCK_BREAK: u16 = 0x0012;
+
+pub const SMB2_FLAGS_SERVER_TO_REDIR: u32 = 0x0000_0001;
+pub const SMB2_FLAGS_ASYNC_COMMAND: u32 = 0x0000_0002;
+pub const SMB2_FLAGS_RELATED_OPERATIONS: u32 = 0x0000_0004;
+pub const SMB2_FLAGS_SIGNED: u32 = 0x0000_0008;
+
+pub const SMB2_DIALECT_202: u16 = 0x0202;
+pub const SMB2_DIALECT_210: u16 = 0x0210;
+pub const SMB2_DIALECT_300: u16 = 0x0300;
+pub const SMB2_DIALECT_302: u16 = 0x0302;
+pub const SMB2_DIALECT_311: u16 = 0x0311;
+/// Reponse d'un serveur SMB2 a un NEGOTIATE SMB1 multi-protocole.
+pub const SMB2_DIALECT_WILDCARD: u16 = 0x02FF;
+
+pub const SMB2_GLOBAL_CAP_DFS: u32 = 0x0000_0001;
+pub const SMB2_GLOBAL_CAP_LEASING: u32 = 0x0000_0002;
+pub const SMB2_GLOBAL_CAP_LARGE_MTU: u32 = 0x0000_0004;
+pub const SMB2_GLOBAL_CAP_MULTI_CHANNEL: u32 = 0x0000_0008;
+pub const SMB2_GLOBAL_CAP_PERSISTENT_HANDLES: u32 = 0x0000_0010;
+pub const SMB2_GLOBAL_CAP_DIRECTORY_LEASING: u32 = 0x0000_0020;
+pub const SMB2_GLOBAL_CAP_ENCRYPTION: u32 = 0x0000_0040;
+pub const SMB2_GLOBAL_CAP_NOTIFICATIONS: u32 = 0x0000_0080;
+
+/// SecurityMode de NEGOTIATE et SESSION_SETUP.
+pub const SMB2_NEGOTIATE_SIGNING_ENABLED: u16 = 0x0001;
+pub const SMB2_NEGOTIATE_SIGNING_REQUIRED: u16 = 0x0002;
+
+/// SessionFlags de la reponse SESSION_SETUP.
+pub const SMB2_SESSION_FLAG_IS_GUEST: u16 = 0x0001;
+pub const SMB2_SESSION_FLAG_IS_NULL: u16 = 0x0002;
+pub const SMB2_SESSION_FLAG_ENCRYPT_DATA: u16 = 0x0004;
+
+/// Contextes de negociation SMB 3.1.1.
+pub const SMB2_PREAUTH_INTEGRITY_CAPABILITIES: u16 = 0x0001;
+pub const SMB2_ENCRYPTION_CAPABILITIES: u16 = 0x0002;
+pub const SMB2_COMPRESSION_CAPABILITIES: u16 = 0x0003;
+pub const SMB2_NETNAME_NEGOTIATE_CONTEXT_ID: u16 = 0x0005;
+pub const SMB2_TRANSPORT_CAPABILITIES: u16 = 0x0006;
+pub const SMB2_RDMA_TRANSFORM_CAPABILITIES: u16 = 0x0007;
+pub const SMB2_SIGNING_CAPABILITIES: u16 = 0x0008;
+/// En-tete d'un contexte : type, longueur des donnees, 4 octets reserves.
+pub const SMB2_NEGOTIATE_CONTEXT_HEADER_LEN: usize = 8;
+
+/// `StructureSize` des corps decodes, mesures MS-SMB2 §2.2.
+pub const SMB2_ERROR_RESPONSE_SIZE: u16 = 9;
+pub const SMB2_NEGOTIATE_REQUEST_SIZE: u16 = 36;
+pub const SMB2_NEGOTIATE_RESPONSE_SIZE: u16 = 65;
+pub const SMB2_SESSION_SETUP_REQUEST_SIZE: u16 = 25;
+pub const SMB2_SESSION_SETUP_RESPONSE_SIZE: u16 = 9;
+pub const SMB2_TREE_CONNECT_REQUEST_SIZE: u16 = 9;
+pub const SMB2_TREE_CONNECT_RESPONSE_SIZE: u16 = 16;
+pub const SMB2_CREATE_REQUEST_SIZE: u16 = 57;
+pub const SMB2_CREATE_RESPONSE_SIZE: u16 = 89;
+pub const SMB2_READ_REQUEST_SIZE: u16 = 49;
+pub const SMB2_READ_RESPONSE_SIZE: u16 = 17;
+pub const SMB2_WRITE_REQUEST_SIZE: u16 = 49;
+pub const SMB2_WRITE_RESPONSE_SIZE: u16 = 17;
+
+pub const STATUS_SUCCESS: u32 = 0x0000_0000;
+pub const STATUS_PENDING: u32 = 0x0000_0103;
+pub const STATUS_BUFFER_OVERFLOW: u32 = 0x8000_0005;
+pub const STATUS_MORE_PROCESSING_REQUIRED: u32 = 0xC000_0016;
+pub const STATUS_ACCESS_DENIED: u32 = 0xC000_0022;
+pub const STATUS_LOGON_FAILURE: u32 = 0xC000_006D;
+
+/// Message NEGOEX (MS-NEGOEX), negocie par SPNEGO comme NTLMSSP.
+pub const NEGOEX_SIGNATURE: [u8; 8] = *b"NEGOEXTS";
+
+/// Contenus DER des OID de mecanismes GSS-API ; ceux de Kerberos sont
+/// dans `checks::application::kerberos`.
+pub const OID_SPNEGO: &[u8] = &[0x2B, 0x06, 0x01, 0x05, 0x05, 0x02];
+pub const OID_NTLMSSP: &[u8] = &[0x2B, 0x06, 0x01, 0x04, 0x01, 0x82, 0x37, 0x02, 0x02, 0x0A];
+pub const OID_NEGOEX: &[u8] = &[0x2B, 0x06, 0x01, 0x04, 0x01, 0x82, 0x37, 0x02, 0x02, 0x1E];
+
+/// Etiquettes DER utiles aux jetons GSS-API et SPNEGO.
+pub const DER_OID_TAG: u8 = 0x06;
+pub const DER_ENUMERATED_TAG: u8 = 0x0A;
+pub const DER_OCTET_STRING_TAG: u8 = 0x04;
+pub const DER_SEQUENCE_TAG: u8 = 0x30;
+/// InitialContextToken GSS-API : [APPLICATION 0].
+pub const GSS_INITIAL_CONTEXT_TOKEN_TAG: u8 = 0x60;
+/// NegTokenInit `[0]` et NegTokenResp `[1]` (RFC 4178 §4.2).
+pub const SPNEGO_NEG_TOKEN_INIT_TAG: u8 = 0xA0;
+pub const SPNEGO_NEG_TOKEN_RESP_TAG: u8 = 0xA1;
+
+/// Verifie qu'un message commence par un en-tete SMB2 complet.
+pub fn validate_smb2_header(message: &[u8]) -> Result<(), SmbError> {
+    if message.len() < SMB2_HEADER_LEN {
+        return Err(SmbError::InvalidLength {
+            expected: SMB2_HEADER_LEN,
+            actual: message.len(),
+        });
+    }
+    let structure_size = u16::from_le_bytes([message[4], message[5]]);
+    if structure_size != SMB2_HEADER_LEN as u16 {
+        return Err(SmbError::InvalidStructureSize {
+            field: "header",
+            expected: SMB2_HEADER_LEN as u16,
+            actual: structure_size,
+        });
+    }
+    Ok(())
+}
+
+/// Corps d'un message SMB2 dont la `StructureSize` vaut `expected`. Une
+/// taille impaire compte le premier octet du tampon variable, qui peut
+/// manquer quand le tampon est vide.
+pub fn extract_smb2_body<'a>(
+    message: &'a [u8],
+    expected: u16,
+    field: &'static str,
+) -> Result<&'a [u8], SmbError> {
+    let body = &message[SMB2_HEADER_LEN.min(message.len())..];
+    if body.len() < 2 {
+        return Err(SmbError::Truncated { field });
+    }
+    let actual = u16::from_le_bytes([body[0], body[1]]);
+    if actual != expected {
+        return Err(SmbError::InvalidStructureSize {
+            field,
+            expected,
+            actual,
+        });
+    }
+    let fixed = usize::from(expected & !1);
+    if body.len() < fixed {
+        return Err(SmbError::Truncated { field });
+    }
+    Ok(body)
+}
+
+/// Tampon variable designe par un offset compte depuis l'en-tete SMB2.
+/// Un tampon vide est accepte quel que soit son offset.
+pub fn extract_smb2_buffer<'a>(
+    message: &'a [u8],
+    offset: usize,
+    length: usize,
+    field: &'static str,
+) -> Result<&'a [u8], SmbError> {
+    if length == 0 {
+        return Ok(&[]);
+    }
+    match offset.checked_add(length) {
+        Some(end) if offset >= SMB2_HEADER_LEN && end <= message.len() => Ok(&message[offset..end]),
+        _ => Err(SmbError::InvalidBufferOffset {
+            field,
+            offset,
+            length,
+        }),
+    }
+}
+
+/// Chaine UTF-16LE sans terminateur (noms de fichier, chemins de partage).
+pub fn extract_smb_utf16(bytes: &[u8], field: &'static str) -> Result<String, SmbError> {
+    if !bytes.len().is_multiple_of(2) {
+        return Err(SmbError::InvalidUtf16 { field });
+    }
+    let units = bytes
+        .chunks_exact(2)
+        .map(|unit| u16::from_le_bytes([unit[0], unit[1]]));
+    char::decode_utf16(units)
+        .collect::<Result<String, _>>()
+        .map_err(|_| SmbError::InvalidUtf16 { field })
+}
+
+/// Element DER en tete de `data` : etiquette sur un octet, valeur, octets
+/// suivants, lu par le decodeur BER commun.
+pub fn extract_der_element<'a>(
+    data: &'a [u8],
+    field: &'static str,
+) -> Result<(u8, &'a [u8], &'a [u8]), SmbError> {
+    let invalid = || SmbError::InvalidSecurityBlob { field };
+    // Etiquettes multi-octets absentes de SPNEGO et des jetons Kerberos.
+    if data
+        .first()
+        .is_some_and(|tag| tag & BER_HIGH_TAG == BER_HIGH_TAG)
+    {
+        return Err(invalid());
+    }
+    let (element, rest) = extract_ber_element(data, field).map_err(|_| invalid())?;
+    let tag = element.identifier().ok_or_else(invalid)?;
+    Ok((tag, element.value, rest))
+}
+
+/// Statut NT de gravite erreur (deux bits de poids fort a 1).
+pub fn is_nt_error(status: u32) -> bool {
+    status >> 30 == 3
+}
+
+pub fn smb1_command_name(command: u8) -> &'static str {
+    match command {
+        0x04 => "CLOSE",
+        0x25 => "TRANSACTION",
+        0x2D => "OPEN_ANDX",
+        0x2E => "READ_ANDX",
+        0x2F => "WRITE_ANDX",
+        0x32 => "TRANSACTION2",
+        0x71 => "TREE_DISCONNECT",
+        SMB1_COM_NEGOTIATE => "NEGOTIATE",
+        SMB1_COM_SESSION_SETUP_ANDX => "SESSION_SETUP_ANDX",
+        0x74 => "LOGOFF_ANDX",
+        SMB1_COM_TREE_CONNECT_ANDX => "TREE_CONNECT_ANDX",
+        0xA0 => "NT_TRANSACT",
+        0xA2 => "NT_CREATE_ANDX",
+        _ => "Unknown",
+    }
+}
+
+pub fn smb2_command_name(command: u16) -> &'static str {
+    match command {
+        SMB2_NEGOTIATE => "NEGOTIATE",
+        SMB2_SESSION_SETUP => "SESSION_SETUP",
+        SMB2_LOGOFF => "LOGOFF",
+        SMB2_TREE_CONNECT => "TREE_CONNECT",
+        SMB2_TREE_DISCONNECT => "TREE_DISCONNECT",
+        SMB2_CREATE => "CREATE",
+        SMB2_CLOSE => "CLOSE",
+        SMB2_FLUSH => "FLUSH",
+        SMB2_READ => "READ",
+        SMB2_WRITE => "WRITE",
+        SMB2_LOCK => "LOCK",
+        SMB2_IOCTL => "IOCTL",
+        SMB2_CANCEL => "CANCEL",
+        SMB2_ECHO => "ECHO",
+        SMB2_QUERY_DIRECTORY => "QUERY_DIRECTORY",
+        SMB2_CHANGE_NOTIFY => "CHANGE_NOTIFY",
+        SMB2_QUERY_INFO => "QUERY_INFO",
+        SMB2_SET_INFO => "SET_INFO",
+        SMB2_OPLOCK_BREAK => "OPLOCK_BREAK",
+        _ => "Unknown",
+    }
+}
+
+pub fn smb2_dialect_name(dialect: u16) -> &'static str {
+    match dialect {
+        SMB2_DIALECT_202 => "SMB 2.0.2",
+        SMB2_DIALECT_210 => "SMB 2.1",
+        SMB2_DIALECT_300 => "SMB 3.0",
+        SMB2_DIALECT_302 => "SMB 3.0.2",
+        SMB2_DIALECT_311 => "SMB 3.1.1",
+        SMB2_DIALECT_WILDCARD => "SMB 2.???",
+        _ => "Unknown",
+    }
+}
+
+/// Noms des bits de capacites globales, dans l'ordre des bits.
+pub fn smb2_capability_names(capabilities: u32) -> Vec<&'static str> {
+    [
+        (SMB2_GLOBAL_CAP_DFS, "DFS"),
+        (SMB2_GLOBAL_CAP_LEASING, "LEASING"),
+        (SMB2_GLOBAL_CAP_LARGE_MTU, "LARGE_MTU"),
+        (SMB2_GLOBAL_CAP_MULTI_CHANNEL, "MULTI_CHANNEL"),
+        (SMB2_GLOBAL_CAP_PERSISTENT_HANDLES, "PERSISTENT_HANDLES"),
+        (SMB2_GLOBAL_CAP_DIRECTORY_LEASING, "DIRECTORY_LEASING"),
+        (SMB2_GLOBAL_CAP_ENCRYPTION, "ENCRYPTION"),
+        (SMB2_GLOBAL_CAP_NOTIFICATIONS, "NOTIFICATIONS"),
+    ]
+    .into_iter()
+    .filter(|(bit, _)| capabilities & bit != 0)
+    .map(|(_, name)| name)
+    .collect()
+}
+
+pub fn smb2_negotiate_context_name(context_type: u16) -> &'static str {
+    match context_type {
+        SMB2_PREAUTH_INTEGRITY_CAPABILITIES => "PREAUTH_INTEGRITY_CAPABILITIES",
+        SMB2_ENCRYPTION_CAPABILITIES => "ENCRYPTION_CAPABILITIES",
+        SMB2_COMPRESSION_CAPABILITIES => "COMPRESSION_CAPABILITIES",
+        SMB2_NETNAME_NEGOTIATE_CONTEXT_ID => "NETNAME_NEGOTIATE_CONTEXT_ID",
+        SMB2_TRANSPORT_CAPABILITIES => "TRANSPORT_CAPABILITIES",
+        SMB2_RDMA_TRANSFORM_CAPABILITIES => "RDMA_TRANSFORM_CAPABILITIES",
+        SMB2_SIGNING_CAPABILITIES => "SIGNING_CAPABILITIES",
+        _ => "Unknown",
+    }
+}
+
+pub fn smb2_share_type_name(share_type: u8) -> &'static str {
+    match share_type {
+        0x01 => "DISK",
+        0x02 => "PIPE",
+        0x03 => "PRINT",
+        _ => "Unknown",
+    }
+}
+
+pub fn smb2_create_disposition_name(disposition: u32) -> &'static str {
+    match disposition {
+        0 => "SUPERSEDE",
+        1 => "OPEN",
+        2 => "CREATE",
+        3 => "OPEN_IF",
+        4 => "OVERWRITE",
+        5 => "OVERWRITE_IF",
+        _ => "Unknown",
+    }
+}
+
+pub fn smb2_create_action_name(action: u32) -> &'static str {
+    match action {
+        0 => "SUPERSEDED",
+        1 => "OPENED",
+        2 => "CREATED",
+        3 => "OVERWRITTEN",
+        _ => "Unknown",
+    }
+}
+
+/// Statuts NT les plus frequents dans les echanges SMB (MS-ERREF §2.3).
+pub fn nt_status_name(status: u32) -> &'static str {
+    match status {
+        STATUS_SUCCESS => "STATUS_SUCCESS",
+        STATUS_PENDING => "STATUS_PENDING",
+        STATUS_BUFFER_OVERFLOW => "STATUS_BUFFER_OVERFLOW",
+        0x8000_001A => "STATUS_NO_MORE_FILES",
+        0xC000_000D => "STATUS_INVALID_PARAMETER",
+        0xC000_000F => "STATUS_NO_SUCH_FILE",
+        0xC000_0011 => "STATUS_END_OF_FILE",
+        STATUS_MORE_PROCESSING_REQUIRED => "STATUS_MORE_PROCESSING_REQUIRED",
+        STATUS_ACCESS_DENIED => "STATUS_ACCESS_DENIED",
+        0xC000_0034 => "STATUS_OBJECT_NAME_NOT_FOUND",
+        0xC000_0035 => "STATUS_OBJECT_NAME_COLLISION",
+        0xC000_003A => "STATUS_OBJECT_PATH_NOT_FOUND",
+        0xC000_0043 => "STATUS_SHARING_VIOLATION",
+        STATUS_LOGON_FAILURE => "STATUS_LOGON_FAILURE",
+        0xC000_0072 => "STATUS_ACCOUNT_DISABLED",
+        0xC000_00BB => "STATUS_NOT_SUPPORTED",
+        0xC000_00CC => "STATUS_BAD_NETWORK_NAME",
+        0xC000_0120 => "STATUS_CANCELLED",
+        0xC000_0203 => "STATUS_USER_SESSION_DELETED",
+        0xC000_035C => "STATUS_NETWORK_SESSION_EXPIRED",
+        _ => "Unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn header(structure_size: u16) -> Vec<u8> {
+        let mut message = vec![0u8; SMB2_HEADER_LEN];
+        message[..4].copy_from_slice(&SMB2_PROTOCOL_ID);
+        message[4..6].copy_from_slice(&structure_size.to_le_bytes());
+        message
+    }
+
+    #[test]
+    fn header_and_body_sizes() {
+        assert_eq!(validate_smb2_header(&header(64)), Ok(()));
+        assert_eq!(
+            validate_smb2_header(&header(64)[..40]),
+            Err(SmbError::InvalidLength {
+                expected: 64,
+                actual: 40
+            })
+        );
+        assert_eq!(
+            validate_smb2_header(&header(65)),
+            Err(SmbError::InvalidStructureSize {
+                field: "header",
+                expected: 64,
+                actual: 65
+            })
+        );
+
+        // Reponse WRITE : 17 annonce, 16 octets fixes suffisent.
+        let mut message = header(64);
+        message.extend_from_slice(&[0x11, 0x00]);
+        message.extend_from_slice(&[0; 14]);
+        assert_eq!(
+            extract_smb2_body(&message, SMB2_WRITE_RESPONSE_SIZE, "WRITE response")
+                .map(<[u8]>::len),
+            Ok(16)
+        );
+        assert_eq!(
+            extract_smb2_body(&message[..70], SMB2_WRITE_RESPONSE_SIZE, "WRITE response"),
+            Err(SmbError::Truncated {
+                field: "WRITE response"
+            })
+        );
+        assert_eq!(
+            extract_smb2_body(&message, SMB2_READ_REQUEST_SIZE, "READ request"),
+            Err(SmbError::InvalidStructureSize {
+                field: "READ request",
+                expected: 49,
+                actual: 17
+            })
+        );
+    }
+
+    #[test]
+    fn buffers_are_bounded_by_the_message() {
+        let message = header(64);
+        assert_eq!(extract_smb2_buffer(&message, 0, 0, "name"), Ok(&[][..]));
+        assert_eq!(
+            extract_smb2_buffer(&message, 60, 4, "name"),
+            Err(SmbError::InvalidBufferOffset {
+                field: "name",
+                offset: 60,
+                length: 4
+            })
+        );
+        assert!(extract_smb2_buffer(&message, 64, 1, "name").is_err());
+        assert!(extract_smb2_buffer(&message, usize::MAX, 2, "name").is_err());
+    }
+
+    #[test]
+    fn utf16_names() {
+        assert_eq!(
+            extract_smb_utf16(&[b'I', 0, b'P', 0, b'C', 0, b'$', 0], "path"),
+            Ok("IPC$".to_string())
+        );
+        assert_eq!(
+            extract_smb_utf16(&[b'a', 0, b'b'], "path"),
+            Err(SmbError::InvalidUtf16 { field: "path" })
+        );
+        // Surrogat haut isole.
+        assert_eq!(
+            extract_smb_utf16(&[0x00, 0xD8], "path"),
+            Err(SmbError::InvalidUtf16 { field: "path" })
+        );
+    }
+
+    #[test]
+    fn der_elements() {
+        assert_eq!(
+            extract_der_element(&[0x0A, 0x01, 0x00, 0xFF], "negState"),
+            Ok((0x0A, &[0x00][..], &[0xFF][..]))
+        );
+        // Longueur longue sur un octet.
+        let mut long = vec![0x04, 0x81, 0x80];
+        long.extend_from_slice(&[0xAA; 0x80]);
+        let (_, value, rest) = extract_der_element(&long, "token").expect("DER valide");
+        assert_eq!((value.len(), rest.len()), (128, 0));
+
+        assert!(extract_der_element(&[0x04, 0x05, 0x00], "token").is_err());
+        assert!(extract_der_element(&[0x1F, 0x01, 0x00], "token").is_err());
+        assert!(extract_der_element(&[0x04, 0x85, 0, 0, 0, 0, 1], "token").is_err());
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(smb2_command_name(SMB2_TREE_CONNECT), "TREE_CONNECT");
+        assert_eq!(smb1_command_name(SMB1_COM_NEGOTIATE), "NEGOTIATE");
+        assert_eq!(smb2_dialect_name(SMB2_DIALECT_311), "SMB 3.1.1");
+        assert_eq!(
+            smb2_capability_names(0x2F),
+            [
+                "DFS",
+                "LEASING",
+                "LARGE_MTU",
+                "MULTI_CHANNEL",
+                "DIRECTORY_LEASING"
+            ]
+        );
+        assert_eq!(
+            smb2_negotiate_context_name(SMB2_ENCRYPTION_CAPABILITIES),
+            "ENCRYPTION_CAPABILITIES"
+        );
+        assert_eq!(
+            nt_status_name(STATUS_MORE_PROCESSING_REQUIRED),
+            "STATUS_MORE_PROCESSING_REQUIRED"
+        );
+        assert!(is_nt_error(STATUS_LOGON_FAILURE));
+        assert!(!is_nt_error(STATUS_BUFFER_OVERFLOW));
+        assert_eq!(smb2_share_type_name(2), "PIPE");
+        assert_eq!(smb2_create_disposition_name(3), "OPEN_IF");
+    }
+}
@@ -6,7 +6,7 @@
 use core::mem::size_of;
 
 use crate::{
-    errors::application::snmp::SnmpError,
+    errors::application::snmp::{SnmpError, SnmpOidError},
     parse::application::protocols::snmp::{SnmpPduType, SnmpValue, SnmpVersion},
 };
 
@@ -315,6 +315,191 @@ pub(crate) fn validate_v3_data_tag(actual: u8) -> Result<(), SnmpError> {
     Ok(())
 }
 
+/// Modèle de sécurité USM (RFC 3414), seul à porter des paramètres décodés.
+pub const SNMP_USM_SECURITY_MODEL: i64 = 3;
+
+/// Bits de msgFlags (RFC 3412 §6.4).
+pub const SNMP_V3_FLAG_AUTH: u8 = 0x01;
+pub const SNMP_V3_FLAG_PRIV: u8 = 0x02;
+pub const SNMP_V3_FLAG_REPORTABLE: u8 = 0x04;
+
+/// Taille minimale d'un snmpEngineID (RFC 3411 §5).
+pub const SNMP_ENGINE_ID_MIN_LEN: usize = 5;
+
+/// sysUpTime.0 et snmpTrapOID.0, deux premières liaisons de toute
+/// notification SNMPv2 (RFC 3416 §4.2.6).
+pub const SNMP_SYS_UP_TIME_0: &[u64] = &[1, 3, 6, 1, 2, 1, 1, 3, 0];
+pub const SNMP_TRAP_OID_0: &[u64] = &[1, 3, 6, 1, 6, 3, 1, 1, 4, 1, 0];
+
+/// snmpTraps : les traps génériques SNMPv1 y sont traduits en
+/// `snmpTraps.(generic-trap + 1)` (RFC 3584 §3.1).
+pub const SNMP_TRAPS_PREFIX: &[u64] = &[1, 3, 6, 1, 6, 3, 1, 1, 5];
+
+/// generic-trap enterpriseSpecific : l'identité du trap vient alors de
+/// l'entreprise et du specific-trap.
+pub const SNMP_GENERIC_TRAP_ENTERPRISE_SPECIFIC: i64 = 6;
+
+/// Noms intégrés : MIB-2 (RFC 1213), IF-MIB (RFC 2863), HOST-RESOURCES-MIB
+/// (RFC 2790), notifications SNMPv2-MIB (RFC 3418), compteurs USM
+/// (RFC 3414) et paramètres de coexistence (RFC 3584).
+pub const SNMP_BUILTIN_OID_NAMES: &[(&str, &str)] = &[
+    ("1.3.6.1.2.1", "mib-2"),
+    ("1.3.6.1.2.1.1", "system"),
+    ("1.3.6.1.2.1.1.1", "sysDescr"),
+    ("1.3.6.1.2.1.1.2", "sysObjectID"),
+    ("1.3.6.1.2.1.1.3", "sysUpTime"),
+    ("1.3.6.1.2.1.1.4", "sysContact"),
+    ("1.3.6.1.2.1.1.5", "sysName"),
+    ("1.3.6.1.2.1.1.6", "sysLocation"),
+    ("1.3.6.1.2.1.1.7", "sysServices"),
+    ("1.3.6.1.2.1.1.8", "sysORLastChange"),
+    ("1.3.6.1.2.1.2", "interfaces"),
+    ("1.3.6.1.2.1.2.1", "ifNumber"),
+    ("1.3.6.1.2.1.2.2", "ifTable"),
+    ("1.3.6.1.2.1.2.2.1", "ifEntry"),
+    ("1.3.6.1.2.1.2.2.1.1", "ifIndex"),
+    ("1.3.6.1.2.1.2.2.1.2", "ifDescr"),
+    ("1.3.6.1.2.1.2.2.1.3", "ifType"),
+    ("1.3.6.1.2.1.2.2.1.4", "ifMtu"),
+    ("1.3.6.1.2.1.2.2.1.5", "ifSpeed"),
+    ("1.3.6.1.2.1.2.2.1.6", "ifPhysAddress"),
+    ("1.3.6.1.2.1.2.2.1.7", "ifAdminStatus"),
+    ("1.3.6.1.2.1.2.2.1.8", "ifOperStatus"),
+    ("1.3.6.1.2.1.2.2.1.9", "ifLastChange"),
+    ("1.3.6.1.2.1.2.2.1.10", "ifInOctets"),
+    ("1.3.6.1.2.1.2.2.1.11", "ifInUcastPkts"),
+    ("1.3.6.1.2.1.2.2.1.13", "ifInDiscards"),
+    ("1.3.6.1.2.1.2.2.1.14", "ifInErrors"),
+    ("1.3.6.1.2.1.2.2.1.16", "ifOutOctets"),
+    ("1.3.6.1.2.1.2.2.1.17", "ifOutUcastPkts"),
+    ("1.3.6.1.2.1.2.2.1.19", "ifOutDiscards"),
+    ("1.3.6.1.2.1.2.2.1.20", "ifOutErrors"),
+    ("1.3.6.1.2.1.4", "ip"),
+    ("1.3.6.1.2.1.4.1", "ipForwarding"),
+    ("1.3.6.1.2.1.4.20.1.1", "ipAdEntAddr"),
+    ("1.3.6.1.2.1.4.20.1.2", "ipAdEntIfIndex"),
+    ("1.3.6.1.2.1.4.20.1.3", "ipAdEntNetMask"),
+    ("1.3.6.1.2.1.4.22.1.2", "ipNetToMediaPhysAddress"),
+    ("1.3.6.1.2.1.11", "snmp"),
+    ("1.3.6.1.2.1.25", "host"),
+    ("1.3.6.1.2.1.25.1.1", "hrSystemUptime"),
+    ("1.3.6.1.2.1.25.1.2", "hrSystemDate"),
+    ("1.3.6.1.2.1.25.1.5", "hrSystemNumUsers"),
+    ("1.3.6.1.2.1.25.1.6", "hrSystemProcesses"),
+    ("1.3.6.1.2.1.25.2.2", "hrMemorySize"),
+    ("1.3.6.1.2.1.25.2.3.1.3", "hrStorageDescr"),
+    ("1.3.6.1.2.1.25.2.3.1.4", "hrStorageAllocationUnits"),
+    ("1.3.6.1.2.1.25.2.3.1.5", "hrStorageSize"),
+    ("1.3.6.1.2.1.25.2.3.1.6", "hrStorageUsed"),
+    ("1.3.6.1.2.1.25.3.2.1.3", "hrDeviceDescr"),
+    ("1.3.6.1.2.1.25.3.3.1.2", "hrProcessorLoad"),
+    ("1.3.6.1.2.1.25.4.2.1.2", "hrSWRunName"),
+    ("1.3.6.1.2.1.25.4.2.1.4", "hrSWRunPath"),
+    ("1.3.6.1.2.1.25.6.3.1.2", "hrSWInstalledName"),
+    ("1.3.6.1.2.1.31", "ifMIB"),
+    ("1.3.6.1.2.1.31.1.1.1.1", "ifName"),
+    ("1.3.6.1.2.1.31.1.1.1.6", "ifHCInOctets"),
+    ("1.3.6.1.2.1.31.1.1.1.10", "ifHCOutOctets"),
+    ("1.3.6.1.2.1.31.1.1.1.15", "ifHighSpeed"),
+    ("1.3.6.1.2.1.31.1.1.1.18", "ifAlias"),
+    ("1.3.6.1.4.1", "enterprises"),
+    ("1.3.6.1.6.3.1.1.4.1", "snmpTrapOID"),
+    ("1.3.6.1.6.3.1.1.4.3", "snmpTrapEnterprise"),
+    ("1.3.6.1.6.3.1.1.5", "snmpTraps"),
+    ("1.3.6.1.6.3.1.1.5.1", "coldStart"),
+    ("1.3.6.1.6.3.1.1.5.2", "warmStart"),
+    ("1.3.6.1.6.3.1.1.5.3", "linkDown"),
+    ("1.3.6.1.6.3.1.1.5.4", "linkUp"),
+    ("1.3.6.1.6.3.1.1.5.5", "authenticationFailure"),
+    ("1.3.6.1.6.3.1.1.5.6", "egpNeighborLoss"),
+    ("1.3.6.1.6.3.10.2.1.1", "snmpEngineID"),
+    ("1.3.6.1.6.3.10.2.1.2", "snmpEngineBoots"),
+    ("1.3.6.1.6.3.10.2.1.3", "snmpEngineTime"),
+    ("1.3.6.1.6.3.15.1.1.1", "usmStatsUnsupportedSecLevels"),
+    ("1.3.6.1.6.3.15.1.1.2", "usmStatsNotInTimeWindows"),
+    ("1.3.6.1.6.3.15.1.1.3", "usmStatsUnknownUserNames"),
+    ("1.3.6.1.6.3.15.1.1.4", "usmStatsUnknownEngineIDs"),
+    ("1.3.6.1.6.3.15.1.1.5", "usmStatsWrongDigests"),
+    ("1.3.6.1.6.3.15.1.1.6", "usmStatsDecryptionErrors"),
+    ("1.3.6.1.6.3.18.1.3", "snmpTrapAddress"),
+    ("1.3.6.1.6.3.18.1.4", "snmpTrapCommunity"),
+];
+
+/// Décode les arcs d'un OBJECT IDENTIFIER BER (X.690 §8.19). Un
+/// sous-identifiant ne peut commencer par un octet 0x80 ni rester inachevé.
+pub fn extract_object_identifier(
+    value: &[u8],
+    field: &'static str,
+) -> Result<Vec<u64>, SnmpOidError> {
+    let invalid = SnmpOidError::InvalidObjectIdentifier { field };
+    if value.is_empty() || value.last().is_some_and(|byte| byte & 0x80 != 0) {
+        return Err(invalid);
+    }
+    let mut arcs = Vec::with_capacity(value.len() + 1);
+    let mut sub_identifier: u64 = 0;
+    let mut starting = true;
+    for &byte in value {
+        if starting && byte == 0x80 {
+            return Err(invalid);
+        }
+        sub_identifier = sub_identifier
+            .checked_mul(128)
+            .ok_or(SnmpOidError::InvalidObjectIdentifier { field })?
+            | u64::from(byte & 0x7F);
+        starting = byte & 0x80 == 0;
+        if starting {
+            if arcs.is_empty() {
+                // Premier sous-identifiant : deux arcs regroupés (X.690 §8.19.4).
+                let first = (sub_identifier / 40).min(2);
+                arcs.push(first);
+                arcs.push(sub_identifier - first * 40);
+            } else {
+                arcs.push(sub_identifier);
+            }
+            sub_identifier = 0;
+        }
+    }
+    Ok(arcs)
+}
+
+/// Lit un OID en notation pointée (« 1.3.6.1 », point initial toléré comme
+/// chez Net-SNMP). Au moins deux arcs.
+pub fn parse_dotted_oid(text: &str) -> Option<Vec<u64>> {
+    let text = text.strip_prefix('.').unwrap_or(text);
+    let arcs = text
+        .split('.')
+        .map(|arc| arc.parse::<u64>().ok())
+        .collect::<Option<Vec<_>>>()?;
+    (arcs.len() >= 2).then_some(arcs)
+}
+
+/// Nom du generic-trap SNMPv1 (RFC 1157 §4.1.6).
+pub fn snmp_generic_trap_name(generic_trap: i64) -> &'static str {
+    match generic_trap {
+        0 => "coldStart",
+        1 => "warmStart",
+        2 => "linkDown",
+        3 => "linkUp",
+        4 => "authenticationFailure",
+        5 => "egpNeighborLoss",
+        SNMP_GENERIC_TRAP_ENTERPRISE_SPECIFIC => "enterpriseSpecific",
+        _ => "unknown",
+    }
+}
+
+/// Nom du format d'un snmpEngineID (RFC 3411 §5, cinquième octet).
+pub fn snmp_engine_id_format_name(format: u8) -> &'static str {
+    match format {
+        1 => "IPv4",
+        2 => "IPv6",
+        3 => "MAC",
+        4 => "text",
+        5 => "octets",
+        6..=127 => "reserved",
+        _ => "enterprise specific",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -382,4 +567,36 @@ mod tests {
             })
         ));
     }
+
+    #[test]
+    fn extract_object_identifier_decode_les_arcs() {
+        // 1.3.6.1.4.1.311 : 311 tient sur deux octets.
+        assert_eq!(
+            extract_object_identifier(&[0x2B, 6, 1, 4, 1, 0x82, 0x37], "oid"),
+            Ok(vec![1, 3, 6, 1, 4, 1, 311])
+        );
+        assert_eq!(
+            extract_object_identifier(&[0x2B, 0x82], "oid"),
+            Err(SnmpOidError::InvalidObjectIdentifier { field: "oid" })
+        );
+        assert_eq!(
+            extract_object_identifier(&[0x2B, 0x80, 0x01], "oid"),
+            Err(SnmpOidError::InvalidObjectIdentifier { field: "oid" })
+        );
+        assert!(extract_object_identifier(&[], "oid").is_err());
+    }
+
+    #[test]
+    fn parse_dotted_oid_et_noms() {
+        assert_eq!(parse_dotted_oid(".1.3.6.1"), Some(vec![1, 3, 6, 1]));
+        assert_eq!(parse_dotted_oid("1"), None);
+        assert_eq!(parse_dotted_oid("1.3.x"), None);
+        assert_eq!(snmp_generic_trap_name(3), "linkUp");
+        assert_eq!(snmp_engine_id_format_name(3), "MAC");
+        assert!(
+            SNMP_BUILTIN_OID_NAMES
+                .iter()
+                .all(|(oid, _)| parse_dotted_oid(oid).is_some())
+        );
+    }
 }
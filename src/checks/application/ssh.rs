@@ -3,7 +3,8 @@
 // Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
 // This file may not be copied, modified, or distributed except according to those terms.
 
-//! Controles pour la chaine d'identification SSH (RFC 4253 §4.2).
+//! Controles pour la chaine d'identification SSH (RFC 4253 §4.2) et pour le
+//! paquet binaire `SSH_MSG_KEXINIT` qui la suit, encore en clair (§6, §7.1).
 //!
 //! Format : `SSH-protoversion-softwareversion [SP comments] CR LF`.
 //!
@@ -141,3 +142,81 @@ pub fn extract_ssh_software_and_comments(rest: &[u8]) -> Result<(&str, Option<&s
 
     Ok((software, comments))
 }
+
+/// `packet_length` (4) + `padding_length` (1).
+pub const SSH_BINARY_PACKET_HEADER_LEN: usize = 5;
+
+/// RFC 4253 §6.1 : toute implementation doit accepter des paquets de 35000
+/// octets ; on n'en accepte pas davantage.
+pub const SSH_MAX_PACKET_LENGTH: usize = 35_000;
+
+/// RFC 4253 §6 : au moins quatre octets de bourrage.
+pub const SSH_MIN_PADDING_LENGTH: usize = 4;
+
+/// Numero de message `SSH_MSG_KEXINIT` (RFC 4253 §12).
+pub const SSH_MSG_KEXINIT: u8 = 20;
+
+/// Longueur du cookie aleatoire de KEXINIT.
+pub const SSH_KEXINIT_COOKIE_LEN: usize = 16;
+
+/// Extrait le payload d'un paquet binaire non chiffre, sans le bourrage ni
+/// le MAC eventuel qui suit.
+pub fn extract_ssh_binary_packet_payload(buf: &[u8]) -> Result<&[u8], SshError> {
+    if buf.len() < SSH_BINARY_PACKET_HEADER_LEN {
+        return Err(SshError::TruncatedBinaryPacket {
+            expected: SSH_BINARY_PACKET_HEADER_LEN,
+            actual: buf.len(),
+        });
+    }
+
+    let packet_length = u32::from_be_bytes([buf[0], buf[1], buf[2], buf[3]]) as usize;
+    if !(SSH_MIN_PADDING_LENGTH + 1..=SSH_MAX_PACKET_LENGTH).contains(&packet_length) {
+        return Err(SshError::InvalidPacketLength {
+            length: packet_length,
+        });
+    }
+    if buf.len() < 4 + packet_length {
+        return Err(SshError::TruncatedBinaryPacket {
+            expected: 4 + packet_length,
+            actual: buf.len(),
+        });
+    }
+
+    let padding_length = buf[4] as usize;
+    if padding_length < SSH_MIN_PADDING_LENGTH || padding_length + 1 >= packet_length {
+        return Err(SshError::InvalidPaddingLength {
+            padding_length,
+            packet_length,
+        });
+    }
+
+    Ok(&buf[SSH_BINARY_PACKET_HEADER_LEN..4 + packet_length - padding_length])
+}
+
+/// Extrait une name-list (RFC 4251 §5) et renvoie le reste du buffer.
+///
+/// Les noms d'algorithmes sont de l'US-ASCII : tout octet non imprimable
+/// signale un paquet chiffre ou corrompu.
+pub fn extract_ssh_name_list(bytes: &[u8]) -> Result<(&str, &[u8]), SshError> {
+    if bytes.len() < 4 {
+        return Err(SshError::TruncatedNameList {
+            declared: 4,
+            available: bytes.len(),
+        });
+    }
+    let declared = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as usize;
+    let rest = &bytes[4..];
+    if rest.len() < declared {
+        return Err(SshError::TruncatedNameList {
+            declared,
+            available: rest.len(),
+        });
+    }
+
+    let (list, rest) = rest.split_at(declared);
+    if !list.iter().all(|byte| (0x21..=0x7e).contains(byte)) {
+        return Err(SshError::InvalidNameList);
+    }
+    let list = core::str::from_utf8(list).map_err(|_| SshError::InvalidNameList)?;
+    Ok((list, rest))
+}
@@ -4,7 +4,11 @@
 // This file may not be copied, modified, or distributed except according to those terms.
 
 use crate::{
-    errors::application::tls::TlsError,
+    checks::application::ber::{BER_HIGH_TAG, extract_ber_element},
+    errors::application::{
+        ber::BerError,
+        tls::{TlsDecryptError, TlsError, TlsHandshakeError},
+    },
     parse::application::protocols::tls::{TlsContentType, TlsVersion},
 };
 
@@ -87,6 +91,181 @@ pub fn validate_tls_record_complete(remaining: usize, length: u16) -> Result<(),
     Ok(())
 }
 
+// Couche handshake (RFC 8446 §4, RFC 5246 §7.4) : type u8 + longueur u24.
+pub const TLS_HANDSHAKE_HEADER_LEN: usize = 4;
+
+/// Longueur du champ `random` des ClientHello/ServerHello.
+pub const TLS_RANDOM_LEN: usize = 32;
+
+/// Plafond de reassemblage d'un message handshake. Le wire autorise 2^24 - 1
+/// octets, mais une chaine de certificats reelle depasse rarement quelques
+/// dizaines de Ko : 256 Ko laisse de la marge sans laisser un pair imposer
+/// 16 Mo de tampon.
+pub const TLS_MAX_HANDSHAKE_LEN: usize = 256 * 1024;
+
+// Types d'extension decodes (registre IANA "TLS ExtensionType Values").
+pub const TLS_EXT_SERVER_NAME: u16 = 0;
+pub const TLS_EXT_SUPPORTED_GROUPS: u16 = 10;
+pub const TLS_EXT_EC_POINT_FORMATS: u16 = 11;
+pub const TLS_EXT_SIGNATURE_ALGORITHMS: u16 = 13;
+pub const TLS_EXT_ALPN: u16 = 16;
+pub const TLS_EXT_SUPPORTED_VERSIONS: u16 = 43;
+pub const TLS_EXT_KEY_SHARE: u16 = 51;
+
+/// Type `host_name` de la liste server_name (RFC 6066 §3).
+pub const TLS_SNI_HOST_NAME: u8 = 0;
+
+/// Random d'un HelloRetryRequest : SHA-256("HelloRetryRequest"), RFC 8446 §4.1.3.
+pub const TLS_HELLO_RETRY_REQUEST_RANDOM: [u8; TLS_RANDOM_LEN] = [
+    0xcf, 0x21, 0xad, 0x74, 0xe5, 0x9a, 0x61, 0x11, 0xbe, 0x1d, 0x8c, 0x02, 0x1e, 0x65, 0xb8, 0x91,
+    0xc2, 0xa2, 0x11, 0x16, 0x7a, 0xbb, 0x8c, 0x5e, 0x07, 0x9e, 0x09, 0xe2, 0xc8, 0xa8, 0x33, 0x9c,
+];
+
+/// Returns `true` for the GREASE values reserved by RFC 8701 (`0x0a0a`,
+/// `0x1a1a`, … `0xfafa`), which clients sprinkle in cipher suites, extension
+/// types, groups and versions to keep servers tolerant of unknown values.
+pub fn is_grease_value(value: u16) -> bool {
+    value & 0x0f0f == 0x0a0a && value >> 8 == value & 0xff
+}
+
+/// Extrait un champ dont la longueur est annoncee par l'emetteur.
+pub fn extract_tls_field<'a>(
+    bytes: &'a [u8],
+    declared: usize,
+    field: &'static str,
+) -> Result<&'a [u8], TlsHandshakeError> {
+    bytes
+        .get(..declared)
+        .ok_or(TlsHandshakeError::TruncatedField {
+            field,
+            declared,
+            available: bytes.len(),
+        })
+}
+
+/// Extracts a TLS vector (RFC 8446 §3.4): a big-endian length on
+/// `length_size` bytes (1 to 3) followed by that many bytes.
+///
+/// Returns the vector content and the bytes that follow it.
+pub fn extract_tls_vector<'a>(
+    bytes: &'a [u8],
+    length_size: usize,
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), TlsHandshakeError> {
+    debug_assert!((1..=3).contains(&length_size), "longueur sur 1 a 3 octets");
+    let prefix = extract_tls_field(bytes, length_size, field)?;
+    let declared = prefix
+        .iter()
+        .fold(0usize, |length, &byte| (length << 8) | byte as usize);
+    let rest = &bytes[length_size..];
+    let content = extract_tls_field(rest, declared, field)?;
+    Ok((content, &rest[declared..]))
+}
+
+/// Verifie qu'un champ a ete entierement consomme.
+pub fn validate_tls_no_trailing_data(
+    rest: &[u8],
+    field: &'static str,
+) -> Result<(), TlsHandshakeError> {
+    if !rest.is_empty() {
+        return Err(TlsHandshakeError::TrailingData {
+            field,
+            remaining: rest.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Reads the handshake header (type u8, length u24) at the start of `buf`.
+pub fn extract_handshake_header(buf: &[u8]) -> Result<(u8, usize), TlsHandshakeError> {
+    let header = extract_tls_field(buf, TLS_HANDSHAKE_HEADER_LEN, "handshake header")?;
+    let length = u32::from_be_bytes([0, header[1], header[2], header[3]]) as usize;
+    Ok((header[0], length))
+}
+
+/// Rejects a handshake message longer than the reassembly limit before any
+/// byte of it is buffered.
+pub fn validate_handshake_length(declared: usize) -> Result<(), TlsHandshakeError> {
+    if declared > TLS_MAX_HANDSHAKE_LEN {
+        return Err(TlsHandshakeError::HandshakeTooLarge {
+            declared,
+            max: TLS_MAX_HANDSHAKE_LEN,
+        });
+    }
+    Ok(())
+}
+
+// Dechiffrement des enregistrements (feature `decrypt`).
+pub const TLS_VERSION_1_2: u16 = 0x0303;
+pub const TLS_VERSION_1_3: u16 = 0x0304;
+
+/// Plafond d'un enregistrement chiffre : 2^14 + 2048 octets en TLS 1.2
+/// (RFC 5246 §6.2.3), TLS 1.3 se limitant a 2^14 + 256.
+pub const TLS_MAX_CIPHERTEXT_LEN: usize = (1 << 14) + 2048;
+
+/// Ports de TLS implicite et port du protocole qu'ils portent en clair :
+/// HTTPS, SMTP submissions, NNTPS, DNS sur TLS et FTPS (controle).
+pub const TLS_IMPLICIT_PORTS: [(u16, u16); 5] =
+    [(443, 80), (465, 25), (563, 119), (853, 53), (990, 21)];
+
+/// Rejects an encrypted record longer than [`TLS_MAX_CIPHERTEXT_LEN`]
+/// before it is buffered.
+pub fn validate_tls_ciphertext_length(length: usize) -> Result<(), TlsDecryptError> {
+    if length > TLS_MAX_CIPHERTEXT_LEN {
+        return Err(TlsDecryptError::RecordTooLarge {
+            length,
+            max: TLS_MAX_CIPHERTEXT_LEN,
+        });
+    }
+    Ok(())
+}
+
+/// Port under which decrypted data is classified: the cleartext port of an
+/// implicit-TLS port, `port` itself otherwise.
+pub fn tls_plaintext_port(port: u16) -> u16 {
+    TLS_IMPLICIT_PORTS
+        .iter()
+        .find(|&&(tls_port, _)| tls_port == port)
+        .map_or(port, |&(_, plaintext_port)| plaintext_port)
+}
+
+// Identifiants DER utilises par X.509 (ITU-T X.690 §8, X.680 §8.6).
+pub const DER_BOOLEAN: u8 = 0x01;
+pub const DER_INTEGER: u8 = 0x02;
+pub const DER_BIT_STRING: u8 = 0x03;
+pub const DER_OCTET_STRING: u8 = 0x04;
+pub const DER_OID: u8 = 0x06;
+pub const DER_UTC_TIME: u8 = 0x17;
+pub const DER_GENERALIZED_TIME: u8 = 0x18;
+pub const DER_SEQUENCE: u8 = 0x30;
+pub const DER_SET: u8 = 0x31;
+
+/// Reads one DER element (ITU-T X.690 §8.1, definite length only) with the
+/// shared BER decoder.
+///
+/// Returns the identifier octet, the content and the bytes that follow. High
+/// tag numbers and the indefinite length form are refused: DER forbids the
+/// latter and X.509 never needs the former.
+pub fn extract_der_element(bytes: &[u8]) -> Result<(u8, &[u8], &[u8]), TlsHandshakeError> {
+    if bytes
+        .first()
+        .is_some_and(|tag| tag & BER_HIGH_TAG == BER_HIGH_TAG)
+    {
+        return Err(TlsHandshakeError::InvalidDer("high tag number form"));
+    }
+    let (element, rest) = extract_ber_element(bytes, "DER element").map_err(|error| {
+        TlsHandshakeError::InvalidDer(match error {
+            BerError::Missing { .. } => "element shorter than its header",
+            BerError::Truncated { .. } => "truncated element",
+            _ => "indefinite length or length on more than 4 octets",
+        })
+    })?;
+    let tag = element
+        .identifier()
+        .ok_or(TlsHandshakeError::InvalidDer("high tag number form"))?;
+    Ok((tag, element.value, rest))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -213,4 +392,98 @@ mod tests {
             }
         ));
     }
+
+    #[test]
+    fn test_is_grease_value() {
+        for value in [0x0a0a, 0x1a1a, 0x2a2a, 0xaaaa, 0xfafa] {
+            assert!(is_grease_value(value), "{value:#06x}");
+        }
+        for value in [0x0000, 0x0a1a, 0x1301, 0xff01, 0x0b0b] {
+            assert!(!is_grease_value(value), "{value:#06x}");
+        }
+    }
+
+    #[test]
+    fn test_extract_tls_vector() {
+        let bytes = [0x00, 0x02, 0xaa, 0xbb, 0xcc];
+        assert_eq!(
+            extract_tls_vector(&bytes, 2, "vector"),
+            Ok((&[0xaa, 0xbb][..], &[0xcc][..]))
+        );
+        assert_eq!(
+            extract_tls_vector(&bytes, 1, "vector"),
+            Ok((&[][..], &bytes[1..]))
+        );
+        assert_eq!(
+            extract_tls_vector(&[0x00, 0x00, 0x05, 0x01], 3, "vector"),
+            Err(TlsHandshakeError::TruncatedField {
+                field: "vector",
+                declared: 5,
+                available: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_validate_tls_ciphertext_length() {
+        assert_eq!(
+            validate_tls_ciphertext_length(TLS_MAX_CIPHERTEXT_LEN),
+            Ok(())
+        );
+        assert_eq!(
+            validate_tls_ciphertext_length(TLS_MAX_CIPHERTEXT_LEN + 1),
+            Err(TlsDecryptError::RecordTooLarge {
+                length: TLS_MAX_CIPHERTEXT_LEN + 1,
+                max: TLS_MAX_CIPHERTEXT_LEN,
+            })
+        );
+    }
+
+    #[test]
+    fn test_tls_plaintext_port() {
+        assert_eq!(tls_plaintext_port(443), 80);
+        assert_eq!(tls_plaintext_port(990), 21);
+        assert_eq!(tls_plaintext_port(8443), 8443);
+    }
+
+    #[test]
+    fn test_validate_handshake_length() {
+        assert!(validate_handshake_length(TLS_MAX_HANDSHAKE_LEN).is_ok());
+        assert_eq!(
+            validate_handshake_length(TLS_MAX_HANDSHAKE_LEN + 1),
+            Err(TlsHandshakeError::HandshakeTooLarge {
+                declared: TLS_MAX_HANDSHAKE_LEN + 1,
+                max: TLS_MAX_HANDSHAKE_LEN
+            })
+        );
+    }
+
+    #[test]
+    fn test_extract_der_element_short_and_long_forms() {
+        assert_eq!(
+            extract_der_element(&[0x02, 0x01, 0x05, 0xff]),
+            Ok((0x02, &[0x05][..], &[0xff][..]))
+        );
+
+        let mut long = vec![0x04, 0x81, 0x80];
+        long.extend_from_slice(&[0x11; 0x80]);
+        let (tag, content, rest) = extract_der_element(&long).unwrap();
+        assert_eq!((tag, content.len(), rest.len()), (0x04, 0x80, 0));
+    }
+
+    #[test]
+    fn test_extract_der_element_rejects_malformed_headers() {
+        assert!(matches!(
+            extract_der_element(&[0x30, 0x80, 0x00, 0x00]),
+            Err(TlsHandshakeError::InvalidDer(_))
+        ));
+        assert!(matches!(
+            extract_der_element(&[0x1f, 0x01, 0x00]),
+            Err(TlsHandshakeError::InvalidDer(_))
+        ));
+        assert!(matches!(
+            extract_der_element(&[0x30, 0x03, 0x00]),
+            Err(TlsHandshakeError::InvalidDer("truncated element"))
+        ));
+    }
 }
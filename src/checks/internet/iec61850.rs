@@ -0,0 +1,159 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use crate::{
+    checks::application::mms::extract_mms_unsigned, errors::internet::iec61850::Iec61850Error,
+};
+
+/// En-tete commun GOOSE / SV : APPID, longueur, deux champs reserves.
+pub const IEC61850_HEADER_LEN: usize = 8;
+
+/// Bit « simulation » du premier champ reserve (IEC 61850-8-1 Ed. 2).
+pub const IEC61850_RESERVED1_SIMULATION: u16 = 0x8000;
+
+/// Etiquettes APPLICATION des PDU : goosePdu [APPLICATION 1], savPdu
+/// [APPLICATION 0].
+pub const IEC61850_GOOSE_PDU_TAG: u32 = 1;
+pub const IEC61850_SV_PDU_TAG: u32 = 0;
+
+/// Champs contextuels du goosePdu.
+pub const GOOSE_GOCB_REF: u32 = 0;
+pub const GOOSE_TIME_ALLOWED_TO_LIVE: u32 = 1;
+pub const GOOSE_DAT_SET: u32 = 2;
+pub const GOOSE_GO_ID: u32 = 3;
+pub const GOOSE_T: u32 = 4;
+pub const GOOSE_ST_NUM: u32 = 5;
+pub const GOOSE_SQ_NUM: u32 = 6;
+pub const GOOSE_SIMULATION: u32 = 7;
+pub const GOOSE_CONF_REV: u32 = 8;
+pub const GOOSE_NDS_COM: u32 = 9;
+pub const GOOSE_NUM_DAT_SET_ENTRIES: u32 = 10;
+pub const GOOSE_ALL_DATA: u32 = 11;
+
+/// Champs contextuels du savPdu et de chaque ASDU.
+pub const SV_NO_ASDU: u32 = 0;
+pub const SV_SEQ_ASDU: u32 = 2;
+pub const SV_ASDU_SV_ID: u32 = 0;
+pub const SV_ASDU_DAT_SET: u32 = 1;
+pub const SV_ASDU_SMP_CNT: u32 = 2;
+pub const SV_ASDU_CONF_REV: u32 = 3;
+pub const SV_ASDU_REFR_TM: u32 = 4;
+pub const SV_ASDU_SMP_SYNCH: u32 = 5;
+pub const SV_ASDU_SMP_RATE: u32 = 6;
+pub const SV_ASDU_SAMPLE: u32 = 7;
+pub const SV_ASDU_SMP_MOD: u32 = 8;
+
+/// Un echantillon IEC 61850-9-2 LE : valeur INT32 puis qualite sur 32 bits.
+pub const SV_SAMPLE_LEN: usize = 8;
+
+/// Verifie l'en-tete et retourne (APPID, longueur, reserve 1, reserve 2)
+/// avec l'APDU. La longueur couvre l'en-tete ; les octets au-dela sont du
+/// bourrage Ethernet.
+pub fn extract_iec61850_header(data: &[u8]) -> Result<([u16; 4], &[u8]), Iec61850Error> {
+    if data.len() < IEC61850_HEADER_LEN {
+        return Err(Iec61850Error::InvalidLength {
+            expected: IEC61850_HEADER_LEN,
+            actual: data.len(),
+        });
+    }
+    let field = |index: usize| u16::from_be_bytes([data[index], data[index + 1]]);
+    let header = [field(0), field(2), field(4), field(6)];
+    let declared = usize::from(header[1]);
+    if declared < IEC61850_HEADER_LEN || declared > data.len() {
+        return Err(Iec61850Error::LengthMismatch {
+            declared: header[1],
+            actual: data.len(),
+        });
+    }
+    Ok((header, &data[IEC61850_HEADER_LEN..declared]))
+}
+
+/// Entier non signe BER sur 16 bits (smpCnt, smpRate, smpMod).
+pub fn extract_iec61850_u16(bytes: &[u8], field: &'static str) -> Result<u16, Iec61850Error> {
+    let value = extract_mms_unsigned(bytes, field)?;
+    u16::try_from(value).map_err(|_| Iec61850Error::InvalidFieldLength {
+        field,
+        length: bytes.len(),
+    })
+}
+
+/// Booleen BER d'un octet.
+pub fn extract_iec61850_boolean(bytes: &[u8], field: &'static str) -> Result<bool, Iec61850Error> {
+    match bytes {
+        [value] => Ok(*value != 0),
+        _ => Err(Iec61850Error::InvalidFieldLength {
+            field,
+            length: bytes.len(),
+        }),
+    }
+}
+
+/// Nature de la trame selon la plage d'APPID recommandee par IEC 61850-8-1
+/// et -9-2.
+pub fn iec61850_appid_kind(appid: u16) -> &'static str {
+    match appid {
+        0x0000..=0x3FFF => "GOOSE",
+        0x4000..=0x7FFF => "SV",
+        _ => "reserved",
+    }
+}
+
+/// Nom du mode d'echantillonnage smpMod.
+pub fn sv_smp_mod_name(smp_mod: u16) -> &'static str {
+    match smp_mod {
+        0 => "samples per nominal period",
+        1 => "samples per second",
+        2 => "seconds per sample",
+        _ => "unknown",
+    }
+}
+
+/// Nom de la synchronisation smpSynch (IEC 61850-9-2 Ed. 2).
+pub fn sv_smp_synch_name(smp_synch: u8) -> &'static str {
+    match smp_synch {
+        0 => "none",
+        1 => "local",
+        2 => "global",
+        5..=254 => "global area clock",
+        _ => "reserved",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_ignores_ethernet_padding() {
+        let data = [
+            0x00, 0x01, 0x00, 0x0A, 0x80, 0x00, 0x00, 0x00, 0x61, 0x00, 0xFF,
+        ];
+        let (header, apdu) = extract_iec61850_header(&data).expect("valid header");
+        assert_eq!(header, [1, 10, IEC61850_RESERVED1_SIMULATION, 0]);
+        assert_eq!(apdu, &[0x61, 0x00]);
+        assert_eq!(
+            extract_iec61850_header(&data[..9]),
+            Err(Iec61850Error::LengthMismatch {
+                declared: 10,
+                actual: 9
+            })
+        );
+    }
+
+    #[test]
+    fn names_and_scalars() {
+        assert_eq!(iec61850_appid_kind(0x4000), "SV");
+        assert_eq!(sv_smp_synch_name(2), "global");
+        assert_eq!(sv_smp_mod_name(1), "samples per second");
+        assert_eq!(extract_iec61850_u16(&[0x0F, 0xA0], "smpCnt"), Ok(4000));
+        assert_eq!(
+            extract_iec61850_boolean(&[], "ndsCom"),
+            Err(Iec61850Error::InvalidFieldLength {
+                field: "ndsCom",
+                length: 0
+            })
+        );
+    }
+}
@@ -0,0 +1,49 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Controles pour l'Authentication Header (RFC 4302).
+
+use crate::errors::transport::ah::AhError;
+
+/// Next header, payload len, reserve, SPI et numero de sequence.
+pub const AH_FIXED_HEADER_LENGTH: usize = 12;
+
+/// Longueur totale de l'en-tete AH : le champ Payload Len compte des mots de
+/// 32 bits, moins 2 (RFC 4302 §2.2). Une valeur annoncant moins que la
+/// partie fixe est refusee.
+pub fn extract_ah_header_length(payload_length: u8) -> Result<usize, AhError> {
+    let length = (usize::from(payload_length) + 2) * 4;
+    if length < AH_FIXED_HEADER_LENGTH {
+        return Err(AhError::InvalidPayloadLength(payload_length));
+    }
+    Ok(length)
+}
+
+/// Verifie que `payload` contient `expected` octets.
+pub fn validate_ah_length(payload: &[u8], expected: usize) -> Result<(), AhError> {
+    if payload.len() < expected {
+        return Err(AhError::InvalidLength {
+            expected,
+            actual: payload.len(),
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn header_length_counts_32_bit_words_minus_two() {
+        // HMAC-SHA1-96 : 12 octets fixes + 12 d'ICV = 24 octets, soit 4.
+        assert_eq!(extract_ah_header_length(4), Ok(24));
+        assert_eq!(extract_ah_header_length(1), Ok(12));
+        assert_eq!(
+            extract_ah_header_length(0),
+            Err(AhError::InvalidPayloadLength(0))
+        );
+    }
+}
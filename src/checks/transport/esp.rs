@@ -0,0 +1,100 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Controles pour ESP (RFC 4303).
+//!
+//! Sans association de securite, seuls le SPI et le numero de sequence sont
+//! lisibles : IV, padding, next header et ICV sont dans la partie chiffree
+//! ou dependent de l'algorithme negocie.
+
+use crate::errors::transport::esp::EspError;
+
+/// SPI et numero de sequence, les deux seuls champs en clair.
+pub const ESP_HEADER_LENGTH: usize = 8;
+
+/// Pad length et next header, en fin de texte clair (RFC 4303 §2.4).
+pub const ESP_TRAILER_LENGTH: usize = 2;
+
+/// Verifie qu'il reste de quoi lire l'en-tete en clair.
+pub fn validate_esp_length(payload: &[u8]) -> Result<(), EspError> {
+    if payload.len() < ESP_HEADER_LENGTH {
+        return Err(EspError::InvalidLength {
+            expected: ESP_HEADER_LENGTH,
+            actual: payload.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Refuse le SPI 0, reserve a un usage local et jamais emis (RFC 4303 §2.1).
+pub fn validate_esp_spi(spi: u32) -> Result<(), EspError> {
+    if spi == 0 {
+        return Err(EspError::ReservedSpi);
+    }
+    Ok(())
+}
+
+/// Retire le trailer d'un texte clair dechiffre et retourne `(donnees, pad
+/// length, next header)`. Le padding par defaut est la suite 1, 2, 3...
+/// (RFC 4303 §2.4) : toute autre valeur trahit une mauvaise cle ou un
+/// mauvais algorithme, et est refusee plutot que livree au parseur.
+pub fn extract_esp_trailer(plaintext: &[u8]) -> Result<(&[u8], u8, u8), EspError> {
+    let invalid = |pad_length| EspError::InvalidPadding {
+        pad_length,
+        available: plaintext.len(),
+    };
+    let [.., pad_length, next_header] = *plaintext else {
+        return Err(invalid(0));
+    };
+    let data_end = plaintext
+        .len()
+        .checked_sub(ESP_TRAILER_LENGTH + usize::from(pad_length))
+        .ok_or(invalid(pad_length))?;
+    let padding = &plaintext[data_end..plaintext.len() - ESP_TRAILER_LENGTH];
+    if padding
+        .iter()
+        .zip(1u8..)
+        .any(|(&byte, expected)| byte != expected)
+    {
+        return Err(invalid(pad_length));
+    }
+    Ok((&plaintext[..data_end], pad_length, next_header))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trailer_strips_monotonic_padding() {
+        let plaintext = [0xAA, 0xBB, 0x01, 0x02, 0x02, 0x04];
+
+        assert_eq!(extract_esp_trailer(&plaintext), Ok((&plaintext[..2], 2, 4)));
+    }
+
+    #[test]
+    fn trailer_rejects_non_monotonic_padding() {
+        let plaintext = [0xAA, 0x07, 0x07, 0x02, 0x04];
+
+        assert_eq!(
+            extract_esp_trailer(&plaintext),
+            Err(EspError::InvalidPadding {
+                pad_length: 2,
+                available: 5
+            })
+        );
+    }
+
+    #[test]
+    fn trailer_rejects_pad_length_beyond_plaintext() {
+        assert_eq!(
+            extract_esp_trailer(&[0x01, 0x09, 0x04]),
+            Err(EspError::InvalidPadding {
+                pad_length: 9,
+                available: 3
+            })
+        );
+    }
+}
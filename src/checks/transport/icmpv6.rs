@@ -80,6 +80,89 @@ pub fn validate_icmpv6_neighbor_length(payload: &[u8]) -> Result<(), Icmpv6Error
     Ok(())
 }
 
+/// Unite de longueur des options de decouverte de voisins (RFC 4861 §4.6) :
+/// le champ Length compte des blocs de 8 octets, en-tete d'option inclus.
+pub const ND_OPTION_UNIT: usize = 8;
+
+/// Type et longueur, en tete de chaque option.
+pub const ND_OPTION_HEADER_LENGTH: usize = 2;
+
+/// Prefix Information (RFC 4861 §4.6.2) : toujours 4 unites.
+pub const ND_PREFIX_INFORMATION_LENGTH: usize = 32;
+
+/// MTU (RFC 4861 §4.6.4) : toujours 1 unite.
+pub const ND_MTU_LENGTH: usize = 8;
+
+/// Route Information (RFC 4191 §2.3) : 1 a 3 unites selon la longueur du
+/// prefixe.
+pub const ND_ROUTE_INFORMATION_MAX_LENGTH: usize = 24;
+
+/// RDNSS (RFC 8106 §5.1) : en-tete de 8 octets puis au moins une adresse.
+pub const ND_RDNSS_MIN_LENGTH: usize = 24;
+
+/// En-tete fixe des options RDNSS et DNSSL : type, longueur, reserve et
+/// duree de vie.
+pub const ND_DNS_OPTION_HEADER_LENGTH: usize = 8;
+
+/// Decoupe l'option qui commence a `offset` et la renvoie en entier, en-tete
+/// compris. Une longueur nulle est refusee (RFC 4861 §4.6 : le message doit
+/// etre ignore), sans quoi l'iteration ne progresserait plus.
+pub fn extract_nd_option(options: &[u8], offset: usize) -> Result<(u8, &[u8]), Icmpv6Error> {
+    let rest = options.get(offset..).unwrap_or_default();
+    if rest.len() < ND_OPTION_HEADER_LENGTH {
+        return Err(Icmpv6Error::TruncatedOption {
+            option_type: rest.first().copied().unwrap_or_default(),
+            expected: ND_OPTION_HEADER_LENGTH,
+            actual: rest.len(),
+        });
+    }
+    let option_type = rest[0];
+    let length = usize::from(rest[1]) * ND_OPTION_UNIT;
+    if length == 0 {
+        return Err(Icmpv6Error::ZeroLengthOption {
+            option_type,
+            offset,
+        });
+    }
+    let option = rest.get(..length).ok_or(Icmpv6Error::TruncatedOption {
+        option_type,
+        expected: length,
+        actual: rest.len(),
+    })?;
+    Ok((option_type, option))
+}
+
+/// Verifie qu'une option a taille fixe a la taille prevue par sa RFC.
+pub fn validate_nd_option_length(
+    option_type: u8,
+    option: &[u8],
+    expected: usize,
+) -> Result<(), Icmpv6Error> {
+    if option.len() != expected {
+        return Err(Icmpv6Error::InvalidOptionLength {
+            option_type,
+            length: option.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Verifie qu'une option a taille variable tient entre `min` et `max`.
+pub fn validate_nd_option_range(
+    option_type: u8,
+    option: &[u8],
+    min: usize,
+    max: usize,
+) -> Result<(), Icmpv6Error> {
+    if option.len() < min || option.len() > max {
+        return Err(Icmpv6Error::InvalidOptionLength {
+            option_type,
+            length: option.len(),
+        });
+    }
+    Ok(())
+}
+
 /// Verifie qu'une Router Solicitation porte au moins ses octets reserves.
 pub fn validate_icmpv6_router_solicitation_length(payload: &[u8]) -> Result<(), Icmpv6Error> {
     if payload.len() < ICMPV6_ROUTER_SOLICITATION_HEADER_LENGTH {
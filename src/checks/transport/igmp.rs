@@ -0,0 +1,177 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Controles de taille et de coherence pour IGMP (RFC 1112, RFC 2236,
+//! RFC 3376).
+//!
+//! Les trois versions partagent le type 0x11 (Membership Query) : seule la
+//! longueur du message les distingue (RFC 3376 §7.1). Ces fonctions portent
+//! cette regle, le parseur se contente de les enchainer.
+
+use crate::errors::transport::igmp::IgmpError;
+
+/// Type, max response code, checksum et adresse de groupe : la forme
+/// IGMPv1/v2 complete, et le prefixe commun des messages IGMPv3.
+pub const IGMP_HEADER_LENGTH: usize = 8;
+
+/// Query IGMPv3 sans source : en-tete commun + S/QRV, QQIC et nombre de
+/// sources (RFC 3376 §4.1).
+pub const IGMPV3_QUERY_HEADER_LENGTH: usize = 12;
+
+/// Report IGMPv3 : type, reserve, checksum, reserve et nombre de records
+/// (RFC 3376 §4.2).
+pub const IGMPV3_REPORT_HEADER_LENGTH: usize = 8;
+
+/// Record de groupe IGMPv3 sans source ni donnee auxiliaire : type, aux data
+/// len, nombre de sources et adresse multicast.
+pub const IGMPV3_GROUP_RECORD_HEADER_LENGTH: usize = 8;
+
+/// Taille d'une adresse source IPv4 dans les listes IGMPv3.
+pub const IGMP_SOURCE_ADDRESS_LENGTH: usize = 4;
+
+/// Version de Membership Query deduite de la longueur (RFC 3376 §7.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IgmpQueryVersion {
+    /// 8 octets, max response code nul.
+    V1,
+    /// 8 octets, max response code non nul.
+    V2,
+    /// 12 octets ou plus.
+    V3,
+}
+
+/// Verifie qu'il reste de quoi lire l'en-tete commun.
+pub fn validate_igmp_min_length(payload: &[u8]) -> Result<(), IgmpError> {
+    if payload.len() < IGMP_HEADER_LENGTH {
+        return Err(IgmpError::InvalidLength {
+            expected: IGMP_HEADER_LENGTH,
+            actual: payload.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Deduit la version d'une Membership Query. Une longueur de 9 a 11 octets
+/// ne correspond a aucune version : RFC 3376 §7.1 impose d'ignorer le
+/// message plutot que de le deviner.
+pub fn extract_igmp_query_version(
+    payload: &[u8],
+    max_response_code: u8,
+) -> Result<IgmpQueryVersion, IgmpError> {
+    match payload.len() {
+        IGMP_HEADER_LENGTH if max_response_code == 0 => Ok(IgmpQueryVersion::V1),
+        IGMP_HEADER_LENGTH => Ok(IgmpQueryVersion::V2),
+        len if len >= IGMPV3_QUERY_HEADER_LENGTH => Ok(IgmpQueryVersion::V3),
+        len => Err(IgmpError::InvalidQueryLength { actual: len }),
+    }
+}
+
+/// Verifie qu'un Report IGMPv3 porte son en-tete complet.
+pub fn validate_igmpv3_report_length(payload: &[u8]) -> Result<(), IgmpError> {
+    if payload.len() < IGMPV3_REPORT_HEADER_LENGTH {
+        return Err(IgmpError::InvalidLength {
+            expected: IGMPV3_REPORT_HEADER_LENGTH,
+            actual: payload.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Extrait la liste de sources annoncee, en verifiant que les octets restants
+/// la contiennent entierement.
+pub fn extract_igmp_source_list(bytes: &[u8], declared: u16) -> Result<&[u8], IgmpError> {
+    let length = usize::from(declared) * IGMP_SOURCE_ADDRESS_LENGTH;
+    bytes.get(..length).ok_or(IgmpError::TruncatedSourceList {
+        declared,
+        available: bytes.len(),
+    })
+}
+
+/// Verifie qu'un record de groupe, sources et donnees auxiliaires comprises,
+/// tient dans les octets restants, et retourne sa longueur totale.
+pub fn extract_igmpv3_group_record_length(record: &[u8], index: usize) -> Result<usize, IgmpError> {
+    if record.len() < IGMPV3_GROUP_RECORD_HEADER_LENGTH {
+        return Err(IgmpError::TruncatedGroupRecord {
+            index,
+            expected: IGMPV3_GROUP_RECORD_HEADER_LENGTH,
+            actual: record.len(),
+        });
+    }
+    // Aux data len est exprime en mots de 32 bits (RFC 3376 §4.2.6).
+    let aux_data_length = usize::from(record[1]) * 4;
+    let sources_length =
+        usize::from(u16::from_be_bytes([record[2], record[3]])) * IGMP_SOURCE_ADDRESS_LENGTH;
+    let expected = IGMPV3_GROUP_RECORD_HEADER_LENGTH + sources_length + aux_data_length;
+    if record.len() < expected {
+        return Err(IgmpError::TruncatedGroupRecord {
+            index,
+            expected,
+            actual: record.len(),
+        });
+    }
+    Ok(expected)
+}
+
+/// Decode le Max Resp Code IGMPv3 en dixiemes de seconde (RFC 3376 §4.1.1) :
+/// valeur directe sous 128, sinon flottant a exposant sur 3 bits et mantisse
+/// sur 4 bits. Le meme encodage sert a QQIC, en secondes.
+pub fn decode_igmpv3_exponential_code(code: u8) -> u16 {
+    if code < 0x80 {
+        return u16::from(code);
+    }
+    let exponent = (code >> 4) & 0x07;
+    let mantissa = u16::from(code & 0x0F);
+    (mantissa | 0x10) << (exponent + 3)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_version_follows_rfc_3376_length_rules() {
+        assert_eq!(
+            extract_igmp_query_version(&[0; 8], 0),
+            Ok(IgmpQueryVersion::V1)
+        );
+        assert_eq!(
+            extract_igmp_query_version(&[0; 8], 100),
+            Ok(IgmpQueryVersion::V2)
+        );
+        assert_eq!(
+            extract_igmp_query_version(&[0; 12], 100),
+            Ok(IgmpQueryVersion::V3)
+        );
+        assert_eq!(
+            extract_igmp_query_version(&[0; 10], 100),
+            Err(IgmpError::InvalidQueryLength { actual: 10 })
+        );
+    }
+
+    #[test]
+    fn exponential_code_matches_rfc_3376_examples() {
+        // Sous 128 : valeur directe.
+        assert_eq!(decode_igmpv3_exponential_code(100), 100);
+        // 0x80 : exposant 0, mantisse 0 -> 0x10 << 3 = 128.
+        assert_eq!(decode_igmpv3_exponential_code(0x80), 128);
+        // Valeur maximale : (0x0F | 0x10) << (7 + 3).
+        assert_eq!(decode_igmpv3_exponential_code(0xFF), 31_744);
+    }
+
+    /// Synthetique : record annoncant deux sources mais n'en portant qu'une.
+    #[test]
+    fn group_record_length_rejects_truncated_sources() {
+        let record = [0x04, 0x00, 0x00, 0x02, 0xe0, 0, 0, 0xfc, 10, 0, 0, 1];
+
+        assert_eq!(
+            extract_igmpv3_group_record_length(&record, 0),
+            Err(IgmpError::TruncatedGroupRecord {
+                index: 0,
+                expected: 16,
+                actual: 12
+            })
+        );
+    }
+}
@@ -3,7 +3,13 @@
 // Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
 // This file may not be copied, modified, or distributed except according to those terms.
 
+pub mod ah;
+pub mod esp;
 pub mod icmp;
 pub mod icmpv6;
+pub mod igmp;
+pub mod ospf;
+pub mod pim;
 pub mod tcp;
 pub mod udp;
+pub mod vrrp;
@@ -0,0 +1,154 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Controles de taille et de coherence pour OSPFv2 (RFC 2328) et OSPFv3
+//! (RFC 5340).
+//!
+//! Le champ Packet Length de l'en-tete borne le message : en OSPFv2 avec
+//! authentification cryptographique, le condensat est ajoute *apres* cette
+//! longueur (RFC 2328 §D.4.3) et ne doit pas etre lu comme du contenu.
+
+use crate::errors::transport::ospf::OspfError;
+
+/// En-tete OSPFv2 : version, type, longueur, router id, area id, checksum,
+/// AuType et 8 octets d'authentification.
+pub const OSPFV2_HEADER_LENGTH: usize = 24;
+
+/// En-tete OSPFv3 : l'authentification disparait au profit d'IPsec, un
+/// instance id et un octet reserve la remplacent.
+pub const OSPFV3_HEADER_LENGTH: usize = 16;
+
+/// Partie fixe d'un Hello, avant la liste des voisins (meme taille dans les
+/// deux versions, disposition differente).
+pub const OSPF_HELLO_LENGTH: usize = 20;
+
+/// Partie fixe d'un Database Description OSPFv2, avant les en-tetes d'LSA.
+pub const OSPFV2_DATABASE_DESCRIPTION_LENGTH: usize = 8;
+
+/// Partie fixe d'un Database Description OSPFv3 (options sur 24 bits).
+pub const OSPFV3_DATABASE_DESCRIPTION_LENGTH: usize = 12;
+
+/// En-tete d'LSA, commun aux Database Description, LSU et LSAck.
+pub const OSPF_LSA_HEADER_LENGTH: usize = 20;
+
+/// Entree d'un Link State Request : type, link state id, routeur annonceur.
+pub const OSPF_LS_REQUEST_LENGTH: usize = 12;
+
+/// Taille d'un router id, area id ou voisin.
+pub const OSPF_ROUTER_ID_LENGTH: usize = 4;
+
+/// Verifie qu'il reste de quoi lire le prefixe commun aux deux versions
+/// (version, type, longueur) puis l'en-tete complet de la version lue.
+pub fn validate_ospf_header_length(payload: &[u8]) -> Result<usize, OspfError> {
+    if payload.is_empty() {
+        return Err(OspfError::InvalidLength {
+            expected: OSPFV3_HEADER_LENGTH,
+            actual: 0,
+        });
+    }
+    let header_length = match payload[0] {
+        2 => OSPFV2_HEADER_LENGTH,
+        3 => OSPFV3_HEADER_LENGTH,
+        version => return Err(OspfError::UnsupportedVersion(version)),
+    };
+    if payload.len() < header_length {
+        return Err(OspfError::InvalidLength {
+            expected: header_length,
+            actual: payload.len(),
+        });
+    }
+    Ok(header_length)
+}
+
+/// Retourne le message borne par son champ Packet Length, qui doit couvrir
+/// au moins l'en-tete et tenir dans les octets recus.
+pub fn extract_ospf_packet(payload: &[u8], header_length: usize) -> Result<&[u8], OspfError> {
+    let declared = usize::from(u16::from_be_bytes([payload[2], payload[3]]));
+    if declared < header_length || declared > payload.len() {
+        return Err(OspfError::InvalidPacketLength {
+            declared,
+            minimum: header_length,
+            available: payload.len(),
+        });
+    }
+    Ok(&payload[..declared])
+}
+
+/// Verifie qu'un corps de message contient sa partie fixe.
+pub fn validate_ospf_body_length(body: &[u8], expected: usize) -> Result<(), OspfError> {
+    if body.len() < expected {
+        return Err(OspfError::InvalidLength {
+            expected,
+            actual: body.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Verifie qu'une LSA complete, annoncee par le champ Length de son en-tete,
+/// tient dans les octets restants, et retourne cette longueur.
+pub fn extract_ospf_lsa_length(lsa: &[u8], index: usize) -> Result<usize, OspfError> {
+    if lsa.len() < OSPF_LSA_HEADER_LENGTH {
+        return Err(OspfError::TruncatedLsa {
+            index,
+            expected: OSPF_LSA_HEADER_LENGTH,
+            actual: lsa.len(),
+        });
+    }
+    let length = usize::from(u16::from_be_bytes([lsa[18], lsa[19]]));
+    if length < OSPF_LSA_HEADER_LENGTH {
+        return Err(OspfError::InvalidLsaLength { index, length });
+    }
+    if lsa.len() < length {
+        return Err(OspfError::TruncatedLsa {
+            index,
+            expected: length,
+            actual: lsa.len(),
+        });
+    }
+    Ok(length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Synthetique : condensat MD5 ajoute apres la longueur declaree.
+    #[test]
+    fn packet_length_excludes_the_cryptographic_trailer() {
+        let mut payload = vec![0u8; 24 + 16];
+        payload[0] = 2;
+        payload[3] = 24;
+
+        assert_eq!(
+            extract_ospf_packet(&payload, OSPFV2_HEADER_LENGTH).map(<[u8]>::len),
+            Ok(24)
+        );
+        payload[3] = 12;
+        assert_eq!(
+            extract_ospf_packet(&payload, OSPFV2_HEADER_LENGTH),
+            Err(OspfError::InvalidPacketLength {
+                declared: 12,
+                minimum: 24,
+                available: 40
+            })
+        );
+    }
+
+    /// Synthetique : LSA dont le champ Length est plus court que l'en-tete.
+    #[test]
+    fn lsa_length_must_cover_its_header() {
+        let mut lsa = [0u8; 20];
+        lsa[19] = 8;
+
+        assert_eq!(
+            extract_ospf_lsa_length(&lsa, 2),
+            Err(OspfError::InvalidLsaLength {
+                index: 2,
+                length: 8
+            })
+        );
+    }
+}
@@ -0,0 +1,217 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Controles et extractions pour PIMv2 (RFC 7761, bootstrap RFC 5059).
+//!
+//! Presque tous les messages PIM sont faits d'adresses encodees (unicast,
+//! groupe, source) dont la taille depend de la famille : 4 octets d'adresse
+//! en IPv4, 16 en IPv6. Chaque extraction verifie la famille, le type
+//! d'encodage et la longueur avant de lire, et retourne l'offset suivant.
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    errors::transport::pim::PimError,
+    parse::transport::protocols::pim::{PimEncodedGroup, PimEncodedSource},
+};
+
+/// Version/type, reserve et checksum.
+pub const PIM_HEADER_LENGTH: usize = 4;
+
+/// Seule version decodee : PIMv1 circule dans IGMP (type 0x14), pas ici.
+pub const PIM_VERSION: u8 = 2;
+
+/// Familles d'adresses IANA utilisees par PIM.
+const ADDRESS_FAMILY_IPV4: u8 = 1;
+const ADDRESS_FAMILY_IPV6: u8 = 2;
+
+/// Encodage natif de la famille, le seul defini par RFC 7761 §4.9.1.
+const NATIVE_ENCODING: u8 = 0;
+
+/// Verifie que `bytes` porte au moins `expected` octets.
+pub fn validate_pim_length(bytes: &[u8], expected: usize) -> Result<(), PimError> {
+    if bytes.len() < expected {
+        return Err(PimError::InvalidLength {
+            expected,
+            actual: bytes.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Extrait la version (quartet de poids fort de l'octet 0) et refuse tout
+/// ce qui n'est pas PIMv2.
+pub fn extract_pim_version(byte: u8) -> Result<u8, PimError> {
+    let version = byte >> 4;
+    if version != PIM_VERSION {
+        return Err(PimError::UnsupportedVersion(version));
+    }
+    Ok(version)
+}
+
+/// Lit l'adresse d'une famille donnee a `offset` ; retourne l'adresse et
+/// l'offset suivant.
+fn extract_family_address(
+    bytes: &[u8],
+    family: u8,
+    offset: usize,
+) -> Result<(IpAddr, usize), PimError> {
+    let truncated = PimError::TruncatedEncodedAddress { offset };
+    match family {
+        ADDRESS_FAMILY_IPV4 => {
+            let octets: [u8; 4] = bytes
+                .get(offset..offset + 4)
+                .and_then(|slice| slice.try_into().ok())
+                .ok_or(truncated)?;
+            Ok((IpAddr::V4(Ipv4Addr::from(octets)), offset + 4))
+        }
+        ADDRESS_FAMILY_IPV6 => {
+            let octets: [u8; 16] = bytes
+                .get(offset..offset + 16)
+                .and_then(|slice| slice.try_into().ok())
+                .ok_or(truncated)?;
+            Ok((IpAddr::V6(Ipv6Addr::from(octets)), offset + 16))
+        }
+        family => Err(PimError::UnsupportedAddressFamily { family, offset }),
+    }
+}
+
+/// Lit la famille et le type d'encodage qui ouvrent toute adresse encodee.
+fn extract_family_and_encoding(bytes: &[u8], offset: usize) -> Result<u8, PimError> {
+    let header = bytes
+        .get(offset..offset + 2)
+        .ok_or(PimError::TruncatedEncodedAddress { offset })?;
+    if header[1] != NATIVE_ENCODING {
+        return Err(PimError::UnsupportedEncodingType {
+            encoding: header[1],
+            offset,
+        });
+    }
+    Ok(header[0])
+}
+
+/// Encoded-Unicast address (RFC 7761 §4.9.1) : famille, encodage, adresse.
+pub fn extract_encoded_unicast(bytes: &[u8], offset: usize) -> Result<(IpAddr, usize), PimError> {
+    let family = extract_family_and_encoding(bytes, offset)?;
+    extract_family_address(bytes, family, offset + 2)
+}
+
+/// Encoded-Group address : famille, encodage, drapeaux B/Z, longueur de
+/// masque, adresse.
+pub fn extract_encoded_group(
+    bytes: &[u8],
+    offset: usize,
+) -> Result<(PimEncodedGroup, usize), PimError> {
+    let family = extract_family_and_encoding(bytes, offset)?;
+    let fields = bytes
+        .get(offset + 2..offset + 4)
+        .ok_or(PimError::TruncatedEncodedAddress { offset })?;
+    let (flags, mask_length) = (fields[0], fields[1]);
+    let (address, next) = extract_family_address(bytes, family, offset + 4)?;
+    Ok((
+        PimEncodedGroup {
+            address,
+            mask_length,
+            bidirectional: flags & 0x80 != 0,
+            admin_scope_zone: flags & 0x01 != 0,
+        },
+        next,
+    ))
+}
+
+/// Encoded-Source address : famille, encodage, drapeaux S/W/R, longueur de
+/// masque, adresse.
+pub fn extract_encoded_source(
+    bytes: &[u8],
+    offset: usize,
+) -> Result<(PimEncodedSource, usize), PimError> {
+    let family = extract_family_and_encoding(bytes, offset)?;
+    let fields = bytes
+        .get(offset + 2..offset + 4)
+        .ok_or(PimError::TruncatedEncodedAddress { offset })?;
+    let (flags, mask_length) = (fields[0], fields[1]);
+    let (address, next) = extract_family_address(bytes, family, offset + 4)?;
+    Ok((
+        PimEncodedSource {
+            address,
+            mask_length,
+            sparse: flags & 0x04 != 0,
+            wildcard: flags & 0x02 != 0,
+            rendezvous_point_tree: flags & 0x01 != 0,
+        },
+        next,
+    ))
+}
+
+/// Extrait la valeur d'une option Hello (TLV type u16, longueur u16) a
+/// `offset` ; retourne type, valeur et offset suivant.
+pub fn extract_pim_hello_option(
+    bytes: &[u8],
+    offset: usize,
+) -> Result<(u16, &[u8], usize), PimError> {
+    validate_pim_length(bytes, offset + 4)?;
+    let option_type = u16::from_be_bytes([bytes[offset], bytes[offset + 1]]);
+    let declared = usize::from(u16::from_be_bytes([bytes[offset + 2], bytes[offset + 3]]));
+    let start = offset + 4;
+    let value = bytes
+        .get(start..start + declared)
+        .ok_or(PimError::TruncatedHelloOption {
+            option_type,
+            declared,
+            available: bytes.len() - start,
+        })?;
+    Ok((option_type, value, start + declared))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_pim_version_2_is_accepted() {
+        assert_eq!(extract_pim_version(0x20), Ok(2));
+        assert_eq!(
+            extract_pim_version(0x10),
+            Err(PimError::UnsupportedVersion(1))
+        );
+    }
+
+    /// Synthetique : Encoded-Group IPv4 224.0.0.0/4.
+    #[test]
+    fn encoded_group_reads_ipv4_prefix() {
+        let bytes = [0x01, 0x00, 0x00, 0x04, 224, 0, 0, 0];
+        let (group, next) = extract_encoded_group(&bytes, 0).expect("valid group");
+
+        assert_eq!(group.address, IpAddr::V4(Ipv4Addr::new(224, 0, 0, 0)));
+        assert_eq!(group.mask_length, 4);
+        assert!(!group.bidirectional);
+        assert_eq!(next, 8);
+    }
+
+    /// Synthetique : famille 3 inconnue.
+    #[test]
+    fn encoded_unicast_rejects_unknown_family() {
+        let bytes = [0x03, 0x00, 10, 0, 0, 1];
+
+        assert_eq!(
+            extract_encoded_unicast(&bytes, 0),
+            Err(PimError::UnsupportedAddressFamily {
+                family: 3,
+                offset: 2
+            })
+        );
+    }
+
+    /// Synthetique : adresse IPv6 annoncee, 4 octets presents.
+    #[test]
+    fn encoded_unicast_rejects_truncated_ipv6() {
+        let bytes = [0x02, 0x00, 0xfe, 0x80, 0, 0];
+
+        assert_eq!(
+            extract_encoded_unicast(&bytes, 0),
+            Err(PimError::TruncatedEncodedAddress { offset: 2 })
+        );
+    }
+}
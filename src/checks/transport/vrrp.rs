@@ -0,0 +1,107 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Controles de taille et de coherence pour VRRP (RFC 3768, RFC 5798).
+//!
+//! VRRPv3 transporte des adresses IPv4 ou IPv6 selon la version IP du
+//! paquet englobant, que la couche transport ne voit pas : la famille est
+//! deduite de la place restante apres l'en-tete, seule information portee
+//! par le message lui-meme.
+
+use crate::{
+    errors::transport::vrrp::VrrpError, parse::transport::protocols::vrrp::VrrpAddressFamily,
+};
+
+/// Version/type, VRID, priorite, nombre d'adresses, puis auth type et
+/// adver int (v2) ou max adver int (v3), et checksum.
+pub const VRRP_HEADER_LENGTH: usize = 8;
+
+/// Seule version IP d'un VRRPv2 : adresses IPv4 de 4 octets.
+pub const VRRP_IPV4_ADDRESS_LENGTH: usize = 4;
+
+/// Adresses IPv6 d'un VRRPv3 transporte sur IPv6.
+pub const VRRP_IPV6_ADDRESS_LENGTH: usize = 16;
+
+/// Donnees d'authentification d'un VRRPv2, apres les adresses (RFC 3768
+/// §5.3.10).
+pub const VRRP_V2_AUTHENTICATION_DATA_LENGTH: usize = 8;
+
+/// Verifie qu'il reste de quoi lire l'en-tete commun aux deux versions.
+pub fn validate_vrrp_min_length(payload: &[u8]) -> Result<(), VrrpError> {
+    if payload.len() < VRRP_HEADER_LENGTH {
+        return Err(VrrpError::InvalidLength {
+            expected: VRRP_HEADER_LENGTH,
+            actual: payload.len(),
+        });
+    }
+    Ok(())
+}
+
+/// Extrait la version des 4 bits hauts du premier octet : 2 ou 3.
+pub fn extract_vrrp_version(version_and_type: u8) -> Result<u8, VrrpError> {
+    match version_and_type >> 4 {
+        version @ (2 | 3) => Ok(version),
+        version => Err(VrrpError::UnsupportedVersion(version)),
+    }
+}
+
+/// Deduit la famille des adresses annoncees. VRRPv2 est IPv4 seulement.
+/// En v3, une taille exacte de `count` adresses IPv4 l'emporte (un VRRPv3
+/// IPv4 n'a pas de queue), puis IPv6 si les octets restants le permettent.
+pub fn extract_vrrp_address_family(
+    version: u8,
+    count: u8,
+    addresses: &[u8],
+) -> Result<VrrpAddressFamily, VrrpError> {
+    let ipv4_length = usize::from(count) * VRRP_IPV4_ADDRESS_LENGTH;
+    let ipv6_length = usize::from(count) * VRRP_IPV6_ADDRESS_LENGTH;
+    let family = if version == 2 || addresses.len() == ipv4_length {
+        VrrpAddressFamily::Ipv4
+    } else if addresses.len() >= ipv6_length {
+        VrrpAddressFamily::Ipv6
+    } else {
+        VrrpAddressFamily::Ipv4
+    };
+    let expected = match family {
+        VrrpAddressFamily::Ipv4 => ipv4_length,
+        VrrpAddressFamily::Ipv6 => ipv6_length,
+    };
+    if addresses.len() < expected {
+        return Err(VrrpError::TruncatedAddresses {
+            count,
+            available: addresses.len(),
+        });
+    }
+    Ok(family)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn version_three_address_family_follows_remaining_length() {
+        assert_eq!(
+            extract_vrrp_address_family(3, 2, &[0; 8]),
+            Ok(VrrpAddressFamily::Ipv4)
+        );
+        assert_eq!(
+            extract_vrrp_address_family(3, 2, &[0; 32]),
+            Ok(VrrpAddressFamily::Ipv6)
+        );
+        // v2 reste IPv4 meme quand la queue d'authentification est longue.
+        assert_eq!(
+            extract_vrrp_address_family(2, 1, &[0; 16]),
+            Ok(VrrpAddressFamily::Ipv4)
+        );
+        assert_eq!(
+            extract_vrrp_address_family(3, 3, &[0; 10]),
+            Err(VrrpError::TruncatedAddresses {
+                count: 3,
+                available: 10
+            })
+        );
+    }
+}
@@ -0,0 +1,406 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Dechiffrement ESP (RFC 4303) depuis une table d'associations de securite
+//! fournie par l'appelant (SPI -> algorithme, cle), typiquement extraite de
+//! `ip xfrm state` ou de la configuration d'un concentrateur VPN.
+//!
+//! Algorithmes : AES-GCM a ICV de 16 octets (RFC 4106) et AES-CBC
+//! (RFC 3602). L'integrite d'AES-GCM est verifiee par construction ; l'ICV
+//! HMAC qui accompagne AES-CBC est retire sans etre verifie, la table ne
+//! portant que la cle de chiffrement. Les numeros de sequence etendus (ESN)
+//! ne sont pas geres.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use aes::{Aes128, Aes192, Aes256};
+use aes_gcm::{
+    AesGcm,
+    aead::{Aead, KeyInit, Payload, consts::U12},
+};
+use cbc::cipher::{BlockDecryptMut, KeyIvInit, block_padding::NoPadding};
+
+use crate::{
+    LinkType, PacketFlow, Transport, TransportDetails,
+    checks::transport::esp::{ESP_HEADER_LENGTH, extract_esp_trailer},
+    errors::transport::esp::EspError,
+    parse::transport::protocols::{TransportProtocol, esp::EspPacket},
+};
+
+/// IV explicite d'AES-GCM dans ESP (RFC 4106 §3.1).
+const GCM_IV_LENGTH: usize = 8;
+/// Seul ICV AES-GCM gere : ENCR_AES_GCM_16, le choix par defaut d'IKEv2.
+const GCM_ICV_LENGTH: usize = 16;
+/// IV d'AES-CBC : un bloc (RFC 3602 §3).
+const CBC_IV_LENGTH: usize = 16;
+const AES_BLOCK_LENGTH: usize = 16;
+
+/// Next header des modes tunnel : paquet IP complet dans le texte clair.
+const IPV4_IN_ESP: u8 = 4;
+const IPV6_IN_ESP: u8 = 41;
+/// Paquet factice (RFC 4303 §2.6), sans donnee utile.
+const NO_NEXT_HEADER: u8 = 59;
+
+/// Algorithme et cle d'une association de securite.
+#[non_exhaustive]
+#[derive(Clone, PartialEq, Eq)]
+pub enum EspCipher {
+    /// AES-GCM, ICV de 16 octets (RFC 4106). Cle de 16, 24 ou 32 octets ;
+    /// le sel correspond aux 4 derniers octets du materiel de cle IKE.
+    AesGcm { key: Vec<u8>, salt: [u8; 4] },
+    /// AES-CBC (RFC 3602). `icv_length` est la taille de l'ICV de
+    /// l'algorithme d'integrite associe (12 pour HMAC-SHA1-96, 16 pour
+    /// HMAC-SHA-256-128), retire sans verification.
+    AesCbc { key: Vec<u8>, icv_length: usize },
+}
+
+/// Les cles ne sont jamais affichees, seulement leur taille.
+impl fmt::Debug for EspCipher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::AesGcm { key, .. } => write!(f, "AesGcm({} bits)", key.len() * 8),
+            Self::AesCbc { key, icv_length } => {
+                write!(f, "AesCbc({} bits, icv {icv_length})", key.len() * 8)
+            }
+        }
+    }
+}
+
+/// Table d'associations de securite, construite en builder comme
+/// [`crate::ParseConfig`].
+#[derive(Debug, Clone, Default)]
+pub struct EspSaTable {
+    associations: HashMap<u32, EspCipher>,
+}
+
+/// Texte clair d'un paquet ESP, trailer retire.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EspPlaintext {
+    pub spi: u32,
+    pub sequence_number: u32,
+    /// Protocole IP des donnees : 4/41 en mode tunnel, le transport en mode
+    /// transport, 59 pour un paquet factice.
+    pub next_header: u8,
+    pub pad_length: u8,
+    pub data: Vec<u8>,
+}
+
+impl EspSaTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declare l'association de securite d'un SPI ; une declaration
+    /// ulterieure du meme SPI remplace la precedente.
+    #[must_use]
+    pub fn with_association(mut self, spi: u32, cipher: EspCipher) -> Self {
+        self.associations.insert(spi, cipher);
+        self
+    }
+
+    /// Dechiffre un paquet ESP avec l'association de son SPI.
+    pub fn decrypt(&self, packet: &EspPacket<'_>) -> Result<EspPlaintext, EspError> {
+        let cipher = self
+            .associations
+            .get(&packet.spi)
+            .ok_or(EspError::UnknownSpi(packet.spi))?;
+
+        let plaintext = match cipher {
+            EspCipher::AesGcm { key, salt } => decrypt_gcm(packet, key, salt)?,
+            EspCipher::AesCbc { key, icv_length } => {
+                decrypt_cbc(packet.encrypted_data, key, *icv_length)?
+            }
+        };
+
+        let (data, pad_length, next_header) = extract_esp_trailer(&plaintext)?;
+        Ok(EspPlaintext {
+            spi: packet.spi,
+            sequence_number: packet.sequence_number,
+            next_header,
+            pad_length,
+            data: data.to_vec(),
+        })
+    }
+
+    /// Dechiffre l'ESP porte par un flux deja parse. `None` si le flux ne
+    /// porte pas d'ESP lisible.
+    pub fn decrypt_flow(&self, flow: &PacketFlow<'_>) -> Option<Result<EspPlaintext, EspError>> {
+        match flow.transport.as_ref()?.details.as_ref()? {
+            TransportDetails::Esp(packet) => Some(self.decrypt(packet)),
+            _ => None,
+        }
+    }
+}
+
+impl EspPlaintext {
+    /// Mode tunnel : re-parse le paquet IP interne en flux complet, tunnels
+    /// compris. `None` en mode transport ou si le paquet est illisible.
+    pub fn inner_flow(&self) -> Option<PacketFlow<'_>> {
+        let link_type = match self.next_header {
+            IPV4_IN_ESP => LinkType::IPV4,
+            IPV6_IN_ESP => LinkType::IPV6,
+            _ => return None,
+        };
+        crate::parse(link_type, &self.data).ok()
+    }
+
+    /// Mode transport : decode la couche transport portee directement.
+    /// `None` en mode tunnel et pour un paquet factice.
+    pub fn transport(&self) -> Option<Transport<'_>> {
+        if matches!(self.next_header, IPV4_IN_ESP | IPV6_IN_ESP | NO_NEXT_HEADER) {
+            return None;
+        }
+        Transport::try_from_parts(
+            Some(TransportProtocol::from_u8(self.next_header)),
+            &self.data,
+        )
+        .ok()
+    }
+}
+
+fn decrypt_gcm(packet: &EspPacket<'_>, key: &[u8], salt: &[u8; 4]) -> Result<Vec<u8>, EspError> {
+    const ALGORITHM: &str = "AES-GCM";
+
+    let encrypted = packet.encrypted_data;
+    if encrypted.len() < GCM_IV_LENGTH + GCM_ICV_LENGTH {
+        return Err(EspError::InvalidCiphertextLength {
+            algorithm: ALGORITHM,
+            actual: encrypted.len(),
+        });
+    }
+    let (iv, ciphertext) = encrypted.split_at(GCM_IV_LENGTH);
+
+    // Nonce = sel || IV explicite ; AAD = SPI || sequence (RFC 4106 §5).
+    let mut nonce = [0u8; 12];
+    nonce[..4].copy_from_slice(salt);
+    nonce[4..].copy_from_slice(iv);
+    let mut aad = [0u8; ESP_HEADER_LENGTH];
+    aad[..4].copy_from_slice(&packet.spi.to_be_bytes());
+    aad[4..].copy_from_slice(&packet.sequence_number.to_be_bytes());
+    let payload = Payload {
+        msg: ciphertext,
+        aad: &aad,
+    };
+
+    let invalid_key = || EspError::InvalidKeyLength {
+        algorithm: ALGORITHM,
+        actual: key.len(),
+    };
+    let result = match key.len() {
+        16 => AesGcm::<Aes128, U12>::new_from_slice(key)
+            .map_err(|_| invalid_key())?
+            .decrypt(&nonce.into(), payload),
+        24 => AesGcm::<Aes192, U12>::new_from_slice(key)
+            .map_err(|_| invalid_key())?
+            .decrypt(&nonce.into(), payload),
+        32 => AesGcm::<Aes256, U12>::new_from_slice(key)
+            .map_err(|_| invalid_key())?
+            .decrypt(&nonce.into(), payload),
+        _ => return Err(invalid_key()),
+    };
+    result.map_err(|_| EspError::AuthenticationFailed)
+}
+
+fn decrypt_cbc(encrypted: &[u8], key: &[u8], icv_length: usize) -> Result<Vec<u8>, EspError> {
+    const ALGORITHM: &str = "AES-CBC";
+
+    let invalid_length = || EspError::InvalidCiphertextLength {
+        algorithm: ALGORITHM,
+        actual: encrypted.len(),
+    };
+    let ciphertext_end = encrypted
+        .len()
+        .checked_sub(icv_length)
+        .filter(|&end| end > CBC_IV_LENGTH)
+        .ok_or_else(invalid_length)?;
+    let (iv, ciphertext) = encrypted[..ciphertext_end].split_at(CBC_IV_LENGTH);
+    if !ciphertext.len().is_multiple_of(AES_BLOCK_LENGTH) {
+        return Err(invalid_length());
+    }
+
+    let invalid_key = || EspError::InvalidKeyLength {
+        algorithm: ALGORITHM,
+        actual: key.len(),
+    };
+    let mut buffer = ciphertext.to_vec();
+    // Le padding ESP est controle par `extract_esp_trailer`, pas par le mode.
+    let decrypted = match key.len() {
+        16 => cbc::Decryptor::<Aes128>::new_from_slices(key, iv)
+            .map_err(|_| invalid_key())?
+            .decrypt_padded_mut::<NoPadding>(&mut buffer)
+            .map(<[u8]>::len),
+        24 => cbc::Decryptor::<Aes192>::new_from_slices(key, iv)
+            .map_err(|_| invalid_key())?
+            .decrypt_padded_mut::<NoPadding>(&mut buffer)
+            .map(<[u8]>::len),
+        32 => cbc::Decryptor::<Aes256>::new_from_slices(key, iv)
+            .map_err(|_| invalid_key())?
+            .decrypt_padded_mut::<NoPadding>(&mut buffer)
+            .map(<[u8]>::len),
+        _ => return Err(invalid_key()),
+    };
+    let length = decrypted.map_err(|_| invalid_length())?;
+    buffer.truncate(length);
+    Ok(buffer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // -----------------------------------------------------------------------
+    // Synthetique : aucune capture ESP dans le corpus, et une capture reelle
+    // serait inutilisable sans ses cles. Les vecteurs sont produits par une
+    // implementation independante (Python `cryptography`) : paquet interne
+    // IPv4 10.1.0.1 -> 10.2.0.1, UDP 40000 -> 53, donnees « hello », en mode
+    // tunnel (next header 4).
+    // -----------------------------------------------------------------------
+
+    const INNER_PACKET_HEX: &str =
+        "4500002112344000401114940a0100010a0200019c400035000d000068656c6c6f";
+
+    /// SPI 0x1001, sequence 1, AES-128-GCM, cle 00..0f, sel cafebabe, IV 1.
+    const ESP_GCM_HEX: &str = concat!(
+        "00001001000000010000000000000001f70519b8584b11f4db0ad5894c7ea936",
+        "d96a814125b748b0feb81ff41f884557eb86b6f830a5de2c9a69abe9821db2bb",
+        "6ddec1e8"
+    );
+
+    /// SPI 0x2002, sequence 7, AES-128-CBC, cle 10..1f, IV a0..af,
+    /// HMAC-SHA1-96.
+    const ESP_CBC_HEX: &str = concat!(
+        "0000200200000007a0a1a2a3a4a5a6a7a8a9aaabacadaeaf3845e30568e348dd",
+        "66fd0a81f8046f16babebc859394e33d4b14a2a057e02a855392de37ebcbcd59",
+        "a1916a87977cc7db901c9fc6eac89bfb523bba0b"
+    );
+
+    /// Le paquet GCM ci-dessus, dans Ethernet / IPv4 192.0.2.1 -> 198.51.100.1.
+    const ESP_GCM_FRAME_HEX: &str = concat!(
+        "0200000000020200000000010800450000581234400040323c0ac0000201c633",
+        "640100001001000000010000000000000001f70519b8584b11f4db0ad5894c7e",
+        "a936d96a814125b748b0feb81ff41f884557eb86b6f830a5de2c9a69abe9821d",
+        "b2bb6ddec1e8"
+    );
+
+    fn bytes(hex_fixture: &str) -> Vec<u8> {
+        hex::decode(hex_fixture).expect("invalid test hex fixture")
+    }
+
+    fn table() -> EspSaTable {
+        EspSaTable::new()
+            .with_association(
+                0x1001,
+                EspCipher::AesGcm {
+                    key: (0..16).collect(),
+                    salt: [0xca, 0xfe, 0xba, 0xbe],
+                },
+            )
+            .with_association(
+                0x2002,
+                EspCipher::AesCbc {
+                    key: (16..32).collect(),
+                    icv_length: 12,
+                },
+            )
+    }
+
+    #[test]
+    fn decrypts_aes_gcm_tunnel_mode() {
+        let raw = bytes(ESP_GCM_HEX);
+        let packet = EspPacket::try_from(raw.as_slice()).expect("esp parses");
+
+        let plaintext = table().decrypt(&packet).expect("decrypts");
+
+        assert_eq!(plaintext.next_header, 4);
+        assert_eq!(plaintext.pad_length, 1);
+        assert_eq!(plaintext.data, bytes(INNER_PACKET_HEX));
+        assert!(plaintext.transport().is_none());
+    }
+
+    #[test]
+    fn decrypts_aes_cbc_and_strips_the_icv() {
+        let raw = bytes(ESP_CBC_HEX);
+        let packet = EspPacket::try_from(raw.as_slice()).expect("esp parses");
+
+        let plaintext = table().decrypt(&packet).expect("decrypts");
+
+        assert_eq!(plaintext.sequence_number, 7);
+        assert_eq!(plaintext.next_header, 4);
+        assert_eq!(plaintext.data, bytes(INNER_PACKET_HEX));
+    }
+
+    #[test]
+    fn decrypted_flow_is_reparsed_as_an_inner_flow() {
+        let frame = bytes(ESP_GCM_FRAME_HEX);
+        let flow = crate::parse(LinkType::ETHERNET, &frame).expect("frame decodes");
+        assert_eq!(
+            flow.transport.as_ref().map(|transport| transport.protocol),
+            Some(TransportProtocol::Esp)
+        );
+
+        let plaintext = table()
+            .decrypt_flow(&flow)
+            .expect("flow carries ESP")
+            .expect("decrypts");
+        let inner = plaintext.inner_flow().expect("inner IPv4 packet parses");
+        let transport = inner.transport.expect("inner UDP");
+
+        assert_eq!(transport.source_port, Some(40000));
+        assert_eq!(transport.destination_port, Some(53));
+        assert_eq!(transport.payload, Some(&b"hello"[..]));
+    }
+
+    #[test]
+    fn tampered_gcm_packet_fails_authentication() {
+        let mut raw = bytes(ESP_GCM_HEX);
+        raw[20] ^= 0x01;
+        let packet = EspPacket::try_from(raw.as_slice()).expect("esp parses");
+
+        assert_eq!(
+            table().decrypt(&packet),
+            Err(EspError::AuthenticationFailed)
+        );
+    }
+
+    #[test]
+    fn wrong_cbc_key_is_caught_by_the_padding_check() {
+        let raw = bytes(ESP_CBC_HEX);
+        let packet = EspPacket::try_from(raw.as_slice()).expect("esp parses");
+        let table = EspSaTable::new().with_association(
+            0x2002,
+            EspCipher::AesCbc {
+                key: vec![0; 16],
+                icv_length: 12,
+            },
+        );
+
+        assert!(matches!(
+            table.decrypt(&packet),
+            Err(EspError::InvalidPadding { .. })
+        ));
+    }
+
+    #[test]
+    fn unknown_spi_is_reported() {
+        let raw = bytes(ESP_GCM_HEX);
+        let packet = EspPacket::try_from(raw.as_slice()).expect("esp parses");
+
+        assert_eq!(
+            EspSaTable::new().decrypt(&packet),
+            Err(EspError::UnknownSpi(0x1001))
+        );
+    }
+
+    #[test]
+    fn debug_output_hides_keys() {
+        let cipher = EspCipher::AesGcm {
+            key: vec![0x42; 32],
+            salt: [0; 4],
+        };
+
+        assert_eq!(format!("{cipher:?}"), "AesGcm(256 bits)");
+    }
+}
@@ -0,0 +1,21 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Dechiffrement opt-in (feature `decrypt`) a partir de cles ou de secrets
+//! fournis par l'appelant (table de SA ESP, secrets TLS 1.3, journal
+//! `SSLKEYLOGFILE`), ou derivees de valeurs publiques pour les paquets
+//! Initial QUIC.
+//!
+//! [`fn@crate::parse`] ne dechiffre jamais : un [`crate::PacketFlow`] emprunte
+//! le tampon d'entree, et un texte clair est un tampon neuf. Le
+//! dechiffrement se fait donc en second temps, sur un flux deja parse, et
+//! rend un texte clair possede que l'on re-parse a son tour — un niveau
+//! interne comme ceux des tunnels, dont la duree de vie est celle du texte
+//! clair.
+
+pub mod esp;
+pub mod quic;
+pub mod tls;
+pub mod tls13;
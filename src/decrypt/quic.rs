@@ -0,0 +1,913 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Dechiffrement des paquets QUIC v1 (RFC 9001 §5) et v2 (RFC 9369 §3.3).
+//!
+//! Paquets Initial : aucune cle n'est a fournir, les secrets Initial
+//! derivent du Destination Connection ID choisi par le client et d'un sel
+//! publie par la version. La protection ne vaut que contre la modification
+//! en vol, pas contre l'observation — c'est ce qui rend le ClientHello (SNI,
+//! ALPN, empreintes JA4) lisible par un observateur passif.
+//!
+//! Les navigateurs recents decoupent et melangent le ClientHello sur
+//! plusieurs frames CRYPTO, voire plusieurs paquets Initial :
+//! [`QuicCryptoReassembler`] le recolle d'apres les offsets.
+//!
+//! Paquets 1-RTT (Short Header) : les cles derivent des secrets de trafic
+//! TLS 1.3 (`CLIENT_TRAFFIC_SECRET_0` / `SERVER_TRAFFIC_SECRET_0` d'un
+//! SSLKEYLOGFILE), fournis par l'appelant a [`QuicPacketKeys`].
+//! [`QuicShortHeaderDecryptor`] suit ensuite le numero de paquet et les
+//! changements de cle d'un sens de la connexion.
+
+use std::fmt;
+
+use aes::{
+    Aes128, Aes256,
+    cipher::{BlockEncrypt, KeyInit},
+};
+use chacha20::{
+    ChaCha20,
+    cipher::{KeyIvInit, StreamCipher, StreamCipherSeek},
+};
+use hkdf::Hkdf;
+use sha2::Sha256;
+
+use super::tls13::{AEAD_NONCE_LEN, Tls13CipherSuite, aead_nonce, aead_open, hkdf_expand_label};
+use crate::{
+    checks::application::quic::{
+        QUIC_HP_SAMPLE_LEN, QUIC_HP_SAMPLE_OFFSET, QUIC_LONG_HEADER_PROTECTED_BITS,
+        QUIC_MAX_CRYPTO_BUFFER, QUIC_SHORT_HEADER_KEY_PHASE, QUIC_SHORT_HEADER_PROTECTED_BITS,
+        QUIC_SHORT_HEADER_SPIN_BIT, QUIC_V1_INITIAL_SALT, QUIC_V2, QUIC_V2_INITIAL_SALT,
+        QuicCursor, decode_packet_number, extract_first_byte, extract_packet_type, extract_version,
+        read_cid, validate_fixed_bit, validate_length_field, validate_payload_available,
+        validate_version,
+    },
+    errors::application::quic::{QuicDecryptError, QuicError},
+    parse::application::protocols::{
+        quic::{
+            CryptoFrame, QuicFrame, QuicPacketType, parse_quic_frames,
+            stream::{QuicApplicationFrame, parse_quic_application_frames},
+        },
+        tls::handshake::{TlsClientHello, TlsHandshake},
+    },
+};
+
+/// AES-128-GCM, seul AEAD des paquets Initial.
+const INITIAL_SUITE: Tls13CipherSuite = Tls13CipherSuite::Aes128GcmSha256;
+const INITIAL_KEY_LEN: usize = 16;
+const INITIAL_SECRET_LEN: usize = 32;
+
+/// Cles d'un sens (client ou serveur) des paquets Initial.
+#[derive(Clone, PartialEq, Eq)]
+pub struct QuicInitialKeys {
+    version: u32,
+    key: [u8; INITIAL_KEY_LEN],
+    iv: [u8; AEAD_NONCE_LEN],
+    hp: [u8; INITIAL_KEY_LEN],
+}
+
+/// Les cles ne sont jamais affichees, meme derivees de valeurs publiques :
+/// le format reste celui de [`EspCipher`](super::esp::EspCipher).
+impl fmt::Debug for QuicInitialKeys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "QuicInitialKeys(version {:#010x})", self.version)
+    }
+}
+
+/// Payload dechiffre d'un paquet Initial, protection d'en-tete retiree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuicInitialPlaintext {
+    pub version: u32,
+    /// Destination Connection ID du paquet.
+    pub dcid: Vec<u8>,
+    /// Numero de paquet tronque, tel que transporte (1 a 4 octets) : sa
+    /// reconstruction exige le plus grand numero deja recu (RFC 9000 §A.3),
+    /// sans objet pour les quelques Initial d'une connexion.
+    pub packet_number: u64,
+    /// Frames en clair, tag AEAD retire.
+    pub payload: Vec<u8>,
+    /// Octets consommes dans le datagramme : un Initial peut etre suivi
+    /// d'autres paquets coalesces (RFC 9000 §12.2).
+    pub packet_length: usize,
+}
+
+impl QuicInitialKeys {
+    /// Cles des Initial emis par le client. `original_dcid` est le DCID du
+    /// premier Initial client, avant tout Retry.
+    pub fn client(version: u32, original_dcid: &[u8]) -> Result<Self, QuicDecryptError> {
+        Self::derive(version, original_dcid, b"client in")
+    }
+
+    /// Cles des Initial emis par le serveur, derivees du meme DCID client.
+    pub fn server(version: u32, original_dcid: &[u8]) -> Result<Self, QuicDecryptError> {
+        Self::derive(version, original_dcid, b"server in")
+    }
+
+    fn derive(version: u32, dcid: &[u8], label: &[u8]) -> Result<Self, QuicDecryptError> {
+        let salt: &[u8] = match version {
+            QUIC_V2 => &QUIC_V2_INITIAL_SALT,
+            _ => &QUIC_V1_INITIAL_SALT,
+        };
+        let prefix = label_prefix(version)?;
+
+        let (initial_secret, _) = Hkdf::<Sha256>::extract(Some(salt), dcid);
+        let mut secret = [0u8; INITIAL_SECRET_LEN];
+        hkdf_expand_label(INITIAL_SUITE, &initial_secret, label, b"", &mut secret);
+
+        let mut keys = QuicInitialKeys {
+            version,
+            key: [0; INITIAL_KEY_LEN],
+            iv: [0; AEAD_NONCE_LEN],
+            hp: [0; INITIAL_KEY_LEN],
+        };
+        let expand = |label: &[u8], output: &mut [u8]| {
+            hkdf_expand_label(
+                INITIAL_SUITE,
+                &secret,
+                &[prefix, label].concat(),
+                b"",
+                output,
+            );
+        };
+        expand(b"key", &mut keys.key);
+        expand(b"iv", &mut keys.iv);
+        expand(b"hp", &mut keys.hp);
+        Ok(keys)
+    }
+
+    /// Retire la protection d'en-tete puis dechiffre un paquet Initial
+    /// place en tete de `packet`.
+    pub fn decrypt(&self, packet: &[u8]) -> Result<QuicInitialPlaintext, QuicDecryptError> {
+        let header = InitialHeader::parse(packet)?;
+        if header.version != self.version {
+            return Err(QuicError::UnsupportedVersion(header.version).into());
+        }
+
+        // RFC 9001 §5.4.2 : l'echantillon suppose un Packet Number de 4
+        // octets, quelle que soit sa longueur reelle. Il garantit du meme
+        // coup les 16 octets du tag AEAD.
+        let sample_start = header.pn_offset + QUIC_HP_SAMPLE_OFFSET;
+        let needed = QUIC_HP_SAMPLE_OFFSET + QUIC_HP_SAMPLE_LEN;
+        if header.length < needed {
+            return Err(QuicDecryptError::SampleTooShort {
+                needed,
+                available: header.length,
+            });
+        }
+        let mask = header_protection_mask(
+            INITIAL_SUITE,
+            &self.hp,
+            &packet[sample_start..sample_start + QUIC_HP_SAMPLE_LEN],
+        )?;
+
+        // Long Header : seuls les 4 bits de poids faible sont proteges.
+        let first_byte = packet[0] ^ (mask[0] & QUIC_LONG_HEADER_PROTECTED_BITS);
+        let (_, pn_length) = extract_first_byte(first_byte)?;
+        let pn_length = pn_length as usize;
+        validate_length_field(header.length as u64, pn_length as u8)?;
+
+        let mut aad = packet[..header.pn_offset + pn_length].to_vec();
+        aad[0] = first_byte;
+        let mut packet_number = 0u64;
+        for (index, byte) in aad[header.pn_offset..].iter_mut().enumerate() {
+            *byte ^= mask[1 + index];
+            packet_number = (packet_number << 8) | u64::from(*byte);
+        }
+
+        let end = header.pn_offset + header.length;
+        let ciphertext = &packet[header.pn_offset + pn_length..end];
+        let payload = aead_open(
+            INITIAL_SUITE,
+            &self.key,
+            &aead_nonce(&self.iv, packet_number),
+            &aad,
+            ciphertext,
+        )
+        .ok_or(QuicDecryptError::AuthenticationFailed)?;
+
+        Ok(QuicInitialPlaintext {
+            version: header.version,
+            dcid: header.dcid.to_vec(),
+            packet_number,
+            payload,
+            packet_length: end,
+        })
+    }
+}
+
+/// Dechiffre l'Initial d'un client avec les cles derivees de son propre
+/// DCID. Les Initial serveur, et les Initial client qui suivent un Retry,
+/// exigent [`QuicInitialKeys`] construit sur le DCID d'origine.
+pub fn decrypt_client_initial(packet: &[u8]) -> Result<QuicInitialPlaintext, QuicDecryptError> {
+    let header = InitialHeader::parse(packet)?;
+    QuicInitialKeys::client(header.version, header.dcid)?.decrypt(packet)
+}
+
+impl QuicInitialPlaintext {
+    /// Frames du payload dechiffre.
+    pub fn frames(&self) -> Result<Vec<QuicFrame<'_>>, QuicError> {
+        parse_quic_frames(&self.payload)
+    }
+}
+
+/// Flux CRYPTO reconstitue a partir de frames arrivees dans le desordre,
+/// eventuellement dupliquees ou chevauchantes.
+///
+/// Borne a [`QUIC_MAX_CRYPTO_BUFFER`] octets : au-dela, les frames sont
+/// refusees plutot que de laisser un offset forge dicter l'allocation.
+#[derive(Debug, Clone, Default)]
+pub struct QuicCryptoReassembler {
+    buffer: Vec<u8>,
+    received: Vec<bool>,
+}
+
+impl QuicCryptoReassembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Range une frame CRYPTO a son offset.
+    pub fn push(&mut self, frame: &CryptoFrame<'_>) -> Result<(), QuicDecryptError> {
+        let end = frame.offset.saturating_add(frame.data.len() as u64);
+        if end > QUIC_MAX_CRYPTO_BUFFER as u64 {
+            return Err(QuicDecryptError::CryptoBufferExceeded {
+                end,
+                max: QUIC_MAX_CRYPTO_BUFFER,
+            });
+        }
+
+        let (start, end) = (frame.offset as usize, end as usize);
+        if self.buffer.len() < end {
+            self.buffer.resize(end, 0);
+            self.received.resize(end, false);
+        }
+        self.buffer[start..end].copy_from_slice(frame.data);
+        self.received[start..end].fill(true);
+        Ok(())
+    }
+
+    /// Range toutes les frames CRYPTO d'un Initial dechiffre.
+    pub fn push_plaintext(
+        &mut self,
+        plaintext: &QuicInitialPlaintext,
+    ) -> Result<(), QuicDecryptError> {
+        for frame in plaintext.frames()? {
+            if let QuicFrame::Crypto(crypto) = frame {
+                self.push(&crypto)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Debut du flux recu sans trou.
+    pub fn contiguous(&self) -> &[u8] {
+        let length = self
+            .received
+            .iter()
+            .position(|received| !received)
+            .unwrap_or(self.received.len());
+        &self.buffer[..length]
+    }
+
+    /// ClientHello, une fois entierement recu.
+    pub fn client_hello(&self) -> Option<TlsClientHello<'_>> {
+        TlsHandshake::try_from(self.contiguous())
+            .ok()?
+            .client_hello()
+            .ok()
+    }
+}
+
+/// Champs d'un en-tete Initial utiles au dechiffrement.
+struct InitialHeader<'a> {
+    version: u32,
+    dcid: &'a [u8],
+    /// Position du Packet Number dans le paquet.
+    pn_offset: usize,
+    /// Champ Length : Packet Number + payload protege.
+    length: usize,
+}
+
+impl<'a> InitialHeader<'a> {
+    fn parse(packet: &'a [u8]) -> Result<Self, QuicDecryptError> {
+        let mut cur = QuicCursor::new(packet);
+        let first_byte = cur.take_u8()?;
+        extract_first_byte(first_byte)?;
+        let version = extract_version(&mut cur)?;
+        if extract_packet_type(first_byte, version) != QuicPacketType::Initial {
+            return Err(QuicDecryptError::NotInitial);
+        }
+
+        let dcid = read_cid(&mut cur)?.bytes;
+        read_cid(&mut cur)?;
+        let token_length = cur.read_varint()? as usize;
+        cur.take(token_length)?;
+        let length = cur.read_varint()? as usize;
+        validate_payload_available(cur.remaining(), length)?;
+
+        Ok(InitialHeader {
+            version,
+            dcid,
+            pn_offset: packet.len() - cur.remaining(),
+            length,
+        })
+    }
+}
+
+/// Cles d'un sens des paquets 1-RTT, derivees d'un secret de trafic TLS
+/// 1.3 (RFC 9001 §5.1).
+#[derive(Clone, PartialEq, Eq)]
+pub struct QuicPacketKeys {
+    version: u32,
+    suite: Tls13CipherSuite,
+    label_prefix: &'static [u8],
+    secret: Vec<u8>,
+    key: Vec<u8>,
+    iv: [u8; AEAD_NONCE_LEN],
+    hp: Vec<u8>,
+}
+
+/// Les secrets et cles ne sont jamais affiches.
+impl fmt::Debug for QuicPacketKeys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "QuicPacketKeys(version {:#010x}, {:?})",
+            self.version, self.suite
+        )
+    }
+}
+
+/// Payload dechiffre d'un paquet 1-RTT, protection d'en-tete retiree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuicShortPlaintext {
+    /// Destination Connection ID, de la longueur fournie au dechiffrement :
+    /// le Short Header ne la transporte pas.
+    pub dcid: Vec<u8>,
+    pub spin_bit: bool,
+    pub key_phase: bool,
+    /// Numero de paquet complet, reconstruit d'apres le plus grand numero
+    /// deja recu (RFC 9000 §A.3).
+    pub packet_number: u64,
+    /// Frames en clair, tag AEAD retire.
+    pub payload: Vec<u8>,
+}
+
+/// En-tete d'un paquet 1-RTT, protection retiree.
+struct ShortHeader {
+    first_byte: u8,
+    packet_number: u64,
+    /// En-tete en clair, donnees associees de l'AEAD.
+    aad: Vec<u8>,
+}
+
+impl QuicPacketKeys {
+    /// Cles derivees d'un secret de trafic (`CLIENT_TRAFFIC_SECRET_0` pour
+    /// les paquets du client, `SERVER_TRAFFIC_SECRET_0` pour ceux du
+    /// serveur), selon la suite negociee par la poignee de main.
+    pub fn from_secret(
+        version: u32,
+        suite: Tls13CipherSuite,
+        secret: &[u8],
+    ) -> Result<Self, QuicDecryptError> {
+        if secret.len() != suite.hash_len() {
+            return Err(QuicDecryptError::InvalidSecretLength {
+                expected: suite.hash_len(),
+                actual: secret.len(),
+            });
+        }
+        let mut keys = QuicPacketKeys {
+            version,
+            suite,
+            label_prefix: label_prefix(version)?,
+            secret: secret.to_vec(),
+            key: vec![0; suite.key_len()],
+            iv: [0; AEAD_NONCE_LEN],
+            hp: vec![0; suite.key_len()],
+        };
+        keys.hp = keys.expand(&keys.secret, b"hp", suite.key_len());
+        keys.derive_packet_protection();
+        Ok(keys)
+    }
+
+    /// Generation suivante apres un changement de cle (RFC 9001 §6.1) : le
+    /// secret est re-derive avec le label `quic ku`, la cle de protection
+    /// d'en-tete reste celle d'origine.
+    pub fn next_generation(&self) -> Self {
+        let mut next = self.clone();
+        next.secret = self.expand(&self.secret, b"ku", self.suite.hash_len());
+        next.derive_packet_protection();
+        next
+    }
+
+    /// Cle et IV AEAD du secret courant.
+    fn derive_packet_protection(&mut self) {
+        self.key = self.expand(&self.secret, b"key", self.suite.key_len());
+        let iv = self.expand(&self.secret, b"iv", AEAD_NONCE_LEN);
+        self.iv.copy_from_slice(&iv);
+    }
+
+    fn expand(&self, secret: &[u8], label: &[u8], length: usize) -> Vec<u8> {
+        let mut output = vec![0; length];
+        hkdf_expand_label(
+            self.suite,
+            secret,
+            &[self.label_prefix, label].concat(),
+            b"",
+            &mut output,
+        );
+        output
+    }
+
+    pub fn suite(&self) -> Tls13CipherSuite {
+        self.suite
+    }
+
+    /// Dechiffre un paquet 1-RTT occupant tout `packet` (un Short Header
+    /// termine toujours le datagramme). `dcid_len` est la longueur des
+    /// Connection IDs choisis par le destinataire ; `largest_pn` le plus
+    /// grand numero de paquet deja dechiffre dans ce sens.
+    ///
+    /// Le bit Key Phase n'est pas interprete : voir
+    /// [`QuicShortHeaderDecryptor`].
+    pub fn decrypt_short(
+        &self,
+        packet: &[u8],
+        dcid_len: usize,
+        largest_pn: Option<u64>,
+    ) -> Result<QuicShortPlaintext, QuicDecryptError> {
+        let header = self.unprotect_short(packet, dcid_len, largest_pn)?;
+        self.open_short(packet, dcid_len, header)
+    }
+
+    fn unprotect_short(
+        &self,
+        packet: &[u8],
+        dcid_len: usize,
+        largest_pn: Option<u64>,
+    ) -> Result<ShortHeader, QuicDecryptError> {
+        let first_byte = *packet.first().ok_or(QuicError::Truncated {
+            needed: 1,
+            remaining: 0,
+        })?;
+        if first_byte & 0x80 != 0 {
+            return Err(QuicDecryptError::NotShortHeader);
+        }
+        validate_fixed_bit(first_byte & 0x40 != 0)?;
+
+        let pn_offset = 1 + dcid_len;
+        let available = packet.len().saturating_sub(pn_offset);
+        let needed = QUIC_HP_SAMPLE_OFFSET + QUIC_HP_SAMPLE_LEN;
+        if available < needed {
+            return Err(QuicDecryptError::SampleTooShort { needed, available });
+        }
+        let sample_start = pn_offset + QUIC_HP_SAMPLE_OFFSET;
+        let mask = header_protection_mask(
+            self.suite,
+            &self.hp,
+            &packet[sample_start..sample_start + QUIC_HP_SAMPLE_LEN],
+        )?;
+
+        // Short Header : 5 bits proteges, Key Phase compris.
+        let first_byte = first_byte ^ (mask[0] & QUIC_SHORT_HEADER_PROTECTED_BITS);
+        let pn_length = (first_byte & 0x03) + 1;
+        let mut aad = packet[..pn_offset + pn_length as usize].to_vec();
+        aad[0] = first_byte;
+        let mut truncated_pn = 0u64;
+        for (index, byte) in aad[pn_offset..].iter_mut().enumerate() {
+            *byte ^= mask[1 + index];
+            truncated_pn = (truncated_pn << 8) | u64::from(*byte);
+        }
+
+        Ok(ShortHeader {
+            first_byte,
+            packet_number: decode_packet_number(largest_pn, truncated_pn, pn_length),
+            aad,
+        })
+    }
+
+    fn open_short(
+        &self,
+        packet: &[u8],
+        dcid_len: usize,
+        header: ShortHeader,
+    ) -> Result<QuicShortPlaintext, QuicDecryptError> {
+        let payload = aead_open(
+            self.suite,
+            &self.key,
+            &aead_nonce(&self.iv, header.packet_number),
+            &header.aad,
+            &packet[header.aad.len()..],
+        )
+        .ok_or(QuicDecryptError::AuthenticationFailed)?;
+
+        Ok(QuicShortPlaintext {
+            dcid: packet[1..1 + dcid_len].to_vec(),
+            spin_bit: header.first_byte & QUIC_SHORT_HEADER_SPIN_BIT != 0,
+            key_phase: header.first_byte & QUIC_SHORT_HEADER_KEY_PHASE != 0,
+            packet_number: header.packet_number,
+            payload,
+        })
+    }
+}
+
+impl QuicShortPlaintext {
+    /// Frames du payload dechiffre, STREAM comprises.
+    pub fn frames(&self) -> Result<Vec<QuicApplicationFrame<'_>>, QuicError> {
+        parse_quic_application_frames(&self.payload)
+    }
+}
+
+/// Dechiffrement des paquets 1-RTT d'un sens de la connexion : reconstruit
+/// les numeros de paquet et suit les changements de cle (RFC 9001 §6).
+///
+/// Un paquet dont le bit Key Phase differe de la phase courante est essaye
+/// avec la generation suivante ; s'il s'authentifie, elle devient la
+/// courante. Un paquet de l'ancienne phase arrive apres le changement
+/// echoue a l'authentification.
+#[derive(Debug, Clone)]
+pub struct QuicShortHeaderDecryptor {
+    current: QuicPacketKeys,
+    next: QuicPacketKeys,
+    key_phase: bool,
+    dcid_len: usize,
+    largest_pn: Option<u64>,
+}
+
+impl QuicShortHeaderDecryptor {
+    /// `keys` est la premiere generation (Key Phase 0), `dcid_len` la
+    /// longueur des Connection IDs du destinataire.
+    pub fn new(keys: QuicPacketKeys, dcid_len: usize) -> Self {
+        QuicShortHeaderDecryptor {
+            next: keys.next_generation(),
+            current: keys,
+            key_phase: false,
+            dcid_len,
+            largest_pn: None,
+        }
+    }
+
+    /// Dechiffre le paquet 1-RTT qui termine un datagramme.
+    pub fn decrypt(&mut self, packet: &[u8]) -> Result<QuicShortPlaintext, QuicDecryptError> {
+        let header = self
+            .current
+            .unprotect_short(packet, self.dcid_len, self.largest_pn)?;
+        let key_phase = header.first_byte & QUIC_SHORT_HEADER_KEY_PHASE != 0;
+
+        let plaintext = if key_phase == self.key_phase {
+            self.current.open_short(packet, self.dcid_len, header)?
+        } else {
+            let plaintext = self.next.open_short(packet, self.dcid_len, header)?;
+            self.current = self.next.clone();
+            self.next = self.current.next_generation();
+            self.key_phase = key_phase;
+            plaintext
+        };
+
+        self.largest_pn = Some(self.largest_pn.map_or(plaintext.packet_number, |largest| {
+            largest.max(plaintext.packet_number)
+        }));
+        Ok(plaintext)
+    }
+
+    /// Plus grand numero de paquet dechiffre.
+    pub fn largest_packet_number(&self) -> Option<u64> {
+        self.largest_pn
+    }
+}
+
+/// Prefixe des labels de derivation : `quic ` pour v1, `quicv2 ` pour v2
+/// (RFC 9369 §3.3.2).
+fn label_prefix(version: u32) -> Result<&'static [u8], QuicError> {
+    match version {
+        QUIC_V2 => Ok(b"quicv2 "),
+        _ => {
+            validate_version(version)?;
+            Ok(b"quic ")
+        }
+    }
+}
+
+/// Masque de protection d'en-tete (RFC 9001 §5.4.3 et §5.4.4) : AES-ECB de
+/// l'echantillon, ou flux ChaCha20 dont le compteur et le nonce sont tires
+/// de l'echantillon.
+fn header_protection_mask(
+    suite: Tls13CipherSuite,
+    hp: &[u8],
+    sample: &[u8],
+) -> Result<[u8; 5], QuicDecryptError> {
+    // Les cles ont la taille de leur suite par construction : un echec ne
+    // peut venir que d'un appel incoherent, traite comme une cle fausse.
+    let invalid_key = |_| QuicDecryptError::AuthenticationFailed;
+    let mut block = [0u8; QUIC_HP_SAMPLE_LEN];
+    block.copy_from_slice(sample);
+    let mut mask = [0u8; 5];
+    match suite {
+        Tls13CipherSuite::Aes128GcmSha256 => {
+            Aes128::new_from_slice(hp)
+                .map_err(invalid_key)?
+                .encrypt_block((&mut block).into());
+            mask.copy_from_slice(&block[..5]);
+        }
+        Tls13CipherSuite::Aes256GcmSha384 => {
+            Aes256::new_from_slice(hp)
+                .map_err(invalid_key)?
+                .encrypt_block((&mut block).into());
+            mask.copy_from_slice(&block[..5]);
+        }
+        Tls13CipherSuite::ChaCha20Poly1305Sha256 => {
+            let counter = u32::from_le_bytes([block[0], block[1], block[2], block[3]]);
+            let mut cipher = ChaCha20::new_from_slices(hp, &block[4..]).map_err(invalid_key)?;
+            cipher.seek(u64::from(counter) * 64);
+            cipher.apply_keystream(&mut mask);
+        }
+    }
+    Ok(mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // -----------------------------------------------------------------------
+    // Cles : vecteurs des annexes A.1 de RFC 9001 et RFC 9369 (DCID
+    // 8394c8f03e515708). Paquet v2 : synthetique, chiffre par une
+    // implementation independante (Python `cryptography`) a partir du
+    // ClientHello de RFC 9001 annexe A.2, suivi de 8 octets de PADDING.
+    // -----------------------------------------------------------------------
+
+    const RFC_DCID: [u8; 8] = [0x83, 0x94, 0xc8, 0xf0, 0x3e, 0x51, 0x57, 0x08];
+
+    const V2_INITIAL_HEX: &str = concat!(
+        "d76b3343cf088394c8f03e51570800004111a0c95e82ffe67b6abcdb4298b485",
+        "dd04de806071bf03dceebfa162e75d6c96058bdbfb127cdfcbf903388e99ad04",
+        "9f9a3dd4425ae4d0992cfff18ecf0fdb5a842d09747052f17ac2053d21f57c5d",
+        "250f2c4f0e0202b70785b7946e992e58a59ac52dea6774d4f03b55545243cf1a",
+        "12834e3f249a78d395e0d18f4d766004f1a2674802a747eaa901c3f10cda5500",
+        "cb9122faa9f1df66c392079a7b40f0de1c6054196a11cbea40afb6ef5253cd68",
+        "18f6625efce3b6def6ba7e4b37a40f7732e093daa7d52190935b8da58976ff33",
+        "12ae50b187c1433c0f028edcc4c2838b6a9bfc226ca4b4530e7a4ccee1bfa2a3",
+        "d396ae5a3fb512384b2fdd851f784a65e03f2c8a31d740a9c3b42040b0f4f562",
+        "ed42d8"
+    );
+
+    #[test]
+    fn derives_the_rfc_9001_initial_keys() {
+        let client = QuicInitialKeys::client(1, &RFC_DCID).unwrap();
+        assert_eq!(hex::encode(client.key), "1f369613dd76d5467730efcbe3b1a22d");
+        assert_eq!(hex::encode(client.iv), "fa044b2f42a3fd3b46fb255c");
+        assert_eq!(hex::encode(client.hp), "9f50449e04a0e810283a1e9933adedd2");
+
+        let server = QuicInitialKeys::server(1, &RFC_DCID).unwrap();
+        assert_eq!(hex::encode(server.key), "cf3a5331653c364c88f0f379b6067e37");
+        assert_eq!(hex::encode(server.iv), "0ac1493ca1905853b0bba03e");
+        assert_eq!(hex::encode(server.hp), "c206b8d9b9f0f37644430b490eeaa314");
+    }
+
+    #[test]
+    fn derives_the_rfc_9369_initial_keys() {
+        let client = QuicInitialKeys::client(QUIC_V2, &RFC_DCID).unwrap();
+        assert_eq!(hex::encode(client.key), "8b1a0bc121284290a29e0971b5cd045d");
+        assert_eq!(hex::encode(client.iv), "91f73e2351d8fa91660e909f");
+        assert_eq!(hex::encode(client.hp), "45b95e15235d6f45a6b19cbcb0294ba9");
+    }
+
+    #[test]
+    fn decrypts_a_v2_initial_up_to_the_client_hello() {
+        let packet = hex::decode(V2_INITIAL_HEX).unwrap();
+        let plaintext = decrypt_client_initial(&packet).expect("decrypts");
+
+        assert_eq!(plaintext.version, QUIC_V2);
+        assert_eq!(plaintext.dcid, RFC_DCID);
+        assert_eq!(plaintext.packet_number, 2);
+        assert_eq!(plaintext.packet_length, packet.len());
+
+        let frames = plaintext.frames().unwrap();
+        assert!(matches!(frames[1], QuicFrame::Padding { length: 8 }));
+
+        let mut reassembler = QuicCryptoReassembler::new();
+        reassembler.push_plaintext(&plaintext).unwrap();
+        let hello = reassembler.client_hello().expect("complete ClientHello");
+        assert_eq!(hello.server_name(), Some("example.com"));
+        assert_eq!(hello.alpn_protocols(), Some(vec![&b"alpn"[..]]));
+    }
+
+    #[test]
+    fn tampered_initial_fails_authentication() {
+        let mut packet = hex::decode(V2_INITIAL_HEX).unwrap();
+        let last = packet.len() - 1;
+        packet[last] ^= 0x01;
+
+        assert_eq!(
+            decrypt_client_initial(&packet),
+            Err(QuicDecryptError::AuthenticationFailed)
+        );
+    }
+
+    #[test]
+    fn keys_of_another_version_are_rejected() {
+        let packet = hex::decode(V2_INITIAL_HEX).unwrap();
+        let keys = QuicInitialKeys::client(1, &RFC_DCID).unwrap();
+
+        assert_eq!(
+            keys.decrypt(&packet),
+            Err(QuicDecryptError::Packet(QuicError::UnsupportedVersion(
+                QUIC_V2
+            )))
+        );
+    }
+
+    /// Synthetique : fragments dans le desordre, avec chevauchement.
+    #[test]
+    fn reassembler_fills_gaps_in_any_order() {
+        let mut reassembler = QuicCryptoReassembler::new();
+        let fragment = |offset: u64, data: &'static [u8]| CryptoFrame {
+            offset,
+            length: data.len() as u64,
+            data,
+        };
+
+        reassembler.push(&fragment(4, b"efgh")).unwrap();
+        assert_eq!(reassembler.contiguous(), b"");
+        reassembler.push(&fragment(0, b"abc")).unwrap();
+        assert_eq!(reassembler.contiguous(), b"abc");
+        reassembler.push(&fragment(2, b"cde")).unwrap();
+        assert_eq!(reassembler.contiguous(), b"abcdefgh");
+
+        assert_eq!(
+            reassembler.push(&fragment(QUIC_MAX_CRYPTO_BUFFER as u64, b"x")),
+            Err(QuicDecryptError::CryptoBufferExceeded {
+                end: QUIC_MAX_CRYPTO_BUFFER as u64 + 1,
+                max: QUIC_MAX_CRYPTO_BUFFER,
+            })
+        );
+    }
+
+    #[test]
+    fn debug_output_hides_keys() {
+        let keys = QuicInitialKeys::client(1, &RFC_DCID).unwrap();
+
+        assert_eq!(format!("{keys:?}"), "QuicInitialKeys(version 0x00000001)");
+    }
+
+    // -----------------------------------------------------------------------
+    // 1-RTT : RFC 9001 annexe A.5 (ChaCha20-Poly1305, DCID vide). Paquets
+    // AES-128-GCM : synthetiques, chiffres par une implementation
+    // independante (Python `cryptography`) avec le secret 01..20, DCID
+    // 1122334455667788 ; le second utilise la generation `quic ku`.
+    // -----------------------------------------------------------------------
+
+    const RFC_CHACHA_SECRET: &str =
+        "9ac312a7f877468ebe69422748ad00a15443f18203a07d6060f688f30f21632b";
+    const RFC_CHACHA_PACKET: &str = "4cfe4189655e5cd55c41f69080575d7999c25a5bfb";
+
+    const SHORT_DCID: [u8; 8] = [0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88];
+    const SHORT_STREAM_HEX: &str = concat!(
+        "5f1122334455667788a3f08fd6340de549ef59bb9fb38c96ddbd3312172b31dd",
+        "46539eb3809ef19df77773e36fa356"
+    );
+    const SHORT_KEY_UPDATE_HEX: &str = concat!(
+        "551122334455667788290bbc3026d5b72274513027d5987a82e2e2cf32645d89",
+        "f4b993e5a4003dbabe49fb585fea4314"
+    );
+
+    fn synthetic_keys() -> QuicPacketKeys {
+        let secret: Vec<u8> = (1..=32).collect();
+        QuicPacketKeys::from_secret(1, Tls13CipherSuite::Aes128GcmSha256, &secret).unwrap()
+    }
+
+    #[test]
+    fn derives_the_rfc_9001_chacha20_keys() {
+        let secret = hex::decode(RFC_CHACHA_SECRET).unwrap();
+        let keys =
+            QuicPacketKeys::from_secret(1, Tls13CipherSuite::ChaCha20Poly1305Sha256, &secret)
+                .unwrap();
+
+        assert_eq!(
+            hex::encode(&keys.key),
+            "c6d98ff3441c3fe1b2182094f69caa2ed4b716b65488960a7a984979fb23e1c8"
+        );
+        assert_eq!(hex::encode(keys.iv), "e0459b3474bdd0e44a41c144");
+        assert_eq!(
+            hex::encode(&keys.hp),
+            "25a282b9e82f06f21f488917a4fc8f1b73573685608597d0efcb076b0ab7a7a4"
+        );
+        assert_eq!(
+            hex::encode(&keys.next_generation().secret),
+            "1223504755036d556342ee9361d253421a826c9ecdf3c7148684b36b714881f9"
+        );
+    }
+
+    /// RFC 9001 A.1 : le client_initial_secret, pris comme secret de
+    /// trafic, redonne les cles Initial du client.
+    #[test]
+    fn traffic_secret_derivation_matches_the_initial_keys() {
+        let secret =
+            hex::decode("c00cf151ca5be075ed0ebfb5c80323c42d6b7db67881289af4008f1f6c357aea")
+                .unwrap();
+        let keys =
+            QuicPacketKeys::from_secret(1, Tls13CipherSuite::Aes128GcmSha256, &secret).unwrap();
+        let initial = QuicInitialKeys::client(1, &RFC_DCID).unwrap();
+
+        assert_eq!(keys.key, initial.key);
+        assert_eq!(keys.iv, initial.iv);
+        assert_eq!(keys.hp, initial.hp);
+    }
+
+    #[test]
+    fn decrypts_the_rfc_9001_chacha20_short_header_packet() {
+        let secret = hex::decode(RFC_CHACHA_SECRET).unwrap();
+        let keys =
+            QuicPacketKeys::from_secret(1, Tls13CipherSuite::ChaCha20Poly1305Sha256, &secret)
+                .unwrap();
+        let packet = hex::decode(RFC_CHACHA_PACKET).unwrap();
+        let plaintext = keys.decrypt_short(&packet, 0, Some(654_360_563)).unwrap();
+
+        assert_eq!(plaintext.packet_number, 654_360_564);
+        assert!(plaintext.dcid.is_empty());
+        assert!(!plaintext.key_phase);
+        assert_eq!(
+            plaintext.frames().unwrap(),
+            vec![QuicApplicationFrame::Ping]
+        );
+    }
+
+    #[test]
+    fn decrypts_a_short_header_packet_carrying_a_stream_frame() {
+        let packet = hex::decode(SHORT_STREAM_HEX).unwrap();
+        let mut decryptor = QuicShortHeaderDecryptor::new(synthetic_keys(), SHORT_DCID.len());
+        let plaintext = decryptor.decrypt(&packet).unwrap();
+
+        assert_eq!(plaintext.dcid, SHORT_DCID);
+        assert_eq!(plaintext.packet_number, 5);
+        assert_eq!(decryptor.largest_packet_number(), Some(5));
+        let frames = plaintext.frames().unwrap();
+        let QuicApplicationFrame::Stream(stream) = &frames[0] else {
+            panic!("expected a STREAM frame, got {frames:?}");
+        };
+        assert_eq!(stream.stream_id, 0);
+        assert!(stream.fin);
+        assert_eq!(
+            hex::encode(stream.data),
+            "010f0000510b2f696e6465782e68746d6c"
+        );
+    }
+
+    #[test]
+    fn decryptor_follows_a_key_update() {
+        let mut decryptor = QuicShortHeaderDecryptor::new(synthetic_keys(), SHORT_DCID.len());
+        decryptor
+            .decrypt(&hex::decode(SHORT_STREAM_HEX).unwrap())
+            .unwrap();
+        let plaintext = decryptor
+            .decrypt(&hex::decode(SHORT_KEY_UPDATE_HEX).unwrap())
+            .unwrap();
+
+        assert!(plaintext.key_phase);
+        assert_eq!(plaintext.packet_number, 6);
+        assert_eq!(
+            plaintext.frames().unwrap(),
+            vec![
+                QuicApplicationFrame::Ping,
+                QuicApplicationFrame::Padding { length: 20 },
+            ]
+        );
+    }
+
+    #[test]
+    fn short_header_errors() {
+        let keys = synthetic_keys();
+        let mut packet = hex::decode(SHORT_STREAM_HEX).unwrap();
+
+        assert_eq!(
+            keys.decrypt_short(&hex::decode(V2_INITIAL_HEX).unwrap(), 8, None),
+            Err(QuicDecryptError::NotShortHeader)
+        );
+        assert_eq!(
+            keys.decrypt_short(&packet[..24], 8, None),
+            Err(QuicDecryptError::SampleTooShort {
+                needed: 20,
+                available: 15,
+            })
+        );
+        let last = packet.len() - 1;
+        packet[last] ^= 0x01;
+        assert_eq!(
+            keys.decrypt_short(&packet, 8, None),
+            Err(QuicDecryptError::AuthenticationFailed)
+        );
+        assert_eq!(
+            QuicPacketKeys::from_secret(1, Tls13CipherSuite::Aes256GcmSha384, &[0; 32]),
+            Err(QuicDecryptError::InvalidSecretLength {
+                expected: 48,
+                actual: 32,
+            })
+        );
+        assert_eq!(
+            format!("{keys:?}"),
+            "QuicPacketKeys(version 0x00000001, Aes128GcmSha256)"
+        );
+    }
+}
@@ -0,0 +1,970 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Dechiffrement TLS 1.2 et 1.3 depuis un journal de cles NSS
+//! (`SSLKEYLOGFILE`), tel que l'ecrivent Firefox, Chrome, curl ou OpenSSL.
+//!
+//! [`TlsKeyLog`] charge les secrets, indexes par client random ;
+//! [`TlsConnection`] suit les deux sens d'une connexion remise en ordre par
+//! TCP : client random du ClientHello, version et suite du ServerHello,
+//! puis dechiffrement de chaque enregistrement.
+//!
+//! - TLS 1.2 : cles derivees du master secret (`CLIENT_RANDOM`) par la PRF
+//!   (RFC 5246 §6.3), actives au ChangeCipherSpec de chaque sens. Suites
+//!   AEAD uniquement : AES-GCM (RFC 5288) et ChaCha20-Poly1305 (RFC 7905) ;
+//!   les suites CBC ne sont pas gerees.
+//! - TLS 1.3 : cles de handshake puis de trafic (`*_HANDSHAKE_TRAFFIC_SECRET`,
+//!   `*_TRAFFIC_SECRET_0`), bascule apres le Finished de chaque sens et
+//!   suivi des KeyUpdate. Les donnees 0-RTT ne sont pas dechiffrees.
+//!
+//! Le texte clair repasse par la table de dispatch via
+//! [`TlsPlaintextRecord::application`].
+
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+use hmac::{Hmac, KeyInit, Mac};
+use sha2::{Sha256, Sha384};
+
+use super::tls13::{
+    AEAD_NONCE_LEN, AEAD_TAG_LEN, Tls13CipherSuite, aead_nonce, aead_open, hkdf_expand_label,
+};
+use crate::{
+    Application, Transport,
+    checks::application::tls::{
+        TLS_MAX_HANDSHAKE_LEN, TLS_RANDOM_LEN, TLS_RECORD_HEADER_LEN, TLS_VERSION_1_2,
+        TLS_VERSION_1_3, extract_content_type, extract_length, extract_version, tls_plaintext_port,
+        validate_tls_ciphertext_length,
+    },
+    convert::try_hex_stream_to_bytes,
+    errors::application::tls::{TlsDecryptError, TlsKeyLogError},
+    parse::{
+        application::protocols::tls::{
+            TlsContentType,
+            handshake::{TlsHandshake, TlsHandshakeReassembler, TlsHandshakeType, TlsServerHello},
+        },
+        dispatch::classify,
+    },
+};
+
+// Labels du format NSS gerees ; les autres (0-RTT, exporter, ECH) sont
+// ignores.
+const CLIENT_RANDOM: &str = "CLIENT_RANDOM";
+const CLIENT_HANDSHAKE_TRAFFIC_SECRET: &str = "CLIENT_HANDSHAKE_TRAFFIC_SECRET";
+const SERVER_HANDSHAKE_TRAFFIC_SECRET: &str = "SERVER_HANDSHAKE_TRAFFIC_SECRET";
+const CLIENT_TRAFFIC_SECRET_0: &str = "CLIENT_TRAFFIC_SECRET_0";
+const SERVER_TRAFFIC_SECRET_0: &str = "SERVER_TRAFFIC_SECRET_0";
+
+/// Master secret TLS 1.2 (RFC 5246 §8.1).
+const TLS12_MASTER_SECRET_LEN: usize = 48;
+/// AES-GCM en TLS 1.2 : sel implicite de 4 octets et nonce explicite de 8
+/// octets en tete de chaque enregistrement (RFC 5288 §3).
+const TLS12_GCM_SALT_LEN: usize = 4;
+const TLS12_GCM_EXPLICIT_NONCE_LEN: usize = 8;
+
+#[derive(Clone, Default)]
+struct SessionSecrets {
+    master_secret: Option<Vec<u8>>,
+    client_handshake: Option<Vec<u8>>,
+    server_handshake: Option<Vec<u8>>,
+    client_traffic: Option<Vec<u8>>,
+    server_traffic: Option<Vec<u8>>,
+}
+
+/// Secrets d'un journal NSS, par client random. Construit d'un bloc avec
+/// [`parse`](Self::parse), puis complete avec
+/// [`push_lines`](Self::push_lines) si le fichier grossit pendant la
+/// capture.
+#[derive(Clone, Default)]
+pub struct TlsKeyLog {
+    sessions: HashMap<[u8; TLS_RANDOM_LEN], SessionSecrets>,
+}
+
+/// Les secrets ne sont jamais affiches, seulement le nombre de sessions.
+impl fmt::Debug for TlsKeyLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "TlsKeyLog({} sessions)", self.sessions.len())
+    }
+}
+
+impl TlsKeyLog {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Charge le contenu d'un fichier `SSLKEYLOGFILE`. Lignes vides,
+    /// commentaires `#` et labels non geres sont ignores ; une ligne d'un
+    /// label gere mais mal formee est une erreur.
+    pub fn parse(text: &str) -> Result<Self, TlsKeyLogError> {
+        let mut key_log = Self::new();
+        key_log.push_lines(text)?;
+        Ok(key_log)
+    }
+
+    /// Ajoute des lignes ; un secret deja connu est remplace. Les numeros
+    /// de ligne des erreurs sont relatifs a `text`.
+    pub fn push_lines(&mut self, text: &str) -> Result<(), TlsKeyLogError> {
+        for (index, line) in text.lines().enumerate() {
+            self.push_line(index + 1, line)?;
+        }
+        Ok(())
+    }
+
+    /// Nombre de sessions (client randoms distincts).
+    pub fn len(&self) -> usize {
+        self.sessions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sessions.is_empty()
+    }
+
+    /// Indique si le journal porte au moins un secret pour ce client random.
+    pub fn contains(&self, client_random: &[u8]) -> bool {
+        <[u8; TLS_RANDOM_LEN]>::try_from(client_random)
+            .is_ok_and(|random| self.sessions.contains_key(&random))
+    }
+
+    fn push_line(&mut self, line: usize, text: &str) -> Result<(), TlsKeyLogError> {
+        let text = text.trim();
+        if text.is_empty() || text.starts_with('#') {
+            return Ok(());
+        }
+        let mut fields = text.split_ascii_whitespace();
+        let label = fields.next().unwrap_or_default();
+        if !matches!(
+            label,
+            CLIENT_RANDOM
+                | CLIENT_HANDSHAKE_TRAFFIC_SECRET
+                | SERVER_HANDSHAKE_TRAFFIC_SECRET
+                | CLIENT_TRAFFIC_SECRET_0
+                | SERVER_TRAFFIC_SECRET_0
+        ) {
+            return Ok(());
+        }
+        let (Some(random), Some(secret), None) = (fields.next(), fields.next(), fields.next())
+        else {
+            return Err(TlsKeyLogError::MalformedLine { line });
+        };
+
+        let random = decode_hex(line, "client random", random)?;
+        let random = <[u8; TLS_RANDOM_LEN]>::try_from(random.as_slice()).map_err(|_| {
+            TlsKeyLogError::InvalidLength {
+                line,
+                field: "client random",
+                actual: random.len(),
+            }
+        })?;
+        let (field, valid) = if label == CLIENT_RANDOM {
+            ("master secret", &[TLS12_MASTER_SECRET_LEN][..])
+        } else {
+            // Taille du condensat de la suite : SHA-256 ou SHA-384.
+            ("traffic secret", &[32, 48][..])
+        };
+        let secret = decode_hex(line, field, secret)?;
+        if !valid.contains(&secret.len()) {
+            return Err(TlsKeyLogError::InvalidLength {
+                line,
+                field,
+                actual: secret.len(),
+            });
+        }
+
+        let session = self.sessions.entry(random).or_default();
+        let slot = match label {
+            CLIENT_RANDOM => &mut session.master_secret,
+            CLIENT_HANDSHAKE_TRAFFIC_SECRET => &mut session.client_handshake,
+            SERVER_HANDSHAKE_TRAFFIC_SECRET => &mut session.server_handshake,
+            CLIENT_TRAFFIC_SECRET_0 => &mut session.client_traffic,
+            _ => &mut session.server_traffic,
+        };
+        *slot = Some(secret);
+        Ok(())
+    }
+}
+
+fn decode_hex(line: usize, field: &'static str, hex: &str) -> Result<Vec<u8>, TlsKeyLogError> {
+    try_hex_stream_to_bytes(hex).map_err(|_| TlsKeyLogError::InvalidHex { line, field })
+}
+
+/// Emetteur d'un enregistrement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TlsDirection {
+    Client,
+    Server,
+}
+
+/// Enregistrement dechiffre. Les enregistrements en clair (ClientHello,
+/// ServerHello, handshake TLS 1.2 avant le ChangeCipherSpec) restent
+/// lisibles via [`crate::parse::application::protocols::tls::TlsPacket`] et
+/// ne sont pas repetes ici.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsPlaintextRecord {
+    pub direction: TlsDirection,
+    /// Type reel : celui de l'en-tete en TLS 1.2, le type interne en TLS 1.3.
+    pub content_type: TlsContentType,
+    pub data: Vec<u8>,
+}
+
+impl TlsPlaintextRecord {
+    /// Classifie des donnees applicatives par la table de dispatch, comme
+    /// si `data` etait le payload de `transport` (le segment qui portait
+    /// l'enregistrement). Les ports de TLS implicite sont remplaces par
+    /// leur port en clair (443 -> 80, 465 -> 25...) pour que les gardes de
+    /// port s'appliquent. `None` pour un autre type de contenu ou un
+    /// enregistrement vide.
+    ///
+    /// Seul le premier enregistrement d'un echange porte une signature :
+    /// les trames HTTP/2 du serveur n'ont pas de preface, et le protocole
+    /// se lit alors dans [`TlsConnection::alpn`].
+    pub fn application(&self, transport: &Transport<'_>) -> Option<Application> {
+        if self.content_type != TlsContentType::ApplicationData {
+            return None;
+        }
+        let plaintext = Transport {
+            protocol: transport.protocol,
+            source_port: transport.source_port.map(tls_plaintext_port),
+            destination_port: transport.destination_port.map(tls_plaintext_port),
+            payload: Some(&self.data),
+            details: None,
+        };
+        classify(&plaintext, &[])
+    }
+}
+
+// Cles d'un sens : AEAD, IV (sel GCM de TLS 1.2 dans les 4 premiers
+// octets) et numero de sequence, remis a zero a chaque changement de cles.
+#[derive(Clone)]
+struct RecordKeys {
+    suite: Tls13CipherSuite,
+    tls13: bool,
+    key: Vec<u8>,
+    iv: [u8; AEAD_NONCE_LEN],
+    // TLS 1.3 : secret de trafic courant, base du KeyUpdate suivant.
+    secret: Vec<u8>,
+    sequence: u64,
+}
+
+impl fmt::Debug for RecordKeys {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "RecordKeys({:?}, sequence {})",
+            self.suite, self.sequence
+        )
+    }
+}
+
+impl RecordKeys {
+    /// Cles TLS 1.3 d'un secret de trafic (RFC 8446 §7.3).
+    fn tls13(suite: Tls13CipherSuite, secret: &[u8]) -> Self {
+        let mut key = vec![0u8; suite.key_len()];
+        let mut iv = [0u8; AEAD_NONCE_LEN];
+        hkdf_expand_label(suite, secret, b"key", b"", &mut key);
+        hkdf_expand_label(suite, secret, b"iv", b"", &mut iv);
+        Self {
+            suite,
+            tls13: true,
+            key,
+            iv,
+            secret: secret.to_vec(),
+            sequence: 0,
+        }
+    }
+
+    /// Generation suivante apres un KeyUpdate (RFC 8446 §7.2).
+    fn update(&self) -> Self {
+        let mut next = vec![0u8; self.suite.hash_len()];
+        hkdf_expand_label(self.suite, &self.secret, b"traffic upd", b"", &mut next);
+        Self::tls13(self.suite, &next)
+    }
+
+    /// Dechiffre un enregistrement ; rend le type reel et le texte clair.
+    fn open(
+        &mut self,
+        header: &[u8],
+        payload: &[u8],
+    ) -> Result<(TlsContentType, Vec<u8>), TlsDecryptError> {
+        let sequence = self.sequence;
+        let explicit_nonce_len = match self.suite {
+            Tls13CipherSuite::ChaCha20Poly1305Sha256 => 0,
+            _ if self.tls13 => 0,
+            _ => TLS12_GCM_EXPLICIT_NONCE_LEN,
+        };
+        if payload.len() < explicit_nonce_len + AEAD_TAG_LEN {
+            return Err(TlsDecryptError::CiphertextTooShort {
+                length: payload.len(),
+            });
+        }
+        let (explicit_nonce, ciphertext) = payload.split_at(explicit_nonce_len);
+
+        let (nonce, aad) = if self.tls13 {
+            // AAD = en-tete de l'enregistrement (RFC 8446 §5.2).
+            (aead_nonce(&self.iv, sequence), header.to_vec())
+        } else {
+            let nonce = if explicit_nonce.is_empty() {
+                aead_nonce(&self.iv, sequence)
+            } else {
+                let mut nonce = [0u8; AEAD_NONCE_LEN];
+                nonce[..TLS12_GCM_SALT_LEN].copy_from_slice(&self.iv[..TLS12_GCM_SALT_LEN]);
+                nonce[TLS12_GCM_SALT_LEN..].copy_from_slice(explicit_nonce);
+                nonce
+            };
+            // AAD = sequence, type, version et longueur du texte clair
+            // (RFC 5246 §6.2.3.3).
+            let length = (ciphertext.len() - AEAD_TAG_LEN) as u16;
+            let mut aad = Vec::with_capacity(13);
+            aad.extend_from_slice(&sequence.to_be_bytes());
+            aad.extend_from_slice(&header[..3]);
+            aad.extend_from_slice(&length.to_be_bytes());
+            (nonce, aad)
+        };
+
+        let mut plaintext = aead_open(self.suite, &self.key, &nonce, &aad, ciphertext)
+            .ok_or(TlsDecryptError::AuthenticationFailed { sequence })?;
+        self.sequence = sequence.wrapping_add(1);
+        if !self.tls13 {
+            return Ok((extract_content_type(header)?, plaintext));
+        }
+        // TLSInnerPlaintext : contenu, type reel, bourrage de zeros.
+        let end = plaintext
+            .iter()
+            .rposition(|&byte| byte != 0)
+            .ok_or(TlsDecryptError::MissingContentType)?;
+        let content_type = TlsContentType::try_from(plaintext[end])?;
+        plaintext.truncate(end);
+        Ok((content_type, plaintext))
+    }
+}
+
+/// Suites AEAD de TLS 1.2 gerees, ramenees a la suite TLS 1.3 de meme
+/// AEAD et de meme condensat (celui de la PRF) : ECDHE/DHE/RSA avec
+/// AES-GCM (RFC 5288, RFC 5289) et ChaCha20-Poly1305 (RFC 7905).
+fn tls12_cipher_suite(id: u16) -> Option<Tls13CipherSuite> {
+    match id {
+        0x009c | 0x009e | 0xc02b | 0xc02f => Some(Tls13CipherSuite::Aes128GcmSha256),
+        0x009d | 0x009f | 0xc02c | 0xc030 => Some(Tls13CipherSuite::Aes256GcmSha384),
+        0xcca8..=0xccaa => Some(Tls13CipherSuite::ChaCha20Poly1305Sha256),
+        _ => None,
+    }
+}
+
+/// Cles client et serveur de TLS 1.2 : key_block = PRF(master_secret,
+/// "key expansion", server_random + client_random), decoupe en cles puis
+/// IV, sans cle MAC pour une suite AEAD (RFC 5246 §6.3).
+fn tls12_keys(
+    suite: Tls13CipherSuite,
+    master_secret: &[u8],
+    client_random: &[u8],
+    server_random: &[u8],
+) -> (RecordKeys, RecordKeys) {
+    let key_len = suite.key_len();
+    let iv_len = match suite {
+        Tls13CipherSuite::ChaCha20Poly1305Sha256 => AEAD_NONCE_LEN,
+        _ => TLS12_GCM_SALT_LEN,
+    };
+    let seed = [server_random, client_random].concat();
+    let mut key_block = vec![0u8; 2 * (key_len + iv_len)];
+    tls12_prf(
+        suite,
+        master_secret,
+        b"key expansion",
+        &seed,
+        &mut key_block,
+    );
+
+    let (client_key, rest) = key_block.split_at(key_len);
+    let (server_key, rest) = rest.split_at(key_len);
+    let (client_iv, server_iv) = rest.split_at(iv_len);
+    let keys = |key: &[u8], iv: &[u8]| {
+        let mut padded = [0u8; AEAD_NONCE_LEN];
+        padded[..iv.len()].copy_from_slice(iv);
+        RecordKeys {
+            suite,
+            tls13: false,
+            key: key.to_vec(),
+            iv: padded,
+            secret: Vec::new(),
+            sequence: 0,
+        }
+    };
+    (keys(client_key, client_iv), keys(server_key, server_iv))
+}
+
+/// PRF de TLS 1.2 (RFC 5246 §5) : P_SHA256, ou P_SHA384 pour les suites
+/// en SHA384.
+fn tls12_prf(suite: Tls13CipherSuite, secret: &[u8], label: &[u8], seed: &[u8], output: &mut [u8]) {
+    match suite {
+        Tls13CipherSuite::Aes256GcmSha384 => p_hash::<Hmac<Sha384>>(secret, label, seed, output),
+        _ => p_hash::<Hmac<Sha256>>(secret, label, seed, output),
+    }
+}
+
+fn p_hash<M: Mac + KeyInit + Clone>(secret: &[u8], label: &[u8], seed: &[u8], output: &mut [u8]) {
+    // HMAC accepte toute taille de cle : l'echec est impossible.
+    let Ok(mac) = <M as KeyInit>::new_from_slice(secret) else {
+        output.fill(0);
+        return;
+    };
+    // A(1) = HMAC(secret, label + seed), A(i) = HMAC(secret, A(i-1)).
+    let mut a = {
+        let mut mac = mac.clone();
+        mac.update(label);
+        mac.update(seed);
+        mac.finalize().into_bytes()
+    };
+    for chunk in output.chunks_mut(a.len()) {
+        let mut block = mac.clone();
+        block.update(&a);
+        block.update(label);
+        block.update(seed);
+        let block = block.finalize().into_bytes();
+        chunk.copy_from_slice(&block[..chunk.len()]);
+
+        let mut next = mac.clone();
+        next.update(&a);
+        a = next.finalize().into_bytes();
+    }
+}
+
+// Etat d'un sens de la connexion.
+#[derive(Debug, Clone, Default)]
+struct Endpoint {
+    buffer: Vec<u8>,
+    handshake: TlsHandshakeReassembler,
+    keys: Option<RecordKeys>,
+    // Cles a venir : en TLS 1.2 actives au ChangeCipherSpec de ce sens, en
+    // TLS 1.3 (cles de trafic) apres son Finished.
+    next_keys: Option<RecordKeys>,
+    failed: bool,
+}
+
+/// Follows both directions of a TLS connection and decrypts its records
+/// with the secrets of a [`TlsKeyLog`].
+///
+/// Feed the TCP payload of each direction, in order and in capture order
+/// across directions, with [`push_client`](Self::push_client) and
+/// [`push_server`](Self::push_server), then drain the decrypted records
+/// with [`next_record`](Self::next_record). Encrypted client records that
+/// arrive before the ServerHello are held until it is seen.
+///
+/// An error abandons the direction it occurred on (both directions when the
+/// ServerHello selects an unsupported version or suite, or when the key log
+/// lacks the session): its later bytes are ignored.
+#[derive(Debug, Clone)]
+pub struct TlsConnection<'k> {
+    key_log: &'k TlsKeyLog,
+    client: Endpoint,
+    server: Endpoint,
+    client_random: Option<[u8; TLS_RANDOM_LEN]>,
+    version: Option<u16>,
+    cipher_suite: Option<u16>,
+    alpn: Option<Vec<u8>>,
+    records: VecDeque<TlsPlaintextRecord>,
+}
+
+impl<'k> TlsConnection<'k> {
+    pub fn new(key_log: &'k TlsKeyLog) -> Self {
+        Self {
+            key_log,
+            client: Endpoint::default(),
+            server: Endpoint::default(),
+            client_random: None,
+            version: None,
+            cipher_suite: None,
+            alpn: None,
+            records: VecDeque::new(),
+        }
+    }
+
+    /// Appends bytes sent by the client.
+    pub fn push_client(&mut self, bytes: &[u8]) -> Result<(), TlsDecryptError> {
+        self.push(TlsDirection::Client, bytes)
+    }
+
+    /// Appends bytes sent by the server.
+    pub fn push_server(&mut self, bytes: &[u8]) -> Result<(), TlsDecryptError> {
+        self.push(TlsDirection::Server, bytes)
+    }
+
+    /// Pops the oldest decrypted record.
+    pub fn next_record(&mut self) -> Option<TlsPlaintextRecord> {
+        self.records.pop_front()
+    }
+
+    /// Version selected by the ServerHello (`0x0303` or `0x0304`).
+    pub fn version(&self) -> Option<u16> {
+        self.version
+    }
+
+    /// Cipher suite selected by the ServerHello.
+    pub fn cipher_suite(&self) -> Option<u16> {
+        self.cipher_suite
+    }
+
+    /// Protocol selected by the server in ALPN (`h2`, `http/1.1`...): in
+    /// TLS 1.3 the extension travels in the encrypted EncryptedExtensions,
+    /// and is only known once they are decrypted.
+    pub fn alpn(&self) -> Option<&[u8]> {
+        self.alpn.as_deref()
+    }
+
+    /// Whether `direction` was abandoned after an error.
+    pub fn has_failed(&self, direction: TlsDirection) -> bool {
+        self.endpoint(direction).failed
+    }
+
+    fn endpoint(&self, direction: TlsDirection) -> &Endpoint {
+        match direction {
+            TlsDirection::Client => &self.client,
+            TlsDirection::Server => &self.server,
+        }
+    }
+
+    fn endpoint_mut(&mut self, direction: TlsDirection) -> &mut Endpoint {
+        match direction {
+            TlsDirection::Client => &mut self.client,
+            TlsDirection::Server => &mut self.server,
+        }
+    }
+
+    fn push(&mut self, direction: TlsDirection, bytes: &[u8]) -> Result<(), TlsDecryptError> {
+        if self.endpoint(direction).failed {
+            return Ok(());
+        }
+        self.endpoint_mut(direction).buffer.extend_from_slice(bytes);
+
+        let version_known = self.version.is_some();
+        if let Err(error) = self.drain_records(direction) {
+            self.abandon(direction);
+            return Err(error);
+        }
+        // Le ServerHello debloque les enregistrements client en attente.
+        if !version_known
+            && self.version.is_some()
+            && !self.client.failed
+            && let Err(error) = self.drain_records(TlsDirection::Client)
+        {
+            self.abandon(TlsDirection::Client);
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    fn abandon(&mut self, direction: TlsDirection) {
+        *self.endpoint_mut(direction) = Endpoint {
+            failed: true,
+            ..Endpoint::default()
+        };
+    }
+
+    fn drain_records(&mut self, direction: TlsDirection) -> Result<(), TlsDecryptError> {
+        loop {
+            let waiting_for_server_hello = direction == TlsDirection::Client
+                && self.version.is_none()
+                && self.client.keys.is_none();
+            let endpoint = self.endpoint_mut(direction);
+            if endpoint.buffer.len() < TLS_RECORD_HEADER_LEN {
+                return Ok(());
+            }
+            let content_type = extract_content_type(&endpoint.buffer)?;
+            extract_version(&endpoint.buffer)?;
+            let length = usize::from(extract_length(&endpoint.buffer)?);
+            validate_tls_ciphertext_length(length)?;
+
+            // Sans ServerHello, ni le role du ChangeCipherSpec ni les cles
+            // de handshake TLS 1.3 ne sont connus.
+            if waiting_for_server_hello
+                && matches!(
+                    content_type,
+                    TlsContentType::ChangeCipherSpec | TlsContentType::ApplicationData
+                )
+            {
+                if endpoint.buffer.len() > TLS_MAX_HANDSHAKE_LEN {
+                    return Err(TlsDecryptError::MissingHandshake);
+                }
+                return Ok(());
+            }
+
+            let total = TLS_RECORD_HEADER_LEN + length;
+            if endpoint.buffer.len() < total {
+                return Ok(());
+            }
+            let record: Vec<u8> = endpoint.buffer.drain(..total).collect();
+            self.handle_record(direction, &record)?;
+        }
+    }
+
+    fn handle_record(
+        &mut self,
+        direction: TlsDirection,
+        record: &[u8],
+    ) -> Result<(), TlsDecryptError> {
+        let (header, payload) = record.split_at(TLS_RECORD_HEADER_LEN);
+        let content_type = extract_content_type(header)?;
+        let tls13 = self.version == Some(TLS_VERSION_1_3);
+        let endpoint = self.endpoint_mut(direction);
+
+        // Le ChangeCipherSpec de compatibilite de TLS 1.3 reste en clair.
+        let keys = endpoint
+            .keys
+            .as_mut()
+            .filter(|_| !(tls13 && content_type == TlsContentType::ChangeCipherSpec));
+        let (content_type, data) = match keys {
+            Some(keys) => {
+                let (content_type, data) = keys.open(header, payload)?;
+                self.records.push_back(TlsPlaintextRecord {
+                    direction,
+                    content_type,
+                    data: data.clone(),
+                });
+                (content_type, data)
+            }
+            None if content_type == TlsContentType::ApplicationData => {
+                return Err(TlsDecryptError::MissingHandshake);
+            }
+            None => (content_type, payload.to_vec()),
+        };
+
+        match content_type {
+            TlsContentType::Handshake => {
+                let endpoint = self.endpoint_mut(direction);
+                endpoint.handshake.push(&data)?;
+                let mut messages = Vec::new();
+                while let Some(message) = endpoint.handshake.next_message()? {
+                    messages.push(message);
+                }
+                for message in messages {
+                    self.handle_handshake(direction, &message)?;
+                }
+            }
+            TlsContentType::ChangeCipherSpec if self.version == Some(TLS_VERSION_1_2) => {
+                let endpoint = self.endpoint_mut(direction);
+                endpoint.keys = endpoint.next_keys.take();
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn handle_handshake(
+        &mut self,
+        direction: TlsDirection,
+        message: &[u8],
+    ) -> Result<(), TlsDecryptError> {
+        let handshake = TlsHandshake::try_from(message)?;
+        let tls13 = self.version == Some(TLS_VERSION_1_3);
+        match (direction, handshake.handshake_type) {
+            (TlsDirection::Client, TlsHandshakeType::ClientHello) => {
+                let hello = handshake.client_hello()?;
+                self.client_random = <[u8; TLS_RANDOM_LEN]>::try_from(hello.random).ok();
+            }
+            (TlsDirection::Server, TlsHandshakeType::ServerHello) => {
+                let hello = handshake.server_hello()?;
+                // Un HelloRetryRequest ne fixe rien : le vrai ServerHello suit.
+                if hello.is_hello_retry_request() {
+                    return Ok(());
+                }
+                if let Err(error) = self.install_keys(&hello) {
+                    self.abandon(TlsDirection::Client);
+                    return Err(error);
+                }
+            }
+            (_, TlsHandshakeType::EncryptedExtensions) if tls13 => {
+                let extensions = handshake.encrypted_extensions()?;
+                self.alpn = extensions.selected_alpn().map(<[u8]>::to_vec);
+            }
+            (_, TlsHandshakeType::Finished) if tls13 => {
+                let endpoint = self.endpoint_mut(direction);
+                if let Some(next_keys) = endpoint.next_keys.take() {
+                    endpoint.keys = Some(next_keys);
+                }
+            }
+            (_, TlsHandshakeType::KeyUpdate) if tls13 => {
+                let endpoint = self.endpoint_mut(direction);
+                endpoint.keys = endpoint.keys.as_ref().map(RecordKeys::update);
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn install_keys(&mut self, hello: &TlsServerHello<'_>) -> Result<(), TlsDecryptError> {
+        let version = hello.negotiated_version();
+        let suite_id = hello.cipher_suite;
+        self.version = Some(version);
+        self.cipher_suite = Some(suite_id);
+        self.alpn = hello.selected_alpn().map(<[u8]>::to_vec);
+
+        let client_random = self
+            .client_random
+            .ok_or(TlsDecryptError::MissingHandshake)?;
+        let secrets = self.key_log.sessions.get(&client_random);
+        match version {
+            TLS_VERSION_1_3 => {
+                let suite = Tls13CipherSuite::from_id(suite_id)
+                    .ok_or(TlsDecryptError::UnsupportedCipherSuite(suite_id))?;
+                let keys = |label: &'static str,
+                            secret: fn(&SessionSecrets) -> Option<&Vec<u8>>|
+                 -> Result<RecordKeys, TlsDecryptError> {
+                    let secret = secrets
+                        .and_then(secret)
+                        .ok_or(TlsDecryptError::MissingSecret { label })?;
+                    if secret.len() != suite.hash_len() {
+                        return Err(TlsDecryptError::InvalidSecretLength {
+                            label,
+                            expected: suite.hash_len(),
+                            actual: secret.len(),
+                        });
+                    }
+                    Ok(RecordKeys::tls13(suite, secret))
+                };
+                self.client.keys = Some(keys(CLIENT_HANDSHAKE_TRAFFIC_SECRET, |s| {
+                    s.client_handshake.as_ref()
+                })?);
+                self.server.keys = Some(keys(SERVER_HANDSHAKE_TRAFFIC_SECRET, |s| {
+                    s.server_handshake.as_ref()
+                })?);
+                self.client.next_keys = Some(keys(CLIENT_TRAFFIC_SECRET_0, |s| {
+                    s.client_traffic.as_ref()
+                })?);
+                self.server.next_keys = Some(keys(SERVER_TRAFFIC_SECRET_0, |s| {
+                    s.server_traffic.as_ref()
+                })?);
+            }
+            TLS_VERSION_1_2 => {
+                let suite = tls12_cipher_suite(suite_id)
+                    .ok_or(TlsDecryptError::UnsupportedCipherSuite(suite_id))?;
+                let master_secret = secrets
+                    .and_then(|secrets| secrets.master_secret.as_deref())
+                    .ok_or(TlsDecryptError::MissingSecret {
+                        label: CLIENT_RANDOM,
+                    })?;
+                let (client_keys, server_keys) =
+                    tls12_keys(suite, master_secret, &client_random, hello.random);
+                self.client.next_keys = Some(client_keys);
+                self.server.next_keys = Some(server_keys);
+            }
+            other => return Err(TlsDecryptError::UnsupportedVersion(other)),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::transport::protocols::TransportProtocol;
+
+    // Synthetique : client random 00..1f, server random 20..3f. TLS 1.3
+    // en TLS_AES_256_GCM_SHA384 ; le client envoie Finished, une requete,
+    // un KeyUpdate puis une requete sous le secret mis a jour, le serveur
+    // EncryptedExtensions + Finished (2 octets de bourrage), une reponse et
+    // une alerte close_notify. Chiffre avec Python `cryptography`.
+    const TLS13_KEY_LOG: &str = "\
+# SSL/TLS secrets log file, generated by NSS
+CLIENT_HANDSHAKE_TRAFFIC_SECRET 000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f 111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111111
+SERVER_HANDSHAKE_TRAFFIC_SECRET 000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f 222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222222
+CLIENT_TRAFFIC_SECRET_0 000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f 333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333333
+SERVER_TRAFFIC_SECRET_0 000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f 444444444444444444444444444444444444444444444444444444444444444444444444444444444444444444444444
+EXPORTER_SECRET 000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f 555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555
+";
+    const TLS13_CLIENT: &str = "160301002f0100002b0303000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f0000021302010000001403030001011703030045ec874e87f7f9432787b7ce472e18d0f2af16416d1e662b5c1bfe6b0118e14a5fb23832f2847c212a1d6cb8c731c654a2b6858b84d5b7ee97116c89eeb6ceed9f3c9436eea91703030036e427a79acdd7c2de06f262b59a85c4a1fb737e1891eca17965d018a14e9db5d2bfeba9da1df95592ca2fea9200a2c94660763e8674581703030016dbf81603caf0f1e5bfcfbc33d77aa12d0d770d183213170303003a2cb47cef52193e3bc454b0b0ad4b77aa5f2d95485b0db144a14176c8ebeb8a58b3f72ea508c0224ed6b05c8451e9b1ca454e2e6fe0172a7c7bea";
+    const TLS13_SERVER: &str = "16030300320200002e0303202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f001302000006002b00020304140303000101170303004dc0d376035dc549b2da399ca91a39375cd097998c2b9932be6cb400a5ab26f1aa091a4a841ed07e07b5c0b3f9780780be28c487288262bfcd7c0af6e3b203e4447d9f1957a323337d7a43dc8145170303002c66d5e6643950da13185cf393f3212d0d45ddddea8fb7033661dfde304ce9000fbf2d8ceda4609be8d19649991703030013f95f4650d0c4d33bb1d77ec14c445e4cba8a84";
+
+    // Synthetique : meme randoms, TLS 1.2 en
+    // TLS_ECDHE_RSA_WITH_CHACHA20_POLY1305_SHA256, master secret 0x55 * 48.
+    // Le client ouvre par la preface HTTP/2, le serveur repond SETTINGS.
+    // Chiffre avec Python `cryptography`.
+    const TLS12_KEY_LOG: &str = "CLIENT_RANDOM 000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f 555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555555";
+    const TLS12_CLIENT: &str = "160301002f0100002b0303000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f000002cca8010000001603030005100000010014030300010116030300200a50a7c03c4879d15c47805acc407bba76adb2ec557375cd713291eb8e12b96417030300286a53c727c1ef2ffe504f198258abded90bc21015d3d30bd96163ceac82eae86809f25df13daf39fa";
+    const TLS12_SERVER: &str = "1603030030020000280303202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f00cca80000000e00000014030300010116030300203b5ed687211c4fb2004c0beaa7f7bd530798f77fbb7df313f02817de316b72e2170303001947343004cec871483dc92afaea1dd1cc4c83c5ae3efcec86d7";
+
+    fn records(connection: &mut TlsConnection<'_>) -> Vec<TlsPlaintextRecord> {
+        std::iter::from_fn(|| connection.next_record()).collect()
+    }
+
+    fn https(payload: &[u8]) -> Transport<'_> {
+        Transport {
+            protocol: TransportProtocol::Tcp,
+            source_port: Some(50_000),
+            destination_port: Some(443),
+            payload: Some(payload),
+            details: None,
+        }
+    }
+
+    #[test]
+    fn key_log_skips_comments_and_unknown_labels() {
+        let key_log = TlsKeyLog::parse(TLS13_KEY_LOG).unwrap();
+
+        assert_eq!(key_log.len(), 1);
+        assert!(key_log.contains(&hex::decode(&TLS12_KEY_LOG[14..78]).unwrap()));
+        assert!(!key_log.contains(&[0u8; 32]));
+        assert_eq!(format!("{key_log:?}"), "TlsKeyLog(1 sessions)");
+    }
+
+    #[test]
+    fn key_log_rejects_malformed_lines() {
+        assert_eq!(
+            TlsKeyLog::parse("\nCLIENT_RANDOM 0001").unwrap_err(),
+            TlsKeyLogError::MalformedLine { line: 2 }
+        );
+        assert_eq!(
+            TlsKeyLog::parse(&TLS12_KEY_LOG.replace("1f 55", "1f 5z")).unwrap_err(),
+            TlsKeyLogError::InvalidHex {
+                line: 1,
+                field: "master secret",
+            }
+        );
+        assert_eq!(
+            TlsKeyLog::parse(&TLS12_KEY_LOG.replace("1e1f", "1e")).unwrap_err(),
+            TlsKeyLogError::InvalidLength {
+                line: 1,
+                field: "client random",
+                actual: 31,
+            }
+        );
+        assert_eq!(
+            TlsKeyLog::parse(&TLS12_KEY_LOG[..TLS12_KEY_LOG.len() - 2]).unwrap_err(),
+            TlsKeyLogError::InvalidLength {
+                line: 1,
+                field: "master secret",
+                actual: 47,
+            }
+        );
+    }
+
+    #[test]
+    fn tls12_prf_sha256_vector() {
+        // Vecteur de test de la PRF TLS 1.2 SHA-256 publie sur la liste
+        // IETF TLS (P_SHA256, 100 octets).
+        let secret = hex::decode("9bbe436ba940f017b17652849a71db35").unwrap();
+        let seed = hex::decode("a0ba9f936cda311827a6f796ffd5198c").unwrap();
+        let mut output = [0u8; 100];
+        tls12_prf(
+            Tls13CipherSuite::Aes128GcmSha256,
+            &secret,
+            b"test label",
+            &seed,
+            &mut output,
+        );
+
+        assert_eq!(
+            hex::encode(output),
+            "e3f229ba727be17b8d122620557cd453c2aab21d07c3d495329b52d4e61edb5a\
+             6b301791e90d35c9c9a46b4e14baf9af0fa022f7077def17abfd3797c0564bab\
+             4fbc91666e9def9b97fce34f796789baa48082d122ee42c5a72e5a5110fff701\
+             87347b66"
+        );
+    }
+
+    #[test]
+    fn tls13_session_decrypts_and_follows_key_update() {
+        let key_log = TlsKeyLog::parse(TLS13_KEY_LOG).unwrap();
+        let mut connection = TlsConnection::new(&key_log);
+        // Le client envoie tout avant le ServerHello : ses enregistrements
+        // chiffres attendent la version.
+        connection
+            .push_client(&hex::decode(TLS13_CLIENT).unwrap())
+            .unwrap();
+        assert!(records(&mut connection).is_empty());
+        connection
+            .push_server(&hex::decode(TLS13_SERVER).unwrap())
+            .unwrap();
+
+        assert_eq!(connection.version(), Some(TLS_VERSION_1_3));
+        assert_eq!(connection.cipher_suite(), Some(0x1302));
+        let records = records(&mut connection);
+        let summary: Vec<_> = records
+            .iter()
+            .map(|record| (record.direction, record.content_type, record.data.len()))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (TlsDirection::Server, TlsContentType::Handshake, 6 + 52),
+                (TlsDirection::Server, TlsContentType::ApplicationData, 27),
+                (TlsDirection::Server, TlsContentType::Alert, 2),
+                (TlsDirection::Client, TlsContentType::Handshake, 52),
+                (TlsDirection::Client, TlsContentType::ApplicationData, 37),
+                (TlsDirection::Client, TlsContentType::Handshake, 5),
+                (TlsDirection::Client, TlsContentType::ApplicationData, 41),
+            ]
+        );
+        assert!(records[6].data.starts_with(b"GET /next HTTP/1.1\r\n"));
+
+        // 443 est ramene a 80 : la table reconnait HTTP/1.1.
+        let transport = https(&[]);
+        assert_eq!(
+            records[4]
+                .application(&transport)
+                .map(|application| application.application_protocol),
+            Some("HTTP")
+        );
+        assert_eq!(records[3].application(&transport), None);
+    }
+
+    #[test]
+    fn tls12_chacha20_session_decrypts_http2() {
+        let key_log = TlsKeyLog::parse(TLS12_KEY_LOG).unwrap();
+        let mut connection = TlsConnection::new(&key_log);
+        connection
+            .push_client(&hex::decode(TLS12_CLIENT).unwrap())
+            .unwrap();
+        connection
+            .push_server(&hex::decode(TLS12_SERVER).unwrap())
+            .unwrap();
+
+        assert_eq!(connection.version(), Some(TLS_VERSION_1_2));
+        let records = records(&mut connection);
+        assert_eq!(records.len(), 4);
+        // Le serveur passe d'abord : le ChangeCipherSpec du client attendait
+        // le ServerHello.
+        assert_eq!(records[0].direction, TlsDirection::Server);
+        assert_eq!(records[0].data[..4], [0x14, 0x00, 0x00, 0x0c]);
+        assert_eq!(records[1].data, hex::decode("000000040000000000").unwrap());
+        assert_eq!(records[3].direction, TlsDirection::Client);
+        assert_eq!(records[3].data, b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n");
+        assert_eq!(
+            records[3]
+                .application(&https(&[]))
+                .map(|application| application.application_protocol),
+            Some("HTTP/2")
+        );
+    }
+
+    #[test]
+    fn missing_secret_and_tampered_record_abandon_the_connection() {
+        let empty = TlsKeyLog::new();
+        let mut connection = TlsConnection::new(&empty);
+        connection
+            .push_client(&hex::decode(TLS13_CLIENT).unwrap())
+            .unwrap();
+        assert_eq!(
+            connection.push_server(&hex::decode(TLS13_SERVER).unwrap()),
+            Err(TlsDecryptError::MissingSecret {
+                label: CLIENT_HANDSHAKE_TRAFFIC_SECRET,
+            })
+        );
+        assert!(connection.has_failed(TlsDirection::Client));
+        assert!(connection.has_failed(TlsDirection::Server));
+
+        let key_log = TlsKeyLog::parse(TLS12_KEY_LOG).unwrap();
+        let mut connection = TlsConnection::new(&key_log);
+        let mut server = hex::decode(TLS12_SERVER).unwrap();
+        let last = server.len() - 1;
+        server[last] ^= 0x01;
+        connection
+            .push_client(&hex::decode(TLS12_CLIENT).unwrap())
+            .unwrap();
+        assert_eq!(
+            connection.push_server(&server),
+            Err(TlsDecryptError::AuthenticationFailed { sequence: 1 })
+        );
+        assert!(connection.has_failed(TlsDirection::Server));
+        assert!(!connection.has_failed(TlsDirection::Client));
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Briques TLS 1.3 partagees par les dechiffrements qui en derivent leurs
+//! cles : suites AEAD (RFC 8446 §B.4), HKDF-Expand-Label (RFC 8446 §7.1)
+//! et ouverture d'un texte chiffre.
+//!
+//! QUIC protege ses paquets 1-RTT avec les secrets de trafic de la poignee
+//! de main TLS 1.3 (RFC 9001 §5.1) : seuls les labels changent.
+
+use aes_gcm::{
+    Aes128Gcm, Aes256Gcm,
+    aead::{Aead, KeyInit, Payload},
+};
+use chacha20poly1305::ChaCha20Poly1305;
+use hkdf::Hkdf;
+use sha2::{Sha256, Sha384};
+
+/// Nonce des trois AEAD geres.
+pub(crate) const AEAD_NONCE_LEN: usize = 12;
+/// Tag d'authentification des AEAD de TLS 1.3.
+pub(crate) const AEAD_TAG_LEN: usize = 16;
+
+/// Cipher suite TLS 1.3 (RFC 8446 §B.4). Les suites AES-CCM, absentes des
+/// navigateurs, ne sont pas gerees.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Tls13CipherSuite {
+    /// TLS_AES_128_GCM_SHA256 (0x1301).
+    Aes128GcmSha256,
+    /// TLS_AES_256_GCM_SHA384 (0x1302).
+    Aes256GcmSha384,
+    /// TLS_CHACHA20_POLY1305_SHA256 (0x1303).
+    ChaCha20Poly1305Sha256,
+}
+
+impl Tls13CipherSuite {
+    /// Suite designee par son identifiant dans le ServerHello.
+    pub fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0x1301 => Some(Self::Aes128GcmSha256),
+            0x1302 => Some(Self::Aes256GcmSha384),
+            0x1303 => Some(Self::ChaCha20Poly1305Sha256),
+            _ => None,
+        }
+    }
+
+    pub fn id(self) -> u16 {
+        match self {
+            Self::Aes128GcmSha256 => 0x1301,
+            Self::Aes256GcmSha384 => 0x1302,
+            Self::ChaCha20Poly1305Sha256 => 0x1303,
+        }
+    }
+
+    /// Taille de la cle AEAD, egale a celle de la cle de protection
+    /// d'en-tete QUIC.
+    pub fn key_len(self) -> usize {
+        match self {
+            Self::Aes128GcmSha256 => 16,
+            Self::Aes256GcmSha384 | Self::ChaCha20Poly1305Sha256 => 32,
+        }
+    }
+
+    /// Taille du condensat, et donc des secrets de trafic.
+    pub fn hash_len(self) -> usize {
+        match self {
+            Self::Aes128GcmSha256 | Self::ChaCha20Poly1305Sha256 => 32,
+            Self::Aes256GcmSha384 => 48,
+        }
+    }
+}
+
+/// HKDF-Expand-Label de TLS 1.3 (RFC 8446 §7.1). `label` s'entend sans le
+/// prefixe `tls13 `.
+///
+/// Un secret plus court que le condensat laisse `output` a zero : les
+/// appelants valident la taille des secrets en amont, et une cle nulle
+/// serait de toute facon rejetee par le controle d'integrite AEAD.
+pub(crate) fn hkdf_expand_label(
+    suite: Tls13CipherSuite,
+    secret: &[u8],
+    label: &[u8],
+    context: &[u8],
+    output: &mut [u8],
+) {
+    let label_length = b"tls13 ".len() + label.len();
+    let mut info = Vec::with_capacity(4 + label_length + context.len());
+    info.extend_from_slice(&(output.len() as u16).to_be_bytes());
+    info.push(label_length as u8);
+    info.extend_from_slice(b"tls13 ");
+    info.extend_from_slice(label);
+    info.push(context.len() as u8);
+    info.extend_from_slice(context);
+
+    let expanded = match suite {
+        Tls13CipherSuite::Aes256GcmSha384 => Hkdf::<Sha384>::from_prk(secret)
+            .ok()
+            .and_then(|hkdf| hkdf.expand(&info, output).ok()),
+        _ => Hkdf::<Sha256>::from_prk(secret)
+            .ok()
+            .and_then(|hkdf| hkdf.expand(&info, output).ok()),
+    };
+    if expanded.is_none() {
+        output.fill(0);
+    }
+}
+
+/// Nonce par enregistrement (RFC 8446 §5.3, RFC 9001 §5.3) : IV statique
+/// XOR le numero de sequence, aligne a droite.
+pub(crate) fn aead_nonce(iv: &[u8; AEAD_NONCE_LEN], sequence: u64) -> [u8; AEAD_NONCE_LEN] {
+    let mut nonce = *iv;
+    for (nonce_byte, sequence_byte) in nonce[AEAD_NONCE_LEN - 8..]
+        .iter_mut()
+        .zip(sequence.to_be_bytes())
+    {
+        *nonce_byte ^= sequence_byte;
+    }
+    nonce
+}
+
+/// Dechiffre et authentifie `ciphertext` (tag compris). `None` si la cle
+/// n'a pas la taille de la suite ou si le tag ne correspond pas.
+pub(crate) fn aead_open(
+    suite: Tls13CipherSuite,
+    key: &[u8],
+    nonce: &[u8; AEAD_NONCE_LEN],
+    aad: &[u8],
+    ciphertext: &[u8],
+) -> Option<Vec<u8>> {
+    let payload = Payload {
+        msg: ciphertext,
+        aad,
+    };
+    match suite {
+        Tls13CipherSuite::Aes128GcmSha256 => Aes128Gcm::new_from_slice(key)
+            .ok()?
+            .decrypt(nonce.into(), payload)
+            .ok(),
+        Tls13CipherSuite::Aes256GcmSha384 => Aes256Gcm::new_from_slice(key)
+            .ok()?
+            .decrypt(nonce.into(), payload)
+            .ok(),
+        Tls13CipherSuite::ChaCha20Poly1305Sha256 => ChaCha20Poly1305::new_from_slice(key)
+            .ok()?
+            .decrypt(nonce.into(), payload)
+            .ok(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // -----------------------------------------------------------------------
+    // RFC 8448 §3 (Simple 1-RTT Handshake) : cles d'enregistrement du
+    // serveur derivees de server_handshake_traffic_secret.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn expands_the_rfc_8448_handshake_keys() {
+        let secret =
+            hex::decode("b67b7d690cc16c4e75e54213cb2d37b4e9c912bcded9105d42befd59d391ad38")
+                .unwrap();
+        let mut key = [0u8; 16];
+        let mut iv = [0u8; AEAD_NONCE_LEN];
+        let suite = Tls13CipherSuite::Aes128GcmSha256;
+        hkdf_expand_label(suite, &secret, b"key", b"", &mut key);
+        hkdf_expand_label(suite, &secret, b"iv", b"", &mut iv);
+
+        assert_eq!(hex::encode(key), "3fce516009c21727d0f2e4e86ee403bc");
+        assert_eq!(hex::encode(iv), "5d313eb2671276ee13000b30");
+    }
+
+    #[test]
+    fn short_secret_yields_a_zero_key() {
+        let mut key = [0xffu8; 32];
+        hkdf_expand_label(
+            Tls13CipherSuite::Aes256GcmSha384,
+            &[0u8; 32],
+            b"key",
+            b"",
+            &mut key,
+        );
+
+        assert_eq!(key, [0u8; 32]);
+    }
+
+    #[test]
+    fn nonce_xors_the_sequence_number() {
+        let iv = [0u8; AEAD_NONCE_LEN];
+
+        assert_eq!(
+            hex::encode(aead_nonce(&iv, 0x0102)),
+            "000000000000000000000102"
+        );
+    }
+
+    #[test]
+    fn cipher_suite_ids_round_trip() {
+        for id in 0x1301..=0x1303 {
+            assert_eq!(
+                Tls13CipherSuite::from_id(id).map(|suite| suite.id()),
+                Some(id)
+            );
+        }
+        assert_eq!(Tls13CipherSuite::from_id(0x1304), None);
+    }
+}
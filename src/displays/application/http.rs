@@ -5,7 +5,7 @@
 
 use std::fmt::{self, Display};
 
-use crate::parse::application::protocols::http::HttpRequest;
+use crate::parse::application::protocols::http::{HttpRequest, response::HttpResponse};
 
 impl Display for HttpRequest<'_> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -17,9 +17,23 @@ impl Display for HttpRequest<'_> {
     }
 }
 
+impl Display for HttpResponse<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "HTTP Response: version={}, status={} {}, headers={:?}, body_len={}",
+            self.version,
+            self.status_code,
+            self.reason,
+            self.headers,
+            self.body.len()
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::parse::application::protocols::http::HttpRequest;
+    use crate::parse::application::protocols::http::{HttpRequest, response::HttpResponse};
 
     #[test]
     fn test_http_request_display() {
@@ -38,4 +52,21 @@ mod tests {
         assert!(rendered.contains("version=HTTP/1.1"));
         assert!(rendered.contains("Host"));
     }
+
+    #[test]
+    fn test_http_response_display() {
+        let response = HttpResponse {
+            version: "HTTP/1.1",
+            status_code: 301,
+            reason: "Moved Permanently",
+            headers: vec![("Location", "http://www.wireshark.org/")],
+            body: b"",
+        };
+
+        let rendered = response.to_string();
+        assert!(rendered.starts_with("HTTP Response:"));
+        assert!(rendered.contains("status=301 Moved Permanently"));
+        assert!(rendered.contains("Location"));
+        assert!(rendered.contains("body_len=0"));
+    }
 }
@@ -27,6 +27,8 @@ mod tests {
             (0x8100, "0x8100 (VLAN-tagged frame)"),
             (0x88CC, "0x88CC (LLDP)"),
             (0x8892, "0x8892 (Profinet)"),
+            (0x88B8, "0x88B8 (GOOSE)"),
+            (0x88BA, "0x88BA (SV)"),
             (0x88E3, "0x88E3 (MRP)"),
             (0x88F7, "0x88F7 (PTP)"),
             (0x9100, "0x9100 (Q-in-Q)"),
@@ -19,3 +19,26 @@ pub enum AmsParseError {
     #[error("Invalid AMS state flags: reserved bits set (0x{0:04x})")]
     InvalidStateFlags(u16),
 }
+
+/// Erreurs du décodage ADS (commandes portées par un paquet AMS déjà validé
+/// par [`AmsParseError`]) et des paquets de découverte UDP 48899.
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum AdsError {
+    #[error("ADS {field} truncated")]
+    Truncated { field: &'static str },
+
+    #[error("ADS {field} followed by {length} unexpected bytes")]
+    TrailingData { field: &'static str, length: usize },
+
+    /// Longueur annoncée différente des octets qui suivent.
+    #[error("ADS {field} length {declared} does not match {actual} available bytes")]
+    LengthMismatch {
+        field: &'static str,
+        declared: u32,
+        actual: usize,
+    },
+
+    #[error("Invalid ADS discovery magic 0x{0:08x}")]
+    InvalidDiscoveryMagic(u32),
+}
@@ -0,0 +1,45 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+/// Errors raised while parsing a BACnet/IP BVLC message, its NPDU or APDU.
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum BacnetError {
+    #[error("BACnet/IP message too short: expected at least {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("BVLC type must be 0x81 (BACnet/IP), got {0:#04x}")]
+    InvalidBvlcType(u8),
+
+    #[error("unknown BVLC function {0:#04x}")]
+    UnknownBvlcFunction(u8),
+
+    #[error("BVLC length {declared} does not match the {actual} bytes of the datagram")]
+    BvlcLengthMismatch { declared: u16, actual: usize },
+
+    #[error("BACnet NPDU version must be 1, got {0}")]
+    InvalidNpduVersion(u8),
+
+    #[error("unknown BACnet APDU type {0}")]
+    UnknownApduType(u8),
+
+    #[error("BACnet {field}: expected tag {expected}, got {actual}")]
+    UnexpectedTag {
+        field: &'static str,
+        expected: u8,
+        actual: u8,
+    },
+
+    #[error("BACnet closing tag {0} without a matching opening tag")]
+    UnbalancedClosingTag(u8),
+
+    #[error("BACnet {field} is {length} bytes long, which does not fit its type")]
+    InvalidTagLength { field: &'static str, length: usize },
+
+    #[error("BACnet {field} truncated")]
+    Truncated { field: &'static str },
+}
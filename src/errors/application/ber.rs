@@ -0,0 +1,39 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+/// Errors raised by the shared BER/DER decoder (X.690).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum BerError {
+    #[error("BER {field} truncated")]
+    Truncated { field: &'static str },
+
+    #[error("BER {field} is missing")]
+    Missing { field: &'static str },
+
+    #[error("unsupported BER length form in {field}")]
+    UnsupportedLength { field: &'static str },
+
+    #[error("BER tag number of {field} does not fit 32 bits")]
+    TagOverflow { field: &'static str },
+
+    #[error("unexpected BER tag (class {class:#04x}, number {number}) in {field}")]
+    UnexpectedTag {
+        field: &'static str,
+        class: u8,
+        number: u32,
+    },
+
+    #[error("BER {field} is {length} bytes long, which does not fit its type")]
+    InvalidValueLength { field: &'static str, length: usize },
+
+    #[error("BER {field} is not a valid UTF-8 string")]
+    InvalidString { field: &'static str },
+
+    #[error("BER {field} is not a valid bit string")]
+    InvalidBitString { field: &'static str },
+}
@@ -0,0 +1,42 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+/// Errors raised while parsing a BGP-4 message (RFC 4271).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum BgpError {
+    #[error("BGP message too short: expected at least {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("BGP marker must be 16 bytes of 0xff")]
+    InvalidMarker,
+
+    #[error("BGP length field {0} is below the 19-byte header")]
+    InvalidMessageLength(u16),
+
+    #[error("BGP message declares {declared} bytes but only {available} are available")]
+    TruncatedMessage { declared: usize, available: usize },
+
+    #[error("unknown BGP message type {0}: defined types are 1 to 5")]
+    UnknownMessageType(u8),
+
+    #[error("unsupported BGP version {0} in OPEN: only BGP-4 is defined")]
+    UnsupportedVersion(u8),
+
+    #[error("BGP KEEPALIVE must be exactly 19 bytes, got {0}")]
+    InvalidKeepaliveLength(u16),
+
+    #[error("BGP {field} declares {declared} bytes but only {available} remain")]
+    TruncatedField {
+        field: &'static str,
+        declared: usize,
+        available: usize,
+    },
+
+    #[error("BGP prefix length {length} exceeds the {max_bits} bits of its address family")]
+    InvalidPrefixLength { length: u8, max_bits: u8 },
+}
@@ -22,3 +22,22 @@ pub enum DhcpParseError {
     #[error("DHCP options area does not start with the RFC 2131 magic cookie")]
     InvalidMagicCookie,
 }
+
+/// Errors raised while decoding the options of an already parsed DHCPv4
+/// message (RFC 2132) or tracking leases from them.
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DhcpOptionError {
+    #[error("DHCP option {code} truncated: expected {expected} bytes, got {actual}")]
+    TruncatedOption {
+        code: u8,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[error("DHCP option {code} has an invalid length of {length} bytes")]
+    InvalidOptionLength { code: u8, length: usize },
+
+    #[error("DHCP option {code} carries an invalid domain name")]
+    InvalidDomainName { code: u8 },
+}
@@ -13,3 +13,28 @@ pub enum Dhcpv6PacketParseError {
     #[error("Invalid DHCPv6 message type: {message_type}")]
     MessageType { message_type: u8 },
 }
+
+/// Errors raised while decoding the options and relay header of an already
+/// parsed DHCPv6 message (RFC 8415 §9, §21).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum Dhcpv6OptionError {
+    #[error("DHCPv6 option {code} truncated: expected {expected} bytes, got {actual}")]
+    TruncatedOption {
+        code: u16,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[error("DHCPv6 option {code} has an invalid length of {length} bytes")]
+    InvalidOptionLength { code: u16, length: usize },
+
+    #[error("DHCPv6 option {code} carries an invalid domain name")]
+    InvalidDomainName { code: u16 },
+
+    #[error("DHCPv6 options nested deeper than {max} levels")]
+    OptionNestingTooDeep { max: usize },
+
+    #[error("DHCPv6 relay message too short: expected at least {expected} bytes, got {actual}")]
+    RelayLength { expected: usize, actual: usize },
+}
@@ -0,0 +1,45 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+/// Errors raised while parsing a DNP3 link frame, transport segment or
+/// application fragment (IEEE 1815).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum Dnp3Error {
+    #[error("DNP3 frame too short: expected at least {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("DNP3 start bytes must be 0x05 0x64, got {0:#06x}")]
+    InvalidStart(u16),
+
+    #[error("DNP3 length field {0} is below the 5 bytes of control and addresses")]
+    InvalidFrameLength(u8),
+
+    #[error(
+        "DNP3 CRC mismatch in block {block}: computed {computed:#06x}, received {received:#06x}"
+    )]
+    CrcMismatch {
+        block: usize,
+        computed: u16,
+        received: u16,
+    },
+
+    #[error("DNP3 transport segment carries no application data")]
+    EmptySegment,
+
+    #[error("DNP3 {field} truncated")]
+    Truncated { field: &'static str },
+
+    #[error("unknown DNP3 object qualifier {0:#04x}")]
+    UnknownQualifier(u8),
+
+    #[error("DNP3 object group {group} variation {variation} has no known size")]
+    UnknownObjectSize { group: u8, variation: u8 },
+
+    #[error("DNP3 range start {start} is above stop {stop}")]
+    InvalidRange { start: u32, stop: u32 },
+}
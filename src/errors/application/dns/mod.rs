@@ -74,3 +74,36 @@ pub enum DnsFlagsError {
     #[error("Rcode = 5, AA must be 0 in Refused responses. Here it's: {0}")]
     AaInRefused(u16),
 }
+
+/// Errors raised while walking borrowed resource records
+/// ([`DnsMessage`](crate::parse::application::protocols::dns::message::DnsMessage))
+/// and decoding their typed data
+/// ([`RData`](crate::parse::application::protocols::dns::rdata::RData)).
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum DnsRDataError {
+    #[error("DNS name error: {0}")]
+    Name(#[from] DnsQueryParseError),
+
+    #[error("Truncated DNS {field}: declared {declared} bytes, only {available} available")]
+    TruncatedField {
+        field: &'static str,
+        declared: usize,
+        available: usize,
+    },
+
+    #[error("Trailing data after DNS {field}: {remaining} bytes left")]
+    TrailingData {
+        field: &'static str,
+        remaining: usize,
+    },
+
+    #[error("Invalid DNSSEC type bitmap: window {window} with length {length}")]
+    InvalidTypeBitmap { window: u8, length: usize },
+
+    #[error("SvcParam keys out of order: key {key} after key {previous}")]
+    UnorderedSvcParamKey { previous: u16, key: u16 },
+
+    #[error("Invalid EDNS option {code}: {reason}")]
+    InvalidEdnsOption { code: u16, reason: &'static str },
+}
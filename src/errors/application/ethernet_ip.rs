@@ -47,3 +47,37 @@ pub enum EtherNetIpError {
     #[error("EtherNet/IP CPF has trailing bytes: consumed {consumed}, actual {actual}")]
     TrailingCpfData { consumed: usize, actual: usize },
 }
+
+/// Erreurs du décodage CIP (messages explicites, chemins EPATH, Forward
+/// Open, identité, I/O implicites) porté par une encapsulation déjà validée
+/// par [`EtherNetIpError`].
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CipError {
+    #[error("CIP {field} truncated")]
+    Truncated { field: &'static str },
+
+    #[error("CIP {field} followed by {length} unexpected bytes")]
+    TrailingData { field: &'static str, length: usize },
+
+    /// Type de segment EPATH réservé ou non pris en charge.
+    #[error("Unsupported CIP path segment 0x{segment:02X}")]
+    UnsupportedPathSegment { segment: u8 },
+
+    /// Nom de tag symbolique qui n'est pas de l'ASCII imprimable.
+    #[error("CIP symbolic segment is not printable ASCII")]
+    InvalidSymbol,
+
+    /// Offset d'un service de Multiple Service Packet hors du paquet ou
+    /// antérieur au précédent.
+    #[error("Invalid CIP Multiple Service Packet offset {offset} for service {index}")]
+    InvalidServiceOffset { index: usize, offset: u16 },
+
+    /// Item CPF attendu absent (Sequenced Address ou Connected Data d'une
+    /// trame d'I/O implicite).
+    #[error("CIP I/O packet without a 0x{type_id:04X} item")]
+    MissingItem { type_id: u16 },
+
+    #[error(transparent)]
+    Encapsulation(#[from] EtherNetIpError),
+}
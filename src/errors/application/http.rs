@@ -28,3 +28,44 @@ pub enum HttpParseError {
     #[error("Invalid HTTP header")]
     InvalidHeader,
 }
+
+/// Errors of HTTP/1.x responses, message framing and transaction tracking.
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum HttpMessageError {
+    #[error(transparent)]
+    Parse(#[from] HttpParseError),
+
+    #[error("Missing HTTP status code")]
+    MissingStatusCode,
+
+    #[error("Invalid HTTP status code: {0}")]
+    InvalidStatusCode(String),
+
+    #[error("Invalid Content-Length: {0}")]
+    InvalidContentLength(String),
+
+    #[error("Conflicting Content-Length values: {first} and {second}")]
+    ConflictingContentLength { first: usize, second: usize },
+
+    #[error("Unsupported request transfer coding: {0}")]
+    UnsupportedTransferCoding(String),
+
+    #[error("Invalid chunk size: {0}")]
+    InvalidChunkSize(String),
+
+    #[error("Missing CRLF after chunk data")]
+    MissingChunkTerminator,
+
+    #[error("Chunked body ends before its last chunk")]
+    IncompleteChunkedBody,
+
+    #[error("Body truncated: {declared} bytes declared, {available} available")]
+    TruncatedBody { declared: usize, available: usize },
+
+    #[error("HTTP line or head longer than {limit} bytes")]
+    LineTooLong { limit: usize },
+
+    #[error("HTTP response {status_code} without a pending request")]
+    UnsolicitedResponse { status_code: u16 },
+}
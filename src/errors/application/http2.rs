@@ -0,0 +1,96 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+/// Errors of HTTP/2 framing and connection tracking (RFC 9113).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum Http2Error {
+    #[error("HTTP/2 frame header truncated: {available} bytes")]
+    TruncatedFrameHeader { available: usize },
+
+    #[error("HTTP/2 frame truncated: {declared} bytes declared, {available} available")]
+    TruncatedFrame { declared: usize, available: usize },
+
+    #[error("HTTP/2 frame of {length} bytes exceeds the {max} bytes limit")]
+    FrameTooLarge { length: usize, max: usize },
+
+    #[error("Invalid stream id {stream_id} for HTTP/2 frame type {frame_type:#04x}")]
+    InvalidStreamId { frame_type: u8, stream_id: u32 },
+
+    #[error("Invalid length {length} for HTTP/2 frame type {frame_type:#04x}")]
+    InvalidFrameLength { frame_type: u8, length: usize },
+
+    #[error("HTTP/2 padding longer than the frame payload")]
+    InvalidPadding,
+
+    #[error("Invalid HTTP/2 connection preface")]
+    InvalidPreface,
+
+    #[error(
+        "Expected CONTINUATION for stream {expected}, got frame type {frame_type:#04x} on stream {stream_id}"
+    )]
+    ExpectedContinuation {
+        expected: u32,
+        frame_type: u8,
+        stream_id: u32,
+    },
+
+    #[error("CONTINUATION frame without a preceding HEADERS on stream {0}")]
+    UnexpectedContinuation(u32),
+
+    #[error("Header block of {length} bytes exceeds the {limit} bytes limit")]
+    HeaderBlockTooLarge { length: usize, limit: usize },
+
+    #[error(transparent)]
+    Hpack(#[from] HpackError),
+}
+
+/// Errors of HPACK header block decoding (RFC 7541).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum HpackError {
+    #[error("HPACK header block truncated")]
+    Truncated,
+
+    #[error("HPACK integer overflow")]
+    IntegerOverflow,
+
+    #[error("HPACK index {0} is out of the header tables")]
+    InvalidIndex(usize),
+
+    #[error("HPACK dynamic table size update to {size} exceeds the {limit} bytes limit")]
+    TableSizeTooLarge { size: usize, limit: usize },
+
+    #[error("HPACK dynamic table size update after the first header field")]
+    LateTableSizeUpdate,
+
+    #[error("Invalid HPACK Huffman padding")]
+    InvalidHuffmanPadding,
+
+    #[error("HPACK Huffman string contains the EOS symbol")]
+    HuffmanEos,
+}
+
+/// Errors of gRPC message framing over HTTP/2.
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum GrpcError {
+    #[error("gRPC message prefix truncated: {available} bytes")]
+    TruncatedPrefix { available: usize },
+
+    #[error("Invalid gRPC compressed flag: {0}")]
+    InvalidCompressedFlag(u8),
+
+    #[error("gRPC message truncated: {declared} bytes declared, {available} available")]
+    TruncatedMessage { declared: usize, available: usize },
+
+    #[error("gRPC message of {length} bytes exceeds the {limit} bytes limit")]
+    MessageTooLarge { length: usize, limit: usize },
+
+    #[error("Invalid gRPC path: {0}")]
+    InvalidPath(String),
+}
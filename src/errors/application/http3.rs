@@ -0,0 +1,90 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+use super::{http2::HpackError, quic::QuicStreamError};
+
+/// Errors of HTTP/3 framing and connection tracking (RFC 9114).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum Http3Error {
+    #[error("HTTP/3 frame header truncated: {available} bytes")]
+    TruncatedFrameHeader { available: usize },
+
+    #[error("HTTP/3 frame truncated: {declared} bytes declared, {available} available")]
+    TruncatedFrame { declared: u64, available: usize },
+
+    #[error("HTTP/3 frame type {frame_type:#x} of {length} bytes exceeds the {max} bytes limit")]
+    FrameTooLarge {
+        frame_type: u64,
+        length: u64,
+        max: usize,
+    },
+
+    #[error("HTTP/2 frame type {0:#x} is reserved in HTTP/3")]
+    ReservedFrameType(u64),
+
+    #[error("Malformed HTTP/3 frame type {0:#x}")]
+    MalformedFrame(u64),
+
+    #[error("HTTP/3 setting {0:#x} sent twice")]
+    DuplicateSetting(u64),
+
+    #[error("HTTP/3 frame type {frame_type:#x} not allowed on stream {stream_id}")]
+    UnexpectedFrame { frame_type: u64, stream_id: u64 },
+
+    #[error("HTTP/3 control stream {0} does not start with SETTINGS")]
+    MissingSettings(u64),
+
+    #[error("Second HTTP/3 unidirectional stream of type {stream_type:#x} (stream {stream_id})")]
+    DuplicateStream { stream_type: u64, stream_id: u64 },
+
+    #[error("HTTP/3 control stream {0} closed")]
+    ClosedCriticalStream(u64),
+
+    #[error(transparent)]
+    Stream(#[from] QuicStreamError),
+
+    #[error(transparent)]
+    Qpack(#[from] QpackError),
+}
+
+/// Errors of QPACK field section and encoder stream decoding (RFC 9204).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum QpackError {
+    /// Integer, string literal or Huffman error: QPACK reuses the HPACK
+    /// primitives (RFC 9204 §4.1).
+    #[error(transparent)]
+    Encoding(#[from] HpackError),
+
+    #[error("Invalid QPACK static table index {0}")]
+    InvalidStaticIndex(usize),
+
+    #[error("Invalid QPACK dynamic table absolute index {0}")]
+    InvalidDynamicIndex(u64),
+
+    #[error("QPACK table capacity {capacity} exceeds the {max} bytes maximum")]
+    CapacityExceeded { capacity: usize, max: usize },
+
+    #[error("QPACK entry of {size} bytes exceeds the {capacity} bytes table capacity")]
+    EntryTooLarge { size: usize, capacity: usize },
+
+    #[error("Invalid QPACK Required Insert Count encoding {0}")]
+    InvalidRequiredInsertCount(usize),
+
+    #[error("QPACK Base below zero")]
+    InvalidBase,
+
+    #[error("QPACK field section needs {required} inserts, {received} received")]
+    Blocked { required: u64, received: u64 },
+
+    #[error("QPACK encoder instruction of {length} bytes exceeds the {max} bytes limit")]
+    InstructionTooLarge { length: usize, max: usize },
+
+    #[error("More than {max} QPACK field sections blocked")]
+    TooManyBlockedSections { max: usize },
+}
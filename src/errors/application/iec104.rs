@@ -0,0 +1,38 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+/// Errors raised while parsing an IEC 60870-5-104 APDU or its ASDU.
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum Iec104Error {
+    #[error("IEC 104 APDU too short: expected at least {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("IEC 104 start byte must be 0x68, got {0:#04x}")]
+    InvalidStart(u8),
+
+    #[error("IEC 104 APDU length {0} is outside 4..=253")]
+    InvalidApduLength(u8),
+
+    #[error("IEC 104 APDU declares {declared} bytes but only {available} are available")]
+    TruncatedApdu { declared: usize, available: usize },
+
+    #[error("IEC 104 {format}-format APDU must be 4 bytes long, got {length}")]
+    UnexpectedAsdu { format: char, length: u8 },
+
+    #[error("unknown IEC 104 U-format function {0:#04x}")]
+    UnknownUFunction(u8),
+
+    #[error("unknown IEC 104 ASDU type id {0}")]
+    UnknownTypeId(u8),
+
+    #[error("IEC 104 ASDU declares {declared} information objects, {actual} bytes do not match")]
+    ObjectCountMismatch { declared: u8, actual: usize },
+
+    #[error("IEC 104 {field} truncated")]
+    Truncated { field: &'static str },
+}
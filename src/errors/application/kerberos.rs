@@ -0,0 +1,40 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+use super::ber::BerError;
+
+/// Errors raised while decoding a Kerberos V5 message (RFC 4120).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum KerberosError {
+    #[error(transparent)]
+    Ber(#[from] BerError),
+
+    #[error("Kerberos TCP record too short: expected at least {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("Kerberos TCP record sets the reserved length bit")]
+    ReservedLengthBit,
+
+    #[error("Kerberos TCP record declares {declared} bytes but only {available} are available")]
+    TruncatedRecord { declared: usize, available: usize },
+
+    #[error("unknown Kerberos message [APPLICATION {0}]")]
+    UnknownMessageType(u32),
+
+    #[error("unsupported Kerberos protocol version {0}")]
+    InvalidVersion(i64),
+
+    #[error("Kerberos msg-type {msg_type} does not match [APPLICATION {tag}]")]
+    MessageTypeMismatch { tag: u32, msg_type: i64 },
+
+    #[error("Kerberos {field} is not a valid KerberosTime")]
+    InvalidTime { field: &'static str },
+
+    #[error("GSS-API token does not wrap a Kerberos V5 message")]
+    InvalidGssToken,
+}
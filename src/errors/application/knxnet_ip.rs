@@ -0,0 +1,30 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+/// Errors raised while parsing a KNXnet/IP frame or the cEMI message it
+/// carries.
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum KnxnetIpError {
+    #[error("KNXnet/IP frame too short: expected at least {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("KNXnet/IP header length must be 6, got {0}")]
+    InvalidHeaderLength(u8),
+
+    #[error("unsupported KNXnet/IP protocol version {0:#04x}")]
+    UnsupportedVersion(u8),
+
+    #[error("KNXnet/IP frame declares {declared} bytes but only {available} are available")]
+    TruncatedFrame { declared: usize, available: usize },
+
+    #[error("KNXnet/IP {field} length {length} is invalid")]
+    InvalidStructureLength { field: &'static str, length: u8 },
+
+    #[error("KNXnet/IP {field} truncated")]
+    Truncated { field: &'static str },
+}
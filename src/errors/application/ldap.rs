@@ -0,0 +1,28 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+use super::ber::BerError;
+
+/// Errors raised while decoding LDAP or CLDAP messages (RFC 4511).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum LdapError {
+    #[error(transparent)]
+    Ber(#[from] BerError),
+
+    #[error("LDAP payload carries no message")]
+    Empty,
+
+    #[error("LDAP message id {0} is out of range")]
+    InvalidMessageId(i64),
+
+    #[error("unknown LDAP search filter choice {0}")]
+    UnknownFilter(u32),
+
+    #[error("LDAP search filter nested deeper than {0} levels")]
+    FilterTooDeep(usize),
+}
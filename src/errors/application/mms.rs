@@ -0,0 +1,56 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+use super::copt::CotpParseError;
+
+/// Errors raised while walking the OSI upper layers down to an MMS PDU, or
+/// while decoding it.
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MmsError {
+    #[error("TPKT frame too short: expected at least {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("TPKT version must be 3, got {0}")]
+    InvalidTpktVersion(u8),
+
+    #[error("TPKT declares {declared} bytes but only {available} are available")]
+    TruncatedTpkt { declared: usize, available: usize },
+
+    #[error(transparent)]
+    Cotp(#[from] CotpParseError),
+
+    #[error("COTP TPDU {0:#04x} does not carry session data")]
+    NotCotpData(u8),
+
+    #[error("COTP data unit is not the last of its TSDU (EOT not set)")]
+    FragmentedTsdu,
+
+    #[error("unsupported session SPDU {0:#04x}")]
+    UnsupportedSpdu(u8),
+
+    #[error("{0} carries no user data")]
+    MissingUserData(&'static str),
+
+    #[error("unexpected tag {tag} in MMS {field}")]
+    UnexpectedTag { field: &'static str, tag: u32 },
+
+    #[error("unsupported BER length form in MMS {field}")]
+    UnsupportedLength { field: &'static str },
+
+    #[error("MMS {field} is {length} bytes long, which does not fit its type")]
+    InvalidValueLength { field: &'static str, length: usize },
+
+    #[error("MMS {field} is not a visible string")]
+    InvalidIdentifier { field: &'static str },
+
+    #[error("MMS data nested deeper than {0} levels")]
+    NestingTooDeep(usize),
+
+    #[error("MMS {field} truncated")]
+    Truncated { field: &'static str },
+}
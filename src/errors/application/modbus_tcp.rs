@@ -22,3 +22,31 @@ pub enum ModbusTcpError {
     #[error("Modbus/TCP PDU too small: needed {needed} bytes, got {actual}")]
     PduTooSmall { needed: usize, actual: usize },
 }
+
+/// Erreurs du décodage typé (requêtes, réponses, identification MEI) d'une
+/// PDU dont le cadrage MBAP est déjà validé par [`ModbusTcpError`].
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum ModbusDecodeError {
+    #[error("Modbus {field} truncated")]
+    Truncated { field: &'static str },
+
+    #[error("Modbus {field} followed by {length} unexpected bytes")]
+    TrailingData { field: &'static str, length: usize },
+
+    /// Byte count d'une écriture multiple incohérent avec la quantité.
+    #[error("Modbus byte count {declared} does not match {expected} bytes for the quantity")]
+    ByteCountMismatch { declared: u8, expected: usize },
+
+    /// Réponse de lecture de registres au byte count impair.
+    #[error("Modbus register byte count {byte_count} is odd")]
+    OddRegisterByteCount { byte_count: u8 },
+
+    /// Write Single Coil n'admet que 0xFF00 (ON) et 0x0000 (OFF).
+    #[error("Invalid Modbus coil value {0:#06X}")]
+    InvalidCoilValue(u16),
+
+    /// More Follows d'une identification d'équipement ni 0x00 ni 0xFF.
+    #[error("Invalid Modbus device identification More Follows {0:#04X}")]
+    InvalidMoreFollows(u8),
+}
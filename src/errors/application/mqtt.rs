@@ -99,6 +99,58 @@ pub enum MqttError {
     #[error("Malformed MQTT {packet_type:?} payload")]
     MalformedSubscriptionPayload { packet_type: MqttPacketType },
 }
+
+/// Erreurs du décodage typé (CONNECT, PUBLISH, propriétés MQTT 5...) d'un
+/// paquet dont le cadrage est déjà validé par [`MqttError`].
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum MqttDecodeError {
+    #[error(transparent)]
+    Packet(#[from] MqttError),
+
+    #[error("MQTT {field} truncated")]
+    Truncated { field: &'static str },
+
+    /// Chaîne qui n'est pas de l'UTF-8 valide ou contient U+0000
+    /// (MQTT 5 §1.5.4).
+    #[error("MQTT {field} is not a valid UTF-8 string")]
+    InvalidUtf8 { field: &'static str },
+
+    #[error("MQTT {field} followed by {length} unexpected bytes")]
+    TrailingData { field: &'static str, length: usize },
+
+    #[error("Malformed MQTT variable byte integer in {field}")]
+    MalformedVarint { field: &'static str },
+
+    /// Identifiant de propriété inconnu : sa taille ne l'est pas non plus,
+    /// le reste du bloc est illisible.
+    #[error("Unknown MQTT property identifier {0:#04X}")]
+    UnknownProperty(u8),
+
+    /// Propriété présente deux fois alors qu'elle est unique (toutes sauf
+    /// User Property et Subscription Identifier).
+    #[error("MQTT property {0:#04X} included more than once")]
+    DuplicateProperty(u8),
+
+    /// Will QoS ou Will Retain posés sans Will Flag.
+    #[error("Invalid MQTT CONNECT will flags {flags:#04X}")]
+    InvalidWillFlags { flags: u8 },
+
+    #[error("Invalid MQTT QoS: {qos}")]
+    InvalidQos { qos: u8 },
+
+    /// Options de souscription à bits réservés posés, QoS 3 ou Retain
+    /// Handling 3.
+    #[error("Invalid MQTT subscription options {0:#04X}")]
+    InvalidSubscriptionOptions(u8),
+
+    #[error("MQTT {packet_type} without any {field}")]
+    MissingEntries {
+        packet_type: MqttPacketType,
+        field: &'static str,
+    },
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -225,4 +277,24 @@ mod tests {
 
         assert!(dbg.contains("MalformedRemainingLength"));
     }
+
+    #[test]
+    fn test_decode_error_display() {
+        assert_eq!(
+            MqttDecodeError::UnknownProperty(0x7F).to_string(),
+            "Unknown MQTT property identifier 0x7F"
+        );
+        assert_eq!(
+            MqttDecodeError::MissingEntries {
+                packet_type: MqttPacketType::Suback,
+                field: "reason code",
+            }
+            .to_string(),
+            "MQTT SUBACK without any reason code"
+        );
+        assert_eq!(
+            MqttDecodeError::from(MqttError::EmptyTopic).to_string(),
+            "Empty MQTT topic"
+        );
+    }
 }
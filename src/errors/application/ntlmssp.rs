@@ -0,0 +1,33 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+/// Errors raised while decoding an NTLMSSP message (MS-NLMP).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum NtlmsspError {
+    #[error("missing NTLMSSP signature")]
+    InvalidSignature,
+
+    #[error("NTLMSSP message too short: expected at least {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("unknown NTLMSSP message type {0}")]
+    UnknownMessageType(u32),
+
+    #[error("NTLMSSP {field} at offset {offset} ({length} bytes) lies outside the message")]
+    InvalidField {
+        field: &'static str,
+        offset: usize,
+        length: usize,
+    },
+
+    #[error("NTLMSSP {field} is not valid UTF-16")]
+    InvalidUtf16 { field: &'static str },
+
+    #[error("NTLMSSP AV_PAIR list truncated")]
+    TruncatedAvPair,
+}
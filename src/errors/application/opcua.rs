@@ -34,3 +34,42 @@ pub enum OpcuaParseError {
     #[error("invalid UTF-8 in OPC UA string")]
     InvalidUtf8,
 }
+
+/// Errors raised while decoding the secure conversation layer and the
+/// binary-encoded services it carries. Kept apart from [`OpcuaParseError`],
+/// which only covers the transport chunks.
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum OpcuaDecodeError {
+    #[error("OPC UA {field} truncated")]
+    Truncated { field: &'static str },
+
+    /// String, ByteString or array length below -1.
+    #[error("invalid OPC UA {field} length: {length}")]
+    InvalidLength { field: &'static str, length: i32 },
+
+    #[error("invalid UTF-8 in OPC UA {field}")]
+    InvalidUtf8 { field: &'static str },
+
+    #[error("unknown OPC UA NodeId encoding 0x{0:02x}")]
+    UnknownNodeIdEncoding(u8),
+
+    #[error("unknown OPC UA ExtensionObject encoding 0x{0:02x}")]
+    UnknownExtensionObjectEncoding(u8),
+
+    #[error("unknown OPC UA Variant type {0}")]
+    UnknownVariantType(u8),
+
+    /// Variant, DataValue or DiagnosticInfo nested deeper than
+    /// `OPCUA_MAX_NESTING_DEPTH`.
+    #[error("OPC UA value nested too deep")]
+    NestingTooDeep,
+
+    /// Reassembled message larger than `OPCUA_MAX_MESSAGE_SIZE`.
+    #[error("OPC UA message of {size} bytes exceeds the reassembly limit")]
+    MessageTooLarge { size: usize },
+
+    /// Chunk cut by the end of the buffer (`OpcuaPayload::Partial`).
+    #[error("incomplete OPC UA chunk")]
+    IncompleteChunk,
+}
@@ -29,3 +29,50 @@ pub enum QuicError {
     #[error("Payload too short: expected {expected} bytes, only {available} available")]
     PayloadTooShort { expected: usize, available: usize },
 }
+
+/// Errors raised while decrypting a QUIC packet (RFC 9001 §5, RFC 9369
+/// §3.3) or reassembling its CRYPTO stream.
+#[non_exhaustive]
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum QuicDecryptError {
+    #[error(transparent)]
+    Packet(#[from] QuicError),
+
+    #[error("QUIC packet is not an Initial packet")]
+    NotInitial,
+
+    #[error(
+        "QUIC packet too short for header protection: needed {needed} bytes after the packet number offset, {available} available"
+    )]
+    SampleTooShort { needed: usize, available: usize },
+
+    #[error("QUIC packet is not a Short Header (1-RTT) packet")]
+    NotShortHeader,
+
+    #[error("QUIC packet integrity check failed (wrong keys or altered packet)")]
+    AuthenticationFailed,
+
+    #[error("Traffic secret of {actual} bytes, the cipher suite expects {expected}")]
+    InvalidSecretLength { expected: usize, actual: usize },
+
+    #[error("CRYPTO data ending at offset {end} exceeds the {max}-byte reassembly limit")]
+    CryptoBufferExceeded { end: u64, max: usize },
+}
+
+/// Errors raised while reassembling the bytes of a QUIC stream from its
+/// STREAM frames (RFC 9000 §2.2, §4.5).
+#[non_exhaustive]
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum QuicStreamError {
+    #[error("STREAM data ending at offset {end} exceeds the 2^62-1 stream limit")]
+    OffsetOverflow { end: u64 },
+
+    #[error("Out-of-order STREAM data of {buffered} bytes exceeds the {max}-byte limit")]
+    BufferExceeded { buffered: usize, max: usize },
+
+    #[error("Stream final size changed from {previous} to {new}")]
+    FinalSizeChanged { previous: u64, new: u64 },
+
+    #[error("STREAM data ending at offset {end} goes past the final size {final_size}")]
+    DataBeyondFinalSize { end: u64, final_size: u64 },
+}
@@ -0,0 +1,30 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+/// Errors raised while parsing a RIP message (RFC 1058, RFC 2453) or a
+/// RIPng message (RFC 2080).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum RipError {
+    #[error("RIP message too short: expected at least {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("unknown RIP command {0}: only request (1) and response (2) are defined")]
+    UnknownCommand(u8),
+
+    #[error("unsupported RIP version {0}")]
+    UnsupportedVersion(u8),
+
+    #[error("RIP entries span {0} bytes, not a whole number of 20-byte entries")]
+    InvalidEntriesLength(usize),
+
+    #[error("RIP entry {index} carries metric {metric}, above infinity (16)")]
+    InvalidMetric { index: usize, metric: u32 },
+
+    #[error("RIPng entry {index} carries prefix length {length}, above 128")]
+    InvalidPrefixLength { index: usize, length: u8 },
+}
@@ -98,4 +98,25 @@ pub enum S7CommParseError {
 
     #[error("S7Comm {context} offset arithmetic overflow")]
     LengthOverflow { context: &'static str },
+
+    #[error("Truncated S7 {field}")]
+    TruncatedField { field: &'static str },
+
+    #[error("S7 data item {index} is truncated")]
+    TruncatedDataItem { index: usize },
+
+    #[error("S7 data section holds {actual} result(s), expected {expected}")]
+    DataItemCountMismatch { expected: usize, actual: usize },
+
+    #[error("Unexpected bytes after S7 data items: {remaining} byte(s) remain")]
+    UnexpectedDataBytes { remaining: usize },
+
+    #[error("Invalid S7 Userdata parameter head: expected 00 01 12, got {head:02x?}")]
+    InvalidUserdataHead { head: [u8; 3] },
+
+    #[error("Invalid S7 Userdata parameter length: expected 4 or 8, got {length}")]
+    InvalidUserdataParameterLength { length: u8 },
+
+    #[error("S7 {field} is not ASCII")]
+    InvalidAscii { field: &'static str },
 }
@@ -0,0 +1,52 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+use super::netbios::NbssError;
+
+/// Errors raised while decoding an SMB1, SMB2 or SMB3 message, or the
+/// security blob of a NEGOTIATE or SESSION_SETUP.
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SmbError {
+    #[error(transparent)]
+    Nbss(#[from] NbssError),
+
+    #[error("NBSS message type {0:#04x} does not carry SMB")]
+    NotSessionMessage(u8),
+
+    #[error("SMB message too short: expected at least {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("unknown SMB protocol identifier {0:#010x}")]
+    UnknownProtocolId(u32),
+
+    #[error("SMB2 {field} structure size is {actual}, expected {expected}")]
+    InvalidStructureSize {
+        field: &'static str,
+        expected: u16,
+        actual: u16,
+    },
+
+    #[error("SMB2 NextCommand offset {offset} does not fit the {available} remaining bytes")]
+    InvalidNextCommand { offset: u32, available: usize },
+
+    #[error("SMB2 {field} buffer at offset {offset} ({length} bytes) lies outside the message")]
+    InvalidBufferOffset {
+        field: &'static str,
+        offset: usize,
+        length: usize,
+    },
+
+    #[error("SMB {field} is not valid UTF-16")]
+    InvalidUtf16 { field: &'static str },
+
+    #[error("SMB {field} truncated")]
+    Truncated { field: &'static str },
+
+    #[error("malformed security blob: {field}")]
+    InvalidSecurityBlob { field: &'static str },
+}
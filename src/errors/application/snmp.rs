@@ -62,3 +62,15 @@ pub enum SnmpError {
     #[error("Invalid SNMP IP address length: {actual}")]
     InvalidIpAddressLength { actual: usize },
 }
+
+/// Errors raised by the OID registry: BER object identifiers and
+/// user-supplied OID name files.
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum SnmpOidError {
+    #[error("Invalid BER object identifier in {field}")]
+    InvalidObjectIdentifier { field: &'static str },
+
+    #[error("Invalid OID name file line {line}: expected a dotted OID and a name")]
+    InvalidOidFileLine { line: usize },
+}
@@ -5,7 +5,8 @@
 
 use thiserror::Error;
 
-/// Errors raised while parsing an SSH identification string (RFC 4253 §4.2).
+/// Errors raised while parsing an SSH identification string (RFC 4253 §4.2)
+/// or an unencrypted `SSH_MSG_KEXINIT` binary packet (RFC 4253 §6, §7.1).
 #[non_exhaustive]
 #[derive(Debug, Error, PartialEq, Eq)]
 pub enum SshError {
@@ -34,4 +35,27 @@ pub enum SshError {
 
     #[error("SSH identification string contains a non-printable byte at offset {offset}")]
     NonPrintableByte { offset: usize },
+
+    #[error("SSH binary packet truncated: expected {expected} bytes, got {actual}")]
+    TruncatedBinaryPacket { expected: usize, actual: usize },
+
+    #[error("SSH packet length {length} outside the bounds allowed by RFC 4253 §6.1")]
+    InvalidPacketLength { length: usize },
+
+    #[error(
+        "SSH padding length {padding_length} leaves no payload in a {packet_length}-byte packet"
+    )]
+    InvalidPaddingLength {
+        padding_length: usize,
+        packet_length: usize,
+    },
+
+    #[error("unexpected SSH message type: expected {expected}, got {actual}")]
+    UnexpectedMessageType { expected: u8, actual: u8 },
+
+    #[error("SSH name-list truncated: declared {declared} bytes, {available} available")]
+    TruncatedNameList { declared: usize, available: usize },
+
+    #[error("SSH name-list contains a non-printable byte")]
+    InvalidNameList,
 }
@@ -19,3 +19,94 @@ pub enum TlsError {
     #[error("TLS record length is inconsistent: declared {declared}, available {available}")]
     InconsistentLength { declared: u16, available: usize },
 }
+
+/// Errors raised while decoding handshake messages and the X.509
+/// certificates they carry (RFC 8446 §4, RFC 5280).
+#[non_exhaustive]
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum TlsHandshakeError {
+    #[error("TLS {field} truncated: declared {declared} bytes, {available} available")]
+    TruncatedField {
+        field: &'static str,
+        declared: usize,
+        available: usize,
+    },
+
+    #[error("unexpected TLS handshake type {actual}, expected {expected}")]
+    UnexpectedHandshakeType { expected: u8, actual: u8 },
+
+    #[error("TLS handshake message of {declared} bytes exceeds the {max} bytes reassembly limit")]
+    HandshakeTooLarge { declared: usize, max: usize },
+
+    #[error("TLS {field} has {remaining} trailing bytes")]
+    TrailingData {
+        field: &'static str,
+        remaining: usize,
+    },
+
+    #[error("malformed DER element: {0}")]
+    InvalidDer(&'static str),
+
+    #[error("invalid X.509 certificate: {0}")]
+    InvalidCertificate(&'static str),
+}
+
+/// Errors raised while loading an NSS key log (`SSLKEYLOGFILE`).
+#[non_exhaustive]
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum TlsKeyLogError {
+    #[error("key log line {line}: expected `<label> <client random> <secret>`")]
+    MalformedLine { line: usize },
+
+    #[error("key log line {line}: {field} is not hexadecimal")]
+    InvalidHex { line: usize, field: &'static str },
+
+    #[error("key log line {line}: {field} of {actual} bytes has an unexpected length")]
+    InvalidLength {
+        line: usize,
+        field: &'static str,
+        actual: usize,
+    },
+}
+
+/// Errors raised while decrypting the records of a TLS connection.
+#[non_exhaustive]
+#[derive(Debug, Error, Clone, PartialEq, Eq)]
+pub enum TlsDecryptError {
+    #[error(transparent)]
+    Record(#[from] TlsError),
+
+    #[error(transparent)]
+    Handshake(#[from] TlsHandshakeError),
+
+    #[error("TLS record of {length} bytes exceeds the {max}-byte ciphertext limit")]
+    RecordTooLarge { length: usize, max: usize },
+
+    #[error("TLS version 0x{0:04x} is not supported for decryption")]
+    UnsupportedVersion(u16),
+
+    #[error("TLS cipher suite 0x{0:04x} is not supported for decryption")]
+    UnsupportedCipherSuite(u16),
+
+    #[error("no {label} in the key log for this client random")]
+    MissingSecret { label: &'static str },
+
+    #[error("{label} of {actual} bytes, the cipher suite expects {expected}")]
+    InvalidSecretLength {
+        label: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[error("encrypted TLS record before the handshake that sets its keys")]
+    MissingHandshake,
+
+    #[error("encrypted TLS record of {length} bytes is too short for its nonce and tag")]
+    CiphertextTooShort { length: usize },
+
+    #[error("TLS record {sequence} failed its integrity check (wrong secret or altered record)")]
+    AuthenticationFailed { sequence: u64 },
+
+    #[error("decrypted TLS 1.3 record carries no content type")]
+    MissingContentType,
+}
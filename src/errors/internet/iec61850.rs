@@ -0,0 +1,35 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+use crate::errors::application::mms::MmsError;
+
+/// Errors raised while decoding IEC 61850 GOOSE and Sampled Values frames.
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum Iec61850Error {
+    #[error("IEC 61850 frame too short: expected at least {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("IEC 61850 header declares {declared} bytes but {actual} are available")]
+    LengthMismatch { declared: u16, actual: usize },
+
+    #[error("unexpected tag {tag:#x} for {field}")]
+    UnexpectedTag { field: &'static str, tag: u32 },
+
+    #[error("missing mandatory field {0}")]
+    MissingField(&'static str),
+
+    #[error("invalid length {length} for {field}")]
+    InvalidFieldLength { field: &'static str, length: usize },
+
+    #[error("noASDU announces {declared} ASDUs but {actual} are encoded")]
+    AsduCountMismatch { declared: u32, actual: usize },
+
+    /// GOOSE and SV PDUs share the BER encoding of MMS.
+    #[error(transparent)]
+    Mms(#[from] MmsError),
+}
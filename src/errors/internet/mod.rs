@@ -6,6 +6,7 @@
 use arp::ArpError;
 use thiserror::Error;
 pub mod arp;
+pub mod iec61850;
 pub mod ipv4;
 pub mod ipv6;
 pub mod profinet;
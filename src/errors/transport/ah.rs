@@ -0,0 +1,18 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+/// Errors raised while parsing an IPsec Authentication Header (IP protocol
+/// 51, RFC 4302).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum AhError {
+    #[error("AH header too short: expected at least {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("AH payload length {0} is below the fixed header size")]
+    InvalidPayloadLength(u8),
+}
@@ -0,0 +1,39 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+/// Errors raised while parsing or decrypting an IPsec ESP packet (IP
+/// protocol 50, RFC 4303).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum EspError {
+    #[error("ESP packet too short: expected at least {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("ESP SPI 0 is reserved and must never be sent")]
+    ReservedSpi,
+
+    #[error("No security association for ESP SPI {0:#010x}")]
+    UnknownSpi(u32),
+
+    #[error("Invalid key length for {algorithm}: {actual} bytes")]
+    InvalidKeyLength {
+        algorithm: &'static str,
+        actual: usize,
+    },
+
+    #[error("ESP ciphertext of {actual} bytes is not valid for {algorithm}")]
+    InvalidCiphertextLength {
+        algorithm: &'static str,
+        actual: usize,
+    },
+
+    #[error("ESP integrity check failed (wrong key or altered packet)")]
+    AuthenticationFailed,
+
+    #[error("ESP trailer is inconsistent: pad length {pad_length} for {available} plaintext bytes")]
+    InvalidPadding { pad_length: u8, available: usize },
+}
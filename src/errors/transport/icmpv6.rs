@@ -32,4 +32,22 @@ pub enum Icmpv6Error {
 
     #[error("ICMPv6 code {code} is not defined for message type {message_type}")]
     InvalidCodeForType { message_type: u8, code: u8 },
+
+    #[error("Neighbor Discovery option {option_type} at offset {offset} has a zero length")]
+    ZeroLengthOption { option_type: u8, offset: usize },
+
+    #[error(
+        "Neighbor Discovery option {option_type} truncated: expected {expected} bytes, got {actual}"
+    )]
+    TruncatedOption {
+        option_type: u8,
+        expected: usize,
+        actual: usize,
+    },
+
+    #[error("Neighbor Discovery option {option_type} has an invalid length of {length} bytes")]
+    InvalidOptionLength { option_type: u8, length: usize },
+
+    #[error("Neighbor Discovery option {option_type} carries an invalid domain name")]
+    InvalidDomainName { option_type: u8 },
 }
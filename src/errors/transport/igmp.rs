@@ -0,0 +1,30 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+/// Errors raised while parsing an IGMP message (IP protocol 2, RFC 1112,
+/// RFC 2236, RFC 3376).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum IgmpError {
+    #[error("IGMP message too short: expected at least {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error(
+        "IGMP query of {actual} bytes matches no version: 8 bytes for IGMPv1/v2, at least 12 for IGMPv3"
+    )]
+    InvalidQueryLength { actual: usize },
+
+    #[error("IGMPv3 source list truncated: {declared} sources announced, {available} bytes left")]
+    TruncatedSourceList { declared: u16, available: usize },
+
+    #[error("IGMPv3 group record {index} truncated: expected {expected} bytes, got {actual}")]
+    TruncatedGroupRecord {
+        index: usize,
+        expected: usize,
+        actual: usize,
+    },
+}
@@ -7,10 +7,16 @@ use thiserror::Error;
 
 use crate::errors::transport::{tcp::TcpError, udp::UdpError};
 
+pub mod ah;
+pub mod esp;
 pub mod icmp;
 pub mod icmpv6;
+pub mod igmp;
+pub mod ospf;
+pub mod pim;
 pub mod tcp;
 pub mod udp;
+pub mod vrrp;
 
 /// Errors that can occur when parsing transport layer packets
 #[derive(Error, Debug)]
@@ -0,0 +1,37 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+/// Errors raised while parsing an OSPF packet (IP protocol 89, RFC 2328 for
+/// OSPFv2, RFC 5340 for OSPFv3).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum OspfError {
+    #[error("OSPF packet too short: expected at least {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("unsupported OSPF version {0}: only versions 2 and 3 are decoded")]
+    UnsupportedVersion(u8),
+
+    #[error(
+        "OSPF packet length field {declared} is outside the {minimum}..={available} bytes available"
+    )]
+    InvalidPacketLength {
+        declared: usize,
+        minimum: usize,
+        available: usize,
+    },
+
+    #[error("OSPF LSA {index} declares length {length}, shorter than its 20-byte header")]
+    InvalidLsaLength { index: usize, length: usize },
+
+    #[error("OSPF LSA {index} truncated: expected {expected} bytes, got {actual}")]
+    TruncatedLsa {
+        index: usize,
+        expected: usize,
+        actual: usize,
+    },
+}
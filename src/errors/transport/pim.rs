@@ -0,0 +1,35 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+/// Errors raised while parsing a PIMv2 message (IP protocol 103, RFC 7761).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum PimError {
+    #[error("PIM message too short: expected at least {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("Unsupported PIM version: {0} (only PIMv2 is decoded)")]
+    UnsupportedVersion(u8),
+
+    #[error("PIM encoded address truncated at offset {offset}")]
+    TruncatedEncodedAddress { offset: usize },
+
+    #[error("Unsupported PIM address family {family} at offset {offset}")]
+    UnsupportedAddressFamily { family: u8, offset: usize },
+
+    #[error("Unsupported PIM encoding type {encoding} at offset {offset}")]
+    UnsupportedEncodingType { encoding: u8, offset: usize },
+
+    #[error(
+        "PIM Hello option {option_type} truncated: {declared} bytes announced, {available} left"
+    )]
+    TruncatedHelloOption {
+        option_type: u16,
+        declared: usize,
+        available: usize,
+    },
+}
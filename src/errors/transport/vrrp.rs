@@ -0,0 +1,21 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+use thiserror::Error;
+
+/// Errors raised while parsing a VRRP advertisement (IP protocol 112,
+/// RFC 3768 for VRRPv2, RFC 5798 for VRRPv3).
+#[non_exhaustive]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum VrrpError {
+    #[error("VRRP message too short: expected at least {expected} bytes, got {actual}")]
+    InvalidLength { expected: usize, actual: usize },
+
+    #[error("unsupported VRRP version {0}: only versions 2 and 3 are defined")]
+    UnsupportedVersion(u8),
+
+    #[error("VRRP advertisement announces {count} addresses but only {available} bytes follow")]
+    TruncatedAddresses { count: u8, available: usize },
+}
@@ -101,7 +101,8 @@
 //! ├── transport:   Option<Transport<'a>>      protocol / source_port / destination_port
 //! │                                           / payload / details
 //! ├── application: Option<Application>        application_protocol: &'static str
-//! ├── inner:       Option<Box<PacketFlow<'a>>>  packet carried inside a tunnel
+//! ├── inner:       Option<Box<PacketFlow<'a>>>  packet carried inside a tunnel,
+//! │                                            or datagram quoted by an ICMP error
 //! └── corrupted:   Option<CorruptedLayer>     set when a recognized layer held
 //!                                             invalid bytes
 //! ```
@@ -134,6 +135,12 @@ pub mod checks;
 /// Module for converting packet formats.
 pub mod convert;
 
+/// Opt-in decryption from caller-supplied keys (IPsec ESP), TLS 1.3 traffic
+/// secrets (QUIC 1-RTT) or an `SSLKEYLOGFILE` (TLS 1.2/1.3), and of QUIC
+/// Initial packets.
+#[cfg(feature = "decrypt")]
+pub mod decrypt;
+
 pub mod owned;
 
 mod link_type;
@@ -169,6 +176,6 @@ pub use parse::transport::{Transport, TransportDetails};
 
 /// Exports data link layer parsing functionality.
 pub use parse::PacketFlow;
-pub use parse::{CorruptedLayer, CorruptedLayerKind};
+pub use parse::{CorruptedLayer, CorruptedLayerKind, InnerKind};
 
 pub mod timing;
@@ -9,13 +9,13 @@ use std::{
     net::IpAddr,
 };
 
-use crate::parse::CorruptedLayer;
 use crate::parse::data_link::vlan_tag::VlanTag;
 use crate::parse::data_link::{ethertype, ethertype::Ethertype, mac_addres::MacAddress};
 use crate::parse::link_layer::{
     Ieee80211Link, LinkLayer, LinkLayerKind, LinuxArphrdType, LinuxCookedPacketType, LinuxSll2Link,
     LinuxSllLink, NetworkProtocol, RawIpLink,
 };
+use crate::parse::{CorruptedLayer, InnerKind};
 use crate::{DataLink, IpType, LinkType, PacketFlow};
 
 #[derive(Debug, Clone, Serialize, PartialEq, Hash, Eq)]
@@ -27,7 +27,8 @@ pub struct PacketFlowOwned {
     pub transport: Option<TransportOwned>,
     #[serde(flatten)]
     pub application: Option<ApplicationOwned>,
-    /// Encapsulated flow when this flow is a tunnel (e.g. CAPWAP), mirroring
+    /// Encapsulated flow when this flow is a tunnel (e.g. CAPWAP), or quoted
+    /// datagram when it is an ICMP error, mirroring
     /// [`PacketFlow::inner`](crate::PacketFlow).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inner: Option<Box<PacketFlowOwned>>,
@@ -528,6 +529,22 @@ impl<'a> From<PacketFlow<'a>> for PacketFlowOwned {
     }
 }
 
+impl PacketFlowOwned {
+    /// Mirrors [`PacketFlow::inner_kind`](crate::PacketFlow::inner_kind),
+    /// from the transport protocol name kept by [`TransportOwned`].
+    pub fn inner_kind(&self) -> Option<InnerKind> {
+        self.inner.as_ref()?;
+        match self
+            .transport
+            .as_ref()
+            .map(|transport| transport.protocol.as_str())
+        {
+            Some("ICMP" | "ICMPv6") => Some(InnerKind::IcmpQuotation),
+            _ => Some(InnerKind::Encapsulation),
+        }
+    }
+}
+
 impl Display for PacketFlowOwned {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         writeln!(f, "Packet Flow:")?;
@@ -543,7 +560,10 @@ impl Display for PacketFlowOwned {
             writeln!(f, "  Application: {application}")?;
         }
         if let Some(inner) = &self.inner {
-            writeln!(f, "  Inner {inner}")?;
+            match self.inner_kind() {
+                Some(InnerKind::IcmpQuotation) => writeln!(f, "  Quoted {inner}")?,
+                _ => writeln!(f, "  Inner {inner}")?,
+            }
         }
 
         Ok(())
@@ -11,11 +11,19 @@ use protocols::{
 use serde::Serialize;
 
 use crate::{
+    checks::application::http2::is_http2_preface,
     errors::application::ApplicationError,
     parse::application::protocols::{
-        dhcp::DhcpPacket, giop::GiopPacket, http::HttpRequest, modbus_tcp::ModbusTcpPacket,
-        mqtt::MqttPacket, ntp::NtpPacket, opcua::OpcuaPacket,
-        postgresql::is_likely_postgresql_payload, quic::QuicPacket, srvloc::SrvlocPacket,
+        dhcp::DhcpPacket,
+        giop::GiopPacket,
+        http::{HttpRequest, response::HttpResponse},
+        modbus_tcp::ModbusTcpPacket,
+        mqtt::MqttPacket,
+        ntp::NtpPacket,
+        opcua::OpcuaPacket,
+        postgresql::is_likely_postgresql_payload,
+        quic::QuicPacket,
+        srvloc::SrvlocPacket,
     },
 };
 
@@ -89,7 +97,12 @@ impl TryFrom<&[u8]> for Application {
                 application_protocol: "SSH",
             });
         }
-        if HttpRequest::try_from(packet).is_ok() {
+        if is_http2_preface(packet) {
+            return Ok(Application {
+                application_protocol: "HTTP/2",
+            });
+        }
+        if HttpRequest::try_from(packet).is_ok() || HttpResponse::try_from(packet).is_ok() {
             return Ok(Application {
                 application_protocol: "HTTP",
             });
@@ -291,6 +304,15 @@ mod tests {
         assert_eq!(parsed.application_protocol, "HTTP");
     }
 
+    #[test]
+    fn test_detects_http2_preface() {
+        // Synthetique : preface h2c suivie d'un SETTINGS vide.
+        let packet = b"PRI * HTTP/2.0\r\n\r\nSM\r\n\r\n\x00\x00\x00\x04\x00\x00\x00\x00\x00";
+
+        let parsed = Application::try_from(&packet[..]).unwrap();
+        assert_eq!(parsed.application_protocol, "HTTP/2");
+    }
+
     #[test]
     fn test_random_text_is_not_http() {
         let packet = b"BONJOUR tout le monde\r\n\r\n";
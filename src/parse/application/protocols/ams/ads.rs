@@ -0,0 +1,642 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! ADS commands carried by AMS packets (Beckhoff TwinCAT).
+//!
+//! The AMS `cmd_id` selects the command and the response bit of
+//! `state_flags` tells a request from its response; the invoke id pairs
+//! them. Data is addressed by index group and index offset: process images,
+//! PLC memory, or the symbol server groups handled in [`super::symbol`].
+//! Device notifications are pushed by the server without a request, several
+//! samples per timestamp.
+
+use super::AmsPacket;
+use crate::{
+    checks::application::ams::{
+        ADS_CMD_ADD_DEVICE_NOTIFICATION, ADS_CMD_DELETE_DEVICE_NOTIFICATION,
+        ADS_CMD_DEVICE_NOTIFICATION, ADS_CMD_READ, ADS_CMD_READ_DEVICE_INFO, ADS_CMD_READ_STATE,
+        ADS_CMD_READ_WRITE, ADS_CMD_WRITE, ADS_CMD_WRITE_CONTROL, ADS_DEVICE_NAME_LEN,
+        AMS_STATE_FLAG_ADS_COMMAND, ads_command_name, ads_index_group_name, ads_return_code_name,
+        extract_ads_bytes, extract_ads_c_string, extract_ads_data, extract_ads_u8, extract_ads_u16,
+        extract_ads_u32, extract_ads_u64, validate_ads_data_length, validate_ads_no_trailing_data,
+    },
+    errors::application::ams::AdsError,
+    parse::application::protocols::bounded_capacity,
+};
+
+/// 100 ns intervals between 1601-01-01 and the Unix epoch.
+const ADS_UNIX_EPOCH_TICKS: u64 = 116_444_736_000_000_000;
+
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdsRequest<'a> {
+    ReadDeviceInfo,
+    Read {
+        index_group: u32,
+        index_offset: u32,
+        length: u32,
+    },
+    Write {
+        index_group: u32,
+        index_offset: u32,
+        data: &'a [u8],
+    },
+    ReadState,
+    /// Requests an ADS state change (Run, Stop, Reset...).
+    WriteControl {
+        ads_state: u16,
+        device_state: u16,
+        data: &'a [u8],
+    },
+    AddDeviceNotification {
+        index_group: u32,
+        index_offset: u32,
+        length: u32,
+        transmission_mode: u32,
+        /// 100 ns units.
+        max_delay: u32,
+        /// 100 ns units.
+        cycle_time: u32,
+    },
+    DeleteDeviceNotification {
+        handle: u32,
+    },
+    /// Samples pushed by the server.
+    DeviceNotification(Vec<AdsStampHeader<'a>>),
+    /// Writes `data` then reads up to `read_length` bytes in one exchange.
+    ReadWrite {
+        index_group: u32,
+        index_offset: u32,
+        read_length: u32,
+        data: &'a [u8],
+    },
+}
+
+impl<'a> AdsRequest<'a> {
+    pub fn parse(cmd_id: u16, data: &'a [u8]) -> Result<Self, AdsError> {
+        match cmd_id {
+            ADS_CMD_READ_DEVICE_INFO => {
+                validate_ads_no_trailing_data(data, "read device info")?;
+                Ok(AdsRequest::ReadDeviceInfo)
+            }
+            ADS_CMD_READ => {
+                let (index_group, index_offset, rest) = extract_index(data)?;
+                let (length, rest) = extract_ads_u32(rest, "length")?;
+                validate_ads_no_trailing_data(rest, "length")?;
+                Ok(AdsRequest::Read {
+                    index_group,
+                    index_offset,
+                    length,
+                })
+            }
+            ADS_CMD_WRITE => {
+                let (index_group, index_offset, rest) = extract_index(data)?;
+                Ok(AdsRequest::Write {
+                    index_group,
+                    index_offset,
+                    data: extract_ads_data(rest, "write data")?,
+                })
+            }
+            ADS_CMD_READ_STATE => {
+                validate_ads_no_trailing_data(data, "read state")?;
+                Ok(AdsRequest::ReadState)
+            }
+            ADS_CMD_WRITE_CONTROL => {
+                let (ads_state, rest) = extract_ads_u16(data, "ads state")?;
+                let (device_state, rest) = extract_ads_u16(rest, "device state")?;
+                Ok(AdsRequest::WriteControl {
+                    ads_state,
+                    device_state,
+                    data: extract_ads_data(rest, "write control data")?,
+                })
+            }
+            ADS_CMD_ADD_DEVICE_NOTIFICATION => {
+                let (index_group, index_offset, rest) = extract_index(data)?;
+                let (length, rest) = extract_ads_u32(rest, "length")?;
+                let (transmission_mode, rest) = extract_ads_u32(rest, "transmission mode")?;
+                let (max_delay, rest) = extract_ads_u32(rest, "max delay")?;
+                let (cycle_time, rest) = extract_ads_u32(rest, "cycle time")?;
+                let (_, rest) = extract_ads_bytes(rest, 16, "reserved")?;
+                validate_ads_no_trailing_data(rest, "reserved")?;
+                Ok(AdsRequest::AddDeviceNotification {
+                    index_group,
+                    index_offset,
+                    length,
+                    transmission_mode,
+                    max_delay,
+                    cycle_time,
+                })
+            }
+            ADS_CMD_DELETE_DEVICE_NOTIFICATION => {
+                let (handle, rest) = extract_ads_u32(data, "notification handle")?;
+                validate_ads_no_trailing_data(rest, "notification handle")?;
+                Ok(AdsRequest::DeleteDeviceNotification { handle })
+            }
+            ADS_CMD_DEVICE_NOTIFICATION => parse_device_notification(data),
+            _ => {
+                // ADS_CMD_READ_WRITE, seule commande restante apres
+                // validate_cmd_id.
+                let (index_group, index_offset, rest) = extract_index(data)?;
+                let (read_length, rest) = extract_ads_u32(rest, "read length")?;
+                let (write_length, rest) = extract_ads_u32(rest, "write length")?;
+                validate_ads_data_length(write_length, rest, "write data")?;
+                Ok(AdsRequest::ReadWrite {
+                    index_group,
+                    index_offset,
+                    read_length,
+                    data: rest,
+                })
+            }
+        }
+    }
+
+    pub fn index_group(&self) -> Option<u32> {
+        match self {
+            AdsRequest::Read { index_group, .. }
+            | AdsRequest::Write { index_group, .. }
+            | AdsRequest::AddDeviceNotification { index_group, .. }
+            | AdsRequest::ReadWrite { index_group, .. } => Some(*index_group),
+            _ => None,
+        }
+    }
+
+    pub fn index_group_name(&self) -> Option<&'static str> {
+        self.index_group().map(ads_index_group_name)
+    }
+
+    /// Whether the request changes data or state on the target.
+    pub fn is_write(&self) -> bool {
+        matches!(
+            self,
+            AdsRequest::Write { .. } | AdsRequest::WriteControl { .. }
+        ) || matches!(self, AdsRequest::ReadWrite { data, .. } if !data.is_empty())
+    }
+}
+
+/// Samples of one timestamp in a DeviceNotification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdsStampHeader<'a> {
+    /// FILETIME: 100 ns intervals since 1601-01-01 UTC.
+    pub timestamp: u64,
+    pub samples: Vec<AdsNotificationSample<'a>>,
+}
+
+impl AdsStampHeader<'_> {
+    pub fn unix_millis(&self) -> Option<u64> {
+        self.timestamp
+            .checked_sub(ADS_UNIX_EPOCH_TICKS)
+            .map(|ticks| ticks / 10_000)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdsNotificationSample<'a> {
+    /// Handle returned by AddDeviceNotification.
+    pub handle: u32,
+    pub data: &'a [u8],
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdsResponse<'a> {
+    ReadDeviceInfo {
+        result: u32,
+        major_version: u8,
+        minor_version: u8,
+        build: u16,
+        /// NUL-padded device name.
+        device_name: &'a [u8],
+    },
+    Read {
+        result: u32,
+        data: &'a [u8],
+    },
+    Write {
+        result: u32,
+    },
+    ReadState {
+        result: u32,
+        ads_state: u16,
+        device_state: u16,
+    },
+    WriteControl {
+        result: u32,
+    },
+    AddDeviceNotification {
+        result: u32,
+        handle: u32,
+    },
+    DeleteDeviceNotification {
+        result: u32,
+    },
+    ReadWrite {
+        result: u32,
+        data: &'a [u8],
+    },
+    /// Response without ADS data: the error is in the AMS header.
+    Failed {
+        error_code: u32,
+    },
+}
+
+impl<'a> AdsResponse<'a> {
+    /// `error_code` is the AMS header error, reported when `data` is empty.
+    pub fn parse(cmd_id: u16, error_code: u32, data: &'a [u8]) -> Result<Self, AdsError> {
+        if data.is_empty() {
+            return Ok(AdsResponse::Failed { error_code });
+        }
+        let (result, rest) = extract_ads_u32(data, "result")?;
+        let response = match cmd_id {
+            ADS_CMD_READ_DEVICE_INFO => {
+                let (major_version, rest) = extract_ads_u8(rest, "version")?;
+                let (minor_version, rest) = extract_ads_u8(rest, "version")?;
+                let (build, rest) = extract_ads_u16(rest, "build")?;
+                let (device_name, rest) =
+                    extract_ads_bytes(rest, ADS_DEVICE_NAME_LEN, "device name")?;
+                validate_ads_no_trailing_data(rest, "device name")?;
+                AdsResponse::ReadDeviceInfo {
+                    result,
+                    major_version,
+                    minor_version,
+                    build,
+                    device_name,
+                }
+            }
+            ADS_CMD_READ => AdsResponse::Read {
+                result,
+                data: extract_read_data(result, rest)?,
+            },
+            ADS_CMD_READ_WRITE => AdsResponse::ReadWrite {
+                result,
+                data: extract_read_data(result, rest)?,
+            },
+            ADS_CMD_READ_STATE => {
+                let (ads_state, rest) = extract_ads_u16(rest, "ads state")?;
+                let (device_state, rest) = extract_ads_u16(rest, "device state")?;
+                validate_ads_no_trailing_data(rest, "device state")?;
+                AdsResponse::ReadState {
+                    result,
+                    ads_state,
+                    device_state,
+                }
+            }
+            ADS_CMD_ADD_DEVICE_NOTIFICATION => {
+                let (handle, rest) = extract_ads_u32(rest, "notification handle")?;
+                validate_ads_no_trailing_data(rest, "notification handle")?;
+                AdsResponse::AddDeviceNotification { result, handle }
+            }
+            ADS_CMD_WRITE | ADS_CMD_WRITE_CONTROL | ADS_CMD_DELETE_DEVICE_NOTIFICATION => {
+                validate_ads_no_trailing_data(rest, "result")?;
+                match cmd_id {
+                    ADS_CMD_WRITE => AdsResponse::Write { result },
+                    ADS_CMD_WRITE_CONTROL => AdsResponse::WriteControl { result },
+                    _ => AdsResponse::DeleteDeviceNotification { result },
+                }
+            }
+            // DeviceNotification n'a pas de reponse : aucune donnee attendue.
+            _ => {
+                return Err(AdsError::TrailingData {
+                    field: "device notification response",
+                    length: data.len(),
+                });
+            }
+        };
+        Ok(response)
+    }
+
+    /// ADS return code; the AMS error code for [`AdsResponse::Failed`].
+    pub fn result(&self) -> u32 {
+        match *self {
+            AdsResponse::ReadDeviceInfo { result, .. }
+            | AdsResponse::Read { result, .. }
+            | AdsResponse::Write { result }
+            | AdsResponse::ReadState { result, .. }
+            | AdsResponse::WriteControl { result }
+            | AdsResponse::AddDeviceNotification { result, .. }
+            | AdsResponse::DeleteDeviceNotification { result }
+            | AdsResponse::ReadWrite { result, .. } => result,
+            AdsResponse::Failed { error_code } => error_code,
+        }
+    }
+
+    pub fn result_name(&self) -> &'static str {
+        ads_return_code_name(self.result())
+    }
+
+    /// Device name of a ReadDeviceInfo response, without its padding.
+    pub fn device_name(&self) -> Option<&'a [u8]> {
+        match *self {
+            AdsResponse::ReadDeviceInfo { device_name, .. } => {
+                Some(extract_ads_c_string(device_name))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdsMessage<'a> {
+    Request(AdsRequest<'a>),
+    Response(AdsResponse<'a>),
+}
+
+impl<'a> AmsPacket<'a> {
+    /// ADS command of the packet, `None` when the ADS command flag of
+    /// `state_flags` is not set.
+    pub fn ads(&self) -> Option<Result<AdsMessage<'a>, AdsError>> {
+        if self.state_flags & AMS_STATE_FLAG_ADS_COMMAND == 0 {
+            return None;
+        }
+        let message = if self.is_response() {
+            AdsResponse::parse(self.cmd_id, self.error_code, self.data).map(AdsMessage::Response)
+        } else {
+            AdsRequest::parse(self.cmd_id, self.data).map(AdsMessage::Request)
+        };
+        Some(message)
+    }
+
+    pub fn command_name(&self) -> &'static str {
+        ads_command_name(self.cmd_id)
+    }
+}
+
+fn extract_index(data: &[u8]) -> Result<(u32, u32, &[u8]), AdsError> {
+    let (index_group, rest) = extract_ads_u32(data, "index group")?;
+    let (index_offset, rest) = extract_ads_u32(rest, "index offset")?;
+    Ok((index_group, index_offset, rest))
+}
+
+/// Longueur u32 puis donnees ; une erreur peut n'envoyer que le resultat.
+fn extract_read_data(result: u32, rest: &[u8]) -> Result<&[u8], AdsError> {
+    if result != 0 && rest.is_empty() {
+        return Ok(rest);
+    }
+    extract_ads_data(rest, "read data")
+}
+
+/// Longueur u32 du reste, nombre d'horodatages, puis pour chacun :
+/// horodatage u64, nombre d'echantillons, echantillons (handle, taille,
+/// donnees).
+fn parse_device_notification(data: &[u8]) -> Result<AdsRequest<'_>, AdsError> {
+    let (length, rest) = extract_ads_u32(data, "notification length")?;
+    validate_ads_data_length(length, rest, "notification length")?;
+    let (stamp_count, mut rest) = extract_ads_u32(rest, "stamp count")?;
+    let mut stamps = Vec::with_capacity(bounded_capacity(stamp_count as usize, rest.len(), 12));
+    for _ in 0..stamp_count {
+        let (timestamp, next) = extract_ads_u64(rest, "timestamp")?;
+        let (sample_count, mut next) = extract_ads_u32(next, "sample count")?;
+        let mut samples =
+            Vec::with_capacity(bounded_capacity(sample_count as usize, next.len(), 8));
+        for _ in 0..sample_count {
+            let (handle, after) = extract_ads_u32(next, "notification handle")?;
+            let (size, after) = extract_ads_u32(after, "sample size")?;
+            let (data, after) = extract_ads_bytes(after, size as usize, "sample data")?;
+            samples.push(AdsNotificationSample { handle, data });
+            next = after;
+        }
+        stamps.push(AdsStampHeader { timestamp, samples });
+        rest = next;
+    }
+    validate_ads_no_trailing_data(rest, "notification samples")?;
+    Ok(AdsRequest::DeviceNotification(stamps))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::application::ams::{
+        ADS_IGRP_SYM_VALUE_BY_HANDLE, ads_state_name, ads_transmission_mode_name,
+    };
+
+    /// Paquet AMS synthetique de 5.12.34.56.1.1:32905 vers le runtime PLC
+    /// 5.1.2.3.1.1:851.
+    fn ams(cmd_id: u16, state_flags: u16, invoke_id: u32, data: &[u8]) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(32 + data.len());
+        if state_flags & 0x0001 == 0 {
+            bytes.extend_from_slice(&[5, 1, 2, 3, 1, 1, 0x53, 0x03]);
+            bytes.extend_from_slice(&[5, 12, 34, 56, 1, 1, 0x89, 0x80]);
+        } else {
+            bytes.extend_from_slice(&[5, 12, 34, 56, 1, 1, 0x89, 0x80]);
+            bytes.extend_from_slice(&[5, 1, 2, 3, 1, 1, 0x53, 0x03]);
+        }
+        bytes.extend_from_slice(&cmd_id.to_le_bytes());
+        bytes.extend_from_slice(&state_flags.to_le_bytes());
+        bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&invoke_id.to_le_bytes());
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    fn message(bytes: &[u8]) -> AdsMessage<'_> {
+        AmsPacket::try_from(bytes)
+            .expect("valid AMS packet")
+            .ads()
+            .expect("ADS command")
+            .expect("valid ADS command")
+    }
+
+    #[test]
+    fn decode_read_request_and_response() {
+        // Lecture de 2 octets par handle 0x0A000001.
+        let bytes = ams(
+            2,
+            0x0004,
+            1,
+            &hex::decode("05f00000010000a002000000").expect("invalid hex fixture"),
+        );
+        let AdsMessage::Request(request) = message(&bytes) else {
+            panic!("expected a request");
+        };
+        assert_eq!(
+            request,
+            AdsRequest::Read {
+                index_group: ADS_IGRP_SYM_VALUE_BY_HANDLE,
+                index_offset: 0xA000_0001,
+                length: 2,
+            }
+        );
+        assert_eq!(request.index_group_name(), Some("Symbol value by handle"));
+        assert!(!request.is_write());
+
+        let bytes = ams(
+            2,
+            0x0005,
+            1,
+            &hex::decode("00000000020000002a00").expect("invalid hex fixture"),
+        );
+        let AdsMessage::Response(response) = message(&bytes) else {
+            panic!("expected a response");
+        };
+        assert_eq!(
+            response,
+            AdsResponse::Read {
+                result: 0,
+                data: &[0x2A, 0x00],
+            }
+        );
+
+        // Erreur "Symbol not found" sans donnees.
+        let bytes = ams(2, 0x0005, 2, &0x0710u32.to_le_bytes());
+        let AdsMessage::Response(response) = message(&bytes) else {
+            panic!("expected a response");
+        };
+        assert_eq!(response.result_name(), "Symbol not found");
+    }
+
+    #[test]
+    fn decode_write_and_write_control() {
+        // Ecriture d'un BOOL a %MX0.1 (groupe 0x4021, offset 1).
+        let bytes = ams(
+            3,
+            0x0004,
+            3,
+            &hex::decode("21400000010000000100000000").expect("invalid hex fixture"),
+        );
+        let AdsMessage::Request(request) = message(&bytes) else {
+            panic!("expected a request");
+        };
+        assert!(request.is_write());
+        assert_eq!(
+            request,
+            AdsRequest::Write {
+                index_group: 0x4021,
+                index_offset: 1,
+                data: &[0x00],
+            }
+        );
+
+        // Passage du PLC en Stop.
+        let bytes = ams(
+            5,
+            0x0004,
+            4,
+            &hex::decode("0600000000000000").expect("invalid hex fixture"),
+        );
+        let AdsMessage::Request(AdsRequest::WriteControl { ads_state, .. }) = message(&bytes)
+        else {
+            panic!("expected WriteControl");
+        };
+        assert_eq!(ads_state_name(ads_state), "Stop");
+
+        // Longueur annoncee incoherente.
+        let bytes = ams(
+            3,
+            0x0004,
+            5,
+            &hex::decode("214000000100000002000000ff").expect("invalid hex fixture"),
+        );
+        let packet = AmsPacket::try_from(bytes.as_slice()).expect("valid AMS packet");
+        assert_eq!(
+            packet.ads(),
+            Some(Err(AdsError::LengthMismatch {
+                field: "write data",
+                declared: 2,
+                actual: 1
+            }))
+        );
+    }
+
+    #[test]
+    fn decode_device_info_and_state() {
+        let mut data = hex::decode("0000000003011010").expect("invalid hex fixture");
+        data.extend_from_slice(b"Plc30 App\0\0\0\0\0\0\0");
+        let bytes = ams(1, 0x0005, 6, &data);
+        let AdsMessage::Response(response) = message(&bytes) else {
+            panic!("expected a response");
+        };
+        assert_eq!(response.device_name(), Some(&b"Plc30 App"[..]));
+        let AdsResponse::ReadDeviceInfo {
+            major_version,
+            minor_version,
+            build,
+            ..
+        } = response
+        else {
+            panic!("expected ReadDeviceInfo");
+        };
+        assert_eq!((major_version, minor_version, build), (3, 1, 4112));
+
+        let bytes = ams(
+            4,
+            0x0005,
+            7,
+            &hex::decode("0000000005000000").expect("invalid hex fixture"),
+        );
+        assert_eq!(
+            message(&bytes),
+            AdsMessage::Response(AdsResponse::ReadState {
+                result: 0,
+                ads_state: 5,
+                device_state: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn decode_notifications() {
+        // Abonnement ServerOnChange a 2 octets, cycle 100 ms.
+        let mut data = hex::decode("054000001000000002000000040000000000000080969800")
+            .expect("invalid hex fixture");
+        data.extend_from_slice(&[0u8; 16]);
+        let bytes = ams(6, 0x0004, 8, &data);
+        let AdsMessage::Request(AdsRequest::AddDeviceNotification {
+            transmission_mode,
+            cycle_time,
+            ..
+        }) = message(&bytes)
+        else {
+            panic!("expected AddDeviceNotification");
+        };
+        assert_eq!(
+            ads_transmission_mode_name(transmission_mode),
+            "ServerOnChange"
+        );
+        assert_eq!(cycle_time, 10_000_000);
+
+        // Un horodatage (epoch Unix), deux echantillons.
+        let mut samples = 1u32.to_le_bytes().to_vec();
+        samples.extend_from_slice(&ADS_UNIX_EPOCH_TICKS.to_le_bytes());
+        samples.extend_from_slice(&2u32.to_le_bytes());
+        samples
+            .extend_from_slice(&hex::decode("0100000002000000e803").expect("invalid hex fixture"));
+        samples.extend_from_slice(&hex::decode("020000000100000001").expect("invalid hex fixture"));
+        let mut data = (samples.len() as u32).to_le_bytes().to_vec();
+        data.extend_from_slice(&samples);
+        let bytes = ams(8, 0x0004, 0, &data);
+        let AdsMessage::Request(AdsRequest::DeviceNotification(stamps)) = message(&bytes) else {
+            panic!("expected DeviceNotification");
+        };
+        assert_eq!(stamps[0].unix_millis(), Some(0));
+        assert_eq!(
+            stamps[0].samples,
+            vec![
+                AdsNotificationSample {
+                    handle: 1,
+                    data: &[0xE8, 0x03],
+                },
+                AdsNotificationSample {
+                    handle: 2,
+                    data: &[0x01],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_response_reports_ams_error() {
+        let mut bytes = ams(2, 0x0005, 9, &[]);
+        bytes[24..28].copy_from_slice(&0x0007u32.to_le_bytes());
+        assert_eq!(
+            message(&bytes),
+            AdsMessage::Response(AdsResponse::Failed { error_code: 7 })
+        );
+
+        // Sans le bit ADS, pas de decodage.
+        let bytes = ams(2, 0x0001, 9, &[]);
+        let packet = AmsPacket::try_from(bytes.as_slice()).expect("valid AMS packet");
+        assert_eq!(packet.ads(), None);
+    }
+}
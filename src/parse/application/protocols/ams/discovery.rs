@@ -0,0 +1,247 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! TwinCAT discovery and route management over UDP 48899.
+//!
+//! Engineering tools broadcast a search request and every ADS router
+//! answers with its host name, AMS Net ID and TwinCAT version. The same
+//! framing adds routes remotely: the request carries the route name, the
+//! user name and the password of the target in clear text.
+
+use std::convert::TryFrom;
+
+use crate::{
+    checks::application::ams::{
+        ADS_DISCOVERY_HEADER_LEN, ADS_DISCOVERY_MAGIC, ADS_DISCOVERY_RESPONSE,
+        ADS_DISCOVERY_TAG_HOST, ADS_DISCOVERY_TAG_PASSWORD, ADS_DISCOVERY_TAG_ROUTE_NAME,
+        ADS_DISCOVERY_TAG_STATUS, ADS_DISCOVERY_TAG_TWINCAT_VERSION, ADS_DISCOVERY_TAG_USER_NAME,
+        ads_discovery_service_name, extract_ads_bytes, extract_ads_c_string, extract_ads_u16,
+        extract_ads_u32, validate_ads_no_trailing_data,
+    },
+    errors::application::ams::AdsError,
+    parse::application::protocols::bounded_capacity,
+};
+
+/// TwinCAT discovery packet
+///
+/// ```mermaid
+/// ---
+/// title: AdsDiscoveryPacket
+/// ---
+/// packet-beta
+/// 0-31: "Magic u32"
+/// 32-63: "Invoke ID u32"
+/// 64-95: "Service ID u32"
+/// 96-143: "AMS Net ID u48"
+/// 144-159: "AMS Port u16"
+/// 160-191: "Tag Count u32"
+/// 192-255: "Tags variable"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AdsDiscoveryPacket<'a> {
+    pub invoke_id: u32,
+    /// Service, with [`ADS_DISCOVERY_RESPONSE`] set in responses.
+    pub service_id: u32,
+    /// Net ID of the sender.
+    pub ams_net_id: [u8; 6],
+    pub ams_port: u16,
+    pub tags: Vec<AdsDiscoveryTag<'a>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AdsDiscoveryTag<'a> {
+    pub tag_id: u16,
+    pub data: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for AdsDiscoveryPacket<'a> {
+    type Error = AdsError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        if bytes.len() < ADS_DISCOVERY_HEADER_LEN {
+            return Err(AdsError::Truncated {
+                field: "discovery header",
+            });
+        }
+        let (magic, rest) = extract_ads_u32(bytes, "magic")?;
+        if magic != ADS_DISCOVERY_MAGIC {
+            return Err(AdsError::InvalidDiscoveryMagic(magic));
+        }
+        let (invoke_id, rest) = extract_ads_u32(rest, "invoke id")?;
+        let (service_id, rest) = extract_ads_u32(rest, "service id")?;
+        let (net_id, rest) = extract_ads_bytes(rest, 6, "ams net id")?;
+        let (ams_port, rest) = extract_ads_u16(rest, "ams port")?;
+        let (tag_count, mut rest) = extract_ads_u32(rest, "tag count")?;
+
+        let mut ams_net_id = [0u8; 6];
+        ams_net_id.copy_from_slice(net_id);
+
+        let mut tags = Vec::with_capacity(bounded_capacity(tag_count as usize, rest.len(), 4));
+        for _ in 0..tag_count {
+            let (tag_id, next) = extract_ads_u16(rest, "tag id")?;
+            let (length, next) = extract_ads_u16(next, "tag length")?;
+            let (data, next) = extract_ads_bytes(next, length as usize, "tag data")?;
+            tags.push(AdsDiscoveryTag { tag_id, data });
+            rest = next;
+        }
+        validate_ads_no_trailing_data(rest, "tags")?;
+
+        Ok(AdsDiscoveryPacket {
+            invoke_id,
+            service_id,
+            ams_net_id,
+            ams_port,
+            tags,
+        })
+    }
+}
+
+impl<'a> AdsDiscoveryPacket<'a> {
+    pub fn is_response(&self) -> bool {
+        self.service_id & ADS_DISCOVERY_RESPONSE != 0
+    }
+
+    pub fn service_name(&self) -> &'static str {
+        ads_discovery_service_name(self.service_id)
+    }
+
+    /// Data of the first tag `tag_id`.
+    pub fn tag(&self, tag_id: u16) -> Option<&'a [u8]> {
+        self.tags
+            .iter()
+            .find(|tag| tag.tag_id == tag_id)
+            .map(|tag| tag.data)
+    }
+
+    pub fn host(&self) -> Option<&'a [u8]> {
+        self.string_tag(ADS_DISCOVERY_TAG_HOST)
+    }
+
+    pub fn route_name(&self) -> Option<&'a [u8]> {
+        self.string_tag(ADS_DISCOVERY_TAG_ROUTE_NAME)
+    }
+
+    pub fn user_name(&self) -> Option<&'a [u8]> {
+        self.string_tag(ADS_DISCOVERY_TAG_USER_NAME)
+    }
+
+    /// Clear-text password of an AddRoute request.
+    pub fn password(&self) -> Option<&'a [u8]> {
+        self.string_tag(ADS_DISCOVERY_TAG_PASSWORD)
+    }
+
+    /// `(major, minor, build)`.
+    pub fn twincat_version(&self) -> Option<(u8, u8, u16)> {
+        match self.tag(ADS_DISCOVERY_TAG_TWINCAT_VERSION)? {
+            [major, minor, low, high, ..] => {
+                Some((*major, *minor, u16::from_le_bytes([*low, *high])))
+            }
+            _ => None,
+        }
+    }
+
+    /// ADS return code of an AddRoute response.
+    pub fn status(&self) -> Option<u32> {
+        match self.tag(ADS_DISCOVERY_TAG_STATUS)? {
+            [a, b, c, d] => Some(u32::from_le_bytes([*a, *b, *c, *d])),
+            _ => None,
+        }
+    }
+
+    fn string_tag(&self, tag_id: u16) -> Option<&'a [u8]> {
+        self.tag(tag_id).map(extract_ads_c_string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn discovery(service_id: u32, tags: &[(u16, &[u8])]) -> Vec<u8> {
+        let mut bytes = hex::decode("036614710100000000000000").expect("invalid hex fixture");
+        bytes[8..12].copy_from_slice(&service_id.to_le_bytes());
+        bytes.extend_from_slice(&[5, 12, 34, 56, 1, 1]);
+        bytes.extend_from_slice(&10000u16.to_le_bytes());
+        bytes.extend_from_slice(&(tags.len() as u32).to_le_bytes());
+        for (tag_id, data) in tags {
+            bytes.extend_from_slice(&tag_id.to_le_bytes());
+            bytes.extend_from_slice(&(data.len() as u16).to_le_bytes());
+            bytes.extend_from_slice(data);
+        }
+        bytes
+    }
+
+    #[test]
+    fn parse_search_request_and_response() {
+        // Recherche diffusee par l'outil d'ingenierie (synthetique).
+        let bytes = discovery(1, &[]);
+        let packet = AdsDiscoveryPacket::try_from(bytes.as_slice()).expect("valid discovery");
+        assert!(!packet.is_response());
+        assert_eq!(packet.service_name(), "Search");
+        assert_eq!(packet.invoke_id, 1);
+        assert_eq!(packet.ams_port, 10000);
+        assert!(packet.tags.is_empty());
+
+        let bytes = discovery(
+            0x8000_0001,
+            &[(5, b"CX-1A2B3C\0"), (4, &[0; 12]), (3, &[3, 1, 0x10, 0x10])],
+        );
+        let packet = AdsDiscoveryPacket::try_from(bytes.as_slice()).expect("valid discovery");
+        assert!(packet.is_response());
+        assert_eq!(packet.host(), Some(&b"CX-1A2B3C"[..]));
+        assert_eq!(packet.twincat_version(), Some((3, 1, 4112)));
+        assert_eq!(packet.password(), None);
+    }
+
+    #[test]
+    fn parse_add_route_credentials() {
+        let bytes = discovery(
+            6,
+            &[
+                (0x0C, b"ENG-PC\0"),
+                (0x07, &[5, 12, 34, 56, 1, 1]),
+                (0x0D, b"Administrator\0"),
+                (0x02, b"1\0"),
+                (0x05, b"192.168.10.5\0"),
+            ],
+        );
+        let packet = AdsDiscoveryPacket::try_from(bytes.as_slice()).expect("valid discovery");
+        assert_eq!(packet.service_name(), "AddRoute");
+        assert_eq!(packet.route_name(), Some(&b"ENG-PC"[..]));
+        assert_eq!(packet.user_name(), Some(&b"Administrator"[..]));
+        assert_eq!(packet.password(), Some(&b"1"[..]));
+
+        let bytes = discovery(0x8000_0006, &[(1, &0x0704u32.to_le_bytes())]);
+        let packet = AdsDiscoveryPacket::try_from(bytes.as_slice()).expect("valid discovery");
+        assert_eq!(packet.status(), Some(0x0704));
+    }
+
+    #[test]
+    fn reject_invalid_discovery() {
+        let mut bytes = discovery(1, &[]);
+        bytes[0] = 0x04;
+        assert_eq!(
+            AdsDiscoveryPacket::try_from(bytes.as_slice()),
+            Err(AdsError::InvalidDiscoveryMagic(0x7114_6604))
+        );
+
+        let mut bytes = discovery(1, &[(5, b"PLC\0")]);
+        bytes.truncate(bytes.len() - 1);
+        assert_eq!(
+            AdsDiscoveryPacket::try_from(bytes.as_slice()),
+            Err(AdsError::Truncated { field: "tag data" })
+        );
+
+        let mut bytes = discovery(1, &[]);
+        bytes.push(0);
+        assert_eq!(
+            AdsDiscoveryPacket::try_from(bytes.as_slice()),
+            Err(AdsError::TrailingData {
+                field: "tags",
+                length: 1
+            })
+        );
+    }
+}
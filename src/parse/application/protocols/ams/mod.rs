@@ -7,14 +7,18 @@ use std::convert::TryFrom;
 
 use crate::{
     checks::application::ams::{
-        AMS_HEADER_LEN, extract_cb_data, extract_cmd_id, extract_error_code, extract_invoke_id,
-        extract_sender_net_id, extract_sender_port, extract_state_flags, extract_target_net_id,
-        extract_target_port, validate_ams_header_length, validate_cb_data_length, validate_cmd_id,
-        validate_state_flags,
+        AMS_HEADER_LEN, AMS_STATE_FLAG_RESPONSE, AMS_TCP_HEADER_LEN, extract_cb_data,
+        extract_cmd_id, extract_error_code, extract_invoke_id, extract_sender_net_id,
+        extract_sender_port, extract_state_flags, extract_target_net_id, extract_target_port,
+        validate_ams_header_length, validate_cb_data_length, validate_cmd_id, validate_state_flags,
     },
     errors::application::ams::AmsParseError,
 };
 
+pub mod ads;
+pub mod discovery;
+pub mod symbol;
+
 /// AMS Packet
 ///
 /// ```mermaid
@@ -132,6 +136,41 @@ impl<'a> TryFrom<&'a [u8]> for AmsPacket<'a> {
     }
 }
 
+impl<'a> AmsPacket<'a> {
+    /// Parse le premier paquet d'un flux AMS/TCP (port 48898) : prefixe de
+    /// 6 octets, commande u16 nulle puis longueur u32 du paquet AMS. Rend
+    /// les octets qui le suivent, eventuellement d'autres paquets.
+    ///
+    /// Une commande AMS/TCP non nulle (0x1000 et suivantes : connexion de
+    /// port, notifications du routeur) est rejetee en `UnknownCommand`.
+    pub fn from_tcp_stream(stream: &'a [u8]) -> Result<(Self, &'a [u8]), AmsParseError> {
+        if stream.len() < AMS_TCP_HEADER_LEN {
+            return Err(AmsParseError::HeaderTooShort {
+                expected: AMS_TCP_HEADER_LEN,
+                actual: stream.len(),
+            });
+        }
+        let command = u16::from_le_bytes([stream[0], stream[1]]);
+        if command != 0 {
+            return Err(AmsParseError::UnknownCommand(command));
+        }
+        let length = u32::from_le_bytes([stream[2], stream[3], stream[4], stream[5]]);
+        let rest = &stream[AMS_TCP_HEADER_LEN..];
+        if rest.len() < length as usize {
+            return Err(AmsParseError::InvalidCbDataLength {
+                cb_data: length,
+                actual: rest.len(),
+            });
+        }
+        let (packet, rest) = rest.split_at(length as usize);
+        Ok((Self::try_from(packet)?, rest))
+    }
+
+    pub fn is_response(&self) -> bool {
+        self.state_flags & AMS_STATE_FLAG_RESPONSE != 0
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -226,4 +265,32 @@ mod tests {
             Err(AmsParseError::InvalidStateFlags(0x0010))
         ));
     }
+
+    #[test]
+    fn test_parse_ams_tcp_stream() {
+        // Deux paquets AMS coalises dans un segment TCP.
+        let packet = build_ams(0x0004, 0x0005, 0, &[]);
+        let mut stream = vec![0x00, 0x00];
+        stream.extend_from_slice(&(packet.len() as u32).to_le_bytes());
+        stream.extend_from_slice(&packet);
+        stream.extend_from_slice(&[0x00, 0x00, 0x20]);
+
+        let (ams, rest) = AmsPacket::from_tcp_stream(&stream).expect("flux AMS/TCP valide");
+        assert_eq!(ams.cmd_id, 4);
+        assert!(ams.is_response());
+        assert_eq!(rest, &[0x00, 0x00, 0x20]);
+
+        // Connexion de port au routeur (commande AMS/TCP 0x1000).
+        assert!(matches!(
+            AmsPacket::from_tcp_stream(&[0x00, 0x10, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00]),
+            Err(AmsParseError::UnknownCommand(0x1000))
+        ));
+        assert!(matches!(
+            AmsPacket::from_tcp_stream(&stream[..20]),
+            Err(AmsParseError::InvalidCbDataLength {
+                cb_data: 32,
+                actual: 14
+            })
+        ));
+    }
 }
@@ -0,0 +1,406 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Symbolic access to PLC variables through the TwinCAT symbol server.
+//!
+//! A client usually sends a ReadWrite on index group `0xF003` with the
+//! variable name (`MAIN.bStart`) as write data and gets back a 4-byte
+//! handle, then reads or writes index group `0xF005` with that handle as
+//! index offset, and finally releases it through `0xF006`. Names can also
+//! be read directly through `0xF004`. [`AdsSymbolTable`] follows the handle
+//! exchange so that later accesses by handle can be named.
+
+use std::collections::VecDeque;
+
+use super::{
+    AmsPacket,
+    ads::{AdsMessage, AdsRequest, AdsResponse},
+};
+use crate::{
+    checks::application::ams::{
+        ADS_IGRP_SUMUP_READ, ADS_IGRP_SUMUP_READ_WRITE, ADS_IGRP_SUMUP_WRITE,
+        ADS_IGRP_SYM_HANDLE_BY_NAME, ADS_IGRP_SYM_INFO_BY_NAME_EX, ADS_IGRP_SYM_RELEASE_HANDLE,
+        ADS_IGRP_SYM_VALUE_BY_HANDLE, ADS_IGRP_SYM_VALUE_BY_NAME, ADS_MAX_PENDING_HANDLE_REQUESTS,
+        ADS_MAX_SYMBOL_HANDLES, extract_ads_c_string,
+    },
+    errors::application::ams::AdsError,
+};
+
+/// Symbol server access carried by an ADS request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdsSymbolAccess<'a> {
+    /// Asks a handle for `name` (ReadWrite on `0xF003`).
+    GetHandle {
+        name: &'a [u8],
+    },
+    /// Reads or writes `name` without a handle (`0xF004`).
+    ValueByName {
+        name: &'a [u8],
+    },
+    /// Asks the type and size of `name` (ReadWrite on `0xF009`).
+    InfoByName {
+        name: &'a [u8],
+    },
+    ReadByHandle {
+        handle: u32,
+    },
+    WriteByHandle {
+        handle: u32,
+        data: &'a [u8],
+    },
+    ReleaseHandle {
+        handle: u32,
+    },
+    /// Several reads or writes batched in one request; `count` is the index
+    /// offset.
+    SumCommand {
+        index_group: u32,
+        count: u32,
+    },
+}
+
+impl<'a> AdsRequest<'a> {
+    /// Symbol server access of the request, `None` for other index groups.
+    pub fn symbol_access(&self) -> Option<AdsSymbolAccess<'a>> {
+        let (index_group, index_offset, data) = match *self {
+            AdsRequest::Read {
+                index_group,
+                index_offset,
+                ..
+            } => (index_group, index_offset, None),
+            AdsRequest::Write {
+                index_group,
+                index_offset,
+                data,
+            }
+            | AdsRequest::ReadWrite {
+                index_group,
+                index_offset,
+                data,
+                ..
+            } => (index_group, index_offset, Some(data)),
+            _ => return None,
+        };
+        let access = match (index_group, data) {
+            (ADS_IGRP_SYM_HANDLE_BY_NAME, Some(data)) => AdsSymbolAccess::GetHandle {
+                name: extract_ads_c_string(data),
+            },
+            (ADS_IGRP_SYM_VALUE_BY_NAME, Some(data)) => AdsSymbolAccess::ValueByName {
+                name: extract_ads_c_string(data),
+            },
+            (ADS_IGRP_SYM_INFO_BY_NAME_EX, Some(data)) => AdsSymbolAccess::InfoByName {
+                name: extract_ads_c_string(data),
+            },
+            (ADS_IGRP_SYM_VALUE_BY_HANDLE, None) => AdsSymbolAccess::ReadByHandle {
+                handle: index_offset,
+            },
+            (ADS_IGRP_SYM_VALUE_BY_HANDLE, Some(data)) => AdsSymbolAccess::WriteByHandle {
+                handle: index_offset,
+                data,
+            },
+            // Le handle voyage dans les donnees ; certains clients le
+            // placent dans l'index offset.
+            (ADS_IGRP_SYM_RELEASE_HANDLE, Some(data)) => AdsSymbolAccess::ReleaseHandle {
+                handle: match data {
+                    [a, b, c, d] => u32::from_le_bytes([*a, *b, *c, *d]),
+                    _ => index_offset,
+                },
+            },
+            (ADS_IGRP_SUMUP_READ | ADS_IGRP_SUMUP_WRITE | ADS_IGRP_SUMUP_READ_WRITE, _) => {
+                AdsSymbolAccess::SumCommand {
+                    index_group,
+                    count: index_offset,
+                }
+            }
+            _ => return None,
+        };
+        Some(access)
+    }
+}
+
+/// AMS address: net id and port.
+type AmsAddress = ([u8; 6], u16);
+
+#[derive(Debug, Clone)]
+struct PendingHandleRequest {
+    client: AmsAddress,
+    invoke_id: u32,
+    server: AmsAddress,
+    name: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+struct SymbolHandle {
+    server: AmsAddress,
+    handle: u32,
+    name: Vec<u8>,
+}
+
+/// Symbol handles obtained on a network, by server address.
+///
+/// Push every AMS packet with [`push`](Self::push): a handle request is
+/// kept until its response, paired through the client address and the
+/// invoke id, then the handle resolves with
+/// [`symbol_name`](Self::symbol_name) until it is released. Beyond
+/// [`ADS_MAX_PENDING_HANDLE_REQUESTS`] unanswered requests or
+/// [`ADS_MAX_SYMBOL_HANDLES`] handles, the oldest is dropped.
+#[derive(Debug, Clone, Default)]
+pub struct AdsSymbolTable {
+    pending: VecDeque<PendingHandleRequest>,
+    handles: VecDeque<SymbolHandle>,
+}
+
+impl AdsSymbolTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records handle requests and responses; other ADS commands and
+    /// non-ADS packets are ignored.
+    pub fn push(&mut self, packet: &AmsPacket<'_>) -> Result<(), AdsError> {
+        let Some(message) = packet.ads() else {
+            return Ok(());
+        };
+        let server = (packet.ams_target_net_id, packet.ams_target_port);
+        match message? {
+            AdsMessage::Request(request) => match request.symbol_access() {
+                Some(AdsSymbolAccess::GetHandle { name })
+                    if matches!(request, AdsRequest::ReadWrite { .. }) =>
+                {
+                    let client = (packet.ams_sender_net_id, packet.ams_sender_port);
+                    self.pending.retain(|pending| {
+                        (pending.client, pending.invoke_id) != (client, packet.invoke_id)
+                    });
+                    if self.pending.len() == ADS_MAX_PENDING_HANDLE_REQUESTS {
+                        self.pending.pop_front();
+                    }
+                    self.pending.push_back(PendingHandleRequest {
+                        client,
+                        invoke_id: packet.invoke_id,
+                        server,
+                        name: name.to_vec(),
+                    });
+                }
+                Some(AdsSymbolAccess::ReleaseHandle { handle }) => {
+                    self.handles
+                        .retain(|known| (known.server, known.handle) != (server, handle));
+                }
+                _ => {}
+            },
+            AdsMessage::Response(response) => {
+                // La reponse va du serveur vers le client.
+                let client = server;
+                let Some(index) = self.pending.iter().position(|pending| {
+                    (pending.client, pending.invoke_id) == (client, packet.invoke_id)
+                }) else {
+                    return Ok(());
+                };
+                let Some(pending) = self.pending.remove(index) else {
+                    return Ok(());
+                };
+                if let AdsResponse::ReadWrite {
+                    result: 0,
+                    data: [a, b, c, d],
+                } = response
+                {
+                    let handle = u32::from_le_bytes([*a, *b, *c, *d]);
+                    self.handles
+                        .retain(|known| (known.server, known.handle) != (pending.server, handle));
+                    if self.handles.len() == ADS_MAX_SYMBOL_HANDLES {
+                        self.handles.pop_front();
+                    }
+                    self.handles.push_back(SymbolHandle {
+                        server: pending.server,
+                        handle,
+                        name: pending.name,
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Name behind `handle` on the server `net_id:port`.
+    pub fn symbol_name(&self, net_id: [u8; 6], port: u16, handle: u32) -> Option<&[u8]> {
+        self.handles
+            .iter()
+            .find(|known| known.server == (net_id, port) && known.handle == handle)
+            .map(|known| known.name.as_slice())
+    }
+
+    /// Name read or written by a request to `packet`'s target: the name
+    /// itself for by-name accesses, the tracked name for by-handle ones.
+    pub fn resolve<'a>(
+        &'a self,
+        packet: &AmsPacket<'a>,
+        access: &AdsSymbolAccess<'a>,
+    ) -> Option<&'a [u8]> {
+        match *access {
+            AdsSymbolAccess::GetHandle { name }
+            | AdsSymbolAccess::ValueByName { name }
+            | AdsSymbolAccess::InfoByName { name } => Some(name),
+            AdsSymbolAccess::ReadByHandle { handle }
+            | AdsSymbolAccess::WriteByHandle { handle, .. }
+            | AdsSymbolAccess::ReleaseHandle { handle } => {
+                self.symbol_name(packet.ams_target_net_id, packet.ams_target_port, handle)
+            }
+            AdsSymbolAccess::SumCommand { .. } => None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.handles.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.handles.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLIENT: [u8; 8] = [5, 12, 34, 56, 1, 1, 0x89, 0x80];
+    const PLC: [u8; 8] = [5, 1, 2, 3, 1, 1, 0x53, 0x03];
+
+    /// Paquet AMS synthetique entre un client et le runtime PLC port 851.
+    fn ams(cmd_id: u16, response: bool, invoke_id: u32, data: &[u8]) -> Vec<u8> {
+        let (target, sender) = if response {
+            (CLIENT, PLC)
+        } else {
+            (PLC, CLIENT)
+        };
+        let mut bytes = [target, sender].concat();
+        bytes.extend_from_slice(&cmd_id.to_le_bytes());
+        bytes.extend_from_slice(&(0x0004u16 | u16::from(response)).to_le_bytes());
+        bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        bytes.extend_from_slice(&invoke_id.to_le_bytes());
+        bytes.extend_from_slice(data);
+        bytes
+    }
+
+    fn push(table: &mut AdsSymbolTable, bytes: &[u8]) {
+        table
+            .push(&AmsPacket::try_from(bytes).expect("valid AMS packet"))
+            .expect("valid ADS command");
+    }
+
+    #[test]
+    fn tracks_handle_lifecycle() {
+        let mut table = AdsSymbolTable::new();
+
+        // ReadWrite 0xF003, lecture 4 octets, ecriture "MAIN.nCounter\0".
+        let mut get_handle =
+            hex::decode("03f0000000000000040000000e000000").expect("invalid hex fixture");
+        get_handle.extend_from_slice(b"MAIN.nCounter\0");
+        let bytes = ams(9, false, 21, &get_handle);
+        let packet = AmsPacket::try_from(bytes.as_slice()).expect("valid AMS packet");
+        let Some(Ok(AdsMessage::Request(request))) = packet.ads() else {
+            panic!("expected a request");
+        };
+        assert_eq!(
+            request.symbol_access(),
+            Some(AdsSymbolAccess::GetHandle {
+                name: b"MAIN.nCounter"
+            })
+        );
+        table.push(&packet).expect("valid ADS command");
+        assert!(table.is_empty());
+
+        // Reponse : handle 0x84000003.
+        push(
+            &mut table,
+            &ams(
+                9,
+                true,
+                21,
+                &hex::decode("000000000400000003000084").expect("invalid hex fixture"),
+            ),
+        );
+        assert_eq!(
+            table.symbol_name([5, 1, 2, 3, 1, 1], 851, 0x8400_0003),
+            Some(&b"MAIN.nCounter"[..])
+        );
+
+        // Ecriture par handle, resolue en nom.
+        let bytes = ams(
+            3,
+            false,
+            22,
+            &hex::decode("05f0000003000084020000000a00").expect("invalid hex fixture"),
+        );
+        let packet = AmsPacket::try_from(bytes.as_slice()).expect("valid AMS packet");
+        let Some(Ok(AdsMessage::Request(request))) = packet.ads() else {
+            panic!("expected a request");
+        };
+        let access = request.symbol_access().expect("symbol access");
+        assert_eq!(
+            access,
+            AdsSymbolAccess::WriteByHandle {
+                handle: 0x8400_0003,
+                data: &[0x0A, 0x00],
+            }
+        );
+        assert_eq!(table.resolve(&packet, &access), Some(&b"MAIN.nCounter"[..]));
+
+        // Liberation du handle.
+        push(
+            &mut table,
+            &ams(
+                3,
+                false,
+                23,
+                &hex::decode("06f00000000000000400000003000084").expect("invalid hex fixture"),
+            ),
+        );
+        assert!(table.is_empty());
+    }
+
+    #[test]
+    fn failed_handle_request_is_not_recorded() {
+        let mut table = AdsSymbolTable::new();
+        let mut get_handle =
+            hex::decode("03f0000000000000040000000a000000").expect("invalid hex fixture");
+        get_handle.extend_from_slice(b"MAIN.nope\0");
+        push(&mut table, &ams(9, false, 30, &get_handle));
+        // Symbol not found.
+        push(
+            &mut table,
+            &ams(
+                9,
+                true,
+                30,
+                &hex::decode("1007000000000000").expect("invalid hex fixture"),
+            ),
+        );
+        assert!(table.is_empty());
+        assert!(table.pending.is_empty());
+    }
+
+    #[test]
+    fn sum_command_and_other_groups() {
+        let request = AdsRequest::ReadWrite {
+            index_group: ADS_IGRP_SUMUP_READ,
+            index_offset: 3,
+            read_length: 24,
+            data: &[0; 36],
+        };
+        assert_eq!(
+            request.symbol_access(),
+            Some(AdsSymbolAccess::SumCommand {
+                index_group: ADS_IGRP_SUMUP_READ,
+                count: 3,
+            })
+        );
+        let request = AdsRequest::Read {
+            index_group: 0x4020,
+            index_offset: 0,
+            length: 2,
+        };
+        assert_eq!(request.symbol_access(), None);
+    }
+}
@@ -0,0 +1,641 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! BACnet application layer: the eight APDU types and the services most
+//! seen on building networks (device discovery with Who-Is/I-Am, property
+//! reads and writes).
+
+use crate::{
+    checks::application::bacnet::{
+        BACNET_APDU_ABORT, BACNET_APDU_COMPLEX_ACK, BACNET_APDU_CONFIRMED_REQUEST,
+        BACNET_APDU_ERROR, BACNET_APDU_MORE_FOLLOWS, BACNET_APDU_NEGATIVE_ACK, BACNET_APDU_REJECT,
+        BACNET_APDU_SEGMENT_ACK, BACNET_APDU_SEGMENTED, BACNET_APDU_SEGMENTED_RESPONSE_ACCEPTED,
+        BACNET_APDU_SERVER, BACNET_APDU_SIMPLE_ACK, BACNET_APDU_UNCONFIRMED_REQUEST,
+        BACNET_SERVICE_I_AM, BACNET_SERVICE_READ_PROPERTY, BACNET_SERVICE_WHO_IS,
+        BACNET_SERVICE_WRITE_PROPERTY, BACNET_TAG_ENUMERATED, BACNET_TAG_OBJECT_IDENTIFIER,
+        BACNET_TAG_UNSIGNED, bacnet_abort_reason_name, bacnet_apdu_type_name,
+        bacnet_confirmed_service_name, bacnet_error_class_name, bacnet_max_apdu_len,
+        bacnet_property_name, bacnet_reject_reason_name, bacnet_unconfirmed_service_name,
+        extract_bacnet_bytes, extract_bacnet_uint,
+    },
+    errors::application::bacnet::BacnetError,
+};
+
+use super::tag::{BacnetObjectId, BacnetTag, split_constructed};
+
+/// Position of a segment in a segmented request or answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BacnetSegment {
+    pub sequence_number: u8,
+    pub window_size: u8,
+}
+
+/// BACnet APDU, typed by the high nibble of its first byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BacnetApdu<'a> {
+    ConfirmedRequest {
+        flags: u8,
+        /// Encoded maximum number of segments accepted in the answer.
+        max_segments: u8,
+        /// Encoded maximum APDU size, see [`BacnetApdu::max_apdu_len`].
+        max_apdu: u8,
+        invoke_id: u8,
+        segment: Option<BacnetSegment>,
+        service_choice: u8,
+        data: &'a [u8],
+    },
+    UnconfirmedRequest {
+        service_choice: u8,
+        data: &'a [u8],
+    },
+    SimpleAck {
+        invoke_id: u8,
+        service_choice: u8,
+    },
+    ComplexAck {
+        flags: u8,
+        invoke_id: u8,
+        segment: Option<BacnetSegment>,
+        service_choice: u8,
+        data: &'a [u8],
+    },
+    SegmentAck {
+        flags: u8,
+        invoke_id: u8,
+        sequence_number: u8,
+        actual_window: u8,
+    },
+    Error {
+        invoke_id: u8,
+        service_choice: u8,
+        data: &'a [u8],
+    },
+    Reject {
+        invoke_id: u8,
+        reason: u8,
+    },
+    Abort {
+        flags: u8,
+        invoke_id: u8,
+        reason: u8,
+    },
+}
+
+impl<'a> BacnetApdu<'a> {
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, BacnetError> {
+        let (first, rest) = extract_bacnet_bytes(bytes, 1, "APDU type")?;
+        let pdu_type = first[0] >> 4;
+        let flags = first[0] & 0x0F;
+
+        let apdu = match pdu_type {
+            BACNET_APDU_CONFIRMED_REQUEST => {
+                let (header, rest) = extract_bacnet_bytes(rest, 2, "confirmed request header")?;
+                let (segment, rest) = extract_segment(flags, rest)?;
+                let (service, data) = extract_bacnet_bytes(rest, 1, "service choice")?;
+                BacnetApdu::ConfirmedRequest {
+                    flags,
+                    max_segments: (header[0] >> 4) & 0x07,
+                    max_apdu: header[0] & 0x0F,
+                    invoke_id: header[1],
+                    segment,
+                    service_choice: service[0],
+                    data,
+                }
+            }
+            BACNET_APDU_UNCONFIRMED_REQUEST => {
+                let (service, data) = extract_bacnet_bytes(rest, 1, "service choice")?;
+                BacnetApdu::UnconfirmedRequest {
+                    service_choice: service[0],
+                    data,
+                }
+            }
+            BACNET_APDU_SIMPLE_ACK => {
+                let (header, _) = extract_bacnet_bytes(rest, 2, "SimpleACK")?;
+                BacnetApdu::SimpleAck {
+                    invoke_id: header[0],
+                    service_choice: header[1],
+                }
+            }
+            BACNET_APDU_COMPLEX_ACK => {
+                let (invoke, rest) = extract_bacnet_bytes(rest, 1, "invoke id")?;
+                let (segment, rest) = extract_segment(flags, rest)?;
+                let (service, data) = extract_bacnet_bytes(rest, 1, "service choice")?;
+                BacnetApdu::ComplexAck {
+                    flags,
+                    invoke_id: invoke[0],
+                    segment,
+                    service_choice: service[0],
+                    data,
+                }
+            }
+            BACNET_APDU_SEGMENT_ACK => {
+                let (header, _) = extract_bacnet_bytes(rest, 3, "SegmentACK")?;
+                BacnetApdu::SegmentAck {
+                    flags,
+                    invoke_id: header[0],
+                    sequence_number: header[1],
+                    actual_window: header[2],
+                }
+            }
+            BACNET_APDU_ERROR => {
+                let (header, data) = extract_bacnet_bytes(rest, 2, "Error header")?;
+                BacnetApdu::Error {
+                    invoke_id: header[0],
+                    service_choice: header[1],
+                    data,
+                }
+            }
+            BACNET_APDU_REJECT => {
+                let (header, _) = extract_bacnet_bytes(rest, 2, "Reject")?;
+                BacnetApdu::Reject {
+                    invoke_id: header[0],
+                    reason: header[1],
+                }
+            }
+            BACNET_APDU_ABORT => {
+                let (header, _) = extract_bacnet_bytes(rest, 2, "Abort")?;
+                BacnetApdu::Abort {
+                    flags,
+                    invoke_id: header[0],
+                    reason: header[1],
+                }
+            }
+            other => return Err(BacnetError::UnknownApduType(other)),
+        };
+        Ok(apdu)
+    }
+
+    pub fn pdu_type(&self) -> u8 {
+        match self {
+            BacnetApdu::ConfirmedRequest { .. } => BACNET_APDU_CONFIRMED_REQUEST,
+            BacnetApdu::UnconfirmedRequest { .. } => BACNET_APDU_UNCONFIRMED_REQUEST,
+            BacnetApdu::SimpleAck { .. } => BACNET_APDU_SIMPLE_ACK,
+            BacnetApdu::ComplexAck { .. } => BACNET_APDU_COMPLEX_ACK,
+            BacnetApdu::SegmentAck { .. } => BACNET_APDU_SEGMENT_ACK,
+            BacnetApdu::Error { .. } => BACNET_APDU_ERROR,
+            BacnetApdu::Reject { .. } => BACNET_APDU_REJECT,
+            BacnetApdu::Abort { .. } => BACNET_APDU_ABORT,
+        }
+    }
+
+    pub fn pdu_type_name(&self) -> &'static str {
+        bacnet_apdu_type_name(self.pdu_type())
+    }
+
+    /// Invoke id pairing a confirmed request with its answer.
+    pub fn invoke_id(&self) -> Option<u8> {
+        match *self {
+            BacnetApdu::UnconfirmedRequest { .. } => None,
+            BacnetApdu::ConfirmedRequest { invoke_id, .. }
+            | BacnetApdu::SimpleAck { invoke_id, .. }
+            | BacnetApdu::ComplexAck { invoke_id, .. }
+            | BacnetApdu::SegmentAck { invoke_id, .. }
+            | BacnetApdu::Error { invoke_id, .. }
+            | BacnetApdu::Reject { invoke_id, .. }
+            | BacnetApdu::Abort { invoke_id, .. } => Some(invoke_id),
+        }
+    }
+
+    pub fn service_name(&self) -> Option<&'static str> {
+        match *self {
+            BacnetApdu::UnconfirmedRequest { service_choice, .. } => {
+                Some(bacnet_unconfirmed_service_name(service_choice))
+            }
+            BacnetApdu::ConfirmedRequest { service_choice, .. }
+            | BacnetApdu::SimpleAck { service_choice, .. }
+            | BacnetApdu::ComplexAck { service_choice, .. }
+            | BacnetApdu::Error { service_choice, .. } => {
+                Some(bacnet_confirmed_service_name(service_choice))
+            }
+            _ => None,
+        }
+    }
+
+    /// Largest APDU the requester accepts in its answer, in bytes.
+    pub fn max_apdu_len(&self) -> Option<u16> {
+        match *self {
+            BacnetApdu::ConfirmedRequest { max_apdu, .. } => bacnet_max_apdu_len(max_apdu),
+            _ => None,
+        }
+    }
+
+    /// The requester accepts an answer split into several segments.
+    pub fn accepts_segmented_response(&self) -> bool {
+        matches!(*self, BacnetApdu::ConfirmedRequest { flags, .. }
+            if flags & BACNET_APDU_SEGMENTED_RESPONSE_ACCEPTED != 0)
+    }
+
+    /// More segments of the same request or answer follow this one.
+    pub fn more_follows(&self) -> bool {
+        matches!(*self, BacnetApdu::ConfirmedRequest { flags, .. }
+            | BacnetApdu::ComplexAck { flags, .. } if flags & BACNET_APDU_MORE_FOLLOWS != 0)
+    }
+
+    /// SegmentACK reporting a missing segment.
+    pub fn is_negative_ack(&self) -> bool {
+        matches!(*self, BacnetApdu::SegmentAck { flags, .. }
+            if flags & BACNET_APDU_NEGATIVE_ACK != 0)
+    }
+
+    /// SegmentACK or Abort sent by the server.
+    pub fn is_from_server(&self) -> bool {
+        matches!(*self, BacnetApdu::SegmentAck { flags, .. }
+            | BacnetApdu::Abort { flags, .. } if flags & BACNET_APDU_SERVER != 0)
+    }
+
+    pub fn reason_name(&self) -> Option<&'static str> {
+        match *self {
+            BacnetApdu::Reject { reason, .. } => Some(bacnet_reject_reason_name(reason)),
+            BacnetApdu::Abort { reason, .. } => Some(bacnet_abort_reason_name(reason)),
+            _ => None,
+        }
+    }
+
+    /// Decoded service parameters, `None` for services this crate does not
+    /// decode and for segments, which only make sense once reassembled.
+    pub fn service(&self) -> Option<Result<BacnetService<'a>, BacnetError>> {
+        match *self {
+            BacnetApdu::UnconfirmedRequest {
+                service_choice: BACNET_SERVICE_WHO_IS,
+                data,
+            } => Some(parse_who_is(data)),
+            BacnetApdu::UnconfirmedRequest {
+                service_choice: BACNET_SERVICE_I_AM,
+                data,
+            } => Some(parse_i_am(data)),
+            BacnetApdu::ConfirmedRequest {
+                segment: None,
+                service_choice: BACNET_SERVICE_READ_PROPERTY,
+                data,
+                ..
+            } => Some(parse_property_reference(data).map(|(reference, _)| {
+                BacnetService::ReadProperty {
+                    object: reference.0,
+                    property: reference.1,
+                    array_index: reference.2,
+                }
+            })),
+            BacnetApdu::ComplexAck {
+                segment: None,
+                service_choice: BACNET_SERVICE_READ_PROPERTY,
+                data,
+                ..
+            } => Some(parse_read_property_ack(data)),
+            BacnetApdu::ConfirmedRequest {
+                segment: None,
+                service_choice: BACNET_SERVICE_WRITE_PROPERTY,
+                data,
+                ..
+            } => Some(parse_write_property(data)),
+            BacnetApdu::Error { data, .. } => Some(parse_error(data)),
+            _ => None,
+        }
+    }
+}
+
+/// Decoded service parameters.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BacnetService<'a> {
+    /// Device discovery, optionally limited to a range of instances.
+    WhoIs {
+        low_limit: Option<u32>,
+        high_limit: Option<u32>,
+    },
+    IAm {
+        device: BacnetObjectId,
+        max_apdu_len: u32,
+        segmentation: u32,
+        vendor_id: u32,
+    },
+    ReadProperty {
+        object: BacnetObjectId,
+        property: u32,
+        array_index: Option<u32>,
+    },
+    /// Answer to a ReadProperty; decode `value` with
+    /// [`BacnetValue::parse_all`](super::tag::BacnetValue::parse_all).
+    ReadPropertyAck {
+        object: BacnetObjectId,
+        property: u32,
+        array_index: Option<u32>,
+        value: &'a [u8],
+    },
+    WriteProperty {
+        object: BacnetObjectId,
+        property: u32,
+        array_index: Option<u32>,
+        value: &'a [u8],
+        /// Command priority, 1 (highest) to 16.
+        priority: Option<u32>,
+    },
+    Error {
+        error_class: u32,
+        error_code: u32,
+    },
+}
+
+impl BacnetService<'_> {
+    pub fn property_name(&self) -> Option<&'static str> {
+        match *self {
+            BacnetService::ReadProperty { property, .. }
+            | BacnetService::ReadPropertyAck { property, .. }
+            | BacnetService::WriteProperty { property, .. } => Some(bacnet_property_name(property)),
+            _ => None,
+        }
+    }
+
+    pub fn error_class_name(&self) -> Option<&'static str> {
+        match *self {
+            BacnetService::Error { error_class, .. } => Some(bacnet_error_class_name(error_class)),
+            _ => None,
+        }
+    }
+}
+
+/// Numero de sequence et fenetre proposee, presents si SEG est leve.
+fn extract_segment(flags: u8, bytes: &[u8]) -> Result<(Option<BacnetSegment>, &[u8]), BacnetError> {
+    if flags & BACNET_APDU_SEGMENTED == 0 {
+        return Ok((None, bytes));
+    }
+    let (segment, rest) = extract_bacnet_bytes(bytes, 2, "segment header")?;
+    Ok((
+        Some(BacnetSegment {
+            sequence_number: segment[0],
+            window_size: segment[1],
+        }),
+        rest,
+    ))
+}
+
+fn tag_number(tag: &BacnetTag<'_>) -> u8 {
+    match *tag {
+        BacnetTag::Application { number, .. }
+        | BacnetTag::Context { number, .. }
+        | BacnetTag::Opening(number)
+        | BacnetTag::Closing(number) => number,
+    }
+}
+
+fn expect_context<'a>(
+    bytes: &'a [u8],
+    number: u8,
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), BacnetError> {
+    match BacnetTag::parse(bytes)? {
+        (
+            BacnetTag::Context {
+                number: found,
+                data,
+            },
+            rest,
+        ) if found == number => Ok((data, rest)),
+        (tag, _) => Err(BacnetError::UnexpectedTag {
+            field,
+            expected: number,
+            actual: tag_number(&tag),
+        }),
+    }
+}
+
+/// Parametre de contexte optionnel : absent si l'etiquette suivante ne
+/// correspond pas.
+fn optional_context_uint<'a>(
+    bytes: &'a [u8],
+    number: u8,
+    field: &'static str,
+) -> Result<(Option<u32>, &'a [u8]), BacnetError> {
+    if bytes.is_empty() {
+        return Ok((None, bytes));
+    }
+    match BacnetTag::parse(bytes)? {
+        (
+            BacnetTag::Context {
+                number: found,
+                data,
+            },
+            rest,
+        ) if found == number => Ok((Some(extract_bacnet_uint(data, field)?), rest)),
+        _ => Ok((None, bytes)),
+    }
+}
+
+fn expect_application<'a>(
+    bytes: &'a [u8],
+    number: u8,
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), BacnetError> {
+    match BacnetTag::parse(bytes)? {
+        (
+            BacnetTag::Application {
+                number: found,
+                data,
+                ..
+            },
+            rest,
+        ) if found == number => Ok((data, rest)),
+        (tag, _) => Err(BacnetError::UnexpectedTag {
+            field,
+            expected: number,
+            actual: tag_number(&tag),
+        }),
+    }
+}
+
+fn expect_constructed<'a>(
+    bytes: &'a [u8],
+    number: u8,
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), BacnetError> {
+    match BacnetTag::parse(bytes)? {
+        (BacnetTag::Opening(found), rest) if found == number => split_constructed(rest, number),
+        (tag, _) => Err(BacnetError::UnexpectedTag {
+            field,
+            expected: number,
+            actual: tag_number(&tag),
+        }),
+    }
+}
+
+fn parse_who_is(data: &[u8]) -> Result<BacnetService<'_>, BacnetError> {
+    let (low_limit, rest) = optional_context_uint(data, 0, "low limit")?;
+    let (high_limit, _) = optional_context_uint(rest, 1, "high limit")?;
+    Ok(BacnetService::WhoIs {
+        low_limit,
+        high_limit,
+    })
+}
+
+fn parse_i_am(data: &[u8]) -> Result<BacnetService<'_>, BacnetError> {
+    let (device, rest) = expect_application(data, BACNET_TAG_OBJECT_IDENTIFIER, "I-Am device")?;
+    let (max_apdu_len, rest) = expect_application(rest, BACNET_TAG_UNSIGNED, "I-Am max APDU")?;
+    let (segmentation, rest) =
+        expect_application(rest, BACNET_TAG_ENUMERATED, "I-Am segmentation")?;
+    let (vendor_id, _) = expect_application(rest, BACNET_TAG_UNSIGNED, "I-Am vendor id")?;
+    Ok(BacnetService::IAm {
+        device: BacnetObjectId::parse(device)?,
+        max_apdu_len: extract_bacnet_uint(max_apdu_len, "I-Am max APDU")?,
+        segmentation: extract_bacnet_uint(segmentation, "I-Am segmentation")?,
+        vendor_id: extract_bacnet_uint(vendor_id, "I-Am vendor id")?,
+    })
+}
+
+type PropertyReference = (BacnetObjectId, u32, Option<u32>);
+
+/// Objet [0], propriete [1] et index de tableau optionnel [2], communs a
+/// ReadProperty, a sa reponse et a WriteProperty.
+fn parse_property_reference(data: &[u8]) -> Result<(PropertyReference, &[u8]), BacnetError> {
+    let (object, rest) = expect_context(data, 0, "object identifier")?;
+    let (property, rest) = expect_context(rest, 1, "property identifier")?;
+    let (array_index, rest) = optional_context_uint(rest, 2, "array index")?;
+    Ok((
+        (
+            BacnetObjectId::parse(object)?,
+            extract_bacnet_uint(property, "property identifier")?,
+            array_index,
+        ),
+        rest,
+    ))
+}
+
+fn parse_read_property_ack(data: &[u8]) -> Result<BacnetService<'_>, BacnetError> {
+    let ((object, property, array_index), rest) = parse_property_reference(data)?;
+    let (value, _) = expect_constructed(rest, 3, "property value")?;
+    Ok(BacnetService::ReadPropertyAck {
+        object,
+        property,
+        array_index,
+        value,
+    })
+}
+
+fn parse_write_property(data: &[u8]) -> Result<BacnetService<'_>, BacnetError> {
+    let ((object, property, array_index), rest) = parse_property_reference(data)?;
+    let (value, rest) = expect_constructed(rest, 3, "property value")?;
+    let (priority, _) = optional_context_uint(rest, 4, "priority")?;
+    Ok(BacnetService::WriteProperty {
+        object,
+        property,
+        array_index,
+        value,
+        priority,
+    })
+}
+
+fn parse_error(data: &[u8]) -> Result<BacnetService<'_>, BacnetError> {
+    // Certains services enveloppent l'erreur dans une etiquette [0].
+    let data = match BacnetTag::parse(data)? {
+        (BacnetTag::Opening(0), rest) => split_constructed(rest, 0)?.0,
+        _ => data,
+    };
+    let (class, rest) = expect_application(data, BACNET_TAG_ENUMERATED, "error class")?;
+    let (code, _) = expect_application(rest, BACNET_TAG_ENUMERATED, "error code")?;
+    Ok(BacnetService::Error {
+        error_class: extract_bacnet_uint(class, "error class")?,
+        error_code: extract_bacnet_uint(code, "error code")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::application::protocols::bacnet::tag::BacnetValue;
+
+    #[test]
+    fn parse_who_is_and_i_am() {
+        let who_is = BacnetApdu::parse(&[0x10, 0x08, 0x09, 0x64, 0x19, 0xC8]).expect("valid APDU");
+        assert_eq!(who_is.service_name(), Some("who-Is"));
+        assert_eq!(
+            who_is.service(),
+            Some(Ok(BacnetService::WhoIs {
+                low_limit: Some(100),
+                high_limit: Some(200)
+            }))
+        );
+
+        let bytes = hex::decode("1000c4020004d22205c4910322010f").expect("invalid hex fixture");
+        let i_am = BacnetApdu::parse(&bytes).expect("valid APDU");
+        let Some(Ok(BacnetService::IAm {
+            device,
+            max_apdu_len,
+            segmentation,
+            vendor_id,
+        })) = i_am.service()
+        else {
+            panic!("I-Am expected");
+        };
+        assert_eq!((device.object_type, device.instance), (8, 1234));
+        assert_eq!((max_apdu_len, segmentation, vendor_id), (1476, 3, 271));
+    }
+
+    #[test]
+    fn parse_write_property_and_answers() {
+        // WriteProperty analog-value 3, present-value = 21.5, priorite 8.
+        let bytes =
+            hex::decode("0005420f0c0080000319553e4441ac00003f4908").expect("invalid hex fixture");
+        let request = BacnetApdu::parse(&bytes).expect("valid APDU");
+        assert_eq!(request.invoke_id(), Some(0x42));
+        assert_eq!(request.max_apdu_len(), Some(1476));
+        let Some(Ok(service)) = request.service() else {
+            panic!("WriteProperty expected");
+        };
+        assert_eq!(service.property_name(), Some("present-value"));
+        let BacnetService::WriteProperty {
+            object,
+            value,
+            priority,
+            ..
+        } = service
+        else {
+            panic!("WriteProperty expected");
+        };
+        assert_eq!(object.object_type_name(), "analog-value");
+        assert_eq!(priority, Some(8));
+        assert_eq!(
+            BacnetValue::parse_all(value),
+            Ok(vec![BacnetValue::Real(21.5)])
+        );
+
+        let ack = BacnetApdu::parse(&[0x20, 0x42, 0x0F]).expect("valid APDU");
+        assert_eq!(ack.service_name(), Some("writeProperty"));
+        assert!(ack.service().is_none());
+
+        // Error property / write-access-denied.
+        let error =
+            BacnetApdu::parse(&[0x50, 0x42, 0x0F, 0x91, 0x02, 0x91, 0x28]).expect("valid APDU");
+        let service = error.service().expect("Error").expect("valid Error");
+        assert_eq!(service.error_class_name(), Some("property"));
+        assert_eq!(
+            service,
+            BacnetService::Error {
+                error_class: 2,
+                error_code: 40
+            }
+        );
+
+        let abort = BacnetApdu::parse(&[0x71, 0x42, 0x04]).expect("valid APDU");
+        assert!(abort.is_from_server());
+        assert_eq!(abort.reason_name(), Some("segmentation-not-supported"));
+        assert_eq!(
+            BacnetApdu::parse(&[0x80, 0x00]),
+            Err(BacnetError::UnknownApduType(8))
+        );
+    }
+
+    #[test]
+    fn segmented_answers_are_not_decoded() {
+        // Premier segment d'un ComplexACK readProperty, d'autres suivent.
+        let ack =
+            BacnetApdu::parse(&[0x3C, 0x07, 0x00, 0x04, 0x0C, 0x0C, 0x02]).expect("valid APDU");
+        assert!(ack.more_follows());
+        assert!(ack.service().is_none());
+        assert_eq!(
+            BacnetApdu::parse(&[0x38, 0x07, 0x00]),
+            Err(BacnetError::Truncated {
+                field: "segment header"
+            })
+        );
+    }
+}
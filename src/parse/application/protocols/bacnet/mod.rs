@@ -0,0 +1,306 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! BACnet/IP (ASHRAE 135 Annex J) over UDP 47808.
+//!
+//! A datagram stacks three layers: the BVLC header of the IP transport,
+//! the NPDU of the network layer, which may route the message to another
+//! BACnet network, and the APDU of the application layer. [`BacnetPacket`]
+//! reads the BVLC, [`BacnetNpdu`] the network header and
+//! [`apdu::BacnetApdu`] the application request or answer.
+
+use std::convert::TryFrom;
+use std::net::{Ipv4Addr, SocketAddrV4};
+
+use crate::{
+    checks::application::bacnet::{
+        BACNET_BIP_ADDRESS_LEN, BACNET_BVLC_DISTRIBUTE_BROADCAST, BACNET_BVLC_FORWARDED_NPDU,
+        BACNET_BVLC_HEADER_LEN, BACNET_BVLC_ORIGINAL_BROADCAST_NPDU,
+        BACNET_BVLC_ORIGINAL_UNICAST_NPDU, BACNET_BVLC_RESULT, BACNET_NPDU_DESTINATION,
+        BACNET_NPDU_EXPECTING_REPLY, BACNET_NPDU_NETWORK_MESSAGE, BACNET_NPDU_PRIORITY_MASK,
+        BACNET_NPDU_PROPRIETARY_MESSAGE, BACNET_NPDU_SOURCE, BACNET_NPDU_VERSION,
+        bacnet_bvlc_function_name, bacnet_network_message_name, extract_bacnet_bvlc_len,
+        extract_bacnet_bytes,
+    },
+    errors::application::bacnet::BacnetError,
+};
+
+pub mod apdu;
+pub mod tag;
+
+use apdu::BacnetApdu;
+
+/// BACnet Virtual Link Control message
+///
+/// ```mermaid
+/// ---
+/// title: BacnetPacket
+/// ---
+/// packet-beta
+/// 0-7: "Type 0x81"
+/// 8-15: "Function u8"
+/// 16-31: "Length u16"
+/// 32-79: "Original source (Forwarded-NPDU only)"
+/// 80-127: "NPDU or BVLC payload (variable)"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BacnetPacket<'a> {
+    pub function: u8,
+    /// Length of the whole message, BVLC header included.
+    pub length: u16,
+    /// B/IP address of the device that originated a Forwarded-NPDU.
+    pub forwarded_from: Option<SocketAddrV4>,
+    pub payload: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for BacnetPacket<'a> {
+    type Error = BacnetError;
+
+    fn try_from(datagram: &'a [u8]) -> Result<Self, BacnetError> {
+        let length = extract_bacnet_bvlc_len(datagram)?;
+        let function = datagram[1];
+        let mut payload = &datagram[BACNET_BVLC_HEADER_LEN..length];
+
+        let mut forwarded_from = None;
+        if function == BACNET_BVLC_FORWARDED_NPDU {
+            let (address, rest) =
+                extract_bacnet_bytes(payload, BACNET_BIP_ADDRESS_LEN, "original source")?;
+            forwarded_from = Some(SocketAddrV4::new(
+                Ipv4Addr::new(address[0], address[1], address[2], address[3]),
+                u16::from_be_bytes([address[4], address[5]]),
+            ));
+            payload = rest;
+        }
+
+        Ok(BacnetPacket {
+            function,
+            length: length as u16,
+            forwarded_from,
+            payload,
+        })
+    }
+}
+
+impl<'a> BacnetPacket<'a> {
+    pub fn function_name(&self) -> &'static str {
+        bacnet_bvlc_function_name(self.function)
+    }
+
+    /// Result code of a BVLC-Result (0 for a successful completion).
+    pub fn result_code(&self) -> Option<u16> {
+        match (self.function, self.payload) {
+            (BACNET_BVLC_RESULT, &[high, low]) => Some(u16::from_be_bytes([high, low])),
+            _ => None,
+        }
+    }
+
+    /// Network layer of the functions that carry one, `None` for the BBMD
+    /// and foreign device management functions.
+    pub fn npdu(&self) -> Option<Result<BacnetNpdu<'a>, BacnetError>> {
+        matches!(
+            self.function,
+            BACNET_BVLC_FORWARDED_NPDU
+                | BACNET_BVLC_DISTRIBUTE_BROADCAST
+                | BACNET_BVLC_ORIGINAL_UNICAST_NPDU
+                | BACNET_BVLC_ORIGINAL_BROADCAST_NPDU
+        )
+        .then(|| BacnetNpdu::parse(self.payload))
+    }
+}
+
+/// Address of a device on a remote BACnet network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BacnetAddress<'a> {
+    /// Network number, 0xFFFF for a global broadcast.
+    pub network: u16,
+    /// MAC address on that network, empty for a broadcast.
+    pub mac: &'a [u8],
+}
+
+/// BACnet network layer header
+///
+/// ```mermaid
+/// ---
+/// title: BacnetNpdu
+/// ---
+/// packet-beta
+/// 0-7: "Version 0x01"
+/// 8-15: "Control u8"
+/// 16-31: "DNET u16 (optional)"
+/// 32-39: "DLEN u8 (optional)"
+/// 40-63: "DADR (DLEN octets)"
+/// 64-79: "SNET u16 (optional)"
+/// 80-87: "SLEN u8 (optional)"
+/// 88-111: "SADR (SLEN octets)"
+/// 112-119: "Hop Count (with DNET)"
+/// 120-127: "Message Type (network messages)"
+/// 128-143: "Vendor ID (proprietary messages)"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BacnetNpdu<'a> {
+    pub control: u8,
+    pub destination: Option<BacnetAddress<'a>>,
+    pub source: Option<BacnetAddress<'a>>,
+    pub hop_count: Option<u8>,
+    pub message_type: Option<u8>,
+    pub vendor_id: Option<u16>,
+    /// APDU, or the body of a network layer message.
+    pub payload: &'a [u8],
+}
+
+impl<'a> BacnetNpdu<'a> {
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, BacnetError> {
+        let (header, mut rest) = extract_bacnet_bytes(bytes, 2, "NPDU header")?;
+        if header[0] != BACNET_NPDU_VERSION {
+            return Err(BacnetError::InvalidNpduVersion(header[0]));
+        }
+        let control = header[1];
+
+        let mut destination = None;
+        if control & BACNET_NPDU_DESTINATION != 0 {
+            let (address, next) = extract_address(rest, "destination address")?;
+            destination = Some(address);
+            rest = next;
+        }
+        let mut source = None;
+        if control & BACNET_NPDU_SOURCE != 0 {
+            let (address, next) = extract_address(rest, "source address")?;
+            source = Some(address);
+            rest = next;
+        }
+        let mut hop_count = None;
+        if destination.is_some() {
+            let (hops, next) = extract_bacnet_bytes(rest, 1, "hop count")?;
+            hop_count = Some(hops[0]);
+            rest = next;
+        }
+
+        let mut message_type = None;
+        let mut vendor_id = None;
+        if control & BACNET_NPDU_NETWORK_MESSAGE != 0 {
+            let (kind, next) = extract_bacnet_bytes(rest, 1, "network message type")?;
+            message_type = Some(kind[0]);
+            rest = next;
+            if kind[0] >= BACNET_NPDU_PROPRIETARY_MESSAGE {
+                let (vendor, next) = extract_bacnet_bytes(rest, 2, "vendor id")?;
+                vendor_id = Some(u16::from_be_bytes([vendor[0], vendor[1]]));
+                rest = next;
+            }
+        }
+
+        Ok(BacnetNpdu {
+            control,
+            destination,
+            source,
+            hop_count,
+            message_type,
+            vendor_id,
+            payload: rest,
+        })
+    }
+
+    pub fn expects_reply(&self) -> bool {
+        self.control & BACNET_NPDU_EXPECTING_REPLY != 0
+    }
+
+    /// Network priority: 0 normal, 1 urgent, 2 critical equipment, 3 life
+    /// safety.
+    pub fn priority(&self) -> u8 {
+        self.control & BACNET_NPDU_PRIORITY_MASK
+    }
+
+    pub fn message_type_name(&self) -> Option<&'static str> {
+        self.message_type.map(bacnet_network_message_name)
+    }
+
+    /// Application layer, `None` for network layer messages.
+    pub fn apdu(&self) -> Option<Result<BacnetApdu<'a>, BacnetError>> {
+        self.message_type
+            .is_none()
+            .then(|| BacnetApdu::parse(self.payload))
+    }
+}
+
+/// Network number on 2 bytes, then a length-prefixed MAC address.
+fn extract_address<'a>(
+    bytes: &'a [u8],
+    field: &'static str,
+) -> Result<(BacnetAddress<'a>, &'a [u8]), BacnetError> {
+    let (header, rest) = extract_bacnet_bytes(bytes, 3, field)?;
+    let (mac, rest) = extract_bacnet_bytes(rest, usize::from(header[2]), field)?;
+    Ok((
+        BacnetAddress {
+            network: u16::from_be_bytes([header[0], header[1]]),
+            mac,
+        },
+        rest,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_forwarded_routed_npdu() {
+        // Forwarded-NPDU de 10.0.0.5:47808, routee depuis le reseau 5
+        // (MAC 0x21) vers le reseau 7 en diffusion.
+        let bytes = hex::decode(concat!(
+            "81040016",
+            "0a000005bac0",
+            "012c",
+            "000700",
+            "00050121",
+            "ff",
+            "1008"
+        ))
+        .expect("invalid hex fixture");
+        let packet = BacnetPacket::try_from(&bytes[..]).expect("valid BVLC");
+        assert_eq!(packet.function_name(), "Forwarded-NPDU");
+        assert_eq!(
+            packet.forwarded_from,
+            Some(SocketAddrV4::new(Ipv4Addr::new(10, 0, 0, 5), 47808))
+        );
+
+        let npdu = packet.npdu().expect("NPDU function").expect("valid NPDU");
+        assert_eq!(
+            npdu.destination,
+            Some(BacnetAddress {
+                network: 7,
+                mac: &[]
+            })
+        );
+        assert_eq!(
+            npdu.source,
+            Some(BacnetAddress {
+                network: 5,
+                mac: &[0x21]
+            })
+        );
+        assert_eq!(npdu.hop_count, Some(255));
+        assert!(npdu.expects_reply());
+        assert_eq!(npdu.payload, &[0x10, 0x08]);
+    }
+
+    #[test]
+    fn parse_network_messages_and_results() {
+        let bytes = hex::decode("810000060000").expect("invalid hex fixture");
+        let result = BacnetPacket::try_from(&bytes[..]).expect("valid BVLC");
+        assert_eq!(result.result_code(), Some(0));
+        assert!(result.npdu().is_none());
+
+        // I-Am-Router-To-Network annoncant les reseaux 5 et 7.
+        let npdu =
+            BacnetNpdu::parse(&[0x01, 0x80, 0x01, 0x00, 0x05, 0x00, 0x07]).expect("valid NPDU");
+        assert_eq!(npdu.message_type_name(), Some("I-Am-Router-To-Network"));
+        assert_eq!(npdu.payload.len(), 4);
+        assert!(npdu.apdu().is_none());
+
+        assert_eq!(
+            BacnetNpdu::parse(&[0x02, 0x00]),
+            Err(BacnetError::InvalidNpduVersion(2))
+        );
+    }
+}
@@ -0,0 +1,391 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! BACnet tagged encoding (ASHRAE 135 clause 20.2): every parameter starts
+//! with a tag giving its number, its class (application type or context
+//! position) and its length, or opens and closes a constructed value.
+
+use crate::{
+    checks::application::bacnet::{
+        BACNET_OBJECT_INSTANCE_BITS, BACNET_OBJECT_INSTANCE_MASK, BACNET_TAG_BIT_STRING,
+        BACNET_TAG_BOOLEAN, BACNET_TAG_CHARACTER_STRING, BACNET_TAG_CLASS_CONTEXT, BACNET_TAG_DATE,
+        BACNET_TAG_DOUBLE, BACNET_TAG_ENUMERATED, BACNET_TAG_LVT_CLOSING, BACNET_TAG_LVT_EXTENDED,
+        BACNET_TAG_LVT_MASK, BACNET_TAG_LVT_OPENING, BACNET_TAG_NULL, BACNET_TAG_NUMBER_EXTENDED,
+        BACNET_TAG_OBJECT_IDENTIFIER, BACNET_TAG_OCTET_STRING, BACNET_TAG_REAL, BACNET_TAG_SIGNED,
+        BACNET_TAG_TIME, BACNET_TAG_UNSIGNED, bacnet_object_type_name, extract_bacnet_bytes,
+        extract_bacnet_sint, extract_bacnet_uint,
+    },
+    errors::application::bacnet::BacnetError,
+};
+
+/// One tagged parameter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BacnetTag<'a> {
+    /// Application-tagged primitive. A boolean carries its value in `lvt`
+    /// and no content; every other type carries `lvt` content bytes.
+    Application {
+        number: u8,
+        lvt: u32,
+        data: &'a [u8],
+    },
+    /// Context-tagged primitive, typed by its position in the service.
+    Context {
+        number: u8,
+        data: &'a [u8],
+    },
+    Opening(u8),
+    Closing(u8),
+}
+
+impl<'a> BacnetTag<'a> {
+    /// Reads the tag at the head of `bytes` and returns what follows it.
+    pub fn parse(bytes: &'a [u8]) -> Result<(Self, &'a [u8]), BacnetError> {
+        let (first, mut rest) = extract_bacnet_bytes(bytes, 1, "tag")?;
+        let first = first[0];
+        let context = first & BACNET_TAG_CLASS_CONTEXT != 0;
+        let lvt = first & BACNET_TAG_LVT_MASK;
+
+        let mut number = first >> 4;
+        if number == BACNET_TAG_NUMBER_EXTENDED {
+            let (extended, next) = extract_bacnet_bytes(rest, 1, "extended tag number")?;
+            number = extended[0];
+            rest = next;
+        }
+
+        if context && lvt == BACNET_TAG_LVT_OPENING {
+            return Ok((BacnetTag::Opening(number), rest));
+        }
+        if context && lvt == BACNET_TAG_LVT_CLOSING {
+            return Ok((BacnetTag::Closing(number), rest));
+        }
+        if !context && number == BACNET_TAG_BOOLEAN {
+            return Ok((
+                BacnetTag::Application {
+                    number,
+                    lvt: u32::from(lvt),
+                    data: &[],
+                },
+                rest,
+            ));
+        }
+
+        let mut length = u32::from(lvt);
+        if lvt == BACNET_TAG_LVT_EXTENDED {
+            let (extended, next) = extract_bacnet_bytes(rest, 1, "tag length")?;
+            rest = next;
+            length = match extended[0] {
+                254 => {
+                    let (wide, next) = extract_bacnet_bytes(rest, 2, "tag length")?;
+                    rest = next;
+                    u32::from(u16::from_be_bytes([wide[0], wide[1]]))
+                }
+                255 => {
+                    let (wide, next) = extract_bacnet_bytes(rest, 4, "tag length")?;
+                    rest = next;
+                    u32::from_be_bytes([wide[0], wide[1], wide[2], wide[3]])
+                }
+                short => u32::from(short),
+            };
+        }
+        let (data, rest) = extract_bacnet_bytes(rest, length as usize, "tag content")?;
+        let tag = if context {
+            BacnetTag::Context { number, data }
+        } else {
+            BacnetTag::Application {
+                number,
+                lvt: length,
+                data,
+            }
+        };
+        Ok((tag, rest))
+    }
+}
+
+/// Object identifier: a 10-bit object type and a 22-bit instance number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BacnetObjectId {
+    pub object_type: u16,
+    pub instance: u32,
+}
+
+impl BacnetObjectId {
+    pub fn parse(data: &[u8]) -> Result<Self, BacnetError> {
+        let bytes: [u8; 4] = data.try_into().map_err(|_| BacnetError::InvalidTagLength {
+            field: "object identifier",
+            length: data.len(),
+        })?;
+        let value = u32::from_be_bytes(bytes);
+        Ok(BacnetObjectId {
+            object_type: (value >> BACNET_OBJECT_INSTANCE_BITS) as u16,
+            instance: value & BACNET_OBJECT_INSTANCE_MASK,
+        })
+    }
+
+    pub fn object_type_name(&self) -> &'static str {
+        bacnet_object_type_name(self.object_type)
+    }
+}
+
+/// Decoded property value.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum BacnetValue<'a> {
+    Null,
+    Boolean(bool),
+    Unsigned(u32),
+    Signed(i32),
+    Real(f32),
+    Double(f64),
+    OctetString(&'a [u8]),
+    /// Character set (0 for UTF-8) and encoded bytes.
+    CharacterString {
+        charset: u8,
+        data: &'a [u8],
+    },
+    BitString {
+        unused_bits: u8,
+        data: &'a [u8],
+    },
+    Enumerated(u32),
+    /// Year since 1900, month, day and day of week; 255 is a wildcard.
+    Date {
+        year: u8,
+        month: u8,
+        day: u8,
+        weekday: u8,
+    },
+    Time {
+        hour: u8,
+        minute: u8,
+        second: u8,
+        hundredths: u8,
+    },
+    ObjectId(BacnetObjectId),
+    /// Context-tagged primitive, whose type depends on the enclosing value.
+    Context {
+        tag: u8,
+        data: &'a [u8],
+    },
+    /// Values between an opening and a closing context tag.
+    Constructed {
+        tag: u8,
+        values: Vec<BacnetValue<'a>>,
+    },
+}
+
+impl<'a> BacnetValue<'a> {
+    /// Decodes every value of an encoded property value.
+    pub fn parse_all(mut bytes: &'a [u8]) -> Result<Vec<Self>, BacnetError> {
+        let mut values = Vec::new();
+        while !bytes.is_empty() {
+            let (value, rest) = Self::parse_one(bytes)?;
+            values.push(value);
+            bytes = rest;
+        }
+        Ok(values)
+    }
+
+    fn parse_one(bytes: &'a [u8]) -> Result<(Self, &'a [u8]), BacnetError> {
+        let (tag, rest) = BacnetTag::parse(bytes)?;
+        match tag {
+            BacnetTag::Application { number, lvt, data } => {
+                Ok((Self::from_application(number, lvt, data)?, rest))
+            }
+            BacnetTag::Context { number, data } => {
+                Ok((BacnetValue::Context { tag: number, data }, rest))
+            }
+            BacnetTag::Opening(tag) => {
+                let (inner, rest) = split_constructed(rest, tag)?;
+                Ok((
+                    BacnetValue::Constructed {
+                        tag,
+                        values: Self::parse_all(inner)?,
+                    },
+                    rest,
+                ))
+            }
+            BacnetTag::Closing(tag) => Err(BacnetError::UnbalancedClosingTag(tag)),
+        }
+    }
+
+    fn from_application(number: u8, lvt: u32, data: &'a [u8]) -> Result<Self, BacnetError> {
+        let value = match number {
+            BACNET_TAG_NULL => BacnetValue::Null,
+            BACNET_TAG_BOOLEAN => BacnetValue::Boolean(lvt != 0),
+            BACNET_TAG_UNSIGNED => BacnetValue::Unsigned(extract_bacnet_uint(data, "unsigned")?),
+            BACNET_TAG_SIGNED => BacnetValue::Signed(extract_bacnet_sint(data, "signed")?),
+            BACNET_TAG_REAL => BacnetValue::Real(f32::from_be_bytes(fixed(data, "real")?)),
+            BACNET_TAG_DOUBLE => BacnetValue::Double(f64::from_be_bytes(fixed(data, "double")?)),
+            BACNET_TAG_OCTET_STRING => BacnetValue::OctetString(data),
+            BACNET_TAG_CHARACTER_STRING => {
+                let (charset, data) = extract_bacnet_bytes(data, 1, "character set")?;
+                BacnetValue::CharacterString {
+                    charset: charset[0],
+                    data,
+                }
+            }
+            BACNET_TAG_BIT_STRING => {
+                let (unused, data) = extract_bacnet_bytes(data, 1, "unused bits")?;
+                BacnetValue::BitString {
+                    unused_bits: unused[0],
+                    data,
+                }
+            }
+            BACNET_TAG_ENUMERATED => {
+                BacnetValue::Enumerated(extract_bacnet_uint(data, "enumerated")?)
+            }
+            BACNET_TAG_DATE => {
+                let [year, month, day, weekday] = fixed(data, "date")?;
+                BacnetValue::Date {
+                    year,
+                    month,
+                    day,
+                    weekday,
+                }
+            }
+            BACNET_TAG_TIME => {
+                let [hour, minute, second, hundredths] = fixed(data, "time")?;
+                BacnetValue::Time {
+                    hour,
+                    minute,
+                    second,
+                    hundredths,
+                }
+            }
+            BACNET_TAG_OBJECT_IDENTIFIER => BacnetValue::ObjectId(BacnetObjectId::parse(data)?),
+            // Etiquettes 13 a 15 reservees par ASHRAE.
+            _ => BacnetValue::Context { tag: number, data },
+        };
+        Ok(value)
+    }
+
+    /// UTF-8 text of a character string, `None` for other character sets.
+    pub fn as_str(&self) -> Option<&'a str> {
+        match self {
+            BacnetValue::CharacterString { charset: 0, data } => std::str::from_utf8(data).ok(),
+            _ => None,
+        }
+    }
+}
+
+/// Splits the content of a constructed value, after its opening tag, from
+/// what follows its matching closing tag.
+pub(crate) fn split_constructed(bytes: &[u8], number: u8) -> Result<(&[u8], &[u8]), BacnetError> {
+    let mut depth = 0usize;
+    let mut rest = bytes;
+    loop {
+        let (tag, next) = BacnetTag::parse(rest)?;
+        match tag {
+            BacnetTag::Opening(_) => depth += 1,
+            BacnetTag::Closing(closing) if depth == 0 => {
+                if closing != number {
+                    return Err(BacnetError::UnexpectedTag {
+                        field: "closing tag",
+                        expected: number,
+                        actual: closing,
+                    });
+                }
+                let inner = &bytes[..bytes.len() - rest.len()];
+                return Ok((inner, next));
+            }
+            BacnetTag::Closing(_) => depth -= 1,
+            _ => {}
+        }
+        rest = next;
+    }
+}
+
+fn fixed<const N: usize>(data: &[u8], field: &'static str) -> Result<[u8; N], BacnetError> {
+    data.try_into().map_err(|_| BacnetError::InvalidTagLength {
+        field,
+        length: data.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_tag_forms() {
+        assert_eq!(
+            BacnetTag::parse(&[0x11, 0xAA]),
+            Ok((
+                BacnetTag::Application {
+                    number: 1,
+                    lvt: 1,
+                    data: &[]
+                },
+                &[0xAA][..]
+            ))
+        );
+        assert_eq!(
+            BacnetTag::parse(&[0x19, 0x55]),
+            Ok((
+                BacnetTag::Context {
+                    number: 1,
+                    data: &[0x55]
+                },
+                &[][..]
+            ))
+        );
+        assert_eq!(
+            BacnetTag::parse(&[0x3E]),
+            Ok((BacnetTag::Opening(3), &[][..]))
+        );
+        // Numero etendu 40 et longueur etendue de 6 octets.
+        let (tag, rest) = BacnetTag::parse(&[0xFD, 40, 6, 1, 2, 3, 4, 5, 6]).expect("valid tag");
+        assert_eq!(
+            tag,
+            BacnetTag::Context {
+                number: 40,
+                data: &[1, 2, 3, 4, 5, 6]
+            }
+        );
+        assert!(rest.is_empty());
+        assert_eq!(
+            BacnetTag::parse(&[0x44, 0x42]),
+            Err(BacnetError::Truncated {
+                field: "tag content"
+            })
+        );
+    }
+
+    #[test]
+    fn parse_values() {
+        // Reel 21.5, chaine "AHU-1", objet device 1234 et une liste
+        // construite sous l'etiquette 0.
+        let bytes = hex::decode(concat!(
+            "4441ac0000",
+            "7506004148552d31",
+            "c4020004d2",
+            "0e21010f"
+        ))
+        .expect("invalid hex fixture");
+        let values = BacnetValue::parse_all(&bytes).expect("valid values");
+        assert_eq!(values[0], BacnetValue::Real(21.5));
+        assert_eq!(values[1].as_str(), Some("AHU-1"));
+        let BacnetValue::ObjectId(device) = values[2] else {
+            panic!("object identifier expected");
+        };
+        assert_eq!(device.object_type_name(), "device");
+        assert_eq!(device.instance, 1234);
+        assert_eq!(
+            values[3],
+            BacnetValue::Constructed {
+                tag: 0,
+                values: vec![BacnetValue::Unsigned(1)]
+            }
+        );
+        assert_eq!(
+            BacnetValue::parse_all(&[0x0E, 0x21, 0x01, 0x2F]),
+            Err(BacnetError::UnexpectedTag {
+                field: "closing tag",
+                expected: 0,
+                actual: 2
+            })
+        );
+    }
+}
@@ -0,0 +1,348 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! BER/DER decoding (ITU-T X.690) shared by the ASN.1 protocols: Kerberos,
+//! LDAP, the SPNEGO envelope of SMB security blobs, X.509 certificates and
+//! MMS / IEC 61850 GOOSE and SV.
+//!
+//! [`BerElement`] is one tag-length-value, borrowed from the packet;
+//! [`BerReader`] walks the elements of a SEQUENCE or SET in order, with
+//! helpers for the `[n]` context tags ASN.1 modules use to mark their
+//! fields. Values decode on demand (`integer()`, `string()`, ...), so a
+//! parser only pays for the fields it reads.
+//!
+//! ```
+//! use packet_parser::parse::application::protocols::ber::BerReader;
+//!
+//! // SEQUENCE { [0] INTEGER 5, [1] OCTET STRING "cn" }
+//! let bytes = [0x30, 0x0B, 0xA0, 0x03, 0x02, 0x01, 0x05, 0xA1, 0x04, 0x04, 0x02, b'c', b'n'];
+//! let mut top = BerReader::new(&bytes);
+//! let mut fields = top.sequence("example").unwrap().children();
+//! let version = fields.context(0, "version").unwrap().children().integer("version").unwrap();
+//! assert_eq!(version, 5);
+//! let name = fields.optional_context(1, "name").unwrap().unwrap();
+//! assert_eq!(name.children().octet_string("name").unwrap(), b"cn");
+//! ```
+
+use std::fmt;
+
+use crate::{
+    checks::application::ber::{
+        BER_CLASS_MASK, BER_CONSTRUCTED, BER_HIGH_TAG, BER_TAG_INTEGER, BER_TAG_OCTET_STRING,
+        BER_TAG_SEQUENCE, extract_ber_bit_string, extract_ber_boolean, extract_ber_element,
+        extract_ber_integer, extract_ber_string, extract_ber_unsigned, validate_ber_tag,
+    },
+    errors::application::ber::BerError,
+};
+
+/// Tag class, the two high bits of the identifier octet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BerClass {
+    Universal,
+    Application,
+    Context,
+    Private,
+}
+
+impl BerClass {
+    pub fn from_identifier(identifier: u8) -> Self {
+        match identifier & BER_CLASS_MASK {
+            0x00 => BerClass::Universal,
+            0x40 => BerClass::Application,
+            0x80 => BerClass::Context,
+            _ => BerClass::Private,
+        }
+    }
+
+    /// Class bits as they appear in the identifier octet.
+    pub fn bits(&self) -> u8 {
+        match self {
+            BerClass::Universal => 0x00,
+            BerClass::Application => 0x40,
+            BerClass::Context => 0x80,
+            BerClass::Private => 0xC0,
+        }
+    }
+}
+
+/// One BER element: tag, constructed flag and contents octets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BerElement<'a> {
+    pub class: BerClass,
+    pub constructed: bool,
+    pub number: u32,
+    pub value: &'a [u8],
+}
+
+impl<'a> BerElement<'a> {
+    pub fn is_universal(&self, number: u32) -> bool {
+        self.class == BerClass::Universal && self.number == number
+    }
+
+    pub fn is_application(&self, number: u32) -> bool {
+        self.class == BerClass::Application && self.number == number
+    }
+
+    pub fn is_context(&self, number: u32) -> bool {
+        self.class == BerClass::Context && self.number == number
+    }
+
+    /// Identifier octet, when the tag number fits in it (below 31).
+    pub fn identifier(&self) -> Option<u8> {
+        let number = u8::try_from(self.number)
+            .ok()
+            .filter(|number| *number < BER_HIGH_TAG)?;
+        let constructed = if self.constructed { BER_CONSTRUCTED } else { 0 };
+        Some(self.class.bits() | constructed | number)
+    }
+
+    /// Elements nested in a constructed value.
+    pub fn children(&self) -> BerReader<'a> {
+        BerReader::new(self.value)
+    }
+
+    pub fn integer(&self, field: &'static str) -> Result<i64, BerError> {
+        extract_ber_integer(self.value, field)
+    }
+
+    pub fn unsigned(&self, field: &'static str) -> Result<u32, BerError> {
+        extract_ber_unsigned(self.value, field)
+    }
+
+    pub fn boolean(&self, field: &'static str) -> Result<bool, BerError> {
+        extract_ber_boolean(self.value, field)
+    }
+
+    pub fn string(&self, field: &'static str) -> Result<&'a str, BerError> {
+        extract_ber_string(self.value, field)
+    }
+
+    /// First 32 bits of a BIT STRING, bit 0 being the most significant:
+    /// the layout of Kerberos flags (RFC 4120 §5.2.8).
+    pub fn flags(&self, field: &'static str) -> Result<u32, BerError> {
+        let (_, bits) = extract_ber_bit_string(self.value, field)?;
+        let mut word = [0u8; 4];
+        let used = bits.len().min(4);
+        word[..used].copy_from_slice(&bits[..used]);
+        Ok(u32::from_be_bytes(word))
+    }
+
+    pub fn oid(&self) -> BerOid<'a> {
+        BerOid(self.value)
+    }
+}
+
+/// Cursor over consecutive BER elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BerReader<'a> {
+    rest: &'a [u8],
+}
+
+impl<'a> BerReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        BerReader { rest: data }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest.is_empty()
+    }
+
+    /// Bytes not read yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.rest
+    }
+
+    pub fn next_element(&mut self, field: &'static str) -> Result<BerElement<'a>, BerError> {
+        let (element, rest) = extract_ber_element(self.rest, field)?;
+        self.rest = rest;
+        Ok(element)
+    }
+
+    /// Next element, which must carry the given tag.
+    pub fn expect(
+        &mut self,
+        class: BerClass,
+        number: u32,
+        field: &'static str,
+    ) -> Result<BerElement<'a>, BerError> {
+        let (element, rest) = extract_ber_element(self.rest, field)?;
+        validate_ber_tag(&element, class, number, field)?;
+        self.rest = rest;
+        Ok(element)
+    }
+
+    /// Next element if it carries the given tag; left unread otherwise.
+    pub fn optional(
+        &mut self,
+        class: BerClass,
+        number: u32,
+        field: &'static str,
+    ) -> Result<Option<BerElement<'a>>, BerError> {
+        if self.rest.is_empty() {
+            return Ok(None);
+        }
+        let (element, rest) = extract_ber_element(self.rest, field)?;
+        if element.class != class || element.number != number {
+            return Ok(None);
+        }
+        self.rest = rest;
+        Ok(Some(element))
+    }
+
+    pub fn context(
+        &mut self,
+        number: u32,
+        field: &'static str,
+    ) -> Result<BerElement<'a>, BerError> {
+        self.expect(BerClass::Context, number, field)
+    }
+
+    pub fn optional_context(
+        &mut self,
+        number: u32,
+        field: &'static str,
+    ) -> Result<Option<BerElement<'a>>, BerError> {
+        self.optional(BerClass::Context, number, field)
+    }
+
+    pub fn sequence(&mut self, field: &'static str) -> Result<BerElement<'a>, BerError> {
+        self.expect(BerClass::Universal, BER_TAG_SEQUENCE, field)
+    }
+
+    pub fn integer(&mut self, field: &'static str) -> Result<i64, BerError> {
+        self.expect(BerClass::Universal, BER_TAG_INTEGER, field)?
+            .integer(field)
+    }
+
+    pub fn octet_string(&mut self, field: &'static str) -> Result<&'a [u8], BerError> {
+        Ok(self
+            .expect(BerClass::Universal, BER_TAG_OCTET_STRING, field)?
+            .value)
+    }
+}
+
+/// Object identifier, kept in its BER encoding. `Display` renders the
+/// dotted form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BerOid<'a>(pub &'a [u8]);
+
+impl BerOid<'_> {
+    /// Arcs of the identifier, `None` when an arc is truncated or does not
+    /// fit 64 bits.
+    pub fn arcs(&self) -> Option<Vec<u64>> {
+        let mut arcs = Vec::new();
+        let mut value = 0u64;
+        let mut pending = false;
+        for &byte in self.0 {
+            value = value.checked_mul(128)? | u64::from(byte & 0x7F);
+            pending = byte & 0x80 != 0;
+            if pending {
+                continue;
+            }
+            if arcs.is_empty() {
+                // Les deux premiers arcs partagent le premier sous-identifiant.
+                let first = (value / 40).min(2);
+                arcs.push(first);
+                arcs.push(value - first * 40);
+            } else {
+                arcs.push(value);
+            }
+            value = 0;
+        }
+        (!pending && !arcs.is_empty()).then_some(arcs)
+    }
+}
+
+impl fmt::Display for BerOid<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.arcs() {
+            Some(arcs) => {
+                for (index, arc) in arcs.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(".")?;
+                    }
+                    write!(f, "{arc}")?;
+                }
+                Ok(())
+            }
+            None => f.write_str("<invalid OID>"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reader_walks_context_tagged_fields() {
+        // SEQUENCE { [0] INTEGER 5, [2] BIT STRING 0x40810010 }
+        let bytes = [
+            0x30, 0x0E, 0xA0, 0x03, 0x02, 0x01, 0x05, 0xA2, 0x07, 0x03, 0x05, 0x00, 0x40, 0x81,
+            0x00, 0x10,
+        ];
+        let mut top = BerReader::new(&bytes);
+        let mut fields = top.sequence("test").expect("SEQUENCE").children();
+        assert!(top.is_empty());
+        assert_eq!(
+            fields
+                .context(0, "pvno")
+                .and_then(|e| e.children().integer("pvno")),
+            Ok(5)
+        );
+        // [1] absent : le champ [2] reste a lire.
+        assert_eq!(fields.optional_context(1, "cname"), Ok(None));
+        let options = fields.context(2, "options").expect("[2]");
+        let flags = options
+            .children()
+            .next_element("options")
+            .expect("BIT STRING");
+        assert_eq!(flags.flags("options"), Ok(0x4081_0010));
+        assert!(fields.is_empty());
+        assert_eq!(
+            fields.context(3, "realm"),
+            Err(BerError::Missing { field: "realm" })
+        );
+    }
+
+    #[test]
+    fn unexpected_tags_name_the_field() {
+        let mut reader = BerReader::new(&[0x04, 0x00]);
+        assert_eq!(
+            reader.integer("version"),
+            Err(BerError::UnexpectedTag {
+                field: "version",
+                class: 0x00,
+                number: 4
+            })
+        );
+        // La lecture en echec ne consomme rien.
+        assert_eq!(reader.octet_string("name"), Ok(&[][..]));
+    }
+
+    #[test]
+    fn identifiers_and_oids() {
+        let element = BerElement {
+            class: BerClass::Context,
+            constructed: true,
+            number: 3,
+            value: &[],
+        };
+        assert_eq!(element.identifier(), Some(0xA3));
+        assert_eq!(
+            BerElement {
+                number: 31,
+                ..element
+            }
+            .identifier(),
+            None
+        );
+
+        // 1.2.840.113554.1.2.2 (Kerberos V5).
+        let oid = BerOid(&[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x12, 0x01, 0x02, 0x02]);
+        assert_eq!(oid.to_string(), "1.2.840.113554.1.2.2");
+        assert_eq!(BerOid(&[0x2A, 0x86]).to_string(), "<invalid OID>");
+    }
+}
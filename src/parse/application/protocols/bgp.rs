@@ -0,0 +1,770 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Parseur BGP-4 (RFC 4271) sur TCP 179.
+//!
+//! Chaque message porte sa longueur : un segment TCP peut en coaliser
+//! plusieurs (UPDATE en rafale lors de la synchronisation initiale).
+//! [`BgpPacket::try_from`] decode le premier, [`BgpPacket::from_stream`] rend
+//! aussi les octets qui le suivent.
+//!
+//! La taille des numeros d'AS d'un AS_PATH (2 ou 4 octets, RFC 6793) est
+//! negociee dans l'OPEN et n'apparait pas dans l'UPDATE : sans etat de
+//! session, le parseur retient 4 octets si les segments consomment
+//! exactement l'attribut, 2 sinon — la regle qu'applique Wireshark.
+
+use std::convert::TryFrom;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    checks::application::bgp::{
+        BGP_HEADER_LENGTH, BGP_OPEN_FIXED_LENGTH, BGP_TLV_HEADER_LENGTH, extract_bgp_field,
+        extract_bgp_message_length, extract_bgp_prefix_octets, validate_bgp_body_length,
+    },
+    errors::application::bgp::BgpError,
+};
+
+/// Types de message (RFC 4271 §4.1, RFC 2918).
+const OPEN_TYPE: u8 = 1;
+const UPDATE_TYPE: u8 = 2;
+const NOTIFICATION_TYPE: u8 = 3;
+const KEEPALIVE_TYPE: u8 = 4;
+const ROUTE_REFRESH_TYPE: u8 = 5;
+
+/// Seule version en service.
+const BGP_VERSION: u8 = 4;
+
+/// Parametre optionnel de l'OPEN portant des capabilities (RFC 5492).
+const CAPABILITIES_PARAMETER: u8 = 2;
+
+/// Marqueur des parametres optionnels etendus (RFC 9072) : longueur 255
+/// suivie d'un type 255 et d'une longueur u16.
+const EXTENDED_PARAMETERS_MARKER: u8 = 255;
+
+/// Codes de capability decodes.
+const MULTIPROTOCOL_CAPABILITY: u8 = 1;
+const ROUTE_REFRESH_CAPABILITY: u8 = 2;
+const FOUR_OCTET_AS_CAPABILITY: u8 = 65;
+
+/// Codes d'attribut de chemin decodes (RFC 4271 §5, RFC 1997, RFC 4760).
+const ORIGIN_ATTRIBUTE: u8 = 1;
+const AS_PATH_ATTRIBUTE: u8 = 2;
+const NEXT_HOP_ATTRIBUTE: u8 = 3;
+const MULTI_EXIT_DISC_ATTRIBUTE: u8 = 4;
+const LOCAL_PREF_ATTRIBUTE: u8 = 5;
+const ATOMIC_AGGREGATE_ATTRIBUTE: u8 = 6;
+const AGGREGATOR_ATTRIBUTE: u8 = 7;
+const COMMUNITIES_ATTRIBUTE: u8 = 8;
+const MP_REACH_NLRI_ATTRIBUTE: u8 = 14;
+const MP_UNREACH_NLRI_ATTRIBUTE: u8 = 15;
+
+/// Bit Extended Length du champ flags d'un attribut : longueur sur 2 octets.
+const EXTENDED_LENGTH_FLAG: u8 = 0x10;
+
+/// Familles d'adresses (AFI) dont les NLRI sont decodees en prefixes.
+const AFI_IPV4: u16 = 1;
+const AFI_IPV6: u16 = 2;
+
+/// SAFI unicast et multicast : NLRI sous forme de prefixes simples.
+const SAFI_UNICAST: u8 = 1;
+const SAFI_MULTICAST: u8 = 2;
+
+fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes([
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ])
+}
+
+/// Prefixe IP d'une liste NLRI ou de routes retirees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BgpPrefix {
+    /// Adresse completee de zeros au-dela de la longueur.
+    pub address: IpAddr,
+    /// Longueur en bits.
+    pub length: u8,
+}
+
+/// Decode une suite de prefixes (longueur en bits, puis octets utiles).
+fn parse_prefixes(mut bytes: &[u8], afi: u16) -> Result<Vec<BgpPrefix>, BgpError> {
+    let max_bits = if afi == AFI_IPV6 { 128 } else { 32 };
+    let mut prefixes = Vec::new();
+    while let Some((&length, rest)) = bytes.split_first() {
+        let octets = extract_bgp_prefix_octets(length, max_bits)?;
+        let prefix = extract_bgp_field(rest, octets, "prefix")?;
+        let mut full = [0u8; 16];
+        full[..octets].copy_from_slice(prefix);
+        let address = if afi == AFI_IPV6 {
+            IpAddr::V6(Ipv6Addr::from(full))
+        } else {
+            IpAddr::V4(Ipv4Addr::new(full[0], full[1], full[2], full[3]))
+        };
+        prefixes.push(BgpPrefix { address, length });
+        bytes = &rest[octets..];
+    }
+    Ok(prefixes)
+}
+
+/// Capability annoncee dans un OPEN (RFC 5492).
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BgpCapability<'a> {
+    /// Extensions multiprotocoles (RFC 4760) : une famille AFI/SAFI.
+    Multiprotocol { afi: u16, safi: u8 },
+    /// Route Refresh (RFC 2918).
+    RouteRefresh,
+    /// Numeros d'AS sur 4 octets (RFC 6793), avec l'AS reel de l'emetteur.
+    FourOctetAs(u32),
+    /// Capability non decodee : code et valeur brute.
+    Other { code: u8, value: &'a [u8] },
+}
+
+impl<'a> BgpCapability<'a> {
+    fn parse(code: u8, value: &'a [u8]) -> Self {
+        match (code, value.len()) {
+            (MULTIPROTOCOL_CAPABILITY, 4) => Self::Multiprotocol {
+                afi: u16::from_be_bytes([value[0], value[1]]),
+                safi: value[3],
+            },
+            (ROUTE_REFRESH_CAPABILITY, 0) => Self::RouteRefresh,
+            (FOUR_OCTET_AS_CAPABILITY, 4) => Self::FourOctetAs(u32_at(value, 0)),
+            _ => Self::Other { code, value },
+        }
+    }
+}
+
+/// Parametre optionnel d'un OPEN.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BgpOptionalParameter<'a> {
+    Capabilities(Vec<BgpCapability<'a>>),
+    /// Parametre non decode (l'authentification, type 1, est obsolete).
+    Other {
+        parameter_type: u8,
+        value: &'a [u8],
+    },
+}
+
+/// OPEN : ouverture de session et negociation des capacites.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BgpOpen<'a> {
+    pub version: u8,
+    /// AS de l'emetteur sur 2 octets ; AS_TRANS (23456) quand l'AS reel ne
+    /// tient pas, voir [`BgpOpen::as_number`].
+    pub my_as: u16,
+    /// Secondes.
+    pub hold_time: u16,
+    pub bgp_identifier: Ipv4Addr,
+    pub parameters: Vec<BgpOptionalParameter<'a>>,
+}
+
+impl<'a> BgpOpen<'a> {
+    /// Toutes les capabilities, quel que soit le parametre qui les porte.
+    pub fn capabilities(&self) -> impl Iterator<Item = &BgpCapability<'a>> {
+        self.parameters
+            .iter()
+            .flat_map(|parameter| match parameter {
+                BgpOptionalParameter::Capabilities(capabilities) => capabilities.as_slice(),
+                BgpOptionalParameter::Other { .. } => &[],
+            })
+    }
+
+    /// AS reel de l'emetteur : celui de la capability 4 octets s'il est
+    /// annonce, `my_as` sinon.
+    pub fn as_number(&self) -> u32 {
+        self.capabilities()
+            .find_map(|capability| match capability {
+                BgpCapability::FourOctetAs(asn) => Some(*asn),
+                _ => None,
+            })
+            .unwrap_or(u32::from(self.my_as))
+    }
+}
+
+/// Segment d'un AS_PATH.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BgpAsPathSegment {
+    /// 1 AS_SET, 2 AS_SEQUENCE (3 et 4 pour les confederations).
+    pub segment_type: u8,
+    pub asns: Vec<u32>,
+}
+
+/// NLRI multiprotocoles annoncees (MP_REACH_NLRI, RFC 4760 §3).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BgpMpReach<'a> {
+    pub afi: u16,
+    pub safi: u8,
+    /// Next hop brut : 4 octets (IPv4), 16 ou 32 (IPv6 globale puis
+    /// link-local), ou plus pour les familles VPN.
+    pub next_hop: &'a [u8],
+    /// NLRI brutes, voir [`BgpMpReach::prefixes`].
+    pub nlri: &'a [u8],
+}
+
+impl BgpMpReach<'_> {
+    /// Prefixes annonces, pour les familles IPv4/IPv6 unicast et
+    /// multicast. `None` pour les autres familles (VPN, EVPN, flowspec...)
+    /// ou des NLRI incoherentes.
+    pub fn prefixes(&self) -> Option<Vec<BgpPrefix>> {
+        mp_prefixes(self.afi, self.safi, self.nlri)
+    }
+}
+
+/// NLRI multiprotocoles retirees (MP_UNREACH_NLRI, RFC 4760 §4).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BgpMpUnreach<'a> {
+    pub afi: u16,
+    pub safi: u8,
+    pub withdrawn: &'a [u8],
+}
+
+impl BgpMpUnreach<'_> {
+    /// Prefixes retires, memes familles que [`BgpMpReach::prefixes`].
+    pub fn prefixes(&self) -> Option<Vec<BgpPrefix>> {
+        mp_prefixes(self.afi, self.safi, self.withdrawn)
+    }
+}
+
+fn mp_prefixes(afi: u16, safi: u8, nlri: &[u8]) -> Option<Vec<BgpPrefix>> {
+    if !matches!(afi, AFI_IPV4 | AFI_IPV6) || !matches!(safi, SAFI_UNICAST | SAFI_MULTICAST) {
+        return None;
+    }
+    parse_prefixes(nlri, afi).ok()
+}
+
+/// Valeur d'un attribut de chemin.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BgpPathAttributeValue<'a> {
+    /// 0 IGP, 1 EGP, 2 INCOMPLETE.
+    Origin(u8),
+    AsPath(Vec<BgpAsPathSegment>),
+    NextHop(Ipv4Addr),
+    MultiExitDisc(u32),
+    LocalPref(u32),
+    AtomicAggregate,
+    Aggregator {
+        asn: u32,
+        address: Ipv4Addr,
+    },
+    /// Communautes RFC 1997, une valeur 32 bits (AS:valeur) chacune.
+    Communities(Vec<u32>),
+    MpReachNlri(BgpMpReach<'a>),
+    MpUnreachNlri(BgpMpUnreach<'a>),
+    /// Attribut non decode, ou dont la valeur ne correspond pas a son type.
+    Other(&'a [u8]),
+}
+
+/// Attribut de chemin d'un UPDATE.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BgpPathAttribute<'a> {
+    /// Bits Optional, Transitive, Partial et Extended Length.
+    pub flags: u8,
+    pub type_code: u8,
+    pub value: BgpPathAttributeValue<'a>,
+}
+
+impl<'a> BgpPathAttributeValue<'a> {
+    /// Decode la valeur d'un attribut. Une valeur dont la taille ne
+    /// correspond pas au type reste brute plutot que de faire echouer
+    /// l'UPDATE entier.
+    fn parse(type_code: u8, value: &'a [u8]) -> Self {
+        let typed = match (type_code, value.len()) {
+            (ORIGIN_ATTRIBUTE, 1) => Some(Self::Origin(value[0])),
+            (AS_PATH_ATTRIBUTE, _) => parse_as_path(value).map(Self::AsPath),
+            (NEXT_HOP_ATTRIBUTE, 4) => Some(Self::NextHop(Ipv4Addr::new(
+                value[0], value[1], value[2], value[3],
+            ))),
+            (MULTI_EXIT_DISC_ATTRIBUTE, 4) => Some(Self::MultiExitDisc(u32_at(value, 0))),
+            (LOCAL_PREF_ATTRIBUTE, 4) => Some(Self::LocalPref(u32_at(value, 0))),
+            (ATOMIC_AGGREGATE_ATTRIBUTE, 0) => Some(Self::AtomicAggregate),
+            (AGGREGATOR_ATTRIBUTE, 6) => Some(Self::Aggregator {
+                asn: u32::from(u16::from_be_bytes([value[0], value[1]])),
+                address: Ipv4Addr::new(value[2], value[3], value[4], value[5]),
+            }),
+            (AGGREGATOR_ATTRIBUTE, 8) => Some(Self::Aggregator {
+                asn: u32_at(value, 0),
+                address: Ipv4Addr::new(value[4], value[5], value[6], value[7]),
+            }),
+            (COMMUNITIES_ATTRIBUTE, length) if length.is_multiple_of(4) => Some(Self::Communities(
+                value
+                    .chunks_exact(4)
+                    .map(|chunk| u32_at(chunk, 0))
+                    .collect(),
+            )),
+            (MP_REACH_NLRI_ATTRIBUTE, _) => parse_mp_reach(value).map(Self::MpReachNlri),
+            (MP_UNREACH_NLRI_ATTRIBUTE, length) if length >= 3 => {
+                Some(Self::MpUnreachNlri(BgpMpUnreach {
+                    afi: u16::from_be_bytes([value[0], value[1]]),
+                    safi: value[2],
+                    withdrawn: &value[3..],
+                }))
+            }
+            _ => None,
+        };
+        typed.unwrap_or(Self::Other(value))
+    }
+}
+
+/// AS_PATH avec des AS de 4 octets si la structure le permet, de 2 sinon.
+fn parse_as_path(value: &[u8]) -> Option<Vec<BgpAsPathSegment>> {
+    parse_as_path_with(value, 4).or_else(|| parse_as_path_with(value, 2))
+}
+
+fn parse_as_path_with(mut value: &[u8], as_size: usize) -> Option<Vec<BgpAsPathSegment>> {
+    let mut segments = Vec::new();
+    while !value.is_empty() {
+        let header = value.get(..2)?;
+        let asns_length = usize::from(header[1]) * as_size;
+        let asns = value.get(2..2 + asns_length)?;
+        segments.push(BgpAsPathSegment {
+            segment_type: header[0],
+            asns: asns
+                .chunks_exact(as_size)
+                .map(|chunk| match chunk {
+                    [high, low] => u32::from(u16::from_be_bytes([*high, *low])),
+                    _ => u32_at(chunk, 0),
+                })
+                .collect(),
+        });
+        value = &value[2 + asns_length..];
+    }
+    Some(segments)
+}
+
+fn parse_mp_reach(value: &[u8]) -> Option<BgpMpReach<'_>> {
+    let header = value.get(..4)?;
+    let next_hop_length = usize::from(header[3]);
+    let next_hop = value.get(4..4 + next_hop_length)?;
+    // Octet reserve (anciennement SNPA) entre next hop et NLRI.
+    let nlri = value.get(4 + next_hop_length + 1..)?;
+    Some(BgpMpReach {
+        afi: u16::from_be_bytes([header[0], header[1]]),
+        safi: header[2],
+        next_hop,
+        nlri,
+    })
+}
+
+/// UPDATE : routes retirees, attributs de chemin et NLRI IPv4 annoncees.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BgpUpdate<'a> {
+    pub withdrawn_routes: Vec<BgpPrefix>,
+    pub path_attributes: Vec<BgpPathAttribute<'a>>,
+    pub nlri: Vec<BgpPrefix>,
+}
+
+impl BgpUpdate<'_> {
+    /// End-of-RIB IPv4 (RFC 4724) : UPDATE entierement vide.
+    pub fn is_end_of_rib(&self) -> bool {
+        self.withdrawn_routes.is_empty() && self.path_attributes.is_empty() && self.nlri.is_empty()
+    }
+}
+
+/// NOTIFICATION : erreur fatale, la session est fermee apres l'envoi.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BgpNotification<'a> {
+    /// 1 en-tete, 2 OPEN, 3 UPDATE, 4 hold timer, 5 FSM, 6 Cease.
+    pub error_code: u8,
+    pub error_subcode: u8,
+    pub data: &'a [u8],
+}
+
+/// Corps d'un message BGP, selon son type.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BgpBody<'a> {
+    Open(BgpOpen<'a>),
+    Update(BgpUpdate<'a>),
+    Notification(BgpNotification<'a>),
+    Keepalive,
+    /// ROUTE-REFRESH (RFC 2918, sous-type RFC 7313).
+    RouteRefresh {
+        afi: u16,
+        subtype: u8,
+        safi: u8,
+    },
+}
+
+/// Message BGP-4.
+///
+/// ```mermaid
+/// ---
+/// title: BgpPacket
+/// ---
+/// packet-beta
+/// 0-127: "Marker 16 octets a 0xff"
+/// 128-143: "Length u16"
+/// 144-151: "Type u8"
+/// 152-183: "Corps variable"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BgpPacket<'a> {
+    /// Longueur du message, en-tete compris.
+    pub length: u16,
+    pub message_type: u8,
+    pub body: BgpBody<'a>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for BgpPacket<'a> {
+    type Error = BgpError;
+
+    fn try_from(payload: &'a [u8]) -> Result<Self, BgpError> {
+        Self::from_stream(payload).map(|(packet, _)| packet)
+    }
+}
+
+impl<'a> BgpPacket<'a> {
+    /// Parse le premier message d'un flux TCP BGP et rend les octets qui le
+    /// suivent — eventuellement d'autres messages coalises dans le segment.
+    pub fn from_stream(stream: &'a [u8]) -> Result<(Self, &'a [u8]), BgpError> {
+        let length = extract_bgp_message_length(stream)?;
+        let (message, rest) = stream.split_at(length);
+        let message_type = message[18];
+        let body = &message[BGP_HEADER_LENGTH..];
+        // Longueur verifiee contre l'en-tete, qui la borne a u16.
+        let length = u16::from_be_bytes([message[16], message[17]]);
+
+        let body = match message_type {
+            OPEN_TYPE => BgpBody::Open(parse_open(body)?),
+            UPDATE_TYPE => BgpBody::Update(parse_update(body)?),
+            NOTIFICATION_TYPE => {
+                validate_bgp_body_length(body, 2)?;
+                BgpBody::Notification(BgpNotification {
+                    error_code: body[0],
+                    error_subcode: body[1],
+                    data: &body[2..],
+                })
+            }
+            KEEPALIVE_TYPE if body.is_empty() => BgpBody::Keepalive,
+            KEEPALIVE_TYPE => return Err(BgpError::InvalidKeepaliveLength(length)),
+            ROUTE_REFRESH_TYPE => {
+                validate_bgp_body_length(body, 4)?;
+                BgpBody::RouteRefresh {
+                    afi: u16::from_be_bytes([body[0], body[1]]),
+                    subtype: body[2],
+                    safi: body[3],
+                }
+            }
+            other => return Err(BgpError::UnknownMessageType(other)),
+        };
+
+        Ok((
+            BgpPacket {
+                length,
+                message_type,
+                body,
+            },
+            rest,
+        ))
+    }
+}
+
+fn parse_open(body: &[u8]) -> Result<BgpOpen<'_>, BgpError> {
+    validate_bgp_body_length(body, BGP_OPEN_FIXED_LENGTH)?;
+    if body[0] != BGP_VERSION {
+        return Err(BgpError::UnsupportedVersion(body[0]));
+    }
+
+    // Parametres etendus (RFC 9072) : longueurs de parametre sur 2 octets.
+    let rest = &body[BGP_OPEN_FIXED_LENGTH..];
+    let (parameters, extended) = match (body[9], rest.first()) {
+        (EXTENDED_PARAMETERS_MARKER, Some(&EXTENDED_PARAMETERS_MARKER)) => {
+            validate_bgp_body_length(rest, 3)?;
+            let declared = usize::from(u16::from_be_bytes([rest[1], rest[2]]));
+            (
+                extract_bgp_field(&rest[3..], declared, "optional parameters")?,
+                true,
+            )
+        }
+        (declared, _) => (
+            extract_bgp_field(rest, usize::from(declared), "optional parameters")?,
+            false,
+        ),
+    };
+
+    Ok(BgpOpen {
+        version: body[0],
+        my_as: u16::from_be_bytes([body[1], body[2]]),
+        hold_time: u16::from_be_bytes([body[3], body[4]]),
+        bgp_identifier: Ipv4Addr::new(body[5], body[6], body[7], body[8]),
+        parameters: parse_optional_parameters(parameters, extended)?,
+    })
+}
+
+fn parse_optional_parameters(
+    mut bytes: &[u8],
+    extended: bool,
+) -> Result<Vec<BgpOptionalParameter<'_>>, BgpError> {
+    let header_length = if extended { 3 } else { BGP_TLV_HEADER_LENGTH };
+    let mut parameters = Vec::new();
+    while !bytes.is_empty() {
+        let header = extract_bgp_field(bytes, header_length, "optional parameter header")?;
+        let declared = if extended {
+            usize::from(u16::from_be_bytes([header[1], header[2]]))
+        } else {
+            usize::from(header[1])
+        };
+        let value = extract_bgp_field(&bytes[header_length..], declared, "optional parameter")?;
+        parameters.push(match header[0] {
+            CAPABILITIES_PARAMETER => {
+                BgpOptionalParameter::Capabilities(parse_capabilities(value)?)
+            }
+            parameter_type => BgpOptionalParameter::Other {
+                parameter_type,
+                value,
+            },
+        });
+        bytes = &bytes[header_length + declared..];
+    }
+    Ok(parameters)
+}
+
+fn parse_capabilities(mut bytes: &[u8]) -> Result<Vec<BgpCapability<'_>>, BgpError> {
+    let mut capabilities = Vec::new();
+    while !bytes.is_empty() {
+        let header = extract_bgp_field(bytes, BGP_TLV_HEADER_LENGTH, "capability header")?;
+        let declared = usize::from(header[1]);
+        let value = extract_bgp_field(&bytes[BGP_TLV_HEADER_LENGTH..], declared, "capability")?;
+        capabilities.push(BgpCapability::parse(header[0], value));
+        bytes = &bytes[BGP_TLV_HEADER_LENGTH + declared..];
+    }
+    Ok(capabilities)
+}
+
+fn parse_update(body: &[u8]) -> Result<BgpUpdate<'_>, BgpError> {
+    validate_bgp_body_length(body, 4)?;
+    let withdrawn_length = usize::from(u16::from_be_bytes([body[0], body[1]]));
+    let withdrawn = extract_bgp_field(&body[2..], withdrawn_length, "withdrawn routes")?;
+
+    let rest = &body[2 + withdrawn_length..];
+    validate_bgp_body_length(rest, 2)?;
+    let attributes_length = usize::from(u16::from_be_bytes([rest[0], rest[1]]));
+    let attributes = extract_bgp_field(&rest[2..], attributes_length, "path attributes")?;
+
+    Ok(BgpUpdate {
+        withdrawn_routes: parse_prefixes(withdrawn, AFI_IPV4)?,
+        path_attributes: parse_path_attributes(attributes)?,
+        nlri: parse_prefixes(&rest[2 + attributes_length..], AFI_IPV4)?,
+    })
+}
+
+fn parse_path_attributes(mut bytes: &[u8]) -> Result<Vec<BgpPathAttribute<'_>>, BgpError> {
+    let mut attributes = Vec::new();
+    while !bytes.is_empty() {
+        let flags = bytes[0];
+        let header_length = if flags & EXTENDED_LENGTH_FLAG != 0 {
+            4
+        } else {
+            3
+        };
+        let header = extract_bgp_field(bytes, header_length, "path attribute header")?;
+        let declared = if header_length == 4 {
+            usize::from(u16::from_be_bytes([header[2], header[3]]))
+        } else {
+            usize::from(header[2])
+        };
+        let value = extract_bgp_field(&bytes[header_length..], declared, "path attribute")?;
+        attributes.push(BgpPathAttribute {
+            flags,
+            type_code: header[1],
+            value: BgpPathAttributeValue::parse(header[1], value),
+        });
+        bytes = &bytes[header_length + declared..];
+    }
+    Ok(attributes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// En-tete BGP complet pour un type et un corps donnes.
+    fn message(message_type: u8, body: &[u8]) -> Vec<u8> {
+        let mut raw = vec![0xff; 16];
+        raw.extend_from_slice(&u16::try_from(19 + body.len()).unwrap().to_be_bytes());
+        raw.push(message_type);
+        raw.extend_from_slice(body);
+        raw
+    }
+
+    /// Synthetique : OPEN AS_TRANS, hold 180 s, capabilities IPv4 unicast,
+    /// route refresh et AS 4 octets 4200000000.
+    #[test]
+    fn parses_open_with_capabilities() {
+        let body = [
+            4, 0x5b, 0xa0, 0x00, 0xb4, 10, 0, 0, 1, 16, 2, 14, 1, 4, 0, 1, 0, 1, 2, 0, 65, 4, 0xfa,
+            0x56, 0xea, 0x00,
+        ];
+        let raw = message(1, &body);
+        let packet = BgpPacket::try_from(raw.as_slice()).expect("open parses");
+
+        let BgpBody::Open(open) = &packet.body else {
+            panic!("expected an OPEN, got {:?}", packet.body);
+        };
+        assert_eq!(open.my_as, 23456);
+        assert_eq!(open.hold_time, 180);
+        assert_eq!(open.bgp_identifier, Ipv4Addr::new(10, 0, 0, 1));
+        assert_eq!(
+            open.capabilities().cloned().collect::<Vec<_>>(),
+            [
+                BgpCapability::Multiprotocol { afi: 1, safi: 1 },
+                BgpCapability::RouteRefresh,
+                BgpCapability::FourOctetAs(4_200_000_000),
+            ]
+        );
+        assert_eq!(open.as_number(), 4_200_000_000);
+    }
+
+    /// Synthetique : UPDATE retirant 10.1.0.0/16 et annoncant 192.0.2.0/24
+    /// avec ORIGIN, AS_PATH (AS 4 octets), NEXT_HOP, LOCAL_PREF et une
+    /// communaute.
+    #[test]
+    fn parses_update_with_attributes_and_nlri() {
+        let mut body = vec![0x00, 0x03, 16, 10, 1];
+        let attributes = [
+            0x40, 1, 1, 0, // ORIGIN IGP
+            0x40, 2, 10, 2, 2, 0, 0, 0xfd, 0xe8, 0, 0, 0xfd, 0xe9, // AS_SEQUENCE 65000 65001
+            0x40, 3, 4, 192, 0, 2, 254, // NEXT_HOP
+            0x40, 5, 4, 0, 0, 0, 100, // LOCAL_PREF
+            0xc0, 8, 4, 0xfd, 0xe8, 0, 1, // COMMUNITIES 65000:1
+        ];
+        body.extend_from_slice(&u16::try_from(attributes.len()).unwrap().to_be_bytes());
+        body.extend_from_slice(&attributes);
+        body.extend_from_slice(&[24, 192, 0, 2]);
+        let raw = message(2, &body);
+        let packet = BgpPacket::try_from(raw.as_slice()).expect("update parses");
+
+        let BgpBody::Update(update) = &packet.body else {
+            panic!("expected an UPDATE, got {:?}", packet.body);
+        };
+        assert_eq!(
+            update.withdrawn_routes,
+            [BgpPrefix {
+                address: IpAddr::V4(Ipv4Addr::new(10, 1, 0, 0)),
+                length: 16
+            }]
+        );
+        assert_eq!(
+            update.nlri,
+            [BgpPrefix {
+                address: IpAddr::V4(Ipv4Addr::new(192, 0, 2, 0)),
+                length: 24
+            }]
+        );
+        let values: Vec<_> = update.path_attributes.iter().map(|a| &a.value).collect();
+        assert_eq!(values[0], &BgpPathAttributeValue::Origin(0));
+        assert_eq!(
+            values[1],
+            &BgpPathAttributeValue::AsPath(vec![BgpAsPathSegment {
+                segment_type: 2,
+                asns: vec![65000, 65001]
+            }])
+        );
+        assert_eq!(
+            values[2],
+            &BgpPathAttributeValue::NextHop(Ipv4Addr::new(192, 0, 2, 254))
+        );
+        assert_eq!(values[3], &BgpPathAttributeValue::LocalPref(100));
+        assert_eq!(
+            values[4],
+            &BgpPathAttributeValue::Communities(vec![0xfde8_0001])
+        );
+        assert!(!update.is_end_of_rib());
+    }
+
+    /// Synthetique : AS_PATH a AS de 2 octets (session sans RFC 6793).
+    #[test]
+    fn as_path_falls_back_to_two_octet_asns() {
+        assert_eq!(
+            parse_as_path(&[2, 3, 0xfd, 0xe8, 0xfd, 0xe9, 0xfd, 0xea]),
+            Some(vec![BgpAsPathSegment {
+                segment_type: 2,
+                asns: vec![65000, 65001, 65002]
+            }])
+        );
+    }
+
+    /// Synthetique : MP_REACH_NLRI IPv6 unicast, next hop 2001:db8::1,
+    /// prefixe 2001:db8:1::/48, attribut a longueur etendue.
+    #[test]
+    fn parses_mp_reach_ipv6_prefixes() {
+        let mut value = vec![0, 2, 1, 16];
+        value.extend_from_slice(&"2001:db8::1".parse::<Ipv6Addr>().unwrap().octets());
+        value.extend_from_slice(&[0, 48, 0x20, 0x01, 0x0d, 0xb8, 0x00, 0x01]);
+        let mut attributes = vec![0x90, 14];
+        attributes.extend_from_slice(&u16::try_from(value.len()).unwrap().to_be_bytes());
+        attributes.extend_from_slice(&value);
+        let mut body = vec![0, 0];
+        body.extend_from_slice(&u16::try_from(attributes.len()).unwrap().to_be_bytes());
+        body.extend_from_slice(&attributes);
+        let raw = message(2, &body);
+        let packet = BgpPacket::try_from(raw.as_slice()).expect("update parses");
+
+        let BgpBody::Update(update) = &packet.body else {
+            panic!("expected an UPDATE, got {:?}", packet.body);
+        };
+        let BgpPathAttributeValue::MpReachNlri(reach) = &update.path_attributes[0].value else {
+            panic!("expected MP_REACH_NLRI");
+        };
+        assert_eq!(reach.next_hop.len(), 16);
+        assert_eq!(
+            reach.prefixes(),
+            Some(vec![BgpPrefix {
+                address: IpAddr::V6("2001:db8:1::".parse().unwrap()),
+                length: 48
+            }])
+        );
+    }
+
+    /// Synthetique : NOTIFICATION Cease / Administrative Shutdown, suivi d'un
+    /// KEEPALIVE coalise dans le meme segment.
+    #[test]
+    fn parses_notification_then_coalesced_keepalive() {
+        let mut raw = message(3, &[6, 2]);
+        raw.extend_from_slice(&message(4, &[]));
+
+        let (first, rest) = BgpPacket::from_stream(&raw).expect("notification parses");
+        assert_eq!(
+            first.body,
+            BgpBody::Notification(BgpNotification {
+                error_code: 6,
+                error_subcode: 2,
+                data: &[]
+            })
+        );
+        let (second, rest) = BgpPacket::from_stream(rest).expect("keepalive parses");
+        assert_eq!(second.body, BgpBody::Keepalive);
+        assert!(rest.is_empty());
+    }
+
+    /// Synthetique : OPEN annoncant une version 3.
+    #[test]
+    fn rejects_open_with_unsupported_version() {
+        let raw = message(1, &[3, 0, 1, 0, 90, 1, 1, 1, 1, 0]);
+
+        assert_eq!(
+            BgpPacket::try_from(raw.as_slice()).unwrap_err(),
+            BgpError::UnsupportedVersion(3)
+        );
+    }
+
+    /// Synthetique : UPDATE dont la longueur d'attributs depasse le message.
+    #[test]
+    fn rejects_update_with_overlong_attributes() {
+        let raw = message(2, &[0, 0, 0, 40, 0x40, 1, 1, 0]);
+
+        assert_eq!(
+            BgpPacket::try_from(raw.as_slice()).unwrap_err(),
+            BgpError::TruncatedField {
+                field: "path attributes",
+                declared: 40,
+                available: 4
+            }
+        );
+    }
+}
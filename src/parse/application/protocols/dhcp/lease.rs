@@ -0,0 +1,367 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Lease tracking across DHCPv4 exchanges.
+//!
+//! A client announces its hostname, vendor class and parameter request list
+//! in DISCOVER and REQUEST; the server offers then acknowledges an address.
+//! [`DhcpLeaseTracker`] folds these messages, keyed by client hardware
+//! address, into one [`DhcpBinding`] per client for asset inventory.
+
+use std::{collections::VecDeque, net::Ipv4Addr};
+
+use crate::{
+    checks::application::dhcp::{
+        DHCP_ACK, DHCP_DECLINE, DHCP_DISCOVER, DHCP_INFORM, DHCP_MAX_TRACKED_LEASES, DHCP_NAK,
+        DHCP_OFFER, DHCP_RELEASE, DHCP_REQUEST,
+    },
+    errors::application::dhcp::DhcpOptionError,
+    parse::application::protocols::dhcp::{
+        DhcpPacket,
+        options::{DhcpOption, parameter_list},
+    },
+};
+
+/// Stage reached by a client in the RFC 2131 exchange.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DhcpLeaseState {
+    /// DISCOVER seen, no offer yet.
+    Selecting,
+    /// The server offered `ip_address`.
+    Offered,
+    /// The client requested `ip_address` (new lease, renewal or reboot).
+    Requesting,
+    /// The server acknowledged `ip_address`.
+    Bound,
+    /// The client declined the address, already in use on the link.
+    Declined,
+    /// The server answered with a NAK.
+    Rejected,
+    /// The client released `ip_address`.
+    Released,
+    /// The client configured `ip_address` by other means and only asked
+    /// for parameters (INFORM).
+    Informed,
+}
+
+/// What the exchanges taught about one client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DhcpBinding {
+    pub client_hardware_address: Vec<u8>,
+    pub hardware_type: u8,
+    /// Address offered, requested or bound, depending on `state`; last
+    /// known address after a release.
+    pub ip_address: Option<Ipv4Addr>,
+    /// Host Name (option 12), or else the Client FQDN (option 81).
+    pub hostname: Option<String>,
+    pub vendor_class_id: Option<String>,
+    /// Parameter Request List as "1,3,6,15", see
+    /// [`DhcpPacket::fingerprint`].
+    pub fingerprint: Option<String>,
+    pub server_identifier: Option<Ipv4Addr>,
+    pub lease_time: Option<u32>,
+    pub transaction_id: u32,
+    pub state: DhcpLeaseState,
+}
+
+impl DhcpBinding {
+    pub fn is_bound(&self) -> bool {
+        self.state == DhcpLeaseState::Bound
+    }
+}
+
+/// Builds MAC → IP → hostname bindings from the DHCPv4 messages of a
+/// capture.
+///
+/// Push every DHCP message, in capture order, with [`push`](Self::push).
+/// Messages without a message type (plain BOOTP) or without a client
+/// hardware address are ignored. Beyond [`DHCP_MAX_TRACKED_LEASES`]
+/// clients, the one updated least recently is forgotten.
+///
+/// # Example
+/// ```
+/// use packet_parser::parse::application::protocols::dhcp::{
+///     DhcpPacket,
+///     lease::{DhcpLeaseState, DhcpLeaseTracker},
+/// };
+///
+/// // DHCPACK for 00:0c:29:36:57:d2, address 192.168.0.10.
+/// let mut ack = vec![0_u8; 236];
+/// ack[..3].copy_from_slice(&[2, 1, 6]);
+/// ack[16..20].copy_from_slice(&[192, 168, 0, 10]);
+/// ack[28..34].copy_from_slice(&[0x00, 0x0c, 0x29, 0x36, 0x57, 0xd2]);
+/// ack.extend_from_slice(&[0x63, 0x82, 0x53, 0x63, 53, 1, 5, 12, 4]);
+/// ack.extend_from_slice(b"plc1");
+/// ack.push(255);
+///
+/// let mut tracker = DhcpLeaseTracker::new();
+/// tracker.push(&DhcpPacket::try_from(&ack[..]).unwrap()).unwrap();
+///
+/// let binding = tracker.binding(&[0x00, 0x0c, 0x29, 0x36, 0x57, 0xd2]).unwrap();
+/// assert_eq!(binding.state, DhcpLeaseState::Bound);
+/// assert_eq!(binding.ip_address, Some([192, 168, 0, 10].into()));
+/// assert_eq!(binding.hostname.as_deref(), Some("plc1"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DhcpLeaseTracker {
+    bindings: VecDeque<DhcpBinding>,
+}
+
+impl DhcpLeaseTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Updates the binding of the client the message is about.
+    pub fn push(&mut self, packet: &DhcpPacket<'_>) -> Result<(), DhcpOptionError> {
+        let options = packet.dhcp_options()?;
+        let Some(message_type) = options.iter().find_map(|option| match option {
+            DhcpOption::MessageType(message_type) => Some(*message_type),
+            _ => None,
+        }) else {
+            return Ok(());
+        };
+        let client_hardware_address = packet.client_hardware_address();
+        if client_hardware_address.iter().all(|byte| *byte == 0) {
+            return Ok(());
+        }
+
+        let ciaddr = nonzero(packet.ciaddr);
+        let yiaddr = nonzero(packet.yiaddr);
+        let requested = options
+            .iter()
+            .find_map(|option| match option {
+                DhcpOption::RequestedIp(address) => nonzero(address.octets()),
+                _ => None,
+            })
+            .or(ciaddr);
+        // Etat atteint et adresse associee ; `None` garde l'adresse connue.
+        let (state, address) = match message_type {
+            DHCP_DISCOVER => (DhcpLeaseState::Selecting, None),
+            DHCP_OFFER => (DhcpLeaseState::Offered, yiaddr),
+            DHCP_REQUEST => (DhcpLeaseState::Requesting, requested),
+            DHCP_DECLINE => (DhcpLeaseState::Declined, None),
+            // Un ACK a un INFORM laisse yiaddr a zero.
+            DHCP_ACK if yiaddr.is_none() => (DhcpLeaseState::Informed, ciaddr),
+            DHCP_ACK => (DhcpLeaseState::Bound, yiaddr),
+            DHCP_NAK => (DhcpLeaseState::Rejected, None),
+            DHCP_RELEASE => (DhcpLeaseState::Released, ciaddr),
+            DHCP_INFORM => (DhcpLeaseState::Informed, ciaddr),
+            _ => return Ok(()),
+        };
+
+        let mut binding = self.take(client_hardware_address).unwrap_or(DhcpBinding {
+            client_hardware_address: client_hardware_address.to_vec(),
+            hardware_type: packet.htype,
+            ip_address: None,
+            hostname: None,
+            vendor_class_id: None,
+            fingerprint: None,
+            server_identifier: None,
+            lease_time: None,
+            transaction_id: packet.xid,
+            state,
+        });
+        binding.transaction_id = packet.xid;
+        binding.state = state;
+        match state {
+            DhcpLeaseState::Declined | DhcpLeaseState::Rejected => {
+                binding.ip_address = None;
+                binding.lease_time = None;
+            }
+            _ => binding.ip_address = address.or(binding.ip_address),
+        }
+
+        let mut fqdn = None;
+        for option in &options {
+            match option {
+                DhcpOption::Hostname(name) => binding.hostname = Some(text(name)),
+                DhcpOption::ClientFqdn(client_fqdn) if !client_fqdn.name.is_empty() => {
+                    fqdn = Some(client_fqdn.name.clone());
+                }
+                DhcpOption::VendorClassId(class) => binding.vendor_class_id = Some(text(class)),
+                DhcpOption::ParameterRequestList(codes) => {
+                    binding.fingerprint = Some(parameter_list(codes));
+                }
+                DhcpOption::ServerId(server) => binding.server_identifier = Some(*server),
+                DhcpOption::LeaseTime(seconds) if message_type == DHCP_ACK => {
+                    binding.lease_time = Some(*seconds);
+                }
+                _ => {}
+            }
+        }
+        if binding.hostname.is_none() {
+            binding.hostname = fqdn;
+        }
+
+        if self.bindings.len() >= DHCP_MAX_TRACKED_LEASES {
+            self.bindings.pop_front();
+        }
+        self.bindings.push_back(binding);
+        Ok(())
+    }
+
+    /// Binding of a client, by hardware address.
+    pub fn binding(&self, client_hardware_address: &[u8]) -> Option<&DhcpBinding> {
+        self.bindings
+            .iter()
+            .find(|binding| binding.client_hardware_address == client_hardware_address)
+    }
+
+    /// Every binding, least recently updated first.
+    pub fn bindings(&self) -> impl Iterator<Item = &DhcpBinding> {
+        self.bindings.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.bindings.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bindings.is_empty()
+    }
+
+    fn take(&mut self, client_hardware_address: &[u8]) -> Option<DhcpBinding> {
+        let position = self
+            .bindings
+            .iter()
+            .position(|binding| binding.client_hardware_address == client_hardware_address)?;
+        self.bindings.remove(position)
+    }
+}
+
+fn nonzero(octets: [u8; 4]) -> Option<Ipv4Addr> {
+    (octets != [0; 4]).then(|| Ipv4Addr::from(octets))
+}
+
+// Certains clients terminent le nom par un NUL.
+fn text(bytes: &[u8]) -> String {
+    let bytes = bytes.strip_suffix(&[0]).unwrap_or(bytes);
+    String::from_utf8_lossy(bytes).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::application::protocols::dhcp::tests_fixtures::{
+        DHCP_DISCOVER_PAYLOAD, DHCP_OFFER_PAYLOAD,
+    };
+
+    const CLIENT: [u8; 6] = [0x00, 0x0b, 0x82, 0x01, 0xfc, 0x42];
+
+    /// Remplace la zone options d'une trame de reference (cookie conserve).
+    fn with_options(base: &[u8], op: u8, options: &[u8]) -> Vec<u8> {
+        let mut payload = base[..240].to_vec();
+        payload[0] = op;
+        payload.extend_from_slice(options);
+        payload
+    }
+
+    fn push(tracker: &mut DhcpLeaseTracker, payload: &[u8]) {
+        let packet = DhcpPacket::try_from(payload).expect("dhcp");
+        tracker.push(&packet).expect("options");
+    }
+
+    #[test]
+    fn discover_offer_request_ack_binds_the_client() {
+        let mut tracker = DhcpLeaseTracker::new();
+        push(&mut tracker, &DHCP_DISCOVER_PAYLOAD);
+        let binding = tracker.binding(&CLIENT).expect("client vu");
+        assert_eq!(binding.state, DhcpLeaseState::Selecting);
+        assert_eq!(binding.fingerprint.as_deref(), Some("1,3,6,42"));
+        assert_eq!(binding.ip_address, None);
+
+        // L'OFFER reel vise le meme client : yiaddr 192.168.0.10.
+        let mut offer = DHCP_OFFER_PAYLOAD.to_vec();
+        offer[28..34].copy_from_slice(&CLIENT);
+        push(&mut tracker, &offer);
+        let binding = tracker.binding(&CLIENT).expect("client vu");
+        assert_eq!(binding.state, DhcpLeaseState::Offered);
+        assert_eq!(binding.ip_address, Some(Ipv4Addr::new(192, 168, 0, 10)));
+        assert_eq!(binding.lease_time, None);
+
+        // REQUEST : adresse demandee (50), nom d'hote et classe fournisseur.
+        let request = with_options(
+            &DHCP_DISCOVER_PAYLOAD,
+            1,
+            &[
+                53, 1, 3, 50, 4, 192, 168, 0, 10, 12, 5, b'h', b'm', b'i', b'0', 0, 60, 4, b'M',
+                b'S', b'F', b'T', 255,
+            ],
+        );
+        push(&mut tracker, &request);
+        let binding = tracker.binding(&CLIENT).expect("client vu");
+        assert_eq!(binding.state, DhcpLeaseState::Requesting);
+        assert_eq!(binding.hostname.as_deref(), Some("hmi0"));
+        assert_eq!(binding.vendor_class_id.as_deref(), Some("MSFT"));
+
+        let mut ack = offer.clone();
+        ack.truncate(240);
+        ack.extend_from_slice(&[
+            53, 1, 5, 51, 4, 0, 0, 0x0e, 0x10, 54, 4, 192, 168, 0, 1, 255,
+        ]);
+        push(&mut tracker, &ack);
+        let binding = tracker.binding(&CLIENT).expect("client vu");
+        assert!(binding.is_bound());
+        assert_eq!(binding.ip_address, Some(Ipv4Addr::new(192, 168, 0, 10)));
+        assert_eq!(binding.lease_time, Some(3600));
+        assert_eq!(
+            binding.server_identifier,
+            Some(Ipv4Addr::new(192, 168, 0, 1))
+        );
+        // Le nom et l'empreinte du client survivent aux messages du serveur.
+        assert_eq!(binding.hostname.as_deref(), Some("hmi0"));
+        assert_eq!(binding.fingerprint.as_deref(), Some("1,3,6,42"));
+        assert_eq!(tracker.len(), 1);
+    }
+
+    #[test]
+    fn nak_and_release() {
+        let mut tracker = DhcpLeaseTracker::new();
+        let mut ack = DHCP_OFFER_PAYLOAD[..240].to_vec();
+        ack[28..34].copy_from_slice(&CLIENT);
+        ack.extend_from_slice(&[53, 1, 5, 51, 4, 0, 0, 0x0e, 0x10, 255]);
+        push(&mut tracker, &ack);
+
+        let mut release = with_options(&DHCP_DISCOVER_PAYLOAD, 1, &[53, 1, 7, 255]);
+        release[12..16].copy_from_slice(&[192, 168, 0, 10]);
+        push(&mut tracker, &release);
+        let binding = tracker.binding(&CLIENT).expect("client vu");
+        assert_eq!(binding.state, DhcpLeaseState::Released);
+        assert_eq!(binding.ip_address, Some(Ipv4Addr::new(192, 168, 0, 10)));
+
+        let mut nak = DHCP_OFFER_PAYLOAD[..240].to_vec();
+        nak[16..20].fill(0);
+        nak[28..34].copy_from_slice(&CLIENT);
+        nak.extend_from_slice(&[53, 1, 6, 255]);
+        push(&mut tracker, &nak);
+        let binding = tracker.binding(&CLIENT).expect("client vu");
+        assert_eq!(binding.state, DhcpLeaseState::Rejected);
+        assert_eq!((binding.ip_address, binding.lease_time), (None, None));
+    }
+
+    #[test]
+    fn ignores_bootp_and_forgets_oldest_clients() {
+        let mut tracker = DhcpLeaseTracker::new();
+        // BOOTP : zone options vide.
+        push(&mut tracker, &DHCP_DISCOVER_PAYLOAD[..236]);
+        assert!(tracker.is_empty());
+
+        let mut discover = DHCP_DISCOVER_PAYLOAD.to_vec();
+        for index in 0..=DHCP_MAX_TRACKED_LEASES {
+            discover[32..34].copy_from_slice(&(index as u16).to_be_bytes());
+            push(&mut tracker, &discover);
+        }
+        assert_eq!(tracker.len(), DHCP_MAX_TRACKED_LEASES);
+        assert!(tracker.binding(&[0x00, 0x0b, 0x82, 0x01, 0, 0]).is_none());
+        assert_eq!(
+            tracker
+                .bindings()
+                .last()
+                .map(|binding| binding.transaction_id),
+            Some(0x3d1d)
+        );
+    }
+}
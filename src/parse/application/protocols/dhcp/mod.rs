@@ -17,6 +17,9 @@ use crate::{
     errors::application::dhcp::DhcpParseError,
 };
 
+pub mod lease;
+pub mod options;
+
 /// DHCP Packet
 ///
 /// ```mermaid
@@ -45,6 +48,7 @@ use crate::{
 ///
 /// Parsing is zero-copy: fixed-size fields (`chaddr`, `sname`, `file`) and the
 /// variable-length `options` area are borrowed slices into the original packet.
+/// [`DhcpPacket::dhcp_options`] decodes the options area on demand.
 #[derive(Debug)]
 pub struct DhcpPacket<'a> {
     pub op: u8,
@@ -0,0 +1,464 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Typed DHCPv4 options (RFC 2132 and its extensions).
+//!
+//! The options area of a [`DhcpPacket`] stays a raw slice; these types
+//! decode it on demand, including the options carried in `file` and
+//! `sname` when the Option Overload (52) asks for it.
+
+use std::net::Ipv4Addr;
+
+use crate::{
+    checks::application::dhcp::{
+        DHCP_FQDN_FLAG_ENCODED, DHCP_MAGIC_COOKIE, DHCP_OPTION_CLIENT_FQDN, DHCP_OPTION_CLIENT_ID,
+        DHCP_OPTION_DNS_SERVERS, DHCP_OPTION_DOMAIN_NAME, DHCP_OPTION_END, DHCP_OPTION_HOSTNAME,
+        DHCP_OPTION_LEASE_TIME, DHCP_OPTION_MESSAGE_TYPE, DHCP_OPTION_OVERLOAD, DHCP_OPTION_PAD,
+        DHCP_OPTION_PARAMETER_REQUEST_LIST, DHCP_OPTION_REBINDING_TIME,
+        DHCP_OPTION_RELAY_AGENT_INFO, DHCP_OPTION_RENEWAL_TIME, DHCP_OPTION_REQUESTED_IP,
+        DHCP_OPTION_ROUTER, DHCP_OPTION_SERVER_ID, DHCP_OPTION_SUBNET_MASK,
+        DHCP_OPTION_VENDOR_CLASS_ID, DHCP_OVERLOAD_FILE, DHCP_OVERLOAD_SNAME,
+        dhcp_message_type_name, extract_dhcp_option, validate_dhcp_address_list,
+        validate_dhcp_option_length,
+    },
+    errors::application::dhcp::DhcpOptionError,
+    parse::application::protocols::dhcp::DhcpPacket,
+};
+
+/// Sous-option de Relay Agent Information (option 82, RFC 3046 §2.0).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DhcpRelayAgentSubOption<'a> {
+    pub code: u8,
+    pub data: &'a [u8],
+}
+
+/// Relay Agent Information (option 82) : sous-options ajoutees par le
+/// relais, typiquement le port (circuit id) et l'equipement (remote id)
+/// par lesquels le client est arrive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DhcpRelayAgentInfo<'a> {
+    pub suboptions: Vec<DhcpRelayAgentSubOption<'a>>,
+}
+
+impl<'a> DhcpRelayAgentInfo<'a> {
+    /// Agent Circuit ID (sous-option 1).
+    pub fn circuit_id(&self) -> Option<&'a [u8]> {
+        self.suboption(1)
+    }
+
+    /// Agent Remote ID (sous-option 2).
+    pub fn remote_id(&self) -> Option<&'a [u8]> {
+        self.suboption(2)
+    }
+
+    fn suboption(&self, code: u8) -> Option<&'a [u8]> {
+        self.suboptions
+            .iter()
+            .find(|suboption| suboption.code == code)
+            .map(|suboption| suboption.data)
+    }
+}
+
+/// Client FQDN (option 81, RFC 4702) : nom que le client souhaite voir
+/// enregistre dans le DNS, et qui du client ou du serveur s'en charge.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DhcpClientFqdn {
+    /// Drapeaux N, E, O et S (RFC 4702 §2.1).
+    pub flags: u8,
+    pub rcode1: u8,
+    pub rcode2: u8,
+    /// Nom en notation pointee, vide si le client laisse le serveur le
+    /// choisir.
+    pub name: String,
+}
+
+/// Option DHCPv4, typee selon son code.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DhcpOption<'a> {
+    SubnetMask(Ipv4Addr),
+    Router(Vec<Ipv4Addr>),
+    DnsServers(Vec<Ipv4Addr>),
+    /// Nom d'hote du client (option 12), sans encodage impose.
+    Hostname(&'a [u8]),
+    DomainName(&'a [u8]),
+    RequestedIp(Ipv4Addr),
+    /// Duree du bail en secondes (option 51), `u32::MAX` pour un bail
+    /// infini.
+    LeaseTime(u32),
+    /// Champs portant des options supplementaires : 1 file, 2 sname,
+    /// 3 les deux (option 52).
+    Overload(u8),
+    MessageType(u8),
+    ServerId(Ipv4Addr),
+    /// Codes d'option demandes par le client, dans son ordre (option 55).
+    ParameterRequestList(&'a [u8]),
+    RenewalTime(u32),
+    RebindingTime(u32),
+    VendorClassId(&'a [u8]),
+    /// Client Identifier (option 61) : type materiel ARP, ou 0 pour un
+    /// identifiant libre, puis l'identifiant.
+    ClientId {
+        hardware_type: u8,
+        identifier: &'a [u8],
+    },
+    ClientFqdn(DhcpClientFqdn),
+    RelayAgentInfo(DhcpRelayAgentInfo<'a>),
+    /// Option non interpretee : code et donnees brutes.
+    Other {
+        code: u8,
+        data: &'a [u8],
+    },
+}
+
+impl<'a> DhcpPacket<'a> {
+    /// Options typees du message, decodees a la demande. Quand l'option 52
+    /// le demande, les options de `file` puis de `sname` suivent celles de
+    /// la zone options (RFC 2131 §4.1). Vide pour un message BOOTP.
+    pub fn dhcp_options(&self) -> Result<Vec<DhcpOption<'a>>, DhcpOptionError> {
+        let Some(area) = self.options.get(DHCP_MAGIC_COOKIE.len()..) else {
+            return Ok(Vec::new());
+        };
+        let mut options = parse_dhcp_options(area)?;
+        let overload = options.iter().find_map(|option| match option {
+            DhcpOption::Overload(overload) => Some(*overload),
+            _ => None,
+        });
+        if let Some(overload) = overload {
+            if overload & DHCP_OVERLOAD_FILE != 0 {
+                options.extend(parse_dhcp_options(self.file)?);
+            }
+            if overload & DHCP_OVERLOAD_SNAME != 0 {
+                options.extend(parse_dhcp_options(self.sname)?);
+            }
+        }
+        Ok(options)
+    }
+
+    /// Adresse materielle du client : les `hlen` premiers octets de
+    /// `chaddr`.
+    pub fn client_hardware_address(&self) -> &'a [u8] {
+        let chaddr: &'a [u8; 16] = self.chaddr;
+        &chaddr[..usize::from(self.hlen).min(chaddr.len())]
+    }
+
+    /// Type de message (option 53), absent pour un message BOOTP ou une
+    /// zone options illisible.
+    pub fn message_type(&self) -> Option<u8> {
+        self.dhcp_options()
+            .ok()?
+            .iter()
+            .find_map(|option| match option {
+                DhcpOption::MessageType(message_type) => Some(*message_type),
+                _ => None,
+            })
+    }
+
+    pub fn message_type_name(&self) -> Option<&'static str> {
+        self.message_type().map(dhcp_message_type_name)
+    }
+
+    /// Empreinte du client : codes de la Parameter Request List separes
+    /// par des virgules, dans l'ordre d'envoi (« 1,3,6,15 »). L'ordre
+    /// depend de la pile DHCP et sert a reconnaitre le systeme.
+    pub fn fingerprint(&self) -> Option<String> {
+        self.dhcp_options()
+            .ok()?
+            .iter()
+            .find_map(|option| match option {
+                DhcpOption::ParameterRequestList(codes) => Some(parameter_list(codes)),
+                _ => None,
+            })
+    }
+}
+
+/// Decode une suite d'options DHCPv4, sans magic cookie, jusqu'a l'option
+/// End ou la fin des donnees. Une option tronquee ou de taille incoherente
+/// fait echouer toute la liste ; une option inconnue est rendue en
+/// [`DhcpOption::Other`].
+pub fn parse_dhcp_options(mut area: &[u8]) -> Result<Vec<DhcpOption<'_>>, DhcpOptionError> {
+    let mut options = Vec::new();
+    while let [code, rest @ ..] = area {
+        match *code {
+            DHCP_OPTION_PAD => area = rest,
+            DHCP_OPTION_END => break,
+            _ => {
+                let (code, data, rest) = extract_dhcp_option(area)?;
+                options.push(parse_option(code, data)?);
+                area = rest;
+            }
+        }
+    }
+    Ok(options)
+}
+
+/// Codes d'une Parameter Request List au format d'empreinte « 1,3,6 ».
+pub(crate) fn parameter_list(codes: &[u8]) -> String {
+    codes
+        .iter()
+        .map(u8::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn ipv4(code: u8, data: &[u8]) -> Result<Ipv4Addr, DhcpOptionError> {
+    validate_dhcp_option_length(code, data, 4)?;
+    Ok(Ipv4Addr::new(data[0], data[1], data[2], data[3]))
+}
+
+fn ipv4_list(code: u8, data: &[u8]) -> Result<Vec<Ipv4Addr>, DhcpOptionError> {
+    validate_dhcp_address_list(code, data)?;
+    Ok(data
+        .chunks_exact(4)
+        .map(|octets| Ipv4Addr::new(octets[0], octets[1], octets[2], octets[3]))
+        .collect())
+}
+
+fn seconds(code: u8, data: &[u8]) -> Result<u32, DhcpOptionError> {
+    validate_dhcp_option_length(code, data, 4)?;
+    Ok(u32::from_be_bytes([data[0], data[1], data[2], data[3]]))
+}
+
+fn parse_option(code: u8, data: &[u8]) -> Result<DhcpOption<'_>, DhcpOptionError> {
+    let invalid = || DhcpOptionError::InvalidOptionLength {
+        code,
+        length: data.len(),
+    };
+    Ok(match code {
+        DHCP_OPTION_SUBNET_MASK => DhcpOption::SubnetMask(ipv4(code, data)?),
+        DHCP_OPTION_ROUTER => DhcpOption::Router(ipv4_list(code, data)?),
+        DHCP_OPTION_DNS_SERVERS => DhcpOption::DnsServers(ipv4_list(code, data)?),
+        DHCP_OPTION_HOSTNAME | DHCP_OPTION_DOMAIN_NAME | DHCP_OPTION_VENDOR_CLASS_ID => {
+            if data.is_empty() {
+                return Err(invalid());
+            }
+            match code {
+                DHCP_OPTION_HOSTNAME => DhcpOption::Hostname(data),
+                DHCP_OPTION_DOMAIN_NAME => DhcpOption::DomainName(data),
+                _ => DhcpOption::VendorClassId(data),
+            }
+        }
+        DHCP_OPTION_REQUESTED_IP => DhcpOption::RequestedIp(ipv4(code, data)?),
+        DHCP_OPTION_LEASE_TIME => DhcpOption::LeaseTime(seconds(code, data)?),
+        DHCP_OPTION_OVERLOAD => {
+            validate_dhcp_option_length(code, data, 1)?;
+            DhcpOption::Overload(data[0])
+        }
+        DHCP_OPTION_MESSAGE_TYPE => {
+            validate_dhcp_option_length(code, data, 1)?;
+            DhcpOption::MessageType(data[0])
+        }
+        DHCP_OPTION_SERVER_ID => DhcpOption::ServerId(ipv4(code, data)?),
+        DHCP_OPTION_PARAMETER_REQUEST_LIST => DhcpOption::ParameterRequestList(data),
+        DHCP_OPTION_RENEWAL_TIME => DhcpOption::RenewalTime(seconds(code, data)?),
+        DHCP_OPTION_REBINDING_TIME => DhcpOption::RebindingTime(seconds(code, data)?),
+        DHCP_OPTION_CLIENT_ID => {
+            // RFC 2132 §9.14 : au moins le type et un octet d'identifiant.
+            let [hardware_type, identifier @ ..] = data else {
+                return Err(invalid());
+            };
+            if identifier.is_empty() {
+                return Err(invalid());
+            }
+            DhcpOption::ClientId {
+                hardware_type: *hardware_type,
+                identifier,
+            }
+        }
+        DHCP_OPTION_CLIENT_FQDN => {
+            let [flags, rcode1, rcode2, name @ ..] = data else {
+                return Err(invalid());
+            };
+            DhcpOption::ClientFqdn(DhcpClientFqdn {
+                flags: *flags,
+                rcode1: *rcode1,
+                rcode2: *rcode2,
+                name: fqdn_name(code, *flags, name)?,
+            })
+        }
+        DHCP_OPTION_RELAY_AGENT_INFO => {
+            let mut suboptions = Vec::new();
+            let mut rest = data;
+            while !rest.is_empty() {
+                let (code, data, next) = extract_dhcp_option(rest)?;
+                suboptions.push(DhcpRelayAgentSubOption { code, data });
+                rest = next;
+            }
+            DhcpOption::RelayAgentInfo(DhcpRelayAgentInfo { suboptions })
+        }
+        _ => DhcpOption::Other { code, data },
+    })
+}
+
+/// Nom de l'option 81 : labels prefixes par leur longueur si le drapeau E
+/// est leve (un nom partiel peut omettre le label vide final), ASCII
+/// sinon (forme historique, parfois terminee par un NUL).
+fn fqdn_name(code: u8, flags: u8, name: &[u8]) -> Result<String, DhcpOptionError> {
+    let invalid = || DhcpOptionError::InvalidDomainName { code };
+    if flags & DHCP_FQDN_FLAG_ENCODED == 0 {
+        let name = name.strip_suffix(&[0]).unwrap_or(name);
+        return std::str::from_utf8(name)
+            .map(str::to_owned)
+            .map_err(|_| invalid());
+    }
+    let mut labels = Vec::new();
+    let mut rest = name;
+    while let [length, tail @ ..] = rest {
+        let length = usize::from(*length);
+        if length == 0 {
+            break;
+        }
+        if length > 63 || tail.len() < length {
+            return Err(invalid());
+        }
+        labels.push(std::str::from_utf8(&tail[..length]).map_err(|_| invalid())?);
+        rest = &tail[length..];
+    }
+    Ok(labels.join("."))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::application::protocols::dhcp::tests_fixtures::{
+        DHCP_DISCOVER_PAYLOAD, DHCP_OFFER_PAYLOAD,
+    };
+
+    #[test]
+    fn discover_options_and_fingerprint() {
+        let packet = DhcpPacket::try_from(&DHCP_DISCOVER_PAYLOAD[..]).expect("discover");
+        let options = packet.dhcp_options().expect("options");
+        assert_eq!(options[0], DhcpOption::MessageType(1));
+        assert_eq!(
+            options[1],
+            DhcpOption::ClientId {
+                hardware_type: 1,
+                identifier: &[0x00, 0x0b, 0x82, 0x01, 0xfc, 0x42]
+            }
+        );
+        assert_eq!(options[2], DhcpOption::RequestedIp(Ipv4Addr::UNSPECIFIED));
+        assert_eq!(options.len(), 4);
+        assert_eq!(packet.message_type_name(), Some("DHCPDISCOVER"));
+        assert_eq!(packet.fingerprint().as_deref(), Some("1,3,6,42"));
+        assert_eq!(
+            packet.client_hardware_address(),
+            &[0x00, 0x0b, 0x82, 0x01, 0xfc, 0x42]
+        );
+    }
+
+    #[test]
+    fn offer_options() {
+        let packet = DhcpPacket::try_from(&DHCP_OFFER_PAYLOAD[..]).expect("offer");
+        let options = packet.dhcp_options().expect("options");
+        assert!(options.contains(&DhcpOption::SubnetMask(Ipv4Addr::new(255, 255, 255, 0))));
+        assert!(options.contains(&DhcpOption::LeaseTime(3600)));
+        assert!(options.contains(&DhcpOption::ServerId(Ipv4Addr::new(192, 168, 0, 1))));
+        assert_eq!(packet.message_type(), Some(2));
+        assert_eq!(packet.fingerprint(), None);
+    }
+
+    #[test]
+    fn fqdn_relay_agent_and_other() {
+        let area = hex::decode(concat!(
+            "51",
+            "0c",
+            "050000",
+            "04686f7374",
+            "026c6e",
+            "00", // FQDN encode
+            "52",
+            "0a",
+            "0103",
+            "657430",
+            "0203",
+            "aabbcc", // option 82
+            "0c",
+            "04",
+            "686f7374", // hostname
+            "2b",
+            "02",
+            "0102", // option 43
+            "ff",
+            "0000"
+        ))
+        .expect("invalid hex fixture");
+        let options = parse_dhcp_options(&area).expect("options");
+        assert_eq!(
+            options[0],
+            DhcpOption::ClientFqdn(DhcpClientFqdn {
+                flags: 0x05,
+                rcode1: 0,
+                rcode2: 0,
+                name: "host.ln".into()
+            })
+        );
+        let DhcpOption::RelayAgentInfo(relay) = &options[1] else {
+            panic!("option 82 attendue");
+        };
+        assert_eq!(relay.circuit_id(), Some(&b"et0"[..]));
+        assert_eq!(relay.remote_id(), Some(&[0xaa, 0xbb, 0xcc][..]));
+        assert_eq!(options[2], DhcpOption::Hostname(b"host"));
+        assert_eq!(
+            options[3],
+            DhcpOption::Other {
+                code: 43,
+                data: &[1, 2]
+            }
+        );
+        // Les octets apres End sont ignores.
+        assert_eq!(options.len(), 4);
+    }
+
+    #[test]
+    fn invalid_options() {
+        // Message Type sur deux octets, Router non multiple de 4, option
+        // tronquee.
+        assert_eq!(
+            parse_dhcp_options(&[53, 2, 1, 1]),
+            Err(DhcpOptionError::InvalidOptionLength {
+                code: 53,
+                length: 2
+            })
+        );
+        assert_eq!(
+            parse_dhcp_options(&[3, 3, 10, 0, 0]),
+            Err(DhcpOptionError::InvalidOptionLength { code: 3, length: 3 })
+        );
+        assert_eq!(
+            parse_dhcp_options(&[12, 8, b'a']),
+            Err(DhcpOptionError::TruncatedOption {
+                code: 12,
+                expected: 8,
+                actual: 1
+            })
+        );
+        assert_eq!(
+            parse_dhcp_options(&[81, 5, 0x04, 0, 0, 9, b'a']),
+            Err(DhcpOptionError::InvalidDomainName { code: 81 })
+        );
+    }
+
+    #[test]
+    fn overload_reads_file_then_sname() {
+        let mut payload = DHCP_DISCOVER_PAYLOAD.to_vec();
+        // Zone options : cookie, Message Type, Overload = 3, End.
+        payload.truncate(240);
+        payload.extend_from_slice(&[53, 1, 3, 52, 1, 3, 255]);
+        // file (108..236) porte le nom d'hote, sname (44..108) la classe.
+        payload[108..115].copy_from_slice(&[12, 4, b'p', b'l', b'c', b'1', 255]);
+        payload[44..50].copy_from_slice(&[60, 3, b'o', b't', b'1', 255]);
+        let packet = DhcpPacket::try_from(&payload[..]).expect("overloaded request");
+        let options = packet.dhcp_options().expect("options");
+        assert_eq!(
+            options,
+            vec![
+                DhcpOption::MessageType(3),
+                DhcpOption::Overload(3),
+                DhcpOption::Hostname(b"plc1"),
+                DhcpOption::VendorClassId(b"ot1"),
+            ]
+        );
+    }
+}
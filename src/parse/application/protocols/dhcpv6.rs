@@ -6,10 +6,198 @@
 //! Module for parsing DHCPv6 packets.
 
 use crate::{
-    checks::application::dhcpv6::{validate_dhcpv6_message_type, validate_dhcpv6_min_length},
-    errors::application::dhcpv6::Dhcpv6PacketParseError,
+    checks::application::dhcpv6::{
+        DHCPV6_IA_FIXED_LENGTH, DHCPV6_IA_TA_FIXED_LENGTH, DHCPV6_IAADDR_FIXED_LENGTH,
+        DHCPV6_IAPREFIX_FIXED_LENGTH, DHCPV6_RELAY_HEADER_LENGTH, MAX_DHCPV6_OPTION_DEPTH,
+        extract_dhcpv6_option, validate_dhcpv6_message_type, validate_dhcpv6_min_length,
+        validate_dhcpv6_option_length, validate_dhcpv6_option_min_length,
+    },
+    errors::application::dhcpv6::{Dhcpv6OptionError, Dhcpv6PacketParseError},
+    parse::application::protocols::dns::utils::name::parse_dns_name,
 };
 use std::fmt;
+use std::net::Ipv6Addr;
+
+/// Types des messages relais (RFC 8415 §7.3), dont l'en-tete differe de
+/// celui des messages client/serveur.
+const RELAY_FORW: u8 = 12;
+const RELAY_REPL: u8 = 13;
+
+/// Codes d'option interpretes (registre IANA « DHCPv6 Option Codes »).
+const OPTION_CLIENTID: u16 = 1;
+const OPTION_SERVERID: u16 = 2;
+const OPTION_IA_NA: u16 = 3;
+const OPTION_IA_TA: u16 = 4;
+const OPTION_IAADDR: u16 = 5;
+const OPTION_ORO: u16 = 6;
+const OPTION_PREFERENCE: u16 = 7;
+const OPTION_ELAPSED_TIME: u16 = 8;
+const OPTION_RELAY_MSG: u16 = 9;
+const OPTION_UNICAST: u16 = 12;
+const OPTION_STATUS_CODE: u16 = 13;
+const OPTION_RAPID_COMMIT: u16 = 14;
+const OPTION_VENDOR_CLASS: u16 = 16;
+const OPTION_VENDOR_OPTS: u16 = 17;
+const OPTION_INTERFACE_ID: u16 = 18;
+const OPTION_RECONF_ACCEPT: u16 = 20;
+const OPTION_DNS_SERVERS: u16 = 23;
+const OPTION_DOMAIN_LIST: u16 = 24;
+const OPTION_IA_PD: u16 = 25;
+const OPTION_IAPREFIX: u16 = 26;
+const OPTION_INFORMATION_REFRESH_TIME: u16 = 32;
+
+/// DHCP Unique Identifier (RFC 8415 §11), porte par Client et Server
+/// Identifier.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Dhcpv6Duid<'a> {
+    /// DUID-LLT (type 1) : adresse de liaison et date de creation, en
+    /// secondes depuis le 1er janvier 2000 UTC.
+    LinkLayerTime {
+        hardware_type: u16,
+        time: u32,
+        link_layer_address: &'a [u8],
+    },
+    /// DUID-EN (type 2) : numero d'entreprise IANA et identifiant libre.
+    Enterprise {
+        enterprise_number: u32,
+        identifier: &'a [u8],
+    },
+    /// DUID-LL (type 3) : adresse de liaison seule.
+    LinkLayer {
+        hardware_type: u16,
+        link_layer_address: &'a [u8],
+    },
+    /// DUID-UUID (type 4, RFC 6355).
+    Uuid([u8; 16]),
+    Other {
+        duid_type: u16,
+        data: &'a [u8],
+    },
+}
+
+/// Identity Association for Non-temporary Addresses (option 3) ou for
+/// Prefix Delegation (option 25) : meme en-tete, options encapsulees
+/// differentes (IAADDR ou IAPREFIX).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dhcpv6IdentityAssociation<'a> {
+    pub iaid: u32,
+    /// Delai avant renouvellement aupres du meme serveur, en secondes.
+    pub t1: u32,
+    /// Delai avant rebind aupres de n'importe quel serveur, en secondes.
+    pub t2: u32,
+    pub options: Vec<Dhcpv6Option<'a>>,
+}
+
+/// Identity Association for Temporary Addresses (option 4).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dhcpv6TemporaryAssociation<'a> {
+    pub iaid: u32,
+    pub options: Vec<Dhcpv6Option<'a>>,
+}
+
+/// Adresse attribuee dans une IA_NA ou IA_TA (option 5).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dhcpv6IaAddress<'a> {
+    pub address: Ipv6Addr,
+    /// Durees en secondes ; `u32::MAX` = infinie.
+    pub preferred_lifetime: u32,
+    pub valid_lifetime: u32,
+    pub options: Vec<Dhcpv6Option<'a>>,
+}
+
+/// Prefixe delegue dans une IA_PD (option 26).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dhcpv6IaPrefix<'a> {
+    /// Durees en secondes ; `u32::MAX` = infinie.
+    pub preferred_lifetime: u32,
+    pub valid_lifetime: u32,
+    pub prefix_length: u8,
+    pub prefix: Ipv6Addr,
+    pub options: Vec<Dhcpv6Option<'a>>,
+}
+
+/// Status Code (option 13) : 0 = succes, message UTF-8 facultatif.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dhcpv6StatusCode<'a> {
+    pub code: u16,
+    pub message: &'a [u8],
+}
+
+impl Dhcpv6StatusCode<'_> {
+    /// Message lisible, quand il est en UTF-8 valide.
+    pub fn message_text(&self) -> Option<&str> {
+        std::str::from_utf8(self.message).ok()
+    }
+}
+
+/// Sous-option d'un Vendor-specific Information (option 17).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dhcpv6VendorOption<'a> {
+    pub code: u16,
+    pub data: &'a [u8],
+}
+
+/// Option DHCPv6 (RFC 8415 §21), typee selon son code.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Dhcpv6Option<'a> {
+    ClientId(Dhcpv6Duid<'a>),
+    ServerId(Dhcpv6Duid<'a>),
+    IaNa(Dhcpv6IdentityAssociation<'a>),
+    IaTa(Dhcpv6TemporaryAssociation<'a>),
+    IaAddress(Dhcpv6IaAddress<'a>),
+    /// Codes d'option demandes par le client (option 6).
+    OptionRequest(Vec<u16>),
+    Preference(u8),
+    /// Duree de l'echange cote client, en centiemes de seconde (option 8).
+    ElapsedTime(u16),
+    /// Message encapsule par un relais (option 9), voir
+    /// [`Dhcpv6Packet::try_from`] pour le relire.
+    RelayMessage(&'a [u8]),
+    /// Adresse que le client peut joindre en unicast (option 12).
+    ServerUnicast(Ipv6Addr),
+    StatusCode(Dhcpv6StatusCode<'a>),
+    RapidCommit,
+    /// Classes fournisseur (option 16) : entreprise puis donnees opaques.
+    VendorClass {
+        enterprise_number: u32,
+        data: Vec<&'a [u8]>,
+    },
+    /// Informations fournisseur (option 17).
+    VendorOptions {
+        enterprise_number: u32,
+        options: Vec<Dhcpv6VendorOption<'a>>,
+    },
+    InterfaceId(&'a [u8]),
+    ReconfigureAccept,
+    DnsServers(Vec<Ipv6Addr>),
+    DomainList(Vec<String>),
+    IaPd(Dhcpv6IdentityAssociation<'a>),
+    IaPrefix(Dhcpv6IaPrefix<'a>),
+    /// Delai avant de redemander les informations sans etat, en secondes
+    /// (option 32).
+    InformationRefreshTime(u32),
+    /// Option non interpretee : code et donnees brutes.
+    Other {
+        code: u16,
+        data: &'a [u8],
+    },
+}
+
+/// En-tete d'un message relais (Relay-forward 12, Relay-reply 13,
+/// RFC 8415 §9).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dhcpv6Relay<'a> {
+    /// Nombre de relais deja traverses.
+    pub hop_count: u8,
+    /// Adresse identifiant le lien du client.
+    pub link_address: Ipv6Addr,
+    /// Adresse du client ou du relais precedent.
+    pub peer_address: Ipv6Addr,
+    /// Options brutes du relais (dont Relay Message), zero-copy.
+    pub options: &'a [u8],
+}
 
 /// DHCPv6 Packet
 ///
@@ -31,6 +219,315 @@ pub struct Dhcpv6Packet<'a> {
     pub options: &'a [u8],
 }
 
+impl<'a> Dhcpv6Packet<'a> {
+    /// Message relais (Relay-forward ou Relay-reply) ?
+    pub fn is_relay(&self) -> bool {
+        matches!(self.message_type, RELAY_FORW | RELAY_REPL)
+    }
+
+    /// En-tete relais, pour les types 12 et 13.
+    ///
+    /// Le parseur lit tout message comme type + transaction id + options :
+    /// pour un relais, ces trois octets sont le hop count suivi des deux
+    /// premiers octets de link-address, dont la suite ouvre `options`.
+    pub fn relay(&self) -> Result<Option<Dhcpv6Relay<'a>>, Dhcpv6OptionError> {
+        if !self.is_relay() {
+            return Ok(None);
+        }
+        // Octets deja consommes par le type et le transaction id.
+        const CONSUMED: usize = 4;
+        let addresses_len = DHCPV6_RELAY_HEADER_LENGTH - CONSUMED;
+        if self.options.len() < addresses_len {
+            return Err(Dhcpv6OptionError::RelayLength {
+                expected: DHCPV6_RELAY_HEADER_LENGTH,
+                actual: CONSUMED + self.options.len(),
+            });
+        }
+        let [_, hop_count, link_high, link_low] = self.transaction_id.to_be_bytes();
+        let mut link_address = [0_u8; 16];
+        link_address[0] = link_high;
+        link_address[1] = link_low;
+        link_address[2..].copy_from_slice(&self.options[..14]);
+        Ok(Some(Dhcpv6Relay {
+            hop_count,
+            link_address: Ipv6Addr::from(link_address),
+            peer_address: ipv6_at(self.options, 14),
+            options: &self.options[addresses_len..],
+        }))
+    }
+
+    /// Options typees du message, decodees a la demande. Pour un relais,
+    /// ce sont les options qui suivent link-address et peer-address.
+    pub fn dhcpv6_options(&self) -> Result<Vec<Dhcpv6Option<'a>>, Dhcpv6OptionError> {
+        match self.relay()? {
+            Some(relay) => parse_dhcpv6_options(relay.options),
+            None => parse_dhcpv6_options(self.options),
+        }
+    }
+}
+
+/// Decode une suite d'options DHCPv6. Une option tronquee ou de taille
+/// incoherente fait echouer toute la liste ; une option inconnue est rendue
+/// en [`Dhcpv6Option::Other`].
+pub fn parse_dhcpv6_options(options: &[u8]) -> Result<Vec<Dhcpv6Option<'_>>, Dhcpv6OptionError> {
+    parse_options_at_depth(options, 0)
+}
+
+fn parse_options_at_depth(
+    options: &[u8],
+    depth: usize,
+) -> Result<Vec<Dhcpv6Option<'_>>, Dhcpv6OptionError> {
+    if depth >= MAX_DHCPV6_OPTION_DEPTH {
+        return Err(Dhcpv6OptionError::OptionNestingTooDeep {
+            max: MAX_DHCPV6_OPTION_DEPTH,
+        });
+    }
+    let mut parsed = Vec::new();
+    let mut offset = 0;
+    while offset < options.len() {
+        let (code, data) = extract_dhcpv6_option(options, offset)?;
+        parsed.push(parse_option(code, data, depth)?);
+        offset += 4 + data.len();
+    }
+    Ok(parsed)
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes([
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ])
+}
+
+fn ipv6_at(bytes: &[u8], offset: usize) -> Ipv6Addr {
+    let mut octets = [0_u8; 16];
+    octets.copy_from_slice(&bytes[offset..offset + 16]);
+    Ipv6Addr::from(octets)
+}
+
+fn parse_option(
+    code: u16,
+    data: &[u8],
+    depth: usize,
+) -> Result<Dhcpv6Option<'_>, Dhcpv6OptionError> {
+    Ok(match code {
+        OPTION_CLIENTID => Dhcpv6Option::ClientId(parse_duid(code, data)?),
+        OPTION_SERVERID => Dhcpv6Option::ServerId(parse_duid(code, data)?),
+        OPTION_IA_NA | OPTION_IA_PD => {
+            validate_dhcpv6_option_min_length(code, data, DHCPV6_IA_FIXED_LENGTH)?;
+            let association = Dhcpv6IdentityAssociation {
+                iaid: u32_at(data, 0),
+                t1: u32_at(data, 4),
+                t2: u32_at(data, 8),
+                options: parse_options_at_depth(&data[DHCPV6_IA_FIXED_LENGTH..], depth + 1)?,
+            };
+            if code == OPTION_IA_NA {
+                Dhcpv6Option::IaNa(association)
+            } else {
+                Dhcpv6Option::IaPd(association)
+            }
+        }
+        OPTION_IA_TA => {
+            validate_dhcpv6_option_min_length(code, data, DHCPV6_IA_TA_FIXED_LENGTH)?;
+            Dhcpv6Option::IaTa(Dhcpv6TemporaryAssociation {
+                iaid: u32_at(data, 0),
+                options: parse_options_at_depth(&data[DHCPV6_IA_TA_FIXED_LENGTH..], depth + 1)?,
+            })
+        }
+        OPTION_IAADDR => {
+            validate_dhcpv6_option_min_length(code, data, DHCPV6_IAADDR_FIXED_LENGTH)?;
+            Dhcpv6Option::IaAddress(Dhcpv6IaAddress {
+                address: ipv6_at(data, 0),
+                preferred_lifetime: u32_at(data, 16),
+                valid_lifetime: u32_at(data, 20),
+                options: parse_options_at_depth(&data[DHCPV6_IAADDR_FIXED_LENGTH..], depth + 1)?,
+            })
+        }
+        OPTION_IAPREFIX => {
+            validate_dhcpv6_option_min_length(code, data, DHCPV6_IAPREFIX_FIXED_LENGTH)?;
+            let prefix_length = data[8];
+            if prefix_length > 128 {
+                return Err(Dhcpv6OptionError::InvalidOptionLength {
+                    code,
+                    length: data.len(),
+                });
+            }
+            Dhcpv6Option::IaPrefix(Dhcpv6IaPrefix {
+                preferred_lifetime: u32_at(data, 0),
+                valid_lifetime: u32_at(data, 4),
+                prefix_length,
+                prefix: ipv6_at(data, 9),
+                options: parse_options_at_depth(&data[DHCPV6_IAPREFIX_FIXED_LENGTH..], depth + 1)?,
+            })
+        }
+        OPTION_ORO => {
+            if !data.len().is_multiple_of(2) {
+                return Err(Dhcpv6OptionError::InvalidOptionLength {
+                    code,
+                    length: data.len(),
+                });
+            }
+            Dhcpv6Option::OptionRequest(
+                data.chunks_exact(2)
+                    .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+                    .collect(),
+            )
+        }
+        OPTION_PREFERENCE => {
+            validate_dhcpv6_option_length(code, data, 1)?;
+            Dhcpv6Option::Preference(data[0])
+        }
+        OPTION_ELAPSED_TIME => {
+            validate_dhcpv6_option_length(code, data, 2)?;
+            Dhcpv6Option::ElapsedTime(u16::from_be_bytes([data[0], data[1]]))
+        }
+        OPTION_RELAY_MSG => Dhcpv6Option::RelayMessage(data),
+        OPTION_UNICAST => {
+            validate_dhcpv6_option_length(code, data, 16)?;
+            Dhcpv6Option::ServerUnicast(ipv6_at(data, 0))
+        }
+        OPTION_STATUS_CODE => {
+            validate_dhcpv6_option_min_length(code, data, 2)?;
+            Dhcpv6Option::StatusCode(Dhcpv6StatusCode {
+                code: u16::from_be_bytes([data[0], data[1]]),
+                message: &data[2..],
+            })
+        }
+        OPTION_RAPID_COMMIT => {
+            validate_dhcpv6_option_length(code, data, 0)?;
+            Dhcpv6Option::RapidCommit
+        }
+        OPTION_VENDOR_CLASS => {
+            validate_dhcpv6_option_min_length(code, data, 4)?;
+            Dhcpv6Option::VendorClass {
+                enterprise_number: u32_at(data, 0),
+                data: parse_length_prefixed(code, &data[4..])?,
+            }
+        }
+        OPTION_VENDOR_OPTS => {
+            validate_dhcpv6_option_min_length(code, data, 4)?;
+            let mut options = Vec::new();
+            let mut offset = 4;
+            while offset < data.len() {
+                let (option_code, option_data) = extract_dhcpv6_option(data, offset)?;
+                options.push(Dhcpv6VendorOption {
+                    code: option_code,
+                    data: option_data,
+                });
+                offset += 4 + option_data.len();
+            }
+            Dhcpv6Option::VendorOptions {
+                enterprise_number: u32_at(data, 0),
+                options,
+            }
+        }
+        OPTION_INTERFACE_ID => Dhcpv6Option::InterfaceId(data),
+        OPTION_RECONF_ACCEPT => {
+            validate_dhcpv6_option_length(code, data, 0)?;
+            Dhcpv6Option::ReconfigureAccept
+        }
+        OPTION_DNS_SERVERS => {
+            if !data.len().is_multiple_of(16) {
+                return Err(Dhcpv6OptionError::InvalidOptionLength {
+                    code,
+                    length: data.len(),
+                });
+            }
+            Dhcpv6Option::DnsServers(
+                data.chunks_exact(16)
+                    .map(|chunk| ipv6_at(chunk, 0))
+                    .collect(),
+            )
+        }
+        OPTION_DOMAIN_LIST => Dhcpv6Option::DomainList(parse_domain_list(code, data)?),
+        OPTION_INFORMATION_REFRESH_TIME => {
+            validate_dhcpv6_option_length(code, data, 4)?;
+            Dhcpv6Option::InformationRefreshTime(u32_at(data, 0))
+        }
+        _ => Dhcpv6Option::Other { code, data },
+    })
+}
+
+fn parse_duid(code: u16, data: &[u8]) -> Result<Dhcpv6Duid<'_>, Dhcpv6OptionError> {
+    let invalid = || Dhcpv6OptionError::InvalidOptionLength {
+        code,
+        length: data.len(),
+    };
+    validate_dhcpv6_option_min_length(code, data, 2)?;
+    let duid_type = u16::from_be_bytes([data[0], data[1]]);
+    let body = &data[2..];
+    Ok(match duid_type {
+        1 => {
+            if body.len() < 6 {
+                return Err(invalid());
+            }
+            Dhcpv6Duid::LinkLayerTime {
+                hardware_type: u16::from_be_bytes([body[0], body[1]]),
+                time: u32_at(body, 2),
+                link_layer_address: &body[6..],
+            }
+        }
+        2 => {
+            if body.len() < 4 {
+                return Err(invalid());
+            }
+            Dhcpv6Duid::Enterprise {
+                enterprise_number: u32_at(body, 0),
+                identifier: &body[4..],
+            }
+        }
+        3 => {
+            if body.len() < 2 {
+                return Err(invalid());
+            }
+            Dhcpv6Duid::LinkLayer {
+                hardware_type: u16::from_be_bytes([body[0], body[1]]),
+                link_layer_address: &body[2..],
+            }
+        }
+        4 => Dhcpv6Duid::Uuid(body.try_into().map_err(|_| invalid())?),
+        _ => Dhcpv6Duid::Other {
+            duid_type,
+            data: body,
+        },
+    })
+}
+
+/// Suite de champs prefixes par leur longueur sur deux octets (Vendor
+/// Class, RFC 8415 §21.16).
+fn parse_length_prefixed(code: u16, mut data: &[u8]) -> Result<Vec<&[u8]>, Dhcpv6OptionError> {
+    let mut items = Vec::new();
+    while !data.is_empty() {
+        let invalid = Dhcpv6OptionError::InvalidOptionLength {
+            code,
+            length: data.len(),
+        };
+        let [high, low, rest @ ..] = data else {
+            return Err(invalid);
+        };
+        let length = usize::from(u16::from_be_bytes([*high, *low]));
+        let item = rest.get(..length).ok_or(invalid)?;
+        items.push(item);
+        data = &rest[length..];
+    }
+    Ok(items)
+}
+
+/// Liste de noms au format DNS non compresse (RFC 8415 §10).
+fn parse_domain_list(code: u16, names: &[u8]) -> Result<Vec<String>, Dhcpv6OptionError> {
+    let mut domains = Vec::new();
+    let mut offset = 0;
+    while offset < names.len() {
+        let (domain, next) = parse_dns_name(names, offset)
+            .map_err(|_| Dhcpv6OptionError::InvalidDomainName { code })?;
+        domains.push(domain);
+        offset = next;
+    }
+    Ok(domains)
+}
+
 impl<'a> fmt::Display for Dhcpv6Packet<'a> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
@@ -117,6 +614,172 @@ mod tests {
         }
     }
 
+    /// Trame 1 : Solicit du client
+    /// (pcaps_exemple/protocols/dhcp/dhcp6_outlease_acquisition.pcapng).
+    const SOLICIT: &str = concat!(
+        "019de03f0001000e000100011def69bd000c295e774400060008001700180027",
+        "001f0008000200000003000c295e774400000e1000001518"
+    );
+
+    /// Trame 2 : Advertise du serveur, une adresse et un serveur DNS
+    /// (meme capture).
+    const ADVERTISE: &str = concat!(
+        "029de03f0001000e000100011def69bd000c295e77440002000e000100011de4",
+        "b858000c291fa75500030028295e77440000070800000b400005001820010db8",
+        "00010002000000000000100200000e1000000e100017001020010db800000000",
+        "0000000000000035"
+    );
+
+    fn decode(hex_fixture: &str) -> Vec<u8> {
+        hex::decode(hex_fixture).expect("invalid test hex fixture")
+    }
+
+    #[test]
+    fn test_decode_solicit_options_from_capture() {
+        let raw = decode(SOLICIT);
+        let packet = Dhcpv6Packet::try_from(raw.as_slice()).expect("captured Solicit parses");
+        let options = packet.dhcpv6_options().expect("captured options decode");
+
+        assert_eq!(
+            options[0],
+            Dhcpv6Option::ClientId(Dhcpv6Duid::LinkLayerTime {
+                hardware_type: 1,
+                time: 0x1def_69bd,
+                link_layer_address: &[0x00, 0x0c, 0x29, 0x5e, 0x77, 0x44],
+            })
+        );
+        // DNS servers, domain list, FQDN, DNSSL : ce que le client demande.
+        assert_eq!(
+            options[1],
+            Dhcpv6Option::OptionRequest(vec![23, 24, 39, 31])
+        );
+        assert_eq!(options[2], Dhcpv6Option::ElapsedTime(0));
+        assert_eq!(
+            options[3],
+            Dhcpv6Option::IaNa(Dhcpv6IdentityAssociation {
+                iaid: 0x295e_7744,
+                t1: 3600,
+                t2: 5400,
+                options: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_advertise_address_and_dns_from_capture() {
+        let raw = decode(ADVERTISE);
+        let packet = Dhcpv6Packet::try_from(raw.as_slice()).expect("captured Advertise parses");
+        let options = packet.dhcpv6_options().expect("captured options decode");
+
+        assert!(matches!(
+            options[1],
+            Dhcpv6Option::ServerId(Dhcpv6Duid::LinkLayerTime {
+                time: 0x1de4_b858,
+                ..
+            })
+        ));
+        let Dhcpv6Option::IaNa(association) = &options[2] else {
+            panic!("expected an IA_NA, got {:?}", options[2]);
+        };
+        assert_eq!((association.t1, association.t2), (1800, 2880));
+        assert_eq!(
+            association.options,
+            vec![Dhcpv6Option::IaAddress(Dhcpv6IaAddress {
+                address: "2001:db8:1:2::1002".parse().unwrap(),
+                preferred_lifetime: 3600,
+                valid_lifetime: 3600,
+                options: vec![],
+            })]
+        );
+        assert_eq!(
+            options[3],
+            Dhcpv6Option::DnsServers(vec!["2001:db8::35".parse().unwrap()])
+        );
+    }
+
+    /// Synthetique : Relay-forward portant une Reply avec IA_PD, statut,
+    /// options fournisseur et liste de domaines.
+    #[test]
+    fn test_decode_relay_forward_with_prefix_delegation() {
+        let raw = decode(concat!(
+            "0c01",                             // RELAY-FORW, hop count 1
+            "20010db8000000000000000000000001", // link-address
+            "fe800000000000000000000000000002", // peer-address
+            "0012000465746830",                 // Interface-Id « eth0 »
+            "00090047",                         // Relay Message, 71 octets
+            "07abcdef",                         // Reply
+            "001900290000000100000e1000001518", // IA_PD, T1 3600, T2 5400
+            "001a001900001c2000001d4c38",       // IAPREFIX /56
+            "20010db8abcd00000000000000000000",
+            "000d00020000",                     // Status Code Success
+            "0011000c0000013700010004c0a80001", // Vendor opts, entreprise 311
+        ));
+        let packet = Dhcpv6Packet::try_from(raw.as_slice()).expect("relay parses");
+
+        let relay = packet.relay().expect("relay header").expect("is a relay");
+        assert_eq!(relay.hop_count, 1);
+        assert_eq!(
+            relay.link_address,
+            "2001:db8::1".parse::<Ipv6Addr>().unwrap()
+        );
+        assert_eq!(relay.peer_address, "fe80::2".parse::<Ipv6Addr>().unwrap());
+
+        let options = packet.dhcpv6_options().expect("relay options decode");
+        assert_eq!(options[0], Dhcpv6Option::InterfaceId(b"eth0"));
+        let Dhcpv6Option::RelayMessage(inner) = options[1] else {
+            panic!("expected a relay message, got {:?}", options[1]);
+        };
+        let reply = Dhcpv6Packet::try_from(inner).expect("relayed Reply parses");
+        assert_eq!(reply.message_type, 7);
+
+        let reply_options = reply.dhcpv6_options().expect("reply options decode");
+        let Dhcpv6Option::IaPd(delegation) = &reply_options[0] else {
+            panic!("expected an IA_PD, got {:?}", reply_options[0]);
+        };
+        let Dhcpv6Option::IaPrefix(prefix) = &delegation.options[0] else {
+            panic!("expected an IAPREFIX, got {:?}", delegation.options[0]);
+        };
+        assert_eq!(prefix.prefix_length, 56);
+        assert_eq!(
+            prefix.prefix,
+            "2001:db8:abcd::".parse::<Ipv6Addr>().unwrap()
+        );
+        let Dhcpv6Option::StatusCode(status) = &reply_options[1] else {
+            panic!("expected a status code, got {:?}", reply_options[1]);
+        };
+        assert_eq!((status.code, status.message_text()), (0, Some("")));
+        assert_eq!(
+            reply_options[2],
+            Dhcpv6Option::VendorOptions {
+                enterprise_number: 311,
+                options: vec![Dhcpv6VendorOption {
+                    code: 1,
+                    data: &[0xc0, 0xa8, 0x00, 0x01],
+                }],
+            }
+        );
+    }
+
+    /// Synthetique : IA_NA imbriquees jusqu'a depasser la borne de recursion.
+    #[test]
+    fn test_option_nesting_is_bounded() {
+        let mut option = Vec::new();
+        for _ in 0..MAX_DHCPV6_OPTION_DEPTH {
+            let mut outer = vec![0x00, 0x03];
+            outer.extend_from_slice(&((12 + option.len()) as u16).to_be_bytes());
+            outer.extend_from_slice(&[0; 12]);
+            outer.extend_from_slice(&option);
+            option = outer;
+        }
+
+        assert_eq!(
+            parse_dhcpv6_options(&option),
+            Err(Dhcpv6OptionError::OptionNestingTooDeep {
+                max: MAX_DHCPV6_OPTION_DEPTH
+            })
+        );
+    }
+
     #[test]
     fn test_parse_dhcpv6_relay_agent() {
         // Relay-forward message (Type 12)
@@ -0,0 +1,584 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! DNP3 application fragment: application control, function code, the
+//! internal indications of responses, then a list of object headers.
+//!
+//! Each object header names a group and variation and, through its
+//! qualifier, how the points are addressed: a start-stop range, a count,
+//! or an index prefix before each object. Requests such as READ only name
+//! the points; the size of the objects that follow otherwise comes from
+//! the group and variation.
+
+use crate::{
+    checks::application::dnp3::{
+        DNP3_APP_CON, DNP3_APP_FIN, DNP3_APP_FIR, DNP3_APP_SEQUENCE_MASK, DNP3_APP_UNS,
+        DNP3_FC_AUTHENTICATE_RESPONSE, DNP3_FC_RESPONSE, DNP3_QUALIFIER_PREFIX_MASK,
+        DNP3_QUALIFIER_PREFIX_SHIFT, DNP3_QUALIFIER_RANGE_MASK, dnp3_control_code_name,
+        dnp3_function_carries_values, dnp3_function_is_control, dnp3_function_name,
+        dnp3_group_name, dnp3_iin_names, dnp3_object_size, dnp3_packed_bits, dnp3_prefix_width,
+        extract_dnp3_bytes, extract_dnp3_uint,
+    },
+    errors::application::dnp3::Dnp3Error,
+    parse::application::protocols::bounded_capacity,
+};
+
+/// DNP3 application fragment
+///
+/// ```mermaid
+/// ---
+/// title: Dnp3Application
+/// ---
+/// packet-beta
+/// 0-0: "FIR"
+/// 1-1: "FIN"
+/// 2-2: "CON"
+/// 3-3: "UNS"
+/// 4-7: "SEQ"
+/// 8-15: "Function Code u8"
+/// 16-31: "IIN u16 (responses)"
+/// 32-39: "Group u8"
+/// 40-47: "Variation u8"
+/// 48-55: "Qualifier u8"
+/// 56-95: "Range and objects variable"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dnp3Application<'a> {
+    pub control: u8,
+    pub function_code: u8,
+    /// Internal indications, IIN1 in the low byte; responses only.
+    pub iin: Option<u16>,
+    pub objects: Vec<Dnp3ObjectHeader<'a>>,
+}
+
+impl<'a> Dnp3Application<'a> {
+    pub fn parse(fragment: &'a [u8]) -> Result<Self, Dnp3Error> {
+        let (header, mut rest) = extract_dnp3_bytes(fragment, 2, "application header")?;
+        let function_code = header[1];
+        let iin = if (DNP3_FC_RESPONSE..=DNP3_FC_AUTHENTICATE_RESPONSE).contains(&function_code) {
+            let (iin, next) = extract_dnp3_bytes(rest, 2, "internal indications")?;
+            rest = next;
+            Some(u16::from_le_bytes([iin[0], iin[1]]))
+        } else {
+            None
+        };
+
+        let carries_values = dnp3_function_carries_values(function_code);
+        let mut objects = Vec::new();
+        while !rest.is_empty() {
+            let (object, next) = Dnp3ObjectHeader::parse(rest, carries_values)?;
+            objects.push(object);
+            rest = next;
+        }
+
+        Ok(Dnp3Application {
+            control: header[0],
+            function_code,
+            iin,
+            objects,
+        })
+    }
+
+    pub fn is_first(&self) -> bool {
+        self.control & DNP3_APP_FIR != 0
+    }
+
+    pub fn is_final(&self) -> bool {
+        self.control & DNP3_APP_FIN != 0
+    }
+
+    /// CON bit: the master must send an application confirm.
+    pub fn needs_confirm(&self) -> bool {
+        self.control & DNP3_APP_CON != 0
+    }
+
+    pub fn is_unsolicited(&self) -> bool {
+        self.control & DNP3_APP_UNS != 0
+    }
+
+    pub fn sequence(&self) -> u8 {
+        self.control & DNP3_APP_SEQUENCE_MASK
+    }
+
+    pub fn function_name(&self) -> &'static str {
+        dnp3_function_name(self.function_code)
+    }
+
+    /// Commands, writes, freezes, restarts and application control.
+    pub fn is_control(&self) -> bool {
+        dnp3_function_is_control(self.function_code)
+    }
+
+    /// Names of the internal indication bits set in a response.
+    pub fn iin_names(&self) -> Vec<&'static str> {
+        self.iin.map(dnp3_iin_names).unwrap_or_default()
+    }
+}
+
+/// Points addressed by an object header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dnp3Range {
+    /// Qualifier range codes 0-2, and 3-5 (virtual addresses).
+    StartStop { start: u32, stop: u32 },
+    /// Range code 6: every point of the group, no objects follow.
+    All,
+    /// Range codes 7-9: `count` objects, indexed by prefix or from 0.
+    Count(u32),
+    /// Range code 0x0B: `count` objects, each prefixed with its size.
+    FreeFormat(u32),
+}
+
+impl Dnp3Range {
+    /// Number of objects that follow the header.
+    pub fn count(&self) -> u32 {
+        match *self {
+            Dnp3Range::StartStop { start, stop } => (stop - start).saturating_add(1),
+            Dnp3Range::All => 0,
+            Dnp3Range::Count(count) | Dnp3Range::FreeFormat(count) => count,
+        }
+    }
+}
+
+/// Object header and the raw objects it introduces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dnp3ObjectHeader<'a> {
+    pub group: u8,
+    pub variation: u8,
+    pub qualifier: u8,
+    pub range: Dnp3Range,
+    /// The function sends object values, not only point indexes.
+    pub carries_values: bool,
+    /// Prefixes and objects, packed bits included.
+    pub data: &'a [u8],
+}
+
+impl<'a> Dnp3ObjectHeader<'a> {
+    fn parse(bytes: &'a [u8], carries_values: bool) -> Result<(Self, &'a [u8]), Dnp3Error> {
+        let (header, rest) = extract_dnp3_bytes(bytes, 3, "object header")?;
+        let (group, variation, qualifier) = (header[0], header[1], header[2]);
+        let prefix_code = (qualifier >> DNP3_QUALIFIER_PREFIX_SHIFT) & DNP3_QUALIFIER_PREFIX_MASK;
+        let prefix_width =
+            dnp3_prefix_width(prefix_code).ok_or(Dnp3Error::UnknownQualifier(qualifier))?;
+
+        let (range, rest) = match qualifier & DNP3_QUALIFIER_RANGE_MASK {
+            code @ 0x00..=0x05 => {
+                let width = 1 << (code % 3);
+                let (start, rest) = extract_dnp3_uint(rest, width, "range start")?;
+                let (stop, rest) = extract_dnp3_uint(rest, width, "range stop")?;
+                if start > stop {
+                    return Err(Dnp3Error::InvalidRange { start, stop });
+                }
+                (Dnp3Range::StartStop { start, stop }, rest)
+            }
+            0x06 => (Dnp3Range::All, rest),
+            code @ 0x07..=0x09 => {
+                let (count, rest) = extract_dnp3_uint(rest, 1 << (code - 0x07), "range count")?;
+                (Dnp3Range::Count(count), rest)
+            }
+            0x0B => {
+                let (count, rest) = extract_dnp3_uint(rest, 1, "range count")?;
+                (Dnp3Range::FreeFormat(count), rest)
+            }
+            _ => return Err(Dnp3Error::UnknownQualifier(qualifier)),
+        };
+
+        let data_len = objects_len(
+            group,
+            variation,
+            prefix_code,
+            prefix_width,
+            range.count(),
+            carries_values,
+            rest,
+        )?;
+        let (data, rest) = extract_dnp3_bytes(rest, data_len, "objects")?;
+        Ok((
+            Dnp3ObjectHeader {
+                group,
+                variation,
+                qualifier,
+                range,
+                carries_values,
+                data,
+            },
+            rest,
+        ))
+    }
+
+    pub fn group_name(&self) -> &'static str {
+        dnp3_group_name(self.group)
+    }
+
+    /// Points of the header with their index and, when the function
+    /// carries values, their decoded object.
+    pub fn points(&self) -> Result<Vec<Dnp3Point<'a>>, Dnp3Error> {
+        let count = self.range.count();
+        let prefix_code =
+            (self.qualifier >> DNP3_QUALIFIER_PREFIX_SHIFT) & DNP3_QUALIFIER_PREFIX_MASK;
+        let prefix_width =
+            dnp3_prefix_width(prefix_code).ok_or(Dnp3Error::UnknownQualifier(self.qualifier))?;
+        let first_index = match self.range {
+            Dnp3Range::StartStop { start, .. } => start,
+            _ => 0,
+        };
+        let mut points = Vec::with_capacity(bounded_capacity(
+            count as usize,
+            self.data.len(),
+            (prefix_width + 1).max(1),
+        ));
+
+        if self.carries_values
+            && prefix_code == 0
+            && let Some(bits) = dnp3_packed_bits(self.group, self.variation)
+        {
+            for offset in 0..count {
+                let bit = offset as usize * bits;
+                let byte = self.data.get(bit / 8).ok_or(Dnp3Error::Truncated {
+                    field: "packed bits",
+                })?;
+                let value = (byte >> (bit % 8)) & ((1 << bits) - 1);
+                points.push(Dnp3Point {
+                    index: first_index + offset,
+                    value: Some(if bits == 1 {
+                        Dnp3Value::Bit(value != 0)
+                    } else {
+                        Dnp3Value::DoubleBit(value)
+                    }),
+                });
+            }
+            return Ok(points);
+        }
+
+        let mut rest = self.data;
+        for offset in 0..count {
+            let (prefix, next) = extract_dnp3_uint(rest, prefix_width, "object prefix")?;
+            rest = next;
+            let index = match prefix_code {
+                1..=3 => prefix,
+                _ => first_index + offset,
+            };
+            let value = if !self.carries_values {
+                None
+            } else {
+                // Prefixe de taille : objet de longueur libre, garde brut.
+                if (4..=6).contains(&prefix_code) {
+                    let (object, next) = extract_dnp3_bytes(rest, prefix as usize, "object")?;
+                    rest = next;
+                    Some(Dnp3Value::Raw(object))
+                } else {
+                    let size = dnp3_object_size(self.group, self.variation).ok_or(
+                        Dnp3Error::UnknownObjectSize {
+                            group: self.group,
+                            variation: self.variation,
+                        },
+                    )?;
+                    let (object, next) = extract_dnp3_bytes(rest, size, "object")?;
+                    rest = next;
+                    Some(decode_object(self.group, self.variation, object))
+                }
+            };
+            points.push(Dnp3Point { index, value });
+        }
+        Ok(points)
+    }
+}
+
+/// Taille des prefixes et objets qui suivent un en-tete, verifiee contre
+/// les octets restants.
+fn objects_len(
+    group: u8,
+    variation: u8,
+    prefix_code: u8,
+    prefix_width: usize,
+    count: u32,
+    carries_values: bool,
+    rest: &[u8],
+) -> Result<usize, Dnp3Error> {
+    let count = count as usize;
+    let unknown_size = Dnp3Error::UnknownObjectSize { group, variation };
+    if !carries_values {
+        return prefix_width
+            .checked_mul(count)
+            .ok_or(Dnp3Error::Truncated { field: "objects" });
+    }
+    if let Some(bits) = dnp3_packed_bits(group, variation) {
+        if prefix_code != 0 {
+            return Err(unknown_size);
+        }
+        return Ok((count * bits).div_ceil(8));
+    }
+    if (4..=6).contains(&prefix_code) {
+        // Prefixe de taille : chaque objet annonce sa longueur.
+        let mut total = 0;
+        let mut remaining = rest;
+        for _ in 0..count {
+            let (size, next) = extract_dnp3_uint(remaining, prefix_width, "object size")?;
+            let (_, next) = extract_dnp3_bytes(next, size as usize, "object")?;
+            total += prefix_width + size as usize;
+            remaining = next;
+        }
+        return Ok(total);
+    }
+    let size = dnp3_object_size(group, variation).ok_or(unknown_size)?;
+    (prefix_width + size)
+        .checked_mul(count)
+        .ok_or(Dnp3Error::Truncated { field: "objects" })
+}
+
+/// Point named by an object header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Dnp3Point<'a> {
+    pub index: u32,
+    /// `None` when the function names points without values (READ).
+    pub value: Option<Dnp3Value<'a>>,
+}
+
+/// Decoded DNP3 object. Flags are kept raw, ONLINE in bit 0.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Dnp3Value<'a> {
+    /// Packed binary point without flags.
+    Bit(bool),
+    /// Packed double-bit point: 1 is off, 2 is on.
+    DoubleBit(u8),
+    /// Binary input or output with flags; the state is bit 7.
+    Binary {
+        state: bool,
+        flags: u8,
+    },
+    Counter {
+        value: u32,
+        flags: Option<u8>,
+    },
+    Analog {
+        value: f64,
+        flags: Option<u8>,
+    },
+    /// Control Relay Output Block.
+    Crob {
+        control_code: u8,
+        count: u8,
+        on_time: u32,
+        off_time: u32,
+        status: u8,
+    },
+    AnalogOutput {
+        value: f64,
+        status: u8,
+    },
+    /// Milliseconds since the Unix epoch (48 bits).
+    Time(u64),
+    Raw(&'a [u8]),
+}
+
+impl Dnp3Value<'_> {
+    /// Operation of a CROB, e.g. `LATCH_ON`.
+    pub fn control_code_name(&self) -> Option<&'static str> {
+        match self {
+            Dnp3Value::Crob { control_code, .. } => Some(dnp3_control_code_name(*control_code)),
+            _ => None,
+        }
+    }
+}
+
+/// Decode un objet dont la taille vient de `dnp3_object_size`.
+fn decode_object(group: u8, variation: u8, object: &[u8]) -> Dnp3Value<'_> {
+    let u16_at = |offset: usize| u16::from_le_bytes([object[offset], object[offset + 1]]);
+    let u32_at = |offset: usize| {
+        u32::from_le_bytes([
+            object[offset],
+            object[offset + 1],
+            object[offset + 2],
+            object[offset + 3],
+        ])
+    };
+    let u48_at = |offset: usize| {
+        object[offset..offset + 6]
+            .iter()
+            .rev()
+            .fold(0u64, |value, byte| (value << 8) | u64::from(*byte))
+    };
+    // Valeur analogique : entier 32 ou 16 bits signe, ou flottant.
+    let analog = |offset: usize, kind: AnalogKind| -> f64 {
+        match kind {
+            AnalogKind::I32 => f64::from(u32_at(offset) as i32),
+            AnalogKind::I16 => f64::from(u16_at(offset) as i16),
+            AnalogKind::F32 => f64::from(f32::from_bits(u32_at(offset))),
+            AnalogKind::F64 => f64::from_bits(u64::from_le_bytes(
+                object[offset..offset + 8].try_into().unwrap_or_default(),
+            )),
+        }
+    };
+
+    match (group, variation) {
+        (1 | 10, 2) | (2 | 11, _) => Dnp3Value::Binary {
+            state: object[0] & 0x80 != 0,
+            flags: object[0],
+        },
+        (3, 2) | (4, _) => Dnp3Value::DoubleBit(object[0] >> 6),
+        (12, 1 | 2) => Dnp3Value::Crob {
+            control_code: object[0],
+            count: object[1],
+            on_time: u32_at(2),
+            off_time: u32_at(6),
+            status: object[10],
+        },
+        (20..=23, _) => {
+            let flagged = matches!(variation, 1 | 2) || (group != 20 && matches!(variation, 5 | 6));
+            let offset = usize::from(flagged);
+            let value = if matches!(variation, 1 | 5 | 9) {
+                u32_at(offset)
+            } else {
+                u32::from(u16_at(offset))
+            };
+            Dnp3Value::Counter {
+                value,
+                flags: flagged.then_some(object[0]),
+            }
+        }
+        (30, 3) => Dnp3Value::Analog {
+            value: analog(0, AnalogKind::I32),
+            flags: None,
+        },
+        (30, 4) => Dnp3Value::Analog {
+            value: analog(0, AnalogKind::I16),
+            flags: None,
+        },
+        (30 | 32 | 40 | 42, _) => {
+            let kind = match (group, variation) {
+                (40, 3) | (_, 5 | 7) => AnalogKind::F32,
+                (40, 4) | (_, 6 | 8) => AnalogKind::F64,
+                (_, 2 | 4) => AnalogKind::I16,
+                _ => AnalogKind::I32,
+            };
+            Dnp3Value::Analog {
+                value: analog(1, kind),
+                flags: Some(object[0]),
+            }
+        }
+        (41, _) => {
+            let (kind, status) = match variation {
+                1 => (AnalogKind::I32, 4),
+                2 => (AnalogKind::I16, 2),
+                3 => (AnalogKind::F32, 4),
+                _ => (AnalogKind::F64, 8),
+            };
+            Dnp3Value::AnalogOutput {
+                value: analog(0, kind),
+                status: object[status],
+            }
+        }
+        (50, 1..=4) | (51, 1 | 2) => Dnp3Value::Time(u48_at(0)),
+        _ => Dnp3Value::Raw(object),
+    }
+}
+
+#[derive(Clone, Copy)]
+enum AnalogKind {
+    I32,
+    I16,
+    F32,
+    F64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn application(hex_fixture: &str) -> Dnp3Application<'static> {
+        let bytes = hex::decode(hex_fixture).expect("invalid hex fixture");
+        Dnp3Application::parse(Box::leak(bytes.into_boxed_slice())).expect("valid fragment")
+    }
+
+    #[test]
+    fn decode_class_poll_and_indexed_read() {
+        // READ classes 1, 2, 3 puis 0, puis g30v1 index 3 et 7 (prefixe 1 octet).
+        let request = application(concat!(
+            "c101",
+            "3c0206",
+            "3c0306",
+            "3c0406",
+            "3c0106",
+            "1e0117020307"
+        ));
+        assert_eq!(request.function_name(), "READ");
+        assert_eq!(request.objects.len(), 5);
+        assert_eq!(request.objects[0].range, Dnp3Range::All);
+        assert_eq!(request.objects[0].group_name(), "Class Data");
+        let indexes: Vec<u32> = request.objects[4]
+            .points()
+            .expect("valid points")
+            .iter()
+            .map(|point| point.index)
+            .collect();
+        assert_eq!(indexes, vec![3, 7]);
+        assert!(!request.is_control());
+    }
+
+    #[test]
+    fn decode_response_points() {
+        // Reponse : IIN DEVICE_RESTART, g1v2 index 0-1, g30v5 index 4, g1v1
+        // packe index 0-9.
+        let response = application(concat!(
+            "c2818000",
+            "0102000001",
+            "0181",
+            "1e0517010401",
+            "00004841",
+            "0101000009e501"
+        ));
+        assert_eq!(response.iin_names(), vec!["DEVICE_RESTART"]);
+        let binaries = response.objects[0].points().expect("valid points");
+        assert_eq!(
+            binaries[1],
+            Dnp3Point {
+                index: 1,
+                value: Some(Dnp3Value::Binary {
+                    state: true,
+                    flags: 0x81
+                })
+            }
+        );
+        let analog = response.objects[1].points().expect("valid points");
+        assert_eq!(
+            analog[0].value,
+            Some(Dnp3Value::Analog {
+                value: 12.5,
+                flags: Some(0x01)
+            })
+        );
+        let packed = response.objects[2].points().expect("valid points");
+        assert_eq!(packed.len(), 10);
+        assert_eq!(packed[0].value, Some(Dnp3Value::Bit(true)));
+        assert_eq!(packed[9].value, Some(Dnp3Value::Bit(false)));
+    }
+
+    #[test]
+    fn decode_direct_operate() {
+        // DIRECT_OPERATE g12v1 index 5, LATCH_ON, 1 fois, 100 ms / 0 ms.
+        let request = application(concat!(
+            "c305", "0c0128", "0100", "0500", "0301", "64000000", "00000000", "00"
+        ));
+        assert!(request.is_control());
+        let points = request.objects[0].points().expect("valid points");
+        assert_eq!(points[0].index, 5);
+        let crob = points[0].value.as_ref().expect("CROB");
+        assert_eq!(crob.control_code_name(), Some("LATCH_ON"));
+    }
+
+    #[test]
+    fn reject_malformed_headers() {
+        let bytes = hex::decode("c0011e01000502").expect("invalid hex fixture");
+        assert_eq!(
+            Dnp3Application::parse(&bytes),
+            Err(Dnp3Error::InvalidRange { start: 5, stop: 2 })
+        );
+        let bytes = hex::decode("c00202ff0a").expect("invalid hex fixture");
+        assert_eq!(
+            Dnp3Application::parse(&bytes),
+            Err(Dnp3Error::UnknownQualifier(0x0A))
+        );
+    }
+}
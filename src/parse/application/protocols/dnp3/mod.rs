@@ -0,0 +1,188 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! DNP3 (IEEE 1815) over TCP or UDP 20000.
+//!
+//! Three layers are stacked: the link frame, whose header and 16-byte data
+//! blocks each end with a CRC; the one-byte transport header, which splits
+//! an application fragment across frames; and the application layer, a
+//! function code followed by object headers. [`Dnp3Frame`] strips and checks
+//! the CRCs, [`transport::Dnp3TransportReassembler`] rebuilds the fragments
+//! and [`application::Dnp3Application`] decodes them.
+
+use std::convert::TryFrom;
+
+use crate::{
+    checks::application::dnp3::{
+        DNP3_BLOCK_LEN, DNP3_CRC_LEN, DNP3_LINK_CONFIRMED_USER_DATA, DNP3_LINK_DIR,
+        DNP3_LINK_HEADER_LEN, DNP3_LINK_PRM, DNP3_LINK_UNCONFIRMED_USER_DATA,
+        DNP3_MIN_LENGTH_FIELD, dnp3_link_function_name, extract_dnp3_frame_len, validate_dnp3_crc,
+    },
+    errors::application::dnp3::Dnp3Error,
+};
+
+pub mod application;
+pub mod transport;
+
+use transport::Dnp3TransportSegment;
+
+/// DNP3 link frame
+///
+/// ```mermaid
+/// ---
+/// title: Dnp3Frame
+/// ---
+/// packet-beta
+/// 0-15: "Start 0x0564"
+/// 16-23: "Length u8"
+/// 24-31: "Control u8"
+/// 32-47: "Destination u16"
+/// 48-63: "Source u16"
+/// 64-79: "Header CRC"
+/// 80-207: "User Data block (16 octets)"
+/// 208-223: "Block CRC"
+/// 224-255: "... up to 16 blocks"
+/// ```
+///
+/// Addresses are little-endian. The user data is copied out of its blocks
+/// once their CRCs have been checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dnp3Frame {
+    /// Control, addresses and user data bytes, CRCs excluded.
+    pub length: u8,
+    pub control: u8,
+    pub destination: u16,
+    pub source: u16,
+    pub user_data: Vec<u8>,
+}
+
+impl TryFrom<&[u8]> for Dnp3Frame {
+    type Error = Dnp3Error;
+
+    fn try_from(payload: &[u8]) -> Result<Self, Dnp3Error> {
+        Self::from_stream(payload).map(|(frame, _)| frame)
+    }
+}
+
+impl Dnp3Frame {
+    /// Parses the first frame of a DNP3 stream and returns the bytes that
+    /// follow it, possibly more frames.
+    pub fn from_stream(stream: &[u8]) -> Result<(Self, &[u8]), Dnp3Error> {
+        let frame_len = extract_dnp3_frame_len(stream)?;
+        let (frame, rest) = stream.split_at(frame_len);
+        let blocks = &frame[DNP3_LINK_HEADER_LEN + DNP3_CRC_LEN..];
+
+        let mut user_data = Vec::with_capacity(usize::from(frame[2] - DNP3_MIN_LENGTH_FIELD));
+        for (index, block) in blocks.chunks(DNP3_BLOCK_LEN + DNP3_CRC_LEN).enumerate() {
+            let (data, crc) = block.split_at(block.len() - DNP3_CRC_LEN);
+            validate_dnp3_crc(data, crc, index + 1)?;
+            user_data.extend_from_slice(data);
+        }
+
+        Ok((
+            Dnp3Frame {
+                length: frame[2],
+                control: frame[3],
+                destination: u16::from_le_bytes([frame[4], frame[5]]),
+                source: u16::from_le_bytes([frame[6], frame[7]]),
+                user_data,
+            },
+            rest,
+        ))
+    }
+
+    /// DIR bit: the frame was sent by the master.
+    pub fn is_from_master(&self) -> bool {
+        self.control & DNP3_LINK_DIR != 0
+    }
+
+    /// PRM bit: the frame initiates a link transaction.
+    pub fn is_primary(&self) -> bool {
+        self.control & DNP3_LINK_PRM != 0
+    }
+
+    /// Link function code, read along with the PRM bit.
+    pub fn function(&self) -> u8 {
+        self.control & 0x0F
+    }
+
+    pub fn function_name(&self) -> &'static str {
+        dnp3_link_function_name(self.control)
+    }
+
+    /// Transport segment of a user data frame, `None` for link-only
+    /// services (reset, test, status, acknowledgements).
+    pub fn transport(&self) -> Option<Result<Dnp3TransportSegment<'_>, Dnp3Error>> {
+        if !self.is_primary()
+            || !matches!(
+                self.function(),
+                DNP3_LINK_CONFIRMED_USER_DATA | DNP3_LINK_UNCONFIRMED_USER_DATA
+            )
+        {
+            return None;
+        }
+        Some(Dnp3TransportSegment::parse(&self.user_data))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_link_status_request() {
+        // REQUEST_LINK_STATUS du maitre 1 vers l'outstation 10, suivie d'une
+        // seconde trame.
+        let mut bytes = hex::decode("056405c90a000100feda").expect("invalid hex fixture");
+        bytes.extend_from_slice(&bytes.clone());
+        let (frame, rest) = Dnp3Frame::from_stream(&bytes).expect("valid frame");
+        assert_eq!(rest.len(), 10);
+        assert_eq!((frame.destination, frame.source), (10, 1));
+        assert!(frame.is_from_master() && frame.is_primary());
+        assert_eq!(frame.function_name(), "REQUEST_LINK_STATUS");
+        assert!(frame.user_data.is_empty());
+        assert!(frame.transport().is_none());
+    }
+
+    #[test]
+    fn strip_block_crcs() {
+        // Reponse de 20 octets de donnees : un bloc plein et un bloc de 4.
+        let bytes = hex::decode(concat!(
+            "0564194401000a00ffce",
+            "c0c08100001e0100000101f401000001e5",
+            "20e80300009d29"
+        ))
+        .expect("invalid hex fixture");
+        let frame = Dnp3Frame::try_from(&bytes[..]).expect("valid frame");
+        assert_eq!(frame.user_data.len(), 20);
+        assert!(!frame.is_from_master());
+        let segment = frame
+            .transport()
+            .expect("user data")
+            .expect("valid segment");
+        assert!(segment.fir && segment.fin);
+        assert_eq!(segment.data.len(), 19);
+    }
+
+    #[test]
+    fn reject_corrupted_frames() {
+        assert_eq!(
+            Dnp3Frame::try_from(
+                &hex::decode("056405c90a000100fedb").expect("invalid hex fixture")[..]
+            ),
+            Err(Dnp3Error::CrcMismatch {
+                block: 0,
+                computed: 0xDAFE,
+                received: 0xDBFE
+            })
+        );
+        assert_eq!(
+            Dnp3Frame::try_from(
+                &hex::decode("056505c90a000100feda").expect("invalid hex fixture")[..]
+            ),
+            Err(Dnp3Error::InvalidStart(0x0565))
+        );
+    }
+}
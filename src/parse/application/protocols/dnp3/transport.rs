@@ -0,0 +1,204 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! DNP3 transport function: one header byte per link frame, marking the
+//! first (FIR) and last (FIN) segments of an application fragment and
+//! numbering them modulo 64.
+
+use std::collections::VecDeque;
+
+use crate::{
+    checks::application::dnp3::{
+        DNP3_MAX_FRAGMENT_LEN, DNP3_MAX_PENDING_FRAGMENTS, DNP3_TRANSPORT_FIN, DNP3_TRANSPORT_FIR,
+        DNP3_TRANSPORT_SEQUENCE_MASK, extract_dnp3_bytes,
+    },
+    errors::application::dnp3::Dnp3Error,
+};
+
+use super::{Dnp3Frame, application::Dnp3Application};
+
+/// Transport segment carried by a user data frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dnp3TransportSegment<'a> {
+    pub fin: bool,
+    pub fir: bool,
+    pub sequence: u8,
+    pub data: &'a [u8],
+}
+
+impl<'a> Dnp3TransportSegment<'a> {
+    pub fn parse(user_data: &'a [u8]) -> Result<Self, Dnp3Error> {
+        let (header, data) = extract_dnp3_bytes(user_data, 1, "transport header")?;
+        if data.is_empty() {
+            return Err(Dnp3Error::EmptySegment);
+        }
+        Ok(Dnp3TransportSegment {
+            fin: header[0] & DNP3_TRANSPORT_FIN != 0,
+            fir: header[0] & DNP3_TRANSPORT_FIR != 0,
+            sequence: header[0] & DNP3_TRANSPORT_SEQUENCE_MASK,
+            data,
+        })
+    }
+
+    /// Application fragment of a single-segment message (FIR and FIN),
+    /// the common case for requests and short responses.
+    pub fn application(&self) -> Option<Result<Dnp3Application<'a>, Dnp3Error>> {
+        (self.fir && self.fin).then(|| Dnp3Application::parse(self.data))
+    }
+}
+
+/// Application fragment rebuilt from its transport segments.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dnp3Fragment {
+    pub source: u16,
+    pub destination: u16,
+    pub data: Vec<u8>,
+}
+
+impl Dnp3Fragment {
+    pub fn application(&self) -> Result<Dnp3Application<'_>, Dnp3Error> {
+        Dnp3Application::parse(&self.data)
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PendingFragment {
+    source: u16,
+    destination: u16,
+    next_sequence: u8,
+    data: Vec<u8>,
+}
+
+/// Rebuilds application fragments per (source, destination) link.
+///
+/// Push every frame of a stream, in order, with [`push`](Self::push), then
+/// drain [`next_fragment`](Self::next_fragment). A FIR segment restarts the
+/// reassembly of its link; a segment out of sequence, or one making the
+/// fragment exceed [`DNP3_MAX_FRAGMENT_LEN`], drops it. Beyond
+/// [`DNP3_MAX_PENDING_FRAGMENTS`] links in progress, the oldest is dropped.
+#[derive(Debug, Clone, Default)]
+pub struct Dnp3TransportReassembler {
+    pending: VecDeque<PendingFragment>,
+    complete: VecDeque<Dnp3Fragment>,
+}
+
+impl Dnp3TransportReassembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the transport segment of `frame`; link-only frames are ignored.
+    pub fn push(&mut self, frame: &Dnp3Frame) -> Result<(), Dnp3Error> {
+        let Some(segment) = frame.transport() else {
+            return Ok(());
+        };
+        let segment = segment?;
+        let link = (frame.source, frame.destination);
+        let position = self
+            .pending
+            .iter()
+            .position(|pending| (pending.source, pending.destination) == link);
+
+        let mut pending = if segment.fir {
+            if let Some(index) = position {
+                self.pending.remove(index);
+            }
+            PendingFragment {
+                source: frame.source,
+                destination: frame.destination,
+                next_sequence: segment.sequence,
+                data: Vec::new(),
+            }
+        } else {
+            // Segment intermediaire sans debut connu : ignore.
+            let Some(pending) = position.and_then(|index| self.pending.remove(index)) else {
+                return Ok(());
+            };
+            pending
+        };
+
+        if segment.sequence != pending.next_sequence
+            || pending.data.len() + segment.data.len() > DNP3_MAX_FRAGMENT_LEN
+        {
+            return Ok(());
+        }
+        pending.data.extend_from_slice(segment.data);
+        pending.next_sequence = (segment.sequence + 1) & DNP3_TRANSPORT_SEQUENCE_MASK;
+
+        if segment.fin {
+            self.complete.push_back(Dnp3Fragment {
+                source: pending.source,
+                destination: pending.destination,
+                data: pending.data,
+            });
+        } else {
+            if self.pending.len() == DNP3_MAX_PENDING_FRAGMENTS {
+                self.pending.pop_front();
+            }
+            self.pending.push_back(pending);
+        }
+        Ok(())
+    }
+
+    /// Next complete fragment, in the order their FIN segments arrived.
+    pub fn next_fragment(&mut self) -> Option<Dnp3Fragment> {
+        self.complete.pop_front()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(source: u16, transport: u8, data: &[u8]) -> Dnp3Frame {
+        let mut user_data = vec![transport];
+        user_data.extend_from_slice(data);
+        Dnp3Frame {
+            length: 5 + user_data.len() as u8,
+            control: 0x44,
+            destination: 1,
+            source,
+            user_data,
+        }
+    }
+
+    #[test]
+    fn reassemble_interleaved_links() {
+        let mut reassembler = Dnp3TransportReassembler::new();
+        for frame in [
+            frame(10, 0x40 | 62, &[0xC0, 0x81]),
+            frame(11, 0xC0 | 5, &[0xC1, 0x81, 0x00, 0x00]),
+            frame(10, 63, &[0x00, 0x00]),
+            frame(10, 0x80, &[0x3C, 0x02]),
+        ] {
+            reassembler.push(&frame).expect("valid segment");
+        }
+        let first = reassembler
+            .next_fragment()
+            .expect("single-segment fragment");
+        assert_eq!((first.source, first.data.len()), (11, 4));
+        let second = reassembler.next_fragment().expect("three-segment fragment");
+        assert_eq!(second.source, 10);
+        assert_eq!(second.data, vec![0xC0, 0x81, 0x00, 0x00, 0x3C, 0x02]);
+        assert!(reassembler.next_fragment().is_none());
+    }
+
+    #[test]
+    fn drop_fragments_out_of_sequence() {
+        let mut reassembler = Dnp3TransportReassembler::new();
+        reassembler
+            .push(&frame(10, 0x40 | 1, &[0xC0, 0x81]))
+            .expect("valid segment");
+        // Segment 3 au lieu de 2 : le fragment est abandonne.
+        reassembler
+            .push(&frame(10, 0x80 | 3, &[0x00, 0x00]))
+            .expect("valid segment");
+        assert!(reassembler.next_fragment().is_none());
+        assert_eq!(
+            Dnp3TransportSegment::parse(&[0xC0]),
+            Err(Dnp3Error::EmptySegment)
+        );
+    }
+}
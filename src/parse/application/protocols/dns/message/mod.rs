@@ -0,0 +1,251 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Borrowed view of a DNS message's resource records.
+//!
+//! [`DnsPacket`](super::DnsPacket) validates the header and copies every
+//! record into owned strings and byte vectors, losing the message the RDATA
+//! compression pointers refer to. [`DnsMessage`] keeps the message and
+//! exposes each record as a [`DnsRecord`] whose [`DnsRecord::rdata`] decodes
+//! the typed [`RData`] on demand.
+
+use super::{
+    rdata::{RData, edns::EdnsHeader},
+    utils::{
+        dns_class::DnsClass,
+        dns_types::{DnsType, DnsTypes},
+        name::DnsName,
+    },
+};
+use crate::{
+    checks::application::dns::{DNS_MINIMUM_SIZE, check_dns_query_size, extract_dns_rdata_field},
+    errors::application::dns::DnsRDataError,
+    parse::application::protocols::bounded_capacity,
+};
+
+/// Taille minimale d'un resource record : nom racine, type, classe, TTL,
+/// longueur.
+const MIN_RECORD_LEN: usize = 11;
+
+/// Resource record borrowed from its message (RFC 1035 §4.1.3).
+///
+/// ```mermaid
+/// ---
+/// title: DnsRecord
+/// ---
+/// packet-beta
+/// 0-31: "Name variable"
+/// 32-47: "Type u16"
+/// 48-63: "Class u16"
+/// 64-95: "TTL u32"
+/// 96-111: "RDLENGTH u16"
+/// 112-143: "RDATA variable"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DnsRecord<'a> {
+    pub name: DnsName<'a>,
+    pub rtype: DnsType,
+    /// Raw CLASS field: the mDNS cache-flush bit is kept, and an OPT record
+    /// carries its UDP payload size here (see [`DnsRecord::edns_header`]).
+    pub rclass: DnsClass,
+    pub ttl: u32,
+    /// Raw RDATA bytes.
+    pub data: &'a [u8],
+    message: &'a [u8],
+    data_offset: usize,
+}
+
+impl<'a> DnsRecord<'a> {
+    /// Parses the record at `*offset` in `message` and moves the offset past
+    /// it.
+    pub fn parse(message: &'a [u8], offset: &mut usize) -> Result<Self, DnsRDataError> {
+        let (name, after_name) = DnsName::parse(message, *offset)?;
+        check_dns_query_size(message, after_name, 10)?;
+        let fixed = &message[after_name..after_name + 10];
+        let data_offset = after_name + 10;
+        let data_length = usize::from(u16::from_be_bytes([fixed[8], fixed[9]]));
+        check_dns_query_size(message, data_offset, data_length)?;
+
+        *offset = data_offset + data_length;
+        Ok(DnsRecord {
+            name,
+            rtype: DnsType(u16::from_be_bytes([fixed[0], fixed[1]])),
+            rclass: DnsClass::new(u16::from_be_bytes([fixed[2], fixed[3]])),
+            ttl: u32::from_be_bytes([fixed[4], fixed[5], fixed[6], fixed[7]]),
+            data: &message[data_offset..*offset],
+            message,
+            data_offset,
+        })
+    }
+
+    /// Decodes the RDATA according to the record type.
+    pub fn rdata(&self) -> Result<RData<'a>, DnsRDataError> {
+        RData::parse(self.message, self.rtype, self.data_offset, self.data.len())
+    }
+
+    /// EDNS(0) fields of an OPT pseudo-record; `None` for other types.
+    pub fn edns_header(&self) -> Option<EdnsHeader> {
+        (self.rtype == DnsTypes::OPT).then(|| EdnsHeader::new(self.rclass.0, self.ttl))
+    }
+}
+
+/// Resource records of a DNS message, borrowed from it.
+///
+/// Only the layout is checked: header flags are left to
+/// [`DnsPacket`](super::DnsPacket) and its mDNS/LLMNR variants, so this view
+/// applies to all three. Questions are skipped.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DnsMessage<'a> {
+    pub answers: Vec<DnsRecord<'a>>,
+    pub authorities: Vec<DnsRecord<'a>>,
+    pub additionals: Vec<DnsRecord<'a>>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for DnsMessage<'a> {
+    type Error = DnsRDataError;
+
+    fn try_from(message: &'a [u8]) -> Result<Self, Self::Error> {
+        let header = extract_dns_rdata_field(message, DNS_MINIMUM_SIZE, "header")?;
+        let count = |index: usize| u16::from_be_bytes([header[index], header[index + 1]]);
+
+        let mut offset = DNS_MINIMUM_SIZE;
+        for _ in 0..count(4) {
+            let (_, after_name) = DnsName::parse(message, offset)?;
+            // Type (2) + classe (2).
+            check_dns_query_size(message, after_name, 4)?;
+            offset = after_name + 4;
+        }
+
+        let mut section = |count: u16| -> Result<Vec<DnsRecord<'a>>, DnsRDataError> {
+            let remaining = message.len().saturating_sub(offset);
+            let mut records =
+                Vec::with_capacity(bounded_capacity(count.into(), remaining, MIN_RECORD_LEN));
+            for _ in 0..count {
+                records.push(DnsRecord::parse(message, &mut offset)?);
+            }
+            Ok(records)
+        };
+        Ok(DnsMessage {
+            answers: section(count(6))?,
+            authorities: section(count(8))?,
+            additionals: section(count(10))?,
+        })
+    }
+}
+
+impl<'a> DnsMessage<'a> {
+    /// Same as [`TryFrom::try_from`] for a message carried over TCP, behind
+    /// its two-byte length prefix (RFC 1035 §4.2.2).
+    pub fn try_from_tcp(bytes: &'a [u8]) -> Result<Self, DnsRDataError> {
+        let prefix = extract_dns_rdata_field(bytes, 2, "TCP length")?;
+        let declared = usize::from(u16::from_be_bytes([prefix[0], prefix[1]]));
+        Self::try_from(extract_dns_rdata_field(
+            &bytes[2..],
+            declared,
+            "TCP message",
+        )?)
+    }
+
+    /// All records, in wire order (answers, authorities, additionals).
+    pub fn records(&self) -> impl Iterator<Item = &DnsRecord<'a>> {
+        self.answers
+            .iter()
+            .chain(&self.authorities)
+            .chain(&self.additionals)
+    }
+
+    /// The OPT pseudo-record (RFC 6891 §6.1.1), found in the additional
+    /// section.
+    pub fn opt(&self) -> Option<&DnsRecord<'a>> {
+        self.additionals
+            .iter()
+            .find(|record| record.rtype == DnsTypes::OPT)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::application::protocols::dns::rdata::edns::EdnsOption;
+
+    /// Reponse de `test_dns_packet_parsing` (`us.pool.ntp.org`) : les NS de
+    /// l'autorite pointent dans la rdata vers des noms deja vus.
+    const NTP_POOL_RESPONSE: &str = "002b81800001000f0006000202757304706f6f6c036e7470036f72670000010001c00c0001000100000d87000443814409c00c0001000100000d870004452c393cc00c0001000100000d870004cfead1b5c00c0001000100000d870004d184b004c00c0001000100000d870004d81bb92ac00c0001000100000d87000418224f2ac00c0001000100000d870004187bcae6c00c0001000100000d8700043fa43ef9c00c0001000100000d8700044070bd0bc00c0001000100000d870004417de9cec00c0001000100000d8700044221ce05c00c0001000100000d8700044221d80bc00c0001000100000d870004425c44f6c00c0001000100000d870004426f2ec8c00c0001000100000d8700044273880404504f4f4c036e7470036f72670000020001000010d60012036e7331086d61696c776f7278036e657400c11100020001000010d6000f067573656e6574036e6574026e7a00c11100020001000010d60014067a626173656c08666f72747974776f02636800c11100020001000010d60018086176656e747572610a62686d732d67726f6570026e6c00c11100020001000010d600110e736c617274696261727466617374c18bc11100020001000010d6000f0161026e73076d61646475636bc136c12900010001000272a500044501c844c1470001000100000daf0004ca313b06";
+
+    #[test]
+    fn records_resolve_compressed_rdata_names() {
+        let bytes = hex::decode(NTP_POOL_RESPONSE).unwrap();
+        let message = DnsMessage::try_from(bytes.as_slice()).unwrap();
+        assert_eq!(message.answers.len(), 15);
+        assert_eq!(message.authorities.len(), 6);
+        assert_eq!(message.additionals.len(), 2);
+
+        assert_eq!(
+            message.answers[0].rdata().unwrap(),
+            RData::A("67.129.68.9".parse().unwrap())
+        );
+
+        // `slartibartfast.<pointeur>` : le nom se termine par un pointeur
+        // vers `bhms-groep.nl`, a l'interieur de la rdata precedente.
+        let names: Vec<String> = message
+            .authorities
+            .iter()
+            .map(|record| match record.rdata().unwrap() {
+                RData::Ns(name) => name.to_string(),
+                other => panic!("NS expected, got {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "ns1.mailworx.net",
+                "usenet.net.nz",
+                "zbasel.fortytwo.ch",
+                "aventura.bhms-groep.nl",
+                "slartibartfast.bhms-groep.nl",
+                "a.ns.madduck.net",
+            ]
+        );
+        assert_eq!(message.authorities[0].name.to_string(), "POOL.ntp.org");
+        assert_eq!(message.records().count(), 23);
+    }
+
+    #[test]
+    fn opt_record_exposes_header_and_options() {
+        // Synthetique : requete `example.com A` avec OPT (1232 octets, DO) et
+        // un cookie client.
+        let mut bytes = hex::decode("abcd01000001000000000001").unwrap();
+        bytes.extend_from_slice(b"\x07example\x03com\x00\x00\x01\x00\x01");
+        bytes.extend_from_slice(&hex::decode("00002904d000008000000c").unwrap());
+        bytes.extend_from_slice(&hex::decode("000a00080102030405060708").unwrap());
+
+        let message = DnsMessage::try_from(bytes.as_slice()).unwrap();
+        let opt = message.opt().expect("OPT record");
+        assert!(opt.name.is_root());
+        let header = opt.edns_header().unwrap();
+        assert_eq!(header.udp_payload_size, 1232);
+        assert!(header.dnssec_ok);
+        let RData::Opt(options) = opt.rdata().unwrap() else {
+            panic!("OPT expected");
+        };
+        assert!(matches!(
+            options[..],
+            [EdnsOption::Cookie { server: None, .. }]
+        ));
+    }
+
+    #[test]
+    fn truncated_sections_are_rejected() {
+        let bytes = hex::decode(NTP_POOL_RESPONSE).unwrap();
+        assert!(DnsMessage::try_from(&bytes[..bytes.len() - 1]).is_err());
+        assert!(matches!(
+            DnsMessage::try_from(&bytes[..11]),
+            Err(DnsRDataError::TruncatedField {
+                field: "header",
+                ..
+            })
+        ));
+    }
+}
@@ -8,6 +8,8 @@ mod dns_answers;
 mod dns_authoritative;
 mod dns_header;
 mod dns_queries;
+pub mod message;
+pub mod rdata;
 pub mod utils;
 
 use crate::{
@@ -0,0 +1,359 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! DNSSEC record data: DNSKEY, RRSIG, DS, NSEC type bitmaps (RFC 4034) and
+//! NSEC3 (RFC 5155).
+
+use super::{RDataReader, read_u16, read_u32};
+use crate::{
+    checks::application::dns::{
+        extract_dns_character_string, extract_dns_rdata_field, validate_type_bitmap_window,
+    },
+    errors::application::dns::DnsRDataError,
+    parse::application::protocols::dns::utils::{dns_types::DnsType, name::DnsName},
+};
+
+/// Public key of a zone (RFC 4034 §2.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dnskey<'a> {
+    pub flags: u16,
+    pub protocol: u8,
+    pub algorithm: u8,
+    pub public_key: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for Dnskey<'a> {
+    type Error = DnsRDataError;
+
+    fn try_from(rdata: &'a [u8]) -> Result<Self, Self::Error> {
+        let fixed = extract_dns_rdata_field(rdata, 4, "DNSKEY")?;
+        Ok(Dnskey {
+            flags: u16::from_be_bytes([fixed[0], fixed[1]]),
+            protocol: fixed[2],
+            algorithm: fixed[3],
+            public_key: &rdata[4..],
+        })
+    }
+}
+
+impl Dnskey<'_> {
+    /// Zone Key flag (bit 7): the key signs the zone's records.
+    pub fn is_zone_key(&self) -> bool {
+        self.flags & 0x0100 != 0
+    }
+
+    /// Secure Entry Point flag (bit 15), conventionally set on KSKs.
+    pub fn is_secure_entry_point(&self) -> bool {
+        self.flags & 0x0001 != 0
+    }
+
+    /// REVOKE flag (bit 8, RFC 5011 §7).
+    pub fn is_revoked(&self) -> bool {
+        self.flags & 0x0080 != 0
+    }
+
+    /// Key tag (RFC 4034 Appendix B), the value quoted by the DS and RRSIG
+    /// records that reference this key.
+    pub fn key_tag(&self) -> u16 {
+        // RSA/MD5 (algorithme 1) : les 16 bits de poids fort des 24 bits de
+        // poids faible du modulus (Annexe B.1).
+        if self.algorithm == 1 {
+            let key = self.public_key;
+            return match key.len() {
+                len if len >= 3 => u16::from_be_bytes([key[len - 3], key[len - 2]]),
+                _ => 0,
+            };
+        }
+
+        let header = [
+            (self.flags >> 8) as u8,
+            self.flags as u8,
+            self.protocol,
+            self.algorithm,
+        ];
+        let sum =
+            header
+                .iter()
+                .chain(self.public_key)
+                .enumerate()
+                .fold(0u32, |sum, (index, &byte)| {
+                    let weight = if index % 2 == 0 { 8 } else { 0 };
+                    sum.wrapping_add(u32::from(byte) << weight)
+                });
+        (sum.wrapping_add(sum >> 16) & 0xffff) as u16
+    }
+}
+
+/// Signature over an RRset (RFC 4034 §3.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rrsig<'a> {
+    pub type_covered: DnsType,
+    pub algorithm: u8,
+    pub labels: u8,
+    pub original_ttl: u32,
+    /// Seconds since the epoch, modulo 2^32 (RFC 4034 §3.1.5).
+    pub signature_expiration: u32,
+    pub signature_inception: u32,
+    pub key_tag: u16,
+    pub signer_name: DnsName<'a>,
+    pub signature: &'a [u8],
+}
+
+impl<'a> Rrsig<'a> {
+    pub(crate) fn parse(reader: &RDataReader<'a>) -> Result<Self, DnsRDataError> {
+        let rdata = reader.rdata();
+        let fixed = extract_dns_rdata_field(rdata, 18, "RRSIG")?;
+        let (signer_name, after) = reader.name(18)?;
+        Ok(Rrsig {
+            type_covered: DnsType(read_u16(fixed, 0, "RRSIG")?),
+            algorithm: fixed[2],
+            labels: fixed[3],
+            original_ttl: read_u32(fixed, 4, "RRSIG")?,
+            signature_expiration: read_u32(fixed, 8, "RRSIG")?,
+            signature_inception: read_u32(fixed, 12, "RRSIG")?,
+            key_tag: read_u16(fixed, 16, "RRSIG")?,
+            signer_name,
+            signature: &rdata[after..],
+        })
+    }
+}
+
+/// Delegation Signer (RFC 4034 §5.1): digest of a child zone's DNSKEY.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ds<'a> {
+    pub key_tag: u16,
+    pub algorithm: u8,
+    pub digest_type: u8,
+    pub digest: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for Ds<'a> {
+    type Error = DnsRDataError;
+
+    fn try_from(rdata: &'a [u8]) -> Result<Self, Self::Error> {
+        let fixed = extract_dns_rdata_field(rdata, 4, "DS")?;
+        Ok(Ds {
+            key_tag: u16::from_be_bytes([fixed[0], fixed[1]]),
+            algorithm: fixed[2],
+            digest_type: fixed[3],
+            digest: &rdata[4..],
+        })
+    }
+}
+
+/// Hashed authenticated denial of existence (RFC 5155 §3.2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Nsec3<'a> {
+    pub hash_algorithm: u8,
+    pub flags: u8,
+    pub iterations: u16,
+    pub salt: &'a [u8],
+    /// Raw hash, shown in base32hex in zone files.
+    pub next_hashed_owner_name: &'a [u8],
+    pub types: TypeBitmap<'a>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for Nsec3<'a> {
+    type Error = DnsRDataError;
+
+    fn try_from(rdata: &'a [u8]) -> Result<Self, Self::Error> {
+        let fixed = extract_dns_rdata_field(rdata, 4, "NSEC3")?;
+        let (salt, rest) = extract_dns_character_string(&rdata[4..], "NSEC3 salt")?;
+        let (next_hashed_owner_name, rest) =
+            extract_dns_character_string(rest, "NSEC3 next hashed owner name")?;
+        Ok(Nsec3 {
+            hash_algorithm: fixed[0],
+            flags: fixed[1],
+            iterations: u16::from_be_bytes([fixed[2], fixed[3]]),
+            salt,
+            next_hashed_owner_name,
+            types: TypeBitmap::try_from(rest)?,
+        })
+    }
+}
+
+impl Nsec3<'_> {
+    /// Opt-Out flag (RFC 5155 §3.1.2.1): unsigned delegations may be skipped.
+    pub fn is_opt_out(&self) -> bool {
+        self.flags & 0x01 != 0
+    }
+}
+
+/// Type bitmap of NSEC and NSEC3 records (RFC 4034 §4.1.2), validated at
+/// construction. An empty bitmap is legal (empty non-terminal).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TypeBitmap<'a>(&'a [u8]);
+
+impl<'a> TryFrom<&'a [u8]> for TypeBitmap<'a> {
+    type Error = DnsRDataError;
+
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        let mut previous = None;
+        let mut rest = bytes;
+        while !rest.is_empty() {
+            let header = extract_dns_rdata_field(rest, 2, "type bitmap window")?;
+            let (window, length) = (header[0], header[1] as usize);
+            validate_type_bitmap_window(previous, window, length)?;
+            extract_dns_rdata_field(&rest[2..], length, "type bitmap window")?;
+            previous = Some(window);
+            rest = &rest[2 + length..];
+        }
+        Ok(TypeBitmap(bytes))
+    }
+}
+
+impl<'a> TypeBitmap<'a> {
+    /// Raw bitmap bytes, windows included.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.0
+    }
+
+    /// Types present in the bitmap, in increasing order.
+    pub fn types(&self) -> impl Iterator<Item = DnsType> + 'a {
+        let mut windows = Vec::new();
+        let mut rest = self.0;
+        // Les bornes ont ete validees par `try_from`.
+        while let [window, length, tail @ ..] = rest {
+            let length = (*length as usize).min(tail.len());
+            windows.push((*window, &tail[..length]));
+            rest = &tail[length..];
+        }
+        windows.into_iter().flat_map(|(window, bits)| {
+            bits.iter().enumerate().flat_map(move |(index, &byte)| {
+                (0..8u16)
+                    .filter(move |bit| byte & (0x80 >> bit) != 0)
+                    .map(move |bit| DnsType(u16::from(window) << 8 | (index as u16) << 3 | bit))
+            })
+        })
+    }
+
+    /// `true` if `rtype` is present in the bitmap.
+    pub fn contains(&self, rtype: DnsType) -> bool {
+        self.types().any(|present| present == rtype)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::application::protocols::dns::{rdata::RData, utils::dns_types::DnsTypes};
+
+    /// DNSKEY de `dskey.example.com` (RFC 4034 §5.4), de key tag 60485.
+    const RFC4034_DNSKEY: &str = concat!(
+        "0100030501039e8a247418e318903b215a848acfd5f37f026bd4062db26c774c",
+        "690968d5d56df8bfda91e6f36d9a279888f41333357c5e6029990d10fdf56630",
+        "62a512763326980a615ddbf17a05ddfcce7e5fb3abcca05a31b0957452d4521e",
+        "83870789063115bf97f6c308ccf57cdc9ce7fe10f6ed1bd0cc0660038c50dcdb",
+        "0feb963c2f17",
+    );
+
+    #[test]
+    fn dnskey_key_tag_follows_rfc4034_appendix_b() {
+        let rdata = hex::decode(RFC4034_DNSKEY).unwrap();
+        let key = Dnskey::try_from(rdata.as_slice()).unwrap();
+        assert_eq!(key.flags, 256);
+        assert_eq!(key.algorithm, 5);
+        assert!(key.is_zone_key());
+        assert!(!key.is_secure_entry_point());
+        assert_eq!(key.key_tag(), 60485);
+    }
+
+    #[test]
+    fn type_bitmap_lists_types_across_windows() {
+        // RFC 4034 §4.3 : "A MX RRSIG NSEC TYPE1234".
+        let bitmap = hex::decode(concat!(
+            "0006400100000003",
+            "041b000000000000000000000000000000000000000000000000000020"
+        ))
+        .unwrap();
+        let bitmap = TypeBitmap::try_from(bitmap.as_slice()).unwrap();
+        let types: Vec<u16> = bitmap.types().map(|rtype| rtype.0).collect();
+        assert_eq!(types, vec![1, 15, 46, 47, 1234]);
+        assert!(bitmap.contains(DnsTypes::MX));
+        assert!(!bitmap.contains(DnsTypes::AAAA));
+    }
+
+    #[test]
+    fn type_bitmap_rejects_unordered_or_oversized_windows() {
+        for bytes in [&[1u8, 1, 0x40, 0, 1, 0x40][..], &[0, 0], &[0, 33]] {
+            assert!(matches!(
+                TypeBitmap::try_from(bytes),
+                Err(DnsRDataError::InvalidTypeBitmap { .. })
+            ));
+        }
+    }
+
+    #[test]
+    fn nsec_accepts_plain_and_compressed_names() {
+        let rdata = b"\x01a\x07example\x03com\x00\x00\x01\x40";
+        let RData::Nsec {
+            next_domain_name,
+            types,
+        } = RData::parse(rdata, DnsTypes::NSEC, 0, rdata.len()).unwrap()
+        else {
+            panic!("NSEC expected");
+        };
+        assert_eq!(next_domain_name.to_string(), "a.example.com");
+        assert!(!next_domain_name.is_compressed());
+        assert!(types.contains(DnsTypes::A));
+
+        // Synthetique, a la maniere de mDNS (RFC 6762 §18.14) : le nom
+        // suivant pointe vers example.com, interdit en DNS unicast.
+        let mut message = vec![0u8; 12];
+        message.extend_from_slice(b"\x07example\x03com\x00");
+        let start = message.len();
+        message.extend_from_slice(b"\x01a\xc0\x0c\x00\x01\x40");
+        let RData::Nsec {
+            next_domain_name: compressed,
+            ..
+        } = RData::parse(&message, DnsTypes::NSEC, start, 7).unwrap()
+        else {
+            panic!("NSEC expected");
+        };
+        assert_eq!(compressed, next_domain_name);
+        assert!(compressed.is_compressed());
+    }
+
+    #[test]
+    fn rrsig_ds_and_nsec3_decode() {
+        // RFC 4034 §3.3 (signature tronquee a 4 octets) : RRSIG A 5 3 86400
+        // 20030322173103 20030220173103 2642 example.com.
+        let mut rdata = hex::decode("0001050300015180").unwrap();
+        rdata.extend_from_slice(&1_048_354_263u32.to_be_bytes());
+        rdata.extend_from_slice(&1_045_762_263u32.to_be_bytes());
+        rdata.extend_from_slice(&2642u16.to_be_bytes());
+        rdata.extend_from_slice(b"\x07example\x03com\x00\xde\xad\xbe\xef");
+        let RData::Rrsig(rrsig) = RData::parse(&rdata, DnsTypes::RRSIG, 0, rdata.len()).unwrap()
+        else {
+            panic!("RRSIG expected");
+        };
+        assert_eq!(rrsig.type_covered, DnsTypes::A);
+        assert_eq!(rrsig.labels, 3);
+        assert_eq!(rrsig.original_ttl, 86400);
+        assert_eq!(rrsig.key_tag, 2642);
+        assert_eq!(rrsig.signer_name.to_string(), "example.com");
+        assert_eq!(rrsig.signature, &[0xde, 0xad, 0xbe, 0xef]);
+
+        // RFC 4034 §5.4 : DS 60485 5 1 2BB183AF5F22588179A53B0A98631FAD1A292118.
+        let rdata = hex::decode("ec4505012bb183af5f22588179a53b0a98631fad1a292118").unwrap();
+        let ds = Ds::try_from(rdata.as_slice()).unwrap();
+        assert_eq!(ds.key_tag, 60485);
+        assert_eq!(ds.digest_type, 1);
+        assert_eq!(ds.digest.len(), 20);
+
+        // Synthetique : NSEC3 SHA-1, opt-out, 12 iterations, sel aabbccdd,
+        // types A NS SOA.
+        let mut rdata = vec![0x01, 0x01, 0x00, 0x0c, 0x04, 0xaa, 0xbb, 0xcc, 0xdd, 0x14];
+        rdata.extend_from_slice(&[0x11; 20]);
+        rdata.extend_from_slice(&[0x00, 0x01, 0x62]);
+        let nsec3 = Nsec3::try_from(rdata.as_slice()).unwrap();
+        assert!(nsec3.is_opt_out());
+        assert_eq!(nsec3.iterations, 12);
+        assert_eq!(nsec3.salt, &[0xaa, 0xbb, 0xcc, 0xdd]);
+        assert_eq!(nsec3.next_hashed_owner_name.len(), 20);
+        let types: Vec<u16> = nsec3.types.types().map(|rtype| rtype.0).collect();
+        assert_eq!(types, vec![1, 2, 6]);
+    }
+}
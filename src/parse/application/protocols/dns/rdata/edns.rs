@@ -0,0 +1,242 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! EDNS(0) OPT pseudo-record (RFC 6891 §6.1).
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use super::read_u16;
+use crate::{
+    checks::application::dns::{
+        EDNS_CLIENT_COOKIE_LEN, EDNS_CLIENT_SUBNET_FAMILY_IPV4, EDNS_CLIENT_SUBNET_FAMILY_IPV6,
+        EDNS_OPTION_CLIENT_SUBNET, EDNS_OPTION_COOKIE, EDNS_OPTION_PADDING,
+        EDNS_SERVER_COOKIE_MAX_LEN, EDNS_SERVER_COOKIE_MIN_LEN, extract_dns_rdata_field,
+    },
+    errors::application::dns::DnsRDataError,
+};
+
+/// Fixed part of an OPT record, carried in its CLASS and TTL fields
+/// (RFC 6891 §6.1.3).
+///
+/// ```mermaid
+/// ---
+/// title: EDNS(0) TTL field
+/// ---
+/// packet-beta
+/// 0-7: "Extended RCODE"
+/// 8-15: "Version"
+/// 16: "DO"
+/// 17-31: "Z"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EdnsHeader {
+    /// Largest UDP payload the sender can reassemble (CLASS field).
+    pub udp_payload_size: u16,
+    /// Upper 8 bits of the 12-bit RCODE.
+    pub extended_rcode: u8,
+    pub version: u8,
+    /// DNSSEC OK bit (RFC 3225).
+    pub dnssec_ok: bool,
+    /// Remaining flag bits, zero unless a future RFC defines them.
+    pub z: u16,
+}
+
+impl EdnsHeader {
+    pub fn new(class: u16, ttl: u32) -> Self {
+        EdnsHeader {
+            udp_payload_size: class,
+            extended_rcode: (ttl >> 24) as u8,
+            version: (ttl >> 16) as u8,
+            dnssec_ok: ttl & 0x8000 != 0,
+            z: (ttl & 0x7fff) as u16,
+        }
+    }
+}
+
+/// Option of an OPT record.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdnsOption<'a> {
+    ClientSubnet(ClientSubnet<'a>),
+    /// DNS Cookies (RFC 7873 §4): the server cookie is absent from a
+    /// client's first query.
+    Cookie {
+        client: &'a [u8],
+        server: Option<&'a [u8]>,
+    },
+    /// Padding (RFC 7830), whose length hides the size of encrypted queries.
+    Padding(&'a [u8]),
+    Unknown {
+        code: u16,
+        data: &'a [u8],
+    },
+}
+
+/// Client Subnet option (RFC 7871 §6): the prefix of the end client's
+/// address that a resolver forwards to authoritative servers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientSubnet<'a> {
+    pub family: u16,
+    pub source_prefix_length: u8,
+    pub scope_prefix_length: u8,
+    /// Address truncated to `source_prefix_length` bits.
+    pub address: &'a [u8],
+}
+
+impl ClientSubnet<'_> {
+    /// The address padded back to its full width, for the IPv4 and IPv6
+    /// families.
+    pub fn ip(&self) -> Option<IpAddr> {
+        match self.family {
+            EDNS_CLIENT_SUBNET_FAMILY_IPV4 => {
+                let mut octets = [0u8; 4];
+                octets
+                    .get_mut(..self.address.len())?
+                    .copy_from_slice(self.address);
+                Some(IpAddr::V4(Ipv4Addr::from(octets)))
+            }
+            EDNS_CLIENT_SUBNET_FAMILY_IPV6 => {
+                let mut octets = [0u8; 16];
+                octets
+                    .get_mut(..self.address.len())?
+                    .copy_from_slice(self.address);
+                Some(IpAddr::V6(Ipv6Addr::from(octets)))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl EdnsOption<'_> {
+    /// Option code (IANA "DNS EDNS0 Option Codes").
+    pub fn code(&self) -> u16 {
+        match self {
+            EdnsOption::ClientSubnet(_) => EDNS_OPTION_CLIENT_SUBNET,
+            EdnsOption::Cookie { .. } => EDNS_OPTION_COOKIE,
+            EdnsOption::Padding(_) => EDNS_OPTION_PADDING,
+            EdnsOption::Unknown { code, .. } => *code,
+        }
+    }
+}
+
+pub(crate) fn parse_edns_options(mut rest: &[u8]) -> Result<Vec<EdnsOption<'_>>, DnsRDataError> {
+    let mut options = Vec::new();
+    while !rest.is_empty() {
+        let code = read_u16(rest, 0, "EDNS option code")?;
+        let length = read_u16(rest, 2, "EDNS option length")? as usize;
+        let data = extract_dns_rdata_field(&rest[4..], length, "EDNS option")?;
+        options.push(parse_edns_option(code, data)?);
+        rest = &rest[4 + length..];
+    }
+    Ok(options)
+}
+
+fn parse_edns_option(code: u16, data: &[u8]) -> Result<EdnsOption<'_>, DnsRDataError> {
+    let invalid = |reason| DnsRDataError::InvalidEdnsOption { code, reason };
+    let option = match code {
+        EDNS_OPTION_CLIENT_SUBNET => {
+            let fixed = extract_dns_rdata_field(data, 4, "EDNS client subnet")?;
+            let subnet = ClientSubnet {
+                family: u16::from_be_bytes([fixed[0], fixed[1]]),
+                source_prefix_length: fixed[2],
+                scope_prefix_length: fixed[3],
+                address: &data[4..],
+            };
+            let max_prefix = match subnet.family {
+                EDNS_CLIENT_SUBNET_FAMILY_IPV4 => 32,
+                EDNS_CLIENT_SUBNET_FAMILY_IPV6 => 128,
+                _ => u8::MAX,
+            };
+            if subnet.source_prefix_length > max_prefix || subnet.scope_prefix_length > max_prefix {
+                return Err(invalid("prefix longer than the address family"));
+            }
+            // L'adresse est tronquee au nombre d'octets du prefixe source
+            // (RFC 7871 §6) : tout octet en plus ou en moins est une erreur.
+            if subnet.address.len() != usize::from(subnet.source_prefix_length).div_ceil(8) {
+                return Err(invalid("address length does not match the source prefix"));
+            }
+            EdnsOption::ClientSubnet(subnet)
+        }
+        EDNS_OPTION_COOKIE => {
+            let server_len = data.len().wrapping_sub(EDNS_CLIENT_COOKIE_LEN);
+            let server = match data.len() {
+                EDNS_CLIENT_COOKIE_LEN => None,
+                _ if (EDNS_SERVER_COOKIE_MIN_LEN..=EDNS_SERVER_COOKIE_MAX_LEN)
+                    .contains(&server_len) =>
+                {
+                    Some(&data[EDNS_CLIENT_COOKIE_LEN..])
+                }
+                _ => return Err(invalid("cookie length outside 8 or 16..=40 bytes")),
+            };
+            EdnsOption::Cookie {
+                client: &data[..EDNS_CLIENT_COOKIE_LEN],
+                server,
+            }
+        }
+        EDNS_OPTION_PADDING => EdnsOption::Padding(data),
+        _ => EdnsOption::Unknown { code, data },
+    };
+    Ok(option)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ttl_field_splits_into_the_edns_header() {
+        let header = EdnsHeader::new(1232, 0x0000_8000);
+        assert_eq!(header.udp_payload_size, 1232);
+        assert_eq!(header.version, 0);
+        assert!(header.dnssec_ok);
+        assert_eq!(header.z, 0);
+    }
+
+    #[test]
+    fn client_subnet_cookie_and_padding_decode() {
+        // Synthetique : ECS 192.0.2.0/24, cookie client seul, 3 octets de
+        // padding, puis une option inconnue.
+        let rdata = hex::decode(concat!(
+            "0008000700011800c00002",
+            "000a00080102030405060708",
+            "000c0003000000",
+            "fde90001ff",
+        ))
+        .unwrap();
+        let options = parse_edns_options(&rdata).unwrap();
+        let EdnsOption::ClientSubnet(subnet) = options[0] else {
+            panic!("client subnet expected");
+        };
+        assert_eq!(subnet.source_prefix_length, 24);
+        assert_eq!(subnet.ip(), Some(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 0))));
+        assert_eq!(
+            options[1],
+            EdnsOption::Cookie {
+                client: &[1, 2, 3, 4, 5, 6, 7, 8],
+                server: None,
+            }
+        );
+        assert_eq!(options[2], EdnsOption::Padding(&[0, 0, 0]));
+        assert_eq!(options[3].code(), 0xfde9);
+    }
+
+    #[test]
+    fn malformed_options_are_rejected() {
+        // Prefixe /24 mais 4 octets d'adresse.
+        let subnet = hex::decode("000800080001180000c0000201").unwrap();
+        // Cookie serveur de 4 octets.
+        let cookie = hex::decode("000a000c010203040506070801020304").unwrap();
+        for rdata in [subnet, cookie] {
+            assert!(matches!(
+                parse_edns_options(&rdata),
+                Err(DnsRDataError::InvalidEdnsOption { .. })
+            ));
+        }
+        assert!(matches!(
+            parse_edns_options(&[0x00, 0x0c, 0x00, 0x04, 0x00]),
+            Err(DnsRDataError::TruncatedField { .. })
+        ));
+    }
+}
@@ -0,0 +1,413 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Typed resource record data (RFC 1035 §3.3 and the RFCs of each type).
+//!
+//! Everything borrows from the DNS message: names inside the RDATA are
+//! [`DnsName`] views whose compression pointers are resolved against the
+//! whole message, byte strings are sub-slices. Types without a dedicated
+//! decoder come back as [`RData::Unknown`] (RFC 3597).
+
+pub mod dnssec;
+pub mod edns;
+pub mod svcb;
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use self::{
+    dnssec::{Dnskey, Ds, Nsec3, Rrsig, TypeBitmap},
+    edns::{EdnsOption, parse_edns_options},
+    svcb::Svcb,
+};
+use super::utils::{
+    dns_types::{DnsType, DnsTypes},
+    name::DnsName,
+};
+use crate::{
+    checks::application::dns::{
+        DNS_A_RDATA_LEN, DNS_AAAA_RDATA_LEN, extract_dns_character_string, extract_dns_rdata_field,
+        validate_dns_rdata_no_trailing_data,
+    },
+    errors::application::dns::DnsRDataError,
+};
+
+/// Decoded RDATA of a resource record.
+///
+/// CDS and CDNSKEY (RFC 7344) share the DS and DNSKEY wire formats and are
+/// decoded as [`RData::Ds`] and [`RData::Dnskey`]; the record type tells
+/// them apart.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RData<'a> {
+    A(Ipv4Addr),
+    Aaaa(Ipv6Addr),
+    Cname(DnsName<'a>),
+    Ns(DnsName<'a>),
+    Ptr(DnsName<'a>),
+    Mx {
+        preference: u16,
+        exchange: DnsName<'a>,
+    },
+    /// One or more `<character-string>`s, kept as raw bytes.
+    Txt(Vec<&'a [u8]>),
+    Soa(Soa<'a>),
+    Srv {
+        priority: u16,
+        weight: u16,
+        port: u16,
+        target: DnsName<'a>,
+    },
+    /// Certification Authority Authorization (RFC 8659 §4.1).
+    Caa {
+        flags: u8,
+        tag: &'a [u8],
+        value: &'a [u8],
+    },
+    Naptr(Naptr<'a>),
+    Svcb(Svcb<'a>),
+    Https(Svcb<'a>),
+    /// EDNS(0) pseudo-record options (RFC 6891 §6.1.2).
+    Opt(Vec<EdnsOption<'a>>),
+    Ds(Ds<'a>),
+    Dnskey(Dnskey<'a>),
+    Rrsig(Rrsig<'a>),
+    Nsec {
+        next_domain_name: DnsName<'a>,
+        types: TypeBitmap<'a>,
+    },
+    Nsec3(Nsec3<'a>),
+    /// RDATA of a type without a dedicated decoder.
+    Unknown(&'a [u8]),
+}
+
+/// Start Of Authority (RFC 1035 §3.3.13).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Soa<'a> {
+    pub mname: DnsName<'a>,
+    pub rname: DnsName<'a>,
+    pub serial: u32,
+    pub refresh: u32,
+    pub retry: u32,
+    pub expire: u32,
+    pub minimum: u32,
+}
+
+/// Naming Authority Pointer (RFC 3403 §4.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Naptr<'a> {
+    pub order: u16,
+    pub preference: u16,
+    pub flags: &'a [u8],
+    pub services: &'a [u8],
+    pub regexp: &'a [u8],
+    pub replacement: DnsName<'a>,
+}
+
+impl<'a> RData<'a> {
+    /// Decodes the `length` bytes of RDATA found at `start` in `message`
+    /// (the whole DNS message, so that compression pointers resolve).
+    ///
+    /// Names may only point backwards, and never past the end of the RDATA.
+    /// Compression is accepted in every type: RFC 4034 and RFC 9460 forbid
+    /// it in RRSIG, NSEC and SVCB/HTTPS, but mDNS compresses them anyway
+    /// (RFC 6762 §18.14). [`DnsName::is_compressed`] tells them apart.
+    pub fn parse(
+        message: &'a [u8],
+        rtype: DnsType,
+        start: usize,
+        length: usize,
+    ) -> Result<Self, DnsRDataError> {
+        let end = start.saturating_add(length);
+        // Le message est tronque a la fin de la RDATA : un nom qui deborde
+        // echoue sur ses bornes, et ses pointeurs (strictement en arriere)
+        // restent valides.
+        let message = extract_dns_rdata_field(message, end, "RDATA")?;
+        let rdata = &message[start..];
+        let reader = RDataReader { message, start };
+
+        let decoded = match rtype {
+            DnsTypes::A => RData::A(Ipv4Addr::from(fixed_address::<DNS_A_RDATA_LEN>(
+                rdata, "A",
+            )?)),
+            DnsTypes::AAAA => RData::Aaaa(Ipv6Addr::from(fixed_address::<DNS_AAAA_RDATA_LEN>(
+                rdata, "AAAA",
+            )?)),
+            DnsTypes::CNAME => RData::Cname(reader.sole_name(0, "CNAME")?),
+            DnsTypes::NS => RData::Ns(reader.sole_name(0, "NS")?),
+            DnsTypes::PTR => RData::Ptr(reader.sole_name(0, "PTR")?),
+            DnsTypes::MX => {
+                let preference = read_u16(rdata, 0, "MX preference")?;
+                RData::Mx {
+                    preference,
+                    exchange: reader.sole_name(2, "MX exchange")?,
+                }
+            }
+            DnsTypes::TXT => RData::Txt(parse_character_strings(rdata, "TXT")?),
+            DnsTypes::SOA => RData::Soa(parse_soa(&reader)?),
+            DnsTypes::SRV => {
+                let fields = extract_dns_rdata_field(rdata, 6, "SRV")?;
+                RData::Srv {
+                    priority: u16::from_be_bytes([fields[0], fields[1]]),
+                    weight: u16::from_be_bytes([fields[2], fields[3]]),
+                    port: u16::from_be_bytes([fields[4], fields[5]]),
+                    target: reader.sole_name(6, "SRV target")?,
+                }
+            }
+            DnsTypes::CAA => {
+                let flags = extract_dns_rdata_field(rdata, 1, "CAA flags")?[0];
+                let (tag, value) = extract_dns_character_string(&rdata[1..], "CAA tag")?;
+                RData::Caa { flags, tag, value }
+            }
+            DnsTypes::NAPTR => RData::Naptr(parse_naptr(&reader)?),
+            DnsTypes::SVCB => RData::Svcb(Svcb::parse(&reader, "SVCB")?),
+            DnsTypes::HTTPS => RData::Https(Svcb::parse(&reader, "HTTPS")?),
+            DnsTypes::OPT => RData::Opt(parse_edns_options(rdata)?),
+            DnsTypes::DS | DnsTypes::CDS => RData::Ds(Ds::try_from(rdata)?),
+            DnsTypes::DNSKEY | DnsTypes::CDNSKEY => RData::Dnskey(Dnskey::try_from(rdata)?),
+            DnsTypes::RRSIG => RData::Rrsig(Rrsig::parse(&reader)?),
+            DnsTypes::NSEC => {
+                let (next_domain_name, after) = reader.name(0)?;
+                RData::Nsec {
+                    next_domain_name,
+                    types: TypeBitmap::try_from(&rdata[after..])?,
+                }
+            }
+            DnsTypes::NSEC3 => RData::Nsec3(Nsec3::try_from(rdata)?),
+            _ => RData::Unknown(rdata),
+        };
+        Ok(decoded)
+    }
+}
+
+/// Vue sur une RDATA qui garde le message pour resoudre les noms.
+pub(crate) struct RDataReader<'a> {
+    /// Message DNS tronque a la fin de la RDATA.
+    message: &'a [u8],
+    /// Offset du debut de la RDATA dans `message`.
+    start: usize,
+}
+
+impl<'a> RDataReader<'a> {
+    pub(crate) fn rdata(&self) -> &'a [u8] {
+        &self.message[self.start..]
+    }
+
+    /// Lit le nom a l'offset `at` de la RDATA ; retourne le nom et l'offset
+    /// qui le suit, relatif a la RDATA.
+    pub(crate) fn name(&self, at: usize) -> Result<(DnsName<'a>, usize), DnsRDataError> {
+        let (name, next) = DnsName::parse(self.message, self.start + at)?;
+        Ok((name, next - self.start))
+    }
+
+    /// Lit un nom qui doit terminer la RDATA.
+    fn sole_name(&self, at: usize, field: &'static str) -> Result<DnsName<'a>, DnsRDataError> {
+        let (name, next) = self.name(at)?;
+        validate_dns_rdata_no_trailing_data(&self.rdata()[next..], field)?;
+        Ok(name)
+    }
+}
+
+pub(crate) fn read_u16(bytes: &[u8], at: usize, field: &'static str) -> Result<u16, DnsRDataError> {
+    let rest = bytes.get(at..).unwrap_or_default();
+    let value = extract_dns_rdata_field(rest, 2, field)?;
+    Ok(u16::from_be_bytes([value[0], value[1]]))
+}
+
+pub(crate) fn read_u32(bytes: &[u8], at: usize, field: &'static str) -> Result<u32, DnsRDataError> {
+    let rest = bytes.get(at..).unwrap_or_default();
+    let value = extract_dns_rdata_field(rest, 4, field)?;
+    Ok(u32::from_be_bytes([value[0], value[1], value[2], value[3]]))
+}
+
+fn fixed_address<const N: usize>(
+    rdata: &[u8],
+    field: &'static str,
+) -> Result<[u8; N], DnsRDataError> {
+    let address = extract_dns_rdata_field(rdata, N, field)?;
+    validate_dns_rdata_no_trailing_data(&rdata[N..], field)?;
+    let mut octets = [0u8; N];
+    octets.copy_from_slice(address);
+    Ok(octets)
+}
+
+fn parse_character_strings<'a>(
+    mut rest: &'a [u8],
+    field: &'static str,
+) -> Result<Vec<&'a [u8]>, DnsRDataError> {
+    let mut strings = Vec::new();
+    while !rest.is_empty() {
+        let (string, after) = extract_dns_character_string(rest, field)?;
+        strings.push(string);
+        rest = after;
+    }
+    Ok(strings)
+}
+
+fn parse_soa<'a>(reader: &RDataReader<'a>) -> Result<Soa<'a>, DnsRDataError> {
+    let (mname, after_mname) = reader.name(0)?;
+    let (rname, after_rname) = reader.name(after_mname)?;
+    let counters = &reader.rdata()[after_rname..];
+    let field = |index: usize| read_u32(counters, index * 4, "SOA");
+    let soa = Soa {
+        mname,
+        rname,
+        serial: field(0)?,
+        refresh: field(1)?,
+        retry: field(2)?,
+        expire: field(3)?,
+        minimum: field(4)?,
+    };
+    validate_dns_rdata_no_trailing_data(&counters[20..], "SOA")?;
+    Ok(soa)
+}
+
+fn parse_naptr<'a>(reader: &RDataReader<'a>) -> Result<Naptr<'a>, DnsRDataError> {
+    let rdata = reader.rdata();
+    let order = read_u16(rdata, 0, "NAPTR order")?;
+    let preference = read_u16(rdata, 2, "NAPTR preference")?;
+    let (flags, rest) = extract_dns_character_string(&rdata[4..], "NAPTR flags")?;
+    let (services, rest) = extract_dns_character_string(rest, "NAPTR services")?;
+    let (regexp, rest) = extract_dns_character_string(rest, "NAPTR regexp")?;
+    let replacement = reader.sole_name(rdata.len() - rest.len(), "NAPTR replacement")?;
+    Ok(Naptr {
+        order,
+        preference,
+        flags,
+        services,
+        regexp,
+        replacement,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Message minimal : en-tete nul puis `example.com` a l'offset 12, pour
+    /// que les RDATA de test puissent y pointer (`c0 0c`).
+    fn message_with(rdata: &[u8]) -> (Vec<u8>, usize) {
+        let mut message = vec![0u8; 12];
+        message.extend_from_slice(b"\x07example\x03com\x00");
+        let start = message.len();
+        message.extend_from_slice(rdata);
+        (message, start)
+    }
+
+    fn decode(rtype: DnsType, rdata: &[u8]) -> Result<String, DnsRDataError> {
+        let (message, start) = message_with(rdata);
+        RData::parse(&message, rtype, start, rdata.len()).map(|rdata| format!("{rdata:?}"))
+    }
+
+    #[test]
+    fn address_records_require_their_exact_length() {
+        let (message, start) = message_with(&[192, 0, 2, 1]);
+        assert_eq!(
+            RData::parse(&message, DnsTypes::A, start, 4).unwrap(),
+            RData::A(Ipv4Addr::new(192, 0, 2, 1))
+        );
+        assert!(matches!(
+            RData::parse(&message, DnsTypes::A, start, 3),
+            Err(DnsRDataError::TruncatedField { field: "A", .. })
+        ));
+        assert!(matches!(
+            decode(DnsTypes::AAAA, &[0; 17]),
+            Err(DnsRDataError::TrailingData { field: "AAAA", .. })
+        ));
+    }
+
+    #[test]
+    fn compressed_names_resolve_against_the_whole_message() {
+        // Synthetique : MX 10 mail.<pointeur vers example.com>.
+        let (message, start) = message_with(b"\x00\x0a\x04mail\xc0\x0c");
+        let RData::Mx {
+            preference,
+            exchange,
+        } = RData::parse(&message, DnsTypes::MX, start, 9).unwrap()
+        else {
+            panic!("MX expected");
+        };
+        assert_eq!(preference, 10);
+        assert_eq!(exchange.to_string(), "mail.example.com");
+        assert!(exchange.is_compressed());
+    }
+
+    #[test]
+    fn a_name_cannot_run_past_its_rdata() {
+        // CNAME annonce 3 octets mais le label en demande 5.
+        let (message, start) = message_with(b"\x04mail\x00");
+        assert!(matches!(
+            RData::parse(&message, DnsTypes::CNAME, start, 3),
+            Err(DnsRDataError::Name(_))
+        ));
+    }
+
+    #[test]
+    fn soa_txt_srv_and_caa_decode() {
+        // Synthetique.
+        let mut soa = b"\x02ns\xc0\x0c\x0ahostmaster\xc0\x0c".to_vec();
+        for counter in [2_026_101_801u32, 7200, 3600, 1_209_600, 300] {
+            soa.extend_from_slice(&counter.to_be_bytes());
+        }
+        let (message, start) = message_with(&soa);
+        let RData::Soa(decoded) = RData::parse(&message, DnsTypes::SOA, start, soa.len()).unwrap()
+        else {
+            panic!("SOA expected");
+        };
+        assert_eq!(decoded.mname.to_string(), "ns.example.com");
+        assert_eq!(decoded.rname.to_string(), "hostmaster.example.com");
+        assert_eq!(decoded.serial, 2_026_101_801);
+        assert_eq!(decoded.minimum, 300);
+
+        let (message, start) = message_with(b"\x05hello\x00\x03a=b");
+        assert_eq!(
+            RData::parse(&message, DnsTypes::TXT, start, 11).unwrap(),
+            RData::Txt(vec![&b"hello"[..], b"", b"a=b"])
+        );
+
+        let (message, start) = message_with(b"\x00\x01\x00\x02\x01\xbb\xc0\x0c");
+        let RData::Srv { port, target, .. } =
+            RData::parse(&message, DnsTypes::SRV, start, 8).unwrap()
+        else {
+            panic!("SRV expected");
+        };
+        assert_eq!(port, 443);
+        assert_eq!(target.to_string(), "example.com");
+
+        let (message, start) = message_with(b"\x80\x05issueletsencrypt.org");
+        assert_eq!(
+            RData::parse(&message, DnsTypes::CAA, start, 22).unwrap(),
+            RData::Caa {
+                flags: 0x80,
+                tag: b"issue",
+                value: b"letsencrypt.org",
+            }
+        );
+    }
+
+    #[test]
+    fn naptr_decodes_its_strings_and_replacement() {
+        // Synthetique, RFC 3403 §6.2 : "U" "E2U+sip" regexp, racine.
+        let rdata = b"\x00\x64\x00\x0a\x01u\x07E2U+sip\x1b!^.*$!sip:info@example.com!\x00";
+        let (message, start) = message_with(rdata);
+        let RData::Naptr(naptr) =
+            RData::parse(&message, DnsTypes::NAPTR, start, rdata.len()).unwrap()
+        else {
+            panic!("NAPTR expected");
+        };
+        assert_eq!(naptr.order, 100);
+        assert_eq!(naptr.services, b"E2U+sip");
+        assert_eq!(naptr.regexp, b"!^.*$!sip:info@example.com!");
+        assert!(naptr.replacement.is_root());
+    }
+
+    #[test]
+    fn unknown_types_keep_their_raw_bytes() {
+        let (message, start) = message_with(&[1, 2, 3]);
+        assert_eq!(
+            RData::parse(&message, DnsType(0xff00), start, 3).unwrap(),
+            RData::Unknown(&[1, 2, 3])
+        );
+    }
+}
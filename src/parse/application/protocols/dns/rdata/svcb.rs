@@ -0,0 +1,232 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! SVCB and HTTPS service binding records (RFC 9460).
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use super::{RDataReader, read_u16};
+use crate::{
+    checks::application::dns::{
+        DNS_A_RDATA_LEN, DNS_AAAA_RDATA_LEN, SVC_PARAM_ALPN, SVC_PARAM_DOH_PATH, SVC_PARAM_ECH,
+        SVC_PARAM_IPV4_HINT, SVC_PARAM_IPV6_HINT, SVC_PARAM_MANDATORY, SVC_PARAM_NO_DEFAULT_ALPN,
+        SVC_PARAM_PORT, extract_dns_character_string, extract_dns_rdata_field,
+        validate_dns_rdata_no_trailing_data,
+    },
+    errors::application::dns::DnsRDataError,
+    parse::application::protocols::dns::utils::name::DnsName,
+};
+
+/// SVCB or HTTPS record data (RFC 9460 §2.2).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Svcb<'a> {
+    /// 0 for AliasMode, otherwise the ServiceMode priority.
+    pub priority: u16,
+    /// Uncompressed per RFC 9460 §2.2; the root name means "the owner".
+    pub target: DnsName<'a>,
+    /// Parameters, in the strictly increasing key order the RFC requires.
+    pub params: Vec<SvcParam<'a>>,
+}
+
+impl<'a> Svcb<'a> {
+    pub(crate) fn parse(
+        reader: &RDataReader<'a>,
+        field: &'static str,
+    ) -> Result<Self, DnsRDataError> {
+        let rdata = reader.rdata();
+        let priority = read_u16(rdata, 0, field)?;
+        let (target, after) = reader.name(2)?;
+
+        let mut params = Vec::new();
+        let mut previous: Option<u16> = None;
+        let mut rest = &rdata[after..];
+        while !rest.is_empty() {
+            let key = read_u16(rest, 0, "SvcParam key")?;
+            let length = read_u16(rest, 2, "SvcParam length")? as usize;
+            let value = extract_dns_rdata_field(&rest[4..], length, "SvcParam value")?;
+            if let Some(previous) = previous.filter(|&previous| key <= previous) {
+                return Err(DnsRDataError::UnorderedSvcParamKey { previous, key });
+            }
+            params.push(SvcParam::parse(key, value)?);
+            previous = Some(key);
+            rest = &rest[4 + length..];
+        }
+
+        Ok(Svcb {
+            priority,
+            target,
+            params,
+        })
+    }
+
+    /// AliasMode (priority 0): the record only redirects to `target`.
+    pub fn is_alias_mode(&self) -> bool {
+        self.priority == 0
+    }
+
+    /// ALPN identifiers advertised by the `alpn` parameter.
+    pub fn alpn(&self) -> Option<&[&'a [u8]]> {
+        self.params.iter().find_map(|param| match param {
+            SvcParam::Alpn(protocols) => Some(protocols.as_slice()),
+            _ => None,
+        })
+    }
+
+    /// Encrypted ClientHello configuration list (`ech` parameter).
+    pub fn ech_config(&self) -> Option<&'a [u8]> {
+        self.params.iter().find_map(|param| match param {
+            SvcParam::Ech(config) => Some(*config),
+            _ => None,
+        })
+    }
+}
+
+/// Service parameter of an SVCB/HTTPS record (RFC 9460 §7, RFC 9461 §5).
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SvcParam<'a> {
+    Mandatory(Vec<u16>),
+    Alpn(Vec<&'a [u8]>),
+    NoDefaultAlpn,
+    Port(u16),
+    Ipv4Hint(Vec<Ipv4Addr>),
+    Ech(&'a [u8]),
+    Ipv6Hint(Vec<Ipv6Addr>),
+    /// URI template of a DoH endpoint, UTF-8 per RFC 9461.
+    DohPath(&'a [u8]),
+    Unknown {
+        key: u16,
+        value: &'a [u8],
+    },
+}
+
+impl<'a> SvcParam<'a> {
+    fn parse(key: u16, value: &'a [u8]) -> Result<Self, DnsRDataError> {
+        let param = match key {
+            SVC_PARAM_MANDATORY => SvcParam::Mandatory(
+                chunks::<2>(value, "SvcParam mandatory")?
+                    .map(u16::from_be_bytes)
+                    .collect(),
+            ),
+            SVC_PARAM_ALPN => {
+                let mut protocols = Vec::new();
+                let mut rest = value;
+                while !rest.is_empty() {
+                    let (protocol, after) = extract_dns_character_string(rest, "SvcParam alpn")?;
+                    protocols.push(protocol);
+                    rest = after;
+                }
+                SvcParam::Alpn(protocols)
+            }
+            SVC_PARAM_NO_DEFAULT_ALPN => {
+                validate_dns_rdata_no_trailing_data(value, "SvcParam no-default-alpn")?;
+                SvcParam::NoDefaultAlpn
+            }
+            SVC_PARAM_PORT => {
+                let port = read_u16(value, 0, "SvcParam port")?;
+                validate_dns_rdata_no_trailing_data(&value[2..], "SvcParam port")?;
+                SvcParam::Port(port)
+            }
+            SVC_PARAM_IPV4_HINT => SvcParam::Ipv4Hint(
+                chunks::<DNS_A_RDATA_LEN>(value, "SvcParam ipv4hint")?
+                    .map(Ipv4Addr::from)
+                    .collect(),
+            ),
+            SVC_PARAM_ECH => SvcParam::Ech(value),
+            SVC_PARAM_IPV6_HINT => SvcParam::Ipv6Hint(
+                chunks::<DNS_AAAA_RDATA_LEN>(value, "SvcParam ipv6hint")?
+                    .map(Ipv6Addr::from)
+                    .collect(),
+            ),
+            SVC_PARAM_DOH_PATH => SvcParam::DohPath(value),
+            _ => SvcParam::Unknown { key, value },
+        };
+        Ok(param)
+    }
+
+    /// SvcParamKey (IANA "Service Parameter Keys").
+    pub fn key(&self) -> u16 {
+        match self {
+            SvcParam::Mandatory(_) => SVC_PARAM_MANDATORY,
+            SvcParam::Alpn(_) => SVC_PARAM_ALPN,
+            SvcParam::NoDefaultAlpn => SVC_PARAM_NO_DEFAULT_ALPN,
+            SvcParam::Port(_) => SVC_PARAM_PORT,
+            SvcParam::Ipv4Hint(_) => SVC_PARAM_IPV4_HINT,
+            SvcParam::Ech(_) => SVC_PARAM_ECH,
+            SvcParam::Ipv6Hint(_) => SVC_PARAM_IPV6_HINT,
+            SvcParam::DohPath(_) => SVC_PARAM_DOH_PATH,
+            SvcParam::Unknown { key, .. } => *key,
+        }
+    }
+}
+
+// Liste non vide d'elements de taille fixe, sans reliquat.
+fn chunks<'a, const N: usize>(
+    value: &'a [u8],
+    field: &'static str,
+) -> Result<impl Iterator<Item = [u8; N]> + 'a, DnsRDataError> {
+    extract_dns_rdata_field(value, N, field)?;
+    let items = value.chunks_exact(N);
+    validate_dns_rdata_no_trailing_data(items.remainder(), field)?;
+    Ok(items.map(|item| {
+        let mut array = [0u8; N];
+        array.copy_from_slice(item);
+        array
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::application::protocols::dns::{rdata::RData, utils::dns_types::DnsTypes};
+
+    #[test]
+    fn https_service_mode_decodes_its_params() {
+        // Synthetique, calque sur RFC 9460 Annexe D.2 (figure 6) :
+        // `1 . alpn=h2,h3 port=8443 ipv4hint=192.0.2.1 ipv6hint=2001:db8::1`.
+        let rdata = hex::decode(concat!(
+            "000100",
+            "00010006026832026833",
+            "0003000220fb",
+            "00040004c0000201",
+            "0006001020010db8000000000000000000000001",
+        ))
+        .unwrap();
+        let RData::Https(https) = RData::parse(&rdata, DnsTypes::HTTPS, 0, rdata.len()).unwrap()
+        else {
+            panic!("HTTPS expected");
+        };
+        assert!(!https.is_alias_mode());
+        assert!(https.target.is_root());
+        assert_eq!(https.alpn(), Some(&[&b"h2"[..], b"h3"][..]));
+        assert_eq!(https.params[1], SvcParam::Port(8443));
+        assert_eq!(
+            https.params[2],
+            SvcParam::Ipv4Hint(vec![Ipv4Addr::new(192, 0, 2, 1)])
+        );
+        assert_eq!(https.params[3].key(), SVC_PARAM_IPV6_HINT);
+        assert_eq!(https.ech_config(), None);
+    }
+
+    #[test]
+    fn svcb_rejects_unordered_keys_and_bad_lengths() {
+        // port (3) avant alpn (1).
+        let unordered = hex::decode("0001000003000201bb00010003026832").unwrap();
+        assert!(matches!(
+            RData::parse(&unordered, DnsTypes::SVCB, 0, unordered.len()),
+            Err(DnsRDataError::UnorderedSvcParamKey {
+                previous: 3,
+                key: 1
+            })
+        ));
+
+        // ipv4hint de 5 octets.
+        let hint = hex::decode("00010000040005c000020101").unwrap();
+        assert!(matches!(
+            RData::parse(&hint, DnsTypes::SVCB, 0, hint.len()),
+            Err(DnsRDataError::TrailingData { .. })
+        ));
+    }
+}
@@ -6,6 +6,8 @@
 //! Parsing des noms DNS (RFC 1035 §3.1) avec support de la compression
 //! (§4.1.4), et parsing générique d'un resource record.
 
+use std::fmt;
+
 use crate::{
     checks::application::dns::{
         check_dns_label_bounds, check_dns_name_offset, check_dns_query_size,
@@ -31,8 +33,31 @@ const MAX_POINTER_JUMPS: usize = 8;
 pub fn parse_dns_name(message: &[u8], start: usize) -> Result<(String, usize), DnsQueryParseError> {
     // Allocation justifiée (METHODE_AJOUT_PROTOCOLE.md) : un nom DNS est
     // découpé en labels potentiellement non contigus (compression) — il doit
-    // être reconstruit, pas emprunté.
+    // être reconstruit, pas emprunté. [`DnsName`] en est la variante
+    // empruntée.
     let mut labels: Vec<String> = Vec::new();
+    let walk = walk_dns_name(message, start, |label| {
+        labels.push(String::from_utf8(label.to_vec())?);
+        Ok(())
+    })?;
+
+    Ok((labels.join("."), walk.resume_offset))
+}
+
+/// Résultat du parcours d'un nom : où reprendre la lecture du flux, et si
+/// le nom a suivi au moins un pointeur de compression.
+struct NameWalk {
+    resume_offset: usize,
+    compressed: bool,
+}
+
+/// Parcourt et valide le nom à `start`, en passant chaque label à
+/// `on_label` dans l'ordre de lecture.
+fn walk_dns_name(
+    message: &[u8],
+    start: usize,
+    mut on_label: impl FnMut(&[u8]) -> Result<(), DnsQueryParseError>,
+) -> Result<NameWalk, DnsQueryParseError> {
     let mut offset = start;
     // Offset de reprise dans le flux : figé au premier pointeur rencontré.
     let mut resume_offset: Option<usize> = None;
@@ -71,12 +96,150 @@ pub fn parse_dns_name(message: &[u8], start: usize) -> Result<(String, usize), D
         offset += 1;
 
         check_dns_label_bounds(message, offset, len)?;
-        let label = String::from_utf8(message[offset..offset + len].to_vec())?;
-        labels.push(label);
+        on_label(&message[offset..offset + len])?;
         offset += len;
     }
 
-    Ok((labels.join("."), resume_offset.unwrap_or(offset)))
+    Ok(NameWalk {
+        compressed: resume_offset.is_some(),
+        resume_offset: resume_offset.unwrap_or(offset),
+    })
+}
+
+/// Nom DNS emprunté au message (RFC 1035 §3.1), compression résolue à la
+/// lecture.
+///
+/// Le nom est validé à la construction (pointeurs strictement en arrière,
+/// labels dans les bornes) ; [`DnsName::labels`] le reparcourt ensuite sans
+/// allouer. Deux noms sont égaux s'ils ont les mêmes labels, à la casse
+/// ASCII près (RFC 4343), quel que soit leur encodage.
+#[derive(Clone, Copy)]
+pub struct DnsName<'a> {
+    message: &'a [u8],
+    start: usize,
+    compressed: bool,
+}
+
+impl<'a> DnsName<'a> {
+    /// Valide le nom à `start` dans `message` (message DNS complet) et
+    /// retourne l'offset qui le suit dans le flux.
+    pub fn parse(message: &'a [u8], start: usize) -> Result<(Self, usize), DnsQueryParseError> {
+        let walk = walk_dns_name(message, start, |_| Ok(()))?;
+        Ok((
+            DnsName {
+                message,
+                start,
+                compressed: walk.compressed,
+            },
+            walk.resume_offset,
+        ))
+    }
+
+    /// Labels du nom, du plus spécifique au plus général, sans le label
+    /// racine.
+    pub fn labels(&self) -> DnsLabels<'a> {
+        DnsLabels {
+            message: self.message,
+            offset: self.start,
+        }
+    }
+
+    /// `true` pour le nom racine (aucun label).
+    pub fn is_root(&self) -> bool {
+        self.labels().next().is_none()
+    }
+
+    /// `true` si le nom suit au moins un pointeur de compression.
+    pub fn is_compressed(&self) -> bool {
+        self.compressed
+    }
+}
+
+impl fmt::Debug for DnsName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DnsName").field(&self.to_string()).finish()
+    }
+}
+
+impl PartialEq for DnsName<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.labels()
+            .map(<[u8]>::to_ascii_lowercase)
+            .eq(other.labels().map(<[u8]>::to_ascii_lowercase))
+    }
+}
+
+impl Eq for DnsName<'_> {}
+
+/// Forme de présentation (RFC 1035 §5.1) : labels séparés par `.`, sans
+/// point final ; `.` pour la racine. Un label UTF-8 valide est rendu tel
+/// quel (noms mDNS, RFC 6762 §16) ; `.`, `\\` et les caractères de contrôle
+/// sont échappés (`\\.`, `\\DDD`), comme tout octet d'un label non UTF-8.
+impl fmt::Display for DnsName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_root() {
+            return f.write_str(".");
+        }
+        for (index, label) in self.labels().enumerate() {
+            if index > 0 {
+                f.write_str(".")?;
+            }
+            match std::str::from_utf8(label) {
+                Ok(text) => {
+                    for character in text.chars() {
+                        match character {
+                            '.' | '\\' => write!(f, "\\{character}")?,
+                            _ if character.is_control() => {
+                                write!(f, "\\{:03}", u32::from(character))?
+                            }
+                            _ => write!(f, "{character}")?,
+                        }
+                    }
+                }
+                Err(_) => {
+                    for &byte in label {
+                        match byte {
+                            b'.' | b'\\' => write!(f, "\\{}", byte as char)?,
+                            0x21..=0x7e => write!(f, "{}", byte as char)?,
+                            _ => write!(f, "\\{byte:03}")?,
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Itérateur sur les labels d'un [`DnsName`].
+#[derive(Debug, Clone)]
+pub struct DnsLabels<'a> {
+    message: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Iterator for DnsLabels<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Le nom a été validé à la construction : les `get` ne servent qu'à
+        // ne jamais indexer hors bornes.
+        loop {
+            let len_byte = *self.message.get(self.offset)?;
+            if len_byte & POINTER_MASK == POINTER_MASK {
+                let low = *self.message.get(self.offset + 1)?;
+                self.offset = (((len_byte & 0x3F) as usize) << 8) | low as usize;
+                continue;
+            }
+            let len = len_byte as usize;
+            if len == 0 {
+                return None;
+            }
+            let label = self.message.get(self.offset + 1..self.offset + 1 + len)?;
+            self.offset += 1 + len;
+            return Some(label);
+        }
+    }
 }
 
 /// Resource record brut (RFC 1035 §4.1.3), partagé par les sections answer,
@@ -291,4 +454,50 @@ mod tests {
 
         assert_eq!(record.rclass, 0x8001);
     }
+
+    #[test]
+    fn test_dns_name_borrows_and_resolves_compression() {
+        // "example.com" à l'offset 0, puis "WWW" + pointeur à l'offset 13.
+        let mut data = vec![7u8];
+        data.extend_from_slice(b"example");
+        data.push(3);
+        data.extend_from_slice(b"com");
+        data.push(0);
+        data.push(3);
+        data.extend_from_slice(b"WWW");
+        data.extend_from_slice(&[0xC0, 0x00]);
+        let mut plain = vec![3u8];
+        plain.extend_from_slice(b"www");
+        plain.extend_from_slice(&data[..13]);
+
+        let (name, offset) = DnsName::parse(&data, 13).unwrap();
+        assert_eq!(offset, 19);
+        assert_eq!(
+            name.labels().collect::<Vec<_>>(),
+            [&b"WWW"[..], b"example", b"com"]
+        );
+        assert_eq!(name.to_string(), "WWW.example.com");
+        assert!(name.is_compressed());
+
+        // Égalité insensible à la casse ASCII, quel que soit l'encodage.
+        let (uncompressed, _) = DnsName::parse(&plain, 0).unwrap();
+        assert_eq!(name, uncompressed);
+        assert!(!uncompressed.is_compressed());
+
+        assert!(matches!(
+            DnsName::parse(&[0xC0u8, 0x00], 0),
+            Err(DnsQueryParseError::InvalidCompressionPointer(_))
+        ));
+    }
+
+    #[test]
+    fn test_dns_name_display_escapes_special_bytes() {
+        assert_eq!(DnsName::parse(&[0], 0).unwrap().0.to_string(), ".");
+
+        let mut data = vec![7u8];
+        data.extend_from_slice("Séjour".as_bytes());
+        data.extend_from_slice(b"\x04a.b\\\x02\x00\xff\x00");
+        let (name, _) = DnsName::parse(&data, 0).unwrap();
+        assert_eq!(name.to_string(), "Séjour.a\\.b\\\\.\\000\\255");
+    }
 }
@@ -0,0 +1,717 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! CIP explicit messages carried by SendRRData and SendUnitData.
+//!
+//! The encapsulation layer only splits the Common Packet Format into items.
+//! [`EtherNetIpPacket::cip`] picks the Unconnected Data (0x00B2) or Connected
+//! Data (0x00B1) item and decodes the Message Router request or response it
+//! carries: service code, EPATH, status and service data. Bit 0x80 of the
+//! service code tells a response from a request, so no port heuristic is
+//! needed here.
+//!
+//! Service codes 0x4B-0x63 are class specific: 0x52 is Unconnected Send on
+//! the Connection Manager and Read Tag Fragmented on a Logix tag. Request
+//! helpers look at the path; responses carry no path, so the caller pairs
+//! them with the request they answer.
+
+use std::fmt::Write as _;
+
+use super::{EtherNetIpCommandData, EtherNetIpPacket};
+use crate::{
+    checks::application::ethernet_ip::{
+        CIP_CLASS_CONNECTION_MANAGER, CIP_SEGMENT_ELECTRONIC_KEY, CIP_SEGMENT_SIMPLE_DATA,
+        CIP_SEGMENT_SYMBOLIC, CIP_SERVICE_MULTIPLE_SERVICE_PACKET, CIP_SERVICE_REPLY,
+        CIP_SERVICE_UNCONNECTED_SEND, CPF_ITEM_CONNECTED_ADDRESS, CPF_ITEM_CONNECTED_DATA,
+        CPF_ITEM_UNCONNECTED_DATA, cip_class_name, cip_general_status_name, cip_service_name,
+        extract_cip_bytes, extract_cip_u8, extract_cip_u16, extract_cip_u32,
+        extract_cip_word_sized, validate_cip_no_trailing_data, validate_cip_symbol,
+    },
+    errors::application::ethernet_ip::CipError,
+};
+
+/// One EPATH segment, padded form (the one used in Message Router requests
+/// and connection paths).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CipPathSegment<'a> {
+    /// Route through a port of the device (backplane, second Ethernet
+    /// port...) to the node at `link_address`.
+    Port {
+        port: u16,
+        link_address: &'a [u8],
+    },
+    Class(u32),
+    Instance(u32),
+    /// Logix array index, or member of a class-defined structure.
+    Member(u32),
+    ConnectionPoint(u32),
+    Attribute(u32),
+    ElectronicKey(CipElectronicKey),
+    /// ANSI extended symbol: a Logix tag name or one of its members.
+    Symbol(&'a str),
+    /// Simple data segment, e.g. the configuration data of a Forward Open.
+    Data(&'a [u8]),
+    /// Network segment (production inhibit time, safety, extended...).
+    Network {
+        segment: u8,
+        data: &'a [u8],
+    },
+}
+
+/// Identity a connection originator expects from its target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CipElectronicKey {
+    pub vendor_id: u16,
+    pub device_type: u16,
+    pub product_code: u16,
+    /// The target must match exactly when `false`, or only be compatible
+    /// with this revision when `true`.
+    pub compatibility: bool,
+    pub major_revision: u8,
+    pub minor_revision: u8,
+}
+
+/// Decoded EPATH.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CipPath<'a> {
+    pub segments: Vec<CipPathSegment<'a>>,
+}
+
+impl<'a> CipPath<'a> {
+    pub fn parse(mut bytes: &'a [u8]) -> Result<Self, CipError> {
+        let mut segments = Vec::with_capacity(bytes.len() / 2);
+        while !bytes.is_empty() {
+            let (segment, rest) = parse_segment(bytes)?;
+            segments.push(segment);
+            bytes = rest;
+        }
+        Ok(CipPath { segments })
+    }
+
+    /// First class segment of the path.
+    pub fn class(&self) -> Option<u32> {
+        self.segments.iter().find_map(|segment| match segment {
+            CipPathSegment::Class(class) => Some(*class),
+            _ => None,
+        })
+    }
+
+    pub fn instance(&self) -> Option<u32> {
+        self.segments.iter().find_map(|segment| match segment {
+            CipPathSegment::Instance(instance) => Some(*instance),
+            _ => None,
+        })
+    }
+
+    pub fn attribute(&self) -> Option<u32> {
+        self.segments.iter().find_map(|segment| match segment {
+            CipPathSegment::Attribute(attribute) => Some(*attribute),
+            _ => None,
+        })
+    }
+
+    pub fn is_symbolic(&self) -> bool {
+        self.segments
+            .iter()
+            .any(|segment| matches!(segment, CipPathSegment::Symbol(_)))
+    }
+
+    /// Logix tag name rebuilt from the symbolic and member segments, e.g.
+    /// `Program:Main.Recipe[2,3].Speed`.
+    pub fn symbol(&self) -> Option<String> {
+        if !self.is_symbolic() {
+            return None;
+        }
+        let mut tag = String::new();
+        let mut in_index = false;
+        for segment in &self.segments {
+            match segment {
+                CipPathSegment::Symbol(name) => {
+                    if in_index {
+                        tag.push(']');
+                        in_index = false;
+                    }
+                    if !tag.is_empty() {
+                        tag.push('.');
+                    }
+                    tag.push_str(name);
+                }
+                CipPathSegment::Member(index) => {
+                    tag.push(if in_index { ',' } else { '[' });
+                    let _ = write!(tag, "{index}");
+                    in_index = true;
+                }
+                _ => {}
+            }
+        }
+        if in_index {
+            tag.push(']');
+        }
+        Some(tag)
+    }
+}
+
+/// Message Router request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CipRequest<'a> {
+    pub service: u8,
+    pub path: CipPath<'a>,
+    pub data: &'a [u8],
+}
+
+/// Message Router response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CipResponse<'a> {
+    /// Request service code with the reply bit (0x80) set.
+    pub service: u8,
+    pub general_status: u8,
+    /// Additional status words, little-endian.
+    pub additional_status: &'a [u8],
+    pub data: &'a [u8],
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CipMessage<'a> {
+    Request(CipRequest<'a>),
+    Response(CipResponse<'a>),
+}
+
+/// CIP message found in a SendRRData or SendUnitData packet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CipExplicitMessage<'a> {
+    /// Connection identifier of the Connected Address item (SendUnitData).
+    pub connection_id: Option<u32>,
+    /// Class 3 sequence count heading the Connected Data item.
+    pub sequence_count: Option<u16>,
+    pub message: CipMessage<'a>,
+}
+
+/// Unconnected Send (Connection Manager service 0x52): a request forwarded
+/// by a bridge, typically from the Ethernet module to a controller slot.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CipUnconnectedSend<'a> {
+    pub priority_time_tick: u8,
+    pub timeout_ticks: u8,
+    pub request: CipRequest<'a>,
+    pub route_path: CipPath<'a>,
+}
+
+impl<'a> CipMessage<'a> {
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, CipError> {
+        match bytes.first() {
+            Some(service) if service & CIP_SERVICE_REPLY != 0 => {
+                CipResponse::parse(bytes).map(CipMessage::Response)
+            }
+            _ => CipRequest::parse(bytes).map(CipMessage::Request),
+        }
+    }
+
+    pub fn service(&self) -> u8 {
+        match self {
+            CipMessage::Request(request) => request.service,
+            CipMessage::Response(response) => response.service,
+        }
+    }
+}
+
+impl<'a> CipRequest<'a> {
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, CipError> {
+        let (service, rest) = extract_cip_u8(bytes, "service")?;
+        let (path, data) = extract_cip_word_sized(rest, "request path")?;
+        Ok(CipRequest {
+            service,
+            path: CipPath::parse(path)?,
+            data,
+        })
+    }
+
+    pub fn service_name(&self) -> &'static str {
+        let class = if self.path.is_symbolic() {
+            None
+        } else {
+            self.path.class()
+        };
+        cip_service_name(self.service, class)
+    }
+
+    pub fn class_name(&self) -> Option<&'static str> {
+        self.path.class().map(cip_class_name)
+    }
+
+    /// Requests bundled in a Multiple Service Packet (service 0x0A).
+    pub fn multiple_service_requests(&self) -> Option<Result<Vec<CipRequest<'a>>, CipError>> {
+        if self.service != CIP_SERVICE_MULTIPLE_SERVICE_PACKET {
+            return None;
+        }
+        Some(
+            split_multiple_service_packet(self.data)
+                .and_then(|services| services.into_iter().map(CipRequest::parse).collect()),
+        )
+    }
+
+    /// Embedded request of an Unconnected Send to the Connection Manager.
+    pub fn unconnected_send(&self) -> Option<Result<CipUnconnectedSend<'a>, CipError>> {
+        if self.service != CIP_SERVICE_UNCONNECTED_SEND
+            || self.path.class() != Some(CIP_CLASS_CONNECTION_MANAGER)
+        {
+            return None;
+        }
+        Some(parse_unconnected_send(self.data))
+    }
+}
+
+impl<'a> CipResponse<'a> {
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, CipError> {
+        let (service, rest) = extract_cip_u8(bytes, "service")?;
+        let (_reserved, rest) = extract_cip_u8(rest, "reserved")?;
+        let (general_status, rest) = extract_cip_u8(rest, "general status")?;
+        let (additional_status, data) = extract_cip_word_sized(rest, "additional status")?;
+        Ok(CipResponse {
+            service,
+            general_status,
+            additional_status,
+            data,
+        })
+    }
+
+    /// Service code of the request this response answers.
+    pub fn request_service(&self) -> u8 {
+        self.service & !CIP_SERVICE_REPLY
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.general_status == 0x00
+    }
+
+    pub fn general_status_name(&self) -> &'static str {
+        cip_general_status_name(self.general_status)
+    }
+
+    /// First additional status word, the extended status of most errors.
+    pub fn extended_status(&self) -> Option<u16> {
+        match self.additional_status {
+            [low, high, ..] => Some(u16::from_le_bytes([*low, *high])),
+            _ => None,
+        }
+    }
+
+    /// Responses bundled in a Multiple Service Packet reply. The list is
+    /// also present when the general status is 0x1E (one embedded service
+    /// failed).
+    pub fn multiple_service_responses(&self) -> Option<Result<Vec<CipResponse<'a>>, CipError>> {
+        if self.request_service() != CIP_SERVICE_MULTIPLE_SERVICE_PACKET || self.data.is_empty() {
+            return None;
+        }
+        Some(
+            split_multiple_service_packet(self.data)
+                .and_then(|services| services.into_iter().map(CipResponse::parse).collect()),
+        )
+    }
+}
+
+impl<'a> EtherNetIpPacket<'a> {
+    /// CIP message of a SendRRData or SendUnitData packet, `None` for other
+    /// commands or when no data item is present.
+    pub fn cip(&self) -> Option<Result<CipExplicitMessage<'a>, CipError>> {
+        let EtherNetIpCommandData::CommonPacketFormat(cpf) = &self.command_data else {
+            return None;
+        };
+        let connection_id = cpf
+            .items
+            .iter()
+            .find(|item| item.type_id == CPF_ITEM_CONNECTED_ADDRESS)
+            .map(|item| item.data);
+        let item = cpf.items.iter().find(|item| {
+            item.type_id == CPF_ITEM_UNCONNECTED_DATA || item.type_id == CPF_ITEM_CONNECTED_DATA
+        })?;
+        Some(parse_explicit_message(
+            connection_id,
+            item.type_id,
+            item.data,
+        ))
+    }
+}
+
+fn parse_explicit_message<'a>(
+    connection_address: Option<&'a [u8]>,
+    type_id: u16,
+    data: &'a [u8],
+) -> Result<CipExplicitMessage<'a>, CipError> {
+    let connection_id = match connection_address {
+        Some(address) => {
+            let (connection_id, rest) = extract_cip_u32(address, "connection id")?;
+            validate_cip_no_trailing_data(rest, "connection id")?;
+            Some(connection_id)
+        }
+        None => None,
+    };
+    let (sequence_count, message) = if type_id == CPF_ITEM_CONNECTED_DATA {
+        let (sequence_count, rest) = extract_cip_u16(data, "sequence count")?;
+        (Some(sequence_count), rest)
+    } else {
+        (None, data)
+    };
+    Ok(CipExplicitMessage {
+        connection_id,
+        sequence_count,
+        message: CipMessage::parse(message)?,
+    })
+}
+
+/// Decoupe le corps d'un Multiple Service Packet : nombre de services, puis
+/// un offset u16 par service, relatif au debut du nombre de services.
+fn split_multiple_service_packet(data: &[u8]) -> Result<Vec<&[u8]>, CipError> {
+    let (count, mut rest) = extract_cip_u16(data, "service count")?;
+    let count = usize::from(count);
+    let header_len = 2 + 2 * count;
+    if data.len() < header_len {
+        return Err(CipError::Truncated {
+            field: "service offsets",
+        });
+    }
+
+    let mut offsets = Vec::with_capacity(count);
+    for index in 0..count {
+        let (offset, next) = extract_cip_u16(rest, "service offsets")?;
+        let previous = offsets.last().copied().unwrap_or(header_len);
+        if usize::from(offset) < previous || usize::from(offset) > data.len() {
+            return Err(CipError::InvalidServiceOffset { index, offset });
+        }
+        offsets.push(usize::from(offset));
+        rest = next;
+    }
+
+    Ok(offsets
+        .iter()
+        .enumerate()
+        .map(|(index, start)| {
+            let end = offsets.get(index + 1).copied().unwrap_or(data.len());
+            &data[*start..end]
+        })
+        .collect())
+}
+
+fn parse_unconnected_send(data: &[u8]) -> Result<CipUnconnectedSend<'_>, CipError> {
+    let (priority_time_tick, rest) = extract_cip_u8(data, "priority/time tick")?;
+    let (timeout_ticks, rest) = extract_cip_u8(rest, "timeout ticks")?;
+    let (request_size, rest) = extract_cip_u16(rest, "embedded request size")?;
+    let (request, mut rest) =
+        extract_cip_bytes(rest, usize::from(request_size), "embedded request")?;
+    // Octet de bourrage quand la requete embarquee a une taille impaire.
+    if request_size % 2 == 1 {
+        rest = extract_cip_bytes(rest, 1, "embedded request pad")?.1;
+    }
+    let (route_words, rest) = extract_cip_u8(rest, "route path size")?;
+    let (_reserved, rest) = extract_cip_u8(rest, "reserved")?;
+    let (route_path, rest) = extract_cip_bytes(rest, usize::from(route_words) * 2, "route path")?;
+    validate_cip_no_trailing_data(rest, "route path")?;
+
+    Ok(CipUnconnectedSend {
+        priority_time_tick,
+        timeout_ticks,
+        request: CipRequest::parse(request)?,
+        route_path: CipPath::parse(route_path)?,
+    })
+}
+
+fn parse_segment(bytes: &[u8]) -> Result<(CipPathSegment<'_>, &[u8]), CipError> {
+    let (segment, rest) = extract_cip_u8(bytes, "path segment")?;
+    match segment {
+        0x00..=0x1F => parse_port_segment(segment, rest),
+        CIP_SEGMENT_ELECTRONIC_KEY => parse_electronic_key(rest),
+        0x20..=0x3F => parse_logical_segment(segment, rest),
+        0x40..=0x4F => {
+            let (data, rest) = extract_cip_bytes(rest, 1, "network segment")?;
+            Ok((CipPathSegment::Network { segment, data }, rest))
+        }
+        0x50..=0x5F => {
+            let (data, rest) = extract_cip_word_sized(rest, "network segment")?;
+            Ok((CipPathSegment::Network { segment, data }, rest))
+        }
+        CIP_SEGMENT_SYMBOLIC => {
+            let (length, rest) = extract_cip_u8(rest, "symbol length")?;
+            let (symbol, mut rest) = extract_cip_bytes(rest, usize::from(length), "symbol")?;
+            if length % 2 == 1 {
+                rest = extract_cip_bytes(rest, 1, "symbol pad")?.1;
+            }
+            Ok((CipPathSegment::Symbol(validate_cip_symbol(symbol)?), rest))
+        }
+        CIP_SEGMENT_SIMPLE_DATA => {
+            let (data, rest) = extract_cip_word_sized(rest, "data segment")?;
+            Ok((CipPathSegment::Data(data), rest))
+        }
+        _ => Err(CipError::UnsupportedPathSegment { segment }),
+    }
+}
+
+/// Segment port : bit 4 = adresse de lien etendue (taille u8 puis adresse),
+/// identifiant 15 = numero de port u16 a la suite. Le segment est complete a
+/// une longueur paire.
+fn parse_port_segment(segment: u8, bytes: &[u8]) -> Result<(CipPathSegment<'_>, &[u8]), CipError> {
+    let extended_link = segment & 0x10 != 0;
+    let (link_len, rest) = if extended_link {
+        let (size, rest) = extract_cip_u8(bytes, "port link address size")?;
+        (usize::from(size), rest)
+    } else {
+        (1, bytes)
+    };
+    let (port, rest) = match segment & 0x0F {
+        0x0F => extract_cip_u16(rest, "port identifier")?,
+        port => (u16::from(port), rest),
+    };
+    let (link_address, mut rest) = extract_cip_bytes(rest, link_len, "port link address")?;
+
+    let segment_len = bytes.len() - rest.len() + 1;
+    if segment_len % 2 == 1 {
+        rest = extract_cip_bytes(rest, 1, "port segment pad")?.1;
+    }
+    Ok((CipPathSegment::Port { port, link_address }, rest))
+}
+
+/// Segment logique : bits 4-2 = type (classe, instance, membre, point de
+/// connexion, attribut), bits 1-0 = format 8/16/32 bits. Les formats 16 et
+/// 32 bits sont precedes d'un octet de bourrage.
+fn parse_logical_segment(
+    segment: u8,
+    bytes: &[u8],
+) -> Result<(CipPathSegment<'_>, &[u8]), CipError> {
+    let (value, rest) = match segment & 0x03 {
+        0x00 => {
+            let (value, rest) = extract_cip_u8(bytes, "logical segment")?;
+            (u32::from(value), rest)
+        }
+        0x01 => {
+            let (_pad, rest) = extract_cip_u8(bytes, "logical segment pad")?;
+            let (value, rest) = extract_cip_u16(rest, "logical segment")?;
+            (u32::from(value), rest)
+        }
+        0x02 => {
+            let (_pad, rest) = extract_cip_u8(bytes, "logical segment pad")?;
+            extract_cip_u32(rest, "logical segment")?
+        }
+        _ => return Err(CipError::UnsupportedPathSegment { segment }),
+    };
+    let segment = match (segment >> 2) & 0x07 {
+        0 => CipPathSegment::Class(value),
+        1 => CipPathSegment::Instance(value),
+        2 => CipPathSegment::Member(value),
+        3 => CipPathSegment::ConnectionPoint(value),
+        4 => CipPathSegment::Attribute(value),
+        _ => return Err(CipError::UnsupportedPathSegment { segment }),
+    };
+    Ok((segment, rest))
+}
+
+/// Electronic key au format 4 : vendeur, type, code produit, revision
+/// majeure (bit 7 = compatibilite) et mineure.
+fn parse_electronic_key(bytes: &[u8]) -> Result<(CipPathSegment<'_>, &[u8]), CipError> {
+    let (format, rest) = extract_cip_u8(bytes, "electronic key format")?;
+    if format != 0x04 {
+        return Err(CipError::UnsupportedPathSegment {
+            segment: CIP_SEGMENT_ELECTRONIC_KEY,
+        });
+    }
+    let (vendor_id, rest) = extract_cip_u16(rest, "electronic key vendor")?;
+    let (device_type, rest) = extract_cip_u16(rest, "electronic key device type")?;
+    let (product_code, rest) = extract_cip_u16(rest, "electronic key product code")?;
+    let (major, rest) = extract_cip_u8(rest, "electronic key major revision")?;
+    let (minor_revision, rest) = extract_cip_u8(rest, "electronic key minor revision")?;
+    Ok((
+        CipPathSegment::ElectronicKey(CipElectronicKey {
+            vendor_id,
+            device_type,
+            product_code,
+            compatibility: major & 0x80 != 0,
+            major_revision: major & 0x7F,
+            minor_revision,
+        }),
+        rest,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_symbolic_path_with_indexes() {
+        // Synthetique : "Program:Main" (12 octets), "Recipe" (6), indices 2
+        // (8 bits) et 300 (16 bits), puis "Speed" (5 + bourrage).
+        let bytes = hex::decode(concat!(
+            "910c50726f6772616d3a4d61696e",
+            "9106526563697065",
+            "2802",
+            "29002c01",
+            "9105537065656400",
+        ))
+        .expect("invalid hex fixture");
+
+        let path = CipPath::parse(&bytes).expect("valid symbolic path");
+        assert_eq!(path.segments.len(), 5);
+        assert_eq!(path.segments[3], CipPathSegment::Member(300));
+        assert_eq!(
+            path.symbol().as_deref(),
+            Some("Program:Main.Recipe[2,300].Speed")
+        );
+        assert_eq!(path.class(), None);
+    }
+
+    #[test]
+    fn parse_port_logical_and_key_segments() {
+        // Synthetique : port 1 slot 0, electronic key (vendeur 1, type 0x0E,
+        // code 0x36, revision 20.11 compatible), classe 0x02 instance 1,
+        // point de connexion 16 bits 0x0100, lien etendu vers 10.0.0.1.
+        let bytes = hex::decode(concat!(
+            "0100",
+            "340401000e003600940b",
+            "20022401",
+            "2d000001",
+            "120831302e302e302e31",
+        ))
+        .expect("invalid hex fixture");
+
+        let path = CipPath::parse(&bytes).expect("valid path");
+        assert_eq!(
+            path.segments[0],
+            CipPathSegment::Port {
+                port: 1,
+                link_address: &[0x00]
+            }
+        );
+        assert_eq!(
+            path.segments[1],
+            CipPathSegment::ElectronicKey(CipElectronicKey {
+                vendor_id: 1,
+                device_type: 0x0E,
+                product_code: 0x36,
+                compatibility: true,
+                major_revision: 20,
+                minor_revision: 11,
+            })
+        );
+        assert_eq!(path.class(), Some(0x02));
+        assert_eq!(path.instance(), Some(1));
+        assert_eq!(path.segments[4], CipPathSegment::ConnectionPoint(0x0100));
+        assert_eq!(
+            path.segments[5],
+            CipPathSegment::Port {
+                port: 2,
+                link_address: b"10.0.0.1"
+            }
+        );
+        assert!(path.symbol().is_none());
+    }
+
+    #[test]
+    fn reject_unsupported_and_truncated_segments() {
+        assert_eq!(
+            CipPath::parse(&[0xE0, 0x00]),
+            Err(CipError::UnsupportedPathSegment { segment: 0xE0 })
+        );
+        assert_eq!(
+            CipPath::parse(&[0x21, 0x00, 0x01]),
+            Err(CipError::Truncated {
+                field: "logical segment"
+            })
+        );
+        assert_eq!(
+            CipPath::parse(&[0x91, 0x02, 0x41, 0x00]),
+            Err(CipError::InvalidSymbol)
+        );
+    }
+
+    #[test]
+    fn split_multiple_service_packet_request_and_response() {
+        // Synthetique : Get Attribute Single identite attribut 1 puis
+        // attribut 7, dans un Multiple Service Packet adresse au Message
+        // Router.
+        let request = hex::decode(concat!(
+            "0a0220022401",
+            "020006000e00",
+            "0e03200124013001",
+            "0e03200124013007"
+        ))
+        .expect("invalid hex fixture");
+        let CipMessage::Request(request) = CipMessage::parse(&request).expect("valid request")
+        else {
+            panic!("expected a request");
+        };
+        assert_eq!(request.service_name(), "Multiple Service Packet");
+        assert_eq!(request.class_name(), Some("Message Router"));
+        let services = request
+            .multiple_service_requests()
+            .expect("multiple service packet")
+            .expect("valid services");
+        assert_eq!(services.len(), 2);
+        assert_eq!(services[0].service_name(), "Get Attribute Single");
+        assert_eq!(services[0].path.attribute(), Some(1));
+        assert_eq!(services[1].path.attribute(), Some(7));
+
+        // Reponse : le deuxieme service echoue (0x14, attribut non supporte)
+        // et le statut global passe a 0x1E.
+        let response =
+            hex::decode("8a001e00020006000c008e00000001008e001400").expect("invalid hex");
+        let CipMessage::Response(response) = CipMessage::parse(&response).expect("valid response")
+        else {
+            panic!("expected a response");
+        };
+        assert_eq!(response.general_status_name(), "Embedded service error");
+        let services = response
+            .multiple_service_responses()
+            .expect("multiple service reply")
+            .expect("valid services");
+        assert_eq!(services.len(), 2);
+        assert!(services[0].is_success());
+        assert_eq!(services[0].data, &[0x01, 0x00]);
+        assert_eq!(services[1].general_status, 0x14);
+    }
+
+    #[test]
+    fn reject_overlapping_service_offsets() {
+        // Synthetique : deux services, le second offset precede le premier.
+        let bytes = hex::decode("0a02200224010200080006000e032001").expect("invalid hex fixture");
+        let request = CipRequest::parse(&bytes).expect("valid outer request");
+        assert_eq!(
+            request.multiple_service_requests(),
+            Some(Err(CipError::InvalidServiceOffset {
+                index: 1,
+                offset: 6
+            }))
+        );
+    }
+
+    #[test]
+    fn unwrap_unconnected_send() {
+        // Synthetique : Unconnected Send vers le slot 0 du fond de panier,
+        // portant un Read Tag "Counter" (requete de 14 octets).
+        let bytes = hex::decode(concat!(
+            "52022006240105f70e00",
+            "4c059107436f756e74657200",
+            "0100",
+            "01000100"
+        ))
+        .expect("invalid hex fixture");
+        let request = CipRequest::parse(&bytes).expect("valid request");
+        assert_eq!(request.service_name(), "Unconnected Send");
+
+        let send = request
+            .unconnected_send()
+            .expect("unconnected send")
+            .expect("valid embedded request");
+        assert_eq!(send.priority_time_tick, 0x05);
+        assert_eq!(send.timeout_ticks, 0xF7);
+        assert_eq!(send.request.service_name(), "Read Tag");
+        assert_eq!(send.request.path.symbol().as_deref(), Some("Counter"));
+        assert_eq!(send.request.data, &[0x01, 0x00]);
+        assert_eq!(
+            send.route_path.segments,
+            vec![CipPathSegment::Port {
+                port: 1,
+                link_address: &[0x00]
+            }]
+        );
+    }
+}
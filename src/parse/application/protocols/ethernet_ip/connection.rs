@@ -0,0 +1,537 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Connection Manager services: Forward Open, Large Forward Open and
+//! Forward Close.
+//!
+//! A Forward Open sets up a class 1 (implicit I/O over UDP 2222) or class 3
+//! (explicit messaging over SendUnitData) connection. The connection
+//! identifiers it returns are the ones found later in Connected Address and
+//! Sequenced Address items, and the triad (connection serial, originator
+//! vendor and serial) is what the matching Forward Close quotes.
+
+use super::cip::{CipPath, CipRequest, CipResponse};
+use crate::{
+    checks::application::ethernet_ip::{
+        CIP_CLASS_CONNECTION_MANAGER, CIP_SERVICE_FORWARD_CLOSE, CIP_SERVICE_FORWARD_OPEN,
+        CIP_SERVICE_LARGE_FORWARD_OPEN, extract_cip_bytes, extract_cip_u8, extract_cip_u16,
+        extract_cip_u32, extract_cip_word_sized, validate_cip_no_trailing_data,
+    },
+    errors::application::ethernet_ip::CipError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CipConnectionType {
+    Null,
+    Multicast,
+    PointToPoint,
+    Reserved,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CipConnectionPriority {
+    Low,
+    High,
+    Scheduled,
+    Urgent,
+}
+
+/// Network connection parameters of one direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CipConnectionParameters {
+    pub redundant_owner: bool,
+    pub connection_type: CipConnectionType,
+    pub priority: CipConnectionPriority,
+    /// Connection size in bytes is a maximum (`true`) or exact (`false`).
+    pub variable_size: bool,
+    pub size: u16,
+}
+
+impl CipConnectionParameters {
+    /// Forward Open word: owner bit 15, type bits 14-13, priority bits
+    /// 11-10, fixed/variable bit 9, size bits 8-0.
+    pub fn from_u16(raw: u16) -> Self {
+        Self::from_fields(u32::from(raw) << 16, raw & 0x01FF)
+    }
+
+    /// Large Forward Open double word: the same flags in the upper half,
+    /// a 16-bit size in the lower half.
+    pub fn from_u32(raw: u32) -> Self {
+        Self::from_fields(raw, (raw & 0xFFFF) as u16)
+    }
+
+    fn from_fields(flags: u32, size: u16) -> Self {
+        CipConnectionParameters {
+            redundant_owner: flags & (1 << 31) != 0,
+            connection_type: match (flags >> 29) & 0x03 {
+                0 => CipConnectionType::Null,
+                1 => CipConnectionType::Multicast,
+                2 => CipConnectionType::PointToPoint,
+                _ => CipConnectionType::Reserved,
+            },
+            priority: match (flags >> 26) & 0x03 {
+                0 => CipConnectionPriority::Low,
+                1 => CipConnectionPriority::High,
+                2 => CipConnectionPriority::Scheduled,
+                _ => CipConnectionPriority::Urgent,
+            },
+            variable_size: flags & (1 << 25) != 0,
+            size,
+        }
+    }
+}
+
+/// Transport class and trigger byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CipTransportTrigger(pub u8);
+
+impl CipTransportTrigger {
+    /// Direction bit: the target produces (server) or the originator does.
+    pub fn is_server(&self) -> bool {
+        self.0 & 0x80 != 0
+    }
+
+    /// 0 cyclic, 1 change of state, 2 application object.
+    pub fn production_trigger(&self) -> u8 {
+        (self.0 >> 4) & 0x07
+    }
+
+    /// 0 and 1 for implicit I/O, 3 for explicit messaging.
+    pub fn transport_class(&self) -> u8 {
+        self.0 & 0x0F
+    }
+}
+
+/// Connection identity quoted by every Connection Manager service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CipConnectionTriad {
+    pub connection_serial: u16,
+    pub originator_vendor_id: u16,
+    pub originator_serial: u32,
+}
+
+/// Forward Open or Large Forward Open request.
+///
+/// ```mermaid
+/// ---
+/// title: CipForwardOpenRequest
+/// ---
+/// packet-beta
+/// 0-7: "Priority/Time Tick"
+/// 8-15: "Timeout Ticks"
+/// 16-47: "O->T Connection ID u32 LE"
+/// 48-79: "T->O Connection ID u32 LE"
+/// 80-95: "Connection Serial u16 LE"
+/// 96-111: "Originator Vendor u16 LE"
+/// 112-143: "Originator Serial u32 LE"
+/// 144-151: "Timeout Multiplier"
+/// 152-175: "Reserved"
+/// 176-207: "O->T RPI (us) u32 LE"
+/// 208-223: "O->T Parameters u16 (u32 Large)"
+/// 224-255: "T->O RPI (us) u32 LE"
+/// 256-271: "T->O Parameters u16 (u32 Large)"
+/// 272-279: "Transport Trigger"
+/// 280-287: "Path Size (words)"
+/// 288-319: "Connection Path variable"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CipForwardOpenRequest<'a> {
+    pub large: bool,
+    pub priority_time_tick: u8,
+    pub timeout_ticks: u8,
+    /// Chosen by the target; the originator usually sends 0.
+    pub o_t_connection_id: u32,
+    pub t_o_connection_id: u32,
+    pub triad: CipConnectionTriad,
+    pub timeout_multiplier: u8,
+    /// Requested packet interval, microseconds.
+    pub o_t_rpi: u32,
+    pub o_t_parameters: CipConnectionParameters,
+    pub t_o_rpi: u32,
+    pub t_o_parameters: CipConnectionParameters,
+    pub transport: CipTransportTrigger,
+    pub connection_path: CipPath<'a>,
+}
+
+/// Successful Forward Open reply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CipForwardOpenResponse<'a> {
+    pub o_t_connection_id: u32,
+    pub t_o_connection_id: u32,
+    pub triad: CipConnectionTriad,
+    /// Actual packet interval, microseconds.
+    pub o_t_api: u32,
+    pub t_o_api: u32,
+    pub application_reply: &'a [u8],
+}
+
+/// Forward Open or Forward Close rejected by the target; the reason is in
+/// the general and extended status of the enclosing response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CipConnectionFailure {
+    pub triad: CipConnectionTriad,
+    /// Words of the connection path left unprocessed, when the target
+    /// reports it.
+    pub remaining_path_size: Option<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CipForwardOpenReply<'a> {
+    Opened(CipForwardOpenResponse<'a>),
+    Rejected(CipConnectionFailure),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CipForwardCloseRequest<'a> {
+    pub priority_time_tick: u8,
+    pub timeout_ticks: u8,
+    pub triad: CipConnectionTriad,
+    pub connection_path: CipPath<'a>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CipForwardCloseReply<'a> {
+    Closed {
+        triad: CipConnectionTriad,
+        application_reply: &'a [u8],
+    },
+    Rejected(CipConnectionFailure),
+}
+
+impl<'a> CipRequest<'a> {
+    /// Forward Open or Large Forward Open sent to the Connection Manager.
+    pub fn forward_open(&self) -> Option<Result<CipForwardOpenRequest<'a>, CipError>> {
+        if self.path.class() != Some(CIP_CLASS_CONNECTION_MANAGER) {
+            return None;
+        }
+        match self.service {
+            CIP_SERVICE_FORWARD_OPEN => Some(CipForwardOpenRequest::parse(self.data, false)),
+            CIP_SERVICE_LARGE_FORWARD_OPEN => Some(CipForwardOpenRequest::parse(self.data, true)),
+            _ => None,
+        }
+    }
+
+    /// Forward Close sent to the Connection Manager.
+    pub fn forward_close(&self) -> Option<Result<CipForwardCloseRequest<'a>, CipError>> {
+        if self.service != CIP_SERVICE_FORWARD_CLOSE
+            || self.path.class() != Some(CIP_CLASS_CONNECTION_MANAGER)
+        {
+            return None;
+        }
+        Some(CipForwardCloseRequest::parse(self.data))
+    }
+}
+
+impl<'a> CipResponse<'a> {
+    /// Reply to a Forward Open or Large Forward Open.
+    pub fn forward_open(&self) -> Option<Result<CipForwardOpenReply<'a>, CipError>> {
+        if !matches!(
+            self.request_service(),
+            CIP_SERVICE_FORWARD_OPEN | CIP_SERVICE_LARGE_FORWARD_OPEN
+        ) {
+            return None;
+        }
+        if !self.is_success() {
+            return Some(parse_failure(self.data).map(CipForwardOpenReply::Rejected));
+        }
+        Some(CipForwardOpenResponse::parse(self.data).map(CipForwardOpenReply::Opened))
+    }
+
+    /// Reply to a Forward Close. The caller checks that the request was a
+    /// Forward Close: 0xCE also answers a Logix Read Modify Write Tag.
+    pub fn forward_close(&self) -> Option<Result<CipForwardCloseReply<'a>, CipError>> {
+        if self.request_service() != CIP_SERVICE_FORWARD_CLOSE {
+            return None;
+        }
+        if !self.is_success() {
+            return Some(parse_failure(self.data).map(CipForwardCloseReply::Rejected));
+        }
+        Some(parse_close_success(self.data))
+    }
+}
+
+impl<'a> CipForwardOpenRequest<'a> {
+    pub fn parse(data: &'a [u8], large: bool) -> Result<Self, CipError> {
+        let (priority_time_tick, rest) = extract_cip_u8(data, "priority/time tick")?;
+        let (timeout_ticks, rest) = extract_cip_u8(rest, "timeout ticks")?;
+        let (o_t_connection_id, rest) = extract_cip_u32(rest, "O->T connection id")?;
+        let (t_o_connection_id, rest) = extract_cip_u32(rest, "T->O connection id")?;
+        let (triad, rest) = extract_triad(rest)?;
+        let (timeout_multiplier, rest) = extract_cip_u8(rest, "timeout multiplier")?;
+        let (_reserved, rest) = extract_cip_bytes(rest, 3, "reserved")?;
+        let (o_t_rpi, rest) = extract_cip_u32(rest, "O->T RPI")?;
+        let (o_t_parameters, rest) = extract_parameters(rest, large, "O->T parameters")?;
+        let (t_o_rpi, rest) = extract_cip_u32(rest, "T->O RPI")?;
+        let (t_o_parameters, rest) = extract_parameters(rest, large, "T->O parameters")?;
+        let (transport, rest) = extract_cip_u8(rest, "transport trigger")?;
+        let (connection_path, rest) = extract_cip_word_sized(rest, "connection path")?;
+        validate_cip_no_trailing_data(rest, "connection path")?;
+
+        Ok(CipForwardOpenRequest {
+            large,
+            priority_time_tick,
+            timeout_ticks,
+            o_t_connection_id,
+            t_o_connection_id,
+            triad,
+            timeout_multiplier,
+            o_t_rpi,
+            o_t_parameters,
+            t_o_rpi,
+            t_o_parameters,
+            transport: CipTransportTrigger(transport),
+            connection_path: CipPath::parse(connection_path)?,
+        })
+    }
+}
+
+impl<'a> CipForwardOpenResponse<'a> {
+    pub fn parse(data: &'a [u8]) -> Result<Self, CipError> {
+        let (o_t_connection_id, rest) = extract_cip_u32(data, "O->T connection id")?;
+        let (t_o_connection_id, rest) = extract_cip_u32(rest, "T->O connection id")?;
+        let (triad, rest) = extract_triad(rest)?;
+        let (o_t_api, rest) = extract_cip_u32(rest, "O->T API")?;
+        let (t_o_api, rest) = extract_cip_u32(rest, "T->O API")?;
+        let (application_reply, rest) = extract_application_reply(rest)?;
+        validate_cip_no_trailing_data(rest, "application reply")?;
+
+        Ok(CipForwardOpenResponse {
+            o_t_connection_id,
+            t_o_connection_id,
+            triad,
+            o_t_api,
+            t_o_api,
+            application_reply,
+        })
+    }
+}
+
+impl<'a> CipForwardCloseRequest<'a> {
+    pub fn parse(data: &'a [u8]) -> Result<Self, CipError> {
+        let (priority_time_tick, rest) = extract_cip_u8(data, "priority/time tick")?;
+        let (timeout_ticks, rest) = extract_cip_u8(rest, "timeout ticks")?;
+        let (triad, rest) = extract_triad(rest)?;
+        let (path_words, rest) = extract_cip_u8(rest, "connection path size")?;
+        let (_reserved, rest) = extract_cip_u8(rest, "reserved")?;
+        let (connection_path, rest) =
+            extract_cip_bytes(rest, usize::from(path_words) * 2, "connection path")?;
+        validate_cip_no_trailing_data(rest, "connection path")?;
+
+        Ok(CipForwardCloseRequest {
+            priority_time_tick,
+            timeout_ticks,
+            triad,
+            connection_path: CipPath::parse(connection_path)?,
+        })
+    }
+}
+
+fn extract_triad(data: &[u8]) -> Result<(CipConnectionTriad, &[u8]), CipError> {
+    let (connection_serial, rest) = extract_cip_u16(data, "connection serial")?;
+    let (originator_vendor_id, rest) = extract_cip_u16(rest, "originator vendor")?;
+    let (originator_serial, rest) = extract_cip_u32(rest, "originator serial")?;
+    Ok((
+        CipConnectionTriad {
+            connection_serial,
+            originator_vendor_id,
+            originator_serial,
+        },
+        rest,
+    ))
+}
+
+fn extract_parameters<'a>(
+    data: &'a [u8],
+    large: bool,
+    field: &'static str,
+) -> Result<(CipConnectionParameters, &'a [u8]), CipError> {
+    if large {
+        let (raw, rest) = extract_cip_u32(data, field)?;
+        return Ok((CipConnectionParameters::from_u32(raw), rest));
+    }
+    let (raw, rest) = extract_cip_u16(data, field)?;
+    Ok((CipConnectionParameters::from_u16(raw), rest))
+}
+
+/// Taille de la reponse applicative en mots, octet reserve, puis donnees.
+fn extract_application_reply(data: &[u8]) -> Result<(&[u8], &[u8]), CipError> {
+    let (words, rest) = extract_cip_u8(data, "application reply size")?;
+    let (_reserved, rest) = extract_cip_u8(rest, "reserved")?;
+    extract_cip_bytes(rest, usize::from(words) * 2, "application reply")
+}
+
+/// Reponse d'echec : le triplet, puis la taille du chemin restant et un
+/// octet reserve, que certains equipements omettent.
+fn parse_failure(data: &[u8]) -> Result<CipConnectionFailure, CipError> {
+    let (triad, rest) = extract_triad(data)?;
+    let remaining_path_size = rest.first().copied();
+    Ok(CipConnectionFailure {
+        triad,
+        remaining_path_size,
+    })
+}
+
+fn parse_close_success(data: &[u8]) -> Result<CipForwardCloseReply<'_>, CipError> {
+    let (triad, rest) = extract_triad(data)?;
+    let (application_reply, rest) = extract_application_reply(rest)?;
+    validate_cip_no_trailing_data(rest, "application reply")?;
+    Ok(CipForwardCloseReply::Closed {
+        triad,
+        application_reply,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::application::protocols::ethernet_ip::cip::{CipMessage, CipPathSegment};
+
+    /// Synthetique : Forward Open classe 1 vers l'assembly 0x64/0x65 d'un
+    /// adaptateur d'I/O, RPI 10 ms dans les deux sens.
+    const FORWARD_OPEN_REQUEST_HEX: &str = concat!(
+        "54022006240107f9",
+        "00000000",
+        "78563412",
+        "2a00",
+        "0100",
+        "efbeadde",
+        "01",
+        "000000",
+        "10270000",
+        "1e48",
+        "10270000",
+        "1e28",
+        "01",
+        "04",
+        "20042401",
+        "2c652c64",
+    );
+
+    #[test]
+    fn decode_forward_open_request() {
+        let bytes = hex::decode(FORWARD_OPEN_REQUEST_HEX).expect("invalid hex fixture");
+        let request = CipRequest::parse(&bytes).expect("valid request");
+        assert_eq!(request.service_name(), "Forward Open");
+
+        let open = request
+            .forward_open()
+            .expect("forward open")
+            .expect("valid forward open");
+        assert!(!open.large);
+        assert_eq!(open.o_t_connection_id, 0);
+        assert_eq!(open.t_o_connection_id, 0x1234_5678);
+        assert_eq!(
+            open.triad,
+            CipConnectionTriad {
+                connection_serial: 0x2A,
+                originator_vendor_id: 1,
+                originator_serial: 0xDEAD_BEEF,
+            }
+        );
+        assert_eq!(open.o_t_rpi, 10_000);
+        assert_eq!(
+            open.o_t_parameters,
+            CipConnectionParameters {
+                redundant_owner: false,
+                connection_type: CipConnectionType::PointToPoint,
+                priority: CipConnectionPriority::Scheduled,
+                variable_size: false,
+                size: 30,
+            }
+        );
+        assert_eq!(
+            open.t_o_parameters.connection_type,
+            CipConnectionType::Multicast
+        );
+        assert_eq!(open.transport.transport_class(), 1);
+        assert!(!open.transport.is_server());
+        assert_eq!(open.connection_path.class(), Some(0x04));
+        assert_eq!(
+            open.connection_path.segments[2..],
+            [
+                CipPathSegment::ConnectionPoint(0x65),
+                CipPathSegment::ConnectionPoint(0x64)
+            ]
+        );
+    }
+
+    #[test]
+    fn decode_large_forward_open_parameters() {
+        let parameters = CipConnectionParameters::from_u32(0x4A00_05DC);
+        assert_eq!(parameters.connection_type, CipConnectionType::PointToPoint);
+        assert_eq!(parameters.priority, CipConnectionPriority::Scheduled);
+        assert!(parameters.variable_size);
+        assert_eq!(parameters.size, 1500);
+    }
+
+    #[test]
+    fn decode_forward_open_replies() {
+        // Synthetique : ouverture acceptee, API egal au RPI demande.
+        let opened = hex::decode(concat!(
+            "d4000000",
+            "01000a00",
+            "78563412",
+            "2a000100efbeadde",
+            "10270000",
+            "10270000",
+            "0000",
+        ))
+        .expect("invalid hex fixture");
+        let CipMessage::Response(response) = CipMessage::parse(&opened).expect("valid response")
+        else {
+            panic!("expected a response");
+        };
+        let Some(Ok(CipForwardOpenReply::Opened(reply))) = response.forward_open() else {
+            panic!("expected an accepted forward open");
+        };
+        assert_eq!(reply.o_t_connection_id, 0x000A_0001);
+        assert_eq!(reply.triad.connection_serial, 0x2A);
+        assert_eq!(reply.t_o_api, 10_000);
+        assert!(reply.application_reply.is_empty());
+
+        // Synthetique : refus 0x01 / 0x0100 (connexion deja utilisee).
+        let rejected =
+            hex::decode("d400010100012a000100efbeadde0000").expect("invalid hex fixture");
+        let CipMessage::Response(response) = CipMessage::parse(&rejected).expect("valid response")
+        else {
+            panic!("expected a response");
+        };
+        assert_eq!(response.extended_status(), Some(0x0100));
+        assert_eq!(
+            response.forward_open(),
+            Some(Ok(CipForwardOpenReply::Rejected(CipConnectionFailure {
+                triad: CipConnectionTriad {
+                    connection_serial: 0x2A,
+                    originator_vendor_id: 1,
+                    originator_serial: 0xDEAD_BEEF,
+                },
+                remaining_path_size: Some(0),
+            })))
+        );
+    }
+
+    #[test]
+    fn decode_forward_close() {
+        let bytes = hex::decode("4e02200624010af92a000100efbeadde0300010020022401")
+            .expect("invalid hex fixture");
+        let request = CipRequest::parse(&bytes).expect("valid request");
+        let close = request
+            .forward_close()
+            .expect("forward close")
+            .expect("valid forward close");
+        assert_eq!(close.triad.originator_serial, 0xDEAD_BEEF);
+        assert_eq!(close.connection_path.class(), Some(0x02));
+
+        let bytes = hex::decode("ce0000002a000100efbeadde0000").expect("invalid hex fixture");
+        let CipMessage::Response(response) = CipMessage::parse(&bytes).expect("valid response")
+        else {
+            panic!("expected a response");
+        };
+        assert!(matches!(
+            response.forward_close(),
+            Some(Ok(CipForwardCloseReply::Closed { .. }))
+        ));
+    }
+}
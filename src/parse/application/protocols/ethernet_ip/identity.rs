@@ -0,0 +1,189 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! ListIdentity responses.
+//!
+//! Scanners broadcast ListIdentity to inventory EtherNet/IP devices; each
+//! device answers with a CIP Identity item (0x000C) giving its vendor,
+//! product, revision, serial number and the socket address it listens on.
+//! The socket address is the only big-endian field of the item.
+
+use std::net::{Ipv4Addr, SocketAddrV4};
+
+use super::{EtherNetIpCommand, EtherNetIpCommandData, EtherNetIpPacket};
+use crate::{
+    checks::application::ethernet_ip::{
+        CPF_ITEM_LIST_IDENTITY, cip_device_type_name, extract_cip_bytes, extract_cip_u8,
+        extract_cip_u16, extract_cip_u32, extract_cpf_item, validate_cip_no_trailing_data,
+        validate_cpf_consumed,
+    },
+    errors::application::ethernet_ip::CipError,
+};
+
+/// CIP Identity item of a ListIdentity response.
+///
+/// ```mermaid
+/// ---
+/// title: CipIdentity
+/// ---
+/// packet-beta
+/// 0-15: "Encapsulation Version u16 LE"
+/// 16-31: "sin_family u16 BE"
+/// 32-47: "sin_port u16 BE"
+/// 48-79: "sin_addr u32 BE"
+/// 80-143: "sin_zero bytes[8]"
+/// 144-159: "Vendor ID u16 LE"
+/// 160-175: "Device Type u16 LE"
+/// 176-191: "Product Code u16 LE"
+/// 192-199: "Revision Major"
+/// 200-207: "Revision Minor"
+/// 208-223: "Status u16 LE"
+/// 224-255: "Serial Number u32 LE"
+/// 256-263: "Name Length"
+/// 264-295: "Product Name variable"
+/// 296-303: "State"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CipIdentity<'a> {
+    pub encapsulation_version: u16,
+    pub socket_address: SocketAddrV4,
+    pub vendor_id: u16,
+    pub device_type: u16,
+    pub product_code: u16,
+    pub revision_major: u8,
+    pub revision_minor: u8,
+    pub status: u16,
+    pub serial_number: u32,
+    pub product_name: &'a [u8],
+    pub state: u8,
+}
+
+impl<'a> CipIdentity<'a> {
+    pub fn parse(data: &'a [u8]) -> Result<Self, CipError> {
+        let (encapsulation_version, rest) = extract_cip_u16(data, "encapsulation version")?;
+        let (socket, rest) = extract_cip_bytes(rest, 16, "socket address")?;
+        let (vendor_id, rest) = extract_cip_u16(rest, "vendor id")?;
+        let (device_type, rest) = extract_cip_u16(rest, "device type")?;
+        let (product_code, rest) = extract_cip_u16(rest, "product code")?;
+        let (revision_major, rest) = extract_cip_u8(rest, "revision")?;
+        let (revision_minor, rest) = extract_cip_u8(rest, "revision")?;
+        let (status, rest) = extract_cip_u16(rest, "status")?;
+        let (serial_number, rest) = extract_cip_u32(rest, "serial number")?;
+        let (name_length, rest) = extract_cip_u8(rest, "product name")?;
+        let (product_name, rest) =
+            extract_cip_bytes(rest, usize::from(name_length), "product name")?;
+        let (state, rest) = extract_cip_u8(rest, "state")?;
+        validate_cip_no_trailing_data(rest, "state")?;
+
+        // sockaddr_in : famille et port en ordre reseau, puis 8 octets nuls.
+        let port = u16::from_be_bytes([socket[2], socket[3]]);
+        let address = Ipv4Addr::new(socket[4], socket[5], socket[6], socket[7]);
+
+        Ok(CipIdentity {
+            encapsulation_version,
+            socket_address: SocketAddrV4::new(address, port),
+            vendor_id,
+            device_type,
+            product_code,
+            revision_major,
+            revision_minor,
+            status,
+            serial_number,
+            product_name,
+            state,
+        })
+    }
+
+    pub fn product_name_str(&self) -> Option<&'a str> {
+        std::str::from_utf8(self.product_name).ok()
+    }
+
+    pub fn device_type_name(&self) -> &'static str {
+        cip_device_type_name(self.device_type)
+    }
+}
+
+impl<'a> EtherNetIpPacket<'a> {
+    /// Identity items of a ListIdentity response, `None` for other commands
+    /// and for the (empty) request.
+    pub fn list_identity(&self) -> Option<Result<Vec<CipIdentity<'a>>, CipError>> {
+        if self.header.command != EtherNetIpCommand::ListIdentity {
+            return None;
+        }
+        let EtherNetIpCommandData::Raw(data) = self.command_data else {
+            return None;
+        };
+        Some(parse_list_identity(data))
+    }
+}
+
+/// Nombre d'items (u16) puis items CPF ; les items d'un autre type que
+/// Identity sont ignores.
+fn parse_list_identity(data: &[u8]) -> Result<Vec<CipIdentity<'_>>, CipError> {
+    let (item_count, _) = extract_cip_u16(data, "item count")?;
+    let mut offset = 2;
+    let mut identities = Vec::new();
+    for _ in 0..item_count {
+        let (type_id, item, end) = extract_cpf_item(data, offset)?;
+        if type_id == CPF_ITEM_LIST_IDENTITY {
+            identities.push(CipIdentity::parse(item)?);
+        }
+        offset = end;
+    }
+    validate_cpf_consumed(offset, data.len())?;
+    Ok(identities)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::application::ethernet_ip::EtherNetIpError;
+
+    /// Synthetique : adaptateur de communication 192.168.1.20:44818,
+    /// produit "ENBT".
+    const IDENTITY_ITEM_HEX: &str = concat!(
+        "0100",
+        "0002af12c0a80114",
+        "0000000000000000",
+        "01000c0017000b02",
+        "6000",
+        "78563412",
+        "04454e4254",
+        "03"
+    );
+
+    #[test]
+    fn decode_identity_item() {
+        let bytes = hex::decode(IDENTITY_ITEM_HEX).expect("invalid hex fixture");
+        let identity = CipIdentity::parse(&bytes).expect("valid identity");
+        assert_eq!(
+            identity.socket_address,
+            SocketAddrV4::new(Ipv4Addr::new(192, 168, 1, 20), 44818)
+        );
+        assert_eq!(identity.vendor_id, 1);
+        assert_eq!(identity.device_type_name(), "Communications Adapter");
+        assert_eq!(identity.product_code, 0x17);
+        assert_eq!((identity.revision_major, identity.revision_minor), (11, 2));
+        assert_eq!(identity.status, 0x0060);
+        assert_eq!(identity.serial_number, 0x1234_5678);
+        assert_eq!(identity.product_name_str(), Some("ENBT"));
+        assert_eq!(identity.state, 3);
+
+        assert_eq!(
+            CipIdentity::parse(&bytes[..bytes.len() - 1]),
+            Err(CipError::Truncated { field: "state" })
+        );
+    }
+
+    #[test]
+    fn list_identity_rejects_items_overrunning_the_body() {
+        // Un item annonce 4 octets mais n'en porte que 2.
+        assert!(matches!(
+            parse_list_identity(&[0x01, 0x00, 0x0C, 0x00, 0x04, 0x00, 0x01, 0x00]),
+            Err(CipError::Encapsulation(EtherNetIpError::Truncated { .. }))
+        ));
+        assert_eq!(parse_list_identity(&[0x00, 0x00]), Ok(Vec::new()));
+    }
+}
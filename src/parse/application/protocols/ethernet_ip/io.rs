@@ -0,0 +1,251 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Implicit (class 0/1) I/O over UDP 2222.
+//!
+//! Cyclic I/O data skips the encapsulation header: the UDP payload is a bare
+//! Common Packet Format whose Sequenced Address item (0x8002) names the
+//! connection opened by a Forward Open and numbers the packet, followed by a
+//! Connected Data item (0x00B1) with the I/O image. The 32-bit encapsulation
+//! sequence number increases by one per packet produced, so a jump reveals
+//! lost packets and a step back a replayed or reordered one.
+//! [`CipIoSequenceTracker`] follows it per connection.
+
+use std::collections::VecDeque;
+
+use crate::{
+    checks::application::ethernet_ip::{
+        CIP_MAX_IO_CONNECTIONS, CPF_ITEM_CONNECTED_DATA, CPF_ITEM_SEQUENCED_ADDRESS,
+        extract_cip_u16, extract_cip_u32, extract_cpf_item, validate_cip_no_trailing_data,
+        validate_cpf_consumed,
+    },
+    errors::application::ethernet_ip::CipError,
+};
+
+/// Implicit I/O packet.
+///
+/// ```mermaid
+/// ---
+/// title: CipIoPacket
+/// ---
+/// packet-beta
+/// 0-15: "Item Count u16 LE"
+/// 16-31: "Type 0x8002 u16 LE"
+/// 32-47: "Length 8 u16 LE"
+/// 48-79: "Connection ID u32 LE"
+/// 80-111: "Sequence Number u32 LE"
+/// 112-127: "Type 0x00B1 u16 LE"
+/// 128-143: "Length u16 LE"
+/// 144-175: "I/O Data variable"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CipIoPacket<'a> {
+    pub connection_id: u32,
+    /// Encapsulation sequence number of the Sequenced Address item.
+    pub sequence_number: u32,
+    /// Connected Data item. Class 1 connections start it with a 16-bit
+    /// sequence count, class 0 connections do not: the transport class is
+    /// only known from the Forward Open.
+    pub data: &'a [u8],
+}
+
+impl<'a> CipIoPacket<'a> {
+    pub fn parse(payload: &'a [u8]) -> Result<Self, CipError> {
+        let (item_count, _) = extract_cip_u16(payload, "item count")?;
+        let mut offset = 2;
+        let mut address = None;
+        let mut data = None;
+        for _ in 0..item_count {
+            let (type_id, item, end) = extract_cpf_item(payload, offset)?;
+            match type_id {
+                CPF_ITEM_SEQUENCED_ADDRESS => address = Some(item),
+                CPF_ITEM_CONNECTED_DATA => data = Some(item),
+                _ => {}
+            }
+            offset = end;
+        }
+        validate_cpf_consumed(offset, payload.len())?;
+
+        let address = address.ok_or(CipError::MissingItem {
+            type_id: CPF_ITEM_SEQUENCED_ADDRESS,
+        })?;
+        let data = data.ok_or(CipError::MissingItem {
+            type_id: CPF_ITEM_CONNECTED_DATA,
+        })?;
+        let (connection_id, rest) = extract_cip_u32(address, "connection id")?;
+        let (sequence_number, rest) = extract_cip_u32(rest, "sequence number")?;
+        validate_cip_no_trailing_data(rest, "sequence number")?;
+
+        Ok(CipIoPacket {
+            connection_id,
+            sequence_number,
+            data,
+        })
+    }
+
+    /// Class 1 sequence count, bumped only when the I/O data changes.
+    pub fn class1_sequence_count(&self) -> Option<u16> {
+        extract_cip_u16(self.data, "sequence count")
+            .ok()
+            .map(|(count, _)| count)
+    }
+
+    /// Class 1 I/O data, after the sequence count.
+    pub fn class1_data(&self) -> Option<&'a [u8]> {
+        self.data.get(2..)
+    }
+}
+
+/// Position of a packet in the sequence of its connection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CipIoSequenceEvent {
+    /// First packet seen on this connection.
+    First,
+    InOrder,
+    /// Same sequence number as the previous packet.
+    Duplicate,
+    /// `missing` packets were skipped since the previous one.
+    Gap {
+        missing: u32,
+    },
+    /// Older than the previous packet: reordered or replayed. The
+    /// connection state is left unchanged.
+    Stale,
+}
+
+/// Sequence follow-up of the implicit I/O connections of a capture.
+///
+/// Sequence numbers wrap around; a number up to 2^31 ahead of the last one
+/// counts as newer. After [`CIP_MAX_IO_CONNECTIONS`] connections the least
+/// recently seen one is forgotten.
+#[derive(Debug, Default)]
+pub struct CipIoSequenceTracker {
+    connections: VecDeque<(u32, u32)>,
+}
+
+impl CipIoSequenceTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, packet: &CipIoPacket<'_>) -> CipIoSequenceEvent {
+        let position = self
+            .connections
+            .iter()
+            .position(|(connection_id, _)| *connection_id == packet.connection_id);
+        let Some(position) = position else {
+            if self.connections.len() >= CIP_MAX_IO_CONNECTIONS {
+                self.connections.pop_front();
+            }
+            self.connections
+                .push_back((packet.connection_id, packet.sequence_number));
+            return CipIoSequenceEvent::First;
+        };
+
+        let (connection_id, last) = self.connections[position];
+        let delta = packet.sequence_number.wrapping_sub(last);
+        let event = match delta {
+            0 => CipIoSequenceEvent::Duplicate,
+            1 => CipIoSequenceEvent::InOrder,
+            delta if delta < 1 << 31 => CipIoSequenceEvent::Gap { missing: delta - 1 },
+            _ => CipIoSequenceEvent::Stale,
+        };
+        // La connexion passe en fin de file : l'eviction vise la moins
+        // recemment vue.
+        self.connections.remove(position);
+        let last = if event == CipIoSequenceEvent::Stale {
+            last
+        } else {
+            packet.sequence_number
+        };
+        self.connections.push_back((connection_id, last));
+        event
+    }
+
+    /// Last sequence number seen on a connection.
+    pub fn last_sequence(&self, connection_id: u32) -> Option<u32> {
+        self.connections
+            .iter()
+            .find(|(id, _)| *id == connection_id)
+            .map(|(_, sequence)| *sequence)
+    }
+
+    pub fn connections(&self) -> usize {
+        self.connections.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn io_packet(connection_id: u32, sequence_number: u32) -> Vec<u8> {
+        let mut payload = vec![0x02, 0x00, 0x02, 0x80, 0x08, 0x00];
+        payload.extend_from_slice(&connection_id.to_le_bytes());
+        payload.extend_from_slice(&sequence_number.to_le_bytes());
+        payload.extend_from_slice(&[0xB1, 0x00, 0x06, 0x00, 0x2A, 0x00, 0x01, 0x00, 0x00, 0x00]);
+        payload
+    }
+
+    #[test]
+    fn decode_class1_io_packet() {
+        // Synthetique : connexion 0x00FE0001, sequence 7, compteur classe 1
+        // 42 puis en-tete run/idle a 1.
+        let bytes = io_packet(0x00FE_0001, 7);
+        let packet = CipIoPacket::parse(&bytes).expect("valid I/O packet");
+        assert_eq!(packet.connection_id, 0x00FE_0001);
+        assert_eq!(packet.sequence_number, 7);
+        assert_eq!(packet.class1_sequence_count(), Some(42));
+        assert_eq!(packet.class1_data(), Some(&[0x01, 0x00, 0x00, 0x00][..]));
+
+        assert_eq!(
+            CipIoPacket::parse(&[0x01, 0x00, 0xB1, 0x00, 0x00, 0x00]),
+            Err(CipError::MissingItem { type_id: 0x8002 })
+        );
+    }
+
+    #[test]
+    fn track_sequence_numbers_per_connection() {
+        let mut tracker = CipIoSequenceTracker::new();
+        let mut push = |connection_id, sequence| {
+            let bytes = io_packet(connection_id, sequence);
+            tracker.push(&CipIoPacket::parse(&bytes).expect("valid I/O packet"))
+        };
+
+        assert_eq!(push(1, 10), CipIoSequenceEvent::First);
+        assert_eq!(push(1, 11), CipIoSequenceEvent::InOrder);
+        assert_eq!(push(2, 500), CipIoSequenceEvent::First);
+        assert_eq!(push(1, 11), CipIoSequenceEvent::Duplicate);
+        assert_eq!(push(1, 15), CipIoSequenceEvent::Gap { missing: 3 });
+        assert_eq!(push(1, 12), CipIoSequenceEvent::Stale);
+        assert_eq!(push(1, 16), CipIoSequenceEvent::InOrder);
+        // Rebouclage du compteur 32 bits.
+        assert_eq!(push(2, u32::MAX), CipIoSequenceEvent::Stale);
+        assert_eq!(push(3, u32::MAX), CipIoSequenceEvent::First);
+        assert_eq!(push(3, 0), CipIoSequenceEvent::InOrder);
+
+        assert_eq!(tracker.last_sequence(1), Some(16));
+        assert_eq!(tracker.last_sequence(2), Some(500));
+        assert_eq!(tracker.connections(), 3);
+    }
+
+    #[test]
+    fn tracker_forgets_least_recently_seen_connection() {
+        let mut tracker = CipIoSequenceTracker::new();
+        for connection_id in 0..CIP_MAX_IO_CONNECTIONS as u32 {
+            let bytes = io_packet(connection_id, 0);
+            tracker.push(&CipIoPacket::parse(&bytes).expect("valid I/O packet"));
+        }
+        // La connexion 0 est revue : c'est la 1 qui sera evincee.
+        let bytes = io_packet(0, 1);
+        tracker.push(&CipIoPacket::parse(&bytes).expect("valid I/O packet"));
+        let bytes = io_packet(u32::MAX, 0);
+        tracker.push(&CipIoPacket::parse(&bytes).expect("valid I/O packet"));
+
+        assert_eq!(tracker.connections(), CIP_MAX_IO_CONNECTIONS);
+        assert_eq!(tracker.last_sequence(0), Some(1));
+        assert_eq!(tracker.last_sequence(1), None);
+    }
+}
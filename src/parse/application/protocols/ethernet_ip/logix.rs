@@ -0,0 +1,275 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Logix tag services (Rockwell ControlLogix/CompactLogix).
+//!
+//! Logix controllers address their data by tag name through symbolic EPATH
+//! segments, or by instance of the Symbol class (0x6B), and reuse service
+//! codes that mean something else on other classes. [`CipRequest::logix_tag`]
+//! therefore only decodes requests whose path designates a tag. Read Tag
+//! replies carry the tag type ahead of the value; the tag name is only in
+//! the request.
+
+use super::cip::{CipRequest, CipResponse};
+use crate::{
+    checks::application::ethernet_ip::{
+        CIP_CLASS_SYMBOL, CIP_DATA_TYPE_STRUCTURE, CIP_SERVICE_READ_MODIFY_WRITE_TAG,
+        CIP_SERVICE_READ_TAG, CIP_SERVICE_READ_TAG_FRAGMENTED, CIP_SERVICE_WRITE_TAG,
+        CIP_SERVICE_WRITE_TAG_FRAGMENTED, cip_data_type_name, extract_cip_bytes, extract_cip_u16,
+        extract_cip_u32, validate_cip_no_trailing_data,
+    },
+    errors::application::ethernet_ip::CipError,
+};
+
+/// Typed tag value: Logix data type, then the raw little-endian elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogixTagValue<'a> {
+    pub data_type: u16,
+    /// Structure handle following the `STRUCT` (0x02A0) data type.
+    pub structure_handle: Option<u16>,
+    pub data: &'a [u8],
+}
+
+impl<'a> LogixTagValue<'a> {
+    pub fn data_type_name(&self) -> &'static str {
+        cip_data_type_name(self.data_type)
+    }
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogixTagRequest<'a> {
+    Read {
+        elements: u16,
+    },
+    ReadFragmented {
+        elements: u16,
+        offset: u32,
+    },
+    Write {
+        elements: u16,
+        value: LogixTagValue<'a>,
+    },
+    WriteFragmented {
+        elements: u16,
+        offset: u32,
+        value: LogixTagValue<'a>,
+    },
+    /// Atomic bit update: `new = (old | or_mask) & and_mask`.
+    ReadModifyWrite {
+        or_mask: &'a [u8],
+        and_mask: &'a [u8],
+    },
+}
+
+impl<'a> LogixTagRequest<'a> {
+    pub fn is_write(&self) -> bool {
+        matches!(
+            self,
+            LogixTagRequest::Write { .. }
+                | LogixTagRequest::WriteFragmented { .. }
+                | LogixTagRequest::ReadModifyWrite { .. }
+        )
+    }
+}
+
+impl<'a> CipRequest<'a> {
+    /// Tag service of a request addressed to a Logix tag, `None` when the
+    /// path is neither symbolic nor a Symbol class instance, or the service
+    /// is not a tag service.
+    pub fn logix_tag(&self) -> Option<Result<LogixTagRequest<'a>, CipError>> {
+        if !self.path.is_symbolic() && self.path.class() != Some(CIP_CLASS_SYMBOL) {
+            return None;
+        }
+        let data = self.data;
+        let request = match self.service {
+            CIP_SERVICE_READ_TAG => parse_read(data),
+            CIP_SERVICE_READ_TAG_FRAGMENTED => parse_read_fragmented(data),
+            CIP_SERVICE_WRITE_TAG => parse_write(data),
+            CIP_SERVICE_WRITE_TAG_FRAGMENTED => parse_write_fragmented(data),
+            CIP_SERVICE_READ_MODIFY_WRITE_TAG => parse_read_modify_write(data),
+            _ => return None,
+        };
+        Some(request)
+    }
+}
+
+impl<'a> CipResponse<'a> {
+    /// Value returned by a successful Read Tag or Read Tag Fragmented. The
+    /// general status 0x06 (partial transfer) of a fragmented read still
+    /// carries a value. The caller checks that the request was a tag read:
+    /// 0xD2 also answers an Unconnected Send.
+    pub fn logix_tag_value(&self) -> Option<Result<LogixTagValue<'a>, CipError>> {
+        let service = self.request_service();
+        if (service != CIP_SERVICE_READ_TAG && service != CIP_SERVICE_READ_TAG_FRAGMENTED)
+            || !matches!(self.general_status, 0x00 | 0x06)
+        {
+            return None;
+        }
+        Some(
+            extract_tag_type(self.data).map(|(data_type, structure_handle, data)| LogixTagValue {
+                data_type,
+                structure_handle,
+                data,
+            }),
+        )
+    }
+}
+
+fn parse_read(data: &[u8]) -> Result<LogixTagRequest<'_>, CipError> {
+    let (elements, rest) = extract_cip_u16(data, "element count")?;
+    validate_cip_no_trailing_data(rest, "element count")?;
+    Ok(LogixTagRequest::Read { elements })
+}
+
+fn parse_read_fragmented(data: &[u8]) -> Result<LogixTagRequest<'_>, CipError> {
+    let (elements, rest) = extract_cip_u16(data, "element count")?;
+    let (offset, rest) = extract_cip_u32(rest, "byte offset")?;
+    validate_cip_no_trailing_data(rest, "byte offset")?;
+    Ok(LogixTagRequest::ReadFragmented { elements, offset })
+}
+
+fn parse_write(data: &[u8]) -> Result<LogixTagRequest<'_>, CipError> {
+    let (data_type, structure_handle, rest) = extract_tag_type(data)?;
+    let (elements, data) = extract_cip_u16(rest, "element count")?;
+    Ok(LogixTagRequest::Write {
+        elements,
+        value: LogixTagValue {
+            data_type,
+            structure_handle,
+            data,
+        },
+    })
+}
+
+fn parse_write_fragmented(data: &[u8]) -> Result<LogixTagRequest<'_>, CipError> {
+    let (data_type, structure_handle, rest) = extract_tag_type(data)?;
+    let (elements, rest) = extract_cip_u16(rest, "element count")?;
+    let (offset, data) = extract_cip_u32(rest, "byte offset")?;
+    Ok(LogixTagRequest::WriteFragmented {
+        elements,
+        offset,
+        value: LogixTagValue {
+            data_type,
+            structure_handle,
+            data,
+        },
+    })
+}
+
+/// Taille des masques (u16) puis masque OR et masque AND de cette taille.
+fn parse_read_modify_write(data: &[u8]) -> Result<LogixTagRequest<'_>, CipError> {
+    let (mask_size, rest) = extract_cip_u16(data, "mask size")?;
+    let (or_mask, rest) = extract_cip_bytes(rest, usize::from(mask_size), "or mask")?;
+    let (and_mask, rest) = extract_cip_bytes(rest, usize::from(mask_size), "and mask")?;
+    validate_cip_no_trailing_data(rest, "and mask")?;
+    Ok(LogixTagRequest::ReadModifyWrite { or_mask, and_mask })
+}
+
+fn extract_tag_type(data: &[u8]) -> Result<(u16, Option<u16>, &[u8]), CipError> {
+    let (data_type, rest) = extract_cip_u16(data, "data type")?;
+    if data_type == CIP_DATA_TYPE_STRUCTURE {
+        let (handle, rest) = extract_cip_u16(rest, "structure handle")?;
+        return Ok((data_type, Some(handle), rest));
+    }
+    Ok((data_type, None, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::application::protocols::ethernet_ip::cip::CipMessage;
+
+    #[test]
+    fn decode_read_and_write_tag_requests() {
+        // Synthetique : Read Tag "Counter", 1 element.
+        let bytes = hex::decode("4c059107436f756e746572000100").expect("invalid hex fixture");
+        let read = CipRequest::parse(&bytes).expect("valid request");
+        assert_eq!(
+            read.logix_tag(),
+            Some(Ok(LogixTagRequest::Read { elements: 1 }))
+        );
+
+        // Synthetique : Write Tag "Speed[4]" en DINT, valeur 1500.
+        let bytes =
+            hex::decode("4d0591055370656564002804c4000100dc050000").expect("invalid hex fixture");
+        let write = CipRequest::parse(&bytes).expect("valid request");
+        assert_eq!(write.path.symbol().as_deref(), Some("Speed[4]"));
+        let Some(Ok(request)) = write.logix_tag() else {
+            panic!("expected a tag write");
+        };
+        assert!(request.is_write());
+        let LogixTagRequest::Write { elements, value } = request else {
+            panic!("expected Write Tag");
+        };
+        assert_eq!(elements, 1);
+        assert_eq!(value.data_type_name(), "DINT");
+        assert_eq!(value.data, &1500_i32.to_le_bytes());
+    }
+
+    #[test]
+    fn decode_fragmented_and_masked_requests() {
+        // Synthetique : Write Tag Fragmented d'une structure (handle 0x0FCE)
+        // sur l'instance 0x1234 de la classe Symbol, offset 480.
+        let bytes =
+            hex::decode("5303206b25003412a002ce0f0a00e0010000aabb").expect("invalid hex fixture");
+        let write = CipRequest::parse(&bytes).expect("valid request");
+        assert_eq!(
+            write.logix_tag(),
+            Some(Ok(LogixTagRequest::WriteFragmented {
+                elements: 10,
+                offset: 480,
+                value: LogixTagValue {
+                    data_type: 0x02A0,
+                    structure_handle: Some(0x0FCE),
+                    data: &[0xAA, 0xBB],
+                },
+            }))
+        );
+
+        // Synthetique : Read Modify Write Tag sur "Flags", bit 3 force a 1.
+        let bytes = hex::decode("4e049105466c6167730002000800ffff").expect("invalid hex fixture");
+        let masked = CipRequest::parse(&bytes).expect("valid request");
+        assert_eq!(
+            masked.logix_tag(),
+            Some(Ok(LogixTagRequest::ReadModifyWrite {
+                or_mask: &[0x08, 0x00],
+                and_mask: &[0xFF, 0xFF],
+            }))
+        );
+    }
+
+    #[test]
+    fn service_on_other_class_is_not_a_tag_service() {
+        // 0x4E sur le Connection Manager est un Forward Close.
+        let close =
+            CipRequest::parse(&[0x4E, 0x02, 0x20, 0x06, 0x24, 0x01]).expect("valid request");
+        assert_eq!(close.logix_tag(), None);
+    }
+
+    #[test]
+    fn decode_read_tag_response_value() {
+        // Synthetique : reponse Read Tag, REAL 1.5.
+        let bytes = hex::decode("cc000000ca000000c03f").expect("invalid hex fixture");
+        let CipMessage::Response(response) = CipMessage::parse(&bytes).expect("valid response")
+        else {
+            panic!("expected a response");
+        };
+        let value = response
+            .logix_tag_value()
+            .expect("read tag reply")
+            .expect("valid value");
+        assert_eq!(value.data_type_name(), "REAL");
+        assert_eq!(value.data, &1.5_f32.to_le_bytes());
+
+        // Erreur 0x04 (path segment error) : pas de valeur.
+        let CipMessage::Response(error) =
+            CipMessage::parse(&[0xCC, 0x00, 0x04, 0x01, 0x00, 0x00]).expect("valid response")
+        else {
+            panic!("expected a response");
+        };
+        assert_eq!(error.logix_tag_value(), None);
+    }
+}
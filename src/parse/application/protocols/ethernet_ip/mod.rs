@@ -18,6 +18,12 @@ use crate::{
     parse::application::protocols::bounded_capacity,
 };
 
+pub mod cip;
+pub mod connection;
+pub mod identity;
+pub mod io;
+pub mod logix;
+
 /// EtherNet/IP Encapsulation Packet
 ///
 /// ```mermaid
@@ -0,0 +1,79 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Client and server fingerprints shared by several protocol parsers.
+//!
+//! The fingerprints themselves are methods of the decoded messages:
+//!
+//! - JA3/JA3S (Salesforce) and JA4/JA4S (FoxIO) on
+//!   [`TlsClientHello`](super::tls::handshake::TlsClientHello) and
+//!   [`TlsServerHello`](super::tls::handshake::TlsServerHello);
+//! - JA4H on [`HttpRequest`](super::http::HttpRequest);
+//! - HASSH/HASSHServer on [`SshKexInit`](super::ssh::SshKexInit).
+//!
+//! This module only holds the hashing conventions they share.
+
+use md5::{Digest, Md5};
+use sha2::Sha256;
+
+/// Transport prefix of a JA4/JA4S fingerprint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Ja4Protocol {
+    /// TLS over TCP (`t`).
+    #[default]
+    Tcp,
+    /// TLS carried by QUIC CRYPTO frames (`q`).
+    Quic,
+    /// DTLS (`d`).
+    Dtls,
+}
+
+impl Ja4Protocol {
+    pub fn prefix(self) -> char {
+        match self {
+            Self::Tcp => 't',
+            Self::Quic => 'q',
+            Self::Dtls => 'd',
+        }
+    }
+}
+
+/// Valeur d'une section JA4 sans element a hacher.
+pub(crate) const JA4_EMPTY_HASH: &str = "000000000000";
+
+/// MD5 en hexadecimal minuscule, forme des empreintes JA3 et HASSH.
+pub(crate) fn md5_hex(input: &str) -> String {
+    hex::encode(Md5::digest(input.as_bytes()))
+}
+
+/// Section hachee d'une empreinte JA4 : 12 premiers caracteres hexadecimaux
+/// du SHA-256, ou des zeros quand la liste source est vide.
+pub(crate) fn ja4_hash(input: &str) -> String {
+    if input.is_empty() {
+        return JA4_EMPTY_HASH.to_string();
+    }
+    let mut digest = hex::encode(Sha256::digest(input.as_bytes()));
+    digest.truncate(12);
+    digest
+}
+
+/// Compteur JA4 sur deux chiffres, plafonne a 99.
+pub(crate) fn ja4_count(count: usize) -> String {
+    format!("{:02}", count.min(99))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hashes_follow_the_reference_conventions() {
+        assert_eq!(md5_hex(""), "d41d8cd98f00b204e9800998ecf8427e");
+        assert_eq!(ja4_hash("abc"), "ba7816bf8f01");
+        assert_eq!(ja4_hash(""), JA4_EMPTY_HASH);
+        assert_eq!(ja4_count(7), "07");
+        assert_eq!(ja4_count(140), "99");
+    }
+}
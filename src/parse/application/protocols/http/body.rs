@@ -0,0 +1,235 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Body framing of HTTP/1.x messages (RFC 9112 §6 and §7.1).
+
+use crate::{
+    checks::application::http::{
+        parse_chunk_size, parse_content_length, validate_chunk_terminator,
+    },
+    errors::application::http::HttpMessageError,
+};
+
+/// How the end of a message body is found (RFC 9112 §6.3).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpBodyLength {
+    /// No body: request without framing fields, response to HEAD, 1xx, 204,
+    /// 304 or a successful CONNECT.
+    Empty,
+    /// `Content-Length` bytes follow the head.
+    ContentLength(usize),
+    /// `Transfer-Encoding` ending with `chunked`.
+    Chunked,
+    /// Response without framing: the body runs until the server closes the
+    /// connection.
+    UntilClose,
+}
+
+// Dernier codage de transfert annonce, tous champs Transfer-Encoding
+// confondus : seul lui indique si le message se termine en chunked.
+fn final_transfer_coding<'a>(headers: &[(&'a str, &'a str)]) -> Option<&'a str> {
+    headers
+        .iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case("Transfer-Encoding"))
+        .flat_map(|&(_, value)| value.split(','))
+        .map(str::trim)
+        .rfind(|coding| !coding.is_empty())
+}
+
+/// `Content-Length` of the message, all fields of that name agreeing.
+pub(crate) fn content_length(headers: &[(&str, &str)]) -> Result<Option<usize>, HttpMessageError> {
+    let mut length = None;
+    for &(_, value) in headers
+        .iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case("Content-Length"))
+    {
+        let parsed = parse_content_length(value)?;
+        match length {
+            Some(first) if first != parsed => {
+                return Err(HttpMessageError::ConflictingContentLength {
+                    first,
+                    second: parsed,
+                });
+            }
+            _ => length = Some(parsed),
+        }
+    }
+    Ok(length)
+}
+
+/// Framing of a request body. A transfer coding other than a final
+/// `chunked` cannot be delimited and is refused (RFC 9112 §6.3, item 4).
+pub(crate) fn request_body_length(
+    headers: &[(&str, &str)],
+) -> Result<HttpBodyLength, HttpMessageError> {
+    match final_transfer_coding(headers) {
+        Some(coding) if coding.eq_ignore_ascii_case("chunked") => Ok(HttpBodyLength::Chunked),
+        Some(coding) => Err(HttpMessageError::UnsupportedTransferCoding(
+            coding.to_string(),
+        )),
+        None => Ok(match content_length(headers)? {
+            Some(length) => HttpBodyLength::ContentLength(length),
+            None => HttpBodyLength::Empty,
+        }),
+    }
+}
+
+/// Framing of a response body, which depends on the request method.
+pub(crate) fn response_body_length(
+    headers: &[(&str, &str)],
+    status_code: u16,
+    request_method: Option<&str>,
+) -> Result<HttpBodyLength, HttpMessageError> {
+    let bodiless_status = matches!(status_code, 100..=199 | 204 | 304);
+    let tunnel = request_method == Some("CONNECT") && (200..=299).contains(&status_code);
+    if bodiless_status || tunnel || request_method == Some("HEAD") {
+        return Ok(HttpBodyLength::Empty);
+    }
+    // Transfer-Encoding l'emporte sur Content-Length (RFC 9112 §6.3, 3).
+    match final_transfer_coding(headers) {
+        Some(coding) if coding.eq_ignore_ascii_case("chunked") => Ok(HttpBodyLength::Chunked),
+        Some(_) => Ok(HttpBodyLength::UntilClose),
+        None => Ok(match content_length(headers)? {
+            Some(length) => HttpBodyLength::ContentLength(length),
+            None => HttpBodyLength::UntilClose,
+        }),
+    }
+}
+
+// Ligne terminee par CRLF a partir de `offset` : (ligne, offset suivant).
+fn chunk_line(body: &[u8], offset: usize) -> Result<(&[u8], usize), HttpMessageError> {
+    let rest = body
+        .get(offset..)
+        .ok_or(HttpMessageError::IncompleteChunkedBody)?;
+    let end = rest
+        .windows(2)
+        .position(|window| window == b"\r\n")
+        .ok_or(HttpMessageError::IncompleteChunkedBody)?;
+    Ok((&rest[..end], offset + end + 2))
+}
+
+/// Decodes a complete chunked body (RFC 9112 §7.1).
+///
+/// Returns the concatenated chunk data and the number of bytes consumed,
+/// trailer section included: whatever follows belongs to the next message.
+/// Chunk extensions and trailer fields are skipped.
+pub fn decode_chunked(body: &[u8]) -> Result<(Vec<u8>, usize), HttpMessageError> {
+    let mut decoded = Vec::new();
+    let mut offset = 0;
+    loop {
+        let (line, after_line) = chunk_line(body, offset)?;
+        let size = parse_chunk_size(line)?;
+        if size == 0 {
+            offset = after_line;
+            break;
+        }
+        let data_end = after_line
+            .checked_add(size)
+            .filter(|&end| end <= body.len())
+            .ok_or(HttpMessageError::IncompleteChunkedBody)?;
+        decoded.extend_from_slice(&body[after_line..data_end]);
+        let terminator = body
+            .get(data_end..data_end + 2)
+            .ok_or(HttpMessageError::IncompleteChunkedBody)?;
+        validate_chunk_terminator(terminator)?;
+        offset = data_end + 2;
+    }
+    loop {
+        let (line, after_line) = chunk_line(body, offset)?;
+        offset = after_line;
+        if line.is_empty() {
+            return Ok((decoded, offset));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunked_body_decodes_and_stops_after_trailers() {
+        // Synthetique : exemple de RFC 9112 §7.1 enrichi d'une extension et
+        // d'un trailer, suivi du debut d'une reponse pipelinee.
+        let body = b"4;name=value\r\nWiki\r\n5\r\npedia\r\nE\r\n in\r\n\r\nchunks.\r\n0\r\nExpires: never\r\n\r\nHTTP/1.1";
+        let (decoded, consumed) = decode_chunked(body).unwrap();
+        assert_eq!(decoded, b"Wikipedia in\r\n\r\nchunks.");
+        assert_eq!(&body[consumed..], b"HTTP/1.1");
+    }
+
+    #[test]
+    fn malformed_chunked_bodies_are_rejected() {
+        assert_eq!(
+            decode_chunked(b"4\r\nWiki\r\n"),
+            Err(HttpMessageError::IncompleteChunkedBody)
+        );
+        assert_eq!(
+            decode_chunked(b"4\r\nWikipedia\r\n0\r\n\r\n"),
+            Err(HttpMessageError::MissingChunkTerminator)
+        );
+        assert_eq!(
+            decode_chunked(b"ffffffffffffffff\r\nx"),
+            Err(HttpMessageError::IncompleteChunkedBody)
+        );
+        assert!(matches!(
+            decode_chunked(b"zz\r\n"),
+            Err(HttpMessageError::InvalidChunkSize(_))
+        ));
+    }
+
+    #[test]
+    fn transfer_encoding_takes_precedence_over_content_length() {
+        let headers = [
+            ("Content-Length", "10"),
+            ("Transfer-Encoding", "gzip, chunked"),
+        ];
+        assert_eq!(request_body_length(&headers), Ok(HttpBodyLength::Chunked));
+        assert_eq!(
+            response_body_length(&headers, 200, Some("GET")),
+            Ok(HttpBodyLength::Chunked)
+        );
+
+        let gzip_only = [
+            ("Transfer-Encoding", "chunked"),
+            ("transfer-encoding", "gzip"),
+        ];
+        assert_eq!(
+            request_body_length(&gzip_only),
+            Err(HttpMessageError::UnsupportedTransferCoding("gzip".into()))
+        );
+        assert_eq!(
+            response_body_length(&gzip_only, 200, None),
+            Ok(HttpBodyLength::UntilClose)
+        );
+    }
+
+    #[test]
+    fn response_framing_depends_on_status_and_method() {
+        let headers = [("Content-Length", "42")];
+        assert_eq!(
+            response_body_length(&headers, 200, Some("GET")),
+            Ok(HttpBodyLength::ContentLength(42))
+        );
+        for (status, method) in [(200, "HEAD"), (204, "GET"), (304, "GET"), (200, "CONNECT")] {
+            assert_eq!(
+                response_body_length(&headers, status, Some(method)),
+                Ok(HttpBodyLength::Empty)
+            );
+        }
+        assert_eq!(
+            response_body_length(&[], 200, None),
+            Ok(HttpBodyLength::UntilClose)
+        );
+        assert_eq!(request_body_length(&[]), Ok(HttpBodyLength::Empty));
+        assert_eq!(
+            content_length(&[("Content-Length", "1"), ("content-length", "2")]),
+            Err(HttpMessageError::ConflictingContentLength {
+                first: 1,
+                second: 2
+            })
+        );
+    }
+}
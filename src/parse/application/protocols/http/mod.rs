@@ -4,6 +4,14 @@
 // This file may not be copied, modified, or distributed except according to those terms.
 
 //! Module for parsing HTTP packets.
+//!
+//! [`HttpRequest`] and [`response::HttpResponse`] decode one message from a
+//! payload; [`transaction::HttpTransactionTracker`] follows a whole TCP
+//! connection and pairs each request with its response.
+
+pub mod body;
+pub mod response;
+pub mod transaction;
 
 use std::convert::TryFrom;
 
@@ -12,8 +20,10 @@ use crate::{
         extract_header_line, parse_payload_as_utf8, require_method, require_uri, require_version,
         split_head_body,
     },
-    errors::application::http::HttpParseError,
+    errors::application::http::{HttpMessageError, HttpParseError},
+    parse::application::protocols::fingerprint::{ja4_count, ja4_hash},
 };
+use body::{HttpBodyLength, request_body_length};
 
 /// HTTP Request
 ///
@@ -93,6 +103,124 @@ pub fn parse_http_request(payload: &[u8]) -> Result<HttpRequest<'_>, HttpParseEr
     })
 }
 
+/// Value of the first header named `name` (case-insensitive).
+pub(crate) fn find_header<'a>(headers: &[(&'a str, &'a str)], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|(header, _)| header.eq_ignore_ascii_case(name))
+        .map(|&(_, value)| value)
+}
+
+impl HttpRequest<'_> {
+    /// Value of the first header named `name` (case-insensitive).
+    pub fn header(&self, name: &str) -> Option<&str> {
+        find_header(&self.headers, name)
+    }
+
+    /// How the body is delimited (RFC 9112 §6.3): `Transfer-Encoding:
+    /// chunked`, `Content-Length`, or no body at all.
+    pub fn body_length(&self) -> Result<HttpBodyLength, HttpMessageError> {
+        request_body_length(&self.headers)
+    }
+
+    /// JA4H fingerprint (FoxIO), e.g.
+    /// `ge11nn020000_818f42cc3fd7_000000000000_000000000000`.
+    pub fn ja4h(&self) -> String {
+        let (headers, cookie_names, cookies) = self.ja4h_lists();
+        format!(
+            "{}_{}_{}_{}",
+            self.ja4h_a(),
+            ja4_hash(&headers),
+            ja4_hash(&cookie_names),
+            ja4_hash(&cookies)
+        )
+    }
+
+    /// Raw JA4H (`ja4h_r`): same sections, lists left unhashed.
+    pub fn ja4h_r(&self) -> String {
+        let (headers, cookie_names, cookies) = self.ja4h_lists();
+        format!("{}_{}_{}_{}", self.ja4h_a(), headers, cookie_names, cookies)
+    }
+
+    // Section a : methode, version, cookie, referer, compteur, langue.
+    fn ja4h_a(&self) -> String {
+        let method: String = self
+            .method
+            .chars()
+            .take(2)
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        let version = match self.version {
+            "HTTP/1.0" => "10",
+            "HTTP/1.1" => "11",
+            "HTTP/2" | "HTTP/2.0" => "20",
+            "HTTP/3" | "HTTP/3.0" => "30",
+            _ => "00",
+        };
+        let cookie = if self.header("Cookie").is_some() {
+            'c'
+        } else {
+            'n'
+        };
+        let referer = if self.header("Referer").is_some() {
+            'r'
+        } else {
+            'n'
+        };
+        // Premiere langue, tirets retires, completee par des zeros.
+        let mut language: String = self
+            .header("Accept-Language")
+            .unwrap_or_default()
+            .split([',', ';'])
+            .next()
+            .unwrap_or_default()
+            .chars()
+            .filter(|&c| c != '-' && c != ' ')
+            .map(|c| c.to_ascii_lowercase())
+            .take(4)
+            .collect();
+        while language.len() < 4 {
+            language.push('0');
+        }
+
+        format!(
+            "{method}{version}{cookie}{referer}{}{language}",
+            ja4_count(self.ja4h_header_names().count())
+        )
+    }
+
+    // Noms d'en-tetes dans l'ordre du wire et casse d'origine, hors Cookie et
+    // Referer (deja signales dans la section a).
+    fn ja4h_header_names(&self) -> impl Iterator<Item = &str> {
+        self.headers.iter().map(|&(name, _)| name).filter(|name| {
+            !name.eq_ignore_ascii_case("Cookie") && !name.eq_ignore_ascii_case("Referer")
+        })
+    }
+
+    // Sections b, c et d avant hachage : les cookies sont tries pour
+    // neutraliser l'ordre choisi par le client.
+    fn ja4h_lists(&self) -> (String, String, String) {
+        let headers = self.ja4h_header_names().collect::<Vec<_>>().join(",");
+
+        let mut cookies: Vec<&str> = self
+            .headers
+            .iter()
+            .filter(|(name, _)| name.eq_ignore_ascii_case("Cookie"))
+            .flat_map(|&(_, value)| value.split(';'))
+            .map(str::trim)
+            .filter(|cookie| !cookie.is_empty())
+            .collect();
+        cookies.sort_unstable();
+        let mut names: Vec<&str> = cookies
+            .iter()
+            .map(|cookie| cookie.split('=').next().unwrap_or_default())
+            .collect();
+        names.sort_unstable();
+
+        (headers, names.join(","), cookies.join(","))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -239,4 +367,44 @@ mod tests {
         let request = HttpRequest::try_from(&http_payload[..]).expect("valid request");
         assert_eq!(request.body, "line1\r\nline2");
     }
+
+    #[test]
+    fn test_ja4h_without_cookie() {
+        // Requete de pcaps_exemple/protocols/http/simple-http-request.pcap (trame 1).
+        let request = parse_http_request(
+            b"GET / HTTP/1.1\r\nHost: example.com\r\nUser-Agent: SampleClient/1.0\r\n\r\n",
+        )
+        .unwrap();
+        assert_eq!(request.ja4h_r(), "ge11nn020000_Host,User-Agent__");
+        assert_eq!(
+            request.ja4h(),
+            "ge11nn020000_818f42cc3fd7_000000000000_000000000000"
+        );
+    }
+
+    #[test]
+    fn test_ja4h_sorts_cookies_and_skips_cookie_and_referer() {
+        // Synthetique : requete de navigateur avec Referer, langue et cookies.
+        let request = parse_http_request(
+            b"GET / HTTP/1.1\r\nHost: www.example.com\r\nUser-Agent: Mozilla/5.0\r\n\
+              Accept: text/html\r\nAccept-Language: en-US,en;q=0.9\r\n\
+              Referer: https://www.example.com/\r\n\
+              Cookie: session=abc123; _ga=GA1.2; theme=dark\r\n\r\n",
+        )
+        .unwrap();
+        assert_eq!(
+            request.header("cookie"),
+            Some("session=abc123; _ga=GA1.2; theme=dark")
+        );
+        assert_eq!(
+            request.ja4h_r(),
+            "ge11cr04enus_Host,User-Agent,Accept,Accept-Language_\
+             _ga,session,theme_\
+             _ga=GA1.2,session=abc123,theme=dark"
+        );
+        assert_eq!(
+            request.ja4h(),
+            "ge11cr04enus_8ddaef5d77af_a0604f4d751d_63867599e212"
+        );
+    }
 }
@@ -0,0 +1,212 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! HTTP/1.x responses (RFC 9112 §4).
+
+use std::borrow::Cow;
+
+use super::{
+    body::{HttpBodyLength, content_length, decode_chunked, response_body_length},
+    find_header,
+};
+use crate::{
+    checks::application::http::{
+        extract_header_line, find_head_end, parse_payload_as_utf8, require_status_code,
+        require_version,
+    },
+    errors::application::http::HttpMessageError,
+};
+
+/// HTTP Response
+///
+/// ```mermaid
+/// ---
+/// title: HttpResponse
+/// ---
+/// packet-beta
+/// 0-63: "Status Line variable (Version SP Code SP Reason CRLF)"
+/// 64-127: "Headers variable (Name: Value CRLF)"
+/// 128-143: "CRLF separator"
+/// 144-207: "Body variable"
+/// ```
+///
+/// Only the head has to be UTF-8: the body is kept as raw bytes, since
+/// responses routinely carry compressed or binary content.
+#[derive(Debug, PartialEq, Eq)]
+pub struct HttpResponse<'a> {
+    pub version: &'a str,
+    pub status_code: u16,
+    /// Reason phrase, possibly empty (RFC 9112 §4).
+    pub reason: &'a str,
+    pub headers: Vec<(&'a str, &'a str)>,
+    /// Every byte after the head, still transfer-encoded: the body may be cut
+    /// by the end of the segment or followed by a pipelined response. See
+    /// [`HttpResponse::decoded_body`].
+    pub body: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for HttpResponse<'a> {
+    type Error = HttpMessageError;
+
+    fn try_from(payload: &'a [u8]) -> Result<Self, Self::Error> {
+        parse_http_response(payload)
+    }
+}
+
+/// Parses an HTTP response from a given payload without copying packet bytes.
+pub fn parse_http_response(payload: &[u8]) -> Result<HttpResponse<'_>, HttpMessageError> {
+    let (head, body) = match find_head_end(payload) {
+        Some(end) => (&payload[..end - 4], &payload[end..]),
+        None => (payload, &payload[payload.len()..]),
+    };
+    let head = parse_payload_as_utf8(head)?;
+    let mut lines = head.split("\r\n");
+
+    // Comme pour la requete, la premiere ligne existe toujours ; le reason
+    // phrase peut contenir des espaces, d'ou le decoupage en trois au plus.
+    let status_line = lines.next().unwrap_or_default();
+    let mut status_parts = status_line.splitn(3, ' ');
+    let version = require_version(status_parts.next().filter(|part| !part.is_empty()))?;
+    let status_code = require_status_code(status_parts.next())?;
+    let reason = status_parts.next().unwrap_or_default();
+
+    let mut headers = Vec::new();
+    for line in lines {
+        if line.is_empty() {
+            break;
+        }
+        headers.push(extract_header_line(line)?);
+    }
+
+    Ok(HttpResponse {
+        version,
+        status_code,
+        reason,
+        headers,
+        body,
+    })
+}
+
+impl<'a> HttpResponse<'a> {
+    /// Value of the first header named `name` (case-insensitive).
+    pub fn header(&self, name: &str) -> Option<&'a str> {
+        find_header(&self.headers, name)
+    }
+
+    /// Declared `Content-Length`, if any.
+    pub fn content_length(&self) -> Result<Option<usize>, HttpMessageError> {
+        content_length(&self.headers)
+    }
+
+    /// How the body is delimited (RFC 9112 §6.3). `request_method` is the
+    /// method of the matching request when known: a response to HEAD or to a
+    /// successful CONNECT has no body whatever its headers say.
+    pub fn body_length(
+        &self,
+        request_method: Option<&str>,
+    ) -> Result<HttpBodyLength, HttpMessageError> {
+        response_body_length(&self.headers, self.status_code, request_method)
+    }
+
+    /// The body with its transfer coding removed: cut to `Content-Length`,
+    /// or reassembled from its chunks. Assumes the request was not HEAD;
+    /// content codings such as gzip are left to the caller.
+    pub fn decoded_body(&self) -> Result<Cow<'a, [u8]>, HttpMessageError> {
+        match self.body_length(None)? {
+            HttpBodyLength::ContentLength(declared) => {
+                self.body.get(..declared).map(Cow::Borrowed).ok_or(
+                    HttpMessageError::TruncatedBody {
+                        declared,
+                        available: self.body.len(),
+                    },
+                )
+            }
+            HttpBodyLength::Chunked => Ok(Cow::Owned(decode_chunked(self.body)?.0)),
+            HttpBodyLength::UntilClose => Ok(Cow::Borrowed(self.body)),
+            _ => Ok(Cow::Borrowed(&[])),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::application::http::HttpParseError;
+
+    #[test]
+    fn status_line_headers_and_binary_body_decode() {
+        // Synthetique : corps binaire (gzip) non UTF-8 apres un en-tete valide.
+        let payload =
+            b"HTTP/1.1 404 Not Found\r\nContent-Type: text/html\r\nContent-Length: 4\r\n\r\n\x1f\x8b\x08\x00";
+        let response = HttpResponse::try_from(&payload[..]).unwrap();
+        assert_eq!(response.version, "HTTP/1.1");
+        assert_eq!(response.status_code, 404);
+        assert_eq!(response.reason, "Not Found");
+        assert_eq!(response.header("content-type"), Some("text/html"));
+        assert_eq!(response.content_length(), Ok(Some(4)));
+        assert_eq!(response.decoded_body().unwrap(), &b"\x1f\x8b\x08\x00"[..]);
+        assert!(matches!(response.decoded_body(), Ok(Cow::Borrowed(_))));
+    }
+
+    #[test]
+    fn chunked_body_is_reassembled() {
+        // Synthetique : deux chunks puis le debut d'une reponse pipelinee.
+        let payload = b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n3\r\nabc\r\n2\r\nde\r\n0\r\n\r\nHTTP/1.1 304 ";
+        let response = HttpResponse::try_from(&payload[..]).unwrap();
+        assert_eq!(
+            response.body_length(Some("GET")),
+            Ok(HttpBodyLength::Chunked)
+        );
+        assert_eq!(response.decoded_body().unwrap(), &b"abcde"[..]);
+    }
+
+    #[test]
+    fn content_length_cuts_pipelined_bytes_and_reports_truncation() {
+        let pipelined = b"HTTP/1.0 200 OK\r\nContent-Length: 2\r\n\r\nokHTTP/1.0 200 OK";
+        let response = HttpResponse::try_from(&pipelined[..]).unwrap();
+        assert_eq!(response.decoded_body().unwrap(), &b"ok"[..]);
+
+        let truncated = b"HTTP/1.1 200 OK\r\nContent-Length: 10\r\n\r\npartial";
+        assert_eq!(
+            HttpResponse::try_from(&truncated[..])
+                .unwrap()
+                .decoded_body(),
+            Err(HttpMessageError::TruncatedBody {
+                declared: 10,
+                available: 7
+            })
+        );
+    }
+
+    #[test]
+    fn empty_reason_and_bodiless_statuses() {
+        let response = HttpResponse::try_from(&b"HTTP/1.1 204\r\n\r\n"[..]).unwrap();
+        assert_eq!(response.reason, "");
+        assert_eq!(response.body_length(None), Ok(HttpBodyLength::Empty));
+        assert!(response.decoded_body().unwrap().is_empty());
+    }
+
+    #[test]
+    fn requests_and_garbage_are_not_responses() {
+        assert_eq!(
+            HttpResponse::try_from(&b"GET / HTTP/1.1\r\n\r\n"[..]),
+            Err(HttpMessageError::Parse(HttpParseError::InvalidVersion(
+                "GET".into()
+            )))
+        );
+        assert_eq!(
+            HttpResponse::try_from(&b""[..]),
+            Err(HttpMessageError::Parse(HttpParseError::MissingVersion))
+        );
+        assert_eq!(
+            HttpResponse::try_from(&b"HTTP/1.1 OK\r\n\r\n"[..]),
+            Err(HttpMessageError::InvalidStatusCode("OK".into()))
+        );
+        assert_eq!(
+            HttpResponse::try_from(&b"HTTP/1.1 200 OK\r\nBroken\r\n\r\n"[..]),
+            Err(HttpMessageError::Parse(HttpParseError::InvalidHeader))
+        );
+    }
+}
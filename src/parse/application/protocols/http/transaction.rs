@@ -0,0 +1,541 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Request/response pairing on an HTTP/1.x connection.
+
+use std::collections::VecDeque;
+
+use super::{body::HttpBodyLength, parse_http_request, response::parse_http_response};
+use crate::{
+    checks::application::http::{
+        HTTP_MAX_CHUNK_LINE_LEN, HTTP_MAX_HEAD_LEN, find_head_end, parse_chunk_size,
+        validate_chunk_terminator,
+    },
+    errors::application::http::HttpMessageError,
+};
+
+/// A request and its response, with the sizes of both bodies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpTransaction {
+    pub method: String,
+    /// Request target as sent: a path, or a full URL through a proxy.
+    pub uri: String,
+    pub host: Option<String>,
+    pub status_code: u16,
+    pub reason: String,
+    /// Request body bytes, chunk framing removed.
+    pub request_body_len: usize,
+    /// Response body bytes, chunk framing removed.
+    pub response_body_len: usize,
+}
+
+impl HttpTransaction {
+    /// URL of the request. A path is prefixed with `http://` and the `Host`
+    /// header; any other target form (absolute URL, CONNECT authority, `*`)
+    /// is returned as sent.
+    pub fn url(&self) -> String {
+        match &self.host {
+            Some(host) if self.uri.starts_with('/') => format!("http://{host}{}", self.uri),
+            _ => self.uri.clone(),
+        }
+    }
+}
+
+/// Position d'un sens de la connexion dans le message en cours.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+enum StreamState {
+    #[default]
+    Head,
+    Body(usize),
+    ChunkSize,
+    ChunkData(usize),
+    ChunkEnd,
+    Trailer,
+    UntilClose,
+    /// Corps termine : le message est rendu au prochain appel.
+    Done,
+    /// Plus de HTTP/1.x dans ce sens (upgrade, tunnel ou desynchronisation).
+    Opaque,
+}
+
+#[derive(Debug)]
+enum StreamEvent {
+    /// En-tete complet, separateur compris ; l'appelant fixe le cadrage du
+    /// corps avec `begin_body`.
+    Head(Vec<u8>),
+    Complete {
+        body_len: usize,
+    },
+}
+
+/// Un sens de la connexion : seuls les en-tetes et les lignes de chunk sont
+/// mis en tampon, les donnees du corps sont comptees puis jetees.
+#[derive(Debug, Clone, Default)]
+struct HttpStream {
+    buffer: Vec<u8>,
+    state: StreamState,
+    body_len: usize,
+}
+
+impl HttpStream {
+    fn push(&mut self, bytes: &[u8]) {
+        if self.state != StreamState::Opaque {
+            self.buffer.extend_from_slice(bytes);
+        }
+    }
+
+    fn begin_body(&mut self, length: HttpBodyLength) {
+        self.body_len = 0;
+        self.state = match length {
+            HttpBodyLength::ContentLength(0) => StreamState::Done,
+            HttpBodyLength::ContentLength(length) => StreamState::Body(length),
+            HttpBodyLength::Chunked => StreamState::ChunkSize,
+            HttpBodyLength::UntilClose => StreamState::UntilClose,
+            _ => StreamState::Done,
+        };
+    }
+
+    fn make_opaque(&mut self) {
+        self.state = StreamState::Opaque;
+        self.buffer = Vec::new();
+    }
+
+    // Consomme au plus `remaining` octets de corps ; vrai si tout est la.
+    fn consume_body(&mut self, remaining: usize) -> (bool, usize) {
+        let taken = remaining.min(self.buffer.len());
+        self.buffer.drain(..taken);
+        self.body_len += taken;
+        (taken == remaining, remaining - taken)
+    }
+
+    // Ligne terminee par CRLF, retiree du tampon sans son CRLF.
+    fn take_line(&mut self) -> Result<Option<Vec<u8>>, HttpMessageError> {
+        let Some(end) = self.buffer.windows(2).position(|window| window == b"\r\n") else {
+            if self.buffer.len() > HTTP_MAX_CHUNK_LINE_LEN {
+                return Err(HttpMessageError::LineTooLong {
+                    limit: HTTP_MAX_CHUNK_LINE_LEN,
+                });
+            }
+            return Ok(None);
+        };
+        let mut line: Vec<u8> = self.buffer.drain(..end + 2).collect();
+        line.truncate(end);
+        Ok(Some(line))
+    }
+
+    fn next_event(&mut self) -> Result<Option<StreamEvent>, HttpMessageError> {
+        loop {
+            match self.state {
+                StreamState::Head => {
+                    // Lignes vides tolerees avant la ligne de depart
+                    // (RFC 9112 §2.2).
+                    let blank = self
+                        .buffer
+                        .iter()
+                        .take_while(|&&byte| byte == b'\r' || byte == b'\n')
+                        .count();
+                    self.buffer.drain(..blank);
+                    let end = match find_head_end(&self.buffer) {
+                        Some(end) if end <= HTTP_MAX_HEAD_LEN => end,
+                        None if self.buffer.len() <= HTTP_MAX_HEAD_LEN => return Ok(None),
+                        _ => {
+                            return Err(HttpMessageError::LineTooLong {
+                                limit: HTTP_MAX_HEAD_LEN,
+                            });
+                        }
+                    };
+                    let rest = self.buffer.split_off(end);
+                    self.state = StreamState::Done;
+                    return Ok(Some(StreamEvent::Head(std::mem::replace(
+                        &mut self.buffer,
+                        rest,
+                    ))));
+                }
+                StreamState::Body(remaining) => match self.consume_body(remaining) {
+                    (true, _) => self.state = StreamState::Done,
+                    (false, left) => {
+                        self.state = StreamState::Body(left);
+                        return Ok(None);
+                    }
+                },
+                StreamState::ChunkSize => {
+                    let Some(line) = self.take_line()? else {
+                        return Ok(None);
+                    };
+                    self.state = match parse_chunk_size(&line)? {
+                        0 => StreamState::Trailer,
+                        size => StreamState::ChunkData(size),
+                    };
+                }
+                StreamState::ChunkData(remaining) => match self.consume_body(remaining) {
+                    (true, _) => self.state = StreamState::ChunkEnd,
+                    (false, left) => {
+                        self.state = StreamState::ChunkData(left);
+                        return Ok(None);
+                    }
+                },
+                StreamState::ChunkEnd => {
+                    let Some(terminator) = self.buffer.get(..2) else {
+                        return Ok(None);
+                    };
+                    validate_chunk_terminator(terminator)?;
+                    self.buffer.drain(..2);
+                    self.state = StreamState::ChunkSize;
+                }
+                StreamState::Trailer => {
+                    let Some(line) = self.take_line()? else {
+                        return Ok(None);
+                    };
+                    if line.is_empty() {
+                        self.state = StreamState::Done;
+                    }
+                }
+                StreamState::UntilClose => {
+                    self.body_len += self.buffer.len();
+                    self.buffer.clear();
+                    return Ok(None);
+                }
+                StreamState::Done => {
+                    self.state = StreamState::Head;
+                    return Ok(Some(StreamEvent::Complete {
+                        body_len: self.body_len,
+                    }));
+                }
+                StreamState::Opaque => {
+                    self.buffer.clear();
+                    return Ok(None);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PendingRequest {
+    method: String,
+    uri: String,
+    host: Option<String>,
+    body_len: usize,
+    /// Corps de requete entierement recu.
+    complete: bool,
+}
+
+#[derive(Debug, Clone)]
+struct InFlightResponse {
+    request: PendingRequest,
+    status_code: u16,
+    reason: String,
+}
+
+/// Pairs the requests and responses of one HTTP/1.x connection.
+///
+/// Feed each direction's TCP payloads in sequence order with
+/// [`push_request`](Self::push_request) and
+/// [`push_response`](Self::push_response), then drain the completed
+/// transactions with [`next_transaction`](Self::next_transaction).
+/// Pipelined requests are queued and answered first-in first-out (RFC 9112
+/// §9.3.2); interim 1xx responses do not consume a request. Bodies are
+/// counted, not buffered, so memory stays bounded by
+/// [`HTTP_MAX_HEAD_LEN`] per direction.
+///
+/// After a 101 Switching Protocols or a successful CONNECT the connection no
+/// longer carries HTTP/1.x and later bytes are ignored. On error the faulty
+/// direction is abandoned the same way: the stream cannot be
+/// resynchronised once a message boundary is lost.
+#[derive(Debug, Clone, Default)]
+pub struct HttpTransactionTracker {
+    client: HttpStream,
+    server: HttpStream,
+    pending: VecDeque<PendingRequest>,
+    in_flight: Option<InFlightResponse>,
+    completed: VecDeque<HttpTransaction>,
+}
+
+impl HttpTransactionTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends client-to-server bytes.
+    pub fn push_request(&mut self, bytes: &[u8]) -> Result<(), HttpMessageError> {
+        self.client.push(bytes);
+        let result = self.drain_requests();
+        if result.is_err() {
+            self.client.make_opaque();
+        }
+        result
+    }
+
+    /// Appends server-to-client bytes.
+    pub fn push_response(&mut self, bytes: &[u8]) -> Result<(), HttpMessageError> {
+        self.server.push(bytes);
+        let result = self.drain_responses();
+        if result.is_err() {
+            self.server.make_opaque();
+        }
+        result
+    }
+
+    /// Ends the connection: a response delimited by the close is complete.
+    pub fn close(&mut self) {
+        if self.server.state == StreamState::UntilClose
+            && let Some(response) = self.in_flight.take()
+        {
+            let body_len = self.server.body_len;
+            self.complete(response, body_len);
+        }
+        self.client.make_opaque();
+        self.server.make_opaque();
+    }
+
+    /// Pops the next completed transaction, in response order.
+    pub fn next_transaction(&mut self) -> Option<HttpTransaction> {
+        self.completed.pop_front()
+    }
+
+    /// Requests still waiting for their response.
+    pub fn pending_requests(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// The connection switched protocols or became a tunnel.
+    pub fn is_upgraded(&self) -> bool {
+        self.client.state == StreamState::Opaque && self.server.state == StreamState::Opaque
+    }
+
+    fn drain_requests(&mut self) -> Result<(), HttpMessageError> {
+        while let Some(event) = self.client.next_event()? {
+            match event {
+                StreamEvent::Head(head) => {
+                    let request = parse_http_request(&head)?;
+                    self.client.begin_body(request.body_length()?);
+                    self.pending.push_back(PendingRequest {
+                        method: request.method.to_string(),
+                        uri: request.uri.to_string(),
+                        host: request.header("Host").map(str::to_string),
+                        body_len: 0,
+                        complete: false,
+                    });
+                }
+                StreamEvent::Complete { body_len } => {
+                    // Une reponse anticipee a pu retirer la requete de la
+                    // file avant la fin de son corps.
+                    if let Some(request) = self.pending.iter_mut().find(|request| !request.complete)
+                    {
+                        request.body_len = body_len;
+                        request.complete = true;
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn drain_responses(&mut self) -> Result<(), HttpMessageError> {
+        while let Some(event) = self.server.next_event()? {
+            match event {
+                StreamEvent::Head(head) => {
+                    let response = parse_http_response(&head)?;
+                    let status_code = response.status_code;
+                    let request = self
+                        .pending
+                        .front()
+                        .ok_or(HttpMessageError::UnsolicitedResponse { status_code })?;
+                    self.server
+                        .begin_body(response.body_length(Some(&request.method))?);
+                    let interim = (100..=199).contains(&status_code) && status_code != 101;
+                    if !interim && let Some(mut request) = self.pending.pop_front() {
+                        if !request.complete {
+                            request.body_len = self.client.body_len;
+                        }
+                        self.in_flight = Some(InFlightResponse {
+                            request,
+                            status_code,
+                            reason: response.reason.to_string(),
+                        });
+                    }
+                }
+                StreamEvent::Complete { body_len } => {
+                    if let Some(response) = self.in_flight.take() {
+                        let switched = response.status_code == 101
+                            || (response.request.method == "CONNECT"
+                                && (200..=299).contains(&response.status_code));
+                        self.complete(response, body_len);
+                        if switched {
+                            self.client.make_opaque();
+                            self.server.make_opaque();
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn complete(&mut self, response: InFlightResponse, response_body_len: usize) {
+        let InFlightResponse {
+            request,
+            status_code,
+            reason,
+        } = response;
+        self.completed.push_back(HttpTransaction {
+            method: request.method,
+            uri: request.uri,
+            host: request.host,
+            status_code,
+            reason,
+            request_body_len: request.body_len,
+            response_body_len,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn drain(tracker: &mut HttpTransactionTracker) -> Vec<HttpTransaction> {
+        std::iter::from_fn(|| tracker.next_transaction()).collect()
+    }
+
+    #[test]
+    fn pipelined_requests_are_answered_in_order() {
+        // Synthetique : trois requetes pipelinees dans un seul segment, les
+        // reponses (longueur fixe, chunked, HEAD) decoupees arbitrairement.
+        let mut tracker = HttpTransactionTracker::new();
+        tracker
+            .push_request(
+                b"GET /a HTTP/1.1\r\nHost: example.com\r\n\r\n\
+                  POST /b HTTP/1.1\r\nHost: example.com\r\nContent-Length: 3\r\n\r\nxyz\
+                  HEAD /c HTTP/1.1\r\nHost: example.com\r\n\r\n",
+            )
+            .unwrap();
+        assert_eq!(tracker.pending_requests(), 3);
+
+        let responses: &[u8] = b"HTTP/1.1 200 OK\r\nContent-Length: 5\r\n\r\nhello\
+            HTTP/1.1 201 Created\r\nTransfer-Encoding: chunked\r\n\r\n2\r\nok\r\n3;x=y\r\n!!!\r\n0\r\nT: v\r\n\r\n\
+            HTTP/1.1 200 OK\r\nContent-Length: 999\r\n\r\n";
+        for piece in responses.chunks(7) {
+            tracker.push_response(piece).unwrap();
+        }
+
+        let transactions = drain(&mut tracker);
+        let summary: Vec<_> = transactions
+            .iter()
+            .map(|t| {
+                (
+                    t.method.as_str(),
+                    t.url(),
+                    t.status_code,
+                    t.request_body_len,
+                    t.response_body_len,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("GET", "http://example.com/a".to_string(), 200, 0, 5),
+                ("POST", "http://example.com/b".to_string(), 201, 3, 5),
+                ("HEAD", "http://example.com/c".to_string(), 200, 0, 0),
+            ]
+        );
+        assert_eq!(transactions[1].reason, "Created");
+        assert_eq!(tracker.pending_requests(), 0);
+    }
+
+    #[test]
+    fn interim_responses_keep_the_request_pending() {
+        // Synthetique : `Expect: 100-continue` avec corps de requete chunked.
+        let mut tracker = HttpTransactionTracker::new();
+        tracker
+            .push_request(
+                b"PUT /upload HTTP/1.1\r\nHost: h\r\nExpect: 100-continue\r\n\
+                  Transfer-Encoding: chunked\r\n\r\n",
+            )
+            .unwrap();
+        tracker
+            .push_response(b"HTTP/1.1 100 Continue\r\n\r\n")
+            .unwrap();
+        assert_eq!(tracker.next_transaction(), None);
+        tracker.push_request(b"4\r\ndata\r\n0\r\n\r\n").unwrap();
+        tracker
+            .push_response(b"HTTP/1.1 204 No Content\r\n\r\n")
+            .unwrap();
+
+        let transaction = tracker.next_transaction().unwrap();
+        assert_eq!(transaction.status_code, 204);
+        assert_eq!(transaction.request_body_len, 4);
+        assert_eq!(transaction.response_body_len, 0);
+    }
+
+    #[test]
+    fn close_delimited_response_completes_on_close() {
+        let mut tracker = HttpTransactionTracker::new();
+        tracker
+            .push_request(b"GET http://proxy.test/x HTTP/1.0\r\n\r\n")
+            .unwrap();
+        tracker
+            .push_response(b"HTTP/1.0 200 OK\r\n\r\nsome")
+            .unwrap();
+        tracker.push_response(b" bytes").unwrap();
+        assert_eq!(tracker.next_transaction(), None);
+
+        tracker.close();
+        let transaction = tracker.next_transaction().unwrap();
+        assert_eq!(transaction.url(), "http://proxy.test/x");
+        assert_eq!(transaction.response_body_len, 10);
+    }
+
+    #[test]
+    fn switching_protocols_stops_tracking() {
+        let mut tracker = HttpTransactionTracker::new();
+        tracker
+            .push_request(b"GET /chat HTTP/1.1\r\nHost: h\r\nUpgrade: websocket\r\n\r\n")
+            .unwrap();
+        tracker
+            .push_response(
+                b"HTTP/1.1 101 Switching Protocols\r\nUpgrade: websocket\r\n\r\n\x81\x05hello",
+            )
+            .unwrap();
+        assert_eq!(tracker.next_transaction().unwrap().status_code, 101);
+        assert!(tracker.is_upgraded());
+        tracker.push_request(b"\x81\x85garbage").unwrap();
+        assert_eq!(tracker.next_transaction(), None);
+    }
+
+    #[test]
+    fn errors_abandon_the_faulty_direction() {
+        let mut tracker = HttpTransactionTracker::new();
+        assert_eq!(
+            tracker.push_response(b"HTTP/1.1 200 OK\r\n\r\n"),
+            Err(HttpMessageError::UnsolicitedResponse { status_code: 200 })
+        );
+        // Le sens serveur est abandonne, le sens client reste suivi.
+        tracker.push_request(b"GET / HTTP/1.1\r\n\r\n").unwrap();
+        tracker
+            .push_response(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n")
+            .unwrap();
+        assert_eq!(tracker.pending_requests(), 1);
+        assert_eq!(tracker.next_transaction(), None);
+
+        let mut tracker = HttpTransactionTracker::new();
+        tracker
+            .push_request(b"POST / HTTP/1.1\r\nTransfer-Encoding: chunked\r\n\r\n")
+            .unwrap();
+        assert!(matches!(
+            tracker.push_request(b"zz\r\n"),
+            Err(HttpMessageError::InvalidChunkSize(_))
+        ));
+
+        let mut tracker = HttpTransactionTracker::new();
+        assert_eq!(
+            tracker.push_request(&vec![b'a'; HTTP_MAX_HEAD_LEN + 1]),
+            Err(HttpMessageError::LineTooLong {
+                limit: HTTP_MAX_HEAD_LEN
+            })
+        );
+    }
+}
@@ -0,0 +1,522 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Passive follow-up of an HTTP/2 connection: frame reassembly, header
+//! blocks split over CONTINUATION frames and HPACK state of each direction.
+
+use std::collections::VecDeque;
+
+use super::{
+    Http2Frame, Http2FramePayload,
+    hpack::{HeaderField, HpackDecoder},
+};
+use crate::{
+    checks::application::http2::{
+        HTTP2_DEFAULT_MAX_FRAME_SIZE, HTTP2_FRAME_CONTINUATION, HTTP2_MAX_HEADER_BLOCK_LEN,
+        HTTP2_PREFACE, HTTP2_SETTINGS_HEADER_TABLE_SIZE, HTTP2_SETTINGS_MAX_FRAME_SIZE,
+        extract_http2_frame_header, validate_http2_frame_length,
+    },
+    errors::application::http2::Http2Error,
+};
+
+/// Sender of a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Http2Direction {
+    /// Client to server: requests.
+    Client,
+    /// Server to client: responses and server pushes.
+    Server,
+}
+
+/// What happened on a stream, in the order the frames were seen.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Http2Event {
+    /// A complete header list: request headers from the client, response
+    /// headers (informational or final) or trailers from either side.
+    Headers {
+        direction: Http2Direction,
+        stream_id: u32,
+        headers: Vec<HeaderField>,
+        end_stream: bool,
+    },
+    /// Content of a DATA frame, padding removed.
+    Data {
+        direction: Http2Direction,
+        stream_id: u32,
+        data: Vec<u8>,
+        end_stream: bool,
+    },
+    /// Request the server announces it will push on `promised_stream_id`.
+    PushPromise {
+        stream_id: u32,
+        promised_stream_id: u32,
+        headers: Vec<HeaderField>,
+    },
+    Reset {
+        direction: Http2Direction,
+        stream_id: u32,
+        error_code: u32,
+    },
+    GoAway {
+        direction: Http2Direction,
+        last_stream_id: u32,
+        error_code: u32,
+    },
+}
+
+// Bloc d'en-tetes en cours de reassemblage, en attente de END_HEADERS.
+#[derive(Debug, Clone)]
+struct PendingBlock {
+    stream_id: u32,
+    end_stream: bool,
+    promised_stream_id: Option<u32>,
+    fragments: Vec<u8>,
+}
+
+// Etat des octets emis par un cote de la connexion.
+#[derive(Debug, Clone)]
+struct Endpoint {
+    direction: Http2Direction,
+    buffer: Vec<u8>,
+    // Decode les blocs emis par ce cote ; limite fixee par les SETTINGS du
+    // cote oppose, comme la taille de trame maximale.
+    decoder: HpackDecoder,
+    max_frame_size: usize,
+    pending: Option<PendingBlock>,
+    failed: bool,
+}
+
+impl Endpoint {
+    fn new(direction: Http2Direction) -> Self {
+        Self {
+            direction,
+            buffer: Vec::new(),
+            decoder: HpackDecoder::new(),
+            max_frame_size: HTTP2_DEFAULT_MAX_FRAME_SIZE,
+            pending: None,
+            failed: false,
+        }
+    }
+}
+
+/// Follows both directions of an HTTP/2 connection.
+///
+/// Feed each direction's reassembled TCP (or decrypted TLS) stream in
+/// order with [`push_client`](Self::push_client) and
+/// [`push_server`](Self::push_server), then drain the stream events with
+/// [`next_event`](Self::next_event). The client stream must start with the
+/// connection preface.
+///
+/// A framing or HPACK error leaves the HPACK state of that direction out of
+/// sync: the direction is abandoned and its later bytes are ignored, the
+/// other one is still decoded.
+#[derive(Debug, Clone)]
+pub struct Http2Connection {
+    preface_pending: bool,
+    client: Endpoint,
+    server: Endpoint,
+    events: VecDeque<Http2Event>,
+}
+
+impl Default for Http2Connection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Http2Connection {
+    pub fn new() -> Self {
+        Self {
+            preface_pending: true,
+            client: Endpoint::new(Http2Direction::Client),
+            server: Endpoint::new(Http2Direction::Server),
+            events: VecDeque::new(),
+        }
+    }
+
+    /// Appends bytes sent by the client.
+    pub fn push_client(&mut self, bytes: &[u8]) -> Result<(), Http2Error> {
+        if self.client.failed {
+            return Ok(());
+        }
+        self.client.buffer.extend_from_slice(bytes);
+        if self.preface_pending {
+            let seen = self.client.buffer.len().min(HTTP2_PREFACE.len());
+            if self.client.buffer[..seen] != HTTP2_PREFACE[..seen] {
+                abandon(&mut self.client);
+                return Err(Http2Error::InvalidPreface);
+            }
+            if seen < HTTP2_PREFACE.len() {
+                return Ok(());
+            }
+            self.client.buffer.drain(..HTTP2_PREFACE.len());
+            self.preface_pending = false;
+        }
+        drain_frames(&mut self.client, &mut self.server, &mut self.events)
+    }
+
+    /// Appends bytes sent by the server.
+    pub fn push_server(&mut self, bytes: &[u8]) -> Result<(), Http2Error> {
+        if self.server.failed {
+            return Ok(());
+        }
+        self.server.buffer.extend_from_slice(bytes);
+        drain_frames(&mut self.server, &mut self.client, &mut self.events)
+    }
+
+    /// Pops the oldest event.
+    pub fn next_event(&mut self) -> Option<Http2Event> {
+        self.events.pop_front()
+    }
+
+    /// Whether `direction` was abandoned after an error.
+    pub fn has_failed(&self, direction: Http2Direction) -> bool {
+        match direction {
+            Http2Direction::Client => self.client.failed,
+            Http2Direction::Server => self.server.failed,
+        }
+    }
+}
+
+fn abandon(endpoint: &mut Endpoint) {
+    endpoint.failed = true;
+    endpoint.buffer = Vec::new();
+    endpoint.pending = None;
+}
+
+// Decode toutes les trames completes emises par `sender`.
+fn drain_frames(
+    sender: &mut Endpoint,
+    receiver: &mut Endpoint,
+    events: &mut VecDeque<Http2Event>,
+) -> Result<(), Http2Error> {
+    let result = decode_frames(sender, receiver, events);
+    if result.is_err() {
+        abandon(sender);
+    }
+    result
+}
+
+fn decode_frames(
+    sender: &mut Endpoint,
+    receiver: &mut Endpoint,
+    events: &mut VecDeque<Http2Event>,
+) -> Result<(), Http2Error> {
+    while let Some((length, ..)) = extract_http2_frame_header(&sender.buffer) {
+        // La longueur est refusee avant d'attendre la trame : le tampon ne
+        // depasse jamais la taille maximale annoncee par le recepteur.
+        validate_http2_frame_length(length, sender.max_frame_size)?;
+        let frame_len = length + 9;
+        if sender.buffer.len() < frame_len {
+            break;
+        }
+        let bytes: Vec<u8> = sender.buffer.drain(..frame_len).collect();
+        let frame = Http2Frame::try_from(bytes.as_slice())?;
+        handle_frame(sender, receiver, events, &frame)?;
+    }
+    Ok(())
+}
+
+fn handle_frame(
+    sender: &mut Endpoint,
+    receiver: &mut Endpoint,
+    events: &mut VecDeque<Http2Event>,
+    frame: &Http2Frame<'_>,
+) -> Result<(), Http2Error> {
+    let direction = sender.direction;
+    // Entre HEADERS et END_HEADERS, seules des CONTINUATION du meme flux
+    // sont permises (RFC 9113 §6.10).
+    if let Some(pending) = &sender.pending
+        && (frame.frame_type != HTTP2_FRAME_CONTINUATION || frame.stream_id != pending.stream_id)
+    {
+        return Err(Http2Error::ExpectedContinuation {
+            expected: pending.stream_id,
+            frame_type: frame.frame_type,
+            stream_id: frame.stream_id,
+        });
+    }
+
+    match frame.payload()? {
+        Http2FramePayload::Headers { header_block, .. } => {
+            sender.pending = Some(PendingBlock {
+                stream_id: frame.stream_id,
+                end_stream: frame.is_end_stream(),
+                promised_stream_id: None,
+                fragments: header_block.to_vec(),
+            });
+        }
+        Http2FramePayload::PushPromise {
+            promised_stream_id,
+            header_block,
+        } => {
+            sender.pending = Some(PendingBlock {
+                stream_id: frame.stream_id,
+                end_stream: false,
+                promised_stream_id: Some(promised_stream_id),
+                fragments: header_block.to_vec(),
+            });
+        }
+        Http2FramePayload::Continuation { header_block } => {
+            let pending = sender
+                .pending
+                .as_mut()
+                .ok_or(Http2Error::UnexpectedContinuation(frame.stream_id))?;
+            let length = pending.fragments.len() + header_block.len();
+            if length > HTTP2_MAX_HEADER_BLOCK_LEN {
+                return Err(Http2Error::HeaderBlockTooLarge {
+                    length,
+                    limit: HTTP2_MAX_HEADER_BLOCK_LEN,
+                });
+            }
+            pending.fragments.extend_from_slice(header_block);
+        }
+        // Une trame DATA vide sans END_STREAM ne porte rien.
+        Http2FramePayload::Data { data } if !data.is_empty() || frame.is_end_stream() => {
+            events.push_back(Http2Event::Data {
+                direction,
+                stream_id: frame.stream_id,
+                data: data.to_vec(),
+                end_stream: frame.is_end_stream(),
+            });
+        }
+        Http2FramePayload::RstStream { error_code } => events.push_back(Http2Event::Reset {
+            direction,
+            stream_id: frame.stream_id,
+            error_code,
+        }),
+        Http2FramePayload::GoAway {
+            last_stream_id,
+            error_code,
+            ..
+        } => events.push_back(Http2Event::GoAway {
+            direction,
+            last_stream_id,
+            error_code,
+        }),
+        // Les SETTINGS d'un cote bornent ce que l'autre lui envoie.
+        Http2FramePayload::Settings { parameters, .. } => {
+            for (identifier, value) in parameters {
+                match identifier {
+                    HTTP2_SETTINGS_HEADER_TABLE_SIZE => {
+                        // `set_limit` plafonne a HPACK_MAX_TABLE_SIZE.
+                        receiver.decoder.set_limit(value as usize);
+                    }
+                    HTTP2_SETTINGS_MAX_FRAME_SIZE => receiver.max_frame_size = value as usize,
+                    _ => {}
+                }
+            }
+        }
+        _ => {}
+    }
+
+    if frame.is_end_headers()
+        && let Some(pending) = sender.pending.take()
+    {
+        let headers = sender.decoder.decode(&pending.fragments)?;
+        events.push_back(match pending.promised_stream_id {
+            Some(promised_stream_id) => Http2Event::PushPromise {
+                stream_id: pending.stream_id,
+                promised_stream_id,
+                headers,
+            },
+            None => Http2Event::Headers {
+                direction,
+                stream_id: pending.stream_id,
+                headers,
+                end_stream: pending.end_stream,
+            },
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        checks::application::http2::HPACK_MAX_TABLE_SIZE, convert::hex_stream_to_bytes,
+        errors::application::http2::HpackError, parse::application::protocols::http2::header_value,
+    };
+
+    fn frame(frame_type: u8, flags: u8, stream_id: u32, payload: &[u8]) -> Vec<u8> {
+        let length = payload.len().to_be_bytes();
+        let mut bytes = vec![length[5], length[6], length[7], frame_type, flags];
+        bytes.extend_from_slice(&stream_id.to_be_bytes());
+        bytes.extend_from_slice(payload);
+        bytes
+    }
+
+    fn events(connection: &mut Http2Connection) -> Vec<Http2Event> {
+        std::iter::from_fn(|| connection.next_event()).collect()
+    }
+
+    #[test]
+    fn h2c_exchange_yields_stream_events() {
+        // Synthetique : blocs HPACK des annexes C.4 (requetes) et C.6
+        // (reponses, table de 256 octets annoncee par le client).
+        let mut client = HTTP2_PREFACE.to_vec();
+        client.extend(frame(0x4, 0x0, 0, &[0x00, 0x01, 0x00, 0x00, 0x01, 0x00]));
+        client.extend(frame(
+            0x1,
+            0x5,
+            1,
+            &hex_stream_to_bytes("828684418cf1e3c2e5f23a6ba0ab90f4ff"),
+        ));
+        // C.4.2 coupe en HEADERS + CONTINUATION sur le flux 3.
+        let second = hex_stream_to_bytes("828684be5886a8eb10649cbf");
+        client.extend(frame(0x1, 0x1, 3, &second[..4]));
+        client.extend(frame(0x9, 0x4, 3, &second[4..]));
+
+        let mut server = frame(0x4, 0x0, 0, &[]);
+        server.extend(frame(0x4, 0x1, 0, &[]));
+        server.extend(frame(
+            0x1,
+            0x4,
+            1,
+            &hex_stream_to_bytes(
+                "488264025885aec3771a4b6196d07abe941054d444a8200595040b8166e082a62d1bff6e919d29ad171863c78f0b97c8e9ae82ae43d3",
+            ),
+        ));
+        server.extend(frame(0x0, 0x9, 1, b"\x03hello\x00\x00\x00"));
+        server.extend(frame(0x1, 0x4, 3, &hex_stream_to_bytes("4883640effc1c0bf")));
+        server.extend(frame(0x3, 0x0, 3, &[0x00, 0x00, 0x00, 0x08]));
+        server.extend(frame(0x7, 0x0, 0, &[0, 0, 0, 3, 0, 0, 0, 0]));
+
+        let mut connection = Http2Connection::new();
+        // Decoupage arbitraire : le suivi reassemble les trames.
+        for chunk in client.chunks(7) {
+            connection.push_client(chunk).unwrap();
+        }
+        for chunk in server.chunks(11) {
+            connection.push_server(chunk).unwrap();
+        }
+
+        let events = events(&mut connection);
+        assert_eq!(events.len(), 7);
+        let Http2Event::Headers {
+            direction: Http2Direction::Client,
+            stream_id: 1,
+            headers,
+            end_stream: true,
+        } = &events[0]
+        else {
+            panic!("unexpected {:?}", events[0]);
+        };
+        assert_eq!(header_value(headers, ":authority"), Some("www.example.com"));
+        let Http2Event::Headers { headers, .. } = &events[1] else {
+            panic!("unexpected {:?}", events[1]);
+        };
+        assert_eq!(header_value(headers, "cache-control"), Some("no-cache"));
+        let Http2Event::Headers {
+            direction: Http2Direction::Server,
+            stream_id: 1,
+            headers,
+            end_stream: false,
+        } = &events[2]
+        else {
+            panic!("unexpected {:?}", events[2]);
+        };
+        assert_eq!(header_value(headers, ":status"), Some("302"));
+        assert_eq!(
+            events[3],
+            Http2Event::Data {
+                direction: Http2Direction::Server,
+                stream_id: 1,
+                data: b"hello".to_vec(),
+                end_stream: true,
+            }
+        );
+        // La table limitee a 256 octets a evince ":status 302" : C.6.2 ne
+        // se decode correctement qu'avec la limite annoncee par le client.
+        let Http2Event::Headers { headers, .. } = &events[4] else {
+            panic!("unexpected {:?}", events[4]);
+        };
+        assert_eq!(header_value(headers, ":status"), Some("307"));
+        assert_eq!(
+            header_value(headers, "location"),
+            Some("https://www.example.com")
+        );
+        assert_eq!(
+            events[5],
+            Http2Event::Reset {
+                direction: Http2Direction::Server,
+                stream_id: 3,
+                error_code: 8,
+            }
+        );
+        assert_eq!(
+            events[6],
+            Http2Event::GoAway {
+                direction: Http2Direction::Server,
+                last_stream_id: 3,
+                error_code: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn bad_preface_abandons_the_client_side() {
+        let mut connection = Http2Connection::new();
+        assert_eq!(connection.push_client(b"PRI * HTTP/2.0\r\n"), Ok(()));
+        assert_eq!(
+            connection.push_client(b"\r\nXX"),
+            Err(Http2Error::InvalidPreface)
+        );
+        assert!(connection.has_failed(Http2Direction::Client));
+        assert_eq!(connection.push_client(b"ignored"), Ok(()));
+        assert!(!connection.has_failed(Http2Direction::Server));
+    }
+
+    #[test]
+    fn interleaved_continuation_and_oversized_frames_are_rejected() {
+        let mut connection = Http2Connection::new();
+        let mut client = HTTP2_PREFACE.to_vec();
+        client.extend(frame(0x1, 0x0, 1, &[0x82]));
+        client.extend(frame(0x0, 0x0, 1, b"x"));
+        assert_eq!(
+            connection.push_client(&client),
+            Err(Http2Error::ExpectedContinuation {
+                expected: 1,
+                frame_type: 0x0,
+                stream_id: 1
+            })
+        );
+
+        // 16385 octets annonces sans SETTINGS_MAX_FRAME_SIZE du client.
+        assert_eq!(
+            connection.push_server(&[0x00, 0x40, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01]),
+            Err(Http2Error::FrameTooLarge {
+                length: 16_385,
+                max: 16_384
+            })
+        );
+        assert!(connection.has_failed(Http2Direction::Server));
+
+        let mut connection = Http2Connection::new();
+        assert_eq!(
+            connection.push_server(&frame(0x9, 0x4, 1, &[0x82])),
+            Err(Http2Error::UnexpectedContinuation(1))
+        );
+    }
+
+    #[test]
+    fn oversized_header_table_size_is_capped() {
+        // Le client annonce une table de 4 Gio ; le serveur tente ensuite de
+        // l'agrandir a 64 Kio + 1 (mise a jour de taille 0x3F + entier HPACK).
+        let mut client = HTTP2_PREFACE.to_vec();
+        client.extend(frame(0x4, 0x0, 0, &[0x00, 0x01, 0xFF, 0xFF, 0xFF, 0xFF]));
+        let mut connection = Http2Connection::new();
+        connection.push_client(&client).unwrap();
+
+        assert_eq!(
+            connection.push_server(&frame(0x1, 0x4, 1, &[0x3F, 0xE2, 0xFF, 0x03, 0x88])),
+            Err(Http2Error::Hpack(HpackError::TableSizeTooLarge {
+                size: HPACK_MAX_TABLE_SIZE + 1,
+                limit: HPACK_MAX_TABLE_SIZE,
+            }))
+        );
+    }
+}
@@ -0,0 +1,630 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! gRPC over HTTP/2: method names, Length-Prefixed-Message framing and
+//! call status ("gRPC over HTTP2" protocol description).
+
+use std::collections::{BTreeMap, VecDeque};
+
+use super::{
+    connection::{Http2Direction, Http2Event},
+    header_value,
+    hpack::HeaderField,
+};
+use crate::{
+    checks::application::http2::{
+        GRPC_DEFAULT_MAX_MESSAGE_LEN, GRPC_MAX_OPEN_CALLS, GRPC_MESSAGE_PREFIX_LEN,
+        extract_grpc_prefix, is_grpc_content_type,
+    },
+    errors::application::http2::GrpcError,
+};
+
+/// Service and method named by the `:path` of a call,
+/// `/<package>.<Service>/<Method>`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GrpcMethod<'a> {
+    /// Fully qualified service name, package included.
+    pub service: &'a str,
+    pub method: &'a str,
+}
+
+impl<'a> GrpcMethod<'a> {
+    pub fn from_path(path: &'a str) -> Result<Self, GrpcError> {
+        path.strip_prefix('/')
+            .and_then(|rest| rest.split_once('/'))
+            .filter(|(service, method)| {
+                !service.is_empty() && !method.is_empty() && !method.contains('/')
+            })
+            .map(|(service, method)| GrpcMethod { service, method })
+            .ok_or_else(|| GrpcError::InvalidPath(path.to_string()))
+    }
+
+    /// Package of the service, when it has one.
+    pub fn package(&self) -> Option<&'a str> {
+        self.service.rsplit_once('.').map(|(package, _)| package)
+    }
+}
+
+/// gRPC Length-Prefixed-Message
+///
+/// ```mermaid
+/// ---
+/// title: GrpcMessage
+/// ---
+/// packet-beta
+/// 0-7: "Compressed-Flag"
+/// 8-39: "Message-Length"
+/// 40-103: "Message variable"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GrpcMessage<'a> {
+    /// The message is compressed with the call's `grpc-encoding`.
+    pub compressed: bool,
+    /// Serialized message, usually protobuf.
+    pub data: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for GrpcMessage<'a> {
+    type Error = GrpcError;
+
+    /// Decodes the first message of `bytes`; a DATA frame may carry several.
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        let (compressed, length) = extract_grpc_prefix(bytes)?;
+        let data = bytes
+            .get(GRPC_MESSAGE_PREFIX_LEN..)
+            .and_then(|rest| rest.get(..length))
+            .ok_or(GrpcError::TruncatedMessage {
+                declared: length,
+                available: bytes.len() - GRPC_MESSAGE_PREFIX_LEN,
+            })?;
+        Ok(GrpcMessage { compressed, data })
+    }
+}
+
+impl GrpcMessage<'_> {
+    /// Size on the wire, prefix included.
+    pub fn encoded_len(&self) -> usize {
+        GRPC_MESSAGE_PREFIX_LEN + self.data.len()
+    }
+}
+
+/// Rebuilds gRPC messages split across DATA frames of one stream direction.
+///
+/// Feed the DATA payloads in order, then drain the complete messages with
+/// [`next_message`](Self::next_message). A message announcing more than the
+/// maximum length is refused before being buffered.
+#[derive(Debug, Clone)]
+pub struct GrpcMessageReassembler {
+    buffer: Vec<u8>,
+    max_message_len: usize,
+}
+
+impl Default for GrpcMessageReassembler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl GrpcMessageReassembler {
+    /// Reassembler accepting messages up to
+    /// [`GRPC_DEFAULT_MAX_MESSAGE_LEN`] bytes.
+    pub fn new() -> Self {
+        Self::with_max_message_len(GRPC_DEFAULT_MAX_MESSAGE_LEN)
+    }
+
+    pub fn with_max_message_len(max_message_len: usize) -> Self {
+        Self {
+            buffer: Vec::new(),
+            max_message_len,
+        }
+    }
+
+    /// Appends the payload of a DATA frame.
+    ///
+    /// On error the buffer is reset: the message boundaries are lost.
+    pub fn push(&mut self, data: &[u8]) -> Result<(), GrpcError> {
+        self.buffer.extend_from_slice(data);
+        match self.check_next_prefix() {
+            Ok(_) => Ok(()),
+            Err(error) => {
+                self.buffer.clear();
+                Err(error)
+            }
+        }
+    }
+
+    /// Pops the next complete message, prefix included, ready for
+    /// [`GrpcMessage::try_from`]. `None` while the message is incomplete.
+    pub fn next_message(&mut self) -> Result<Option<Vec<u8>>, GrpcError> {
+        let result = self.check_next_prefix();
+        if result.is_err() {
+            self.buffer.clear();
+        }
+        let Some(length) = result? else {
+            return Ok(None);
+        };
+        let total = GRPC_MESSAGE_PREFIX_LEN + length;
+        if self.buffer.len() < total {
+            return Ok(None);
+        }
+        Ok(Some(self.buffer.drain(..total).collect()))
+    }
+
+    /// Bytes of an incomplete message still buffered.
+    pub fn pending_len(&self) -> usize {
+        self.buffer.len()
+    }
+
+    fn check_next_prefix(&self) -> Result<Option<usize>, GrpcError> {
+        if self.buffer.len() < GRPC_MESSAGE_PREFIX_LEN {
+            return Ok(None);
+        }
+        let (_, length) = extract_grpc_prefix(&self.buffer)?;
+        if length > self.max_message_len {
+            return Err(GrpcError::MessageTooLarge {
+                length,
+                limit: self.max_message_len,
+            });
+        }
+        Ok(Some(length))
+    }
+}
+
+/// `grpc-status` and decoded `grpc-message` of a header list, found in the
+/// trailers or in a Trailers-Only response.
+pub fn grpc_status(headers: &[HeaderField]) -> Option<(u32, Option<String>)> {
+    let status = header_value(headers, "grpc-status")?.trim().parse().ok()?;
+    let message = header_value(headers, "grpc-message").map(percent_decode);
+    Some((status, message))
+}
+
+// `grpc-message` est encode en pourcentage ; une sequence invalide est
+// conservee telle quelle.
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut index = 0;
+    while index < bytes.len() {
+        let escaped = (bytes[index] == b'%')
+            .then(|| bytes.get(index + 1..index + 3))
+            .flatten()
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match escaped {
+            Some(byte) => {
+                decoded.push(byte);
+                index += 3;
+            }
+            None => {
+                decoded.push(bytes[index]);
+                index += 1;
+            }
+        }
+    }
+    String::from_utf8_lossy(&decoded).into_owned()
+}
+
+/// A gRPC call seen on one stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GrpcCall {
+    pub stream_id: u32,
+    pub service: String,
+    pub method: String,
+    pub authority: Option<String>,
+    /// Complete messages sent by the client, prefix included, ready for
+    /// [`GrpcMessage::try_from`].
+    pub request_messages: Vec<Vec<u8>>,
+    /// Complete messages sent by the server, likewise.
+    pub response_messages: Vec<Vec<u8>>,
+    pub http_status: Option<u16>,
+    /// `grpc-status`, 0 meaning OK.
+    pub status: Option<u32>,
+    pub status_message: Option<String>,
+    /// Error code of a RST_STREAM that ended the call.
+    pub reset: Option<u32>,
+}
+
+#[derive(Debug, Clone)]
+struct CallState {
+    call: GrpcCall,
+    request: GrpcMessageReassembler,
+    response: GrpcMessageReassembler,
+}
+
+/// Turns the events of an [`Http2Connection`](super::connection::Http2Connection)
+/// into gRPC calls.
+///
+/// A stream becomes a call when the client's headers carry an
+/// `application/grpc` content type. The call is complete once the server
+/// ends the stream or either side resets it; [`close`](Self::close) flushes
+/// the calls still open when the capture ends. Beyond
+/// [`GRPC_MAX_OPEN_CALLS`] open calls, the oldest one is completed as is.
+#[derive(Debug, Clone, Default)]
+pub struct GrpcCallTracker {
+    open: BTreeMap<u32, CallState>,
+    completed: VecDeque<GrpcCall>,
+}
+
+impl GrpcCallTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Consumes one connection event; events of non-gRPC streams are
+    /// ignored.
+    ///
+    /// A framing error abandons the call: it is completed with the messages
+    /// decoded so far.
+    pub fn push_event(&mut self, event: &Http2Event) -> Result<(), GrpcError> {
+        match event {
+            Http2Event::Headers {
+                direction: Http2Direction::Client,
+                stream_id,
+                headers,
+                ..
+            } if !self.open.contains_key(stream_id) => self.open_call(*stream_id, headers)?,
+            Http2Event::Headers {
+                direction: Http2Direction::Server,
+                stream_id,
+                headers,
+                end_stream,
+            } => {
+                let Some(state) = self.open.get_mut(stream_id) else {
+                    return Ok(());
+                };
+                if let Some(status) =
+                    header_value(headers, ":status").and_then(|status| status.parse().ok())
+                {
+                    state.call.http_status = Some(status);
+                }
+                if let Some((status, message)) = grpc_status(headers) {
+                    state.call.status = Some(status);
+                    state.call.status_message = message;
+                }
+                if *end_stream {
+                    self.complete(*stream_id);
+                }
+            }
+            Http2Event::Data {
+                direction,
+                stream_id,
+                data,
+                end_stream,
+            } => {
+                let Some(state) = self.open.get_mut(stream_id) else {
+                    return Ok(());
+                };
+                let (reassembler, messages) = match direction {
+                    Http2Direction::Client => {
+                        (&mut state.request, &mut state.call.request_messages)
+                    }
+                    Http2Direction::Server => {
+                        (&mut state.response, &mut state.call.response_messages)
+                    }
+                };
+                let drained = reassembler.push(data).and_then(|()| {
+                    while let Some(message) = reassembler.next_message()? {
+                        messages.push(message);
+                    }
+                    Ok(())
+                });
+                if let Err(error) = drained {
+                    self.complete(*stream_id);
+                    return Err(error);
+                }
+                if *end_stream && *direction == Http2Direction::Server {
+                    self.complete(*stream_id);
+                }
+            }
+            Http2Event::Reset {
+                stream_id,
+                error_code,
+                ..
+            } => {
+                if let Some(state) = self.open.get_mut(stream_id) {
+                    state.call.reset = Some(*error_code);
+                    self.complete(*stream_id);
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Completes the calls still open, in stream order.
+    pub fn close(&mut self) {
+        let open = std::mem::take(&mut self.open);
+        self.completed
+            .extend(open.into_values().map(|state| state.call));
+    }
+
+    /// Pops the oldest completed call.
+    pub fn next_call(&mut self) -> Option<GrpcCall> {
+        self.completed.pop_front()
+    }
+
+    fn open_call(&mut self, stream_id: u32, headers: &[HeaderField]) -> Result<(), GrpcError> {
+        if !header_value(headers, "content-type").is_some_and(is_grpc_content_type) {
+            return Ok(());
+        }
+        let path = header_value(headers, ":path").unwrap_or_default();
+        let method = GrpcMethod::from_path(path)?;
+        let call = GrpcCall {
+            stream_id,
+            service: method.service.to_string(),
+            method: method.method.to_string(),
+            authority: header_value(headers, ":authority").map(str::to_string),
+            request_messages: Vec::new(),
+            response_messages: Vec::new(),
+            http_status: None,
+            status: None,
+            status_message: None,
+            reset: None,
+        };
+        if self.open.len() >= GRPC_MAX_OPEN_CALLS
+            && let Some((_, oldest)) = self.open.pop_first()
+        {
+            self.completed.push_back(oldest.call);
+        }
+        self.open.insert(
+            stream_id,
+            CallState {
+                call,
+                request: GrpcMessageReassembler::new(),
+                response: GrpcMessageReassembler::new(),
+            },
+        );
+        Ok(())
+    }
+
+    fn complete(&mut self, stream_id: u32) {
+        if let Some(state) = self.open.remove(&stream_id) {
+            self.completed.push_back(state.call);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(name: &str, value: &str) -> HeaderField {
+        HeaderField {
+            name: name.as_bytes().to_vec(),
+            value: value.as_bytes().to_vec(),
+            never_indexed: false,
+        }
+    }
+
+    fn headers(direction: Http2Direction, fields: &[(&str, &str)], end_stream: bool) -> Http2Event {
+        Http2Event::Headers {
+            direction,
+            stream_id: 1,
+            headers: fields
+                .iter()
+                .map(|&(name, value)| field(name, value))
+                .collect(),
+            end_stream,
+        }
+    }
+
+    fn data(direction: Http2Direction, data: &[u8], end_stream: bool) -> Http2Event {
+        Http2Event::Data {
+            direction,
+            stream_id: 1,
+            data: data.to_vec(),
+            end_stream,
+        }
+    }
+
+    #[test]
+    fn paths_name_service_and_method() {
+        let method = GrpcMethod::from_path("/grpc.health.v1.Health/Check").unwrap();
+        assert_eq!(method.service, "grpc.health.v1.Health");
+        assert_eq!(method.method, "Check");
+        assert_eq!(method.package(), Some("grpc.health.v1"));
+        assert_eq!(GrpcMethod::from_path("/Echo/Say").unwrap().package(), None);
+        for path in ["/", "Health/Check", "/Health/", "//Check", "/a/b/c"] {
+            assert_eq!(
+                GrpcMethod::from_path(path),
+                Err(GrpcError::InvalidPath(path.to_string()))
+            );
+        }
+    }
+
+    #[test]
+    fn messages_are_split_and_reassembled() {
+        // Synthetique : deux messages dans une trame, le second coupe.
+        let mut reassembler = GrpcMessageReassembler::new();
+        reassembler
+            .push(b"\x00\x00\x00\x00\x02\x08\x01\x01\x00\x00\x00\x03ab")
+            .unwrap();
+        let first = reassembler.next_message().unwrap().unwrap();
+        assert_eq!(
+            GrpcMessage::try_from(first.as_slice()),
+            Ok(GrpcMessage {
+                compressed: false,
+                data: b"\x08\x01"
+            })
+        );
+        assert_eq!(reassembler.next_message(), Ok(None));
+        assert_eq!(reassembler.pending_len(), 7);
+        reassembler.push(b"c").unwrap();
+        let second = reassembler.next_message().unwrap().unwrap();
+        let second = GrpcMessage::try_from(second.as_slice()).unwrap();
+        assert!(second.compressed);
+        assert_eq!(second.data, b"abc");
+        assert_eq!(second.encoded_len(), 8);
+
+        let mut bounded = GrpcMessageReassembler::with_max_message_len(4);
+        assert_eq!(
+            bounded.push(b"\x00\x00\x00\x00\x05"),
+            Err(GrpcError::MessageTooLarge {
+                length: 5,
+                limit: 4
+            })
+        );
+        assert_eq!(bounded.pending_len(), 0);
+        assert_eq!(
+            GrpcMessage::try_from(&b"\x00\x00\x00\x00\x05abc"[..]),
+            Err(GrpcError::TruncatedMessage {
+                declared: 5,
+                available: 3
+            })
+        );
+    }
+
+    #[test]
+    fn unary_call_is_tracked_to_its_trailers() {
+        // Synthetique : appel Health/Check, reponse SERVING coupee en deux
+        // trames DATA, trailers avec statut OK.
+        let mut tracker = GrpcCallTracker::new();
+        let events = [
+            headers(
+                Http2Direction::Client,
+                &[
+                    (":method", "POST"),
+                    (":scheme", "http"),
+                    (":path", "/grpc.health.v1.Health/Check"),
+                    (":authority", "localhost:50051"),
+                    ("content-type", "application/grpc"),
+                    ("te", "trailers"),
+                ],
+                false,
+            ),
+            data(Http2Direction::Client, b"\x00\x00\x00\x00\x00", true),
+            headers(
+                Http2Direction::Server,
+                &[(":status", "200"), ("content-type", "application/grpc")],
+                false,
+            ),
+            data(Http2Direction::Server, b"\x00\x00\x00", false),
+            data(Http2Direction::Server, b"\x00\x02\x08\x01", false),
+            headers(
+                Http2Direction::Server,
+                &[("grpc-status", "0"), ("grpc-message", "")],
+                true,
+            ),
+        ];
+        for event in &events {
+            tracker.push_event(event).unwrap();
+        }
+        let call = tracker.next_call().unwrap();
+        assert_eq!(call.service, "grpc.health.v1.Health");
+        assert_eq!(call.method, "Check");
+        assert_eq!(call.authority.as_deref(), Some("localhost:50051"));
+        assert_eq!(call.request_messages, [b"\x00\x00\x00\x00\x00".to_vec()]);
+        assert_eq!(
+            call.response_messages,
+            [b"\x00\x00\x00\x00\x02\x08\x01".to_vec()]
+        );
+        assert_eq!(call.http_status, Some(200));
+        assert_eq!(call.status, Some(0));
+        assert_eq!(tracker.next_call(), None);
+    }
+
+    #[test]
+    fn trailers_only_errors_and_plain_http2_streams() {
+        let mut tracker = GrpcCallTracker::new();
+        // Flux HTTP/2 ordinaire : ignore.
+        tracker
+            .push_event(&headers(
+                Http2Direction::Client,
+                &[(":method", "GET"), (":path", "/index.html")],
+                true,
+            ))
+            .unwrap();
+        tracker.close();
+        assert_eq!(tracker.next_call(), None);
+
+        // Synthetique : reponse Trailers-Only, message encode en pourcentage.
+        tracker
+            .push_event(&headers(
+                Http2Direction::Client,
+                &[
+                    (":path", "/echo.Echo/Say"),
+                    ("content-type", "application/grpc+proto"),
+                ],
+                false,
+            ))
+            .unwrap();
+        tracker
+            .push_event(&headers(
+                Http2Direction::Server,
+                &[
+                    (":status", "200"),
+                    ("grpc-status", "12"),
+                    ("grpc-message", "unknown%20method%3A%20Say"),
+                ],
+                true,
+            ))
+            .unwrap();
+        let call = tracker.next_call().unwrap();
+        assert_eq!(call.status, Some(12));
+        assert_eq!(call.status_message.as_deref(), Some("unknown method: Say"));
+        assert!(call.response_messages.is_empty());
+    }
+
+    #[test]
+    fn reset_and_malformed_streams_complete_the_call() {
+        let mut tracker = GrpcCallTracker::new();
+        let open = headers(
+            Http2Direction::Client,
+            &[
+                (":path", "/echo.Echo/Say"),
+                ("content-type", "application/grpc"),
+            ],
+            false,
+        );
+        tracker.push_event(&open).unwrap();
+        tracker
+            .push_event(&Http2Event::Reset {
+                direction: Http2Direction::Client,
+                stream_id: 1,
+                error_code: 8,
+            })
+            .unwrap();
+        assert_eq!(tracker.next_call().unwrap().reset, Some(8));
+
+        tracker.push_event(&open).unwrap();
+        assert_eq!(
+            tracker.push_event(&data(
+                Http2Direction::Client,
+                b"\x07\x00\x00\x00\x00",
+                false
+            )),
+            Err(GrpcError::InvalidCompressedFlag(7))
+        );
+        assert_eq!(tracker.next_call().unwrap().method, "Say");
+    }
+
+    #[test]
+    fn open_calls_are_bounded() {
+        // Synthetique : appels jamais clos sur des flux clients successifs.
+        let mut tracker = GrpcCallTracker::new();
+        let mut open = headers(
+            Http2Direction::Client,
+            &[
+                (":path", "/echo.Echo/Say"),
+                ("content-type", "application/grpc"),
+            ],
+            false,
+        );
+        for stream_id in (1..).step_by(2).take(GRPC_MAX_OPEN_CALLS + 1) {
+            if let Http2Event::Headers { stream_id: id, .. } = &mut open {
+                *id = stream_id;
+            }
+            tracker.push_event(&open).unwrap();
+        }
+        assert_eq!(tracker.open.len(), GRPC_MAX_OPEN_CALLS);
+        let oldest = tracker.next_call().unwrap();
+        assert_eq!(oldest.stream_id, 1);
+        assert_eq!(oldest.status, None);
+        assert_eq!(tracker.next_call(), None);
+
+        tracker.close();
+        assert_eq!(tracker.next_call().unwrap().stream_id, 3);
+    }
+}
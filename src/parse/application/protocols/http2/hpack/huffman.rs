@@ -0,0 +1,177 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Huffman code of HPACK string literals (RFC 7541 Appendix B).
+//!
+//! The code is canonical: sorted by length then by symbol, codes are
+//! consecutive integers. The bit lengths alone are therefore enough to
+//! rebuild it, which keeps the table at 257 bytes instead of the 257
+//! `(code, length)` pairs printed in the RFC.
+
+use crate::errors::application::http2::HpackError;
+
+const EOS: u16 = 256;
+const MAX_CODE_LEN: usize = 30;
+
+/// Longueur en bits du code de chaque symbole, octets 0 a 255 puis EOS.
+const CODE_LENGTHS: [u8; 257] = [
+    13, 23, 28, 28, 28, 28, 28, 28, 28, 24, 30, 28, 28, 30, 28, 28, 28, 28, 28, 28, 28, 28, 30, 28,
+    28, 28, 28, 28, 28, 28, 28, 28, 6, 10, 10, 12, 13, 6, 8, 11, 10, 10, 8, 11, 8, 6, 6, 6, 5, 5,
+    5, 6, 6, 6, 6, 6, 6, 6, 7, 8, 15, 6, 12, 10, 13, 6, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7,
+    7, 7, 7, 7, 7, 7, 7, 7, 8, 7, 8, 13, 19, 13, 14, 6, 15, 5, 6, 5, 6, 5, 6, 6, 6, 5, 7, 7, 6, 6,
+    6, 5, 6, 7, 6, 5, 5, 6, 7, 7, 7, 7, 7, 15, 11, 14, 13, 28, 20, 22, 20, 20, 22, 22, 22, 23, 22,
+    23, 23, 23, 23, 23, 24, 23, 24, 24, 22, 23, 24, 23, 23, 23, 23, 21, 22, 23, 22, 23, 23, 24, 22,
+    21, 20, 22, 22, 23, 23, 21, 23, 22, 22, 24, 21, 22, 23, 23, 21, 21, 22, 21, 23, 22, 23, 23, 20,
+    22, 22, 22, 23, 22, 22, 23, 26, 26, 20, 19, 22, 23, 22, 25, 26, 26, 26, 27, 27, 26, 24, 25, 19,
+    21, 26, 27, 27, 26, 27, 24, 21, 21, 26, 26, 28, 27, 27, 27, 20, 24, 20, 21, 22, 21, 21, 23, 22,
+    22, 25, 25, 24, 24, 26, 23, 26, 27, 26, 26, 27, 27, 27, 27, 27, 28, 27, 27, 27, 27, 27, 26, 30,
+];
+
+struct CanonicalCode {
+    // Pour chaque longueur : premier code, nombre de codes et position du
+    // premier symbole de cette longueur dans `symbols`.
+    first_code: [u32; MAX_CODE_LEN + 1],
+    count: [u32; MAX_CODE_LEN + 1],
+    offset: [u16; MAX_CODE_LEN + 1],
+    symbols: [u16; 257],
+}
+
+const fn build_canonical_code() -> CanonicalCode {
+    let mut count = [0u32; MAX_CODE_LEN + 1];
+    let mut symbol = 0;
+    while symbol < CODE_LENGTHS.len() {
+        count[CODE_LENGTHS[symbol] as usize] += 1;
+        symbol += 1;
+    }
+
+    let mut first_code = [0u32; MAX_CODE_LEN + 1];
+    let mut offset = [0u16; MAX_CODE_LEN + 1];
+    let mut code = 0u32;
+    let mut position = 0u16;
+    let mut length = 1;
+    while length <= MAX_CODE_LEN {
+        code = (code + count[length - 1]) << 1;
+        first_code[length] = code;
+        offset[length] = position;
+        position += count[length] as u16;
+        length += 1;
+    }
+
+    // Symboles tries par longueur puis par valeur.
+    let mut symbols = [0u16; 257];
+    let mut next = offset;
+    let mut symbol = 0;
+    while symbol < CODE_LENGTHS.len() {
+        let length = CODE_LENGTHS[symbol] as usize;
+        symbols[next[length] as usize] = symbol as u16;
+        next[length] += 1;
+        symbol += 1;
+    }
+
+    CanonicalCode {
+        first_code,
+        count,
+        offset,
+        symbols,
+    }
+}
+
+static CANONICAL_CODE: CanonicalCode = build_canonical_code();
+
+/// Decodes a Huffman-encoded string literal.
+///
+/// The final partial code must be at most 7 bits of the EOS prefix, i.e.
+/// all ones; a decoded EOS symbol is an error (RFC 7541 §5.2).
+pub(crate) fn decode_huffman(input: &[u8]) -> Result<Vec<u8>, HpackError> {
+    let table = &CANONICAL_CODE;
+    let mut decoded = Vec::with_capacity(input.len() * 8 / 5);
+    let mut code = 0u32;
+    let mut length = 0;
+    for byte in input {
+        for shift in (0..8).rev() {
+            code = code << 1 | u32::from(byte >> shift & 1);
+            length += 1;
+            let index = code.wrapping_sub(table.first_code[length]);
+            if index < table.count[length] {
+                let symbol = table.symbols[usize::from(table.offset[length]) + index as usize];
+                if symbol == EOS {
+                    return Err(HpackError::HuffmanEos);
+                }
+                decoded.push(symbol as u8);
+                code = 0;
+                length = 0;
+            } else if length == MAX_CODE_LEN {
+                // Inatteignable avec un code complet ; garde-fou seulement.
+                return Err(HpackError::HuffmanEos);
+            }
+        }
+    }
+    if length > 7 || code != (1 << length) - 1 {
+        return Err(HpackError::InvalidHuffmanPadding);
+    }
+    Ok(decoded)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Code canonique reconstruit pour `symbol`, a comparer a l'annexe B.
+    fn code_of(symbol: u16) -> (u32, usize) {
+        let table = &CANONICAL_CODE;
+        let length = usize::from(CODE_LENGTHS[usize::from(symbol)]);
+        let start = usize::from(table.offset[length]);
+        let rank = table.symbols[start..start + table.count[length] as usize]
+            .iter()
+            .position(|&candidate| candidate == symbol)
+            .unwrap();
+        (table.first_code[length] + rank as u32, length)
+    }
+
+    #[test]
+    fn canonical_codes_match_appendix_b() {
+        assert_eq!(code_of(0), (0x1ff8, 13));
+        assert_eq!(code_of(u16::from(b'0')), (0x0, 5));
+        assert_eq!(code_of(u16::from(b'a')), (0x3, 5));
+        assert_eq!(code_of(u16::from(b' ')), (0x14, 6));
+        assert_eq!(code_of(u16::from(b'~')), (0x1ffd, 13));
+        assert_eq!(code_of(255), (0x3ffffee, 26));
+        assert_eq!(code_of(EOS), (0x3fffffff, 30));
+    }
+
+    #[test]
+    fn rfc_7541_strings_decode() {
+        // Annexe C.4.1 : "www.example.com".
+        let encoded = [
+            0xf1, 0xe3, 0xc2, 0xe5, 0xf2, 0x3a, 0x6b, 0xa0, 0xab, 0x90, 0xf4, 0xff,
+        ];
+        assert_eq!(decode_huffman(&encoded).unwrap(), b"www.example.com");
+        // Annexe C.4.2 : "no-cache".
+        assert_eq!(
+            decode_huffman(&[0xa8, 0xeb, 0x10, 0x64, 0x9c, 0xbf]).unwrap(),
+            b"no-cache"
+        );
+        assert_eq!(decode_huffman(&[]).unwrap(), b"");
+    }
+
+    #[test]
+    fn padding_and_eos_are_checked() {
+        // Synthetique : "0" (00000) complete par 3 bits a 0 au lieu de 1.
+        assert_eq!(
+            decode_huffman(&[0x00]),
+            Err(HpackError::InvalidHuffmanPadding)
+        );
+        // Un octet entier de bourrage (8 bits a 1) depasse les 7 permis.
+        assert_eq!(
+            decode_huffman(&[0x07, 0xff]),
+            Err(HpackError::InvalidHuffmanPadding)
+        );
+        // EOS explicite : 30 bits a 1.
+        assert_eq!(
+            decode_huffman(&[0xff, 0xff, 0xff, 0xfc]),
+            Err(HpackError::HuffmanEos)
+        );
+    }
+}
@@ -0,0 +1,469 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! HPACK header block decoding (RFC 7541).
+
+use std::collections::VecDeque;
+
+use crate::{
+    checks::application::http2::{HPACK_DEFAULT_TABLE_SIZE, HPACK_MAX_TABLE_SIZE},
+    errors::application::http2::HpackError,
+};
+
+mod huffman;
+mod table;
+
+pub(crate) use huffman::decode_huffman;
+use table::STATIC_TABLE;
+
+/// Surcout fixe d'une entree de table dynamique (RFC 7541 §4.1).
+const ENTRY_OVERHEAD: usize = 32;
+
+/// A decoded header field.
+///
+/// Names and values are kept as bytes: HPACK carries any octet, and a value
+/// may hold obs-text that is not UTF-8.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HeaderField {
+    pub name: Vec<u8>,
+    pub value: Vec<u8>,
+    /// Literal flagged "never indexed" (RFC 7541 §6.2.3): the encoder marked
+    /// the value as sensitive, typically a cookie or a credential.
+    pub never_indexed: bool,
+}
+
+impl HeaderField {
+    /// The name as text, when it is UTF-8.
+    pub fn name_str(&self) -> Option<&str> {
+        std::str::from_utf8(&self.name).ok()
+    }
+
+    /// The value as text, when it is UTF-8.
+    pub fn value_str(&self) -> Option<&str> {
+        std::str::from_utf8(&self.value).ok()
+    }
+}
+
+/// Decodes an HPACK integer with an N-bit prefix (RFC 7541 §5.1).
+///
+/// Returns the value and the bytes that follow it. The prefix bits of the
+/// first byte are the only ones read from it: the caller owns the flags
+/// above them.
+pub(crate) fn decode_integer(input: &[u8], prefix_bits: u8) -> Result<(usize, &[u8]), HpackError> {
+    let (&first, mut rest) = input.split_first().ok_or(HpackError::Truncated)?;
+    let mask = u8::MAX >> (8 - prefix_bits);
+    let mut value = u64::from(first & mask);
+    if value < u64::from(mask) {
+        return Ok((value as usize, rest));
+    }
+    // Au-dela de 5 octets de continuation (35 bits), aucune valeur
+    // legitime : taille de table, index ou longueur de chaine.
+    let mut shift = 0;
+    loop {
+        let (&byte, tail) = rest.split_first().ok_or(HpackError::Truncated)?;
+        rest = tail;
+        if shift > 28 {
+            return Err(HpackError::IntegerOverflow);
+        }
+        value += u64::from(byte & 0x7f) << shift;
+        shift += 7;
+        if byte & 0x80 == 0 {
+            break;
+        }
+    }
+    let value = usize::try_from(value).map_err(|_| HpackError::IntegerOverflow)?;
+    Ok((value, rest))
+}
+
+/// Decodes a string literal whose length has an N-bit prefix, the Huffman
+/// flag being the bit just above it (RFC 7541 §5.2 uses N = 7).
+pub(crate) fn decode_string(input: &[u8], prefix_bits: u8) -> Result<(Vec<u8>, &[u8]), HpackError> {
+    let huffman = input
+        .first()
+        .is_some_and(|&first| first & (1 << prefix_bits) != 0);
+    let (length, rest) = decode_integer(input, prefix_bits)?;
+    if rest.len() < length {
+        return Err(HpackError::Truncated);
+    }
+    let (raw, rest) = rest.split_at(length);
+    let decoded = if huffman {
+        decode_huffman(raw)?
+    } else {
+        raw.to_vec()
+    };
+    Ok((decoded, rest))
+}
+
+/// Stateful HPACK decoder: one per direction of an HTTP/2 connection.
+///
+/// Header blocks must be fed in the order they were sent; a decoding error
+/// leaves the dynamic table out of sync with the encoder and the decoder
+/// cannot be used for that connection anymore (RFC 9113 §4.3).
+#[derive(Debug, Clone)]
+pub struct HpackDecoder {
+    // Entree la plus recente en tete : index 62.
+    dynamic: VecDeque<(Vec<u8>, Vec<u8>)>,
+    size: usize,
+    max_size: usize,
+    limit: usize,
+}
+
+impl Default for HpackDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HpackDecoder {
+    /// Decoder with the default 4096-byte table.
+    pub fn new() -> Self {
+        Self::with_limit(HPACK_DEFAULT_TABLE_SIZE)
+    }
+
+    /// Decoder whose table may grow up to `limit` bytes, the
+    /// SETTINGS_HEADER_TABLE_SIZE announced by the decoding side, capped at
+    /// [`HPACK_MAX_TABLE_SIZE`].
+    pub fn with_limit(limit: usize) -> Self {
+        let limit = limit.min(HPACK_MAX_TABLE_SIZE);
+        Self {
+            dynamic: VecDeque::new(),
+            size: 0,
+            max_size: limit,
+            limit,
+        }
+    }
+
+    /// Applies a new SETTINGS_HEADER_TABLE_SIZE, capped at
+    /// [`HPACK_MAX_TABLE_SIZE`]. Shrinking below the current table size
+    /// evicts entries right away.
+    pub fn set_limit(&mut self, limit: usize) {
+        let limit = limit.min(HPACK_MAX_TABLE_SIZE);
+        self.limit = limit;
+        if self.max_size > limit {
+            self.max_size = limit;
+            self.evict_to(limit);
+        }
+    }
+
+    /// Current size of the dynamic table, overhead included.
+    pub fn table_size(&self) -> usize {
+        self.size
+    }
+
+    /// Dynamic table entries, most recent first.
+    pub fn dynamic_entries(&self) -> impl Iterator<Item = (&[u8], &[u8])> {
+        self.dynamic
+            .iter()
+            .map(|(name, value)| (name.as_slice(), value.as_slice()))
+    }
+
+    /// Decodes a complete header block (HEADERS or PUSH_PROMISE fragment
+    /// followed by its CONTINUATION fragments).
+    pub fn decode(&mut self, block: &[u8]) -> Result<Vec<HeaderField>, HpackError> {
+        let mut fields = Vec::new();
+        let mut rest = block;
+        while let Some(&first) = rest.first() {
+            if first & 0x80 != 0 {
+                // Champ indexe (§6.1).
+                let (index, tail) = decode_integer(rest, 7)?;
+                let (name, value) = self.entry(index)?;
+                fields.push(HeaderField {
+                    name: name.to_vec(),
+                    value: value.to_vec(),
+                    never_indexed: false,
+                });
+                rest = tail;
+            } else if first & 0x40 != 0 {
+                // Litteral avec indexation incrementale (§6.2.1).
+                let (field, tail) = self.decode_literal(rest, 6, false)?;
+                self.insert(field.name.clone(), field.value.clone());
+                fields.push(field);
+                rest = tail;
+            } else if first & 0x20 != 0 {
+                // Mise a jour de taille (§6.3) : uniquement en tete de bloc.
+                if !fields.is_empty() {
+                    return Err(HpackError::LateTableSizeUpdate);
+                }
+                let (size, tail) = decode_integer(rest, 5)?;
+                if size > self.limit {
+                    return Err(HpackError::TableSizeTooLarge {
+                        size,
+                        limit: self.limit,
+                    });
+                }
+                self.max_size = size;
+                self.evict_to(size);
+                rest = tail;
+            } else {
+                // Litteral sans indexation (0000) ou jamais indexe (0001).
+                let never_indexed = first & 0x10 != 0;
+                let (field, tail) = self.decode_literal(rest, 4, never_indexed)?;
+                fields.push(field);
+                rest = tail;
+            }
+        }
+        Ok(fields)
+    }
+
+    fn entry(&self, index: usize) -> Result<(&[u8], &[u8]), HpackError> {
+        if let Some(&(name, value)) = index.checked_sub(1).and_then(|i| STATIC_TABLE.get(i)) {
+            return Ok((name.as_bytes(), value.as_bytes()));
+        }
+        index
+            .checked_sub(STATIC_TABLE.len() + 1)
+            .and_then(|i| self.dynamic.get(i))
+            .map(|(name, value)| (name.as_slice(), value.as_slice()))
+            .ok_or(HpackError::InvalidIndex(index))
+    }
+
+    fn decode_literal<'b>(
+        &self,
+        input: &'b [u8],
+        prefix_bits: u8,
+        never_indexed: bool,
+    ) -> Result<(HeaderField, &'b [u8]), HpackError> {
+        let (index, rest) = decode_integer(input, prefix_bits)?;
+        let (name, rest) = if index == 0 {
+            decode_string(rest, 7)?
+        } else {
+            (self.entry(index)?.0.to_vec(), rest)
+        };
+        let (value, rest) = decode_string(rest, 7)?;
+        Ok((
+            HeaderField {
+                name,
+                value,
+                never_indexed,
+            },
+            rest,
+        ))
+    }
+
+    fn insert(&mut self, name: Vec<u8>, value: Vec<u8>) {
+        let entry_size = name.len() + value.len() + ENTRY_OVERHEAD;
+        // Une entree plus grande que la table la vide sans y entrer (§4.4).
+        if entry_size > self.max_size {
+            self.evict_to(0);
+            return;
+        }
+        self.evict_to(self.max_size - entry_size);
+        self.size += entry_size;
+        self.dynamic.push_front((name, value));
+    }
+
+    fn evict_to(&mut self, target: usize) {
+        while self.size > target {
+            let Some((name, value)) = self.dynamic.pop_back() else {
+                break;
+            };
+            self.size -= name.len() + value.len() + ENTRY_OVERHEAD;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::convert::hex_stream_to_bytes;
+
+    fn pairs(fields: &[HeaderField]) -> Vec<(&str, &str)> {
+        fields
+            .iter()
+            .map(|field| (field.name_str().unwrap(), field.value_str().unwrap()))
+            .collect()
+    }
+
+    fn table(decoder: &HpackDecoder) -> Vec<(&[u8], &[u8])> {
+        decoder.dynamic_entries().collect()
+    }
+
+    #[test]
+    fn integers_decode_per_rfc_7541_c1() {
+        // C.1.1 : 10 sur un prefixe de 5 bits ; C.1.2 : 1337 ; C.1.3 : 42
+        // sur 8 bits.
+        assert_eq!(decode_integer(&[0x0a], 5), Ok((10, &[][..])));
+        assert_eq!(
+            decode_integer(&[0x1f, 0x9a, 0x0a, 0xff], 5),
+            Ok((1337, &[0xff][..]))
+        );
+        assert_eq!(decode_integer(&[0x2a], 8), Ok((42, &[][..])));
+        assert_eq!(decode_integer(&[0x1f, 0x9a], 5), Err(HpackError::Truncated));
+        assert_eq!(
+            decode_integer(&[0x1f, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01], 5),
+            Err(HpackError::IntegerOverflow)
+        );
+    }
+
+    #[test]
+    fn literal_representations_per_rfc_7541_c2() {
+        // C.2.1 : litteral indexe, nom litteral.
+        let mut decoder = HpackDecoder::new();
+        let block = hex_stream_to_bytes("400a637573746f6d2d6b65790d637573746f6d2d686561646572");
+        assert_eq!(
+            pairs(&decoder.decode(&block).unwrap()),
+            [("custom-key", "custom-header")]
+        );
+        assert_eq!(decoder.table_size(), 55);
+
+        // C.2.2 : non indexe, nom indexe ; la table ne change pas.
+        let block = hex_stream_to_bytes("040c2f73616d706c652f70617468");
+        assert_eq!(
+            pairs(&decoder.decode(&block).unwrap()),
+            [(":path", "/sample/path")]
+        );
+        assert_eq!(decoder.table_size(), 55);
+
+        // C.2.3 : jamais indexe.
+        let block = hex_stream_to_bytes("100870617373776f726406736563726574");
+        let fields = decoder.decode(&block).unwrap();
+        assert_eq!(pairs(&fields), [("password", "secret")]);
+        assert!(fields[0].never_indexed);
+
+        // C.2.4 : champ indexe.
+        assert_eq!(
+            pairs(&decoder.decode(&[0x82]).unwrap()),
+            [(":method", "GET")]
+        );
+    }
+
+    #[test]
+    fn huffman_requests_per_rfc_7541_c4() {
+        let mut decoder = HpackDecoder::new();
+        let first = hex_stream_to_bytes("828684418cf1e3c2e5f23a6ba0ab90f4ff");
+        assert_eq!(
+            pairs(&decoder.decode(&first).unwrap()),
+            [
+                (":method", "GET"),
+                (":scheme", "http"),
+                (":path", "/"),
+                (":authority", "www.example.com"),
+            ]
+        );
+        assert_eq!(decoder.table_size(), 57);
+
+        let second = hex_stream_to_bytes("828684be5886a8eb10649cbf");
+        assert_eq!(
+            pairs(&decoder.decode(&second).unwrap()),
+            [
+                (":method", "GET"),
+                (":scheme", "http"),
+                (":path", "/"),
+                (":authority", "www.example.com"),
+                ("cache-control", "no-cache"),
+            ]
+        );
+        assert_eq!(decoder.table_size(), 110);
+
+        let third = hex_stream_to_bytes("828785bf408825a849e95ba97d7f8925a849e95bb8e8b4bf");
+        assert_eq!(
+            pairs(&decoder.decode(&third).unwrap()),
+            [
+                (":method", "GET"),
+                (":scheme", "https"),
+                (":path", "/index.html"),
+                (":authority", "www.example.com"),
+                ("custom-key", "custom-value"),
+            ]
+        );
+        assert_eq!(
+            table(&decoder),
+            [
+                (&b"custom-key"[..], &b"custom-value"[..]),
+                (b"cache-control", b"no-cache"),
+                (b":authority", b"www.example.com"),
+            ]
+        );
+        assert_eq!(decoder.table_size(), 164);
+    }
+
+    #[test]
+    fn huffman_responses_evict_per_rfc_7541_c6() {
+        let mut decoder = HpackDecoder::with_limit(256);
+        let first = hex_stream_to_bytes(
+            "488264025885aec3771a4b6196d07abe941054d444a8200595040b8166e082a62d1bff6e919d29ad171863c78f0b97c8e9ae82ae43d3",
+        );
+        assert_eq!(
+            pairs(&decoder.decode(&first).unwrap()),
+            [
+                (":status", "302"),
+                ("cache-control", "private"),
+                ("date", "Mon, 21 Oct 2013 20:13:21 GMT"),
+                ("location", "https://www.example.com"),
+            ]
+        );
+        assert_eq!(decoder.table_size(), 222);
+
+        // C.6.2 : l'ajout de ":status 307" evince ":status 302".
+        let second = hex_stream_to_bytes("4883640effc1c0bf");
+        assert_eq!(
+            pairs(&decoder.decode(&second).unwrap())[0],
+            (":status", "307")
+        );
+        assert_eq!(decoder.table_size(), 222);
+
+        let third = hex_stream_to_bytes(
+            "88c16196d07abe941054d444a8200595040b8166e084a62d1bffc05a839bd9ab77ad94e7821dd7f2e6c7b335dfdfcd5b3960d5af27087f3672c1ab270fb5291f9587316065c003ed4ee5b1063d5007",
+        );
+        assert_eq!(
+            pairs(&decoder.decode(&third).unwrap()),
+            [
+                (":status", "200"),
+                ("cache-control", "private"),
+                ("date", "Mon, 21 Oct 2013 20:13:22 GMT"),
+                ("location", "https://www.example.com"),
+                ("content-encoding", "gzip"),
+                (
+                    "set-cookie",
+                    "foo=ASDJKHQKBZXOQWEOPIUAXQWEOIU; max-age=3600; version=1"
+                ),
+            ]
+        );
+        assert_eq!(
+            table(&decoder),
+            [
+                (
+                    &b"set-cookie"[..],
+                    &b"foo=ASDJKHQKBZXOQWEOPIUAXQWEOIU; max-age=3600; version=1"[..]
+                ),
+                (b"content-encoding", b"gzip"),
+                (b"date", b"Mon, 21 Oct 2013 20:13:22 GMT"),
+            ]
+        );
+        assert_eq!(decoder.table_size(), 215);
+    }
+
+    #[test]
+    fn table_size_updates_are_bounded_and_leading() {
+        let mut decoder = HpackDecoder::new();
+        decoder
+            .decode(&hex_stream_to_bytes(
+                "400a637573746f6d2d6b65790d637573746f6d2d686561646572",
+            ))
+            .unwrap();
+        // Synthetique : mise a jour a 0 (vide la table) puis index 62 absent.
+        assert_eq!(
+            decoder.decode(&[0x20, 0xbe]),
+            Err(HpackError::InvalidIndex(62))
+        );
+        assert_eq!(decoder.table_size(), 0);
+        // 4097 > 4096 annonces par SETTINGS.
+        assert_eq!(
+            decoder.decode(&[0x3f, 0xe2, 0x1f]),
+            Err(HpackError::TableSizeTooLarge {
+                size: 4097,
+                limit: 4096
+            })
+        );
+        assert_eq!(
+            decoder.decode(&[0x82, 0x20]),
+            Err(HpackError::LateTableSizeUpdate)
+        );
+        assert_eq!(decoder.decode(&[0x80]), Err(HpackError::InvalidIndex(0)));
+        assert_eq!(
+            decoder.decode(&[0x41, 0x05, b'a']),
+            Err(HpackError::Truncated)
+        );
+    }
+}
@@ -0,0 +1,429 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! HTTP/2 framing (RFC 9113), HPACK header compression and gRPC calls.
+//!
+//! [`Http2Frame`] decodes a single frame without state. Header blocks need
+//! the per-connection HPACK state: [`connection::Http2Connection`] follows
+//! both directions of a connection and yields per-stream header lists and
+//! data, which [`grpc::GrpcCallTracker`] turns into gRPC calls.
+
+pub mod connection;
+pub mod grpc;
+pub mod hpack;
+
+use crate::{
+    checks::application::http2::{
+        HTTP2_FLAG_ACK, HTTP2_FLAG_END_HEADERS, HTTP2_FLAG_END_STREAM, HTTP2_FLAG_PRIORITY,
+        HTTP2_FRAME_CONTINUATION, HTTP2_FRAME_DATA, HTTP2_FRAME_GOAWAY, HTTP2_FRAME_HEADER_LEN,
+        HTTP2_FRAME_HEADERS, HTTP2_FRAME_PING, HTTP2_FRAME_PRIORITY, HTTP2_FRAME_PUSH_PROMISE,
+        HTTP2_FRAME_RST_STREAM, HTTP2_FRAME_SETTINGS, HTTP2_FRAME_WINDOW_UPDATE,
+        extract_http2_frame_header, strip_http2_padding, validate_http2_payload_length,
+        validate_http2_stream_id,
+    },
+    errors::application::http2::Http2Error,
+};
+use hpack::HeaderField;
+
+/// HTTP/2 Frame
+///
+/// ```mermaid
+/// ---
+/// title: Http2Frame
+/// ---
+/// packet-beta
+/// 0-23: "Length"
+/// 24-31: "Type"
+/// 32-39: "Flags"
+/// 40: "R"
+/// 41-71: "Stream Identifier"
+/// 72-135: "Frame Payload variable"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Http2Frame<'a> {
+    pub frame_type: u8,
+    pub flags: u8,
+    /// Stream identifier, reserved bit cleared.
+    pub stream_id: u32,
+    /// The `Length` bytes following the header, padding included.
+    pub payload: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for Http2Frame<'a> {
+    type Error = Http2Error;
+
+    /// Decodes the first frame of `bytes`; anything after it is left to the
+    /// caller, see [`Http2Frame::encoded_len`].
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        let (length, frame_type, flags, stream_id) =
+            extract_http2_frame_header(bytes).ok_or(Http2Error::TruncatedFrameHeader {
+                available: bytes.len(),
+            })?;
+        let payload = bytes
+            .get(HTTP2_FRAME_HEADER_LEN..HTTP2_FRAME_HEADER_LEN + length)
+            .ok_or(Http2Error::TruncatedFrame {
+                declared: length,
+                available: bytes.len() - HTTP2_FRAME_HEADER_LEN,
+            })?;
+        validate_http2_stream_id(frame_type, stream_id)?;
+        Ok(Http2Frame {
+            frame_type,
+            flags,
+            stream_id,
+            payload,
+        })
+    }
+}
+
+/// Stream dependency carried by PRIORITY and prioritised HEADERS frames.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Http2Priority {
+    pub exclusive: bool,
+    pub dependency: u32,
+    /// Raw weight; the effective weight is this value plus one.
+    pub weight: u8,
+}
+
+/// Type-specific content of a frame, padding removed (RFC 9113 §6).
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Http2FramePayload<'a> {
+    Data {
+        data: &'a [u8],
+    },
+    Headers {
+        priority: Option<Http2Priority>,
+        /// HPACK fragment, possibly continued by CONTINUATION frames.
+        header_block: &'a [u8],
+    },
+    Priority(Http2Priority),
+    RstStream {
+        error_code: u32,
+    },
+    Settings {
+        ack: bool,
+        parameters: Vec<(u16, u32)>,
+    },
+    PushPromise {
+        promised_stream_id: u32,
+        header_block: &'a [u8],
+    },
+    Ping {
+        ack: bool,
+        opaque_data: [u8; 8],
+    },
+    GoAway {
+        last_stream_id: u32,
+        error_code: u32,
+        debug_data: &'a [u8],
+    },
+    WindowUpdate {
+        increment: u32,
+    },
+    Continuation {
+        header_block: &'a [u8],
+    },
+    /// Extension frame type, to be ignored (RFC 9113 §5.5).
+    Unknown {
+        payload: &'a [u8],
+    },
+}
+
+fn read_u32(bytes: &[u8]) -> u32 {
+    let mut word = [0; 4];
+    word.copy_from_slice(&bytes[..4]);
+    u32::from_be_bytes(word)
+}
+
+fn read_priority(bytes: &[u8]) -> Http2Priority {
+    let dependency = read_u32(bytes);
+    Http2Priority {
+        exclusive: dependency & 0x8000_0000 != 0,
+        dependency: dependency & 0x7fff_ffff,
+        weight: bytes[4],
+    }
+}
+
+impl<'a> Http2Frame<'a> {
+    /// Size of the frame on the wire, header included.
+    pub fn encoded_len(&self) -> usize {
+        HTTP2_FRAME_HEADER_LEN + self.payload.len()
+    }
+
+    /// Registered name of the frame type.
+    pub fn type_name(&self) -> &'static str {
+        match self.frame_type {
+            HTTP2_FRAME_DATA => "DATA",
+            HTTP2_FRAME_HEADERS => "HEADERS",
+            HTTP2_FRAME_PRIORITY => "PRIORITY",
+            HTTP2_FRAME_RST_STREAM => "RST_STREAM",
+            HTTP2_FRAME_SETTINGS => "SETTINGS",
+            HTTP2_FRAME_PUSH_PROMISE => "PUSH_PROMISE",
+            HTTP2_FRAME_PING => "PING",
+            HTTP2_FRAME_GOAWAY => "GOAWAY",
+            HTTP2_FRAME_WINDOW_UPDATE => "WINDOW_UPDATE",
+            HTTP2_FRAME_CONTINUATION => "CONTINUATION",
+            _ => "UNKNOWN",
+        }
+    }
+
+    /// END_STREAM on a DATA or HEADERS frame.
+    pub fn is_end_stream(&self) -> bool {
+        matches!(self.frame_type, HTTP2_FRAME_DATA | HTTP2_FRAME_HEADERS)
+            && self.flags & HTTP2_FLAG_END_STREAM != 0
+    }
+
+    /// END_HEADERS on a HEADERS, PUSH_PROMISE or CONTINUATION frame.
+    pub fn is_end_headers(&self) -> bool {
+        matches!(
+            self.frame_type,
+            HTTP2_FRAME_HEADERS | HTTP2_FRAME_PUSH_PROMISE | HTTP2_FRAME_CONTINUATION
+        ) && self.flags & HTTP2_FLAG_END_HEADERS != 0
+    }
+
+    /// Decodes the type-specific payload, checking its length.
+    pub fn payload(&self) -> Result<Http2FramePayload<'a>, Http2Error> {
+        let frame_type = self.frame_type;
+        let payload = self.payload;
+        Ok(match frame_type {
+            HTTP2_FRAME_DATA => Http2FramePayload::Data {
+                data: strip_http2_padding(payload, self.flags)?,
+            },
+            HTTP2_FRAME_HEADERS => {
+                let fragment = strip_http2_padding(payload, self.flags)?;
+                if self.flags & HTTP2_FLAG_PRIORITY != 0 {
+                    if fragment.len() < 5 {
+                        return Err(Http2Error::InvalidFrameLength {
+                            frame_type,
+                            length: payload.len(),
+                        });
+                    }
+                    Http2FramePayload::Headers {
+                        priority: Some(read_priority(fragment)),
+                        header_block: &fragment[5..],
+                    }
+                } else {
+                    Http2FramePayload::Headers {
+                        priority: None,
+                        header_block: fragment,
+                    }
+                }
+            }
+            HTTP2_FRAME_PRIORITY => {
+                validate_http2_payload_length(frame_type, payload, 5)?;
+                Http2FramePayload::Priority(read_priority(payload))
+            }
+            HTTP2_FRAME_RST_STREAM => {
+                validate_http2_payload_length(frame_type, payload, 4)?;
+                Http2FramePayload::RstStream {
+                    error_code: read_u32(payload),
+                }
+            }
+            HTTP2_FRAME_SETTINGS => {
+                let ack = self.flags & HTTP2_FLAG_ACK != 0;
+                if !payload.len().is_multiple_of(6) || (ack && !payload.is_empty()) {
+                    return Err(Http2Error::InvalidFrameLength {
+                        frame_type,
+                        length: payload.len(),
+                    });
+                }
+                let parameters = payload
+                    .chunks_exact(6)
+                    .map(|setting| {
+                        (
+                            u16::from_be_bytes([setting[0], setting[1]]),
+                            read_u32(&setting[2..]),
+                        )
+                    })
+                    .collect();
+                Http2FramePayload::Settings { ack, parameters }
+            }
+            HTTP2_FRAME_PUSH_PROMISE => {
+                let fragment = strip_http2_padding(payload, self.flags)?;
+                if fragment.len() < 4 {
+                    return Err(Http2Error::InvalidFrameLength {
+                        frame_type,
+                        length: payload.len(),
+                    });
+                }
+                Http2FramePayload::PushPromise {
+                    promised_stream_id: read_u32(fragment) & 0x7fff_ffff,
+                    header_block: &fragment[4..],
+                }
+            }
+            HTTP2_FRAME_PING => {
+                validate_http2_payload_length(frame_type, payload, 8)?;
+                let mut opaque_data = [0; 8];
+                opaque_data.copy_from_slice(payload);
+                Http2FramePayload::Ping {
+                    ack: self.flags & HTTP2_FLAG_ACK != 0,
+                    opaque_data,
+                }
+            }
+            HTTP2_FRAME_GOAWAY => {
+                if payload.len() < 8 {
+                    return Err(Http2Error::InvalidFrameLength {
+                        frame_type,
+                        length: payload.len(),
+                    });
+                }
+                Http2FramePayload::GoAway {
+                    last_stream_id: read_u32(payload) & 0x7fff_ffff,
+                    error_code: read_u32(&payload[4..]),
+                    debug_data: &payload[8..],
+                }
+            }
+            HTTP2_FRAME_WINDOW_UPDATE => {
+                validate_http2_payload_length(frame_type, payload, 4)?;
+                Http2FramePayload::WindowUpdate {
+                    increment: read_u32(payload) & 0x7fff_ffff,
+                }
+            }
+            HTTP2_FRAME_CONTINUATION => Http2FramePayload::Continuation {
+                header_block: payload,
+            },
+            _ => Http2FramePayload::Unknown { payload },
+        })
+    }
+}
+
+/// Value of the first header field named `name` (ASCII case-insensitive),
+/// when it is UTF-8. HTTP/2 field names are lowercase on the wire.
+pub fn header_value<'a>(headers: &'a [HeaderField], name: &str) -> Option<&'a str> {
+    headers
+        .iter()
+        .find(|field| field.name.eq_ignore_ascii_case(name.as_bytes()))
+        .and_then(HeaderField::value_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checks::application::http2::HTTP2_FLAG_PADDED;
+
+    #[test]
+    fn settings_frame_decodes() {
+        // Synthetique : SETTINGS serveur, HEADER_TABLE_SIZE=8192 et
+        // MAX_CONCURRENT_STREAMS=100, suivi d'un octet d'une autre trame.
+        let bytes = [
+            0x00, 0x00, 0x0c, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x20,
+            0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x64, 0xff,
+        ];
+        let frame = Http2Frame::try_from(&bytes[..]).unwrap();
+        assert_eq!(frame.type_name(), "SETTINGS");
+        assert_eq!(frame.encoded_len(), 21);
+        assert_eq!(
+            frame.payload(),
+            Ok(Http2FramePayload::Settings {
+                ack: false,
+                parameters: vec![(1, 8192), (3, 100)],
+            })
+        );
+    }
+
+    #[test]
+    fn padded_prioritised_headers_decode() {
+        // Synthetique : HEADERS PADDED|PRIORITY|END_HEADERS|END_STREAM sur le
+        // flux 3, dependance exclusive du flux 1, poids 16, bloc 0x82,
+        // 2 octets de bourrage.
+        let bytes = [
+            0x00, 0x00, 0x09, 0x01, 0x2d, 0x00, 0x00, 0x00, 0x03, 0x02, 0x80, 0x00, 0x00, 0x01,
+            0x0f, 0x82, 0x00, 0x00,
+        ];
+        let frame = Http2Frame::try_from(&bytes[..]).unwrap();
+        assert!(frame.flags & HTTP2_FLAG_PADDED != 0);
+        assert!(frame.is_end_stream());
+        assert!(frame.is_end_headers());
+        assert_eq!(
+            frame.payload(),
+            Ok(Http2FramePayload::Headers {
+                priority: Some(Http2Priority {
+                    exclusive: true,
+                    dependency: 1,
+                    weight: 15,
+                }),
+                header_block: &[0x82],
+            })
+        );
+    }
+
+    #[test]
+    fn control_frames_decode() {
+        // Synthetique : GOAWAY (dernier flux 5, ENHANCE_YOUR_CALM, "slow"),
+        // WINDOW_UPDATE de 65535 et RST_STREAM CANCEL.
+        let goaway = [
+            0x00, 0x00, 0x0c, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x05, 0x00,
+            0x00, 0x00, 0x0b, b's', b'l', b'o', b'w',
+        ];
+        assert_eq!(
+            Http2Frame::try_from(&goaway[..]).unwrap().payload(),
+            Ok(Http2FramePayload::GoAway {
+                last_stream_id: 5,
+                error_code: 0x0b,
+                debug_data: b"slow",
+            })
+        );
+        let window_update = [
+            0x00, 0x00, 0x04, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0xff,
+        ];
+        assert_eq!(
+            Http2Frame::try_from(&window_update[..]).unwrap().payload(),
+            Ok(Http2FramePayload::WindowUpdate { increment: 65_535 })
+        );
+        let rst_stream = [
+            0x00, 0x00, 0x04, 0x03, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x08,
+        ];
+        assert_eq!(
+            Http2Frame::try_from(&rst_stream[..]).unwrap().payload(),
+            Ok(Http2FramePayload::RstStream { error_code: 8 })
+        );
+    }
+
+    #[test]
+    fn malformed_frames_are_rejected() {
+        assert_eq!(
+            Http2Frame::try_from(&[0x00, 0x00, 0x04][..]),
+            Err(Http2Error::TruncatedFrameHeader { available: 3 })
+        );
+        assert_eq!(
+            Http2Frame::try_from(&[0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0xaa][..]),
+            Err(Http2Error::TruncatedFrame {
+                declared: 4,
+                available: 1
+            })
+        );
+        // PING sur un flux, SETTINGS ACK avec contenu, PING de 7 octets.
+        assert!(matches!(
+            Http2Frame::try_from(&[0x00, 0x00, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x01][..]),
+            Err(Http2Error::InvalidStreamId { .. })
+        ));
+        let settings_ack = [
+            0x00, 0x00, 0x06, 0x04, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x10,
+            0x00,
+        ];
+        assert!(
+            Http2Frame::try_from(&settings_ack[..])
+                .unwrap()
+                .payload()
+                .is_err()
+        );
+        let short_ping = [
+            0x00, 0x00, 0x07, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        assert_eq!(
+            Http2Frame::try_from(&short_ping[..]).unwrap().payload(),
+            Err(Http2Error::InvalidFrameLength {
+                frame_type: HTTP2_FRAME_PING,
+                length: 7
+            })
+        );
+        // Type inconnu : transmis tel quel.
+        let extension = [0x00, 0x00, 0x01, 0x42, 0x00, 0x00, 0x00, 0x00, 0x00, 0x07];
+        assert_eq!(
+            Http2Frame::try_from(&extension[..]).unwrap().payload(),
+            Ok(Http2FramePayload::Unknown { payload: &[0x07] })
+        );
+    }
+}
@@ -0,0 +1,1028 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Passive follow-up of an HTTP/3 connection: reassembly of each QUIC
+//! stream, unidirectional stream types, control stream SETTINGS and QPACK
+//! state of each direction.
+
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+use super::{Http3Frame, Http3FramePayload, qpack::QpackDecoder};
+use crate::{
+    checks::application::{
+        http3::{
+            HTTP3_FRAME_DATA, HTTP3_MAX_TRACKED_STREAMS, HTTP3_SETTINGS_QPACK_MAX_TABLE_CAPACITY,
+            HTTP3_STREAM_CONTROL, HTTP3_STREAM_PUSH, HTTP3_STREAM_QPACK_DECODER,
+            HTTP3_STREAM_QPACK_ENCODER, QPACK_MAX_BLOCKED_SECTIONS, extract_http3_frame_header,
+            validate_http3_frame_length, validate_http3_frame_type,
+        },
+        quic::{QUIC_MAX_STREAM_BUFFER, QuicCursor},
+    },
+    errors::application::{
+        http3::{Http3Error, QpackError},
+        quic::QuicStreamError,
+    },
+    parse::application::protocols::{
+        http2::hpack::HeaderField,
+        quic::stream::{QuicStreamFrame, QuicStreamReassembler},
+    },
+};
+
+/// Sender of a STREAM frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Http3Direction {
+    /// Client to server: requests.
+    Client,
+    /// Server to client: responses and server pushes.
+    Server,
+}
+
+/// What happened on a stream, in stream order.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Http3Event {
+    /// A decoded field section: request headers from the client, response
+    /// headers (informational or final) or trailers from either side.
+    Headers {
+        direction: Http3Direction,
+        stream_id: u64,
+        headers: Vec<HeaderField>,
+    },
+    /// Content of a DATA frame, delivered as it arrives: a large frame
+    /// spans several events.
+    Data {
+        direction: Http3Direction,
+        stream_id: u64,
+        data: Vec<u8>,
+    },
+    /// The sender closed its side of a request or push stream (QUIC FIN).
+    StreamEnd {
+        direction: Http3Direction,
+        stream_id: u64,
+    },
+    /// Request the server announces it will push as `push_id`.
+    PushPromise {
+        stream_id: u64,
+        push_id: u64,
+        headers: Vec<HeaderField>,
+    },
+    /// Server push stream carrying the response for `push_id`.
+    PushStream { stream_id: u64, push_id: u64 },
+    Settings {
+        direction: Http3Direction,
+        parameters: Vec<(u64, u64)>,
+    },
+    GoAway {
+        direction: Http3Direction,
+        /// Stream id (from the server) or push id (from the client).
+        id: u64,
+    },
+}
+
+// Role d'un flux, connu a l'ouverture pour un flux bidirectionnel, apres
+// le premier varint pour un flux unidirectionnel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum StreamKind {
+    Request,
+    Unidirectional,
+    Control { settings_seen: bool },
+    Push { push_id: Option<u64> },
+    QpackEncoder,
+    QpackDecoder,
+    // Type inconnu ou grease : contenu ignore (RFC 9114 §6.2).
+    Ignored,
+}
+
+// Section bloquee en attente d'insertions du flux encodeur.
+#[derive(Debug, Clone)]
+struct BlockedSection {
+    push_id: Option<u64>,
+    section: Vec<u8>,
+}
+
+#[derive(Debug, Clone)]
+struct StreamState {
+    kind: StreamKind,
+    reassembler: QuicStreamReassembler,
+    buffer: Vec<u8>,
+    // Octets restant a remettre de la trame DATA en cours.
+    data_remaining: u64,
+    blocked: Option<BlockedSection>,
+    ended: bool,
+}
+
+impl StreamState {
+    fn new(frame: &QuicStreamFrame<'_>) -> Self {
+        Self {
+            kind: if frame.is_unidirectional() {
+                StreamKind::Unidirectional
+            } else {
+                StreamKind::Request
+            },
+            reassembler: QuicStreamReassembler::new(),
+            buffer: Vec::new(),
+            data_remaining: 0,
+            blocked: None,
+            ended: false,
+        }
+    }
+
+    fn is_critical(&self) -> bool {
+        matches!(
+            self.kind,
+            StreamKind::Control { .. } | StreamKind::QpackEncoder | StreamKind::QpackDecoder
+        )
+    }
+
+    // Flux dont l'etat peut etre abandonne : requete, push ou type ignore.
+    // Un flux unidirectionnel dont le type n'est pas encore lu peut encore
+    // se reveler critique.
+    fn is_evictable(&self) -> bool {
+        matches!(
+            self.kind,
+            StreamKind::Request | StreamKind::Push { .. } | StreamKind::Ignored
+        )
+    }
+
+    fn is_closed(&self) -> bool {
+        self.ended || (self.kind == StreamKind::Ignored && self.reassembler.is_finished())
+    }
+}
+
+// Etat des flux ouverts ou alimentes par un cote de la connexion.
+#[derive(Debug, Clone)]
+struct Endpoint {
+    direction: Http3Direction,
+    streams: HashMap<u64, StreamState>,
+    // Flux termines, abandonnes ou evinces : leurs trames tardives sont
+    // ignorees.
+    closed: BTreeSet<u64>,
+    // Decode les sections emises par ce cote ; capacite maximale fixee par
+    // les SETTINGS du cote oppose.
+    decoder: QpackDecoder,
+    control_stream: Option<u64>,
+    encoder_stream: Option<u64>,
+    decoder_stream: Option<u64>,
+    blocked_sections: usize,
+    failed: bool,
+}
+
+impl Endpoint {
+    fn new(direction: Http3Direction) -> Self {
+        Self {
+            direction,
+            streams: HashMap::new(),
+            closed: BTreeSet::new(),
+            decoder: QpackDecoder::new(),
+            control_stream: None,
+            encoder_stream: None,
+            decoder_stream: None,
+            blocked_sections: 0,
+            failed: false,
+        }
+    }
+}
+
+/// Follows both directions of an HTTP/3 connection.
+///
+/// Feed the STREAM frames of the decrypted 1-RTT packets of each direction
+/// with [`push_stream_frame`](Self::push_stream_frame), in any order, then
+/// drain the stream events with [`next_event`](Self::next_event). Each
+/// stream is reassembled on its own: a lost or reordered packet only delays
+/// the streams it carries.
+///
+/// The state of a request or push stream is dropped once it ends, and at
+/// most [`HTTP3_MAX_TRACKED_STREAMS`] of them are followed per direction:
+/// beyond, the lowest stream id is abandoned. Late frames of a dropped
+/// stream are ignored.
+///
+/// An error on a request stream abandons that stream. An error on a control
+/// or QPACK stream leaves the QPACK state of that direction out of sync: the
+/// direction is abandoned and its later frames are ignored, the other one
+/// is still decoded.
+#[derive(Debug, Clone)]
+pub struct Http3Connection {
+    client: Endpoint,
+    server: Endpoint,
+    events: VecDeque<Http3Event>,
+}
+
+impl Default for Http3Connection {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Http3Connection {
+    pub fn new() -> Self {
+        Self {
+            client: Endpoint::new(Http3Direction::Client),
+            server: Endpoint::new(Http3Direction::Server),
+            events: VecDeque::new(),
+        }
+    }
+
+    /// Takes a STREAM frame sent by `direction`.
+    pub fn push_stream_frame(
+        &mut self,
+        direction: Http3Direction,
+        frame: &QuicStreamFrame<'_>,
+    ) -> Result<(), Http3Error> {
+        let (sender, receiver) = match direction {
+            Http3Direction::Client => (&mut self.client, &mut self.server),
+            Http3Direction::Server => (&mut self.server, &mut self.client),
+        };
+        if sender.failed || sender.closed.contains(&frame.stream_id) {
+            return Ok(());
+        }
+
+        let mut state = match sender.streams.remove(&frame.stream_id) {
+            Some(state) => state,
+            None => {
+                evict_oldest_stream(sender);
+                StreamState::new(frame)
+            }
+        };
+        let result = receive(&mut state, frame).and_then(|()| {
+            process_stream(
+                sender,
+                receiver,
+                &mut self.events,
+                frame.stream_id,
+                &mut state,
+            )
+        });
+        let inserted = settle(sender, frame.stream_id, state, result)?;
+        if inserted {
+            retry_blocked(sender, receiver, &mut self.events)?;
+        }
+        Ok(())
+    }
+
+    /// Pops the oldest event.
+    pub fn next_event(&mut self) -> Option<Http3Event> {
+        self.events.pop_front()
+    }
+
+    /// Whether `direction` was abandoned after an error on a control or
+    /// QPACK stream.
+    pub fn has_failed(&self, direction: Http3Direction) -> bool {
+        self.endpoint(direction).failed
+    }
+
+    /// QPACK decoder of the field sections sent by `direction`.
+    pub fn decoder(&self, direction: Http3Direction) -> &QpackDecoder {
+        &self.endpoint(direction).decoder
+    }
+
+    fn endpoint(&self, direction: Http3Direction) -> &Endpoint {
+        match direction {
+            Http3Direction::Client => &self.client,
+            Http3Direction::Server => &self.server,
+        }
+    }
+}
+
+// Range la frame et transfere les octets contigus dans le tampon du flux.
+fn receive(state: &mut StreamState, frame: &QuicStreamFrame<'_>) -> Result<(), Http3Error> {
+    state.reassembler.push(frame)?;
+    while let Some(chunk) = state.reassembler.next_chunk() {
+        state.buffer.extend_from_slice(&chunk);
+    }
+    // Seul un flux bloque par QPACK accumule au-dela d'une trame.
+    if state.buffer.len() > QUIC_MAX_STREAM_BUFFER {
+        return Err(QuicStreamError::BufferExceeded {
+            buffered: state.buffer.len(),
+            max: QUIC_MAX_STREAM_BUFFER,
+        }
+        .into());
+    }
+    Ok(())
+}
+
+// Remet l'etat du flux en place ; une erreur abandonne le flux, et tout le
+// sens s'il s'agit d'un flux critique. Un flux termine ou abandonne ne
+// garde que son identifiant.
+fn settle(
+    sender: &mut Endpoint,
+    stream_id: u64,
+    state: StreamState,
+    result: Result<bool, Http3Error>,
+) -> Result<bool, Http3Error> {
+    if result.is_err() {
+        if state.is_critical() {
+            sender.failed = true;
+            sender.streams.clear();
+            sender.closed.clear();
+            sender.blocked_sections = 0;
+            return result;
+        }
+        close_stream(sender, stream_id, state);
+        return result;
+    }
+    if state.is_closed() {
+        close_stream(sender, stream_id, state);
+    } else {
+        sender.streams.insert(stream_id, state);
+    }
+    result
+}
+
+// Oublie l'etat d'un flux et retient son identifiant, dans la limite de
+// HTTP3_MAX_TRACKED_STREAMS (le plus petit est oublie en premier).
+fn close_stream(sender: &mut Endpoint, stream_id: u64, state: StreamState) {
+    if state.blocked.is_some() {
+        sender.blocked_sections -= 1;
+    }
+    sender.closed.insert(stream_id);
+    if sender.closed.len() > HTTP3_MAX_TRACKED_STREAMS {
+        sender.closed.pop_first();
+    }
+}
+
+// Libere une place avant d'ouvrir un flux : au-dela de
+// HTTP3_MAX_TRACKED_STREAMS, le flux abandonnable de plus petit
+// identifiant, donc le plus ancien de son type, est abandonne.
+fn evict_oldest_stream(sender: &mut Endpoint) {
+    if sender.streams.len() < HTTP3_MAX_TRACKED_STREAMS {
+        return;
+    }
+    let oldest = sender
+        .streams
+        .iter()
+        .filter(|(_, state)| state.is_evictable())
+        .map(|(&stream_id, _)| stream_id)
+        .min();
+    if let Some(stream_id) = oldest
+        && let Some(state) = sender.streams.remove(&stream_id)
+    {
+        close_stream(sender, stream_id, state);
+    }
+}
+
+// Reprend les flux bloques apres de nouvelles insertions.
+fn retry_blocked(
+    sender: &mut Endpoint,
+    receiver: &mut Endpoint,
+    events: &mut VecDeque<Http3Event>,
+) -> Result<(), Http3Error> {
+    let mut blocked: Vec<u64> = sender
+        .streams
+        .iter()
+        .filter(|(_, state)| state.blocked.is_some())
+        .map(|(&stream_id, _)| stream_id)
+        .collect();
+    blocked.sort_unstable();
+
+    let mut first_error = Ok(());
+    for stream_id in blocked {
+        let Some(mut state) = sender.streams.remove(&stream_id) else {
+            continue;
+        };
+        let result = process_stream(sender, receiver, events, stream_id, &mut state);
+        if let Err(error) = settle(sender, stream_id, state, result)
+            && first_error.is_ok()
+        {
+            first_error = Err(error);
+        }
+    }
+    first_error
+}
+
+// Traite les octets en tampon ; vrai si le flux encodeur a insere des
+// entrees.
+fn process_stream(
+    sender: &mut Endpoint,
+    receiver: &mut Endpoint,
+    events: &mut VecDeque<Http3Event>,
+    stream_id: u64,
+    state: &mut StreamState,
+) -> Result<bool, Http3Error> {
+    if state.kind == StreamKind::Unidirectional {
+        let mut cur = QuicCursor::new(&state.buffer);
+        let Ok(stream_type) = cur.read_varint() else {
+            return Ok(false);
+        };
+        let consumed = state.buffer.len() - cur.remaining();
+        state.kind = match stream_type {
+            HTTP3_STREAM_CONTROL => StreamKind::Control {
+                settings_seen: false,
+            },
+            HTTP3_STREAM_PUSH => StreamKind::Push { push_id: None },
+            HTTP3_STREAM_QPACK_ENCODER => StreamKind::QpackEncoder,
+            HTTP3_STREAM_QPACK_DECODER => StreamKind::QpackDecoder,
+            _ => StreamKind::Ignored,
+        };
+        // Un flux critique en double est une erreur de connexion (RFC 9114
+        // §6.2.1) : le type est fixe avant pour que tout le sens tombe.
+        let slot = match state.kind {
+            StreamKind::Control { .. } => Some(&mut sender.control_stream),
+            StreamKind::QpackEncoder => Some(&mut sender.encoder_stream),
+            StreamKind::QpackDecoder => Some(&mut sender.decoder_stream),
+            _ => None,
+        };
+        if let Some(slot) = slot {
+            if slot.is_some() {
+                return Err(Http3Error::DuplicateStream {
+                    stream_type,
+                    stream_id,
+                });
+            }
+            *slot = Some(stream_id);
+        }
+        state.buffer.drain(..consumed);
+    }
+
+    if state.kind == (StreamKind::Push { push_id: None }) {
+        let mut cur = QuicCursor::new(&state.buffer);
+        let Ok(push_id) = cur.read_varint() else {
+            return Ok(false);
+        };
+        let consumed = state.buffer.len() - cur.remaining();
+        state.buffer.drain(..consumed);
+        state.kind = StreamKind::Push {
+            push_id: Some(push_id),
+        };
+        events.push_back(Http3Event::PushStream { stream_id, push_id });
+    }
+
+    match state.kind {
+        StreamKind::QpackEncoder => {
+            let inserted = sender.decoder.feed_encoder_stream(&state.buffer)?;
+            state.buffer.clear();
+            if state.reassembler.is_finished() {
+                return Err(Http3Error::ClosedCriticalStream(stream_id));
+            }
+            Ok(inserted > 0)
+        }
+        // Le flux decodeur porte les acquittements du pair, sans effet sur
+        // le decodage ; les flux inconnus sont ignores.
+        StreamKind::QpackDecoder | StreamKind::Ignored => {
+            state.buffer.clear();
+            if state.kind == StreamKind::QpackDecoder && state.reassembler.is_finished() {
+                return Err(Http3Error::ClosedCriticalStream(stream_id));
+            }
+            Ok(false)
+        }
+        StreamKind::Unidirectional | StreamKind::Push { push_id: None } => Ok(false),
+        StreamKind::Request | StreamKind::Control { .. } | StreamKind::Push { .. } => {
+            process_frames(sender, receiver, events, stream_id, state)?;
+            Ok(false)
+        }
+    }
+}
+
+fn process_frames(
+    sender: &mut Endpoint,
+    receiver: &mut Endpoint,
+    events: &mut VecDeque<Http3Event>,
+    stream_id: u64,
+    state: &mut StreamState,
+) -> Result<(), Http3Error> {
+    let direction = sender.direction;
+    loop {
+        if let Some(blocked) = &state.blocked {
+            match sender.decoder.decode(&blocked.section) {
+                Err(QpackError::Blocked { .. }) => return Ok(()),
+                Err(error) => return Err(error.into()),
+                Ok(headers) => {
+                    events.push_back(section_event(
+                        direction,
+                        stream_id,
+                        blocked.push_id,
+                        headers,
+                    ));
+                    state.blocked = None;
+                    sender.blocked_sections -= 1;
+                }
+            }
+        }
+
+        // DATA est remis au fil de l'eau, sans attendre la fin de la trame.
+        if state.data_remaining > 0 {
+            if state.buffer.is_empty() {
+                break;
+            }
+            let length = state.buffer.len().min(state.data_remaining as usize);
+            let data: Vec<u8> = state.buffer.drain(..length).collect();
+            state.data_remaining -= length as u64;
+            events.push_back(Http3Event::Data {
+                direction,
+                stream_id,
+                data,
+            });
+            continue;
+        }
+
+        let Some((frame_type, length, header_len)) = extract_http3_frame_header(&state.buffer)
+        else {
+            break;
+        };
+        validate_http3_frame_type(frame_type)?;
+        // La longueur est refusee avant d'attendre la trame : seules les
+        // trames DATA depassent HTTP3_MAX_FRAME_LEN, et elles ne sont pas
+        // mises en tampon.
+        validate_http3_frame_length(frame_type, length)?;
+        if frame_type == HTTP3_FRAME_DATA {
+            if matches!(state.kind, StreamKind::Control { .. }) {
+                return Err(Http3Error::UnexpectedFrame {
+                    frame_type,
+                    stream_id,
+                });
+            }
+            state.buffer.drain(..header_len);
+            state.data_remaining = length;
+            continue;
+        }
+        let frame_len = header_len + length as usize;
+        if state.buffer.len() < frame_len {
+            break;
+        }
+        let bytes: Vec<u8> = state.buffer.drain(..frame_len).collect();
+        let frame = Http3Frame::try_from(bytes.as_slice())?;
+        handle_frame(sender, receiver, events, stream_id, state, &frame)?;
+    }
+
+    if !state.reassembler.is_finished() || state.ended {
+        return Ok(());
+    }
+    if !state.buffer.is_empty() {
+        // Trame coupee par la fin du flux.
+        Http3Frame::try_from(state.buffer.as_slice())?;
+    }
+    if state.data_remaining > 0 {
+        return Err(Http3Error::TruncatedFrame {
+            declared: state.data_remaining,
+            available: 0,
+        });
+    }
+    if matches!(state.kind, StreamKind::Control { .. }) {
+        return Err(Http3Error::ClosedCriticalStream(stream_id));
+    }
+    if state.blocked.is_none() {
+        state.ended = true;
+        events.push_back(Http3Event::StreamEnd {
+            direction,
+            stream_id,
+        });
+    }
+    Ok(())
+}
+
+fn handle_frame(
+    sender: &mut Endpoint,
+    receiver: &mut Endpoint,
+    events: &mut VecDeque<Http3Event>,
+    stream_id: u64,
+    state: &mut StreamState,
+    frame: &Http3Frame<'_>,
+) -> Result<(), Http3Error> {
+    let direction = sender.direction;
+    let unexpected = Http3Error::UnexpectedFrame {
+        frame_type: frame.frame_type,
+        stream_id,
+    };
+    let payload = frame.payload()?;
+
+    if let StreamKind::Control { settings_seen } = state.kind {
+        // Le flux de controle s'ouvre sur SETTINGS, et une seule fois
+        // (RFC 9114 §6.2.1).
+        return match payload {
+            Http3FramePayload::Settings { parameters } if !settings_seen => {
+                state.kind = StreamKind::Control {
+                    settings_seen: true,
+                };
+                // Les SETTINGS d'un cote bornent la table QPACK de l'autre.
+                for &(identifier, value) in &parameters {
+                    if identifier == HTTP3_SETTINGS_QPACK_MAX_TABLE_CAPACITY {
+                        // `set_max_capacity` plafonne a QPACK_MAX_TABLE_CAPACITY.
+                        receiver
+                            .decoder
+                            .set_max_capacity(usize::try_from(value).unwrap_or(usize::MAX));
+                    }
+                }
+                events.push_back(Http3Event::Settings {
+                    direction,
+                    parameters,
+                });
+                Ok(())
+            }
+            _ if !settings_seen => Err(Http3Error::MissingSettings(stream_id)),
+            Http3FramePayload::GoAway { id } => {
+                events.push_back(Http3Event::GoAway { direction, id });
+                Ok(())
+            }
+            Http3FramePayload::CancelPush { .. }
+            | Http3FramePayload::MaxPushId { .. }
+            | Http3FramePayload::Unknown { .. } => Ok(()),
+            _ => Err(unexpected),
+        };
+    }
+
+    match payload {
+        Http3FramePayload::Headers { field_section } => {
+            decode_section(sender, events, stream_id, state, None, field_section)
+        }
+        Http3FramePayload::PushPromise {
+            push_id,
+            field_section,
+        } if direction == Http3Direction::Server && state.kind == StreamKind::Request => {
+            decode_section(
+                sender,
+                events,
+                stream_id,
+                state,
+                Some(push_id),
+                field_section,
+            )
+        }
+        Http3FramePayload::Unknown { .. } => Ok(()),
+        _ => Err(unexpected),
+    }
+}
+
+// Decode une section, ou la met en attente si elle reference des entrees
+// pas encore inserees (RFC 9204 §2.1.2).
+fn decode_section(
+    sender: &mut Endpoint,
+    events: &mut VecDeque<Http3Event>,
+    stream_id: u64,
+    state: &mut StreamState,
+    push_id: Option<u64>,
+    section: &[u8],
+) -> Result<(), Http3Error> {
+    match sender.decoder.decode(section) {
+        Ok(headers) => {
+            events.push_back(section_event(sender.direction, stream_id, push_id, headers));
+            Ok(())
+        }
+        Err(QpackError::Blocked { .. }) => {
+            if sender.blocked_sections >= QPACK_MAX_BLOCKED_SECTIONS {
+                return Err(QpackError::TooManyBlockedSections {
+                    max: QPACK_MAX_BLOCKED_SECTIONS,
+                }
+                .into());
+            }
+            sender.blocked_sections += 1;
+            state.blocked = Some(BlockedSection {
+                push_id,
+                section: section.to_vec(),
+            });
+            Ok(())
+        }
+        Err(error) => Err(error.into()),
+    }
+}
+
+fn section_event(
+    direction: Http3Direction,
+    stream_id: u64,
+    push_id: Option<u64>,
+    headers: Vec<HeaderField>,
+) -> Http3Event {
+    match push_id {
+        Some(push_id) => Http3Event::PushPromise {
+            stream_id,
+            push_id,
+            headers,
+        },
+        None => Http3Event::Headers {
+            direction,
+            stream_id,
+            headers,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        checks::application::http3::QPACK_MAX_TABLE_CAPACITY,
+        parse::application::protocols::http2::header_value,
+    };
+
+    // -----------------------------------------------------------------------
+    // Echange synthetique : flux de controle (SETTINGS serveur avec une
+    // capacite QPACK de 220 octets), flux encodeur client portant les
+    // instructions de RFC 9204 B.2, requete sur le flux 0 referencant la
+    // table dynamique et reponse a champs statiques.
+    // -----------------------------------------------------------------------
+
+    fn stream(stream_id: u64, offset: u64, fin: bool, data: &[u8]) -> QuicStreamFrame<'_> {
+        QuicStreamFrame {
+            stream_id,
+            offset,
+            fin,
+            data,
+        }
+    }
+
+    fn events(connection: &mut Http3Connection) -> Vec<Http3Event> {
+        std::iter::from_fn(|| connection.next_event()).collect()
+    }
+
+    fn h(hex: &str) -> Vec<u8> {
+        hex::decode(hex).unwrap()
+    }
+
+    #[test]
+    fn request_and_response_yield_stream_events() {
+        let mut connection = Http3Connection::new();
+        use Http3Direction::{Client, Server};
+
+        // Flux de controle serveur (3) et client (2).
+        let server_control = h("0004030140dc");
+        connection
+            .push_stream_frame(Server, &stream(3, 0, false, &server_control))
+            .unwrap();
+        connection
+            .push_stream_frame(Client, &stream(2, 0, false, &h("000400")))
+            .unwrap();
+        assert_eq!(connection.decoder(Client).insert_count(), 0);
+
+        // Requete : DATA (offset 6) recue avant HEADERS, qui reste bloquee
+        // tant que le flux encodeur n'est pas arrive.
+        let request = h("010403811011000568656c6c6f");
+        connection
+            .push_stream_frame(Client, &stream(0, 6, true, &request[6..]))
+            .unwrap();
+        connection
+            .push_stream_frame(Client, &stream(0, 0, false, &request[..6]))
+            .unwrap();
+        assert_eq!(
+            events(&mut connection),
+            vec![
+                Http3Event::Settings {
+                    direction: Server,
+                    parameters: vec![(0x01, 220)],
+                },
+                Http3Event::Settings {
+                    direction: Client,
+                    parameters: vec![],
+                },
+            ]
+        );
+
+        let encoder = h(concat!(
+            "02",
+            "3fbd01",
+            "c00f7777772e6578616d706c652e636f6d",
+            "c10c2f73616d706c652f70617468"
+        ));
+        connection
+            .push_stream_frame(Client, &stream(6, 0, false, &encoder))
+            .unwrap();
+        let request_events = events(&mut connection);
+        let Http3Event::Headers { headers, .. } = &request_events[0] else {
+            panic!("expected request headers, got {request_events:?}");
+        };
+        assert_eq!(header_value(headers, ":authority"), Some("www.example.com"));
+        assert_eq!(header_value(headers, ":path"), Some("/sample/path"));
+        assert_eq!(
+            request_events[1..],
+            [
+                Http3Event::Data {
+                    direction: Client,
+                    stream_id: 0,
+                    data: b"hello".to_vec(),
+                },
+                Http3Event::StreamEnd {
+                    direction: Client,
+                    stream_id: 0,
+                },
+            ]
+        );
+
+        // Reponse : :status 200 (statique 25), DATA de 6 octets remise en
+        // deux morceaux, puis GOAWAY serveur.
+        let response = h("01030000d90006776f726c6421");
+        connection
+            .push_stream_frame(Server, &stream(0, 0, false, &response[..10]))
+            .unwrap();
+        connection
+            .push_stream_frame(Server, &stream(0, 10, true, &response[10..]))
+            .unwrap();
+        connection
+            .push_stream_frame(
+                Server,
+                &stream(3, server_control.len() as u64, false, &h("070100")),
+            )
+            .unwrap();
+
+        let response_events = events(&mut connection);
+        let Http3Event::Headers { headers, .. } = &response_events[0] else {
+            panic!("expected response headers, got {response_events:?}");
+        };
+        assert_eq!(header_value(headers, ":status"), Some("200"));
+        assert_eq!(
+            response_events[1..],
+            [
+                Http3Event::Data {
+                    direction: Server,
+                    stream_id: 0,
+                    data: b"wor".to_vec(),
+                },
+                Http3Event::Data {
+                    direction: Server,
+                    stream_id: 0,
+                    data: b"ld!".to_vec(),
+                },
+                Http3Event::StreamEnd {
+                    direction: Server,
+                    stream_id: 0,
+                },
+                Http3Event::GoAway {
+                    direction: Server,
+                    id: 0,
+                },
+            ]
+        );
+        assert!(!connection.has_failed(Client) && !connection.has_failed(Server));
+    }
+
+    #[test]
+    fn server_push_is_announced_then_delivered() {
+        let mut connection = Http3Connection::new();
+        use Http3Direction::{Client, Server};
+
+        // PUSH_PROMISE push 0 (section RFC 9204 B.1) sur le flux 0, puis flux
+        // push 15 (type 1, push 0) portant :status 200.
+        connection
+            .push_stream_frame(
+                Server,
+                &stream(0, 0, false, &h("0510000000510b2f696e6465782e68746d6c")),
+            )
+            .unwrap();
+        connection
+            .push_stream_frame(Server, &stream(15, 0, true, &h("010001030000d9")))
+            .unwrap();
+
+        let events = events(&mut connection);
+        let Http3Event::PushPromise {
+            stream_id: 0,
+            push_id: 0,
+            headers,
+        } = &events[0]
+        else {
+            panic!("expected a push promise, got {events:?}");
+        };
+        assert_eq!(header_value(headers, ":path"), Some("/index.html"));
+        assert_eq!(
+            events[1],
+            Http3Event::PushStream {
+                stream_id: 15,
+                push_id: 0,
+            }
+        );
+        let Http3Event::Headers {
+            direction: Server,
+            stream_id: 15,
+            headers,
+        } = &events[2]
+        else {
+            panic!("expected pushed response headers, got {events:?}");
+        };
+        assert_eq!(header_value(headers, ":status"), Some("200"));
+        assert_eq!(
+            events[3],
+            Http3Event::StreamEnd {
+                direction: Server,
+                stream_id: 15,
+            }
+        );
+        assert!(!connection.has_failed(Client));
+    }
+
+    #[test]
+    fn control_stream_errors_abandon_the_direction() {
+        let mut connection = Http3Connection::new();
+        use Http3Direction::{Client, Server};
+
+        // Flux de controle client ouvert par GOAWAY au lieu de SETTINGS.
+        assert_eq!(
+            connection.push_stream_frame(Client, &stream(2, 0, false, &h("00070100"))),
+            Err(Http3Error::MissingSettings(2))
+        );
+        assert!(connection.has_failed(Client));
+        assert_eq!(
+            connection.push_stream_frame(Client, &stream(0, 0, false, &h("0100"))),
+            Ok(())
+        );
+
+        // Deuxieme flux de controle serveur : le sens serveur tombe aussi.
+        connection
+            .push_stream_frame(Server, &stream(3, 0, false, &h("000400")))
+            .unwrap();
+        assert_eq!(
+            connection.push_stream_frame(Server, &stream(7, 0, false, &h("00"))),
+            Err(Http3Error::DuplicateStream {
+                stream_type: 0,
+                stream_id: 7,
+            })
+        );
+        assert!(connection.has_failed(Server));
+    }
+
+    #[test]
+    fn request_stream_errors_only_abandon_the_stream() {
+        let mut connection = Http3Connection::new();
+        use Http3Direction::Client;
+
+        // SETTINGS sur un flux de requete, puis CONTINUATION (HTTP/2).
+        assert_eq!(
+            connection.push_stream_frame(Client, &stream(0, 0, false, &h("0400"))),
+            Err(Http3Error::UnexpectedFrame {
+                frame_type: 0x04,
+                stream_id: 0,
+            })
+        );
+        assert_eq!(
+            connection.push_stream_frame(Client, &stream(4, 0, false, &h("0900"))),
+            Err(Http3Error::ReservedFrameType(0x09))
+        );
+        assert!(!connection.has_failed(Client));
+
+        // Requete valide sur le flux 8 : section statique, FIN.
+        connection
+            .push_stream_frame(Client, &stream(8, 0, true, &h("01030000d1")))
+            .unwrap();
+        let events = events(&mut connection);
+        let Http3Event::Headers { headers, .. } = &events[0] else {
+            panic!("expected request headers, got {events:?}");
+        };
+        assert_eq!(header_value(headers, ":method"), Some("GET"));
+    }
+
+    #[test]
+    fn ended_and_excess_streams_are_dropped() {
+        let mut connection = Http3Connection::new();
+        use Http3Direction::Client;
+
+        // Requetes completes (GET statique, FIN) : aucun etat ne subsiste,
+        // et une retransmission tardive du flux 0 est ignoree.
+        let request = h("01030000d1");
+        for stream_id in (0..).step_by(4).take(HTTP3_MAX_TRACKED_STREAMS + 1) {
+            connection
+                .push_stream_frame(Client, &stream(stream_id, 0, true, &request))
+                .unwrap();
+        }
+        assert!(connection.client.streams.is_empty());
+        assert_eq!(connection.client.closed.len(), HTTP3_MAX_TRACKED_STREAMS);
+        let last = 4 * HTTP3_MAX_TRACKED_STREAMS as u64;
+        events(&mut connection);
+        connection
+            .push_stream_frame(Client, &stream(last, 0, true, &request))
+            .unwrap();
+        assert!(events(&mut connection).is_empty());
+
+        // Requetes jamais terminees : au-dela de la borne, le plus petit
+        // identifiant est abandonne et ses trames suivantes ignorees.
+        let mut connection = Http3Connection::new();
+        let first = 1 << 20;
+        for stream_id in (first..).step_by(4).take(HTTP3_MAX_TRACKED_STREAMS + 1) {
+            connection
+                .push_stream_frame(Client, &stream(stream_id, 0, false, &request))
+                .unwrap();
+        }
+        assert_eq!(connection.client.streams.len(), HTTP3_MAX_TRACKED_STREAMS);
+        assert!(!connection.client.streams.contains_key(&first));
+        events(&mut connection);
+        connection
+            .push_stream_frame(Client, &stream(first, 5, true, &[]))
+            .unwrap();
+        assert!(events(&mut connection).is_empty());
+        connection
+            .push_stream_frame(Client, &stream(first + 4, 5, true, &[]))
+            .unwrap();
+        assert_eq!(
+            events(&mut connection),
+            vec![Http3Event::StreamEnd {
+                direction: Client,
+                stream_id: first + 4,
+            }]
+        );
+    }
+
+    #[test]
+    fn oversized_qpack_capacity_is_capped() {
+        let mut connection = Http3Connection::new();
+        use Http3Direction::{Client, Server};
+
+        // Le serveur annonce la capacite maximale d'un varint (2^62 - 1) ;
+        // le flux encodeur client tente ensuite 64 Kio + 1.
+        connection
+            .push_stream_frame(Server, &stream(3, 0, false, &h("00040901ffffffffffffffff")))
+            .unwrap();
+        assert_eq!(
+            connection.push_stream_frame(Client, &stream(6, 0, false, &h("023fe2ff03"))),
+            Err(Http3Error::Qpack(QpackError::CapacityExceeded {
+                capacity: QPACK_MAX_TABLE_CAPACITY + 1,
+                max: QPACK_MAX_TABLE_CAPACITY,
+            }))
+        );
+        assert_eq!(connection.decoder(Client).table_size(), 0);
+    }
+}
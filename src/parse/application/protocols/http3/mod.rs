@@ -0,0 +1,253 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! HTTP/3 framing (RFC 9114) and QPACK field compression (RFC 9204).
+//!
+//! HTTP/3 runs on QUIC streams, so its bytes are only readable once the
+//! 1-RTT packets are decrypted (feature `decrypt`). [`Http3Frame`] decodes a
+//! single frame without state; [`connection::Http3Connection`] takes the
+//! STREAM frames of both directions, reassembles each stream, follows the
+//! control and QPACK streams and yields per-stream header lists and data.
+
+pub mod connection;
+pub mod qpack;
+
+use crate::{
+    checks::application::{
+        http3::{
+            HTTP3_FRAME_CANCEL_PUSH, HTTP3_FRAME_DATA, HTTP3_FRAME_GOAWAY, HTTP3_FRAME_HEADERS,
+            HTTP3_FRAME_MAX_PUSH_ID, HTTP3_FRAME_PUSH_PROMISE, HTTP3_FRAME_SETTINGS,
+            extract_http3_frame_header, validate_http3_frame_type,
+        },
+        quic::QuicCursor,
+    },
+    errors::application::http3::Http3Error,
+};
+
+/// HTTP/3 Frame
+///
+/// ```mermaid
+/// ---
+/// title: Http3Frame
+/// ---
+/// packet-beta
+/// 0-7: "Type varint"
+/// 8-15: "Length varint"
+/// 16-63: "Frame Payload variable"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Http3Frame<'a> {
+    pub frame_type: u64,
+    /// The `Length` bytes following the header.
+    pub payload: &'a [u8],
+    header_len: usize,
+}
+
+impl<'a> TryFrom<&'a [u8]> for Http3Frame<'a> {
+    type Error = Http3Error;
+
+    /// Decodes the first frame of `bytes`; anything after it is left to the
+    /// caller, see [`Http3Frame::encoded_len`].
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        let (frame_type, length, header_len) =
+            extract_http3_frame_header(bytes).ok_or(Http3Error::TruncatedFrameHeader {
+                available: bytes.len(),
+            })?;
+        validate_http3_frame_type(frame_type)?;
+        let available = bytes.len() - header_len;
+        let payload = usize::try_from(length)
+            .ok()
+            .filter(|&length| length <= available)
+            .map(|length| &bytes[header_len..header_len + length])
+            .ok_or(Http3Error::TruncatedFrame {
+                declared: length,
+                available,
+            })?;
+        Ok(Http3Frame {
+            frame_type,
+            payload,
+            header_len,
+        })
+    }
+}
+
+/// Type-specific content of a frame (RFC 9114 §7.2).
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Http3FramePayload<'a> {
+    Data {
+        data: &'a [u8],
+    },
+    Headers {
+        /// QPACK-encoded field section.
+        field_section: &'a [u8],
+    },
+    CancelPush {
+        push_id: u64,
+    },
+    Settings {
+        parameters: Vec<(u64, u64)>,
+    },
+    PushPromise {
+        push_id: u64,
+        field_section: &'a [u8],
+    },
+    GoAway {
+        /// Stream id (from the server) or push id (from the client).
+        id: u64,
+    },
+    MaxPushId {
+        push_id: u64,
+    },
+    /// Extension or reserved frame type, to be ignored (RFC 9114 §9).
+    Unknown {
+        payload: &'a [u8],
+    },
+}
+
+impl<'a> Http3Frame<'a> {
+    /// Size of the frame on the wire, header included.
+    pub fn encoded_len(&self) -> usize {
+        self.header_len + self.payload.len()
+    }
+
+    /// Registered name of the frame type.
+    pub fn type_name(&self) -> &'static str {
+        match self.frame_type {
+            HTTP3_FRAME_DATA => "DATA",
+            HTTP3_FRAME_HEADERS => "HEADERS",
+            HTTP3_FRAME_CANCEL_PUSH => "CANCEL_PUSH",
+            HTTP3_FRAME_SETTINGS => "SETTINGS",
+            HTTP3_FRAME_PUSH_PROMISE => "PUSH_PROMISE",
+            HTTP3_FRAME_GOAWAY => "GOAWAY",
+            HTTP3_FRAME_MAX_PUSH_ID => "MAX_PUSH_ID",
+            _ => "UNKNOWN",
+        }
+    }
+
+    /// Decodes the type-specific payload, checking that it is fully used.
+    pub fn payload(&self) -> Result<Http3FramePayload<'a>, Http3Error> {
+        let frame_type = self.frame_type;
+        let malformed = |_| Http3Error::MalformedFrame(frame_type);
+        let mut cur = QuicCursor::new(self.payload);
+        let payload = match frame_type {
+            HTTP3_FRAME_DATA => Http3FramePayload::Data {
+                data: cur.take_rest(),
+            },
+            HTTP3_FRAME_HEADERS => Http3FramePayload::Headers {
+                field_section: cur.take_rest(),
+            },
+            HTTP3_FRAME_CANCEL_PUSH => Http3FramePayload::CancelPush {
+                push_id: cur.read_varint().map_err(malformed)?,
+            },
+            HTTP3_FRAME_SETTINGS => {
+                let mut parameters: Vec<(u64, u64)> = Vec::new();
+                while cur.remaining() > 0 {
+                    let identifier = cur.read_varint().map_err(malformed)?;
+                    let value = cur.read_varint().map_err(malformed)?;
+                    if parameters.iter().any(|&(known, _)| known == identifier) {
+                        return Err(Http3Error::DuplicateSetting(identifier));
+                    }
+                    parameters.push((identifier, value));
+                }
+                Http3FramePayload::Settings { parameters }
+            }
+            HTTP3_FRAME_PUSH_PROMISE => Http3FramePayload::PushPromise {
+                push_id: cur.read_varint().map_err(malformed)?,
+                field_section: cur.take_rest(),
+            },
+            HTTP3_FRAME_GOAWAY => Http3FramePayload::GoAway {
+                id: cur.read_varint().map_err(malformed)?,
+            },
+            HTTP3_FRAME_MAX_PUSH_ID => Http3FramePayload::MaxPushId {
+                push_id: cur.read_varint().map_err(malformed)?,
+            },
+            _ => Http3FramePayload::Unknown {
+                payload: cur.take_rest(),
+            },
+        };
+        // Un varint unique doit occuper toute la charge (RFC 9114 §7.1).
+        if cur.remaining() > 0 {
+            return Err(Http3Error::MalformedFrame(frame_type));
+        }
+        Ok(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn settings_frame_decodes() {
+        // Synthetique : SETTINGS QPACK_MAX_TABLE_CAPACITY=4096 (varint 2
+        // octets), QPACK_BLOCKED_STREAMS=16, parametre grease 0x21=0, suivi
+        // d'un octet d'une autre trame.
+        let bytes = [0x04, 0x07, 0x01, 0x50, 0x00, 0x07, 0x10, 0x21, 0x00, 0xff];
+        let frame = Http3Frame::try_from(&bytes[..]).unwrap();
+
+        assert_eq!(frame.type_name(), "SETTINGS");
+        assert_eq!(frame.encoded_len(), 9);
+        assert_eq!(
+            frame.payload(),
+            Ok(Http3FramePayload::Settings {
+                parameters: vec![(0x01, 4096), (0x07, 16), (0x21, 0)],
+            })
+        );
+    }
+
+    #[test]
+    fn push_promise_and_goaway_decode() {
+        // Synthetique : PUSH_PROMISE push 3, section RFC 9204 B.1 ; GOAWAY
+        // sur le flux 8.
+        let bytes = hex::decode("0510030000510b2f696e6465782e68746d6c").unwrap();
+        let frame = Http3Frame::try_from(&bytes[..]).unwrap();
+        assert_eq!(
+            frame.payload(),
+            Ok(Http3FramePayload::PushPromise {
+                push_id: 3,
+                field_section: &bytes[3..],
+            })
+        );
+
+        let goaway = Http3Frame::try_from(&[0x07, 0x01, 0x08][..]).unwrap();
+        assert_eq!(goaway.payload(), Ok(Http3FramePayload::GoAway { id: 8 }));
+    }
+
+    #[test]
+    fn malformed_frames_are_rejected() {
+        // GOAWAY dont la charge deborde le varint.
+        let frame = Http3Frame::try_from(&[0x07, 0x02, 0x08, 0x00][..]).unwrap();
+        assert_eq!(frame.payload(), Err(Http3Error::MalformedFrame(0x07)));
+
+        // SETTINGS avec un identifiant repete.
+        let frame = Http3Frame::try_from(&[0x04, 0x04, 0x06, 0x01, 0x06, 0x02][..]).unwrap();
+        assert_eq!(frame.payload(), Err(Http3Error::DuplicateSetting(0x06)));
+
+        // CONTINUATION (HTTP/2) et trame tronquee.
+        assert_eq!(
+            Http3Frame::try_from(&[0x09, 0x00][..]),
+            Err(Http3Error::ReservedFrameType(0x09))
+        );
+        assert_eq!(
+            Http3Frame::try_from(&[0x00, 0x05, 0xaa][..]),
+            Err(Http3Error::TruncatedFrame {
+                declared: 5,
+                available: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn grease_frames_are_unknown() {
+        let frame = Http3Frame::try_from(&[0x21, 0x01, 0xaa][..]).unwrap();
+
+        assert_eq!(frame.type_name(), "UNKNOWN");
+        assert_eq!(
+            frame.payload(),
+            Ok(Http3FramePayload::Unknown { payload: &[0xaa] })
+        );
+    }
+}
@@ -0,0 +1,517 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! QPACK field section decoding (RFC 9204).
+//!
+//! QPACK keeps the HPACK primitives (prefixed integers, string literals,
+//! Huffman code) but moves dynamic table updates to a dedicated encoder
+//! stream, so that field sections arriving out of order on independent
+//! QUIC streams can still be decoded.
+
+use std::collections::VecDeque;
+
+use crate::{
+    checks::application::http3::{QPACK_MAX_INSTRUCTION_LEN, QPACK_MAX_TABLE_CAPACITY},
+    errors::application::{http2::HpackError, http3::QpackError},
+    parse::application::protocols::http2::hpack::{HeaderField, decode_integer, decode_string},
+};
+
+mod table;
+
+use table::STATIC_TABLE;
+
+/// Surcout fixe d'une entree de table dynamique (RFC 9204 §3.2.1).
+const ENTRY_OVERHEAD: usize = 32;
+
+/// Stateful QPACK decoder: one per direction of an HTTP/3 connection,
+/// fed with the peer's encoder stream and with the field sections of its
+/// HEADERS and PUSH_PROMISE frames.
+///
+/// A field section referring to inserts not received yet is refused with
+/// [`QpackError::Blocked`] and leaves the decoder untouched: decode it
+/// again once [`feed_encoder_stream`](Self::feed_encoder_stream) has
+/// applied enough inserts.
+#[derive(Debug, Clone, Default)]
+pub struct QpackDecoder {
+    // Entree la plus ancienne en tete : index absolu `dropped`.
+    entries: VecDeque<(Vec<u8>, Vec<u8>)>,
+    dropped: u64,
+    size: usize,
+    capacity: usize,
+    max_capacity: usize,
+    // MaxEntries de la capacite annoncee, avant plafonnement : l'encodeur
+    // s'en sert pour coder le Required Insert Count.
+    max_entries: u64,
+    // Instruction du flux encodeur dont les derniers octets manquent.
+    pending: Vec<u8>,
+}
+
+impl QpackDecoder {
+    /// Decoder without a dynamic table, the default until SETTINGS
+    /// (RFC 9204 §3.2.3).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decoder whose table may grow up to `max_capacity` bytes, the
+    /// SETTINGS_QPACK_MAX_TABLE_CAPACITY announced by the decoding side,
+    /// capped at [`QPACK_MAX_TABLE_CAPACITY`].
+    pub fn with_max_capacity(max_capacity: usize) -> Self {
+        let mut decoder = Self::default();
+        decoder.set_max_capacity(max_capacity);
+        decoder
+    }
+
+    /// Applies the decoding side's SETTINGS_QPACK_MAX_TABLE_CAPACITY. The
+    /// table is capped at [`QPACK_MAX_TABLE_CAPACITY`]; an encoder setting a
+    /// larger capacity is refused with [`QpackError::CapacityExceeded`].
+    pub fn set_max_capacity(&mut self, max_capacity: usize) {
+        self.max_capacity = max_capacity.min(QPACK_MAX_TABLE_CAPACITY);
+        self.max_entries = (max_capacity / ENTRY_OVERHEAD) as u64;
+    }
+
+    /// Current size of the dynamic table, overhead included.
+    pub fn table_size(&self) -> usize {
+        self.size
+    }
+
+    /// Capacity set by the encoder stream.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Total number of inserts received on the encoder stream.
+    pub fn insert_count(&self) -> u64 {
+        self.dropped + self.entries.len() as u64
+    }
+
+    /// Dynamic table entries, most recent first.
+    pub fn dynamic_entries(&self) -> impl Iterator<Item = (&[u8], &[u8])> {
+        self.entries
+            .iter()
+            .rev()
+            .map(|(name, value)| (name.as_slice(), value.as_slice()))
+    }
+
+    /// Applies the encoder stream instructions in `data` (RFC 9204 §4.3).
+    /// A trailing partial instruction is kept for the next call.
+    ///
+    /// Returns the number of entries inserted.
+    pub fn feed_encoder_stream(&mut self, data: &[u8]) -> Result<usize, QpackError> {
+        let mut input = std::mem::take(&mut self.pending);
+        input.extend_from_slice(data);
+        let inserted_before = self.insert_count();
+
+        let mut rest = &input[..];
+        while !rest.is_empty() {
+            match self.apply_instruction(rest) {
+                Ok(tail) => rest = tail,
+                Err(QpackError::Encoding(HpackError::Truncated)) => {
+                    if rest.len() > QPACK_MAX_INSTRUCTION_LEN {
+                        return Err(QpackError::InstructionTooLarge {
+                            length: rest.len(),
+                            max: QPACK_MAX_INSTRUCTION_LEN,
+                        });
+                    }
+                    self.pending = rest.to_vec();
+                    break;
+                }
+                Err(error) => return Err(error),
+            }
+        }
+        Ok((self.insert_count() - inserted_before) as usize)
+    }
+
+    fn apply_instruction<'b>(&mut self, input: &'b [u8]) -> Result<&'b [u8], QpackError> {
+        let first = input[0];
+        if first & 0x80 != 0 {
+            // Insertion avec reference de nom (1T, index 6 bits).
+            let (index, rest) = decode_integer(input, 6)?;
+            let (value, rest) = decode_string(rest, 7)?;
+            let name = if first & 0x40 != 0 {
+                self.static_entry(index)?.0.to_vec()
+            } else {
+                let absolute = self.relative_to_absolute(index)?;
+                self.dynamic_entry(absolute)?.0.to_vec()
+            };
+            self.insert(name, value)?;
+            Ok(rest)
+        } else if first & 0x40 != 0 {
+            // Insertion avec nom litteral (01H, longueur 5 bits).
+            let (name, rest) = decode_string(input, 5)?;
+            let (value, rest) = decode_string(rest, 7)?;
+            self.insert(name, value)?;
+            Ok(rest)
+        } else if first & 0x20 != 0 {
+            // Capacite de la table (001, 5 bits).
+            let (capacity, rest) = decode_integer(input, 5)?;
+            if capacity > self.max_capacity {
+                return Err(QpackError::CapacityExceeded {
+                    capacity,
+                    max: self.max_capacity,
+                });
+            }
+            self.capacity = capacity;
+            self.evict_to(capacity);
+            Ok(rest)
+        } else {
+            // Duplication (000, index relatif 5 bits).
+            let (index, rest) = decode_integer(input, 5)?;
+            let absolute = self.relative_to_absolute(index)?;
+            let (name, value) = self.dynamic_entry(absolute)?;
+            let (name, value) = (name.to_vec(), value.to_vec());
+            self.insert(name, value)?;
+            Ok(rest)
+        }
+    }
+
+    /// Decodes a complete field section (HEADERS or PUSH_PROMISE payload).
+    pub fn decode(&self, section: &[u8]) -> Result<Vec<HeaderField>, QpackError> {
+        let (encoded_insert_count, rest) = decode_integer(section, 8)?;
+        let required = self.required_insert_count(encoded_insert_count)?;
+        if required > self.insert_count() {
+            return Err(QpackError::Blocked {
+                required,
+                received: self.insert_count(),
+            });
+        }
+
+        let sign = rest.first().is_some_and(|&byte| byte & 0x80 != 0);
+        let (delta_base, mut rest) = decode_integer(rest, 7)?;
+        let base = if sign {
+            required
+                .checked_sub(delta_base as u64 + 1)
+                .ok_or(QpackError::InvalidBase)?
+        } else {
+            required
+                .checked_add(delta_base as u64)
+                .ok_or(QpackError::InvalidBase)?
+        };
+
+        let mut fields = Vec::new();
+        while let Some(&first) = rest.first() {
+            let (field, tail) = if first & 0x80 != 0 {
+                // Ligne indexee (1T, index 6 bits).
+                let (index, tail) = decode_integer(rest, 6)?;
+                let (name, value) = if first & 0x40 != 0 {
+                    self.static_entry(index)?
+                } else {
+                    let absolute = base_relative(base, index)?;
+                    self.section_entry(absolute, required)?
+                };
+                (header_field(name, value.to_vec(), false), tail)
+            } else if first & 0x40 != 0 {
+                // Litteral avec reference de nom (01NT, index 4 bits).
+                let (index, tail) = decode_integer(rest, 4)?;
+                let name = if first & 0x10 != 0 {
+                    self.static_entry(index)?.0
+                } else {
+                    let absolute = base_relative(base, index)?;
+                    self.section_entry(absolute, required)?.0
+                };
+                let (value, tail) = decode_string(tail, 7)?;
+                (header_field(name, value, first & 0x20 != 0), tail)
+            } else if first & 0x20 != 0 {
+                // Litteral avec nom litteral (001NH, longueur 3 bits).
+                let (name, tail) = decode_string(rest, 3)?;
+                let (value, tail) = decode_string(tail, 7)?;
+                let never_indexed = first & 0x10 != 0;
+                (
+                    HeaderField {
+                        name,
+                        value,
+                        never_indexed,
+                    },
+                    tail,
+                )
+            } else if first & 0x10 != 0 {
+                // Ligne indexee post-base (0001, index 4 bits).
+                let (index, tail) = decode_integer(rest, 4)?;
+                let absolute = post_base(base, index)?;
+                let (name, value) = self.section_entry(absolute, required)?;
+                (header_field(name, value.to_vec(), false), tail)
+            } else {
+                // Litteral avec reference de nom post-base (0000N, 3 bits).
+                let (index, tail) = decode_integer(rest, 3)?;
+                let absolute = post_base(base, index)?;
+                let name = self.section_entry(absolute, required)?.0;
+                let (value, tail) = decode_string(tail, 7)?;
+                (header_field(name, value, first & 0x08 != 0), tail)
+            };
+            fields.push(field);
+            rest = tail;
+        }
+        Ok(fields)
+    }
+
+    /// Required Insert Count from its encoded form (RFC 9204 §4.5.1.1).
+    fn required_insert_count(&self, encoded: usize) -> Result<u64, QpackError> {
+        if encoded == 0 {
+            return Ok(0);
+        }
+        let max_entries = self.max_entries;
+        let full_range = 2 * max_entries;
+        if encoded as u64 > full_range {
+            return Err(QpackError::InvalidRequiredInsertCount(encoded));
+        }
+        let max_value = self.insert_count() + max_entries;
+        let max_wrapped = (max_value / full_range) * full_range;
+        let mut required = max_wrapped + encoded as u64 - 1;
+        if required > max_value {
+            if required <= full_range {
+                return Err(QpackError::InvalidRequiredInsertCount(encoded));
+            }
+            required -= full_range;
+        }
+        if required == 0 {
+            return Err(QpackError::InvalidRequiredInsertCount(encoded));
+        }
+        Ok(required)
+    }
+
+    fn static_entry(&self, index: usize) -> Result<(&[u8], &[u8]), QpackError> {
+        STATIC_TABLE
+            .get(index)
+            .map(|&(name, value)| (name.as_bytes(), value.as_bytes()))
+            .ok_or(QpackError::InvalidStaticIndex(index))
+    }
+
+    fn dynamic_entry(&self, absolute: u64) -> Result<(&[u8], &[u8]), QpackError> {
+        absolute
+            .checked_sub(self.dropped)
+            .and_then(|position| self.entries.get(position as usize))
+            .map(|(name, value)| (name.as_slice(), value.as_slice()))
+            .ok_or(QpackError::InvalidDynamicIndex(absolute))
+    }
+
+    /// Entree referencee par une section : son index absolu doit preceder
+    /// le Required Insert Count (RFC 9204 §4.5.2).
+    fn section_entry(&self, absolute: u64, required: u64) -> Result<(&[u8], &[u8]), QpackError> {
+        if absolute >= required {
+            return Err(QpackError::InvalidDynamicIndex(absolute));
+        }
+        self.dynamic_entry(absolute)
+    }
+
+    /// Index relatif du flux encodeur : 0 designe la derniere insertion.
+    fn relative_to_absolute(&self, relative: usize) -> Result<u64, QpackError> {
+        self.insert_count()
+            .checked_sub(relative as u64 + 1)
+            .ok_or(QpackError::InvalidDynamicIndex(relative as u64))
+    }
+
+    fn insert(&mut self, name: Vec<u8>, value: Vec<u8>) -> Result<(), QpackError> {
+        let size = name.len() + value.len() + ENTRY_OVERHEAD;
+        if size > self.capacity {
+            return Err(QpackError::EntryTooLarge {
+                size,
+                capacity: self.capacity,
+            });
+        }
+        self.evict_to(self.capacity - size);
+        self.size += size;
+        self.entries.push_back((name, value));
+        Ok(())
+    }
+
+    fn evict_to(&mut self, target: usize) {
+        while self.size > target {
+            let Some((name, value)) = self.entries.pop_front() else {
+                break;
+            };
+            self.size -= name.len() + value.len() + ENTRY_OVERHEAD;
+            self.dropped += 1;
+        }
+    }
+}
+
+fn header_field(name: &[u8], value: Vec<u8>, never_indexed: bool) -> HeaderField {
+    HeaderField {
+        name: name.to_vec(),
+        value,
+        never_indexed,
+    }
+}
+
+/// Index relatif a la Base d'une section : 0 designe l'entree Base - 1.
+fn base_relative(base: u64, index: usize) -> Result<u64, QpackError> {
+    base.checked_sub(index as u64 + 1)
+        .ok_or(QpackError::InvalidDynamicIndex(index as u64))
+}
+
+/// Index post-base : 0 designe l'entree Base.
+fn post_base(base: u64, index: usize) -> Result<u64, QpackError> {
+    base.checked_add(index as u64)
+        .ok_or(QpackError::InvalidDynamicIndex(index as u64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // -----------------------------------------------------------------------
+    // RFC 9204 annexe B : le decodeur annonce une capacite de 220 octets.
+    // -----------------------------------------------------------------------
+
+    fn pairs(fields: &[HeaderField]) -> Vec<(&str, &str)> {
+        fields
+            .iter()
+            .map(|field| (field.name_str().unwrap(), field.value_str().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn decodes_the_rfc_9204_b1_literal() {
+        let decoder = QpackDecoder::new();
+        let section = hex::decode("0000510b2f696e6465782e68746d6c").unwrap();
+
+        assert_eq!(
+            pairs(&decoder.decode(&section).unwrap()),
+            vec![(":path", "/index.html")]
+        );
+    }
+
+    #[test]
+    fn follows_the_rfc_9204_b2_to_b5_dynamic_table() {
+        let mut decoder = QpackDecoder::with_max_capacity(220);
+        let stream_4 = hex::decode("03811011").unwrap();
+
+        // B.2 : section recue avant les insertions, puis flux encodeur.
+        assert_eq!(
+            decoder.decode(&stream_4),
+            Err(QpackError::Blocked {
+                required: 2,
+                received: 0,
+            })
+        );
+        let encoder = hex::decode(concat!(
+            "3fbd01",
+            "c00f7777772e6578616d706c652e636f6d",
+            "c10c2f73616d706c652f70617468"
+        ))
+        .unwrap();
+        // Flux encodeur coupe au milieu d'une instruction.
+        assert_eq!(decoder.feed_encoder_stream(&encoder[..10]).unwrap(), 0);
+        assert_eq!(decoder.feed_encoder_stream(&encoder[10..]).unwrap(), 2);
+        assert_eq!(decoder.capacity(), 220);
+        assert_eq!(decoder.table_size(), 106);
+        assert_eq!(
+            pairs(&decoder.decode(&stream_4).unwrap()),
+            vec![(":authority", "www.example.com"), (":path", "/sample/path")]
+        );
+
+        // B.3 : nom litteral.
+        let encoder = hex::decode("4a637573746f6d2d6b65790c637573746f6d2d76616c7565").unwrap();
+        assert_eq!(decoder.feed_encoder_stream(&encoder).unwrap(), 1);
+        assert_eq!(decoder.table_size(), 160);
+
+        // B.4 : duplication de l'entree 0, puis section du flux 8.
+        assert_eq!(decoder.feed_encoder_stream(&[0x02]).unwrap(), 1);
+        assert_eq!(decoder.table_size(), 217);
+        let stream_8 = hex::decode("050080c181").unwrap();
+        assert_eq!(
+            pairs(&decoder.decode(&stream_8).unwrap()),
+            vec![
+                (":authority", "www.example.com"),
+                (":path", "/"),
+                ("custom-key", "custom-value"),
+            ]
+        );
+
+        // B.5 : reference de nom dynamique ; l'entree 0 est evincee.
+        let encoder = hex::decode("810d637573746f6d2d76616c756532").unwrap();
+        assert_eq!(decoder.feed_encoder_stream(&encoder).unwrap(), 1);
+        assert_eq!(decoder.table_size(), 215);
+        assert_eq!(decoder.insert_count(), 5);
+        assert_eq!(
+            decoder.dynamic_entries().next(),
+            Some((&b"custom-key"[..], &b"custom-value2"[..]))
+        );
+        assert_eq!(
+            decoder.dynamic_entry(0),
+            Err(QpackError::InvalidDynamicIndex(0))
+        );
+    }
+
+    /// Synthetique : litteral post-base avec bit N, Huffman sur le nom.
+    #[test]
+    fn decodes_post_base_and_never_indexed_literals() {
+        let mut decoder = QpackDecoder::with_max_capacity(220);
+        // Capacite 220, insertion "custom-key: custom-value".
+        let encoder =
+            hex::decode("3fbd014a637573746f6d2d6b65790c637573746f6d2d76616c7565").unwrap();
+        decoder.feed_encoder_stream(&encoder).unwrap();
+
+        // RIC 1 (encode 2), Base 0 (S=1, delta 0) : post-base 0 avec N,
+        // valeur "x" ; puis nom litteral Huffman "custom-key" (001NH, N=1,
+        // longueur 8 sur un prefixe de 3 bits).
+        let section = hex::decode("02800801783f0125a849e95ba97d7f0178").unwrap();
+        let fields = decoder.decode(&section).unwrap();
+
+        assert_eq!(
+            pairs(&fields),
+            vec![("custom-key", "x"), ("custom-key", "x")]
+        );
+        assert!(fields[0].never_indexed && fields[1].never_indexed);
+    }
+
+    #[test]
+    fn rejects_invalid_references() {
+        let mut decoder = QpackDecoder::with_max_capacity(100);
+
+        assert_eq!(
+            decoder.feed_encoder_stream(&[0x3f, 0xbd, 0x01]),
+            Err(QpackError::CapacityExceeded {
+                capacity: 220,
+                max: 100,
+            })
+        );
+        assert_eq!(
+            decoder.decode(&[0x00, 0x00, 0xff, 0x24]),
+            Err(QpackError::InvalidStaticIndex(99))
+        );
+        // RIC encode 8 > 2 * MaxEntries (3).
+        assert_eq!(
+            decoder.decode(&[0x08, 0x00]),
+            Err(QpackError::InvalidRequiredInsertCount(8))
+        );
+        // Section sans table dynamique referencant l'entree Base - 1.
+        assert_eq!(
+            decoder.decode(&[0x00, 0x00, 0x80]),
+            Err(QpackError::InvalidDynamicIndex(0))
+        );
+    }
+
+    #[test]
+    fn required_insert_count_wraps() {
+        // MaxEntries 3, FullRange 6 : apres 10 insertions, 5 encode 10.
+        let mut decoder = QpackDecoder::with_max_capacity(100);
+        decoder.feed_encoder_stream(&[0x3f, 0x45]).unwrap();
+        for _ in 0..10 {
+            // Insertion ":authority" = "" (statique 0) : 42 octets, la
+            // table n'en garde que deux.
+            decoder.feed_encoder_stream(&[0xc0, 0x00]).unwrap();
+        }
+
+        assert_eq!(decoder.insert_count(), 10);
+        assert_eq!(decoder.required_insert_count(5), Ok(10));
+        assert_eq!(decoder.required_insert_count(4), Ok(9));
+    }
+
+    #[test]
+    fn capacity_is_capped_but_keeps_the_announced_max_entries() {
+        // 1 Mio annonce : table bornee a 64 Kio, mais MaxEntries (32768)
+        // reste celui de l'encodeur pour decoder le Required Insert Count.
+        let mut decoder = QpackDecoder::with_max_capacity(1 << 20);
+        assert_eq!(
+            decoder.feed_encoder_stream(&[0x3f, 0xe2, 0xff, 0x03]),
+            Err(QpackError::CapacityExceeded {
+                capacity: QPACK_MAX_TABLE_CAPACITY + 1,
+                max: QPACK_MAX_TABLE_CAPACITY,
+            })
+        );
+        assert_eq!(decoder.required_insert_count(5000), Ok(4999));
+    }
+}
@@ -0,0 +1,503 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! ASDU of an I-format APDU (IEC 60870-5-101 §7, with the IEC 104 field
+//! sizes: 2-byte cause of transmission, 2-byte common address, 3-byte
+//! information object address).
+//!
+//! The type id fixes the size of every information element; the variable
+//! structure qualifier gives their count and whether they follow a single
+//! address (SQ = 1) or each carry their own.
+
+use crate::{
+    checks::application::iec104::{
+        IEC104_ASDU_HEADER_LEN, IEC104_C_BO_NA_1, IEC104_C_BO_TA_1, IEC104_C_CI_NA_1,
+        IEC104_C_CS_NA_1, IEC104_C_DC_NA_1, IEC104_C_DC_TA_1, IEC104_C_IC_NA_1, IEC104_C_RC_NA_1,
+        IEC104_C_RC_TA_1, IEC104_C_RD_NA_1, IEC104_C_RP_NA_1, IEC104_C_SC_NA_1, IEC104_C_SC_TA_1,
+        IEC104_C_SE_NA_1, IEC104_C_SE_NB_1, IEC104_C_SE_NC_1, IEC104_C_SE_TA_1, IEC104_C_SE_TB_1,
+        IEC104_C_SE_TC_1, IEC104_C_TS_TA_1, IEC104_COT_CAUSE_MASK, IEC104_COT_NEGATIVE,
+        IEC104_COT_TEST, IEC104_CP56_LEN, IEC104_F_SG_NA_1, IEC104_IOA_LEN, IEC104_M_BO_NA_1,
+        IEC104_M_BO_TB_1, IEC104_M_DP_NA_1, IEC104_M_DP_TB_1, IEC104_M_EI_NA_1, IEC104_M_IT_NA_1,
+        IEC104_M_IT_TB_1, IEC104_M_ME_NA_1, IEC104_M_ME_NB_1, IEC104_M_ME_NC_1, IEC104_M_ME_ND_1,
+        IEC104_M_ME_TD_1, IEC104_M_ME_TE_1, IEC104_M_ME_TF_1, IEC104_M_SP_NA_1, IEC104_M_SP_TB_1,
+        IEC104_M_ST_NA_1, IEC104_M_ST_TB_1, IEC104_VSQ_COUNT_MASK, IEC104_VSQ_SEQUENCE,
+        extract_iec104_bytes, iec104_cause_name, iec104_element_size, iec104_type_is_command,
+        iec104_type_name,
+    },
+    errors::application::iec104::Iec104Error,
+    parse::application::protocols::bounded_capacity,
+};
+
+/// Application Service Data Unit
+///
+/// ```mermaid
+/// ---
+/// title: Iec104Asdu
+/// ---
+/// packet-beta
+/// 0-7: "Type ID u8"
+/// 8-8: "SQ"
+/// 9-15: "Number of objects"
+/// 16-16: "T"
+/// 17-17: "P/N"
+/// 18-23: "Cause"
+/// 24-31: "Originator Address u8"
+/// 32-47: "Common Address u16"
+/// 48-71: "IOA u24"
+/// 72-103: "Elements variable"
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Iec104Asdu<'a> {
+    pub type_id: u8,
+    /// SQ bit: the objects share the first address, incremented by one.
+    pub sequence: bool,
+    pub cause: u8,
+    /// P/N bit: negative confirmation.
+    pub negative: bool,
+    pub test: bool,
+    pub originator_address: u8,
+    pub common_address: u16,
+    pub objects: Vec<Iec104InformationObject<'a>>,
+}
+
+impl<'a> Iec104Asdu<'a> {
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, Iec104Error> {
+        let (header, mut rest) =
+            extract_iec104_bytes(bytes, IEC104_ASDU_HEADER_LEN, "ASDU header")?;
+        let type_id = header[0];
+        let sequence = header[1] & IEC104_VSQ_SEQUENCE != 0;
+        let count = header[1] & IEC104_VSQ_COUNT_MASK;
+        let common_address = u16::from_le_bytes([header[4], header[5]]);
+
+        let element_size = match iec104_element_size(type_id) {
+            Some(size) => size,
+            // Segment de fichier : un seul objet, element de taille libre.
+            None if type_id == IEC104_F_SG_NA_1 && count == 1 => {
+                rest.len().saturating_sub(IEC104_IOA_LEN)
+            }
+            None => return Err(Iec104Error::UnknownTypeId(type_id)),
+        };
+        let expected = if sequence {
+            IEC104_IOA_LEN + usize::from(count) * element_size
+        } else {
+            usize::from(count) * (IEC104_IOA_LEN + element_size)
+        };
+        if rest.len() != expected {
+            return Err(Iec104Error::ObjectCountMismatch {
+                declared: count,
+                actual: rest.len(),
+            });
+        }
+
+        let mut objects = Vec::with_capacity(bounded_capacity(
+            usize::from(count),
+            rest.len(),
+            element_size.max(1),
+        ));
+        let mut address = 0;
+        for index in 0..count {
+            if !sequence || index == 0 {
+                let (ioa, next) = extract_iec104_bytes(rest, IEC104_IOA_LEN, "IOA")?;
+                address = u32::from_le_bytes([ioa[0], ioa[1], ioa[2], 0]);
+                rest = next;
+            } else {
+                address += 1;
+            }
+            let (element, next) = extract_iec104_bytes(rest, element_size, "information element")?;
+            rest = next;
+            let (value, time) = decode_element(type_id, element);
+            objects.push(Iec104InformationObject {
+                address,
+                value,
+                time,
+            });
+        }
+
+        Ok(Iec104Asdu {
+            type_id,
+            sequence,
+            cause: header[2] & IEC104_COT_CAUSE_MASK,
+            negative: header[2] & IEC104_COT_NEGATIVE != 0,
+            test: header[2] & IEC104_COT_TEST != 0,
+            originator_address: header[3],
+            common_address,
+            objects,
+        })
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        iec104_type_name(self.type_id)
+    }
+
+    pub fn cause_name(&self) -> &'static str {
+        iec104_cause_name(self.cause)
+    }
+
+    /// Command or parameter ASDU: it acts on the outstation.
+    pub fn is_command(&self) -> bool {
+        iec104_type_is_command(self.type_id)
+    }
+}
+
+/// Information object: its address, decoded element and optional time tag.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Iec104InformationObject<'a> {
+    pub address: u32,
+    pub value: Iec104Value<'a>,
+    /// CP56Time2a tag of the time-tagged types (30-40, 58-64, 103, 107).
+    pub time: Option<Cp56Time2a>,
+}
+
+/// Information element. Quality descriptors are kept raw (IV, NT, SB, BL,
+/// OV bits); command qualifiers keep the S/E bit apart.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum Iec104Value<'a> {
+    SinglePoint {
+        on: bool,
+        quality: u8,
+    },
+    /// 0 and 3 are indeterminate, 1 is off, 2 is on.
+    DoublePoint {
+        state: u8,
+        quality: u8,
+    },
+    StepPosition {
+        value: i8,
+        transient: bool,
+        quality: u8,
+    },
+    Bitstring {
+        value: u32,
+        quality: Option<u8>,
+    },
+    /// Fraction of full scale: `value / 32768`.
+    Normalized {
+        value: i16,
+        quality: Option<u8>,
+    },
+    Scaled {
+        value: i16,
+        quality: u8,
+    },
+    Float {
+        value: f32,
+        quality: u8,
+    },
+    IntegratedTotal {
+        value: i32,
+        /// Sequence number and CY, CA, IV flags.
+        sequence: u8,
+    },
+    SingleCommand {
+        on: bool,
+        select: bool,
+        qualifier: u8,
+    },
+    DoubleCommand {
+        state: u8,
+        select: bool,
+        qualifier: u8,
+    },
+    /// 1 is next step lower, 2 next step higher.
+    RegulatingStep {
+        state: u8,
+        select: bool,
+        qualifier: u8,
+    },
+    SetpointNormalized {
+        value: i16,
+        select: bool,
+        qualifier: u8,
+    },
+    SetpointScaled {
+        value: i16,
+        select: bool,
+        qualifier: u8,
+    },
+    SetpointFloat {
+        value: f32,
+        select: bool,
+        qualifier: u8,
+    },
+    EndOfInitialization {
+        cause: u8,
+    },
+    /// Qualifier 20 is station interrogation, 21-36 groups 1-16.
+    Interrogation {
+        qualifier: u8,
+    },
+    CounterInterrogation {
+        qualifier: u8,
+    },
+    Read,
+    /// The new time is the object time tag.
+    ClockSynchronization,
+    ResetProcess {
+        qualifier: u8,
+    },
+    TestCommand {
+        counter: u16,
+    },
+    /// Element of a type decoded only for its length.
+    Raw(&'a [u8]),
+}
+
+/// Seven-octet binary time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cp56Time2a {
+    /// Milliseconds within the minute, seconds included.
+    pub milliseconds: u16,
+    pub minute: u8,
+    pub hour: u8,
+    pub day: u8,
+    /// 1 is Monday, 0 when unused.
+    pub day_of_week: u8,
+    pub month: u8,
+    /// Years since 2000.
+    pub year: u8,
+    pub invalid: bool,
+    pub summer_time: bool,
+}
+
+impl Cp56Time2a {
+    fn parse(bytes: &[u8]) -> Self {
+        Cp56Time2a {
+            milliseconds: u16::from_le_bytes([bytes[0], bytes[1]]),
+            minute: bytes[2] & 0x3F,
+            invalid: bytes[2] & 0x80 != 0,
+            hour: bytes[3] & 0x1F,
+            summer_time: bytes[3] & 0x80 != 0,
+            day: bytes[4] & 0x1F,
+            day_of_week: bytes[4] >> 5,
+            month: bytes[5] & 0x0F,
+            year: bytes[6] & 0x7F,
+        }
+    }
+}
+
+/// Types dont l'element se termine par un temps CP56Time2a.
+fn has_cp56_time(type_id: u8) -> bool {
+    matches!(
+        type_id,
+        IEC104_M_SP_TB_1..=40
+            | IEC104_C_SC_TA_1..=IEC104_C_BO_TA_1
+            | IEC104_C_CS_NA_1
+            | IEC104_C_TS_TA_1
+    )
+}
+
+/// Decode un element dont la taille a ete verifiee par
+/// `iec104_element_size`.
+fn decode_element(type_id: u8, element: &[u8]) -> (Iec104Value<'_>, Option<Cp56Time2a>) {
+    let (body, time) = if has_cp56_time(type_id) {
+        let (body, time) = element.split_at(element.len() - IEC104_CP56_LEN);
+        (body, Some(Cp56Time2a::parse(time)))
+    } else {
+        (element, None)
+    };
+    let i16_at = |offset: usize| i16::from_le_bytes([body[offset], body[offset + 1]]);
+    let u32_at = |offset: usize| {
+        u32::from_le_bytes([
+            body[offset],
+            body[offset + 1],
+            body[offset + 2],
+            body[offset + 3],
+        ])
+    };
+    // Qualificatif de commande : S/E en bit 7, qualificatif en bits 2-6.
+    let select = |byte: u8| byte & 0x80 != 0;
+    let command_qualifier = |byte: u8| (byte >> 2) & 0x1F;
+
+    let value = match type_id {
+        IEC104_M_SP_NA_1 | IEC104_M_SP_TB_1 => Iec104Value::SinglePoint {
+            on: body[0] & 0x01 != 0,
+            quality: body[0] & 0xF0,
+        },
+        IEC104_M_DP_NA_1 | IEC104_M_DP_TB_1 => Iec104Value::DoublePoint {
+            state: body[0] & 0x03,
+            quality: body[0] & 0xF0,
+        },
+        IEC104_M_ST_NA_1 | IEC104_M_ST_TB_1 => Iec104Value::StepPosition {
+            // Valeur signee sur 7 bits.
+            value: ((body[0] << 1) as i8) >> 1,
+            transient: body[0] & 0x80 != 0,
+            quality: body[1],
+        },
+        IEC104_M_BO_NA_1 | IEC104_M_BO_TB_1 => Iec104Value::Bitstring {
+            value: u32_at(0),
+            quality: Some(body[4]),
+        },
+        IEC104_M_ME_NA_1 | IEC104_M_ME_TD_1 => Iec104Value::Normalized {
+            value: i16_at(0),
+            quality: Some(body[2]),
+        },
+        IEC104_M_ME_ND_1 => Iec104Value::Normalized {
+            value: i16_at(0),
+            quality: None,
+        },
+        IEC104_M_ME_NB_1 | IEC104_M_ME_TE_1 => Iec104Value::Scaled {
+            value: i16_at(0),
+            quality: body[2],
+        },
+        IEC104_M_ME_NC_1 | IEC104_M_ME_TF_1 => Iec104Value::Float {
+            value: f32::from_bits(u32_at(0)),
+            quality: body[4],
+        },
+        IEC104_M_IT_NA_1 | IEC104_M_IT_TB_1 => Iec104Value::IntegratedTotal {
+            value: u32_at(0) as i32,
+            sequence: body[4],
+        },
+        IEC104_C_SC_NA_1 | IEC104_C_SC_TA_1 => Iec104Value::SingleCommand {
+            on: body[0] & 0x01 != 0,
+            select: select(body[0]),
+            qualifier: command_qualifier(body[0]),
+        },
+        IEC104_C_DC_NA_1 | IEC104_C_DC_TA_1 => Iec104Value::DoubleCommand {
+            state: body[0] & 0x03,
+            select: select(body[0]),
+            qualifier: command_qualifier(body[0]),
+        },
+        IEC104_C_RC_NA_1 | IEC104_C_RC_TA_1 => Iec104Value::RegulatingStep {
+            state: body[0] & 0x03,
+            select: select(body[0]),
+            qualifier: command_qualifier(body[0]),
+        },
+        // Consignes : QOS en dernier octet, S/E en bit 7, QL en bits 0-6.
+        IEC104_C_SE_NA_1 | IEC104_C_SE_TA_1 => Iec104Value::SetpointNormalized {
+            value: i16_at(0),
+            select: select(body[2]),
+            qualifier: body[2] & 0x7F,
+        },
+        IEC104_C_SE_NB_1 | IEC104_C_SE_TB_1 => Iec104Value::SetpointScaled {
+            value: i16_at(0),
+            select: select(body[2]),
+            qualifier: body[2] & 0x7F,
+        },
+        IEC104_C_SE_NC_1 | IEC104_C_SE_TC_1 => Iec104Value::SetpointFloat {
+            value: f32::from_bits(u32_at(0)),
+            select: select(body[4]),
+            qualifier: body[4] & 0x7F,
+        },
+        IEC104_C_BO_NA_1 | IEC104_C_BO_TA_1 => Iec104Value::Bitstring {
+            value: u32_at(0),
+            quality: None,
+        },
+        IEC104_M_EI_NA_1 => Iec104Value::EndOfInitialization { cause: body[0] },
+        IEC104_C_IC_NA_1 => Iec104Value::Interrogation { qualifier: body[0] },
+        IEC104_C_CI_NA_1 => Iec104Value::CounterInterrogation { qualifier: body[0] },
+        IEC104_C_RD_NA_1 => Iec104Value::Read,
+        IEC104_C_CS_NA_1 => Iec104Value::ClockSynchronization,
+        IEC104_C_RP_NA_1 => Iec104Value::ResetProcess { qualifier: body[0] },
+        IEC104_C_TS_TA_1 => Iec104Value::TestCommand {
+            counter: u16::from_le_bytes([body[0], body[1]]),
+        },
+        _ => Iec104Value::Raw(body),
+    };
+    (value, time)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_asdu(hex_fixture: &str) -> Iec104Asdu<'static> {
+        let bytes = hex::decode(hex_fixture).expect("invalid hex fixture");
+        Iec104Asdu::parse(Box::leak(bytes.into_boxed_slice())).expect("valid ASDU")
+    }
+
+    #[test]
+    fn decode_general_interrogation() {
+        let asdu = parse_asdu("64010600010000000014");
+        assert_eq!(asdu.type_name(), "C_IC_NA_1");
+        assert_eq!(asdu.cause_name(), "activation");
+        assert_eq!(asdu.common_address, 1);
+        // L'interrogation lit le poste sans en changer l'etat.
+        assert!(!asdu.is_command());
+        assert_eq!(
+            asdu.objects,
+            vec![Iec104InformationObject {
+                address: 0,
+                value: Iec104Value::Interrogation { qualifier: 20 },
+                time: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn decode_sequence_of_measurements() {
+        // M_ME_NC_1, SQ = 1, trois flottants a partir de l'IOA 1001, cause
+        // "interrogated by station interrogation".
+        let asdu = parse_asdu("0d831400010ae903000000204100000048c0000000a04210");
+        assert!(asdu.sequence);
+        assert_eq!(asdu.cause_name(), "interrogated by station interrogation");
+        assert_eq!(asdu.common_address, 2561);
+        let addresses: Vec<u32> = asdu.objects.iter().map(|object| object.address).collect();
+        assert_eq!(addresses, vec![1001, 1002, 1003]);
+        assert_eq!(
+            asdu.objects[1].value,
+            Iec104Value::Float {
+                value: -3.125,
+                quality: 0
+            }
+        );
+        assert_eq!(
+            asdu.objects[2].value,
+            Iec104Value::Float {
+                value: 80.0,
+                quality: 0x10
+            }
+        );
+    }
+
+    #[test]
+    fn decode_time_tagged_single_point_and_command() {
+        // M_SP_TB_1 spontane, IOA 3000, ON, 2026-10-19 14:30:12.345.
+        let asdu = parse_asdu("1e0103000100b80b000139301e0e330a1a");
+        let object = &asdu.objects[0];
+        assert_eq!(
+            object.value,
+            Iec104Value::SinglePoint {
+                on: true,
+                quality: 0
+            }
+        );
+        let time = object.time.expect("CP56Time2a");
+        assert_eq!(
+            (time.year, time.month, time.day, time.hour, time.minute),
+            (26, 10, 19, 14, 30)
+        );
+        assert_eq!(time.milliseconds, 12_345);
+        assert_eq!(time.day_of_week, 1);
+
+        // C_SC_NA_1 select, ON, IOA 5000.
+        let asdu = parse_asdu("2d010600010088130081");
+        assert_eq!(
+            asdu.objects[0].value,
+            Iec104Value::SingleCommand {
+                on: true,
+                select: true,
+                qualifier: 0
+            }
+        );
+    }
+
+    #[test]
+    fn reject_inconsistent_asdu() {
+        let bytes = hex::decode("640206000100000000").expect("invalid hex fixture");
+        assert_eq!(
+            Iec104Asdu::parse(&bytes),
+            Err(Iec104Error::ObjectCountMismatch {
+                declared: 2,
+                actual: 3
+            })
+        );
+        let bytes = hex::decode("fe0106000100000000").expect("invalid hex fixture");
+        assert_eq!(
+            Iec104Asdu::parse(&bytes),
+            Err(Iec104Error::UnknownTypeId(0xFE))
+        );
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! IEC 60870-5-104 over TCP 2404.
+//!
+//! Each APDU starts with a 6-byte APCI whose control field selects the
+//! format: I-format frames number and carry an ASDU, S-format frames only
+//! acknowledge, U-format frames start, stop and test the data transfer. A
+//! TCP segment often carries several APDUs: [`Iec104Apdu::from_stream`]
+//! returns the bytes that follow the first one.
+
+use std::convert::TryFrom;
+
+use crate::{
+    checks::application::iec104::{
+        IEC104_APCI_LEN, IEC104_ASDU_HEADER_LEN, IEC104_MIN_APDU_LENGTH, IEC104_U_STARTDT_ACT,
+        IEC104_U_STARTDT_CON, IEC104_U_STOPDT_ACT, IEC104_U_STOPDT_CON, IEC104_U_TESTFR_ACT,
+        IEC104_U_TESTFR_CON, extract_iec104_apdu_len, iec104_u_function_name,
+    },
+    errors::application::iec104::Iec104Error,
+};
+
+pub mod asdu;
+
+use asdu::Iec104Asdu;
+
+/// APCI control field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Iec104Control {
+    /// Numbered information transfer, followed by an ASDU.
+    I {
+        send_sequence: u16,
+        receive_sequence: u16,
+    },
+    /// Supervisory acknowledgement of the I-format frames received.
+    S { receive_sequence: u16 },
+    /// Unnumbered control function (STARTDT, STOPDT, TESTFR).
+    U(u8),
+}
+
+impl Iec104Control {
+    fn parse(control: &[u8], length: u8) -> Result<Self, Iec104Error> {
+        let first = u16::from_le_bytes([control[0], control[1]]);
+        let second = u16::from_le_bytes([control[2], control[3]]);
+        if control[0] & 0x01 == 0 {
+            if usize::from(length) < usize::from(IEC104_MIN_APDU_LENGTH) + IEC104_ASDU_HEADER_LEN {
+                return Err(Iec104Error::InvalidApduLength(length));
+            }
+            return Ok(Iec104Control::I {
+                send_sequence: first >> 1,
+                receive_sequence: second >> 1,
+            });
+        }
+        let format = if control[0] & 0x03 == 0x01 { 'S' } else { 'U' };
+        if length != IEC104_MIN_APDU_LENGTH {
+            return Err(Iec104Error::UnexpectedAsdu { format, length });
+        }
+        if format == 'S' {
+            return Ok(Iec104Control::S {
+                receive_sequence: second >> 1,
+            });
+        }
+        match control[0] {
+            IEC104_U_STARTDT_ACT | IEC104_U_STARTDT_CON | IEC104_U_STOPDT_ACT
+            | IEC104_U_STOPDT_CON | IEC104_U_TESTFR_ACT | IEC104_U_TESTFR_CON => {
+                Ok(Iec104Control::U(control[0]))
+            }
+            other => Err(Iec104Error::UnknownUFunction(other)),
+        }
+    }
+
+    /// Name of a U-format function, `None` for I and S frames.
+    pub fn u_function_name(&self) -> Option<&'static str> {
+        match self {
+            Iec104Control::U(function) => Some(iec104_u_function_name(*function)),
+            _ => None,
+        }
+    }
+}
+
+/// IEC 104 APDU
+///
+/// ```mermaid
+/// ---
+/// title: Iec104Apdu
+/// ---
+/// packet-beta
+/// 0-7: "Start 0x68"
+/// 8-15: "Length u8"
+/// 16-47: "Control Field 4 octets"
+/// 48-95: "ASDU variable (I-format)"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Iec104Apdu<'a> {
+    /// Bytes after the length field: control field and ASDU.
+    pub length: u8,
+    pub control: Iec104Control,
+    /// Raw ASDU of an I-format APDU, empty otherwise.
+    pub asdu_bytes: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for Iec104Apdu<'a> {
+    type Error = Iec104Error;
+
+    fn try_from(payload: &'a [u8]) -> Result<Self, Iec104Error> {
+        Self::from_stream(payload).map(|(apdu, _)| apdu)
+    }
+}
+
+impl<'a> Iec104Apdu<'a> {
+    /// Parses the first APDU of an IEC 104 TCP stream and returns the bytes
+    /// that follow it, possibly more APDUs.
+    pub fn from_stream(stream: &'a [u8]) -> Result<(Self, &'a [u8]), Iec104Error> {
+        let apdu_len = extract_iec104_apdu_len(stream)?;
+        let (apdu, rest) = stream.split_at(apdu_len);
+        let length = apdu[1];
+        let control = Iec104Control::parse(&apdu[2..IEC104_APCI_LEN], length)?;
+        Ok((
+            Iec104Apdu {
+                length,
+                control,
+                asdu_bytes: &apdu[IEC104_APCI_LEN..],
+            },
+            rest,
+        ))
+    }
+
+    /// Every APDU of a TCP segment.
+    pub fn parse_all(mut stream: &'a [u8]) -> Result<Vec<Self>, Iec104Error> {
+        let mut apdus = Vec::new();
+        while !stream.is_empty() {
+            let (apdu, rest) = Self::from_stream(stream)?;
+            apdus.push(apdu);
+            stream = rest;
+        }
+        Ok(apdus)
+    }
+
+    /// Decoded ASDU of an I-format APDU, `None` for S and U frames.
+    pub fn asdu(&self) -> Option<Result<Iec104Asdu<'a>, Iec104Error>> {
+        match self.control {
+            Iec104Control::I { .. } => Some(Iec104Asdu::parse(self.asdu_bytes)),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_u_and_s_frames() {
+        let bytes =
+            hex::decode("680407000000680443000000680401000a00").expect("invalid hex fixture");
+        let apdus = Iec104Apdu::parse_all(&bytes).expect("valid APDUs");
+        assert_eq!(apdus.len(), 3);
+        assert_eq!(apdus[0].control, Iec104Control::U(IEC104_U_STARTDT_ACT));
+        assert_eq!(apdus[0].control.u_function_name(), Some("STARTDT act"));
+        assert_eq!(apdus[1].control.u_function_name(), Some("TESTFR act"));
+        assert_eq!(
+            apdus[2].control,
+            Iec104Control::S {
+                receive_sequence: 5
+            }
+        );
+        assert!(apdus[2].asdu().is_none());
+    }
+
+    #[test]
+    fn parse_i_frame_sequences() {
+        // Interrogation generale, N(S) = 1, N(R) = 2.
+        let bytes = hex::decode("680e0200040064010600010000000014").expect("invalid hex fixture");
+        let (apdu, rest) = Iec104Apdu::from_stream(&bytes).expect("valid APDU");
+        assert!(rest.is_empty());
+        assert_eq!(
+            apdu.control,
+            Iec104Control::I {
+                send_sequence: 1,
+                receive_sequence: 2
+            }
+        );
+        assert_eq!(apdu.asdu_bytes.len(), 10);
+    }
+
+    #[test]
+    fn reject_malformed_control_fields() {
+        assert_eq!(
+            Iec104Apdu::try_from(&[0x68, 0x04, 0x0F, 0, 0, 0][..]),
+            Err(Iec104Error::UnknownUFunction(0x0F))
+        );
+        assert_eq!(
+            Iec104Apdu::try_from(&[0x68, 0x05, 0x01, 0, 0, 0, 0xFF][..]),
+            Err(Iec104Error::UnexpectedAsdu {
+                format: 'S',
+                length: 5
+            })
+        );
+        // Trame I sans place pour un en-tete d'ASDU.
+        assert_eq!(
+            Iec104Apdu::try_from(&[0x68, 0x04, 0x00, 0, 0, 0][..]),
+            Err(Iec104Error::InvalidApduLength(4))
+        );
+    }
+}
@@ -0,0 +1,688 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Kerberos V5 messages (RFC 4120), over UDP or TCP port 88 and inside
+//! GSS-API tokens (SMB, LDAP SASL, HTTP Negotiate).
+//!
+//! Only the cleartext parts are decoded: realms, client and service
+//! principals, KDC options, offered and used encryption types,
+//! pre-authentication data and error codes. That is what Active Directory
+//! monitoring needs to spot account enumeration (`KDC_ERR_C_PRINCIPAL_UNKNOWN`),
+//! AS-REP roasting (AS-REQ without pre-authentication), kerberoasting
+//! (TGS-REP tickets encrypted with RC4) and password spraying
+//! (`KDC_ERR_PREAUTH_FAILED`). Encrypted parts stay opaque.
+//!
+//! ```mermaid
+//! sequenceDiagram
+//!     Client->>KDC: AS-REQ (cname, realm, etypes, padata?)
+//!     KDC->>Client: KRB-ERROR PREAUTH_REQUIRED / AS-REP (TGT)
+//!     Client->>KDC: TGS-REQ (PA-TGS-REQ = AP-REQ with TGT, sname)
+//!     KDC->>Client: TGS-REP (service ticket)
+//!     Client->>Service: AP-REQ (ticket, authenticator)
+//! ```
+
+use std::{convert::TryFrom, fmt};
+
+use chrono::{DateTime, Utc};
+
+use super::ber::{BerClass, BerElement, BerReader};
+use crate::{
+    checks::application::{
+        ber::{BER_TAG_GENERALIZED_TIME, BER_TAG_OBJECT_IDENTIFIER},
+        kerberos::{
+            KRB_AP_REP, KRB_AP_REQ, KRB_AS_REP, KRB_AS_REQ, KRB_ERROR, KRB_TGS_REP, KRB_TGS_REQ,
+            KRB_TICKET, KRB_TOKEN_AP_REP, KRB_TOKEN_AP_REQ, KRB_TOKEN_ERROR, OID_KERBEROS_V5,
+            OID_KERBEROS_V5_USER_TO_USER, OID_MS_KERBEROS_V5, PA_ENC_TIMESTAMP, PA_TGS_REQ,
+            extract_kerberos_tcp_record, extract_kerberos_time, is_weak_etype, kdc_option_names,
+            kerberos_error_code_name, kerberos_etype_name, kerberos_message_type_name,
+            kerberos_padata_type_name, kerberos_principal_type_name, validate_kerberos_header,
+        },
+    },
+    errors::application::kerberos::KerberosError,
+};
+
+/// Pre-authentication types that prove knowledge of the client key:
+/// encrypted timestamp, PKINIT and FAST encrypted challenge.
+const PREAUTH_PROOF_TYPES: [i64; 3] = [PA_ENC_TIMESTAMP, 16, 138];
+
+/// A Kerberos message, named after its `[APPLICATION n]` tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KerberosMessage<'a> {
+    AsReq(KdcRequest<'a>),
+    AsRep(KdcReply<'a>),
+    TgsReq(KdcRequest<'a>),
+    TgsRep(KdcReply<'a>),
+    ApReq(ApRequest<'a>),
+    /// Only the encrypted part of an AP-REP travels in clear.
+    ApRep(EncryptedData<'a>),
+    Error(KrbErrorMessage<'a>),
+}
+
+impl<'a> TryFrom<&'a [u8]> for KerberosMessage<'a> {
+    type Error = KerberosError;
+
+    fn try_from(message: &'a [u8]) -> Result<Self, KerberosError> {
+        let element = BerReader::new(message).next_element("Kerberos message")?;
+        if element.class != BerClass::Application {
+            return Err(KerberosError::UnknownMessageType(element.number));
+        }
+        match element.number {
+            KRB_AS_REQ => Ok(KerberosMessage::AsReq(KdcRequest::parse(element)?)),
+            KRB_TGS_REQ => Ok(KerberosMessage::TgsReq(KdcRequest::parse(element)?)),
+            KRB_AS_REP => Ok(KerberosMessage::AsRep(KdcReply::parse(element)?)),
+            KRB_TGS_REP => Ok(KerberosMessage::TgsRep(KdcReply::parse(element)?)),
+            KRB_AP_REQ => Ok(KerberosMessage::ApReq(ApRequest::parse(element)?)),
+            KRB_AP_REP => {
+                let mut fields = header(element, 0, "AP-REP")?;
+                Ok(KerberosMessage::ApRep(EncryptedData::parse(
+                    fields.context(2, "enc-part")?,
+                )?))
+            }
+            KRB_ERROR => Ok(KerberosMessage::Error(KrbErrorMessage::parse(element)?)),
+            other => Err(KerberosError::UnknownMessageType(other)),
+        }
+    }
+}
+
+impl<'a> KerberosMessage<'a> {
+    /// Parses the first message of a TCP stream, each message being
+    /// preceded by its 4-byte length, and returns the bytes that follow.
+    pub fn from_tcp_stream(stream: &'a [u8]) -> Result<(Self, &'a [u8]), KerberosError> {
+        let (record, rest) = extract_kerberos_tcp_record(stream)?;
+        Ok((Self::try_from(record)?, rest))
+    }
+
+    /// Parses a GSS-API Kerberos token (RFC 4121 §4.1): `[APPLICATION 0]`,
+    /// the Kerberos OID, a 2-byte TOK_ID, then the message.
+    pub fn from_gss_token(token: &'a [u8]) -> Result<Self, KerberosError> {
+        let mut top = BerReader::new(token);
+        let mut inner = top
+            .expect(BerClass::Application, 0, "GSS-API token")?
+            .children();
+        let oid = inner.expect(
+            BerClass::Universal,
+            BER_TAG_OBJECT_IDENTIFIER,
+            "GSS-API mechanism",
+        )?;
+        if !matches!(
+            oid.value,
+            OID_KERBEROS_V5 | OID_MS_KERBEROS_V5 | OID_KERBEROS_V5_USER_TO_USER
+        ) {
+            return Err(KerberosError::InvalidGssToken);
+        }
+        match inner.remaining().split_first_chunk::<2>() {
+            Some((&(KRB_TOKEN_AP_REQ | KRB_TOKEN_AP_REP | KRB_TOKEN_ERROR), message)) => {
+                Self::try_from(message)
+            }
+            _ => Err(KerberosError::InvalidGssToken),
+        }
+    }
+
+    pub fn message_type(&self) -> u32 {
+        match self {
+            KerberosMessage::AsReq(_) => KRB_AS_REQ,
+            KerberosMessage::AsRep(_) => KRB_AS_REP,
+            KerberosMessage::TgsReq(_) => KRB_TGS_REQ,
+            KerberosMessage::TgsRep(_) => KRB_TGS_REP,
+            KerberosMessage::ApReq(_) => KRB_AP_REQ,
+            KerberosMessage::ApRep(_) => KRB_AP_REP,
+            KerberosMessage::Error(_) => KRB_ERROR,
+        }
+    }
+
+    pub fn message_type_name(&self) -> &'static str {
+        kerberos_message_type_name(self.message_type())
+    }
+
+    /// Realm the message is about: that of the request, of the client in
+    /// a reply, of the ticket in an AP-REQ, or of the server in an error.
+    pub fn realm(&self) -> Option<&'a str> {
+        match self {
+            KerberosMessage::AsReq(request) | KerberosMessage::TgsReq(request) => {
+                Some(request.realm)
+            }
+            KerberosMessage::AsRep(reply) | KerberosMessage::TgsRep(reply) => Some(reply.crealm),
+            KerberosMessage::ApReq(request) => Some(request.ticket.realm),
+            KerberosMessage::ApRep(_) => None,
+            KerberosMessage::Error(error) => Some(error.realm),
+        }
+    }
+}
+
+/// PA-DATA: typed pre-authentication element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaData<'a> {
+    pub padata_type: i64,
+    pub value: &'a [u8],
+}
+
+impl PaData<'_> {
+    pub fn type_name(&self) -> &'static str {
+        kerberos_padata_type_name(self.padata_type)
+    }
+}
+
+/// PrincipalName: name type and components, such as `cifs` and
+/// `fs01.corp.example` for a service.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PrincipalName<'a> {
+    pub name_type: i64,
+    pub components: Vec<&'a str>,
+}
+
+impl<'a> PrincipalName<'a> {
+    fn parse(element: BerElement<'a>) -> Result<Self, KerberosError> {
+        let mut fields = element.children().sequence("PrincipalName")?.children();
+        let name_type = explicit_integer(&mut fields, 0, "name-type")?;
+        let mut strings = fields
+            .context(1, "name-string")?
+            .children()
+            .sequence("name-string")?
+            .children();
+        let mut components = Vec::new();
+        while !strings.is_empty() {
+            components.push(strings.next_element("name-string")?.string("name-string")?);
+        }
+        Ok(PrincipalName {
+            name_type,
+            components,
+        })
+    }
+
+    pub fn type_name(&self) -> &'static str {
+        kerberos_principal_type_name(self.name_type)
+    }
+}
+
+impl fmt::Display for PrincipalName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.components.join("/"))
+    }
+}
+
+/// EncryptedData: encryption type, key version and opaque cipher text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EncryptedData<'a> {
+    pub etype: i64,
+    pub kvno: Option<u32>,
+    pub cipher: &'a [u8],
+}
+
+impl<'a> EncryptedData<'a> {
+    fn parse(element: BerElement<'a>) -> Result<Self, KerberosError> {
+        let mut fields = element.children().sequence("EncryptedData")?.children();
+        let etype = explicit_integer(&mut fields, 0, "etype")?;
+        let kvno = fields
+            .optional_context(1, "kvno")?
+            .map(|kvno| kvno.children().next_element("kvno")?.unsigned("kvno"))
+            .transpose()?;
+        let cipher = fields
+            .context(2, "cipher")?
+            .children()
+            .octet_string("cipher")?;
+        Ok(EncryptedData {
+            etype,
+            kvno,
+            cipher,
+        })
+    }
+
+    pub fn etype_name(&self) -> &'static str {
+        kerberos_etype_name(self.etype)
+    }
+
+    pub fn is_weak(&self) -> bool {
+        is_weak_etype(self.etype)
+    }
+}
+
+/// Ticket, `[APPLICATION 1]`: realm and service in clear, the rest
+/// encrypted with the service key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ticket<'a> {
+    pub realm: &'a str,
+    pub sname: PrincipalName<'a>,
+    pub enc_part: EncryptedData<'a>,
+}
+
+impl<'a> Ticket<'a> {
+    fn parse(element: BerElement<'a>) -> Result<Self, KerberosError> {
+        let mut wrapper = element.children();
+        let mut fields = wrapper
+            .expect(BerClass::Application, KRB_TICKET, "Ticket")?
+            .children()
+            .sequence("Ticket")?
+            .children();
+        explicit_integer(&mut fields, 0, "tkt-vno")?;
+        Ok(Ticket {
+            realm: explicit_string(&mut fields, 1, "realm")?,
+            sname: PrincipalName::parse(fields.context(2, "sname")?)?,
+            enc_part: EncryptedData::parse(fields.context(3, "enc-part")?)?,
+        })
+    }
+}
+
+/// AS-REQ or TGS-REQ (KDC-REQ and KDC-REQ-BODY).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KdcRequest<'a> {
+    pub padata: Vec<PaData<'a>>,
+    pub kdc_options: u32,
+    /// Client, absent from TGS-REQ (it travels in the TGT).
+    pub cname: Option<PrincipalName<'a>>,
+    pub realm: &'a str,
+    pub sname: Option<PrincipalName<'a>>,
+    pub till: DateTime<Utc>,
+    pub nonce: u32,
+    /// Encryption types the client accepts, in order of preference.
+    pub etypes: Vec<i64>,
+}
+
+impl<'a> KdcRequest<'a> {
+    fn parse(element: BerElement<'a>) -> Result<Self, KerberosError> {
+        let mut fields = header(element, 1, "KDC-REQ")?;
+        let padata = parse_padata(&mut fields, 3)?;
+        let mut body = fields
+            .context(4, "req-body")?
+            .children()
+            .sequence("KDC-REQ-BODY")?
+            .children();
+        let kdc_options = body
+            .context(0, "kdc-options")?
+            .children()
+            .next_element("kdc-options")?
+            .flags("kdc-options")?;
+        let cname = body
+            .optional_context(1, "cname")?
+            .map(PrincipalName::parse)
+            .transpose()?;
+        let realm = explicit_string(&mut body, 2, "realm")?;
+        let sname = body
+            .optional_context(3, "sname")?
+            .map(PrincipalName::parse)
+            .transpose()?;
+        // from [4] : demande de ticket post-date, sans interet ici.
+        body.optional_context(4, "from")?;
+        let till = explicit_time(&mut body, 5, "till")?;
+        body.optional_context(6, "rtime")?;
+        let nonce = body
+            .context(7, "nonce")?
+            .children()
+            .next_element("nonce")?
+            .unsigned("nonce")?;
+        let mut list = body
+            .context(8, "etype")?
+            .children()
+            .sequence("etype")?
+            .children();
+        let mut etypes = Vec::new();
+        while !list.is_empty() {
+            etypes.push(list.integer("etype")?);
+        }
+        Ok(KdcRequest {
+            padata,
+            kdc_options,
+            cname,
+            realm,
+            sname,
+            till,
+            nonce,
+            etypes,
+        })
+    }
+
+    pub fn option_names(&self) -> Vec<&'static str> {
+        kdc_option_names(self.kdc_options)
+    }
+
+    /// Whether the request proves knowledge of the client key. An AS-REQ
+    /// without such proof yields an AS-REP crackable offline when the
+    /// account does not require pre-authentication.
+    pub fn is_pre_authenticated(&self) -> bool {
+        self.padata
+            .iter()
+            .any(|padata| PREAUTH_PROOF_TYPES.contains(&padata.padata_type))
+    }
+
+    /// AP-REQ carrying the TGT of a TGS-REQ, from its PA-TGS-REQ.
+    pub fn ap_request(&self) -> Option<Result<ApRequest<'a>, KerberosError>> {
+        let padata = self
+            .padata
+            .iter()
+            .find(|padata| padata.padata_type == PA_TGS_REQ)?;
+        Some(match KerberosMessage::try_from(padata.value) {
+            Ok(KerberosMessage::ApReq(request)) => Ok(request),
+            Ok(other) => Err(KerberosError::UnknownMessageType(other.message_type())),
+            Err(error) => Err(error),
+        })
+    }
+}
+
+/// AS-REP or TGS-REP (KDC-REP).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KdcReply<'a> {
+    pub padata: Vec<PaData<'a>>,
+    pub crealm: &'a str,
+    pub cname: PrincipalName<'a>,
+    pub ticket: Ticket<'a>,
+    /// Part encrypted with the client key (AS-REP) or session key (TGS-REP).
+    pub enc_part: EncryptedData<'a>,
+}
+
+impl<'a> KdcReply<'a> {
+    fn parse(element: BerElement<'a>) -> Result<Self, KerberosError> {
+        let mut fields = header(element, 0, "KDC-REP")?;
+        Ok(KdcReply {
+            padata: parse_padata(&mut fields, 2)?,
+            crealm: explicit_string(&mut fields, 3, "crealm")?,
+            cname: PrincipalName::parse(fields.context(4, "cname")?)?,
+            ticket: Ticket::parse(fields.context(5, "ticket")?)?,
+            enc_part: EncryptedData::parse(fields.context(6, "enc-part")?)?,
+        })
+    }
+}
+
+/// AP-REQ: ticket and authenticator presented to a service.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApRequest<'a> {
+    pub ap_options: u32,
+    pub ticket: Ticket<'a>,
+    pub authenticator: EncryptedData<'a>,
+}
+
+impl<'a> ApRequest<'a> {
+    fn parse(element: BerElement<'a>) -> Result<Self, KerberosError> {
+        let mut fields = header(element, 0, "AP-REQ")?;
+        Ok(ApRequest {
+            ap_options: fields
+                .context(2, "ap-options")?
+                .children()
+                .next_element("ap-options")?
+                .flags("ap-options")?,
+            ticket: Ticket::parse(fields.context(3, "ticket")?)?,
+            authenticator: EncryptedData::parse(fields.context(4, "authenticator")?)?,
+        })
+    }
+}
+
+/// KRB-ERROR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct KrbErrorMessage<'a> {
+    pub stime: DateTime<Utc>,
+    pub error_code: i64,
+    pub crealm: Option<&'a str>,
+    pub cname: Option<PrincipalName<'a>>,
+    pub realm: &'a str,
+    pub sname: PrincipalName<'a>,
+    pub e_text: Option<&'a str>,
+    /// Typed data, such as the PA-ETYPE-INFO2 hints of PREAUTH_REQUIRED.
+    pub e_data: Option<&'a [u8]>,
+}
+
+impl<'a> KrbErrorMessage<'a> {
+    fn parse(element: BerElement<'a>) -> Result<Self, KerberosError> {
+        let mut fields = header(element, 0, "KRB-ERROR")?;
+        // ctime [2] et cusec [3] : horloge du client, optionnelle.
+        fields.optional_context(2, "ctime")?;
+        fields.optional_context(3, "cusec")?;
+        let stime = explicit_time(&mut fields, 4, "stime")?;
+        explicit_integer(&mut fields, 5, "susec")?;
+        let error_code = explicit_integer(&mut fields, 6, "error-code")?;
+        let crealm = fields
+            .optional_context(7, "crealm")?
+            .map(|crealm| crealm.children().next_element("crealm")?.string("crealm"))
+            .transpose()?;
+        let cname = fields
+            .optional_context(8, "cname")?
+            .map(PrincipalName::parse)
+            .transpose()?;
+        let realm = explicit_string(&mut fields, 9, "realm")?;
+        let sname = PrincipalName::parse(fields.context(10, "sname")?)?;
+        let e_text = fields
+            .optional_context(11, "e-text")?
+            .map(|text| text.children().next_element("e-text")?.string("e-text"))
+            .transpose()?;
+        let e_data = fields
+            .optional_context(12, "e-data")?
+            .map(|data| data.children().octet_string("e-data"))
+            .transpose()?;
+        Ok(KrbErrorMessage {
+            stime,
+            error_code,
+            crealm,
+            cname,
+            realm,
+            sname,
+            e_text,
+            e_data,
+        })
+    }
+
+    pub fn error_code_name(&self) -> &'static str {
+        kerberos_error_code_name(self.error_code)
+    }
+}
+
+/// Ouvre la SEQUENCE d'un message `[APPLICATION n]` et verifie pvno et
+/// msg-type, ranges a partir du champ `[first]` (1 pour KDC-REQ, 0 sinon).
+fn header<'a>(
+    element: BerElement<'a>,
+    first: u32,
+    field: &'static str,
+) -> Result<BerReader<'a>, KerberosError> {
+    let mut fields = element.children().sequence(field)?.children();
+    let pvno = explicit_integer(&mut fields, first, "pvno")?;
+    let msg_type = explicit_integer(&mut fields, first + 1, "msg-type")?;
+    validate_kerberos_header(element.number, pvno, msg_type)?;
+    Ok(fields)
+}
+
+fn parse_padata<'a>(
+    fields: &mut BerReader<'a>,
+    number: u32,
+) -> Result<Vec<PaData<'a>>, KerberosError> {
+    let Some(element) = fields.optional_context(number, "padata")? else {
+        return Ok(Vec::new());
+    };
+    let mut list = element.children().sequence("padata")?.children();
+    let mut padata = Vec::new();
+    while !list.is_empty() {
+        let mut entry = list.sequence("PA-DATA")?.children();
+        padata.push(PaData {
+            padata_type: explicit_integer(&mut entry, 1, "padata-type")?,
+            value: entry
+                .context(2, "padata-value")?
+                .children()
+                .octet_string("padata-value")?,
+        });
+    }
+    Ok(padata)
+}
+
+/// Champ `[n] INTEGER` a etiquetage explicite.
+fn explicit_integer(
+    fields: &mut BerReader<'_>,
+    number: u32,
+    field: &'static str,
+) -> Result<i64, KerberosError> {
+    Ok(fields.context(number, field)?.children().integer(field)?)
+}
+
+/// Champ `[n] KerberosString` ; GeneralString en principe, mais certaines
+/// implementations emettent UTF8String ou IA5String.
+fn explicit_string<'a>(
+    fields: &mut BerReader<'a>,
+    number: u32,
+    field: &'static str,
+) -> Result<&'a str, KerberosError> {
+    Ok(fields
+        .context(number, field)?
+        .children()
+        .next_element(field)?
+        .string(field)?)
+}
+
+fn explicit_time(
+    fields: &mut BerReader<'_>,
+    number: u32,
+    field: &'static str,
+) -> Result<DateTime<Utc>, KerberosError> {
+    let time = fields.context(number, field)?.children().expect(
+        BerClass::Universal,
+        BER_TAG_GENERALIZED_TIME,
+        field,
+    )?;
+    extract_kerberos_time(time.value, field)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// AS-REQ sans pre-authentification de `alice@CORP.EXAMPLE` vers
+    /// `krbtgt/CORP.EXAMPLE` : options 0x40810010, PA-PAC-REQUEST seul,
+    /// etypes AES256, AES128, RC4, RC4-EXP et -135.
+    const AS_REQ_HEX: &str = concat!(
+        "6a81bc3081b9a103020105a20302010aa31530133011a10402020080a2090407",
+        "3005a0030101ffa48195308192a00703050040810010a1123010a003020101a1",
+        "0930071b05616c696365a20e1b0c434f52502e4558414d504c45a321301fa003",
+        "020102a11830161b066b72627467741b0c434f52502e4558414d504c45a51118",
+        "0f32303337303931333032343830355aa611180f323033373039313330323438",
+        "30355aa70602041c2d3e4fa81230100201120201110201170201180202ff79",
+    );
+
+    /// KRB-ERROR KDC_ERR_PREAUTH_REQUIRED, e-data PA-ETYPE-INFO2 puis
+    /// PA-ENC-TIMESTAMP vide.
+    const PREAUTH_REQUIRED_HEX: &str = concat!(
+        "7e81a030819da003020105a10302011ea411180f323032363130313931303131",
+        "31325aa505020305a4b1a603020119a90e1b0c434f52502e4558414d504c45aa",
+        "21301fa003020102a11830161b066b72627467741b0c434f52502e4558414d50",
+        "4c45ac3f043d303b302ea103020113a22704253023301aa003020112a1131b11",
+        "434f52502e4558414d504c45616c6963653005a0030201173009a103020102a2",
+        "020400",
+    );
+
+    /// Jeton GSS-API krb5 (TOK_ID 01 00) enveloppant un AP-REQ
+    /// mutual-required pour `cifs/fs01`.
+    const GSS_AP_REQ_HEX: &str = concat!(
+        "60818706092a864886f71201020201006e783076a003020105a10302010ea207",
+        "03050020000000a34c614a3048a003020105a10e1b0c434f52502e4558414d50",
+        "4c45a2173015a003020102a10e300c1b04636966731b0466733031a3183016a0",
+        "03020112a103020105a20a04080001020304050607a4133011a003020112a20a",
+        "040808090a0b0c0d0e0f",
+    );
+
+    fn fixture(hex: &str) -> Vec<u8> {
+        hex::decode(hex).expect("invalid test hex fixture")
+    }
+
+    #[test]
+    fn as_req_without_pre_authentication() {
+        let bytes = fixture(AS_REQ_HEX);
+        let KerberosMessage::AsReq(request) =
+            KerberosMessage::try_from(bytes.as_slice()).expect("AS-REQ valide")
+        else {
+            panic!("attendu un AS-REQ");
+        };
+        assert_eq!(request.realm, "CORP.EXAMPLE");
+        assert_eq!(
+            request
+                .cname
+                .as_ref()
+                .map(|name| name.to_string())
+                .as_deref(),
+            Some("alice")
+        );
+        assert_eq!(
+            request
+                .sname
+                .as_ref()
+                .map(|name| name.to_string())
+                .as_deref(),
+            Some("krbtgt/CORP.EXAMPLE")
+        );
+        assert_eq!(request.etypes, [18, 17, 23, 24, -135]);
+        assert_eq!(request.nonce, 0x1c2d_3e4f);
+        assert_eq!(request.till.to_rfc3339(), "2037-09-13T02:48:05+00:00");
+        assert_eq!(
+            request.option_names(),
+            ["forwardable", "renewable", "canonicalize", "renewable-ok"]
+        );
+        assert_eq!(request.padata[0].type_name(), "PA-PAC-REQUEST");
+        assert!(!request.is_pre_authenticated());
+        assert!(request.ap_request().is_none());
+    }
+
+    #[test]
+    fn preauth_required_over_tcp() {
+        let message = fixture(PREAUTH_REQUIRED_HEX);
+        let mut stream = (message.len() as u32).to_be_bytes().to_vec();
+        stream.extend(&message);
+        stream.extend([0x00, 0x00]);
+        let (parsed, rest) = KerberosMessage::from_tcp_stream(&stream).expect("flux valide");
+        assert_eq!(rest, [0x00, 0x00]);
+        assert_eq!(parsed.message_type_name(), "KRB-ERROR");
+        assert_eq!(parsed.realm(), Some("CORP.EXAMPLE"));
+        let KerberosMessage::Error(error) = parsed else {
+            panic!("attendu un KRB-ERROR");
+        };
+        assert_eq!(error.error_code_name(), "KDC_ERR_PREAUTH_REQUIRED");
+        assert_eq!(error.stime.to_rfc3339(), "2026-10-19T10:11:12+00:00");
+        assert_eq!(error.cname, None);
+        assert_eq!(error.e_data.map(<[u8]>::len), Some(61));
+    }
+
+    #[test]
+    fn ap_req_in_gss_token() {
+        let token = fixture(GSS_AP_REQ_HEX);
+        let KerberosMessage::ApReq(request) =
+            KerberosMessage::from_gss_token(&token).expect("jeton GSS valide")
+        else {
+            panic!("attendu un AP-REQ");
+        };
+        assert_eq!(request.ap_options, 0x2000_0000);
+        assert_eq!(request.ticket.sname.to_string(), "cifs/fs01");
+        assert_eq!(request.ticket.enc_part.kvno, Some(5));
+        assert_eq!(
+            request.authenticator.etype_name(),
+            "aes256-cts-hmac-sha1-96"
+        );
+
+        // Dernier arc de l'OID modifie : 1.2.840.113554.1.2.3 n'est pas krb5.
+        let mut ntlm = token.clone();
+        ntlm[13] = 0x03;
+        assert_eq!(
+            KerberosMessage::from_gss_token(&ntlm),
+            Err(KerberosError::InvalidGssToken)
+        );
+    }
+
+    #[test]
+    fn malformed_messages() {
+        let mut bytes = fixture(AS_REQ_HEX);
+        bytes[10] = 4;
+        assert_eq!(
+            KerberosMessage::try_from(bytes.as_slice()),
+            Err(KerberosError::InvalidVersion(4))
+        );
+        let mut bytes = fixture(AS_REQ_HEX);
+        bytes[15] = 12;
+        assert_eq!(
+            KerberosMessage::try_from(bytes.as_slice()),
+            Err(KerberosError::MessageTypeMismatch {
+                tag: 10,
+                msg_type: 12
+            })
+        );
+        assert_eq!(
+            KerberosMessage::try_from(&[0x30, 0x00][..]),
+            Err(KerberosError::UnknownMessageType(16))
+        );
+        let bytes = fixture(AS_REQ_HEX);
+        assert!(matches!(
+            KerberosMessage::try_from(&bytes[..100]),
+            Err(KerberosError::Ber(_))
+        ));
+    }
+}
@@ -0,0 +1,260 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Common External Message Interface (cEMI, KNX 03.06.03): the telegram
+//! format tunnelled and routed over IP. Link layer data messages carry the
+//! source and destination addresses and the transport and application
+//! control fields, the latter naming the group or device service.
+
+use core::fmt;
+
+use crate::{
+    checks::application::knxnet_ip::{
+        KNX_APCI_GROUP_VALUE_RESPONSE, KNX_APCI_GROUP_VALUE_WRITE, KNX_APCI_MASK,
+        KNX_APCI_SHORT_DATA_MASK, KNX_CEMI_GROUP_ADDRESS, KNX_CEMI_L_DATA_CON,
+        KNX_CEMI_L_DATA_HEADER_LEN, KNX_CEMI_L_DATA_IND, KNX_CEMI_L_DATA_REQ, KNX_TPCI_CONTROL,
+        extract_knxnet_ip_bytes, knx_apci_name, knx_cemi_message_name, knx_priority_name,
+    },
+    errors::application::knxnet_ip::KnxnetIpError,
+};
+
+/// Individual address of a device: area, line and device number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KnxIndividualAddress(pub u16);
+
+impl fmt::Display for KnxIndividualAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}",
+            self.0 >> 12,
+            (self.0 >> 8) & 0x0F,
+            self.0 & 0xFF
+        )
+    }
+}
+
+/// Group address, shown in the three-level main/middle/sub notation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KnxGroupAddress(pub u16);
+
+impl fmt::Display for KnxGroupAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}/{}/{}",
+            self.0 >> 11,
+            (self.0 >> 8) & 0x07,
+            self.0 & 0xFF
+        )
+    }
+}
+
+/// Destination of a telegram, typed by the address type bit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KnxAddress {
+    Individual(KnxIndividualAddress),
+    Group(KnxGroupAddress),
+}
+
+impl fmt::Display for KnxAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KnxAddress::Individual(address) => address.fmt(f),
+            KnxAddress::Group(address) => address.fmt(f),
+        }
+    }
+}
+
+/// cEMI message
+///
+/// ```mermaid
+/// ---
+/// title: KnxCemi
+/// ---
+/// packet-beta
+/// 0-7: "Message Code u8"
+/// 8-15: "Additional Info Length u8"
+/// 16-31: "Additional Info (variable)"
+/// 32-39: "Control 1"
+/// 40-47: "Control 2"
+/// 48-63: "Source Address"
+/// 64-79: "Destination Address"
+/// 80-87: "NPDU Length"
+/// 88-95: "TPCI / APCI"
+/// 96-103: "APCI / Data"
+/// 104-127: "Data (variable)"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KnxCemi<'a> {
+    pub message_code: u8,
+    pub additional_info: &'a [u8],
+    /// Service information following the additional information.
+    pub service: &'a [u8],
+}
+
+impl<'a> KnxCemi<'a> {
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, KnxnetIpError> {
+        let (header, rest) = extract_knxnet_ip_bytes(bytes, 2, "cEMI header")?;
+        let (additional_info, service) =
+            extract_knxnet_ip_bytes(rest, usize::from(header[1]), "cEMI additional info")?;
+        Ok(KnxCemi {
+            message_code: header[0],
+            additional_info,
+            service,
+        })
+    }
+
+    pub fn message_name(&self) -> &'static str {
+        knx_cemi_message_name(self.message_code)
+    }
+
+    /// Link layer data of an L_Data request, confirmation or indication;
+    /// `None` for raw, busmonitor and management messages.
+    pub fn l_data(&self) -> Option<Result<KnxLData<'a>, KnxnetIpError>> {
+        matches!(
+            self.message_code,
+            KNX_CEMI_L_DATA_REQ | KNX_CEMI_L_DATA_CON | KNX_CEMI_L_DATA_IND
+        )
+        .then(|| KnxLData::parse(self.service))
+    }
+}
+
+/// Link layer telegram of an L_Data message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KnxLData<'a> {
+    pub control1: u8,
+    pub control2: u8,
+    pub source: KnxIndividualAddress,
+    pub destination: KnxAddress,
+    /// Transport PDU: TPCI and APCI bits, then the application data.
+    pub tpdu: &'a [u8],
+}
+
+impl<'a> KnxLData<'a> {
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, KnxnetIpError> {
+        let (header, rest) =
+            extract_knxnet_ip_bytes(bytes, KNX_CEMI_L_DATA_HEADER_LEN - 1, "L_Data header")?;
+        let (length, rest) = extract_knxnet_ip_bytes(rest, 1, "NPDU length")?;
+        // La longueur du NPDU ne compte pas l'octet TPCI.
+        let (tpdu, _) = extract_knxnet_ip_bytes(rest, usize::from(length[0]) + 1, "TPDU")?;
+        let destination = u16::from_be_bytes([header[4], header[5]]);
+        Ok(KnxLData {
+            control1: header[0],
+            control2: header[1],
+            source: KnxIndividualAddress(u16::from_be_bytes([header[2], header[3]])),
+            destination: if header[1] & KNX_CEMI_GROUP_ADDRESS != 0 {
+                KnxAddress::Group(KnxGroupAddress(destination))
+            } else {
+                KnxAddress::Individual(KnxIndividualAddress(destination))
+            },
+            tpdu,
+        })
+    }
+
+    pub fn priority(&self) -> u8 {
+        (self.control1 >> 2) & 0x03
+    }
+
+    pub fn priority_name(&self) -> &'static str {
+        knx_priority_name(self.priority())
+    }
+
+    pub fn hop_count(&self) -> u8 {
+        (self.control2 >> 4) & 0x07
+    }
+
+    /// Transport control PDU (connect, disconnect, ack) rather than data.
+    pub fn is_transport_control(&self) -> bool {
+        self.tpdu[0] & KNX_TPCI_CONTROL != 0
+    }
+
+    /// Application control field on 10 bits, `None` for transport control
+    /// PDUs.
+    pub fn apci(&self) -> Option<u16> {
+        match self.tpdu {
+            [tpci, apci, ..] if tpci & KNX_TPCI_CONTROL == 0 => {
+                Some(u16::from(tpci & 0x03) << 8 | u16::from(*apci))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn apci_name(&self) -> Option<&'static str> {
+        self.apci().map(knx_apci_name)
+    }
+
+    /// Value carried by a GroupValueWrite or GroupValueResponse.
+    pub fn group_value(&self) -> Option<KnxGroupValue<'a>> {
+        let apci = self.apci()?;
+        if !matches!(
+            apci & KNX_APCI_MASK,
+            KNX_APCI_GROUP_VALUE_WRITE | KNX_APCI_GROUP_VALUE_RESPONSE
+        ) {
+            return None;
+        }
+        Some(match &self.tpdu[2..] {
+            [] => KnxGroupValue::Short(self.tpdu[1] & KNX_APCI_SHORT_DATA_MASK),
+            data => KnxGroupValue::Data(data),
+        })
+    }
+}
+
+/// Group value: up to 6 bits packed in the APCI byte (switching, dimming
+/// step), or whole bytes after it (datapoint types of a byte or more).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KnxGroupValue<'a> {
+    Short(u8),
+    Data(&'a [u8]),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_group_value_write() {
+        // L_Data.ind 1.1.5 -> 1/2/3 GroupValueWrite valeur courte 1, puis
+        // 1.1.5 -> 2/0/10 GroupValueWrite DPT 9 (0x0C1A, 21.0 degres).
+        let cemi = KnxCemi::parse(&[
+            0x29, 0x00, 0xBC, 0xE0, 0x11, 0x05, 0x0A, 0x03, 0x01, 0x00, 0x81,
+        ])
+        .expect("valid cEMI");
+        assert_eq!(cemi.message_name(), "L_Data.ind");
+        let data = cemi.l_data().expect("L_Data").expect("valid L_Data");
+        assert_eq!(data.source.to_string(), "1.1.5");
+        assert_eq!(data.destination.to_string(), "1/2/3");
+        assert_eq!(data.priority_name(), "low");
+        assert_eq!(data.hop_count(), 6);
+        assert_eq!(data.apci_name(), Some("GroupValueWrite"));
+        assert_eq!(data.group_value(), Some(KnxGroupValue::Short(1)));
+
+        let data = KnxLData::parse(&[
+            0xBC, 0xE0, 0x11, 0x05, 0x10, 0x0A, 0x03, 0x00, 0x80, 0x0C, 0x1A,
+        ])
+        .expect("valid L_Data");
+        assert_eq!(data.destination.to_string(), "2/0/10");
+        assert_eq!(data.group_value(), Some(KnxGroupValue::Data(&[0x0C, 0x1A])));
+    }
+
+    #[test]
+    fn reject_short_telegrams() {
+        let data = KnxLData::parse(&[0xBC, 0x60, 0x11, 0x05, 0x11, 0x01, 0x00, 0x81])
+            .expect("valid L_Data");
+        // TPCI de connexion : ni APCI ni valeur.
+        assert!(data.is_transport_control());
+        assert_eq!(data.apci(), None);
+        assert_eq!(
+            KnxLData::parse(&[0xBC, 0xE0, 0x11, 0x05, 0x0A, 0x03, 0x03, 0x00, 0x80]),
+            Err(KnxnetIpError::Truncated { field: "TPDU" })
+        );
+        assert_eq!(
+            KnxCemi::parse(&[0x29, 0x04, 0x03]),
+            Err(KnxnetIpError::Truncated {
+                field: "cEMI additional info"
+            })
+        );
+    }
+}
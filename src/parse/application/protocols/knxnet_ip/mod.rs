@@ -0,0 +1,505 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! KNXnet/IP (ISO 22510) over UDP 3671.
+//!
+//! [`KnxnetIpPacket`] reads the common header and [`KnxnetIpBody`] the
+//! services used to discover interfaces, open tunnelling connections and
+//! route telegrams between lines. Tunnelled and routed telegrams are cEMI
+//! messages, decoded by [`cemi::KnxCemi`].
+
+use std::convert::TryFrom;
+use std::net::{Ipv4Addr, SocketAddrV4};
+
+use crate::{
+    checks::application::knxnet_ip::{
+        KNXNET_IP_CONNECT_REQUEST, KNXNET_IP_CONNECT_RESPONSE, KNXNET_IP_CONNECTION_HEADER_LEN,
+        KNXNET_IP_CONNECTIONSTATE_REQUEST, KNXNET_IP_CONNECTIONSTATE_RESPONSE,
+        KNXNET_IP_DESCRIPTION_REQUEST, KNXNET_IP_DESCRIPTION_RESPONSE,
+        KNXNET_IP_DEVICE_CONFIGURATION_ACK, KNXNET_IP_DEVICE_CONFIGURATION_REQUEST,
+        KNXNET_IP_DIB_DEVICE_INFO, KNXNET_IP_DIB_DEVICE_INFO_LEN, KNXNET_IP_DISCONNECT_REQUEST,
+        KNXNET_IP_DISCONNECT_RESPONSE, KNXNET_IP_FRIENDLY_NAME_LEN, KNXNET_IP_HEADER_LEN,
+        KNXNET_IP_HPAI_LEN, KNXNET_IP_HPAI_TCP, KNXNET_IP_ROUTING_BUSY,
+        KNXNET_IP_ROUTING_INDICATION, KNXNET_IP_ROUTING_LOST_MESSAGE, KNXNET_IP_SEARCH_REQUEST,
+        KNXNET_IP_SEARCH_RESPONSE, KNXNET_IP_TUNNELLING_ACK, KNXNET_IP_TUNNELLING_REQUEST,
+        extract_knxnet_ip_bytes, extract_knxnet_ip_frame_len, extract_knxnet_ip_structure,
+        knxnet_ip_service_name, knxnet_ip_status_name,
+    },
+    errors::application::knxnet_ip::KnxnetIpError,
+};
+
+pub mod cemi;
+
+use cemi::{KnxCemi, KnxIndividualAddress};
+
+/// KNXnet/IP frame
+///
+/// ```mermaid
+/// ---
+/// title: KnxnetIpPacket
+/// ---
+/// packet-beta
+/// 0-7: "Header Length 0x06"
+/// 8-15: "Version 0x10"
+/// 16-31: "Service Type u16"
+/// 32-47: "Total Length u16"
+/// 48-95: "Body (variable)"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KnxnetIpPacket<'a> {
+    pub service_type: u16,
+    /// Length of the whole frame, header included.
+    pub total_length: u16,
+    pub body: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for KnxnetIpPacket<'a> {
+    type Error = KnxnetIpError;
+
+    fn try_from(payload: &'a [u8]) -> Result<Self, KnxnetIpError> {
+        Self::from_stream(payload).map(|(packet, _)| packet)
+    }
+}
+
+impl<'a> KnxnetIpPacket<'a> {
+    /// Parses the first frame of `stream` and returns the bytes that follow
+    /// it; several frames share a segment over KNXnet/IP TCP.
+    pub fn from_stream(stream: &'a [u8]) -> Result<(Self, &'a [u8]), KnxnetIpError> {
+        let total = extract_knxnet_ip_frame_len(stream)?;
+        let (frame, rest) = stream.split_at(total);
+        Ok((
+            KnxnetIpPacket {
+                service_type: u16::from_be_bytes([frame[2], frame[3]]),
+                total_length: total as u16,
+                body: &frame[usize::from(KNXNET_IP_HEADER_LEN)..],
+            },
+            rest,
+        ))
+    }
+
+    pub fn service_name(&self) -> &'static str {
+        knxnet_ip_service_name(self.service_type)
+    }
+
+    pub fn parse_body(&self) -> Result<KnxnetIpBody<'a>, KnxnetIpError> {
+        KnxnetIpBody::parse(self.service_type, self.body)
+    }
+
+    /// cEMI message of a tunnelling, device configuration or routing frame.
+    pub fn cemi(&self) -> Option<Result<KnxCemi<'a>, KnxnetIpError>> {
+        match self.parse_body() {
+            Ok(body) => body.cemi().map(KnxCemi::parse),
+            Err(error) => Some(Err(error)),
+        }
+    }
+}
+
+/// Host Protocol Address Information: the endpoint a client asks the
+/// server to answer on. 0.0.0.0:0 asks to answer to the sender (NAT).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KnxnetIpHpai {
+    pub protocol: u8,
+    pub address: SocketAddrV4,
+}
+
+impl KnxnetIpHpai {
+    fn parse(bytes: &[u8]) -> Result<(Self, &[u8]), KnxnetIpError> {
+        let (hpai, rest) = extract_knxnet_ip_structure(bytes, KNXNET_IP_HPAI_LEN, "HPAI")?;
+        Ok((
+            KnxnetIpHpai {
+                protocol: hpai[1],
+                address: SocketAddrV4::new(
+                    Ipv4Addr::new(hpai[2], hpai[3], hpai[4], hpai[5]),
+                    u16::from_be_bytes([hpai[6], hpai[7]]),
+                ),
+            },
+            rest,
+        ))
+    }
+
+    pub fn is_tcp(&self) -> bool {
+        self.protocol == KNXNET_IP_HPAI_TCP
+    }
+}
+
+/// Channel, sequence counter and status shared by the tunnelling and
+/// device configuration services.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KnxnetIpConnectionHeader {
+    pub channel_id: u8,
+    pub sequence: u8,
+    pub status: u8,
+}
+
+impl KnxnetIpConnectionHeader {
+    fn parse(bytes: &[u8]) -> Result<(Self, &[u8]), KnxnetIpError> {
+        let (header, rest) = extract_knxnet_ip_structure(
+            bytes,
+            KNXNET_IP_CONNECTION_HEADER_LEN,
+            "connection header",
+        )?;
+        Ok((
+            KnxnetIpConnectionHeader {
+                channel_id: header[1],
+                sequence: header[2],
+                status: header[3],
+            },
+            rest,
+        ))
+    }
+
+    pub fn status_name(&self) -> &'static str {
+        knxnet_ip_status_name(self.status)
+    }
+}
+
+/// DEVICE_INFO description block, returned by search and description
+/// responses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KnxnetIpDeviceInfo<'a> {
+    pub medium: u8,
+    pub status: u8,
+    pub individual_address: KnxIndividualAddress,
+    pub project_installation_id: u16,
+    pub serial_number: [u8; 6],
+    pub multicast_address: Ipv4Addr,
+    pub mac_address: [u8; 6],
+    /// Friendly name, ISO 8859-1, padded with NUL bytes.
+    pub friendly_name: &'a [u8],
+}
+
+impl<'a> KnxnetIpDeviceInfo<'a> {
+    /// Finds the DEVICE_INFO block among the description blocks.
+    fn find(mut dibs: &'a [u8]) -> Result<Option<Self>, KnxnetIpError> {
+        while !dibs.is_empty() {
+            let (dib, rest) = extract_knxnet_ip_structure(dibs, 2, "description block")?;
+            if dib[1] == KNXNET_IP_DIB_DEVICE_INFO {
+                if dib.len() != usize::from(KNXNET_IP_DIB_DEVICE_INFO_LEN) {
+                    return Err(KnxnetIpError::InvalidStructureLength {
+                        field: "DEVICE_INFO",
+                        length: dib[0],
+                    });
+                }
+                let name = &dib[24..24 + KNXNET_IP_FRIENDLY_NAME_LEN];
+                let name_len = name.iter().position(|&b| b == 0).unwrap_or(name.len());
+                return Ok(Some(KnxnetIpDeviceInfo {
+                    medium: dib[2],
+                    status: dib[3],
+                    individual_address: KnxIndividualAddress(u16::from_be_bytes([dib[4], dib[5]])),
+                    project_installation_id: u16::from_be_bytes([dib[6], dib[7]]),
+                    serial_number: [dib[8], dib[9], dib[10], dib[11], dib[12], dib[13]],
+                    multicast_address: Ipv4Addr::new(dib[14], dib[15], dib[16], dib[17]),
+                    mac_address: [dib[18], dib[19], dib[20], dib[21], dib[22], dib[23]],
+                    friendly_name: &name[..name_len],
+                }));
+            }
+            dibs = rest;
+        }
+        Ok(None)
+    }
+}
+
+/// Decoded body of the core, tunnelling and routing services.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KnxnetIpBody<'a> {
+    SearchRequest {
+        discovery: KnxnetIpHpai,
+    },
+    SearchResponse {
+        control: KnxnetIpHpai,
+        device: Option<KnxnetIpDeviceInfo<'a>>,
+    },
+    DescriptionRequest {
+        control: KnxnetIpHpai,
+    },
+    DescriptionResponse {
+        device: Option<KnxnetIpDeviceInfo<'a>>,
+    },
+    ConnectRequest {
+        control: KnxnetIpHpai,
+        data: KnxnetIpHpai,
+        connection_type: u8,
+        /// Connection-type specific options, such as the tunnelling layer.
+        options: &'a [u8],
+    },
+    ConnectResponse {
+        channel_id: u8,
+        status: u8,
+        data: Option<KnxnetIpHpai>,
+        /// Individual address granted to a tunnelling connection.
+        tunnel_address: Option<KnxIndividualAddress>,
+    },
+    ConnectionStateRequest {
+        channel_id: u8,
+        control: KnxnetIpHpai,
+    },
+    ConnectionStateResponse {
+        channel_id: u8,
+        status: u8,
+    },
+    DisconnectRequest {
+        channel_id: u8,
+        control: KnxnetIpHpai,
+    },
+    DisconnectResponse {
+        channel_id: u8,
+        status: u8,
+    },
+    TunnellingRequest {
+        header: KnxnetIpConnectionHeader,
+        cemi: &'a [u8],
+    },
+    TunnellingAck {
+        header: KnxnetIpConnectionHeader,
+    },
+    DeviceConfigurationRequest {
+        header: KnxnetIpConnectionHeader,
+        cemi: &'a [u8],
+    },
+    DeviceConfigurationAck {
+        header: KnxnetIpConnectionHeader,
+    },
+    RoutingIndication {
+        cemi: &'a [u8],
+    },
+    RoutingLostMessage {
+        device_state: u8,
+        lost_messages: u16,
+    },
+    RoutingBusy {
+        device_state: u8,
+        /// Milliseconds senders should wait before routing again.
+        wait_time: u16,
+        control: u16,
+    },
+    /// Service this crate does not decode (extended search, secure
+    /// wrapper, tunnelling features...).
+    Other(&'a [u8]),
+}
+
+impl<'a> KnxnetIpBody<'a> {
+    pub fn parse(service_type: u16, body: &'a [u8]) -> Result<Self, KnxnetIpError> {
+        let decoded = match service_type {
+            KNXNET_IP_SEARCH_REQUEST => KnxnetIpBody::SearchRequest {
+                discovery: KnxnetIpHpai::parse(body)?.0,
+            },
+            KNXNET_IP_SEARCH_RESPONSE => {
+                let (control, dibs) = KnxnetIpHpai::parse(body)?;
+                KnxnetIpBody::SearchResponse {
+                    control,
+                    device: KnxnetIpDeviceInfo::find(dibs)?,
+                }
+            }
+            KNXNET_IP_DESCRIPTION_REQUEST => KnxnetIpBody::DescriptionRequest {
+                control: KnxnetIpHpai::parse(body)?.0,
+            },
+            KNXNET_IP_DESCRIPTION_RESPONSE => KnxnetIpBody::DescriptionResponse {
+                device: KnxnetIpDeviceInfo::find(body)?,
+            },
+            KNXNET_IP_CONNECT_REQUEST => {
+                let (control, rest) = KnxnetIpHpai::parse(body)?;
+                let (data, rest) = KnxnetIpHpai::parse(rest)?;
+                let (cri, _) = extract_knxnet_ip_structure(rest, 2, "CRI")?;
+                KnxnetIpBody::ConnectRequest {
+                    control,
+                    data,
+                    connection_type: cri[1],
+                    options: &cri[2..],
+                }
+            }
+            KNXNET_IP_CONNECT_RESPONSE => {
+                let (ids, rest) = extract_knxnet_ip_bytes(body, 2, "channel and status")?;
+                // Un refus ne porte ni HPAI ni CRD.
+                let (data, tunnel_address) = if rest.is_empty() {
+                    (None, None)
+                } else {
+                    let (data, rest) = KnxnetIpHpai::parse(rest)?;
+                    let (crd, _) = extract_knxnet_ip_structure(rest, 2, "CRD")?;
+                    let address = match crd {
+                        [_, _, high, low, ..] => {
+                            Some(KnxIndividualAddress(u16::from_be_bytes([*high, *low])))
+                        }
+                        _ => None,
+                    };
+                    (Some(data), address)
+                };
+                KnxnetIpBody::ConnectResponse {
+                    channel_id: ids[0],
+                    status: ids[1],
+                    data,
+                    tunnel_address,
+                }
+            }
+            KNXNET_IP_CONNECTIONSTATE_REQUEST | KNXNET_IP_DISCONNECT_REQUEST => {
+                let (ids, rest) = extract_knxnet_ip_bytes(body, 2, "channel id")?;
+                let control = KnxnetIpHpai::parse(rest)?.0;
+                if service_type == KNXNET_IP_DISCONNECT_REQUEST {
+                    KnxnetIpBody::DisconnectRequest {
+                        channel_id: ids[0],
+                        control,
+                    }
+                } else {
+                    KnxnetIpBody::ConnectionStateRequest {
+                        channel_id: ids[0],
+                        control,
+                    }
+                }
+            }
+            KNXNET_IP_CONNECTIONSTATE_RESPONSE | KNXNET_IP_DISCONNECT_RESPONSE => {
+                let (ids, _) = extract_knxnet_ip_bytes(body, 2, "channel and status")?;
+                if service_type == KNXNET_IP_DISCONNECT_RESPONSE {
+                    KnxnetIpBody::DisconnectResponse {
+                        channel_id: ids[0],
+                        status: ids[1],
+                    }
+                } else {
+                    KnxnetIpBody::ConnectionStateResponse {
+                        channel_id: ids[0],
+                        status: ids[1],
+                    }
+                }
+            }
+            KNXNET_IP_TUNNELLING_REQUEST => {
+                let (header, cemi) = KnxnetIpConnectionHeader::parse(body)?;
+                KnxnetIpBody::TunnellingRequest { header, cemi }
+            }
+            KNXNET_IP_TUNNELLING_ACK => KnxnetIpBody::TunnellingAck {
+                header: KnxnetIpConnectionHeader::parse(body)?.0,
+            },
+            KNXNET_IP_DEVICE_CONFIGURATION_REQUEST => {
+                let (header, cemi) = KnxnetIpConnectionHeader::parse(body)?;
+                KnxnetIpBody::DeviceConfigurationRequest { header, cemi }
+            }
+            KNXNET_IP_DEVICE_CONFIGURATION_ACK => KnxnetIpBody::DeviceConfigurationAck {
+                header: KnxnetIpConnectionHeader::parse(body)?.0,
+            },
+            KNXNET_IP_ROUTING_INDICATION => KnxnetIpBody::RoutingIndication { cemi: body },
+            KNXNET_IP_ROUTING_LOST_MESSAGE => {
+                let (info, _) = extract_knxnet_ip_structure(body, 4, "lost message info")?;
+                KnxnetIpBody::RoutingLostMessage {
+                    device_state: info[1],
+                    lost_messages: u16::from_be_bytes([info[2], info[3]]),
+                }
+            }
+            KNXNET_IP_ROUTING_BUSY => {
+                let (info, _) = extract_knxnet_ip_structure(body, 6, "busy info")?;
+                KnxnetIpBody::RoutingBusy {
+                    device_state: info[1],
+                    wait_time: u16::from_be_bytes([info[2], info[3]]),
+                    control: u16::from_be_bytes([info[4], info[5]]),
+                }
+            }
+            _ => KnxnetIpBody::Other(body),
+        };
+        Ok(decoded)
+    }
+
+    /// Undecoded cEMI message carried by the body, if any.
+    pub fn cemi(&self) -> Option<&'a [u8]> {
+        match *self {
+            KnxnetIpBody::TunnellingRequest { cemi, .. }
+            | KnxnetIpBody::DeviceConfigurationRequest { cemi, .. }
+            | KnxnetIpBody::RoutingIndication { cemi } => Some(cemi),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_connect_exchange() {
+        // CONNECT_REQUEST tunnel couche liaison, reponse avec le canal 21 et
+        // l'adresse 1.1.250, puis un refus E_NO_MORE_CONNECTIONS.
+        let request = hex::decode(concat!(
+            "06100205001a",
+            "08010a000001e1f0",
+            "08010a000001e1f0",
+            "04040200"
+        ))
+        .expect("invalid hex fixture");
+        let packet = KnxnetIpPacket::try_from(&request[..]).expect("valid frame");
+        assert_eq!(packet.service_name(), "CONNECT_REQUEST");
+        let KnxnetIpBody::ConnectRequest {
+            control,
+            connection_type,
+            options,
+            ..
+        } = packet.parse_body().expect("valid body")
+        else {
+            panic!("CONNECT_REQUEST expected");
+        };
+        assert_eq!(
+            control.address,
+            SocketAddrV4::new(Ipv4Addr::new(10, 0, 0, 1), 57840)
+        );
+        assert!(!control.is_tcp());
+        assert_eq!((connection_type, options), (0x04, &[0x02, 0x00][..]));
+
+        let response =
+            hex::decode("061002060014150008010a0000640e5704041180").expect("invalid hex fixture");
+        let body = KnxnetIpPacket::try_from(&response[..])
+            .and_then(|packet| packet.parse_body())
+            .expect("valid body");
+        let KnxnetIpBody::ConnectResponse {
+            channel_id,
+            tunnel_address,
+            ..
+        } = body
+        else {
+            panic!("CONNECT_RESPONSE expected");
+        };
+        assert_eq!(channel_id, 0x15);
+        assert_eq!(
+            tunnel_address.map(|address| address.to_string()),
+            Some("1.1.128".to_string())
+        );
+
+        let refused =
+            KnxnetIpBody::parse(KNXNET_IP_CONNECT_RESPONSE, &[0x00, 0x24]).expect("valid body");
+        assert_eq!(
+            refused,
+            KnxnetIpBody::ConnectResponse {
+                channel_id: 0,
+                status: 0x24,
+                data: None,
+                tunnel_address: None
+            }
+        );
+    }
+
+    #[test]
+    fn parse_search_response_device_info() {
+        let mut body = hex::decode("08010a0000640e57").expect("invalid hex fixture");
+        body.extend_from_slice(&[54, 0x01, 0x02, 0x00, 0x11, 0x0A, 0x00, 0x00]);
+        body.extend_from_slice(&[0x00, 0xFA, 0x12, 0x34, 0x56, 0x78]);
+        body.extend_from_slice(&[224, 0, 23, 12]);
+        body.extend_from_slice(&[0x00, 0x24, 0x6D, 0x01, 0x02, 0x03]);
+        let mut name = b"IP Router".to_vec();
+        name.resize(KNXNET_IP_FRIENDLY_NAME_LEN, 0);
+        body.extend_from_slice(&name);
+        // Second bloc (familles de services) ignore.
+        body.extend_from_slice(&[0x04, 0x02, 0x02, 0x01]);
+
+        let KnxnetIpBody::SearchResponse { device, .. } =
+            KnxnetIpBody::parse(KNXNET_IP_SEARCH_RESPONSE, &body).expect("valid body")
+        else {
+            panic!("SEARCH_RESPONSE expected");
+        };
+        let device = device.expect("DEVICE_INFO block");
+        assert_eq!(device.individual_address.to_string(), "1.1.10");
+        assert_eq!(device.friendly_name, b"IP Router");
+        assert_eq!(device.multicast_address, Ipv4Addr::new(224, 0, 23, 12));
+        assert_eq!(
+            KnxnetIpBody::parse(KNXNET_IP_SEARCH_RESPONSE, &body[..20]),
+            Err(KnxnetIpError::Truncated {
+                field: "description block"
+            })
+        );
+    }
+}
@@ -0,0 +1,318 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! LDAP search filters (RFC 4511 §4.5.1.7), rendered in the string form
+//! of RFC 4515: `(&(objectClass=user)(servicePrincipalName=*))`.
+
+use std::fmt;
+
+use crate::{
+    checks::application::{
+        ber::extract_ber_string,
+        ldap::{
+            LDAP_FILTER_AND, LDAP_FILTER_APPROX_MATCH, LDAP_FILTER_EQUALITY_MATCH,
+            LDAP_FILTER_EXTENSIBLE_MATCH, LDAP_FILTER_GREATER_OR_EQUAL, LDAP_FILTER_LESS_OR_EQUAL,
+            LDAP_FILTER_NOT, LDAP_FILTER_OR, LDAP_FILTER_PRESENT, LDAP_FILTER_SUBSTRINGS,
+            LDAP_MAX_FILTER_DEPTH,
+        },
+    },
+    errors::application::ldap::LdapError,
+    parse::application::protocols::ber::{BerClass, BerElement, BerReader},
+};
+
+/// A search filter.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LdapFilter<'a> {
+    And(Vec<LdapFilter<'a>>),
+    Or(Vec<LdapFilter<'a>>),
+    Not(Box<LdapFilter<'a>>),
+    EqualityMatch {
+        attribute: &'a str,
+        value: &'a [u8],
+    },
+    Substrings {
+        attribute: &'a str,
+        initial: Option<&'a [u8]>,
+        any: Vec<&'a [u8]>,
+        final_value: Option<&'a [u8]>,
+    },
+    GreaterOrEqual {
+        attribute: &'a str,
+        value: &'a [u8],
+    },
+    LessOrEqual {
+        attribute: &'a str,
+        value: &'a [u8],
+    },
+    Present(&'a str),
+    ApproxMatch {
+        attribute: &'a str,
+        value: &'a [u8],
+    },
+    ExtensibleMatch {
+        matching_rule: Option<&'a str>,
+        attribute: Option<&'a str>,
+        value: &'a [u8],
+        dn_attributes: bool,
+    },
+}
+
+impl<'a> LdapFilter<'a> {
+    pub(crate) fn parse(element: BerElement<'a>, depth: usize) -> Result<Self, LdapError> {
+        if depth > LDAP_MAX_FILTER_DEPTH {
+            return Err(LdapError::FilterTooDeep(LDAP_MAX_FILTER_DEPTH));
+        }
+        if element.class != BerClass::Context {
+            return Err(LdapError::UnknownFilter(element.number));
+        }
+        let mut fields = element.children();
+        let filter = match element.number {
+            LDAP_FILTER_AND => LdapFilter::And(Self::set(fields, depth)?),
+            LDAP_FILTER_OR => LdapFilter::Or(Self::set(fields, depth)?),
+            LDAP_FILTER_NOT => LdapFilter::Not(Box::new(Self::parse(
+                fields.next_element("not")?,
+                depth + 1,
+            )?)),
+            LDAP_FILTER_EQUALITY_MATCH => {
+                let (attribute, value) = assertion(&mut fields)?;
+                LdapFilter::EqualityMatch { attribute, value }
+            }
+            LDAP_FILTER_SUBSTRINGS => {
+                let attribute = extract_ber_string(fields.octet_string("type")?, "type")?;
+                let mut parts = fields.sequence("substrings")?.children();
+                let (mut initial, mut any, mut final_value) = (None, Vec::new(), None);
+                while !parts.is_empty() {
+                    let part = parts.next_element("substring")?;
+                    match part.number {
+                        0 => initial = Some(part.value),
+                        1 => any.push(part.value),
+                        _ => final_value = Some(part.value),
+                    }
+                }
+                LdapFilter::Substrings {
+                    attribute,
+                    initial,
+                    any,
+                    final_value,
+                }
+            }
+            LDAP_FILTER_GREATER_OR_EQUAL => {
+                let (attribute, value) = assertion(&mut fields)?;
+                LdapFilter::GreaterOrEqual { attribute, value }
+            }
+            LDAP_FILTER_LESS_OR_EQUAL => {
+                let (attribute, value) = assertion(&mut fields)?;
+                LdapFilter::LessOrEqual { attribute, value }
+            }
+            // present [7] : AttributeDescription en etiquetage implicite.
+            LDAP_FILTER_PRESENT => LdapFilter::Present(element.string("present")?),
+            LDAP_FILTER_APPROX_MATCH => {
+                let (attribute, value) = assertion(&mut fields)?;
+                LdapFilter::ApproxMatch { attribute, value }
+            }
+            LDAP_FILTER_EXTENSIBLE_MATCH => {
+                let matching_rule = fields
+                    .optional_context(1, "matchingRule")?
+                    .map(|rule| rule.string("matchingRule"))
+                    .transpose()?;
+                let attribute = fields
+                    .optional_context(2, "type")?
+                    .map(|attribute| attribute.string("type"))
+                    .transpose()?;
+                let value = fields.context(3, "matchValue")?.value;
+                let dn_attributes = fields
+                    .optional_context(4, "dnAttributes")?
+                    .map(|flag| flag.boolean("dnAttributes"))
+                    .transpose()?
+                    .unwrap_or(false);
+                LdapFilter::ExtensibleMatch {
+                    matching_rule,
+                    attribute,
+                    value,
+                    dn_attributes,
+                }
+            }
+            other => return Err(LdapError::UnknownFilter(other)),
+        };
+        Ok(filter)
+    }
+
+    fn set(mut fields: BerReader<'a>, depth: usize) -> Result<Vec<Self>, LdapError> {
+        let mut filters = Vec::new();
+        while !fields.is_empty() {
+            filters.push(Self::parse(fields.next_element("filter")?, depth + 1)?);
+        }
+        Ok(filters)
+    }
+
+    /// Attributes the filter tests, in order of appearance.
+    pub fn attributes(&self) -> Vec<&'a str> {
+        let mut attributes = Vec::new();
+        self.collect_attributes(&mut attributes);
+        attributes
+    }
+
+    fn collect_attributes(&self, attributes: &mut Vec<&'a str>) {
+        match self {
+            LdapFilter::And(filters) | LdapFilter::Or(filters) => {
+                for filter in filters {
+                    filter.collect_attributes(attributes);
+                }
+            }
+            LdapFilter::Not(filter) => filter.collect_attributes(attributes),
+            LdapFilter::EqualityMatch { attribute, .. }
+            | LdapFilter::Substrings { attribute, .. }
+            | LdapFilter::GreaterOrEqual { attribute, .. }
+            | LdapFilter::LessOrEqual { attribute, .. }
+            | LdapFilter::Present(attribute)
+            | LdapFilter::ApproxMatch { attribute, .. } => attributes.push(attribute),
+            LdapFilter::ExtensibleMatch { attribute, .. } => attributes.extend(*attribute),
+        }
+    }
+}
+
+impl fmt::Display for LdapFilter<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("(")?;
+        match self {
+            LdapFilter::And(filters) | LdapFilter::Or(filters) => {
+                f.write_str(if matches!(self, LdapFilter::And(_)) {
+                    "&"
+                } else {
+                    "|"
+                })?;
+                for filter in filters {
+                    write!(f, "{filter}")?;
+                }
+            }
+            LdapFilter::Not(filter) => write!(f, "!{filter}")?,
+            LdapFilter::EqualityMatch { attribute, value } => {
+                write!(f, "{attribute}=")?;
+                write_escaped(f, value)?;
+            }
+            LdapFilter::Substrings {
+                attribute,
+                initial,
+                any,
+                final_value,
+            } => {
+                write!(f, "{attribute}=")?;
+                if let Some(initial) = initial {
+                    write_escaped(f, initial)?;
+                }
+                f.write_str("*")?;
+                for part in any {
+                    write_escaped(f, part)?;
+                    f.write_str("*")?;
+                }
+                if let Some(final_value) = final_value {
+                    write_escaped(f, final_value)?;
+                }
+            }
+            LdapFilter::GreaterOrEqual { attribute, value } => {
+                write!(f, "{attribute}>=")?;
+                write_escaped(f, value)?;
+            }
+            LdapFilter::LessOrEqual { attribute, value } => {
+                write!(f, "{attribute}<=")?;
+                write_escaped(f, value)?;
+            }
+            LdapFilter::Present(attribute) => write!(f, "{attribute}=*")?,
+            LdapFilter::ApproxMatch { attribute, value } => {
+                write!(f, "{attribute}~=")?;
+                write_escaped(f, value)?;
+            }
+            LdapFilter::ExtensibleMatch {
+                matching_rule,
+                attribute,
+                value,
+                dn_attributes,
+            } => {
+                if let Some(attribute) = attribute {
+                    f.write_str(attribute)?;
+                }
+                if *dn_attributes {
+                    f.write_str(":dn")?;
+                }
+                if let Some(rule) = matching_rule {
+                    write!(f, ":{rule}")?;
+                }
+                f.write_str(":=")?;
+                write_escaped(f, value)?;
+            }
+        }
+        f.write_str(")")
+    }
+}
+
+/// AttributeValueAssertion en etiquetage implicite : description puis
+/// valeur, deux OCTET STRING.
+fn assertion<'a>(fields: &mut BerReader<'a>) -> Result<(&'a str, &'a [u8]), LdapError> {
+    let attribute = extract_ber_string(fields.octet_string("attributeDesc")?, "attributeDesc")?;
+    Ok((attribute, fields.octet_string("assertionValue")?))
+}
+
+/// Echappement RFC 4515 §3 : `*`, `(`, `)`, `\`, NUL et tout octet hors
+/// ASCII imprimable (valeurs binaires comme objectSid) en `\xx`.
+fn write_escaped(f: &mut fmt::Formatter<'_>, value: &[u8]) -> fmt::Result {
+    for &byte in value {
+        match byte {
+            b'*' | b'(' | b')' | b'\\' => write!(f, "\\{byte:02x}")?,
+            0x20..=0x7E => write!(f, "{}", char::from(byte))?,
+            _ => write!(f, "\\{byte:02x}")?,
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(bytes: &[u8]) -> Result<LdapFilter<'_>, LdapError> {
+        LdapFilter::parse(BerReader::new(bytes).next_element("filter")?, 0)
+    }
+
+    #[test]
+    fn substrings_and_binary_values_are_escaped() {
+        // (|(cn=adm*in*\2a)(objectSid=...)(badPwdCount>=3))
+        let bytes = hex::decode(concat!(
+            "a141a4120402636e300c800361646d8102696e82012aa31904096f626a656374",
+            "536964040c010500000000000515000000a510040b626164507764436f756e74",
+            "040133"
+        ))
+        .expect("invalid test hex fixture");
+        let filter = parse(&bytes).expect("filtre valide");
+        assert_eq!(
+            filter.to_string(),
+            "(|(cn=adm*in*\\2a)(objectSid=\\01\\05\\00\\00\\00\\00\\00\\05\\15\\00\\00\\00)\
+             (badPwdCount>=3))"
+        );
+        assert_eq!(filter.attributes(), ["cn", "objectSid", "badPwdCount"]);
+    }
+
+    #[test]
+    fn nesting_and_unknown_choices() {
+        // (!(!(...(objectClass=*)))) au-dela de la profondeur admise.
+        let mut bytes = b"\x87\x0bobjectClass".to_vec();
+        for _ in 0..=LDAP_MAX_FILTER_DEPTH {
+            let mut outer = vec![0xA2, bytes.len() as u8];
+            if bytes.len() >= 0x80 {
+                outer = vec![0xA2, 0x81, bytes.len() as u8];
+            }
+            outer.extend(bytes);
+            bytes = outer;
+        }
+        assert_eq!(
+            parse(&bytes),
+            Err(LdapError::FilterTooDeep(LDAP_MAX_FILTER_DEPTH))
+        );
+        assert_eq!(parse(&[0xAA, 0x00]), Err(LdapError::UnknownFilter(10)));
+        assert_eq!(
+            parse(b"\x87\x02cn").map(|filter| filter.to_string()),
+            Ok("(cn=*)".to_string())
+        );
+    }
+}
@@ -0,0 +1,647 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! LDAPv3 messages (RFC 4511) over TCP 389 and 3268 (global catalog),
+//! and CLDAP over UDP 389 (the Active Directory domain controller
+//! locator).
+//!
+//! Binds reveal who authenticates and how: cleartext simple binds,
+//! SASL mechanisms, and the Sicily NTLM binds of Active Directory whose
+//! NTLMSSP tokens [`LdapAuthentication::ntlmssp_token`] hands to the
+//! NTLMSSP decoder. Search requests keep their base, scope and filter,
+//! rendered as RFC 4515 strings, which is what reconnaissance tools such
+//! as BloodHound leave behind. Traffic protected by SASL integrity or
+//! confidentiality, or by TLS, is not BER and does not decode.
+//!
+//! ```mermaid
+//! sequenceDiagram
+//!     Client->>DC: bindRequest (DN, simple / SASL / Sicily)
+//!     DC->>Client: bindResponse (resultCode)
+//!     Client->>DC: searchRequest (base, scope, filter, attributes)
+//!     DC->>Client: searchResEntry x N
+//!     DC->>Client: searchResDone
+//! ```
+
+pub mod filter;
+
+use std::convert::TryFrom;
+
+pub use self::filter::LdapFilter;
+use super::ber::{BerClass, BerElement, BerReader};
+use crate::{
+    checks::application::{
+        ber::{BER_TAG_BOOLEAN, BER_TAG_ENUMERATED, BER_TAG_SET, extract_ber_string},
+        ldap::{
+            LDAP_ADD_RESPONSE, LDAP_AUTH_SASL, LDAP_AUTH_SICILY_NEGOTIATE,
+            LDAP_AUTH_SICILY_PACKAGE_DISCOVERY, LDAP_AUTH_SICILY_RESPONSE, LDAP_AUTH_SIMPLE,
+            LDAP_BIND_REQUEST, LDAP_BIND_RESPONSE, LDAP_COMPARE_RESPONSE, LDAP_DEL_RESPONSE,
+            LDAP_EXTENDED_REQUEST, LDAP_EXTENDED_RESPONSE, LDAP_MODIFY_DN_RESPONSE,
+            LDAP_MODIFY_RESPONSE, LDAP_RESULT_SUCCESS, LDAP_SEARCH_REQUEST,
+            LDAP_SEARCH_RESULT_DONE, LDAP_SEARCH_RESULT_ENTRY, LDAP_SEARCH_RESULT_REFERENCE,
+            LDAP_STARTTLS_OID, LDAP_UNBIND_REQUEST, ldap_extended_operation_name,
+            ldap_operation_name, ldap_result_code_name, ldap_scope_name, validate_ldap_message_id,
+        },
+        ntlmssp::NTLMSSP_SIGNATURE,
+    },
+    errors::application::{ber::BerError, ldap::LdapError},
+};
+
+/// The LDAP messages of one TCP segment or UDP datagram.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LdapPacket<'a> {
+    pub messages: Vec<LdapMessage<'a>>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for LdapPacket<'a> {
+    type Error = LdapError;
+
+    /// Every message of the payload must be complete: a segment cut in
+    /// the middle of a large search result is rejected as a whole.
+    fn try_from(payload: &'a [u8]) -> Result<Self, LdapError> {
+        let mut reader = BerReader::new(payload);
+        let mut messages = Vec::new();
+        while !reader.is_empty() {
+            messages.push(LdapMessage::parse(reader.sequence("LDAPMessage")?)?);
+        }
+        if messages.is_empty() {
+            return Err(LdapError::Empty);
+        }
+        Ok(LdapPacket { messages })
+    }
+}
+
+/// One LDAPMessage.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LdapMessage<'a> {
+    pub message_id: u32,
+    pub operation: LdapOperation<'a>,
+    pub controls: Vec<LdapControl<'a>>,
+}
+
+impl<'a> LdapMessage<'a> {
+    fn parse(element: BerElement<'a>) -> Result<Self, LdapError> {
+        let mut fields = element.children();
+        let message_id = validate_ldap_message_id(fields.integer("messageID")?)?;
+        let operation = LdapOperation::parse(fields.next_element("protocolOp")?)?;
+        let mut controls = Vec::new();
+        if let Some(list) = fields.optional_context(0, "controls")? {
+            let mut list = list.children();
+            while !list.is_empty() {
+                controls.push(LdapControl::parse(list.sequence("Control")?)?);
+            }
+        }
+        Ok(LdapMessage {
+            message_id,
+            operation,
+            controls,
+        })
+    }
+}
+
+/// Control attached to a message, such as the paged results control
+/// `1.2.840.113556.1.4.319` of large Active Directory searches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LdapControl<'a> {
+    pub oid: &'a str,
+    pub critical: bool,
+    pub value: Option<&'a [u8]>,
+}
+
+impl<'a> LdapControl<'a> {
+    fn parse(element: BerElement<'a>) -> Result<Self, LdapError> {
+        let mut fields = element.children();
+        let oid = extract_ber_string(fields.octet_string("controlType")?, "controlType")?;
+        let critical = fields
+            .optional(BerClass::Universal, BER_TAG_BOOLEAN, "criticality")?
+            .map(|flag| flag.boolean("criticality"))
+            .transpose()?
+            .unwrap_or(false);
+        let value = (!fields.is_empty())
+            .then(|| fields.octet_string("controlValue"))
+            .transpose()?;
+        Ok(LdapControl {
+            oid,
+            critical,
+            value,
+        })
+    }
+}
+
+/// protocolOp of a message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LdapOperation<'a> {
+    BindRequest(LdapBindRequest<'a>),
+    BindResponse {
+        result: LdapResult<'a>,
+        /// Server token of a SASL or Sicily exchange (NTLMSSP CHALLENGE).
+        server_sasl_credentials: Option<&'a [u8]>,
+    },
+    UnbindRequest,
+    SearchRequest(LdapSearchRequest<'a>),
+    SearchResultEntry(LdapSearchResultEntry<'a>),
+    SearchResultReference(Vec<&'a str>),
+    SearchResultDone(LdapResult<'a>),
+    ExtendedRequest {
+        name: &'a str,
+        value: Option<&'a [u8]>,
+    },
+    ExtendedResponse {
+        result: LdapResult<'a>,
+        name: Option<&'a str>,
+        value: Option<&'a [u8]>,
+    },
+    /// Modify, add, delete, modify DN and compare responses: a bare
+    /// LDAPResult.
+    Response {
+        operation: u32,
+        result: LdapResult<'a>,
+    },
+    /// Operation kept undecoded (modify, add, delete, compare, abandon...).
+    Other {
+        operation: u32,
+        value: &'a [u8],
+    },
+}
+
+impl<'a> LdapOperation<'a> {
+    fn parse(element: BerElement<'a>) -> Result<Self, LdapError> {
+        if element.class != BerClass::Application {
+            return Err(LdapError::Ber(BerError::UnexpectedTag {
+                field: "protocolOp",
+                class: element.class.bits(),
+                number: element.number,
+            }));
+        }
+        let mut fields = element.children();
+        let operation = match element.number {
+            LDAP_BIND_REQUEST => LdapOperation::BindRequest(LdapBindRequest::parse(fields)?),
+            LDAP_BIND_RESPONSE => LdapOperation::BindResponse {
+                result: LdapResult::parse(&mut fields)?,
+                server_sasl_credentials: fields
+                    .optional_context(7, "serverSaslCreds")?
+                    .map(|credentials| credentials.value),
+            },
+            LDAP_UNBIND_REQUEST => LdapOperation::UnbindRequest,
+            LDAP_SEARCH_REQUEST => LdapOperation::SearchRequest(LdapSearchRequest::parse(fields)?),
+            LDAP_SEARCH_RESULT_ENTRY => {
+                LdapOperation::SearchResultEntry(LdapSearchResultEntry::parse(fields)?)
+            }
+            LDAP_SEARCH_RESULT_REFERENCE => {
+                let mut uris = Vec::new();
+                while !fields.is_empty() {
+                    uris.push(extract_ber_string(fields.octet_string("URI")?, "URI")?);
+                }
+                LdapOperation::SearchResultReference(uris)
+            }
+            LDAP_SEARCH_RESULT_DONE => {
+                LdapOperation::SearchResultDone(LdapResult::parse(&mut fields)?)
+            }
+            // requestName [0] et requestValue [1] en etiquetage implicite.
+            LDAP_EXTENDED_REQUEST => LdapOperation::ExtendedRequest {
+                name: fields.context(0, "requestName")?.string("requestName")?,
+                value: fields
+                    .optional_context(1, "requestValue")?
+                    .map(|value| value.value),
+            },
+            LDAP_EXTENDED_RESPONSE => LdapOperation::ExtendedResponse {
+                result: LdapResult::parse(&mut fields)?,
+                name: fields
+                    .optional_context(10, "responseName")?
+                    .map(|name| name.string("responseName"))
+                    .transpose()?,
+                value: fields
+                    .optional_context(11, "responseValue")?
+                    .map(|value| value.value),
+            },
+            operation @ (LDAP_MODIFY_RESPONSE
+            | LDAP_ADD_RESPONSE
+            | LDAP_DEL_RESPONSE
+            | LDAP_MODIFY_DN_RESPONSE
+            | LDAP_COMPARE_RESPONSE) => LdapOperation::Response {
+                operation,
+                result: LdapResult::parse(&mut fields)?,
+            },
+            operation => LdapOperation::Other {
+                operation,
+                value: element.value,
+            },
+        };
+        Ok(operation)
+    }
+
+    pub fn operation(&self) -> u32 {
+        match self {
+            LdapOperation::BindRequest(_) => LDAP_BIND_REQUEST,
+            LdapOperation::BindResponse { .. } => LDAP_BIND_RESPONSE,
+            LdapOperation::UnbindRequest => LDAP_UNBIND_REQUEST,
+            LdapOperation::SearchRequest(_) => LDAP_SEARCH_REQUEST,
+            LdapOperation::SearchResultEntry(_) => LDAP_SEARCH_RESULT_ENTRY,
+            LdapOperation::SearchResultReference(_) => LDAP_SEARCH_RESULT_REFERENCE,
+            LdapOperation::SearchResultDone(_) => LDAP_SEARCH_RESULT_DONE,
+            LdapOperation::ExtendedRequest { .. } => LDAP_EXTENDED_REQUEST,
+            LdapOperation::ExtendedResponse { .. } => LDAP_EXTENDED_RESPONSE,
+            LdapOperation::Response { operation, .. } | LdapOperation::Other { operation, .. } => {
+                *operation
+            }
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        ldap_operation_name(self.operation())
+    }
+
+    /// LDAPResult of a response.
+    pub fn result(&self) -> Option<&LdapResult<'a>> {
+        match self {
+            LdapOperation::BindResponse { result, .. }
+            | LdapOperation::SearchResultDone(result)
+            | LdapOperation::ExtendedResponse { result, .. }
+            | LdapOperation::Response { result, .. } => Some(result),
+            _ => None,
+        }
+    }
+
+    /// NTLMSSP token of a bind: the client's Sicily or SASL token, or the
+    /// server's CHALLENGE, in matchedDN for Sicily and in
+    /// serverSaslCreds for SASL.
+    pub fn ntlmssp_token(&self) -> Option<&'a [u8]> {
+        match self {
+            LdapOperation::BindRequest(bind) => bind.authentication.ntlmssp_token(),
+            LdapOperation::BindResponse {
+                result,
+                server_sasl_credentials,
+            } => [Some(result.matched_dn), *server_sasl_credentials]
+                .into_iter()
+                .flatten()
+                .find(|token| token.starts_with(&NTLMSSP_SIGNATURE)),
+            _ => None,
+        }
+    }
+
+    pub fn is_start_tls(&self) -> bool {
+        matches!(
+            self,
+            LdapOperation::ExtendedRequest {
+                name: LDAP_STARTTLS_OID,
+                ..
+            }
+        )
+    }
+
+    pub fn extended_operation_name(&self) -> Option<&'static str> {
+        match self {
+            LdapOperation::ExtendedRequest { name, .. }
+            | LdapOperation::ExtendedResponse {
+                name: Some(name), ..
+            } => Some(ldap_extended_operation_name(name)),
+            _ => None,
+        }
+    }
+}
+
+/// LDAPResult: outcome of an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LdapResult<'a> {
+    pub result_code: u32,
+    /// Kept raw: the bindResponse of a Sicily negotiate carries the
+    /// NTLMSSP CHALLENGE here instead of a DN (MS-ADTS §5.1.1.1.3).
+    pub matched_dn: &'a [u8],
+    /// Active Directory puts the Win32 error here, as in
+    /// `80090308: LdapErr: DSID-0C09041C, ... data 52e`.
+    pub diagnostic_message: &'a str,
+}
+
+impl<'a> LdapResult<'a> {
+    fn parse(fields: &mut BerReader<'a>) -> Result<Self, LdapError> {
+        let result_code = fields
+            .expect(BerClass::Universal, BER_TAG_ENUMERATED, "resultCode")?
+            .unsigned("resultCode")?;
+        let matched_dn = fields.octet_string("matchedDN")?;
+        let diagnostic_message = extract_ber_string(
+            fields.octet_string("diagnosticMessage")?,
+            "diagnosticMessage",
+        )?;
+        // referral [3] optionnel, ignore.
+        fields.optional_context(3, "referral")?;
+        Ok(LdapResult {
+            result_code,
+            matched_dn,
+            diagnostic_message,
+        })
+    }
+
+    pub fn result_code_name(&self) -> &'static str {
+        ldap_result_code_name(self.result_code)
+    }
+
+    pub fn is_success(&self) -> bool {
+        self.result_code == LDAP_RESULT_SUCCESS
+    }
+}
+
+/// BindRequest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LdapBindRequest<'a> {
+    pub version: i64,
+    /// Bind DN; empty for anonymous and most SASL binds, a user name for
+    /// Sicily.
+    pub name: &'a str,
+    pub authentication: LdapAuthentication<'a>,
+}
+
+impl<'a> LdapBindRequest<'a> {
+    fn parse(mut fields: BerReader<'a>) -> Result<Self, LdapError> {
+        let version = fields.integer("version")?;
+        let name = extract_ber_string(fields.octet_string("name")?, "name")?;
+        let authentication = LdapAuthentication::parse(fields.next_element("authentication")?)?;
+        Ok(LdapBindRequest {
+            version,
+            name,
+            authentication,
+        })
+    }
+
+    /// Anonymous bind: simple, with no password.
+    pub fn is_anonymous(&self) -> bool {
+        matches!(self.authentication, LdapAuthentication::Simple(password) if password.is_empty())
+    }
+}
+
+/// AuthenticationChoice of a BindRequest.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LdapAuthentication<'a> {
+    /// Cleartext password.
+    Simple(&'a [u8]),
+    Sasl {
+        mechanism: &'a str,
+        credentials: Option<&'a [u8]>,
+    },
+    SicilyPackageDiscovery,
+    /// NTLMSSP NEGOTIATE.
+    SicilyNegotiate(&'a [u8]),
+    /// NTLMSSP AUTHENTICATE.
+    SicilyResponse(&'a [u8]),
+    Other {
+        choice: u32,
+        value: &'a [u8],
+    },
+}
+
+impl<'a> LdapAuthentication<'a> {
+    fn parse(element: BerElement<'a>) -> Result<Self, LdapError> {
+        if element.class != BerClass::Context {
+            return Ok(LdapAuthentication::Other {
+                choice: element.number,
+                value: element.value,
+            });
+        }
+        Ok(match element.number {
+            LDAP_AUTH_SIMPLE => LdapAuthentication::Simple(element.value),
+            LDAP_AUTH_SASL => {
+                let mut fields = element.children();
+                LdapAuthentication::Sasl {
+                    mechanism: extract_ber_string(fields.octet_string("mechanism")?, "mechanism")?,
+                    credentials: (!fields.is_empty())
+                        .then(|| fields.octet_string("credentials"))
+                        .transpose()?,
+                }
+            }
+            LDAP_AUTH_SICILY_PACKAGE_DISCOVERY => LdapAuthentication::SicilyPackageDiscovery,
+            LDAP_AUTH_SICILY_NEGOTIATE => LdapAuthentication::SicilyNegotiate(element.value),
+            LDAP_AUTH_SICILY_RESPONSE => LdapAuthentication::SicilyResponse(element.value),
+            choice => LdapAuthentication::Other {
+                choice,
+                value: element.value,
+            },
+        })
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            LdapAuthentication::Simple(_) => "simple",
+            LdapAuthentication::Sasl { .. } => "SASL",
+            LdapAuthentication::SicilyPackageDiscovery => "sicilyPackageDiscovery",
+            LdapAuthentication::SicilyNegotiate(_) => "sicilyNegotiate",
+            LdapAuthentication::SicilyResponse(_) => "sicilyResponse",
+            LdapAuthentication::Other { .. } => "Other",
+        }
+    }
+
+    /// Raw NTLMSSP token of a Sicily bind or of a SASL bind whose
+    /// credentials are not wrapped in SPNEGO.
+    pub fn ntlmssp_token(&self) -> Option<&'a [u8]> {
+        match self {
+            LdapAuthentication::SicilyNegotiate(token)
+            | LdapAuthentication::SicilyResponse(token)
+            | LdapAuthentication::Sasl {
+                credentials: Some(token),
+                ..
+            } => Some(*token).filter(|token| token.starts_with(&NTLMSSP_SIGNATURE)),
+            _ => None,
+        }
+    }
+}
+
+/// SearchRequest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LdapSearchRequest<'a> {
+    pub base_object: &'a str,
+    pub scope: u32,
+    pub deref_aliases: u32,
+    pub size_limit: i64,
+    pub time_limit: i64,
+    pub types_only: bool,
+    pub filter: LdapFilter<'a>,
+    pub attributes: Vec<&'a str>,
+}
+
+impl<'a> LdapSearchRequest<'a> {
+    fn parse(mut fields: BerReader<'a>) -> Result<Self, LdapError> {
+        let base_object = extract_ber_string(fields.octet_string("baseObject")?, "baseObject")?;
+        let scope = fields
+            .expect(BerClass::Universal, BER_TAG_ENUMERATED, "scope")?
+            .unsigned("scope")?;
+        let deref_aliases = fields
+            .expect(BerClass::Universal, BER_TAG_ENUMERATED, "derefAliases")?
+            .unsigned("derefAliases")?;
+        let size_limit = fields.integer("sizeLimit")?;
+        let time_limit = fields.integer("timeLimit")?;
+        let types_only = fields
+            .expect(BerClass::Universal, BER_TAG_BOOLEAN, "typesOnly")?
+            .boolean("typesOnly")?;
+        let filter = LdapFilter::parse(fields.next_element("filter")?, 0)?;
+        let mut list = fields.sequence("attributes")?.children();
+        let mut attributes = Vec::new();
+        while !list.is_empty() {
+            attributes.push(extract_ber_string(
+                list.octet_string("attribute")?,
+                "attribute",
+            )?);
+        }
+        Ok(LdapSearchRequest {
+            base_object,
+            scope,
+            deref_aliases,
+            size_limit,
+            time_limit,
+            types_only,
+            filter,
+            attributes,
+        })
+    }
+
+    pub fn scope_name(&self) -> &'static str {
+        ldap_scope_name(self.scope)
+    }
+}
+
+/// SearchResultEntry: one returned object.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LdapSearchResultEntry<'a> {
+    pub object_name: &'a str,
+    pub attributes: Vec<LdapAttribute<'a>>,
+}
+
+impl<'a> LdapSearchResultEntry<'a> {
+    fn parse(mut fields: BerReader<'a>) -> Result<Self, LdapError> {
+        let object_name = extract_ber_string(fields.octet_string("objectName")?, "objectName")?;
+        let mut list = fields.sequence("attributes")?.children();
+        let mut attributes = Vec::new();
+        while !list.is_empty() {
+            let mut attribute = list.sequence("PartialAttribute")?.children();
+            let name = extract_ber_string(attribute.octet_string("type")?, "type")?;
+            let mut set = attribute
+                .expect(BerClass::Universal, BER_TAG_SET, "vals")?
+                .children();
+            let mut values = Vec::new();
+            while !set.is_empty() {
+                values.push(set.octet_string("value")?);
+            }
+            attributes.push(LdapAttribute { name, values });
+        }
+        Ok(LdapSearchResultEntry {
+            object_name,
+            attributes,
+        })
+    }
+
+    /// Values of an attribute, matched without regard to case.
+    pub fn values(&self, name: &str) -> Option<&[&'a [u8]]> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.name.eq_ignore_ascii_case(name))
+            .map(|attribute| attribute.values.as_slice())
+    }
+}
+
+/// Attribute of a search result: binary values such as `objectSid`
+/// stay raw.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LdapAttribute<'a> {
+    pub name: &'a str,
+    pub values: Vec<&'a [u8]>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// searchRequest de kerberoasting : comptes utilisateurs actifs dotes
+    /// d'un servicePrincipalName, avec le controle de pagination.
+    const SEARCH_REQUEST_HEX: &str = concat!(
+        "3081f40201056381c5041244433d636f72702c44433d6578616d706c650a0102",
+        "0a0100020100020100010100a078a318040e6f626a65637443617465676f7279",
+        "0406706572736f6ea313040b6f626a656374436c617373040475736572871473",
+        "6572766963655072696e636970616c4e616d65a231a92f8116312e322e383430",
+        "2e3131333535362e312e342e3830338212757365724163636f756e74436f6e74",
+        "726f6c8301323026040e73414d4163636f756e744e616d650414736572766963",
+        "655072696e636970616c4e616d65a02730250416312e322e3834302e31313335",
+        "35362e312e342e3331390101ff04083006020203e80400",
+    );
+
+    /// bindRequest simple en clair, puis bindResponse invalidCredentials
+    /// (data 52e) dans le meme tampon.
+    const BIND_EXCHANGE_HEX: &str = concat!(
+        "3040020101603b0201030429434e3d7376635f6261636b75702c434e3d557365",
+        "72732c44433d636f72702c44433d6578616d706c65800b57696e746572323032",
+        "3621",
+        "3063020101615e0a01310400045738303039303330383a204c6461704572723a",
+        "20445349442d30433039303434452c20636f6d6d656e743a2041636365707453",
+        "65637572697479436f6e74657874206572726f722c2064617461203532652c20",
+        "7634353633",
+    );
+
+    fn fixture(hex: &str) -> Vec<u8> {
+        hex::decode(hex).expect("invalid test hex fixture")
+    }
+
+    #[test]
+    fn kerberoasting_search() {
+        let bytes = fixture(SEARCH_REQUEST_HEX);
+        let packet = LdapPacket::try_from(bytes.as_slice()).expect("searchRequest valide");
+        let [message] = packet.messages.as_slice() else {
+            panic!("attendu un seul message");
+        };
+        assert_eq!(message.message_id, 5);
+        assert_eq!(message.controls[0].oid, "1.2.840.113556.1.4.319");
+        assert!(message.controls[0].critical);
+        let LdapOperation::SearchRequest(search) = &message.operation else {
+            panic!("attendu un searchRequest");
+        };
+        assert_eq!(search.base_object, "DC=corp,DC=example");
+        assert_eq!(search.scope_name(), "wholeSubtree");
+        assert_eq!(
+            search.filter.to_string(),
+            "(&(objectCategory=person)(objectClass=user)(servicePrincipalName=*)\
+             (!(userAccountControl:1.2.840.113556.1.4.803:=2)))"
+        );
+        assert_eq!(
+            search.attributes,
+            ["sAMAccountName", "servicePrincipalName"]
+        );
+    }
+
+    #[test]
+    fn simple_bind_and_failure() {
+        let bytes = fixture(BIND_EXCHANGE_HEX);
+        let packet = LdapPacket::try_from(bytes.as_slice()).expect("echange valide");
+        assert_eq!(packet.messages.len(), 2);
+        let LdapOperation::BindRequest(bind) = &packet.messages[0].operation else {
+            panic!("attendu un bindRequest");
+        };
+        assert_eq!(bind.name, "CN=svc_backup,CN=Users,DC=corp,DC=example");
+        assert_eq!(
+            bind.authentication,
+            LdapAuthentication::Simple(b"Winter2026!")
+        );
+        assert!(!bind.is_anonymous());
+        assert_eq!(bind.authentication.ntlmssp_token(), None);
+
+        let response = &packet.messages[1].operation;
+        assert_eq!(response.name(), "bindResponse");
+        let result = response.result().expect("LDAPResult");
+        assert_eq!(result.result_code_name(), "invalidCredentials");
+        assert!(result.diagnostic_message.contains("data 52e"));
+    }
+
+    #[test]
+    fn malformed_packets() {
+        assert_eq!(LdapPacket::try_from(&[][..]), Err(LdapError::Empty));
+        let bytes = fixture(BIND_EXCHANGE_HEX);
+        // Second message coupe par la fin du segment.
+        assert!(matches!(
+            LdapPacket::try_from(&bytes[..100]),
+            Err(LdapError::Ber(BerError::Truncated { .. }))
+        ));
+        // messageID negatif.
+        assert_eq!(
+            LdapPacket::try_from(&[0x30, 0x05, 0x02, 0x01, 0xFF, 0x42, 0x00][..]),
+            Err(LdapError::InvalidMessageId(-1))
+        );
+        // Sicily NEGOTIATE : jeton NTLMSSP brut.
+        let negotiate = LdapAuthentication::SicilyNegotiate(b"NTLMSSP\0\x01\0\0\0");
+        assert_eq!(negotiate.ntlmssp_token().map(<[u8]>::len), Some(12));
+    }
+}
@@ -0,0 +1,291 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! MMS `Data` values (ISO 9506-2, 14.4), shared by read and write services,
+//! information reports and the `allData` of IEC 61850 GOOSE messages.
+
+use crate::{
+    checks::application::mms::{
+        MMS_MAX_NESTING_DEPTH, extract_mms_identifier, extract_mms_integer, extract_mms_tlv,
+        extract_mms_unsigned, mms_data_access_error_name,
+    },
+    errors::application::mms::MmsError,
+    parse::application::protocols::{ber::BerClass, bounded_capacity},
+};
+
+/// Smallest encoded `Data` element: tag and length of an empty value.
+const MMS_MIN_DATA_LEN: usize = 2;
+
+/// UTC timestamp of IEC 61850: seconds since the Unix epoch, a binary
+/// fraction of second on 24 bits and a quality byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MmsUtcTime {
+    pub seconds: u32,
+    pub fraction: u32,
+    /// Leap second known, clock failure, clock not synchronized and the
+    /// number of significant fraction bits.
+    pub quality: u8,
+}
+
+impl MmsUtcTime {
+    pub fn parse(data: &[u8]) -> Result<Self, MmsError> {
+        let bytes: [u8; 8] = data.try_into().map_err(|_| MmsError::InvalidValueLength {
+            field: "utc-time",
+            length: data.len(),
+        })?;
+        Ok(MmsUtcTime {
+            seconds: u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]),
+            fraction: u32::from_be_bytes([0, bytes[4], bytes[5], bytes[6]]),
+            quality: bytes[7],
+        })
+    }
+
+    pub fn subsec_nanos(&self) -> u32 {
+        ((u64::from(self.fraction) * 1_000_000_000) >> 24) as u32
+    }
+}
+
+/// Decoded MMS value.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum MmsData<'a> {
+    Array(Vec<MmsData<'a>>),
+    Structure(Vec<MmsData<'a>>),
+    Boolean(bool),
+    /// Unused bits of the last byte, then the bits; IEC 61850 qualities
+    /// and double-point positions are bit strings.
+    BitString {
+        unused_bits: u8,
+        data: &'a [u8],
+    },
+    Integer(i64),
+    Unsigned(u64),
+    Float(f32),
+    Double(f64),
+    OctetString(&'a [u8]),
+    VisibleString(&'a str),
+    /// TimeOfDay: milliseconds since midnight, then days since 1984-01-01.
+    BinaryTime(&'a [u8]),
+    MmsString(&'a str),
+    UtcTime(MmsUtcTime),
+    /// Types rarely seen in practice (generalized time, BCD, boolean
+    /// arrays, object identifiers).
+    Other {
+        tag: u32,
+        data: &'a [u8],
+    },
+}
+
+impl<'a> MmsData<'a> {
+    /// Decodes a sequence of `Data` elements.
+    pub fn parse_all(bytes: &'a [u8]) -> Result<Vec<Self>, MmsError> {
+        Self::parse_list(bytes, 0)
+    }
+
+    /// Decodes the `Data` element at the head of `bytes` and returns what
+    /// follows it.
+    pub fn parse(bytes: &'a [u8]) -> Result<(Self, &'a [u8]), MmsError> {
+        Self::parse_nested(bytes, 0)
+    }
+
+    fn parse_list(mut bytes: &'a [u8], depth: usize) -> Result<Vec<Self>, MmsError> {
+        let mut values =
+            Vec::with_capacity(bounded_capacity(bytes.len(), bytes.len(), MMS_MIN_DATA_LEN));
+        while !bytes.is_empty() {
+            let (value, rest) = Self::parse_nested(bytes, depth)?;
+            values.push(value);
+            bytes = rest;
+        }
+        Ok(values)
+    }
+
+    fn parse_nested(bytes: &'a [u8], depth: usize) -> Result<(Self, &'a [u8]), MmsError> {
+        let (tlv, rest) = extract_mms_tlv(bytes, "data")?;
+        if tlv.class != BerClass::Context {
+            return Err(MmsError::UnexpectedTag {
+                field: "data",
+                tag: tlv.number,
+            });
+        }
+        let data = tlv.value;
+        let value = match tlv.number {
+            1 | 2 => {
+                if depth == MMS_MAX_NESTING_DEPTH {
+                    return Err(MmsError::NestingTooDeep(MMS_MAX_NESTING_DEPTH));
+                }
+                let items = Self::parse_list(data, depth + 1)?;
+                if tlv.number == 1 {
+                    MmsData::Array(items)
+                } else {
+                    MmsData::Structure(items)
+                }
+            }
+            3 => match data {
+                [flag] => MmsData::Boolean(*flag != 0),
+                _ => return Err(invalid_length("boolean", data)),
+            },
+            4 => match data.split_first() {
+                Some((&unused_bits, data)) if unused_bits < 8 => {
+                    MmsData::BitString { unused_bits, data }
+                }
+                _ => return Err(invalid_length("bit-string", data)),
+            },
+            5 => MmsData::Integer(extract_mms_integer(data, "integer")?),
+            6 => MmsData::Unsigned(extract_unsigned64(data)?),
+            // Premier octet : largeur de l'exposant (8 en simple precision).
+            7 => match data {
+                [_, a, b, c, d] => MmsData::Float(f32::from_be_bytes([*a, *b, *c, *d])),
+                [_, rest @ ..] if rest.len() == 8 => {
+                    let mut bytes = [0u8; 8];
+                    bytes.copy_from_slice(rest);
+                    MmsData::Double(f64::from_be_bytes(bytes))
+                }
+                _ => return Err(invalid_length("floating-point", data)),
+            },
+            9 => MmsData::OctetString(data),
+            10 => MmsData::VisibleString(extract_mms_identifier(data, "visible-string")?),
+            12 => match data.len() {
+                4 | 6 => MmsData::BinaryTime(data),
+                _ => return Err(invalid_length("binary-time", data)),
+            },
+            16 => MmsData::MmsString(std::str::from_utf8(data).map_err(|_| {
+                MmsError::InvalidIdentifier {
+                    field: "mms-string",
+                }
+            })?),
+            17 => MmsData::UtcTime(MmsUtcTime::parse(data)?),
+            tag => MmsData::Other { tag, data },
+        };
+        Ok((value, rest))
+    }
+}
+
+/// Result of reading one variable.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MmsAccessResult<'a> {
+    Success(MmsData<'a>),
+    /// DataAccessError code.
+    Failure(u32),
+}
+
+impl<'a> MmsAccessResult<'a> {
+    /// Decodes a list of access results: `failure [0]` or a `Data` value.
+    pub fn parse_all(mut bytes: &'a [u8]) -> Result<Vec<Self>, MmsError> {
+        let mut results =
+            Vec::with_capacity(bounded_capacity(bytes.len(), bytes.len(), MMS_MIN_DATA_LEN));
+        while !bytes.is_empty() {
+            let (tlv, rest) = extract_mms_tlv(bytes, "access result")?;
+            if tlv.is_context(0) {
+                results.push(MmsAccessResult::Failure(extract_mms_unsigned(
+                    tlv.value,
+                    "data access error",
+                )?));
+                bytes = rest;
+            } else {
+                let (value, rest) = MmsData::parse(bytes)?;
+                results.push(MmsAccessResult::Success(value));
+                bytes = rest;
+            }
+        }
+        Ok(results)
+    }
+
+    pub fn failure_name(&self) -> Option<&'static str> {
+        match self {
+            MmsAccessResult::Failure(code) => Some(mms_data_access_error_name(*code)),
+            MmsAccessResult::Success(_) => None,
+        }
+    }
+}
+
+fn invalid_length(field: &'static str, data: &[u8]) -> MmsError {
+    MmsError::InvalidValueLength {
+        field,
+        length: data.len(),
+    }
+}
+
+/// Entier non signe jusqu'a 64 bits, octet nul de tete admis.
+fn extract_unsigned64(data: &[u8]) -> Result<u64, MmsError> {
+    let digits = match data {
+        [0, rest @ ..] if !rest.is_empty() => rest,
+        _ => data,
+    };
+    if digits.is_empty() || digits.len() > 8 || (digits.len() == data.len() && data[0] & 0x80 != 0)
+    {
+        return Err(invalid_length("unsigned", data));
+    }
+    Ok(digits
+        .iter()
+        .fold(0u64, |value, &byte| (value << 8) | u64::from(byte)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_nested_values() {
+        // Structure { position double (2 bits), qualite (13 bits),
+        // horodatage }, puis un flottant 230.5 et un entier -3.
+        let bytes = hex::decode(concat!(
+            "a213",
+            "84020680",
+            "8403030000",
+            "91086ad63e808000000a",
+            "87050843668000",
+            "8501fd"
+        ))
+        .expect("invalid hex fixture");
+        let values = MmsData::parse_all(&bytes).expect("valid data");
+        let MmsData::Structure(items) = &values[0] else {
+            panic!("structure expected");
+        };
+        assert_eq!(
+            items[0],
+            MmsData::BitString {
+                unused_bits: 6,
+                data: &[0x80]
+            }
+        );
+        let MmsData::UtcTime(time) = items[2] else {
+            panic!("utc-time expected");
+        };
+        assert_eq!(time.seconds, 1_792_425_600);
+        assert_eq!(time.subsec_nanos(), 500_000_000);
+        assert_eq!(values[1..], [MmsData::Float(230.5), MmsData::Integer(-3)]);
+    }
+
+    #[test]
+    fn reject_malformed_values() {
+        assert_eq!(
+            MmsData::parse_all(&[0x83, 0x02, 0x00, 0x01]),
+            Err(MmsError::InvalidValueLength {
+                field: "boolean",
+                length: 2
+            })
+        );
+        assert_eq!(
+            MmsData::parse_all(&[0x86, 0x02, 0x00, 0xFF]),
+            Ok(vec![MmsData::Unsigned(255)])
+        );
+        // Un booleen au fond de 17 tableaux imbriques.
+        let mut bytes = vec![0x83, 0x01, 0x01];
+        for _ in 0..=MMS_MAX_NESTING_DEPTH {
+            let mut outer = vec![0xA1, bytes.len() as u8];
+            outer.extend_from_slice(&bytes);
+            bytes = outer;
+        }
+        assert_eq!(
+            MmsData::parse_all(&bytes),
+            Err(MmsError::NestingTooDeep(MMS_MAX_NESTING_DEPTH))
+        );
+        assert_eq!(
+            MmsAccessResult::parse_all(&[0x80, 0x01, 0x0A])
+                .map(|results| results[0].failure_name()),
+            Ok(Some("object-non-existent"))
+        );
+    }
+}
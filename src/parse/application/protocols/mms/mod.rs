@@ -0,0 +1,398 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! MMS (ISO 9506), the client/server protocol of IEC 61850, over the OSI
+//! upper layers on TCP 102.
+//!
+//! An MMS PDU travels in a COTP data TPDU, behind a session SPDU, a
+//! presentation PPDU and, while an association is set up or released, an
+//! ACSE APDU. [`MmsPacket`] walks these layers down to the [`MmsPdu`];
+//! [`MmsPdu::service`] decodes the services IEC 61850 relies on.
+
+use std::convert::TryFrom;
+
+use crate::{
+    checks::application::mms::{
+        MMS_ACSE_USER_INFORMATION, MMS_PDU_CANCEL_ERROR, MMS_PDU_CANCEL_REQUEST,
+        MMS_PDU_CANCEL_RESPONSE, MMS_PDU_CONFIRMED_ERROR, MMS_PDU_CONFIRMED_REQUEST,
+        MMS_PDU_CONFIRMED_RESPONSE, MMS_PDU_INITIATE_REQUEST, MMS_PDU_INITIATE_RESPONSE,
+        MMS_PDU_REJECT, MMS_PDU_UNCONFIRMED, MMS_PRESENTATION_FULLY_ENCODED,
+        MMS_SERVICE_GET_NAME_LIST, MMS_SERVICE_IDENTIFY, MMS_SERVICE_INFORMATION_REPORT,
+        MMS_SERVICE_READ, MMS_SERVICE_WRITE, MMS_TAG_EXTERNAL, MMS_TAG_INTEGER, MMS_TAG_SEQUENCE,
+        MMS_TAG_SET, extract_mms_session_user_data, extract_mms_tlv, extract_mms_tpkt,
+        extract_mms_unsigned, mms_acse_name, mms_confirmed_service_name, mms_pdu_name,
+        mms_spdu_name, mms_unconfirmed_service_name,
+    },
+    errors::application::mms::MmsError,
+    parse::application::protocols::{
+        ber::{BerClass, BerElement},
+        copt::{CotpHeader, CotpParameter, CotpPduType},
+    },
+};
+
+pub mod data;
+pub mod service;
+
+use service::MmsService;
+
+/// MMS PDU with the session and association context it was found in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MmsPacket<'a> {
+    /// Session SPDU: DATA TRANSFER, or CONNECT/ACCEPT/FINISH/DISCONNECT
+    /// while the association is set up or released.
+    pub spdu_type: u8,
+    /// ACSE APDU (0 AARQ, 1 AARE, 2 RLRQ, 3 RLRE, 4 ABRT) wrapping the PDU.
+    pub acse: Option<u32>,
+    pub pdu: MmsPdu<'a>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for MmsPacket<'a> {
+    type Error = MmsError;
+
+    fn try_from(payload: &'a [u8]) -> Result<Self, MmsError> {
+        Self::from_stream(payload).map(|(packet, _)| packet)
+    }
+}
+
+impl<'a> MmsPacket<'a> {
+    /// Parses the first TPKT frame of a TCP 102 stream and returns the
+    /// bytes that follow it.
+    pub fn from_stream(stream: &'a [u8]) -> Result<(Self, &'a [u8]), MmsError> {
+        let (tpdu, rest) = extract_mms_tpkt(stream)?;
+        let (cotp, _) = CotpHeader::from_bytes(tpdu)?;
+        if cotp.pdu_type != CotpPduType::Data {
+            return Err(MmsError::NotCotpData(tpdu[1]));
+        }
+        if cotp.parameters.contains(&CotpParameter::Eot(false)) {
+            return Err(MmsError::FragmentedTsdu);
+        }
+        Ok((Self::parse(cotp.user_data)?, rest))
+    }
+
+    /// Parses the user data of a COTP data TPDU, from the session SPDU on.
+    pub fn parse(session: &'a [u8]) -> Result<Self, MmsError> {
+        let (spdu_type, presentation) = extract_mms_session_user_data(session)?;
+        let content = presentation_data(presentation)?;
+        let (outer, _) = extract_mms_tlv(content, "presentation data value")?;
+
+        let (acse, pdu) = if outer.class == BerClass::Application && outer.number <= 4 {
+            (Some(outer.number), acse_user_information(&outer)?)
+        } else {
+            (None, content)
+        };
+        Ok(MmsPacket {
+            spdu_type,
+            acse,
+            pdu: MmsPdu::parse(pdu)?,
+        })
+    }
+
+    pub fn spdu_name(&self) -> &'static str {
+        mms_spdu_name(self.spdu_type)
+    }
+
+    pub fn acse_name(&self) -> Option<&'static str> {
+        self.acse.map(mms_acse_name)
+    }
+}
+
+fn find<'a>(
+    mut bytes: &'a [u8],
+    field: &'static str,
+    wanted: impl Fn(&BerElement<'a>) -> bool,
+) -> Result<BerElement<'a>, MmsError> {
+    while !bytes.is_empty() {
+        let (tlv, rest) = extract_mms_tlv(bytes, field)?;
+        if wanted(&tlv) {
+            return Ok(tlv);
+        }
+        bytes = rest;
+    }
+    Err(MmsError::MissingUserData(field))
+}
+
+/// Contenu de la valeur de presentation d'une PPDU de donnees, CP ou CPA.
+fn presentation_data(ppdu: &[u8]) -> Result<&[u8], MmsError> {
+    let (tlv, _) = extract_mms_tlv(ppdu, "presentation PPDU")?;
+    let is_fully_encoded = |tlv: &BerElement<'_>| {
+        tlv.class == BerClass::Application && tlv.number == MMS_PRESENTATION_FULLY_ENCODED
+    };
+    let user_data = if is_fully_encoded(&tlv) {
+        tlv.value
+    } else if tlv.is_universal(MMS_TAG_SET) {
+        // CP et CPA : parametres du mode normal [2], puis user-data.
+        let normal = find(tlv.value, "normal-mode parameters", |tlv| tlv.is_context(2))?;
+        find(normal.value, "presentation user data", is_fully_encoded)?.value
+    } else {
+        return Err(MmsError::UnexpectedTag {
+            field: "presentation PPDU",
+            tag: tlv.number,
+        });
+    };
+
+    let (pdv_list, _) = extract_mms_tlv(user_data, "PDV list")?;
+    if !pdv_list.is_universal(MMS_TAG_SEQUENCE) {
+        return Err(MmsError::UnexpectedTag {
+            field: "PDV list",
+            tag: pdv_list.number,
+        });
+    }
+    // single-ASN1-type [0], apres l'identifiant de contexte.
+    Ok(find(pdv_list.value, "presentation data values", |tlv| {
+        tlv.is_context(0)
+    })?
+    .value)
+}
+
+/// PDU MMS portee par user-information [30] d'un APDU ACSE, dans un
+/// EXTERNAL encode en single-ASN1-type [0].
+fn acse_user_information<'a>(apdu: &BerElement<'a>) -> Result<&'a [u8], MmsError> {
+    let information = find(apdu.value, "ACSE APDU", |tlv| {
+        tlv.is_context(MMS_ACSE_USER_INFORMATION)
+    })?;
+    let external = find(information.value, "ACSE user information", |tlv| {
+        tlv.is_universal(MMS_TAG_EXTERNAL)
+    })?;
+    Ok(find(external.value, "EXTERNAL", |tlv| tlv.is_context(0))?.value)
+}
+
+/// MMS PDU, typed by its context tag in the `MMSpdu` CHOICE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MmsPdu<'a> {
+    pub pdu_type: u32,
+    pub invoke_id: Option<u32>,
+    /// Service choice of confirmed requests and responses and of
+    /// unconfirmed PDUs.
+    pub service: Option<u32>,
+    /// Service parameters, or the whole PDU body for the other types.
+    pub data: &'a [u8],
+}
+
+impl<'a> MmsPdu<'a> {
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, MmsError> {
+        let (tlv, _) = extract_mms_tlv(bytes, "MMS PDU")?;
+        if tlv.class != BerClass::Context {
+            return Err(MmsError::UnexpectedTag {
+                field: "MMS PDU",
+                tag: tlv.number,
+            });
+        }
+        let mut pdu = MmsPdu {
+            pdu_type: tlv.number,
+            invoke_id: None,
+            service: None,
+            data: tlv.value,
+        };
+        match tlv.number {
+            MMS_PDU_CONFIRMED_REQUEST | MMS_PDU_CONFIRMED_RESPONSE => {
+                let (invoke, rest) = extract_mms_tlv(tlv.value, "invoke id")?;
+                if !invoke.is_universal(MMS_TAG_INTEGER) {
+                    return Err(MmsError::UnexpectedTag {
+                        field: "invoke id",
+                        tag: invoke.number,
+                    });
+                }
+                pdu.invoke_id = Some(extract_mms_unsigned(invoke.value, "invoke id")?);
+                let (mut service, rest) = extract_mms_tlv(rest, "service")?;
+                // listOfModifier optionnel avant le service d'une requete.
+                if service.is_universal(MMS_TAG_SEQUENCE) {
+                    service = extract_mms_tlv(rest, "service")?.0;
+                }
+                pdu.service = Some(service.number);
+                pdu.data = service.value;
+            }
+            MMS_PDU_UNCONFIRMED => {
+                let (service, _) = extract_mms_tlv(tlv.value, "service")?;
+                pdu.service = Some(service.number);
+                pdu.data = service.value;
+            }
+            MMS_PDU_CONFIRMED_ERROR | MMS_PDU_REJECT | MMS_PDU_CANCEL_ERROR => {
+                // originalInvokeID [0], optionnel dans un rejet.
+                let (first, _) = extract_mms_tlv(tlv.value, "invoke id")?;
+                if first.is_context(0) {
+                    pdu.invoke_id = Some(extract_mms_unsigned(first.value, "invoke id")?);
+                }
+            }
+            MMS_PDU_CANCEL_REQUEST | MMS_PDU_CANCEL_RESPONSE => {
+                pdu.invoke_id = Some(extract_mms_unsigned(tlv.value, "invoke id")?);
+            }
+            _ => {}
+        }
+        Ok(pdu)
+    }
+
+    pub fn pdu_name(&self) -> &'static str {
+        mms_pdu_name(self.pdu_type)
+    }
+
+    pub fn service_name(&self) -> Option<&'static str> {
+        let service = self.service?;
+        Some(if self.pdu_type == MMS_PDU_UNCONFIRMED {
+            mms_unconfirmed_service_name(service)
+        } else {
+            mms_confirmed_service_name(service)
+        })
+    }
+
+    /// Decoded service parameters, `None` for services this crate does not
+    /// decode.
+    pub fn service(&self) -> Option<Result<MmsService<'a>, MmsError>> {
+        let data = self.data;
+        let decoded = match (self.pdu_type, self.service) {
+            (MMS_PDU_INITIATE_REQUEST | MMS_PDU_INITIATE_RESPONSE, _) => {
+                service::parse_initiate(data)
+            }
+            (MMS_PDU_CONFIRMED_REQUEST, Some(MMS_SERVICE_GET_NAME_LIST)) => {
+                service::parse_get_name_list(data)
+            }
+            (MMS_PDU_CONFIRMED_RESPONSE, Some(MMS_SERVICE_GET_NAME_LIST)) => {
+                service::parse_get_name_list_response(data)
+            }
+            (MMS_PDU_CONFIRMED_RESPONSE, Some(MMS_SERVICE_IDENTIFY)) => {
+                service::parse_identify_response(data)
+            }
+            (MMS_PDU_CONFIRMED_REQUEST, Some(MMS_SERVICE_READ)) => service::parse_read(data),
+            (MMS_PDU_CONFIRMED_RESPONSE, Some(MMS_SERVICE_READ)) => {
+                service::parse_read_response(data)
+            }
+            (MMS_PDU_CONFIRMED_REQUEST, Some(MMS_SERVICE_WRITE)) => service::parse_write(data),
+            (MMS_PDU_CONFIRMED_RESPONSE, Some(MMS_SERVICE_WRITE)) => {
+                service::parse_write_response(data)
+            }
+            (MMS_PDU_UNCONFIRMED, Some(MMS_SERVICE_INFORMATION_REPORT)) => {
+                service::parse_information_report(data)
+            }
+            (MMS_PDU_CONFIRMED_ERROR, _) => find(data, "confirmed error", |tlv| tlv.is_context(2))
+                .and_then(|error| service::parse_service_error(error.value)),
+            _ => return None,
+        };
+        Some(decoded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use data::{MmsAccessResult, MmsData};
+    use service::{MmsObjectName, MmsVariableAccess};
+
+    #[test]
+    fn parse_read_request_in_data_transfer() {
+        // SPDU Give Tokens + Data Transfer, PPDU fully-encoded-data, lecture
+        // de IED1LD0/LLN0$ST$Mod$stVal, invokeID 5.
+        let session = hex::decode(concat!(
+            "0100010061343032020103a02d",
+            "a02b020105a426a124a0223020a01ea11c1a07494544314c44301a114c4c4e30",
+            "245354244d6f6424737456616c"
+        ))
+        .expect("invalid hex fixture");
+        let packet = MmsPacket::parse(&session).expect("valid MMS packet");
+        assert_eq!(packet.spdu_name(), "DATA TRANSFER");
+        assert_eq!(packet.acse, None);
+        assert_eq!(packet.pdu.invoke_id, Some(5));
+        assert_eq!(packet.pdu.service_name(), Some("read"));
+
+        let Some(Ok(MmsService::Read { variables, .. })) = packet.pdu.service() else {
+            panic!("read request expected");
+        };
+        let MmsVariableAccess::Variables(names) = variables else {
+            panic!("named variables expected");
+        };
+        assert_eq!(names[0].to_string(), "IED1LD0/LLN0$ST$Mod$stVal");
+    }
+
+    #[test]
+    fn parse_association_request() {
+        // CONNECT portant CP-type, AARQ et initiate-RequestPDU.
+        let session = hex::decode(concat!(
+            "0d940506130100160102140200023302000134020001c17e317ca00380010",
+            "1a275810400000001820400000001a422300d0201010602520130040602510",
+            "13011020103060628ca2202010430040602510161433041020101a03c603aa",
+            "107060528ca220203be2f282d020103a028a826800300fde88101058201058",
+            "3010aa416800101810305f100820c03ee1c00000408000079ef18"
+        ))
+        .expect("invalid hex fixture");
+        let packet = MmsPacket::parse(&session).expect("valid MMS packet");
+        assert_eq!(packet.spdu_name(), "CONNECT");
+        assert_eq!(packet.acse_name(), Some("AARQ"));
+        assert_eq!(packet.pdu.pdu_name(), "initiate-RequestPDU");
+        assert_eq!(
+            packet.pdu.service(),
+            Some(Ok(MmsService::Initiate {
+                local_detail: Some(65_000),
+                max_outstanding_calling: 5,
+                max_outstanding_called: 5,
+                nesting_level: Some(10)
+            }))
+        );
+    }
+
+    #[test]
+    fn parse_answers_and_reports() {
+        let response = hex::decode("a10d020105a408a10685010180010a").expect("invalid hex fixture");
+        let Some(Ok(MmsService::ReadResponse { results, .. })) =
+            MmsPdu::parse(&response).expect("valid PDU").service()
+        else {
+            panic!("read response expected");
+        };
+        assert_eq!(results[0], MmsAccessResult::Success(MmsData::Integer(1)));
+        assert_eq!(results[1].failure_name(), Some("object-non-existent"));
+
+        let report = hex::decode("a315a013a1058003525054a00a8a047572636284020680")
+            .expect("invalid hex fixture");
+        let pdu = MmsPdu::parse(&report).expect("valid PDU");
+        assert_eq!(pdu.service_name(), Some("informationReport"));
+        let Some(Ok(MmsService::InformationReport { variables, results })) = pdu.service() else {
+            panic!("information report expected");
+        };
+        assert_eq!(
+            variables,
+            MmsVariableAccess::VariableList(MmsObjectName::VmdSpecific("RPT"))
+        );
+        assert_eq!(
+            results[0],
+            MmsAccessResult::Success(MmsData::VisibleString("urcb"))
+        );
+
+        let write = hex::decode(concat!(
+            "a031020106a52ca0233021a01fa11d1a08494544314354524c1a114353574931",
+            "24434f24506f73244f706572a005a203830101"
+        ))
+        .expect("invalid hex fixture");
+        let Some(Ok(MmsService::Write { data, .. })) =
+            MmsPdu::parse(&write).expect("valid PDU").service()
+        else {
+            panic!("write request expected");
+        };
+        assert_eq!(data, vec![MmsData::Structure(vec![MmsData::Boolean(true)])]);
+
+        let error = hex::decode("a20a800107a205a003870102").expect("invalid hex fixture");
+        let error = MmsPdu::parse(&error).expect("valid PDU");
+        assert_eq!(error.invoke_id, Some(7));
+        let service = error
+            .service()
+            .expect("service error")
+            .expect("valid error");
+        assert_eq!(service.error_class_name(), Some("access"));
+    }
+
+    #[test]
+    fn reject_other_cotp_payloads() {
+        // CR COTP : pas de donnees de session.
+        let connection_request = hex::decode("0300001611e00000000100c0010ac1020001c2020001")
+            .expect("invalid hex fixture");
+        assert_eq!(
+            MmsPacket::try_from(&connection_request[..]),
+            Err(MmsError::NotCotpData(0xE0))
+        );
+        // DT portant S7Comm (identifiant 0x32).
+        let s7 = hex::decode("0300001902f08032010000000000080000f0000001000101e0")
+            .expect("invalid hex fixture");
+        assert_eq!(
+            MmsPacket::try_from(&s7[..]),
+            Err(MmsError::UnsupportedSpdu(0x32))
+        );
+    }
+}
@@ -0,0 +1,398 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! MMS services used by IEC 61850 clients and servers: association
+//! (initiate), model discovery (getNameList, identify), variable reads and
+//! writes, and the information reports that carry buffered and unbuffered
+//! reports.
+
+use core::fmt;
+
+use crate::{
+    checks::application::mms::{
+        MMS_TAG_VISIBLE_STRING, extract_mms_identifier, extract_mms_tlv, extract_mms_unsigned,
+        mms_error_class_name,
+    },
+    errors::application::mms::MmsError,
+    parse::application::protocols::ber::BerElement,
+};
+
+use super::data::{MmsAccessResult, MmsData};
+
+/// Name of a variable, variable list or domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MmsObjectName<'a> {
+    VmdSpecific(&'a str),
+    /// IEC 61850 maps a logical device to a domain and a data attribute
+    /// path (`LLN0$ST$Mod$stVal`) to an item.
+    DomainSpecific {
+        domain_id: &'a str,
+        item_id: &'a str,
+    },
+    AaSpecific(&'a str),
+}
+
+impl<'a> MmsObjectName<'a> {
+    fn parse(bytes: &'a [u8]) -> Result<(Self, &'a [u8]), MmsError> {
+        let (tlv, rest) = extract_mms_tlv(bytes, "object name")?;
+        let name = match tlv.number {
+            0 if tlv.is_context(0) => {
+                MmsObjectName::VmdSpecific(extract_mms_identifier(tlv.value, "object name")?)
+            }
+            1 if tlv.is_context(1) => {
+                let (domain, rest) = extract_mms_tlv(tlv.value, "domain id")?;
+                let (item, _) = extract_mms_tlv(rest, "item id")?;
+                MmsObjectName::DomainSpecific {
+                    domain_id: visible_string(&domain, "domain id")?,
+                    item_id: visible_string(&item, "item id")?,
+                }
+            }
+            2 if tlv.is_context(2) => {
+                MmsObjectName::AaSpecific(extract_mms_identifier(tlv.value, "object name")?)
+            }
+            tag => {
+                return Err(MmsError::UnexpectedTag {
+                    field: "object name",
+                    tag,
+                });
+            }
+        };
+        Ok((name, rest))
+    }
+}
+
+impl fmt::Display for MmsObjectName<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MmsObjectName::VmdSpecific(name) | MmsObjectName::AaSpecific(name) => f.write_str(name),
+            MmsObjectName::DomainSpecific { domain_id, item_id } => {
+                write!(f, "{domain_id}/{item_id}")
+            }
+        }
+    }
+}
+
+/// Variables a read, write or report applies to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MmsVariableAccess<'a> {
+    /// Variables named one by one.
+    Variables(Vec<MmsObjectName<'a>>),
+    /// Named variable list (an IEC 61850 data set).
+    VariableList(MmsObjectName<'a>),
+}
+
+impl<'a> MmsVariableAccess<'a> {
+    /// Decodes the `VariableAccessSpecification` CHOICE element `tlv`.
+    fn from_tlv(tlv: &BerElement<'a>) -> Result<Self, MmsError> {
+        if tlv.is_context(1) {
+            return Ok(MmsVariableAccess::VariableList(
+                MmsObjectName::parse(tlv.value)?.0,
+            ));
+        }
+        if !tlv.is_context(0) {
+            return Err(MmsError::UnexpectedTag {
+                field: "variable access specification",
+                tag: tlv.number,
+            });
+        }
+        let mut names = Vec::new();
+        let mut entries = tlv.value;
+        while !entries.is_empty() {
+            let (entry, rest) = extract_mms_tlv(entries, "variable")?;
+            // Seule la specification par nom [0] est decodee ; adresse,
+            // description et acces disperse sont propres aux automates.
+            let (specification, _) = extract_mms_tlv(entry.value, "variable specification")?;
+            if !specification.is_context(0) {
+                return Err(MmsError::UnexpectedTag {
+                    field: "variable specification",
+                    tag: specification.number,
+                });
+            }
+            names.push(MmsObjectName::parse(specification.value)?.0);
+            entries = rest;
+        }
+        Ok(MmsVariableAccess::Variables(names))
+    }
+}
+
+/// Decoded service parameters.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum MmsService<'a> {
+    Initiate {
+        local_detail: Option<u32>,
+        max_outstanding_calling: u32,
+        max_outstanding_called: u32,
+        nesting_level: Option<u32>,
+    },
+    GetNameList {
+        /// Basic object class: 0 named variable, 2 named variable list,
+        /// 9 domain...
+        object_class: u32,
+        /// Domain of a domain-specific scope, `None` for the VMD scope.
+        domain: Option<&'a str>,
+        continue_after: Option<&'a str>,
+    },
+    GetNameListResponse {
+        identifiers: Vec<&'a str>,
+        more_follows: bool,
+    },
+    IdentifyResponse {
+        vendor: &'a str,
+        model: &'a str,
+        revision: &'a str,
+    },
+    Read {
+        specification_with_result: bool,
+        variables: MmsVariableAccess<'a>,
+    },
+    ReadResponse {
+        variables: Option<MmsVariableAccess<'a>>,
+        results: Vec<MmsAccessResult<'a>>,
+    },
+    Write {
+        variables: MmsVariableAccess<'a>,
+        data: Vec<MmsData<'a>>,
+    },
+    /// One entry per variable: `None` on success, the DataAccessError
+    /// code on failure.
+    WriteResponse {
+        results: Vec<Option<u32>>,
+    },
+    InformationReport {
+        variables: MmsVariableAccess<'a>,
+        results: Vec<MmsAccessResult<'a>>,
+    },
+    Error {
+        error_class: u32,
+        error_code: u32,
+    },
+}
+
+impl MmsService<'_> {
+    pub fn error_class_name(&self) -> Option<&'static str> {
+        match *self {
+            MmsService::Error { error_class, .. } => Some(mms_error_class_name(error_class)),
+            _ => None,
+        }
+    }
+}
+
+fn visible_string<'a>(tlv: &BerElement<'a>, field: &'static str) -> Result<&'a str, MmsError> {
+    if !tlv.is_universal(MMS_TAG_VISIBLE_STRING) {
+        return Err(MmsError::UnexpectedTag {
+            field,
+            tag: tlv.number,
+        });
+    }
+    extract_mms_identifier(tlv.value, field)
+}
+
+/// Elements of a SEQUENCE body, in order.
+fn elements<'a>(mut bytes: &'a [u8], field: &'static str) -> Result<Vec<BerElement<'a>>, MmsError> {
+    let mut elements = Vec::new();
+    while !bytes.is_empty() {
+        let (tlv, rest) = extract_mms_tlv(bytes, field)?;
+        elements.push(tlv);
+        bytes = rest;
+    }
+    Ok(elements)
+}
+
+fn context_unsigned(
+    elements: &[BerElement<'_>],
+    number: u32,
+    field: &'static str,
+) -> Result<Option<u32>, MmsError> {
+    elements
+        .iter()
+        .find(|tlv| tlv.is_context(number))
+        .map(|tlv| extract_mms_unsigned(tlv.value, field))
+        .transpose()
+}
+
+pub(super) fn parse_initiate(data: &[u8]) -> Result<MmsService<'_>, MmsError> {
+    let elements = elements(data, "initiate")?;
+    let required = |number, field| {
+        context_unsigned(&elements, number, field)?.ok_or(MmsError::Truncated { field })
+    };
+    Ok(MmsService::Initiate {
+        local_detail: context_unsigned(&elements, 0, "local detail")?,
+        max_outstanding_calling: required(1, "max outstanding calling")?,
+        max_outstanding_called: required(2, "max outstanding called")?,
+        nesting_level: context_unsigned(&elements, 3, "nesting level")?,
+    })
+}
+
+pub(super) fn parse_get_name_list(data: &[u8]) -> Result<MmsService<'_>, MmsError> {
+    let elements = elements(data, "getNameList")?;
+    let class = elements
+        .iter()
+        .find(|tlv| tlv.is_context(0))
+        .ok_or(MmsError::Truncated {
+            field: "object class",
+        })?;
+    let (basic, _) = extract_mms_tlv(class.value, "object class")?;
+    let scope = elements
+        .iter()
+        .find(|tlv| tlv.is_context(1))
+        .ok_or(MmsError::Truncated {
+            field: "object scope",
+        })?;
+    let (scope, _) = extract_mms_tlv(scope.value, "object scope")?;
+    let domain = if scope.is_context(1) {
+        Some(extract_mms_identifier(scope.value, "domain")?)
+    } else {
+        None
+    };
+    let continue_after = elements
+        .iter()
+        .find(|tlv| tlv.is_context(2))
+        .map(|tlv| extract_mms_identifier(tlv.value, "continue after"))
+        .transpose()?;
+    Ok(MmsService::GetNameList {
+        object_class: extract_mms_unsigned(basic.value, "object class")?,
+        domain,
+        continue_after,
+    })
+}
+
+pub(super) fn parse_get_name_list_response(data: &[u8]) -> Result<MmsService<'_>, MmsError> {
+    let elements = elements(data, "getNameList response")?;
+    let mut identifiers = Vec::new();
+    if let Some(list) = elements.iter().find(|tlv| tlv.is_context(0)) {
+        for name in self::elements(list.value, "identifier")? {
+            identifiers.push(visible_string(&name, "identifier")?);
+        }
+    }
+    // moreFollows vaut TRUE par defaut.
+    let more_follows = elements
+        .iter()
+        .find(|tlv| tlv.is_context(1))
+        .is_none_or(|tlv| tlv.value.first().is_some_and(|&flag| flag != 0));
+    Ok(MmsService::GetNameListResponse {
+        identifiers,
+        more_follows,
+    })
+}
+
+pub(super) fn parse_identify_response(data: &[u8]) -> Result<MmsService<'_>, MmsError> {
+    let elements = elements(data, "identify response")?;
+    let field = |number, field| {
+        elements
+            .iter()
+            .find(|tlv| tlv.is_context(number))
+            .ok_or(MmsError::Truncated { field })
+            .and_then(|tlv| extract_mms_identifier(tlv.value, field))
+    };
+    Ok(MmsService::IdentifyResponse {
+        vendor: field(0, "vendor name")?,
+        model: field(1, "model name")?,
+        revision: field(2, "revision")?,
+    })
+}
+
+pub(super) fn parse_read(data: &[u8]) -> Result<MmsService<'_>, MmsError> {
+    let elements = elements(data, "read")?;
+    let specification_with_result = elements
+        .iter()
+        .find(|tlv| tlv.is_context(0))
+        .is_some_and(|tlv| tlv.value.first().is_some_and(|&flag| flag != 0));
+    let access = elements
+        .iter()
+        .find(|tlv| tlv.is_context(1))
+        .ok_or(MmsError::Truncated {
+            field: "variable access specification",
+        })?;
+    let (choice, _) = extract_mms_tlv(access.value, "variable access specification")?;
+    Ok(MmsService::Read {
+        specification_with_result,
+        variables: MmsVariableAccess::from_tlv(&choice)?,
+    })
+}
+
+pub(super) fn parse_read_response(data: &[u8]) -> Result<MmsService<'_>, MmsError> {
+    let elements = elements(data, "read response")?;
+    let variables = match elements.iter().find(|tlv| tlv.is_context(0)) {
+        Some(access) => Some(MmsVariableAccess::from_tlv(
+            &extract_mms_tlv(access.value, "variable access specification")?.0,
+        )?),
+        None => None,
+    };
+    let results = elements
+        .iter()
+        .find(|tlv| tlv.is_context(1))
+        .ok_or(MmsError::Truncated {
+            field: "list of access results",
+        })?;
+    Ok(MmsService::ReadResponse {
+        variables,
+        results: MmsAccessResult::parse_all(results.value)?,
+    })
+}
+
+pub(super) fn parse_write(data: &[u8]) -> Result<MmsService<'_>, MmsError> {
+    // La specification (choix non etiquete) precede listOfData [0].
+    let (access, rest) = extract_mms_tlv(data, "variable access specification")?;
+    let (list, _) = extract_mms_tlv(rest, "list of data")?;
+    if !list.is_context(0) {
+        return Err(MmsError::UnexpectedTag {
+            field: "list of data",
+            tag: list.number,
+        });
+    }
+    Ok(MmsService::Write {
+        variables: MmsVariableAccess::from_tlv(&access)?,
+        data: MmsData::parse_all(list.value)?,
+    })
+}
+
+pub(super) fn parse_write_response(data: &[u8]) -> Result<MmsService<'_>, MmsError> {
+    let results = elements(data, "write response")?
+        .iter()
+        .map(|tlv| match tlv.number {
+            0 if tlv.is_context(0) => {
+                extract_mms_unsigned(tlv.value, "data access error").map(Some)
+            }
+            1 if tlv.is_context(1) => Ok(None),
+            tag => Err(MmsError::UnexpectedTag {
+                field: "write result",
+                tag,
+            }),
+        })
+        .collect::<Result<_, _>>()?;
+    Ok(MmsService::WriteResponse { results })
+}
+
+pub(super) fn parse_information_report(data: &[u8]) -> Result<MmsService<'_>, MmsError> {
+    let (access, rest) = extract_mms_tlv(data, "variable access specification")?;
+    let (list, _) = extract_mms_tlv(rest, "list of access results")?;
+    if !list.is_context(0) {
+        return Err(MmsError::UnexpectedTag {
+            field: "list of access results",
+            tag: list.number,
+        });
+    }
+    Ok(MmsService::InformationReport {
+        variables: MmsVariableAccess::from_tlv(&access)?,
+        results: MmsAccessResult::parse_all(list.value)?,
+    })
+}
+
+/// ServiceError : la classe est un choix [0] dont l'etiquette donne la
+/// categorie et le contenu le code.
+pub(super) fn parse_service_error(data: &[u8]) -> Result<MmsService<'_>, MmsError> {
+    let class = elements(data, "service error")?
+        .into_iter()
+        .find(|tlv| tlv.is_context(0))
+        .ok_or(MmsError::Truncated {
+            field: "error class",
+        })?;
+    let (code, _) = extract_mms_tlv(class.value, "error class")?;
+    Ok(MmsService::Error {
+        error_class: code.number,
+        error_code: extract_mms_unsigned(code.value, "error code")?,
+    })
+}
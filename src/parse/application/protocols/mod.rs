@@ -24,25 +24,40 @@ use crate::parse::application::protocols::{
 };
 
 pub mod ams;
+pub mod bacnet;
+pub mod ber;
+pub mod bgp;
 pub mod bitcoin;
 pub mod copt;
 pub mod dhcp;
 pub mod dhcpv6;
+pub mod dnp3;
 pub mod dns;
 pub mod ethernet_ip;
+pub mod fingerprint;
 pub mod ftp;
 pub mod giop;
 pub mod http;
+pub mod http2;
+pub mod http3;
+pub mod iec104;
+pub mod kerberos;
+pub mod knxnet_ip;
+pub mod ldap;
+pub mod mms;
 pub mod modbus_tcp;
 pub mod mqtt;
 pub mod netbios;
 pub mod nntp;
+pub mod ntlmssp;
 pub mod ntp;
 pub mod opcua;
 pub mod openvpn;
 pub mod postgresql;
 pub mod quic;
+pub mod rip;
 pub mod s7comm;
+pub mod smb;
 pub mod smtp;
 pub mod snmp;
 pub mod srvloc;
@@ -0,0 +1,663 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Typed Modbus requests and responses.
+//!
+//! A Modbus PDU does not say whether it is a request or a response, and
+//! several function codes use both shapes with the same length (a Read
+//! Holding Registers request and a one-register response are both four data
+//! bytes). The caller knows the direction from the TCP port:
+//! [`Modbus::request`] decodes what the client sent to port 502,
+//! [`Modbus::response`] what the server returned.
+
+use super::Modbus;
+use crate::{
+    checks::application::modbus_tcp::{
+        MODBUS_EXCEPTION_FLAG, MODBUS_FC_ENCAPSULATED_INTERFACE, MODBUS_FC_MASK_WRITE_REGISTER,
+        MODBUS_FC_READ_COILS, MODBUS_FC_READ_DISCRETE_INPUTS, MODBUS_FC_READ_HOLDING_REGISTERS,
+        MODBUS_FC_READ_INPUT_REGISTERS, MODBUS_FC_READ_WRITE_MULTIPLE_REGISTERS,
+        MODBUS_FC_WRITE_MULTIPLE_COILS, MODBUS_FC_WRITE_MULTIPLE_REGISTERS,
+        MODBUS_FC_WRITE_SINGLE_COIL, MODBUS_FC_WRITE_SINGLE_REGISTER,
+        MODBUS_MEI_READ_DEVICE_IDENTIFICATION, MODBUS_MORE_FOLLOWS, extract_modbus_bytes,
+        extract_modbus_coil_value, extract_modbus_counted_bytes, extract_modbus_u8,
+        extract_modbus_u16, modbus_bit_byte_count, modbus_device_object_name,
+        validate_modbus_byte_count, validate_modbus_no_trailing_data,
+        validate_modbus_register_byte_count,
+    },
+    errors::application::modbus_tcp::ModbusDecodeError,
+};
+
+/// First address and number of coils or registers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModbusRange {
+    pub address: u16,
+    pub quantity: u16,
+}
+
+/// Packed coil or discrete input states, least significant bit first.
+///
+/// A request knows how many bits it carries; a read response only has its
+/// byte count, so `len` is a multiple of 8 there and the padding bits read
+/// as `false`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModbusBits<'a> {
+    pub packed: &'a [u8],
+    pub len: usize,
+}
+
+impl<'a> ModbusBits<'a> {
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index >= self.len {
+            return None;
+        }
+        Some(self.packed.get(index / 8)? & (1 << (index % 8)) != 0)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = bool> + use<'a> {
+        let packed = self.packed;
+        (0..self.len.min(packed.len() * 8))
+            .map(move |index| packed[index / 8] & (1 << (index % 8)) != 0)
+    }
+}
+
+/// Big-endian 16-bit register values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModbusRegisters<'a>(pub &'a [u8]);
+
+impl<'a> ModbusRegisters<'a> {
+    pub fn len(&self) -> usize {
+        self.0.len() / 2
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.len() < 2
+    }
+
+    pub fn get(&self, index: usize) -> Option<u16> {
+        let bytes = self.0.get(index * 2..index * 2 + 2)?;
+        Some(u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u16> + use<'a> {
+        self.0
+            .chunks_exact(2)
+            .map(|bytes| u16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+}
+
+/// Mask Write Register (0x16): `(current AND and_mask) OR (or_mask AND NOT
+/// and_mask)`, echoed unchanged by the response.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ModbusMaskWrite {
+    pub address: u16,
+    pub and_mask: u16,
+    pub or_mask: u16,
+}
+
+/// Decoded client request.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModbusRequest<'a> {
+    ReadCoils(ModbusRange),
+    ReadDiscreteInputs(ModbusRange),
+    ReadHoldingRegisters(ModbusRange),
+    ReadInputRegisters(ModbusRange),
+    WriteSingleCoil {
+        address: u16,
+        value: bool,
+    },
+    WriteSingleRegister {
+        address: u16,
+        value: u16,
+    },
+    WriteMultipleCoils {
+        address: u16,
+        values: ModbusBits<'a>,
+    },
+    WriteMultipleRegisters {
+        address: u16,
+        values: ModbusRegisters<'a>,
+    },
+    MaskWriteRegister(ModbusMaskWrite),
+    /// Function 0x17: the write is performed before the read.
+    ReadWriteMultipleRegisters {
+        read: ModbusRange,
+        write_address: u16,
+        values: ModbusRegisters<'a>,
+    },
+    /// Function 43 / MEI 0x0E.
+    ReadDeviceIdentification {
+        read_device_id_code: u8,
+        object_id: u8,
+    },
+    /// Function 43 with another MEI type (CANopen general reference...).
+    EncapsulatedInterface {
+        mei_type: u8,
+        data: &'a [u8],
+    },
+    /// Function without a typed decoding.
+    Other {
+        function_code: u8,
+        data: &'a [u8],
+    },
+}
+
+/// Decoded server response.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModbusResponse<'a> {
+    ReadCoils(ModbusBits<'a>),
+    ReadDiscreteInputs(ModbusBits<'a>),
+    ReadHoldingRegisters(ModbusRegisters<'a>),
+    ReadInputRegisters(ModbusRegisters<'a>),
+    WriteSingleCoil {
+        address: u16,
+        value: bool,
+    },
+    WriteSingleRegister {
+        address: u16,
+        value: u16,
+    },
+    WriteMultipleCoils(ModbusRange),
+    WriteMultipleRegisters(ModbusRange),
+    MaskWriteRegister(ModbusMaskWrite),
+    ReadWriteMultipleRegisters(ModbusRegisters<'a>),
+    ReadDeviceIdentification(ModbusDeviceIdentification<'a>),
+    EncapsulatedInterface {
+        mei_type: u8,
+        data: &'a [u8],
+    },
+    /// Exception response: `function_code` is the request's, without the
+    /// 0x80 flag (see
+    /// [`modbus_exception_code_name`](crate::checks::application::modbus_tcp::modbus_exception_code_name)).
+    Exception {
+        function_code: u8,
+        exception_code: u8,
+    },
+    Other {
+        function_code: u8,
+        data: &'a [u8],
+    },
+}
+
+/// Read Device Identification response (function 43 / MEI 0x0E).
+///
+/// ```mermaid
+/// ---
+/// title: ModbusDeviceIdentification
+/// ---
+/// packet-beta
+/// 0-7: "MEI Type 0x0E"
+/// 8-15: "Read Device ID Code u8"
+/// 16-23: "Conformity Level u8"
+/// 24-31: "More Follows u8"
+/// 32-39: "Next Object Id u8"
+/// 40-47: "Number of Objects u8"
+/// 48-55: "Object Id u8"
+/// 56-63: "Object Length u8"
+/// 64-95: "Object Value (variable)"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModbusDeviceIdentification<'a> {
+    /// 1 basic, 2 regular, 3 extended, 4 one specific object.
+    pub read_device_id_code: u8,
+    pub conformity_level: u8,
+    /// The objects did not fit: ask again from `next_object_id`.
+    pub more_follows: bool,
+    pub next_object_id: u8,
+    pub objects: Vec<ModbusDeviceObject<'a>>,
+}
+
+impl<'a> ModbusDeviceIdentification<'a> {
+    pub fn object(&self, object_id: u8) -> Option<&'a [u8]> {
+        self.objects
+            .iter()
+            .find(|object| object.id == object_id)
+            .map(|object| object.value)
+    }
+
+    pub fn vendor_name(&self) -> Option<&'a str> {
+        self.text(0x00)
+    }
+
+    pub fn product_code(&self) -> Option<&'a str> {
+        self.text(0x01)
+    }
+
+    pub fn revision(&self) -> Option<&'a str> {
+        self.text(0x02)
+    }
+
+    fn text(&self, object_id: u8) -> Option<&'a str> {
+        std::str::from_utf8(self.object(object_id)?).ok()
+    }
+}
+
+/// One identification object; the basic and regular ones are ASCII strings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ModbusDeviceObject<'a> {
+    pub id: u8,
+    pub value: &'a [u8],
+}
+
+impl<'a> ModbusDeviceObject<'a> {
+    pub fn name(&self) -> &'static str {
+        modbus_device_object_name(self.id)
+    }
+
+    pub fn as_str(&self) -> Option<&'a str> {
+        std::str::from_utf8(self.value).ok()
+    }
+}
+
+impl<'a> Modbus<'a> {
+    /// The function code carries the exception flag.
+    pub fn is_exception(&self) -> bool {
+        self.function_code & MODBUS_EXCEPTION_FLAG != 0
+    }
+
+    /// Decodes the PDU as a client request.
+    ///
+    /// # Example
+    /// ```
+    /// use packet_parser::parse::application::protocols::modbus_tcp::{
+    ///     MBAP,
+    ///     message::{ModbusRange, ModbusRequest},
+    /// };
+    ///
+    /// // Read Holding Registers 1, frame 1 of
+    /// // pcaps_exemple/protocols/modbus/Modbus.pcap.
+    /// let raw = hex::decode("00d100000006010300010001").unwrap();
+    /// let adu = MBAP::try_from(&raw[..]).unwrap();
+    /// assert_eq!(
+    ///     adu.pdu.request().unwrap(),
+    ///     ModbusRequest::ReadHoldingRegisters(ModbusRange { address: 1, quantity: 1 })
+    /// );
+    /// ```
+    pub fn request(&self) -> Result<ModbusRequest<'a>, ModbusDecodeError> {
+        ModbusRequest::parse(self.function_code, self.pdu_data)
+    }
+
+    /// Decodes the PDU as a server response.
+    pub fn response(&self) -> Result<ModbusResponse<'a>, ModbusDecodeError> {
+        ModbusResponse::parse(self.function_code, self.pdu_data)
+    }
+}
+
+impl<'a> ModbusRequest<'a> {
+    /// Decodes the data that follows `function_code` in a request PDU.
+    pub fn parse(function_code: u8, data: &'a [u8]) -> Result<Self, ModbusDecodeError> {
+        let request = match function_code {
+            MODBUS_FC_READ_COILS => Self::ReadCoils(parse_range(data)?),
+            MODBUS_FC_READ_DISCRETE_INPUTS => Self::ReadDiscreteInputs(parse_range(data)?),
+            MODBUS_FC_READ_HOLDING_REGISTERS => Self::ReadHoldingRegisters(parse_range(data)?),
+            MODBUS_FC_READ_INPUT_REGISTERS => Self::ReadInputRegisters(parse_range(data)?),
+            MODBUS_FC_WRITE_SINGLE_COIL => {
+                let (address, value) = parse_single_coil(data)?;
+                Self::WriteSingleCoil { address, value }
+            }
+            MODBUS_FC_WRITE_SINGLE_REGISTER => {
+                let (address, value) = parse_single_register(data)?;
+                Self::WriteSingleRegister { address, value }
+            }
+            MODBUS_FC_WRITE_MULTIPLE_COILS => {
+                let (address, rest) = extract_modbus_u16(data, "starting address")?;
+                let (quantity, rest) = extract_modbus_u16(rest, "quantity of outputs")?;
+                let (byte_count, packed, rest) =
+                    extract_modbus_counted_bytes(rest, "output values")?;
+                validate_modbus_byte_count(byte_count, modbus_bit_byte_count(quantity))?;
+                validate_modbus_no_trailing_data(rest, "output values")?;
+                Self::WriteMultipleCoils {
+                    address,
+                    values: ModbusBits {
+                        packed,
+                        len: usize::from(quantity),
+                    },
+                }
+            }
+            MODBUS_FC_WRITE_MULTIPLE_REGISTERS => {
+                let (address, rest) = extract_modbus_u16(data, "starting address")?;
+                let (quantity, rest) = extract_modbus_u16(rest, "quantity of registers")?;
+                let values = parse_register_values(rest, quantity)?;
+                Self::WriteMultipleRegisters { address, values }
+            }
+            MODBUS_FC_MASK_WRITE_REGISTER => Self::MaskWriteRegister(parse_mask_write(data)?),
+            MODBUS_FC_READ_WRITE_MULTIPLE_REGISTERS => {
+                let (address, rest) = extract_modbus_u16(data, "read starting address")?;
+                let (quantity, rest) = extract_modbus_u16(rest, "quantity to read")?;
+                let (write_address, rest) = extract_modbus_u16(rest, "write starting address")?;
+                let (write_quantity, rest) = extract_modbus_u16(rest, "quantity to write")?;
+                let values = parse_register_values(rest, write_quantity)?;
+                Self::ReadWriteMultipleRegisters {
+                    read: ModbusRange { address, quantity },
+                    write_address,
+                    values,
+                }
+            }
+            MODBUS_FC_ENCAPSULATED_INTERFACE => {
+                let (mei_type, rest) = extract_modbus_u8(data, "MEI type")?;
+                if mei_type == MODBUS_MEI_READ_DEVICE_IDENTIFICATION {
+                    let (read_device_id_code, rest) =
+                        extract_modbus_u8(rest, "read device ID code")?;
+                    let (object_id, rest) = extract_modbus_u8(rest, "object id")?;
+                    validate_modbus_no_trailing_data(rest, "device identification request")?;
+                    Self::ReadDeviceIdentification {
+                        read_device_id_code,
+                        object_id,
+                    }
+                } else {
+                    Self::EncapsulatedInterface {
+                        mei_type,
+                        data: rest,
+                    }
+                }
+            }
+            _ => Self::Other {
+                function_code,
+                data,
+            },
+        };
+        Ok(request)
+    }
+}
+
+impl<'a> ModbusResponse<'a> {
+    /// Decodes the data that follows `function_code` in a response PDU.
+    pub fn parse(function_code: u8, data: &'a [u8]) -> Result<Self, ModbusDecodeError> {
+        if function_code & MODBUS_EXCEPTION_FLAG != 0 {
+            let (exception_code, rest) = extract_modbus_u8(data, "exception code")?;
+            validate_modbus_no_trailing_data(rest, "exception code")?;
+            return Ok(Self::Exception {
+                function_code: function_code & !MODBUS_EXCEPTION_FLAG,
+                exception_code,
+            });
+        }
+
+        let response = match function_code {
+            MODBUS_FC_READ_COILS => Self::ReadCoils(parse_read_bits(data)?),
+            MODBUS_FC_READ_DISCRETE_INPUTS => Self::ReadDiscreteInputs(parse_read_bits(data)?),
+            MODBUS_FC_READ_HOLDING_REGISTERS => {
+                Self::ReadHoldingRegisters(parse_read_registers(data)?)
+            }
+            MODBUS_FC_READ_INPUT_REGISTERS => Self::ReadInputRegisters(parse_read_registers(data)?),
+            MODBUS_FC_WRITE_SINGLE_COIL => {
+                let (address, value) = parse_single_coil(data)?;
+                Self::WriteSingleCoil { address, value }
+            }
+            MODBUS_FC_WRITE_SINGLE_REGISTER => {
+                let (address, value) = parse_single_register(data)?;
+                Self::WriteSingleRegister { address, value }
+            }
+            MODBUS_FC_WRITE_MULTIPLE_COILS => Self::WriteMultipleCoils(parse_range(data)?),
+            MODBUS_FC_WRITE_MULTIPLE_REGISTERS => Self::WriteMultipleRegisters(parse_range(data)?),
+            MODBUS_FC_MASK_WRITE_REGISTER => Self::MaskWriteRegister(parse_mask_write(data)?),
+            MODBUS_FC_READ_WRITE_MULTIPLE_REGISTERS => {
+                Self::ReadWriteMultipleRegisters(parse_read_registers(data)?)
+            }
+            MODBUS_FC_ENCAPSULATED_INTERFACE => {
+                let (mei_type, rest) = extract_modbus_u8(data, "MEI type")?;
+                if mei_type == MODBUS_MEI_READ_DEVICE_IDENTIFICATION {
+                    Self::ReadDeviceIdentification(parse_device_identification(rest)?)
+                } else {
+                    Self::EncapsulatedInterface {
+                        mei_type,
+                        data: rest,
+                    }
+                }
+            }
+            _ => Self::Other {
+                function_code,
+                data,
+            },
+        };
+        Ok(response)
+    }
+}
+
+// Adresse + quantité : requêtes de lecture, réponses d'écriture multiple.
+fn parse_range(data: &[u8]) -> Result<ModbusRange, ModbusDecodeError> {
+    let (address, rest) = extract_modbus_u16(data, "starting address")?;
+    let (quantity, rest) = extract_modbus_u16(rest, "quantity")?;
+    validate_modbus_no_trailing_data(rest, "quantity")?;
+    Ok(ModbusRange { address, quantity })
+}
+
+fn parse_single_coil(data: &[u8]) -> Result<(u16, bool), ModbusDecodeError> {
+    let (address, rest) = extract_modbus_u16(data, "output address")?;
+    let (value, rest) = extract_modbus_coil_value(rest)?;
+    validate_modbus_no_trailing_data(rest, "coil value")?;
+    Ok((address, value))
+}
+
+fn parse_single_register(data: &[u8]) -> Result<(u16, u16), ModbusDecodeError> {
+    let (address, rest) = extract_modbus_u16(data, "register address")?;
+    let (value, rest) = extract_modbus_u16(rest, "register value")?;
+    validate_modbus_no_trailing_data(rest, "register value")?;
+    Ok((address, value))
+}
+
+fn parse_mask_write(data: &[u8]) -> Result<ModbusMaskWrite, ModbusDecodeError> {
+    let (address, rest) = extract_modbus_u16(data, "reference address")?;
+    let (and_mask, rest) = extract_modbus_u16(rest, "AND mask")?;
+    let (or_mask, rest) = extract_modbus_u16(rest, "OR mask")?;
+    validate_modbus_no_trailing_data(rest, "OR mask")?;
+    Ok(ModbusMaskWrite {
+        address,
+        and_mask,
+        or_mask,
+    })
+}
+
+fn parse_register_values(
+    data: &[u8],
+    quantity: u16,
+) -> Result<ModbusRegisters<'_>, ModbusDecodeError> {
+    let (byte_count, values, rest) = extract_modbus_counted_bytes(data, "register values")?;
+    validate_modbus_byte_count(byte_count, usize::from(quantity) * 2)?;
+    validate_modbus_no_trailing_data(rest, "register values")?;
+    Ok(ModbusRegisters(values))
+}
+
+fn parse_read_bits(data: &[u8]) -> Result<ModbusBits<'_>, ModbusDecodeError> {
+    let (_, packed, rest) = extract_modbus_counted_bytes(data, "coil status")?;
+    validate_modbus_no_trailing_data(rest, "coil status")?;
+    Ok(ModbusBits {
+        packed,
+        len: packed.len() * 8,
+    })
+}
+
+fn parse_read_registers(data: &[u8]) -> Result<ModbusRegisters<'_>, ModbusDecodeError> {
+    let (byte_count, values, rest) = extract_modbus_counted_bytes(data, "register values")?;
+    validate_modbus_register_byte_count(byte_count)?;
+    validate_modbus_no_trailing_data(rest, "register values")?;
+    Ok(ModbusRegisters(values))
+}
+
+fn parse_device_identification(
+    data: &[u8],
+) -> Result<ModbusDeviceIdentification<'_>, ModbusDecodeError> {
+    let (read_device_id_code, rest) = extract_modbus_u8(data, "read device ID code")?;
+    let (conformity_level, rest) = extract_modbus_u8(rest, "conformity level")?;
+    let (more_follows, rest) = extract_modbus_u8(rest, "more follows")?;
+    let more_follows = match more_follows {
+        0x00 => false,
+        MODBUS_MORE_FOLLOWS => true,
+        other => return Err(ModbusDecodeError::InvalidMoreFollows(other)),
+    };
+    let (next_object_id, rest) = extract_modbus_u8(rest, "next object id")?;
+    let (count, mut rest) = extract_modbus_u8(rest, "number of objects")?;
+
+    let mut objects = Vec::with_capacity(usize::from(count));
+    for _ in 0..count {
+        let (id, tail) = extract_modbus_u8(rest, "object id")?;
+        let (length, tail) = extract_modbus_u8(tail, "object length")?;
+        let (value, tail) = extract_modbus_bytes(tail, usize::from(length), "object value")?;
+        objects.push(ModbusDeviceObject { id, value });
+        rest = tail;
+    }
+    validate_modbus_no_trailing_data(rest, "device identification objects")?;
+
+    Ok(ModbusDeviceIdentification {
+        read_device_id_code,
+        conformity_level,
+        more_follows,
+        next_object_id,
+        objects,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pdu(hex_str: &str) -> (u8, Vec<u8>) {
+        let bytes = hex::decode(hex_str).expect("valid hex fixture");
+        (bytes[0], bytes[1..].to_vec())
+    }
+
+    #[test]
+    fn test_write_requests() {
+        // Synthetique : Write Multiple Coils, 10 bobines à partir de 19
+        // (exemple du §6.11 de la spécification).
+        let (function_code, data) = pdu("0f0013000a02cd01");
+        let ModbusRequest::WriteMultipleCoils { address, values } =
+            ModbusRequest::parse(function_code, &data).unwrap()
+        else {
+            panic!("Write Multiple Coils expected");
+        };
+        assert_eq!(address, 19);
+        let bits: Vec<_> = values.iter().map(u8::from).collect();
+        assert_eq!(bits, [1, 0, 1, 1, 0, 0, 1, 1, 1, 0]);
+        assert_eq!(values.get(10), None);
+
+        // Synthetique : Write Multiple Registers 1-2 = 0x000A, 0x0102
+        // (§6.12).
+        let (function_code, data) = pdu("100001000204000a0102");
+        let ModbusRequest::WriteMultipleRegisters { address, values } =
+            ModbusRequest::parse(function_code, &data).unwrap()
+        else {
+            panic!("Write Multiple Registers expected");
+        };
+        assert_eq!(address, 1);
+        assert_eq!(values.iter().collect::<Vec<_>>(), [0x000a, 0x0102]);
+
+        // Byte count qui ne correspond pas à la quantité.
+        let (function_code, data) = pdu("100001000202000a");
+        assert_eq!(
+            ModbusRequest::parse(function_code, &data).unwrap_err(),
+            ModbusDecodeError::ByteCountMismatch {
+                declared: 2,
+                expected: 4
+            }
+        );
+
+        // Synthetique : Mask Write Register et Read/Write Multiple
+        // Registers (§6.16, §6.17).
+        let (function_code, data) = pdu("16000400f20025");
+        assert_eq!(
+            ModbusRequest::parse(function_code, &data).unwrap(),
+            ModbusRequest::MaskWriteRegister(ModbusMaskWrite {
+                address: 4,
+                and_mask: 0x00f2,
+                or_mask: 0x0025,
+            })
+        );
+        let (function_code, data) = pdu("1700030006000e00030600ff00ff00ff");
+        let ModbusRequest::ReadWriteMultipleRegisters {
+            read,
+            write_address,
+            values,
+        } = ModbusRequest::parse(function_code, &data).unwrap()
+        else {
+            panic!("Read/Write Multiple Registers expected");
+        };
+        assert_eq!(
+            read,
+            ModbusRange {
+                address: 3,
+                quantity: 6
+            }
+        );
+        assert_eq!(write_address, 14);
+        assert_eq!(values.len(), 3);
+    }
+
+    #[test]
+    fn test_single_writes_and_exceptions() {
+        let (function_code, data) = pdu("0500acff00");
+        assert_eq!(
+            ModbusRequest::parse(function_code, &data).unwrap(),
+            ModbusRequest::WriteSingleCoil {
+                address: 172,
+                value: true
+            }
+        );
+        let (function_code, data) = pdu("0500ac1234");
+        assert_eq!(
+            ModbusRequest::parse(function_code, &data).unwrap_err(),
+            ModbusDecodeError::InvalidCoilValue(0x1234)
+        );
+
+        // Trame 324 de MODBUS-TestDataPart2.pcap : Read Coils à l'adresse
+        // 0xFFFF refusée.
+        let (function_code, data) = pdu("8102");
+        assert_eq!(
+            ModbusResponse::parse(function_code, &data).unwrap(),
+            ModbusResponse::Exception {
+                function_code: 0x01,
+                exception_code: 0x02
+            }
+        );
+        // Trame 320 : huit bobines lues, octet 0x15.
+        let (function_code, data) = pdu("010115");
+        let ModbusResponse::ReadCoils(bits) = ModbusResponse::parse(function_code, &data).unwrap()
+        else {
+            panic!("Read Coils expected");
+        };
+        assert_eq!(bits.len, 8);
+        assert_eq!(bits.get(0), Some(true));
+        assert_eq!(bits.get(1), Some(false));
+        assert_eq!(bits.get(2), Some(true));
+    }
+
+    #[test]
+    fn test_device_identification() {
+        // Synthetique : identification basique (§6.21), trois objets et
+        // suite annoncée à partir de l'objet 3.
+        let (function_code, data) = pdu(concat!(
+            "2b0e0101ff0303",
+            "0007436f6d70616e79",
+            "010750726f64756374",
+            "020556322e3031"
+        ));
+        let ModbusResponse::ReadDeviceIdentification(identification) =
+            ModbusResponse::parse(function_code, &data).unwrap()
+        else {
+            panic!("Read Device Identification expected");
+        };
+        assert!(identification.more_follows);
+        assert_eq!(identification.next_object_id, 3);
+        assert_eq!(identification.vendor_name(), Some("Company"));
+        assert_eq!(identification.product_code(), Some("Product"));
+        assert_eq!(identification.revision(), Some("V2.01"));
+        assert_eq!(identification.objects[1].name(), "ProductCode");
+
+        let (function_code, data) = pdu("2b0e0100");
+        assert_eq!(
+            ModbusRequest::parse(function_code, &data).unwrap(),
+            ModbusRequest::ReadDeviceIdentification {
+                read_device_id_code: 1,
+                object_id: 0
+            }
+        );
+        // More Follows ni 0x00 ni 0xFF.
+        let (function_code, data) = pdu("2b0e01010100");
+        assert_eq!(
+            ModbusResponse::parse(function_code, &data).unwrap_err(),
+            ModbusDecodeError::InvalidMoreFollows(0x01)
+        );
+    }
+}
@@ -14,6 +14,9 @@ use crate::{
     errors::application::modbus_tcp::ModbusTcpError,
 };
 
+pub mod message;
+pub mod transaction;
+
 /// Modbus/TCP Protocol Packet
 ///
 /// ```mermaid
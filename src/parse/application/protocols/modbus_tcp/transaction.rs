@@ -0,0 +1,392 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Request/response correlation for one Modbus/TCP connection.
+//!
+//! The server copies the MBAP transaction identifier of each request into
+//! its response, which may arrive out of order when the server handles
+//! several transactions at once. [`ModbusTransactionTracker`] pairs them
+//! through that identifier and reduces each pair to the table, addresses
+//! and values it read or wrote.
+
+use std::collections::VecDeque;
+
+use crate::{
+    checks::application::modbus_tcp::{MODBUS_EXCEPTION_FLAG, MODBUS_MAX_PENDING_TRANSACTIONS},
+    errors::application::modbus_tcp::ModbusDecodeError,
+    parse::application::protocols::modbus_tcp::{
+        MBAP, ModbusTcpPacket,
+        message::{ModbusBits, ModbusRange, ModbusRegisters, ModbusRequest, ModbusResponse},
+    },
+};
+
+/// Modbus data model table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ModbusTable {
+    Coils,
+    DiscreteInputs,
+    HoldingRegisters,
+    InputRegisters,
+}
+
+/// Values read from or written to a table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ModbusValues {
+    Bits(Vec<bool>),
+    Registers(Vec<u16>),
+    /// Mask Write Register: the result depends on the current value, which
+    /// does not travel on the wire.
+    Mask {
+        and_mask: u16,
+        or_mask: u16,
+    },
+}
+
+/// One read or write of a contiguous range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModbusAccess {
+    pub table: ModbusTable,
+    pub address: u16,
+    pub quantity: u16,
+    /// Written values come from the request, read values from the response;
+    /// `None` for a read answered by an exception.
+    pub values: Option<ModbusValues>,
+}
+
+/// A request paired with its response.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModbusTransaction {
+    pub transaction_identifier: u16,
+    pub unit_identifier: u8,
+    pub function_code: u8,
+    /// Range read, for functions 1-4 and 23.
+    pub read: Option<ModbusAccess>,
+    /// Range written, for functions 5, 6, 15, 16, 22 and 23. Kept when the
+    /// server answers with an exception: the attempt is what matters.
+    pub write: Option<ModbusAccess>,
+    pub exception_code: Option<u8>,
+}
+
+impl ModbusTransaction {
+    pub fn is_exception(&self) -> bool {
+        self.exception_code.is_some()
+    }
+
+    /// Write accepted by the server.
+    pub fn is_successful_write(&self) -> bool {
+        self.write.is_some() && !self.is_exception()
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PendingRequest {
+    transaction_identifier: u16,
+    unit_identifier: u8,
+    function_code: u8,
+    read: Option<(ModbusTable, ModbusRange)>,
+    write: Option<ModbusAccess>,
+}
+
+/// Pairs the requests and responses of one Modbus/TCP connection.
+///
+/// Push the client-to-server packets with
+/// [`push_request`](Self::push_request) and the server-to-client ones with
+/// [`push_response`](Self::push_response), then drain the pairs with
+/// [`next_transaction`](Self::next_transaction). A response whose
+/// transaction identifier or function code matches no pending request is
+/// ignored; a request reusing a pending identifier replaces it, and beyond
+/// [`MODBUS_MAX_PENDING_TRANSACTIONS`] unanswered requests the oldest is
+/// dropped.
+///
+/// # Example
+/// ```
+/// use packet_parser::parse::application::protocols::modbus_tcp::{
+///     ModbusTcpPacket,
+///     transaction::{ModbusTable, ModbusTransactionTracker, ModbusValues},
+/// };
+///
+/// // Read Holding Registers 0 and its response, frames 3 and 4 of
+/// // pcaps_exemple/protocols/modbus/Modbus.pcap.
+/// let request = hex::decode("00d200000006010300000001").unwrap();
+/// let response = hex::decode("00d20000000501030241c8").unwrap();
+///
+/// let mut tracker = ModbusTransactionTracker::new();
+/// tracker.push_request(&ModbusTcpPacket::try_from(&request[..]).unwrap()).unwrap();
+/// tracker.push_response(&ModbusTcpPacket::try_from(&response[..]).unwrap()).unwrap();
+///
+/// let transaction = tracker.next_transaction().expect("paired read");
+/// let read = transaction.read.unwrap();
+/// assert_eq!((read.table, read.address), (ModbusTable::HoldingRegisters, 0));
+/// assert_eq!(read.values, Some(ModbusValues::Registers(vec![0x41c8])));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct ModbusTransactionTracker {
+    pending: VecDeque<PendingRequest>,
+    completed: VecDeque<ModbusTransaction>,
+}
+
+impl ModbusTransactionTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records every request ADU of a client-to-server packet.
+    pub fn push_request(&mut self, packet: &ModbusTcpPacket<'_>) -> Result<(), ModbusDecodeError> {
+        packet
+            .mbaps
+            .iter()
+            .try_for_each(|adu| self.push_request_adu(adu))
+    }
+
+    /// Pairs every response ADU of a server-to-client packet.
+    pub fn push_response(&mut self, packet: &ModbusTcpPacket<'_>) -> Result<(), ModbusDecodeError> {
+        packet
+            .mbaps
+            .iter()
+            .try_for_each(|adu| self.push_response_adu(adu))
+    }
+
+    /// Next paired transaction, in response order.
+    pub fn next_transaction(&mut self) -> Option<ModbusTransaction> {
+        self.completed.pop_front()
+    }
+
+    /// Requests still waiting for their response.
+    pub fn pending_requests(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Records one request ADU.
+    pub fn push_request_adu(&mut self, adu: &MBAP<'_>) -> Result<(), ModbusDecodeError> {
+        let (read, write) = accesses(adu.pdu.request()?);
+
+        let transaction_identifier = adu.transaction_identifier;
+        self.pending
+            .retain(|request| request.transaction_identifier != transaction_identifier);
+        if self.pending.len() >= MODBUS_MAX_PENDING_TRANSACTIONS {
+            self.pending.pop_front();
+        }
+        self.pending.push_back(PendingRequest {
+            transaction_identifier,
+            unit_identifier: adu.unit_identifier,
+            function_code: adu.pdu.function_code,
+            read,
+            write,
+        });
+        Ok(())
+    }
+
+    /// Pairs one response ADU with its pending request.
+    pub fn push_response_adu(&mut self, adu: &MBAP<'_>) -> Result<(), ModbusDecodeError> {
+        let function_code = adu.pdu.function_code & !MODBUS_EXCEPTION_FLAG;
+        let Some(position) = self.pending.iter().position(|request| {
+            request.transaction_identifier == adu.transaction_identifier
+                && request.function_code == function_code
+        }) else {
+            return Ok(());
+        };
+
+        let response = adu.pdu.response()?;
+        let Some(request) = self.pending.remove(position) else {
+            return Ok(());
+        };
+        let exception_code = match response {
+            ModbusResponse::Exception { exception_code, .. } => Some(exception_code),
+            _ => None,
+        };
+        let read = request.read.map(|(table, range)| ModbusAccess {
+            table,
+            address: range.address,
+            quantity: range.quantity,
+            values: read_values(&response, range.quantity),
+        });
+
+        self.completed.push_back(ModbusTransaction {
+            transaction_identifier: request.transaction_identifier,
+            unit_identifier: request.unit_identifier,
+            function_code: request.function_code,
+            read,
+            write: request.write,
+            exception_code,
+        });
+        Ok(())
+    }
+}
+
+type Accesses = (Option<(ModbusTable, ModbusRange)>, Option<ModbusAccess>);
+
+// Plage lue (valeurs attendues dans la réponse) et écriture complète, dont
+// les valeurs sont déjà dans la requête.
+fn accesses(request: ModbusRequest<'_>) -> Accesses {
+    match request {
+        ModbusRequest::ReadCoils(range) => (Some((ModbusTable::Coils, range)), None),
+        ModbusRequest::ReadDiscreteInputs(range) => {
+            (Some((ModbusTable::DiscreteInputs, range)), None)
+        }
+        ModbusRequest::ReadHoldingRegisters(range) => {
+            (Some((ModbusTable::HoldingRegisters, range)), None)
+        }
+        ModbusRequest::ReadInputRegisters(range) => {
+            (Some((ModbusTable::InputRegisters, range)), None)
+        }
+        ModbusRequest::WriteSingleCoil { address, value } => (
+            None,
+            Some(ModbusAccess {
+                table: ModbusTable::Coils,
+                address,
+                quantity: 1,
+                values: Some(ModbusValues::Bits(vec![value])),
+            }),
+        ),
+        ModbusRequest::WriteSingleRegister { address, value } => (
+            None,
+            Some(ModbusAccess {
+                table: ModbusTable::HoldingRegisters,
+                address,
+                quantity: 1,
+                values: Some(ModbusValues::Registers(vec![value])),
+            }),
+        ),
+        ModbusRequest::WriteMultipleCoils { address, values } => {
+            (None, Some(bits_access(ModbusTable::Coils, address, values)))
+        }
+        ModbusRequest::WriteMultipleRegisters { address, values } => {
+            (None, Some(registers_access(address, values)))
+        }
+        ModbusRequest::MaskWriteRegister(mask) => (
+            None,
+            Some(ModbusAccess {
+                table: ModbusTable::HoldingRegisters,
+                address: mask.address,
+                quantity: 1,
+                values: Some(ModbusValues::Mask {
+                    and_mask: mask.and_mask,
+                    or_mask: mask.or_mask,
+                }),
+            }),
+        ),
+        ModbusRequest::ReadWriteMultipleRegisters {
+            read,
+            write_address,
+            values,
+        } => (
+            Some((ModbusTable::HoldingRegisters, read)),
+            Some(registers_access(write_address, values)),
+        ),
+        _ => (None, None),
+    }
+}
+
+fn bits_access(table: ModbusTable, address: u16, values: ModbusBits<'_>) -> ModbusAccess {
+    let values: Vec<bool> = values.iter().collect();
+    ModbusAccess {
+        table,
+        address,
+        quantity: u16::try_from(values.len()).unwrap_or(u16::MAX),
+        values: Some(ModbusValues::Bits(values)),
+    }
+}
+
+fn registers_access(address: u16, values: ModbusRegisters<'_>) -> ModbusAccess {
+    let values: Vec<u16> = values.iter().collect();
+    ModbusAccess {
+        table: ModbusTable::HoldingRegisters,
+        address,
+        quantity: u16::try_from(values.len()).unwrap_or(u16::MAX),
+        values: Some(ModbusValues::Registers(values)),
+    }
+}
+
+// Les bits de bourrage du dernier octet sont retirés grâce à la quantité
+// demandée.
+fn read_values(response: &ModbusResponse<'_>, quantity: u16) -> Option<ModbusValues> {
+    match response {
+        ModbusResponse::ReadCoils(bits) | ModbusResponse::ReadDiscreteInputs(bits) => Some(
+            ModbusValues::Bits(bits.iter().take(usize::from(quantity)).collect()),
+        ),
+        ModbusResponse::ReadHoldingRegisters(registers)
+        | ModbusResponse::ReadInputRegisters(registers)
+        | ModbusResponse::ReadWriteMultipleRegisters(registers) => {
+            Some(ModbusValues::Registers(registers.iter().collect()))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(bytes: &[u8]) -> ModbusTcpPacket<'_> {
+        ModbusTcpPacket::try_from(bytes).expect("valid Modbus/TCP fixture")
+    }
+
+    #[test]
+    fn test_out_of_order_responses() {
+        // Synthetique : deux requêtes en vol (Read Coils 8 bits, Write
+        // Single Register), réponses dans l'ordre inverse.
+        let requests = hex::decode(concat!(
+            "000100000006110100130008",
+            "000200000006110600070102"
+        ))
+        .unwrap();
+        let write_response = hex::decode("000200000006110600070102").unwrap();
+        let read_response = hex::decode("000100000004110101cd").unwrap();
+
+        let mut tracker = ModbusTransactionTracker::new();
+        tracker.push_request(&packet(&requests)).unwrap();
+        assert_eq!(tracker.pending_requests(), 2);
+        tracker.push_response(&packet(&write_response)).unwrap();
+        tracker.push_response(&packet(&read_response)).unwrap();
+
+        let write = tracker.next_transaction().unwrap();
+        assert_eq!(write.transaction_identifier, 2);
+        assert!(write.is_successful_write());
+        assert_eq!(
+            write.write,
+            Some(ModbusAccess {
+                table: ModbusTable::HoldingRegisters,
+                address: 7,
+                quantity: 1,
+                values: Some(ModbusValues::Registers(vec![0x0102])),
+            })
+        );
+
+        let read = tracker.next_transaction().unwrap();
+        assert_eq!(read.unit_identifier, 0x11);
+        let access = read.read.unwrap();
+        assert_eq!((access.table, access.address), (ModbusTable::Coils, 19));
+        assert_eq!(
+            access.values,
+            Some(ModbusValues::Bits(vec![
+                true, false, true, true, false, false, true, true
+            ]))
+        );
+        assert_eq!(tracker.pending_requests(), 0);
+    }
+
+    #[test]
+    fn test_rejected_write_and_unmatched_response() {
+        // Synthetique : Write Multiple Coils (3 bobines) refusé par une
+        // exception 02, précédé d'une réponse sans requête.
+        let request = hex::decode("000500000008010f001000030105").unwrap();
+        let exception = hex::decode("000500000003018f02").unwrap();
+        let stray = hex::decode("000900000003018302").unwrap();
+
+        let mut tracker = ModbusTransactionTracker::new();
+        tracker.push_request(&packet(&request)).unwrap();
+        tracker.push_response(&packet(&stray)).unwrap();
+        assert!(tracker.next_transaction().is_none());
+        tracker.push_response(&packet(&exception)).unwrap();
+
+        let transaction = tracker.next_transaction().unwrap();
+        assert_eq!(transaction.exception_code, Some(0x02));
+        assert!(!transaction.is_successful_write());
+        assert_eq!(
+            transaction.write.unwrap().values,
+            Some(ModbusValues::Bits(vec![true, false, true]))
+        );
+    }
+}
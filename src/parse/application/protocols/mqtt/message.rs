@@ -0,0 +1,749 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Typed MQTT 3.1, 3.1.1 and 5 control packets.
+//!
+//! Only CONNECT names its protocol level; every other packet is read with
+//! the version its CONNECT announced, both directions of the connection
+//! sharing it. [`MqttMessage::parse`] takes that version and decodes the
+//! variable header and payload that [`MqttPacket`] leaves as raw slices.
+
+use super::{MqttPacket, MqttPacketType, parse_one, properties::MqttProperties};
+use crate::{
+    checks::application::mqtt::{
+        MQTT_CONNECT_CLEAN_START, MQTT_CONNECT_PASSWORD, MQTT_CONNECT_USERNAME, MQTT_CONNECT_WILL,
+        MQTT_CONNECT_WILL_QOS_MASK, MQTT_CONNECT_WILL_RETAIN, MQTT_LEVEL_3_1, MQTT_LEVEL_3_1_1,
+        MQTT_LEVEL_5, extract_mqtt_binary, extract_mqtt_string, extract_mqtt_u8, extract_mqtt_u16,
+        validate_mqtt_no_trailing_data, validate_mqtt_subscription_options,
+        validate_mqtt_will_flags,
+    },
+    errors::application::mqtt::{MqttDecodeError, MqttError},
+    parse::application::protocols::mqtt::properties::split_mqtt_properties,
+};
+
+/// Protocol version of a connection, from the CONNECT protocol level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MqttVersion {
+    /// Level 3, protocol name `MQIsdp`.
+    V3_1,
+    /// Level 4.
+    V3_1_1,
+    /// Level 5: properties and reason codes on every packet.
+    V5,
+}
+
+impl MqttVersion {
+    pub fn from_level(level: u8) -> Option<Self> {
+        match level {
+            MQTT_LEVEL_3_1 => Some(Self::V3_1),
+            MQTT_LEVEL_3_1_1 => Some(Self::V3_1_1),
+            MQTT_LEVEL_5 => Some(Self::V5),
+            _ => None,
+        }
+    }
+
+    pub fn level(self) -> u8 {
+        match self {
+            Self::V3_1 => MQTT_LEVEL_3_1,
+            Self::V3_1_1 => MQTT_LEVEL_3_1_1,
+            Self::V5 => MQTT_LEVEL_5,
+        }
+    }
+}
+
+/// Decoded control packet.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MqttMessage<'a> {
+    Connect(MqttConnect<'a>),
+    Connack(MqttConnack<'a>),
+    Publish(MqttPublish<'a>),
+    Puback(MqttAck<'a>),
+    Pubrec(MqttAck<'a>),
+    Pubrel(MqttAck<'a>),
+    Pubcomp(MqttAck<'a>),
+    Subscribe(MqttSubscribe<'a>),
+    Suback(MqttSuback<'a>),
+    Unsubscribe(MqttUnsubscribe<'a>),
+    Unsuback(MqttUnsuback<'a>),
+    Pingreq,
+    Pingresp,
+    Disconnect(MqttDisconnect<'a>),
+}
+
+/// CONNECT (§3.1): session parameters, will and credentials.
+///
+/// ```mermaid
+/// ---
+/// title: MqttConnect
+/// ---
+/// packet-beta
+/// 0-15: "Protocol Name Length u16"
+/// 16-47: "Protocol Name variable"
+/// 48-55: "Protocol Level u8"
+/// 56-63: "Connect Flags u8"
+/// 64-79: "Keep Alive u16"
+/// 80-111: "Properties (v5) variable"
+/// 112-143: "Client Identifier variable"
+/// 144-175: "Will Properties, Topic, Payload variable"
+/// 176-207: "User Name, Password variable"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqttConnect<'a> {
+    pub protocol_name: &'a str,
+    pub version: MqttVersion,
+    /// Clean Start (MQTT 5) or Clean Session (3.1.1).
+    pub clean_start: bool,
+    /// Seconds, 0 disables the keep alive.
+    pub keep_alive: u16,
+    pub properties: MqttProperties<'a>,
+    /// May be empty: the server then assigns one.
+    pub client_id: &'a str,
+    pub will: Option<MqttWill<'a>>,
+    pub username: Option<&'a str>,
+    pub password: Option<&'a [u8]>,
+}
+
+/// Will message published by the server if the client vanishes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqttWill<'a> {
+    pub qos: u8,
+    pub retain: bool,
+    pub properties: MqttProperties<'a>,
+    pub topic: &'a str,
+    pub payload: &'a [u8],
+}
+
+/// CONNACK (§3.2). `reason_code` is a 3.1.1 return code (see
+/// [`mqtt_connect_return_code_name`](crate::checks::application::mqtt::mqtt_connect_return_code_name))
+/// or an MQTT 5 reason code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqttConnack<'a> {
+    pub session_present: bool,
+    pub reason_code: u8,
+    pub properties: MqttProperties<'a>,
+}
+
+/// PUBLISH (§3.3).
+///
+/// ```mermaid
+/// ---
+/// title: MqttPublish
+/// ---
+/// packet-beta
+/// 0-15: "Topic Length u16"
+/// 16-47: "Topic Name variable"
+/// 48-63: "Packet Identifier u16 (QoS > 0)"
+/// 64-95: "Properties (v5) variable"
+/// 96-127: "Application Payload variable"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqttPublish<'a> {
+    pub dup: bool,
+    pub qos: u8,
+    pub retain: bool,
+    /// Empty in MQTT 5 when a topic alias stands for it.
+    pub topic: &'a str,
+    /// Present for QoS 1 and 2.
+    pub packet_id: Option<u16>,
+    pub properties: MqttProperties<'a>,
+    pub payload: &'a [u8],
+}
+
+impl MqttPublish<'_> {
+    pub fn topic_alias(&self) -> Option<u16> {
+        self.properties.topic_alias()
+    }
+}
+
+/// PUBACK, PUBREC, PUBREL or PUBCOMP (§3.4 to §3.7). `reason_code` is
+/// 0x00 (Success) in 3.1.1 and when MQTT 5 omits it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqttAck<'a> {
+    pub packet_id: u16,
+    pub reason_code: u8,
+    pub properties: MqttProperties<'a>,
+}
+
+/// SUBSCRIBE (§3.8).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqttSubscribe<'a> {
+    pub packet_id: u16,
+    pub properties: MqttProperties<'a>,
+    pub subscriptions: Vec<MqttSubscription<'a>>,
+}
+
+/// Topic filter and subscription options (§3.8.3.1); everything but `qos`
+/// is MQTT 5 only and false or 0 in 3.1.1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MqttSubscription<'a> {
+    pub filter: &'a str,
+    pub qos: u8,
+    pub no_local: bool,
+    pub retain_as_published: bool,
+    /// 0: send retained messages at subscribe, 1: only for a new
+    /// subscription, 2: never.
+    pub retain_handling: u8,
+}
+
+/// SUBACK (§3.9): one reason code per requested filter, in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqttSuback<'a> {
+    pub packet_id: u16,
+    pub properties: MqttProperties<'a>,
+    pub reason_codes: &'a [u8],
+}
+
+/// UNSUBSCRIBE (§3.10).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqttUnsubscribe<'a> {
+    pub packet_id: u16,
+    pub properties: MqttProperties<'a>,
+    pub filters: Vec<&'a str>,
+}
+
+/// UNSUBACK (§3.11): reason codes are MQTT 5 only, empty in 3.1.1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqttUnsuback<'a> {
+    pub packet_id: u16,
+    pub properties: MqttProperties<'a>,
+    pub reason_codes: &'a [u8],
+}
+
+/// DISCONNECT (§3.14): empty in 3.1.1, reason code 0x00 then.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MqttDisconnect<'a> {
+    pub reason_code: u8,
+    pub properties: MqttProperties<'a>,
+}
+
+impl<'a> MqttMessage<'a> {
+    /// Decodes the first packet of `bytes` and returns it with its size on
+    /// the wire. CONNECT reads its own version and ignores `version`.
+    pub fn parse(bytes: &'a [u8], version: MqttVersion) -> Result<(Self, usize), MqttDecodeError> {
+        let (packet, len) = parse_one(bytes)?;
+        let flags = bytes[0] & 0x0F;
+        Ok((decode(&packet, flags, version)?, len))
+    }
+
+    pub fn packet_type(&self) -> MqttPacketType {
+        match self {
+            Self::Connect(_) => MqttPacketType::Connect,
+            Self::Connack(_) => MqttPacketType::Connack,
+            Self::Publish(_) => MqttPacketType::Publish,
+            Self::Puback(_) => MqttPacketType::Puback,
+            Self::Pubrec(_) => MqttPacketType::Pubrec,
+            Self::Pubrel(_) => MqttPacketType::Pubrel,
+            Self::Pubcomp(_) => MqttPacketType::Pubcomp,
+            Self::Subscribe(_) => MqttPacketType::Subscribe,
+            Self::Suback(_) => MqttPacketType::Suback,
+            Self::Unsubscribe(_) => MqttPacketType::Unsubscribe,
+            Self::Unsuback(_) => MqttPacketType::Unsuback,
+            Self::Pingreq => MqttPacketType::Pingreq,
+            Self::Pingresp => MqttPacketType::Pingresp,
+            Self::Disconnect(_) => MqttPacketType::Disconnect,
+        }
+    }
+}
+
+/// Decodes every packet of a buffer (a TCP segment can carry several). A
+/// CONNECT switches the version for the packets after it.
+pub fn parse_mqtt_messages(
+    bytes: &[u8],
+    mut version: MqttVersion,
+) -> Result<Vec<MqttMessage<'_>>, MqttDecodeError> {
+    let mut messages = Vec::new();
+    let mut offset = 0;
+    while offset < bytes.len() {
+        let (message, len) = MqttMessage::parse(&bytes[offset..], version)?;
+        if let MqttMessage::Connect(connect) = &message {
+            version = connect.version;
+        }
+        messages.push(message);
+        offset += len;
+    }
+    Ok(messages)
+}
+
+// Le découpage variable header / payload de MqttPacket est celui de 3.1.1 :
+// en MQTT 5, les propriétés ouvrent le payload de CONNECT, PUBLISH,
+// SUBSCRIBE, SUBACK, UNSUBSCRIBE et UNSUBACK, et terminent le variable
+// header des autres types.
+fn decode<'a>(
+    packet: &MqttPacket<'a>,
+    flags: u8,
+    version: MqttVersion,
+) -> Result<MqttMessage<'a>, MqttDecodeError> {
+    let v5 = version == MqttVersion::V5;
+    let vh = packet.variable_header;
+    let payload = packet.payload;
+    Ok(match packet.fixed_header.packet_type {
+        MqttPacketType::Connect => MqttMessage::Connect(decode_connect(vh, payload)?),
+        MqttPacketType::Connack => {
+            let (ack_flags, rest) = extract_mqtt_u8(vh, "connect acknowledge flags")?;
+            let (reason_code, rest) = extract_mqtt_u8(rest, "connect reason code")?;
+            let properties = trailing_properties(v5, rest, "CONNACK")?;
+            MqttMessage::Connack(MqttConnack {
+                session_present: ack_flags & 0x01 != 0,
+                reason_code,
+                properties,
+            })
+        }
+        MqttPacketType::Publish => {
+            let (topic, rest) = extract_mqtt_string(vh, "topic name")?;
+            let qos = (flags >> 1) & 0b11;
+            let (packet_id, rest) = if qos > 0 {
+                let (packet_id, rest) = extract_mqtt_u16(rest, "packet identifier")?;
+                (Some(packet_id), rest)
+            } else {
+                (None, rest)
+            };
+            validate_mqtt_no_trailing_data(rest, "PUBLISH variable header")?;
+            let (properties, payload) = leading_properties(v5, payload)?;
+            MqttMessage::Publish(MqttPublish {
+                dup: flags & 0x08 != 0,
+                qos,
+                retain: flags & 0x01 != 0,
+                topic,
+                packet_id,
+                properties,
+                payload,
+            })
+        }
+        packet_type @ (MqttPacketType::Puback
+        | MqttPacketType::Pubrec
+        | MqttPacketType::Pubrel
+        | MqttPacketType::Pubcomp) => {
+            let ack = decode_ack(v5, vh)?;
+            match packet_type {
+                MqttPacketType::Puback => MqttMessage::Puback(ack),
+                MqttPacketType::Pubrec => MqttMessage::Pubrec(ack),
+                MqttPacketType::Pubrel => MqttMessage::Pubrel(ack),
+                _ => MqttMessage::Pubcomp(ack),
+            }
+        }
+        MqttPacketType::Subscribe => {
+            let packet_id = packet_id_only(vh)?;
+            let (properties, mut entries) = leading_properties(v5, payload)?;
+            let mut subscriptions = Vec::new();
+            while !entries.is_empty() {
+                let (filter, rest) = extract_mqtt_string(entries, "topic filter")?;
+                let (options, rest) = extract_mqtt_u8(rest, "subscription options")?;
+                validate_mqtt_subscription_options(version, options)?;
+                subscriptions.push(MqttSubscription {
+                    filter,
+                    qos: options & 0x03,
+                    no_local: options & 0x04 != 0,
+                    retain_as_published: options & 0x08 != 0,
+                    retain_handling: (options >> 4) & 0x03,
+                });
+                entries = rest;
+            }
+            require_entries(&subscriptions, MqttPacketType::Subscribe, "topic filter")?;
+            MqttMessage::Subscribe(MqttSubscribe {
+                packet_id,
+                properties,
+                subscriptions,
+            })
+        }
+        MqttPacketType::Suback => {
+            let packet_id = packet_id_only(vh)?;
+            let (properties, reason_codes) = leading_properties(v5, payload)?;
+            require_entries(reason_codes, MqttPacketType::Suback, "reason code")?;
+            MqttMessage::Suback(MqttSuback {
+                packet_id,
+                properties,
+                reason_codes,
+            })
+        }
+        MqttPacketType::Unsubscribe => {
+            let packet_id = packet_id_only(vh)?;
+            let (properties, mut entries) = leading_properties(v5, payload)?;
+            let mut filters = Vec::new();
+            while !entries.is_empty() {
+                let (filter, rest) = extract_mqtt_string(entries, "topic filter")?;
+                filters.push(filter);
+                entries = rest;
+            }
+            require_entries(&filters, MqttPacketType::Unsubscribe, "topic filter")?;
+            MqttMessage::Unsubscribe(MqttUnsubscribe {
+                packet_id,
+                properties,
+                filters,
+            })
+        }
+        MqttPacketType::Unsuback => {
+            let packet_id = packet_id_only(vh)?;
+            let (properties, reason_codes) = if v5 {
+                let (properties, reason_codes) = split_mqtt_properties(payload)?;
+                require_entries(reason_codes, MqttPacketType::Unsuback, "reason code")?;
+                (properties, reason_codes)
+            } else {
+                validate_mqtt_no_trailing_data(payload, "UNSUBACK")?;
+                (MqttProperties::default(), payload)
+            };
+            MqttMessage::Unsuback(MqttUnsuback {
+                packet_id,
+                properties,
+                reason_codes,
+            })
+        }
+        MqttPacketType::Pingreq => MqttMessage::Pingreq,
+        MqttPacketType::Pingresp => MqttMessage::Pingresp,
+        MqttPacketType::Disconnect => {
+            let (reason_code, properties) = optional_reason(v5, vh, "DISCONNECT")?;
+            MqttMessage::Disconnect(MqttDisconnect {
+                reason_code,
+                properties,
+            })
+        }
+    })
+}
+
+fn decode_connect<'a>(vh: &'a [u8], payload: &'a [u8]) -> Result<MqttConnect<'a>, MqttDecodeError> {
+    let (protocol_name, rest) = extract_mqtt_string(vh, "protocol name")?;
+    let (level, rest) = extract_mqtt_u8(rest, "protocol level")?;
+    let (connect_flags, rest) = extract_mqtt_u8(rest, "connect flags")?;
+    let (keep_alive, rest) = extract_mqtt_u16(rest, "keep alive")?;
+    validate_mqtt_no_trailing_data(rest, "CONNECT variable header")?;
+    // Le couple nom/niveau a déjà été vérifié par MqttPacket.
+    let version =
+        MqttVersion::from_level(level).ok_or(MqttError::InvalidProtocolLevel { level })?;
+    validate_mqtt_will_flags(connect_flags)?;
+    let v5 = version == MqttVersion::V5;
+
+    let (properties, rest) = leading_properties(v5, payload)?;
+    let (client_id, mut rest) = extract_mqtt_string(rest, "client identifier")?;
+    let will = if connect_flags & MQTT_CONNECT_WILL != 0 {
+        let (properties, next) = leading_properties(v5, rest)?;
+        let (topic, next) = extract_mqtt_string(next, "will topic")?;
+        let (payload, next) = extract_mqtt_binary(next, "will payload")?;
+        rest = next;
+        Some(MqttWill {
+            qos: (connect_flags & MQTT_CONNECT_WILL_QOS_MASK) >> 3,
+            retain: connect_flags & MQTT_CONNECT_WILL_RETAIN != 0,
+            properties,
+            topic,
+            payload,
+        })
+    } else {
+        None
+    };
+    let username = if connect_flags & MQTT_CONNECT_USERNAME != 0 {
+        let (username, next) = extract_mqtt_string(rest, "user name")?;
+        rest = next;
+        Some(username)
+    } else {
+        None
+    };
+    let password = if connect_flags & MQTT_CONNECT_PASSWORD != 0 {
+        let (password, next) = extract_mqtt_binary(rest, "password")?;
+        rest = next;
+        Some(password)
+    } else {
+        None
+    };
+    validate_mqtt_no_trailing_data(rest, "CONNECT payload")?;
+
+    Ok(MqttConnect {
+        protocol_name,
+        version,
+        clean_start: connect_flags & MQTT_CONNECT_CLEAN_START != 0,
+        keep_alive,
+        properties,
+        client_id,
+        will,
+        username,
+        password,
+    })
+}
+
+/// Corps d'un accusé PUBACK/PUBREC/PUBREL/PUBCOMP : packet id, puis en
+/// MQTT 5 reason code et propriétés, chacun omis s'il n'y a plus d'octets.
+fn decode_ack(v5: bool, vh: &[u8]) -> Result<MqttAck<'_>, MqttDecodeError> {
+    let (packet_id, rest) = extract_mqtt_u16(vh, "packet identifier")?;
+    let (reason_code, properties) = optional_reason(v5, rest, "acknowledgement")?;
+    Ok(MqttAck {
+        packet_id,
+        reason_code,
+        properties,
+    })
+}
+
+/// Reason code puis propriétés, tous deux facultatifs en MQTT 5 ; rien en
+/// 3.1.1.
+fn optional_reason<'a>(
+    v5: bool,
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(u8, MqttProperties<'a>), MqttDecodeError> {
+    if !v5 || buf.is_empty() {
+        validate_mqtt_no_trailing_data(buf, field)?;
+        return Ok((0x00, MqttProperties::default()));
+    }
+    let (reason_code, rest) = extract_mqtt_u8(buf, "reason code")?;
+    Ok((reason_code, trailing_properties(v5, rest, field)?))
+}
+
+/// Propriétés qui terminent un variable header MQTT 5 ; un bloc absent vaut
+/// un bloc vide.
+fn trailing_properties<'a>(
+    v5: bool,
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<MqttProperties<'a>, MqttDecodeError> {
+    if !v5 || buf.is_empty() {
+        validate_mqtt_no_trailing_data(buf, field)?;
+        return Ok(MqttProperties::default());
+    }
+    let (properties, rest) = split_mqtt_properties(buf)?;
+    validate_mqtt_no_trailing_data(rest, field)?;
+    Ok(properties)
+}
+
+/// Propriétés en tête de payload (MQTT 5) ; en 3.1.1, payload inchangé.
+fn leading_properties(
+    v5: bool,
+    buf: &[u8],
+) -> Result<(MqttProperties<'_>, &[u8]), MqttDecodeError> {
+    if v5 {
+        split_mqtt_properties(buf)
+    } else {
+        Ok((MqttProperties::default(), buf))
+    }
+}
+
+fn packet_id_only(vh: &[u8]) -> Result<u16, MqttDecodeError> {
+    let (packet_id, rest) = extract_mqtt_u16(vh, "packet identifier")?;
+    validate_mqtt_no_trailing_data(rest, "packet identifier")?;
+    Ok(packet_id)
+}
+
+fn require_entries<T>(
+    entries: &[T],
+    packet_type: MqttPacketType,
+    field: &'static str,
+) -> Result<(), MqttDecodeError> {
+    if entries.is_empty() {
+        return Err(MqttDecodeError::MissingEntries { packet_type, field });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn connect_v311_with_will_and_credentials() {
+        // Synthétique : CONNECT 3.1.1, clean session, will QoS 1 retain sur
+        // "dead/plc1", user "ops", mot de passe "pw", keep alive 60.
+        let bytes = hex::decode(
+            "102a00044d51545404ee003c0004706c63310009646561642f706c63310004\
+             6279652100036f707300027077",
+        )
+        .unwrap();
+        // La version passée est ignorée : CONNECT porte la sienne.
+        let (message, len) = MqttMessage::parse(&bytes, MqttVersion::V5).unwrap();
+        assert_eq!(len, bytes.len());
+
+        let MqttMessage::Connect(connect) = message else {
+            panic!("CONNECT attendu");
+        };
+        assert_eq!(connect.version, MqttVersion::V3_1_1);
+        assert!(connect.clean_start);
+        assert_eq!(connect.keep_alive, 60);
+        assert_eq!(connect.client_id, "plc1");
+        assert_eq!(
+            connect.will,
+            Some(MqttWill {
+                qos: 1,
+                retain: true,
+                properties: MqttProperties::default(),
+                topic: "dead/plc1",
+                payload: b"bye!",
+            })
+        );
+        assert_eq!(connect.username, Some("ops"));
+        assert_eq!(connect.password, Some(&b"pw"[..]));
+    }
+
+    #[test]
+    fn connect_v5_reads_properties() {
+        // Synthétique : CONNECT MQTT 5, Session Expiry Interval 3600 et
+        // User Property ("fleet", "north"), client id "s1".
+        let bytes = hex::decode(
+            "10230004 4d515454 05 02 001e 14 1100000e10 26 0005666c656574 00056e6f727468 0002 7331"
+                .replace(' ', ""),
+        )
+        .unwrap();
+        let (message, _) = MqttMessage::parse(&bytes, MqttVersion::V3_1_1).unwrap();
+        let MqttMessage::Connect(connect) = message else {
+            panic!("CONNECT attendu");
+        };
+
+        assert_eq!(connect.version, MqttVersion::V5);
+        assert_eq!(connect.client_id, "s1");
+        assert_eq!(
+            connect.properties.user_properties().collect::<Vec<_>>(),
+            [("fleet", "north")]
+        );
+        assert_eq!(connect.will, None);
+    }
+
+    #[test]
+    fn publish_v5_with_topic_alias_and_qos2() {
+        // Synthétique : PUBLISH MQTT 5 QoS 2 retain, topic vide remplacé
+        // par l'alias 7, packet id 10, payload "21.5".
+        let bytes = hex::decode("350c0000000a0323000732312e35").unwrap();
+        let (message, _) = MqttMessage::parse(&bytes, MqttVersion::V5).unwrap();
+
+        assert_eq!(
+            message,
+            MqttMessage::Publish(MqttPublish {
+                dup: false,
+                qos: 2,
+                retain: true,
+                topic: "",
+                packet_id: Some(10),
+                properties: MqttProperties::try_from(&[0x03, 0x23, 0x00, 0x07][..]).unwrap(),
+                payload: b"21.5",
+            })
+        );
+        let MqttMessage::Publish(publish) = message else {
+            unreachable!()
+        };
+        assert_eq!(publish.topic_alias(), Some(7));
+    }
+
+    #[test]
+    fn subscribe_and_suback_in_both_versions() {
+        // Synthétique : SUBSCRIBE 3.1.1 "plant/#" QoS 1 et "cmd/+" QoS 0.
+        let v3 = hex::decode("821400010007706c616e742f23010005636d642f2b00").unwrap();
+        let (message, _) = MqttMessage::parse(&v3, MqttVersion::V3_1_1).unwrap();
+        let MqttMessage::Subscribe(subscribe) = message else {
+            panic!("SUBSCRIBE attendu");
+        };
+        assert_eq!(subscribe.packet_id, 1);
+        assert_eq!(
+            subscribe
+                .subscriptions
+                .iter()
+                .map(|subscription| (subscription.filter, subscription.qos))
+                .collect::<Vec<_>>(),
+            [("plant/#", 1), ("cmd/+", 0)]
+        );
+
+        // Synthétique : SUBSCRIBE MQTT 5, Subscription Identifier 3, filtre
+        // "a/b" QoS 1, No Local, Retain Handling 2.
+        let v5 = hex::decode("820b000202 0b03 0003612f62 25".replace(' ', "")).unwrap();
+        let (message, _) = MqttMessage::parse(&v5, MqttVersion::V5).unwrap();
+        let MqttMessage::Subscribe(subscribe) = message else {
+            panic!("SUBSCRIBE attendu");
+        };
+        assert_eq!(
+            subscribe.subscriptions,
+            [MqttSubscription {
+                filter: "a/b",
+                qos: 1,
+                no_local: true,
+                retain_as_published: false,
+                retain_handling: 2,
+            }]
+        );
+
+        // SUBACK MQTT 5 : Reason String "ok", QoS 1 accordé puis refus 0x87.
+        let suback = hex::decode("900a0002 05 1f00026f6b 0187".replace(' ', "")).unwrap();
+        let (message, _) = MqttMessage::parse(&suback, MqttVersion::V5).unwrap();
+        let MqttMessage::Suback(suback) = message else {
+            panic!("SUBACK attendu");
+        };
+        assert_eq!(suback.properties.reason_string(), Some("ok"));
+        assert_eq!(suback.reason_codes, [0x01, 0x87]);
+    }
+
+    #[test]
+    fn acks_and_disconnect_default_to_success() {
+        let (puback, _) = MqttMessage::parse(&[0x40, 0x02, 0x00, 0x05], MqttVersion::V5).unwrap();
+        assert_eq!(
+            puback,
+            MqttMessage::Puback(MqttAck {
+                packet_id: 5,
+                reason_code: 0x00,
+                properties: MqttProperties::default(),
+            })
+        );
+        let (pubrec, _) =
+            MqttMessage::parse(&[0x50, 0x03, 0x00, 0x05, 0x10], MqttVersion::V5).unwrap();
+        assert!(matches!(
+            pubrec,
+            MqttMessage::Pubrec(MqttAck {
+                reason_code: 0x10,
+                ..
+            })
+        ));
+        let (disconnect, _) = MqttMessage::parse(&[0xE0, 0x01, 0x8E], MqttVersion::V5).unwrap();
+        assert_eq!(
+            disconnect,
+            MqttMessage::Disconnect(MqttDisconnect {
+                reason_code: 0x8E,
+                properties: MqttProperties::default(),
+            })
+        );
+    }
+
+    #[test]
+    fn coalesced_packets_follow_the_connect_version() {
+        // CONNECT MQTT 5 minimal (client id vide) puis PUBACK avec reason
+        // code, lisible seulement en MQTT 5.
+        let bytes = hex::decode("100d00044d5154540502003c0000004003000110").unwrap();
+        let messages = parse_mqtt_messages(&bytes, MqttVersion::V3_1_1).unwrap();
+
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[1].packet_type(), MqttPacketType::Puback);
+        assert!(matches!(
+            messages[1],
+            MqttMessage::Puback(MqttAck {
+                packet_id: 1,
+                reason_code: 0x10,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn invalid_fields_are_reported() {
+        // Will QoS 1 sans Will Flag.
+        let bytes = hex::decode("100d00044d51545404080000000161").unwrap();
+        assert_eq!(
+            MqttMessage::parse(&bytes, MqttVersion::V3_1_1).map(|_| ()),
+            Err(MqttDecodeError::InvalidWillFlags { flags: 0x08 })
+        );
+        // PUBACK MQTT 5 lu en 3.1.1 : octet en trop.
+        assert_eq!(
+            MqttMessage::parse(&[0x40, 0x03, 0x00, 0x05, 0x10], MqttVersion::V3_1_1).map(|_| ()),
+            Err(MqttDecodeError::TrailingData {
+                field: "acknowledgement",
+                length: 1,
+            })
+        );
+        // SUBSCRIBE 3.1.1 avec No Local (option MQTT 5).
+        let bytes = hex::decode("8206000100016104").unwrap();
+        assert_eq!(
+            MqttMessage::parse(&bytes, MqttVersion::V3_1_1).map(|_| ()),
+            Err(MqttDecodeError::InvalidSubscriptionOptions(0x04))
+        );
+        // Erreur de cadrage remontée telle quelle.
+        assert_eq!(
+            MqttMessage::parse(&[0xC0, 0x05], MqttVersion::V5).map(|_| ()),
+            Err(MqttDecodeError::Packet(
+                MqttError::RemainingLengthExceedsBuffer {
+                    remaining_length: 5,
+                    available: 0,
+                }
+            ))
+        );
+    }
+}
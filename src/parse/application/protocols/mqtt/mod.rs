@@ -15,6 +15,9 @@ use crate::{
     errors::application::mqtt::MqttError,
 };
 
+pub mod message;
+pub mod properties;
+
 /// MQTT Control Packet
 ///
 /// ```mermaid
@@ -0,0 +1,328 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! MQTT 5 properties (§2.2.2): a Variable Byte Integer length followed by
+//! `identifier, value` pairs, carried by every packet type except PINGREQ
+//! and PINGRESP.
+
+use crate::{
+    checks::application::mqtt::{
+        MQTT_PROP_ASSIGNED_CLIENT_IDENTIFIER, MQTT_PROP_AUTHENTICATION_DATA,
+        MQTT_PROP_AUTHENTICATION_METHOD, MQTT_PROP_CONTENT_TYPE, MQTT_PROP_CORRELATION_DATA,
+        MQTT_PROP_MAXIMUM_PACKET_SIZE, MQTT_PROP_MAXIMUM_QOS, MQTT_PROP_MESSAGE_EXPIRY_INTERVAL,
+        MQTT_PROP_PAYLOAD_FORMAT_INDICATOR, MQTT_PROP_REASON_STRING, MQTT_PROP_RECEIVE_MAXIMUM,
+        MQTT_PROP_REQUEST_PROBLEM_INFORMATION, MQTT_PROP_REQUEST_RESPONSE_INFORMATION,
+        MQTT_PROP_RESPONSE_INFORMATION, MQTT_PROP_RESPONSE_TOPIC, MQTT_PROP_RETAIN_AVAILABLE,
+        MQTT_PROP_SERVER_KEEP_ALIVE, MQTT_PROP_SERVER_REFERENCE, MQTT_PROP_SESSION_EXPIRY_INTERVAL,
+        MQTT_PROP_SHARED_SUBSCRIPTION_AVAILABLE, MQTT_PROP_SUBSCRIPTION_IDENTIFIER,
+        MQTT_PROP_SUBSCRIPTION_IDENTIFIER_AVAILABLE, MQTT_PROP_TOPIC_ALIAS,
+        MQTT_PROP_TOPIC_ALIAS_MAXIMUM, MQTT_PROP_USER_PROPERTY,
+        MQTT_PROP_WILDCARD_SUBSCRIPTION_AVAILABLE, MQTT_PROP_WILL_DELAY_INTERVAL,
+        extract_mqtt_binary, extract_mqtt_string, extract_mqtt_u8, extract_mqtt_u16,
+        extract_mqtt_u32, extract_mqtt_varint, validate_mqtt_no_trailing_data,
+    },
+    errors::application::mqtt::MqttDecodeError,
+};
+
+/// One MQTT 5 property, its value borrowed from the packet.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MqttProperty<'a> {
+    PayloadFormatIndicator(u8),
+    MessageExpiryInterval(u32),
+    ContentType(&'a str),
+    ResponseTopic(&'a str),
+    CorrelationData(&'a [u8]),
+    SubscriptionIdentifier(u32),
+    SessionExpiryInterval(u32),
+    AssignedClientIdentifier(&'a str),
+    ServerKeepAlive(u16),
+    AuthenticationMethod(&'a str),
+    AuthenticationData(&'a [u8]),
+    RequestProblemInformation(u8),
+    WillDelayInterval(u32),
+    RequestResponseInformation(u8),
+    ResponseInformation(&'a str),
+    ServerReference(&'a str),
+    ReasonString(&'a str),
+    ReceiveMaximum(u16),
+    TopicAliasMaximum(u16),
+    TopicAlias(u16),
+    MaximumQos(u8),
+    RetainAvailable(u8),
+    UserProperty { name: &'a str, value: &'a str },
+    MaximumPacketSize(u32),
+    WildcardSubscriptionAvailable(u8),
+    SubscriptionIdentifierAvailable(u8),
+    SharedSubscriptionAvailable(u8),
+}
+
+impl MqttProperty<'_> {
+    /// Identifier byte on the wire.
+    pub fn identifier(&self) -> u8 {
+        match self {
+            Self::PayloadFormatIndicator(_) => MQTT_PROP_PAYLOAD_FORMAT_INDICATOR,
+            Self::MessageExpiryInterval(_) => MQTT_PROP_MESSAGE_EXPIRY_INTERVAL,
+            Self::ContentType(_) => MQTT_PROP_CONTENT_TYPE,
+            Self::ResponseTopic(_) => MQTT_PROP_RESPONSE_TOPIC,
+            Self::CorrelationData(_) => MQTT_PROP_CORRELATION_DATA,
+            Self::SubscriptionIdentifier(_) => MQTT_PROP_SUBSCRIPTION_IDENTIFIER,
+            Self::SessionExpiryInterval(_) => MQTT_PROP_SESSION_EXPIRY_INTERVAL,
+            Self::AssignedClientIdentifier(_) => MQTT_PROP_ASSIGNED_CLIENT_IDENTIFIER,
+            Self::ServerKeepAlive(_) => MQTT_PROP_SERVER_KEEP_ALIVE,
+            Self::AuthenticationMethod(_) => MQTT_PROP_AUTHENTICATION_METHOD,
+            Self::AuthenticationData(_) => MQTT_PROP_AUTHENTICATION_DATA,
+            Self::RequestProblemInformation(_) => MQTT_PROP_REQUEST_PROBLEM_INFORMATION,
+            Self::WillDelayInterval(_) => MQTT_PROP_WILL_DELAY_INTERVAL,
+            Self::RequestResponseInformation(_) => MQTT_PROP_REQUEST_RESPONSE_INFORMATION,
+            Self::ResponseInformation(_) => MQTT_PROP_RESPONSE_INFORMATION,
+            Self::ServerReference(_) => MQTT_PROP_SERVER_REFERENCE,
+            Self::ReasonString(_) => MQTT_PROP_REASON_STRING,
+            Self::ReceiveMaximum(_) => MQTT_PROP_RECEIVE_MAXIMUM,
+            Self::TopicAliasMaximum(_) => MQTT_PROP_TOPIC_ALIAS_MAXIMUM,
+            Self::TopicAlias(_) => MQTT_PROP_TOPIC_ALIAS,
+            Self::MaximumQos(_) => MQTT_PROP_MAXIMUM_QOS,
+            Self::RetainAvailable(_) => MQTT_PROP_RETAIN_AVAILABLE,
+            Self::UserProperty { .. } => MQTT_PROP_USER_PROPERTY,
+            Self::MaximumPacketSize(_) => MQTT_PROP_MAXIMUM_PACKET_SIZE,
+            Self::WildcardSubscriptionAvailable(_) => MQTT_PROP_WILDCARD_SUBSCRIPTION_AVAILABLE,
+            Self::SubscriptionIdentifierAvailable(_) => MQTT_PROP_SUBSCRIPTION_IDENTIFIER_AVAILABLE,
+            Self::SharedSubscriptionAvailable(_) => MQTT_PROP_SHARED_SUBSCRIPTION_AVAILABLE,
+        }
+    }
+}
+
+/// Property block of an MQTT 5 packet, in wire order. Empty for MQTT
+/// 3.1/3.1.1 packets, which have none.
+///
+/// ```mermaid
+/// ---
+/// title: MqttProperties
+/// ---
+/// packet-beta
+/// 0-31: "Property Length varint"
+/// 32-39: "Identifier u8"
+/// 40-71: "Value variable"
+/// 72-95: "..."
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MqttProperties<'a> {
+    pub properties: Vec<MqttProperty<'a>>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for MqttProperties<'a> {
+    type Error = MqttDecodeError;
+
+    /// Parses a property block, length included, that fills `bytes`.
+    fn try_from(bytes: &'a [u8]) -> Result<Self, Self::Error> {
+        let (properties, rest) = split_mqtt_properties(bytes)?;
+        validate_mqtt_no_trailing_data(rest, "properties")?;
+        Ok(properties)
+    }
+}
+
+impl<'a> MqttProperties<'a> {
+    pub fn is_empty(&self) -> bool {
+        self.properties.is_empty()
+    }
+
+    /// First property with the given identifier.
+    pub fn get(&self, identifier: u8) -> Option<&MqttProperty<'a>> {
+        self.properties
+            .iter()
+            .find(|property| property.identifier() == identifier)
+    }
+
+    /// User properties as `(name, value)` pairs, duplicates and order kept.
+    pub fn user_properties(&self) -> impl Iterator<Item = (&'a str, &'a str)> + '_ {
+        self.properties
+            .iter()
+            .filter_map(|property| match property {
+                MqttProperty::UserProperty { name, value } => Some((*name, *value)),
+                _ => None,
+            })
+    }
+
+    pub fn topic_alias(&self) -> Option<u16> {
+        match self.get(MQTT_PROP_TOPIC_ALIAS)? {
+            MqttProperty::TopicAlias(alias) => Some(*alias),
+            _ => None,
+        }
+    }
+
+    pub fn reason_string(&self) -> Option<&'a str> {
+        match self.get(MQTT_PROP_REASON_STRING)? {
+            MqttProperty::ReasonString(reason) => Some(reason),
+            _ => None,
+        }
+    }
+}
+
+/// Lit un bloc de propriétés en tête de `buf` et rend le reste.
+pub(crate) fn split_mqtt_properties(
+    buf: &[u8],
+) -> Result<(MqttProperties<'_>, &[u8]), MqttDecodeError> {
+    let (length, rest) = extract_mqtt_varint(buf, "property length")?;
+    let length = length as usize;
+    if rest.len() < length {
+        return Err(MqttDecodeError::Truncated {
+            field: "properties",
+        });
+    }
+    let (mut block, rest) = rest.split_at(length);
+
+    let mut properties: Vec<MqttProperty<'_>> = Vec::new();
+    while !block.is_empty() {
+        let (property, next) = extract_property(block)?;
+        let identifier = property.identifier();
+        // Seules User Property et Subscription Identifier peuvent se
+        // répéter (§2.2.2.2).
+        let repeatable = matches!(
+            identifier,
+            MQTT_PROP_USER_PROPERTY | MQTT_PROP_SUBSCRIPTION_IDENTIFIER
+        );
+        if !repeatable
+            && properties
+                .iter()
+                .any(|known| known.identifier() == identifier)
+        {
+            return Err(MqttDecodeError::DuplicateProperty(identifier));
+        }
+        properties.push(property);
+        block = next;
+    }
+    Ok((MqttProperties { properties }, rest))
+}
+
+fn extract_property(buf: &[u8]) -> Result<(MqttProperty<'_>, &[u8]), MqttDecodeError> {
+    // L'identifiant est un Variable Byte Integer, mais toutes les valeurs
+    // définies tiennent sur un octet.
+    let (identifier, rest) = extract_mqtt_varint(buf, "property identifier")?;
+    let identifier = u8::try_from(identifier)
+        .ok()
+        .filter(|identifier| identifier & 0x80 == 0)
+        .ok_or(MqttDecodeError::UnknownProperty(buf[0]))?;
+
+    let byte = |build: fn(u8) -> MqttProperty<'static>| {
+        extract_mqtt_u8(rest, "property value").map(|(value, rest)| (build(value), rest))
+    };
+    let two = |build: fn(u16) -> MqttProperty<'static>| {
+        extract_mqtt_u16(rest, "property value").map(|(value, rest)| (build(value), rest))
+    };
+    let four = |build: fn(u32) -> MqttProperty<'static>| {
+        extract_mqtt_u32(rest, "property value").map(|(value, rest)| (build(value), rest))
+    };
+
+    match identifier {
+        MQTT_PROP_PAYLOAD_FORMAT_INDICATOR => byte(MqttProperty::PayloadFormatIndicator),
+        MQTT_PROP_MESSAGE_EXPIRY_INTERVAL => four(MqttProperty::MessageExpiryInterval),
+        MQTT_PROP_CONTENT_TYPE => extract_mqtt_string(rest, "content type")
+            .map(|(value, rest)| (MqttProperty::ContentType(value), rest)),
+        MQTT_PROP_RESPONSE_TOPIC => extract_mqtt_string(rest, "response topic")
+            .map(|(value, rest)| (MqttProperty::ResponseTopic(value), rest)),
+        MQTT_PROP_CORRELATION_DATA => extract_mqtt_binary(rest, "correlation data")
+            .map(|(value, rest)| (MqttProperty::CorrelationData(value), rest)),
+        MQTT_PROP_SUBSCRIPTION_IDENTIFIER => extract_mqtt_varint(rest, "subscription identifier")
+            .map(|(value, rest)| (MqttProperty::SubscriptionIdentifier(value), rest)),
+        MQTT_PROP_SESSION_EXPIRY_INTERVAL => four(MqttProperty::SessionExpiryInterval),
+        MQTT_PROP_ASSIGNED_CLIENT_IDENTIFIER => {
+            extract_mqtt_string(rest, "assigned client identifier")
+                .map(|(value, rest)| (MqttProperty::AssignedClientIdentifier(value), rest))
+        }
+        MQTT_PROP_SERVER_KEEP_ALIVE => two(MqttProperty::ServerKeepAlive),
+        MQTT_PROP_AUTHENTICATION_METHOD => extract_mqtt_string(rest, "authentication method")
+            .map(|(value, rest)| (MqttProperty::AuthenticationMethod(value), rest)),
+        MQTT_PROP_AUTHENTICATION_DATA => extract_mqtt_binary(rest, "authentication data")
+            .map(|(value, rest)| (MqttProperty::AuthenticationData(value), rest)),
+        MQTT_PROP_REQUEST_PROBLEM_INFORMATION => byte(MqttProperty::RequestProblemInformation),
+        MQTT_PROP_WILL_DELAY_INTERVAL => four(MqttProperty::WillDelayInterval),
+        MQTT_PROP_REQUEST_RESPONSE_INFORMATION => byte(MqttProperty::RequestResponseInformation),
+        MQTT_PROP_RESPONSE_INFORMATION => extract_mqtt_string(rest, "response information")
+            .map(|(value, rest)| (MqttProperty::ResponseInformation(value), rest)),
+        MQTT_PROP_SERVER_REFERENCE => extract_mqtt_string(rest, "server reference")
+            .map(|(value, rest)| (MqttProperty::ServerReference(value), rest)),
+        MQTT_PROP_REASON_STRING => extract_mqtt_string(rest, "reason string")
+            .map(|(value, rest)| (MqttProperty::ReasonString(value), rest)),
+        MQTT_PROP_RECEIVE_MAXIMUM => two(MqttProperty::ReceiveMaximum),
+        MQTT_PROP_TOPIC_ALIAS_MAXIMUM => two(MqttProperty::TopicAliasMaximum),
+        MQTT_PROP_TOPIC_ALIAS => two(MqttProperty::TopicAlias),
+        MQTT_PROP_MAXIMUM_QOS => byte(MqttProperty::MaximumQos),
+        MQTT_PROP_RETAIN_AVAILABLE => byte(MqttProperty::RetainAvailable),
+        MQTT_PROP_USER_PROPERTY => {
+            let (name, rest) = extract_mqtt_string(rest, "user property name")?;
+            let (value, rest) = extract_mqtt_string(rest, "user property value")?;
+            Ok((MqttProperty::UserProperty { name, value }, rest))
+        }
+        MQTT_PROP_MAXIMUM_PACKET_SIZE => four(MqttProperty::MaximumPacketSize),
+        MQTT_PROP_WILDCARD_SUBSCRIPTION_AVAILABLE => {
+            byte(MqttProperty::WildcardSubscriptionAvailable)
+        }
+        MQTT_PROP_SUBSCRIPTION_IDENTIFIER_AVAILABLE => {
+            byte(MqttProperty::SubscriptionIdentifierAvailable)
+        }
+        MQTT_PROP_SHARED_SUBSCRIPTION_AVAILABLE => byte(MqttProperty::SharedSubscriptionAvailable),
+        _ => Err(MqttDecodeError::UnknownProperty(identifier)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn property_block_decodes_in_wire_order() {
+        // Synthétique : Topic Alias 5, deux User Property ("site", "a") et
+        // ("site", "b"), Subscription Identifier 200 (varint 2 octets),
+        // puis un octet hors bloc.
+        let bytes =
+            hex::decode("1a23000526000473697465000161260004736974650001620bc801ff").unwrap();
+        let (properties, rest) = split_mqtt_properties(&bytes).unwrap();
+
+        assert_eq!(rest, [0xff]);
+        assert_eq!(properties.topic_alias(), Some(5));
+        assert_eq!(
+            properties.user_properties().collect::<Vec<_>>(),
+            [("site", "a"), ("site", "b")]
+        );
+        assert_eq!(
+            properties.get(MQTT_PROP_SUBSCRIPTION_IDENTIFIER),
+            Some(&MqttProperty::SubscriptionIdentifier(200))
+        );
+        assert_eq!(
+            MqttProperties::try_from(&bytes[..]),
+            Err(MqttDecodeError::TrailingData {
+                field: "properties",
+                length: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn malformed_property_blocks_are_rejected() {
+        // Topic Alias répété.
+        assert_eq!(
+            MqttProperties::try_from(&[0x06, 0x23, 0x00, 0x01, 0x23, 0x00, 0x02][..]),
+            Err(MqttDecodeError::DuplicateProperty(MQTT_PROP_TOPIC_ALIAS))
+        );
+        // Identifiant 0x7F non attribué.
+        assert_eq!(
+            MqttProperties::try_from(&[0x02, 0x7F, 0x00][..]),
+            Err(MqttDecodeError::UnknownProperty(0x7F))
+        );
+        // Reason String dont la longueur déborde du bloc.
+        assert_eq!(
+            MqttProperties::try_from(&[0x04, 0x1F, 0x00, 0x05, b'x'][..]),
+            Err(MqttDecodeError::Truncated {
+                field: "reason string",
+            })
+        );
+        assert_eq!(
+            MqttProperties::try_from(&[0x05, 0x01][..]),
+            Err(MqttDecodeError::Truncated {
+                field: "properties",
+            })
+        );
+    }
+}
@@ -0,0 +1,550 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! NTLMSSP messages (MS-NLMP §2.2), as carried by SMB SESSION_SETUP, LDAP
+//! binds and HTTP `Authorization: NTLM` headers.
+//!
+//! The three messages of an NTLM handshake name the parties: the
+//! NEGOTIATE may carry the client's domain and workstation, the CHALLENGE
+//! the server's NetBIOS and DNS names in its target information, and the
+//! AUTHENTICATE the domain, user and workstation that logged on. The
+//! response lengths tell NTLMv1 from NTLMv2 and anonymous logons.
+//!
+//! ```mermaid
+//! sequenceDiagram
+//!     Client->>Server: NEGOTIATE (flags, domain?, workstation?)
+//!     Server->>Client: CHALLENGE (server challenge, target info)
+//!     Client->>Server: AUTHENTICATE (domain, user, workstation, responses)
+//! ```
+
+use std::{convert::TryFrom, fmt};
+
+use crate::{
+    checks::application::ntlmssp::{
+        MSV_AV_DNS_COMPUTER_NAME, MSV_AV_DNS_DOMAIN_NAME, MSV_AV_DNS_TREE_NAME, MSV_AV_EOL,
+        MSV_AV_FLAGS, MSV_AV_NB_COMPUTER_NAME, MSV_AV_NB_DOMAIN_NAME, MSV_AV_TARGET_NAME,
+        MSV_AV_TIMESTAMP, NTLMSSP_AUTHENTICATE, NTLMSSP_AUTHENTICATE_FIXED_LEN,
+        NTLMSSP_AV_PAIR_HEADER_LEN, NTLMSSP_CHALLENGE, NTLMSSP_CHALLENGE_FIXED_LEN,
+        NTLMSSP_NEGOTIATE, NTLMSSP_NEGOTIATE_FIXED_LEN, NTLMSSP_NEGOTIATE_MIN_LEN,
+        NTLMSSP_NEGOTIATE_OEM_DOMAIN_SUPPLIED, NTLMSSP_NEGOTIATE_OEM_WORKSTATION_SUPPLIED,
+        NTLMSSP_NEGOTIATE_UNICODE, NTLMSSP_NEGOTIATE_VERSION, NTLMSSP_VERSION_LEN,
+        NTLMV1_RESPONSE_LEN, extract_ntlmssp_field, extract_ntlmssp_message_type,
+        extract_ntlmssp_string, ntlmssp_av_pair_name, ntlmssp_flag_names,
+        ntlmssp_message_type_name, validate_ntlmssp_length,
+    },
+    errors::application::ntlmssp::NtlmsspError,
+};
+
+/// Bit MsvAvFlags annoncant un MIC dans l'AUTHENTICATE (MS-NLMP §2.2.2.1).
+const MSV_AV_FLAG_MIC_PRESENT: u32 = 0x0000_0002;
+/// NTProofStr puis en-tete fixe de NTLMv2_CLIENT_CHALLENGE avant ses AV_PAIR.
+const NTLMV2_RESPONSE_AV_PAIRS_OFFSET: usize = 16 + 28;
+const NTLMSSP_MIC_OFFSET: usize = 72;
+const NTLMSSP_MIC_LEN: usize = 16;
+
+/// An NTLMSSP message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NtlmsspMessage<'a> {
+    Negotiate(NtlmsspNegotiate),
+    Challenge(NtlmsspChallenge<'a>),
+    Authenticate(NtlmsspAuthenticate<'a>),
+}
+
+impl<'a> TryFrom<&'a [u8]> for NtlmsspMessage<'a> {
+    type Error = NtlmsspError;
+
+    fn try_from(message: &'a [u8]) -> Result<Self, NtlmsspError> {
+        match extract_ntlmssp_message_type(message)? {
+            NTLMSSP_NEGOTIATE => Ok(NtlmsspMessage::Negotiate(NtlmsspNegotiate::parse(message)?)),
+            NTLMSSP_CHALLENGE => Ok(NtlmsspMessage::Challenge(NtlmsspChallenge::parse(message)?)),
+            NTLMSSP_AUTHENTICATE => Ok(NtlmsspMessage::Authenticate(NtlmsspAuthenticate::parse(
+                message,
+            )?)),
+            other => Err(NtlmsspError::UnknownMessageType(other)),
+        }
+    }
+}
+
+impl NtlmsspMessage<'_> {
+    pub fn message_type(&self) -> u32 {
+        match self {
+            NtlmsspMessage::Negotiate(_) => NTLMSSP_NEGOTIATE,
+            NtlmsspMessage::Challenge(_) => NTLMSSP_CHALLENGE,
+            NtlmsspMessage::Authenticate(_) => NTLMSSP_AUTHENTICATE,
+        }
+    }
+
+    pub fn message_type_name(&self) -> &'static str {
+        ntlmssp_message_type_name(self.message_type())
+    }
+
+    pub fn flags(&self) -> u32 {
+        match self {
+            NtlmsspMessage::Negotiate(message) => message.flags,
+            NtlmsspMessage::Challenge(message) => message.flags,
+            NtlmsspMessage::Authenticate(message) => message.flags,
+        }
+    }
+
+    pub fn flag_names(&self) -> Vec<&'static str> {
+        ntlmssp_flag_names(self.flags())
+    }
+}
+
+/// Operating system version a peer advertises (MS-NLMP §2.2.2.10).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NtlmsspVersion {
+    pub major: u8,
+    pub minor: u8,
+    pub build: u16,
+    pub revision: u8,
+}
+
+impl NtlmsspVersion {
+    /// Structure VERSION a `at` si le drapeau l'annonce et que la partie
+    /// fixe la contient.
+    fn parse(message: &[u8], flags: u32, at: usize) -> Option<Self> {
+        if flags & NTLMSSP_NEGOTIATE_VERSION == 0 {
+            return None;
+        }
+        let version = message.get(at..at + NTLMSSP_VERSION_LEN)?;
+        Some(NtlmsspVersion {
+            major: version[0],
+            minor: version[1],
+            build: u16::from_le_bytes([version[2], version[3]]),
+            revision: version[7],
+        })
+    }
+}
+
+impl fmt::Display for NtlmsspVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.build)
+    }
+}
+
+/// NEGOTIATE_MESSAGE, sent by the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NtlmsspNegotiate {
+    pub flags: u32,
+    /// Present when NTLMSSP_NEGOTIATE_OEM_DOMAIN_SUPPLIED is set.
+    pub domain: Option<String>,
+    /// Present when NTLMSSP_NEGOTIATE_OEM_WORKSTATION_SUPPLIED is set.
+    pub workstation: Option<String>,
+    pub version: Option<NtlmsspVersion>,
+}
+
+impl NtlmsspNegotiate {
+    fn parse(message: &[u8]) -> Result<Self, NtlmsspError> {
+        validate_ntlmssp_length(message, NTLMSSP_NEGOTIATE_MIN_LEN)?;
+        let flags = u32_at(message, 12);
+        // Les champs domaine / poste sont optionnels dans les implementations
+        // anciennes : un NEGOTIATE de 16 octets reste valide.
+        let long = message.len() >= NTLMSSP_NEGOTIATE_FIXED_LEN;
+        let oem_field = |supplied: u32, at: usize, field: &'static str| {
+            if !long || flags & supplied == 0 {
+                return Ok(None);
+            }
+            let bytes = extract_ntlmssp_field(message, at, field)?;
+            extract_ntlmssp_string(bytes, false, field).map(Some)
+        };
+        Ok(NtlmsspNegotiate {
+            flags,
+            domain: oem_field(NTLMSSP_NEGOTIATE_OEM_DOMAIN_SUPPLIED, 16, "domain")?,
+            workstation: oem_field(
+                NTLMSSP_NEGOTIATE_OEM_WORKSTATION_SUPPLIED,
+                24,
+                "workstation",
+            )?,
+            version: NtlmsspVersion::parse(message, flags, NTLMSSP_NEGOTIATE_FIXED_LEN),
+        })
+    }
+}
+
+/// CHALLENGE_MESSAGE, sent by the server.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NtlmsspChallenge<'a> {
+    pub flags: u32,
+    pub target_name: String,
+    pub server_challenge: [u8; 8],
+    pub target_info: Vec<NtlmsspAvPair<'a>>,
+    pub version: Option<NtlmsspVersion>,
+}
+
+impl<'a> NtlmsspChallenge<'a> {
+    fn parse(message: &'a [u8]) -> Result<Self, NtlmsspError> {
+        validate_ntlmssp_length(message, NTLMSSP_CHALLENGE_FIXED_LEN)?;
+        let flags = u32_at(message, 20);
+        let unicode = flags & NTLMSSP_NEGOTIATE_UNICODE != 0;
+        let mut server_challenge = [0u8; 8];
+        server_challenge.copy_from_slice(&message[24..32]);
+        Ok(NtlmsspChallenge {
+            flags,
+            target_name: extract_ntlmssp_string(
+                extract_ntlmssp_field(message, 12, "target name")?,
+                unicode,
+                "target name",
+            )?,
+            server_challenge,
+            target_info: parse_av_pairs(extract_ntlmssp_field(message, 40, "target info")?)?,
+            version: NtlmsspVersion::parse(message, flags, NTLMSSP_CHALLENGE_FIXED_LEN),
+        })
+    }
+
+    fn av_string(&self, id: u16) -> Option<String> {
+        self.target_info
+            .iter()
+            .find(|pair| pair.id == id)
+            .and_then(NtlmsspAvPair::string)
+    }
+
+    pub fn nb_domain_name(&self) -> Option<String> {
+        self.av_string(MSV_AV_NB_DOMAIN_NAME)
+    }
+
+    pub fn nb_computer_name(&self) -> Option<String> {
+        self.av_string(MSV_AV_NB_COMPUTER_NAME)
+    }
+
+    pub fn dns_domain_name(&self) -> Option<String> {
+        self.av_string(MSV_AV_DNS_DOMAIN_NAME)
+    }
+
+    pub fn dns_computer_name(&self) -> Option<String> {
+        self.av_string(MSV_AV_DNS_COMPUTER_NAME)
+    }
+
+    /// Server time as a FILETIME (100 ns intervals since 1601-01-01).
+    pub fn timestamp(&self) -> Option<u64> {
+        self.target_info
+            .iter()
+            .find(|pair| pair.id == MSV_AV_TIMESTAMP)
+            .and_then(NtlmsspAvPair::timestamp)
+    }
+}
+
+/// Kind of challenge response an AUTHENTICATE carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NtlmResponseVersion {
+    /// Null session: no user and no NT response.
+    Anonymous,
+    /// 24-byte NT response, crackable offline.
+    NtlmV1,
+    NtlmV2,
+}
+
+/// AUTHENTICATE_MESSAGE, sent by the client.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NtlmsspAuthenticate<'a> {
+    pub flags: u32,
+    pub lm_response: &'a [u8],
+    pub nt_response: &'a [u8],
+    pub domain: String,
+    pub user: String,
+    pub workstation: String,
+    pub encrypted_session_key: &'a [u8],
+    pub version: Option<NtlmsspVersion>,
+}
+
+impl<'a> NtlmsspAuthenticate<'a> {
+    fn parse(message: &'a [u8]) -> Result<Self, NtlmsspError> {
+        // Les clients sans VERSION ni MIC s'arretent apres les drapeaux.
+        validate_ntlmssp_length(message, NTLMSSP_AUTHENTICATE_FIXED_LEN)?;
+        let flags = u32_at(message, 60);
+        let unicode = flags & NTLMSSP_NEGOTIATE_UNICODE != 0;
+        let string = |at: usize, field: &'static str| {
+            extract_ntlmssp_string(extract_ntlmssp_field(message, at, field)?, unicode, field)
+        };
+        Ok(NtlmsspAuthenticate {
+            flags,
+            lm_response: extract_ntlmssp_field(message, 12, "LM response")?,
+            nt_response: extract_ntlmssp_field(message, 20, "NT response")?,
+            domain: string(28, "domain")?,
+            user: string(36, "user")?,
+            workstation: string(44, "workstation")?,
+            encrypted_session_key: extract_ntlmssp_field(message, 52, "session key")?,
+            version: NtlmsspVersion::parse(message, flags, NTLMSSP_AUTHENTICATE_FIXED_LEN),
+        })
+    }
+
+    pub fn response_version(&self) -> NtlmResponseVersion {
+        match self.nt_response.len() {
+            0 => NtlmResponseVersion::Anonymous,
+            NTLMV1_RESPONSE_LEN => NtlmResponseVersion::NtlmV1,
+            _ => NtlmResponseVersion::NtlmV2,
+        }
+    }
+
+    /// `DOMAIN\user`, or the bare user name when no domain is given.
+    pub fn account(&self) -> String {
+        if self.domain.is_empty() {
+            self.user.clone()
+        } else {
+            format!("{}\\{}", self.domain, self.user)
+        }
+    }
+
+    /// AV_PAIRs the client echoes in its NTLMv2 response (target name,
+    /// channel bindings, flags); empty for NTLMv1.
+    pub fn ntlmv2_target_info(&self) -> Result<Vec<NtlmsspAvPair<'a>>, NtlmsspError> {
+        match self.response_version() {
+            NtlmResponseVersion::NtlmV2 => self
+                .nt_response
+                .get(NTLMV2_RESPONSE_AV_PAIRS_OFFSET..)
+                .map_or(Err(NtlmsspError::TruncatedAvPair), parse_av_pairs),
+            _ => Ok(Vec::new()),
+        }
+    }
+
+    /// Service principal the client meant to authenticate to
+    /// (`cifs/fileserver`), from MsvAvTargetName.
+    pub fn target_name(&self) -> Option<String> {
+        self.ntlmv2_target_info()
+            .ok()?
+            .iter()
+            .find(|pair| pair.id == MSV_AV_TARGET_NAME)
+            .and_then(NtlmsspAvPair::string)
+    }
+
+    /// Whether MsvAvFlags announces a message integrity code, that is a
+    /// 16-byte MIC right after the VERSION structure.
+    pub fn has_mic(&self) -> bool {
+        self.ntlmv2_target_info().is_ok_and(|pairs| {
+            pairs.iter().any(|pair| {
+                pair.id == MSV_AV_FLAGS
+                    && pair.value.first_chunk::<4>().is_some_and(|flags| {
+                        u32::from_le_bytes(*flags) & MSV_AV_FLAG_MIC_PRESENT != 0
+                    })
+            })
+        })
+    }
+
+    /// MIC of `message` (the bytes this AUTHENTICATE was parsed from),
+    /// when [`has_mic`](Self::has_mic) holds.
+    pub fn mic(&self, message: &'a [u8]) -> Option<&'a [u8]> {
+        if !self.has_mic() || self.version.is_none() {
+            return None;
+        }
+        message.get(NTLMSSP_MIC_OFFSET..NTLMSSP_MIC_OFFSET + NTLMSSP_MIC_LEN)
+    }
+}
+
+/// Attribute-value pair of the target information (MS-NLMP §2.2.2.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NtlmsspAvPair<'a> {
+    pub id: u16,
+    pub value: &'a [u8],
+}
+
+impl NtlmsspAvPair<'_> {
+    pub fn name(&self) -> &'static str {
+        ntlmssp_av_pair_name(self.id)
+    }
+
+    /// Value of a name pair, always UTF-16LE.
+    pub fn string(&self) -> Option<String> {
+        match self.id {
+            MSV_AV_NB_COMPUTER_NAME
+            | MSV_AV_NB_DOMAIN_NAME
+            | MSV_AV_DNS_COMPUTER_NAME
+            | MSV_AV_DNS_DOMAIN_NAME
+            | MSV_AV_DNS_TREE_NAME
+            | MSV_AV_TARGET_NAME => extract_ntlmssp_string(self.value, true, "AV_PAIR").ok(),
+            _ => None,
+        }
+    }
+
+    pub fn timestamp(&self) -> Option<u64> {
+        if self.id != MSV_AV_TIMESTAMP {
+            return None;
+        }
+        self.value
+            .first_chunk::<8>()
+            .map(|time| u64::from_le_bytes(*time))
+    }
+}
+
+/// Liste d'AV_PAIR jusqu'a MsvAvEOL ; une liste sans terminateur qui
+/// s'arrete proprement en fin de tampon est admise.
+fn parse_av_pairs(mut bytes: &[u8]) -> Result<Vec<NtlmsspAvPair<'_>>, NtlmsspError> {
+    let mut pairs = Vec::new();
+    while !bytes.is_empty() {
+        let header = bytes
+            .get(..NTLMSSP_AV_PAIR_HEADER_LEN)
+            .ok_or(NtlmsspError::TruncatedAvPair)?;
+        let id = u16::from_le_bytes([header[0], header[1]]);
+        let length = usize::from(u16::from_le_bytes([header[2], header[3]]));
+        if id == MSV_AV_EOL {
+            break;
+        }
+        let end = NTLMSSP_AV_PAIR_HEADER_LEN + length;
+        let value = bytes
+            .get(NTLMSSP_AV_PAIR_HEADER_LEN..end)
+            .ok_or(NtlmsspError::TruncatedAvPair)?;
+        pairs.push(NtlmsspAvPair { id, value });
+        bytes = &bytes[end..];
+    }
+    Ok(pairs)
+}
+
+fn u32_at(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// NEGOTIATE d'un client Windows 10 (build 19041) : drapeaux
+    /// 0xe2088297, sans domaine ni poste fournis.
+    const NEGOTIATE_HEX: &str = concat!(
+        "4e544c4d5353500001000000978208e200000000000000000000000000000000",
+        "0a00614a0000000f"
+    );
+
+    fn utf16(text: &str) -> Vec<u8> {
+        text.encode_utf16().flat_map(u16::to_le_bytes).collect()
+    }
+
+    fn av_pair(id: u16, value: &[u8]) -> Vec<u8> {
+        let mut pair = id.to_le_bytes().to_vec();
+        pair.extend((value.len() as u16).to_le_bytes());
+        pair.extend_from_slice(value);
+        pair
+    }
+
+    /// Descripteur de champ : longueur, longueur maximale, offset.
+    fn field(length: usize, offset: usize) -> Vec<u8> {
+        let mut field = (length as u16).to_le_bytes().to_vec();
+        field.extend((length as u16).to_le_bytes());
+        field.extend((offset as u32).to_le_bytes());
+        field
+    }
+
+    #[test]
+    fn negotiate_without_supplied_names() {
+        let bytes = hex::decode(NEGOTIATE_HEX).expect("invalid test hex fixture");
+        let NtlmsspMessage::Negotiate(negotiate) =
+            NtlmsspMessage::try_from(bytes.as_slice()).expect("NEGOTIATE valide")
+        else {
+            panic!("attendu un NEGOTIATE");
+        };
+        assert_eq!(negotiate.flags, 0xe208_8297);
+        assert_eq!(negotiate.domain, None);
+        assert_eq!(
+            negotiate.version.map(|version| version.to_string()),
+            Some("10.0.19041".to_string())
+        );
+    }
+
+    #[test]
+    fn challenge_names_the_server() {
+        let target = utf16("CORP");
+        let mut info = av_pair(MSV_AV_NB_DOMAIN_NAME, &target);
+        info.extend(av_pair(
+            MSV_AV_DNS_COMPUTER_NAME,
+            &utf16("dc01.corp.example"),
+        ));
+        info.extend(av_pair(
+            MSV_AV_TIMESTAMP,
+            &0x01DB_4000_0000_0000u64.to_le_bytes(),
+        ));
+        info.extend(av_pair(MSV_AV_EOL, &[]));
+
+        let mut message = b"NTLMSSP\0".to_vec();
+        message.extend(NTLMSSP_CHALLENGE.to_le_bytes());
+        message.extend(field(target.len(), 56));
+        message.extend(0xE28A_8215u32.to_le_bytes());
+        message.extend([1, 2, 3, 4, 5, 6, 7, 8]);
+        message.extend([0; 8]);
+        message.extend(field(info.len(), 56 + target.len()));
+        message.extend([10, 0, 0x61, 0x4A, 0, 0, 0, 15]);
+        message.extend(&target);
+        message.extend(&info);
+
+        let NtlmsspMessage::Challenge(challenge) =
+            NtlmsspMessage::try_from(message.as_slice()).expect("CHALLENGE valide")
+        else {
+            panic!("attendu un CHALLENGE");
+        };
+        assert_eq!(challenge.target_name, "CORP");
+        assert_eq!(challenge.server_challenge, [1, 2, 3, 4, 5, 6, 7, 8]);
+        assert_eq!(challenge.nb_domain_name().as_deref(), Some("CORP"));
+        assert_eq!(
+            challenge.dns_computer_name().as_deref(),
+            Some("dc01.corp.example")
+        );
+        assert_eq!(challenge.timestamp(), Some(0x01DB_4000_0000_0000));
+        assert_eq!(challenge.version.map(|version| version.build), Some(19041));
+    }
+
+    #[test]
+    fn authenticate_versions_and_malformed_fields() {
+        let domain = utf16("CORP");
+        let user = utf16("alice");
+        let workstation = utf16("WS01");
+        let build = |nt_response: &[u8]| {
+            let mut payload = Vec::new();
+            let mut offsets = Vec::new();
+            for part in [&[][..], nt_response, &domain, &user, &workstation, &[]] {
+                offsets.push((part.len(), 64 + payload.len()));
+                payload.extend_from_slice(part);
+            }
+            let mut message = b"NTLMSSP\0".to_vec();
+            message.extend(NTLMSSP_AUTHENTICATE.to_le_bytes());
+            for (length, offset) in offsets {
+                message.extend(field(length, offset));
+            }
+            message.extend(NTLMSSP_NEGOTIATE_UNICODE.to_le_bytes());
+            message.extend(payload);
+            message
+        };
+
+        let message = build(&[0xAA; 24]);
+        let NtlmsspMessage::Authenticate(authenticate) =
+            NtlmsspMessage::try_from(message.as_slice()).expect("AUTHENTICATE valide")
+        else {
+            panic!("attendu un AUTHENTICATE");
+        };
+        assert_eq!(authenticate.account(), "CORP\\alice");
+        assert_eq!(authenticate.workstation, "WS01");
+        assert_eq!(authenticate.response_version(), NtlmResponseVersion::NtlmV1);
+        assert!(!authenticate.has_mic());
+
+        // NTLMv2 : NTProofStr, en-tete du client challenge, AV_PAIR.
+        let mut nt_response = vec![0xBB; NTLMV2_RESPONSE_AV_PAIRS_OFFSET];
+        nt_response.extend(av_pair(
+            MSV_AV_FLAGS,
+            &MSV_AV_FLAG_MIC_PRESENT.to_le_bytes(),
+        ));
+        nt_response.extend(av_pair(MSV_AV_TARGET_NAME, &utf16("cifs/fs01")));
+        nt_response.extend(av_pair(MSV_AV_EOL, &[]));
+        let message = build(&nt_response);
+        let NtlmsspMessage::Authenticate(authenticate) =
+            NtlmsspMessage::try_from(message.as_slice()).expect("AUTHENTICATE valide")
+        else {
+            panic!("attendu un AUTHENTICATE");
+        };
+        assert_eq!(authenticate.response_version(), NtlmResponseVersion::NtlmV2);
+        assert_eq!(authenticate.target_name().as_deref(), Some("cifs/fs01"));
+        assert!(authenticate.has_mic());
+
+        // Offset du nom d'utilisateur au-dela du message.
+        let mut message = build(&[0xAA; 24]);
+        message[40] = 0xF0;
+        assert_eq!(
+            NtlmsspMessage::try_from(message.as_slice()),
+            Err(NtlmsspError::InvalidField {
+                field: "user",
+                offset: 0xF0,
+                length: 10
+            })
+        );
+        assert_eq!(
+            NtlmsspMessage::try_from(&b"NTLMSSP\0\x04\x00\x00\x00"[..]),
+            Err(NtlmsspError::UnknownMessageType(4))
+        );
+    }
+}
@@ -0,0 +1,407 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Secure conversation chunks and message reassembly.
+//!
+//! After the secure channel id, `OPN` chunks carry the asymmetric security
+//! header (policy URI and certificates) and `MSG`/`CLO` chunks the token id
+//! of the symmetric header. The sequence header that follows is only
+//! readable in clear when the channel uses the None policy: with Sign or
+//! SignAndEncrypt the body is signed, and usually encrypted, with keys this
+//! crate does not have.
+//!
+//! A service message larger than the negotiated buffer is split into `C`
+//! (intermediate) chunks ended by an `F` (final) chunk, or abandoned by an
+//! `A` (abort) chunk. [`OpcuaMessageAssembler`] puts the bodies back
+//! together per channel and request id.
+
+use std::collections::VecDeque;
+
+use super::{OpcuaChunk, OpcuaChunkType, OpcuaMessageType, OpcuaPayload, service::OpcuaService};
+use crate::{
+    checks::application::opcua::{
+        OPCUA_MAX_PENDING_MESSAGES, OPCUA_MAX_SECURED_CHANNELS, OPCUA_SECURITY_POLICY_NONE,
+        extract_opcua_byte_string, extract_opcua_string, extract_opcua_u32,
+        validate_opcua_message_size,
+    },
+    errors::application::opcua::OpcuaDecodeError,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpcuaSecurityHeader<'a> {
+    /// Header of `OPN` chunks.
+    Asymmetric {
+        security_policy_uri: Option<&'a str>,
+        sender_certificate: Option<&'a [u8]>,
+        receiver_certificate_thumbprint: Option<&'a [u8]>,
+    },
+    /// Header of `MSG` and `CLO` chunks: token issued by OpenSecureChannel.
+    Symmetric { token_id: u32 },
+}
+
+impl OpcuaSecurityHeader<'_> {
+    /// `Some(true)` for an `OPN` chunk under the None policy, `None` for a
+    /// symmetric header, whose policy is only known from the `OPN`.
+    pub fn is_policy_none(&self) -> Option<bool> {
+        match self {
+            OpcuaSecurityHeader::Asymmetric {
+                security_policy_uri,
+                ..
+            } => Some(*security_policy_uri == Some(OPCUA_SECURITY_POLICY_NONE)),
+            OpcuaSecurityHeader::Symmetric { .. } => None,
+        }
+    }
+}
+
+/// Secure conversation chunk, read as if unencrypted.
+///
+/// ```mermaid
+/// ---
+/// title: OpcuaSecureChunk (MSG)
+/// ---
+/// packet-beta
+/// 0-31: "Secure Channel ID u32 LE"
+/// 32-63: "Token ID u32 LE"
+/// 64-95: "Sequence Number u32 LE"
+/// 96-127: "Request ID u32 LE"
+/// 128-159: "Body variable"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpcuaSecureChunk<'a> {
+    pub message_type: OpcuaMessageType,
+    pub chunk_type: OpcuaChunkType,
+    pub secure_channel_id: u32,
+    pub security: OpcuaSecurityHeader<'a>,
+    /// Increases by one per chunk sent on the channel.
+    pub sequence_number: u32,
+    /// Shared by all chunks of a message and by the response chunks.
+    pub request_id: u32,
+    /// Part of the service message carried by this chunk.
+    pub body: &'a [u8],
+}
+
+impl<'a> OpcuaChunk<'a> {
+    /// Security and sequence headers of an `OPN`, `MSG` or `CLO` chunk,
+    /// `None` for the connection protocol messages (`HEL`, `ACK`, `ERR`,
+    /// `RHE`).
+    pub fn secure_chunk(&self) -> Option<Result<OpcuaSecureChunk<'a>, OpcuaDecodeError>> {
+        match self.payload {
+            OpcuaPayload::SecureConversation(ref conversation) => Some(parse_secure_chunk(
+                self.header.message_type,
+                self.header.chunk_type,
+                conversation.secure_channel_id,
+                conversation.data,
+            )),
+            OpcuaPayload::Partial(_) if is_secure_conversation(self.header.message_type) => {
+                Some(Err(OpcuaDecodeError::IncompleteChunk))
+            }
+            _ => None,
+        }
+    }
+}
+
+fn is_secure_conversation(message_type: OpcuaMessageType) -> bool {
+    matches!(
+        message_type,
+        OpcuaMessageType::OpenSecureChannel
+            | OpcuaMessageType::Message
+            | OpcuaMessageType::CloseSecureChannel
+    )
+}
+
+fn parse_secure_chunk(
+    message_type: OpcuaMessageType,
+    chunk_type: OpcuaChunkType,
+    secure_channel_id: u32,
+    data: &[u8],
+) -> Result<OpcuaSecureChunk<'_>, OpcuaDecodeError> {
+    let (security, rest) = if message_type == OpcuaMessageType::OpenSecureChannel {
+        let (security_policy_uri, rest) = extract_opcua_string(data, "security policy uri")?;
+        let (sender_certificate, rest) = extract_opcua_byte_string(rest, "sender certificate")?;
+        let (receiver_certificate_thumbprint, rest) =
+            extract_opcua_byte_string(rest, "receiver certificate thumbprint")?;
+        (
+            OpcuaSecurityHeader::Asymmetric {
+                security_policy_uri,
+                sender_certificate,
+                receiver_certificate_thumbprint,
+            },
+            rest,
+        )
+    } else {
+        let (token_id, rest) = extract_opcua_u32(data, "token id")?;
+        (OpcuaSecurityHeader::Symmetric { token_id }, rest)
+    };
+    let (sequence_number, rest) = extract_opcua_u32(rest, "sequence number")?;
+    let (request_id, body) = extract_opcua_u32(rest, "request id")?;
+    Ok(OpcuaSecureChunk {
+        message_type,
+        chunk_type,
+        secure_channel_id,
+        security,
+        sequence_number,
+        request_id,
+        body,
+    })
+}
+
+/// Service message rebuilt from its chunks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaMessage {
+    pub message_type: OpcuaMessageType,
+    pub secure_channel_id: u32,
+    pub request_id: u32,
+    /// Sequence number of the first chunk.
+    pub sequence_number: u32,
+    pub chunk_count: usize,
+    pub body: Vec<u8>,
+}
+
+impl OpcuaMessage {
+    pub fn service(&self) -> Result<OpcuaService<'_>, OpcuaDecodeError> {
+        OpcuaService::parse(&self.body)
+    }
+}
+
+/// Reassembly of the service messages of one TCP connection.
+///
+/// Chunks are pushed in stream order; complete messages come out of
+/// [`next_message`](Self::next_message) in the order their final chunk
+/// arrived. Chunks of channels whose `OPN` announced a policy other than
+/// None are skipped. At most [`OPCUA_MAX_PENDING_MESSAGES`] messages are
+/// reassembled at once: the oldest is dropped to make room.
+#[derive(Debug, Default)]
+pub struct OpcuaMessageAssembler {
+    pending: VecDeque<OpcuaMessage>,
+    complete: VecDeque<OpcuaMessage>,
+    secured_channels: VecDeque<u32>,
+}
+
+impl OpcuaMessageAssembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a chunk. Connection protocol chunks are ignored. A partial
+    /// chunk is an error, as is a message growing past the size limit,
+    /// which is then dropped.
+    pub fn push(&mut self, chunk: &OpcuaChunk<'_>) -> Result<(), OpcuaDecodeError> {
+        let Some(secure_chunk) = chunk.secure_chunk() else {
+            return Ok(());
+        };
+        let secure_chunk = secure_chunk?;
+        let channel = secure_chunk.secure_channel_id;
+
+        if let Some(policy_none) = secure_chunk.security.is_policy_none() {
+            // Le premier OPN porte le canal 0 : seul l'identifiant attribue
+            // par le serveur est retenu.
+            self.secured_channels.retain(|id| *id != channel);
+            if !policy_none {
+                if channel != 0 {
+                    if self.secured_channels.len() >= OPCUA_MAX_SECURED_CHANNELS {
+                        self.secured_channels.pop_front();
+                    }
+                    self.secured_channels.push_back(channel);
+                }
+                return Ok(());
+            }
+        } else if self.is_secured(channel) {
+            return Ok(());
+        }
+
+        let key = (channel, secure_chunk.request_id);
+        let position = self
+            .pending
+            .iter()
+            .position(|message| (message.secure_channel_id, message.request_id) == key);
+
+        if secure_chunk.chunk_type == OpcuaChunkType::Abort {
+            if let Some(position) = position {
+                self.pending.remove(position);
+            }
+            return Ok(());
+        }
+
+        let mut message = match position.and_then(|position| self.pending.remove(position)) {
+            Some(message) => message,
+            None => OpcuaMessage {
+                message_type: secure_chunk.message_type,
+                secure_channel_id: channel,
+                request_id: secure_chunk.request_id,
+                sequence_number: secure_chunk.sequence_number,
+                chunk_count: 0,
+                body: Vec::new(),
+            },
+        };
+        validate_opcua_message_size(message.body.len() + secure_chunk.body.len())?;
+        message.body.extend_from_slice(secure_chunk.body);
+        message.chunk_count += 1;
+
+        if secure_chunk.chunk_type == OpcuaChunkType::Final {
+            self.complete.push_back(message);
+        } else {
+            if self.pending.len() >= OPCUA_MAX_PENDING_MESSAGES {
+                self.pending.pop_front();
+            }
+            self.pending.push_back(message);
+        }
+        Ok(())
+    }
+
+    pub fn next_message(&mut self) -> Option<OpcuaMessage> {
+        self.complete.pop_front()
+    }
+
+    /// Whether the channel was opened with a policy other than None.
+    pub fn is_secured(&self, secure_channel_id: u32) -> bool {
+        self.secured_channels.contains(&secure_channel_id)
+    }
+
+    /// Messages still waiting for their final chunk.
+    pub fn pending_messages(&self) -> usize {
+        self.pending.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::application::protocols::opcua::OpcuaPacket;
+
+    /// Chunk MSG synthetique : canal 7, jeton 1, requete `request_id`.
+    fn msg_chunk(chunk_type: u8, sequence_number: u32, request_id: u32, body: &[u8]) -> Vec<u8> {
+        let mut chunk = b"MSG".to_vec();
+        chunk.push(chunk_type);
+        chunk.extend_from_slice(&(24 + body.len() as u32).to_le_bytes());
+        chunk.extend_from_slice(&7u32.to_le_bytes());
+        chunk.extend_from_slice(&1u32.to_le_bytes());
+        chunk.extend_from_slice(&sequence_number.to_le_bytes());
+        chunk.extend_from_slice(&request_id.to_le_bytes());
+        chunk.extend_from_slice(body);
+        chunk
+    }
+
+    fn opn_chunk(channel: u32, policy: &str) -> Vec<u8> {
+        let mut chunk = b"OPNF".to_vec();
+        chunk.extend_from_slice(&(32 + policy.len() as u32).to_le_bytes());
+        chunk.extend_from_slice(&channel.to_le_bytes());
+        chunk.extend_from_slice(&(policy.len() as i32).to_le_bytes());
+        chunk.extend_from_slice(policy.as_bytes());
+        chunk.extend_from_slice(&(-1i32).to_le_bytes());
+        chunk.extend_from_slice(&(-1i32).to_le_bytes());
+        chunk.extend_from_slice(&1u32.to_le_bytes());
+        chunk.extend_from_slice(&1u32.to_le_bytes());
+        chunk
+    }
+
+    fn push_all(assembler: &mut OpcuaMessageAssembler, bytes: &[u8]) {
+        let packet = OpcuaPacket::try_from(bytes).expect("valid OPC UA packet");
+        for chunk in &packet.chunks {
+            assembler.push(chunk).expect("valid chunk");
+        }
+    }
+
+    #[test]
+    fn decode_asymmetric_security_header() {
+        let bytes = opn_chunk(0, OPCUA_SECURITY_POLICY_NONE);
+        let packet = OpcuaPacket::try_from(bytes.as_slice()).expect("valid OPC UA packet");
+        let chunk = packet.chunks[0]
+            .secure_chunk()
+            .expect("secure conversation chunk")
+            .expect("valid secure chunk");
+        assert_eq!(chunk.security.is_policy_none(), Some(true));
+        assert_eq!((chunk.sequence_number, chunk.request_id), (1, 1));
+        assert!(chunk.body.is_empty());
+    }
+
+    #[test]
+    fn reassemble_intermediate_and_final_chunks() {
+        let mut bytes = msg_chunk(b'C', 10, 5, &[0x01, 0x02]);
+        bytes.extend(msg_chunk(b'F', 11, 6, &[0xAA]));
+        bytes.extend(msg_chunk(b'C', 12, 5, &[0x03]));
+        bytes.extend(msg_chunk(b'F', 13, 5, &[0x04]));
+
+        let mut assembler = OpcuaMessageAssembler::new();
+        push_all(&mut assembler, &bytes);
+
+        let single = assembler.next_message().expect("single-chunk message");
+        assert_eq!(
+            (single.request_id, single.body.as_slice()),
+            (6, &[0xAA][..])
+        );
+        let message = assembler.next_message().expect("reassembled message");
+        assert_eq!(message.request_id, 5);
+        assert_eq!(message.sequence_number, 10);
+        assert_eq!(message.chunk_count, 3);
+        assert_eq!(message.body, vec![0x01, 0x02, 0x03, 0x04]);
+        assert_eq!(assembler.next_message(), None);
+        assert_eq!(assembler.pending_messages(), 0);
+    }
+
+    #[test]
+    fn abort_chunk_discards_pending_message() {
+        let mut bytes = msg_chunk(b'C', 1, 9, &[0x01]);
+        bytes.extend(msg_chunk(b'A', 2, 9, &[]));
+        bytes.extend(msg_chunk(b'F', 3, 9, &[0x02]));
+
+        let mut assembler = OpcuaMessageAssembler::new();
+        push_all(&mut assembler, &bytes);
+        let message = assembler.next_message().expect("message after abort");
+        assert_eq!(message.body, vec![0x02]);
+        assert_eq!(message.chunk_count, 1);
+    }
+
+    #[test]
+    fn skip_channels_opened_with_a_security_policy() {
+        let mut bytes = opn_chunk(
+            7,
+            "http://opcfoundation.org/UA/SecurityPolicy#Basic256Sha256",
+        );
+        bytes.extend(msg_chunk(b'F', 2, 2, &[0xFF]));
+
+        let mut assembler = OpcuaMessageAssembler::new();
+        push_all(&mut assembler, &bytes);
+        assert!(assembler.is_secured(7));
+        assert_eq!(assembler.next_message(), None);
+
+        // Le canal est rouvert sans securite : ses MSG redeviennent lisibles.
+        let mut bytes = opn_chunk(7, OPCUA_SECURITY_POLICY_NONE);
+        bytes.extend(msg_chunk(b'F', 3, 3, &[0x01]));
+        push_all(&mut assembler, &bytes);
+        assert!(!assembler.is_secured(7));
+        assert_eq!(
+            assembler.next_message().map(|m| m.message_type),
+            Some(OpcuaMessageType::OpenSecureChannel)
+        );
+        assert_eq!(assembler.next_message().map(|m| m.body), Some(vec![0x01]));
+    }
+
+    #[test]
+    fn partial_chunk_is_an_error() {
+        let bytes = msg_chunk(b'F', 1, 1, &[0x01, 0x02]);
+        let packet = OpcuaPacket::try_from(&bytes[..bytes.len() - 1]).expect("valid OPC UA packet");
+        let mut assembler = OpcuaMessageAssembler::new();
+        assert_eq!(
+            assembler.push(&packet.chunks[0]),
+            Err(OpcuaDecodeError::IncompleteChunk)
+        );
+    }
+
+    #[test]
+    fn pending_messages_are_bounded() {
+        let mut assembler = OpcuaMessageAssembler::new();
+        for request_id in 0..=OPCUA_MAX_PENDING_MESSAGES as u32 {
+            push_all(
+                &mut assembler,
+                &msg_chunk(b'C', request_id, request_id, &[0x00]),
+            );
+        }
+        assert_eq!(assembler.pending_messages(), OPCUA_MAX_PENDING_MESSAGES);
+
+        // La requete 0, la plus ancienne, a ete abandonnee.
+        push_all(&mut assembler, &msg_chunk(b'F', 100, 0, &[0x01]));
+        assert_eq!(assembler.next_message().map(|m| m.chunk_count), Some(1));
+    }
+}
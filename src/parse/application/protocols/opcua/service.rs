@@ -0,0 +1,1362 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Binary-encoded service requests and responses (Part 4, Part 6 5.2).
+//!
+//! A message body starts with the NodeId of the `DefaultBinary` encoding of
+//! the service, then the request or response header and the service
+//! parameters. [`OpcuaService::parse`] decodes the session, browse,
+//! attribute, method and subscription services used to follow or audit a
+//! plant floor client; the other services keep their raw body.
+
+use super::types::{
+    OpcuaDataValue, OpcuaDateTime, OpcuaDiagnosticInfo, OpcuaExpandedNodeId, OpcuaExtensionObject,
+    OpcuaLocalizedText, OpcuaNodeId, OpcuaQualifiedName, OpcuaVariant, decode_opcua_array,
+};
+use crate::{
+    checks::application::opcua::{
+        OPCUA_ACTIVATE_SESSION_REQUEST, OPCUA_ACTIVATE_SESSION_RESPONSE,
+        OPCUA_ANONYMOUS_IDENTITY_TOKEN, OPCUA_BROWSE_REQUEST, OPCUA_BROWSE_RESPONSE,
+        OPCUA_CALL_REQUEST, OPCUA_CALL_RESPONSE, OPCUA_CLOSE_SESSION_REQUEST,
+        OPCUA_CLOSE_SESSION_RESPONSE, OPCUA_CREATE_SESSION_REQUEST, OPCUA_CREATE_SESSION_RESPONSE,
+        OPCUA_CREATE_SUBSCRIPTION_REQUEST, OPCUA_CREATE_SUBSCRIPTION_RESPONSE,
+        OPCUA_DATA_CHANGE_NOTIFICATION, OPCUA_ISSUED_IDENTITY_TOKEN,
+        OPCUA_OPEN_SECURE_CHANNEL_REQUEST, OPCUA_OPEN_SECURE_CHANNEL_RESPONSE,
+        OPCUA_PUBLISH_REQUEST, OPCUA_PUBLISH_RESPONSE, OPCUA_READ_REQUEST, OPCUA_READ_RESPONSE,
+        OPCUA_SERVICE_FAULT, OPCUA_USER_NAME_IDENTITY_TOKEN, OPCUA_WRITE_REQUEST,
+        OPCUA_WRITE_RESPONSE, OPCUA_X509_IDENTITY_TOKEN, extract_opcua_bool,
+        extract_opcua_byte_string, extract_opcua_f64, extract_opcua_i64, extract_opcua_string,
+        extract_opcua_u8, extract_opcua_u32, opcua_attribute_name, opcua_security_mode_name,
+        opcua_service_name, opcua_status_code_name,
+    },
+    errors::application::opcua::OpcuaDecodeError,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaRequestHeader<'a> {
+    /// Session authentication token, null before ActivateSession.
+    pub authentication_token: OpcuaNodeId<'a>,
+    pub timestamp: OpcuaDateTime,
+    /// Echoed by the response: pairs a request with its response.
+    pub request_handle: u32,
+    pub return_diagnostics: u32,
+    pub audit_entry_id: Option<&'a str>,
+    /// Milliseconds, 0 for no timeout.
+    pub timeout_hint: u32,
+    pub additional_header: OpcuaExtensionObject<'a>,
+}
+
+impl<'a> OpcuaRequestHeader<'a> {
+    pub fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        let (authentication_token, rest) = OpcuaNodeId::decode(buf)?;
+        let (timestamp, rest) = extract_opcua_i64(rest, "request timestamp")?;
+        let (request_handle, rest) = extract_opcua_u32(rest, "request handle")?;
+        let (return_diagnostics, rest) = extract_opcua_u32(rest, "return diagnostics")?;
+        let (audit_entry_id, rest) = extract_opcua_string(rest, "audit entry id")?;
+        let (timeout_hint, rest) = extract_opcua_u32(rest, "timeout hint")?;
+        let (additional_header, rest) = OpcuaExtensionObject::decode(rest)?;
+        Ok((
+            OpcuaRequestHeader {
+                authentication_token,
+                timestamp: OpcuaDateTime(timestamp),
+                request_handle,
+                return_diagnostics,
+                audit_entry_id,
+                timeout_hint,
+                additional_header,
+            },
+            rest,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaResponseHeader<'a> {
+    pub timestamp: OpcuaDateTime,
+    pub request_handle: u32,
+    /// StatusCode of the whole service call.
+    pub service_result: u32,
+    pub service_diagnostics: OpcuaDiagnosticInfo<'a>,
+    pub string_table: Vec<Option<&'a str>>,
+    pub additional_header: OpcuaExtensionObject<'a>,
+}
+
+impl<'a> OpcuaResponseHeader<'a> {
+    pub fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        let (timestamp, rest) = extract_opcua_i64(buf, "response timestamp")?;
+        let (request_handle, rest) = extract_opcua_u32(rest, "request handle")?;
+        let (service_result, rest) = extract_opcua_u32(rest, "service result")?;
+        let (service_diagnostics, rest) = OpcuaDiagnosticInfo::decode(rest)?;
+        let (string_table, rest) = decode_strings(rest, "string table")?;
+        let (additional_header, rest) = OpcuaExtensionObject::decode(rest)?;
+        Ok((
+            OpcuaResponseHeader {
+                timestamp: OpcuaDateTime(timestamp),
+                request_handle,
+                service_result,
+                service_diagnostics,
+                string_table,
+                additional_header,
+            },
+            rest,
+        ))
+    }
+
+    pub fn is_good(&self) -> bool {
+        self.service_result >> 30 == 0
+    }
+
+    pub fn service_result_name(&self) -> &'static str {
+        opcua_status_code_name(self.service_result)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaOpenSecureChannelRequest<'a> {
+    pub header: OpcuaRequestHeader<'a>,
+    pub client_protocol_version: u32,
+    /// 0 Issue, 1 Renew.
+    pub request_type: u32,
+    pub security_mode: u32,
+    pub client_nonce: Option<&'a [u8]>,
+    /// Milliseconds.
+    pub requested_lifetime: u32,
+}
+
+impl OpcuaOpenSecureChannelRequest<'_> {
+    pub fn security_mode_name(&self) -> &'static str {
+        opcua_security_mode_name(self.security_mode)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaOpenSecureChannelResponse<'a> {
+    pub header: OpcuaResponseHeader<'a>,
+    pub server_protocol_version: u32,
+    pub channel_id: u32,
+    pub token_id: u32,
+    pub created_at: OpcuaDateTime,
+    pub revised_lifetime: u32,
+    pub server_nonce: Option<&'a [u8]>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaApplicationDescription<'a> {
+    pub application_uri: Option<&'a str>,
+    pub product_uri: Option<&'a str>,
+    pub application_name: OpcuaLocalizedText<'a>,
+    /// 0 Server, 1 Client, 2 ClientAndServer, 3 DiscoveryServer.
+    pub application_type: u32,
+    pub gateway_server_uri: Option<&'a str>,
+    pub discovery_profile_uri: Option<&'a str>,
+    pub discovery_urls: Vec<Option<&'a str>>,
+}
+
+impl<'a> OpcuaApplicationDescription<'a> {
+    fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        let (application_uri, rest) = extract_opcua_string(buf, "application uri")?;
+        let (product_uri, rest) = extract_opcua_string(rest, "product uri")?;
+        let (application_name, rest) = OpcuaLocalizedText::decode(rest)?;
+        let (application_type, rest) = extract_opcua_u32(rest, "application type")?;
+        let (gateway_server_uri, rest) = extract_opcua_string(rest, "gateway server uri")?;
+        let (discovery_profile_uri, rest) = extract_opcua_string(rest, "discovery profile uri")?;
+        let (discovery_urls, rest) = decode_strings(rest, "discovery urls")?;
+        Ok((
+            OpcuaApplicationDescription {
+                application_uri,
+                product_uri,
+                application_name,
+                application_type,
+                gateway_server_uri,
+                discovery_profile_uri,
+                discovery_urls,
+            },
+            rest,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaUserTokenPolicy<'a> {
+    pub policy_id: Option<&'a str>,
+    /// 0 Anonymous, 1 UserName, 2 Certificate, 3 IssuedToken.
+    pub token_type: u32,
+    pub issued_token_type: Option<&'a str>,
+    pub issuer_endpoint_url: Option<&'a str>,
+    /// Policy protecting the token; empty means the endpoint policy.
+    pub security_policy_uri: Option<&'a str>,
+}
+
+impl<'a> OpcuaUserTokenPolicy<'a> {
+    fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        let (policy_id, rest) = extract_opcua_string(buf, "policy id")?;
+        let (token_type, rest) = extract_opcua_u32(rest, "token type")?;
+        let (issued_token_type, rest) = extract_opcua_string(rest, "issued token type")?;
+        let (issuer_endpoint_url, rest) = extract_opcua_string(rest, "issuer endpoint url")?;
+        let (security_policy_uri, rest) = extract_opcua_string(rest, "security policy uri")?;
+        Ok((
+            OpcuaUserTokenPolicy {
+                policy_id,
+                token_type,
+                issued_token_type,
+                issuer_endpoint_url,
+                security_policy_uri,
+            },
+            rest,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaEndpointDescription<'a> {
+    pub endpoint_url: Option<&'a str>,
+    pub server: OpcuaApplicationDescription<'a>,
+    pub server_certificate: Option<&'a [u8]>,
+    pub security_mode: u32,
+    pub security_policy_uri: Option<&'a str>,
+    pub user_identity_tokens: Vec<OpcuaUserTokenPolicy<'a>>,
+    pub transport_profile_uri: Option<&'a str>,
+    pub security_level: u8,
+}
+
+impl<'a> OpcuaEndpointDescription<'a> {
+    fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        let (endpoint_url, rest) = extract_opcua_string(buf, "endpoint url")?;
+        let (server, rest) = OpcuaApplicationDescription::decode(rest)?;
+        let (server_certificate, rest) = extract_opcua_byte_string(rest, "server certificate")?;
+        let (security_mode, rest) = extract_opcua_u32(rest, "security mode")?;
+        let (security_policy_uri, rest) = extract_opcua_string(rest, "security policy uri")?;
+        let (user_identity_tokens, rest) = decode_opcua_array(
+            rest,
+            "user identity tokens",
+            20,
+            OpcuaUserTokenPolicy::decode,
+        )?;
+        let (transport_profile_uri, rest) = extract_opcua_string(rest, "transport profile uri")?;
+        let (security_level, rest) = extract_opcua_u8(rest, "security level")?;
+        Ok((
+            OpcuaEndpointDescription {
+                endpoint_url,
+                server,
+                server_certificate,
+                security_mode,
+                security_policy_uri,
+                user_identity_tokens,
+                transport_profile_uri,
+                security_level,
+            },
+            rest,
+        ))
+    }
+
+    pub fn security_mode_name(&self) -> &'static str {
+        opcua_security_mode_name(self.security_mode)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpcuaCreateSessionRequest<'a> {
+    pub header: OpcuaRequestHeader<'a>,
+    pub client_description: OpcuaApplicationDescription<'a>,
+    pub server_uri: Option<&'a str>,
+    pub endpoint_url: Option<&'a str>,
+    pub session_name: Option<&'a str>,
+    pub client_nonce: Option<&'a [u8]>,
+    pub client_certificate: Option<&'a [u8]>,
+    /// Milliseconds.
+    pub requested_session_timeout: f64,
+    pub max_response_message_size: u32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpcuaCreateSessionResponse<'a> {
+    pub header: OpcuaResponseHeader<'a>,
+    pub session_id: OpcuaNodeId<'a>,
+    /// Secret the client puts in every later request header.
+    pub authentication_token: OpcuaNodeId<'a>,
+    pub revised_session_timeout: f64,
+    pub server_nonce: Option<&'a [u8]>,
+    pub server_certificate: Option<&'a [u8]>,
+    pub server_endpoints: Vec<OpcuaEndpointDescription<'a>>,
+    pub server_signature_algorithm: Option<&'a str>,
+    pub max_request_message_size: u32,
+}
+
+/// User identity presented by ActivateSession.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OpcuaUserIdentity<'a> {
+    Anonymous {
+        policy_id: Option<&'a str>,
+    },
+    UserName {
+        policy_id: Option<&'a str>,
+        user_name: Option<&'a str>,
+        password: Option<&'a [u8]>,
+        /// Asymmetric algorithm URI, empty when the password is sent as is.
+        encryption_algorithm: Option<&'a str>,
+    },
+    X509 {
+        policy_id: Option<&'a str>,
+        certificate: Option<&'a [u8]>,
+    },
+    Issued {
+        policy_id: Option<&'a str>,
+        token_data: Option<&'a [u8]>,
+        encryption_algorithm: Option<&'a str>,
+    },
+    Other(OpcuaExtensionObject<'a>),
+}
+
+impl<'a> OpcuaUserIdentity<'a> {
+    fn from_extension_object(token: OpcuaExtensionObject<'a>) -> Result<Self, OpcuaDecodeError> {
+        let type_id = token.type_id.ns0_numeric();
+        let Some(body) = type_id.and_then(|type_id| token.binary_body(type_id)) else {
+            return Ok(OpcuaUserIdentity::Other(token));
+        };
+        let (policy_id, rest) = extract_opcua_string(body, "policy id")?;
+        let identity = match type_id {
+            Some(OPCUA_ANONYMOUS_IDENTITY_TOKEN) => OpcuaUserIdentity::Anonymous { policy_id },
+            Some(OPCUA_USER_NAME_IDENTITY_TOKEN) => {
+                let (user_name, rest) = extract_opcua_string(rest, "user name")?;
+                let (password, rest) = extract_opcua_byte_string(rest, "password")?;
+                let (encryption_algorithm, _) = extract_opcua_string(rest, "encryption algorithm")?;
+                OpcuaUserIdentity::UserName {
+                    policy_id,
+                    user_name,
+                    password,
+                    encryption_algorithm,
+                }
+            }
+            Some(OPCUA_X509_IDENTITY_TOKEN) => {
+                let (certificate, _) = extract_opcua_byte_string(rest, "certificate data")?;
+                OpcuaUserIdentity::X509 {
+                    policy_id,
+                    certificate,
+                }
+            }
+            Some(OPCUA_ISSUED_IDENTITY_TOKEN) => {
+                let (token_data, rest) = extract_opcua_byte_string(rest, "token data")?;
+                let (encryption_algorithm, _) = extract_opcua_string(rest, "encryption algorithm")?;
+                OpcuaUserIdentity::Issued {
+                    policy_id,
+                    token_data,
+                    encryption_algorithm,
+                }
+            }
+            _ => OpcuaUserIdentity::Other(token),
+        };
+        Ok(identity)
+    }
+
+    /// Password of a UserName token sent without encryption.
+    pub fn cleartext_password(&self) -> Option<&'a [u8]> {
+        match self {
+            OpcuaUserIdentity::UserName {
+                password,
+                encryption_algorithm,
+                ..
+            } if encryption_algorithm.unwrap_or_default().is_empty() => *password,
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaActivateSessionRequest<'a> {
+    pub header: OpcuaRequestHeader<'a>,
+    pub client_signature_algorithm: Option<&'a str>,
+    pub locale_ids: Vec<Option<&'a str>>,
+    pub user_identity: OpcuaUserIdentity<'a>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaActivateSessionResponse<'a> {
+    pub header: OpcuaResponseHeader<'a>,
+    pub server_nonce: Option<&'a [u8]>,
+    pub results: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaBrowseDescription<'a> {
+    pub node_id: OpcuaNodeId<'a>,
+    /// 0 Forward, 1 Inverse, 2 Both.
+    pub browse_direction: u32,
+    pub reference_type_id: OpcuaNodeId<'a>,
+    pub include_subtypes: bool,
+    pub node_class_mask: u32,
+    pub result_mask: u32,
+}
+
+impl<'a> OpcuaBrowseDescription<'a> {
+    fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        let (node_id, rest) = OpcuaNodeId::decode(buf)?;
+        let (browse_direction, rest) = extract_opcua_u32(rest, "browse direction")?;
+        let (reference_type_id, rest) = OpcuaNodeId::decode(rest)?;
+        let (include_subtypes, rest) = extract_opcua_bool(rest, "include subtypes")?;
+        let (node_class_mask, rest) = extract_opcua_u32(rest, "node class mask")?;
+        let (result_mask, rest) = extract_opcua_u32(rest, "result mask")?;
+        Ok((
+            OpcuaBrowseDescription {
+                node_id,
+                browse_direction,
+                reference_type_id,
+                include_subtypes,
+                node_class_mask,
+                result_mask,
+            },
+            rest,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaBrowseRequest<'a> {
+    pub header: OpcuaRequestHeader<'a>,
+    /// View to browse, null for the whole address space.
+    pub view_id: OpcuaNodeId<'a>,
+    pub requested_max_references_per_node: u32,
+    pub nodes_to_browse: Vec<OpcuaBrowseDescription<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaReferenceDescription<'a> {
+    pub reference_type_id: OpcuaNodeId<'a>,
+    pub is_forward: bool,
+    pub node_id: OpcuaExpandedNodeId<'a>,
+    pub browse_name: OpcuaQualifiedName<'a>,
+    pub display_name: OpcuaLocalizedText<'a>,
+    pub node_class: u32,
+    pub type_definition: OpcuaExpandedNodeId<'a>,
+}
+
+impl<'a> OpcuaReferenceDescription<'a> {
+    fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        let (reference_type_id, rest) = OpcuaNodeId::decode(buf)?;
+        let (is_forward, rest) = extract_opcua_bool(rest, "is forward")?;
+        let (node_id, rest) = OpcuaExpandedNodeId::decode(rest)?;
+        let (browse_name, rest) = OpcuaQualifiedName::decode(rest)?;
+        let (display_name, rest) = OpcuaLocalizedText::decode(rest)?;
+        let (node_class, rest) = extract_opcua_u32(rest, "node class")?;
+        let (type_definition, rest) = OpcuaExpandedNodeId::decode(rest)?;
+        Ok((
+            OpcuaReferenceDescription {
+                reference_type_id,
+                is_forward,
+                node_id,
+                browse_name,
+                display_name,
+                node_class,
+                type_definition,
+            },
+            rest,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaBrowseResult<'a> {
+    pub status_code: u32,
+    pub continuation_point: Option<&'a [u8]>,
+    pub references: Vec<OpcuaReferenceDescription<'a>>,
+}
+
+impl<'a> OpcuaBrowseResult<'a> {
+    fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        let (status_code, rest) = extract_opcua_u32(buf, "status code")?;
+        let (continuation_point, rest) = extract_opcua_byte_string(rest, "continuation point")?;
+        let (references, rest) =
+            decode_opcua_array(rest, "references", 24, OpcuaReferenceDescription::decode)?;
+        Ok((
+            OpcuaBrowseResult {
+                status_code,
+                continuation_point,
+                references,
+            },
+            rest,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaBrowseResponse<'a> {
+    pub header: OpcuaResponseHeader<'a>,
+    pub results: Vec<OpcuaBrowseResult<'a>>,
+}
+
+/// Attribute of a node, as read or written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaReadValueId<'a> {
+    pub node_id: OpcuaNodeId<'a>,
+    pub attribute_id: u32,
+    pub index_range: Option<&'a str>,
+    pub data_encoding: OpcuaQualifiedName<'a>,
+}
+
+impl<'a> OpcuaReadValueId<'a> {
+    fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        let (node_id, rest) = OpcuaNodeId::decode(buf)?;
+        let (attribute_id, rest) = extract_opcua_u32(rest, "attribute id")?;
+        let (index_range, rest) = extract_opcua_string(rest, "index range")?;
+        let (data_encoding, rest) = OpcuaQualifiedName::decode(rest)?;
+        Ok((
+            OpcuaReadValueId {
+                node_id,
+                attribute_id,
+                index_range,
+                data_encoding,
+            },
+            rest,
+        ))
+    }
+
+    pub fn attribute_name(&self) -> &'static str {
+        opcua_attribute_name(self.attribute_id)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpcuaReadRequest<'a> {
+    pub header: OpcuaRequestHeader<'a>,
+    /// Milliseconds; 0 forces a device read.
+    pub max_age: f64,
+    /// 0 Source, 1 Server, 2 Both, 3 Neither.
+    pub timestamps_to_return: u32,
+    pub nodes_to_read: Vec<OpcuaReadValueId<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpcuaReadResponse<'a> {
+    pub header: OpcuaResponseHeader<'a>,
+    /// One value per node read, in request order.
+    pub results: Vec<OpcuaDataValue<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpcuaWriteValue<'a> {
+    pub node_id: OpcuaNodeId<'a>,
+    pub attribute_id: u32,
+    pub index_range: Option<&'a str>,
+    pub value: OpcuaDataValue<'a>,
+}
+
+impl<'a> OpcuaWriteValue<'a> {
+    fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        let (node_id, rest) = OpcuaNodeId::decode(buf)?;
+        let (attribute_id, rest) = extract_opcua_u32(rest, "attribute id")?;
+        let (index_range, rest) = extract_opcua_string(rest, "index range")?;
+        let (value, rest) = OpcuaDataValue::decode(rest)?;
+        Ok((
+            OpcuaWriteValue {
+                node_id,
+                attribute_id,
+                index_range,
+                value,
+            },
+            rest,
+        ))
+    }
+
+    pub fn attribute_name(&self) -> &'static str {
+        opcua_attribute_name(self.attribute_id)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpcuaWriteRequest<'a> {
+    pub header: OpcuaRequestHeader<'a>,
+    pub nodes_to_write: Vec<OpcuaWriteValue<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaWriteResponse<'a> {
+    pub header: OpcuaResponseHeader<'a>,
+    /// StatusCode per node written, in request order.
+    pub results: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpcuaCallMethodRequest<'a> {
+    pub object_id: OpcuaNodeId<'a>,
+    pub method_id: OpcuaNodeId<'a>,
+    pub input_arguments: Vec<OpcuaVariant<'a>>,
+}
+
+impl<'a> OpcuaCallMethodRequest<'a> {
+    fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        let (object_id, rest) = OpcuaNodeId::decode(buf)?;
+        let (method_id, rest) = OpcuaNodeId::decode(rest)?;
+        let (input_arguments, rest) =
+            decode_opcua_array(rest, "input arguments", 1, OpcuaVariant::decode)?;
+        Ok((
+            OpcuaCallMethodRequest {
+                object_id,
+                method_id,
+                input_arguments,
+            },
+            rest,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpcuaCallRequest<'a> {
+    pub header: OpcuaRequestHeader<'a>,
+    pub methods_to_call: Vec<OpcuaCallMethodRequest<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpcuaCallMethodResult<'a> {
+    pub status_code: u32,
+    pub input_argument_results: Vec<u32>,
+    pub output_arguments: Vec<OpcuaVariant<'a>>,
+}
+
+impl<'a> OpcuaCallMethodResult<'a> {
+    fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        let (status_code, rest) = extract_opcua_u32(buf, "status code")?;
+        let (input_argument_results, rest) = decode_status_codes(rest, "input argument results")?;
+        let (_, rest) = decode_diagnostic_infos(rest)?;
+        let (output_arguments, rest) =
+            decode_opcua_array(rest, "output arguments", 1, OpcuaVariant::decode)?;
+        Ok((
+            OpcuaCallMethodResult {
+                status_code,
+                input_argument_results,
+                output_arguments,
+            },
+            rest,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpcuaCallResponse<'a> {
+    pub header: OpcuaResponseHeader<'a>,
+    pub results: Vec<OpcuaCallMethodResult<'a>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpcuaCreateSubscriptionRequest<'a> {
+    pub header: OpcuaRequestHeader<'a>,
+    pub requested_publishing_interval: f64,
+    pub requested_lifetime_count: u32,
+    pub requested_max_keep_alive_count: u32,
+    pub max_notifications_per_publish: u32,
+    pub publishing_enabled: bool,
+    pub priority: u8,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpcuaCreateSubscriptionResponse<'a> {
+    pub header: OpcuaResponseHeader<'a>,
+    pub subscription_id: u32,
+    /// Milliseconds.
+    pub revised_publishing_interval: f64,
+    pub revised_lifetime_count: u32,
+    pub revised_max_keep_alive_count: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OpcuaPublishRequest<'a> {
+    pub header: OpcuaRequestHeader<'a>,
+    /// (subscription id, sequence number) of the notifications received.
+    pub acknowledgements: Vec<(u32, u32)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpcuaMonitoredItemNotification<'a> {
+    /// Handle chosen by the client in CreateMonitoredItems.
+    pub client_handle: u32,
+    pub value: OpcuaDataValue<'a>,
+}
+
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpcuaNotification<'a> {
+    DataChange(Vec<OpcuaMonitoredItemNotification<'a>>),
+    /// Event or status change notification, left encoded.
+    Other(OpcuaExtensionObject<'a>),
+}
+
+impl<'a> OpcuaNotification<'a> {
+    fn from_extension_object(data: OpcuaExtensionObject<'a>) -> Result<Self, OpcuaDecodeError> {
+        let Some(body) = data.binary_body(OPCUA_DATA_CHANGE_NOTIFICATION) else {
+            return Ok(OpcuaNotification::Other(data));
+        };
+        let (items, _) = decode_opcua_array(body, "monitored items", 5, |item| {
+            let (client_handle, rest) = extract_opcua_u32(item, "client handle")?;
+            let (value, rest) = OpcuaDataValue::decode(rest)?;
+            Ok((
+                OpcuaMonitoredItemNotification {
+                    client_handle,
+                    value,
+                },
+                rest,
+            ))
+        })?;
+        Ok(OpcuaNotification::DataChange(items))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpcuaPublishResponse<'a> {
+    pub header: OpcuaResponseHeader<'a>,
+    pub subscription_id: u32,
+    pub available_sequence_numbers: Vec<u32>,
+    pub more_notifications: bool,
+    /// Sequence number of the notification message, 0 for a keep-alive.
+    pub sequence_number: u32,
+    pub publish_time: OpcuaDateTime,
+    pub notifications: Vec<OpcuaNotification<'a>>,
+    pub results: Vec<u32>,
+}
+
+/// Decoded service message.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpcuaService<'a> {
+    OpenSecureChannelRequest(OpcuaOpenSecureChannelRequest<'a>),
+    OpenSecureChannelResponse(OpcuaOpenSecureChannelResponse<'a>),
+    CreateSessionRequest(OpcuaCreateSessionRequest<'a>),
+    CreateSessionResponse(OpcuaCreateSessionResponse<'a>),
+    ActivateSessionRequest(OpcuaActivateSessionRequest<'a>),
+    ActivateSessionResponse(OpcuaActivateSessionResponse<'a>),
+    CloseSessionRequest {
+        header: OpcuaRequestHeader<'a>,
+        delete_subscriptions: bool,
+    },
+    CloseSessionResponse(OpcuaResponseHeader<'a>),
+    BrowseRequest(OpcuaBrowseRequest<'a>),
+    BrowseResponse(OpcuaBrowseResponse<'a>),
+    ReadRequest(OpcuaReadRequest<'a>),
+    ReadResponse(OpcuaReadResponse<'a>),
+    WriteRequest(OpcuaWriteRequest<'a>),
+    WriteResponse(OpcuaWriteResponse<'a>),
+    CallRequest(OpcuaCallRequest<'a>),
+    CallResponse(OpcuaCallResponse<'a>),
+    CreateSubscriptionRequest(OpcuaCreateSubscriptionRequest<'a>),
+    CreateSubscriptionResponse(OpcuaCreateSubscriptionResponse<'a>),
+    PublishRequest(OpcuaPublishRequest<'a>),
+    PublishResponse(OpcuaPublishResponse<'a>),
+    /// Response of a failed request of any service.
+    ServiceFault(OpcuaResponseHeader<'a>),
+    /// Service not decoded here, body after the type id.
+    Other {
+        type_id: OpcuaNodeId<'a>,
+        body: &'a [u8],
+    },
+}
+
+impl<'a> OpcuaService<'a> {
+    /// Decodes a message body: encoding NodeId, then the service structure.
+    /// Bytes left after the structure are ignored.
+    pub fn parse(body: &'a [u8]) -> Result<Self, OpcuaDecodeError> {
+        let (type_id, rest) = OpcuaNodeId::decode(body)?;
+        let Some(encoding_id) = type_id.ns0_numeric() else {
+            return Ok(OpcuaService::Other {
+                type_id,
+                body: rest,
+            });
+        };
+        let service = match encoding_id {
+            OPCUA_OPEN_SECURE_CHANNEL_REQUEST => {
+                let (header, rest) = OpcuaRequestHeader::decode(rest)?;
+                let (client_protocol_version, rest) = extract_opcua_u32(rest, "protocol version")?;
+                let (request_type, rest) = extract_opcua_u32(rest, "request type")?;
+                let (security_mode, rest) = extract_opcua_u32(rest, "security mode")?;
+                let (client_nonce, rest) = extract_opcua_byte_string(rest, "client nonce")?;
+                let (requested_lifetime, _) = extract_opcua_u32(rest, "requested lifetime")?;
+                OpcuaService::OpenSecureChannelRequest(OpcuaOpenSecureChannelRequest {
+                    header,
+                    client_protocol_version,
+                    request_type,
+                    security_mode,
+                    client_nonce,
+                    requested_lifetime,
+                })
+            }
+            OPCUA_OPEN_SECURE_CHANNEL_RESPONSE => {
+                let (header, rest) = OpcuaResponseHeader::decode(rest)?;
+                let (server_protocol_version, rest) = extract_opcua_u32(rest, "protocol version")?;
+                let (channel_id, rest) = extract_opcua_u32(rest, "channel id")?;
+                let (token_id, rest) = extract_opcua_u32(rest, "token id")?;
+                let (created_at, rest) = extract_opcua_i64(rest, "created at")?;
+                let (revised_lifetime, rest) = extract_opcua_u32(rest, "revised lifetime")?;
+                let (server_nonce, _) = extract_opcua_byte_string(rest, "server nonce")?;
+                OpcuaService::OpenSecureChannelResponse(OpcuaOpenSecureChannelResponse {
+                    header,
+                    server_protocol_version,
+                    channel_id,
+                    token_id,
+                    created_at: OpcuaDateTime(created_at),
+                    revised_lifetime,
+                    server_nonce,
+                })
+            }
+            OPCUA_CREATE_SESSION_REQUEST => {
+                let (header, rest) = OpcuaRequestHeader::decode(rest)?;
+                let (client_description, rest) = OpcuaApplicationDescription::decode(rest)?;
+                let (server_uri, rest) = extract_opcua_string(rest, "server uri")?;
+                let (endpoint_url, rest) = extract_opcua_string(rest, "endpoint url")?;
+                let (session_name, rest) = extract_opcua_string(rest, "session name")?;
+                let (client_nonce, rest) = extract_opcua_byte_string(rest, "client nonce")?;
+                let (client_certificate, rest) =
+                    extract_opcua_byte_string(rest, "client certificate")?;
+                let (requested_session_timeout, rest) =
+                    extract_opcua_f64(rest, "requested session timeout")?;
+                let (max_response_message_size, _) =
+                    extract_opcua_u32(rest, "max response message size")?;
+                OpcuaService::CreateSessionRequest(OpcuaCreateSessionRequest {
+                    header,
+                    client_description,
+                    server_uri,
+                    endpoint_url,
+                    session_name,
+                    client_nonce,
+                    client_certificate,
+                    requested_session_timeout,
+                    max_response_message_size,
+                })
+            }
+            OPCUA_CREATE_SESSION_RESPONSE => {
+                let (header, rest) = OpcuaResponseHeader::decode(rest)?;
+                let (session_id, rest) = OpcuaNodeId::decode(rest)?;
+                let (authentication_token, rest) = OpcuaNodeId::decode(rest)?;
+                let (revised_session_timeout, rest) =
+                    extract_opcua_f64(rest, "revised session timeout")?;
+                let (server_nonce, rest) = extract_opcua_byte_string(rest, "server nonce")?;
+                let (server_certificate, rest) =
+                    extract_opcua_byte_string(rest, "server certificate")?;
+                let (server_endpoints, rest) = decode_opcua_array(
+                    rest,
+                    "server endpoints",
+                    32,
+                    OpcuaEndpointDescription::decode,
+                )?;
+                // SignedSoftwareCertificate[] : deux ByteString par element.
+                let (_, rest) = decode_opcua_array(rest, "software certificates", 8, |item| {
+                    let (_, item) = extract_opcua_byte_string(item, "certificate data")?;
+                    extract_opcua_byte_string(item, "signature")
+                })?;
+                let (server_signature_algorithm, rest) = decode_signature(rest)?;
+                let (max_request_message_size, _) =
+                    extract_opcua_u32(rest, "max request message size")?;
+                OpcuaService::CreateSessionResponse(OpcuaCreateSessionResponse {
+                    header,
+                    session_id,
+                    authentication_token,
+                    revised_session_timeout,
+                    server_nonce,
+                    server_certificate,
+                    server_endpoints,
+                    server_signature_algorithm,
+                    max_request_message_size,
+                })
+            }
+            OPCUA_ACTIVATE_SESSION_REQUEST => {
+                let (header, rest) = OpcuaRequestHeader::decode(rest)?;
+                let (client_signature_algorithm, rest) = decode_signature(rest)?;
+                let (_, rest) = decode_opcua_array(rest, "software certificates", 8, |item| {
+                    let (_, item) = extract_opcua_byte_string(item, "certificate data")?;
+                    extract_opcua_byte_string(item, "signature")
+                })?;
+                let (locale_ids, rest) = decode_strings(rest, "locale ids")?;
+                let (token, _) = OpcuaExtensionObject::decode(rest)?;
+                OpcuaService::ActivateSessionRequest(OpcuaActivateSessionRequest {
+                    header,
+                    client_signature_algorithm,
+                    locale_ids,
+                    user_identity: OpcuaUserIdentity::from_extension_object(token)?,
+                })
+            }
+            OPCUA_ACTIVATE_SESSION_RESPONSE => {
+                let (header, rest) = OpcuaResponseHeader::decode(rest)?;
+                let (server_nonce, rest) = extract_opcua_byte_string(rest, "server nonce")?;
+                let (results, _) = decode_status_codes(rest, "results")?;
+                OpcuaService::ActivateSessionResponse(OpcuaActivateSessionResponse {
+                    header,
+                    server_nonce,
+                    results,
+                })
+            }
+            OPCUA_CLOSE_SESSION_REQUEST => {
+                let (header, rest) = OpcuaRequestHeader::decode(rest)?;
+                let (delete_subscriptions, _) = extract_opcua_bool(rest, "delete subscriptions")?;
+                OpcuaService::CloseSessionRequest {
+                    header,
+                    delete_subscriptions,
+                }
+            }
+            OPCUA_CLOSE_SESSION_RESPONSE => {
+                OpcuaService::CloseSessionResponse(OpcuaResponseHeader::decode(rest)?.0)
+            }
+            OPCUA_BROWSE_REQUEST => {
+                let (header, rest) = OpcuaRequestHeader::decode(rest)?;
+                // ViewDescription : vue, horodatage, version.
+                let (view_id, rest) = OpcuaNodeId::decode(rest)?;
+                let (_, rest) = extract_opcua_i64(rest, "view timestamp")?;
+                let (_, rest) = extract_opcua_u32(rest, "view version")?;
+                let (requested_max_references_per_node, rest) =
+                    extract_opcua_u32(rest, "max references per node")?;
+                let (nodes_to_browse, _) = decode_opcua_array(
+                    rest,
+                    "nodes to browse",
+                    15,
+                    OpcuaBrowseDescription::decode,
+                )?;
+                OpcuaService::BrowseRequest(OpcuaBrowseRequest {
+                    header,
+                    view_id,
+                    requested_max_references_per_node,
+                    nodes_to_browse,
+                })
+            }
+            OPCUA_BROWSE_RESPONSE => {
+                let (header, rest) = OpcuaResponseHeader::decode(rest)?;
+                let (results, _) =
+                    decode_opcua_array(rest, "browse results", 12, OpcuaBrowseResult::decode)?;
+                OpcuaService::BrowseResponse(OpcuaBrowseResponse { header, results })
+            }
+            OPCUA_READ_REQUEST => {
+                let (header, rest) = OpcuaRequestHeader::decode(rest)?;
+                let (max_age, rest) = extract_opcua_f64(rest, "max age")?;
+                let (timestamps_to_return, rest) = extract_opcua_u32(rest, "timestamps to return")?;
+                let (nodes_to_read, _) =
+                    decode_opcua_array(rest, "nodes to read", 13, OpcuaReadValueId::decode)?;
+                OpcuaService::ReadRequest(OpcuaReadRequest {
+                    header,
+                    max_age,
+                    timestamps_to_return,
+                    nodes_to_read,
+                })
+            }
+            OPCUA_READ_RESPONSE => {
+                let (header, rest) = OpcuaResponseHeader::decode(rest)?;
+                let (results, _) = decode_opcua_array(rest, "results", 1, OpcuaDataValue::decode)?;
+                OpcuaService::ReadResponse(OpcuaReadResponse { header, results })
+            }
+            OPCUA_WRITE_REQUEST => {
+                let (header, rest) = OpcuaRequestHeader::decode(rest)?;
+                let (nodes_to_write, _) =
+                    decode_opcua_array(rest, "nodes to write", 11, OpcuaWriteValue::decode)?;
+                OpcuaService::WriteRequest(OpcuaWriteRequest {
+                    header,
+                    nodes_to_write,
+                })
+            }
+            OPCUA_WRITE_RESPONSE => {
+                let (header, rest) = OpcuaResponseHeader::decode(rest)?;
+                let (results, _) = decode_status_codes(rest, "results")?;
+                OpcuaService::WriteResponse(OpcuaWriteResponse { header, results })
+            }
+            OPCUA_CALL_REQUEST => {
+                let (header, rest) = OpcuaRequestHeader::decode(rest)?;
+                let (methods_to_call, _) =
+                    decode_opcua_array(rest, "methods to call", 8, OpcuaCallMethodRequest::decode)?;
+                OpcuaService::CallRequest(OpcuaCallRequest {
+                    header,
+                    methods_to_call,
+                })
+            }
+            OPCUA_CALL_RESPONSE => {
+                let (header, rest) = OpcuaResponseHeader::decode(rest)?;
+                let (results, _) =
+                    decode_opcua_array(rest, "call results", 16, OpcuaCallMethodResult::decode)?;
+                OpcuaService::CallResponse(OpcuaCallResponse { header, results })
+            }
+            OPCUA_CREATE_SUBSCRIPTION_REQUEST => {
+                let (header, rest) = OpcuaRequestHeader::decode(rest)?;
+                let (requested_publishing_interval, rest) =
+                    extract_opcua_f64(rest, "publishing interval")?;
+                let (requested_lifetime_count, rest) = extract_opcua_u32(rest, "lifetime count")?;
+                let (requested_max_keep_alive_count, rest) =
+                    extract_opcua_u32(rest, "max keep alive count")?;
+                let (max_notifications_per_publish, rest) =
+                    extract_opcua_u32(rest, "max notifications per publish")?;
+                let (publishing_enabled, rest) = extract_opcua_bool(rest, "publishing enabled")?;
+                let (priority, _) = extract_opcua_u8(rest, "priority")?;
+                OpcuaService::CreateSubscriptionRequest(OpcuaCreateSubscriptionRequest {
+                    header,
+                    requested_publishing_interval,
+                    requested_lifetime_count,
+                    requested_max_keep_alive_count,
+                    max_notifications_per_publish,
+                    publishing_enabled,
+                    priority,
+                })
+            }
+            OPCUA_CREATE_SUBSCRIPTION_RESPONSE => {
+                let (header, rest) = OpcuaResponseHeader::decode(rest)?;
+                let (subscription_id, rest) = extract_opcua_u32(rest, "subscription id")?;
+                let (revised_publishing_interval, rest) =
+                    extract_opcua_f64(rest, "publishing interval")?;
+                let (revised_lifetime_count, rest) = extract_opcua_u32(rest, "lifetime count")?;
+                let (revised_max_keep_alive_count, _) =
+                    extract_opcua_u32(rest, "max keep alive count")?;
+                OpcuaService::CreateSubscriptionResponse(OpcuaCreateSubscriptionResponse {
+                    header,
+                    subscription_id,
+                    revised_publishing_interval,
+                    revised_lifetime_count,
+                    revised_max_keep_alive_count,
+                })
+            }
+            OPCUA_PUBLISH_REQUEST => {
+                let (header, rest) = OpcuaRequestHeader::decode(rest)?;
+                let (acknowledgements, _) =
+                    decode_opcua_array(rest, "acknowledgements", 8, |item| {
+                        let (subscription_id, item) = extract_opcua_u32(item, "subscription id")?;
+                        let (sequence_number, item) = extract_opcua_u32(item, "sequence number")?;
+                        Ok(((subscription_id, sequence_number), item))
+                    })?;
+                OpcuaService::PublishRequest(OpcuaPublishRequest {
+                    header,
+                    acknowledgements,
+                })
+            }
+            OPCUA_PUBLISH_RESPONSE => {
+                let (header, rest) = OpcuaResponseHeader::decode(rest)?;
+                let (subscription_id, rest) = extract_opcua_u32(rest, "subscription id")?;
+                let (available_sequence_numbers, rest) =
+                    decode_status_codes(rest, "available sequence numbers")?;
+                let (more_notifications, rest) = extract_opcua_bool(rest, "more notifications")?;
+                let (sequence_number, rest) = extract_opcua_u32(rest, "sequence number")?;
+                let (publish_time, rest) = extract_opcua_i64(rest, "publish time")?;
+                let (notification_data, rest) =
+                    decode_opcua_array(rest, "notification data", 4, OpcuaExtensionObject::decode)?;
+                let notifications = notification_data
+                    .into_iter()
+                    .map(OpcuaNotification::from_extension_object)
+                    .collect::<Result<Vec<_>, _>>()?;
+                let (results, _) = decode_status_codes(rest, "results")?;
+                OpcuaService::PublishResponse(OpcuaPublishResponse {
+                    header,
+                    subscription_id,
+                    available_sequence_numbers,
+                    more_notifications,
+                    sequence_number,
+                    publish_time: OpcuaDateTime(publish_time),
+                    notifications,
+                    results,
+                })
+            }
+            OPCUA_SERVICE_FAULT => OpcuaService::ServiceFault(OpcuaResponseHeader::decode(rest)?.0),
+            _ => OpcuaService::Other {
+                type_id,
+                body: rest,
+            },
+        };
+        Ok(service)
+    }
+
+    /// Header of a request, `None` for responses and undecoded services.
+    pub fn request_header(&self) -> Option<&OpcuaRequestHeader<'a>> {
+        match self {
+            OpcuaService::OpenSecureChannelRequest(request) => Some(&request.header),
+            OpcuaService::CreateSessionRequest(request) => Some(&request.header),
+            OpcuaService::ActivateSessionRequest(request) => Some(&request.header),
+            OpcuaService::CloseSessionRequest { header, .. } => Some(header),
+            OpcuaService::BrowseRequest(request) => Some(&request.header),
+            OpcuaService::ReadRequest(request) => Some(&request.header),
+            OpcuaService::WriteRequest(request) => Some(&request.header),
+            OpcuaService::CallRequest(request) => Some(&request.header),
+            OpcuaService::CreateSubscriptionRequest(request) => Some(&request.header),
+            OpcuaService::PublishRequest(request) => Some(&request.header),
+            _ => None,
+        }
+    }
+
+    /// Header of a response or ServiceFault.
+    pub fn response_header(&self) -> Option<&OpcuaResponseHeader<'a>> {
+        match self {
+            OpcuaService::OpenSecureChannelResponse(response) => Some(&response.header),
+            OpcuaService::CreateSessionResponse(response) => Some(&response.header),
+            OpcuaService::ActivateSessionResponse(response) => Some(&response.header),
+            OpcuaService::CloseSessionResponse(header) | OpcuaService::ServiceFault(header) => {
+                Some(header)
+            }
+            OpcuaService::BrowseResponse(response) => Some(&response.header),
+            OpcuaService::ReadResponse(response) => Some(&response.header),
+            OpcuaService::WriteResponse(response) => Some(&response.header),
+            OpcuaService::CallResponse(response) => Some(&response.header),
+            OpcuaService::CreateSubscriptionResponse(response) => Some(&response.header),
+            OpcuaService::PublishResponse(response) => Some(&response.header),
+            _ => None,
+        }
+    }
+
+    /// Request handle, shared by a request and its response.
+    pub fn request_handle(&self) -> Option<u32> {
+        self.request_header()
+            .map(|header| header.request_handle)
+            .or_else(|| self.response_header().map(|header| header.request_handle))
+    }
+
+    /// Numeric id of the service encoding, `None` outside namespace 0.
+    pub fn encoding_id(&self) -> Option<u32> {
+        let id = match self {
+            OpcuaService::OpenSecureChannelRequest(_) => OPCUA_OPEN_SECURE_CHANNEL_REQUEST,
+            OpcuaService::OpenSecureChannelResponse(_) => OPCUA_OPEN_SECURE_CHANNEL_RESPONSE,
+            OpcuaService::CreateSessionRequest(_) => OPCUA_CREATE_SESSION_REQUEST,
+            OpcuaService::CreateSessionResponse(_) => OPCUA_CREATE_SESSION_RESPONSE,
+            OpcuaService::ActivateSessionRequest(_) => OPCUA_ACTIVATE_SESSION_REQUEST,
+            OpcuaService::ActivateSessionResponse(_) => OPCUA_ACTIVATE_SESSION_RESPONSE,
+            OpcuaService::CloseSessionRequest { .. } => OPCUA_CLOSE_SESSION_REQUEST,
+            OpcuaService::CloseSessionResponse(_) => OPCUA_CLOSE_SESSION_RESPONSE,
+            OpcuaService::BrowseRequest(_) => OPCUA_BROWSE_REQUEST,
+            OpcuaService::BrowseResponse(_) => OPCUA_BROWSE_RESPONSE,
+            OpcuaService::ReadRequest(_) => OPCUA_READ_REQUEST,
+            OpcuaService::ReadResponse(_) => OPCUA_READ_RESPONSE,
+            OpcuaService::WriteRequest(_) => OPCUA_WRITE_REQUEST,
+            OpcuaService::WriteResponse(_) => OPCUA_WRITE_RESPONSE,
+            OpcuaService::CallRequest(_) => OPCUA_CALL_REQUEST,
+            OpcuaService::CallResponse(_) => OPCUA_CALL_RESPONSE,
+            OpcuaService::CreateSubscriptionRequest(_) => OPCUA_CREATE_SUBSCRIPTION_REQUEST,
+            OpcuaService::CreateSubscriptionResponse(_) => OPCUA_CREATE_SUBSCRIPTION_RESPONSE,
+            OpcuaService::PublishRequest(_) => OPCUA_PUBLISH_REQUEST,
+            OpcuaService::PublishResponse(_) => OPCUA_PUBLISH_RESPONSE,
+            OpcuaService::ServiceFault(_) => OPCUA_SERVICE_FAULT,
+            OpcuaService::Other { type_id, .. } => return type_id.ns0_numeric(),
+        };
+        Some(id)
+    }
+
+    pub fn name(&self) -> &'static str {
+        self.encoding_id().map_or("Unknown", opcua_service_name)
+    }
+}
+
+fn decode_strings<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(Vec<Option<&'a str>>, &'a [u8]), OpcuaDecodeError> {
+    decode_opcua_array(buf, field, 4, |item| extract_opcua_string(item, field))
+}
+
+fn decode_status_codes<'a>(
+    buf: &'a [u8],
+    field: &'static str,
+) -> Result<(Vec<u32>, &'a [u8]), OpcuaDecodeError> {
+    decode_opcua_array(buf, field, 4, |item| extract_opcua_u32(item, field))
+}
+
+fn decode_diagnostic_infos(
+    buf: &[u8],
+) -> Result<(Vec<OpcuaDiagnosticInfo<'_>>, &[u8]), OpcuaDecodeError> {
+    decode_opcua_array(buf, "diagnostic infos", 1, OpcuaDiagnosticInfo::decode)
+}
+
+/// SignatureData : algorithme (String) puis signature (ByteString) ; seul
+/// l'algorithme est conserve.
+fn decode_signature(buf: &[u8]) -> Result<(Option<&str>, &[u8]), OpcuaDecodeError> {
+    let (algorithm, rest) = extract_opcua_string(buf, "signature algorithm")?;
+    let (_, rest) = extract_opcua_byte_string(rest, "signature")?;
+    Ok((algorithm, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::application::protocols::opcua::types::{OpcuaIdentifier, OpcuaScalar};
+
+    /// En-tete de requete synthetique : jeton ns=0;i=0, handle `handle`.
+    fn request(encoding_id: u16, handle: u32) -> Vec<u8> {
+        let mut body = vec![0x01, 0x00];
+        body.extend_from_slice(&encoding_id.to_le_bytes());
+        body.extend_from_slice(&[0x00, 0x00]);
+        body.extend_from_slice(&0i64.to_le_bytes());
+        body.extend_from_slice(&handle.to_le_bytes());
+        body.extend_from_slice(&0u32.to_le_bytes());
+        body.extend_from_slice(&(-1i32).to_le_bytes());
+        body.extend_from_slice(&10_000u32.to_le_bytes());
+        body.extend_from_slice(&[0x00, 0x00, 0x00]);
+        body
+    }
+
+    /// En-tete de reponse synthetique, statut Good.
+    fn response(encoding_id: u16, handle: u32) -> Vec<u8> {
+        let mut body = vec![0x01, 0x00];
+        body.extend_from_slice(&encoding_id.to_le_bytes());
+        body.extend_from_slice(&0i64.to_le_bytes());
+        body.extend_from_slice(&handle.to_le_bytes());
+        body.extend_from_slice(&0u32.to_le_bytes());
+        body.push(0x00);
+        body.extend_from_slice(&(-1i32).to_le_bytes());
+        body.extend_from_slice(&[0x00, 0x00, 0x00]);
+        body
+    }
+
+    fn string(value: &str) -> Vec<u8> {
+        let mut bytes = (value.len() as i32).to_le_bytes().to_vec();
+        bytes.extend_from_slice(value.as_bytes());
+        bytes
+    }
+
+    #[test]
+    fn decode_read_request_and_response() {
+        // Lecture de la valeur (13) de ns=2;s=Pump.Speed.
+        let mut body = request(631, 42);
+        body.extend_from_slice(&0f64.to_le_bytes());
+        body.extend_from_slice(&2u32.to_le_bytes());
+        body.extend_from_slice(&1i32.to_le_bytes());
+        body.extend_from_slice(&[0x03, 0x02, 0x00]);
+        body.extend(string("Pump.Speed"));
+        body.extend_from_slice(&13u32.to_le_bytes());
+        body.extend_from_slice(&(-1i32).to_le_bytes());
+        body.extend_from_slice(&[0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF]);
+
+        let service = OpcuaService::parse(&body).expect("valid ReadRequest");
+        assert_eq!(service.request_handle(), Some(42));
+        let OpcuaService::ReadRequest(read) = service else {
+            panic!("expected ReadRequest");
+        };
+        assert_eq!(read.timestamps_to_return, 2);
+        assert_eq!(read.nodes_to_read.len(), 1);
+        assert_eq!(
+            read.nodes_to_read[0].node_id.to_string(),
+            "ns=2;s=Pump.Speed"
+        );
+        assert_eq!(read.nodes_to_read[0].attribute_name(), "Value");
+
+        // Reponse : Float 1450.5 avec horodatage source.
+        let mut body = response(634, 42);
+        body.extend_from_slice(&1i32.to_le_bytes());
+        body.extend_from_slice(&[0x05, 0x0A]);
+        body.extend_from_slice(&1450.5f32.to_le_bytes());
+        body.extend_from_slice(&133_000_000_000_000_000i64.to_le_bytes());
+        body.extend_from_slice(&(-1i32).to_le_bytes());
+
+        let service = OpcuaService::parse(&body).expect("valid ReadResponse");
+        assert_eq!(service.name(), "ReadResponse");
+        let OpcuaService::ReadResponse(read) = service else {
+            panic!("expected ReadResponse");
+        };
+        assert_eq!(
+            read.results[0].value,
+            Some(OpcuaVariant::Scalar(OpcuaScalar::Float(1450.5)))
+        );
+        assert!(read.results[0].source_timestamp.is_some());
+    }
+
+    #[test]
+    fn decode_write_request_value() {
+        // Ecriture de Boolean true sur ns=3;i=1001, attribut Value.
+        let mut body = request(673, 7);
+        body.extend_from_slice(&1i32.to_le_bytes());
+        body.extend_from_slice(&[0x01, 0x03, 0xE9, 0x03]);
+        body.extend_from_slice(&13u32.to_le_bytes());
+        body.extend_from_slice(&(-1i32).to_le_bytes());
+        body.extend_from_slice(&[0x01, 0x01, 0x01]);
+
+        let OpcuaService::WriteRequest(write) =
+            OpcuaService::parse(&body).expect("valid WriteRequest")
+        else {
+            panic!("expected WriteRequest");
+        };
+        let node = &write.nodes_to_write[0];
+        assert_eq!(node.node_id.identifier, OpcuaIdentifier::Numeric(1001));
+        assert_eq!(node.node_id.namespace, 3);
+        assert_eq!(
+            node.value.value,
+            Some(OpcuaVariant::Scalar(OpcuaScalar::Boolean(true)))
+        );
+    }
+
+    #[test]
+    fn decode_activate_session_user_name_token() {
+        // Jeton UserName avec mot de passe non chiffre (algorithme vide).
+        let mut token = string("username");
+        token.extend(string("operator"));
+        token.extend(string("secret"));
+        token.extend_from_slice(&(-1i32).to_le_bytes());
+
+        let mut body = request(467, 3);
+        body.extend_from_slice(&(-1i32).to_le_bytes());
+        body.extend_from_slice(&(-1i32).to_le_bytes());
+        body.extend_from_slice(&0i32.to_le_bytes());
+        body.extend_from_slice(&1i32.to_le_bytes());
+        body.extend(string("en"));
+        body.extend_from_slice(&[0x01, 0x00, 0x44, 0x01, 0x01]);
+        body.extend_from_slice(&(token.len() as i32).to_le_bytes());
+        body.extend(token);
+        body.extend_from_slice(&(-1i32).to_le_bytes());
+        body.extend_from_slice(&(-1i32).to_le_bytes());
+
+        let OpcuaService::ActivateSessionRequest(activate) =
+            OpcuaService::parse(&body).expect("valid ActivateSessionRequest")
+        else {
+            panic!("expected ActivateSessionRequest");
+        };
+        assert_eq!(activate.locale_ids, vec![Some("en")]);
+        assert!(matches!(
+            activate.user_identity,
+            OpcuaUserIdentity::UserName {
+                user_name: Some("operator"),
+                ..
+            }
+        ));
+        assert_eq!(
+            activate.user_identity.cleartext_password(),
+            Some(&b"secret"[..])
+        );
+    }
+
+    #[test]
+    fn decode_publish_response_data_change() {
+        // Abonnement 5, message 12 : un item (handle 1) passe a Int32 -3.
+        let mut notification = 1i32.to_le_bytes().to_vec();
+        notification.extend_from_slice(&1u32.to_le_bytes());
+        notification.extend_from_slice(&[0x01, 0x06]);
+        notification.extend_from_slice(&(-3i32).to_le_bytes());
+        notification.extend_from_slice(&(-1i32).to_le_bytes());
+
+        let mut body = response(829, 9);
+        body.extend_from_slice(&5u32.to_le_bytes());
+        body.extend_from_slice(&1i32.to_le_bytes());
+        body.extend_from_slice(&12u32.to_le_bytes());
+        body.push(0x00);
+        body.extend_from_slice(&12u32.to_le_bytes());
+        body.extend_from_slice(&0i64.to_le_bytes());
+        body.extend_from_slice(&1i32.to_le_bytes());
+        body.extend_from_slice(&[0x01, 0x00, 0x2B, 0x03, 0x01]);
+        body.extend_from_slice(&(notification.len() as i32).to_le_bytes());
+        body.extend(notification);
+        body.extend_from_slice(&0i32.to_le_bytes());
+        body.extend_from_slice(&(-1i32).to_le_bytes());
+
+        let OpcuaService::PublishResponse(publish) =
+            OpcuaService::parse(&body).expect("valid PublishResponse")
+        else {
+            panic!("expected PublishResponse");
+        };
+        assert_eq!(publish.subscription_id, 5);
+        assert_eq!(publish.available_sequence_numbers, vec![12]);
+        assert_eq!(publish.sequence_number, 12);
+        let [OpcuaNotification::DataChange(items)] = publish.notifications.as_slice() else {
+            panic!("expected one data change notification");
+        };
+        assert_eq!(items[0].client_handle, 1);
+        assert_eq!(
+            items[0].value.value,
+            Some(OpcuaVariant::Scalar(OpcuaScalar::Int32(-3)))
+        );
+    }
+
+    #[test]
+    fn keep_unknown_services_raw_and_report_truncation() {
+        // GetEndpointsRequest n'est pas decode.
+        let body = request(428, 1);
+        let service = OpcuaService::parse(&body).expect("valid body");
+        assert_eq!(service.name(), "GetEndpointsRequest");
+        assert!(matches!(service, OpcuaService::Other { .. }));
+
+        let body = request(631, 1);
+        assert_eq!(
+            OpcuaService::parse(&body),
+            Err(OpcuaDecodeError::Truncated { field: "max age" })
+        );
+    }
+}
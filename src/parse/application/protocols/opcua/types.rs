@@ -0,0 +1,699 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! OPC UA binary encoding of the built-in types (Part 6, 5.2).
+//!
+//! Every decoder takes the remaining buffer and returns the value with what
+//! is left after it, so service decoders read a structure field by field in
+//! wire order. Strings and ByteStrings stay borrowed from the message body.
+//! Variant, DataValue and DiagnosticInfo can contain themselves; their
+//! nesting is bounded by `OPCUA_MAX_NESTING_DEPTH`.
+
+use core::fmt;
+
+use crate::{
+    checks::application::opcua::{
+        extract_opcua_array, extract_opcua_bool, extract_opcua_byte_string, extract_opcua_f64,
+        extract_opcua_i32, extract_opcua_i64, extract_opcua_length, extract_opcua_string,
+        extract_opcua_u8, extract_opcua_u16, extract_opcua_u32, extract_opcua_u64,
+        validate_opcua_depth,
+    },
+    errors::application::opcua::OpcuaDecodeError,
+    parse::application::protocols::bounded_capacity,
+};
+
+/// 100 ns intervals between 1601-01-01 and the Unix epoch.
+const OPCUA_UNIX_EPOCH_TICKS: i64 = 116_444_736_000_000_000;
+
+/// 16-byte GUID, stored as on the wire (first three fields little-endian).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OpcuaGuid(pub [u8; 16]);
+
+impl fmt::Display for OpcuaGuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let b = &self.0;
+        write!(
+            f,
+            "{:08x}-{:04x}-{:04x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+            u32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+            u16::from_le_bytes([b[4], b[5]]),
+            u16::from_le_bytes([b[6], b[7]]),
+            b[8],
+            b[9],
+            b[10],
+            b[11],
+            b[12],
+            b[13],
+            b[14],
+            b[15]
+        )
+    }
+}
+
+/// Timestamp in 100 ns intervals since 1601-01-01 UTC; 0 means unset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct OpcuaDateTime(pub i64);
+
+impl OpcuaDateTime {
+    /// Milliseconds since the Unix epoch, `None` when unset.
+    pub fn unix_millis(&self) -> Option<i64> {
+        if self.0 == 0 {
+            return None;
+        }
+        Some((self.0 - OPCUA_UNIX_EPOCH_TICKS).div_euclid(10_000))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OpcuaIdentifier<'a> {
+    Numeric(u32),
+    String(Option<&'a str>),
+    Guid(OpcuaGuid),
+    Opaque(Option<&'a [u8]>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OpcuaNodeId<'a> {
+    pub namespace: u16,
+    pub identifier: OpcuaIdentifier<'a>,
+}
+
+impl<'a> OpcuaNodeId<'a> {
+    /// Two-byte, four-byte, numeric, string, GUID or opaque encoding; the
+    /// ExpandedNodeId flags (0x40, 0x80) are rejected here.
+    pub fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        let (encoding, rest) = extract_opcua_u8(buf, "NodeId encoding")?;
+        if encoding & 0xC0 != 0 {
+            return Err(OpcuaDecodeError::UnknownNodeIdEncoding(encoding));
+        }
+        decode_node_id_body(encoding, rest)
+    }
+
+    /// Identifier of a namespace 0 numeric NodeId, the form of every
+    /// standard type and encoding id.
+    pub fn ns0_numeric(&self) -> Option<u32> {
+        match (self.namespace, self.identifier) {
+            (0, OpcuaIdentifier::Numeric(id)) => Some(id),
+            _ => None,
+        }
+    }
+
+    pub fn is_null(&self) -> bool {
+        self.ns0_numeric() == Some(0)
+    }
+}
+
+/// Text form of Part 6, 5.3.1.10: `i=2258`, `ns=2;s=Pump.Speed`...
+impl fmt::Display for OpcuaNodeId<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.namespace != 0 {
+            write!(f, "ns={};", self.namespace)?;
+        }
+        match self.identifier {
+            OpcuaIdentifier::Numeric(id) => write!(f, "i={id}"),
+            OpcuaIdentifier::String(value) => write!(f, "s={}", value.unwrap_or_default()),
+            OpcuaIdentifier::Guid(guid) => write!(f, "g={guid}"),
+            OpcuaIdentifier::Opaque(value) => {
+                write!(f, "b=")?;
+                value
+                    .unwrap_or_default()
+                    .iter()
+                    .try_for_each(|byte| write!(f, "{byte:02x}"))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OpcuaExpandedNodeId<'a> {
+    pub node_id: OpcuaNodeId<'a>,
+    pub namespace_uri: Option<&'a str>,
+    pub server_index: u32,
+}
+
+impl<'a> OpcuaExpandedNodeId<'a> {
+    pub fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        let (encoding, rest) = extract_opcua_u8(buf, "NodeId encoding")?;
+        let (node_id, rest) = decode_node_id_body(encoding & 0x3F, rest)?;
+        let (namespace_uri, rest) = if encoding & 0x80 != 0 {
+            extract_opcua_string(rest, "namespace uri")?
+        } else {
+            (None, rest)
+        };
+        let (server_index, rest) = if encoding & 0x40 != 0 {
+            extract_opcua_u32(rest, "server index")?
+        } else {
+            (0, rest)
+        };
+        Ok((
+            OpcuaExpandedNodeId {
+                node_id,
+                namespace_uri,
+                server_index,
+            },
+            rest,
+        ))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OpcuaQualifiedName<'a> {
+    pub namespace: u16,
+    pub name: Option<&'a str>,
+}
+
+impl<'a> OpcuaQualifiedName<'a> {
+    pub fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        let (namespace, rest) = extract_opcua_u16(buf, "QualifiedName namespace")?;
+        let (name, rest) = extract_opcua_string(rest, "QualifiedName name")?;
+        Ok((OpcuaQualifiedName { namespace, name }, rest))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct OpcuaLocalizedText<'a> {
+    pub locale: Option<&'a str>,
+    pub text: Option<&'a str>,
+}
+
+impl<'a> OpcuaLocalizedText<'a> {
+    pub fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        let (mask, rest) = extract_opcua_u8(buf, "LocalizedText mask")?;
+        let (locale, rest) = if mask & 0x01 != 0 {
+            extract_opcua_string(rest, "LocalizedText locale")?
+        } else {
+            (None, rest)
+        };
+        let (text, rest) = if mask & 0x02 != 0 {
+            extract_opcua_string(rest, "LocalizedText text")?
+        } else {
+            (None, rest)
+        };
+        Ok((OpcuaLocalizedText { locale, text }, rest))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OpcuaExtensionBody<'a> {
+    None,
+    Binary(&'a [u8]),
+    Xml(&'a [u8]),
+}
+
+/// Structure the receiver may not know, tagged with the NodeId of its
+/// encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OpcuaExtensionObject<'a> {
+    pub type_id: OpcuaNodeId<'a>,
+    pub body: OpcuaExtensionBody<'a>,
+}
+
+impl<'a> OpcuaExtensionObject<'a> {
+    pub fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        let (type_id, rest) = OpcuaNodeId::decode(buf)?;
+        let (encoding, rest) = extract_opcua_u8(rest, "ExtensionObject encoding")?;
+        let (body, rest) = match encoding {
+            0x00 => (OpcuaExtensionBody::None, rest),
+            0x01 | 0x02 => {
+                let (body, rest) = extract_opcua_byte_string(rest, "ExtensionObject body")?;
+                let body = body.unwrap_or_default();
+                if encoding == 0x01 {
+                    (OpcuaExtensionBody::Binary(body), rest)
+                } else {
+                    (OpcuaExtensionBody::Xml(body), rest)
+                }
+            }
+            _ => return Err(OpcuaDecodeError::UnknownExtensionObjectEncoding(encoding)),
+        };
+        Ok((OpcuaExtensionObject { type_id, body }, rest))
+    }
+
+    /// Binary body when the type id is the namespace 0 encoding `encoding_id`.
+    pub fn binary_body(&self, encoding_id: u32) -> Option<&'a [u8]> {
+        match self.body {
+            OpcuaExtensionBody::Binary(body) if self.type_id.ns0_numeric() == Some(encoding_id) => {
+                Some(body)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Value of one of the 25 built-in types.
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpcuaScalar<'a> {
+    Boolean(bool),
+    SByte(i8),
+    Byte(u8),
+    Int16(i16),
+    UInt16(u16),
+    Int32(i32),
+    UInt32(u32),
+    Int64(i64),
+    UInt64(u64),
+    Float(f32),
+    Double(f64),
+    String(Option<&'a str>),
+    DateTime(OpcuaDateTime),
+    Guid(OpcuaGuid),
+    ByteString(Option<&'a [u8]>),
+    XmlElement(Option<&'a [u8]>),
+    NodeId(OpcuaNodeId<'a>),
+    ExpandedNodeId(OpcuaExpandedNodeId<'a>),
+    StatusCode(u32),
+    QualifiedName(OpcuaQualifiedName<'a>),
+    LocalizedText(OpcuaLocalizedText<'a>),
+    ExtensionObject(OpcuaExtensionObject<'a>),
+    DataValue(Box<OpcuaDataValue<'a>>),
+    Variant(Box<OpcuaVariant<'a>>),
+    DiagnosticInfo(Box<OpcuaDiagnosticInfo<'a>>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OpcuaVariant<'a> {
+    Null,
+    Scalar(OpcuaScalar<'a>),
+    /// One-dimensional array, or a matrix flattened in row-major order with
+    /// its `dimensions`.
+    Array {
+        type_id: u8,
+        values: Vec<OpcuaScalar<'a>>,
+        dimensions: Option<Vec<i32>>,
+    },
+}
+
+impl<'a> OpcuaVariant<'a> {
+    pub fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        decode_variant(buf, 0)
+    }
+}
+
+/// Value with its quality and timestamps, as read, written or notified.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct OpcuaDataValue<'a> {
+    pub value: Option<OpcuaVariant<'a>>,
+    /// StatusCode, absent when Good.
+    pub status: Option<u32>,
+    pub source_timestamp: Option<OpcuaDateTime>,
+    pub source_picoseconds: Option<u16>,
+    pub server_timestamp: Option<OpcuaDateTime>,
+    pub server_picoseconds: Option<u16>,
+}
+
+impl<'a> OpcuaDataValue<'a> {
+    pub fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        decode_data_value(buf, 0)
+    }
+
+    pub fn status_code(&self) -> u32 {
+        self.status.unwrap_or(0)
+    }
+}
+
+/// Vendor-specific error details; the integers index the response string
+/// table.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct OpcuaDiagnosticInfo<'a> {
+    pub symbolic_id: Option<i32>,
+    pub namespace_uri: Option<i32>,
+    pub locale: Option<i32>,
+    pub localized_text: Option<i32>,
+    pub additional_info: Option<&'a str>,
+    pub inner_status_code: Option<u32>,
+    pub inner_diagnostic_info: Option<Box<OpcuaDiagnosticInfo<'a>>>,
+}
+
+impl<'a> OpcuaDiagnosticInfo<'a> {
+    pub fn decode(buf: &'a [u8]) -> Result<(Self, &'a [u8]), OpcuaDecodeError> {
+        decode_diagnostic_info(buf, 0)
+    }
+}
+
+/// Decode un tableau OPC UA (longueur i32, -1 = null rendu vide) element
+/// par element. `min_item_len` borne la preallocation pilotee par la
+/// longueur annoncee.
+pub(crate) fn decode_opcua_array<'a, T>(
+    buf: &'a [u8],
+    field: &'static str,
+    min_item_len: usize,
+    mut decode: impl FnMut(&'a [u8]) -> Result<(T, &'a [u8]), OpcuaDecodeError>,
+) -> Result<(Vec<T>, &'a [u8]), OpcuaDecodeError> {
+    let (length, mut rest) = extract_opcua_length(buf, field)?;
+    let length = length.unwrap_or(0);
+    let mut values = Vec::with_capacity(bounded_capacity(length, rest.len(), min_item_len));
+    for _ in 0..length {
+        let (value, next) = decode(rest)?;
+        values.push(value);
+        rest = next;
+    }
+    Ok((values, rest))
+}
+
+fn decode_node_id_body(
+    encoding: u8,
+    buf: &[u8],
+) -> Result<(OpcuaNodeId<'_>, &[u8]), OpcuaDecodeError> {
+    let (namespace, identifier, rest) = match encoding {
+        0x00 => {
+            let (id, rest) = extract_opcua_u8(buf, "NodeId identifier")?;
+            (0, OpcuaIdentifier::Numeric(u32::from(id)), rest)
+        }
+        0x01 => {
+            let (namespace, rest) = extract_opcua_u8(buf, "NodeId namespace")?;
+            let (id, rest) = extract_opcua_u16(rest, "NodeId identifier")?;
+            (
+                u16::from(namespace),
+                OpcuaIdentifier::Numeric(u32::from(id)),
+                rest,
+            )
+        }
+        0x02..=0x05 => {
+            let (namespace, rest) = extract_opcua_u16(buf, "NodeId namespace")?;
+            let (identifier, rest) = match encoding {
+                0x02 => {
+                    let (id, rest) = extract_opcua_u32(rest, "NodeId identifier")?;
+                    (OpcuaIdentifier::Numeric(id), rest)
+                }
+                0x03 => {
+                    let (id, rest) = extract_opcua_string(rest, "NodeId identifier")?;
+                    (OpcuaIdentifier::String(id), rest)
+                }
+                0x04 => {
+                    let (guid, rest) = extract_opcua_array::<16>(rest, "NodeId identifier")?;
+                    (OpcuaIdentifier::Guid(OpcuaGuid(guid)), rest)
+                }
+                _ => {
+                    let (id, rest) = extract_opcua_byte_string(rest, "NodeId identifier")?;
+                    (OpcuaIdentifier::Opaque(id), rest)
+                }
+            };
+            (namespace, identifier, rest)
+        }
+        _ => return Err(OpcuaDecodeError::UnknownNodeIdEncoding(encoding)),
+    };
+    Ok((
+        OpcuaNodeId {
+            namespace,
+            identifier,
+        },
+        rest,
+    ))
+}
+
+/// Masque d'encodage : bits 0-5 = type, 0x80 = tableau, 0x40 = dimensions.
+fn decode_variant(buf: &[u8], depth: usize) -> Result<(OpcuaVariant<'_>, &[u8]), OpcuaDecodeError> {
+    validate_opcua_depth(depth)?;
+    let (mask, rest) = extract_opcua_u8(buf, "Variant mask")?;
+    let type_id = mask & 0x3F;
+    if type_id == 0 {
+        return Ok((OpcuaVariant::Null, rest));
+    }
+    if mask & 0x80 == 0 {
+        let (value, rest) = decode_scalar(type_id, rest, depth)?;
+        return Ok((OpcuaVariant::Scalar(value), rest));
+    }
+
+    let (values, rest) = decode_opcua_array(rest, "Variant array", 1, |item| {
+        decode_scalar(type_id, item, depth)
+    })?;
+    let (dimensions, rest) = if mask & 0x40 != 0 {
+        let (dimensions, rest) = decode_opcua_array(rest, "Variant dimensions", 4, |item| {
+            extract_opcua_i32(item, "Variant dimensions")
+        })?;
+        (Some(dimensions), rest)
+    } else {
+        (None, rest)
+    };
+    Ok((
+        OpcuaVariant::Array {
+            type_id,
+            values,
+            dimensions,
+        },
+        rest,
+    ))
+}
+
+fn decode_scalar(
+    type_id: u8,
+    buf: &[u8],
+    depth: usize,
+) -> Result<(OpcuaScalar<'_>, &[u8]), OpcuaDecodeError> {
+    const FIELD: &str = "Variant value";
+    let scalar = match type_id {
+        1 => extract_opcua_bool(buf, FIELD).map(|(v, r)| (OpcuaScalar::Boolean(v), r))?,
+        2 => extract_opcua_u8(buf, FIELD).map(|(v, r)| (OpcuaScalar::SByte(v as i8), r))?,
+        3 => extract_opcua_u8(buf, FIELD).map(|(v, r)| (OpcuaScalar::Byte(v), r))?,
+        4 => extract_opcua_u16(buf, FIELD).map(|(v, r)| (OpcuaScalar::Int16(v as i16), r))?,
+        5 => extract_opcua_u16(buf, FIELD).map(|(v, r)| (OpcuaScalar::UInt16(v), r))?,
+        6 => extract_opcua_i32(buf, FIELD).map(|(v, r)| (OpcuaScalar::Int32(v), r))?,
+        7 => extract_opcua_u32(buf, FIELD).map(|(v, r)| (OpcuaScalar::UInt32(v), r))?,
+        8 => extract_opcua_i64(buf, FIELD).map(|(v, r)| (OpcuaScalar::Int64(v), r))?,
+        9 => extract_opcua_u64(buf, FIELD).map(|(v, r)| (OpcuaScalar::UInt64(v), r))?,
+        10 => extract_opcua_array::<4>(buf, FIELD)
+            .map(|(v, r)| (OpcuaScalar::Float(f32::from_le_bytes(v)), r))?,
+        11 => extract_opcua_f64(buf, FIELD).map(|(v, r)| (OpcuaScalar::Double(v), r))?,
+        12 => extract_opcua_string(buf, FIELD).map(|(v, r)| (OpcuaScalar::String(v), r))?,
+        13 => extract_opcua_i64(buf, FIELD)
+            .map(|(v, r)| (OpcuaScalar::DateTime(OpcuaDateTime(v)), r))?,
+        14 => extract_opcua_array::<16>(buf, FIELD)
+            .map(|(v, r)| (OpcuaScalar::Guid(OpcuaGuid(v)), r))?,
+        15 => {
+            extract_opcua_byte_string(buf, FIELD).map(|(v, r)| (OpcuaScalar::ByteString(v), r))?
+        }
+        16 => {
+            extract_opcua_byte_string(buf, FIELD).map(|(v, r)| (OpcuaScalar::XmlElement(v), r))?
+        }
+        17 => OpcuaNodeId::decode(buf).map(|(v, r)| (OpcuaScalar::NodeId(v), r))?,
+        18 => OpcuaExpandedNodeId::decode(buf).map(|(v, r)| (OpcuaScalar::ExpandedNodeId(v), r))?,
+        19 => extract_opcua_u32(buf, FIELD).map(|(v, r)| (OpcuaScalar::StatusCode(v), r))?,
+        20 => OpcuaQualifiedName::decode(buf).map(|(v, r)| (OpcuaScalar::QualifiedName(v), r))?,
+        21 => OpcuaLocalizedText::decode(buf).map(|(v, r)| (OpcuaScalar::LocalizedText(v), r))?,
+        22 => {
+            OpcuaExtensionObject::decode(buf).map(|(v, r)| (OpcuaScalar::ExtensionObject(v), r))?
+        }
+        23 => decode_data_value(buf, depth + 1)
+            .map(|(v, r)| (OpcuaScalar::DataValue(Box::new(v)), r))?,
+        24 => {
+            decode_variant(buf, depth + 1).map(|(v, r)| (OpcuaScalar::Variant(Box::new(v)), r))?
+        }
+        25 => decode_diagnostic_info(buf, depth + 1)
+            .map(|(v, r)| (OpcuaScalar::DiagnosticInfo(Box::new(v)), r))?,
+        _ => return Err(OpcuaDecodeError::UnknownVariantType(type_id)),
+    };
+    Ok(scalar)
+}
+
+/// Masque : 0x01 valeur, 0x02 statut, 0x04 horodatage source, 0x08
+/// horodatage serveur, 0x10 / 0x20 picosecondes ; ordre sur le fil :
+/// valeur, statut, source, picosecondes source, serveur, picosecondes
+/// serveur.
+fn decode_data_value(
+    buf: &[u8],
+    depth: usize,
+) -> Result<(OpcuaDataValue<'_>, &[u8]), OpcuaDecodeError> {
+    validate_opcua_depth(depth)?;
+    let (mask, mut rest) = extract_opcua_u8(buf, "DataValue mask")?;
+    let mut data_value = OpcuaDataValue::default();
+    if mask & 0x01 != 0 {
+        let (value, next) = decode_variant(rest, depth + 1)?;
+        data_value.value = Some(value);
+        rest = next;
+    }
+    if mask & 0x02 != 0 {
+        let (status, next) = extract_opcua_u32(rest, "DataValue status")?;
+        data_value.status = Some(status);
+        rest = next;
+    }
+    if mask & 0x04 != 0 {
+        let (timestamp, next) = extract_opcua_i64(rest, "DataValue source timestamp")?;
+        data_value.source_timestamp = Some(OpcuaDateTime(timestamp));
+        rest = next;
+    }
+    if mask & 0x10 != 0 {
+        let (picoseconds, next) = extract_opcua_u16(rest, "DataValue source picoseconds")?;
+        data_value.source_picoseconds = Some(picoseconds);
+        rest = next;
+    }
+    if mask & 0x08 != 0 {
+        let (timestamp, next) = extract_opcua_i64(rest, "DataValue server timestamp")?;
+        data_value.server_timestamp = Some(OpcuaDateTime(timestamp));
+        rest = next;
+    }
+    if mask & 0x20 != 0 {
+        let (picoseconds, next) = extract_opcua_u16(rest, "DataValue server picoseconds")?;
+        data_value.server_picoseconds = Some(picoseconds);
+        rest = next;
+    }
+    Ok((data_value, rest))
+}
+
+fn decode_diagnostic_info(
+    buf: &[u8],
+    depth: usize,
+) -> Result<(OpcuaDiagnosticInfo<'_>, &[u8]), OpcuaDecodeError> {
+    validate_opcua_depth(depth)?;
+    const FIELD: &str = "DiagnosticInfo";
+    let (mask, mut rest) = extract_opcua_u8(buf, FIELD)?;
+    let mut info = OpcuaDiagnosticInfo::default();
+    let optional_i32 = |bit: u8, rest: &mut &[u8]| -> Result<Option<i32>, OpcuaDecodeError> {
+        if mask & bit == 0 {
+            return Ok(None);
+        }
+        let (value, next) = extract_opcua_i32(rest, FIELD)?;
+        *rest = next;
+        Ok(Some(value))
+    };
+    info.symbolic_id = optional_i32(0x01, &mut rest)?;
+    info.namespace_uri = optional_i32(0x02, &mut rest)?;
+    info.locale = optional_i32(0x08, &mut rest)?;
+    info.localized_text = optional_i32(0x04, &mut rest)?;
+    if mask & 0x10 != 0 {
+        let (additional_info, next) = extract_opcua_string(rest, FIELD)?;
+        info.additional_info = additional_info;
+        rest = next;
+    }
+    if mask & 0x20 != 0 {
+        let (status, next) = extract_opcua_u32(rest, FIELD)?;
+        info.inner_status_code = Some(status);
+        rest = next;
+    }
+    if mask & 0x40 != 0 {
+        let (inner, next) = decode_diagnostic_info(rest, depth + 1)?;
+        info.inner_diagnostic_info = Some(Box::new(inner));
+        rest = next;
+    }
+    Ok((info, rest))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_node_id_encodings() {
+        // Deux octets (i=85), quatre octets (ns=1;i=1000), chaine, GUID.
+        let (node, rest) = OpcuaNodeId::decode(&[0x00, 0x55, 0xAA]).expect("two-byte NodeId");
+        assert_eq!(node.ns0_numeric(), Some(85));
+        assert_eq!(rest, &[0xAA]);
+
+        let (node, _) = OpcuaNodeId::decode(&[0x01, 0x01, 0xE8, 0x03]).expect("four-byte NodeId");
+        assert_eq!(node.to_string(), "ns=1;i=1000");
+
+        let bytes = hex::decode("030200050000004d6f746f72").expect("invalid hex fixture");
+        let (node, rest) = OpcuaNodeId::decode(&bytes).expect("string NodeId");
+        assert_eq!(node.to_string(), "ns=2;s=Motor");
+        assert!(rest.is_empty());
+
+        let bytes = hex::decode("040100914dbde1f1c4d011b23400c04fd430c8").expect("invalid hex");
+        let (node, _) = OpcuaNodeId::decode(&bytes).expect("GUID NodeId");
+        assert_eq!(
+            node.to_string(),
+            "ns=1;g=e1bd4d91-c4f1-11d0-b234-00c04fd430c8"
+        );
+
+        assert_eq!(
+            OpcuaNodeId::decode(&[0x06, 0x00]),
+            Err(OpcuaDecodeError::UnknownNodeIdEncoding(0x06))
+        );
+    }
+
+    #[test]
+    fn decode_expanded_node_id_with_uri_and_server() {
+        // Synthetique : i=2253 avec URI de namespace "urn:x" et serveur 3.
+        let bytes = hex::decode("c1 00 cd08 05000000 75726e3a78 03000000".replace(' ', ""))
+            .expect("invalid hex fixture");
+        let (node, rest) = OpcuaExpandedNodeId::decode(&bytes).expect("expanded NodeId");
+        assert_eq!(node.node_id.ns0_numeric(), Some(2253));
+        assert_eq!(node.namespace_uri, Some("urn:x"));
+        assert_eq!(node.server_index, 3);
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn decode_variant_scalars_and_arrays() {
+        // Double 21.5.
+        let mut bytes = vec![0x0B];
+        bytes.extend_from_slice(&21.5f64.to_le_bytes());
+        let (variant, _) = OpcuaVariant::decode(&bytes).expect("double variant");
+        assert_eq!(variant, OpcuaVariant::Scalar(OpcuaScalar::Double(21.5)));
+
+        // Matrice 2x2 d'Int16 avec dimensions.
+        let bytes = hex::decode("c404000000010002000300040002000000020000000200000000")
+            .expect("invalid hex");
+        let (variant, rest) = OpcuaVariant::decode(&bytes).expect("matrix variant");
+        assert_eq!(
+            variant,
+            OpcuaVariant::Array {
+                type_id: 4,
+                values: vec![
+                    OpcuaScalar::Int16(1),
+                    OpcuaScalar::Int16(2),
+                    OpcuaScalar::Int16(3),
+                    OpcuaScalar::Int16(4)
+                ],
+                dimensions: Some(vec![2, 2]),
+            }
+        );
+        assert_eq!(rest, &[0x00]);
+
+        assert_eq!(
+            OpcuaVariant::decode(&[0x3F]),
+            Err(OpcuaDecodeError::UnknownVariantType(0x3F))
+        );
+    }
+
+    #[test]
+    fn reject_deeply_nested_variant() {
+        // Variant de Variant de ... : la profondeur est bornee avant la pile.
+        let bytes = vec![0x18; 64];
+        assert_eq!(
+            OpcuaVariant::decode(&bytes),
+            Err(OpcuaDecodeError::NestingTooDeep)
+        );
+    }
+
+    #[test]
+    fn decode_data_value_fields_in_wire_order() {
+        // Synthetique : UInt32 7, statut Uncertain, horodatages source et
+        // serveur, picosecondes source seulement.
+        let mut bytes = vec![0x1F, 0x07, 0x07, 0x00, 0x00, 0x00];
+        bytes.extend_from_slice(&0x4000_0000u32.to_le_bytes());
+        bytes.extend_from_slice(&OPCUA_UNIX_EPOCH_TICKS.to_le_bytes());
+        bytes.extend_from_slice(&500u16.to_le_bytes());
+        bytes.extend_from_slice(&(OPCUA_UNIX_EPOCH_TICKS + 10_000_000).to_le_bytes());
+
+        let (value, rest) = OpcuaDataValue::decode(&bytes).expect("data value");
+        assert_eq!(
+            value.value,
+            Some(OpcuaVariant::Scalar(OpcuaScalar::UInt32(7)))
+        );
+        assert_eq!(value.status_code(), 0x4000_0000);
+        assert_eq!(
+            value.source_timestamp.and_then(|t| t.unix_millis()),
+            Some(0)
+        );
+        assert_eq!(value.source_picoseconds, Some(500));
+        assert_eq!(
+            value.server_timestamp.and_then(|t| t.unix_millis()),
+            Some(1000)
+        );
+        assert!(rest.is_empty());
+    }
+
+    #[test]
+    fn decode_localized_text_and_diagnostic_info() {
+        let bytes = hex::decode("0302000000656e0400000050756d70").expect("invalid hex");
+        let (text, _) = OpcuaLocalizedText::decode(&bytes).expect("localized text");
+        assert_eq!(text.locale, Some("en"));
+        assert_eq!(text.text, Some("Pump"));
+
+        // Synthetique : SymbolicId 1 et statut interne BadTimeout.
+        let bytes = hex::decode("210100000000000a80").expect("invalid hex");
+        let (info, _) = OpcuaDiagnosticInfo::decode(&bytes).expect("diagnostic info");
+        assert_eq!(info.symbolic_id, Some(1));
+        assert_eq!(info.inner_status_code, Some(0x800A_0000));
+    }
+}
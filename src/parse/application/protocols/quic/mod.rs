@@ -3,12 +3,16 @@
 // Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
 // This file may not be copied, modified, or distributed except according to those terms.
 
+pub mod stream;
+
 use crate::{
     checks::application::quic::{
-        QUIC_RETRY_INTEGRITY_TAG_LEN, QuicCursor, extract_first_byte, extract_version, read_cid,
-        read_pn_and_payload, split_retry_token_and_tag,
+        QUIC_FRAME_ACK, QUIC_FRAME_ACK_ECN, QUIC_FRAME_CRYPTO, QUIC_FRAME_PADDING, QUIC_FRAME_PING,
+        QUIC_RETRY_INTEGRITY_TAG_LEN, QuicCursor, extract_first_byte, extract_packet_type,
+        extract_version, read_cid, read_pn_and_payload, split_retry_token_and_tag,
     },
     errors::application::quic::QuicError,
+    parse::application::protocols::tls::handshake::{TlsClientHello, TlsHandshake},
 };
 
 /// QUIC Long Header Packet
@@ -31,7 +35,7 @@ use crate::{
 /// 376-439: "Token / Length / Packet Number / Payload variable"
 /// ```
 ///
-/// Modélisation minimale d'un paquet QUIC v1 (RFC 9000/9001) avec Long Header:
+/// Modélisation minimale d'un paquet QUIC v1 ou v2 (RFC 9000/9001/9369) avec Long Header:
 /// couvre `Initial` et `Handshake`, ainsi que quelques frames fréquentes.
 ///
 /// Le parsing est zero-copy : `token`, les Connection IDs et le payload sont
@@ -43,7 +47,9 @@ use crate::{
 /// - Le champ `length` du Long Header inclut PN + payload chiffré (frames).
 /// - Le `packet_number` est encodé sur 1..=4 octets ; on expose ici la longueur et la valeur étendue.
 /// - Les frames peuvent rester chiffrées selon le contexte ; si tu ne déchiffres pas,
-///   utilise `QuicPayload::EncryptedPayload(&'a [u8])`.
+///   utilise `QuicPayload::EncryptedPayload(&'a [u8])`. Les paquets Initial se
+///   déchiffrent avec `decrypt::quic` (feature `decrypt`), puis
+///   [`parse_quic_frames`] décode le texte clair.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum QuicPacket<'a> {
     /// Paquet QUIC avec Long Header de type Initial (Packet Type = 0x00)
@@ -206,6 +212,112 @@ pub struct CryptoFrame<'a> {
     pub data: &'a [u8],
 }
 
+impl<'a> CryptoFrame<'a> {
+    /// ClientHello porte par la frame, pret pour les empreintes JA3/JA4
+    /// (avec [`Ja4Protocol::Quic`](super::fingerprint::Ja4Protocol::Quic)).
+    ///
+    /// QUIC transporte les messages handshake sans record layer (RFC 9001
+    /// §4.1.3). `None` si la frame ne commence pas le flux CRYPTO ou si le
+    /// ClientHello deborde sur une frame suivante.
+    pub fn client_hello(&self) -> Option<TlsClientHello<'a>> {
+        if self.offset != 0 {
+            return None;
+        }
+        TlsHandshake::try_from(self.data).ok()?.client_hello().ok()
+    }
+}
+
+/// Exposant `ack_delay_exponent` par defaut (RFC 9000 §18.2) : le parametre
+/// de transport negocie n'est pas connu d'un parseur stateless.
+const DEFAULT_ACK_DELAY_EXPONENT: u32 = 3;
+
+/// Decode les frames d'un payload QUIC dechiffre (RFC 9000 §19).
+///
+/// Couvre les frames autorisees dans les paquets Initial et Handshake :
+/// PADDING (les octets nuls consecutifs forment une seule frame), PING, ACK
+/// et CRYPTO. Un autre type s'arrete sur `QuicFrame::Unknown`, qui garde le
+/// reste du payload : sans connaitre sa structure, la frame suivante ne peut
+/// pas etre localisee.
+pub fn parse_quic_frames(buf: &[u8]) -> Result<Vec<QuicFrame<'_>>, QuicError> {
+    let mut cur = QuicCursor::new(buf);
+    let mut frames = Vec::new();
+
+    while cur.remaining() > 0 {
+        let frame_type = cur.read_varint()?;
+        let frame = match frame_type {
+            QUIC_FRAME_PADDING => QuicFrame::Padding {
+                length: read_padding(&mut cur),
+            },
+            QUIC_FRAME_PING => QuicFrame::Ping,
+            QUIC_FRAME_ACK | QUIC_FRAME_ACK_ECN => QuicFrame::Ack(read_ack(&mut cur, frame_type)?),
+            QUIC_FRAME_CRYPTO => QuicFrame::Crypto(read_crypto(&mut cur)?),
+            _ => {
+                frames.push(QuicFrame::Unknown {
+                    frame_type,
+                    raw: cur.take_rest(),
+                });
+                break;
+            }
+        };
+        frames.push(frame);
+    }
+
+    Ok(frames)
+}
+
+/// Lit la suite d'une frame PADDING : les octets nuls consecutifs forment
+/// une seule frame, dont la longueur inclut l'octet de type deja lu.
+pub(crate) fn read_padding(cur: &mut QuicCursor<'_>) -> u64 {
+    let mut length = 1;
+    let mut peek = *cur;
+    while peek.take_u8() == Ok(0) {
+        *cur = peek;
+        length += 1;
+    }
+    length
+}
+
+/// Lit le corps d'une frame ACK ou ACK_ECN (RFC 9000 §19.3).
+pub(crate) fn read_ack(cur: &mut QuicCursor<'_>, frame_type: u64) -> Result<AckFrame, QuicError> {
+    let largest_acknowledged = cur.read_varint()?;
+    let ack_delay = cur.read_varint()?;
+    let ack_range_count = cur.read_varint()?;
+    let first_ack_range = cur.read_varint()?;
+    let mut additional_ranges = Vec::new();
+    for _ in 0..ack_range_count {
+        // Le nombre annonce est borne par le buffer : chaque
+        // range consomme au moins deux octets.
+        let gap = cur.read_varint()?;
+        let ack_range_len = cur.read_varint()?;
+        additional_ranges.push(AckRange { gap, ack_range_len });
+    }
+    if frame_type == QUIC_FRAME_ACK_ECN {
+        // Compteurs ECT0, ECT1 et ECN-CE, non exposes.
+        for _ in 0..3 {
+            cur.read_varint()?;
+        }
+    }
+    Ok(AckFrame {
+        largest_acknowledged,
+        ack_delay_us: ack_delay.saturating_mul(1 << DEFAULT_ACK_DELAY_EXPONENT),
+        ack_range_count,
+        first_ack_range,
+        additional_ranges,
+    })
+}
+
+/// Lit le corps d'une frame CRYPTO (RFC 9000 §19.6).
+pub(crate) fn read_crypto<'a>(cur: &mut QuicCursor<'a>) -> Result<CryptoFrame<'a>, QuicError> {
+    let offset = cur.read_varint()?;
+    let length = cur.read_varint()?;
+    let data = cur.take(length as usize)?;
+    Ok(CryptoFrame {
+        offset,
+        length,
+        data,
+    })
+}
+
 impl<'a> TryFrom<&'a [u8]> for QuicPacket<'a> {
     type Error = QuicError;
 
@@ -217,10 +329,11 @@ impl<'a> TryFrom<&'a [u8]> for QuicPacket<'a> {
 
         // 1) Octet 0 : Long Header bits (NotLongHeader prime sur FixedBitNotSet)
         let b0 = cur.take_u8()?;
-        let (packet_type, pn_length) = extract_first_byte(b0)?;
+        let (_, pn_length) = extract_first_byte(b0)?;
 
-        // 2) Version
+        // 2) Version, puis type de paquet : son codage depend de la version.
         let version = extract_version(&mut cur)?;
+        let packet_type = extract_packet_type(b0, version);
 
         // 3) DCID / SCID
         let dcid = read_cid(&mut cur)?;
@@ -480,6 +593,7 @@ mod tests {
 #[cfg(test)]
 mod extra_tests {
     use super::*;
+    use crate::checks::application::quic::QUIC_V2;
 
     /// En-tête long commun : version 1, DCID de 2 octets, SCID vide.
     fn long_header(first_byte: u8) -> Vec<u8> {
@@ -782,4 +896,112 @@ mod extra_tests {
             Err(QuicError::PayloadTooShort { .. })
         ));
     }
+
+    /// ClientHello de l'Initial client de la RFC 9001 (annexe A.2), tel que
+    /// porte en clair par la frame CRYPTO.
+    const RFC9001_CLIENT_HELLO: &str = "010000ed0303ebf8fa56f12939b9584a3896472ec40bb863cfd3e86804fe3a47f06a2b69484c00000413011302010000c000000010000e00000b6578616d706c652e636f6dff01000100000a00080006001d0017001800100007000504616c706e000500050100000000003300260024001d00209370b2c9caa47fbabaf4559fedba753de171fa71f50f1ce15d43e994ec74b748002b0003020304000d0010000e0403050306030203080408050806002d00020101001c00024001003900320408ffffffffffffffff05048000ffff07048000ffff0801100104800075300901100f088394c8f03e51570806048000ffff";
+
+    #[test]
+    fn crypto_frame_exposes_client_hello_fingerprints() {
+        use crate::parse::application::protocols::fingerprint::Ja4Protocol;
+
+        let data = hex::decode(RFC9001_CLIENT_HELLO).unwrap();
+        let frame = CryptoFrame {
+            offset: 0,
+            length: data.len() as u64,
+            data: &data,
+        };
+        let hello = frame.client_hello().expect("ClientHello complet");
+        assert_eq!(hello.server_name(), Some("example.com"));
+        assert_eq!(
+            hello.ja4_r(Ja4Protocol::Quic),
+            "q13d0211an_1301,1302_0005,000a,000d,001c,002b,002d,0033,0039,ff01_\
+             0403,0503,0603,0203,0804,0805,0806"
+        );
+        assert_eq!(
+            hello.ja4(Ja4Protocol::Quic),
+            "q13d0211an_62ed6f6ca7ad_4d634acda6c0"
+        );
+
+        // Fragment suivant ou ClientHello tronque : rien a empreinter.
+        let later = CryptoFrame {
+            offset: 10,
+            ..frame.clone()
+        };
+        assert!(later.client_hello().is_none());
+        let truncated = CryptoFrame {
+            data: &data[..100],
+            ..frame
+        };
+        assert!(truncated.client_hello().is_none());
+    }
+
+    /// Synthetique : ACK (plus grand 5, delai 2, un range additionnel),
+    /// PADDING de 3 octets, CRYPTO puis type inconnu.
+    #[test]
+    fn parse_quic_frames_decodes_initial_frames() {
+        let payload = [
+            0x02, 0x05, 0x02, 0x01, 0x00, 0x01, 0x01, // ACK
+            0x00, 0x00, 0x00, // PADDING x3
+            0x01, // PING
+            0x06, 0x00, 0x02, 0xAB, 0xCD, // CRYPTO offset 0, 2 octets
+            0x1e, 0x99, // HANDSHAKE_DONE (inconnu ici) + reste
+        ];
+        let frames = parse_quic_frames(&payload).expect("frames valides");
+
+        assert_eq!(
+            frames,
+            vec![
+                QuicFrame::Ack(AckFrame {
+                    largest_acknowledged: 5,
+                    ack_delay_us: 16,
+                    ack_range_count: 1,
+                    first_ack_range: 0,
+                    additional_ranges: vec![AckRange {
+                        gap: 1,
+                        ack_range_len: 1
+                    }],
+                }),
+                QuicFrame::Padding { length: 3 },
+                QuicFrame::Ping,
+                QuicFrame::Crypto(CryptoFrame {
+                    offset: 0,
+                    length: 2,
+                    data: &[0xAB, 0xCD],
+                }),
+                QuicFrame::Unknown {
+                    frame_type: 0x1e,
+                    raw: &[0x99],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_quic_frames_rejects_truncated_crypto() {
+        assert_eq!(
+            parse_quic_frames(&[0x06, 0x00, 0x04, 0xAB]),
+            Err(QuicError::Truncated {
+                needed: 4,
+                remaining: 1
+            })
+        );
+    }
+
+    /// Synthetique : en-tete v2, Long Packet Type 01 = Initial (RFC 9369).
+    #[test]
+    fn parses_v2_initial() {
+        let mut buf = vec![0xD0];
+        buf.extend_from_slice(&QUIC_V2.to_be_bytes());
+        buf.extend_from_slice(&[0x00, 0x00, 0x00, 0x02, 0x07, 0xAA]);
+
+        let packet = QuicPacket::try_from(buf.as_slice()).expect("Initial v2 valide");
+        match packet {
+            QuicPacket::Initial { header, .. } => {
+                assert_eq!(header.version, QUIC_V2);
+                assert_eq!(header.packet_type, QuicPacketType::Initial);
+            }
+            other => panic!("attendu Initial, obtenu {other:?}"),
+        }
+    }
 }
@@ -0,0 +1,595 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Frames des paquets 0-RTT et 1-RTT (RFC 9000 §19, RFC 9221) et
+//! reassemblage des flux QUIC a partir de leurs frames STREAM.
+//!
+//! Les paquets 1-RTT ne se lisent qu'une fois dechiffres : voir
+//! `decrypt::quic::QuicShortHeaderDecryptor` (feature `decrypt`).
+
+use std::collections::BTreeMap;
+
+use super::{AckFrame, CryptoFrame, read_ack, read_crypto, read_padding};
+use crate::{
+    checks::application::quic::{
+        QUIC_FRAME_ACK, QUIC_FRAME_ACK_ECN, QUIC_FRAME_CONNECTION_CLOSE,
+        QUIC_FRAME_CONNECTION_CLOSE_APP, QUIC_FRAME_CRYPTO, QUIC_FRAME_DATA_BLOCKED,
+        QUIC_FRAME_DATAGRAM, QUIC_FRAME_DATAGRAM_LEN, QUIC_FRAME_HANDSHAKE_DONE,
+        QUIC_FRAME_MAX_DATA, QUIC_FRAME_MAX_STREAM_DATA, QUIC_FRAME_MAX_STREAMS_BIDI,
+        QUIC_FRAME_MAX_STREAMS_UNI, QUIC_FRAME_NEW_CONNECTION_ID, QUIC_FRAME_NEW_TOKEN,
+        QUIC_FRAME_PADDING, QUIC_FRAME_PATH_CHALLENGE, QUIC_FRAME_PATH_RESPONSE, QUIC_FRAME_PING,
+        QUIC_FRAME_RESET_STREAM, QUIC_FRAME_RETIRE_CONNECTION_ID, QUIC_FRAME_STOP_SENDING,
+        QUIC_FRAME_STREAM, QUIC_FRAME_STREAM_DATA_BLOCKED, QUIC_FRAME_STREAM_MAX,
+        QUIC_FRAME_STREAMS_BLOCKED_BIDI, QUIC_FRAME_STREAMS_BLOCKED_UNI, QUIC_MAX_STREAM_BUFFER,
+        QUIC_MAX_STREAM_OFFSET, QUIC_STREAM_FLAG_FIN, QUIC_STREAM_FLAG_LEN, QUIC_STREAM_FLAG_OFF,
+        QUIC_STREAM_ID_SERVER_INITIATED, QUIC_STREAM_ID_UNIDIRECTIONAL, QuicCursor,
+    },
+    errors::application::quic::{QuicError, QuicStreamError},
+};
+
+/// QUIC STREAM Frame
+///
+/// ```mermaid
+/// ---
+/// title: QuicStreamFrame
+/// ---
+/// packet-beta
+/// 0-7: "Type 0x08-0x0f (OFF/LEN/FIN)"
+/// 8-15: "Stream ID varint"
+/// 16-23: "Offset varint (si OFF)"
+/// 24-31: "Length varint (si LEN)"
+/// 32-63: "Stream Data variable"
+/// ```
+///
+/// Sans le bit LEN, les donnees courent jusqu'a la fin du paquet.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuicStreamFrame<'a> {
+    pub stream_id: u64,
+    /// Position de `data` dans le flux ; 0 sans le bit OFF.
+    pub offset: u64,
+    /// Bit FIN : `offset + data.len()` est la taille finale du flux.
+    pub fin: bool,
+    pub data: &'a [u8],
+}
+
+impl QuicStreamFrame<'_> {
+    /// Flux ouvert par le client (bit 0x01 du Stream ID a zero).
+    pub fn is_client_initiated(&self) -> bool {
+        self.stream_id & QUIC_STREAM_ID_SERVER_INITIATED == 0
+    }
+
+    /// Flux unidirectionnel (bit 0x02 du Stream ID).
+    pub fn is_unidirectional(&self) -> bool {
+        self.stream_id & QUIC_STREAM_ID_UNIDIRECTIONAL != 0
+    }
+}
+
+/// Frame d'un paquet 0-RTT ou 1-RTT.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuicApplicationFrame<'a> {
+    /// PADDING : octets nuls consecutifs regroupes.
+    Padding {
+        length: u64,
+    },
+    Ping,
+    Ack(AckFrame),
+    ResetStream {
+        stream_id: u64,
+        error_code: u64,
+        final_size: u64,
+    },
+    StopSending {
+        stream_id: u64,
+        error_code: u64,
+    },
+    Crypto(CryptoFrame<'a>),
+    NewToken {
+        token: &'a [u8],
+    },
+    Stream(QuicStreamFrame<'a>),
+    MaxData {
+        maximum: u64,
+    },
+    MaxStreamData {
+        stream_id: u64,
+        maximum: u64,
+    },
+    MaxStreams {
+        unidirectional: bool,
+        maximum: u64,
+    },
+    DataBlocked {
+        limit: u64,
+    },
+    StreamDataBlocked {
+        stream_id: u64,
+        limit: u64,
+    },
+    StreamsBlocked {
+        unidirectional: bool,
+        limit: u64,
+    },
+    NewConnectionId {
+        sequence_number: u64,
+        retire_prior_to: u64,
+        connection_id: &'a [u8],
+        stateless_reset_token: &'a [u8],
+    },
+    RetireConnectionId {
+        sequence_number: u64,
+    },
+    PathChallenge {
+        data: &'a [u8],
+    },
+    PathResponse {
+        data: &'a [u8],
+    },
+    /// CONNECTION_CLOSE : `frame_type` n'existe que pour une erreur de
+    /// transport (0x1c), pas pour une fermeture applicative (0x1d).
+    ConnectionClose {
+        application: bool,
+        error_code: u64,
+        frame_type: Option<u64>,
+        reason: &'a [u8],
+    },
+    HandshakeDone,
+    /// DATAGRAM (RFC 9221).
+    Datagram {
+        data: &'a [u8],
+    },
+    /// Type inconnu : garde le reste du payload, comme
+    /// [`QuicFrame::Unknown`](super::QuicFrame::Unknown).
+    Unknown {
+        frame_type: u64,
+        raw: &'a [u8],
+    },
+}
+
+/// Decode les frames d'un payload 0-RTT ou 1-RTT dechiffre (RFC 9000 §19).
+///
+/// Toutes les frames de RFC 9000 sont reconnues, plus DATAGRAM (RFC 9221).
+/// Un type inconnu s'arrete sur `QuicApplicationFrame::Unknown`, comme
+/// [`parse_quic_frames`](super::parse_quic_frames).
+pub fn parse_quic_application_frames(
+    buf: &[u8],
+) -> Result<Vec<QuicApplicationFrame<'_>>, QuicError> {
+    let mut cur = QuicCursor::new(buf);
+    let mut frames = Vec::new();
+
+    while cur.remaining() > 0 {
+        let frame_type = cur.read_varint()?;
+        let frame = match frame_type {
+            QUIC_FRAME_PADDING => QuicApplicationFrame::Padding {
+                length: read_padding(&mut cur),
+            },
+            QUIC_FRAME_PING => QuicApplicationFrame::Ping,
+            QUIC_FRAME_ACK | QUIC_FRAME_ACK_ECN => {
+                QuicApplicationFrame::Ack(read_ack(&mut cur, frame_type)?)
+            }
+            QUIC_FRAME_RESET_STREAM => QuicApplicationFrame::ResetStream {
+                stream_id: cur.read_varint()?,
+                error_code: cur.read_varint()?,
+                final_size: cur.read_varint()?,
+            },
+            QUIC_FRAME_STOP_SENDING => QuicApplicationFrame::StopSending {
+                stream_id: cur.read_varint()?,
+                error_code: cur.read_varint()?,
+            },
+            QUIC_FRAME_CRYPTO => QuicApplicationFrame::Crypto(read_crypto(&mut cur)?),
+            QUIC_FRAME_NEW_TOKEN => {
+                let length = cur.read_varint()? as usize;
+                QuicApplicationFrame::NewToken {
+                    token: cur.take(length)?,
+                }
+            }
+            QUIC_FRAME_STREAM..=QUIC_FRAME_STREAM_MAX => {
+                QuicApplicationFrame::Stream(read_stream(&mut cur, frame_type)?)
+            }
+            QUIC_FRAME_MAX_DATA => QuicApplicationFrame::MaxData {
+                maximum: cur.read_varint()?,
+            },
+            QUIC_FRAME_MAX_STREAM_DATA => QuicApplicationFrame::MaxStreamData {
+                stream_id: cur.read_varint()?,
+                maximum: cur.read_varint()?,
+            },
+            QUIC_FRAME_MAX_STREAMS_BIDI | QUIC_FRAME_MAX_STREAMS_UNI => {
+                QuicApplicationFrame::MaxStreams {
+                    unidirectional: frame_type == QUIC_FRAME_MAX_STREAMS_UNI,
+                    maximum: cur.read_varint()?,
+                }
+            }
+            QUIC_FRAME_DATA_BLOCKED => QuicApplicationFrame::DataBlocked {
+                limit: cur.read_varint()?,
+            },
+            QUIC_FRAME_STREAM_DATA_BLOCKED => QuicApplicationFrame::StreamDataBlocked {
+                stream_id: cur.read_varint()?,
+                limit: cur.read_varint()?,
+            },
+            QUIC_FRAME_STREAMS_BLOCKED_BIDI | QUIC_FRAME_STREAMS_BLOCKED_UNI => {
+                QuicApplicationFrame::StreamsBlocked {
+                    unidirectional: frame_type == QUIC_FRAME_STREAMS_BLOCKED_UNI,
+                    limit: cur.read_varint()?,
+                }
+            }
+            QUIC_FRAME_NEW_CONNECTION_ID => {
+                let sequence_number = cur.read_varint()?;
+                let retire_prior_to = cur.read_varint()?;
+                let length = cur.take_u8()? as usize;
+                QuicApplicationFrame::NewConnectionId {
+                    sequence_number,
+                    retire_prior_to,
+                    connection_id: cur.take(length)?,
+                    stateless_reset_token: cur.take(16)?,
+                }
+            }
+            QUIC_FRAME_RETIRE_CONNECTION_ID => QuicApplicationFrame::RetireConnectionId {
+                sequence_number: cur.read_varint()?,
+            },
+            QUIC_FRAME_PATH_CHALLENGE => QuicApplicationFrame::PathChallenge { data: cur.take(8)? },
+            QUIC_FRAME_PATH_RESPONSE => QuicApplicationFrame::PathResponse { data: cur.take(8)? },
+            QUIC_FRAME_CONNECTION_CLOSE | QUIC_FRAME_CONNECTION_CLOSE_APP => {
+                let application = frame_type == QUIC_FRAME_CONNECTION_CLOSE_APP;
+                let error_code = cur.read_varint()?;
+                let frame_type = if application {
+                    None
+                } else {
+                    Some(cur.read_varint()?)
+                };
+                let length = cur.read_varint()? as usize;
+                QuicApplicationFrame::ConnectionClose {
+                    application,
+                    error_code,
+                    frame_type,
+                    reason: cur.take(length)?,
+                }
+            }
+            QUIC_FRAME_HANDSHAKE_DONE => QuicApplicationFrame::HandshakeDone,
+            QUIC_FRAME_DATAGRAM => QuicApplicationFrame::Datagram {
+                data: cur.take_rest(),
+            },
+            QUIC_FRAME_DATAGRAM_LEN => {
+                let length = cur.read_varint()? as usize;
+                QuicApplicationFrame::Datagram {
+                    data: cur.take(length)?,
+                }
+            }
+            _ => {
+                frames.push(QuicApplicationFrame::Unknown {
+                    frame_type,
+                    raw: cur.take_rest(),
+                });
+                break;
+            }
+        };
+        frames.push(frame);
+    }
+
+    Ok(frames)
+}
+
+/// Lit le corps d'une frame STREAM ; les bits OFF, LEN et FIN sont ceux du
+/// type deja lu.
+fn read_stream<'a>(
+    cur: &mut QuicCursor<'a>,
+    frame_type: u64,
+) -> Result<QuicStreamFrame<'a>, QuicError> {
+    let stream_id = cur.read_varint()?;
+    let offset = if frame_type & QUIC_STREAM_FLAG_OFF != 0 {
+        cur.read_varint()?
+    } else {
+        0
+    };
+    let data = if frame_type & QUIC_STREAM_FLAG_LEN != 0 {
+        let length = cur.read_varint()? as usize;
+        cur.take(length)?
+    } else {
+        cur.take_rest()
+    };
+    Ok(QuicStreamFrame {
+        stream_id,
+        offset,
+        fin: frame_type & QUIC_STREAM_FLAG_FIN != 0,
+        data,
+    })
+}
+
+/// Octets d'un flux QUIC remis dans l'ordre, a partir de frames STREAM
+/// arrivees dans le desordre, dupliquees ou chevauchantes.
+///
+/// Les donnees contigues sont remises des leur arrivee par
+/// [`next_chunk`](Self::next_chunk) ; seules celles qui suivent un trou sont
+/// gardees, dans la limite de [`QUIC_MAX_STREAM_BUFFER`] octets. Une frame
+/// refusee n'est pas conservee et laisse le flux dans son etat precedent.
+#[derive(Debug, Clone, Default)]
+pub struct QuicStreamReassembler {
+    /// Offset du prochain octet a remettre.
+    delivered: u64,
+    /// Segments en attente, par offset de debut.
+    segments: BTreeMap<u64, Vec<u8>>,
+    buffered: usize,
+    final_size: Option<u64>,
+}
+
+impl QuicStreamReassembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Range les donnees d'une frame STREAM.
+    pub fn push(&mut self, frame: &QuicStreamFrame<'_>) -> Result<(), QuicStreamError> {
+        let end = frame
+            .offset
+            .checked_add(frame.data.len() as u64)
+            .filter(|end| *end <= QUIC_MAX_STREAM_OFFSET)
+            .ok_or(QuicStreamError::OffsetOverflow {
+                end: frame.offset.saturating_add(frame.data.len() as u64),
+            })?;
+
+        if frame.fin {
+            if let Some(previous) = self.final_size
+                && previous != end
+            {
+                return Err(QuicStreamError::FinalSizeChanged { previous, new: end });
+            }
+            if end < self.delivered {
+                return Err(QuicStreamError::FinalSizeChanged {
+                    previous: self.delivered,
+                    new: end,
+                });
+            }
+        }
+        if let Some(final_size) = self.final_size
+            && end > final_size
+        {
+            return Err(QuicStreamError::DataBeyondFinalSize { end, final_size });
+        }
+        if frame.fin {
+            self.final_size = Some(end);
+        }
+        if end <= self.delivered {
+            return Ok(());
+        }
+
+        // Retransmission partielle : seul ce qui suit l'offset deja remis
+        // compte.
+        let start = frame.offset.max(self.delivered);
+        let data = &frame.data[(start - frame.offset) as usize..];
+        let replaced = self.segments.get(&start).map_or(0, Vec::len);
+        if replaced >= data.len() {
+            return Ok(());
+        }
+        let buffered = self.buffered - replaced + data.len();
+        if buffered > QUIC_MAX_STREAM_BUFFER {
+            return Err(QuicStreamError::BufferExceeded {
+                buffered,
+                max: QUIC_MAX_STREAM_BUFFER,
+            });
+        }
+        self.buffered = buffered;
+        self.segments.insert(start, data.to_vec());
+        Ok(())
+    }
+
+    /// Prochain bloc d'octets contigus, dans l'ordre du flux.
+    pub fn next_chunk(&mut self) -> Option<Vec<u8>> {
+        loop {
+            let (&start, _) = self.segments.first_key_value()?;
+            if start > self.delivered {
+                return None;
+            }
+            let (_, mut data) = self.segments.pop_first()?;
+            self.buffered -= data.len();
+            let end = start + data.len() as u64;
+            if end <= self.delivered {
+                continue;
+            }
+            data.drain(..(self.delivered - start) as usize);
+            self.delivered = end;
+            return Some(data);
+        }
+    }
+
+    /// Offset du prochain octet a remettre.
+    pub fn delivered(&self) -> u64 {
+        self.delivered
+    }
+
+    /// Vrai quand tout le flux, jusqu'a sa taille finale, a ete remis.
+    pub fn is_finished(&self) -> bool {
+        self.final_size == Some(self.delivered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // -----------------------------------------------------------------------
+    // Frames : synthetiques, encodees a la main d'apres RFC 9000 §19.
+    // -----------------------------------------------------------------------
+
+    #[test]
+    fn parses_stream_frames_with_and_without_length() {
+        // STREAM|OFF|LEN (0x0e) flux 4 offset 3 "abc", puis STREAM|FIN
+        // (0x09) flux 0 jusqu'a la fin du paquet.
+        let payload = hex::decode("0e0403036162630900686921").unwrap();
+        let frames = parse_quic_application_frames(&payload).unwrap();
+
+        assert_eq!(
+            frames,
+            vec![
+                QuicApplicationFrame::Stream(QuicStreamFrame {
+                    stream_id: 4,
+                    offset: 3,
+                    fin: false,
+                    data: b"abc",
+                }),
+                QuicApplicationFrame::Stream(QuicStreamFrame {
+                    stream_id: 0,
+                    offset: 0,
+                    fin: true,
+                    data: b"hi!",
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_control_frames() {
+        let payload = hex::decode(concat!(
+            "1e",                 // HANDSHAKE_DONE
+            "0400010a",           // RESET_STREAM flux 0, code 1, taille 10
+            "124064",             // MAX_STREAMS bidi, varint 2 octets : 100
+            "1a0102030405060708", // PATH_CHALLENGE
+            "1d01026f6b",         // CONNECTION_CLOSE applicatif, code 1, "ok"
+        ))
+        .unwrap();
+        let frames = parse_quic_application_frames(&payload).unwrap();
+
+        assert_eq!(frames[0], QuicApplicationFrame::HandshakeDone);
+        assert_eq!(
+            frames[1],
+            QuicApplicationFrame::ResetStream {
+                stream_id: 0,
+                error_code: 1,
+                final_size: 10,
+            }
+        );
+        assert_eq!(
+            frames[2],
+            QuicApplicationFrame::MaxStreams {
+                unidirectional: false,
+                maximum: 100,
+            }
+        );
+        assert_eq!(
+            frames[3],
+            QuicApplicationFrame::PathChallenge {
+                data: &[1, 2, 3, 4, 5, 6, 7, 8],
+            }
+        );
+        assert_eq!(
+            frames[4],
+            QuicApplicationFrame::ConnectionClose {
+                application: true,
+                error_code: 1,
+                frame_type: None,
+                reason: b"ok",
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_frame_keeps_the_rest() {
+        let frames = parse_quic_application_frames(&[0x01, 0x2f, 0xaa]).unwrap();
+
+        assert_eq!(
+            frames,
+            vec![
+                QuicApplicationFrame::Ping,
+                QuicApplicationFrame::Unknown {
+                    frame_type: 0x2f,
+                    raw: &[0xaa],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn stream_id_bits() {
+        let frame = |stream_id| QuicStreamFrame {
+            stream_id,
+            offset: 0,
+            fin: false,
+            data: b"",
+        };
+
+        assert!(frame(0).is_client_initiated() && !frame(0).is_unidirectional());
+        assert!(frame(2).is_client_initiated() && frame(2).is_unidirectional());
+        assert!(!frame(3).is_client_initiated() && frame(3).is_unidirectional());
+    }
+
+    // -----------------------------------------------------------------------
+    // Reassemblage : synthetique.
+    // -----------------------------------------------------------------------
+
+    fn fragment(offset: u64, data: &'static [u8], fin: bool) -> QuicStreamFrame<'static> {
+        QuicStreamFrame {
+            stream_id: 0,
+            offset,
+            fin,
+            data,
+        }
+    }
+
+    #[test]
+    fn reassembler_delivers_in_order_across_gaps_and_overlaps() {
+        let mut stream = QuicStreamReassembler::new();
+
+        stream.push(&fragment(6, b"ghi", true)).unwrap();
+        assert_eq!(stream.next_chunk(), None);
+        stream.push(&fragment(0, b"abcd", false)).unwrap();
+        assert_eq!(stream.next_chunk().as_deref(), Some(&b"abcd"[..]));
+        assert_eq!(stream.next_chunk(), None);
+
+        // Retransmission chevauchant l'offset deja remis.
+        stream.push(&fragment(2, b"cdef", false)).unwrap();
+        assert_eq!(stream.next_chunk().as_deref(), Some(&b"ef"[..]));
+        assert_eq!(stream.next_chunk().as_deref(), Some(&b"ghi"[..]));
+        assert_eq!(stream.next_chunk(), None);
+        assert!(stream.is_finished());
+        assert_eq!(stream.delivered(), 9);
+    }
+
+    #[test]
+    fn reassembler_enforces_the_final_size() {
+        let mut stream = QuicStreamReassembler::new();
+        stream.push(&fragment(0, b"abc", true)).unwrap();
+
+        assert_eq!(
+            stream.push(&fragment(2, b"cd", false)),
+            Err(QuicStreamError::DataBeyondFinalSize {
+                end: 4,
+                final_size: 3,
+            })
+        );
+        assert_eq!(
+            stream.push(&fragment(0, b"ab", true)),
+            Err(QuicStreamError::FinalSizeChanged {
+                previous: 3,
+                new: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn reassembler_bounds_out_of_order_data() {
+        let mut stream = QuicStreamReassembler::new();
+        let data = vec![0u8; QUIC_MAX_STREAM_BUFFER];
+        stream
+            .push(&QuicStreamFrame {
+                stream_id: 0,
+                offset: 1,
+                fin: false,
+                data: &data,
+            })
+            .unwrap();
+
+        assert_eq!(
+            stream.push(&fragment(QUIC_MAX_STREAM_BUFFER as u64 + 1, b"x", false)),
+            Err(QuicStreamError::BufferExceeded {
+                buffered: QUIC_MAX_STREAM_BUFFER + 1,
+                max: QUIC_MAX_STREAM_BUFFER,
+            })
+        );
+        assert_eq!(
+            stream.push(&fragment(QUIC_MAX_STREAM_OFFSET, b"x", false)),
+            Err(QuicStreamError::OffsetOverflow {
+                end: QUIC_MAX_STREAM_OFFSET + 1,
+            })
+        );
+    }
+}
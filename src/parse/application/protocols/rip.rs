@@ -0,0 +1,332 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Parseurs RIPv1/v2 (RFC 1058, RFC 2453) sur UDP 520 et RIPng (RFC 2080)
+//! sur UDP 521.
+//!
+//! RIPv1 et RIPv2 partagent la disposition des entrees : les champs que v1
+//! laisse a zero (route tag, masque, next hop) sont ceux que v2 ajoute. Une
+//! entree de famille 0xffff porte l'authentification RIPv2 (RFC 2453 §4.1,
+//! RFC 2082/4822) au lieu d'une route.
+
+use std::convert::TryFrom;
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    checks::application::rip::{
+        RIP_ENTRY_LENGTH, RIP_INFINITY_METRIC, extract_rip_entries, validate_rip_metric,
+    },
+    errors::application::rip::RipError,
+};
+
+/// Famille d'adresses d'une entree d'authentification RIPv2.
+const AUTHENTICATION_FAMILY: u16 = 0xffff;
+
+/// Version unique de RIPng.
+const RIPNG_VERSION: u8 = 1;
+
+/// Metrique d'un RTE RIPng qui annonce un next hop au lieu d'une route.
+const RIPNG_NEXT_HOP_METRIC: u8 = 0xff;
+
+fn ipv4_at(bytes: &[u8], offset: usize) -> Ipv4Addr {
+    Ipv4Addr::new(
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    )
+}
+
+/// Commande RIP ou RIPng.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RipCommand {
+    Request,
+    Response,
+}
+
+impl RipCommand {
+    /// L'en-tete a deja ete valide : seules les valeurs 1 et 2 arrivent ici.
+    fn from_validated(command: u8) -> Self {
+        if command == 1 {
+            Self::Request
+        } else {
+            Self::Response
+        }
+    }
+}
+
+/// Entree de route RIPv1/v2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RipEntry {
+    /// 2 (AF_INET) pour une route, 0 dans une requete de table complete.
+    pub address_family: u16,
+    /// Marque de route externe (RIPv2), 0 en v1.
+    pub route_tag: u16,
+    pub address: Ipv4Addr,
+    /// Masque (RIPv2), 0.0.0.0 en v1 ou la classe de l'adresse s'applique.
+    pub subnet_mask: Ipv4Addr,
+    /// Next hop (RIPv2), 0.0.0.0 pour « via l'emetteur ».
+    pub next_hop: Ipv4Addr,
+    /// 1 a 16, 16 signifiant injoignable.
+    pub metric: u32,
+}
+
+impl RipEntry {
+    pub fn is_unreachable(&self) -> bool {
+        self.metric == RIP_INFINITY_METRIC
+    }
+}
+
+/// Entree d'authentification RIPv2 (famille 0xffff).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RipAuthentication<'a> {
+    /// 2 mot de passe en clair, 3 cryptographique (RFC 4822) ; 1 marque le
+    /// condensat place en queue de message.
+    pub authentication_type: u16,
+    /// Les 16 octets qui suivent, zero-copy.
+    pub data: &'a [u8],
+}
+
+/// Message RIPv1 ou RIPv2.
+///
+/// ```mermaid
+/// ---
+/// title: RipPacket (entree RIPv2)
+/// ---
+/// packet-beta
+/// 0-7: "Command u8"
+/// 8-15: "Version u8"
+/// 16-31: "Zero u16"
+/// 32-47: "Address Family u16"
+/// 48-63: "Route Tag u16"
+/// 64-95: "IP Address u32"
+/// 96-127: "Subnet Mask u32"
+/// 128-159: "Next Hop u32"
+/// 160-191: "Metric u32"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RipPacket<'a> {
+    pub command: RipCommand,
+    /// 1 ou 2.
+    pub version: u8,
+    pub entries: Vec<RipEntry>,
+    /// Entrees d'authentification, dans l'ordre du message (en tete, et
+    /// en queue pour le condensat cryptographique).
+    pub authentication: Vec<RipAuthentication<'a>>,
+}
+
+impl RipPacket<'_> {
+    /// Requete de la table complete (RFC 2453 §3.9.1) : une seule entree,
+    /// famille 0 et metrique infinie.
+    pub fn is_whole_table_request(&self) -> bool {
+        self.command == RipCommand::Request
+            && matches!(
+                self.entries.as_slice(),
+                [entry] if entry.address_family == 0 && entry.is_unreachable()
+            )
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for RipPacket<'a> {
+    type Error = RipError;
+
+    fn try_from(payload: &'a [u8]) -> Result<Self, RipError> {
+        let entries_bytes = extract_rip_entries(payload, &[1, 2])?;
+
+        let mut entries = Vec::with_capacity(entries_bytes.len() / RIP_ENTRY_LENGTH);
+        let mut authentication = Vec::new();
+        for (index, entry) in entries_bytes.chunks_exact(RIP_ENTRY_LENGTH).enumerate() {
+            let address_family = u16::from_be_bytes([entry[0], entry[1]]);
+            if address_family == AUTHENTICATION_FAMILY {
+                authentication.push(RipAuthentication {
+                    authentication_type: u16::from_be_bytes([entry[2], entry[3]]),
+                    data: &entry[4..],
+                });
+                continue;
+            }
+            let metric = u32::from_be_bytes([entry[16], entry[17], entry[18], entry[19]]);
+            validate_rip_metric(index, metric)?;
+            entries.push(RipEntry {
+                address_family,
+                route_tag: u16::from_be_bytes([entry[2], entry[3]]),
+                address: ipv4_at(entry, 4),
+                subnet_mask: ipv4_at(entry, 8),
+                next_hop: ipv4_at(entry, 12),
+                metric,
+            });
+        }
+
+        Ok(RipPacket {
+            command: RipCommand::from_validated(payload[0]),
+            version: payload[1],
+            entries,
+            authentication,
+        })
+    }
+}
+
+/// Route Table Entry RIPng.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RipngEntry {
+    pub prefix: Ipv6Addr,
+    pub route_tag: u16,
+    pub prefix_length: u8,
+    /// 1 a 16 pour une route ; 0xff pour un RTE next hop.
+    pub metric: u8,
+}
+
+impl RipngEntry {
+    /// RTE next hop (RFC 2080 §2.1.1) : `prefix` est le next hop des
+    /// routes qui suivent, `::` pour « via l'emetteur ».
+    pub fn is_next_hop(&self) -> bool {
+        self.metric == RIPNG_NEXT_HOP_METRIC
+    }
+}
+
+/// Message RIPng.
+///
+/// ```mermaid
+/// ---
+/// title: RipngPacket
+/// ---
+/// packet-beta
+/// 0-7: "Command u8"
+/// 8-15: "Version u8"
+/// 16-31: "Zero u16"
+/// 32-159: "IPv6 Prefix 16 octets"
+/// 160-175: "Route Tag u16"
+/// 176-183: "Prefix Len u8"
+/// 184-191: "Metric u8"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RipngPacket {
+    pub command: RipCommand,
+    /// Toujours 1.
+    pub version: u8,
+    pub entries: Vec<RipngEntry>,
+}
+
+impl TryFrom<&[u8]> for RipngPacket {
+    type Error = RipError;
+
+    fn try_from(payload: &[u8]) -> Result<Self, RipError> {
+        let entries_bytes = extract_rip_entries(payload, &[RIPNG_VERSION])?;
+
+        let mut entries = Vec::with_capacity(entries_bytes.len() / RIP_ENTRY_LENGTH);
+        for (index, entry) in entries_bytes.chunks_exact(RIP_ENTRY_LENGTH).enumerate() {
+            let prefix_length = entry[18];
+            let metric = entry[19];
+            if metric != RIPNG_NEXT_HOP_METRIC {
+                validate_rip_metric(index, u32::from(metric))?;
+                if prefix_length > 128 {
+                    return Err(RipError::InvalidPrefixLength {
+                        index,
+                        length: prefix_length,
+                    });
+                }
+            }
+            let mut prefix = [0u8; 16];
+            prefix.copy_from_slice(&entry[..16]);
+            entries.push(RipngEntry {
+                prefix: Ipv6Addr::from(prefix),
+                route_tag: u16::from_be_bytes([entry[16], entry[17]]),
+                prefix_length,
+                metric,
+            });
+        }
+
+        Ok(RipngPacket {
+            command: RipCommand::from_validated(payload[0]),
+            version: payload[1],
+            entries,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Synthetique : reponse RIPv2 avec authentification en clair puis une
+    /// route 10.1.0.0/16 via 10.0.0.254, metrique 2.
+    #[test]
+    fn parses_v2_response_with_authentication() {
+        let mut raw = vec![2, 2, 0, 0, 0xff, 0xff, 0, 2];
+        raw.extend_from_slice(b"secret\0\0\0\0\0\0\0\0\0\0");
+        raw.extend_from_slice(&[
+            0, 2, 0, 7, 10, 1, 0, 0, 255, 255, 0, 0, 10, 0, 0, 254, 0, 0, 0, 2,
+        ]);
+        let packet = RipPacket::try_from(raw.as_slice()).expect("v2 response parses");
+
+        assert_eq!(packet.command, RipCommand::Response);
+        assert_eq!(packet.version, 2);
+        assert_eq!(packet.authentication.len(), 1);
+        assert_eq!(packet.authentication[0].authentication_type, 2);
+        assert_eq!(&packet.authentication[0].data[..6], b"secret");
+        assert_eq!(
+            packet.entries,
+            [RipEntry {
+                address_family: 2,
+                route_tag: 7,
+                address: Ipv4Addr::new(10, 1, 0, 0),
+                subnet_mask: Ipv4Addr::new(255, 255, 0, 0),
+                next_hop: Ipv4Addr::new(10, 0, 0, 254),
+                metric: 2,
+            }]
+        );
+    }
+
+    /// Synthetique : requete RIPv1 de la table complete.
+    #[test]
+    fn parses_v1_whole_table_request() {
+        let mut raw = vec![1, 1, 0, 0];
+        raw.extend_from_slice(&[0; 16]);
+        raw.extend_from_slice(&16u32.to_be_bytes());
+        let packet = RipPacket::try_from(raw.as_slice()).expect("v1 request parses");
+
+        assert!(packet.is_whole_table_request());
+    }
+
+    /// Synthetique : metrique 17, au-dela de l'infini.
+    #[test]
+    fn rejects_metric_above_infinity() {
+        let mut raw = vec![2, 2, 0, 0, 0, 2, 0, 0, 10, 0, 0, 0];
+        raw.extend_from_slice(&[0; 8]);
+        raw.extend_from_slice(&17u32.to_be_bytes());
+
+        assert_eq!(
+            RipPacket::try_from(raw.as_slice()).unwrap_err(),
+            RipError::InvalidMetric {
+                index: 0,
+                metric: 17
+            }
+        );
+    }
+
+    /// Synthetique : reponse RIPng, RTE next hop fe80::1 puis 2001:db8::/32.
+    #[test]
+    fn parses_ripng_response_with_next_hop() {
+        let mut raw = vec![2, 1, 0, 0];
+        raw.extend_from_slice(&"fe80::1".parse::<Ipv6Addr>().unwrap().octets());
+        raw.extend_from_slice(&[0, 0, 0, 0xff]);
+        raw.extend_from_slice(&"2001:db8::".parse::<Ipv6Addr>().unwrap().octets());
+        raw.extend_from_slice(&[0, 0, 32, 1]);
+        let packet = RipngPacket::try_from(raw.as_slice()).expect("RIPng parses");
+
+        assert_eq!(packet.entries.len(), 2);
+        assert!(packet.entries[0].is_next_hop());
+        assert_eq!(packet.entries[1].prefix_length, 32);
+        assert_eq!(packet.entries[1].metric, 1);
+    }
+
+    /// Synthetique : RIPng version 2, jamais definie.
+    #[test]
+    fn ripng_rejects_other_versions() {
+        assert_eq!(
+            RipngPacket::try_from(&[2u8, 2, 0, 0][..]).unwrap_err(),
+            RipError::UnsupportedVersion(2)
+        );
+    }
+}
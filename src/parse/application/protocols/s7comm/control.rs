@@ -0,0 +1,297 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! PLC Control / PLC Stop and block upload/download parameters.
+//!
+//! These are the functions that change what a PLC runs: a download is
+//! `Request download` (0x1a), one or more `Download block` (0x1b) and
+//! `Download ended` (0x1c), followed by a PLC Control `_INSE` that activates
+//! the block; `P_PROGRAM` starts the CPU through PLC Control and stops it
+//! through PLC Stop (0x29). Uploads use 0x1d to 0x1f.
+
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::{
+    checks::application::s7comm::{
+        S7_FUNCTION_DOWNLOAD_BLOCK, S7_FUNCTION_REQUEST_DOWNLOAD, S7_FUNCTION_START_UPLOAD,
+        S7_FUNCTION_STATUS_MORE_DATA, S7_FUNCTION_UPLOAD, S7_ROSCTR_ACK_DATA, S7_ROSCTR_JOB,
+        extract_s7_ascii, extract_s7_bytes, extract_s7_prefixed_ascii, extract_s7_u8,
+        extract_s7_u16, extract_s7_u32, parse_s7_ascii_decimal, parse_s7_block_type,
+        s7_block_type_name, validate_s7_data_consumed, validate_s7_parameter_consumed,
+    },
+    errors::application::s7comm::S7CommParseError,
+};
+
+/// Block designation used in file names (`_0A00001P`) and in the parameter
+/// block of `_INSE`/`_DELE` (`0A00001P`).
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct S7BlockId {
+    /// Block type code (0x08 = OB, 0x0A = DB, 0x0C = FC, 0x0E = FB...).
+    pub block_type: u8,
+
+    pub number: u32,
+
+    /// Destination file system: `b'P'` passive, `b'A'` active, `b'B'` both.
+    pub file_system: u8,
+}
+
+impl S7BlockId {
+    /// Parses the 8-character form `TTNNNNNF`.
+    pub fn parse(name: &[u8]) -> Option<Self> {
+        let [type_high, type_low, n1, n2, n3, n4, n5, file_system] = *name else {
+            return None;
+        };
+        Some(Self {
+            block_type: parse_s7_block_type(&[type_high, type_low])?,
+            number: parse_s7_ascii_decimal(&[n1, n2, n3, n4, n5])?,
+            file_system,
+        })
+    }
+
+    /// Parses a file name, `_` followed by the 8-character form.
+    pub fn from_filename(filename: &str) -> Option<Self> {
+        Self::parse(filename.strip_prefix('_')?.as_bytes())
+    }
+}
+
+/// Formats as `DB 1`.
+impl fmt::Display for S7BlockId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", s7_block_type_name(self.block_type), self.number)
+    }
+}
+
+/// Parameters (and block data) of the upload/download functions 0x1a-0x1f.
+///
+/// JOBs carry `function, status, u16, u32 id` then, for 0x1a-0x1d, the file
+/// name; a Request download adds the load memory and MC7 code lengths.
+/// ACK-Data parameters stop after the status, except Start upload which
+/// returns the upload id and the block length. The ACK-Data of Download
+/// block and Upload carries the block bytes in the data section.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct S7BlockControl<'a> {
+    pub function: u8,
+
+    /// Function status; bit 0 means more data follows.
+    pub function_status: Option<u8>,
+
+    /// Upload id (uploads) or error code (downloads).
+    pub upload_id: Option<u32>,
+
+    /// File name such as `_0A00001P`.
+    pub filename: Option<&'a str>,
+
+    /// Load memory length announced by Request download.
+    pub load_memory_length: Option<u32>,
+
+    /// MC7 code length announced by Request download.
+    pub mc7_code_length: Option<u32>,
+
+    /// Block length returned by Start upload.
+    pub block_length: Option<u32>,
+
+    /// Block bytes of a Download block / Upload ACK-Data.
+    pub data: Option<&'a [u8]>,
+}
+
+impl<'a> S7BlockControl<'a> {
+    /// Decodes the parameter and data sections of a block transfer PDU.
+    pub fn parse(
+        rosctr: u8,
+        parameter: &'a [u8],
+        data: &'a [u8],
+    ) -> Result<Self, S7CommParseError> {
+        let (function, rest) = extract_s7_u8(parameter, "block control function")?;
+        let mut control = Self {
+            function,
+            function_status: None,
+            upload_id: None,
+            filename: None,
+            load_memory_length: None,
+            mc7_code_length: None,
+            block_length: None,
+            data: None,
+        };
+
+        let mut rest = rest;
+        if let Some((&status, tail)) = rest.split_first() {
+            control.function_status = Some(status);
+            rest = tail;
+        }
+        if !rest.is_empty() {
+            let (_, tail) = extract_s7_u16(rest, "block control reserved")?;
+            let (upload_id, tail) = extract_s7_u32(tail, "block control upload id")?;
+            control.upload_id = Some(upload_id);
+            rest = tail;
+        }
+
+        if rosctr == S7_ROSCTR_JOB
+            && (S7_FUNCTION_REQUEST_DOWNLOAD..=S7_FUNCTION_START_UPLOAD).contains(&function)
+            && !rest.is_empty()
+        {
+            let (filename, tail) = extract_s7_prefixed_ascii(rest, "block file name")?;
+            control.filename = Some(filename);
+            rest = tail;
+        }
+
+        if rosctr == S7_ROSCTR_JOB && function == S7_FUNCTION_REQUEST_DOWNLOAD && !rest.is_empty() {
+            // "1", longueur en memoire de chargement puis longueur du code
+            // MC7, six chiffres ASCII chacune.
+            let (length, tail) = extract_s7_u8(rest, "request download lengths")?;
+            let (lengths, tail) =
+                extract_s7_bytes(tail, usize::from(length), "request download lengths")?;
+            if let Some(lengths) = lengths.get(1..13) {
+                control.load_memory_length = parse_s7_ascii_decimal(&lengths[..6]);
+                control.mc7_code_length = parse_s7_ascii_decimal(&lengths[6..]);
+            }
+            rest = tail;
+        }
+
+        if rosctr == S7_ROSCTR_ACK_DATA && function == S7_FUNCTION_START_UPLOAD && !rest.is_empty()
+        {
+            let (length, tail) = extract_s7_u8(rest, "upload block length")?;
+            let (digits, tail) =
+                extract_s7_ascii(tail, usize::from(length), "upload block length")?;
+            control.block_length = parse_s7_ascii_decimal(digits.as_bytes());
+            rest = tail;
+        }
+        validate_s7_parameter_consumed(rest)?;
+
+        if rosctr == S7_ROSCTR_ACK_DATA
+            && matches!(function, S7_FUNCTION_DOWNLOAD_BLOCK | S7_FUNCTION_UPLOAD)
+            && !data.is_empty()
+        {
+            let (length, tail) = extract_s7_u16(data, "block data length")?;
+            let (_, tail) = extract_s7_u16(tail, "block data reserved")?;
+            let (block, tail) = extract_s7_bytes(tail, usize::from(length), "block data")?;
+            validate_s7_data_consumed(tail)?;
+            control.data = Some(block);
+        }
+
+        Ok(control)
+    }
+
+    /// Block named by the file name.
+    pub fn block(&self) -> Option<S7BlockId> {
+        S7BlockId::from_filename(self.filename?)
+    }
+
+    pub fn more_data_following(&self) -> bool {
+        self.function_status
+            .is_some_and(|status| status & S7_FUNCTION_STATUS_MORE_DATA != 0)
+    }
+}
+
+/// PLC Control (0x28) JOB: starts a PI service.
+///
+/// ```mermaid
+/// ---
+/// title: S7PlcControl
+/// ---
+/// packet-beta
+/// 0-7: "Function 0x28"
+/// 8-63: "Unknown (7 bytes)"
+/// 64-79: "Parameter block length u16"
+/// 80-111: "Parameter block (variable)"
+/// 112-119: "PI service length u8"
+/// 120-159: "PI service name (variable)"
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct S7PlcControl<'a> {
+    /// Service arguments: block list for `_INSE`/`_DELE`, `"C "` for a
+    /// cold restart with `P_PROGRAM`.
+    pub parameter_block: &'a [u8],
+
+    /// PI service name (`_INSE`, `_DELE`, `P_PROGRAM`...).
+    pub pi_service: &'a str,
+}
+
+impl<'a> S7PlcControl<'a> {
+    pub fn parse(parameter: &'a [u8]) -> Result<Self, S7CommParseError> {
+        let (_, rest) = extract_s7_bytes(parameter, 8, "PLC control header")?;
+        let (length, rest) = extract_s7_u16(rest, "PLC control parameter block")?;
+        let (parameter_block, rest) =
+            extract_s7_bytes(rest, usize::from(length), "PLC control parameter block")?;
+        let (pi_service, rest) = extract_s7_prefixed_ascii(rest, "PI service")?;
+        validate_s7_parameter_consumed(rest)?;
+
+        Ok(Self {
+            parameter_block,
+            pi_service,
+        })
+    }
+
+    /// Blocks named in the parameter block of `_INSE` and `_DELE`: a count
+    /// byte, a reserved byte, then 8 characters per block.
+    pub fn blocks(&self) -> impl Iterator<Item = S7BlockId> + use<'a> {
+        let names = match (self.pi_service, self.parameter_block) {
+            ("_INSE" | "_DELE", [count, _, names @ ..]) => {
+                &names[..names.len().min(usize::from(*count) * 8)]
+            }
+            _ => &[][..],
+        };
+        names.chunks_exact(8).filter_map(S7BlockId::parse)
+    }
+}
+
+/// PLC Stop (0x29) JOB.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct S7PlcStop<'a> {
+    /// PI service name, `P_PROGRAM` in practice.
+    pub pi_service: &'a str,
+}
+
+impl<'a> S7PlcStop<'a> {
+    pub fn parse(parameter: &'a [u8]) -> Result<Self, S7CommParseError> {
+        let (_, rest) = extract_s7_bytes(parameter, 6, "PLC stop header")?;
+        let (pi_service, rest) = extract_s7_prefixed_ascii(rest, "PI service")?;
+        validate_s7_parameter_consumed(rest)?;
+
+        Ok(Self { pi_service })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_id_parsing() {
+        let block = S7BlockId::from_filename("_0A00001P").expect("DB1");
+        assert_eq!(block.block_type, 0x0a);
+        assert_eq!(block.number, 1);
+        assert_eq!(block.file_system, b'P');
+        assert_eq!(block.to_string(), "DB 1");
+        assert_eq!(S7BlockId::parse(b"0E00042A").unwrap().to_string(), "FB 42");
+        assert!(S7BlockId::from_filename("0A00001P").is_none());
+        assert!(S7BlockId::parse(b"0A0000xP").is_none());
+    }
+
+    #[test]
+    fn test_plc_control_and_stop_parameters() {
+        // Synthetique : demarrage a chaud (P_PROGRAM sans argument) puis
+        // arret, formes relevees par Wireshark.
+        let start = hex::decode("28000000000000fd000009505f50524f4752414d").unwrap();
+        let control = S7PlcControl::parse(&start).expect("hot restart");
+        assert_eq!(control.pi_service, "P_PROGRAM");
+        assert!(control.parameter_block.is_empty());
+        assert_eq!(control.blocks().count(), 0);
+
+        let stop = hex::decode("29000000000009505f50524f4752414d").unwrap();
+        assert_eq!(S7PlcStop::parse(&stop).unwrap().pi_service, "P_PROGRAM");
+        assert_eq!(
+            S7PlcStop::parse(&stop[..10]).unwrap_err(),
+            S7CommParseError::TruncatedField {
+                field: "PI service"
+            }
+        );
+    }
+}
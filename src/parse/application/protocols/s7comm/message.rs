@@ -0,0 +1,281 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Typed view of an S7Comm PDU, decoded on demand from the parameter and
+//! data sections that [`S7CommPacket`] keeps as raw slices.
+
+use serde::Serialize;
+
+use crate::{
+    checks::application::s7comm::{
+        S7_FUNCTION_END_UPLOAD, S7_FUNCTION_PLC_CONTROL, S7_FUNCTION_PLC_STOP,
+        S7_FUNCTION_READ_VAR, S7_FUNCTION_REQUEST_DOWNLOAD, S7_FUNCTION_SETUP_COMMUNICATION,
+        S7_FUNCTION_WRITE_VAR, S7_ROSCTR_ACK_DATA, S7_ROSCTR_JOB, S7_ROSCTR_USERDATA,
+        extract_s7_data_items, extract_s7_u8, extract_s7_u16, validate_s7_parameter_consumed,
+    },
+    errors::application::s7comm::S7CommParseError,
+    parse::application::protocols::s7comm::{
+        S7CommPacket, S7ParameterItem,
+        control::{S7BlockControl, S7PlcControl, S7PlcStop},
+        userdata::S7Userdata,
+        var::S7DataItem,
+    },
+};
+
+/// Decoded S7Comm PDU.
+///
+/// Functions without a dedicated variant (acknowledgements of PLC Control
+/// and PLC Stop, CPU services...) decode as [`S7Message::Other`]; their
+/// bytes stay available on the packet.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub enum S7Message<'a> {
+    /// Setup communication JOB or ACK-Data.
+    SetupCommunication(S7SetupCommunication),
+
+    /// Read Var JOB: the addresses to read.
+    ReadVarRequest(Vec<S7ParameterItem<'a>>),
+
+    /// Read Var ACK-Data: one result per requested address.
+    ReadVarResponse(Vec<S7DataItem<'a>>),
+
+    /// Write Var JOB: the addresses and the values written to them.
+    WriteVarRequest {
+        items: Vec<S7ParameterItem<'a>>,
+        values: Vec<S7DataItem<'a>>,
+    },
+
+    /// Write Var ACK-Data: one return code per item.
+    WriteVarResponse(&'a [u8]),
+
+    /// Upload/download functions 0x1a-0x1f, JOB or ACK-Data.
+    BlockControl(S7BlockControl<'a>),
+
+    /// PLC Control JOB (block activation, CPU start...).
+    PlcControl(S7PlcControl<'a>),
+
+    /// PLC Stop JOB.
+    PlcStop(S7PlcStop<'a>),
+
+    /// Userdata PDU (SZL reads, block lists, clock...).
+    Userdata(S7Userdata<'a>),
+
+    /// ACK or ACK-Data whose header reports an error.
+    Error {
+        function: u8,
+        error_class: u8,
+        error_code: u8,
+    },
+
+    /// PDU without a typed decoding.
+    Other { rosctr: u8, function: u8 },
+}
+
+/// Setup communication parameters, identical in the JOB and its ACK-Data.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct S7SetupCommunication {
+    /// Parallel jobs the calling side accepts.
+    pub max_amq_calling: u16,
+
+    /// Parallel jobs the called side accepts.
+    pub max_amq_called: u16,
+
+    /// Negotiated PDU length.
+    pub pdu_length: u16,
+}
+
+impl S7SetupCommunication {
+    pub fn parse(parameter: &[u8]) -> Result<Self, S7CommParseError> {
+        let (_, rest) = extract_s7_u8(parameter, "setup communication function")?;
+        let (_, rest) = extract_s7_u8(rest, "setup communication reserved")?;
+        let (max_amq_calling, rest) = extract_s7_u16(rest, "max AmQ calling")?;
+        let (max_amq_called, rest) = extract_s7_u16(rest, "max AmQ called")?;
+        let (pdu_length, rest) = extract_s7_u16(rest, "PDU length")?;
+        validate_s7_parameter_consumed(rest)?;
+
+        Ok(Self {
+            max_amq_calling,
+            max_amq_called,
+            pdu_length,
+        })
+    }
+}
+
+impl<'a> S7CommPacket<'a> {
+    /// Decodes the parameter and data sections into an [`S7Message`].
+    ///
+    /// # Example
+    /// ```
+    /// use packet_parser::parse::application::protocols::s7comm::{
+    ///     S7CommPacket, message::S7Message,
+    /// };
+    ///
+    /// // Read Var ACK-Data, frame 12 of
+    /// // pcaps_exemple/protocols/s7comm/s7comm_varservice_libnodavedemo.pcap.
+    /// let mut raw = hex::decode("0300005902f0803203000000000002004400000401ff040200").unwrap();
+    /// raw.resize(0x59, 0);
+    ///
+    /// let packet = S7CommPacket::try_from(&raw[..]).unwrap();
+    /// let S7Message::ReadVarResponse(items) = packet.message().unwrap() else {
+    ///     panic!("Read Var response expected");
+    /// };
+    /// assert!(items[0].is_success());
+    /// assert_eq!(items[0].data.len(), 64);
+    /// ```
+    pub fn message(&self) -> Result<S7Message<'a>, S7CommParseError> {
+        let header = &self.s7_header;
+        let function = self.parameter.function;
+        let raw = self.parameter.raw;
+        let data = self.payload.unwrap_or_default();
+        let item_count = usize::from(self.parameter.item_count.unwrap_or(0));
+
+        if header.rosctr == S7_ROSCTR_USERDATA {
+            return S7Userdata::parse(raw, data).map(S7Message::Userdata);
+        }
+        let error_class = header.error_class.unwrap_or(0);
+        let error_code = header.error_code.unwrap_or(0);
+        if error_class != 0 || error_code != 0 {
+            return Ok(S7Message::Error {
+                function,
+                error_class,
+                error_code,
+            });
+        }
+
+        let message = match (header.rosctr, function) {
+            (S7_ROSCTR_JOB | S7_ROSCTR_ACK_DATA, S7_FUNCTION_SETUP_COMMUNICATION) => {
+                S7Message::SetupCommunication(S7SetupCommunication::parse(raw)?)
+            }
+            (S7_ROSCTR_JOB, S7_FUNCTION_READ_VAR) => {
+                S7Message::ReadVarRequest(self.parameter.items.clone())
+            }
+            (S7_ROSCTR_ACK_DATA, S7_FUNCTION_READ_VAR) => {
+                S7Message::ReadVarResponse(extract_s7_data_items(data, item_count)?)
+            }
+            (S7_ROSCTR_JOB, S7_FUNCTION_WRITE_VAR) => S7Message::WriteVarRequest {
+                items: self.parameter.items.clone(),
+                values: extract_s7_data_items(data, item_count)?,
+            },
+            (S7_ROSCTR_ACK_DATA, S7_FUNCTION_WRITE_VAR) => {
+                if data.len() != item_count {
+                    return Err(S7CommParseError::DataItemCountMismatch {
+                        expected: item_count,
+                        actual: data.len(),
+                    });
+                }
+                S7Message::WriteVarResponse(data)
+            }
+            (
+                S7_ROSCTR_JOB | S7_ROSCTR_ACK_DATA,
+                S7_FUNCTION_REQUEST_DOWNLOAD..=S7_FUNCTION_END_UPLOAD,
+            ) => S7Message::BlockControl(S7BlockControl::parse(header.rosctr, raw, data)?),
+            (S7_ROSCTR_JOB, S7_FUNCTION_PLC_CONTROL) => {
+                S7Message::PlcControl(S7PlcControl::parse(raw)?)
+            }
+            (S7_ROSCTR_JOB, S7_FUNCTION_PLC_STOP) => S7Message::PlcStop(S7PlcStop::parse(raw)?),
+            (rosctr, function) => S7Message::Other { rosctr, function },
+        };
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(hex_str: &str) -> Vec<u8> {
+        hex::decode(hex_str).expect("valid hex fixture")
+    }
+
+    fn message(bytes: &[u8]) -> Result<S7Message<'_>, S7CommParseError> {
+        S7CommPacket::try_from(bytes)
+            .expect("valid S7Comm frame")
+            .message()
+    }
+
+    #[test]
+    fn test_setup_communication() {
+        // Trame 9 de s7comm_varservice_libnodavedemo.pcap.
+        let bytes = decode("0300001902f08032010000ffff00080000f000000100010780");
+        let Ok(S7Message::SetupCommunication(setup)) = message(&bytes) else {
+            panic!("setup communication expected");
+        };
+        assert_eq!(setup.max_amq_calling, 1);
+        assert_eq!(setup.max_amq_called, 1);
+        assert_eq!(setup.pdu_length, 0x0780);
+    }
+
+    #[test]
+    fn test_write_var_request_values() {
+        // Trame 15 de s7comm_varservice_libnodavedemo.pcap.
+        let bytes = decode(
+            "0300002702f080320100000002000e00080501120a1002000400008300000000040020a9100001",
+        );
+        let Ok(S7Message::WriteVarRequest { items, values }) = message(&bytes) else {
+            panic!("Write Var request expected");
+        };
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].var_address().unwrap().to_string(), "M 0.0 BYTE 4");
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].return_code, 0x00);
+        assert_eq!(values[0].length, 32);
+        assert_eq!(values[0].data, [0xa9, 0x10, 0x00, 0x01]);
+    }
+
+    #[test]
+    fn test_data_items_fill_byte_and_truncation() {
+        // Synthetique : Write Var de deux bits (M 0.1 et M 0.2), le premier
+        // suivi de son octet de remplissage.
+        let bytes = decode(concat!(
+            "0300003602f080320100000003001a000b0502120a10010001000083000001120a",
+            "100100010000830000020003000101000003000101"
+        ));
+        let Ok(S7Message::WriteVarRequest { items, values }) = message(&bytes) else {
+            panic!("Write Var request expected");
+        };
+        let addresses: Vec<_> = items
+            .iter()
+            .map(|item| item.var_address().unwrap().to_string())
+            .collect();
+        assert_eq!(addresses, ["M 0.1 BIT 1", "M 0.2 BIT 1"]);
+        assert_eq!(values[0].data, [0x01]);
+        assert_eq!(values[1].data, [0x01]);
+
+        // Le meme JOB ampute de la valeur du second item.
+        let bytes = decode(concat!(
+            "0300003502f080320100000003001a000a0502120a10010001000083000001120a",
+            "1001000100008300000200030001010000030001"
+        ));
+        assert_eq!(
+            message(&bytes).unwrap_err(),
+            S7CommParseError::TruncatedDataItem { index: 1 }
+        );
+    }
+
+    #[test]
+    fn test_error_ack_and_other_functions() {
+        // Synthetique : ACK-Data Write Var refuse (classe 0x85, code 0x00).
+        let bytes = decode("0300001502f0803203000000020002000085000501");
+        assert_eq!(
+            message(&bytes).unwrap(),
+            S7Message::Error {
+                function: 0x05,
+                error_class: 0x85,
+                error_code: 0x00,
+            }
+        );
+        // Acquittement de PLC Control, trame 59 de
+        // s7comm_downloading_block_db1.pcap.
+        let bytes = decode("0300001402f080320300000f0000010000000028");
+        assert_eq!(
+            message(&bytes).unwrap(),
+            S7Message::Other {
+                rosctr: 0x03,
+                function: 0x28,
+            }
+        );
+    }
+}
@@ -50,6 +50,11 @@ use crate::{
     parse::application::protocols::bounded_capacity,
 };
 
+pub mod control;
+pub mod message;
+pub mod userdata;
+pub mod var;
+
 /// S7Comm Protocol Packet
 ///
 /// ```mermaid
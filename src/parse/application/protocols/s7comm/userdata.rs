@@ -0,0 +1,385 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Userdata (ROSCTR 0x07) parameters and system status list (SZL) reads.
+//!
+//! Engineering tools identify a CPU through Userdata "CPU functions / Read
+//! SZL" requests: the request names an SZL id and index, the response
+//! returns fixed-length records such as the module identification
+//! (`0x0011`, order number and versions) or the component identification
+//! (`0x001C`, station and module names, serial number).
+//!
+//! A long SZL answer is split over several responses. Only the first one
+//! starts with the SZL header decoded by [`S7Userdata::szl_response`]; the
+//! following fragments, requested with an 8-byte parameter carrying the same
+//! data unit reference, hold raw record bytes in [`S7Userdata::data`].
+
+use serde::Serialize;
+
+use crate::{
+    checks::application::s7comm::{
+        S7_RETURN_CODE_SUCCESS, S7_SZL_COMPONENT_IDENTIFICATION_LENGTH,
+        S7_SZL_MODULE_IDENTIFICATION_LENGTH, S7_USERDATA_GROUP_CPU,
+        S7_USERDATA_SUBFUNCTION_READ_SZL, S7_USERDATA_TYPE_REQUEST, S7_USERDATA_TYPE_RESPONSE,
+        extract_s7_bytes, extract_s7_u8, extract_s7_u16, validate_s7_data_consumed,
+        validate_s7_parameter_consumed, validate_s7_userdata_head,
+        validate_s7_userdata_parameter_length,
+    },
+    errors::application::s7comm::S7CommParseError,
+};
+
+/// Userdata parameter section.
+///
+/// ```mermaid
+/// ---
+/// title: S7UserdataParameter
+/// ---
+/// packet-beta
+/// 0-23: "Head 00 01 12"
+/// 24-31: "Length (4 or 8)"
+/// 32-39: "Method (0x11 req, 0x12 resp)"
+/// 40-43: "Type"
+/// 44-47: "Function group"
+/// 48-55: "Subfunction"
+/// 56-63: "Sequence number"
+/// 64-71: "Data unit reference (8-byte form)"
+/// 72-79: "Last data unit (8-byte form)"
+/// 80-95: "Error code (8-byte form)"
+/// ```
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct S7UserdataParameter {
+    /// 0x11 for a request, 0x12 for a response or a follow-up request.
+    pub method: u8,
+
+    /// Type nibble: 0x0 push, 0x4 request, 0x8 response.
+    pub kind: u8,
+
+    /// Function group nibble (0x4 = CPU functions, 0x3 = block functions...).
+    pub group: u8,
+
+    pub subfunction: u8,
+
+    pub sequence_number: u8,
+
+    /// Fragment reference, present in the 8-byte form only.
+    pub data_unit_reference: Option<u8>,
+
+    /// Whether this is the last fragment (wire value 0x00), 8-byte form only.
+    pub last_data_unit: Option<bool>,
+
+    /// Error code, 8-byte form only.
+    pub error_code: Option<u16>,
+}
+
+impl S7UserdataParameter {
+    /// Parses the whole parameter section of a Userdata PDU.
+    pub fn parse(parameter: &[u8]) -> Result<Self, S7CommParseError> {
+        let (head, rest) = extract_s7_bytes(parameter, 3, "userdata parameter head")?;
+        validate_s7_userdata_head([head[0], head[1], head[2]])?;
+        let (length, rest) = extract_s7_u8(rest, "userdata parameter length")?;
+        validate_s7_userdata_parameter_length(length)?;
+        let (method, rest) = extract_s7_u8(rest, "userdata method")?;
+        let (type_group, rest) = extract_s7_u8(rest, "userdata function group")?;
+        let (subfunction, rest) = extract_s7_u8(rest, "userdata subfunction")?;
+        let (sequence_number, rest) = extract_s7_u8(rest, "userdata sequence number")?;
+
+        let (data_unit_reference, last_data_unit, error_code, rest) = if length == 8 {
+            let (reference, rest) = extract_s7_u8(rest, "userdata data unit reference")?;
+            let (last, rest) = extract_s7_u8(rest, "userdata last data unit")?;
+            let (error_code, rest) = extract_s7_u16(rest, "userdata error code")?;
+            (Some(reference), Some(last == 0), Some(error_code), rest)
+        } else {
+            (None, None, None, rest)
+        };
+        validate_s7_parameter_consumed(rest)?;
+
+        Ok(Self {
+            method,
+            kind: type_group >> 4,
+            group: type_group & 0x0f,
+            subfunction,
+            sequence_number,
+            data_unit_reference,
+            last_data_unit,
+            error_code,
+        })
+    }
+
+    fn is_read_szl(&self) -> bool {
+        self.group == S7_USERDATA_GROUP_CPU && self.subfunction == S7_USERDATA_SUBFUNCTION_READ_SZL
+    }
+}
+
+/// Decoded Userdata PDU: parameter plus the single data item that follows.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct S7Userdata<'a> {
+    pub parameter: S7UserdataParameter,
+
+    /// Return code of the data item (0xff = success, 0x0a = not found).
+    pub return_code: u8,
+
+    /// Data transport size (0x09 = octet string).
+    pub transport_size: u8,
+
+    /// Function-specific data, length in bytes.
+    pub data: &'a [u8],
+}
+
+impl<'a> S7Userdata<'a> {
+    /// Decodes the parameter and data sections of a Userdata PDU.
+    pub fn parse(parameter: &[u8], data: &'a [u8]) -> Result<Self, S7CommParseError> {
+        let parameter = S7UserdataParameter::parse(parameter)?;
+        let (return_code, rest) = extract_s7_u8(data, "userdata return code")?;
+        let (transport_size, rest) = extract_s7_u8(rest, "userdata transport size")?;
+        let (length, rest) = extract_s7_u16(rest, "userdata data length")?;
+        let (data, rest) = extract_s7_bytes(rest, usize::from(length), "userdata data")?;
+        validate_s7_data_consumed(rest)?;
+
+        Ok(Self {
+            parameter,
+            return_code,
+            transport_size,
+            data,
+        })
+    }
+
+    /// SZL id and index asked by a "Read SZL" request.
+    pub fn szl_request(&self) -> Option<S7SzlRequest> {
+        if !self.parameter.is_read_szl()
+            || self.parameter.kind != S7_USERDATA_TYPE_REQUEST
+            || self.parameter.data_unit_reference.is_some()
+        {
+            return None;
+        }
+        let [high_id, low_id, high_index, low_index] = *self.data else {
+            return None;
+        };
+        Some(S7SzlRequest {
+            szl_id: u16::from_be_bytes([high_id, low_id]),
+            index: u16::from_be_bytes([high_index, low_index]),
+        })
+    }
+
+    /// SZL header and records of a successful "Read SZL" response,
+    /// `Ok(None)` for any other Userdata PDU.
+    pub fn szl_response(&self) -> Result<Option<S7SzlResponse<'a>>, S7CommParseError> {
+        if !self.parameter.is_read_szl()
+            || self.parameter.kind != S7_USERDATA_TYPE_RESPONSE
+            || self.return_code != S7_RETURN_CODE_SUCCESS
+        {
+            return Ok(None);
+        }
+        let (szl_id, rest) = extract_s7_u16(self.data, "SZL id")?;
+        let (index, rest) = extract_s7_u16(rest, "SZL index")?;
+        let (record_length, rest) = extract_s7_u16(rest, "SZL record length")?;
+        let (record_count, records) = extract_s7_u16(rest, "SZL record count")?;
+
+        Ok(Some(S7SzlResponse {
+            szl_id,
+            index,
+            record_length,
+            record_count,
+            records,
+        }))
+    }
+}
+
+/// "Read SZL" request.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct S7SzlRequest {
+    pub szl_id: u16,
+    pub index: u16,
+}
+
+/// First fragment of a "Read SZL" response.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct S7SzlResponse<'a> {
+    pub szl_id: u16,
+
+    pub index: u16,
+
+    /// Length of one record, in bytes.
+    pub record_length: u16,
+
+    /// Number of records in the whole list, possibly across fragments.
+    pub record_count: u16,
+
+    /// Record bytes carried by this PDU.
+    pub records: &'a [u8],
+}
+
+impl<'a> S7SzlResponse<'a> {
+    /// Complete records present in this PDU; a record cut by the fragment
+    /// boundary is left out.
+    pub fn records(&self) -> impl Iterator<Item = &'a [u8]> + use<'a> {
+        let length = usize::from(self.record_length).max(1);
+        let records = if self.record_length == 0 {
+            &[][..]
+        } else {
+            self.records
+        };
+        records.chunks_exact(length)
+    }
+
+    /// Module identification records (SZL `xy11`, e.g. `0x0011`, `0x0111`).
+    pub fn module_identifications(
+        &self,
+    ) -> impl Iterator<Item = S7SzlModuleIdentification<'a>> + use<'a> {
+        let matches = self.szl_id & 0x00ff == 0x11
+            && usize::from(self.record_length) == S7_SZL_MODULE_IDENTIFICATION_LENGTH;
+        self.records()
+            .filter(move |_| matches)
+            .map(|record| S7SzlModuleIdentification {
+                index: u16::from_be_bytes([record[0], record[1]]),
+                mlfb: &record[2..22],
+                module_type: u16::from_be_bytes([record[22], record[23]]),
+                version: u16::from_be_bytes([record[24], record[25]]),
+                version_extension: u16::from_be_bytes([record[26], record[27]]),
+            })
+    }
+
+    /// Component identification records (SZL `xy1C`, e.g. `0x001C`).
+    pub fn component_identifications(
+        &self,
+    ) -> impl Iterator<Item = S7SzlComponentIdentification<'a>> + use<'a> {
+        let matches = self.szl_id & 0x00ff == 0x1c
+            && usize::from(self.record_length) == S7_SZL_COMPONENT_IDENTIFICATION_LENGTH;
+        self.records()
+            .filter(move |_| matches)
+            .map(|record| S7SzlComponentIdentification {
+                index: u16::from_be_bytes([record[0], record[1]]),
+                text: &record[2..],
+            })
+    }
+}
+
+/// Module identification record (SZL `xy11`).
+///
+/// Index 1 identifies the module, 6 its hardware and 7 its firmware.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct S7SzlModuleIdentification<'a> {
+    pub index: u16,
+
+    /// Order number (MLFB), 20 ASCII bytes padded with spaces.
+    pub mlfb: &'a [u8],
+
+    /// Module type identifier (BGTyp).
+    pub module_type: u16,
+
+    /// Version (Ausbg); for the firmware record, `'V'` and the major number.
+    pub version: u16,
+
+    /// Version extension (Ausbe); minor numbers for the firmware record.
+    pub version_extension: u16,
+}
+
+impl<'a> S7SzlModuleIdentification<'a> {
+    /// Order number without its padding, e.g. `6ES7 151-8AB01-0AB0`.
+    pub fn order_number(&self) -> Option<&'a str> {
+        trimmed_text(self.mlfb)
+    }
+}
+
+/// Component identification record (SZL `xy1C`).
+///
+/// Index 1 is the automation system name, 2 the module name, 3 the plant
+/// designation, 4 the copyright, 5 the serial number, 7 the module type.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct S7SzlComponentIdentification<'a> {
+    pub index: u16,
+
+    /// 32 bytes of text, NUL-padded for the text indexes.
+    pub text: &'a [u8],
+}
+
+impl<'a> S7SzlComponentIdentification<'a> {
+    /// Text without its NUL/space padding, `None` when it is not ASCII.
+    pub fn name(&self) -> Option<&'a str> {
+        trimmed_text(self.text)
+    }
+}
+
+fn trimmed_text(bytes: &[u8]) -> Option<&str> {
+    let end = bytes
+        .iter()
+        .rposition(|byte| !matches!(byte, 0x00 | b' '))
+        .map_or(0, |last| last + 1);
+    let text = &bytes[..end];
+    if !text.is_ascii() {
+        return None;
+    }
+    std::str::from_utf8(text).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_userdata_parameter_forms() {
+        // Requete initiale (4 octets) puis requete de suite (8 octets) :
+        // trames 16 et 19 de s7comm_reading_plc_status.pcap.
+        let initial = S7UserdataParameter::parse(&hex::decode("0001120411440100").unwrap())
+            .expect("4-byte parameter");
+        assert_eq!(initial.method, 0x11);
+        assert_eq!(initial.kind, S7_USERDATA_TYPE_REQUEST);
+        assert_eq!(initial.group, S7_USERDATA_GROUP_CPU);
+        assert_eq!(initial.data_unit_reference, None);
+
+        let follow_up =
+            S7UserdataParameter::parse(&hex::decode("000112081244010105010000").unwrap())
+                .expect("8-byte parameter");
+        assert_eq!(follow_up.method, 0x12);
+        assert_eq!(follow_up.sequence_number, 1);
+        assert_eq!(follow_up.data_unit_reference, Some(5));
+        assert_eq!(follow_up.last_data_unit, Some(false));
+        assert_eq!(follow_up.error_code, Some(0));
+
+        assert_eq!(
+            S7UserdataParameter::parse(&hex::decode("0001130411440100").unwrap()).unwrap_err(),
+            S7CommParseError::InvalidUserdataHead {
+                head: [0x00, 0x01, 0x13]
+            }
+        );
+        assert_eq!(
+            S7UserdataParameter::parse(&hex::decode("00011206114401000000").unwrap()).unwrap_err(),
+            S7CommParseError::InvalidUserdataParameterLength { length: 6 }
+        );
+    }
+
+    #[test]
+    fn test_szl_records_skip_partial_record() {
+        // Synthetique : SZL 0x0011, deux enregistrements annonces, un et
+        // demi presents.
+        let mut data = hex::decode("00110000001c0002").unwrap();
+        data.extend_from_slice(&[0x00, 0x01]);
+        data.extend_from_slice(b"6ES7 315-2EH14-0AB0 ");
+        data.extend_from_slice(&[0x00, 0xc0, 0x00, 0x04, 0x00, 0x01]);
+        data.extend_from_slice(&[0x00, 0x06, b'6']);
+        let userdata = S7Userdata {
+            parameter: S7UserdataParameter::parse(
+                &hex::decode("000112081284010101010000").unwrap(),
+            )
+            .unwrap(),
+            return_code: S7_RETURN_CODE_SUCCESS,
+            transport_size: 0x09,
+            data: &data,
+        };
+
+        let response = userdata.szl_response().unwrap().expect("SZL response");
+        assert_eq!(response.record_count, 2);
+        let modules: Vec<_> = response.module_identifications().collect();
+        assert_eq!(modules.len(), 1);
+        assert_eq!(modules[0].order_number(), Some("6ES7 315-2EH14-0AB0"));
+        assert_eq!(modules[0].version, 4);
+        assert_eq!(response.component_identifications().count(), 0);
+    }
+}
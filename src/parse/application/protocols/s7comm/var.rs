@@ -0,0 +1,413 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Read Var / Write Var addresses, data items, and request/response pairing.
+//!
+//! A Read Var JOB lists S7ANY addresses in its parameter section and the
+//! matching ACK-Data returns one data item per address. A Write Var JOB
+//! carries the addresses and the values to write; its ACK-Data returns one
+//! return code per item. [`S7VarAccessTracker`] pairs both halves through
+//! the PDU reference so that each access reads as "address → value".
+
+use std::{collections::VecDeque, fmt};
+
+use serde::Serialize;
+
+use crate::{
+    checks::application::s7comm::{
+        S7_FUNCTION_READ_VAR, S7_FUNCTION_WRITE_VAR, S7_MAX_PENDING_VAR_JOBS,
+        S7_RETURN_CODE_SUCCESS, S7_ROSCTR_ACK, S7_ROSCTR_ACK_DATA, S7_ROSCTR_JOB, s7_area_name,
+        s7_transport_size_name,
+    },
+    errors::application::s7comm::S7CommParseError,
+    parse::application::protocols::s7comm::{S7CommPacket, S7ParameterItem, message::S7Message},
+};
+
+/// Decoded S7ANY variable address.
+///
+/// The 3-byte wire address counts bits: the byte offset is `address >> 3`
+/// and the bit offset `address & 7`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct S7VarAddress {
+    /// Memory area (0x81 = I, 0x82 = Q, 0x83 = M, 0x84 = DB...).
+    pub area: u8,
+
+    /// DB number, 0 outside the DB/DI areas.
+    pub db_number: u16,
+
+    /// Byte offset within the area.
+    pub byte_offset: u32,
+
+    /// Bit offset within the byte (0-7).
+    pub bit_offset: u8,
+
+    /// S7ANY transport size (0x01 = BIT, 0x02 = BYTE, 0x04 = WORD...).
+    pub transport_size: u8,
+
+    /// Number of elements of `transport_size`.
+    pub count: u16,
+}
+
+impl S7VarAddress {
+    /// Whether the address targets a data block.
+    pub fn is_data_block(&self) -> bool {
+        self.area == 0x84
+    }
+}
+
+/// Formats the address the way Wireshark does: `DB 1.DBX 0.0 BYTE 64`,
+/// `M 0.0 BYTE 4`.
+impl fmt::Display for S7VarAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_data_block() {
+            write!(f, "DB {}.DBX ", self.db_number)?;
+        } else {
+            write!(f, "{} ", s7_area_name(self.area))?;
+        }
+        write!(
+            f,
+            "{}.{} {} {}",
+            self.byte_offset,
+            self.bit_offset,
+            s7_transport_size_name(self.transport_size),
+            self.count
+        )
+    }
+}
+
+impl S7ParameterItem<'_> {
+    /// Typed address of an S7ANY item (syntax id 0x10), `None` for the
+    /// other addressing schemes (DBREAD, NCK...).
+    pub fn var_address(&self) -> Option<S7VarAddress> {
+        if self.spec_type != 0x12 || self.syntax_id != 0x10 {
+            return None;
+        }
+        Some(S7VarAddress {
+            area: self.area,
+            db_number: self.db_number,
+            byte_offset: self.address >> 3,
+            bit_offset: (self.address & 0x07) as u8,
+            transport_size: self.transport_size,
+            count: self.count,
+        })
+    }
+}
+
+/// One item of a data section: a Read Var result or a Write Var value.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct S7DataItem<'a> {
+    /// Return code (0xff = success; 0x00 in Write Var requests).
+    pub return_code: u8,
+
+    /// Data transport size (0x03 = BIT, 0x04 = BYTE/WORD/DWORD, 0x09 =
+    /// octet string...).
+    pub transport_size: u8,
+
+    /// Wire length, in bits or bytes depending on `transport_size`.
+    pub length: u16,
+
+    /// Value bytes, without the fill byte.
+    pub data: &'a [u8],
+}
+
+impl S7DataItem<'_> {
+    pub fn is_success(&self) -> bool {
+        self.return_code == S7_RETURN_CODE_SUCCESS
+    }
+}
+
+/// Direction of a paired variable access.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum S7VarAccessKind {
+    Read,
+    Write,
+}
+
+/// One address of a paired access with its outcome.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct S7VarAccessItem {
+    /// Requested address, `None` for a non-S7ANY item.
+    pub address: Option<S7VarAddress>,
+
+    /// Return code from the response, `None` when the response rejected the
+    /// whole request or returned fewer items.
+    pub return_code: Option<u8>,
+
+    /// Value read (Read Var) or written (Write Var).
+    pub value: Vec<u8>,
+}
+
+/// A Read Var or Write Var request paired with its response.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct S7VarAccess {
+    /// PDU reference shared by the JOB and its acknowledgement.
+    pub pduref: u16,
+
+    pub kind: S7VarAccessKind,
+
+    /// Header error class of the response (0 on success).
+    pub error_class: u8,
+
+    /// Header error code of the response (0 on success).
+    pub error_code: u8,
+
+    pub items: Vec<S7VarAccessItem>,
+}
+
+impl S7VarAccess {
+    /// Whether the PLC accepted the request and every item.
+    pub fn is_success(&self) -> bool {
+        self.error_class == 0
+            && self.error_code == 0
+            && self
+                .items
+                .iter()
+                .all(|item| item.return_code == Some(S7_RETURN_CODE_SUCCESS))
+    }
+}
+
+#[derive(Debug, Clone)]
+struct PendingJob {
+    pduref: u16,
+    kind: S7VarAccessKind,
+    addresses: Vec<Option<S7VarAddress>>,
+    values: Vec<Vec<u8>>,
+}
+
+/// Pairs Read Var / Write Var JOBs with their ACK-Data by PDU reference.
+///
+/// Feed every S7Comm packet of one TCP connection, in either direction,
+/// with [`push`](Self::push), then drain [`next_access`](Self::next_access).
+/// Other functions are ignored. Acknowledgements without a known JOB (the
+/// capture started mid-exchange) are dropped, and at most
+/// [`S7_MAX_PENDING_VAR_JOBS`] unanswered JOBs are kept, oldest evicted
+/// first.
+///
+/// # Example
+/// ```
+/// use packet_parser::parse::application::protocols::s7comm::{
+///     S7CommPacket,
+///     var::{S7VarAccessKind, S7VarAccessTracker},
+/// };
+///
+/// // Write Var "M 0.0 BYTE 4" and its ACK-Data, frames 15 and 16 of
+/// // pcaps_exemple/protocols/s7comm/s7comm_varservice_libnodavedemo.pcap.
+/// let job = hex::decode(
+///     "0300002702f080320100000002000e00080501120a1002000400008300000000040020a9100001",
+/// )
+/// .unwrap();
+/// let ack = hex::decode("0300001602f0803203000000020002000100000501ff").unwrap();
+///
+/// let mut tracker = S7VarAccessTracker::new();
+/// tracker.push(&S7CommPacket::try_from(&job[..]).unwrap()).unwrap();
+/// tracker.push(&S7CommPacket::try_from(&ack[..]).unwrap()).unwrap();
+///
+/// let access = tracker.next_access().expect("paired write");
+/// assert_eq!(access.kind, S7VarAccessKind::Write);
+/// assert_eq!(access.items[0].address.unwrap().to_string(), "M 0.0 BYTE 4");
+/// assert_eq!(access.items[0].value, [0xa9, 0x10, 0x00, 0x01]);
+/// assert!(access.is_success());
+/// ```
+#[derive(Debug, Default)]
+pub struct S7VarAccessTracker {
+    pending: VecDeque<PendingJob>,
+    completed: VecDeque<S7VarAccess>,
+}
+
+impl S7VarAccessTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a JOB or completes the access its acknowledgement answers.
+    pub fn push(&mut self, packet: &S7CommPacket<'_>) -> Result<(), S7CommParseError> {
+        let header = &packet.s7_header;
+        match header.rosctr {
+            S7_ROSCTR_JOB => self.push_job(packet),
+            S7_ROSCTR_ACK | S7_ROSCTR_ACK_DATA => self.push_ack(packet),
+            _ => Ok(()),
+        }
+    }
+
+    /// Next paired access, in acknowledgement order.
+    pub fn next_access(&mut self) -> Option<S7VarAccess> {
+        self.completed.pop_front()
+    }
+
+    fn push_job(&mut self, packet: &S7CommPacket<'_>) -> Result<(), S7CommParseError> {
+        let (kind, items, values) = match packet.message()? {
+            S7Message::ReadVarRequest(items) => (S7VarAccessKind::Read, items, Vec::new()),
+            S7Message::WriteVarRequest { items, values } => (
+                S7VarAccessKind::Write,
+                items,
+                values.iter().map(|value| value.data.to_vec()).collect(),
+            ),
+            _ => return Ok(()),
+        };
+
+        let pduref = packet.s7_header.pduref;
+        self.pending.retain(|job| job.pduref != pduref);
+        if self.pending.len() >= S7_MAX_PENDING_VAR_JOBS {
+            self.pending.pop_front();
+        }
+        self.pending.push_back(PendingJob {
+            pduref,
+            kind,
+            addresses: items.iter().map(S7ParameterItem::var_address).collect(),
+            values,
+        });
+        Ok(())
+    }
+
+    fn push_ack(&mut self, packet: &S7CommPacket<'_>) -> Result<(), S7CommParseError> {
+        let header = &packet.s7_header;
+        let function = packet.parameter.function;
+        // Un ACK sans parametres (rejet) ne rappelle pas la fonction : seule
+        // la reference PDU le rattache au JOB.
+        if header.rosctr == S7_ROSCTR_ACK_DATA
+            && !matches!(function, S7_FUNCTION_READ_VAR | S7_FUNCTION_WRITE_VAR)
+        {
+            return Ok(());
+        }
+        let Some(position) = self
+            .pending
+            .iter()
+            .position(|job| job.pduref == header.pduref)
+        else {
+            return Ok(());
+        };
+
+        let message = packet.message()?;
+        let Some(job) = self.pending.remove(position) else {
+            return Ok(());
+        };
+        let (return_codes, read_values): (Vec<u8>, Vec<Vec<u8>>) = match message {
+            S7Message::ReadVarResponse(items) => items
+                .iter()
+                .map(|item| (item.return_code, item.data.to_vec()))
+                .unzip(),
+            S7Message::WriteVarResponse(codes) => (codes.to_vec(), Vec::new()),
+            _ => (Vec::new(), Vec::new()),
+        };
+
+        let mut written = job.values.into_iter();
+        let mut read = read_values.into_iter();
+        let items = job
+            .addresses
+            .into_iter()
+            .enumerate()
+            .map(|(index, address)| S7VarAccessItem {
+                address,
+                return_code: return_codes.get(index).copied(),
+                value: match job.kind {
+                    S7VarAccessKind::Read => read.next(),
+                    S7VarAccessKind::Write => written.next(),
+                }
+                .unwrap_or_default(),
+            })
+            .collect();
+
+        self.completed.push_back(S7VarAccess {
+            pduref: header.pduref,
+            kind: job.kind,
+            error_class: header.error_class.unwrap_or(0),
+            error_code: header.error_code.unwrap_or(0),
+            items,
+        });
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(hex_str: &str) -> Vec<u8> {
+        hex::decode(hex_str).expect("valid hex fixture")
+    }
+
+    /// Trames 4077/4078 de s7comm_varservice_libnodavedemo_bench.pcap :
+    /// Read Var de cinq items (I, puis quatre plages de M) et ses resultats.
+    #[test]
+    fn test_tracker_pairs_multi_item_read() {
+        let job = packet(concat!(
+            "0300004f02f0803201000007d5003e00000405120a10020006000081000000120a",
+            "10020006000083000000120a10020006000083000030120a1002003600008300",
+            "0020120a10020004000083000020"
+        ));
+        let ack = packet(concat!(
+            "0300007502f0803203000007d50002006000000405ff040030000000000000ff04",
+            "00300a1000020000ff040030010500000006ff0401b00000010500000006400ccc",
+            "cd0000000000000000000000000000000000000000000000000000000000000000",
+            "00000000000000000000ff04002000000105"
+        ));
+
+        let mut tracker = S7VarAccessTracker::new();
+        tracker
+            .push(&S7CommPacket::try_from(&job[..]).unwrap())
+            .unwrap();
+        assert!(tracker.next_access().is_none());
+        tracker
+            .push(&S7CommPacket::try_from(&ack[..]).unwrap())
+            .unwrap();
+
+        let access = tracker.next_access().expect("paired read");
+        assert_eq!(access.pduref, 0x07d5);
+        assert_eq!(access.kind, S7VarAccessKind::Read);
+        assert!(access.is_success());
+        let rendered: Vec<_> = access
+            .items
+            .iter()
+            .map(|item| item.address.unwrap().to_string())
+            .collect();
+        assert_eq!(
+            rendered,
+            [
+                "I 0.0 BYTE 6",
+                "M 0.0 BYTE 6",
+                "M 6.0 BYTE 6",
+                "M 4.0 BYTE 54",
+                "M 4.0 BYTE 4"
+            ]
+        );
+        let lengths: Vec<_> = access.items.iter().map(|item| item.value.len()).collect();
+        assert_eq!(lengths, [6, 6, 6, 54, 4]);
+        assert_eq!(access.items[1].value, [0x0a, 0x10, 0x00, 0x02, 0x00, 0x00]);
+        assert!(tracker.next_access().is_none());
+    }
+
+    #[test]
+    fn test_tracker_reports_rejected_request_and_ignores_orphans() {
+        // Synthetique : Read Var "DB 1.DBX 0.0 BYTE 64" puis ACK (ROSCTR 2)
+        // sans parametres, classe d'erreur 0x81 code 0x04.
+        let job = packet("0300001f02f080320100000042000e00000401120a10020040000184000000");
+        let rejected = packet("0300001302f080320200000042000000008104");
+        let orphan = packet("0300001602f0803203000000990002000100000501ff");
+
+        let mut tracker = S7VarAccessTracker::new();
+        tracker
+            .push(&S7CommPacket::try_from(&orphan[..]).unwrap())
+            .unwrap();
+        tracker
+            .push(&S7CommPacket::try_from(&job[..]).unwrap())
+            .unwrap();
+        tracker
+            .push(&S7CommPacket::try_from(&rejected[..]).unwrap())
+            .unwrap();
+
+        let access = tracker.next_access().expect("rejected read");
+        assert_eq!((access.error_class, access.error_code), (0x81, 0x04));
+        assert_eq!(access.items.len(), 1);
+        assert_eq!(access.items[0].return_code, None);
+        assert!(access.items[0].value.is_empty());
+        assert!(!access.is_success());
+        assert!(tracker.next_access().is_none());
+    }
+}
@@ -0,0 +1,704 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Bodies of the SMB2 commands that matter for inventory and auditing
+//! (MS-SMB2 §2.2.3 to §2.2.22): dialect and capability negotiation, session
+//! setup, share access, file opens and data transfers.
+//!
+//! Every fixed part is checked against its `StructureSize` and every
+//! variable buffer against the message bounds; READ and WRITE expose their
+//! data as a slice rather than a copy.
+
+use crate::{
+    checks::application::smb::{
+        SMB2_CREATE, SMB2_CREATE_REQUEST_SIZE, SMB2_CREATE_RESPONSE_SIZE, SMB2_DIALECT_311,
+        SMB2_ERROR_RESPONSE_SIZE, SMB2_HEADER_LEN, SMB2_NEGOTIATE,
+        SMB2_NEGOTIATE_CONTEXT_HEADER_LEN, SMB2_NEGOTIATE_REQUEST_SIZE,
+        SMB2_NEGOTIATE_RESPONSE_SIZE, SMB2_READ, SMB2_READ_REQUEST_SIZE, SMB2_READ_RESPONSE_SIZE,
+        SMB2_SESSION_SETUP, SMB2_SESSION_SETUP_REQUEST_SIZE, SMB2_SESSION_SETUP_RESPONSE_SIZE,
+        SMB2_TREE_CONNECT, SMB2_TREE_CONNECT_REQUEST_SIZE, SMB2_TREE_CONNECT_RESPONSE_SIZE,
+        SMB2_WRITE, SMB2_WRITE_REQUEST_SIZE, SMB2_WRITE_RESPONSE_SIZE,
+        STATUS_MORE_PROCESSING_REQUIRED, STATUS_PENDING, extract_smb_utf16, extract_smb2_body,
+        extract_smb2_buffer, is_nt_error, smb2_capability_names, smb2_create_action_name,
+        smb2_create_disposition_name, smb2_dialect_name, smb2_negotiate_context_name,
+        smb2_share_type_name,
+    },
+    errors::application::smb::SmbError,
+    parse::application::protocols::{bounded_capacity, smb::Smb2Message},
+};
+
+/// Handle of an open file, returned by CREATE and reused by READ, WRITE
+/// and CLOSE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Smb2FileId {
+    pub persistent: u64,
+    pub volatile: u64,
+}
+
+/// Negotiate context of an SMB 3.1.1 NEGOTIATE, data kept raw.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Smb2NegotiateContext<'a> {
+    pub context_type: u16,
+    pub data: &'a [u8],
+}
+
+impl Smb2NegotiateContext<'_> {
+    pub fn name(&self) -> &'static str {
+        smb2_negotiate_context_name(self.context_type)
+    }
+}
+
+/// Decoded body of an SMB2 message.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Smb2Body<'a> {
+    NegotiateRequest {
+        security_mode: u16,
+        capabilities: u32,
+        client_guid: [u8; 16],
+        /// Dialects offered, in the client's order.
+        dialects: Vec<u16>,
+        /// Present when SMB 3.1.1 is offered.
+        negotiate_contexts: Vec<Smb2NegotiateContext<'a>>,
+    },
+    NegotiateResponse {
+        security_mode: u16,
+        dialect: u16,
+        server_guid: [u8; 16],
+        capabilities: u32,
+        max_transact_size: u32,
+        max_read_size: u32,
+        max_write_size: u32,
+        /// FILETIME: 100 ns intervals since 1601-01-01.
+        system_time: u64,
+        /// GSS-API token listing the mechanisms the server accepts.
+        security_buffer: &'a [u8],
+        negotiate_contexts: Vec<Smb2NegotiateContext<'a>>,
+    },
+    SessionSetupRequest {
+        flags: u8,
+        security_mode: u8,
+        capabilities: u32,
+        previous_session_id: u64,
+        security_buffer: &'a [u8],
+    },
+    SessionSetupResponse {
+        session_flags: u16,
+        security_buffer: &'a [u8],
+    },
+    TreeConnectRequest {
+        flags: u16,
+        /// UNC path of the share, `\\server\share`.
+        path: String,
+    },
+    TreeConnectResponse {
+        share_type: u8,
+        share_flags: u32,
+        capabilities: u32,
+        maximal_access: u32,
+    },
+    CreateRequest {
+        requested_oplock_level: u8,
+        impersonation_level: u32,
+        desired_access: u32,
+        file_attributes: u32,
+        share_access: u32,
+        create_disposition: u32,
+        create_options: u32,
+        /// Path relative to the share root; empty for the root itself.
+        name: String,
+    },
+    CreateResponse {
+        oplock_level: u8,
+        create_action: u32,
+        allocation_size: u64,
+        end_of_file: u64,
+        file_attributes: u32,
+        file_id: Smb2FileId,
+    },
+    ReadRequest {
+        length: u32,
+        offset: u64,
+        file_id: Smb2FileId,
+        minimum_count: u32,
+    },
+    ReadResponse {
+        data_remaining: u32,
+        data: &'a [u8],
+    },
+    WriteRequest {
+        offset: u64,
+        file_id: Smb2FileId,
+        data: &'a [u8],
+    },
+    WriteResponse {
+        count: u32,
+    },
+    /// ERROR response: failure, or interim response of an async command.
+    Error {
+        error_context_count: u8,
+        error_data: &'a [u8],
+    },
+    /// Command whose body is not decoded, structure size included.
+    Other(&'a [u8]),
+}
+
+impl<'a> Smb2Body<'a> {
+    /// GSS-API token of a NEGOTIATE response or a SESSION_SETUP.
+    pub fn security_buffer(&self) -> Option<&'a [u8]> {
+        match self {
+            Smb2Body::NegotiateResponse {
+                security_buffer, ..
+            }
+            | Smb2Body::SessionSetupRequest {
+                security_buffer, ..
+            }
+            | Smb2Body::SessionSetupResponse {
+                security_buffer, ..
+            } => Some(security_buffer),
+            _ => None,
+        }
+    }
+
+    /// Names of the dialects a NEGOTIATE offers or selects.
+    pub fn dialect_names(&self) -> Vec<&'static str> {
+        match self {
+            Smb2Body::NegotiateRequest { dialects, .. } => {
+                dialects.iter().copied().map(smb2_dialect_name).collect()
+            }
+            Smb2Body::NegotiateResponse { dialect, .. } => vec![smb2_dialect_name(*dialect)],
+            _ => Vec::new(),
+        }
+    }
+
+    /// Names of the global capabilities of a NEGOTIATE.
+    pub fn capability_names(&self) -> Vec<&'static str> {
+        match self {
+            Smb2Body::NegotiateRequest { capabilities, .. }
+            | Smb2Body::NegotiateResponse { capabilities, .. } => {
+                smb2_capability_names(*capabilities)
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    /// Last component of a TREE_CONNECT path (`C$`, `IPC$`...).
+    pub fn share_name(&self) -> Option<&str> {
+        match self {
+            Smb2Body::TreeConnectRequest { path, .. } => path.rsplit('\\').next(),
+            _ => None,
+        }
+    }
+
+    pub fn share_type_name(&self) -> Option<&'static str> {
+        match self {
+            Smb2Body::TreeConnectResponse { share_type, .. } => {
+                Some(smb2_share_type_name(*share_type))
+            }
+            _ => None,
+        }
+    }
+
+    pub fn create_disposition_name(&self) -> Option<&'static str> {
+        match self {
+            Smb2Body::CreateRequest {
+                create_disposition, ..
+            } => Some(smb2_create_disposition_name(*create_disposition)),
+            _ => None,
+        }
+    }
+
+    pub fn create_action_name(&self) -> Option<&'static str> {
+        match self {
+            Smb2Body::CreateResponse { create_action, .. } => {
+                Some(smb2_create_action_name(*create_action))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl<'a> Smb2Message<'a> {
+    /// Body of the message, decoded according to its command and direction.
+    pub fn body(&self) -> Result<Smb2Body<'a>, SmbError> {
+        let header = &self.header;
+        let message = self.message;
+        if header.is_response() && is_error_response(header.command, header.status) {
+            let body = extract_smb2_body(message, SMB2_ERROR_RESPONSE_SIZE, "ERROR response")?;
+            let byte_count = le32(body, 4) as usize;
+            return Ok(Smb2Body::Error {
+                error_context_count: body[2],
+                error_data: extract_smb2_buffer(
+                    message,
+                    SMB2_HEADER_LEN + 8,
+                    byte_count,
+                    "error data",
+                )?,
+            });
+        }
+        match (header.command, header.is_response()) {
+            (SMB2_NEGOTIATE, false) => negotiate_request(message),
+            (SMB2_NEGOTIATE, true) => negotiate_response(message),
+            (SMB2_SESSION_SETUP, false) => {
+                let body = extract_smb2_body(
+                    message,
+                    SMB2_SESSION_SETUP_REQUEST_SIZE,
+                    "SESSION_SETUP request",
+                )?;
+                Ok(Smb2Body::SessionSetupRequest {
+                    flags: body[2],
+                    security_mode: body[3],
+                    capabilities: le32(body, 4),
+                    previous_session_id: le64(body, 16),
+                    security_buffer: buffer16(message, body, 12, "security buffer")?,
+                })
+            }
+            (SMB2_SESSION_SETUP, true) => {
+                let body = extract_smb2_body(
+                    message,
+                    SMB2_SESSION_SETUP_RESPONSE_SIZE,
+                    "SESSION_SETUP response",
+                )?;
+                Ok(Smb2Body::SessionSetupResponse {
+                    session_flags: le16(body, 2),
+                    security_buffer: buffer16(message, body, 4, "security buffer")?,
+                })
+            }
+            (SMB2_TREE_CONNECT, false) => {
+                let body = extract_smb2_body(
+                    message,
+                    SMB2_TREE_CONNECT_REQUEST_SIZE,
+                    "TREE_CONNECT request",
+                )?;
+                Ok(Smb2Body::TreeConnectRequest {
+                    flags: le16(body, 2),
+                    path: extract_smb_utf16(
+                        buffer16(message, body, 4, "share path")?,
+                        "share path",
+                    )?,
+                })
+            }
+            (SMB2_TREE_CONNECT, true) => {
+                let body = extract_smb2_body(
+                    message,
+                    SMB2_TREE_CONNECT_RESPONSE_SIZE,
+                    "TREE_CONNECT response",
+                )?;
+                Ok(Smb2Body::TreeConnectResponse {
+                    share_type: body[2],
+                    share_flags: le32(body, 4),
+                    capabilities: le32(body, 8),
+                    maximal_access: le32(body, 12),
+                })
+            }
+            (SMB2_CREATE, false) => {
+                let body = extract_smb2_body(message, SMB2_CREATE_REQUEST_SIZE, "CREATE request")?;
+                Ok(Smb2Body::CreateRequest {
+                    requested_oplock_level: body[3],
+                    impersonation_level: le32(body, 4),
+                    desired_access: le32(body, 24),
+                    file_attributes: le32(body, 28),
+                    share_access: le32(body, 32),
+                    create_disposition: le32(body, 36),
+                    create_options: le32(body, 40),
+                    name: extract_smb_utf16(
+                        buffer16(message, body, 44, "file name")?,
+                        "file name",
+                    )?,
+                })
+            }
+            (SMB2_CREATE, true) => {
+                let body =
+                    extract_smb2_body(message, SMB2_CREATE_RESPONSE_SIZE, "CREATE response")?;
+                Ok(Smb2Body::CreateResponse {
+                    oplock_level: body[2],
+                    create_action: le32(body, 4),
+                    allocation_size: le64(body, 40),
+                    end_of_file: le64(body, 48),
+                    file_attributes: le32(body, 56),
+                    file_id: file_id(body, 64),
+                })
+            }
+            (SMB2_READ, false) => {
+                let body = extract_smb2_body(message, SMB2_READ_REQUEST_SIZE, "READ request")?;
+                Ok(Smb2Body::ReadRequest {
+                    length: le32(body, 4),
+                    offset: le64(body, 8),
+                    file_id: file_id(body, 16),
+                    minimum_count: le32(body, 32),
+                })
+            }
+            (SMB2_READ, true) => {
+                let body = extract_smb2_body(message, SMB2_READ_RESPONSE_SIZE, "READ response")?;
+                Ok(Smb2Body::ReadResponse {
+                    data_remaining: le32(body, 8),
+                    data: extract_smb2_buffer(
+                        message,
+                        usize::from(body[2]),
+                        le32(body, 4) as usize,
+                        "read data",
+                    )?,
+                })
+            }
+            (SMB2_WRITE, false) => {
+                let body = extract_smb2_body(message, SMB2_WRITE_REQUEST_SIZE, "WRITE request")?;
+                Ok(Smb2Body::WriteRequest {
+                    offset: le64(body, 8),
+                    file_id: file_id(body, 16),
+                    data: extract_smb2_buffer(
+                        message,
+                        usize::from(le16(body, 2)),
+                        le32(body, 4) as usize,
+                        "write data",
+                    )?,
+                })
+            }
+            (SMB2_WRITE, true) => {
+                let body = extract_smb2_body(message, SMB2_WRITE_RESPONSE_SIZE, "WRITE response")?;
+                Ok(Smb2Body::WriteResponse {
+                    count: le32(body, 4),
+                })
+            }
+            _ => Ok(Smb2Body::Other(&message[SMB2_HEADER_LEN..])),
+        }
+    }
+}
+
+/// Une reponse en echec porte un corps ERROR, sauf SESSION_SETUP qui
+/// poursuit l'authentification sous STATUS_MORE_PROCESSING_REQUIRED ;
+/// STATUS_PENDING annonce une reponse asynchrone intermediaire.
+fn is_error_response(command: u16, status: u32) -> bool {
+    if command == SMB2_SESSION_SETUP && status == STATUS_MORE_PROCESSING_REQUIRED {
+        return false;
+    }
+    status == STATUS_PENDING || is_nt_error(status)
+}
+
+fn negotiate_request(message: &[u8]) -> Result<Smb2Body<'_>, SmbError> {
+    let body = extract_smb2_body(message, SMB2_NEGOTIATE_REQUEST_SIZE, "NEGOTIATE request")?;
+    let dialect_count = usize::from(le16(body, 2));
+    let dialect_bytes = body
+        .get(36..36 + 2 * dialect_count)
+        .ok_or(SmbError::Truncated { field: "dialects" })?;
+    let dialects: Vec<u16> = dialect_bytes
+        .chunks_exact(2)
+        .map(|dialect| u16::from_le_bytes([dialect[0], dialect[1]]))
+        .collect();
+    let negotiate_contexts = if dialects.contains(&SMB2_DIALECT_311) {
+        negotiate_contexts(
+            message,
+            le32(body, 28) as usize,
+            usize::from(le16(body, 32)),
+        )?
+    } else {
+        Vec::new()
+    };
+    Ok(Smb2Body::NegotiateRequest {
+        security_mode: le16(body, 4),
+        capabilities: le32(body, 8),
+        client_guid: guid(body, 12),
+        dialects,
+        negotiate_contexts,
+    })
+}
+
+fn negotiate_response(message: &[u8]) -> Result<Smb2Body<'_>, SmbError> {
+    let body = extract_smb2_body(message, SMB2_NEGOTIATE_RESPONSE_SIZE, "NEGOTIATE response")?;
+    let dialect = le16(body, 4);
+    let negotiate_contexts = if dialect == SMB2_DIALECT_311 {
+        negotiate_contexts(message, le32(body, 60) as usize, usize::from(le16(body, 6)))?
+    } else {
+        Vec::new()
+    };
+    Ok(Smb2Body::NegotiateResponse {
+        security_mode: le16(body, 2),
+        dialect,
+        server_guid: guid(body, 8),
+        capabilities: le32(body, 24),
+        max_transact_size: le32(body, 28),
+        max_read_size: le32(body, 32),
+        max_write_size: le32(body, 36),
+        system_time: le64(body, 40),
+        security_buffer: buffer16(message, body, 56, "security buffer")?,
+        negotiate_contexts,
+    })
+}
+
+fn negotiate_contexts(
+    message: &[u8],
+    offset: usize,
+    count: usize,
+) -> Result<Vec<Smb2NegotiateContext<'_>>, SmbError> {
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+    let mut contexts = Vec::with_capacity(bounded_capacity(
+        count,
+        message.len().saturating_sub(offset),
+        SMB2_NEGOTIATE_CONTEXT_HEADER_LEN,
+    ));
+    let mut at = offset;
+    for index in 0..count {
+        // Chaque contexte apres le premier est aligne sur 8 octets.
+        if index > 0 {
+            at = at.next_multiple_of(8);
+        }
+        let header = extract_smb2_buffer(
+            message,
+            at,
+            SMB2_NEGOTIATE_CONTEXT_HEADER_LEN,
+            "negotiate context",
+        )?;
+        let data_length = usize::from(le16(header, 2));
+        let data = extract_smb2_buffer(
+            message,
+            at + SMB2_NEGOTIATE_CONTEXT_HEADER_LEN,
+            data_length,
+            "negotiate context",
+        )?;
+        contexts.push(Smb2NegotiateContext {
+            context_type: le16(header, 0),
+            data,
+        });
+        at += SMB2_NEGOTIATE_CONTEXT_HEADER_LEN + data_length;
+    }
+    Ok(contexts)
+}
+
+/// Tampon decrit par un couple offset u16 / longueur u16 a `at` du corps.
+fn buffer16<'a>(
+    message: &'a [u8],
+    body: &[u8],
+    at: usize,
+    field: &'static str,
+) -> Result<&'a [u8], SmbError> {
+    extract_smb2_buffer(
+        message,
+        usize::from(le16(body, at)),
+        usize::from(le16(body, at + 2)),
+        field,
+    )
+}
+
+fn le16(bytes: &[u8], at: usize) -> u16 {
+    u16::from_le_bytes([bytes[at], bytes[at + 1]])
+}
+
+fn le32(bytes: &[u8], at: usize) -> u32 {
+    u32::from_le_bytes([bytes[at], bytes[at + 1], bytes[at + 2], bytes[at + 3]])
+}
+
+fn le64(bytes: &[u8], at: usize) -> u64 {
+    u64::from(le32(bytes, at)) | (u64::from(le32(bytes, at + 4)) << 32)
+}
+
+fn guid(bytes: &[u8], at: usize) -> [u8; 16] {
+    let mut guid = [0u8; 16];
+    guid.copy_from_slice(&bytes[at..at + 16]);
+    guid
+}
+
+fn file_id(bytes: &[u8], at: usize) -> Smb2FileId {
+    Smb2FileId {
+        persistent: le64(bytes, at),
+        volatile: le64(bytes, at + 8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        checks::application::smb::{
+            SMB2_FLAGS_SERVER_TO_REDIR, SMB2_PROTOCOL_ID, STATUS_ACCESS_DENIED,
+        },
+        parse::application::protocols::smb::SmbPacket,
+    };
+
+    use super::*;
+
+    /// CREATE Request de « RSX_DR-SF » (ouverture pour lecture
+    /// d'attributs, contextes MxAc et QFid), extrait d'une trame reelle
+    /// tunnelee en CAPWAP (segment TCP 56699 -> 445, en-tete NBSS inclus).
+    const CREATE_REQUEST_HEX: &str = concat!(
+        "000000c0fe534d42400001000300000005000000300000000000000067040900",
+        "00000000fffe0000966e611ec3ba49eb00000000000000000000000000000000",
+        "0000000039000000020000000000000000000000000000000000000080000000",
+        "0000000007000000010000000000200078001200900000003000000052005300",
+        "58005f00440052002d0053004600000000000000180000001000040000001800",
+        "000000004d784163000000000000000010000400000018000000000051466964",
+        "00000000"
+    );
+
+    /// CREATE Response correspondante (segment TCP 445 -> 56699).
+    const CREATE_RESPONSE_HEX: &str = concat!(
+        "000000f0fe534d424000010000000000050001003100000000000000120a0600",
+        "00000000fffe0000966e611ec3ba49eb00000000000000000000000000000000",
+        "00000000590000000100000080a3cbc22d69d80100e1f6d4ba47db0100437f0f",
+        "2e46db0100437f0f2e46db010000000000000000000000000000000010000000",
+        "00000000c8edb59c0000000058d03e7b00000000980000005800000020000000",
+        "1000040000001800080000004d7841630000000000000000a900120000000000",
+        "1000040000001800200000005146696400000000500158020000000001fe0000",
+        "0000000000000000000000000000000000000000"
+    );
+
+    fn single_message(segment: &[u8]) -> Smb2Body<'_> {
+        let packet = SmbPacket::from_nbss(segment).expect("message SMB2 valide");
+        let [message] = packet.smb2_messages() else {
+            panic!("attendu un seul message");
+        };
+        message.body().expect("corps valide")
+    }
+
+    /// Message SMB2 synthetique : en-tete puis corps.
+    fn message(command: u16, flags: u32, status: u32, body: &[u8]) -> Vec<u8> {
+        let mut message = vec![0u8; SMB2_HEADER_LEN];
+        message[..4].copy_from_slice(&SMB2_PROTOCOL_ID);
+        message[4] = 64;
+        message[8..12].copy_from_slice(&status.to_le_bytes());
+        message[12..14].copy_from_slice(&command.to_le_bytes());
+        message[16..20].copy_from_slice(&flags.to_le_bytes());
+        message.extend_from_slice(body);
+        message
+    }
+
+    fn body_of(bytes: &[u8]) -> Result<Smb2Body<'_>, SmbError> {
+        let packet = SmbPacket::try_from(bytes).expect("en-tete SMB2 valide");
+        packet.smb2_messages()[0].body()
+    }
+
+    #[test]
+    fn captured_create_request_and_response() {
+        let segment = hex::decode(CREATE_REQUEST_HEX).expect("invalid test hex fixture");
+        let request = single_message(&segment);
+        let Smb2Body::CreateRequest {
+            ref name,
+            desired_access,
+            share_access,
+            ..
+        } = request
+        else {
+            panic!("attendu une CREATE Request");
+        };
+        assert_eq!(name, "RSX_DR-SF");
+        assert_eq!(desired_access, 0x80);
+        assert_eq!(share_access, 0x07);
+        assert_eq!(request.create_disposition_name(), Some("OPEN"));
+
+        let segment = hex::decode(CREATE_RESPONSE_HEX).expect("invalid test hex fixture");
+        let response = single_message(&segment);
+        let Smb2Body::CreateResponse {
+            file_attributes,
+            file_id,
+            ..
+        } = response
+        else {
+            panic!("attendu une CREATE Response");
+        };
+        // FILE_ATTRIBUTE_DIRECTORY.
+        assert_eq!(file_attributes, 0x10);
+        assert_eq!(file_id.persistent, 0x9cb5_edc8);
+        assert_eq!(response.create_action_name(), Some("OPENED"));
+    }
+
+    #[test]
+    fn tree_connect_read_and_write() {
+        let path: Vec<u8> = "\\\\srv\\C$"
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes)
+            .collect();
+        let mut body = vec![9, 0, 0, 0, 72, 0, path.len() as u8, 0];
+        body.extend_from_slice(&path);
+        let bytes = message(SMB2_TREE_CONNECT, 0, 0, &body);
+        let tree = body_of(&bytes).expect("TREE_CONNECT valide");
+        assert_eq!(tree.share_name(), Some("C$"));
+
+        // READ Response : 5 octets de donnees a l'offset 80.
+        let mut body = vec![17, 0, 80, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        body.extend_from_slice(b"hello");
+        let bytes = message(SMB2_READ, SMB2_FLAGS_SERVER_TO_REDIR, 0, &body);
+        assert_eq!(
+            body_of(&bytes),
+            Ok(Smb2Body::ReadResponse {
+                data_remaining: 0,
+                data: b"hello"
+            })
+        );
+
+        // WRITE Request dont la longueur depasse le message.
+        let mut body = vec![0u8; 48];
+        body[0] = 49;
+        body[2] = 112;
+        body[4] = 0x40;
+        let bytes = message(SMB2_WRITE, 0, 0, &body);
+        assert_eq!(
+            body_of(&bytes),
+            Err(SmbError::InvalidBufferOffset {
+                field: "write data",
+                offset: 112,
+                length: 64
+            })
+        );
+    }
+
+    #[test]
+    fn negotiate_with_smb311_contexts() {
+        // 2 dialectes (3.0.2, 3.1.1) puis un contexte PREAUTH a l'offset 104.
+        let mut body = vec![0u8; 36];
+        body[0] = 36;
+        body[2] = 2;
+        body[8] = 0x44;
+        body[28] = 104;
+        body[32] = 1;
+        body.extend_from_slice(&[0x02, 0x03, 0x11, 0x03]);
+        body.extend_from_slice(&[1, 0, 4, 0, 0, 0, 0, 0, 1, 0, 0, 0]);
+        let bytes = message(SMB2_NEGOTIATE, 0, 0, &body);
+        let negotiate = body_of(&bytes).expect("NEGOTIATE valide");
+        assert_eq!(negotiate.dialect_names(), ["SMB 3.0.2", "SMB 3.1.1"]);
+        assert_eq!(negotiate.capability_names(), ["LARGE_MTU", "ENCRYPTION"]);
+        let Smb2Body::NegotiateRequest {
+            negotiate_contexts, ..
+        } = negotiate
+        else {
+            panic!("attendu une NEGOTIATE Request");
+        };
+        assert_eq!(negotiate_contexts.len(), 1);
+        assert_eq!(
+            negotiate_contexts[0].name(),
+            "PREAUTH_INTEGRITY_CAPABILITIES"
+        );
+        assert_eq!(negotiate_contexts[0].data, [1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn failed_responses_carry_an_error_body() {
+        let body = [9, 0, 0, 0, 0, 0, 0, 0, 0];
+        let bytes = message(
+            SMB2_TREE_CONNECT,
+            SMB2_FLAGS_SERVER_TO_REDIR,
+            STATUS_ACCESS_DENIED,
+            &body,
+        );
+        assert_eq!(
+            body_of(&bytes),
+            Ok(Smb2Body::Error {
+                error_context_count: 0,
+                error_data: &[]
+            })
+        );
+
+        // STATUS_MORE_PROCESSING_REQUIRED : SESSION_SETUP normal.
+        let body = [9, 0, 0, 0, 72, 0, 1, 0, 0xA1];
+        let bytes = message(
+            SMB2_SESSION_SETUP,
+            SMB2_FLAGS_SERVER_TO_REDIR,
+            STATUS_MORE_PROCESSING_REQUIRED,
+            &body,
+        );
+        assert_eq!(
+            body_of(&bytes).map(|body| body.security_buffer()),
+            Ok(Some(&[0xA1][..]))
+        );
+    }
+}
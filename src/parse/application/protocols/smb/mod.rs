@@ -0,0 +1,533 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! SMB over the NetBIOS session service (TCP 139) or direct-hosted on
+//! TCP 445 (MS-SMB2 §2.1).
+//!
+//! [`SmbPacket`] reads the message an NBSS session message carries: an
+//! SMB1 message (kept for legacy alerting, with the dialects of its
+//! NEGOTIATE), one or several compounded SMB2/SMB3 messages, or the
+//! transform header of an encrypted or compressed SMB3 message. The body
+//! of an SMB2 message decodes on demand through [`Smb2Message::body`]
+//! ([`command`]); the security blobs of NEGOTIATE and SESSION_SETUP
+//! through [`security::SmbSecurityBlob`].
+
+use std::convert::TryFrom;
+
+use crate::{
+    checks::application::smb::{
+        SMB1_COM_NEGOTIATE, SMB1_DIALECT_BUFFER_FORMAT, SMB1_FLAGS_REPLY, SMB1_HEADER_LEN,
+        SMB1_PROTOCOL_ID, SMB2_COMPRESSION_HEADER_LEN, SMB2_COMPRESSION_PROTOCOL_ID,
+        SMB2_FLAGS_ASYNC_COMMAND, SMB2_FLAGS_RELATED_OPERATIONS, SMB2_FLAGS_SERVER_TO_REDIR,
+        SMB2_FLAGS_SIGNED, SMB2_HEADER_LEN, SMB2_PROTOCOL_ID, SMB2_TRANSFORM_HEADER_LEN,
+        SMB2_TRANSFORM_PROTOCOL_ID, nt_status_name, smb1_command_name, smb2_command_name,
+        validate_smb2_header,
+    },
+    errors::application::smb::SmbError,
+    parse::application::protocols::netbios::{NbssMessageType, NbssPacket},
+};
+
+pub mod command;
+pub mod security;
+
+/// Message carried by an NBSS session message.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SmbPacket<'a> {
+    /// SMB1 (CIFS), deprecated since 2014: its presence on the network is
+    /// worth an alert on its own.
+    Smb1(Smb1Message<'a>),
+    /// SMB2/SMB3 messages; more than one when compounded through
+    /// `NextCommand`.
+    Smb2(Vec<Smb2Message<'a>>),
+    /// SMB3 encrypted message: only the transform header is readable.
+    Encrypted(Smb2TransformHeader<'a>),
+    /// SMB 3.1.1 compressed message.
+    Compressed(Smb2CompressionHeader<'a>),
+}
+
+impl<'a> TryFrom<&'a [u8]> for SmbPacket<'a> {
+    type Error = SmbError;
+
+    fn try_from(payload: &'a [u8]) -> Result<Self, SmbError> {
+        let Some(protocol_id) = payload.first_chunk::<4>() else {
+            return Err(SmbError::InvalidLength {
+                expected: 4,
+                actual: payload.len(),
+            });
+        };
+        match *protocol_id {
+            SMB2_PROTOCOL_ID => parse_compound(payload).map(SmbPacket::Smb2),
+            SMB1_PROTOCOL_ID => Smb1Message::parse(payload).map(SmbPacket::Smb1),
+            SMB2_TRANSFORM_PROTOCOL_ID => {
+                Smb2TransformHeader::parse(payload).map(SmbPacket::Encrypted)
+            }
+            SMB2_COMPRESSION_PROTOCOL_ID => {
+                Smb2CompressionHeader::parse(payload).map(SmbPacket::Compressed)
+            }
+            _ => Err(SmbError::UnknownProtocolId(u32::from_be_bytes(
+                *protocol_id,
+            ))),
+        }
+    }
+}
+
+impl<'a> SmbPacket<'a> {
+    /// Reads the NBSS header at the start of a TCP 139 or 445 segment, then
+    /// the SMB message it carries.
+    pub fn from_nbss(segment: &'a [u8]) -> Result<Self, SmbError> {
+        NbssPacket::try_from(segment)?.smb()
+    }
+
+    pub fn is_smb1(&self) -> bool {
+        matches!(self, SmbPacket::Smb1(_))
+    }
+
+    /// SMB2 messages of the packet, empty for the other forms.
+    pub fn smb2_messages(&self) -> &[Smb2Message<'a>] {
+        match self {
+            SmbPacket::Smb2(messages) => messages,
+            _ => &[],
+        }
+    }
+}
+
+impl<'a> NbssPacket<'a> {
+    /// SMB message of a session message.
+    pub fn smb(&self) -> Result<SmbPacket<'a>, SmbError> {
+        if self.header.message_type != NbssMessageType::SessionMessage {
+            return Err(SmbError::NotSessionMessage(self.header.message_type.code()));
+        }
+        SmbPacket::try_from(self.payload)
+    }
+}
+
+/// SMB1 message (MS-CIFS §2.2.3).
+///
+/// ```mermaid
+/// ---
+/// title: Smb1Message
+/// ---
+/// packet-beta
+/// 0-31: "Protocol 0xFF 'SMB'"
+/// 32-39: "Command u8"
+/// 40-71: "Status u32"
+/// 72-79: "Flags u8"
+/// 80-95: "Flags2 u16"
+/// 96-111: "PIDHigh u16"
+/// 112-175: "SecurityFeatures (8 bytes)"
+/// 176-191: "Reserved u16"
+/// 192-207: "TID u16"
+/// 208-223: "PIDLow u16"
+/// 224-239: "UID u16"
+/// 240-255: "MID u16"
+/// 256-263: "WordCount u8"
+/// 264-295: "Words, ByteCount u16, Bytes (variable)"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Smb1Message<'a> {
+    pub command: u8,
+    pub status: u32,
+    pub flags: u8,
+    pub flags2: u16,
+    pub tree_id: u16,
+    /// PIDHigh and PIDLow joined.
+    pub process_id: u32,
+    pub user_id: u16,
+    pub multiplex_id: u16,
+    /// Parameter words, `WordCount` * 2 bytes.
+    pub parameters: &'a [u8],
+    /// Byte block, `ByteCount` bytes.
+    pub data: &'a [u8],
+}
+
+impl<'a> Smb1Message<'a> {
+    fn parse(message: &'a [u8]) -> Result<Self, SmbError> {
+        if message.len() < SMB1_HEADER_LEN + 1 {
+            return Err(SmbError::InvalidLength {
+                expected: SMB1_HEADER_LEN + 1,
+                actual: message.len(),
+            });
+        }
+        let word_count = usize::from(message[SMB1_HEADER_LEN]);
+        let parameters_start = SMB1_HEADER_LEN + 1;
+        let byte_count_start = parameters_start + 2 * word_count;
+        let Some(byte_count) = message.get(byte_count_start..byte_count_start + 2) else {
+            return Err(SmbError::Truncated {
+                field: "SMB1 parameter words",
+            });
+        };
+        let data_start = byte_count_start + 2;
+        let byte_count = usize::from(u16::from_le_bytes([byte_count[0], byte_count[1]]));
+        let Some(data) = message.get(data_start..data_start + byte_count) else {
+            return Err(SmbError::Truncated {
+                field: "SMB1 byte block",
+            });
+        };
+        Ok(Smb1Message {
+            command: message[4],
+            status: u32::from_le_bytes([message[5], message[6], message[7], message[8]]),
+            flags: message[9],
+            flags2: u16::from_le_bytes([message[10], message[11]]),
+            tree_id: u16::from_le_bytes([message[24], message[25]]),
+            process_id: u32::from_le_bytes([message[26], message[27], message[12], message[13]]),
+            user_id: u16::from_le_bytes([message[28], message[29]]),
+            multiplex_id: u16::from_le_bytes([message[30], message[31]]),
+            parameters: &message[parameters_start..byte_count_start],
+            data,
+        })
+    }
+
+    pub fn is_response(&self) -> bool {
+        self.flags & SMB1_FLAGS_REPLY != 0
+    }
+
+    pub fn command_name(&self) -> &'static str {
+        smb1_command_name(self.command)
+    }
+
+    /// Dialects offered by a NEGOTIATE request, in the client's order.
+    /// `None` for other messages or a malformed dialect list.
+    pub fn negotiate_dialects(&self) -> Option<Vec<&'a str>> {
+        if self.command != SMB1_COM_NEGOTIATE || self.is_response() {
+            return None;
+        }
+        let mut dialects = Vec::new();
+        let mut rest = self.data;
+        while let [SMB1_DIALECT_BUFFER_FORMAT, tail @ ..] = rest {
+            let end = tail.iter().position(|byte| *byte == 0)?;
+            dialects.push(std::str::from_utf8(&tail[..end]).ok()?);
+            rest = &tail[end + 1..];
+        }
+        rest.is_empty().then_some(dialects)
+    }
+
+    /// Whether a NEGOTIATE request also offers SMB2, so that the server can
+    /// answer with an SMB2 NEGOTIATE response.
+    pub fn offers_smb2(&self) -> bool {
+        self.negotiate_dialects()
+            .is_some_and(|dialects| dialects.iter().any(|d| d.starts_with("SMB 2.")))
+    }
+}
+
+/// SMB2 packet header (MS-SMB2 §2.2.1), sync or async form.
+///
+/// ```mermaid
+/// ---
+/// title: Smb2Header
+/// ---
+/// packet-beta
+/// 0-31: "ProtocolId 0xFE 'SMB'"
+/// 32-47: "StructureSize 64"
+/// 48-63: "CreditCharge u16"
+/// 64-95: "Status / ChannelSequence u32"
+/// 96-111: "Command u16"
+/// 112-127: "Credits u16"
+/// 128-159: "Flags u32"
+/// 160-191: "NextCommand u32"
+/// 192-255: "MessageId u64"
+/// 256-319: "AsyncId u64 or Reserved u32 + TreeId u32"
+/// 320-383: "SessionId u64"
+/// 384-511: "Signature (16 bytes)"
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Smb2Header {
+    pub credit_charge: u16,
+    /// NT status of a response; channel sequence of an SMB 3.x request.
+    pub status: u32,
+    pub command: u16,
+    /// Credits requested or granted.
+    pub credits: u16,
+    pub flags: u32,
+    /// Offset of the next compounded message, 0 for the last one.
+    pub next_command: u32,
+    pub message_id: u64,
+    /// Set by the async form of the header.
+    pub async_id: Option<u64>,
+    /// Set by the sync form of the header.
+    pub tree_id: Option<u32>,
+    pub session_id: u64,
+    pub signature: [u8; 16],
+}
+
+impl Smb2Header {
+    fn parse(header: &[u8]) -> Self {
+        let u32_at = |at: usize| {
+            u32::from_le_bytes([header[at], header[at + 1], header[at + 2], header[at + 3]])
+        };
+        let u64_at = |at: usize| u64::from(u32_at(at)) | (u64::from(u32_at(at + 4)) << 32);
+        let flags = u32_at(16);
+        let (async_id, tree_id) = if flags & SMB2_FLAGS_ASYNC_COMMAND != 0 {
+            (Some(u64_at(32)), None)
+        } else {
+            (None, Some(u32_at(36)))
+        };
+        let mut signature = [0u8; 16];
+        signature.copy_from_slice(&header[48..64]);
+        Smb2Header {
+            credit_charge: u16::from_le_bytes([header[6], header[7]]),
+            status: u32_at(8),
+            command: u16::from_le_bytes([header[12], header[13]]),
+            credits: u16::from_le_bytes([header[14], header[15]]),
+            flags,
+            next_command: u32_at(20),
+            message_id: u64_at(24),
+            async_id,
+            tree_id,
+            session_id: u64_at(40),
+            signature,
+        }
+    }
+
+    pub fn is_response(&self) -> bool {
+        self.flags & SMB2_FLAGS_SERVER_TO_REDIR != 0
+    }
+
+    pub fn is_async(&self) -> bool {
+        self.flags & SMB2_FLAGS_ASYNC_COMMAND != 0
+    }
+
+    /// Compounded with the previous message and sharing its handles.
+    pub fn is_related(&self) -> bool {
+        self.flags & SMB2_FLAGS_RELATED_OPERATIONS != 0
+    }
+
+    pub fn is_signed(&self) -> bool {
+        self.flags & SMB2_FLAGS_SIGNED != 0
+    }
+
+    pub fn command_name(&self) -> &'static str {
+        smb2_command_name(self.command)
+    }
+
+    /// Name of the NT status of a response.
+    pub fn status_name(&self) -> &'static str {
+        nt_status_name(self.status)
+    }
+}
+
+/// One SMB2 message of a packet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Smb2Message<'a> {
+    pub header: Smb2Header,
+    /// The whole message, header included: buffer offsets count from its
+    /// first byte.
+    pub message: &'a [u8],
+}
+
+fn parse_compound(payload: &[u8]) -> Result<Vec<Smb2Message<'_>>, SmbError> {
+    let mut messages = Vec::new();
+    let mut rest = payload;
+    loop {
+        validate_smb2_header(rest)?;
+        if rest[..4] != SMB2_PROTOCOL_ID {
+            return Err(SmbError::UnknownProtocolId(u32::from_be_bytes([
+                rest[0], rest[1], rest[2], rest[3],
+            ])));
+        }
+        let header = Smb2Header::parse(rest);
+        let next = header.next_command as usize;
+        if next == 0 {
+            messages.push(Smb2Message {
+                header,
+                message: rest,
+            });
+            return Ok(messages);
+        }
+        // Chaque message chaine est aligne sur 8 octets.
+        if next < SMB2_HEADER_LEN || !next.is_multiple_of(8) || next >= rest.len() {
+            return Err(SmbError::InvalidNextCommand {
+                offset: header.next_command,
+                available: rest.len(),
+            });
+        }
+        let (message, tail) = rest.split_at(next);
+        messages.push(Smb2Message { header, message });
+        rest = tail;
+    }
+}
+
+/// SMB3 TRANSFORM_HEADER (MS-SMB2 §2.2.41): the message that follows is
+/// encrypted with the session key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Smb2TransformHeader<'a> {
+    pub signature: [u8; 16],
+    pub nonce: [u8; 16],
+    pub original_message_size: u32,
+    /// `Flags` (3.1.1) or `EncryptionAlgorithm` (3.0): 1 means encrypted.
+    pub flags: u16,
+    pub session_id: u64,
+    pub encrypted: &'a [u8],
+}
+
+impl<'a> Smb2TransformHeader<'a> {
+    fn parse(message: &'a [u8]) -> Result<Self, SmbError> {
+        if message.len() < SMB2_TRANSFORM_HEADER_LEN {
+            return Err(SmbError::InvalidLength {
+                expected: SMB2_TRANSFORM_HEADER_LEN,
+                actual: message.len(),
+            });
+        }
+        let mut signature = [0u8; 16];
+        signature.copy_from_slice(&message[4..20]);
+        let mut nonce = [0u8; 16];
+        nonce.copy_from_slice(&message[20..36]);
+        let mut session_id = [0u8; 8];
+        session_id.copy_from_slice(&message[44..52]);
+        Ok(Smb2TransformHeader {
+            signature,
+            nonce,
+            original_message_size: u32::from_le_bytes([
+                message[36],
+                message[37],
+                message[38],
+                message[39],
+            ]),
+            flags: u16::from_le_bytes([message[42], message[43]]),
+            session_id: u64::from_le_bytes(session_id),
+            encrypted: &message[SMB2_TRANSFORM_HEADER_LEN..],
+        })
+    }
+}
+
+/// SMB 3.1.1 COMPRESSION_TRANSFORM_HEADER (MS-SMB2 §2.2.42).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Smb2CompressionHeader<'a> {
+    pub original_size: u32,
+    /// 1 LZNT1, 2 LZ77, 3 LZ77+Huffman, 4 Pattern_V1, 5 LZ4.
+    pub algorithm: u16,
+    /// 1 when the payload is chained.
+    pub flags: u16,
+    /// Uncompressed prefix length (unchained) or first payload length
+    /// (chained).
+    pub offset: u32,
+    pub data: &'a [u8],
+}
+
+impl<'a> Smb2CompressionHeader<'a> {
+    fn parse(message: &'a [u8]) -> Result<Self, SmbError> {
+        if message.len() < SMB2_COMPRESSION_HEADER_LEN {
+            return Err(SmbError::InvalidLength {
+                expected: SMB2_COMPRESSION_HEADER_LEN,
+                actual: message.len(),
+            });
+        }
+        Ok(Smb2CompressionHeader {
+            original_size: u32::from_le_bytes([message[4], message[5], message[6], message[7]]),
+            algorithm: u16::from_le_bytes([message[8], message[9]]),
+            flags: u16::from_le_bytes([message[10], message[11]]),
+            offset: u32::from_le_bytes([message[12], message[13], message[14], message[15]]),
+            data: &message[SMB2_COMPRESSION_HEADER_LEN..],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Negotiate Protocol Request SMB1 de la trame 39979
+    /// (pcaps_exemple/The-Ultimate-PCAP.pcapng, segment TCP 49958 -> 445).
+    const NBSS_SMB1_NEGOTIATE_HEX: &str = concat!(
+        "00000045ff534d4272000000001853c8000000000000000000000000fffffffe",
+        "00000000002200024e54204c4d20302e31320002534d4220322e303032000253",
+        "4d4220322e3f3f3f00"
+    );
+
+    fn payload(hex_fixture: &str) -> Vec<u8> {
+        hex::decode(hex_fixture).expect("invalid test hex fixture")
+    }
+
+    /// En-tete SMB2 synthetique : commande, drapeaux, NextCommand.
+    fn smb2_header(command: u16, flags: u32, next_command: u32) -> Vec<u8> {
+        let mut header = vec![0u8; SMB2_HEADER_LEN];
+        header[..4].copy_from_slice(&SMB2_PROTOCOL_ID);
+        header[4] = 64;
+        header[12..14].copy_from_slice(&command.to_le_bytes());
+        header[16..20].copy_from_slice(&flags.to_le_bytes());
+        header[20..24].copy_from_slice(&next_command.to_le_bytes());
+        header[32..40].copy_from_slice(&0x1122_3344_5566_7788_u64.to_le_bytes());
+        header
+    }
+
+    #[test]
+    fn smb1_negotiate_lists_its_dialects() {
+        let bytes = payload(NBSS_SMB1_NEGOTIATE_HEX);
+        let packet = SmbPacket::from_nbss(&bytes).expect("NEGOTIATE SMB1 valide");
+        assert!(packet.is_smb1());
+        let SmbPacket::Smb1(message) = packet else {
+            unreachable!();
+        };
+        assert_eq!(message.command_name(), "NEGOTIATE");
+        assert!(!message.is_response());
+        assert_eq!(message.process_id, 0xfeff);
+        assert_eq!(
+            message.negotiate_dialects(),
+            Some(vec!["NT LM 0.12", "SMB 2.002", "SMB 2.???"])
+        );
+        assert!(message.offers_smb2());
+    }
+
+    #[test]
+    fn compound_messages_follow_next_command() {
+        // ECHO (corps de 4 octets) chaine a 72 octets, puis un second ECHO.
+        let mut bytes = smb2_header(0x000D, 0, 72);
+        bytes.extend_from_slice(&[4, 0, 0, 0, 0, 0, 0, 0]);
+        bytes.extend_from_slice(&smb2_header(0x000D, SMB2_FLAGS_ASYNC_COMMAND, 0));
+        bytes.extend_from_slice(&[4, 0, 0, 0]);
+
+        let packet = SmbPacket::try_from(bytes.as_slice()).expect("compose valide");
+        let messages = packet.smb2_messages();
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0].message.len(), 72);
+        assert_eq!(messages[0].header.command_name(), "ECHO");
+        assert_eq!(messages[0].header.tree_id, Some(0x1122_3344));
+        assert_eq!(messages[1].header.async_id, Some(0x1122_3344_5566_7788));
+        assert_eq!(messages[1].header.tree_id, None);
+
+        // NextCommand non aligne sur 8 octets.
+        bytes[20] = 70;
+        assert_eq!(
+            SmbPacket::try_from(bytes.as_slice()),
+            Err(SmbError::InvalidNextCommand {
+                offset: 70,
+                available: 140
+            })
+        );
+    }
+
+    #[test]
+    fn transform_headers_and_unknown_protocols() {
+        let mut encrypted = vec![0u8; SMB2_TRANSFORM_HEADER_LEN + 3];
+        encrypted[..4].copy_from_slice(&SMB2_TRANSFORM_PROTOCOL_ID);
+        encrypted[36] = 0x80;
+        encrypted[42] = 1;
+        encrypted[44] = 0x25;
+        let SmbPacket::Encrypted(header) =
+            SmbPacket::try_from(encrypted.as_slice()).expect("TRANSFORM_HEADER valide")
+        else {
+            panic!("attendu un message chiffre");
+        };
+        assert_eq!(header.original_message_size, 0x80);
+        assert_eq!((header.flags, header.session_id), (1, 0x25));
+        assert_eq!(header.encrypted.len(), 3);
+
+        assert_eq!(
+            SmbPacket::try_from(&b"\xfeSMB"[..]),
+            Err(SmbError::InvalidLength {
+                expected: 64,
+                actual: 4
+            })
+        );
+        assert_eq!(
+            SmbPacket::try_from(&b"\x00SMB"[..]),
+            Err(SmbError::UnknownProtocolId(0x00534d42))
+        );
+        // Un keep-alive NBSS ne porte pas de SMB.
+        assert_eq!(
+            SmbPacket::from_nbss(&[0x85, 0x00, 0x00, 0x00]),
+            Err(SmbError::NotSessionMessage(0x85))
+        );
+    }
+}
@@ -0,0 +1,384 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Security blobs of SMB NEGOTIATE and SESSION_SETUP messages.
+//!
+//! The blob is a GSS-API token, in practice SPNEGO (RFC 4178) wrapping an
+//! NTLMSSP or Kerberos token. Only the envelope is read: the offered and
+//! selected mechanisms, the negotiation state and the inner token, enough
+//! to tell which authentication a session uses and to spot NTLM fallbacks.
+//!
+//! ```mermaid
+//! flowchart LR
+//!     A["0x60 GSS token"] -->|SPNEGO OID| B["0xA0 NegTokenInit"]
+//!     B --> C["mechTypes / mechToken"]
+//!     D["0xA1 NegTokenResp"] --> E["negState / supportedMech / responseToken"]
+//!     C --> F{"token"}
+//!     E --> F
+//!     F -->|NTLMSSP\0| G["NTLMSSP"]
+//!     F -->|krb5 OID, AP-REQ/AP-REP| H["Kerberos"]
+//! ```
+
+use crate::{
+    checks::application::{
+        ber::extract_ber_element,
+        kerberos::{
+            KRB_AP_REP, KRB_AP_REQ, KRB_ERROR, OID_KERBEROS_V5, OID_KERBEROS_V5_USER_TO_USER,
+            OID_MS_KERBEROS_V5,
+        },
+        ntlmssp::{NTLMSSP_SIGNATURE, ntlmssp_message_type_name},
+        smb::{
+            DER_ENUMERATED_TAG, DER_OCTET_STRING_TAG, DER_OID_TAG, DER_SEQUENCE_TAG,
+            GSS_INITIAL_CONTEXT_TOKEN_TAG, NEGOEX_SIGNATURE, OID_NEGOEX, OID_NTLMSSP, OID_SPNEGO,
+            SPNEGO_NEG_TOKEN_INIT_TAG, SPNEGO_NEG_TOKEN_RESP_TAG, extract_der_element,
+        },
+    },
+    errors::application::{kerberos::KerberosError, ntlmssp::NtlmsspError, smb::SmbError},
+    parse::application::protocols::{kerberos::KerberosMessage, ntlmssp::NtlmsspMessage},
+};
+
+/// Authentication mechanism named by an OID or recognised from a token.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SmbAuthMechanism {
+    Ntlmssp,
+    Kerberos,
+    Negoex,
+    Other,
+}
+
+impl SmbAuthMechanism {
+    fn from_oid(oid: &[u8]) -> Self {
+        match oid {
+            OID_NTLMSSP => SmbAuthMechanism::Ntlmssp,
+            OID_KERBEROS_V5 | OID_MS_KERBEROS_V5 | OID_KERBEROS_V5_USER_TO_USER => {
+                SmbAuthMechanism::Kerberos
+            }
+            OID_NEGOEX => SmbAuthMechanism::Negoex,
+            _ => SmbAuthMechanism::Other,
+        }
+    }
+
+    /// Mechanism of a raw token, recognised by its signature or tag.
+    fn from_token(token: &[u8]) -> Self {
+        if token.starts_with(&NTLMSSP_SIGNATURE) {
+            return SmbAuthMechanism::Ntlmssp;
+        }
+        if token.starts_with(&NEGOEX_SIGNATURE) {
+            return SmbAuthMechanism::Negoex;
+        }
+        // AP-REQ, AP-REP ou KRB-ERROR hors enveloppe GSS-API.
+        if extract_ber_element(token, "Kerberos token").is_ok_and(|(element, _)| {
+            element.constructed
+                && [KRB_AP_REQ, KRB_AP_REP, KRB_ERROR]
+                    .iter()
+                    .any(|number| element.is_application(*number))
+        }) {
+            return SmbAuthMechanism::Kerberos;
+        }
+        match token.first() {
+            // Jeton GSS : le mecanisme est l'OID en tete (RFC 2743 §3.1).
+            Some(&GSS_INITIAL_CONTEXT_TOKEN_TAG) => match gss_oid(token) {
+                Some((oid, _)) => SmbAuthMechanism::from_oid(oid),
+                None => SmbAuthMechanism::Other,
+            },
+            _ => SmbAuthMechanism::Other,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            SmbAuthMechanism::Ntlmssp => "NTLMSSP",
+            SmbAuthMechanism::Kerberos => "Kerberos",
+            SmbAuthMechanism::Negoex => "NEGOEX",
+            SmbAuthMechanism::Other => "Other",
+        }
+    }
+}
+
+/// Envelope of an SMB security blob.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SmbSecurityBlob<'a> {
+    /// Token wrapped in SPNEGO rather than sent raw.
+    pub spnego: bool,
+    /// mechTypes of a NegTokenInit, in order of preference.
+    pub offered_mechanisms: Vec<SmbAuthMechanism>,
+    /// negState of a NegTokenResp (0 accept-completed to 3 request-mic).
+    pub negotiation_state: Option<u8>,
+    /// supportedMech of a NegTokenResp.
+    pub supported_mechanism: Option<SmbAuthMechanism>,
+    /// mechToken or responseToken, or the raw token itself.
+    pub token: Option<&'a [u8]>,
+    pub mech_list_mic: Option<&'a [u8]>,
+}
+
+impl<'a> SmbSecurityBlob<'a> {
+    pub fn parse(blob: &'a [u8]) -> Result<Self, SmbError> {
+        match blob.first() {
+            None => Err(SmbError::InvalidSecurityBlob {
+                field: "empty blob",
+            }),
+            Some(&GSS_INITIAL_CONTEXT_TOKEN_TAG) => {
+                let (oid, negotiation) = gss_oid(blob).ok_or(SmbError::InvalidSecurityBlob {
+                    field: "GSS-API token",
+                })?;
+                if oid == OID_SPNEGO {
+                    Self::negotiation_token(negotiation)
+                } else {
+                    Ok(Self::raw(blob))
+                }
+            }
+            Some(&(SPNEGO_NEG_TOKEN_INIT_TAG | SPNEGO_NEG_TOKEN_RESP_TAG)) => {
+                Self::negotiation_token(blob)
+            }
+            Some(_) if SmbAuthMechanism::from_token(blob) != SmbAuthMechanism::Other => {
+                Ok(Self::raw(blob))
+            }
+            Some(_) => Err(SmbError::InvalidSecurityBlob {
+                field: "unknown token",
+            }),
+        }
+    }
+
+    fn raw(token: &'a [u8]) -> Self {
+        SmbSecurityBlob {
+            token: Some(token),
+            ..Self::default()
+        }
+    }
+
+    /// NegotiationToken SPNEGO : NegTokenInit `[0]` ou NegTokenResp `[1]`,
+    /// chacun une SEQUENCE de champs etiquetes par contexte.
+    fn negotiation_token(data: &'a [u8]) -> Result<Self, SmbError> {
+        let (choice, value, _) = extract_der_element(data, "NegotiationToken")?;
+        let (tag, mut fields, _) = extract_der_element(value, "NegotiationToken")?;
+        if tag != DER_SEQUENCE_TAG {
+            return Err(SmbError::InvalidSecurityBlob {
+                field: "NegotiationToken",
+            });
+        }
+        let mut blob = SmbSecurityBlob {
+            spnego: true,
+            ..Self::default()
+        };
+        while !fields.is_empty() {
+            let (field, value, rest) = extract_der_element(fields, "NegotiationToken field")?;
+            fields = rest;
+            match (choice, field) {
+                (SPNEGO_NEG_TOKEN_INIT_TAG, 0xA0) => {
+                    blob.offered_mechanisms = mech_types(value)?;
+                }
+                (SPNEGO_NEG_TOKEN_INIT_TAG, 0xA2) | (SPNEGO_NEG_TOKEN_RESP_TAG, 0xA2) => {
+                    blob.token = Some(octet_string(value, "mechToken")?);
+                }
+                (SPNEGO_NEG_TOKEN_RESP_TAG, 0xA0) => {
+                    let (tag, state, _) = extract_der_element(value, "negState")?;
+                    let [state] = state else {
+                        return Err(SmbError::InvalidSecurityBlob { field: "negState" });
+                    };
+                    if tag != DER_ENUMERATED_TAG {
+                        return Err(SmbError::InvalidSecurityBlob { field: "negState" });
+                    }
+                    blob.negotiation_state = Some(*state);
+                }
+                (SPNEGO_NEG_TOKEN_RESP_TAG, 0xA1) => {
+                    let (tag, oid, _) = extract_der_element(value, "supportedMech")?;
+                    if tag != DER_OID_TAG {
+                        return Err(SmbError::InvalidSecurityBlob {
+                            field: "supportedMech",
+                        });
+                    }
+                    blob.supported_mechanism = Some(SmbAuthMechanism::from_oid(oid));
+                }
+                (SPNEGO_NEG_TOKEN_RESP_TAG, 0xA3) => {
+                    blob.mech_list_mic = Some(octet_string(value, "mechListMIC")?);
+                }
+                // reqFlags, negHints (Windows) et mechListMIC d'un NegTokenInit.
+                _ => {}
+            }
+        }
+        Ok(blob)
+    }
+
+    /// Mechanism actually used: that of the token, else the one the
+    /// server selected.
+    pub fn mechanism(&self) -> Option<SmbAuthMechanism> {
+        self.token
+            .map(SmbAuthMechanism::from_token)
+            .filter(|mechanism| *mechanism != SmbAuthMechanism::Other)
+            .or(self.supported_mechanism)
+    }
+
+    /// MessageType of an NTLMSSP token (1 NEGOTIATE, 2 CHALLENGE,
+    /// 3 AUTHENTICATE).
+    pub fn ntlmssp_message_type(&self) -> Option<u32> {
+        let token = self.token?.strip_prefix(&NTLMSSP_SIGNATURE)?;
+        let (message_type, _) = token.split_first_chunk::<4>()?;
+        Some(u32::from_le_bytes(*message_type))
+    }
+
+    pub fn ntlmssp_message_type_name(&self) -> Option<&'static str> {
+        self.ntlmssp_message_type().map(ntlmssp_message_type_name)
+    }
+
+    /// Decoded NTLMSSP token, with the domain, user and workstation of an
+    /// AUTHENTICATE.
+    pub fn ntlmssp(&self) -> Option<Result<NtlmsspMessage<'a>, NtlmsspError>> {
+        self.token
+            .filter(|token| token.starts_with(&NTLMSSP_SIGNATURE))
+            .map(NtlmsspMessage::try_from)
+    }
+
+    /// Decoded Kerberos token, GSS-API wrapped or raw.
+    pub fn kerberos(&self) -> Option<Result<KerberosMessage<'a>, KerberosError>> {
+        let token = self.token?;
+        if SmbAuthMechanism::from_token(token) != SmbAuthMechanism::Kerberos {
+            return None;
+        }
+        Some(match token.first() {
+            Some(&GSS_INITIAL_CONTEXT_TOKEN_TAG) => KerberosMessage::from_gss_token(token),
+            _ => KerberosMessage::try_from(token),
+        })
+    }
+
+    pub fn negotiation_state_name(&self) -> Option<&'static str> {
+        self.negotiation_state.map(|state| match state {
+            0 => "accept-completed",
+            1 => "accept-incomplete",
+            2 => "reject",
+            3 => "request-mic",
+            _ => "Unknown",
+        })
+    }
+}
+
+/// OID de tete d'un jeton GSS [APPLICATION 0] et octets qui le suivent.
+fn gss_oid(token: &[u8]) -> Option<(&[u8], &[u8])> {
+    let (_, value, _) = extract_der_element(token, "GSS-API token").ok()?;
+    let (tag, oid, rest) = extract_der_element(value, "GSS-API token").ok()?;
+    (tag == DER_OID_TAG).then_some((oid, rest))
+}
+
+fn mech_types(value: &[u8]) -> Result<Vec<SmbAuthMechanism>, SmbError> {
+    let (tag, mut oids, _) = extract_der_element(value, "mechTypes")?;
+    if tag != DER_SEQUENCE_TAG {
+        return Err(SmbError::InvalidSecurityBlob { field: "mechTypes" });
+    }
+    let mut mechanisms = Vec::new();
+    while !oids.is_empty() {
+        let (tag, oid, rest) = extract_der_element(oids, "mechTypes")?;
+        if tag != DER_OID_TAG {
+            return Err(SmbError::InvalidSecurityBlob { field: "mechTypes" });
+        }
+        mechanisms.push(SmbAuthMechanism::from_oid(oid));
+        oids = rest;
+    }
+    Ok(mechanisms)
+}
+
+fn octet_string<'a>(value: &'a [u8], field: &'static str) -> Result<&'a [u8], SmbError> {
+    let (tag, bytes, _) = extract_der_element(value, field)?;
+    if tag != DER_OCTET_STRING_TAG {
+        return Err(SmbError::InvalidSecurityBlob { field });
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Element DER en forme courte.
+    fn der(tag: u8, value: &[u8]) -> Vec<u8> {
+        let mut element = vec![tag, value.len() as u8];
+        element.extend_from_slice(value);
+        element
+    }
+
+    fn oid(oid: &[u8]) -> Vec<u8> {
+        der(DER_OID_TAG, oid)
+    }
+
+    /// NegTokenInit SPNEGO enveloppe dans un jeton GSS.
+    fn neg_token_init(mechanisms: &[&[u8]], token: &[u8]) -> Vec<u8> {
+        let oids: Vec<u8> = mechanisms.iter().flat_map(|mech| oid(mech)).collect();
+        let mut fields = der(0xA0, &der(DER_SEQUENCE_TAG, &oids));
+        fields.extend(der(0xA2, &der(DER_OCTET_STRING_TAG, token)));
+        let mut gss = oid(OID_SPNEGO);
+        gss.extend(der(0xA0, &der(DER_SEQUENCE_TAG, &fields)));
+        der(GSS_INITIAL_CONTEXT_TOKEN_TAG, &gss)
+    }
+
+    #[test]
+    fn captured_neg_token_resp() {
+        // Blob de la SESSION_SETUP Response finale de la trame 40012 :
+        // accept-completed et mechListMIC NTLM, sans jeton.
+        let blob = hex::decode("a11b3019a0030a0100a312041001000000ca1d85ba1649942500000000")
+            .expect("invalid test hex fixture");
+        let parsed = SmbSecurityBlob::parse(&blob).expect("NegTokenResp valide");
+        assert!(parsed.spnego);
+        assert_eq!(parsed.negotiation_state_name(), Some("accept-completed"));
+        assert_eq!(parsed.token, None);
+        assert_eq!(parsed.mech_list_mic.map(<[u8]>::len), Some(16));
+        assert_eq!(parsed.mechanism(), None);
+    }
+
+    #[test]
+    fn ntlmssp_and_kerberos_tokens() {
+        let mut ntlm = NTLMSSP_SIGNATURE.to_vec();
+        ntlm.extend_from_slice(&[1, 0, 0, 0, 0x97, 0x82, 0x08, 0xE2]);
+        let blob = neg_token_init(&[OID_MS_KERBEROS_V5, OID_NTLMSSP], &ntlm);
+        let parsed = SmbSecurityBlob::parse(&blob).expect("NegTokenInit valide");
+        assert_eq!(
+            parsed.offered_mechanisms,
+            [SmbAuthMechanism::Kerberos, SmbAuthMechanism::Ntlmssp]
+        );
+        assert_eq!(parsed.mechanism(), Some(SmbAuthMechanism::Ntlmssp));
+        assert_eq!(parsed.ntlmssp_message_type_name(), Some("NEGOTIATE"));
+        assert!(matches!(
+            parsed.ntlmssp(),
+            Some(Ok(NtlmsspMessage::Negotiate(_)))
+        ));
+        assert_eq!(parsed.kerberos(), None);
+
+        // Jeton Kerberos : OID krb5, TOK_ID 01 00 puis AP-REQ.
+        let mut krb = oid(OID_KERBEROS_V5);
+        krb.extend_from_slice(&[0x01, 0x00]);
+        krb.extend(der(0x6E, &[0x30, 0x00]));
+        let krb = der(GSS_INITIAL_CONTEXT_TOKEN_TAG, &krb);
+        let blob = neg_token_init(&[OID_KERBEROS_V5], &krb);
+        let parsed = SmbSecurityBlob::parse(&blob).expect("NegTokenInit valide");
+        assert_eq!(parsed.mechanism(), Some(SmbAuthMechanism::Kerberos));
+        assert_eq!(parsed.ntlmssp_message_type(), None);
+
+        // NTLMSSP brut, sans SPNEGO.
+        let parsed = SmbSecurityBlob::parse(&ntlm).expect("NTLMSSP brut");
+        assert!(!parsed.spnego);
+        assert_eq!(parsed.mechanism(), Some(SmbAuthMechanism::Ntlmssp));
+    }
+
+    #[test]
+    fn malformed_blobs() {
+        assert_eq!(
+            SmbSecurityBlob::parse(&[]),
+            Err(SmbError::InvalidSecurityBlob {
+                field: "empty blob"
+            })
+        );
+        assert_eq!(
+            SmbSecurityBlob::parse(b"\x42junk"),
+            Err(SmbError::InvalidSecurityBlob {
+                field: "unknown token"
+            })
+        );
+        // Longueur annoncee au-dela du blob.
+        assert_eq!(
+            SmbSecurityBlob::parse(&[0xA1, 0x10, 0x30, 0x00]),
+            Err(SmbError::InvalidSecurityBlob {
+                field: "NegotiationToken"
+            })
+        );
+    }
+}
@@ -18,6 +18,10 @@ use crate::{
     errors::application::snmp::SnmpError,
 };
 
+pub mod oid;
+pub mod trap;
+pub mod usm;
+
 /// SNMP Message
 ///
 /// ```mermaid
@@ -0,0 +1,238 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! SNMP object identifiers and their names.
+//!
+//! [`SnmpOid`] wraps the BER encoding found in variable bindings and traps.
+//! [`SnmpOidRegistry`] names identifiers by longest prefix, so that
+//! `1.3.6.1.2.1.1.5.0` reads `sysName.0`: it ships with MIB-2, IF-MIB,
+//! HOST-RESOURCES-MIB and SNMPv2 notification names and loads simple
+//! name files for enterprise MIBs.
+
+use std::{collections::HashMap, fmt};
+
+use crate::{
+    checks::application::snmp::{
+        SNMP_BUILTIN_OID_NAMES, extract_object_identifier, parse_dotted_oid,
+    },
+    errors::application::snmp::SnmpOidError,
+    parse::application::protocols::snmp::SnmpVarBind,
+};
+
+/// Object identifier, kept in its BER encoding. `Display` renders the
+/// dotted form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnmpOid<'a>(pub &'a [u8]);
+
+impl SnmpOid<'_> {
+    /// Arcs of the identifier, `None` when the encoding is malformed.
+    pub fn arcs(&self) -> Option<Vec<u64>> {
+        extract_object_identifier(self.0, "oid").ok()
+    }
+
+    /// Whether the identifier equals `arcs` or lies below it.
+    pub fn starts_with(&self, arcs: &[u64]) -> bool {
+        self.arcs().is_some_and(|own| own.starts_with(arcs))
+    }
+}
+
+impl fmt::Display for SnmpOid<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(arcs) = self.arcs() else {
+            return write!(f, "invalid OID {}", hex::encode(self.0));
+        };
+        write!(f, "{}", dotted(&arcs))
+    }
+}
+
+impl<'a> SnmpVarBind<'a> {
+    /// Bound variable as an [`SnmpOid`].
+    pub fn object_identifier(&self) -> SnmpOid<'a> {
+        SnmpOid(self.oid)
+    }
+}
+
+/// Dotted notation of a list of arcs.
+pub fn dotted(arcs: &[u64]) -> String {
+    arcs.iter()
+        .map(u64::to_string)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Names of object identifiers, resolved by longest prefix.
+///
+/// [`builtin`](Self::builtin) starts from the names of
+/// [`SNMP_BUILTIN_OID_NAMES`]; [`load`](Self::load) adds the lines of a
+/// name file, one `<dotted OID> <name>` pair per line: `=` also separates,
+/// `#` starts a comment, the OID may come second and either may be quoted,
+/// so the output of `snmptranslate -Tz` loads as is.
+///
+/// # Example
+/// ```
+/// use packet_parser::parse::application::protocols::snmp::oid::SnmpOidRegistry;
+///
+/// let mut registry = SnmpOidRegistry::builtin();
+/// registry.load("# Siemens\n1.3.6.1.4.1.4329 siemens\n").unwrap();
+///
+/// assert_eq!(registry.format(&[1, 3, 6, 1, 2, 1, 1, 5, 0]), "sysName.0");
+/// assert_eq!(registry.format(&[1, 3, 6, 1, 4, 1, 4329, 6]), "siemens.6");
+/// assert_eq!(registry.format(&[1, 2, 3]), "1.2.3");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SnmpOidRegistry {
+    names: HashMap<Vec<u64>, String>,
+}
+
+impl SnmpOidRegistry {
+    /// Empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry holding the built-in names.
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        for (oid, name) in SNMP_BUILTIN_OID_NAMES {
+            if let Some(arcs) = parse_dotted_oid(oid) {
+                registry.insert(arcs, *name);
+            }
+        }
+        registry
+    }
+
+    /// Names `arcs`, replacing any previous name.
+    pub fn insert(&mut self, arcs: Vec<u64>, name: impl Into<String>) {
+        self.names.insert(arcs, name.into());
+    }
+
+    /// Adds the names of a name file and returns how many were read. A
+    /// malformed line stops the load with its 1-based number; the names
+    /// read before it are kept.
+    pub fn load(&mut self, text: &str) -> Result<usize, SnmpOidError> {
+        let mut loaded = 0;
+        for (index, line) in text.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let invalid = SnmpOidError::InvalidOidFileLine { line: index + 1 };
+            let mut fields = line
+                .split(|c: char| c.is_whitespace() || c == '=')
+                .map(|field| field.trim_matches('"'))
+                .filter(|field| !field.is_empty());
+            let (Some(first), Some(second), None) = (fields.next(), fields.next(), fields.next())
+            else {
+                return Err(invalid);
+            };
+            // L'OID peut preceder ou suivre le nom.
+            let (arcs, name) = match (parse_dotted_oid(first), parse_dotted_oid(second)) {
+                (Some(arcs), None) => (arcs, second),
+                (None, Some(arcs)) => (arcs, first),
+                _ => return Err(invalid),
+            };
+            self.insert(arcs, name);
+            loaded += 1;
+        }
+        Ok(loaded)
+    }
+
+    /// Name registered for exactly `arcs`.
+    pub fn name(&self, arcs: &[u64]) -> Option<&str> {
+        self.names.get(arcs).map(String::as_str)
+    }
+
+    /// Longest named prefix of `arcs`, with the arcs that follow it (the
+    /// instance, for a table column or a scalar).
+    pub fn resolve<'r, 'o>(&'r self, arcs: &'o [u64]) -> Option<(&'r str, &'o [u64])> {
+        (1..=arcs.len())
+            .rev()
+            .find_map(|length| Some((self.name(&arcs[..length])?, &arcs[length..])))
+    }
+
+    /// `name.instance` when a prefix is named, dotted notation otherwise.
+    pub fn format(&self, arcs: &[u64]) -> String {
+        match self.resolve(arcs) {
+            Some((name, [])) => name.to_string(),
+            Some((name, instance)) => format!("{name}.{}", dotted(instance)),
+            None => dotted(arcs),
+        }
+    }
+
+    /// [`format`](Self::format) for a BER-encoded identifier.
+    pub fn format_oid(&self, oid: SnmpOid<'_>) -> String {
+        match oid.arcs() {
+            Some(arcs) => self.format(&arcs),
+            None => oid.to_string(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_names_resolve_by_longest_prefix() {
+        let registry = SnmpOidRegistry::builtin();
+        // ifDescr.3, hrSWRunName.1234, linkDown.
+        assert_eq!(
+            registry.format(&[1, 3, 6, 1, 2, 1, 2, 2, 1, 2, 3]),
+            "ifDescr.3"
+        );
+        assert_eq!(
+            registry.format(&[1, 3, 6, 1, 2, 1, 25, 4, 2, 1, 2, 1234]),
+            "hrSWRunName.1234"
+        );
+        assert_eq!(registry.format(&[1, 3, 6, 1, 6, 3, 1, 1, 5, 3]), "linkDown");
+        assert_eq!(
+            registry.format(&[1, 3, 6, 1, 4, 1, 9, 1, 1]),
+            "enterprises.9.1.1"
+        );
+        assert_eq!(registry.resolve(&[1, 3, 6]), None);
+    }
+
+    #[test]
+    fn load_accepts_both_orders_and_reports_bad_lines() {
+        let mut registry = SnmpOidRegistry::new();
+        let loaded = registry
+            .load("# commentaire\n\n.1.3.6.1.4.1.9 cisco\n\"ciscoProducts\"\t\"1.3.6.1.4.1.9.1\"\n")
+            .expect("fichier valide");
+        assert_eq!(loaded, 2);
+        assert_eq!(registry.name(&[1, 3, 6, 1, 4, 1, 9]), Some("cisco"));
+        assert_eq!(
+            registry.format(&[1, 3, 6, 1, 4, 1, 9, 1, 7]),
+            "ciscoProducts.7"
+        );
+
+        assert_eq!(
+            registry.load("1.3.6.1.4.1.311 microsoft\nmicrosoft\n"),
+            Err(SnmpOidError::InvalidOidFileLine { line: 2 })
+        );
+        // La ligne valide precedant l'erreur est conservee.
+        assert_eq!(registry.len(), 3);
+        assert_eq!(
+            registry.load("a b c"),
+            Err(SnmpOidError::InvalidOidFileLine { line: 1 })
+        );
+    }
+
+    #[test]
+    fn oid_display_and_prefix() {
+        let oid = SnmpOid(&[0x2B, 6, 1, 2, 1, 1, 5, 0]);
+        assert_eq!(oid.to_string(), "1.3.6.1.2.1.1.5.0");
+        assert!(oid.starts_with(&[1, 3, 6, 1, 2, 1, 1]));
+        assert_eq!(SnmpOid(&[0x2B, 0x86]).to_string(), "invalid OID 2b86");
+        assert_eq!(SnmpOidRegistry::builtin().format_oid(oid), "sysName.0");
+    }
+}
@@ -0,0 +1,239 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! One view over SNMPv1 traps and SNMPv2 notifications.
+//!
+//! An SNMPv1 Trap-PDU names its event through the enterprise, generic-trap
+//! and specific-trap fields; SNMPv2 Trap and Inform PDUs carry
+//! `snmpTrapOID.0` in their second variable binding. [`SnmpTrap`] reduces
+//! both to the notification OID, translating v1 traps as RFC 3584 §3.1
+//! does, so that a single [`SnmpOidRegistry`](super::oid::SnmpOidRegistry)
+//! names them.
+
+use crate::{
+    checks::application::snmp::{
+        SNMP_GENERIC_TRAP_ENTERPRISE_SPECIFIC, SNMP_SYS_UP_TIME_0, SNMP_TRAP_OID_0,
+        SNMP_TRAPS_PREFIX, snmp_generic_trap_name,
+    },
+    parse::application::protocols::snmp::{
+        SnmpPdu, SnmpPduPayload, SnmpPduType, SnmpValue, SnmpVarBind, oid::SnmpOid,
+    },
+};
+
+/// SNMPv1 trap identity (RFC 1157 §4.1.6).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnmpTrapV1Fields<'a> {
+    pub enterprise: SnmpOid<'a>,
+    pub agent_address: [u8; 4],
+    pub generic_trap: i64,
+    pub specific_trap: i64,
+}
+
+impl SnmpTrapV1Fields<'_> {
+    pub fn generic_trap_name(&self) -> &'static str {
+        snmp_generic_trap_name(self.generic_trap)
+    }
+}
+
+/// Notification carried by a Trap-PDU, SNMPv2-Trap-PDU or InformRequest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnmpTrap<'a> {
+    /// `snmpTrapOID.0`, or its RFC 3584 translation for a v1 trap.
+    pub trap_oid: Vec<u64>,
+    /// Agent uptime in hundredths of a second: `sysUpTime.0`, or the v1
+    /// time-stamp.
+    pub uptime: Option<u64>,
+    /// Fields proper to an SNMPv1 trap.
+    pub v1: Option<SnmpTrapV1Fields<'a>>,
+}
+
+impl<'a> SnmpPdu<'a> {
+    /// Variable bindings of the PDU, whatever its type.
+    pub fn variable_bindings(&self) -> &[SnmpVarBind<'a>] {
+        match &self.payload {
+            SnmpPduPayload::Standard {
+                variable_bindings, ..
+            }
+            | SnmpPduPayload::TrapV1 {
+                variable_bindings, ..
+            } => variable_bindings,
+        }
+    }
+
+    /// Notification carried by the PDU. `None` for other PDU types, for a
+    /// v1 enterprise OID that does not decode, and for a v2 notification
+    /// whose second binding is not `snmpTrapOID.0`.
+    pub fn trap(&self) -> Option<SnmpTrap<'a>> {
+        match (&self.payload, self.pdu_type) {
+            (
+                SnmpPduPayload::TrapV1 {
+                    enterprise,
+                    agent_address,
+                    generic_trap,
+                    specific_trap,
+                    timestamp,
+                    ..
+                },
+                _,
+            ) => {
+                let enterprise = SnmpOid(enterprise);
+                let trap_oid = if (0..SNMP_GENERIC_TRAP_ENTERPRISE_SPECIFIC).contains(generic_trap)
+                {
+                    let mut oid = SNMP_TRAPS_PREFIX.to_vec();
+                    oid.push(u64::try_from(*generic_trap + 1).ok()?);
+                    oid
+                } else {
+                    // enterprise.0.specific-trap
+                    let mut oid = enterprise.arcs()?;
+                    oid.push(0);
+                    oid.push(u64::try_from(*specific_trap).ok()?);
+                    oid
+                };
+                Some(SnmpTrap {
+                    trap_oid,
+                    uptime: Some(*timestamp),
+                    v1: Some(SnmpTrapV1Fields {
+                        enterprise,
+                        agent_address: *agent_address,
+                        generic_trap: *generic_trap,
+                        specific_trap: *specific_trap,
+                    }),
+                })
+            }
+            (
+                SnmpPduPayload::Standard {
+                    variable_bindings, ..
+                },
+                SnmpPduType::TrapV2 | SnmpPduType::InformRequest,
+            ) => {
+                let [uptime, trap_oid, ..] = variable_bindings.as_slice() else {
+                    return None;
+                };
+                let SnmpValue::ObjectIdentifier(value) = trap_oid.value else {
+                    return None;
+                };
+                if trap_oid.object_identifier().arcs()? != SNMP_TRAP_OID_0 {
+                    return None;
+                }
+                let uptime = match uptime.value {
+                    SnmpValue::TimeTicks(ticks)
+                        if uptime.object_identifier().arcs().as_deref()
+                            == Some(SNMP_SYS_UP_TIME_0) =>
+                    {
+                        Some(u64::from(ticks))
+                    }
+                    _ => None,
+                };
+                Some(SnmpTrap {
+                    trap_oid: SnmpOid(value).arcs()?,
+                    uptime,
+                    v1: None,
+                })
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        checks::application::snmp::{
+            ASN1_INTEGER_TAG, ASN1_OBJECT_IDENTIFIER_TAG, ASN1_OCTET_STRING_TAG, ASN1_SEQUENCE_TAG,
+            SNMP_IP_ADDRESS_TAG, SNMP_TIMETICKS_TAG,
+        },
+        parse::application::protocols::snmp::{SnmpMessage, SnmpPacket, oid::SnmpOidRegistry},
+    };
+
+    fn tlv(tag: u8, body: &[u8]) -> Vec<u8> {
+        let mut out = vec![tag, body.len() as u8];
+        out.extend_from_slice(body);
+        out
+    }
+
+    fn varbind(oid: &[u8], value_tlv: &[u8]) -> Vec<u8> {
+        let mut body = tlv(ASN1_OBJECT_IDENTIFIER_TAG, oid);
+        body.extend_from_slice(value_tlv);
+        tlv(ASN1_SEQUENCE_TAG, &body)
+    }
+
+    fn message(version: u8, pdu: &[u8]) -> Vec<u8> {
+        let mut message = tlv(ASN1_INTEGER_TAG, &[version]);
+        message.extend_from_slice(&tlv(ASN1_OCTET_STRING_TAG, b"public"));
+        message.extend_from_slice(pdu);
+        tlv(ASN1_SEQUENCE_TAG, &message)
+    }
+
+    fn v1_trap(generic: u8, specific: u8) -> Vec<u8> {
+        // enterprise 1.3.6.1.4.1.4329.6 (Siemens)
+        let mut body = tlv(
+            ASN1_OBJECT_IDENTIFIER_TAG,
+            &[0x2B, 6, 1, 4, 1, 0xA1, 0x69, 6],
+        );
+        body.extend_from_slice(&tlv(SNMP_IP_ADDRESS_TAG, &[10, 0, 0, 7]));
+        body.extend_from_slice(&tlv(ASN1_INTEGER_TAG, &[generic]));
+        body.extend_from_slice(&tlv(ASN1_INTEGER_TAG, &[specific]));
+        body.extend_from_slice(&tlv(SNMP_TIMETICKS_TAG, &[0x30, 0x39]));
+        body.extend_from_slice(&tlv(ASN1_SEQUENCE_TAG, &[]));
+        message(0, &tlv(0xA4, &body))
+    }
+
+    fn trap_of(bytes: &[u8]) -> Option<super::SnmpTrap<'_>> {
+        let packet = SnmpPacket::try_from(bytes).expect("SNMP valide");
+        let SnmpMessage::V1V2c(message) = packet.message else {
+            panic!("attendu v1/v2c");
+        };
+        message.pdu.trap()
+    }
+
+    #[test]
+    fn v1_generic_trap_translates_to_snmp_traps() {
+        let bytes = v1_trap(2, 0);
+        let trap = trap_of(&bytes).expect("trap v1");
+        assert_eq!(trap.trap_oid, [1, 3, 6, 1, 6, 3, 1, 1, 5, 3]);
+        assert_eq!(trap.uptime, Some(12345));
+        let v1 = trap.v1.expect("champs v1");
+        assert_eq!(v1.agent_address, [10, 0, 0, 7]);
+        assert_eq!(v1.generic_trap_name(), "linkDown");
+        assert_eq!(v1.enterprise.to_string(), "1.3.6.1.4.1.4329.6");
+        assert_eq!(
+            SnmpOidRegistry::builtin().format(&trap.trap_oid),
+            "linkDown"
+        );
+    }
+
+    #[test]
+    fn v1_enterprise_specific_trap_appends_zero_and_specific() {
+        let bytes = v1_trap(6, 17);
+        let trap = trap_of(&bytes).expect("trap v1");
+        assert_eq!(trap.trap_oid, [1, 3, 6, 1, 4, 1, 4329, 6, 0, 17]);
+    }
+
+    #[test]
+    fn v2_trap_reads_snmp_trap_oid() {
+        let mut varbinds = varbind(
+            &[0x2B, 6, 1, 2, 1, 1, 3, 0],
+            &tlv(SNMP_TIMETICKS_TAG, &[0x01, 0x00]),
+        );
+        varbinds.extend_from_slice(&varbind(
+            &[0x2B, 6, 1, 6, 3, 1, 1, 4, 1, 0],
+            &tlv(ASN1_OBJECT_IDENTIFIER_TAG, &[0x2B, 6, 1, 6, 3, 1, 1, 5, 1]),
+        ));
+        let mut body = tlv(ASN1_INTEGER_TAG, &[7]);
+        body.extend_from_slice(&tlv(ASN1_INTEGER_TAG, &[0]));
+        body.extend_from_slice(&tlv(ASN1_INTEGER_TAG, &[0]));
+        body.extend_from_slice(&tlv(ASN1_SEQUENCE_TAG, &varbinds));
+        let bytes = message(1, &tlv(0xA7, &body));
+
+        let trap = trap_of(&bytes).expect("trap v2");
+        assert_eq!(trap.trap_oid, [1, 3, 6, 1, 6, 3, 1, 1, 5, 1]);
+        assert_eq!(trap.uptime, Some(256));
+        assert_eq!(trap.v1, None);
+
+        // Un GetRequest ne porte pas de notification.
+        let bytes = message(1, &tlv(0xA0, &body));
+        assert_eq!(trap_of(&bytes), None);
+    }
+}
@@ -0,0 +1,231 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! SNMPv3 User-based Security Model parameters (RFC 3414 §2.4).
+//!
+//! `msgSecurityParameters` is an OCTET STRING wrapping a BER sequence; for
+//! the USM it names the authoritative engine and the user, which stay in
+//! clear even when the scoped PDU is encrypted.
+
+use crate::{
+    checks::application::snmp::{
+        ASN1_OCTET_STRING_TAG, ASN1_SEQUENCE_TAG, SNMP_ENGINE_ID_MIN_LEN, SNMP_USM_SECURITY_MODEL,
+        SNMP_V3_FLAG_AUTH, SNMP_V3_FLAG_PRIV, SNMP_V3_FLAG_REPORTABLE, read_tlv,
+        snmp_engine_id_format_name, validate_no_trailing, validate_tag,
+    },
+    errors::application::snmp::SnmpError,
+    parse::application::protocols::snmp::{SnmpV3Message, read_integer_field},
+};
+
+/// UsmSecurityParameters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnmpUsmSecurityParameters<'a> {
+    pub authoritative_engine_id: &'a [u8],
+    pub authoritative_engine_boots: i64,
+    pub authoritative_engine_time: i64,
+    pub user_name: &'a [u8],
+    /// HMAC truncated to 12 bytes (or more for SHA-2), empty without
+    /// authentication.
+    pub authentication_parameters: &'a [u8],
+    /// Salt of the privacy protocol, empty without encryption.
+    pub privacy_parameters: &'a [u8],
+}
+
+impl<'a> SnmpUsmSecurityParameters<'a> {
+    /// User name as text, when it is valid UTF-8.
+    pub fn user_name_text(&self) -> Option<&'a str> {
+        std::str::from_utf8(self.user_name).ok()
+    }
+
+    /// Engine discovery request (RFC 3414 §4): the manager does not know
+    /// the authoritative engine yet.
+    pub fn is_discovery(&self) -> bool {
+        self.authoritative_engine_id.is_empty()
+    }
+
+    /// Structure of the authoritative engine identifier.
+    pub fn engine_id(&self) -> Option<SnmpEngineId<'a>> {
+        SnmpEngineId::parse(self.authoritative_engine_id)
+    }
+}
+
+/// snmpEngineID (RFC 3411 §5): an IANA enterprise number, then either a
+/// format byte and its data, or 8 bytes chosen by the vendor for engines
+/// predating RFC 3411.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnmpEngineId<'a> {
+    pub enterprise: u32,
+    /// `None` for the pre-RFC 3411 layout.
+    pub format: Option<u8>,
+    pub data: &'a [u8],
+}
+
+impl<'a> SnmpEngineId<'a> {
+    /// `None` below the 5 bytes every engine identifier carries.
+    pub fn parse(engine_id: &'a [u8]) -> Option<Self> {
+        if engine_id.len() < SNMP_ENGINE_ID_MIN_LEN {
+            return None;
+        }
+        let prefix = u32::from_be_bytes([engine_id[0], engine_id[1], engine_id[2], engine_id[3]]);
+        // Bit de poids fort a 1 : format RFC 3411, sinon format SNMPv1/v2.
+        Some(if prefix & 0x8000_0000 != 0 {
+            SnmpEngineId {
+                enterprise: prefix & 0x7FFF_FFFF,
+                format: Some(engine_id[4]),
+                data: &engine_id[5..],
+            }
+        } else {
+            SnmpEngineId {
+                enterprise: prefix,
+                format: None,
+                data: &engine_id[4..],
+            }
+        })
+    }
+
+    pub fn format_name(&self) -> Option<&'static str> {
+        self.format.map(snmp_engine_id_format_name)
+    }
+
+    /// MAC address of an engine identifier in the MAC format (3).
+    pub fn mac_address(&self) -> Option<[u8; 6]> {
+        match self.format {
+            Some(3) => self.data.try_into().ok(),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> SnmpV3Message<'a> {
+    /// USM parameters, `None` under another security model.
+    pub fn usm(&self) -> Result<Option<SnmpUsmSecurityParameters<'a>>, SnmpError> {
+        if self.security_model != SNMP_USM_SECURITY_MODEL {
+            return Ok(None);
+        }
+        parse_usm_security_parameters(self.security_parameters).map(Some)
+    }
+
+    pub fn is_authenticated(&self) -> bool {
+        self.flag(SNMP_V3_FLAG_AUTH)
+    }
+
+    pub fn is_encrypted(&self) -> bool {
+        self.flag(SNMP_V3_FLAG_PRIV)
+    }
+
+    pub fn is_reportable(&self) -> bool {
+        self.flag(SNMP_V3_FLAG_REPORTABLE)
+    }
+
+    fn flag(&self, mask: u8) -> bool {
+        self.flags.first().is_some_and(|flags| flags & mask != 0)
+    }
+}
+
+/// Décode le contenu de msgSecurityParameters pour l'USM.
+pub fn parse_usm_security_parameters(
+    security_parameters: &[u8],
+) -> Result<SnmpUsmSecurityParameters<'_>, SnmpError> {
+    let mut offset = 0usize;
+    let sequence = read_tlv(security_parameters, &mut offset, "usm_parameters")?;
+    validate_tag("usm_parameters", sequence.tag, ASN1_SEQUENCE_TAG)?;
+    validate_no_trailing("usm_parameters", offset, security_parameters.len())?;
+
+    let body = sequence.value;
+    let mut offset = 0usize;
+    let authoritative_engine_id = read_octet_string(body, &mut offset, "usm_engine_id")?;
+    let authoritative_engine_boots = read_integer_field(body, &mut offset, "usm_engine_boots")?;
+    let authoritative_engine_time = read_integer_field(body, &mut offset, "usm_engine_time")?;
+    let user_name = read_octet_string(body, &mut offset, "usm_user_name")?;
+    let authentication_parameters =
+        read_octet_string(body, &mut offset, "usm_authentication_parameters")?;
+    let privacy_parameters = read_octet_string(body, &mut offset, "usm_privacy_parameters")?;
+    validate_no_trailing("usm_parameters", offset, body.len())?;
+
+    Ok(SnmpUsmSecurityParameters {
+        authoritative_engine_id,
+        authoritative_engine_boots,
+        authoritative_engine_time,
+        user_name,
+        authentication_parameters,
+        privacy_parameters,
+    })
+}
+
+fn read_octet_string<'a>(
+    data: &'a [u8],
+    offset: &mut usize,
+    field: &'static str,
+) -> Result<&'a [u8], SnmpError> {
+    let tlv = read_tlv(data, offset, field)?;
+    validate_tag(field, tlv.tag, ASN1_OCTET_STRING_TAG)?;
+    Ok(tlv.value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse::application::protocols::snmp::{SnmpMessage, SnmpPacket};
+
+    /// GetRequest SNMPv3 authPriv de l'utilisateur « ops », moteur
+    /// net-snmp (8072) au format MAC ; ScopedPDU chiffree.
+    const AUTH_PRIV_GET: &str = concat!(
+        "3065020103301102044d2e8a3d020300ffe304010702010304333031040b8000",
+        "1f880300163e1a2b3c02011202020e1004036f7073040cdeadbeefdeadbeefde",
+        "adbeef040800000001000000020418aabbccddeeff00112233445566778899aa",
+        "bbccddeeff0011"
+    );
+
+    #[test]
+    fn usm_parameters_of_an_auth_priv_message() {
+        let bytes = hex::decode(AUTH_PRIV_GET).expect("invalid hex fixture");
+        let packet = SnmpPacket::try_from(bytes.as_slice()).expect("SNMPv3 valide");
+        let SnmpMessage::V3(message) = &packet.message else {
+            panic!("attendu v3");
+        };
+        assert!(message.is_authenticated());
+        assert!(message.is_encrypted());
+        assert!(message.is_reportable());
+
+        let usm = message.usm().expect("USM valide").expect("modele USM");
+        assert_eq!(usm.user_name_text(), Some("ops"));
+        assert_eq!(usm.authoritative_engine_boots, 18);
+        assert_eq!(usm.authoritative_engine_time, 3600);
+        assert_eq!(usm.authentication_parameters.len(), 12);
+        assert_eq!(usm.privacy_parameters, &[0, 0, 0, 1, 0, 0, 0, 2]);
+        assert!(!usm.is_discovery());
+
+        let engine = usm.engine_id().expect("engine id");
+        assert_eq!(engine.enterprise, 8072);
+        assert_eq!(engine.format_name(), Some("MAC"));
+        assert_eq!(
+            engine.mac_address(),
+            Some([0x00, 0x16, 0x3e, 0x1a, 0x2b, 0x3c])
+        );
+    }
+
+    #[test]
+    fn discovery_and_malformed_parameters() {
+        // Decouverte : engine id et utilisateur vides.
+        let discovery =
+            hex::decode("300e0400020100020100040004000400").expect("invalid hex fixture");
+        let usm = parse_usm_security_parameters(&discovery).expect("USM valide");
+        assert!(usm.is_discovery());
+        assert_eq!(usm.engine_id(), None);
+
+        // Format anterieur a RFC 3411 : bit de poids fort a zero.
+        let legacy = SnmpEngineId::parse(&[0, 0, 0, 9, 1, 2, 3, 4, 5, 6, 7, 8]).expect("engine id");
+        assert_eq!((legacy.enterprise, legacy.format), (9, None));
+
+        assert_eq!(
+            parse_usm_security_parameters(&[0x30, 0x05, 0x04]),
+            Err(SnmpError::Truncated {
+                field: "usm_parameters",
+                needed: 7,
+                actual: 3
+            })
+        );
+    }
+}
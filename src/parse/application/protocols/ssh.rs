@@ -8,16 +8,22 @@
 //! Seule cette chaine est lisible sans etat : tout ce qui suit l'echange de
 //! versions est chiffre. Elle suffit pourtant a identifier le protocole et,
 //! en analyse securite, a exposer la version du serveur.
+//!
+//! Exception : le premier paquet binaire de chaque cote, `SSH_MSG_KEXINIT`
+//! (RFC 4253 §7.1), circule encore en clair. [`SshKexInit`] le decode et en
+//! tire les empreintes HASSH et HASSHServer (Salesforce).
 
 use std::convert::TryFrom;
 
 use crate::{
     checks::application::ssh::{
-        extract_ssh_identification_line, extract_ssh_protocol_version,
+        SSH_KEXINIT_COOKIE_LEN, SSH_MSG_KEXINIT, extract_ssh_binary_packet_payload,
+        extract_ssh_identification_line, extract_ssh_name_list, extract_ssh_protocol_version,
         extract_ssh_software_and_comments, validate_ssh_min_length, validate_ssh_prefix,
         validate_ssh_printable,
     },
     errors::application::ssh::SshError,
+    parse::application::protocols::fingerprint::md5_hex,
 };
 
 /// Chaine d'identification SSH (RFC 4253 §4.2).
@@ -68,6 +74,143 @@ impl<'a> TryFrom<&'a [u8]> for SshPacket<'a> {
     }
 }
 
+/// Message `SSH_MSG_KEXINIT` (RFC 4253 §7.1), lu depuis son paquet binaire.
+///
+/// Les name-lists restent empruntees telles quelles (noms separes par des
+/// virgules, ordre de preference de l'emetteur).
+///
+/// ```mermaid
+/// ---
+/// title: SshKexInit
+/// ---
+/// packet-beta
+/// 0-31: "packet_length"
+/// 32-39: "padding_length"
+/// 40-47: "SSH_MSG_KEXINIT (20)"
+/// 48-175: "cookie (16 octets)"
+/// 176-207: "10 name-lists (longueur u32 + noms)"
+/// 208-215: "first_kex_packet_follows"
+/// 216-247: "reserved (0)"
+/// 248-279: "padding (4..255 octets)"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SshKexInit<'a> {
+    /// Cookie aleatoire de 16 octets.
+    pub cookie: &'a [u8],
+    pub kex_algorithms: &'a str,
+    pub server_host_key_algorithms: &'a str,
+    pub encryption_algorithms_client_to_server: &'a str,
+    pub encryption_algorithms_server_to_client: &'a str,
+    pub mac_algorithms_client_to_server: &'a str,
+    pub mac_algorithms_server_to_client: &'a str,
+    pub compression_algorithms_client_to_server: &'a str,
+    pub compression_algorithms_server_to_client: &'a str,
+    pub languages_client_to_server: &'a str,
+    pub languages_server_to_client: &'a str,
+    /// Un paquet d'echange de cles devine suit immediatement.
+    pub first_kex_packet_follows: bool,
+}
+
+impl<'a> TryFrom<&'a [u8]> for SshKexInit<'a> {
+    type Error = SshError;
+
+    fn try_from(buf: &'a [u8]) -> Result<Self, SshError> {
+        let payload = extract_ssh_binary_packet_payload(buf)?;
+
+        let (&message_type, rest) =
+            payload
+                .split_first()
+                .ok_or(SshError::TruncatedBinaryPacket {
+                    expected: 1,
+                    actual: 0,
+                })?;
+        if message_type != SSH_MSG_KEXINIT {
+            return Err(SshError::UnexpectedMessageType {
+                expected: SSH_MSG_KEXINIT,
+                actual: message_type,
+            });
+        }
+        if rest.len() < SSH_KEXINIT_COOKIE_LEN {
+            return Err(SshError::TruncatedBinaryPacket {
+                expected: SSH_KEXINIT_COOKIE_LEN,
+                actual: rest.len(),
+            });
+        }
+        let (cookie, rest) = rest.split_at(SSH_KEXINIT_COOKIE_LEN);
+
+        let (kex_algorithms, rest) = extract_ssh_name_list(rest)?;
+        let (server_host_key_algorithms, rest) = extract_ssh_name_list(rest)?;
+        let (encryption_algorithms_client_to_server, rest) = extract_ssh_name_list(rest)?;
+        let (encryption_algorithms_server_to_client, rest) = extract_ssh_name_list(rest)?;
+        let (mac_algorithms_client_to_server, rest) = extract_ssh_name_list(rest)?;
+        let (mac_algorithms_server_to_client, rest) = extract_ssh_name_list(rest)?;
+        let (compression_algorithms_client_to_server, rest) = extract_ssh_name_list(rest)?;
+        let (compression_algorithms_server_to_client, rest) = extract_ssh_name_list(rest)?;
+        let (languages_client_to_server, rest) = extract_ssh_name_list(rest)?;
+        let (languages_server_to_client, rest) = extract_ssh_name_list(rest)?;
+
+        // first_kex_packet_follows (1) + reserved (4).
+        if rest.len() < 5 {
+            return Err(SshError::TruncatedBinaryPacket {
+                expected: 5,
+                actual: rest.len(),
+            });
+        }
+
+        Ok(SshKexInit {
+            cookie,
+            kex_algorithms,
+            server_host_key_algorithms,
+            encryption_algorithms_client_to_server,
+            encryption_algorithms_server_to_client,
+            mac_algorithms_client_to_server,
+            mac_algorithms_server_to_client,
+            compression_algorithms_client_to_server,
+            compression_algorithms_server_to_client,
+            languages_client_to_server,
+            languages_server_to_client,
+            first_kex_packet_follows: rest[0] != 0,
+        })
+    }
+}
+
+impl SshKexInit<'_> {
+    /// HASSH source string (KEXINIT client) :
+    /// `kex;enc_c2s;mac_c2s;comp_c2s`.
+    pub fn hassh_string(&self) -> String {
+        format!(
+            "{};{};{};{}",
+            self.kex_algorithms,
+            self.encryption_algorithms_client_to_server,
+            self.mac_algorithms_client_to_server,
+            self.compression_algorithms_client_to_server,
+        )
+    }
+
+    /// HASSH fingerprint: MD5 of [`hassh_string`](Self::hassh_string).
+    pub fn hassh(&self) -> String {
+        md5_hex(&self.hassh_string())
+    }
+
+    /// HASSHServer source string (KEXINIT serveur) :
+    /// `kex;enc_s2c;mac_s2c;comp_s2c`.
+    pub fn hassh_server_string(&self) -> String {
+        format!(
+            "{};{};{};{}",
+            self.kex_algorithms,
+            self.encryption_algorithms_server_to_client,
+            self.mac_algorithms_server_to_client,
+            self.compression_algorithms_server_to_client,
+        )
+    }
+
+    /// HASSHServer fingerprint: MD5 of
+    /// [`hassh_server_string`](Self::hassh_server_string).
+    pub fn hassh_server(&self) -> String {
+        md5_hex(&self.hassh_server_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -185,4 +328,81 @@ mod tests {
             SshError::NonPrintableByte { offset: 12 }
         );
     }
+
+    /// Synthetique : paquet KEXINIT non chiffre, name-lists a la OpenSSH.
+    fn kexinit_packet(message_type: u8) -> Vec<u8> {
+        let lists = [
+            "curve25519-sha256,ecdh-sha2-nistp256",
+            "ssh-ed25519",
+            "aes128-ctr,aes256-gcm@openssh.com",
+            "aes256-gcm@openssh.com",
+            "hmac-sha2-256",
+            "hmac-sha2-512",
+            "none,zlib@openssh.com",
+            "none",
+            "",
+            "",
+        ];
+        let mut payload = vec![message_type];
+        payload.extend_from_slice(&[0x5a; 16]);
+        for list in lists {
+            payload.extend_from_slice(&(list.len() as u32).to_be_bytes());
+            payload.extend_from_slice(list.as_bytes());
+        }
+        payload.extend_from_slice(&[0, 0, 0, 0, 0]);
+
+        let padding = 8 - (payload.len() + 5) % 8 + 8;
+        let mut packet = ((payload.len() + padding + 1) as u32)
+            .to_be_bytes()
+            .to_vec();
+        packet.push(padding as u8);
+        packet.extend_from_slice(&payload);
+        packet.extend(std::iter::repeat_n(0, padding));
+        packet
+    }
+
+    #[test]
+    fn decodes_kexinit_and_computes_hassh() {
+        let packet = kexinit_packet(SSH_MSG_KEXINIT);
+        let kexinit = SshKexInit::try_from(packet.as_slice()).expect("KEXINIT valide");
+
+        assert_eq!(kexinit.cookie, &[0x5a; 16]);
+        assert_eq!(kexinit.server_host_key_algorithms, "ssh-ed25519");
+        assert_eq!(kexinit.languages_server_to_client, "");
+        assert!(!kexinit.first_kex_packet_follows);
+
+        assert_eq!(
+            kexinit.hassh_string(),
+            "curve25519-sha256,ecdh-sha2-nistp256;aes128-ctr,aes256-gcm@openssh.com;\
+             hmac-sha2-256;none,zlib@openssh.com"
+        );
+        assert_eq!(kexinit.hassh(), "d2134446406c1efe3cd32a1f400e36b3");
+        assert_eq!(kexinit.hassh_server(), "64a601e739e298f5366260bf591623ad");
+    }
+
+    /// Synthetique : un autre message (ici NEWKEYS, 21) n'est pas un KEXINIT.
+    #[test]
+    fn rejects_binary_packet_of_another_message_type() {
+        let packet = kexinit_packet(21);
+
+        assert_eq!(
+            SshKexInit::try_from(packet.as_slice()).unwrap_err(),
+            SshError::UnexpectedMessageType {
+                expected: 20,
+                actual: 21
+            }
+        );
+    }
+
+    /// Synthetique : paquet coupe par la fin du segment TCP.
+    #[test]
+    fn rejects_truncated_kexinit_packet() {
+        let packet = kexinit_packet(SSH_MSG_KEXINIT);
+        let result = SshKexInit::try_from(&packet[..packet.len() - 1]);
+
+        assert!(matches!(
+            result,
+            Err(SshError::TruncatedBinaryPacket { .. })
+        ));
+    }
 }
@@ -0,0 +1,287 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! JA3/JA3S (Salesforce) and JA4/JA4S (FoxIO) fingerprints of the Hello
+//! messages.
+//!
+//! Both families drop the GREASE values of RFC 8701; JA3 keeps wire order,
+//! JA4 sorts cipher suites and extensions so that randomised orderings (as
+//! Chrome does since version 110) yield a stable fingerprint.
+
+use super::handshake::{TlsClientHello, TlsExtension, TlsServerHello};
+use crate::{
+    checks::application::tls::{TLS_EXT_ALPN, TLS_EXT_SERVER_NAME, is_grease_value},
+    parse::application::protocols::fingerprint::{Ja4Protocol, ja4_count, ja4_hash, md5_hex},
+};
+
+impl TlsClientHello<'_> {
+    /// JA3 source string:
+    /// `version,ciphers,extensions,groups,point_formats`, decimal values
+    /// joined by `-`.
+    pub fn ja3_string(&self) -> String {
+        format!(
+            "{},{},{},{},{}",
+            self.legacy_version,
+            join_decimal(self.cipher_suites.iter().copied()),
+            join_decimal(extension_types(&self.extensions)),
+            join_decimal(self.supported_groups().unwrap_or_default().into_iter()),
+            join_decimal(
+                self.ec_point_formats()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|&format| u16::from(format))
+            ),
+        )
+    }
+
+    /// JA3 fingerprint: MD5 of [`ja3_string`](Self::ja3_string).
+    pub fn ja3(&self) -> String {
+        md5_hex(&self.ja3_string())
+    }
+
+    /// JA4 fingerprint, e.g. `t13d1516h2_8daaf6152771_e5627efa2ab1`.
+    pub fn ja4(&self, protocol: Ja4Protocol) -> String {
+        let (ciphers, extensions) = self.ja4_lists();
+        format!(
+            "{}_{}_{}",
+            self.ja4_a(protocol),
+            ja4_hash(&ciphers),
+            ja4_hash(&extensions)
+        )
+    }
+
+    /// Raw JA4 (`ja4_r`): same sections, lists left unhashed.
+    pub fn ja4_r(&self, protocol: Ja4Protocol) -> String {
+        let (ciphers, extensions) = self.ja4_lists();
+        format!("{}_{}_{}", self.ja4_a(protocol), ciphers, extensions)
+    }
+
+    // Section a : protocole, version, SNI, compteurs, ALPN.
+    fn ja4_a(&self, protocol: Ja4Protocol) -> String {
+        // La plus haute version annoncee prime sur le champ legacy.
+        let version = self
+            .supported_versions()
+            .and_then(|versions| {
+                versions
+                    .into_iter()
+                    .filter(|&version| !is_grease_value(version))
+                    .max()
+            })
+            .unwrap_or(self.legacy_version);
+        let sni = if self.extension(TLS_EXT_SERVER_NAME).is_some() {
+            'd'
+        } else {
+            'i'
+        };
+        let alpn = self
+            .alpn_protocols()
+            .and_then(|protocols| protocols.first().copied());
+
+        format!(
+            "{}{}{}{}{}{}",
+            protocol.prefix(),
+            ja4_version(version),
+            sni,
+            ja4_count(
+                self.cipher_suites
+                    .iter()
+                    .filter(|&&suite| !is_grease_value(suite))
+                    .count()
+            ),
+            ja4_count(extension_types(&self.extensions).count()),
+            ja4_alpn(alpn),
+        )
+    }
+
+    // Sections b et c avant hachage. SNI et ALPN sortent de la liste des
+    // extensions (deja comptes dans a) ; les algorithmes de signature y sont
+    // accoles dans l'ordre du wire.
+    fn ja4_lists(&self) -> (String, String) {
+        let mut ciphers: Vec<u16> = self
+            .cipher_suites
+            .iter()
+            .copied()
+            .filter(|&suite| !is_grease_value(suite))
+            .collect();
+        ciphers.sort_unstable();
+
+        let mut extensions: Vec<u16> = extension_types(&self.extensions)
+            .filter(|&extension| extension != TLS_EXT_SERVER_NAME && extension != TLS_EXT_ALPN)
+            .collect();
+        extensions.sort_unstable();
+
+        let mut extensions = join_hex(extensions.into_iter());
+        let signatures = self.signature_algorithms().unwrap_or_default();
+        if !signatures.is_empty() {
+            extensions.push('_');
+            extensions.push_str(&join_hex(signatures.into_iter()));
+        }
+        (join_hex(ciphers.into_iter()), extensions)
+    }
+}
+
+impl TlsServerHello<'_> {
+    /// JA3S source string: `version,cipher,extensions`, decimal values.
+    pub fn ja3s_string(&self) -> String {
+        format!(
+            "{},{},{}",
+            self.legacy_version,
+            self.cipher_suite,
+            join_decimal(extension_types(&self.extensions)),
+        )
+    }
+
+    /// JA3S fingerprint: MD5 of [`ja3s_string`](Self::ja3s_string).
+    pub fn ja3s(&self) -> String {
+        md5_hex(&self.ja3s_string())
+    }
+
+    /// JA4S fingerprint, e.g. `t130200_1301_234ea6891581`.
+    pub fn ja4s(&self, protocol: Ja4Protocol) -> String {
+        format!(
+            "{}_{:04x}_{}",
+            self.ja4s_a(protocol),
+            self.cipher_suite,
+            ja4_hash(&self.ja4s_extensions())
+        )
+    }
+
+    /// Raw JA4S (`ja4s_r`): extensions listed instead of hashed.
+    pub fn ja4s_r(&self, protocol: Ja4Protocol) -> String {
+        format!(
+            "{}_{:04x}_{}",
+            self.ja4s_a(protocol),
+            self.cipher_suite,
+            self.ja4s_extensions()
+        )
+    }
+
+    fn ja4s_a(&self, protocol: Ja4Protocol) -> String {
+        format!(
+            "{}{}{}{}",
+            protocol.prefix(),
+            ja4_version(self.negotiated_version()),
+            ja4_count(extension_types(&self.extensions).count()),
+            ja4_alpn(self.selected_alpn()),
+        )
+    }
+
+    // JA4S garde l'ordre du wire : le serveur ne randomise pas ses extensions.
+    fn ja4s_extensions(&self) -> String {
+        join_hex(extension_types(&self.extensions))
+    }
+}
+
+fn extension_types<'b>(extensions: &'b [TlsExtension<'_>]) -> impl Iterator<Item = u16> + 'b {
+    extensions
+        .iter()
+        .map(|extension| extension.extension_type)
+        .filter(|&extension| !is_grease_value(extension))
+}
+
+fn join_decimal(values: impl Iterator<Item = u16>) -> String {
+    values
+        .filter(|&value| !is_grease_value(value))
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+fn join_hex(values: impl Iterator<Item = u16>) -> String {
+    values
+        .map(|value| format!("{value:04x}"))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Version sur deux caracteres de la section a (table de reference FoxIO).
+fn ja4_version(version: u16) -> &'static str {
+    match version {
+        0x0304 => "13",
+        0x0303 => "12",
+        0x0302 => "11",
+        0x0301 => "10",
+        0x0300 => "s3",
+        0x0002 => "s2",
+        0xfeff => "d1",
+        0xfefd => "d2",
+        0xfefc => "d3",
+        _ => "00",
+    }
+}
+
+/// Premier et dernier caracteres de l'ALPN, ou de son ecriture hexadecimale
+/// lorsque l'un des deux octets n'est pas alphanumerique ; `00` sans ALPN.
+fn ja4_alpn(alpn: Option<&[u8]>) -> String {
+    match alpn {
+        Some([first, .., last] | [first @ last])
+            if first.is_ascii_alphanumeric() && last.is_ascii_alphanumeric() =>
+        {
+            format!("{}{}", *first as char, *last as char)
+        }
+        Some(value @ [_, ..]) => {
+            let encoded = hex::encode(value);
+            let mut chars = encoded.chars();
+            let first = chars.next().unwrap_or('0');
+            let last = chars.next_back().unwrap_or('0');
+            format!("{first}{last}")
+        }
+        _ => "00".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::TryFrom;
+
+    /// Synthetique : ServerHello TLS 1.3 dont les extensions key_share puis
+    /// supported_versions reproduisent l'exemple de la documentation JA4S
+    /// (`t130200_1301_234ea6891581`).
+    #[test]
+    fn ja4s_matches_the_reference_example() {
+        let mut body = vec![0x03, 0x03];
+        body.extend_from_slice(&[0x22; 32]);
+        body.extend_from_slice(&[0x00, 0x13, 0x01, 0x00]);
+        body.extend_from_slice(&[
+            0x00, 0x0e, 0x00, 0x33, 0x00, 0x04, 0x00, 0x1d, 0x00, 0x00, 0x00, 0x2b, 0x00, 0x02,
+            0x03, 0x04,
+        ]);
+        let hello = TlsServerHello::try_from(body.as_slice()).unwrap();
+
+        assert_eq!(hello.ja4s(Ja4Protocol::Tcp), "t130200_1301_234ea6891581");
+        assert_eq!(hello.ja4s_r(Ja4Protocol::Tcp), "t130200_1301_0033,002b");
+        assert_eq!(hello.ja3s_string(), "771,4865,51-43");
+    }
+
+    /// Synthetique : les valeurs GREASE (0x0a0a) disparaissent de JA3 comme
+    /// des compteurs et listes JA4.
+    #[test]
+    fn grease_is_ignored() {
+        let mut body = vec![0x03, 0x03];
+        body.extend_from_slice(&[0x11; 32]);
+        body.push(0x00);
+        body.extend_from_slice(&[0x00, 0x04, 0x0a, 0x0a, 0x13, 0x01, 0x01, 0x00]);
+        body.extend_from_slice(&[
+            0x00, 0x0e, 0x0a, 0x0a, 0x00, 0x00, 0x00, 0x0a, 0x00, 0x06, 0x00, 0x04, 0x0a, 0x0a,
+            0x00, 0x1d,
+        ]);
+        let hello = TlsClientHello::try_from(body.as_slice()).unwrap();
+
+        assert_eq!(hello.ja3_string(), "771,4865,10,29,");
+        assert_eq!(hello.ja4_r(Ja4Protocol::Tcp), "t12i010100_1301_000a");
+    }
+
+    #[test]
+    fn ja4_alpn_handles_printable_and_binary_values() {
+        assert_eq!(ja4_alpn(Some(b"h2")), "h2");
+        assert_eq!(ja4_alpn(Some(b"http/1.1")), "h1");
+        assert_eq!(ja4_alpn(Some(b"x")), "xx");
+        // Synthetique : ALPN binaire 0xab..0xcd, ecrit en hexadecimal.
+        assert_eq!(ja4_alpn(Some(&[0xab, 0x01, 0xcd])), "ad");
+        assert_eq!(ja4_alpn(None), "00");
+        assert_eq!(ja4_alpn(Some(&[])), "00");
+    }
+}
@@ -0,0 +1,853 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! TLS handshake layer (RFC 5246 §7.4, RFC 8446 §4).
+//!
+//! A handshake record carries one or several handshake messages, and a single
+//! message may span several records (a certificate chain easily exceeds the
+//! 16 KiB record limit). [`TlsPacket::handshake_messages`] covers the first
+//! case zero-copy; [`TlsHandshakeReassembler`] rebuilds messages split across
+//! records.
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use super::x509::X509Certificate;
+use super::{TlsContentType, TlsPacket};
+use crate::{
+    checks::application::tls::{
+        TLS_EXT_ALPN, TLS_EXT_EC_POINT_FORMATS, TLS_EXT_KEY_SHARE, TLS_EXT_SERVER_NAME,
+        TLS_EXT_SIGNATURE_ALGORITHMS, TLS_EXT_SUPPORTED_GROUPS, TLS_EXT_SUPPORTED_VERSIONS,
+        TLS_HANDSHAKE_HEADER_LEN, TLS_HELLO_RETRY_REQUEST_RANDOM, TLS_RANDOM_LEN,
+        TLS_SNI_HOST_NAME, extract_handshake_header, extract_tls_field, extract_tls_vector,
+        is_grease_value, validate_handshake_length, validate_tls_no_trailing_data,
+    },
+    errors::application::tls::TlsHandshakeError,
+};
+
+/// Handshake message type (IANA "TLS HandshakeType").
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TlsHandshakeType {
+    HelloRequest,
+    ClientHello,
+    ServerHello,
+    NewSessionTicket,
+    EndOfEarlyData,
+    EncryptedExtensions,
+    Certificate,
+    ServerKeyExchange,
+    CertificateRequest,
+    ServerHelloDone,
+    CertificateVerify,
+    ClientKeyExchange,
+    Finished,
+    CertificateStatus,
+    KeyUpdate,
+    MessageHash,
+    Unknown(u8),
+}
+
+impl From<u8> for TlsHandshakeType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Self::HelloRequest,
+            1 => Self::ClientHello,
+            2 => Self::ServerHello,
+            4 => Self::NewSessionTicket,
+            5 => Self::EndOfEarlyData,
+            8 => Self::EncryptedExtensions,
+            11 => Self::Certificate,
+            12 => Self::ServerKeyExchange,
+            13 => Self::CertificateRequest,
+            14 => Self::ServerHelloDone,
+            15 => Self::CertificateVerify,
+            16 => Self::ClientKeyExchange,
+            20 => Self::Finished,
+            22 => Self::CertificateStatus,
+            24 => Self::KeyUpdate,
+            254 => Self::MessageHash,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+impl From<TlsHandshakeType> for u8 {
+    fn from(value: TlsHandshakeType) -> Self {
+        match value {
+            TlsHandshakeType::HelloRequest => 0,
+            TlsHandshakeType::ClientHello => 1,
+            TlsHandshakeType::ServerHello => 2,
+            TlsHandshakeType::NewSessionTicket => 4,
+            TlsHandshakeType::EndOfEarlyData => 5,
+            TlsHandshakeType::EncryptedExtensions => 8,
+            TlsHandshakeType::Certificate => 11,
+            TlsHandshakeType::ServerKeyExchange => 12,
+            TlsHandshakeType::CertificateRequest => 13,
+            TlsHandshakeType::ServerHelloDone => 14,
+            TlsHandshakeType::CertificateVerify => 15,
+            TlsHandshakeType::ClientKeyExchange => 16,
+            TlsHandshakeType::Finished => 20,
+            TlsHandshakeType::CertificateStatus => 22,
+            TlsHandshakeType::KeyUpdate => 24,
+            TlsHandshakeType::MessageHash => 254,
+            TlsHandshakeType::Unknown(other) => other,
+        }
+    }
+}
+
+impl fmt::Display for TlsHandshakeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::HelloRequest => "HelloRequest",
+            Self::ClientHello => "ClientHello",
+            Self::ServerHello => "ServerHello",
+            Self::NewSessionTicket => "NewSessionTicket",
+            Self::EndOfEarlyData => "EndOfEarlyData",
+            Self::EncryptedExtensions => "EncryptedExtensions",
+            Self::Certificate => "Certificate",
+            Self::ServerKeyExchange => "ServerKeyExchange",
+            Self::CertificateRequest => "CertificateRequest",
+            Self::ServerHelloDone => "ServerHelloDone",
+            Self::CertificateVerify => "CertificateVerify",
+            Self::ClientKeyExchange => "ClientKeyExchange",
+            Self::Finished => "Finished",
+            Self::CertificateStatus => "CertificateStatus",
+            Self::KeyUpdate => "KeyUpdate",
+            Self::MessageHash => "MessageHash",
+            Self::Unknown(other) => return write!(f, "Unknown({other})"),
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// TLS Handshake Message
+///
+/// ```mermaid
+/// ---
+/// title: TlsHandshake
+/// ---
+/// packet-beta
+/// 0-7: "Handshake Type u8"
+/// 8-31: "Length u24"
+/// 32-95: "Body variable"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsHandshake<'a> {
+    pub handshake_type: TlsHandshakeType,
+    pub length: u32,
+    pub body: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for TlsHandshake<'a> {
+    type Error = TlsHandshakeError;
+
+    fn try_from(buf: &'a [u8]) -> Result<Self, Self::Error> {
+        let (handshake_type, length) = extract_handshake_header(buf)?;
+        let body = extract_tls_field(
+            &buf[TLS_HANDSHAKE_HEADER_LEN..],
+            length,
+            "handshake message",
+        )?;
+
+        Ok(TlsHandshake {
+            handshake_type: TlsHandshakeType::from(handshake_type),
+            length: length as u32,
+            body,
+        })
+    }
+}
+
+impl<'a> TlsHandshake<'a> {
+    /// Decodes the body of the messages this crate understands.
+    ///
+    /// `Certificate` is decoded in its TLS 1.2 form: the TLS 1.3 one travels
+    /// encrypted and never reaches the wire in clear.
+    pub fn decode(&self) -> Result<TlsHandshakeMessage<'a>, TlsHandshakeError> {
+        Ok(match self.handshake_type {
+            TlsHandshakeType::ClientHello => {
+                TlsHandshakeMessage::ClientHello(TlsClientHello::try_from(self.body)?)
+            }
+            TlsHandshakeType::ServerHello => {
+                TlsHandshakeMessage::ServerHello(TlsServerHello::try_from(self.body)?)
+            }
+            TlsHandshakeType::Certificate => {
+                TlsHandshakeMessage::Certificate(TlsCertificateChain::try_from(self.body)?)
+            }
+            _ => TlsHandshakeMessage::Other(self.clone()),
+        })
+    }
+
+    /// Decodes the body as a ClientHello.
+    pub fn client_hello(&self) -> Result<TlsClientHello<'a>, TlsHandshakeError> {
+        self.expect(TlsHandshakeType::ClientHello)?;
+        TlsClientHello::try_from(self.body)
+    }
+
+    /// Decodes the body as a ServerHello (or HelloRetryRequest).
+    pub fn server_hello(&self) -> Result<TlsServerHello<'a>, TlsHandshakeError> {
+        self.expect(TlsHandshakeType::ServerHello)?;
+        TlsServerHello::try_from(self.body)
+    }
+
+    /// Decodes the body as a TLS 1.3 EncryptedExtensions message.
+    pub fn encrypted_extensions(&self) -> Result<TlsEncryptedExtensions<'a>, TlsHandshakeError> {
+        self.expect(TlsHandshakeType::EncryptedExtensions)?;
+        TlsEncryptedExtensions::try_from(self.body)
+    }
+
+    /// Decodes the body as a TLS 1.2 Certificate message.
+    pub fn certificate_chain(&self) -> Result<TlsCertificateChain<'a>, TlsHandshakeError> {
+        self.expect(TlsHandshakeType::Certificate)?;
+        TlsCertificateChain::try_from(self.body)
+    }
+
+    fn expect(&self, expected: TlsHandshakeType) -> Result<(), TlsHandshakeError> {
+        if self.handshake_type != expected {
+            return Err(TlsHandshakeError::UnexpectedHandshakeType {
+                expected: expected.into(),
+                actual: self.handshake_type.into(),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Handshake message decoded by [`TlsHandshake::decode`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TlsHandshakeMessage<'a> {
+    ClientHello(TlsClientHello<'a>),
+    ServerHello(TlsServerHello<'a>),
+    Certificate(TlsCertificateChain<'a>),
+    /// Message whose body is kept raw.
+    Other(TlsHandshake<'a>),
+}
+
+/// Parse les messages handshake complets d'un payload de record.
+///
+/// S'arrete au premier message incomplet : la suite est dans le record
+/// suivant, a confier a [`TlsHandshakeReassembler`].
+pub fn parse_handshake_messages(buf: &[u8]) -> Vec<TlsHandshake<'_>> {
+    let mut messages = Vec::new();
+    let mut rest = buf;
+    while let Ok(message) = TlsHandshake::try_from(rest) {
+        rest = &rest[TLS_HANDSHAKE_HEADER_LEN + message.body.len()..];
+        messages.push(message);
+    }
+    messages
+}
+
+impl<'a> TlsPacket<'a> {
+    /// Complete handshake messages carried by this record; empty for any
+    /// other content type.
+    pub fn handshake_messages(&self) -> Vec<TlsHandshake<'a>> {
+        if self.content_type != TlsContentType::Handshake {
+            return Vec::new();
+        }
+        parse_handshake_messages(self.payload)
+    }
+}
+
+/// Rebuilds handshake messages fragmented across records.
+///
+/// Feed the payload of each handshake record, in order, then drain the
+/// complete messages with [`next_message`](Self::next_message). A message
+/// announcing more than
+/// [`TLS_MAX_HANDSHAKE_LEN`](crate::checks::application::tls::TLS_MAX_HANDSHAKE_LEN)
+/// bytes is refused before being buffered.
+#[derive(Debug, Clone, Default)]
+pub struct TlsHandshakeReassembler {
+    buffer: Vec<u8>,
+}
+
+impl TlsHandshakeReassembler {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends the payload of a handshake record.
+    ///
+    /// On error the buffer is reset: the stream cannot be resynchronised
+    /// once a length is known to be bogus.
+    pub fn push(&mut self, fragment: &[u8]) -> Result<(), TlsHandshakeError> {
+        self.buffer.extend_from_slice(fragment);
+        if let Ok((_, declared)) = extract_handshake_header(&self.buffer)
+            && let Err(error) = validate_handshake_length(declared)
+        {
+            self.buffer.clear();
+            return Err(error);
+        }
+        Ok(())
+    }
+
+    /// Appends `record` when it carries handshake data; other content types
+    /// are ignored.
+    pub fn push_record(&mut self, record: &TlsPacket<'_>) -> Result<(), TlsHandshakeError> {
+        if record.content_type != TlsContentType::Handshake {
+            return Ok(());
+        }
+        self.push(record.payload)
+    }
+
+    /// Pops the next complete message, header included, ready for
+    /// [`TlsHandshake::try_from`]. `None` while the message is incomplete.
+    pub fn next_message(&mut self) -> Result<Option<Vec<u8>>, TlsHandshakeError> {
+        let Ok((_, declared)) = extract_handshake_header(&self.buffer) else {
+            return Ok(None);
+        };
+        if let Err(error) = validate_handshake_length(declared) {
+            self.buffer.clear();
+            return Err(error);
+        }
+
+        let total = TLS_HANDSHAKE_HEADER_LEN + declared;
+        if self.buffer.len() < total {
+            return Ok(None);
+        }
+        let rest = self.buffer.split_off(total);
+        Ok(Some(std::mem::replace(&mut self.buffer, rest)))
+    }
+
+    /// Number of buffered bytes not yet returned.
+    pub fn pending(&self) -> usize {
+        self.buffer.len()
+    }
+}
+
+/// Extension TLS brute : type et contenu, decodes a la demande par les
+/// accesseurs des Hello.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TlsExtension<'a> {
+    pub extension_type: u16,
+    pub data: &'a [u8],
+}
+
+/// Entree `key_share` (RFC 8446 §4.2.8).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TlsKeyShare<'a> {
+    pub group: u16,
+    pub key_exchange: &'a [u8],
+}
+
+/// TLS ClientHello
+///
+/// ```mermaid
+/// ---
+/// title: TlsClientHello
+/// ---
+/// packet-beta
+/// 0-15: "Legacy Version u16"
+/// 16-271: "Random 32 bytes"
+/// 272-279: "Session ID Length u8"
+/// 280-311: "Session ID variable"
+/// 312-327: "Cipher Suites Length u16"
+/// 328-359: "Cipher Suites variable"
+/// 360-367: "Compression Methods Length u8"
+/// 368-399: "Compression Methods variable"
+/// 400-415: "Extensions Length u16"
+/// 416-479: "Extensions variable"
+/// ```
+///
+/// Extensions are kept raw in wire order (fingerprints depend on it); the
+/// accessors decode them on demand and return `None` when the extension is
+/// absent or malformed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsClientHello<'a> {
+    pub legacy_version: u16,
+    pub random: &'a [u8],
+    pub session_id: &'a [u8],
+    pub cipher_suites: Vec<u16>,
+    pub compression_methods: &'a [u8],
+    pub extensions: Vec<TlsExtension<'a>>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for TlsClientHello<'a> {
+    type Error = TlsHandshakeError;
+
+    /// Parses a ClientHello body (handshake header excluded).
+    fn try_from(body: &'a [u8]) -> Result<Self, Self::Error> {
+        let (legacy_version, random, rest) = parse_hello_prefix(body)?;
+        let (session_id, rest) = extract_tls_vector(rest, 1, "session_id")?;
+        let (suites, rest) = extract_tls_vector(rest, 2, "cipher_suites")?;
+        let cipher_suites = parse_u16_list(suites, "cipher_suites")?;
+        let (compression_methods, rest) = extract_tls_vector(rest, 1, "compression_methods")?;
+        let extensions = parse_extensions(rest, "client_hello")?;
+
+        Ok(TlsClientHello {
+            legacy_version,
+            random,
+            session_id,
+            cipher_suites,
+            compression_methods,
+            extensions,
+        })
+    }
+}
+
+impl<'a> TlsClientHello<'a> {
+    /// First extension of the given type.
+    pub fn extension(&self, extension_type: u16) -> Option<&TlsExtension<'a>> {
+        find_extension(&self.extensions, extension_type)
+    }
+
+    /// Host name of the server_name extension (RFC 6066 §3).
+    pub fn server_name(&self) -> Option<&'a str> {
+        let data = self.extension(TLS_EXT_SERVER_NAME)?.data;
+        let (mut list, rest) = extract_tls_vector(data, 2, "server_name_list").ok()?;
+        validate_tls_no_trailing_data(rest, "server_name").ok()?;
+        while let [name_type, entry @ ..] = list {
+            let (name, rest) = extract_tls_vector(entry, 2, "server_name").ok()?;
+            if *name_type == TLS_SNI_HOST_NAME {
+                return std::str::from_utf8(name).ok();
+            }
+            list = rest;
+        }
+        None
+    }
+
+    /// Protocols offered in the ALPN extension (RFC 7301), in order.
+    pub fn alpn_protocols(&self) -> Option<Vec<&'a [u8]>> {
+        parse_alpn(self.extension(TLS_EXT_ALPN)?.data)
+    }
+
+    /// Versions offered in supported_versions (RFC 8446 §4.2.1).
+    pub fn supported_versions(&self) -> Option<Vec<u16>> {
+        let data = self.extension(TLS_EXT_SUPPORTED_VERSIONS)?.data;
+        let (versions, rest) = extract_tls_vector(data, 1, "supported_versions").ok()?;
+        validate_tls_no_trailing_data(rest, "supported_versions").ok()?;
+        parse_u16_list(versions, "supported_versions").ok()
+    }
+
+    /// Named groups of supported_groups (RFC 8422 §5.1.1, RFC 8446 §4.2.7).
+    pub fn supported_groups(&self) -> Option<Vec<u16>> {
+        self.u16_vector_extension(TLS_EXT_SUPPORTED_GROUPS, "supported_groups")
+    }
+
+    /// Point formats of ec_point_formats (RFC 8422 §5.1.2).
+    pub fn ec_point_formats(&self) -> Option<&'a [u8]> {
+        let data = self.extension(TLS_EXT_EC_POINT_FORMATS)?.data;
+        let (formats, rest) = extract_tls_vector(data, 1, "ec_point_formats").ok()?;
+        validate_tls_no_trailing_data(rest, "ec_point_formats").ok()?;
+        Some(formats)
+    }
+
+    /// Schemes of signature_algorithms (RFC 8446 §4.2.3).
+    pub fn signature_algorithms(&self) -> Option<Vec<u16>> {
+        self.u16_vector_extension(TLS_EXT_SIGNATURE_ALGORITHMS, "signature_algorithms")
+    }
+
+    /// Key shares offered by the client (RFC 8446 §4.2.8).
+    pub fn key_shares(&self) -> Option<Vec<TlsKeyShare<'a>>> {
+        let data = self.extension(TLS_EXT_KEY_SHARE)?.data;
+        let (mut entries, rest) = extract_tls_vector(data, 2, "client_shares").ok()?;
+        validate_tls_no_trailing_data(rest, "key_share").ok()?;
+        let mut shares = Vec::new();
+        while !entries.is_empty() {
+            let (share, rest) = parse_key_share_entry(entries).ok()?;
+            shares.push(share);
+            entries = rest;
+        }
+        Some(shares)
+    }
+
+    /// Returns `true` when a GREASE value (RFC 8701) appears among cipher
+    /// suites, extension types, supported groups or supported versions.
+    pub fn has_grease(&self) -> bool {
+        self.cipher_suites.iter().copied().any(is_grease_value)
+            || self
+                .extensions
+                .iter()
+                .any(|extension| is_grease_value(extension.extension_type))
+            || self
+                .supported_groups()
+                .is_some_and(|groups| groups.into_iter().any(is_grease_value))
+            || self
+                .supported_versions()
+                .is_some_and(|versions| versions.into_iter().any(is_grease_value))
+    }
+
+    fn u16_vector_extension(&self, extension_type: u16, field: &'static str) -> Option<Vec<u16>> {
+        let data = self.extension(extension_type)?.data;
+        let (values, rest) = extract_tls_vector(data, 2, field).ok()?;
+        validate_tls_no_trailing_data(rest, field).ok()?;
+        parse_u16_list(values, field).ok()
+    }
+}
+
+/// TLS ServerHello
+///
+/// ```mermaid
+/// ---
+/// title: TlsServerHello
+/// ---
+/// packet-beta
+/// 0-15: "Legacy Version u16"
+/// 16-271: "Random 32 bytes"
+/// 272-279: "Session ID Length u8"
+/// 280-311: "Session ID variable"
+/// 312-327: "Cipher Suite u16"
+/// 328-335: "Compression Method u8"
+/// 336-351: "Extensions Length u16"
+/// 352-415: "Extensions variable"
+/// ```
+///
+/// A TLS 1.3 HelloRetryRequest shares this layout; see
+/// [`is_hello_retry_request`](Self::is_hello_retry_request).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsServerHello<'a> {
+    pub legacy_version: u16,
+    pub random: &'a [u8],
+    pub session_id: &'a [u8],
+    pub cipher_suite: u16,
+    pub compression_method: u8,
+    pub extensions: Vec<TlsExtension<'a>>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for TlsServerHello<'a> {
+    type Error = TlsHandshakeError;
+
+    /// Parses a ServerHello body (handshake header excluded).
+    fn try_from(body: &'a [u8]) -> Result<Self, Self::Error> {
+        let (legacy_version, random, rest) = parse_hello_prefix(body)?;
+        let (session_id, rest) = extract_tls_vector(rest, 1, "session_id")?;
+        let fixed = extract_tls_field(rest, 3, "cipher_suite")?;
+        let cipher_suite = u16::from_be_bytes([fixed[0], fixed[1]]);
+        let compression_method = fixed[2];
+        let extensions = parse_extensions(&rest[3..], "server_hello")?;
+
+        Ok(TlsServerHello {
+            legacy_version,
+            random,
+            session_id,
+            cipher_suite,
+            compression_method,
+            extensions,
+        })
+    }
+}
+
+impl<'a> TlsServerHello<'a> {
+    /// First extension of the given type.
+    pub fn extension(&self, extension_type: u16) -> Option<&TlsExtension<'a>> {
+        find_extension(&self.extensions, extension_type)
+    }
+
+    /// `true` for a HelloRetryRequest, recognised by its fixed random
+    /// (RFC 8446 §4.1.3).
+    pub fn is_hello_retry_request(&self) -> bool {
+        self.random == TLS_HELLO_RETRY_REQUEST_RANDOM
+    }
+
+    /// Negotiated version: the supported_versions selection in TLS 1.3, the
+    /// legacy field otherwise.
+    pub fn negotiated_version(&self) -> u16 {
+        self.extension(TLS_EXT_SUPPORTED_VERSIONS)
+            .and_then(|extension| match extension.data {
+                [major, minor] => Some(u16::from_be_bytes([*major, *minor])),
+                _ => None,
+            })
+            .unwrap_or(self.legacy_version)
+    }
+
+    /// Protocol selected by the server in ALPN (a single-entry list).
+    pub fn selected_alpn(&self) -> Option<&'a [u8]> {
+        match parse_alpn(self.extension(TLS_EXT_ALPN)?.data)?.as_slice() {
+            [protocol] => Some(protocol),
+            _ => None,
+        }
+    }
+
+    /// Group of the key_share extension: the server share in a ServerHello,
+    /// the requested group in a HelloRetryRequest.
+    pub fn key_share_group(&self) -> Option<u16> {
+        match self.extension(TLS_EXT_KEY_SHARE)?.data {
+            [high, low, ..] => Some(u16::from_be_bytes([*high, *low])),
+            _ => None,
+        }
+    }
+}
+
+/// TLS 1.3 EncryptedExtensions message (RFC 8446 §4.3.1): the server
+/// extensions that do not shape the key exchange, ALPN among them. It is
+/// the first encrypted handshake message and is only readable once
+/// decrypted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsEncryptedExtensions<'a> {
+    pub extensions: Vec<TlsExtension<'a>>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for TlsEncryptedExtensions<'a> {
+    type Error = TlsHandshakeError;
+
+    /// Parses an EncryptedExtensions body (handshake header excluded).
+    fn try_from(body: &'a [u8]) -> Result<Self, Self::Error> {
+        // Le vecteur d'extensions est obligatoire, meme vide.
+        extract_tls_field(body, 2, "extensions")?;
+        let extensions = parse_extensions(body, "encrypted_extensions")?;
+        Ok(TlsEncryptedExtensions { extensions })
+    }
+}
+
+impl<'a> TlsEncryptedExtensions<'a> {
+    /// First extension of the given type.
+    pub fn extension(&self, extension_type: u16) -> Option<&TlsExtension<'a>> {
+        find_extension(&self.extensions, extension_type)
+    }
+
+    /// Protocol selected by the server in ALPN (a single-entry list).
+    pub fn selected_alpn(&self) -> Option<&'a [u8]> {
+        match parse_alpn(self.extension(TLS_EXT_ALPN)?.data)?.as_slice() {
+            [protocol] => Some(protocol),
+            _ => None,
+        }
+    }
+}
+
+/// TLS 1.2 Certificate message (RFC 5246 §7.4.2): DER certificates, the
+/// sender's own first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TlsCertificateChain<'a> {
+    pub certificates: Vec<&'a [u8]>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for TlsCertificateChain<'a> {
+    type Error = TlsHandshakeError;
+
+    /// Parses a Certificate body (handshake header excluded).
+    fn try_from(body: &'a [u8]) -> Result<Self, Self::Error> {
+        let (mut list, rest) = extract_tls_vector(body, 3, "certificate_list")?;
+        validate_tls_no_trailing_data(rest, "certificate")?;
+
+        let mut certificates = Vec::new();
+        while !list.is_empty() {
+            let (certificate, rest) = extract_tls_vector(list, 3, "certificate")?;
+            certificates.push(certificate);
+            list = rest;
+        }
+        Ok(TlsCertificateChain { certificates })
+    }
+}
+
+impl<'a> TlsCertificateChain<'a> {
+    /// Parses every certificate of the chain as X.509.
+    pub fn decode_certificates(&self) -> Result<Vec<X509Certificate<'a>>, TlsHandshakeError> {
+        self.certificates
+            .iter()
+            .map(|certificate| X509Certificate::try_from(*certificate))
+            .collect()
+    }
+}
+
+// Prefixe commun aux deux Hello : legacy_version puis random.
+fn parse_hello_prefix(body: &[u8]) -> Result<(u16, &[u8], &[u8]), TlsHandshakeError> {
+    let version = extract_tls_field(body, 2, "legacy_version")?;
+    let random = extract_tls_field(&body[2..], TLS_RANDOM_LEN, "random")?;
+    Ok((
+        u16::from_be_bytes([version[0], version[1]]),
+        random,
+        &body[2 + TLS_RANDOM_LEN..],
+    ))
+}
+
+// Le bloc d'extensions est optionnel avant TLS 1.3 (RFC 5246 §7.4.1.2) :
+// un Hello qui s'arrete apres la compression n'en porte aucune.
+fn parse_extensions<'a>(
+    rest: &'a [u8],
+    field: &'static str,
+) -> Result<Vec<TlsExtension<'a>>, TlsHandshakeError> {
+    if rest.is_empty() {
+        return Ok(Vec::new());
+    }
+    let (mut block, rest) = extract_tls_vector(rest, 2, "extensions")?;
+    validate_tls_no_trailing_data(rest, field)?;
+
+    let mut extensions = Vec::new();
+    while !block.is_empty() {
+        let header = extract_tls_field(block, 2, "extension type")?;
+        let extension_type = u16::from_be_bytes([header[0], header[1]]);
+        let (data, rest) = extract_tls_vector(&block[2..], 2, "extension")?;
+        extensions.push(TlsExtension {
+            extension_type,
+            data,
+        });
+        block = rest;
+    }
+    Ok(extensions)
+}
+
+fn find_extension<'b, 'a>(
+    extensions: &'b [TlsExtension<'a>],
+    extension_type: u16,
+) -> Option<&'b TlsExtension<'a>> {
+    extensions
+        .iter()
+        .find(|extension| extension.extension_type == extension_type)
+}
+
+fn parse_u16_list(bytes: &[u8], field: &'static str) -> Result<Vec<u16>, TlsHandshakeError> {
+    let values = bytes.chunks_exact(2);
+    validate_tls_no_trailing_data(values.remainder(), field)?;
+    Ok(values
+        .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+        .collect())
+}
+
+fn parse_alpn(data: &[u8]) -> Option<Vec<&[u8]>> {
+    let (mut list, rest) = extract_tls_vector(data, 2, "protocol_name_list").ok()?;
+    validate_tls_no_trailing_data(rest, "alpn").ok()?;
+    let mut protocols = Vec::new();
+    while !list.is_empty() {
+        let (protocol, rest) = extract_tls_vector(list, 1, "protocol_name").ok()?;
+        protocols.push(protocol);
+        list = rest;
+    }
+    Some(protocols)
+}
+
+fn parse_key_share_entry(bytes: &[u8]) -> Result<(TlsKeyShare<'_>, &[u8]), TlsHandshakeError> {
+    let group = extract_tls_field(bytes, 2, "key_share group")?;
+    let (key_exchange, rest) = extract_tls_vector(&bytes[2..], 2, "key_exchange")?;
+    Ok((
+        TlsKeyShare {
+            group: u16::from_be_bytes([group[0], group[1]]),
+            key_exchange,
+        },
+        rest,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Synthetique : ClientHello minimal de type Chrome, GREASE en tete des
+    /// suites (0x3a3a), des extensions (0x8a8a) et des groupes (0xdada).
+    fn grease_client_hello_body() -> Vec<u8> {
+        let mut body = vec![0x03, 0x03];
+        body.extend_from_slice(&[0x11; TLS_RANDOM_LEN]);
+        body.push(0x00); // session_id vide
+        body.extend_from_slice(&[0x00, 0x04, 0x3a, 0x3a, 0x13, 0x01]);
+        body.extend_from_slice(&[0x01, 0x00]);
+        body.extend_from_slice(&[
+            0x00, 0x20, // extensions : 32 octets
+            0x8a, 0x8a, 0x00, 0x00, // GREASE vide
+            0x00, 0x0a, 0x00, 0x06, 0x00, 0x04, 0xda, 0xda, 0x00, 0x1d, // groupes
+            0x00, 0x10, 0x00, 0x0e, 0x00, 0x0c, 0x02, b'h', b'2', 0x08, b'h', b't', b't', b'p',
+            b'/', b'1', b'.', b'1', // ALPN
+        ]);
+        body
+    }
+
+    #[test]
+    fn client_hello_reports_grease_and_alpn() {
+        let body = grease_client_hello_body();
+        let hello = TlsClientHello::try_from(body.as_slice()).unwrap();
+
+        assert_eq!(hello.cipher_suites, vec![0x3a3a, 0x1301]);
+        assert!(hello.has_grease());
+        assert_eq!(hello.supported_groups(), Some(vec![0xdada, 0x001d]));
+        assert_eq!(
+            hello.alpn_protocols(),
+            Some(vec![&b"h2"[..], &b"http/1.1"[..]])
+        );
+        assert_eq!(hello.server_name(), None);
+        assert_eq!(hello.supported_versions(), None);
+    }
+
+    #[test]
+    fn client_hello_rejects_trailing_bytes() {
+        let mut body = grease_client_hello_body();
+        body.push(0x00);
+        assert_eq!(
+            TlsClientHello::try_from(body.as_slice()),
+            Err(TlsHandshakeError::TrailingData {
+                field: "client_hello",
+                remaining: 1
+            })
+        );
+    }
+
+    /// Synthetique : HelloRetryRequest TLS 1.3 demandant le groupe secp256r1.
+    #[test]
+    fn server_hello_detects_hello_retry_request() {
+        let mut body = vec![0x03, 0x03];
+        body.extend_from_slice(&TLS_HELLO_RETRY_REQUEST_RANDOM);
+        body.extend_from_slice(&[0x00, 0x13, 0x01, 0x00]);
+        body.extend_from_slice(&[
+            0x00, 0x0c, 0x00, 0x2b, 0x00, 0x02, 0x03, 0x04, 0x00, 0x33, 0x00, 0x02, 0x00, 0x17,
+        ]);
+
+        let hello = TlsServerHello::try_from(body.as_slice()).unwrap();
+        assert!(hello.is_hello_retry_request());
+        assert_eq!(hello.negotiated_version(), 0x0304);
+        assert_eq!(hello.key_share_group(), Some(0x0017));
+        assert_eq!(hello.cipher_suite, 0x1301);
+    }
+
+    /// Synthetique : un message de 6 octets coupe sur deux records, suivi
+    /// d'un ServerHelloDone dans le second.
+    #[test]
+    fn reassembler_rebuilds_a_message_split_across_records() {
+        let mut reassembler = TlsHandshakeReassembler::new();
+
+        reassembler
+            .push(&[0x0b, 0x00, 0x00, 0x06, 0xaa, 0xbb])
+            .unwrap();
+        assert_eq!(reassembler.next_message(), Ok(None));
+
+        reassembler
+            .push(&[0xcc, 0xdd, 0xee, 0xff, 0x0e, 0x00, 0x00, 0x00])
+            .unwrap();
+        let first = reassembler.next_message().unwrap().unwrap();
+        let message = TlsHandshake::try_from(first.as_slice()).unwrap();
+        assert_eq!(message.handshake_type, TlsHandshakeType::Certificate);
+        assert_eq!(message.body, &[0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff]);
+
+        let second = reassembler.next_message().unwrap().unwrap();
+        assert_eq!(second, vec![0x0e, 0x00, 0x00, 0x00]);
+        assert_eq!(reassembler.next_message(), Ok(None));
+        assert_eq!(reassembler.pending(), 0);
+    }
+
+    #[test]
+    fn reassembler_refuses_oversized_messages() {
+        let mut reassembler = TlsHandshakeReassembler::new();
+        assert!(matches!(
+            reassembler.push(&[0x0b, 0xff, 0xff, 0xff]),
+            Err(TlsHandshakeError::HandshakeTooLarge { .. })
+        ));
+        assert_eq!(reassembler.pending(), 0);
+    }
+
+    #[test]
+    fn handshake_accessors_check_the_message_type() {
+        let bytes = [0x0e, 0x00, 0x00, 0x00];
+        let message = TlsHandshake::try_from(&bytes[..]).unwrap();
+        assert_eq!(message.handshake_type, TlsHandshakeType::ServerHelloDone);
+        assert_eq!(
+            message.client_hello(),
+            Err(TlsHandshakeError::UnexpectedHandshakeType {
+                expected: 1,
+                actual: 14
+            })
+        );
+        assert!(matches!(
+            message.decode(),
+            Ok(TlsHandshakeMessage::Other(_))
+        ));
+    }
+
+    #[test]
+    fn encrypted_extensions_expose_alpn() {
+        // Synthetique : EncryptedExtensions avec ALPN "h2" seul.
+        let bytes = hex::decode("0800000b0009001000050003026832").unwrap();
+        let message = TlsHandshake::try_from(&bytes[..]).unwrap();
+        let extensions = message.encrypted_extensions().unwrap();
+        assert_eq!(extensions.selected_alpn(), Some(&b"h2"[..]));
+
+        let empty = TlsEncryptedExtensions::try_from(&[0x00, 0x00][..]).unwrap();
+        assert!(empty.extensions.is_empty());
+        assert!(TlsEncryptedExtensions::try_from(&[][..]).is_err());
+    }
+}
@@ -3,6 +3,10 @@
 // Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
 // This file may not be copied, modified, or distributed except according to those terms.
 
+mod fingerprint;
+pub mod handshake;
+pub mod x509;
+
 use std::convert::TryFrom;
 use std::fmt;
 
@@ -459,6 +463,75 @@ mod tests {
 
     // --- Tests sur les types et versions ---
 
+    #[test]
+    fn golden_tls13_client_hello_decodes_extensions() {
+        let buf = tls13_client_hello_record();
+        let record = TlsPacket::try_from(buf.as_slice()).unwrap();
+        let messages = record.handshake_messages();
+        assert_eq!(messages.len(), 1);
+        assert_eq!(messages[0].length, 508);
+
+        let hello = messages[0].client_hello().expect("ClientHello complet");
+        assert_eq!(hello.legacy_version, 0x0303);
+        assert_eq!(hello.session_id.len(), 32);
+        assert_eq!(hello.cipher_suites.len(), 18);
+        assert_eq!(&hello.cipher_suites[..3], &[0x1301, 0x1302, 0x1303]);
+        assert_eq!(hello.compression_methods, &[0x00]);
+
+        // Ordre du wire, celui de la JA3 Fullstring tshark.
+        let types: Vec<u16> = hello.extensions.iter().map(|e| e.extension_type).collect();
+        assert_eq!(
+            types,
+            vec![0, 23, 65281, 10, 11, 35, 13, 51, 45, 43, 21, 41]
+        );
+
+        assert_eq!(hello.server_name(), Some("unleash.codeium.com"));
+        assert_eq!(hello.supported_versions(), Some(vec![0x0304, 0x0303]));
+        assert_eq!(hello.supported_groups(), Some(vec![0x001d, 0x0017, 0x0018]));
+        assert_eq!(hello.ec_point_formats(), Some(&[0x00][..]));
+        assert_eq!(
+            hello.signature_algorithms(),
+            Some(vec![
+                0x0403, 0x0804, 0x0401, 0x0503, 0x0805, 0x0501, 0x0806, 0x0601, 0x0201
+            ])
+        );
+        let shares = hello.key_shares().expect("key_share");
+        assert_eq!(shares.len(), 1);
+        assert_eq!(shares[0].group, 0x001d);
+        assert_eq!(shares[0].key_exchange.len(), 32);
+        assert_eq!(hello.alpn_protocols(), None);
+        assert!(!hello.has_grease());
+    }
+
+    /// Empreintes comparees aux champs `[JA3 Fullstring]`, `[JA3]`, `[JA4]` et
+    /// `[JA4_r]` de la dissection tshark en fin de fichier.
+    #[test]
+    fn golden_tls13_client_hello_fingerprints_match_tshark() {
+        use crate::parse::application::protocols::fingerprint::Ja4Protocol;
+
+        let buf = tls13_client_hello_record();
+        let record = TlsPacket::try_from(buf.as_slice()).unwrap();
+        let hello = record.handshake_messages()[0].client_hello().unwrap();
+
+        assert_eq!(
+            hello.ja3_string(),
+            "771,4865-4866-4867-49199-49195-49200-49196-49191-52393-52392-49161-49171-\
+             49162-49172-156-157-47-53,0-23-65281-10-11-35-13-51-45-43-21-41,29-23-24,0"
+        );
+        assert_eq!(hello.ja3(), "d92981146534550ae85075b70b1c352a");
+        assert_eq!(
+            hello.ja4(Ja4Protocol::Tcp),
+            "t13d181200_5d04281c6031_02c8e53ee398"
+        );
+        assert_eq!(
+            hello.ja4_r(Ja4Protocol::Tcp),
+            "t13d181200_002f,0035,009c,009d,1301,1302,1303,c009,c00a,c013,c014,c027,\
+             c02b,c02c,c02f,c030,cca8,cca9_000a,000b,000d,0015,0017,0023,0029,002b,\
+             002d,0033,ff01_0403,0804,0401,0503,0805,0501,0806,0601,0201"
+        );
+        assert!(hello.ja4(Ja4Protocol::Quic).starts_with("q13d1812"));
+    }
+
     #[test]
     fn test_tls_content_type_from_u8_all_valid_values() {
         for (value, expected) in [
@@ -0,0 +1,598 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Minimal X.509 v1/v3 certificate reader (RFC 5280 §4.1).
+//!
+//! Only what a passive observer needs is extracted: serial, issuer, subject,
+//! validity, key and signature algorithms, and the subjectAltName extension.
+//! The signature is not verified: a sniffer has no trust anchor to check it
+//! against.
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use chrono::{DateTime, NaiveDate, Utc};
+
+use crate::{
+    checks::application::tls::{
+        DER_BIT_STRING, DER_BOOLEAN, DER_GENERALIZED_TIME, DER_INTEGER, DER_OCTET_STRING, DER_OID,
+        DER_SEQUENCE, DER_SET, DER_UTC_TIME, extract_der_element, validate_tls_no_trailing_data,
+    },
+    errors::application::tls::TlsHandshakeError,
+};
+
+// Balises contextuelles du TBSCertificate et du GeneralName (RFC 5280 §4.1,
+// §4.2.1.6).
+const TAG_VERSION: u8 = 0xa0;
+const TAG_EXTENSIONS: u8 = 0xa3;
+const TAG_RFC822_NAME: u8 = 0x81;
+const TAG_DNS_NAME: u8 = 0x82;
+const TAG_URI: u8 = 0x86;
+const TAG_IP_ADDRESS: u8 = 0x87;
+
+// OID en encodage DER (contenu seul, sans en-tete).
+const OID_COMMON_NAME: &[u8] = &[0x55, 0x04, 0x03];
+const OID_SERIAL_NUMBER: &[u8] = &[0x55, 0x04, 0x05];
+const OID_COUNTRY: &[u8] = &[0x55, 0x04, 0x06];
+const OID_LOCALITY: &[u8] = &[0x55, 0x04, 0x07];
+const OID_STATE: &[u8] = &[0x55, 0x04, 0x08];
+const OID_ORGANIZATION: &[u8] = &[0x55, 0x04, 0x0a];
+const OID_ORGANIZATIONAL_UNIT: &[u8] = &[0x55, 0x04, 0x0b];
+const OID_EMAIL_ADDRESS: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x09, 0x01];
+const OID_SUBJECT_ALT_NAME: &[u8] = &[0x55, 0x1d, 0x11];
+const OID_RSA_ENCRYPTION: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x01];
+const OID_SHA1_WITH_RSA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x05];
+const OID_SHA256_WITH_RSA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0b];
+const OID_SHA384_WITH_RSA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0c];
+const OID_SHA512_WITH_RSA: &[u8] = &[0x2a, 0x86, 0x48, 0x86, 0xf7, 0x0d, 0x01, 0x01, 0x0d];
+const OID_EC_PUBLIC_KEY: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x02, 0x01];
+const OID_ECDSA_WITH_SHA256: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x02];
+const OID_ECDSA_WITH_SHA384: &[u8] = &[0x2a, 0x86, 0x48, 0xce, 0x3d, 0x04, 0x03, 0x03];
+const OID_ED25519: &[u8] = &[0x2b, 0x65, 0x70];
+
+/// Object identifier, kept in its DER encoding. `Display` renders the dotted
+/// form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct X509Oid<'a>(pub &'a [u8]);
+
+impl X509Oid<'_> {
+    /// Arcs of the identifier, `None` when the encoding is malformed.
+    pub fn arcs(&self) -> Option<Vec<u64>> {
+        let mut arcs = Vec::new();
+        let mut value: u64 = 0;
+        for &byte in self.0 {
+            // Pas de zero de tete dans un sous-identifiant (X.690 §8.19.2).
+            if value == 0 && byte == 0x80 {
+                return None;
+            }
+            value = value.checked_mul(128)? | u64::from(byte & 0x7f);
+            if byte & 0x80 == 0 {
+                if arcs.is_empty() {
+                    // Premier sous-identifiant : deux arcs packes (X.690 §8.19.4).
+                    let first = (value / 40).min(2);
+                    arcs.push(first);
+                    arcs.push(value - first * 40);
+                } else {
+                    arcs.push(value);
+                }
+                value = 0;
+            }
+        }
+        if arcs.is_empty() || self.0.last().is_some_and(|byte| byte & 0x80 != 0) {
+            return None;
+        }
+        Some(arcs)
+    }
+
+    /// Conventional name of the identifiers this reader knows about.
+    pub fn name(&self) -> Option<&'static str> {
+        Some(match self.0 {
+            OID_COMMON_NAME => "commonName",
+            OID_SERIAL_NUMBER => "serialNumber",
+            OID_COUNTRY => "countryName",
+            OID_LOCALITY => "localityName",
+            OID_STATE => "stateOrProvinceName",
+            OID_ORGANIZATION => "organizationName",
+            OID_ORGANIZATIONAL_UNIT => "organizationalUnitName",
+            OID_EMAIL_ADDRESS => "emailAddress",
+            OID_SUBJECT_ALT_NAME => "subjectAltName",
+            OID_RSA_ENCRYPTION => "rsaEncryption",
+            OID_SHA1_WITH_RSA => "sha1WithRSAEncryption",
+            OID_SHA256_WITH_RSA => "sha256WithRSAEncryption",
+            OID_SHA384_WITH_RSA => "sha384WithRSAEncryption",
+            OID_SHA512_WITH_RSA => "sha512WithRSAEncryption",
+            OID_EC_PUBLIC_KEY => "ecPublicKey",
+            OID_ECDSA_WITH_SHA256 => "ecdsa-with-SHA256",
+            OID_ECDSA_WITH_SHA384 => "ecdsa-with-SHA384",
+            OID_ED25519 => "Ed25519",
+            _ => return None,
+        })
+    }
+}
+
+impl fmt::Display for X509Oid<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Some(arcs) = self.arcs() else {
+            return write!(f, "invalid OID {}", hex::encode(self.0));
+        };
+        for (index, arc) in arcs.iter().enumerate() {
+            if index > 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{arc}")?;
+        }
+        Ok(())
+    }
+}
+
+/// One attribute of a distinguished name, such as `CN=example.com`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct X509NameAttribute<'a> {
+    pub oid: X509Oid<'a>,
+    /// DER tag of the value (UTF8String, PrintableString…).
+    pub tag: u8,
+    pub value: &'a [u8],
+}
+
+impl<'a> X509NameAttribute<'a> {
+    /// Value as text, for the string types whose bytes are UTF-8 compatible.
+    /// BMPString and UniversalString values return `None`.
+    pub fn value_str(&self) -> Option<&'a str> {
+        match self.tag {
+            // UTF8String, PrintableString, TeletexString, IA5String, VisibleString.
+            0x0c | 0x13 | 0x14 | 0x16 | 0x1a => std::str::from_utf8(self.value).ok(),
+            _ => None,
+        }
+    }
+
+    fn label(&self) -> Option<&'static str> {
+        Some(match self.oid.0 {
+            OID_COMMON_NAME => "CN",
+            OID_COUNTRY => "C",
+            OID_LOCALITY => "L",
+            OID_STATE => "ST",
+            OID_ORGANIZATION => "O",
+            OID_ORGANIZATIONAL_UNIT => "OU",
+            OID_SERIAL_NUMBER => "serialNumber",
+            OID_EMAIL_ADDRESS => "emailAddress",
+            _ => return None,
+        })
+    }
+}
+
+/// Distinguished name (issuer or subject), attributes in wire order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct X509Name<'a> {
+    /// DER content of the Name, used to compare issuer and subject.
+    pub raw: &'a [u8],
+    pub attributes: Vec<X509NameAttribute<'a>>,
+}
+
+impl<'a> X509Name<'a> {
+    /// First value of the attribute identified by `oid` (DER content).
+    pub fn attribute(&self, oid: &[u8]) -> Option<&'a str> {
+        self.attributes
+            .iter()
+            .find(|attribute| attribute.oid.0 == oid)
+            .and_then(|attribute| attribute.value_str())
+    }
+
+    pub fn common_name(&self) -> Option<&'a str> {
+        self.attribute(OID_COMMON_NAME)
+    }
+
+    pub fn organization(&self) -> Option<&'a str> {
+        self.attribute(OID_ORGANIZATION)
+    }
+
+    pub fn country(&self) -> Option<&'a str> {
+        self.attribute(OID_COUNTRY)
+    }
+}
+
+impl fmt::Display for X509Name<'_> {
+    /// Rendu a la OpenSSL : `C=US, O=Example, CN=example.com`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, attribute) in self.attributes.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            match attribute.label() {
+                Some(label) => write!(f, "{label}=")?,
+                None => write!(f, "{}=", attribute.oid)?,
+            }
+            match attribute.value_str() {
+                Some(value) => write!(f, "{value}")?,
+                None => write!(f, "#{}", hex::encode(attribute.value))?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Certificate extension, value kept raw (content of the extnValue OCTET
+/// STRING).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct X509Extension<'a> {
+    pub oid: X509Oid<'a>,
+    pub critical: bool,
+    pub value: &'a [u8],
+}
+
+/// Entry of the subjectAltName extension (RFC 5280 §4.2.1.6).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum X509GeneralName<'a> {
+    Email(&'a str),
+    DnsName(&'a str),
+    Uri(&'a str),
+    IpAddress(IpAddr),
+    /// otherName, directoryName… kept with their context tag.
+    Other {
+        tag: u8,
+        value: &'a [u8],
+    },
+}
+
+/// X.509 certificate (RFC 5280 §4.1), parsed from its DER encoding.
+///
+/// Unlike the wire protocols of this crate, DER has no fixed layout: every
+/// field is a tag-length-value element nested in the `tbsCertificate`
+/// SEQUENCE.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct X509Certificate<'a> {
+    /// 1, 2 or 3 (the wire value plus one).
+    pub version: u8,
+    /// INTEGER content, two's complement big-endian.
+    pub serial_number: &'a [u8],
+    pub signature_algorithm: X509Oid<'a>,
+    pub issuer: X509Name<'a>,
+    pub not_before: DateTime<Utc>,
+    pub not_after: DateTime<Utc>,
+    pub subject: X509Name<'a>,
+    pub public_key_algorithm: X509Oid<'a>,
+    /// subjectPublicKey BIT STRING, unused-bits octet stripped.
+    pub public_key: &'a [u8],
+    pub extensions: Vec<X509Extension<'a>>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for X509Certificate<'a> {
+    type Error = TlsHandshakeError;
+
+    fn try_from(der: &'a [u8]) -> Result<Self, Self::Error> {
+        let (certificate, rest) = expect_element(der, DER_SEQUENCE, "Certificate")?;
+        validate_tls_no_trailing_data(rest, "X.509 certificate")?;
+
+        let (tbs, rest) = expect_element(certificate, DER_SEQUENCE, "tbsCertificate")?;
+        let (algorithm, rest) = expect_element(rest, DER_SEQUENCE, "signatureAlgorithm")?;
+        let (signature_algorithm, _) = expect_element(algorithm, DER_OID, "signatureAlgorithm")?;
+        expect_element(rest, DER_BIT_STRING, "signatureValue")?;
+
+        // version [0] EXPLICIT INTEGER DEFAULT v1.
+        let (version, rest) = match tbs.first() {
+            Some(&TAG_VERSION) => {
+                let (explicit, rest) = expect_element(tbs, TAG_VERSION, "version")?;
+                let (version, _) = expect_element(explicit, DER_INTEGER, "version")?;
+                match version {
+                    [value @ 0..=2] => (value + 1, rest),
+                    _ => return Err(TlsHandshakeError::InvalidCertificate("unsupported version")),
+                }
+            }
+            _ => (1, tbs),
+        };
+
+        let (serial_number, rest) = expect_element(rest, DER_INTEGER, "serialNumber")?;
+        let (_, rest) = expect_element(rest, DER_SEQUENCE, "signature")?;
+        let (issuer, rest) = expect_element(rest, DER_SEQUENCE, "issuer")?;
+        let (validity, rest) = expect_element(rest, DER_SEQUENCE, "validity")?;
+        let (subject, rest) = expect_element(rest, DER_SEQUENCE, "subject")?;
+        let (key_info, mut rest) = expect_element(rest, DER_SEQUENCE, "subjectPublicKeyInfo")?;
+
+        let (not_before, not_after) = parse_validity(validity)?;
+        let (key_algorithm, key_rest) = expect_element(key_info, DER_SEQUENCE, "algorithm")?;
+        let (public_key_algorithm, _) = expect_element(key_algorithm, DER_OID, "algorithm")?;
+        let (public_key, _) = expect_element(key_rest, DER_BIT_STRING, "subjectPublicKey")?;
+
+        // issuerUniqueID [1] et subjectUniqueID [2] sont ignores ; seules les
+        // extensions [3] sont retenues.
+        let mut extensions = Vec::new();
+        while !rest.is_empty() {
+            let (tag, content, next) = extract_der_element(rest)?;
+            if tag == TAG_EXTENSIONS {
+                let (list, _) = expect_element(content, DER_SEQUENCE, "extensions")?;
+                extensions = parse_extensions(list)?;
+            }
+            rest = next;
+        }
+
+        Ok(X509Certificate {
+            version,
+            serial_number,
+            signature_algorithm: X509Oid(signature_algorithm),
+            issuer: parse_name(issuer)?,
+            not_before,
+            not_after,
+            subject: parse_name(subject)?,
+            public_key_algorithm: X509Oid(public_key_algorithm),
+            public_key: public_key.get(1..).unwrap_or_default(),
+            extensions,
+        })
+    }
+}
+
+impl<'a> X509Certificate<'a> {
+    /// First extension identified by `oid` (DER content).
+    pub fn extension(&self, oid: &[u8]) -> Option<&X509Extension<'a>> {
+        self.extensions
+            .iter()
+            .find(|extension| extension.oid.0 == oid)
+    }
+
+    /// Entries of the subjectAltName extension; empty when it is absent, as
+    /// in every v1 certificate.
+    pub fn subject_alt_names(&self) -> Result<Vec<X509GeneralName<'a>>, TlsHandshakeError> {
+        let Some(extension) = self.extension(OID_SUBJECT_ALT_NAME) else {
+            return Ok(Vec::new());
+        };
+        let (mut names, _) = expect_element(extension.value, DER_SEQUENCE, "subjectAltName")?;
+
+        let mut entries = Vec::new();
+        while !names.is_empty() {
+            let (tag, value, rest) = extract_der_element(names)?;
+            let text = || {
+                std::str::from_utf8(value)
+                    .map_err(|_| TlsHandshakeError::InvalidCertificate("non-ASCII subjectAltName"))
+            };
+            entries.push(match tag {
+                TAG_RFC822_NAME => X509GeneralName::Email(text()?),
+                TAG_DNS_NAME => X509GeneralName::DnsName(text()?),
+                TAG_URI => X509GeneralName::Uri(text()?),
+                TAG_IP_ADDRESS => match value.len() {
+                    4 => X509GeneralName::IpAddress(IpAddr::V4(Ipv4Addr::from(
+                        <[u8; 4]>::try_from(value).unwrap_or_default(),
+                    ))),
+                    16 => X509GeneralName::IpAddress(IpAddr::V6(Ipv6Addr::from(
+                        <[u8; 16]>::try_from(value).unwrap_or_default(),
+                    ))),
+                    _ => X509GeneralName::Other { tag, value },
+                },
+                _ => X509GeneralName::Other { tag, value },
+            });
+            names = rest;
+        }
+        Ok(entries)
+    }
+
+    /// DNS names the certificate vouches for: the subjectAltName dNSName
+    /// entries, or the subject CN when the extension is absent.
+    pub fn dns_names(&self) -> Vec<&'a str> {
+        let names: Vec<&'a str> = self
+            .subject_alt_names()
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|name| match name {
+                X509GeneralName::DnsName(name) => Some(name),
+                _ => None,
+            })
+            .collect();
+        if names.is_empty() {
+            return self.subject.common_name().into_iter().collect();
+        }
+        names
+    }
+
+    /// `true` when `at` lies within the validity period (bounds included).
+    pub fn is_valid_at(&self, at: DateTime<Utc>) -> bool {
+        self.not_before <= at && at <= self.not_after
+    }
+
+    /// `true` when issuer and subject are byte-identical, as in a root or a
+    /// self-signed certificate.
+    pub fn is_self_issued(&self) -> bool {
+        self.issuer.raw == self.subject.raw
+    }
+}
+
+fn expect_element<'a>(
+    bytes: &'a [u8],
+    tag: u8,
+    field: &'static str,
+) -> Result<(&'a [u8], &'a [u8]), TlsHandshakeError> {
+    let (actual, content, rest) = extract_der_element(bytes)?;
+    if actual != tag {
+        return Err(TlsHandshakeError::InvalidCertificate(field));
+    }
+    Ok((content, rest))
+}
+
+// Name ::= SEQUENCE OF SET OF AttributeTypeAndValue.
+fn parse_name(raw: &[u8]) -> Result<X509Name<'_>, TlsHandshakeError> {
+    let mut attributes = Vec::new();
+    let mut rdns = raw;
+    while !rdns.is_empty() {
+        let (mut set, rest) = expect_element(rdns, DER_SET, "RelativeDistinguishedName")?;
+        while !set.is_empty() {
+            let (pair, next) = expect_element(set, DER_SEQUENCE, "AttributeTypeAndValue")?;
+            let (oid, value) = expect_element(pair, DER_OID, "AttributeType")?;
+            let (tag, value, _) = extract_der_element(value)?;
+            attributes.push(X509NameAttribute {
+                oid: X509Oid(oid),
+                tag,
+                value,
+            });
+            set = next;
+        }
+        rdns = rest;
+    }
+    Ok(X509Name { raw, attributes })
+}
+
+fn parse_validity(validity: &[u8]) -> Result<(DateTime<Utc>, DateTime<Utc>), TlsHandshakeError> {
+    let (tag, not_before, rest) = extract_der_element(validity)?;
+    let not_before = parse_time(tag, not_before)?;
+    let (tag, not_after, _) = extract_der_element(rest)?;
+    Ok((not_before, parse_time(tag, not_after)?))
+}
+
+/// Decodes a UTCTime (`YYMMDDHHMMSSZ`) or GeneralizedTime
+/// (`YYYYMMDDHHMMSSZ`), the only forms RFC 5280 §4.1.2.5 allows.
+fn parse_time(tag: u8, value: &[u8]) -> Result<DateTime<Utc>, TlsHandshakeError> {
+    const INVALID: TlsHandshakeError = TlsHandshakeError::InvalidCertificate("validity time");
+
+    let digits = match (tag, value) {
+        (DER_UTC_TIME, [digits @ .., b'Z']) if digits.len() == 12 => digits,
+        (DER_GENERALIZED_TIME, [digits @ .., b'Z']) if digits.len() == 14 => digits,
+        _ => return Err(INVALID),
+    };
+    if !digits.iter().all(u8::is_ascii_digit) {
+        return Err(INVALID);
+    }
+    let number = |range: std::ops::Range<usize>| {
+        digits[range]
+            .iter()
+            .fold(0u32, |value, digit| value * 10 + u32::from(digit - b'0'))
+    };
+
+    // UTCTime : YY >= 50 designe 19YY, sinon 20YY (RFC 5280 §4.1.2.5.1).
+    let (year, rest) = if tag == DER_UTC_TIME {
+        let year = number(0..2);
+        (if year >= 50 { 1900 + year } else { 2000 + year }, 2)
+    } else {
+        (number(0..4), 4)
+    };
+
+    NaiveDate::from_ymd_opt(
+        year as i32,
+        number(rest..rest + 2),
+        number(rest + 2..rest + 4),
+    )
+    .and_then(|date| {
+        date.and_hms_opt(
+            number(rest + 4..rest + 6),
+            number(rest + 6..rest + 8),
+            number(rest + 8..rest + 10),
+        )
+    })
+    .map(|datetime| datetime.and_utc())
+    .ok_or(INVALID)
+}
+
+fn parse_extensions(mut list: &[u8]) -> Result<Vec<X509Extension<'_>>, TlsHandshakeError> {
+    let mut extensions = Vec::new();
+    while !list.is_empty() {
+        let (extension, rest) = expect_element(list, DER_SEQUENCE, "Extension")?;
+        let (oid, fields) = expect_element(extension, DER_OID, "extnID")?;
+        // critical BOOLEAN DEFAULT FALSE : absent dans la plupart des cas.
+        let (critical, fields) = match fields.first() {
+            Some(&DER_BOOLEAN) => {
+                let (flag, fields) = expect_element(fields, DER_BOOLEAN, "critical")?;
+                (flag.first().is_some_and(|&byte| byte != 0), fields)
+            }
+            _ => (false, fields),
+        };
+        let (value, _) = expect_element(fields, DER_OCTET_STRING, "extnValue")?;
+        extensions.push(X509Extension {
+            oid: X509Oid(oid),
+            critical,
+            value,
+        });
+        list = rest;
+    }
+    Ok(extensions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn oid_renders_in_dotted_form() {
+        assert_eq!(
+            X509Oid(OID_SHA256_WITH_RSA).to_string(),
+            "1.2.840.113549.1.1.11"
+        );
+        assert_eq!(X509Oid(OID_SUBJECT_ALT_NAME).to_string(), "2.5.29.17");
+        assert_eq!(
+            X509Oid(OID_SHA256_WITH_RSA).name(),
+            Some("sha256WithRSAEncryption")
+        );
+        // Dernier octet avec bit de continuation : encodage tronque.
+        assert_eq!(X509Oid(&[0x2a, 0x86]).arcs(), None);
+    }
+
+    #[test]
+    fn parses_utc_and_generalized_times() {
+        assert_eq!(
+            parse_time(DER_UTC_TIME, b"151103000000Z"),
+            Ok(Utc.with_ymd_and_hms(2015, 11, 3, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            parse_time(DER_UTC_TIME, b"991231235959Z"),
+            Ok(Utc.with_ymd_and_hms(1999, 12, 31, 23, 59, 59).unwrap())
+        );
+        assert_eq!(
+            parse_time(DER_GENERALIZED_TIME, b"20500101000000Z"),
+            Ok(Utc.with_ymd_and_hms(2050, 1, 1, 0, 0, 0).unwrap())
+        );
+        assert!(parse_time(DER_UTC_TIME, b"151303000000Z").is_err());
+        assert!(parse_time(DER_UTC_TIME, b"1511030000+0100").is_err());
+    }
+
+    /// Synthetique : Name `C=FR, CN=exemple.fr` puis subjectAltName portant
+    /// un dNSName, une IPv4 et un rfc822Name.
+    #[test]
+    fn parses_names_and_subject_alt_names() {
+        let name = [
+            0x31, 0x0b, 0x30, 0x09, 0x06, 0x03, 0x55, 0x04, 0x06, 0x13, 0x02, b'F', b'R', 0x31,
+            0x13, 0x30, 0x11, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0c, 0x0a, b'e', b'x', b'e', b'm',
+            b'p', b'l', b'e', b'.', b'f', b'r',
+        ];
+        let name = parse_name(&name).unwrap();
+        assert_eq!(name.common_name(), Some("exemple.fr"));
+        assert_eq!(name.country(), Some("FR"));
+        assert_eq!(name.to_string(), "C=FR, CN=exemple.fr");
+
+        let san = [
+            0x30, 0x16, 0x82, 0x06, b'a', b'.', b't', b'e', b's', b't', 0x87, 0x04, 192, 0, 2, 1,
+            0x81, 0x06, b'a', b'@', b'b', b'.', b'f', b'r',
+        ];
+        let certificate = X509Certificate {
+            version: 3,
+            serial_number: &[0x01],
+            signature_algorithm: X509Oid(OID_SHA256_WITH_RSA),
+            issuer: name.clone(),
+            not_before: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            not_after: Utc.with_ymd_and_hms(2027, 1, 1, 0, 0, 0).unwrap(),
+            subject: name,
+            public_key_algorithm: X509Oid(OID_RSA_ENCRYPTION),
+            public_key: &[],
+            extensions: vec![X509Extension {
+                oid: X509Oid(OID_SUBJECT_ALT_NAME),
+                critical: false,
+                value: &san,
+            }],
+        };
+        assert_eq!(
+            certificate.subject_alt_names(),
+            Ok(vec![
+                X509GeneralName::DnsName("a.test"),
+                X509GeneralName::IpAddress(IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1))),
+                X509GeneralName::Email("a@b.fr"),
+            ])
+        );
+        assert_eq!(certificate.dns_names(), vec!["a.test"]);
+        assert!(certificate.is_self_issued());
+        assert!(certificate.is_valid_at(Utc.with_ymd_and_hms(2026, 10, 18, 0, 0, 0).unwrap()));
+    }
+
+    #[test]
+    fn rejects_a_non_sequence_certificate() {
+        assert_eq!(
+            X509Certificate::try_from(&[0x02, 0x01, 0x00][..]),
+            Err(TlsHandshakeError::InvalidCertificate("Certificate"))
+        );
+    }
+}
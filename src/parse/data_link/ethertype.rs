@@ -23,6 +23,8 @@ impl Ethertype {
             0x8100 => Some("VLAN-tagged frame"),
             0x88CC => Some("LLDP"),
             0x8892 => Some("Profinet"),
+            0x88B8 => Some("GOOSE"),
+            0x88BA => Some("SV"),
             0x88E3 => Some("MRP"),
             0x88F7 => Some("PTP"),
             0x9100 => Some("Q-in-Q"),
@@ -83,6 +85,8 @@ mod tests {
             (0x8100, "VLAN-tagged frame"),
             (0x88CC, "LLDP"),
             (0x8892, "Profinet"),
+            (0x88B8, "GOOSE"),
+            (0x88BA, "SV"),
             (0x88E3, "MRP"),
             (0x88F7, "PTP"),
             (0x9100, "Q-in-Q"),
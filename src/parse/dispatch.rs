@@ -24,15 +24,23 @@
 //!   sonde doive voir en entier (un record TLS chiffre : 5 + 16 Ko + tag).
 
 use super::application::Application;
-use super::application::protocols::ams::AmsPacket;
+use super::application::protocols::ams::{AmsPacket, discovery::AdsDiscoveryPacket};
+use super::application::protocols::bacnet::BacnetPacket;
+use super::application::protocols::bgp::BgpPacket;
 use super::application::protocols::bitcoin::BitcoinPacket;
 use super::application::protocols::dhcp::DhcpPacket;
 use super::application::protocols::dhcpv6::Dhcpv6Packet;
+use super::application::protocols::dnp3::Dnp3Frame;
 use super::application::protocols::dns::DnsPacket;
 use super::application::protocols::ethernet_ip::EtherNetIpPacket;
 use super::application::protocols::ftp::FtpMessage;
 use super::application::protocols::giop::GiopPacket;
-use super::application::protocols::http::HttpRequest;
+use super::application::protocols::http::{HttpRequest, response::HttpResponse};
+use super::application::protocols::iec104::Iec104Apdu;
+use super::application::protocols::kerberos::KerberosMessage;
+use super::application::protocols::knxnet_ip::KnxnetIpPacket;
+use super::application::protocols::ldap::LdapPacket;
+use super::application::protocols::mms::MmsPacket;
 use super::application::protocols::modbus_tcp::ModbusTcpPacket;
 use super::application::protocols::mqtt::MqttPacket;
 use super::application::protocols::netbios::{NbnsPacket, NbssPacket};
@@ -42,7 +50,9 @@ use super::application::protocols::opcua::OpcuaPacket;
 use super::application::protocols::openvpn::OpenVpnPacket;
 use super::application::protocols::postgresql::is_likely_postgresql_payload;
 use super::application::protocols::quic::QuicPacket;
+use super::application::protocols::rip::{RipPacket, RipngPacket};
 use super::application::protocols::s7comm::S7CommPacket;
+use super::application::protocols::smb::SmbPacket;
 use super::application::protocols::smtp::SmtpMessage;
 use super::application::protocols::snmp::SnmpPacket;
 use super::application::protocols::srvloc::SrvlocPacket;
@@ -52,6 +62,7 @@ use super::application::protocols::tls::TlsPacket;
 use super::cotp_from_tpkt;
 use super::transport::Transport;
 use super::transport::protocols::TransportProtocol;
+use crate::checks::application::http2::is_http2_preface;
 use crate::checks::application::quic::is_plausible_short_header;
 
 /// Nombre d'octets soumis aux sondes. Au-dela, seul le prefixe est sonde :
@@ -89,6 +100,7 @@ enum ProbeId {
     Snmp,
     Dhcpv6,
     S7Comm,
+    Mms,
     CotpTpkt,
     Ftp,
     Smtp,
@@ -98,9 +110,19 @@ enum ProbeId {
     Ssdp,
     Nbns,
     Nbss,
+    Smb1,
+    Smb2,
+    KerberosUdp,
+    KerberosTcp,
+    Ldap,
     OpenVpnUdp,
     OpenVpnTcp,
-    Ams,
+    AmsTcp,
+    AmsUdp,
+    Dnp3,
+    Iec104,
+    Bacnet,
+    KnxnetIp,
     FtpUnambiguous,
     SmtpUnambiguous,
     NntpUnambiguous,
@@ -114,6 +136,7 @@ enum ProbeId {
     DnsTcp,
     Tls,
     Ssh,
+    Http2Preface,
     Http,
     Giop,
     Dhcp,
@@ -121,6 +144,9 @@ enum ProbeId {
     ModbusTcp,
     QuicLongHeader,
     Mqtt,
+    Bgp,
+    Rip,
+    Ripng,
 }
 
 fn run_probe(probe: ProbeId, payload: &[u8], full_payload: &[u8]) -> bool {
@@ -128,6 +154,9 @@ fn run_probe(probe: ProbeId, payload: &[u8], full_payload: &[u8]) -> bool {
         ProbeId::Snmp => SnmpPacket::try_from(payload).is_ok(),
         ProbeId::Dhcpv6 => Dhcpv6Packet::try_from(payload).is_ok(),
         ProbeId::S7Comm => S7CommPacket::try_from(payload).is_ok(),
+        // Les controles stricts de COTP d'abord, puis la pile session /
+        // presentation / ACSE jusqu'au PDU MMS.
+        ProbeId::Mms => cotp_from_tpkt(payload).is_some() && MmsPacket::try_from(payload).is_ok(),
         ProbeId::CotpTpkt => cotp_from_tpkt(payload).is_some(),
         ProbeId::Ftp => FtpMessage::try_from(payload).is_ok(),
         ProbeId::Smtp => SmtpMessage::try_from(payload).is_ok(),
@@ -137,6 +166,16 @@ fn run_probe(probe: ProbeId, payload: &[u8], full_payload: &[u8]) -> bool {
         ProbeId::Ssdp => SsdpPacket::try_from(payload).is_ok(),
         ProbeId::Nbns => NbnsPacket::try_from(payload).is_ok(),
         ProbeId::Nbss => NbssPacket::try_from(payload).is_ok(),
+        // Un WRITE ou un READ SMB2 depasse vite PROBE_CAP : la longueur NBSS
+        // est verifiee contre le segment reel.
+        ProbeId::Smb1 => SmbPacket::from_nbss(full_payload).is_ok_and(|packet| packet.is_smb1()),
+        ProbeId::Smb2 => SmbPacket::from_nbss(full_payload).is_ok_and(|packet| !packet.is_smb1()),
+        // Un TGS-REP portant un PAC ou un resultat de recherche LDAP peut
+        // depasser PROBE_CAP : les longueurs BER sont verifiees contre le
+        // payload reel.
+        ProbeId::KerberosUdp => KerberosMessage::try_from(full_payload).is_ok(),
+        ProbeId::KerberosTcp => KerberosMessage::from_tcp_stream(full_payload).is_ok(),
+        ProbeId::Ldap => LdapPacket::try_from(full_payload).is_ok(),
         ProbeId::OpenVpnUdp => OpenVpnPacket::try_from(payload).is_ok(),
         // Le prefixe u16 du record TCP est verifie contre le payload REEL :
         // le plafond de sondage tronquerait a tort un record legitime plus
@@ -145,7 +184,20 @@ fn run_probe(probe: ProbeId, payload: &[u8], full_payload: &[u8]) -> bool {
         ProbeId::FtpUnambiguous => is_unambiguous_ftp_command(payload),
         ProbeId::SmtpUnambiguous => is_unambiguous_smtp_command(payload),
         ProbeId::NntpUnambiguous => is_unambiguous_nntp_command(payload),
-        ProbeId::Ams => AmsPacket::try_from(payload).is_ok(),
+        // AMS/TCP porte un prefixe de 6 octets, verifie contre le payload
+        // reel comme OpenVPN/TCP.
+        ProbeId::AmsTcp => AmsPacket::from_tcp_stream(full_payload).is_ok(),
+        // AMS/UDP porte le paquet nu ; le port 48899 sert aussi a la
+        // decouverte des routeurs ADS.
+        ProbeId::AmsUdp => {
+            AmsPacket::try_from(payload).is_ok() || AdsDiscoveryPacket::try_from(payload).is_ok()
+        }
+        // Les CRC de bloc sont verifies sur la trame entiere, que le
+        // plafond de sondage pourrait couper.
+        ProbeId::Dnp3 => Dnp3Frame::from_stream(full_payload).is_ok(),
+        ProbeId::Iec104 => Iec104Apdu::try_from(payload).is_ok(),
+        ProbeId::Bacnet => BacnetPacket::try_from(payload).is_ok(),
+        ProbeId::KnxnetIp => KnxnetIpPacket::try_from(payload).is_ok(),
         ProbeId::QuicShortHeader => is_plausible_short_header(payload),
         ProbeId::Ntp => NtpPacket::try_from(payload).is_ok(),
         ProbeId::Bitcoin => BitcoinPacket::try_from(payload).is_ok(),
@@ -156,13 +208,19 @@ fn run_probe(probe: ProbeId, payload: &[u8], full_payload: &[u8]) -> bool {
         ProbeId::DnsTcp => DnsPacket::try_from_tcp(payload).is_ok(),
         ProbeId::Tls => TlsPacket::try_from(payload).is_ok(),
         ProbeId::Ssh => SshPacket::try_from(payload).is_ok(),
-        ProbeId::Http => HttpRequest::try_from(payload).is_ok(),
+        ProbeId::Http2Preface => is_http2_preface(payload),
+        ProbeId::Http => {
+            HttpRequest::try_from(payload).is_ok() || HttpResponse::try_from(payload).is_ok()
+        }
         ProbeId::Giop => GiopPacket::try_from(payload).is_ok(),
         ProbeId::Dhcp => DhcpPacket::try_from(payload).is_ok(),
         ProbeId::Srvloc => SrvlocPacket::try_from(payload).is_ok(),
         ProbeId::ModbusTcp => ModbusTcpPacket::try_from(payload).is_ok(),
         ProbeId::QuicLongHeader => QuicPacket::try_from(payload).is_ok(),
         ProbeId::Mqtt => MqttPacket::try_from(payload).is_ok(),
+        ProbeId::Bgp => BgpPacket::try_from(payload).is_ok(),
+        ProbeId::Rip => RipPacket::try_from(payload).is_ok(),
+        ProbeId::Ripng => RipngPacket::try_from(payload).is_ok(),
     }
 }
 
@@ -222,6 +280,9 @@ static RULES: &[Rule] = &[
     // Signature assez forte pour le probing aveugle sur TCP, et doit gagner
     // sur l'etiquette COTP generique, y compris sur le port 102.
     rule("S7Comm", Guard::Tcp, ProbeId::S7Comm),
+    // MMS (IEC 61850 client/serveur) partage le port 102 et gagne aussi sur
+    // COTP des qu'un PDU MMS complet est lisible.
+    port_rule("MMS", Guard::Tcp, is_iso_tsap_tcp_port, ProbeId::Mms),
     port_rule("COTP", Guard::Tcp, is_iso_tsap_tcp_port, ProbeId::CotpTpkt),
     // FTP, SMTP et NNTP partagent la meme forme de reponse ("code SP texte
     // CRLF") et plusieurs commandes : strictement gardes par port.
@@ -281,14 +342,57 @@ static RULES: &[Rule] = &[
     // SSDP est du HTTPU sur le port multicast reserve 1900.
     port_rule("SSDP", Guard::Udp, is_ssdp_udp_port, ProbeId::Ssdp),
     // NetBIOS : name service sur UDP 137, session service sur TCP 139 et
-    // TCP 445 (SMB direct hosting, meme framing a 4 octets).
+    // TCP 445 (SMB direct hosting, meme framing a 4 octets). Le message SMB
+    // porte est reconnu avant l'enveloppe NBSS nue ; SMB1 garde son propre
+    // label pour les alertes d'usage d'un protocole obsolete.
     port_rule("NBNS", Guard::Udp, is_nbns_udp_port, ProbeId::Nbns),
+    port_rule("SMB", Guard::Tcp, is_nbss_tcp_port, ProbeId::Smb1),
+    port_rule("SMB2", Guard::Tcp, is_nbss_tcp_port, ProbeId::Smb2),
     port_rule("NBSS", Guard::Tcp, is_nbss_tcp_port, ProbeId::Nbss),
+    // Active Directory : Kerberos sur UDP et TCP 88 (prefixe de longueur a
+    // bit reserve sur TCP), LDAP sur TCP 389 et 3268 (catalogue global),
+    // CLDAP (localisation des controleurs de domaine) sur UDP 389. Les
+    // sondes exigent un message BER complet et coherent.
+    port_rule(
+        "Kerberos",
+        Guard::Udp,
+        is_kerberos_port,
+        ProbeId::KerberosUdp,
+    ),
+    port_rule(
+        "Kerberos",
+        Guard::Tcp,
+        is_kerberos_port,
+        ProbeId::KerberosTcp,
+    ),
+    port_rule("LDAP", Guard::Tcp, is_ldap_tcp_port, ProbeId::Ldap),
+    port_rule("CLDAP", Guard::Udp, is_cldap_udp_port, ProbeId::Ldap),
     // OpenVPN : datagramme nu sur UDP 1194, prefixe de longueur sur TCP.
     port_rule("OpenVPN", Guard::Udp, is_openvpn_port, ProbeId::OpenVpnUdp),
     port_rule("OpenVPN", Guard::Tcp, is_openvpn_port, ProbeId::OpenVpnTcp),
-    port_rule("AMS", Guard::Tcp, is_ams_tcp_port, ProbeId::Ams),
-    port_rule("AMS", Guard::Udp, is_ams_udp_port, ProbeId::Ams),
+    port_rule("AMS", Guard::Tcp, is_ams_tcp_port, ProbeId::AmsTcp),
+    port_rule("AMS", Guard::Udp, is_ams_udp_port, ProbeId::AmsUdp),
+    // Telecontrole : DNP3 sur TCP et UDP 20000 (CRC d'en-tete exige),
+    // IEC 104 sur TCP 2404 (APCI a format de controle coherent).
+    port_rule("DNP3", Guard::Tcp, is_dnp3_port, ProbeId::Dnp3),
+    port_rule("DNP3", Guard::Udp, is_dnp3_port, ProbeId::Dnp3),
+    port_rule("IEC 104", Guard::Tcp, is_iec104_tcp_port, ProbeId::Iec104),
+    // Immotique : BACnet/IP sur UDP 47808 (longueur BVLC egale au
+    // datagramme), KNXnet/IP sur UDP et TCP 3671 (en-tete de 6 octets,
+    // version 1.0).
+    port_rule("BACnet", Guard::Udp, is_bacnet_udp_port, ProbeId::Bacnet),
+    port_rule(
+        "KNXnet/IP",
+        Guard::Udp,
+        is_knxnet_ip_port,
+        ProbeId::KnxnetIp,
+    ),
+    port_rule(
+        "KNXnet/IP",
+        Guard::Tcp,
+        is_knxnet_ip_port,
+        ProbeId::KnxnetIp,
+    ),
     // QUIC 1-RTT (Short Header) : en-tete volontairement opaque (RFC 9000
     // §17.3), une heuristique gardee par le port est le maximum stateless.
     port_rule(
@@ -306,6 +410,13 @@ static RULES: &[Rule] = &[
     // dns_axfr.pcapng) : chaque transport n'a que sa forme.
     port_rule("DNS", Guard::Tcp, is_dns_port, ProbeId::DnsTcp),
     port_rule("DNS", Guard::Udp, is_dns_port, ProbeId::Dns),
+    // Protocoles de routage, echanges entre routeurs sur leurs ports
+    // standards : la garde de port evite de sonder chaque payload de la
+    // cascade. La sonde reste exigeante (marqueur BGP de 16 octets a 0xff,
+    // entrees RIP de 20 octets a metrique bornee).
+    port_rule("BGP", Guard::Tcp, is_bgp_tcp_port, ProbeId::Bgp),
+    port_rule("RIP", Guard::Udp, is_rip_udp_port, ProbeId::Rip),
+    port_rule("RIPng", Guard::Udp, is_ripng_udp_port, ProbeId::Ripng),
     // --- cascade aveugle, ordre historique de `Application::try_from`,
     // avec les gardes de transport que les RFC imposent : sonder NTP sur du
     // TCP etiquetait "NTP" des Encrypted Alerts TLS (0x15 = LI/VN/mode
@@ -328,6 +439,9 @@ static RULES: &[Rule] = &[
     // SSH avant HTTP : prefixe litteral `SSH-` + version exacte, aucun
     // recouvrement avec les methodes HTTP.
     rule("SSH", Guard::Tcp, ProbeId::Ssh),
+    // La preface h2c commence par `PRI *`, que la sonde HTTP/1.x refuse
+    // (methode inconnue) : aucun recouvrement, l'ordre est indifferent.
+    rule("HTTP/2", Guard::Tcp, ProbeId::Http2Preface),
     rule("HTTP", Guard::Tcp, ProbeId::Http),
     // GIOP/IIOP est transporte par TCP.
     rule("GIOP", Guard::Tcp, ProbeId::Giop),
@@ -368,6 +482,14 @@ pub enum DecodeAsProtocol {
     Ams,
     QuicShortHeader,
     OpenVpn,
+    Bgp,
+    Rip,
+    Ripng,
+    Dnp3,
+    Iec104,
+    Bacnet,
+    KnxnetIp,
+    Mms,
 }
 
 impl DecodeAsProtocol {
@@ -395,12 +517,23 @@ impl DecodeAsProtocol {
             Self::PostgreSql => ("PostgreSQL", Guard::Tcp, ProbeId::Postgresql),
             Self::ModbusTcp => ("ModbusTCP", Guard::Tcp, ProbeId::ModbusTcp),
             Self::EthernetIp => ("EtherNet/IP", Guard::Any, ProbeId::EthernetIp),
-            Self::Ams => ("AMS", Guard::Any, ProbeId::Ams),
+            Self::Ams => match protocol {
+                TransportProtocol::Tcp => ("AMS", Guard::Tcp, ProbeId::AmsTcp),
+                _ => ("AMS", Guard::Udp, ProbeId::AmsUdp),
+            },
             Self::QuicShortHeader => ("QUIC", Guard::Udp, ProbeId::QuicShortHeader),
             Self::OpenVpn => match protocol {
                 TransportProtocol::Tcp => ("OpenVPN", Guard::Tcp, ProbeId::OpenVpnTcp),
                 _ => ("OpenVPN", Guard::Udp, ProbeId::OpenVpnUdp),
             },
+            Self::Bgp => ("BGP", Guard::Tcp, ProbeId::Bgp),
+            Self::Rip => ("RIP", Guard::Udp, ProbeId::Rip),
+            Self::Ripng => ("RIPng", Guard::Udp, ProbeId::Ripng),
+            Self::Dnp3 => ("DNP3", Guard::Any, ProbeId::Dnp3),
+            Self::Iec104 => ("IEC 104", Guard::Tcp, ProbeId::Iec104),
+            Self::Bacnet => ("BACnet", Guard::Udp, ProbeId::Bacnet),
+            Self::KnxnetIp => ("KNXnet/IP", Guard::Any, ProbeId::KnxnetIp),
+            Self::Mms => ("MMS", Guard::Tcp, ProbeId::Mms),
         }
     }
 }
@@ -409,7 +542,7 @@ impl DecodeAsProtocol {
 /// sonder » (pas de payload, payload vide, ou port mDNS sans contenu mDNS) ;
 /// un payload sonde sans succes reste etiquete `"Unknown"`, comme
 /// l'historique `Application::try_from`.
-pub(super) fn classify(
+pub(crate) fn classify(
     transport: &Transport<'_>,
     decode_as: &[(u16, DecodeAsProtocol)],
 ) -> Option<Application> {
@@ -567,6 +700,26 @@ fn is_ams_udp_port(port: Option<u16>) -> bool {
     matches!(port, Some(48899))
 }
 
+/// DNP3 (IEEE 1815) : TCP et UDP 20000.
+fn is_dnp3_port(port: Option<u16>) -> bool {
+    matches!(port, Some(20000))
+}
+
+/// IEC 60870-5-104 : TCP 2404.
+fn is_iec104_tcp_port(port: Option<u16>) -> bool {
+    matches!(port, Some(2404))
+}
+
+/// BACnet/IP (BVLL Annex J) : UDP 47808 (0xBAC0).
+fn is_bacnet_udp_port(port: Option<u16>) -> bool {
+    matches!(port, Some(47808))
+}
+
+/// KNXnet/IP : UDP 3671 (unicast et multicast de routage), TCP 3671.
+fn is_knxnet_ip_port(port: Option<u16>) -> bool {
+    matches!(port, Some(3671))
+}
+
 /// QUIC (HTTP/3) : UDP 443.
 fn is_quic_udp_port(port: Option<u16>) -> bool {
     matches!(port, Some(443))
@@ -610,6 +763,21 @@ fn is_nbss_tcp_port(port: Option<u16>) -> bool {
     matches!(port, Some(139 | 445))
 }
 
+/// Kerberos KDC : UDP et TCP 88 (RFC 4120 §7.2).
+fn is_kerberos_port(port: Option<u16>) -> bool {
+    matches!(port, Some(88))
+}
+
+/// LDAP : TCP 389, et TCP 3268 (catalogue global Active Directory).
+fn is_ldap_tcp_port(port: Option<u16>) -> bool {
+    matches!(port, Some(389 | 3268))
+}
+
+/// CLDAP : UDP 389 (MS-ADTS §6.3.3, ping LDAP des controleurs de domaine).
+fn is_cldap_udp_port(port: Option<u16>) -> bool {
+    matches!(port, Some(389))
+}
+
 /// Un des deux ports touche-t-il une regle terminale de la table (port
 /// reserve par RFC) ? Source unique : la table elle-meme.
 fn matches_terminal_reserved_port(transport: &Transport<'_>) -> bool {
@@ -641,6 +809,21 @@ fn is_dns_port(port: Option<u16>) -> bool {
     matches!(port, Some(53))
 }
 
+/// BGP : TCP 179 (RFC 4271 §8.2.1).
+fn is_bgp_tcp_port(port: Option<u16>) -> bool {
+    matches!(port, Some(179))
+}
+
+/// RIPv1/v2 : UDP 520.
+fn is_rip_udp_port(port: Option<u16>) -> bool {
+    matches!(port, Some(520))
+}
+
+/// RIPng : UDP 521 (RFC 2080).
+fn is_ripng_udp_port(port: Option<u16>) -> bool {
+    matches!(port, Some(521))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -738,6 +921,14 @@ mod tests {
             DecodeAsProtocol::Ams,
             DecodeAsProtocol::QuicShortHeader,
             DecodeAsProtocol::OpenVpn,
+            DecodeAsProtocol::Bgp,
+            DecodeAsProtocol::Rip,
+            DecodeAsProtocol::Ripng,
+            DecodeAsProtocol::Dnp3,
+            DecodeAsProtocol::Iec104,
+            DecodeAsProtocol::Bacnet,
+            DecodeAsProtocol::KnxnetIp,
+            DecodeAsProtocol::Mms,
         ];
         for protocol in all {
             for transport in [TransportProtocol::Tcp, TransportProtocol::Udp] {
@@ -778,6 +969,199 @@ mod tests {
         );
     }
 
+    /// BGP sur TCP 179 et RIP sur UDP 520 : port ET contenu, comme toute
+    /// regle a port.
+    #[test]
+    fn routing_protocols_classify_on_their_ports() {
+        let mut keepalive = vec![0xff; 16];
+        keepalive.extend_from_slice(&[0x00, 0x13, 0x04]);
+        let bgp = Transport {
+            protocol: TransportProtocol::Tcp,
+            source_port: Some(179),
+            destination_port: Some(50_000),
+            payload: Some(&keepalive),
+            details: None,
+        };
+        assert_eq!(
+            classify(&bgp, &[]).map(|a| a.application_protocol),
+            Some("BGP")
+        );
+        // Hors port, le marqueur seul ne suffit pas.
+        assert_ne!(
+            classify(&tcp_transport(&keepalive), &[]).map(|a| a.application_protocol),
+            Some("BGP")
+        );
+
+        let mut response = vec![2, 2, 0, 0, 0, 2, 0, 0, 10, 1, 0, 0, 255, 255, 0, 0];
+        response.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 1]);
+        let rip = Transport {
+            protocol: TransportProtocol::Udp,
+            source_port: Some(520),
+            destination_port: Some(520),
+            payload: Some(&response),
+            details: None,
+        };
+        assert_eq!(
+            classify(&rip, &[]).map(|a| a.application_protocol),
+            Some("RIP")
+        );
+    }
+
+    /// AMS/TCP garde son prefixe de 6 octets ; le port UDP 48899 transporte
+    /// aussi la decouverte des routeurs ADS.
+    #[test]
+    fn ams_tcp_stream_and_discovery_classify_on_their_ports() {
+        let mut stream = vec![0x00, 0x00, 0x20, 0x00, 0x00, 0x00];
+        stream.extend_from_slice(&[5, 1, 2, 3, 1, 1, 0x53, 0x03]);
+        stream.extend_from_slice(&[5, 12, 34, 56, 1, 1, 0x89, 0x80]);
+        stream.extend_from_slice(&[0x04, 0x00, 0x04, 0x00]);
+        stream.extend_from_slice(&[0; 12]);
+        let ams = Transport {
+            protocol: TransportProtocol::Tcp,
+            source_port: Some(50_000),
+            destination_port: Some(48898),
+            payload: Some(&stream),
+            details: None,
+        };
+        assert_eq!(
+            classify(&ams, &[]).map(|a| a.application_protocol),
+            Some("AMS")
+        );
+
+        let mut search = vec![0x03, 0x66, 0x14, 0x71, 1, 0, 0, 0, 1, 0, 0, 0];
+        search.extend_from_slice(&[5, 12, 34, 56, 1, 1, 0x10, 0x27, 0, 0, 0, 0]);
+        let discovery = Transport {
+            protocol: TransportProtocol::Udp,
+            source_port: Some(50_000),
+            destination_port: Some(48899),
+            payload: Some(&search),
+            details: None,
+        };
+        assert_eq!(
+            classify(&discovery, &[]).map(|a| a.application_protocol),
+            Some("AMS")
+        );
+
+        // Chaque transport garde son format : la decouverte n'est pas de
+        // l'AMS/TCP, le prefixe AMS/TCP n'est pas un datagramme AMS/UDP.
+        let discovery_over_tcp = Transport {
+            protocol: TransportProtocol::Tcp,
+            destination_port: Some(48898),
+            ..discovery
+        };
+        assert_ne!(
+            classify(&discovery_over_tcp, &[]).map(|a| a.application_protocol),
+            Some("AMS")
+        );
+        let stream_over_udp = Transport {
+            protocol: TransportProtocol::Udp,
+            destination_port: Some(48899),
+            ..ams
+        };
+        assert_ne!(
+            classify(&stream_over_udp, &[]).map(|a| a.application_protocol),
+            Some("AMS")
+        );
+    }
+
+    #[test]
+    fn telecontrol_protocols_classify_on_their_ports() {
+        // REQUEST_LINK_STATUS DNP3, CRC d'en-tete valide.
+        let link_status = [0x05, 0x64, 0x05, 0xC9, 0x0A, 0x00, 0x01, 0x00, 0xFE, 0xDA];
+        for protocol in [TransportProtocol::Tcp, TransportProtocol::Udp] {
+            let dnp3 = Transport {
+                protocol,
+                source_port: Some(50_000),
+                destination_port: Some(20000),
+                payload: Some(&link_status),
+                details: None,
+            };
+            assert_eq!(
+                classify(&dnp3, &[]).map(|a| a.application_protocol),
+                Some("DNP3")
+            );
+        }
+
+        let startdt = [0x68, 0x04, 0x07, 0x00, 0x00, 0x00];
+        let iec104 = Transport {
+            protocol: TransportProtocol::Tcp,
+            source_port: Some(50_000),
+            destination_port: Some(2404),
+            payload: Some(&startdt),
+            details: None,
+        };
+        assert_eq!(
+            classify(&iec104, &[]).map(|a| a.application_protocol),
+            Some("IEC 104")
+        );
+    }
+
+    #[test]
+    fn building_automation_protocols_classify_on_their_ports() {
+        // Who-Is BACnet diffuse localement (BVLC Original-Broadcast-NPDU).
+        let who_is = [0x81, 0x0B, 0x00, 0x08, 0x01, 0x00, 0x10, 0x08];
+        let bacnet = Transport {
+            protocol: TransportProtocol::Udp,
+            source_port: Some(47808),
+            destination_port: Some(47808),
+            payload: Some(&who_is),
+            details: None,
+        };
+        assert_eq!(
+            classify(&bacnet, &[]).map(|a| a.application_protocol),
+            Some("BACnet")
+        );
+
+        // SEARCH_REQUEST KNXnet/IP, HPAI de retour 192.168.1.10:3671.
+        let search = [
+            0x06, 0x10, 0x02, 0x01, 0x00, 0x0E, 0x08, 0x01, 0xC0, 0xA8, 0x01, 0x0A, 0x0E, 0x57,
+        ];
+        let knx = Transport {
+            protocol: TransportProtocol::Udp,
+            source_port: Some(3671),
+            destination_port: Some(3671),
+            payload: Some(&search),
+            details: None,
+        };
+        assert_eq!(
+            classify(&knx, &[]).map(|a| a.application_protocol),
+            Some("KNXnet/IP")
+        );
+    }
+
+    /// MMS et COTP partagent TCP 102 : un DT portant un PDU MMS lisible est
+    /// etiquete MMS, un DT quelconque reste COTP.
+    #[test]
+    fn mms_wins_over_cotp_on_iso_tsap_port() {
+        let read = hex::decode(concat!(
+            "0300004102f0800100010061343032020103a02da02b020105a426a124a02230",
+            "20a01ea11c1a07494544314c44301a114c4c4e30245354244d6f642473745661",
+            "6c"
+        ))
+        .expect("invalid hex fixture");
+        let mms = Transport {
+            protocol: TransportProtocol::Tcp,
+            source_port: Some(50_000),
+            destination_port: Some(102),
+            payload: Some(&read),
+            details: None,
+        };
+        assert_eq!(
+            classify(&mms, &[]).map(|a| a.application_protocol),
+            Some("MMS")
+        );
+
+        let data = [0x03, 0x00, 0x00, 0x09, 0x02, 0xF0, 0x80, 0xAA, 0xBB];
+        let cotp = Transport {
+            payload: Some(&data),
+            ..mms
+        };
+        assert_eq!(
+            classify(&cotp, &[]).map(|a| a.application_protocol),
+            Some("COTP")
+        );
+    }
+
     /// La memoisation exige un identifiant de sonde par bit d'un u64.
     #[test]
     fn probe_ids_fit_the_memoization_bitmask() {
@@ -826,6 +1210,7 @@ mod tests {
                 "SNMP",
                 "TLS",
                 "SSH",
+                "HTTP/2",
                 "HTTP",
                 "GIOP",
                 "DHCP",
@@ -9,10 +9,11 @@ pub mod protocols;
 use std::convert::TryFrom;
 use std::net::IpAddr;
 
-use crate::errors::internet::InternetError;
+use crate::errors::internet::{InternetError, iec61850::Iec61850Error};
 use crate::parse::data_link::ethertype::Ethertype;
-use crate::parse::internet::protocols::profinet;
+use crate::parse::internet::protocols::{iec61850, profinet};
 use crate::parse::transport::protocols::TransportProtocol;
+use crate::parse::transport::protocols::ah::AhPacket;
 use protocols::arp::ArpPacket;
 use protocols::ipv4;
 use protocols::ipv6;
@@ -22,6 +23,11 @@ use super::transport::Transport;
 use crate::NetworkProtocol;
 use ip_type::IpType;
 
+/// Numeros de protocole IP de l'Authentication Header et de « No Next
+/// Header » (RFC 8200 §4.7), utilises pour traverser AH derriere IPv4.
+const AH_PROTOCOL: u8 = 51;
+const NO_NEXT_HEADER: u8 = 59;
+
 /// Protocol-specific parsed header, kept alongside the flattened summary
 /// fields so consumers never need to re-parse the payload to reach fields
 /// like DSCP/ECN, TTL or the ARP operation.
@@ -34,6 +40,8 @@ pub enum InternetDetails<'a> {
     Ipv4(ipv4::Ipv4Packet<'a>),
     Ipv6(ipv6::Ipv6Packet<'a>),
     Arp(ArpPacket),
+    Goose(iec61850::GoosePacket<'a>),
+    SampledValues(iec61850::SvPacket<'a>),
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -112,10 +120,41 @@ impl<'a> Internet<'a> {
                 profinet::ProfinetPacket::try_from(payload)?;
                 Ok(Self::profinet())
             }
+            // `Iec61850Error` reste propre au decodeur GOOSE/SV : la couche
+            // internet n'en garde que le message.
+            NetworkProtocol::Goose => Ok(Self::from_iec61850(
+                "GOOSE",
+                InternetDetails::Goose(
+                    iec61850::GoosePacket::try_from(payload).map_err(invalid_iec61850)?,
+                ),
+            )),
+            NetworkProtocol::SampledValues => Ok(Self::from_iec61850(
+                "SV",
+                InternetDetails::SampledValues(
+                    iec61850::SvPacket::try_from(payload).map_err(invalid_iec61850)?,
+                ),
+            )),
             NetworkProtocol::Other(_) => Err(InternetError::UnsupportedProtocol),
         }
     }
 
+    /// Parses the IP packet quoted inside an ICMP or ICMPv6 error message.
+    ///
+    /// Same dispatch as [`Internet::try_from_network_parts`] for IPv4 and
+    /// IPv6, but tolerant of the truncation every quotation carries: the
+    /// length fields describe the original packet, not the quoted bytes.
+    /// Any other protocol → [`InternetError::UnsupportedProtocol`].
+    pub fn try_from_quotation(
+        protocol: NetworkProtocol,
+        quoted: &'a [u8],
+    ) -> Result<Self, InternetError> {
+        match protocol {
+            NetworkProtocol::Ipv4 => Ok(Self::from_ipv4(ipv4::Ipv4Packet::from_quotation(quoted)?)),
+            NetworkProtocol::Ipv6 => Ok(Self::from_ipv6(ipv6::Ipv6Packet::from_quotation(quoted)?)),
+            _ => Err(InternetError::UnsupportedProtocol),
+        }
+    }
+
     fn from_arp(arp_packet: ArpPacket) -> Self {
         Internet {
             source: Some(arp_packet.sender_protocol_addr),
@@ -130,10 +169,10 @@ impl<'a> Internet<'a> {
     }
 
     fn from_ipv4(ipv4_packet: ipv4::Ipv4Packet<'a>) -> Self {
-        let payload_protocol = if ipv4_packet.is_fragmented() {
-            None
+        let (payload_protocol, payload) = if ipv4_packet.is_fragmented() {
+            (None, ipv4_packet.payload)
         } else {
-            Some(Transport::transport_from_u8(&ipv4_packet.protocol))
+            Self::skip_ipv4_authentication_header(&ipv4_packet)
         };
 
         Internet {
@@ -143,11 +182,44 @@ impl<'a> Internet<'a> {
             destination_type: Some(IpType::from_addr(&IpAddr::V4(ipv4_packet.dest_addr))),
             protocol_name: "IPv4",
             payload_protocol,
-            payload: ipv4_packet.payload,
+            payload,
             details: Some(InternetDetails::Ipv4(ipv4_packet)),
         }
     }
 
+    /// AH authentifie sans chiffrer (RFC 4302) : comme le fait deja la
+    /// chaine d'extensions IPv6, la couche transport est celle qu'annonce
+    /// l'en-tete AH, pas AH lui-meme. Un AH illisible reste expose tel quel
+    /// (protocole 51, sans details de transport).
+    fn skip_ipv4_authentication_header(
+        ipv4_packet: &ipv4::Ipv4Packet<'a>,
+    ) -> (Option<TransportProtocol>, &'a [u8]) {
+        if ipv4_packet.protocol == AH_PROTOCOL
+            && let Ok(ah) = AhPacket::try_from(ipv4_packet.payload)
+        {
+            let next = (ah.next_header != NO_NEXT_HEADER)
+                .then(|| Transport::transport_from_u8(&ah.next_header));
+            return (next, ah.payload);
+        }
+        (
+            Some(Transport::transport_from_u8(&ipv4_packet.protocol)),
+            ipv4_packet.payload,
+        )
+    }
+
+    /// En-tete AH IPsec du paquet, s'il en porte un : derriere IPv4 (protocole
+    /// 51) ou dans la chaine d'extensions IPv6. Son `next_header` donne le
+    /// protocole qui suit, deja decode en couche transport.
+    pub fn authentication_header(&self) -> Option<AhPacket<'a>> {
+        match self.details.as_ref()? {
+            InternetDetails::Ipv4(ipv4_packet) if ipv4_packet.protocol == AH_PROTOCOL => {
+                AhPacket::try_from(ipv4_packet.payload).ok()
+            }
+            InternetDetails::Ipv6(ipv6_packet) => ipv6_packet.authentication_header(),
+            _ => None,
+        }
+    }
+
     fn from_ipv6(ipv6_packet: ipv6::Ipv6Packet<'a>) -> Self {
         Internet {
             source: Some(IpAddr::V6(ipv6_packet.source_addr)),
@@ -175,6 +247,25 @@ impl<'a> Internet<'a> {
             details: None,
         }
     }
+
+    /// GOOSE et SV n'ont ni adresse IP ni couche transport : seul le PDU
+    /// decode est conserve dans `details`.
+    fn from_iec61850(protocol_name: &'static str, details: InternetDetails<'a>) -> Self {
+        Internet {
+            source: None,
+            source_type: None,
+            destination: None,
+            destination_type: None,
+            protocol_name,
+            payload_protocol: None,
+            payload: &[],
+            details: Some(details),
+        }
+    }
+}
+
+fn invalid_iec61850(error: Iec61850Error) -> InternetError {
+    InternetError::InvalidFormat(error.to_string())
 }
 
 impl<'a> TryFrom<&'a [u8]> for Internet<'a> {
@@ -421,6 +512,27 @@ mod tests {
         assert_eq!(result.payload_protocol, Some(TransportProtocol::Udp));
     }
 
+    #[test]
+    fn test_internet_ipv4_authentication_header_is_traversed() {
+        // IPv4 (proto 51) → AH HMAC-SHA1-96 (24 octets, next header UDP) → UDP.
+        let mut packet = vec![
+            0x45, 0x00, 0x00, 0x34, 0x12, 0x34, 0x00, 0x00, 64, 51, 0x00, 0x00, 10, 0, 0, 1, 10, 0,
+            0, 2,
+        ];
+        packet.extend_from_slice(&[17, 4, 0, 0, 0, 0, 0x02, 0x00, 0, 0, 0, 3]);
+        packet.extend_from_slice(&[0x5a; 12]);
+        packet.extend_from_slice(&[0x00, 0x35, 0x00, 0x35, 0x00, 0x08, 0x00, 0x00]);
+
+        let result = Internet::try_from(packet.as_slice()).unwrap();
+
+        assert_eq!(result.payload_protocol, Some(TransportProtocol::Udp));
+        assert_eq!(result.payload, &packet[44..]);
+        let ah = result.authentication_header().expect("AH is exposed");
+        assert_eq!(ah.spi, 0x200);
+        assert_eq!(ah.sequence_number, 3);
+        assert_eq!(ah.next_header, 17);
+    }
+
     #[test]
     fn test_internet_try_from_ipv4_initial_fragment_skips_transport_protocol() {
         let packet = ipv4_udp_packet(0x2000);
@@ -0,0 +1,456 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! IEC 61850 GOOSE (EtherType 0x88B8) and Sampled Values (EtherType 0x88BA).
+//!
+//! Both are published straight over Ethernet: an 8-byte header (APPID,
+//! length, two reserved fields) followed by a BER-encoded PDU. GOOSE carries
+//! a dataset in the MMS `Data` encoding, decoded on demand by
+//! [`GoosePdu::values`]; SV carries one or more ASDUs whose `sample` field is
+//! read with [`SvAsdu::samples`] for the IEC 61850-9-2 LE profile.
+
+use std::convert::TryFrom;
+
+use crate::{
+    checks::{
+        application::mms::{extract_mms_identifier, extract_mms_tlv, extract_mms_unsigned},
+        internet::iec61850::{
+            GOOSE_ALL_DATA, GOOSE_CONF_REV, GOOSE_DAT_SET, GOOSE_GO_ID, GOOSE_GOCB_REF,
+            GOOSE_NDS_COM, GOOSE_NUM_DAT_SET_ENTRIES, GOOSE_SIMULATION, GOOSE_SQ_NUM, GOOSE_ST_NUM,
+            GOOSE_T, GOOSE_TIME_ALLOWED_TO_LIVE, IEC61850_GOOSE_PDU_TAG,
+            IEC61850_RESERVED1_SIMULATION, IEC61850_SV_PDU_TAG, SV_ASDU_CONF_REV, SV_ASDU_DAT_SET,
+            SV_ASDU_REFR_TM, SV_ASDU_SAMPLE, SV_ASDU_SMP_CNT, SV_ASDU_SMP_MOD, SV_ASDU_SMP_RATE,
+            SV_ASDU_SMP_SYNCH, SV_ASDU_SV_ID, SV_NO_ASDU, SV_SAMPLE_LEN, SV_SEQ_ASDU,
+            extract_iec61850_boolean, extract_iec61850_header, extract_iec61850_u16,
+            sv_smp_mod_name, sv_smp_synch_name,
+        },
+    },
+    errors::{application::mms::MmsError, internet::iec61850::Iec61850Error},
+    parse::application::protocols::{
+        ber::{BerClass, BerElement},
+        bounded_capacity,
+        mms::data::{MmsData, MmsUtcTime},
+    },
+};
+
+/// Smallest encoded ASDU: its SEQUENCE tag and length.
+const SV_MIN_ASDU_LEN: usize = 2;
+
+/// Header shared by GOOSE and SV frames
+///
+/// ```mermaid
+/// ---
+/// title: Iec61850Header
+/// ---
+/// packet-beta
+/// 0-15: "APPID"
+/// 16-31: "Length"
+/// 32-47: "Reserved 1"
+/// 48-63: "Reserved 2"
+/// 64-95: "APDU (BER, variable length)"
+/// ```
+///
+/// The length covers the header and the APDU; Ethernet padding after it is
+/// ignored.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Iec61850Header {
+    pub appid: u16,
+    pub length: u16,
+    pub reserved1: u16,
+    pub reserved2: u16,
+}
+
+impl Iec61850Header {
+    fn parse(data: &[u8]) -> Result<(Self, &[u8]), Iec61850Error> {
+        let ([appid, length, reserved1, reserved2], apdu) = extract_iec61850_header(data)?;
+        Ok((
+            Iec61850Header {
+                appid,
+                length,
+                reserved1,
+                reserved2,
+            },
+            apdu,
+        ))
+    }
+
+    /// Simulation bit of the first reserved field (Edition 2): the frame
+    /// comes from a test set, not from the process.
+    pub fn is_simulated(&self) -> bool {
+        self.reserved1 & IEC61850_RESERVED1_SIMULATION != 0
+    }
+}
+
+/// Reads the APPLICATION-tagged PDU of an APDU.
+fn pdu<'a>(apdu: &'a [u8], tag: u32, field: &'static str) -> Result<&'a [u8], Iec61850Error> {
+    let (tlv, _) = extract_mms_tlv(apdu, field)?;
+    if tlv.class != BerClass::Application || !tlv.constructed || tlv.number != tag {
+        return Err(Iec61850Error::UnexpectedTag {
+            field,
+            tag: tlv.number,
+        });
+    }
+    Ok(tlv.value)
+}
+
+/// Splits a SEQUENCE body into its context-tagged fields.
+fn context_fields<'a>(
+    mut bytes: &'a [u8],
+    field: &'static str,
+) -> Result<Vec<BerElement<'a>>, Iec61850Error> {
+    let mut fields = Vec::new();
+    while !bytes.is_empty() {
+        let (tlv, rest) = extract_mms_tlv(bytes, field)?;
+        if tlv.class != BerClass::Context {
+            return Err(Iec61850Error::UnexpectedTag {
+                field,
+                tag: tlv.number,
+            });
+        }
+        fields.push(tlv);
+        bytes = rest;
+    }
+    Ok(fields)
+}
+
+fn optional<'a>(fields: &[BerElement<'a>], number: u32) -> Option<&'a [u8]> {
+    fields
+        .iter()
+        .find(|tlv| tlv.number == number)
+        .map(|tlv| tlv.value)
+}
+
+fn required<'a>(
+    fields: &[BerElement<'a>],
+    number: u32,
+    field: &'static str,
+) -> Result<&'a [u8], Iec61850Error> {
+    optional(fields, number).ok_or(Iec61850Error::MissingField(field))
+}
+
+fn utc_time(data: &[u8], field: &'static str) -> Result<MmsUtcTime, Iec61850Error> {
+    MmsUtcTime::parse(data).map_err(|_| Iec61850Error::InvalidFieldLength {
+        field,
+        length: data.len(),
+    })
+}
+
+/// GOOSE frame
+///
+/// ```mermaid
+/// ---
+/// title: GoosePacket
+/// ---
+/// packet-beta
+/// 0-63: "Iec61850Header"
+/// 64-71: "goosePdu tag 0x61"
+/// 72-79: "Length"
+/// 80-127: "gocbRef, timeAllowedToLive, datSet, goID, t, stNum, sqNum, ..."
+/// 128-159: "allData (MMS Data)"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoosePacket<'a> {
+    pub header: Iec61850Header,
+    pub pdu: GoosePdu<'a>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for GoosePacket<'a> {
+    type Error = Iec61850Error;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let (header, apdu) = Iec61850Header::parse(data)?;
+        Ok(GoosePacket {
+            header,
+            pdu: GoosePdu::parse(pdu(apdu, IEC61850_GOOSE_PDU_TAG, "goosePdu")?)?,
+        })
+    }
+}
+
+/// Body of a GOOSE message.
+///
+/// `st_num` increases on every change of the dataset and `sq_num` counts
+/// the retransmissions of the same state; a subscriber that sees `sq_num`
+/// restart without `st_num` moving has missed an event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GoosePdu<'a> {
+    pub gocb_ref: &'a str,
+    /// Milliseconds before the next retransmission is due.
+    pub time_allowed_to_live: u32,
+    pub dat_set: &'a str,
+    pub go_id: Option<&'a str>,
+    /// Time of the last state change.
+    pub t: MmsUtcTime,
+    pub st_num: u32,
+    pub sq_num: u32,
+    /// Edition 1 test flag, superseded by [`Iec61850Header::is_simulated`].
+    pub simulation: bool,
+    pub conf_rev: u32,
+    /// Needs commissioning: the dataset is not configured.
+    pub nds_com: bool,
+    pub num_dat_set_entries: u32,
+    /// Encoded dataset members.
+    pub all_data: &'a [u8],
+}
+
+impl<'a> GoosePdu<'a> {
+    /// Decodes the body of a `goosePdu`, tag and length excluded.
+    pub fn parse(body: &'a [u8]) -> Result<Self, Iec61850Error> {
+        let fields = context_fields(body, "goosePdu")?;
+        let unsigned = |number, field| -> Result<u32, Iec61850Error> {
+            Ok(extract_mms_unsigned(
+                required(&fields, number, field)?,
+                field,
+            )?)
+        };
+        let text = |data, field| -> Result<&'a str, Iec61850Error> {
+            Ok(extract_mms_identifier(data, field)?)
+        };
+
+        Ok(GoosePdu {
+            gocb_ref: text(required(&fields, GOOSE_GOCB_REF, "gocbRef")?, "gocbRef")?,
+            time_allowed_to_live: unsigned(GOOSE_TIME_ALLOWED_TO_LIVE, "timeAllowedToLive")?,
+            dat_set: text(required(&fields, GOOSE_DAT_SET, "datSet")?, "datSet")?,
+            go_id: optional(&fields, GOOSE_GO_ID)
+                .map(|data| text(data, "goID"))
+                .transpose()?,
+            t: utc_time(required(&fields, GOOSE_T, "t")?, "t")?,
+            st_num: unsigned(GOOSE_ST_NUM, "stNum")?,
+            sq_num: unsigned(GOOSE_SQ_NUM, "sqNum")?,
+            simulation: optional(&fields, GOOSE_SIMULATION)
+                .map(|data| extract_iec61850_boolean(data, "simulation"))
+                .transpose()?
+                .unwrap_or(false),
+            conf_rev: unsigned(GOOSE_CONF_REV, "confRev")?,
+            nds_com: extract_iec61850_boolean(
+                required(&fields, GOOSE_NDS_COM, "ndsCom")?,
+                "ndsCom",
+            )?,
+            num_dat_set_entries: unsigned(GOOSE_NUM_DAT_SET_ENTRIES, "numDatSetEntries")?,
+            all_data: required(&fields, GOOSE_ALL_DATA, "allData")?,
+        })
+    }
+
+    /// Dataset members, in dataset order.
+    pub fn values(&self) -> Result<Vec<MmsData<'a>>, MmsError> {
+        MmsData::parse_all(self.all_data)
+    }
+}
+
+/// Sampled Values frame
+///
+/// ```mermaid
+/// ---
+/// title: SvPacket
+/// ---
+/// packet-beta
+/// 0-63: "Iec61850Header"
+/// 64-71: "savPdu tag 0x60"
+/// 72-79: "Length"
+/// 80-95: "noASDU"
+/// 96-127: "seqASDU: svID, smpCnt, confRev, smpSynch, sample, ..."
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SvPacket<'a> {
+    pub header: Iec61850Header,
+    pub asdus: Vec<SvAsdu<'a>>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for SvPacket<'a> {
+    type Error = Iec61850Error;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let (header, apdu) = Iec61850Header::parse(data)?;
+        let fields = context_fields(pdu(apdu, IEC61850_SV_PDU_TAG, "savPdu")?, "savPdu")?;
+        let declared = extract_mms_unsigned(required(&fields, SV_NO_ASDU, "noASDU")?, "noASDU")?;
+
+        let mut sequence = required(&fields, SV_SEQ_ASDU, "seqASDU")?;
+        let mut asdus = Vec::with_capacity(bounded_capacity(
+            declared as usize,
+            sequence.len(),
+            SV_MIN_ASDU_LEN,
+        ));
+        while !sequence.is_empty() {
+            let (tlv, rest) = extract_mms_tlv(sequence, "ASDU")?;
+            asdus.push(SvAsdu::parse(tlv.value)?);
+            sequence = rest;
+        }
+        if asdus.len() != declared as usize {
+            return Err(Iec61850Error::AsduCountMismatch {
+                declared,
+                actual: asdus.len(),
+            });
+        }
+        Ok(SvPacket { header, asdus })
+    }
+}
+
+/// One set of samples of a Sampled Values stream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SvAsdu<'a> {
+    pub sv_id: &'a str,
+    pub dat_set: Option<&'a str>,
+    /// Sample counter, reset every second (or on each synchronisation
+    /// pulse) by the merging unit.
+    pub smp_cnt: u16,
+    pub conf_rev: u32,
+    pub refr_tm: Option<MmsUtcTime>,
+    pub smp_synch: u8,
+    pub smp_rate: Option<u16>,
+    /// Encoded dataset.
+    pub sample: &'a [u8],
+    pub smp_mod: Option<u16>,
+}
+
+/// One 9-2 LE sample: an INT32 value and its quality.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SvSample {
+    pub value: i32,
+    pub quality: u32,
+}
+
+impl<'a> SvAsdu<'a> {
+    /// Decodes the body of an ASDU, tag and length excluded.
+    pub fn parse(body: &'a [u8]) -> Result<Self, Iec61850Error> {
+        let fields = context_fields(body, "ASDU")?;
+        let smp_synch = match required(&fields, SV_ASDU_SMP_SYNCH, "smpSynch")? {
+            // Edition 1 : BOOLEAN ; Edition 2 : INTEGER sur un octet.
+            [value] => *value,
+            data => {
+                return Err(Iec61850Error::InvalidFieldLength {
+                    field: "smpSynch",
+                    length: data.len(),
+                });
+            }
+        };
+
+        Ok(SvAsdu {
+            sv_id: extract_mms_identifier(required(&fields, SV_ASDU_SV_ID, "svID")?, "svID")?,
+            dat_set: optional(&fields, SV_ASDU_DAT_SET)
+                .map(|data| extract_mms_identifier(data, "datSet"))
+                .transpose()?,
+            smp_cnt: extract_iec61850_u16(required(&fields, SV_ASDU_SMP_CNT, "smpCnt")?, "smpCnt")?,
+            conf_rev: extract_mms_unsigned(
+                required(&fields, SV_ASDU_CONF_REV, "confRev")?,
+                "confRev",
+            )?,
+            refr_tm: optional(&fields, SV_ASDU_REFR_TM)
+                .map(|data| utc_time(data, "refrTm"))
+                .transpose()?,
+            smp_synch,
+            smp_rate: optional(&fields, SV_ASDU_SMP_RATE)
+                .map(|data| extract_iec61850_u16(data, "smpRate"))
+                .transpose()?,
+            sample: required(&fields, SV_ASDU_SAMPLE, "sample")?,
+            smp_mod: optional(&fields, SV_ASDU_SMP_MOD)
+                .map(|data| extract_iec61850_u16(data, "smpMod"))
+                .transpose()?,
+        })
+    }
+
+    pub fn smp_synch_name(&self) -> &'static str {
+        sv_smp_synch_name(self.smp_synch)
+    }
+
+    pub fn smp_mod_name(&self) -> Option<&'static str> {
+        self.smp_mod.map(sv_smp_mod_name)
+    }
+
+    /// Samples of the 9-2 LE profile (four currents then four voltages,
+    /// each followed by its quality). Trailing bytes that do not form a
+    /// whole sample are ignored.
+    pub fn samples(&self) -> impl Iterator<Item = SvSample> + 'a {
+        self.sample
+            .chunks_exact(SV_SAMPLE_LEN)
+            .map(|chunk| SvSample {
+                value: i32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]),
+                quality: u32::from_be_bytes([chunk[4], chunk[5], chunk[6], chunk[7]]),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_goose_with_dataset() {
+        // APPID 0x0001 ; stNum 3, sqNum 0 ; allData : booleen vrai et
+        // qualite (bit-string 13 bits).
+        let bytes = hex::decode(concat!(
+            "000100620000000061588015494544314c44302f4c4c4e3024474f2467636230",
+            "3181020fa08210494544314c44302f4c4c4e30244453318303676f3184086a1a",
+            "3f00800000188501038601008701008801018901008a0102ab08830101840303",
+            "0000"
+        ))
+        .expect("invalid hex fixture");
+        let goose = GoosePacket::try_from(&bytes[..]).expect("valid GOOSE frame");
+        assert_eq!(goose.header.appid, 1);
+        assert!(!goose.header.is_simulated());
+
+        let pdu = &goose.pdu;
+        assert_eq!(pdu.gocb_ref, "IED1LD0/LLN0$GO$gcb01");
+        assert_eq!(pdu.dat_set, "IED1LD0/LLN0$DS1");
+        assert_eq!(pdu.go_id, Some("go1"));
+        assert_eq!(
+            (pdu.st_num, pdu.sq_num, pdu.time_allowed_to_live),
+            (3, 0, 4000)
+        );
+        assert_eq!(pdu.t.seconds, 0x6A1A_3F00);
+        assert_eq!(pdu.t.subsec_nanos(), 500_000_000);
+        assert_eq!(
+            pdu.values().expect("valid dataset"),
+            vec![
+                MmsData::Boolean(true),
+                MmsData::BitString {
+                    unused_bits: 3,
+                    data: &[0x00, 0x00]
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn reject_goose_without_mandatory_field() {
+        // goosePdu reduit a gocbRef.
+        let bytes = hex::decode("0001000d0000000061038001").expect("invalid hex fixture");
+        assert!(matches!(
+            GoosePacket::try_from(&bytes[..]),
+            Err(Iec61850Error::LengthMismatch { declared: 13, .. })
+        ));
+        let bytes = hex::decode("0001000d000000006103800141").expect("invalid hex fixture");
+        assert_eq!(
+            GoosePacket::try_from(&bytes[..]),
+            Err(Iec61850Error::MissingField("timeAllowedToLive"))
+        );
+    }
+
+    #[test]
+    fn parse_sv_asdu_samples() {
+        // savPdu : une ASDU, deux echantillons 9-2 LE.
+        let bytes = hex::decode(concat!(
+            "4000003600000000602c800101a227302580044d55303182020fa08304000000",
+            "018501028710000003e800000000fffffc1800002000"
+        ))
+        .expect("invalid hex fixture");
+        let sv = SvPacket::try_from(&bytes[..]).expect("valid SV frame");
+        assert_eq!(sv.header.appid, 0x4000);
+        let asdu = &sv.asdus[0];
+        assert_eq!((asdu.sv_id, asdu.smp_cnt, asdu.conf_rev), ("MU01", 4000, 1));
+        assert_eq!(asdu.smp_synch_name(), "global");
+        assert_eq!(
+            asdu.samples().collect::<Vec<_>>(),
+            vec![
+                SvSample {
+                    value: 1000,
+                    quality: 0
+                },
+                SvSample {
+                    value: -1000,
+                    quality: 0x2000
+                }
+            ]
+        );
+    }
+}
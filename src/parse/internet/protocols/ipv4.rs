@@ -129,6 +129,20 @@ impl<'a> TryFrom<&'a [u8]> for Ipv4Packet<'a> {
     /// # Returns
     /// * `Result<Ipv4Packet, Ipv4Error>` - The parsed IPv4 packet or an error
     fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::parse(data, false)
+    }
+}
+
+impl<'a> Ipv4Packet<'a> {
+    /// Parses the IPv4 header quoted by an ICMP error (RFC 792, RFC 1812
+    /// §4.3.2.3). The quotation is usually cut after the first transport
+    /// bytes, so a Total Length larger than the available data is accepted
+    /// and the payload is clamped to what was quoted.
+    pub fn from_quotation(data: &'a [u8]) -> Result<Self, Ipv4Error> {
+        Self::parse(data, true)
+    }
+
+    fn parse(data: &'a [u8], truncated: bool) -> Result<Self, Ipv4Error> {
         validate_ipv4_min_length(data)?;
 
         let version_ihl = data[0];
@@ -144,7 +158,14 @@ impl<'a> TryFrom<&'a [u8]> for Ipv4Packet<'a> {
         let dscp_ecn = data[1];
         let total_length = u16::from_be_bytes([data[2], data[3]]);
 
-        validate_ipv4_total_length(total_length, header_len, data.len())?;
+        // Citation ICMP : seule la borne basse (Total Length >= en-tete)
+        // reste verifiee, la troncature est attendue.
+        let available = if truncated {
+            data.len().max(total_length as usize)
+        } else {
+            data.len()
+        };
+        validate_ipv4_total_length(total_length, header_len, available)?;
 
         let identification = u16::from_be_bytes([data[4], data[5]]);
         let flags_fragment = u16::from_be_bytes([data[6], data[7]]);
@@ -257,4 +278,28 @@ mod tests {
         assert!(packet.is_fragmented());
         assert!(packet.is_non_initial_fragment());
     }
+
+    #[test]
+    fn test_quotation_accepts_truncated_total_length() {
+        // Synthetique : en-tete annoncant 60 octets, seuls 20 + 8 sont cites
+        // (RFC 792).
+        let mut data = vec![
+            0x45, 0x00, 0x00, 0x3c, 0x1c, 0x46, 0x40, 0x00, 0x40, 0x11, 0x00, 0x00, 0xc0, 0xa8,
+            0x01, 0x01, 0xc0, 0xa8, 0x01, 0x02,
+        ];
+        data.extend_from_slice(&[0x04, 0xd2, 0x00, 0x35, 0x00, 0x28, 0x00, 0x00]);
+
+        assert!(matches!(
+            Ipv4Packet::try_from(&data[..]),
+            Err(Ipv4Error::InvalidTotalLength { .. })
+        ));
+
+        let packet = Ipv4Packet::from_quotation(&data[..]).unwrap();
+        assert_eq!(packet.total_length, 60);
+        assert_eq!(packet.payload.len(), 8);
+
+        // La borne basse reste verifiee : Total Length plus court que l'en-tete.
+        data[3] = 0x10;
+        assert!(Ipv4Packet::from_quotation(&data[..]).is_err());
+    }
 }
@@ -10,6 +10,7 @@ use crate::{
     },
     errors::internet::ipv6::Ipv6Error,
     parse::internet::dscp_ecn::{Dscp, Ecn},
+    parse::transport::protocols::ah::AhPacket,
 };
 use std::convert::TryFrom;
 use std::net::Ipv6Addr;
@@ -77,6 +78,21 @@ fn is_extension_header(next_header: u8) -> bool {
     )
 }
 
+/// Length in bytes of an extension header, from its type and its second
+/// byte.
+fn extension_header_length(kind: u8, length_byte: u8) -> usize {
+    match kind {
+        // Fragment header has a fixed 8-byte size (its second byte is
+        // reserved, not a length).
+        FRAGMENT => 8,
+        // AH expresses its length in 4-byte units, minus 2 (RFC 4302).
+        AUTH_HEADER => (length_byte as usize + 2) * 4,
+        // Hop-by-Hop, Routing and Destination Options use 8-byte
+        // units, not counting the first 8 bytes.
+        _ => (length_byte as usize + 1) * 8,
+    }
+}
+
 impl<'a> Ipv6Packet<'a> {
     /// Returns the IP version (should be 6 for IPv6)
     pub fn version(&self) -> u8 {
@@ -112,6 +128,26 @@ impl<'a> Ipv6Packet<'a> {
     pub fn is_fragmented(&self) -> bool {
         self.fragmented
     }
+
+    /// Returns the IPsec Authentication Header of the extension chain, if
+    /// any. The chain was validated at parse time, so the walk only has to
+    /// find it again. The returned `payload` holds the extension headers that
+    /// follow AH, not the upper-layer payload (see [`Ipv6Packet::payload`]).
+    pub fn authentication_header(&self) -> Option<AhPacket<'a>> {
+        let mut current = self.next_header;
+        let mut rest = self.extension_headers;
+        while is_extension_header(current) && rest.len() >= 2 {
+            if current == AUTH_HEADER {
+                // `rest` runs to the end of the chain only: the AH payload
+                // stops where the upper-layer payload starts.
+                return AhPacket::try_from(rest).ok();
+            }
+            let header_len = extension_header_length(current, rest[1]);
+            current = rest[0];
+            rest = rest.get(header_len..)?;
+        }
+        None
+    }
 }
 
 impl<'a> TryFrom<&'a [u8]> for Ipv6Packet<'a> {
@@ -125,6 +161,20 @@ impl<'a> TryFrom<&'a [u8]> for Ipv6Packet<'a> {
     /// # Returns
     /// * `Result<Ipv6Packet, Ipv6Error>` - The parsed IPv6 packet or an error
     fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        Self::parse(data, false)
+    }
+}
+
+impl<'a> Ipv6Packet<'a> {
+    /// Parses the IPv6 packet quoted by an ICMPv6 error (RFC 4443 §2.4 c).
+    /// The quotation is cut to fit the minimum MTU, so a Payload Length
+    /// larger than the available data is accepted and the payload is clamped
+    /// to what was quoted. A truncated extension header is still rejected.
+    pub fn from_quotation(data: &'a [u8]) -> Result<Self, Ipv6Error> {
+        Self::parse(data, true)
+    }
+
+    fn parse(data: &'a [u8], truncated: bool) -> Result<Self, Ipv6Error> {
         validate_ipv6_header_length(data)?;
 
         let version = data[0] >> 4;
@@ -158,7 +208,11 @@ impl<'a> TryFrom<&'a [u8]> for Ipv6Packet<'a> {
             u16::from_be_bytes([data[38], data[39]]),
         );
 
-        let total_expected_len = validate_ipv6_payload_length(data.len(), payload_length)?;
+        let total_expected_len = if truncated {
+            (IPV6_HEADER_LEN + payload_length as usize).min(data.len())
+        } else {
+            validate_ipv6_payload_length(data.len(), payload_length)?
+        };
         let full_payload = &data[IPV6_HEADER_LEN..total_expected_len];
 
         // Walk the extension header chain (RFC 8200 §4) until an upper-layer
@@ -175,16 +229,7 @@ impl<'a> TryFrom<&'a [u8]> for Ipv6Packet<'a> {
                     "truncated extension header (type {current})"
                 )));
             }
-            let header_len = match current {
-                // Fragment header has a fixed 8-byte size (its second byte is
-                // reserved, not a length).
-                FRAGMENT => 8,
-                // AH expresses its length in 4-byte units, minus 2 (RFC 4302).
-                AUTH_HEADER => (rest[1] as usize + 2) * 4,
-                // Hop-by-Hop, Routing and Destination Options use 8-byte
-                // units, not counting the first 8 bytes.
-                _ => (rest[1] as usize + 1) * 8,
-            };
+            let header_len = extension_header_length(current, rest[1]);
             if rest.len() < header_len {
                 return Err(Ipv6Error::InvalidExtensionHeader(format!(
                     "extension header (type {current}) longer than payload: \
@@ -313,6 +358,26 @@ mod tests {
         assert!(!packet.is_fragmented());
     }
 
+    #[test]
+    fn test_authentication_header_is_found_in_the_chain() {
+        // Hop-by-Hop → AH (HMAC-SHA1-96, 24 bytes) → UDP.
+        let mut data = ipv6_header(0, 8 + 24 + 4);
+        data.extend_from_slice(&[51, 0, 0, 0, 0, 0, 0, 0]);
+        data.extend_from_slice(&[17, 4, 0, 0, 0, 0, 0x02, 0x00, 0, 0, 0, 9]);
+        data.extend_from_slice(&[0x5a; 12]);
+        data.extend_from_slice(&[0xde, 0xad, 0xbe, 0xef]);
+
+        let packet = Ipv6Packet::try_from(&data[..]).unwrap();
+        let ah = packet.authentication_header().expect("AH in the chain");
+
+        assert_eq!(packet.transport_protocol, Some(17));
+        assert_eq!(ah.next_header, 17);
+        assert_eq!(ah.spi, 0x200);
+        assert_eq!(ah.sequence_number, 9);
+        assert!(ah.payload.is_empty());
+        assert_eq!(packet.payload, &[0xde, 0xad, 0xbe, 0xef]);
+    }
+
     #[test]
     fn test_chained_extension_headers() {
         // Hop-by-Hop → Destination Options (16 bytes) → TCP.
@@ -362,6 +427,19 @@ mod tests {
         assert!(matches!(result, Err(Ipv6Error::InvalidExtensionHeader(_))));
     }
 
+    #[test]
+    fn test_quotation_clamps_payload_length() {
+        // Synthetique : UDP annoncant 512 octets, seuls les 8 premiers cites.
+        let mut data = ipv6_header(17, 512);
+        data.extend_from_slice(&[0x04, 0xd2, 0x27, 0x0f, 0x02, 0x00, 0x00, 0x00]);
+
+        let packet = Ipv6Packet::from_quotation(&data[..]).unwrap();
+
+        assert_eq!(packet.payload_length, 512);
+        assert_eq!(packet.transport_protocol, Some(17));
+        assert_eq!(packet.payload.len(), 8);
+    }
+
     #[test]
     fn test_invalid_payload_length() {
         // Packet with payload length longer than actual data
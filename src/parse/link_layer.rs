@@ -25,6 +25,8 @@ pub enum NetworkProtocol {
     Ipv6,
     Arp,
     Profinet,
+    Goose,
+    SampledValues,
     Other(u16),
 }
 
@@ -35,6 +37,8 @@ impl NetworkProtocol {
             0x86dd => Self::Ipv6,
             0x0806 => Self::Arp,
             0x8892 => Self::Profinet,
+            0x88b8 => Self::Goose,
+            0x88ba => Self::SampledValues,
             other => Self::Other(other),
         }
     }
@@ -47,6 +51,8 @@ impl fmt::Display for NetworkProtocol {
             Self::Ipv6 => f.write_str("IPv6"),
             Self::Arp => f.write_str("ARP"),
             Self::Profinet => f.write_str("Profinet"),
+            Self::Goose => f.write_str("GOOSE"),
+            Self::SampledValues => f.write_str("SV"),
             Self::Other(value) => write!(f, "0x{value:04X}"),
         }
     }
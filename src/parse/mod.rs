@@ -36,6 +36,7 @@ use data_link::stp::BpduPacket;
 use internet::Internet;
 use serde::Serialize;
 use transport::Transport;
+use transport::protocols::TransportProtocol;
 
 use crate::{
     LinkLayer, LinkType, NetworkProtocol, ParseError,
@@ -45,11 +46,12 @@ use crate::{
 
 pub mod application;
 pub mod data_link;
-mod dispatch;
+pub(crate) mod dispatch;
 pub use dispatch::DecodeAsProtocol;
 pub mod internet;
 mod link;
 pub mod link_layer;
+mod quotation;
 pub mod transport;
 pub(crate) mod tunnel;
 
@@ -138,6 +140,21 @@ pub enum CorruptedLayerKind {
     Transport,
 }
 
+/// What the [`PacketFlow::inner`] flow is relative to its outer flow, as
+/// returned by [`PacketFlow::inner_kind`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum InnerKind {
+    /// The outer flow is a tunnel (CAPWAP, GRE, IP-in-IP…) carrying the inner
+    /// packet: the inner flow is a live conversation of its own.
+    Encapsulation,
+    /// The outer flow is an ICMP/ICMPv6 error quoting the start of the
+    /// packet that triggered it. The inner flow is a truncated copy: its
+    /// addresses and ports identify the offending flow, but its lengths do
+    /// not match its bytes and no application is detected on it.
+    IcmpQuotation,
+}
+
 /// A layer that was **recognized** (by the EtherType or the IP protocol
 /// field) but whose bytes are invalid.
 ///
@@ -192,7 +209,9 @@ pub struct PacketFlow<'a> {
     /// Encapsulated packet (optional). When this flow is a tunnel (e.g. CAPWAP,
     /// carried as the application protocol), `inner` holds the packet parsed
     /// from inside the tunnel — recursively, from the outermost to the
-    /// innermost. See [`PacketFlow::flatten`].
+    /// innermost. See [`PacketFlow::flatten`]. When this flow is an ICMP
+    /// error, `inner` holds the quoted datagram instead; see
+    /// [`PacketFlow::icmp_quotation`].
     #[serde(skip_serializing_if = "Option::is_none")]
     pub inner: Option<Box<PacketFlow<'a>>>,
 
@@ -276,19 +295,50 @@ impl<'a> PacketFlow<'a> {
     /// Returns this flow and every encapsulated flow, from the outermost to the
     /// innermost. A non-tunneled packet yields a single entry; a tunneled one
     /// yields several (outer tunnel + inner conversation(s)).
+    ///
+    /// A datagram quoted by an ICMP error is not a conversation carried by the
+    /// packet and is left out; reach it through [`PacketFlow::icmp_quotation`].
     pub fn flatten(&self) -> Vec<&PacketFlow<'a>> {
         let mut out = Vec::new();
         let mut current = self;
         loop {
             out.push(current);
             match current.inner.as_deref() {
-                Some(next) => current = next,
-                None => break,
+                Some(next) if current.inner_kind() != Some(InnerKind::IcmpQuotation) => {
+                    current = next
+                }
+                _ => break,
             }
         }
         out
     }
 
+    /// How [`PacketFlow::inner`] relates to this flow: a tunnel's
+    /// encapsulated packet or the datagram quoted by an ICMP error. `None`
+    /// exactly when `inner` is.
+    ///
+    /// ICMP carries no tunnel, so an inner flow under an ICMP/ICMPv6
+    /// transport is always a quotation.
+    pub fn inner_kind(&self) -> Option<InnerKind> {
+        self.inner.as_ref()?;
+        match self.transport.as_ref().map(|transport| &transport.protocol) {
+            Some(TransportProtocol::Icmp | TransportProtocol::Ipv6Icmp) => {
+                Some(InnerKind::IcmpQuotation)
+            }
+            _ => Some(InnerKind::Encapsulation),
+        }
+    }
+
+    /// The datagram quoted by this ICMP/ICMPv6 error, parsed as a truncated
+    /// flow (IP header, then ports when the quotation reaches them). `None`
+    /// when this flow is not an ICMP error or the quotation is unreadable.
+    pub fn icmp_quotation(&self) -> Option<&PacketFlow<'a>> {
+        match self.inner_kind() {
+            Some(InnerKind::IcmpQuotation) => self.inner.as_deref(),
+            _ => None,
+        }
+    }
+
     /// Parses the internet layer from the data-link layer, dispatching on the
     /// EtherType. An unknown EtherType yields `(None, None)`; a known
     /// EtherType with a corrupt payload yields `(None, Some(corruption))` —
@@ -335,7 +385,8 @@ impl<'a> PacketFlow<'a> {
 
     /// If the transport layer encapsulates a tunnel (e.g. CAPWAP), record the
     /// tunnel name as THIS flow's application protocol and parse the inner
-    /// packet into `inner`. Otherwise, best-effort application detection.
+    /// packet into `inner`. If it is an ICMP error, parse the quoted datagram
+    /// into `inner` instead. Otherwise, best-effort application detection.
     #[inline(always)]
     fn parse_l7_and_inner(
         internet: Option<&Internet<'a>>,
@@ -358,20 +409,27 @@ impl<'a> PacketFlow<'a> {
             );
         }
 
-        match transport {
-            Some(transport) => match tunnel::detect_inner(transport, depth, decode_as) {
-                Some((tunnel_name, inner_flow)) => (
-                    Some(Application {
-                        application_protocol: tunnel_name,
-                    }),
-                    Some(Box::new(inner_flow)),
-                ),
-                None => (
-                    Self::parse_application_from_transport(transport, decode_as),
-                    None,
-                ),
-            },
-            None => (None, None),
+        let Some(transport) = transport else {
+            return (None, None);
+        };
+
+        // Erreur ICMP : le datagramme cite n'est pas transporte, ICMP reste
+        // sans couche applicative.
+        if let Some(quoted_flow) = quotation::detect_quotation(transport, depth) {
+            return (None, Some(Box::new(quoted_flow)));
+        }
+
+        match tunnel::detect_inner(transport, depth, decode_as) {
+            Some((tunnel_name, inner_flow)) => (
+                Some(Application {
+                    application_protocol: tunnel_name,
+                }),
+                Some(Box::new(inner_flow)),
+            ),
+            None => (
+                Self::parse_application_from_transport(transport, decode_as),
+                None,
+            ),
         }
     }
 
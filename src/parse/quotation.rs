@@ -0,0 +1,117 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! ICMP error quotations.
+//!
+//! An ICMP error (destination unreachable, time exceeded, parameter problem,
+//! packet too big…) quotes the start of the datagram that triggered it: the IP
+//! header and the first transport bytes. Parsing that quotation back into a
+//! [`PacketFlow`] lets a consumer correlate the error with the flow it
+//! reports on (addresses, protocol, ports).
+//!
+//! Unlike a tunnel, the quoted packet is **not** carried: it is a truncated
+//! copy of a packet that already crossed the network. The inner flow is
+//! therefore marked [`InnerKind::IcmpQuotation`](super::InnerKind), length
+//! fields are not enforced, and no application detection is attempted on it.
+
+use super::PacketFlow;
+use super::internet::Internet;
+use super::link::RawIpDecoder;
+use super::transport::protocols::icmp::IcmpBody;
+use super::transport::protocols::icmpv6::Icmpv6Body;
+use super::transport::{Transport, TransportDetails};
+use super::tunnel::MAX_TUNNEL_DEPTH;
+use crate::LinkType;
+
+/// Parses the datagram quoted by an ICMP/ICMPv6 error carried by `transport`.
+///
+/// Returns `None` (graceful degradation, never an error) when `transport` is
+/// not an ICMP error, the quoted IP header cannot be read, or the nesting
+/// guard shared with tunnels is reached. An unreadable quoted transport
+/// header only leaves the inner `transport` to `None`.
+pub(crate) fn detect_quotation<'a>(transport: &Transport<'a>, depth: u8) -> Option<PacketFlow<'a>> {
+    if depth + 1 >= MAX_TUNNEL_DEPTH {
+        return None;
+    }
+
+    // ICMPv4 cite un datagramme IPv4, ICMPv6 un paquet IPv6 : le decodeur
+    // raw-IP refuse une citation dont le quartet de version contredit la
+    // famille du message d'erreur.
+    let (link_type, quoted) = match transport.details.as_ref()? {
+        TransportDetails::Icmp(icmp) => match &icmp.body {
+            IcmpBody::Error(report) => (LinkType::IPV4, report.original_datagram),
+            _ => return None,
+        },
+        TransportDetails::Icmpv6(icmpv6) => match &icmpv6.body {
+            Icmpv6Body::Error(report) => (LinkType::IPV6, report.invoking_packet),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let (data_link, network_protocol, network_payload) = RawIpDecoder::decode_as(link_type, quoted)
+        .ok()?
+        .into_parts();
+    let internet = Internet::try_from_quotation(network_protocol, network_payload).ok()?;
+    let transport = Transport::try_from_quotation(internet.payload_protocol, internet.payload).ok();
+
+    // Pas de couche applicative ni de recursion : une citation ne porte que
+    // quelques octets de transport, et une erreur ICMP citant elle-meme une
+    // erreur ICMP est interdite (RFC 1122 §3.2.2).
+    Some(PacketFlow {
+        data_link,
+        internet: Some(internet),
+        transport,
+        application: None,
+        inner: None,
+        corrupted: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+    use crate::parse::transport::protocols::TransportProtocol;
+
+    /// Synthetique : Time Exceeded (type 11) citant un SYN TCP
+    /// 10.0.0.1:49152 -> 10.0.0.2:443 coupe apres le numero de sequence,
+    /// comme l'autorise RFC 792 (en-tete IP + 8 octets).
+    fn time_exceeded_quoting_tcp() -> Vec<u8> {
+        let mut packet = vec![
+            0x45, 0x00, 0x00, 0x38, 0x00, 0x01, 0x00, 0x00, 0x40, 0x01, 0x00, 0x00, 10, 0, 0, 254,
+            10, 0, 0, 1, // IPv4 externe, protocole 1
+            0x0b, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // ICMP time exceeded
+            0x45, 0x00, 0x00, 0x3c, 0x00, 0x02, 0x40, 0x00, 0x01, 0x06, 0x00, 0x00, 10, 0, 0, 1,
+            10, 0, 0, 2, // IPv4 cite, protocole 6, 60 octets annonces
+        ];
+        packet.extend_from_slice(&[0xc0, 0x00, 0x01, 0xbb, 0x12, 0x34, 0x56, 0x78]);
+        packet
+    }
+
+    #[test]
+    fn truncated_tcp_quotation_keeps_the_ports() {
+        let packet = time_exceeded_quoting_tcp();
+        let flow = parse(LinkType::IPV4, &packet).expect("raw IPv4 decodes");
+
+        let quoted = flow.icmp_quotation().expect("quoted datagram");
+        let transport = quoted.transport.as_ref().expect("ports are quoted");
+        assert_eq!(transport.protocol, TransportProtocol::Tcp);
+        assert_eq!(transport.source_port, Some(49152));
+        assert_eq!(transport.destination_port, Some(443));
+        assert!(transport.details.is_none());
+        assert!(transport.payload.is_none());
+    }
+
+    #[test]
+    fn quotation_respects_the_nesting_guard() {
+        let packet = time_exceeded_quoting_tcp();
+        let flow = parse(LinkType::IPV4, &packet).expect("raw IPv4 decodes");
+        let transport = flow.transport.as_ref().expect("ICMP transport");
+
+        assert!(detect_quotation(transport, 0).is_some());
+        assert!(detect_quotation(transport, MAX_TUNNEL_DEPTH - 1).is_none());
+    }
+}
@@ -8,7 +8,8 @@ use std::convert::TryFrom;
 pub mod protocols;
 
 use protocols::{
-    TransportProtocol, icmp::IcmpPacket, icmpv6::Icmpv6Packet, tcp::TcpPacket, udp::UdpPacket,
+    TransportProtocol, esp::EspPacket, icmp::IcmpPacket, icmpv6::Icmpv6Packet, igmp::IgmpPacket,
+    ospf::OspfPacket, pim::PimPacket, tcp::TcpPacket, udp::UdpPacket, vrrp::VrrpPacket,
 };
 use serde::Serialize;
 
@@ -18,7 +19,7 @@ use crate::errors::transport::TransportError;
 /// fields so consumers never need to re-parse the transport payload.
 ///
 /// `None` on [`Transport`] means the protocol has no dedicated parser yet
-/// (ICMP, IGMP, …) or the value was built by hand.
+/// (SCTP, GRE, …) or the value was built by hand.
 #[non_exhaustive]
 #[derive(Debug, Clone)]
 pub enum TransportDetails<'a> {
@@ -26,6 +27,11 @@ pub enum TransportDetails<'a> {
     Udp(UdpPacket<'a>),
     Icmp(IcmpPacket<'a>),
     Icmpv6(Icmpv6Packet<'a>),
+    Igmp(IgmpPacket<'a>),
+    Pim(PimPacket<'a>),
+    Esp(EspPacket<'a>),
+    Ospf(OspfPacket<'a>),
+    Vrrp(VrrpPacket<'a>),
 }
 
 /// Represents a transport layer packet (UDP, TCP, etc.)
@@ -53,6 +59,36 @@ impl<'a> Transport<'a> {
         TransportProtocol::from_u8(*protocol)
     }
 
+    /// Parses the first transport bytes quoted by an ICMP error.
+    ///
+    /// An ICMPv4 error only guarantees 8 bytes past the IP header (RFC 792):
+    /// a TCP header is then cut after its sequence number, and a UDP header
+    /// announces a length the quotation no longer holds. When TCP or UDP
+    /// cannot be parsed in full, the ports (first 4 bytes) are still kept,
+    /// with `payload` and `details` left to `None`. Other protocols go
+    /// through [`Transport::try_from_parts`].
+    pub fn try_from_quotation(
+        payload_protocol: Option<TransportProtocol>,
+        quoted: &'a [u8],
+    ) -> Result<Self, TransportError> {
+        match payload_protocol {
+            Some(protocol @ (TransportProtocol::Tcp | TransportProtocol::Udp)) => {
+                if let Ok(transport) = Self::try_from_parts(payload_protocol, quoted) {
+                    return Ok(transport);
+                }
+                let ports = quoted.get(..4).ok_or(TransportError::PacketTooShort)?;
+                Ok(Transport {
+                    protocol,
+                    source_port: Some(u16::from_be_bytes([ports[0], ports[1]])),
+                    destination_port: Some(u16::from_be_bytes([ports[2], ports[3]])),
+                    payload: None,
+                    details: None,
+                })
+            }
+            _ => Self::try_from_parts(payload_protocol, quoted),
+        }
+    }
+
     pub fn try_from_parts(
         payload_protocol: Option<TransportProtocol>,
         payload: &'a [u8],
@@ -107,6 +143,55 @@ impl<'a> Transport<'a> {
                     .ok()
                     .map(TransportDetails::Icmpv6),
             }),
+            // IGMP (2) et PIM (103) : protocoles de controle multicast, sans
+            // port, traites comme ICMP. Le Register PIM encapsule un paquet
+            // IP, expose via `details` sans etre empile.
+            Some(TransportProtocol::Igmp) => Ok(Transport {
+                protocol: TransportProtocol::Igmp,
+                source_port: None,
+                destination_port: None,
+                payload: None,
+                details: IgmpPacket::try_from(payload)
+                    .ok()
+                    .map(TransportDetails::Igmp),
+            }),
+            Some(TransportProtocol::Pim) => Ok(Transport {
+                protocol: TransportProtocol::Pim,
+                source_port: None,
+                destination_port: None,
+                payload: None,
+                details: PimPacket::try_from(payload).ok().map(TransportDetails::Pim),
+            }),
+            // ESP : seuls SPI et sequence sont en clair. Le reste n'est lisible
+            // qu'avec les cles de l'association de securite (feature
+            // `decrypt`), jamais livre au probing applicatif.
+            Some(TransportProtocol::Esp) => Ok(Transport {
+                protocol: TransportProtocol::Esp,
+                source_port: None,
+                destination_port: None,
+                payload: None,
+                details: EspPacket::try_from(payload).ok().map(TransportDetails::Esp),
+            }),
+            // OSPF (89) et VRRP (112) : protocoles de routage et de
+            // redondance portes directement par IP, sans port.
+            Some(TransportProtocol::Ospfigp) => Ok(Transport {
+                protocol: TransportProtocol::Ospfigp,
+                source_port: None,
+                destination_port: None,
+                payload: None,
+                details: OspfPacket::try_from(payload)
+                    .ok()
+                    .map(TransportDetails::Ospf),
+            }),
+            Some(TransportProtocol::Vrrp) => Ok(Transport {
+                protocol: TransportProtocol::Vrrp,
+                source_port: None,
+                destination_port: None,
+                payload: None,
+                details: VrrpPacket::try_from(payload)
+                    .ok()
+                    .map(TransportDetails::Vrrp),
+            }),
             Some(other) => Ok(Transport {
                 protocol: other,
                 source_port: None,
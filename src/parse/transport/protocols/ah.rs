@@ -0,0 +1,114 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Parseur de l'Authentication Header IPsec (IP protocole 51, RFC 4302).
+//!
+//! AH authentifie sans chiffrer : le protocole suivant reste lisible. La
+//! couche internet le traverse donc comme une extension IPv6 — le transport
+//! du flux est celui qu'annonce `next_header`, et l'en-tete AH reste
+//! accessible via [`crate::Internet::authentication_header`].
+
+use std::convert::TryFrom;
+
+use crate::{
+    checks::transport::ah::{AH_FIXED_HEADER_LENGTH, extract_ah_header_length, validate_ah_length},
+    errors::transport::ah::AhError,
+};
+
+/// En-tete AH. L'ICV a la taille que fixe l'algorithme d'integrite (12
+/// octets pour HMAC-SHA1-96, 16 pour HMAC-SHA-256-128), deduite ici du
+/// champ Payload Len.
+///
+/// ```mermaid
+/// ---
+/// title: AhPacket
+/// ---
+/// packet-beta
+/// 0-7: "Next Header u8"
+/// 8-15: "Payload Len u8"
+/// 16-31: "Reserved u16"
+/// 32-63: "Security Parameters Index u32"
+/// 64-95: "Sequence Number u32"
+/// 96-127: "Integrity Check Value (variable)"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AhPacket<'a> {
+    /// Numero de protocole IP de ce qui suit l'en-tete AH.
+    pub next_header: u8,
+    /// Valeur brute : longueur de l'en-tete en mots de 32 bits, moins 2.
+    pub payload_length: u8,
+    pub reserved: u16,
+    pub spi: u32,
+    pub sequence_number: u32,
+    pub icv: &'a [u8],
+    /// Octets suivant l'en-tete AH, a decoder selon `next_header`.
+    pub payload: &'a [u8],
+}
+
+impl AhPacket<'_> {
+    /// Longueur totale de l'en-tete AH, ICV compris.
+    pub fn header_length(&self) -> usize {
+        AH_FIXED_HEADER_LENGTH + self.icv.len()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for AhPacket<'a> {
+    type Error = AhError;
+
+    fn try_from(payload: &'a [u8]) -> Result<Self, AhError> {
+        validate_ah_length(payload, AH_FIXED_HEADER_LENGTH)?;
+        let header_length = extract_ah_header_length(payload[1])?;
+        validate_ah_length(payload, header_length)?;
+
+        Ok(AhPacket {
+            next_header: payload[0],
+            payload_length: payload[1],
+            reserved: u16::from_be_bytes([payload[2], payload[3]]),
+            spi: u32::from_be_bytes([payload[4], payload[5], payload[6], payload[7]]),
+            sequence_number: u32::from_be_bytes([payload[8], payload[9], payload[10], payload[11]]),
+            icv: &payload[AH_FIXED_HEADER_LENGTH..header_length],
+            payload: &payload[header_length..],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Synthetique : AH HMAC-SHA1-96 devant un en-tete UDP.
+    #[test]
+    fn parses_ah_and_exposes_next_header_payload() {
+        let mut raw = vec![
+            17, 4, 0x00, 0x00, // UDP, 24 octets d'en-tete
+            0x00, 0x00, 0x02, 0x00, // SPI 0x200
+            0x00, 0x00, 0x00, 0x07, // sequence 7
+        ];
+        raw.extend_from_slice(&[0x5a; 12]);
+        raw.extend_from_slice(&[0x00, 0x35, 0x00, 0x35, 0x00, 0x08, 0x00, 0x00]);
+        let ah = AhPacket::try_from(raw.as_slice()).expect("ah parses");
+
+        assert_eq!(ah.next_header, 17);
+        assert_eq!(ah.spi, 0x200);
+        assert_eq!(ah.sequence_number, 7);
+        assert_eq!(ah.icv, &[0x5a; 12]);
+        assert_eq!(ah.header_length(), 24);
+        assert_eq!(ah.payload.len(), 8);
+    }
+
+    /// Synthetique : Payload Len annoncant un ICV absent.
+    #[test]
+    fn rejects_truncated_icv() {
+        let raw = [17, 4, 0, 0, 0, 0, 2, 0, 0, 0, 0, 7, 0x5a, 0x5a];
+
+        assert_eq!(
+            AhPacket::try_from(&raw[..]),
+            Err(AhError::InvalidLength {
+                expected: 24,
+                actual: 14
+            })
+        );
+    }
+}
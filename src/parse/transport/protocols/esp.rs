@@ -0,0 +1,92 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Parseur ESP (IP protocole 50, RFC 4303).
+//!
+//! Atteint par le numero de protocole IP. Tout ce qui suit le numero de
+//! sequence est chiffre : le parseur s'arrete la et expose ces octets tels
+//! quels. Le dechiffrement, opt-in, vit dans `crate::decrypt::esp` (feature
+//! `decrypt`) et demande les cles de l'association de securite.
+
+use std::convert::TryFrom;
+
+use crate::{
+    checks::transport::esp::{ESP_HEADER_LENGTH, validate_esp_length, validate_esp_spi},
+    errors::transport::esp::EspError,
+};
+
+/// Paquet ESP vu sans cle.
+///
+/// ```mermaid
+/// ---
+/// title: EspPacket
+/// ---
+/// packet-beta
+/// 0-31: "Security Parameters Index u32"
+/// 32-63: "Sequence Number u32"
+/// 64-95: "IV, donnees chiffrees, padding, trailer et ICV (variable)"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EspPacket<'a> {
+    pub spi: u32,
+    pub sequence_number: u32,
+    /// IV, texte chiffre et ICV, zero-copy. Leur decoupage depend de
+    /// l'algorithme de l'association de securite.
+    pub encrypted_data: &'a [u8],
+}
+
+impl<'a> TryFrom<&'a [u8]> for EspPacket<'a> {
+    type Error = EspError;
+
+    fn try_from(payload: &'a [u8]) -> Result<Self, EspError> {
+        validate_esp_length(payload)?;
+
+        let spi = u32::from_be_bytes([payload[0], payload[1], payload[2], payload[3]]);
+        validate_esp_spi(spi)?;
+
+        Ok(EspPacket {
+            spi,
+            sequence_number: u32::from_be_bytes([payload[4], payload[5], payload[6], payload[7]]),
+            encrypted_data: &payload[ESP_HEADER_LENGTH..],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Synthetique : SPI 0x00001001, sequence 1, quatre octets chiffres.
+    #[test]
+    fn parses_spi_and_sequence_number() {
+        let raw = [
+            0x00, 0x00, 0x10, 0x01, 0x00, 0x00, 0x00, 0x01, 0xde, 0xad, 0xbe, 0xef,
+        ];
+        let packet = EspPacket::try_from(&raw[..]).expect("esp parses");
+
+        assert_eq!(packet.spi, 0x1001);
+        assert_eq!(packet.sequence_number, 1);
+        assert_eq!(packet.encrypted_data, &[0xde, 0xad, 0xbe, 0xef]);
+    }
+
+    #[test]
+    fn rejects_reserved_spi() {
+        assert_eq!(
+            EspPacket::try_from(&[0, 0, 0, 0, 0, 0, 0, 1][..]),
+            Err(EspError::ReservedSpi)
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_header() {
+        assert_eq!(
+            EspPacket::try_from(&[0, 0, 0x10, 0x01, 0][..]),
+            Err(EspError::InvalidLength {
+                expected: 8,
+                actual: 5
+            })
+        );
+    }
+}
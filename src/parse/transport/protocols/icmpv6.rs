@@ -17,11 +17,17 @@ use crate::{
     checks::transport::icmpv6::{
         ICMPV6_ECHO_HEADER_LENGTH, ICMPV6_ERROR_HEADER_LENGTH, ICMPV6_HEADER_LENGTH,
         ICMPV6_NEIGHBOR_HEADER_LENGTH, ICMPV6_ROUTER_ADVERTISEMENT_HEADER_LENGTH,
-        ICMPV6_ROUTER_SOLICITATION_HEADER_LENGTH, extract_icmpv6_code, validate_icmpv6_echo_length,
-        validate_icmpv6_error_length, validate_icmpv6_min_length, validate_icmpv6_neighbor_length,
-        validate_icmpv6_router_advertisement_length, validate_icmpv6_router_solicitation_length,
+        ICMPV6_ROUTER_SOLICITATION_HEADER_LENGTH, ND_DNS_OPTION_HEADER_LENGTH, ND_MTU_LENGTH,
+        ND_OPTION_HEADER_LENGTH, ND_OPTION_UNIT, ND_PREFIX_INFORMATION_LENGTH, ND_RDNSS_MIN_LENGTH,
+        ND_ROUTE_INFORMATION_MAX_LENGTH, extract_icmpv6_code, extract_nd_option,
+        validate_icmpv6_echo_length, validate_icmpv6_error_length, validate_icmpv6_min_length,
+        validate_icmpv6_neighbor_length, validate_icmpv6_router_advertisement_length,
+        validate_icmpv6_router_solicitation_length, validate_nd_option_length,
+        validate_nd_option_range,
     },
     errors::transport::icmpv6::Icmpv6Error,
+    parse::application::protocols::dns::utils::name::parse_dns_name,
+    parse::data_link::mac_addres::MacAddress,
 };
 
 /// Types de message ICMPv6 interpretes (registre IANA « ICMPv6 Parameters »).
@@ -36,6 +42,17 @@ const ROUTER_ADVERTISEMENT_TYPE: u8 = 134;
 const NEIGHBOR_SOLICITATION_TYPE: u8 = 135;
 const NEIGHBOR_ADVERTISEMENT_TYPE: u8 = 136;
 
+/// Types d'option de decouverte de voisins interpretes (registre IANA
+/// « IPv6 Neighbor Discovery Option Formats »).
+const SOURCE_LINK_LAYER_ADDRESS_OPTION: u8 = 1;
+const TARGET_LINK_LAYER_ADDRESS_OPTION: u8 = 2;
+const PREFIX_INFORMATION_OPTION: u8 = 3;
+const REDIRECTED_HEADER_OPTION: u8 = 4;
+const MTU_OPTION: u8 = 5;
+const ROUTE_INFORMATION_OPTION: u8 = 24;
+const RDNSS_OPTION: u8 = 25;
+const DNSSL_OPTION: u8 = 31;
+
 /// Corps d'un message Echo (types 128 et 129).
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Icmpv6Echo<'a> {
@@ -107,6 +124,245 @@ pub struct Icmpv6RouterAdvertisement<'a> {
     pub options: &'a [u8],
 }
 
+/// Prefix Information (option 3, RFC 4861 §4.6.2) : prefixe annonce par un
+/// routeur pour la determination on-link et l'autoconfiguration SLAAC.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NdPrefixInformation {
+    pub prefix_length: u8,
+    /// Drapeau L : le prefixe est joignable directement sur le lien.
+    pub on_link: bool,
+    /// Drapeau A : le prefixe sert a l'autoconfiguration d'adresses.
+    pub autonomous: bool,
+    /// Drapeau R (RFC 6275 §7.2) : `prefix` porte l'adresse complete du
+    /// routeur, pas seulement le prefixe.
+    pub router_address: bool,
+    /// Duree de validite en secondes ; `u32::MAX` = infinie.
+    pub valid_lifetime: u32,
+    /// Duree de preference en secondes ; `u32::MAX` = infinie.
+    pub preferred_lifetime: u32,
+    pub prefix: Ipv6Addr,
+}
+
+/// Preference d'un routeur ou d'une route (RFC 4191 §2.1), codee sur deux
+/// bits signes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NdRoutePreference {
+    High,
+    Medium,
+    Low,
+    /// Valeur 10 binaire : reservee, a traiter comme Medium par un hote.
+    Reserved,
+}
+
+impl NdRoutePreference {
+    fn from_bits(bits: u8) -> Self {
+        match bits & 0b11 {
+            0b01 => Self::High,
+            0b00 => Self::Medium,
+            0b11 => Self::Low,
+            _ => Self::Reserved,
+        }
+    }
+}
+
+/// Route Information (option 24, RFC 4191 §2.3) : route plus specifique
+/// que la route par defaut, annoncee par le routeur.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NdRouteInformation {
+    pub prefix_length: u8,
+    pub preference: NdRoutePreference,
+    /// Duree de vie en secondes ; `u32::MAX` = infinie.
+    pub route_lifetime: u32,
+    /// Prefixe complete de zeros au-dela des octets transportes.
+    pub prefix: Ipv6Addr,
+}
+
+/// Recursive DNS Server (option 25, RFC 8106 §5.1).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NdRecursiveDnsServers {
+    /// Duree d'utilisation en secondes ; 0 = retirer ces serveurs.
+    pub lifetime: u32,
+    pub servers: Vec<Ipv6Addr>,
+}
+
+/// DNS Search List (option 31, RFC 8106 §5.2).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NdDnsSearchList {
+    /// Duree d'utilisation en secondes ; 0 = retirer ces domaines.
+    pub lifetime: u32,
+    pub domains: Vec<String>,
+}
+
+/// Option de decouverte de voisins (RFC 4861 §4.6), typee selon son numero.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NdOption<'a> {
+    /// Adresse de liaison de l'emetteur (option 1), bourrage compris.
+    SourceLinkLayerAddress(&'a [u8]),
+    /// Adresse de liaison de la cible (option 2), bourrage compris.
+    TargetLinkLayerAddress(&'a [u8]),
+    PrefixInformation(NdPrefixInformation),
+    /// Debut du paquet redirige (option 4), apres les six octets d'en-tete.
+    RedirectedHeader(&'a [u8]),
+    /// MTU du lien (option 5).
+    Mtu(u32),
+    RouteInformation(NdRouteInformation),
+    RecursiveDnsServers(NdRecursiveDnsServers),
+    DnsSearchList(NdDnsSearchList),
+    /// Option non interpretee : octets apres type et longueur.
+    Other {
+        option_type: u8,
+        data: &'a [u8],
+    },
+}
+
+impl NdOption<'_> {
+    /// Adresse MAC portee par une option Source/Target Link-Layer Address,
+    /// quand le lien est Ethernet (six premiers octets).
+    pub fn mac_address(&self) -> Option<MacAddress> {
+        match self {
+            Self::SourceLinkLayerAddress(address) | Self::TargetLinkLayerAddress(address) => {
+                let octets: [u8; 6] = address.get(..6)?.try_into().ok()?;
+                Some(MacAddress(octets))
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Lit 16 octets d'adresse IPv6 a `offset`. L'appelant a deja verifie la
+/// longueur de l'option.
+fn ipv6_at(bytes: &[u8], offset: usize) -> Ipv6Addr {
+    let mut octets = [0_u8; 16];
+    octets.copy_from_slice(&bytes[offset..offset + 16]);
+    Ipv6Addr::from(octets)
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes([
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ])
+}
+
+/// Decode une suite d'options de decouverte de voisins.
+///
+/// Une option tronquee ou de longueur nulle fait echouer toute la liste :
+/// RFC 4861 §4.6 demande alors d'ignorer le message, pas d'en garder une
+/// partie. Une option connue mais de taille incoherente est refusee de la
+/// meme facon ; une option inconnue est rendue en [`NdOption::Other`].
+pub fn parse_nd_options(options: &[u8]) -> Result<Vec<NdOption<'_>>, Icmpv6Error> {
+    let mut parsed = Vec::with_capacity(options.len() / ND_OPTION_UNIT);
+    let mut offset = 0;
+    while offset < options.len() {
+        let (option_type, option) = extract_nd_option(options, offset)?;
+        parsed.push(parse_nd_option(option_type, option)?);
+        offset += option.len();
+    }
+    Ok(parsed)
+}
+
+fn parse_nd_option(option_type: u8, option: &[u8]) -> Result<NdOption<'_>, Icmpv6Error> {
+    let data = &option[ND_OPTION_HEADER_LENGTH..];
+    Ok(match option_type {
+        SOURCE_LINK_LAYER_ADDRESS_OPTION => NdOption::SourceLinkLayerAddress(data),
+        TARGET_LINK_LAYER_ADDRESS_OPTION => NdOption::TargetLinkLayerAddress(data),
+        PREFIX_INFORMATION_OPTION => {
+            validate_nd_option_length(option_type, option, ND_PREFIX_INFORMATION_LENGTH)?;
+            let flags = option[3];
+            NdOption::PrefixInformation(NdPrefixInformation {
+                prefix_length: option[2],
+                on_link: flags & 0b1000_0000 != 0,
+                autonomous: flags & 0b0100_0000 != 0,
+                router_address: flags & 0b0010_0000 != 0,
+                valid_lifetime: u32_at(option, 4),
+                preferred_lifetime: u32_at(option, 8),
+                prefix: ipv6_at(option, 16),
+            })
+        }
+        // Deux octets de longueur, quatre reserves, puis le paquet cite.
+        REDIRECTED_HEADER_OPTION => NdOption::RedirectedHeader(&option[ND_OPTION_UNIT..]),
+        MTU_OPTION => {
+            validate_nd_option_length(option_type, option, ND_MTU_LENGTH)?;
+            NdOption::Mtu(u32_at(option, 4))
+        }
+        ROUTE_INFORMATION_OPTION => {
+            validate_nd_option_range(
+                option_type,
+                option,
+                ND_OPTION_UNIT,
+                ND_ROUTE_INFORMATION_MAX_LENGTH,
+            )?;
+            let prefix_length = option[2];
+            // RFC 4191 §2.3 : la longueur d'option doit suffire a porter le
+            // prefixe annonce (1 unite jusqu'a /0, 2 jusqu'a /64, 3 au-dela).
+            let prefix_bytes = &option[ND_OPTION_UNIT..];
+            if prefix_length > 128 || usize::from(prefix_length) > prefix_bytes.len() * 8 {
+                return Err(Icmpv6Error::InvalidOptionLength {
+                    option_type,
+                    length: option.len(),
+                });
+            }
+            let mut octets = [0_u8; 16];
+            octets[..prefix_bytes.len()].copy_from_slice(prefix_bytes);
+            NdOption::RouteInformation(NdRouteInformation {
+                prefix_length,
+                preference: NdRoutePreference::from_bits(option[3] >> 3),
+                route_lifetime: u32_at(option, 4),
+                prefix: Ipv6Addr::from(octets),
+            })
+        }
+        RDNSS_OPTION => {
+            // Longueur impaire imposee : 8 octets d'en-tete puis N x 16.
+            let addresses = option
+                .get(ND_DNS_OPTION_HEADER_LENGTH..)
+                .unwrap_or_default();
+            if option.len() < ND_RDNSS_MIN_LENGTH || !addresses.len().is_multiple_of(16) {
+                return Err(Icmpv6Error::InvalidOptionLength {
+                    option_type,
+                    length: option.len(),
+                });
+            }
+            NdOption::RecursiveDnsServers(NdRecursiveDnsServers {
+                lifetime: u32_at(option, 4),
+                servers: addresses
+                    .chunks_exact(16)
+                    .map(|chunk| ipv6_at(chunk, 0))
+                    .collect(),
+            })
+        }
+        DNSSL_OPTION => {
+            validate_nd_option_range(
+                option_type,
+                option,
+                ND_DNS_OPTION_HEADER_LENGTH + ND_OPTION_UNIT,
+                usize::MAX,
+            )?;
+            NdOption::DnsSearchList(NdDnsSearchList {
+                lifetime: u32_at(option, 4),
+                domains: parse_domain_list(option_type, &option[ND_DNS_OPTION_HEADER_LENGTH..])?,
+            })
+        }
+        _ => NdOption::Other { option_type, data },
+    })
+}
+
+/// Noms de domaine au format DNS (RFC 1035 §3.1), mis bout a bout et
+/// completes par des octets nuls jusqu'a la fin de l'option.
+fn parse_domain_list(option_type: u8, names: &[u8]) -> Result<Vec<String>, Icmpv6Error> {
+    let mut domains = Vec::new();
+    let mut offset = 0;
+    while offset < names.len() && names[offset] != 0 {
+        let (domain, next) = parse_dns_name(names, offset)
+            .map_err(|_| Icmpv6Error::InvalidDomainName { option_type })?;
+        domains.push(domain);
+        offset = next;
+    }
+    Ok(domains)
+}
+
 /// Corps ICMPv6, choisi selon le type de message.
 #[non_exhaustive]
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -146,6 +402,34 @@ pub struct Icmpv6Packet<'a> {
     pub body: Icmpv6Body<'a>,
 }
 
+impl<'a> Icmpv6RouterSolicitation<'a> {
+    /// Options typees, decodees a la demande depuis [`Self::options`].
+    pub fn nd_options(&self) -> Result<Vec<NdOption<'a>>, Icmpv6Error> {
+        parse_nd_options(self.options)
+    }
+}
+
+impl<'a> Icmpv6RouterAdvertisement<'a> {
+    /// Options typees, decodees a la demande depuis [`Self::options`].
+    pub fn nd_options(&self) -> Result<Vec<NdOption<'a>>, Icmpv6Error> {
+        parse_nd_options(self.options)
+    }
+}
+
+impl<'a> Icmpv6NeighborSolicitation<'a> {
+    /// Options typees, decodees a la demande depuis [`Self::options`].
+    pub fn nd_options(&self) -> Result<Vec<NdOption<'a>>, Icmpv6Error> {
+        parse_nd_options(self.options)
+    }
+}
+
+impl<'a> Icmpv6NeighborAdvertisement<'a> {
+    /// Options typees, decodees a la demande depuis [`Self::options`].
+    pub fn nd_options(&self) -> Result<Vec<NdOption<'a>>, Icmpv6Error> {
+        parse_nd_options(self.options)
+    }
+}
+
 /// Lit l'adresse cible des messages de decouverte de voisins.
 ///
 /// L'appelant doit avoir passe [`validate_icmpv6_neighbor_length`] : les 16
@@ -454,6 +738,118 @@ mod router_discovery_tests {
         assert_eq!(advertisement.options[0], 3);
     }
 
+    #[test]
+    fn decodes_router_advertisement_options_from_capture() {
+        let raw = bytes(ROUTER_ADVERTISEMENT);
+        let packet = Icmpv6Packet::try_from(raw.as_slice()).expect("captured RA parses");
+        let Icmpv6Body::RouterAdvertisement(advertisement) = &packet.body else {
+            panic!("expected a router advertisement, got {:?}", packet.body);
+        };
+
+        let options = advertisement.nd_options().expect("captured options decode");
+        assert_eq!(
+            options,
+            vec![
+                NdOption::PrefixInformation(NdPrefixInformation {
+                    prefix_length: 64,
+                    on_link: true,
+                    autonomous: true,
+                    router_address: false,
+                    valid_lifetime: 604_800,
+                    preferred_lifetime: 86_400,
+                    prefix: "2003:50:aa10:4243::".parse().unwrap(),
+                }),
+                NdOption::Mtu(1492),
+            ]
+        );
+    }
+
+    #[test]
+    fn decodes_source_link_layer_address_from_capture() {
+        let raw = bytes(ROUTER_SOLICITATION);
+        let packet = Icmpv6Packet::try_from(raw.as_slice()).expect("captured RS parses");
+        let Icmpv6Body::RouterSolicitation(solicitation) = &packet.body else {
+            panic!("expected a router solicitation, got {:?}", packet.body);
+        };
+
+        let options = solicitation.nd_options().expect("captured option decodes");
+        assert_eq!(options.len(), 1);
+        assert_eq!(
+            options[0].mac_address(),
+            Some(MacAddress([0x00, 0x21, 0x6a, 0x2d, 0x3b, 0x8e]))
+        );
+    }
+
+    /// Synthetique : Route Information 2001:db8:1::/48 preference haute,
+    /// RDNSS avec deux serveurs et DNSSL « example.com » (RFC 4191, RFC 8106).
+    #[test]
+    fn decodes_route_information_and_dns_options() {
+        let raw = bytes(concat!(
+            "180330080000070820010db8000100000000000000000000",
+            "190500000000012c20010db8000000000000000000000053",
+            "20010db8000000000000000000000054",
+            "1f030000000001",
+            "2c076578616d706c6503636f6d00000000",
+        ));
+
+        let options = parse_nd_options(&raw).expect("synthetic options decode");
+
+        assert_eq!(
+            options[0],
+            NdOption::RouteInformation(NdRouteInformation {
+                prefix_length: 48,
+                preference: NdRoutePreference::High,
+                route_lifetime: 1800,
+                prefix: "2001:db8:1::".parse().unwrap(),
+            })
+        );
+        assert_eq!(
+            options[1],
+            NdOption::RecursiveDnsServers(NdRecursiveDnsServers {
+                lifetime: 300,
+                servers: vec![
+                    "2001:db8::53".parse().unwrap(),
+                    "2001:db8::54".parse().unwrap()
+                ],
+            })
+        );
+        assert_eq!(
+            options[2],
+            NdOption::DnsSearchList(NdDnsSearchList {
+                lifetime: 300,
+                domains: vec!["example.com".to_string()],
+            })
+        );
+    }
+
+    /// Synthetique : une option de longueur nulle bloquerait l'iteration ;
+    /// RFC 4861 §4.6 impose d'ignorer le message.
+    #[test]
+    fn rejects_zero_length_and_truncated_options() {
+        assert_eq!(
+            parse_nd_options(&[1, 0, 0, 0, 0, 0, 0, 0]),
+            Err(Icmpv6Error::ZeroLengthOption {
+                option_type: 1,
+                offset: 0
+            })
+        );
+        assert_eq!(
+            parse_nd_options(&[3, 4, 64, 0xc0]),
+            Err(Icmpv6Error::TruncatedOption {
+                option_type: 3,
+                expected: 32,
+                actual: 4
+            })
+        );
+        assert_eq!(
+            parse_nd_options(&[5, 2, 0, 0, 0, 0, 5, 0xdc, 0, 0, 0, 0, 0, 0, 0, 0]),
+            Err(Icmpv6Error::InvalidOptionLength {
+                option_type: 5,
+                length: 16
+            })
+        );
+    }
+
     /// Synthetique : RA tronquee avant le retrans timer.
     #[test]
     fn rejects_router_advertisement_missing_fixed_fields() {
@@ -0,0 +1,480 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Parseur IGMP (IP protocole 2) : IGMPv1 (RFC 1112), IGMPv2 (RFC 2236) et
+//! IGMPv3 (RFC 3376).
+//!
+//! Comme ICMP, IGMP n'a ni port ni session : il est atteint par le numero de
+//! protocole IP dans `Transport::try_from_parts`, jamais par probing. Les
+//! Membership Query des trois versions partagent le type 0x11 ; la version
+//! est deduite de la longueur du message, comme le fait un routeur.
+
+use std::convert::TryFrom;
+use std::net::Ipv4Addr;
+
+use crate::{
+    checks::transport::igmp::{
+        IGMP_SOURCE_ADDRESS_LENGTH, IGMPV3_GROUP_RECORD_HEADER_LENGTH, IGMPV3_QUERY_HEADER_LENGTH,
+        IGMPV3_REPORT_HEADER_LENGTH, IgmpQueryVersion, decode_igmpv3_exponential_code,
+        extract_igmp_query_version, extract_igmp_source_list, extract_igmpv3_group_record_length,
+        validate_igmp_min_length, validate_igmpv3_report_length,
+    },
+    errors::transport::igmp::IgmpError,
+    parse::application::protocols::bounded_capacity,
+};
+
+/// Types de message IGMP interpretes (registre IANA « IGMP Type Numbers »).
+const MEMBERSHIP_QUERY_TYPE: u8 = 0x11;
+const V1_MEMBERSHIP_REPORT_TYPE: u8 = 0x12;
+const V2_MEMBERSHIP_REPORT_TYPE: u8 = 0x16;
+const V2_LEAVE_GROUP_TYPE: u8 = 0x17;
+const V3_MEMBERSHIP_REPORT_TYPE: u8 = 0x22;
+
+/// Lit une adresse IPv4 a `offset`. L'appelant a verifie la longueur.
+fn ipv4_at(bytes: &[u8], offset: usize) -> Ipv4Addr {
+    Ipv4Addr::new(
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    )
+}
+
+/// Iterateur sur une liste de sources IGMPv3, lue a la demande depuis le
+/// paquet.
+fn iter_sources(sources: &[u8]) -> impl Iterator<Item = Ipv4Addr> + '_ {
+    sources
+        .chunks_exact(IGMP_SOURCE_ADDRESS_LENGTH)
+        .map(|chunk| Ipv4Addr::new(chunk[0], chunk[1], chunk[2], chunk[3]))
+}
+
+/// Membership Query, toutes versions (type 0x11).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IgmpQuery<'a> {
+    /// 0.0.0.0 pour une General Query, le groupe interroge sinon.
+    pub group_address: Ipv4Addr,
+    /// Drapeau S (IGMPv3) : les routeurs ne mettent pas a jour leurs
+    /// temporisateurs a la reception. Toujours faux en v1/v2.
+    pub suppress_router_processing: bool,
+    /// QRV (IGMPv3) : variable de robustesse du querier, 0 en v1/v2.
+    pub robustness_variable: u8,
+    /// QQIC brut (IGMPv3), 0 en v1/v2. Voir
+    /// [`IgmpQuery::query_interval_seconds`].
+    pub query_interval_code: u8,
+    /// Adresses sources (IGMPv3), 4 octets par source, zero-copy. Vide pour
+    /// une query sans source et pour v1/v2.
+    pub sources: &'a [u8],
+}
+
+impl IgmpQuery<'_> {
+    /// Intervalle de query du querier, en secondes (QQIC decode).
+    pub fn query_interval_seconds(&self) -> u16 {
+        decode_igmpv3_exponential_code(self.query_interval_code)
+    }
+
+    /// Adresses sources d'une Group-and-Source-Specific Query.
+    pub fn source_addresses(&self) -> impl Iterator<Item = Ipv4Addr> + '_ {
+        iter_sources(self.sources)
+    }
+
+    /// Query generale (tous les groupes), par opposition a une query
+    /// specifique a un groupe.
+    pub fn is_general(&self) -> bool {
+        self.group_address.is_unspecified()
+    }
+}
+
+/// Type d'un record de groupe IGMPv3 (RFC 3376 §4.2.12).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IgmpGroupRecordType {
+    ModeIsInclude,
+    ModeIsExclude,
+    ChangeToIncludeMode,
+    ChangeToExcludeMode,
+    AllowNewSources,
+    BlockOldSources,
+    Unknown(u8),
+}
+
+impl From<u8> for IgmpGroupRecordType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => Self::ModeIsInclude,
+            2 => Self::ModeIsExclude,
+            3 => Self::ChangeToIncludeMode,
+            4 => Self::ChangeToExcludeMode,
+            5 => Self::AllowNewSources,
+            6 => Self::BlockOldSources,
+            other => Self::Unknown(other),
+        }
+    }
+}
+
+/// Record de groupe d'un Membership Report IGMPv3.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IgmpGroupRecord<'a> {
+    pub record_type: IgmpGroupRecordType,
+    pub multicast_address: Ipv4Addr,
+    /// Adresses sources, 4 octets par source, zero-copy.
+    pub sources: &'a [u8],
+    /// Donnees auxiliaires (aucune n'est definie par RFC 3376), zero-copy.
+    pub auxiliary_data: &'a [u8],
+}
+
+impl IgmpGroupRecord<'_> {
+    pub fn source_addresses(&self) -> impl Iterator<Item = Ipv4Addr> + '_ {
+        iter_sources(self.sources)
+    }
+
+    /// Un record INCLUDE vide (IS_IN({}) ou TO_IN({})) signale que l'hote
+    /// quitte le groupe (RFC 3376 §6.4) ; tout autre record maintient une
+    /// adhesion, (*,G) en EXCLUDE ou (S,G) en INCLUDE.
+    pub fn is_leave(&self) -> bool {
+        matches!(
+            self.record_type,
+            IgmpGroupRecordType::ModeIsInclude | IgmpGroupRecordType::ChangeToIncludeMode
+        ) && self.sources.is_empty()
+    }
+}
+
+/// Corps IGMP, choisi selon le type et la version.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IgmpBody<'a> {
+    /// Membership Query v1, v2 ou v3 (voir [`IgmpPacket::version`]).
+    Query(IgmpQuery<'a>),
+    /// Membership Report v1 (0x12) ou v2 (0x16) : un seul groupe.
+    Report { group_address: Ipv4Addr },
+    /// Leave Group v2 (0x17).
+    Leave { group_address: Ipv4Addr },
+    /// Membership Report v3 (0x22) : liste de records de groupe.
+    V3Report(Vec<IgmpGroupRecord<'a>>),
+    /// Type non interprete (DVMRP, mtrace, PIMv1...) : octets bruts apres
+    /// l'en-tete commun de quatre octets.
+    Other(&'a [u8]),
+}
+
+/// Version IGMP d'un message.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IgmpVersion {
+    V1,
+    V2,
+    V3,
+    /// Type non interprete : la version n'est pas deductible.
+    Unknown,
+}
+
+impl From<IgmpQueryVersion> for IgmpVersion {
+    fn from(value: IgmpQueryVersion) -> Self {
+        match value {
+            IgmpQueryVersion::V1 => Self::V1,
+            IgmpQueryVersion::V2 => Self::V2,
+            IgmpQueryVersion::V3 => Self::V3,
+        }
+    }
+}
+
+/// Message IGMP (RFC 1112, RFC 2236, RFC 3376).
+///
+/// Le schema montre une Membership Query IGMPv3, la forme la plus
+/// structuree ; v1/v2 s'arretent apres l'adresse de groupe.
+///
+/// ```mermaid
+/// ---
+/// title: IgmpPacket (Membership Query v3)
+/// ---
+/// packet-beta
+/// 0-7: "Type u8"
+/// 8-15: "Max Resp Code u8"
+/// 16-31: "Checksum u16"
+/// 32-63: "Group Address u32"
+/// 64-67: "Resv"
+/// 68: "S"
+/// 69-71: "QRV"
+/// 72-79: "QQIC u8"
+/// 80-95: "Number of Sources u16"
+/// 96-127: "Source Addresses variable"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IgmpPacket<'a> {
+    pub message_type: u8,
+    /// Max Resp Time (v2, dixiemes de seconde) ou Max Resp Code (v3) ; nul
+    /// ou reserve ailleurs. Voir [`IgmpPacket::max_response_time_tenths`].
+    pub max_response_code: u8,
+    pub checksum: u16,
+    pub version: IgmpVersion,
+    pub body: IgmpBody<'a>,
+}
+
+impl IgmpPacket<'_> {
+    /// Delai de reponse maximal d'une query, en dixiemes de seconde. En v3
+    /// le code est decode (RFC 3376 §4.1.1) ; en v1, ou il vaut 0, RFC 2236
+    /// §4 impose d'utiliser 10 secondes.
+    pub fn max_response_time_tenths(&self) -> u16 {
+        match self.version {
+            IgmpVersion::V1 => 100,
+            IgmpVersion::V3 => decode_igmpv3_exponential_code(self.max_response_code),
+            IgmpVersion::V2 | IgmpVersion::Unknown => u16::from(self.max_response_code),
+        }
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for IgmpPacket<'a> {
+    type Error = IgmpError;
+
+    fn try_from(payload: &'a [u8]) -> Result<Self, IgmpError> {
+        validate_igmp_min_length(payload)?;
+
+        let message_type = payload[0];
+        let max_response_code = payload[1];
+        let checksum = u16::from_be_bytes([payload[2], payload[3]]);
+
+        let (version, body) = match message_type {
+            MEMBERSHIP_QUERY_TYPE => {
+                let version = extract_igmp_query_version(payload, max_response_code)?;
+                (
+                    version.into(),
+                    IgmpBody::Query(parse_query(payload, version)?),
+                )
+            }
+            V1_MEMBERSHIP_REPORT_TYPE => (
+                IgmpVersion::V1,
+                IgmpBody::Report {
+                    group_address: ipv4_at(payload, 4),
+                },
+            ),
+            V2_MEMBERSHIP_REPORT_TYPE => (
+                IgmpVersion::V2,
+                IgmpBody::Report {
+                    group_address: ipv4_at(payload, 4),
+                },
+            ),
+            V2_LEAVE_GROUP_TYPE => (
+                IgmpVersion::V2,
+                IgmpBody::Leave {
+                    group_address: ipv4_at(payload, 4),
+                },
+            ),
+            V3_MEMBERSHIP_REPORT_TYPE => (
+                IgmpVersion::V3,
+                IgmpBody::V3Report(parse_v3_report(payload)?),
+            ),
+            _ => (IgmpVersion::Unknown, IgmpBody::Other(&payload[4..])),
+        };
+
+        Ok(IgmpPacket {
+            message_type,
+            max_response_code,
+            checksum,
+            version,
+            body,
+        })
+    }
+}
+
+fn parse_query(payload: &[u8], version: IgmpQueryVersion) -> Result<IgmpQuery<'_>, IgmpError> {
+    let group_address = ipv4_at(payload, 4);
+    if version != IgmpQueryVersion::V3 {
+        return Ok(IgmpQuery {
+            group_address,
+            suppress_router_processing: false,
+            robustness_variable: 0,
+            query_interval_code: 0,
+            sources: &[],
+        });
+    }
+
+    let number_of_sources = u16::from_be_bytes([payload[10], payload[11]]);
+    let sources =
+        extract_igmp_source_list(&payload[IGMPV3_QUERY_HEADER_LENGTH..], number_of_sources)?;
+    Ok(IgmpQuery {
+        group_address,
+        suppress_router_processing: payload[8] & 0x08 != 0,
+        robustness_variable: payload[8] & 0x07,
+        query_interval_code: payload[9],
+        sources,
+    })
+}
+
+fn parse_v3_report(payload: &[u8]) -> Result<Vec<IgmpGroupRecord<'_>>, IgmpError> {
+    validate_igmpv3_report_length(payload)?;
+    let record_count = usize::from(u16::from_be_bytes([payload[6], payload[7]]));
+
+    let mut offset = IGMPV3_REPORT_HEADER_LENGTH;
+    let mut records = Vec::with_capacity(bounded_capacity(
+        record_count,
+        payload.len() - offset,
+        IGMPV3_GROUP_RECORD_HEADER_LENGTH,
+    ));
+    for index in 0..record_count {
+        let record = &payload[offset..];
+        let record_length = extract_igmpv3_group_record_length(record, index)?;
+        let sources_end = IGMPV3_GROUP_RECORD_HEADER_LENGTH
+            + usize::from(u16::from_be_bytes([record[2], record[3]])) * IGMP_SOURCE_ADDRESS_LENGTH;
+        records.push(IgmpGroupRecord {
+            record_type: IgmpGroupRecordType::from(record[0]),
+            multicast_address: ipv4_at(record, 4),
+            sources: &record[IGMPV3_GROUP_RECORD_HEADER_LENGTH..sources_end],
+            auxiliary_data: &record[sources_end..record_length],
+        });
+        offset += record_length;
+    }
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Trame 1032 : Membership Report IGMPv3 de 192.168.0.102 vers
+    /// 224.0.0.22, trois records CHANGE_TO_EXCLUDE sans source
+    /// (pcaps_exemple/vlan0--packet-capture CAPWAP a partir de ligne 520
+    /// (Ap = 192_168.0.104 ) + radius _ partir de la ligne 33003 .cap).
+    const V3_REPORT: &str = concat!(
+        "2200200900000003040000",
+        "00e00000fc04000000e00000fb04000000effffffa"
+    );
+
+    fn bytes(hex_fixture: &str) -> Vec<u8> {
+        hex::decode(hex_fixture).expect("invalid test hex fixture")
+    }
+
+    #[test]
+    fn parses_v3_report_from_capture() {
+        let raw = bytes(V3_REPORT);
+        let packet = IgmpPacket::try_from(raw.as_slice()).expect("captured v3 report parses");
+
+        assert_eq!(packet.message_type, 0x22);
+        assert_eq!(packet.version, IgmpVersion::V3);
+        assert_eq!(packet.checksum, 0x2009);
+        let IgmpBody::V3Report(records) = &packet.body else {
+            panic!("expected a v3 report, got {:?}", packet.body);
+        };
+        let groups: Vec<Ipv4Addr> = records.iter().map(|r| r.multicast_address).collect();
+        assert_eq!(
+            groups,
+            [
+                Ipv4Addr::new(224, 0, 0, 252),
+                Ipv4Addr::new(224, 0, 0, 251),
+                Ipv4Addr::new(239, 255, 255, 250),
+            ]
+        );
+        for record in records {
+            assert_eq!(record.record_type, IgmpGroupRecordType::ChangeToExcludeMode);
+            assert!(record.sources.is_empty());
+            assert!(!record.is_leave());
+        }
+    }
+
+    /// Synthetique : General Query IGMPv2, max resp time 10 s.
+    #[test]
+    fn parses_v2_general_query() {
+        let raw = [0x11, 0x64, 0xee, 0x9b, 0, 0, 0, 0];
+        let packet = IgmpPacket::try_from(&raw[..]).expect("v2 query parses");
+
+        assert_eq!(packet.version, IgmpVersion::V2);
+        assert_eq!(packet.max_response_time_tenths(), 100);
+        let IgmpBody::Query(query) = &packet.body else {
+            panic!("expected a query, got {:?}", packet.body);
+        };
+        assert!(query.is_general());
+        assert!(query.sources.is_empty());
+    }
+
+    /// Synthetique : query v1, max resp code nul.
+    #[test]
+    fn query_with_zero_response_code_is_v1() {
+        let raw = [0x11, 0x00, 0xee, 0xff, 0, 0, 0, 0];
+        let packet = IgmpPacket::try_from(&raw[..]).expect("v1 query parses");
+
+        assert_eq!(packet.version, IgmpVersion::V1);
+        assert_eq!(packet.max_response_time_tenths(), 100);
+    }
+
+    /// Synthetique : Group-and-Source-Specific Query IGMPv3 avec deux sources.
+    #[test]
+    fn parses_v3_source_specific_query() {
+        let raw = [
+            0x11, 0x0a, 0x00, 0x00, 232, 1, 1, 1, 0x0a, 125, 0x00, 0x02, 10, 0, 0, 1, 10, 0, 0, 2,
+        ];
+        let packet = IgmpPacket::try_from(&raw[..]).expect("v3 query parses");
+
+        assert_eq!(packet.version, IgmpVersion::V3);
+        let IgmpBody::Query(query) = &packet.body else {
+            panic!("expected a query, got {:?}", packet.body);
+        };
+        assert!(query.suppress_router_processing);
+        assert_eq!(query.robustness_variable, 2);
+        assert_eq!(query.query_interval_seconds(), 125);
+        assert_eq!(
+            query.source_addresses().collect::<Vec<_>>(),
+            [Ipv4Addr::new(10, 0, 0, 1), Ipv4Addr::new(10, 0, 0, 2)]
+        );
+    }
+
+    /// Synthetique : Leave Group IGMPv2.
+    #[test]
+    fn parses_v2_leave() {
+        let raw = [0x17, 0x00, 0x00, 0x00, 239, 1, 2, 3];
+        let packet = IgmpPacket::try_from(&raw[..]).expect("leave parses");
+
+        assert_eq!(
+            packet.body,
+            IgmpBody::Leave {
+                group_address: Ipv4Addr::new(239, 1, 2, 3)
+            }
+        );
+    }
+
+    /// Synthetique : query de 10 octets, aucune version ne correspond.
+    #[test]
+    fn rejects_query_of_ambiguous_length() {
+        let raw = [0x11, 0x64, 0, 0, 0, 0, 0, 0, 0, 0];
+
+        assert_eq!(
+            IgmpPacket::try_from(&raw[..]).unwrap_err(),
+            IgmpError::InvalidQueryLength { actual: 10 }
+        );
+    }
+
+    /// Synthetique : query v3 annoncant plus de sources que d'octets.
+    #[test]
+    fn rejects_v3_query_with_truncated_sources() {
+        let raw = [
+            0x11, 0x0a, 0, 0, 232, 1, 1, 1, 0x02, 125, 0x00, 0x03, 10, 0, 0, 1,
+        ];
+
+        assert_eq!(
+            IgmpPacket::try_from(&raw[..]).unwrap_err(),
+            IgmpError::TruncatedSourceList {
+                declared: 3,
+                available: 4
+            }
+        );
+    }
+
+    /// Synthetique : report v3 annoncant un record absent.
+    #[test]
+    fn rejects_v3_report_with_missing_record() {
+        let raw = [0x22, 0, 0, 0, 0, 0, 0x00, 0x01];
+
+        assert!(matches!(
+            IgmpPacket::try_from(&raw[..]),
+            Err(IgmpError::TruncatedGroupRecord { index: 0, .. })
+        ));
+    }
+
+    /// Synthetique : troncature sous l'en-tete commun.
+    #[test]
+    fn rejects_packet_shorter_than_common_header() {
+        assert_eq!(
+            IgmpPacket::try_from(&[0x16, 0x00, 0x00][..]).unwrap_err(),
+            IgmpError::InvalidLength {
+                expected: 8,
+                actual: 3
+            }
+        );
+    }
+}
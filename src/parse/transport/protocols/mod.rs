@@ -7,10 +7,16 @@ use serde::Serialize;
 
 use crate::Transport;
 
+pub mod ah;
+pub mod esp;
 pub mod icmp;
 pub mod icmpv6;
+pub mod igmp;
+pub mod ospf;
+pub mod pim;
 pub mod tcp;
 pub mod udp;
+pub mod vrrp;
 
 /// Represents transport protocols AND IPv6 extension headers
 #[derive(Debug, Clone, Copy, Serialize, Hash, PartialEq, Eq)]
@@ -0,0 +1,700 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Parseur OSPF (IP protocole 89) : OSPFv2 (RFC 2328) et OSPFv3 (RFC 5340).
+//!
+//! Comme IGMP, OSPF n'a ni port ni session : il est atteint par le numero de
+//! protocole IP dans `Transport::try_from_parts`. Les cinq types de paquet
+//! sont decodes dans les deux versions ; le contenu des LSA est type pour
+//! les LSA OSPFv2 router, network, summary et AS-external/NSSA, les autres
+//! (opaques, LSA OSPFv3 a prefixes) restent bruts.
+
+use std::convert::TryFrom;
+use std::net::Ipv4Addr;
+
+use crate::{
+    checks::transport::ospf::{
+        OSPF_HELLO_LENGTH, OSPF_LS_REQUEST_LENGTH, OSPF_LSA_HEADER_LENGTH, OSPF_ROUTER_ID_LENGTH,
+        OSPFV2_DATABASE_DESCRIPTION_LENGTH, OSPFV3_DATABASE_DESCRIPTION_LENGTH,
+        extract_ospf_lsa_length, extract_ospf_packet, validate_ospf_body_length,
+        validate_ospf_header_length,
+    },
+    errors::transport::ospf::OspfError,
+    parse::application::protocols::bounded_capacity,
+};
+
+/// Types de paquet OSPF (RFC 2328 §A.3.1, inchanges en v3).
+const HELLO_TYPE: u8 = 1;
+const DATABASE_DESCRIPTION_TYPE: u8 = 2;
+const LINK_STATE_REQUEST_TYPE: u8 = 3;
+const LINK_STATE_UPDATE_TYPE: u8 = 4;
+const LINK_STATE_ACK_TYPE: u8 = 5;
+
+/// Types d'LSA OSPFv2 dont le contenu est decode (RFC 2328 §A.4, RFC 3101).
+const ROUTER_LSA_TYPE: u16 = 1;
+const NETWORK_LSA_TYPE: u16 = 2;
+const SUMMARY_NETWORK_LSA_TYPE: u16 = 3;
+const SUMMARY_ASBR_LSA_TYPE: u16 = 4;
+const AS_EXTERNAL_LSA_TYPE: u16 = 5;
+const NSSA_EXTERNAL_LSA_TYPE: u16 = 7;
+
+/// Bits du champ flags d'un Database Description (RFC 2328 §A.3.3).
+const DD_INIT_FLAG: u8 = 0x04;
+const DD_MORE_FLAG: u8 = 0x02;
+const DD_MASTER_FLAG: u8 = 0x01;
+
+/// Lit une adresse IPv4 (ou un identifiant 32 bits note comme telle) a
+/// `offset`. L'appelant a verifie la longueur.
+fn ipv4_at(bytes: &[u8], offset: usize) -> Ipv4Addr {
+    Ipv4Addr::new(
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    )
+}
+
+fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes([
+        bytes[offset],
+        bytes[offset + 1],
+        bytes[offset + 2],
+        bytes[offset + 3],
+    ])
+}
+
+fn u24_at(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_be_bytes([0, bytes[offset], bytes[offset + 1], bytes[offset + 2]])
+}
+
+/// Iterateur sur une liste d'identifiants de routeur, lue a la demande.
+fn iter_router_ids(ids: &[u8]) -> impl Iterator<Item = Ipv4Addr> + '_ {
+    ids.chunks_exact(OSPF_ROUTER_ID_LENGTH)
+        .map(|chunk| Ipv4Addr::new(chunk[0], chunk[1], chunk[2], chunk[3]))
+}
+
+/// Version OSPF, lue dans le premier octet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OspfVersion {
+    V2,
+    V3,
+}
+
+/// Hello : decouverte des voisins et election du DR/BDR.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OspfHello<'a> {
+    /// Masque du reseau de l'interface (OSPFv2 seulement).
+    pub network_mask: Option<Ipv4Addr>,
+    /// Identifiant d'interface de l'emetteur (OSPFv3 seulement).
+    pub interface_id: Option<u32>,
+    /// Secondes.
+    pub hello_interval: u16,
+    /// Options : 8 bits en v2, 24 bits en v3.
+    pub options: u32,
+    pub router_priority: u8,
+    /// Secondes : 32 bits en v2, 16 bits en v3.
+    pub router_dead_interval: u32,
+    /// Adresse d'interface (v2) ou router id (v3) du DR, 0.0.0.0 si aucun.
+    pub designated_router: Ipv4Addr,
+    pub backup_designated_router: Ipv4Addr,
+    /// Router ids des voisins entendus, 4 octets chacun, zero-copy.
+    pub neighbors: &'a [u8],
+}
+
+impl OspfHello<'_> {
+    pub fn neighbor_ids(&self) -> impl Iterator<Item = Ipv4Addr> + '_ {
+        iter_router_ids(self.neighbors)
+    }
+}
+
+/// En-tete d'LSA : identifie une instance d'LSA sans son contenu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OspfLsaHeader {
+    /// Secondes depuis l'origine de l'LSA.
+    pub age: u16,
+    /// Options (OSPFv2 seulement : en v3 elles sont dans le corps).
+    pub options: Option<u8>,
+    /// Type sur 8 bits en v2 ; en v3, 16 bits dont les bits U, S2 et S1
+    /// (voir [`OspfLsaHeader::function_code`]).
+    pub lsa_type: u16,
+    pub link_state_id: Ipv4Addr,
+    pub advertising_router: Ipv4Addr,
+    pub sequence_number: u32,
+    pub checksum: u16,
+    /// Longueur totale de l'LSA, en-tete compris.
+    pub length: u16,
+}
+
+impl OspfLsaHeader {
+    /// Code de fonction OSPFv3 (13 bits bas du type). En v2 le type entier.
+    pub fn function_code(&self) -> u16 {
+        self.lsa_type & 0x1FFF
+    }
+
+    fn parse(bytes: &[u8], version: OspfVersion) -> Self {
+        let (options, lsa_type) = match version {
+            OspfVersion::V2 => (Some(bytes[2]), u16::from(bytes[3])),
+            OspfVersion::V3 => (None, u16::from_be_bytes([bytes[2], bytes[3]])),
+        };
+        OspfLsaHeader {
+            age: u16::from_be_bytes([bytes[0], bytes[1]]),
+            options,
+            lsa_type,
+            link_state_id: ipv4_at(bytes, 4),
+            advertising_router: ipv4_at(bytes, 8),
+            sequence_number: u32_at(bytes, 12),
+            checksum: u16::from_be_bytes([bytes[16], bytes[17]]),
+            length: u16::from_be_bytes([bytes[18], bytes[19]]),
+        }
+    }
+}
+
+/// Database Description : resume de la base lors de l'echange initial.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OspfDatabaseDescription {
+    pub interface_mtu: u16,
+    /// Options : 8 bits en v2, 24 bits en v3.
+    pub options: u32,
+    /// Bits I, M et MS.
+    pub flags: u8,
+    pub sequence_number: u32,
+    pub lsa_headers: Vec<OspfLsaHeader>,
+}
+
+impl OspfDatabaseDescription {
+    /// Bit I : premier paquet de l'echange.
+    pub fn is_initial(&self) -> bool {
+        self.flags & DD_INIT_FLAG != 0
+    }
+
+    /// Bit M : d'autres Database Description suivent.
+    pub fn has_more(&self) -> bool {
+        self.flags & DD_MORE_FLAG != 0
+    }
+
+    /// Bit MS : l'emetteur est master de l'echange.
+    pub fn is_master(&self) -> bool {
+        self.flags & DD_MASTER_FLAG != 0
+    }
+}
+
+/// Entree d'un Link State Request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OspfLsRequest {
+    /// Type d'LSA : 32 bits en v2, 16 bits en v3.
+    pub lsa_type: u32,
+    pub link_state_id: Ipv4Addr,
+    pub advertising_router: Ipv4Addr,
+}
+
+/// Lien d'une Router LSA OSPFv2 (RFC 2328 §A.4.2).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OspfRouterLink {
+    pub link_id: Ipv4Addr,
+    pub link_data: Ipv4Addr,
+    /// 1 point-a-point, 2 transit, 3 stub, 4 virtuel.
+    pub link_type: u8,
+    /// Nombre de metriques TOS additionnelles (obsoletes, non exposees).
+    pub tos_count: u8,
+    pub metric: u16,
+}
+
+/// Router LSA OSPFv2 : les liens d'un routeur dans une aire.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OspfRouterLsa {
+    /// Bits V (extremite de lien virtuel), E (ASBR) et B (ABR).
+    pub flags: u8,
+    pub links: Vec<OspfRouterLink>,
+}
+
+/// Network LSA OSPFv2 : les routeurs attaches a un reseau de transit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OspfNetworkLsa<'a> {
+    pub network_mask: Ipv4Addr,
+    /// Router ids, 4 octets chacun, zero-copy.
+    pub attached_routers: &'a [u8],
+}
+
+impl OspfNetworkLsa<'_> {
+    pub fn attached_router_ids(&self) -> impl Iterator<Item = Ipv4Addr> + '_ {
+        iter_router_ids(self.attached_routers)
+    }
+}
+
+/// Summary LSA OSPFv2 (types 3 et 4) : route inter-aire.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OspfSummaryLsa {
+    pub network_mask: Ipv4Addr,
+    /// Metrique sur 24 bits.
+    pub metric: u32,
+}
+
+/// AS-external (type 5) ou NSSA (type 7) LSA OSPFv2 : route externe.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OspfExternalLsa {
+    pub network_mask: Ipv4Addr,
+    /// Bit E : metrique externe de type 2 (non additionnee au cout interne).
+    pub is_type_2_metric: bool,
+    /// Metrique sur 24 bits.
+    pub metric: u32,
+    pub forwarding_address: Ipv4Addr,
+    pub external_route_tag: u32,
+}
+
+/// Contenu d'une LSA apres son en-tete.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OspfLsaBody<'a> {
+    Router(OspfRouterLsa),
+    Network(OspfNetworkLsa<'a>),
+    Summary(OspfSummaryLsa),
+    External(OspfExternalLsa),
+    /// Type non decode (OSPFv3, LSA opaques) ou contenu incoherent avec
+    /// son type : octets bruts apres l'en-tete.
+    Other(&'a [u8]),
+}
+
+/// LSA complete d'un Link State Update.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OspfLsa<'a> {
+    pub header: OspfLsaHeader,
+    pub body: OspfLsaBody<'a>,
+}
+
+/// Corps OSPF, selon le type de paquet.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OspfBody<'a> {
+    Hello(OspfHello<'a>),
+    DatabaseDescription(OspfDatabaseDescription),
+    LinkStateRequest(Vec<OspfLsRequest>),
+    LinkStateUpdate(Vec<OspfLsa<'a>>),
+    LinkStateAck(Vec<OspfLsaHeader>),
+    /// Type non defini : octets bruts apres l'en-tete.
+    Other(&'a [u8]),
+}
+
+/// Paquet OSPF, v2 ou v3.
+///
+/// ```mermaid
+/// ---
+/// title: OspfPacket (en-tete OSPFv2)
+/// ---
+/// packet-beta
+/// 0-7: "Version u8"
+/// 8-15: "Type u8"
+/// 16-31: "Packet Length u16"
+/// 32-63: "Router ID u32"
+/// 64-95: "Area ID u32"
+/// 96-111: "Checksum u16"
+/// 112-127: "AuType u16"
+/// 128-191: "Authentication 8 octets"
+/// 192-223: "Corps variable"
+/// ```
+///
+/// En OSPFv3 l'AuType et l'authentification sont remplaces par l'Instance
+/// ID (8 bits) et un octet reserve : l'en-tete fait 16 octets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OspfPacket<'a> {
+    pub version: OspfVersion,
+    pub message_type: u8,
+    pub packet_length: u16,
+    pub router_id: Ipv4Addr,
+    pub area_id: Ipv4Addr,
+    pub checksum: u16,
+    /// AuType (OSPFv2) : 0 aucune, 1 mot de passe, 2 cryptographique.
+    pub authentication_type: Option<u16>,
+    /// Les 8 octets d'authentification OSPFv2, vide en v3.
+    pub authentication: &'a [u8],
+    /// Instance ID (OSPFv3 seulement).
+    pub instance_id: Option<u8>,
+    pub body: OspfBody<'a>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for OspfPacket<'a> {
+    type Error = OspfError;
+
+    fn try_from(payload: &'a [u8]) -> Result<Self, OspfError> {
+        let header_length = validate_ospf_header_length(payload)?;
+        let packet = extract_ospf_packet(payload, header_length)?;
+
+        let (version, authentication_type, authentication, instance_id) = match payload[0] {
+            2 => (
+                OspfVersion::V2,
+                Some(u16::from_be_bytes([packet[14], packet[15]])),
+                &packet[16..24],
+                None,
+            ),
+            _ => (OspfVersion::V3, None, &packet[..0], Some(packet[14])),
+        };
+
+        let message_type = packet[1];
+        let body = &packet[header_length..];
+        let body = match message_type {
+            HELLO_TYPE => OspfBody::Hello(parse_hello(body, version)?),
+            DATABASE_DESCRIPTION_TYPE => {
+                OspfBody::DatabaseDescription(parse_database_description(body, version)?)
+            }
+            LINK_STATE_REQUEST_TYPE => OspfBody::LinkStateRequest(parse_requests(body, version)),
+            LINK_STATE_UPDATE_TYPE => OspfBody::LinkStateUpdate(parse_update(body, version)?),
+            LINK_STATE_ACK_TYPE => OspfBody::LinkStateAck(parse_lsa_headers(body, version)),
+            _ => OspfBody::Other(body),
+        };
+
+        Ok(OspfPacket {
+            version,
+            message_type,
+            packet_length: u16::from_be_bytes([packet[2], packet[3]]),
+            router_id: ipv4_at(packet, 4),
+            area_id: ipv4_at(packet, 8),
+            checksum: u16::from_be_bytes([packet[12], packet[13]]),
+            authentication_type,
+            authentication,
+            instance_id,
+            body,
+        })
+    }
+}
+
+fn parse_hello(body: &[u8], version: OspfVersion) -> Result<OspfHello<'_>, OspfError> {
+    validate_ospf_body_length(body, OSPF_HELLO_LENGTH)?;
+    // La liste des voisins s'arrete au dernier identifiant complet.
+    let neighbors_length =
+        (body.len() - OSPF_HELLO_LENGTH) / OSPF_ROUTER_ID_LENGTH * OSPF_ROUTER_ID_LENGTH;
+    let neighbors = &body[OSPF_HELLO_LENGTH..OSPF_HELLO_LENGTH + neighbors_length];
+    Ok(match version {
+        OspfVersion::V2 => OspfHello {
+            network_mask: Some(ipv4_at(body, 0)),
+            interface_id: None,
+            hello_interval: u16::from_be_bytes([body[4], body[5]]),
+            options: u32::from(body[6]),
+            router_priority: body[7],
+            router_dead_interval: u32_at(body, 8),
+            designated_router: ipv4_at(body, 12),
+            backup_designated_router: ipv4_at(body, 16),
+            neighbors,
+        },
+        OspfVersion::V3 => OspfHello {
+            network_mask: None,
+            interface_id: Some(u32_at(body, 0)),
+            hello_interval: u16::from_be_bytes([body[8], body[9]]),
+            options: u24_at(body, 5),
+            router_priority: body[4],
+            router_dead_interval: u32::from(u16::from_be_bytes([body[10], body[11]])),
+            designated_router: ipv4_at(body, 12),
+            backup_designated_router: ipv4_at(body, 16),
+            neighbors,
+        },
+    })
+}
+
+fn parse_database_description(
+    body: &[u8],
+    version: OspfVersion,
+) -> Result<OspfDatabaseDescription, OspfError> {
+    let (fixed_length, interface_mtu, options, flags) = match version {
+        OspfVersion::V2 => {
+            validate_ospf_body_length(body, OSPFV2_DATABASE_DESCRIPTION_LENGTH)?;
+            (
+                OSPFV2_DATABASE_DESCRIPTION_LENGTH,
+                u16::from_be_bytes([body[0], body[1]]),
+                u32::from(body[2]),
+                body[3],
+            )
+        }
+        OspfVersion::V3 => {
+            validate_ospf_body_length(body, OSPFV3_DATABASE_DESCRIPTION_LENGTH)?;
+            (
+                OSPFV3_DATABASE_DESCRIPTION_LENGTH,
+                u16::from_be_bytes([body[4], body[5]]),
+                u24_at(body, 1),
+                body[7],
+            )
+        }
+    };
+    Ok(OspfDatabaseDescription {
+        interface_mtu,
+        options,
+        flags,
+        sequence_number: u32_at(body, fixed_length - 4),
+        lsa_headers: parse_lsa_headers(&body[fixed_length..], version),
+    })
+}
+
+/// En-tetes d'LSA juxtaposes (Database Description, LSAck). Un reste de
+/// moins de 20 octets est ignore, comme le fait un routeur.
+fn parse_lsa_headers(bytes: &[u8], version: OspfVersion) -> Vec<OspfLsaHeader> {
+    bytes
+        .chunks_exact(OSPF_LSA_HEADER_LENGTH)
+        .map(|chunk| OspfLsaHeader::parse(chunk, version))
+        .collect()
+}
+
+fn parse_requests(bytes: &[u8], version: OspfVersion) -> Vec<OspfLsRequest> {
+    bytes
+        .chunks_exact(OSPF_LS_REQUEST_LENGTH)
+        .map(|chunk| OspfLsRequest {
+            lsa_type: match version {
+                OspfVersion::V2 => u32_at(chunk, 0),
+                OspfVersion::V3 => u32::from(u16::from_be_bytes([chunk[2], chunk[3]])),
+            },
+            link_state_id: ipv4_at(chunk, 4),
+            advertising_router: ipv4_at(chunk, 8),
+        })
+        .collect()
+}
+
+fn parse_update(body: &[u8], version: OspfVersion) -> Result<Vec<OspfLsa<'_>>, OspfError> {
+    validate_ospf_body_length(body, 4)?;
+    let count = u32_at(body, 0);
+    let count = usize::try_from(count).unwrap_or(usize::MAX);
+
+    let mut offset = 4;
+    let mut lsas = Vec::with_capacity(bounded_capacity(
+        count,
+        body.len() - offset,
+        OSPF_LSA_HEADER_LENGTH,
+    ));
+    for index in 0..count {
+        let lsa = &body[offset..];
+        let length = extract_ospf_lsa_length(lsa, index)?;
+        let header = OspfLsaHeader::parse(lsa, version);
+        let content = &lsa[OSPF_LSA_HEADER_LENGTH..length];
+        let body = match version {
+            OspfVersion::V2 => parse_v2_lsa_body(header.lsa_type, content),
+            OspfVersion::V3 => None,
+        };
+        lsas.push(OspfLsa {
+            header,
+            body: body.unwrap_or(OspfLsaBody::Other(content)),
+        });
+        offset += length;
+    }
+    Ok(lsas)
+}
+
+/// Decode le contenu d'une LSA OSPFv2. `None` si le type n'est pas decode
+/// ou si le contenu ne correspond pas a son type : l'LSA reste alors brute
+/// plutot que de faire echouer tout le Link State Update.
+fn parse_v2_lsa_body(lsa_type: u16, content: &[u8]) -> Option<OspfLsaBody<'_>> {
+    match lsa_type {
+        ROUTER_LSA_TYPE => parse_router_lsa(content).map(OspfLsaBody::Router),
+        NETWORK_LSA_TYPE => {
+            let network_mask = ipv4_at(content.get(..4)?, 0);
+            let routers = &content[4..];
+            let routers_length = routers.len() / OSPF_ROUTER_ID_LENGTH * OSPF_ROUTER_ID_LENGTH;
+            Some(OspfLsaBody::Network(OspfNetworkLsa {
+                network_mask,
+                attached_routers: &routers[..routers_length],
+            }))
+        }
+        SUMMARY_NETWORK_LSA_TYPE | SUMMARY_ASBR_LSA_TYPE => {
+            let content = content.get(..8)?;
+            Some(OspfLsaBody::Summary(OspfSummaryLsa {
+                network_mask: ipv4_at(content, 0),
+                metric: u24_at(content, 5),
+            }))
+        }
+        AS_EXTERNAL_LSA_TYPE | NSSA_EXTERNAL_LSA_TYPE => {
+            let content = content.get(..16)?;
+            Some(OspfLsaBody::External(OspfExternalLsa {
+                network_mask: ipv4_at(content, 0),
+                is_type_2_metric: content[4] & 0x80 != 0,
+                metric: u24_at(content, 5),
+                forwarding_address: ipv4_at(content, 8),
+                external_route_tag: u32_at(content, 12),
+            }))
+        }
+        _ => None,
+    }
+}
+
+/// Router LSA : flags, nombre de liens, puis chaque lien suivi de ses
+/// metriques TOS (4 octets chacune, sautees).
+fn parse_router_lsa(content: &[u8]) -> Option<OspfRouterLsa> {
+    let header = content.get(..4)?;
+    let count = usize::from(u16::from_be_bytes([header[2], header[3]]));
+
+    let mut offset = 4;
+    let mut links = Vec::with_capacity(bounded_capacity(count, content.len() - offset, 12));
+    for _ in 0..count {
+        let link = content.get(offset..offset + 12)?;
+        let tos_count = link[9];
+        links.push(OspfRouterLink {
+            link_id: ipv4_at(link, 0),
+            link_data: ipv4_at(link, 4),
+            link_type: link[8],
+            tos_count,
+            metric: u16::from_be_bytes([link[10], link[11]]),
+        });
+        offset += 12 + usize::from(tos_count) * 4;
+    }
+    if offset > content.len() {
+        return None;
+    }
+    Some(OspfRouterLsa {
+        flags: header[0],
+        links,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// En-tete OSPFv2 sans authentification, aire 0.0.0.0, routeur 1.1.1.1.
+    fn v2_packet(message_type: u8, body: &[u8]) -> Vec<u8> {
+        let length = u16::try_from(24 + body.len()).unwrap();
+        let mut packet = vec![2, message_type];
+        packet.extend_from_slice(&length.to_be_bytes());
+        packet.extend_from_slice(&[1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0]);
+        packet.extend_from_slice(&[0; 8]);
+        packet.extend_from_slice(body);
+        packet
+    }
+
+    /// En-tete d'LSA OSPFv2 pour un type et une longueur de contenu donnes.
+    fn v2_lsa(lsa_type: u8, content: &[u8]) -> Vec<u8> {
+        let length = u16::try_from(20 + content.len()).unwrap();
+        let mut lsa = vec![0x00, 0x01, 0x22, lsa_type, 10, 0, 0, 0, 1, 1, 1, 1];
+        lsa.extend_from_slice(&0x8000_0001_u32.to_be_bytes());
+        lsa.extend_from_slice(&[0x12, 0x34]);
+        lsa.extend_from_slice(&length.to_be_bytes());
+        lsa.extend_from_slice(content);
+        lsa
+    }
+
+    /// Synthetique : Hello OSPFv2 sur 10.0.0.0/24, DR 10.0.0.1, un voisin.
+    #[test]
+    fn parses_v2_hello() {
+        let body = [
+            255, 255, 255, 0, 0x00, 0x0a, 0x02, 0x01, 0x00, 0x00, 0x00, 0x28, 10, 0, 0, 1, 0, 0, 0,
+            0, 2, 2, 2, 2,
+        ];
+        let raw = v2_packet(1, &body);
+        let packet = OspfPacket::try_from(raw.as_slice()).expect("hello parses");
+
+        assert_eq!(packet.version, OspfVersion::V2);
+        assert_eq!(packet.router_id, Ipv4Addr::new(1, 1, 1, 1));
+        assert_eq!(packet.authentication_type, Some(0));
+        let OspfBody::Hello(hello) = &packet.body else {
+            panic!("expected a hello, got {:?}", packet.body);
+        };
+        assert_eq!(hello.network_mask, Some(Ipv4Addr::new(255, 255, 255, 0)));
+        assert_eq!(hello.hello_interval, 10);
+        assert_eq!(hello.router_dead_interval, 40);
+        assert_eq!(hello.designated_router, Ipv4Addr::new(10, 0, 0, 1));
+        assert_eq!(
+            hello.neighbor_ids().collect::<Vec<_>>(),
+            [Ipv4Addr::new(2, 2, 2, 2)]
+        );
+    }
+
+    /// Synthetique : Hello OSPFv3, instance 0, interface 5, priorite 1.
+    #[test]
+    fn parses_v3_hello() {
+        let mut raw = vec![3, 1, 0, 36, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0];
+        raw.extend_from_slice(&[
+            0, 0, 0, 5, 1, 0x00, 0x00, 0x13, 0x00, 0x0a, 0x00, 0x28, 0, 0, 0, 0, 0, 0, 0, 0,
+        ]);
+        let packet = OspfPacket::try_from(raw.as_slice()).expect("v3 hello parses");
+
+        assert_eq!(packet.version, OspfVersion::V3);
+        assert_eq!(packet.instance_id, Some(0));
+        assert!(packet.authentication.is_empty());
+        let OspfBody::Hello(hello) = &packet.body else {
+            panic!("expected a hello, got {:?}", packet.body);
+        };
+        assert_eq!(hello.interface_id, Some(5));
+        assert_eq!(hello.options, 0x13);
+        assert_eq!(hello.router_dead_interval, 40);
+        assert_eq!(hello.neighbor_ids().count(), 0);
+    }
+
+    /// Synthetique : Database Description initiale, master, un en-tete d'LSA.
+    #[test]
+    fn parses_v2_database_description() {
+        let mut body = vec![0x05, 0xdc, 0x42, 0x07, 0x00, 0x00, 0x10, 0x00];
+        body.extend_from_slice(&v2_lsa(1, &[])[..20]);
+        let raw = v2_packet(2, &body);
+        let packet = OspfPacket::try_from(raw.as_slice()).expect("dbd parses");
+
+        let OspfBody::DatabaseDescription(dbd) = &packet.body else {
+            panic!("expected a DBD, got {:?}", packet.body);
+        };
+        assert_eq!(dbd.interface_mtu, 1500);
+        assert!(dbd.is_initial() && dbd.has_more() && dbd.is_master());
+        assert_eq!(dbd.sequence_number, 0x1000);
+        assert_eq!(dbd.lsa_headers.len(), 1);
+        assert_eq!(dbd.lsa_headers[0].sequence_number, 0x8000_0001);
+    }
+
+    /// Synthetique : LSU portant une Router LSA (un lien stub avec une
+    /// metrique TOS) et une AS-external de type 2.
+    #[test]
+    fn parses_v2_link_state_update_with_typed_lsas() {
+        let router = v2_lsa(
+            1,
+            &[
+                0x02, 0, 0, 1, 10, 0, 0, 0, 255, 255, 255, 0, 3, 1, 0, 10, 0, 0, 0, 20,
+            ],
+        );
+        let external = v2_lsa(
+            5,
+            &[255, 255, 255, 0, 0x80, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 7],
+        );
+        let mut body = 2u32.to_be_bytes().to_vec();
+        body.extend_from_slice(&router);
+        body.extend_from_slice(&external);
+        let raw = v2_packet(4, &body);
+        let packet = OspfPacket::try_from(raw.as_slice()).expect("lsu parses");
+
+        let OspfBody::LinkStateUpdate(lsas) = &packet.body else {
+            panic!("expected an LSU, got {:?}", packet.body);
+        };
+        assert_eq!(lsas.len(), 2);
+        let OspfLsaBody::Router(router) = &lsas[0].body else {
+            panic!("expected a router LSA, got {:?}", lsas[0].body);
+        };
+        assert_eq!(router.flags, 0x02);
+        assert_eq!(router.links.len(), 1);
+        assert_eq!(router.links[0].link_type, 3);
+        assert_eq!(router.links[0].metric, 10);
+        assert_eq!(router.links[0].tos_count, 1);
+
+        let OspfLsaBody::External(external) = &lsas[1].body else {
+            panic!("expected an external LSA, got {:?}", lsas[1].body);
+        };
+        assert!(external.is_type_2_metric);
+        assert_eq!(external.metric, 20);
+        assert_eq!(external.external_route_tag, 7);
+    }
+
+    /// Synthetique : LSU annoncant deux LSA mais n'en portant qu'une.
+    #[test]
+    fn rejects_update_with_missing_lsa() {
+        let mut body = 2u32.to_be_bytes().to_vec();
+        body.extend_from_slice(&v2_lsa(3, &[255, 255, 0, 0, 0, 0, 0, 1]));
+        let raw = v2_packet(4, &body);
+
+        assert_eq!(
+            OspfPacket::try_from(raw.as_slice()).unwrap_err(),
+            OspfError::TruncatedLsa {
+                index: 1,
+                expected: 20,
+                actual: 0
+            }
+        );
+    }
+
+    /// Synthetique : version 1, jamais deployee.
+    #[test]
+    fn rejects_unknown_version() {
+        assert_eq!(
+            OspfPacket::try_from(&[1u8, 1, 0, 24][..]).unwrap_err(),
+            OspfError::UnsupportedVersion(1)
+        );
+    }
+}
@@ -0,0 +1,606 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Parseur PIMv2 (IP protocole 103) : PIM-SM (RFC 7761) et son mecanisme de
+//! bootstrap (RFC 5059).
+//!
+//! Atteint par le numero de protocole IP, comme ICMP et IGMP. Seule la
+//! version 2 est decodee : PIMv1 n'a pas de numero de protocole propre, il
+//! circule dans IGMP (type 0x14) et y reste expose brut.
+
+use std::convert::TryFrom;
+use std::net::IpAddr;
+
+use crate::{
+    checks::transport::pim::{
+        PIM_HEADER_LENGTH, extract_encoded_group, extract_encoded_source, extract_encoded_unicast,
+        extract_pim_hello_option, extract_pim_version, validate_pim_length,
+    },
+    errors::transport::pim::PimError,
+    parse::application::protocols::bounded_capacity,
+};
+
+/// Types de message PIMv2 interpretes (registre IANA « PIM Message Types »).
+const HELLO_TYPE: u8 = 0;
+const REGISTER_TYPE: u8 = 1;
+const REGISTER_STOP_TYPE: u8 = 2;
+const JOIN_PRUNE_TYPE: u8 = 3;
+const BOOTSTRAP_TYPE: u8 = 4;
+const ASSERT_TYPE: u8 = 5;
+const GRAFT_TYPE: u8 = 6;
+const GRAFT_ACK_TYPE: u8 = 7;
+
+/// Options Hello interpretees (RFC 7761 §4.9.2).
+const HOLDTIME_OPTION: u16 = 1;
+const LAN_PRUNE_DELAY_OPTION: u16 = 2;
+const DR_PRIORITY_OPTION: u16 = 19;
+const GENERATION_ID_OPTION: u16 = 20;
+const ADDRESS_LIST_OPTION: u16 = 24;
+
+/// Plus petite adresse encodee IPv4 (groupe ou source) : famille, encodage,
+/// drapeaux, masque et 4 octets d'adresse. Sert a borner les pre-allocations.
+const MIN_ENCODED_GROUP_LENGTH: usize = 8;
+
+/// Encoded-Group address (RFC 7761 §4.9.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PimEncodedGroup {
+    pub address: IpAddr,
+    pub mask_length: u8,
+    /// Drapeau B : groupe bidirectionnel (RFC 5015).
+    pub bidirectional: bool,
+    /// Drapeau Z : zone administrative (RFC 5059).
+    pub admin_scope_zone: bool,
+}
+
+/// Encoded-Source address (RFC 7761 §4.9.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PimEncodedSource {
+    pub address: IpAddr,
+    pub mask_length: u8,
+    /// Drapeau S, toujours pose en PIM-SM.
+    pub sparse: bool,
+    /// Drapeau W : l'entree est un (*,G), l'adresse est celle du RP.
+    pub wildcard: bool,
+    /// Drapeau R : l'entree suit l'arbre partage du RP.
+    pub rendezvous_point_tree: bool,
+}
+
+/// Option d'un Hello (RFC 7761 §4.9.2). Une option connue dont la longueur
+/// ne correspond pas a sa definition est exposee en `Other`, pas devinee.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PimHelloOption<'a> {
+    /// Duree de validite du voisin, en secondes (0xFFFF = infini).
+    Holdtime(u16),
+    LanPruneDelay {
+        tracking_support: bool,
+        /// En millisecondes.
+        propagation_delay: u16,
+        /// En millisecondes.
+        override_interval: u16,
+    },
+    DrPriority(u32),
+    GenerationId(u32),
+    /// Adresses secondaires de l'interface emettrice.
+    AddressList(Vec<IpAddr>),
+    Other {
+        option_type: u16,
+        value: &'a [u8],
+    },
+}
+
+/// Register (type 1) : un paquet multicast encapsule par le DR vers le RP.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PimRegister<'a> {
+    /// Drapeau B : emis par un Border Router.
+    pub border: bool,
+    /// Drapeau N : Null-Register, sonde sans paquet encapsule utile.
+    pub null_register: bool,
+    /// Paquet IP multicast encapsule, zero-copy.
+    pub inner_packet: &'a [u8],
+}
+
+/// Register-Stop (type 2).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PimRegisterStop {
+    pub group: PimEncodedGroup,
+    pub source: IpAddr,
+}
+
+/// Groupe d'un Join/Prune, avec ses sources jointes et elaguees.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PimJoinPruneGroup {
+    pub group: PimEncodedGroup,
+    pub joined_sources: Vec<PimEncodedSource>,
+    pub pruned_sources: Vec<PimEncodedSource>,
+}
+
+/// Join/Prune (type 3) ; Graft (6) et Graft-Ack (7) partagent ce format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PimJoinPrune {
+    /// Voisin amont a qui s'adresse le message.
+    pub upstream_neighbor: IpAddr,
+    /// Duree de validite de l'etat, en secondes.
+    pub holdtime: u16,
+    pub groups: Vec<PimJoinPruneGroup>,
+}
+
+/// RP candidat d'un Bootstrap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PimBootstrapRp {
+    pub address: IpAddr,
+    pub holdtime: u16,
+    pub priority: u8,
+}
+
+/// Plage de groupes d'un Bootstrap et les RP qui la servent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PimBootstrapGroup {
+    pub group: PimEncodedGroup,
+    /// Nombre total de RP pour la plage, tous fragments confondus.
+    pub rp_count: u8,
+    pub rps: Vec<PimBootstrapRp>,
+}
+
+/// Bootstrap (type 4, RFC 5059 §4.1).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PimBootstrap {
+    pub fragment_tag: u16,
+    pub hash_mask_length: u8,
+    pub bsr_priority: u8,
+    pub bsr_address: IpAddr,
+    pub groups: Vec<PimBootstrapGroup>,
+}
+
+/// Assert (type 5) : election du forwarder d'un LAN.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PimAssert {
+    pub group: PimEncodedGroup,
+    pub source: IpAddr,
+    /// Drapeau R : l'assert porte sur l'arbre partage.
+    pub rendezvous_point_tree: bool,
+    pub metric_preference: u32,
+    pub metric: u32,
+}
+
+/// Corps PIM, choisi selon le type de message.
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PimBody<'a> {
+    Hello(Vec<PimHelloOption<'a>>),
+    Register(PimRegister<'a>),
+    RegisterStop(PimRegisterStop),
+    /// Join/Prune, Graft ou Graft-Ack (voir [`PimPacket::message_type`]).
+    JoinPrune(PimJoinPrune),
+    Bootstrap(PimBootstrap),
+    Assert(PimAssert),
+    /// Type non interprete (Candidate-RP-Advertisement, State Refresh...) :
+    /// octets bruts apres l'en-tete commun.
+    Other(&'a [u8]),
+}
+
+/// Message PIMv2 (RFC 7761 §4.9).
+///
+/// L'en-tete commun tient en quatre octets ; le schema montre un Assert, de
+/// taille fixe en IPv4.
+///
+/// ```mermaid
+/// ---
+/// title: PimPacket (corps Assert, IPv4)
+/// ---
+/// packet-beta
+/// 0-3: "Version u4"
+/// 4-7: "Type u4"
+/// 8-15: "Reserved u8"
+/// 16-31: "Checksum u16"
+/// 32-95: "Group Address (Encoded-Group) 8 octets"
+/// 96-143: "Source Address (Encoded-Unicast) 6 octets"
+/// 144: "R"
+/// 145-175: "Metric Preference u31"
+/// 176-207: "Metric u32"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PimPacket<'a> {
+    pub version: u8,
+    pub message_type: u8,
+    pub checksum: u16,
+    pub body: PimBody<'a>,
+}
+
+impl<'a> TryFrom<&'a [u8]> for PimPacket<'a> {
+    type Error = PimError;
+
+    fn try_from(payload: &'a [u8]) -> Result<Self, PimError> {
+        validate_pim_length(payload, PIM_HEADER_LENGTH)?;
+
+        let version = extract_pim_version(payload[0])?;
+        let message_type = payload[0] & 0x0F;
+        let checksum = u16::from_be_bytes([payload[2], payload[3]]);
+
+        let body = match message_type {
+            HELLO_TYPE => PimBody::Hello(parse_hello(payload)?),
+            REGISTER_TYPE => PimBody::Register(parse_register(payload)?),
+            REGISTER_STOP_TYPE => PimBody::RegisterStop(parse_register_stop(payload)?),
+            JOIN_PRUNE_TYPE | GRAFT_TYPE | GRAFT_ACK_TYPE => {
+                PimBody::JoinPrune(parse_join_prune(payload)?)
+            }
+            BOOTSTRAP_TYPE => PimBody::Bootstrap(parse_bootstrap(payload)?),
+            ASSERT_TYPE => PimBody::Assert(parse_assert(payload)?),
+            _ => PimBody::Other(&payload[PIM_HEADER_LENGTH..]),
+        };
+
+        Ok(PimPacket {
+            version,
+            message_type,
+            checksum,
+            body,
+        })
+    }
+}
+
+fn parse_hello(payload: &[u8]) -> Result<Vec<PimHelloOption<'_>>, PimError> {
+    let mut options = Vec::new();
+    let mut offset = PIM_HEADER_LENGTH;
+    while offset < payload.len() {
+        let (option_type, value, next) = extract_pim_hello_option(payload, offset)?;
+        options.push(hello_option(option_type, value)?);
+        offset = next;
+    }
+    Ok(options)
+}
+
+fn hello_option(option_type: u16, value: &[u8]) -> Result<PimHelloOption<'_>, PimError> {
+    let option = match (option_type, value.len()) {
+        (HOLDTIME_OPTION, 2) => PimHelloOption::Holdtime(u16::from_be_bytes([value[0], value[1]])),
+        (LAN_PRUNE_DELAY_OPTION, 4) => PimHelloOption::LanPruneDelay {
+            tracking_support: value[0] & 0x80 != 0,
+            propagation_delay: u16::from_be_bytes([value[0] & 0x7F, value[1]]),
+            override_interval: u16::from_be_bytes([value[2], value[3]]),
+        },
+        (DR_PRIORITY_OPTION, 4) => {
+            PimHelloOption::DrPriority(u32::from_be_bytes([value[0], value[1], value[2], value[3]]))
+        }
+        (GENERATION_ID_OPTION, 4) => PimHelloOption::GenerationId(u32::from_be_bytes([
+            value[0], value[1], value[2], value[3],
+        ])),
+        (ADDRESS_LIST_OPTION, _) => {
+            let mut addresses = Vec::new();
+            let mut offset = 0;
+            while offset < value.len() {
+                let (address, next) = extract_encoded_unicast(value, offset)?;
+                addresses.push(address);
+                offset = next;
+            }
+            PimHelloOption::AddressList(addresses)
+        }
+        _ => PimHelloOption::Other { option_type, value },
+    };
+    Ok(option)
+}
+
+fn parse_register(payload: &[u8]) -> Result<PimRegister<'_>, PimError> {
+    validate_pim_length(payload, PIM_HEADER_LENGTH + 4)?;
+    let flags = payload[PIM_HEADER_LENGTH];
+    Ok(PimRegister {
+        border: flags & 0x80 != 0,
+        null_register: flags & 0x40 != 0,
+        inner_packet: &payload[PIM_HEADER_LENGTH + 4..],
+    })
+}
+
+fn parse_register_stop(payload: &[u8]) -> Result<PimRegisterStop, PimError> {
+    let (group, offset) = extract_encoded_group(payload, PIM_HEADER_LENGTH)?;
+    let (source, _) = extract_encoded_unicast(payload, offset)?;
+    Ok(PimRegisterStop { group, source })
+}
+
+/// Lit `count` adresses sources encodees a partir de `offset`.
+fn parse_sources(
+    payload: &[u8],
+    mut offset: usize,
+    count: u16,
+) -> Result<(Vec<PimEncodedSource>, usize), PimError> {
+    let mut sources = Vec::with_capacity(bounded_capacity(
+        usize::from(count),
+        payload.len().saturating_sub(offset),
+        MIN_ENCODED_GROUP_LENGTH,
+    ));
+    for _ in 0..count {
+        let (source, next) = extract_encoded_source(payload, offset)?;
+        sources.push(source);
+        offset = next;
+    }
+    Ok((sources, offset))
+}
+
+fn parse_join_prune(payload: &[u8]) -> Result<PimJoinPrune, PimError> {
+    let (upstream_neighbor, offset) = extract_encoded_unicast(payload, PIM_HEADER_LENGTH)?;
+    // Reserve u8, nombre de groupes u8, holdtime u16.
+    validate_pim_length(payload, offset + 4)?;
+    let group_count = payload[offset + 1];
+    let holdtime = u16::from_be_bytes([payload[offset + 2], payload[offset + 3]]);
+
+    let mut offset = offset + 4;
+    let mut groups = Vec::with_capacity(bounded_capacity(
+        usize::from(group_count),
+        payload.len() - offset,
+        MIN_ENCODED_GROUP_LENGTH + 4,
+    ));
+    for _ in 0..group_count {
+        let (group, next) = extract_encoded_group(payload, offset)?;
+        validate_pim_length(payload, next + 4)?;
+        let joined_count = u16::from_be_bytes([payload[next], payload[next + 1]]);
+        let pruned_count = u16::from_be_bytes([payload[next + 2], payload[next + 3]]);
+        let (joined_sources, next) = parse_sources(payload, next + 4, joined_count)?;
+        let (pruned_sources, next) = parse_sources(payload, next, pruned_count)?;
+        groups.push(PimJoinPruneGroup {
+            group,
+            joined_sources,
+            pruned_sources,
+        });
+        offset = next;
+    }
+
+    Ok(PimJoinPrune {
+        upstream_neighbor,
+        holdtime,
+        groups,
+    })
+}
+
+fn parse_bootstrap(payload: &[u8]) -> Result<PimBootstrap, PimError> {
+    // Fragment tag u16, hash mask len u8, BSR priority u8.
+    validate_pim_length(payload, PIM_HEADER_LENGTH + 4)?;
+    let fragment_tag = u16::from_be_bytes([payload[4], payload[5]]);
+    let hash_mask_length = payload[6];
+    let bsr_priority = payload[7];
+    let (bsr_address, mut offset) = extract_encoded_unicast(payload, PIM_HEADER_LENGTH + 4)?;
+
+    let mut groups = Vec::new();
+    while offset < payload.len() {
+        let (group, next) = extract_encoded_group(payload, offset)?;
+        // RP count, fragment RP count, reserve u16.
+        validate_pim_length(payload, next + 4)?;
+        let rp_count = payload[next];
+        let fragment_rp_count = payload[next + 1];
+
+        offset = next + 4;
+        let mut rps = Vec::with_capacity(bounded_capacity(
+            usize::from(fragment_rp_count),
+            payload.len() - offset,
+            MIN_ENCODED_GROUP_LENGTH + 2,
+        ));
+        for _ in 0..fragment_rp_count {
+            let (address, next) = extract_encoded_unicast(payload, offset)?;
+            // Holdtime u16, priorite u8, reserve u8.
+            validate_pim_length(payload, next + 4)?;
+            rps.push(PimBootstrapRp {
+                address,
+                holdtime: u16::from_be_bytes([payload[next], payload[next + 1]]),
+                priority: payload[next + 2],
+            });
+            offset = next + 4;
+        }
+        groups.push(PimBootstrapGroup {
+            group,
+            rp_count,
+            rps,
+        });
+    }
+
+    Ok(PimBootstrap {
+        fragment_tag,
+        hash_mask_length,
+        bsr_priority,
+        bsr_address,
+        groups,
+    })
+}
+
+fn parse_assert(payload: &[u8]) -> Result<PimAssert, PimError> {
+    let (group, offset) = extract_encoded_group(payload, PIM_HEADER_LENGTH)?;
+    let (source, offset) = extract_encoded_unicast(payload, offset)?;
+    validate_pim_length(payload, offset + 8)?;
+    let preference = u32::from_be_bytes([
+        payload[offset],
+        payload[offset + 1],
+        payload[offset + 2],
+        payload[offset + 3],
+    ]);
+    Ok(PimAssert {
+        group,
+        source,
+        rendezvous_point_tree: preference & 0x8000_0000 != 0,
+        metric_preference: preference & 0x7FFF_FFFF,
+        metric: u32::from_be_bytes([
+            payload[offset + 4],
+            payload[offset + 5],
+            payload[offset + 6],
+            payload[offset + 7],
+        ]),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    fn v4(a: u8, b: u8, c: u8, d: u8) -> IpAddr {
+        IpAddr::V4(Ipv4Addr::new(a, b, c, d))
+    }
+
+    /// Synthetique : Hello avec holdtime 105 s, DR priority 1, generation id
+    /// et LAN prune delay (disposition d'un Hello Cisco IOS).
+    #[test]
+    fn parses_hello_options() {
+        let raw = [
+            0x20, 0x00, 0x00, 0x00, // v2, Hello
+            0x00, 0x01, 0x00, 0x02, 0x00, 0x69, // holdtime 105
+            0x00, 0x13, 0x00, 0x04, 0x00, 0x00, 0x00, 0x01, // DR priority 1
+            0x00, 0x14, 0x00, 0x04, 0x12, 0x34, 0x56, 0x78, // generation id
+            0x00, 0x02, 0x00, 0x04, 0x81, 0xf4, 0x09, 0xc4, // T, 500 ms, 2500 ms
+        ];
+        let packet = PimPacket::try_from(&raw[..]).expect("hello parses");
+
+        assert_eq!(packet.version, 2);
+        assert_eq!(packet.message_type, 0);
+        let PimBody::Hello(options) = &packet.body else {
+            panic!("expected a hello, got {:?}", packet.body);
+        };
+        assert_eq!(
+            options,
+            &[
+                PimHelloOption::Holdtime(105),
+                PimHelloOption::DrPriority(1),
+                PimHelloOption::GenerationId(0x1234_5678),
+                PimHelloOption::LanPruneDelay {
+                    tracking_support: true,
+                    propagation_delay: 500,
+                    override_interval: 2500,
+                },
+            ]
+        );
+    }
+
+    /// Synthetique : Join/Prune d'un (*,G) vers le voisin 10.0.0.1.
+    #[test]
+    fn parses_join_prune_with_wildcard_join() {
+        let raw = [
+            0x23, 0x00, 0x00, 0x00, // v2, Join/Prune
+            0x01, 0x00, 10, 0, 0, 1, // upstream neighbor
+            0x00, 0x01, 0x00, 0xd2, // reserve, 1 groupe, holdtime 210
+            0x01, 0x00, 0x00, 0x20, 239, 1, 1, 1, // groupe 239.1.1.1/32
+            0x00, 0x01, 0x00, 0x00, // 1 joint, 0 elague
+            0x01, 0x00, 0x07, 0x20, 10, 9, 9, 9, // S/W/R, RP 10.9.9.9/32
+        ];
+        let packet = PimPacket::try_from(&raw[..]).expect("join/prune parses");
+
+        let PimBody::JoinPrune(join) = &packet.body else {
+            panic!("expected a join/prune, got {:?}", packet.body);
+        };
+        assert_eq!(join.upstream_neighbor, v4(10, 0, 0, 1));
+        assert_eq!(join.holdtime, 210);
+        assert_eq!(join.groups.len(), 1);
+        let group = &join.groups[0];
+        assert_eq!(group.group.address, v4(239, 1, 1, 1));
+        assert_eq!(group.joined_sources.len(), 1);
+        assert!(group.joined_sources[0].wildcard);
+        assert!(group.joined_sources[0].rendezvous_point_tree);
+        assert_eq!(group.joined_sources[0].address, v4(10, 9, 9, 9));
+        assert!(group.pruned_sources.is_empty());
+    }
+
+    /// Synthetique : Register d'un paquet IPv4 tronque, drapeau N absent.
+    #[test]
+    fn parses_register_and_exposes_inner_packet() {
+        let raw = [0x21, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x45, 0x00];
+        let packet = PimPacket::try_from(&raw[..]).expect("register parses");
+
+        let PimBody::Register(register) = &packet.body else {
+            panic!("expected a register, got {:?}", packet.body);
+        };
+        assert!(!register.border);
+        assert!(!register.null_register);
+        assert_eq!(register.inner_packet, &[0x45, 0x00]);
+    }
+
+    /// Synthetique : Assert (S,G) metrique 10, preference 110.
+    #[test]
+    fn parses_assert() {
+        let raw = [
+            0x25, 0x00, 0x00, 0x00, // v2, Assert
+            0x01, 0x00, 0x00, 0x20, 239, 1, 1, 1, // groupe
+            0x01, 0x00, 10, 1, 1, 1, // source
+            0x00, 0x00, 0x00, 0x6e, // R=0, preference 110
+            0x00, 0x00, 0x00, 0x0a, // metrique 10
+        ];
+        let packet = PimPacket::try_from(&raw[..]).expect("assert parses");
+
+        assert_eq!(
+            packet.body,
+            PimBody::Assert(PimAssert {
+                group: PimEncodedGroup {
+                    address: v4(239, 1, 1, 1),
+                    mask_length: 32,
+                    bidirectional: false,
+                    admin_scope_zone: false,
+                },
+                source: v4(10, 1, 1, 1),
+                rendezvous_point_tree: false,
+                metric_preference: 110,
+                metric: 10,
+            })
+        );
+    }
+
+    /// Synthetique : Bootstrap annoncant un RP pour 224.0.0.0/4.
+    #[test]
+    fn parses_bootstrap() {
+        let raw = [
+            0x24, 0x00, 0x00, 0x00, // v2, Bootstrap
+            0x12, 0x34, 0x1e, 0x64, // tag, hash mask 30, priorite 100
+            0x01, 0x00, 10, 0, 0, 254, // BSR
+            0x01, 0x00, 0x00, 0x04, 224, 0, 0, 0, // groupe 224.0.0.0/4
+            0x01, 0x01, 0x00, 0x00, // 1 RP, 1 dans ce fragment
+            0x01, 0x00, 10, 0, 0, 1, 0x00, 0x96, 0xc0, 0x00, // RP, 150 s, prio 192
+        ];
+        let packet = PimPacket::try_from(&raw[..]).expect("bootstrap parses");
+
+        let PimBody::Bootstrap(bootstrap) = &packet.body else {
+            panic!("expected a bootstrap, got {:?}", packet.body);
+        };
+        assert_eq!(bootstrap.fragment_tag, 0x1234);
+        assert_eq!(bootstrap.hash_mask_length, 30);
+        assert_eq!(bootstrap.bsr_priority, 100);
+        assert_eq!(bootstrap.bsr_address, v4(10, 0, 0, 254));
+        assert_eq!(bootstrap.groups.len(), 1);
+        assert_eq!(
+            bootstrap.groups[0].rps,
+            [PimBootstrapRp {
+                address: v4(10, 0, 0, 1),
+                holdtime: 150,
+                priority: 192,
+            }]
+        );
+    }
+
+    /// Synthetique : PIMv1 (version 1) refuse.
+    #[test]
+    fn rejects_pim_version_1() {
+        assert_eq!(
+            PimPacket::try_from(&[0x10, 0x00, 0x00, 0x00][..]).unwrap_err(),
+            PimError::UnsupportedVersion(1)
+        );
+    }
+
+    /// Synthetique : Join/Prune annoncant un groupe absent.
+    #[test]
+    fn rejects_truncated_join_prune() {
+        let raw = [
+            0x23, 0x00, 0x00, 0x00, 0x01, 0x00, 10, 0, 0, 1, 0x00, 0x01, 0x00, 0xd2,
+        ];
+
+        assert_eq!(
+            PimPacket::try_from(&raw[..]).unwrap_err(),
+            PimError::TruncatedEncodedAddress { offset: 14 }
+        );
+    }
+
+    /// Synthetique : option Hello annoncant plus d'octets que presents.
+    #[test]
+    fn rejects_truncated_hello_option() {
+        let raw = [0x20, 0x00, 0x00, 0x00, 0x00, 0x01, 0x00, 0x04, 0x00, 0x69];
+
+        assert_eq!(
+            PimPacket::try_from(&raw[..]).unwrap_err(),
+            PimError::TruncatedHelloOption {
+                option_type: 1,
+                declared: 4,
+                available: 2
+            }
+        );
+    }
+}
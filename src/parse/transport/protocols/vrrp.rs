@@ -0,0 +1,243 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Parseur VRRP (IP protocole 112) : VRRPv2 (RFC 3768) et VRRPv3
+//! (RFC 5798).
+//!
+//! Comme IGMP, VRRP n'a ni port ni session : il est atteint par le numero de
+//! protocole IP dans `Transport::try_from_parts`. Les deux versions ne
+//! different que par le second mot de l'en-tete — type et intervalle
+//! d'authentification en v2, intervalle en centiemes de seconde en v3 — et
+//! par la famille des adresses virtuelles.
+
+use std::convert::TryFrom;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::{
+    checks::transport::vrrp::{
+        VRRP_HEADER_LENGTH, VRRP_IPV4_ADDRESS_LENGTH, VRRP_IPV6_ADDRESS_LENGTH,
+        VRRP_V2_AUTHENTICATION_DATA_LENGTH, extract_vrrp_address_family, extract_vrrp_version,
+        validate_vrrp_min_length,
+    },
+    errors::transport::vrrp::VrrpError,
+};
+
+/// Priorite du proprietaire des adresses virtuelles (RFC 5798 §5.2.4).
+const ADDRESS_OWNER_PRIORITY: u8 = 255;
+
+/// Priorite annoncee par un master qui quitte son role.
+const RESIGNING_PRIORITY: u8 = 0;
+
+/// Famille des adresses virtuelles annoncees.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VrrpAddressFamily {
+    Ipv4,
+    Ipv6,
+}
+
+/// Annonce VRRP, v2 ou v3.
+///
+/// ```mermaid
+/// ---
+/// title: VrrpPacket (v3)
+/// ---
+/// packet-beta
+/// 0-3: "Version"
+/// 4-7: "Type"
+/// 8-15: "Virtual Rtr ID"
+/// 16-23: "Priority"
+/// 24-31: "Count IPvX Addr"
+/// 32-35: "Rsvd"
+/// 36-47: "Max Adver Int"
+/// 48-63: "Checksum"
+/// 64-95: "IPvX Address(es) variable"
+/// ```
+///
+/// En v2 le second mot porte l'Auth Type (8 bits) et l'Adver Int en
+/// secondes (8 bits), et les adresses IPv4 sont suivies de 8 octets de
+/// donnees d'authentification.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VrrpPacket<'a> {
+    /// 2 ou 3.
+    pub version: u8,
+    /// 1 (Advertisement), seul type defini.
+    pub message_type: u8,
+    pub virtual_router_id: u8,
+    pub priority: u8,
+    pub address_count: u8,
+    /// Auth Type (v2 seulement) : 0 aucune, 1 mot de passe en clair ;
+    /// 254 est la variante MD5 proprietaire rencontree en production.
+    pub authentication_type: Option<u8>,
+    /// Intervalle d'annonce brut : secondes en v2, centiemes de seconde en
+    /// v3. Voir [`VrrpPacket::advertisement_interval_centiseconds`].
+    pub advertisement_interval: u16,
+    pub checksum: u16,
+    pub address_family: VrrpAddressFamily,
+    /// Adresses virtuelles, zero-copy.
+    pub addresses: &'a [u8],
+    /// Les 8 octets d'authentification d'un VRRPv2 (RFC 3768), moins si
+    /// le paquet est tronque. Vide en v3.
+    pub authentication_data: &'a [u8],
+    /// Octets restants apres l'authentification (v2) ou les adresses
+    /// (v3) : bourrage ou extension proprietaire, comme le HMAC de
+    /// l'Auth Type 254.
+    pub trailer: &'a [u8],
+}
+
+impl VrrpPacket<'_> {
+    /// Intervalle d'annonce normalise en centiemes de seconde.
+    pub fn advertisement_interval_centiseconds(&self) -> u32 {
+        match self.version {
+            2 => u32::from(self.advertisement_interval) * 100,
+            _ => u32::from(self.advertisement_interval),
+        }
+    }
+
+    /// Adresses virtuelles annoncees.
+    pub fn virtual_addresses(&self) -> impl Iterator<Item = IpAddr> + '_ {
+        let chunk_length = match self.address_family {
+            VrrpAddressFamily::Ipv4 => VRRP_IPV4_ADDRESS_LENGTH,
+            VrrpAddressFamily::Ipv6 => VRRP_IPV6_ADDRESS_LENGTH,
+        };
+        self.addresses
+            .chunks_exact(chunk_length)
+            .map(|chunk| match <[u8; 16]>::try_from(chunk) {
+                Ok(octets) => IpAddr::V6(Ipv6Addr::from(octets)),
+                Err(_) => IpAddr::V4(Ipv4Addr::new(chunk[0], chunk[1], chunk[2], chunk[3])),
+            })
+    }
+
+    /// L'emetteur possede les adresses virtuelles (priorite 255).
+    pub fn is_address_owner(&self) -> bool {
+        self.priority == ADDRESS_OWNER_PRIORITY
+    }
+
+    /// Le master abandonne son role (priorite 0) : un backup doit prendre
+    /// le relais sans attendre l'expiration.
+    pub fn is_resigning(&self) -> bool {
+        self.priority == RESIGNING_PRIORITY
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for VrrpPacket<'a> {
+    type Error = VrrpError;
+
+    fn try_from(payload: &'a [u8]) -> Result<Self, VrrpError> {
+        validate_vrrp_min_length(payload)?;
+
+        let version = extract_vrrp_version(payload[0])?;
+        let address_count = payload[3];
+        let (authentication_type, advertisement_interval) = match version {
+            2 => (Some(payload[4]), u16::from(payload[5])),
+            _ => (None, u16::from_be_bytes([payload[4], payload[5]]) & 0x0FFF),
+        };
+
+        let rest = &payload[VRRP_HEADER_LENGTH..];
+        let address_family = extract_vrrp_address_family(version, address_count, rest)?;
+        let addresses_length = usize::from(address_count)
+            * match address_family {
+                VrrpAddressFamily::Ipv4 => VRRP_IPV4_ADDRESS_LENGTH,
+                VrrpAddressFamily::Ipv6 => VRRP_IPV6_ADDRESS_LENGTH,
+            };
+        let (addresses, rest) = rest.split_at(addresses_length);
+        let (authentication_data, trailer) = match version {
+            2 => rest.split_at(rest.len().min(VRRP_V2_AUTHENTICATION_DATA_LENGTH)),
+            _ => (&[][..], rest),
+        };
+
+        Ok(VrrpPacket {
+            version,
+            message_type: payload[0] & 0x0F,
+            virtual_router_id: payload[1],
+            priority: payload[2],
+            address_count,
+            authentication_type,
+            advertisement_interval,
+            checksum: u16::from_be_bytes([payload[6], payload[7]]),
+            address_family,
+            addresses,
+            authentication_data,
+            trailer,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Trame 28153 : annonce VRRPv2 de 192.168.42.5, VRID 1, auth type 254
+    /// suivi de l'extension MD5 proprietaire
+    /// (pcaps_exemple/The-Ultimate-PCAP.pcapng, LINKTYPE 274).
+    const V2_ADVERTISEMENT: &str = concat!(
+        "21016401fe01e76ec0a82a010000000000000000fe1c01000000c0a82a050000",
+        "0000c010264f5ec8561d45c7a086694bd638"
+    );
+
+    #[test]
+    fn parses_v2_advertisement_from_capture() {
+        let raw = hex::decode(V2_ADVERTISEMENT).expect("invalid test hex fixture");
+        let packet = VrrpPacket::try_from(raw.as_slice()).expect("captured advertisement parses");
+
+        assert_eq!(packet.version, 2);
+        assert_eq!(packet.message_type, 1);
+        assert_eq!(packet.virtual_router_id, 1);
+        assert_eq!(packet.priority, 100);
+        assert_eq!(packet.authentication_type, Some(254));
+        assert_eq!(packet.advertisement_interval_centiseconds(), 100);
+        assert_eq!(packet.checksum, 0xe76e);
+        assert_eq!(
+            packet.virtual_addresses().collect::<Vec<_>>(),
+            [IpAddr::V4(Ipv4Addr::new(192, 168, 42, 1))]
+        );
+        assert_eq!(packet.authentication_data, [0; 8]);
+        // Extension MD5 proprietaire de l'Auth Type 254.
+        assert_eq!(packet.trailer.len(), 30);
+        assert!(!packet.is_address_owner());
+    }
+
+    /// Synthetique : VRRPv3 IPv6, proprietaire, intervalle 100 cs.
+    #[test]
+    fn parses_v3_ipv6_advertisement() {
+        let mut raw = vec![0x31, 0x0a, 0xff, 0x01, 0x00, 0x64, 0x00, 0x00];
+        raw.extend_from_slice(&"fe80::1".parse::<Ipv6Addr>().unwrap().octets());
+        let packet = VrrpPacket::try_from(raw.as_slice()).expect("v3 parses");
+
+        assert_eq!(packet.version, 3);
+        assert_eq!(packet.authentication_type, None);
+        assert_eq!(packet.advertisement_interval_centiseconds(), 100);
+        assert_eq!(packet.address_family, VrrpAddressFamily::Ipv6);
+        assert!(packet.is_address_owner());
+        assert!(packet.authentication_data.is_empty());
+        assert!(packet.trailer.is_empty());
+        assert_eq!(
+            packet.virtual_addresses().collect::<Vec<_>>(),
+            [IpAddr::V6("fe80::1".parse().unwrap())]
+        );
+    }
+
+    /// Synthetique : VRRPv3 IPv4, master qui se retire.
+    #[test]
+    fn parses_v3_ipv4_resignation() {
+        let raw = [
+            0x31, 0x05, 0x00, 0x01, 0x00, 0x64, 0x00, 0x00, 10, 0, 0, 254,
+        ];
+        let packet = VrrpPacket::try_from(&raw[..]).expect("v3 parses");
+
+        assert_eq!(packet.address_family, VrrpAddressFamily::Ipv4);
+        assert!(packet.is_resigning());
+    }
+
+    /// Synthetique : version 1, jamais normalisee.
+    #[test]
+    fn rejects_unknown_version() {
+        let raw = [0x11, 0x01, 0x64, 0x00, 0, 0, 0, 0];
+
+        assert_eq!(
+            VrrpPacket::try_from(&raw[..]).unwrap_err(),
+            VrrpError::UnsupportedVersion(1)
+        );
+    }
+}
@@ -0,0 +1,140 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests BACnet/IP sur trames Ethernet completes.
+//!
+//! Aucune capture BACnet publique n'est versionnee dans le depot : les
+//! trames sont synthetiques, construites d'apres ASHRAE 135 (Annex J pour
+//! BVLL, checksums IPv4/UDP corrects). Poste de supervision 192.168.20.50,
+//! automate 192.168.20.10 (device 100), UDP 47808.
+
+use packet_parser::parse::application::protocols::bacnet::{
+    BacnetPacket,
+    apdu::{BacnetApdu, BacnetService},
+    tag::BacnetValue,
+};
+use packet_parser::{LinkType, parse};
+
+/// Supervision -> diffusion : Who-Is global (DNET 0xFFFF, hop count 255).
+const WHO_IS_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e0001020800450000280100400040118f43c0a81432c0a8",
+    "14ffbac0bac000144c84810b000c0120ffff00ff1008"
+);
+
+/// Automate -> diffusion : I-Am du device 100, APDU max 1476, sans
+/// segmentation, vendor 260.
+const I_AM_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a010800450000310200400040118e62c0a8140ac0a8",
+    "14ffbac0bac0001d68ca810b001501001000c4020000642205c49103220104"
+);
+
+/// Supervision -> automate : ReadProperty analog-input 1, present-value.
+const READ_PROPERTY_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e00010208004500002d0101400040119032c0a81432c0a8",
+    "140abac0bac00019fb63810a001101040005010c0c000000011955"
+);
+
+/// Automate -> supervision : ComplexAck portant la valeur REAL 12.5.
+const READ_PROPERTY_ACK_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a010800450000330201400040118f2cc0a8140ac0a8",
+    "1432bac0bac0001f4a90810a0017010030010c0c0000000119553e4441480000",
+    "3f"
+);
+
+/// Ethernet (14) + IPv4 (20) + UDP (8).
+const UDP_PAYLOAD_OFFSET: usize = 42;
+
+fn frame(hex_fixture: &str, expected_len: usize) -> Vec<u8> {
+    let bytes = hex::decode(hex_fixture).expect("invalid test hex fixture");
+    assert_eq!(bytes.len(), expected_len, "fixture length must match frame");
+    bytes
+}
+
+fn application_label(bytes: &[u8]) -> &'static str {
+    parse(LinkType::ETHERNET, bytes)
+        .expect("synthetic frame decodes")
+        .application
+        .expect("an application layer is detected")
+        .application_protocol
+}
+
+fn apdu(bytes: &[u8]) -> BacnetApdu<'_> {
+    let packet = BacnetPacket::try_from(&bytes[UDP_PAYLOAD_OFFSET..]).expect("valid BVLC");
+    packet
+        .npdu()
+        .expect("BVLC function carries an NPDU")
+        .expect("valid NPDU")
+        .apdu()
+        .expect("application message")
+        .expect("valid APDU")
+}
+
+#[test]
+fn packet_flow_labels_bacnet() {
+    for (hex_fixture, len) in [
+        (WHO_IS_FRAME_HEX, 54),
+        (I_AM_FRAME_HEX, 63),
+        (READ_PROPERTY_FRAME_HEX, 59),
+        (READ_PROPERTY_ACK_FRAME_HEX, 65),
+    ] {
+        assert_eq!(application_label(&frame(hex_fixture, len)), "BACnet");
+    }
+}
+
+#[test]
+fn who_is_and_i_am_discover_the_device() {
+    let bytes = frame(WHO_IS_FRAME_HEX, 54);
+    let packet = BacnetPacket::try_from(&bytes[UDP_PAYLOAD_OFFSET..]).expect("valid BVLC");
+    assert_eq!(packet.function_name(), "Original-Broadcast-NPDU");
+    let npdu = packet.npdu().expect("NPDU").expect("valid NPDU");
+    let destination = npdu.destination.expect("global broadcast destination");
+    assert_eq!(destination.network, 0xFFFF);
+    assert_eq!(npdu.hop_count, Some(255));
+    let who_is = npdu.apdu().expect("APDU").expect("valid APDU");
+    assert_eq!(who_is.service_name(), Some("who-Is"));
+
+    let bytes = frame(I_AM_FRAME_HEX, 63);
+    let Some(Ok(BacnetService::IAm {
+        device,
+        max_apdu_len,
+        segmentation,
+        vendor_id,
+    })) = apdu(&bytes).service()
+    else {
+        panic!("I-Am expected");
+    };
+    assert_eq!(
+        (device.object_type_name(), device.instance),
+        ("device", 100)
+    );
+    assert_eq!((max_apdu_len, segmentation, vendor_id), (1476, 3, 260));
+}
+
+#[test]
+fn read_property_round_trip_decodes_present_value() {
+    let bytes = frame(READ_PROPERTY_FRAME_HEX, 59);
+    let request = apdu(&bytes);
+    assert_eq!(request.pdu_type_name(), "Confirmed-Request");
+    assert_eq!(request.invoke_id(), Some(1));
+    assert_eq!(request.max_apdu_len(), Some(1476));
+    let service = request
+        .service()
+        .expect("decoded service")
+        .expect("valid service");
+    assert_eq!(service.property_name(), Some("present-value"));
+
+    let bytes = frame(READ_PROPERTY_ACK_FRAME_HEX, 65);
+    let ack = apdu(&bytes);
+    assert!(matches!(ack, BacnetApdu::ComplexAck { .. }));
+    assert_eq!(ack.invoke_id(), Some(1));
+    let Some(Ok(BacnetService::ReadPropertyAck { object, value, .. })) = ack.service() else {
+        panic!("ReadProperty-ACK expected");
+    };
+    assert_eq!(object.object_type_name(), "analog-input");
+    assert_eq!(
+        BacnetValue::parse_all(value).expect("valid value"),
+        vec![BacnetValue::Real(12.5)]
+    );
+}
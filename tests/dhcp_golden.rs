@@ -0,0 +1,147 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests des options DHCPv4 typees et du suivi des baux sur les
+//! captures reelles de `pcaps_exemple/protocols/dhcp` (DORA complet puis
+//! renouvellement du meme client).
+
+use std::net::Ipv4Addr;
+use std::path::Path;
+
+use packet_parser::{
+    LinkType, parse,
+    parse::application::protocols::dhcp::{
+        DhcpPacket,
+        lease::{DhcpLeaseState, DhcpLeaseTracker},
+        options::DhcpOption,
+    },
+};
+
+mod common;
+use common::{FileRead, read_capture};
+
+const CLIENT: [u8; 6] = [0x00, 0x0c, 0x29, 0x59, 0xfd, 0x21];
+
+/// Payload UDP de chaque trame de la capture.
+fn dhcp_payloads(name: &str) -> Vec<Vec<u8>> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("pcaps_exemple/protocols/dhcp")
+        .join(name);
+    let frames: Vec<(LinkType, Vec<u8>)> = match read_capture(&path) {
+        FileRead::Frames { frames, .. } => frames,
+        FileRead::Unsupported => panic!("{}: unreadable capture", path.display()),
+    };
+    frames
+        .iter()
+        .map(|(link_type, data)| {
+            let transport = parse(*link_type, data)
+                .expect("frame parses")
+                .transport
+                .expect("UDP");
+            transport.payload.expect("DHCP payload").to_vec()
+        })
+        .collect()
+}
+
+#[test]
+fn dora_options_decode() {
+    let payloads = dhcp_payloads("dhcp_nolease_initialization.pcapng");
+    let packets: Vec<DhcpPacket<'_>> = payloads
+        .iter()
+        .map(|payload| DhcpPacket::try_from(payload.as_slice()).expect("DHCP"))
+        .collect();
+    let names: Vec<_> = packets
+        .iter()
+        .map(|packet| packet.message_type_name())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            Some("DHCPDISCOVER"),
+            Some("DHCPOFFER"),
+            Some("DHCPREQUEST"),
+            Some("DHCPACK")
+        ]
+    );
+
+    // Trame 1 : le client redemande son ancienne adresse et annonce son nom.
+    let discover = packets[0].dhcp_options().expect("options");
+    assert_eq!(
+        discover[..3],
+        [
+            DhcpOption::MessageType(1),
+            DhcpOption::RequestedIp(Ipv4Addr::new(192, 168, 100, 129)),
+            DhcpOption::Hostname(b"ubuntu2"),
+        ]
+    );
+    assert_eq!(
+        packets[0].fingerprint().as_deref(),
+        Some("1,28,2,3,15,6,119,12,44,47,26,121,42")
+    );
+
+    // Trame 4 : bail de 600 s, passerelle, domaine et deux serveurs DNS.
+    let ack = packets[3].dhcp_options().expect("options");
+    assert_eq!(
+        ack,
+        [
+            DhcpOption::MessageType(5),
+            DhcpOption::ServerId(Ipv4Addr::new(192, 168, 1, 5)),
+            DhcpOption::LeaseTime(600),
+            DhcpOption::SubnetMask(Ipv4Addr::new(255, 255, 255, 0)),
+            DhcpOption::Other {
+                code: 28,
+                data: &[192, 168, 1, 255]
+            },
+            DhcpOption::Router(vec![Ipv4Addr::new(192, 168, 1, 254)]),
+            DhcpOption::DomainName(b"mydomain.example"),
+            DhcpOption::DnsServers(vec![
+                Ipv4Addr::new(192, 168, 1, 1),
+                Ipv4Addr::new(192, 168, 1, 2)
+            ]),
+        ]
+    );
+}
+
+#[test]
+fn dora_then_renewal_bind_the_client() {
+    let mut tracker = DhcpLeaseTracker::new();
+    let mut states = Vec::new();
+    for name in [
+        "dhcp_nolease_initialization.pcapng",
+        "dhcp_inlease_renewal.pcapng",
+    ] {
+        for payload in dhcp_payloads(name) {
+            let packet = DhcpPacket::try_from(payload.as_slice()).expect("DHCP");
+            tracker.push(&packet).expect("options");
+            let binding = tracker.binding(&CLIENT).expect("client tracked");
+            states.push((binding.state, binding.ip_address));
+        }
+    }
+
+    let offered = Some(Ipv4Addr::new(192, 168, 1, 10));
+    assert_eq!(
+        states,
+        [
+            // Le DISCOVER ne lie aucune adresse, meme demandee.
+            (DhcpLeaseState::Selecting, None),
+            (DhcpLeaseState::Offered, offered),
+            (DhcpLeaseState::Requesting, offered),
+            (DhcpLeaseState::Bound, offered),
+            (DhcpLeaseState::Requesting, offered),
+            (DhcpLeaseState::Bound, offered),
+        ]
+    );
+
+    let binding = tracker.binding(&CLIENT).expect("client tracked");
+    assert_eq!(tracker.len(), 1);
+    assert_eq!(binding.hostname.as_deref(), Some("ubuntu2"));
+    assert_eq!(binding.lease_time, Some(600));
+    assert_eq!(
+        binding.server_identifier,
+        Some(Ipv4Addr::new(192, 168, 1, 5))
+    );
+    // Transaction du renouvellement.
+    assert_eq!(binding.transaction_id, 0x81bc_c90f);
+}
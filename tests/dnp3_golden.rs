@@ -0,0 +1,184 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests DNP3 (IEEE 1815) sur trames Ethernet completes.
+//!
+//! Aucune capture DNP3 publique n'est versionnee dans le depot : les trames
+//! sont synthetiques, construites octet par octet d'apres IEEE 1815-2012
+//! (checksums IPv4/TCP/UDP et CRC-16/DNP de chaque bloc corrects). Maitre
+//! 192.168.10.1 (adresse de liaison 1), outstation 192.168.10.20 (adresse
+//! 10), port 20000.
+
+use packet_parser::parse::application::protocols::dnp3::{
+    Dnp3Frame,
+    application::{Dnp3Range, Dnp3Value},
+    transport::Dnp3TransportReassembler,
+};
+use packet_parser::{LinkType, parse};
+
+/// Maitre -> outstation sur TCP : READ des classes 1, 2, 3 puis 0 (integrity
+/// poll), une seule trame de liaison UNCONFIRMED_USER_DATA (controle 0xC4).
+const TCP_CLASS_POLL_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e000102080045000043123440004006931bc0a80a01c0a8",
+    "0a14c3cb4e20000003e8000007d0501820006bcd0000056414c40a0001008fed",
+    "c0c1013c02063c03063c04063c01067a6f"
+);
+
+/// Outstation -> maitre sur TCP : RESPONSE (IIN 0x0000) portant g1v2 index
+/// 0-1, g30v5 index 4 (12.5) et g20v1 index 0 (12345) ; 32 octets de
+/// donnees utilisateur, donc deux blocs CRC.
+const TCP_RESPONSE_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a010800450000565678400040064ec4c0a80a14c0a8",
+    "0a014e20c3cb000007d00000040350182000780c00000564254401000a000e90",
+    "c0c1810000010200000101811e05170179380401000048411401000000013930",
+    "0000933c"
+);
+
+/// Maitre -> outstation sur UDP : DIRECT_OPERATE g12v1 index 5, LATCH_ON,
+/// on-time 100 ms.
+const UDP_DIRECT_OPERATE_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e00010208004500003f1235400040119313c0a80a01c0a8",
+    "0a144e204e20002ba88205641ac40a0001008a1cc1c2050c0128010005000301",
+    "640000003b320000000000ffff"
+);
+
+/// Ethernet (14) + IPv4 (20) + TCP sans options (20).
+const TCP_PAYLOAD_OFFSET: usize = 54;
+
+/// Ethernet (14) + IPv4 (20) + UDP (8).
+const UDP_PAYLOAD_OFFSET: usize = 42;
+
+fn frame(hex_fixture: &str, expected_len: usize) -> Vec<u8> {
+    let bytes = hex::decode(hex_fixture).expect("invalid test hex fixture");
+    assert_eq!(bytes.len(), expected_len, "fixture length must match frame");
+    bytes
+}
+
+fn application_label(bytes: &[u8]) -> &'static str {
+    parse(LinkType::ETHERNET, bytes)
+        .expect("synthetic frame decodes")
+        .application
+        .expect("an application layer is detected")
+        .application_protocol
+}
+
+#[test]
+fn packet_flow_labels_dnp3_over_tcp_and_udp() {
+    assert_eq!(
+        application_label(&frame(TCP_CLASS_POLL_FRAME_HEX, 81)),
+        "DNP3"
+    );
+    assert_eq!(
+        application_label(&frame(TCP_RESPONSE_FRAME_HEX, 100)),
+        "DNP3"
+    );
+    assert_eq!(
+        application_label(&frame(UDP_DIRECT_OPERATE_FRAME_HEX, 77)),
+        "DNP3"
+    );
+}
+
+#[test]
+fn class_poll_decodes_to_class_data_headers() {
+    let bytes = frame(TCP_CLASS_POLL_FRAME_HEX, 81);
+    let link = Dnp3Frame::try_from(&bytes[TCP_PAYLOAD_OFFSET..]).expect("valid link frame");
+    assert!(link.is_from_master());
+    assert_eq!(link.function_name(), "UNCONFIRMED_USER_DATA");
+    assert_eq!((link.source, link.destination), (1, 10));
+
+    let segment = link
+        .transport()
+        .expect("user data frame")
+        .expect("valid segment");
+    let application = segment
+        .application()
+        .expect("single-segment fragment")
+        .expect("valid fragment");
+    assert_eq!(application.function_name(), "READ");
+    let classes: Vec<(u8, u8)> = application
+        .objects
+        .iter()
+        .map(|object| (object.group, object.variation))
+        .collect();
+    assert_eq!(classes, vec![(60, 2), (60, 3), (60, 4), (60, 1)]);
+    assert!(
+        application
+            .objects
+            .iter()
+            .all(|object| object.range == Dnp3Range::All)
+    );
+}
+
+#[test]
+fn response_points_decode_through_the_reassembler() {
+    let bytes = frame(TCP_RESPONSE_FRAME_HEX, 100);
+    let link = Dnp3Frame::try_from(&bytes[TCP_PAYLOAD_OFFSET..]).expect("valid link frame");
+    assert_eq!(link.user_data.len(), 32);
+
+    let mut reassembler = Dnp3TransportReassembler::new();
+    reassembler.push(&link).expect("valid segment");
+    let fragment = reassembler.next_fragment().expect("complete fragment");
+    assert_eq!((fragment.source, fragment.destination), (10, 1));
+
+    let application = fragment.application().expect("valid fragment");
+    assert_eq!(application.function_name(), "RESPONSE");
+    assert_eq!(application.iin, Some(0));
+    let binaries = application.objects[0].points().expect("valid points");
+    assert_eq!(
+        binaries[1].value,
+        Some(Dnp3Value::Binary {
+            state: true,
+            flags: 0x81
+        })
+    );
+    let analog = application.objects[1].points().expect("valid points");
+    assert_eq!(analog[0].index, 4);
+    assert_eq!(
+        analog[0].value,
+        Some(Dnp3Value::Analog {
+            value: 12.5,
+            flags: Some(0x01)
+        })
+    );
+    let counter = application.objects[2].points().expect("valid points");
+    assert_eq!(
+        counter[0].value,
+        Some(Dnp3Value::Counter {
+            value: 12_345,
+            flags: Some(0x01)
+        })
+    );
+}
+
+#[test]
+fn direct_operate_over_udp_decodes_crob() {
+    let bytes = frame(UDP_DIRECT_OPERATE_FRAME_HEX, 77);
+    let link = Dnp3Frame::try_from(&bytes[UDP_PAYLOAD_OFFSET..]).expect("valid link frame");
+    let segment = link
+        .transport()
+        .expect("user data frame")
+        .expect("valid segment");
+    let application = segment
+        .application()
+        .expect("single-segment fragment")
+        .expect("valid fragment");
+    assert_eq!(application.function_name(), "DIRECT_OPERATE");
+    assert!(application.is_control());
+
+    let points = application.objects[0].points().expect("valid points");
+    assert_eq!(points[0].index, 5);
+    let crob = points[0].value.as_ref().expect("CROB value");
+    assert_eq!(crob.control_code_name(), Some("LATCH_ON"));
+    assert_eq!(
+        *crob,
+        Dnp3Value::Crob {
+            control_code: 0x03,
+            count: 1,
+            on_time: 100,
+            off_time: 0,
+            status: 0
+        }
+    );
+}
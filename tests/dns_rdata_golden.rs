@@ -0,0 +1,157 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests du decodage type des RDATA DNS (`DnsMessage` / `RData`),
+//! relus depuis les captures de `pcaps_exemple/protocols/dns` (voir son
+//! `SOURCE.md`) et les sessions Linux `sll.pcap` / `capture_sll2.pcap`.
+//! Numeros de trame Wireshark, a partir de 1.
+
+use std::{net::Ipv4Addr, path::Path};
+
+use packet_parser::{
+    parse,
+    parse::application::protocols::dns::{
+        message::DnsMessage,
+        rdata::RData,
+        utils::dns_types::{DnsType, DnsTypes},
+    },
+};
+
+mod common;
+use common::{FileRead, read_capture};
+
+/// Payload de transport de la trame `number` de `capture`.
+fn payload(capture: &str, number: usize) -> Vec<u8> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(capture);
+    let frames = match read_capture(&path) {
+        FileRead::Frames { frames, .. } => frames,
+        FileRead::Unsupported => panic!("{}: unreadable capture", path.display()),
+    };
+    let (link_type, data) = &frames[number - 1];
+    let flow = parse(*link_type, data).expect("captured frame decodes");
+    flow.transport
+        .and_then(|transport| transport.payload)
+        .expect("transport payload")
+        .to_vec()
+}
+
+/// Trame 7 de `dns_axfr.pcapng` : transfert de la zone Active Directory
+/// `contoso.local` sur TCP, encadre par deux SOA. Les cibles SRV ne sont pas
+/// compressees, le mname SOA et les NS le sont.
+#[test]
+fn zone_transfer_decodes_soa_ns_and_srv() {
+    let bytes = payload("pcaps_exemple/protocols/dns/dns_axfr.pcapng", 7);
+    let message = DnsMessage::try_from_tcp(&bytes).expect("AXFR message decodes");
+    assert_eq!(message.answers.len(), 21);
+
+    let RData::Soa(soa) = message.answers[0].rdata().unwrap() else {
+        panic!("zone transfer starts with its SOA");
+    };
+    assert_eq!(soa.mname.to_string(), "dns3.contoso.local");
+    assert!(soa.mname.is_compressed());
+    assert_eq!(soa.rname.to_string(), "hostmaster");
+    assert_eq!(
+        (soa.serial, soa.refresh, soa.retry, soa.expire, soa.minimum),
+        (71, 900, 600, 86400, 3600)
+    );
+    assert_eq!(message.answers[20].rdata().unwrap(), RData::Soa(soa));
+
+    assert_eq!(
+        message.answers[1].rdata().unwrap(),
+        RData::A(Ipv4Addr::new(172, 16, 16, 139))
+    );
+    let RData::Ns(ns) = message.answers[3].rdata().unwrap() else {
+        panic!("NS expected");
+    };
+    assert_eq!(message.answers[3].name.to_string(), "_msdcs.contoso.local");
+    assert_eq!(ns.to_string(), "csanders-9ceae1.contoso.local");
+
+    let kerberos = &message.answers[5];
+    assert_eq!(
+        kerberos.name.to_string(),
+        "_kerberos._tcp.Default-First-Site-Name._sites.contoso.local"
+    );
+    let RData::Srv {
+        priority,
+        weight,
+        port,
+        target,
+    } = kerberos.rdata().unwrap()
+    else {
+        panic!("SRV expected");
+    };
+    assert_eq!((priority, weight, port), (0, 100, 88));
+    assert_eq!(target, soa.mname);
+    assert!(!target.is_compressed());
+}
+
+/// Trame 2 de `dns_tcp.pcapng` : reponse tronquee (TC) a la requete ANY
+/// `ripe.net`, qui ne porte que les deux DNSKEY RSA/SHA-1 de la zone. Key
+/// tags recoupes par une implementation independante (Python).
+#[test]
+fn dnskey_records_yield_their_key_tags() {
+    let bytes = payload("pcaps_exemple/protocols/dns/dns_tcp.pcapng", 2);
+    let message = DnsMessage::try_from(bytes.as_slice()).expect("UDP response decodes");
+
+    let keys: Vec<(u16, bool, u16)> = message
+        .answers
+        .iter()
+        .map(|record| match record.rdata().unwrap() {
+            RData::Dnskey(key) => {
+                assert_eq!((key.protocol, key.algorithm), (3, 5));
+                (key.flags, key.is_secure_entry_point(), key.key_tag())
+            }
+            other => panic!("DNSKEY expected, got {other:?}"),
+        })
+        .collect();
+    assert_eq!(keys, [(256, false, 34336), (257, true, 60338)]);
+}
+
+/// Trame 682 de `capture_sll2.pcap` : annonce mDNS d'un service AirPlay.
+/// Le nom d'instance est en UTF-8 et le NSEC compresse son nom suivant,
+/// comme l'autorise RFC 6762 §18.14.
+#[test]
+fn mdns_announcement_decodes_txt_and_compressed_nsec() {
+    let bytes = payload("pcaps_exemple/capture_sll2.pcap", 682);
+    let message = DnsMessage::try_from(bytes.as_slice()).expect("mDNS announcement decodes");
+
+    let txt = &message.answers[0];
+    assert_eq!(txt.name.to_string(), "Séjour._airplay._tcp.local");
+    // Bit cache-flush conserve dans la classe brute.
+    assert_eq!(txt.rclass.0, 0x8001);
+    let RData::Txt(strings) = txt.rdata().unwrap() else {
+        panic!("TXT expected");
+    };
+    assert_eq!(strings[0], b"acl=0");
+    assert_eq!(strings[1], b"btaddr=7C:C2:3B:75:48:ED");
+
+    let nsec = &message.additionals[0];
+    let RData::Nsec {
+        next_domain_name,
+        types,
+    } = nsec.rdata().unwrap()
+    else {
+        panic!("NSEC expected");
+    };
+    assert!(next_domain_name.is_compressed());
+    assert_eq!(next_domain_name, txt.name);
+    let types: Vec<DnsType> = types.types().collect();
+    assert_eq!(types, [DnsTypes::TXT, DnsTypes::SRV]);
+}
+
+/// Trame 98 de `sll.pcap` : requete HTTPS (type 65) `unleash.codeium.com`
+/// avec un OPT sans option, annoncant 1472 octets de payload UDP.
+#[test]
+fn edns_opt_record_exposes_its_header() {
+    let bytes = payload("pcaps_exemple/sll.pcap", 98);
+    let message = DnsMessage::try_from(bytes.as_slice()).expect("query decodes");
+
+    let opt = message.opt().expect("OPT record");
+    let header = opt.edns_header().unwrap();
+    assert_eq!(header.udp_payload_size, 1472);
+    assert_eq!(header.version, 0);
+    assert!(!header.dnssec_ok);
+    assert_eq!(opt.rdata().unwrap(), RData::Opt(Vec::new()));
+}
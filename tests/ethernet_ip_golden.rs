@@ -13,8 +13,11 @@
 //! aucun RegisterSession (0x65) : la couverture reelle porte sur
 //! ListIdentity, SendRRData et SendUnitData.
 
+use std::net::{Ipv4Addr, SocketAddrV4};
+
 use packet_parser::parse::application::protocols::ethernet_ip::{
     EtherNetIpCommand, EtherNetIpCommandData, EtherNetIpPacket,
+    cip::{CipMessage, CipPathSegment},
 };
 use packet_parser::parse::transport::protocols::TransportProtocol;
 use packet_parser::{LinkType, parse};
@@ -30,7 +33,8 @@ const LIST_IDENTITY_REQUEST_FRAME_HEX: &str = concat!(
 
 /// `pcaps_exemple/protocols/ethernet_ip/enip_test.pcap`, trame 7 : reponse
 /// ListIdentity du module 1756-ENBT/A — 51 octets de command data (item
-/// Identity 0x000C), que le parseur expose en `Raw`.
+/// Identity 0x000C), que le parseur expose en `Raw` et que
+/// `list_identity` decode.
 const LIST_IDENTITY_RESPONSE_FRAME_HEX: &str = concat!(
     "3ca9f42122f8001907243cca080045000073f258400034063ce00a0101a40a01",
     "01a7af12148e87b046928c05d68550181000f992000063003300000000000000",
@@ -117,6 +121,7 @@ fn real_list_identity_request_decodes_with_an_empty_body() {
     assert_eq!(packet.header.options, 0);
 
     assert!(matches!(packet.command_data, EtherNetIpCommandData::Empty));
+    assert!(packet.list_identity().is_none());
 }
 
 #[test]
@@ -138,8 +143,8 @@ fn real_list_identity_response_exposes_the_identity_item_as_raw_data() {
         &[0x00, 0x00, 0x00, 0x00, 0xC1, 0xDE, 0xBE, 0xD1]
     );
 
-    // Le decodage CIP de l'item Identity n'est pas implemente : le parseur
-    // doit exposer les 51 octets tels quels, sans les tronquer.
+    // Le parseur d'encapsulation expose les 51 octets tels quels, sans les
+    // tronquer ; l'item Identity est decode a la demande.
     let EtherNetIpCommandData::Raw(raw) = packet.command_data else {
         panic!("expected raw ListIdentity command data");
     };
@@ -152,6 +157,29 @@ fn real_list_identity_response_exposes_the_identity_item_as_raw_data() {
     assert_eq!(u16::from_le_bytes([raw[4], raw[5]]), 45);
     assert_eq!(&raw[39..50], b"1756-ENBT/A");
     assert_eq!(raw[50], 3);
+
+    // tshark : cip.vendor == 1, cip.devtype == 12, cip.prodcode == 58,
+    // revision 4.3, cip.serial == 0x00524d8e, socket 10.1.1.164:44818.
+    let identities = packet
+        .list_identity()
+        .expect("ListIdentity command")
+        .expect("valid identity item");
+    assert_eq!(identities.len(), 1);
+    let identity = &identities[0];
+    assert_eq!(identity.encapsulation_version, 1);
+    assert_eq!(
+        identity.socket_address,
+        SocketAddrV4::new(Ipv4Addr::new(10, 1, 1, 164), 44818)
+    );
+    assert_eq!(identity.vendor_id, 1);
+    assert_eq!(identity.device_type, 12);
+    assert_eq!(identity.device_type_name(), "Communications Adapter");
+    assert_eq!(identity.product_code, 58);
+    assert_eq!((identity.revision_major, identity.revision_minor), (4, 3));
+    assert_eq!(identity.status, 0x0030);
+    assert_eq!(identity.serial_number, 0x0052_4D8E);
+    assert_eq!(identity.product_name_str(), Some("1756-ENBT/A"));
+    assert_eq!(identity.state, 3);
 }
 
 #[test]
@@ -173,7 +201,7 @@ fn real_send_rr_data_response_decodes_its_common_packet_format() {
     );
     assert_eq!(packet.header.options, 0);
 
-    let EtherNetIpCommandData::CommonPacketFormat(cpf) = packet.command_data else {
+    let EtherNetIpCommandData::CommonPacketFormat(cpf) = &packet.command_data else {
         panic!("expected common packet format");
     };
     assert_eq!(cpf.interface_handle, 0);
@@ -186,6 +214,21 @@ fn real_send_rr_data_response_decodes_its_common_packet_format() {
     // statut general 0x00 (succes).
     assert_eq!(cpf.items[1].type_id, 0x00B2);
     assert_eq!(cpf.items[1].data, &[0x90, 0x00, 0x00, 0x00]);
+
+    let message = packet
+        .cip()
+        .expect("CIP data item")
+        .expect("valid CIP message");
+    assert_eq!(message.connection_id, None);
+    assert_eq!(message.sequence_count, None);
+    let CipMessage::Response(response) = message.message else {
+        panic!("expected a CIP response");
+    };
+    assert_eq!(response.request_service(), 0x10);
+    assert!(response.is_success());
+    assert_eq!(response.general_status_name(), "Success");
+    assert!(response.additional_status.is_empty());
+    assert!(response.data.is_empty());
 }
 
 #[test]
@@ -205,7 +248,7 @@ fn real_send_unit_data_request_decodes_its_connected_items() {
     assert_eq!(packet.header.sender_context, &[0u8; 8]);
     assert_eq!(packet.header.options, 0);
 
-    let EtherNetIpCommandData::CommonPacketFormat(cpf) = packet.command_data else {
+    let EtherNetIpCommandData::CommonPacketFormat(cpf) = &packet.command_data else {
         panic!("expected common packet format");
     };
     assert_eq!(cpf.interface_handle, 0);
@@ -215,7 +258,7 @@ fn real_send_unit_data_request_decodes_its_connected_items() {
     assert_eq!(cpf.items[0].type_id, 0x00A1);
     assert_eq!(cpf.items[0].data, &0x0007_6B01_u32.to_le_bytes());
     // Connected Data Item : compteur de sequence CIP puis requete (service
-    // 0x4C, chemin classe 0x8E / instance 0x2474 / attribut 0x2474...).
+    // 0x4C, chemin de 4 mots).
     assert_eq!(cpf.items[1].type_id, 0x00B1);
     assert_eq!(cpf.items[1].data.len(), 12);
     assert_eq!(
@@ -223,4 +266,32 @@ fn real_send_unit_data_request_decodes_its_connected_items() {
         7244
     );
     assert_eq!(cpf.items[1].data[2], 0x4C);
+
+    // tshark : cip.service == 0x4c, cip.class == 0x8e, cip.instance == 1,
+    // puis classe 0x74 instance 1 ; aucune donnee de service.
+    let message = packet
+        .cip()
+        .expect("CIP data item")
+        .expect("valid CIP message");
+    assert_eq!(message.connection_id, Some(0x0007_6B01));
+    assert_eq!(message.sequence_count, Some(7244));
+    let CipMessage::Request(request) = message.message else {
+        panic!("expected a CIP request");
+    };
+    assert_eq!(request.service, 0x4C);
+    assert_eq!(
+        request.path.segments,
+        vec![
+            CipPathSegment::Class(0x8E),
+            CipPathSegment::Instance(1),
+            CipPathSegment::Class(0x74),
+            CipPathSegment::Instance(1),
+        ]
+    );
+    assert_eq!(request.class_name(), Some("Controller"));
+    // Service propre a la classe 0x8E : ni Read Tag, ni Forward Open.
+    assert_eq!(request.service_name(), "Unknown");
+    assert!(request.logix_tag().is_none());
+    assert!(request.forward_open().is_none());
+    assert!(request.data.is_empty());
 }
@@ -0,0 +1,147 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests des reponses HTTP/1.x et de l'appariement requete/reponse
+//! (`HttpResponse`, `HttpTransactionTracker`), relus depuis
+//! `protocols/tcp/tcp_ports.pcapng` (navigation sur wireshark.org) et la
+//! session Linux `sll.pcap`. Numeros de trame Wireshark, a partir de 1.
+
+use std::path::Path;
+
+use packet_parser::{
+    parse,
+    parse::{
+        application::protocols::http::{
+            response::HttpResponse,
+            transaction::{HttpTransaction, HttpTransactionTracker},
+        },
+        transport::TransportDetails,
+    },
+};
+
+mod common;
+use common::{FileRead, read_capture};
+
+fn frames(capture: &str) -> Vec<(packet_parser::LinkType, Vec<u8>)> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(capture);
+    match read_capture(&path) {
+        FileRead::Frames { frames, .. } => frames,
+        FileRead::Unsupported => panic!("{}: unreadable capture", path.display()),
+    }
+}
+
+/// Rejoue la connexion TCP dont le client utilise `client_port` dans un
+/// tracker, dans l'ordre des trames. Les retransmissions (sequence deja
+/// vue) sont ecartees : le tracker attend un flux deja reassemble.
+fn replay(capture: &str, client_port: u16) -> Vec<HttpTransaction> {
+    let mut tracker = HttpTransactionTracker::new();
+    let mut next_seq: [Option<u32>; 2] = [None, None];
+    for (link_type, data) in frames(capture) {
+        let flow = parse(link_type, &data).expect("captured frame decodes");
+        let Some(TransportDetails::Tcp(tcp)) = flow.transport.and_then(|t| t.details) else {
+            continue;
+        };
+        let from_client = tcp.header.source_port == client_port;
+        if (!from_client && tcp.header.destination_port != client_port) || tcp.payload.is_empty() {
+            continue;
+        }
+        let expected = &mut next_seq[usize::from(from_client)];
+        let seq = tcp.header.sequence_number;
+        let skip = expected.map_or(0, |expected| expected.wrapping_sub(seq) as usize);
+        let Some(fresh) = tcp.payload.get(skip..).filter(|fresh| !fresh.is_empty()) else {
+            continue;
+        };
+        *expected = Some(seq.wrapping_add(tcp.payload.len() as u32));
+
+        if from_client {
+            tracker.push_request(fresh).expect("request stream decodes");
+        } else {
+            tracker
+                .push_response(fresh)
+                .expect("response stream decodes");
+        }
+    }
+    tracker.close();
+    std::iter::from_fn(|| tracker.next_transaction()).collect()
+}
+
+/// Trame 43 de `tcp_ports.pcapng` : redirection de `wireshark.org` vers
+/// `www.wireshark.org`, corps gzip de 231 octets dans le meme segment.
+#[test]
+fn redirect_response_decodes() {
+    let frames = frames("pcaps_exemple/protocols/tcp/tcp_ports.pcapng");
+    let (link_type, data) = &frames[42];
+    let flow = parse(*link_type, data).expect("captured frame decodes");
+    let payload = flow.transport.and_then(|t| t.payload).expect("payload");
+
+    let response = HttpResponse::try_from(payload).expect("301 decodes");
+    assert_eq!(response.status_code, 301);
+    assert_eq!(response.reason, "Moved Permanently");
+    assert_eq!(
+        response.header("Location"),
+        Some("http://www.wireshark.org/")
+    );
+    assert_eq!(response.content_length(), Ok(Some(231)));
+    let body = response.decoded_body().expect("body complete");
+    assert_eq!(body.len(), 231);
+    assert_eq!(&body[..2], b"\x1f\x8b");
+    assert_eq!(
+        flow.application.map(|a| a.application_protocol),
+        Some("HTTP")
+    );
+}
+
+/// Connexion keep-alive du port client 2828 de `tcp_ports.pcapng` : sept GET
+/// successifs, des reponses reparties sur 61 segments plus une
+/// retransmission (trame 79, copie de la trame 55).
+#[test]
+fn keep_alive_connection_pairs_seven_transactions() {
+    let transactions = replay("pcaps_exemple/protocols/tcp/tcp_ports.pcapng", 2828);
+    let summary: Vec<(String, u16, usize)> = transactions
+        .iter()
+        .map(|t| (t.url(), t.status_code, t.response_body_len))
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("http://www.wireshark.org/", 200, 3308),
+            ("http://www.wireshark.org/css/print.css", 200, 552),
+            ("http://www.wireshark.org/blogfeed.js", 200, 535),
+            ("http://www.wireshark.org/image/header.png", 200, 69_012),
+            ("http://www.wireshark.org/image/rss.gif", 200, 1065),
+            (
+                "http://www.wireshark.org/image/h_enhancements.png",
+                200,
+                1583
+            ),
+            (
+                "http://www.wireshark.org/image/entertainment.png",
+                200,
+                1347
+            ),
+        ]
+        .map(|(url, status, size)| (url.to_string(), status, size))
+    );
+    assert!(transactions.iter().all(|t| t.method == "GET"));
+    assert!(transactions.iter().all(|t| t.request_body_len == 0));
+}
+
+/// Port client 34866 de `sll.pcap` : POST dont le corps protobuf est envoye
+/// en un chunk de 0x219 octets (trames 74, 76, 78), reponse gzip de 39
+/// octets (trame 80).
+#[test]
+fn chunked_request_body_is_counted() {
+    let transactions = replay("pcaps_exemple/sll.pcap", 34866);
+    assert_eq!(transactions.len(), 1);
+    let transaction = &transactions[0];
+    assert_eq!(transaction.method, "POST");
+    assert_eq!(
+        transaction.url(),
+        "http://127.0.0.1:42629/exa.language_server_pb.LanguageServerService/Heartbeat"
+    );
+    assert_eq!(transaction.status_code, 200);
+    assert_eq!(transaction.request_body_len, 0x219);
+    assert_eq!(transaction.response_body_len, 39);
+}
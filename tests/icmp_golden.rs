@@ -13,8 +13,8 @@ use packet_parser::parse::transport::TransportDetails;
 use packet_parser::parse::transport::protocols::TransportProtocol;
 use packet_parser::parse::transport::protocols::icmp::IcmpBody;
 use packet_parser::parse::transport::protocols::icmpv6::Icmpv6Body;
-use packet_parser::{LinkType, parse};
-use std::net::Ipv6Addr;
+use packet_parser::{InnerKind, LinkType, parse};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 /// Trame 1 : Echo request (ping) 192.168.100.138 -> 192.168.100.1
 /// (pcaps_exemple/protocols/icmp/icmp_echo.pcapng).
@@ -422,3 +422,123 @@ fn packet_flow_decodes_icmpv6_router_advertisement() {
     // Prefix Information (3) puis MTU (5).
     assert_eq!(advertisement.options[0], 3);
 }
+
+// ---------------------------------------------------------------------------
+// Datagramme cite par les erreurs ICMP, expose en flux interne
+// (`PacketFlow::icmp_quotation`) pour correler l'erreur au flux fautif.
+// ---------------------------------------------------------------------------
+
+#[test]
+fn time_exceeded_quotation_identifies_the_original_echo_request() {
+    let bytes = frame(TIME_EXCEEDED_FRAME_HEX, 70);
+    let flow = parse(LinkType::ETHERNET, bytes.as_slice()).expect("captured frame decodes");
+
+    assert_eq!(flow.inner_kind(), Some(InnerKind::IcmpQuotation));
+    assert_eq!(flow.to_owned().inner_kind(), Some(InnerKind::IcmpQuotation));
+    let quoted = flow.icmp_quotation().expect("quoted datagram is parsed");
+
+    let internet = quoted.internet.as_ref().expect("quoted IPv4 header");
+    assert_eq!(
+        internet.source,
+        Some(IpAddr::V4(Ipv4Addr::new(192, 168, 100, 138)))
+    );
+    assert_eq!(
+        internet.destination,
+        Some(IpAddr::V4(Ipv4Addr::new(4, 2, 2, 1)))
+    );
+    // Total Length annonce 92 octets, seuls 28 sont cites : la citation est
+    // toleree, pas signalee comme corrompue.
+    assert!(quoted.corrupted.is_none());
+
+    let transport = quoted.transport.as_ref().expect("quoted ICMP header");
+    assert_eq!(transport.protocol, TransportProtocol::Icmp);
+    let Some(TransportDetails::Icmp(icmp)) = &transport.details else {
+        panic!("the quoted echo request is decoded");
+    };
+    let IcmpBody::Echo(echo) = &icmp.body else {
+        panic!("the quoted message is an echo request");
+    };
+    assert_eq!(echo.identifier, 0x0500);
+    assert_eq!(echo.sequence_number, 0x3800);
+
+    // Une citation n'est pas une conversation portee par le paquet.
+    assert_eq!(flow.flatten().len(), 1);
+    assert!(quoted.application.is_none());
+}
+
+#[test]
+fn port_unreachable_quotations_expose_the_offending_udp_flow() {
+    let bytes = frame(ICMPV4_PORT_UNREACHABLE_FRAME_HEX, 71);
+    let flow = parse(LinkType::ETHERNET, bytes.as_slice()).expect("captured frame decodes");
+    let quoted = flow.icmp_quotation().expect("quoted IPv4 datagram");
+    let internet = quoted.internet.as_ref().expect("quoted IPv4 header");
+    assert_eq!(internet.destination, Some(IpAddr::V4(Ipv4Addr::LOCALHOST)));
+    let transport = quoted.transport.as_ref().expect("quoted UDP header");
+    assert_eq!(transport.protocol, TransportProtocol::Udp);
+    assert_eq!(transport.source_port, Some(38435));
+    assert_eq!(transport.destination_port, Some(9999));
+
+    let bytes = frame(ICMPV6_PORT_UNREACHABLE_FRAME_HEX, 111);
+    let flow = parse(LinkType::ETHERNET, bytes.as_slice()).expect("captured frame decodes");
+    let quoted = flow.icmp_quotation().expect("quoted IPv6 packet");
+    let internet = quoted.internet.as_ref().expect("quoted IPv6 header");
+    assert_eq!(internet.destination, Some(IpAddr::V6(Ipv6Addr::LOCALHOST)));
+    let transport = quoted.transport.as_ref().expect("quoted UDP header");
+    assert_eq!(transport.protocol, TransportProtocol::Udp);
+    assert_eq!(transport.source_port, Some(36309));
+    assert_eq!(transport.destination_port, Some(9999));
+}
+
+#[test]
+fn mtu_errors_quote_the_oversized_packets() {
+    // Trame 2 : fragmentation needed citant un ping 10.0.1.1 -> 10.0.2.2.
+    let bytes = frame_from_capture(MTU_CAPTURE, 2);
+    let flow = parse(LinkType::ETHERNET, bytes.as_slice()).expect("captured frame decodes");
+    let quoted = flow.icmp_quotation().expect("quoted IPv4 datagram");
+    let internet = quoted.internet.as_ref().expect("quoted IPv4 header");
+    assert_eq!(
+        internet.source,
+        Some(IpAddr::V4(Ipv4Addr::new(10, 0, 1, 1)))
+    );
+    assert_eq!(
+        internet.destination,
+        Some(IpAddr::V4(Ipv4Addr::new(10, 0, 2, 2)))
+    );
+    let transport = quoted.transport.as_ref().expect("quoted ICMP header");
+    assert_eq!(transport.protocol, TransportProtocol::Icmp);
+
+    // Trame 5 : packet too big citant un ping fd00:1::1 -> fd00:2::2 de
+    // 1448 octets, coupe pour tenir dans le MTU minimal IPv6.
+    let bytes = frame_from_capture(MTU_CAPTURE, 5);
+    let flow = parse(LinkType::ETHERNET, bytes.as_slice()).expect("captured frame decodes");
+    let quoted = flow.icmp_quotation().expect("quoted IPv6 packet");
+    let internet = quoted.internet.as_ref().expect("quoted IPv6 header");
+    assert_eq!(
+        internet.source,
+        Some(IpAddr::V6(Ipv6Addr::new(0xfd00, 1, 0, 0, 0, 0, 0, 1)))
+    );
+    assert_eq!(
+        internet.destination,
+        Some(IpAddr::V6(Ipv6Addr::new(0xfd00, 2, 0, 0, 0, 0, 0, 2)))
+    );
+    let transport = quoted.transport.as_ref().expect("quoted ICMPv6 header");
+    let Some(TransportDetails::Icmpv6(icmpv6)) = &transport.details else {
+        panic!("the quoted echo request is decoded");
+    };
+    assert_eq!(icmpv6.message_type, 128);
+    let Icmpv6Body::Echo(echo) = &icmpv6.body else {
+        panic!("the quoted message is an echo request");
+    };
+    assert_eq!(echo.identifier, 50147);
+    assert_eq!(echo.sequence_number, 1);
+}
+
+#[test]
+fn non_error_icmp_has_no_quotation() {
+    let bytes = frame(ECHO_REQUEST_FRAME_HEX, 74);
+    let flow = parse(LinkType::ETHERNET, bytes.as_slice()).expect("captured frame decodes");
+
+    assert!(flow.inner.is_none());
+    assert!(flow.inner_kind().is_none());
+    assert!(flow.icmp_quotation().is_none());
+}
@@ -0,0 +1,141 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests IEC 60870-5-104 sur trames Ethernet completes.
+//!
+//! Aucune capture IEC 104 publique n'est versionnee dans le depot : les
+//! trames sont synthetiques, construites d'apres IEC 60870-5-104 et -101
+//! (checksums IPv4/TCP corrects). Station de controle 192.168.10.1,
+//! poste 192.168.10.20 sur TCP 2404, adresse commune 2561.
+
+use packet_parser::parse::application::protocols::iec104::{
+    Iec104Apdu, Iec104Control, asdu::Iec104Value,
+};
+use packet_parser::{LinkType, parse};
+
+/// Station de controle -> poste : U-format STARTDT act.
+const STARTDT_ACT_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e00010208004500002e2000400040068564c0a80a01c0a8",
+    "0a14c3cc096400000bb800000fa050182000a2d30000680407000000"
+);
+
+/// Poste -> station de controle : deux APDU I-format dans le meme segment.
+/// N(S) = 1 : M_ME_NC_1 en sequence (SQ = 1), IOA 1001-1003, cause 20
+/// (interrogation generale) ; N(S) = 2 : M_SP_TB_1 spontane, IOA 3000,
+/// horodate 2026-10-19 14:30:12.345.
+const TWO_I_FRAMES_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a0108004500005d3000400040067535c0a80a14c0a8",
+    "0a010964c3cc00000fa000000bbe50182000e0f80000681c020002000d831400",
+    "010ae903000000204100000048c0000000a042106815040002001e0103000100",
+    "b80b000139301e0e330a1a"
+);
+
+/// Ethernet (14) + IPv4 (20) + TCP sans options (20).
+const TCP_PAYLOAD_OFFSET: usize = 54;
+
+fn frame(hex_fixture: &str, expected_len: usize) -> Vec<u8> {
+    let bytes = hex::decode(hex_fixture).expect("invalid test hex fixture");
+    assert_eq!(bytes.len(), expected_len, "fixture length must match frame");
+    bytes
+}
+
+fn application_label(bytes: &[u8]) -> &'static str {
+    parse(LinkType::ETHERNET, bytes)
+        .expect("synthetic frame decodes")
+        .application
+        .expect("an application layer is detected")
+        .application_protocol
+}
+
+#[test]
+fn packet_flow_labels_iec104() {
+    assert_eq!(
+        application_label(&frame(STARTDT_ACT_FRAME_HEX, 60)),
+        "IEC 104"
+    );
+    assert_eq!(
+        application_label(&frame(TWO_I_FRAMES_FRAME_HEX, 107)),
+        "IEC 104"
+    );
+}
+
+#[test]
+fn startdt_act_decodes_as_u_frame() {
+    let bytes = frame(STARTDT_ACT_FRAME_HEX, 60);
+    let apdu = Iec104Apdu::try_from(&bytes[TCP_PAYLOAD_OFFSET..]).expect("valid APDU");
+    assert_eq!(apdu.control.u_function_name(), Some("STARTDT act"));
+    assert!(apdu.asdu().is_none());
+}
+
+#[test]
+fn segment_with_two_i_frames_decodes_both_asdus() {
+    let bytes = frame(TWO_I_FRAMES_FRAME_HEX, 107);
+    let apdus = Iec104Apdu::parse_all(&bytes[TCP_PAYLOAD_OFFSET..]).expect("valid APDUs");
+    assert_eq!(apdus.len(), 2);
+    assert_eq!(
+        apdus[1].control,
+        Iec104Control::I {
+            send_sequence: 2,
+            receive_sequence: 1
+        }
+    );
+
+    let measurements = apdus[0].asdu().expect("I-format APDU").expect("valid ASDU");
+    assert_eq!(measurements.type_name(), "M_ME_NC_1");
+    assert_eq!(
+        measurements.cause_name(),
+        "interrogated by station interrogation"
+    );
+    assert_eq!(measurements.common_address, 2561);
+    let values: Vec<(u32, Iec104Value<'_>)> = measurements
+        .objects
+        .iter()
+        .map(|object| (object.address, object.value.clone()))
+        .collect();
+    assert_eq!(
+        values,
+        vec![
+            (
+                1001,
+                Iec104Value::Float {
+                    value: 10.0,
+                    quality: 0
+                }
+            ),
+            (
+                1002,
+                Iec104Value::Float {
+                    value: -3.125,
+                    quality: 0
+                }
+            ),
+            (
+                1003,
+                Iec104Value::Float {
+                    value: 80.0,
+                    quality: 0x10
+                }
+            ),
+        ]
+    );
+
+    let event = apdus[1].asdu().expect("I-format APDU").expect("valid ASDU");
+    assert_eq!(event.cause_name(), "spontaneous");
+    let object = &event.objects[0];
+    assert_eq!(object.address, 3000);
+    assert_eq!(
+        object.value,
+        Iec104Value::SinglePoint {
+            on: true,
+            quality: 0
+        }
+    );
+    let time = object.time.expect("CP56Time2a");
+    assert_eq!(
+        (time.year, time.month, time.day, time.hour, time.minute),
+        (26, 10, 19, 14, 30)
+    );
+    assert_eq!(time.milliseconds, 12_345);
+}
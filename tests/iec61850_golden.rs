@@ -0,0 +1,100 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests IEC 61850 GOOSE et Sampled Values sur trames Ethernet.
+//!
+//! Aucune capture de poste electrique n'est versionnee dans le depot : les
+//! trames sont synthetiques, construites d'apres IEC 61850-8-1 (GOOSE,
+//! EtherType 0x88B8) et IEC 61850-9-2 LE (SV, EtherType 0x88BA). IED
+//! 00:50:c2:00:0a:14, adresses multicast 01:0c:cd:01:00:01 et
+//! 01:0c:cd:04:00:00.
+
+use packet_parser::errors::internet::InternetError;
+use packet_parser::parse::application::protocols::mms::data::MmsData;
+use packet_parser::parse::data_link::ethertype::Ethertype;
+use packet_parser::parse::internet::protocols::iec61850::SvSample;
+use packet_parser::{Internet, InternetDetails, LinkType, parse};
+
+/// GOOSE APPID 1 : stNum 3, sqNum 0, dataset (booleen vrai, qualite).
+const GOOSE_FRAME_HEX: &str = concat!(
+    "010ccd0100010050c2000a1488b8000100620000000061588015494544314c44",
+    "302f4c4c4e3024474f24676362303181020fa08210494544314c44302f4c4c4e",
+    "30244453318303676f3184086a1a3f0080000018850103860100870100880101",
+    "8901008a0102ab088301018403030000"
+);
+
+/// Meme GOOSE derriere une etiquette 802.1Q (VLAN 0, priorite 4).
+const GOOSE_VLAN_FRAME_HEX: &str = concat!(
+    "010ccd0100010050c2000a148100800088b80001006200000000615880154945",
+    "44314c44302f4c4c4e3024474f24676362303181020fa08210494544314c4430",
+    "2f4c4c4e30244453318303676f3184086a1a3f00800000188501038601008701",
+    "008801018901008a0102ab088301018403030000"
+);
+
+/// SV APPID 0x4000 : une ASDU « MU01 », smpCnt 4000, deux echantillons.
+const SV_FRAME_HEX: &str = concat!(
+    "010ccd0400000050c2000a1488ba4000003600000000602c800101a227302580",
+    "044d55303182020fa08304000000018501028710000003e800000000fffffc18",
+    "00002000"
+);
+
+fn frame(hex_fixture: &str, expected_len: usize) -> Vec<u8> {
+    let bytes = hex::decode(hex_fixture).expect("invalid test hex fixture");
+    assert_eq!(bytes.len(), expected_len, "fixture length must match frame");
+    bytes
+}
+
+#[test]
+fn goose_decodes_at_the_internet_layer() {
+    for (hex_fixture, len) in [(GOOSE_FRAME_HEX, 112), (GOOSE_VLAN_FRAME_HEX, 116)] {
+        let bytes = frame(hex_fixture, len);
+        let packet = parse(LinkType::ETHERNET, &bytes).expect("synthetic frame decodes");
+        assert!(packet.transport.is_none());
+        let internet = packet.internet.expect("GOOSE internet layer");
+        assert_eq!(internet.protocol_name, "GOOSE");
+        assert!(internet.source.is_none());
+
+        let Some(InternetDetails::Goose(goose)) = internet.details else {
+            panic!("GOOSE details expected");
+        };
+        assert_eq!(goose.header.appid, 1);
+        assert_eq!(goose.pdu.gocb_ref, "IED1LD0/LLN0$GO$gcb01");
+        assert_eq!((goose.pdu.st_num, goose.pdu.sq_num), (3, 0));
+        assert_eq!(goose.pdu.num_dat_set_entries, 2);
+        let values = goose.pdu.values().expect("valid dataset");
+        assert_eq!(values[0], MmsData::Boolean(true));
+    }
+}
+
+#[test]
+fn truncated_goose_is_an_invalid_internet_format() {
+    let bytes = frame(GOOSE_FRAME_HEX, 112);
+    // Charge utile GOOSE (apres l'en-tete Ethernet) amputee de son dernier octet.
+    let payload = &bytes[14..bytes.len() - 1];
+    let err = Internet::try_from_parts(Ethertype(0x88B8), payload).unwrap_err();
+    assert!(matches!(err, InternetError::InvalidFormat(_)), "{err:?}");
+}
+
+#[test]
+fn sampled_values_decode_at_the_internet_layer() {
+    let bytes = frame(SV_FRAME_HEX, 68);
+    let packet = parse(LinkType::ETHERNET, &bytes).expect("synthetic frame decodes");
+    let internet = packet.internet.expect("SV internet layer");
+    assert_eq!(internet.protocol_name, "SV");
+
+    let Some(InternetDetails::SampledValues(sv)) = internet.details else {
+        panic!("SV details expected");
+    };
+    assert_eq!(sv.header.appid, 0x4000);
+    let asdu = &sv.asdus[0];
+    assert_eq!((asdu.sv_id, asdu.smp_cnt), ("MU01", 4000));
+    assert_eq!(
+        asdu.samples().next(),
+        Some(SvSample {
+            value: 1000,
+            quality: 0
+        })
+    );
+}
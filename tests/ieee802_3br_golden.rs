@@ -150,6 +150,21 @@ fn express_mpacket_decodes_vrrp_announcement() {
 
     let transport = flow.transport.expect("VRRP at the transport slot");
     assert_eq!(transport.protocol, TransportProtocol::Vrrp);
+    let Some(TransportDetails::Vrrp(vrrp)) = transport.details else {
+        panic!("VRRP details are decoded");
+    };
+    assert_eq!(vrrp.version, 2);
+    assert_eq!(vrrp.virtual_router_id, 1);
+    assert_eq!(vrrp.priority, 100);
+    assert_eq!(
+        vrrp.virtual_addresses().collect::<Vec<_>>(),
+        [IpAddr::V4(Ipv4Addr::new(192, 168, 42, 1))]
+    );
+    // 70 octets IP - 20 (en-tete IP) - 12 (en-tete VRRP et adresse) = 8
+    // d'authentification et 30 d'extension : le CRC de queue du mPacket ne
+    // fuit pas dans la queue VRRP.
+    assert_eq!(vrrp.authentication_data.len(), 8);
+    assert_eq!(vrrp.trailer.len(), 30);
 }
 
 #[test]
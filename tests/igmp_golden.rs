@@ -0,0 +1,102 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests IGMP au niveau `PacketFlow`, sur trames Ethernet completes.
+//!
+//! Capture : `pcaps_exemple/vlan0--packet-capture CAPWAP a partir de ligne 520
+//! (Ap = 192_168.0.104 ) + radius _ partir de la ligne 33003 .cap`, Reports
+//! IGMPv3 emis par 192.168.0.102 (en-tete IPv4 avec option Router Alert).
+//!
+//! PIM : aucune trame PIMv2 dans le corpus, golden en attente de capture ;
+//! le parseur est couvert par ses tests unitaires synthetiques.
+
+use packet_parser::parse::transport::TransportDetails;
+use packet_parser::parse::transport::protocols::TransportProtocol;
+use packet_parser::parse::transport::protocols::igmp::{
+    IgmpBody, IgmpGroupRecordType, IgmpVersion,
+};
+use packet_parser::{LinkType, parse};
+use std::net::Ipv4Addr;
+
+/// Trame 1003 : Report IGMPv3, un record CHANGE_TO_EXCLUDE 224.0.0.252.
+const V3_REPORT_SINGLE_RECORD_FRAME_HEX: &str = concat!(
+    "01005e000016382c4a2b2c0c0800460000283605000001024da6c0a80066e000",
+    "0016940400002200f9010000000104000000e00000fc"
+);
+
+/// Trame 1032 : Report IGMPv3, trois records CHANGE_TO_EXCLUDE (LLMNR, mDNS,
+/// SSDP).
+const V3_REPORT_THREE_RECORDS_FRAME_HEX: &str = concat!(
+    "01005e000016382c4a2b2c0c080046000038360c000001024d8fc0a80066e000",
+    "001694040000220020090000000304000000e00000fc04000000e00000fb0400",
+    "0000effffffa"
+);
+
+fn frame(hex_fixture: &str, expected_len: usize) -> Vec<u8> {
+    let bytes = hex::decode(hex_fixture).expect("invalid test hex fixture");
+    assert_eq!(
+        bytes.len(),
+        expected_len,
+        "fixture length must match capture"
+    );
+    bytes
+}
+
+#[test]
+fn packet_flow_decodes_igmpv3_report() {
+    let bytes = frame(V3_REPORT_SINGLE_RECORD_FRAME_HEX, 54);
+    let flow = parse(LinkType::ETHERNET, bytes.as_slice()).expect("captured frame decodes");
+
+    let transport = flow
+        .transport
+        .expect("IGMP is reported at the transport slot");
+    assert_eq!(transport.protocol, TransportProtocol::Igmp);
+    assert_eq!(transport.payload, None);
+
+    let Some(TransportDetails::Igmp(igmp)) = transport.details else {
+        panic!("IGMP details are now decoded, not left empty");
+    };
+    assert_eq!(igmp.message_type, 0x22);
+    assert_eq!(igmp.version, IgmpVersion::V3);
+
+    let IgmpBody::V3Report(records) = igmp.body else {
+        panic!("type 0x22 must expose IGMPv3 group records");
+    };
+    assert_eq!(records.len(), 1);
+    assert_eq!(
+        records[0].record_type,
+        IgmpGroupRecordType::ChangeToExcludeMode
+    );
+    assert_eq!(records[0].multicast_address, Ipv4Addr::new(224, 0, 0, 252));
+    assert_eq!(records[0].source_addresses().count(), 0);
+    assert!(!records[0].is_leave());
+}
+
+#[test]
+fn packet_flow_decodes_every_igmpv3_group_record() {
+    let bytes = frame(V3_REPORT_THREE_RECORDS_FRAME_HEX, 70);
+    let flow = parse(LinkType::ETHERNET, bytes.as_slice()).expect("captured frame decodes");
+
+    let Some(TransportDetails::Igmp(igmp)) = flow
+        .transport
+        .expect("IGMP is reported at the transport slot")
+        .details
+    else {
+        panic!("IGMP details are now decoded, not left empty");
+    };
+
+    let IgmpBody::V3Report(records) = igmp.body else {
+        panic!("type 0x22 must expose IGMPv3 group records");
+    };
+    let groups: Vec<Ipv4Addr> = records.iter().map(|r| r.multicast_address).collect();
+    assert_eq!(
+        groups,
+        [
+            Ipv4Addr::new(224, 0, 0, 252),
+            Ipv4Addr::new(224, 0, 0, 251),
+            Ipv4Addr::new(239, 255, 255, 250),
+        ]
+    );
+}
@@ -0,0 +1,250 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests Kerberos : detection sur UDP et TCP 88 et decodage des
+//! echanges AS et TGS d'un domaine Active Directory.
+//!
+//! Le corpus `pcaps_exemple` ne contient aucune trame Kerberos : les trames
+//! sont synthetiques, construites d'apres RFC 4120 (DER, checksums
+//! IPv4/UDP/TCP corrects). Client 192.168.40.10, KDC 192.168.40.5, royaume
+//! `CORP.EXAMPLE`.
+
+use packet_parser::parse::application::protocols::kerberos::KerberosMessage;
+use packet_parser::{LinkType, parse};
+
+/// AS-REQ de `alice` sans pre-authentification (UDP 51001 -> 88) :
+/// PA-PAC-REQUEST seul, etypes AES256, AES128, RC4, RC4-EXP et -135.
+const AS_REQ_NOAUTH_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e0001020800450000db03004000401165b2c0a8280ac0a8",
+    "2805c739005800c7681f6a81bc3081b9a103020105a20302010aa31530133011",
+    "a10402020080a20904073005a0030101ffa48195308192a00703050040810010",
+    "a1123010a003020101a10930071b05616c696365a20e1b0c434f52502e455841",
+    "4d504c45a321301fa003020102a11830161b066b72627467741b0c434f52502e",
+    "4558414d504c45a511180f32303337303931333032343830355aa611180f3230",
+    "3337303931333032343830355aa70602041c2d3e4fa812301002011202011102",
+    "01170201180202ff79"
+);
+
+/// KRB-ERROR KDC_ERR_PREAUTH_REQUIRED en reponse (88 -> 51001), e-data
+/// PA-ETYPE-INFO2 (AES256 sale, RC4) puis PA-ENC-TIMESTAMP.
+const PREAUTH_REQUIRED_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a010800450000bf04004000401164cec0a82805c0a8",
+    "280a0058c73900ab53677e81a030819da003020105a10302011ea411180f3230",
+    "3236313031393130313131325aa505020305a4b1a603020119a90e1b0c434f52",
+    "502e4558414d504c45aa21301fa003020102a11830161b066b72627467741b0c",
+    "434f52502e4558414d504c45ac3f043d303b302ea103020113a2270425302330",
+    "1aa003020112a1131b11434f52502e4558414d504c45616c6963653005a00302",
+    "01173009a103020102a2020400"
+);
+
+/// AS-REQ pre-authentifie par PA-ENC-TIMESTAMP (TCP 51002 -> 88).
+const AS_REQ_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e0001020800450001160301400040066581c0a8280ac0a8",
+    "2805c73a0058000013880000232850182000f01b0000000000ea6a81e73081e4",
+    "a103020105a20302010aa34730453030a103020102a22904273025a003020112",
+    "a21e041c303132333435363738393a3b3c3d3e3f404142434445464748494a4b",
+    "3011a10402020080a20904073005a0030101ffa4818e30818ba0070305004081",
+    "0010a1123010a003020101a10930071b05616c696365a20e1b0c434f52502e45",
+    "58414d504c45a321301fa003020102a11830161b066b72627467741b0c434f52",
+    "502e4558414d504c45a511180f32303337303931333032343830355aa611180f",
+    "32303337303931333032343830355aa70602041c2d3e50a80b30090201120201",
+    "11020117"
+);
+
+/// AS-REP delivrant le TGT `krbtgt/CORP.EXAMPLE` (AES256, kvno 2).
+const AS_REP_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a01080045000138040140004006645fc0a82805c0a8",
+    "280a0058c73a000023280000149450182000700e00000000010c6b8201083082",
+    "0104a003020105a10302010ba2323030302ea103020113a22704253023301aa0",
+    "03020112a1131b11434f52502e4558414d504c45616c6963653005a003020117",
+    "a30e1b0c434f52502e4558414d504c45a4123010a003020101a10930071b0561",
+    "6c696365a56e616c306aa003020105a10e1b0c434f52502e4558414d504c45a2",
+    "21301fa003020102a11830161b066b72627467741b0c434f52502e4558414d50",
+    "4c45a330302ea003020112a103020102a2220420808182838485868788898a8b",
+    "8c8d8e8f909192939495969798999a9b9c9d9e9fa630302ea003020112a10302",
+    "0103a2220420101112131415161718191a1b1c1d1e1f20212223242526272829",
+    "2a2b2c2d2e2f"
+);
+
+/// TGS-REQ pour `cifs/fs01.corp.example`, PA-TGS-REQ portant l'AP-REQ
+/// du TGT (TCP 51003 -> 88).
+const TGS_REQ_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e00010208004500017e0302400040066518c0a8280ac0a8",
+    "2805c73b0058000017700000271050182000fcf60000000001526c82014e3082",
+    "014aa103020105a20302010ca381bf3081bc3081b9a103020101a281b10481ae",
+    "6e81ab3081a8a003020105a10302010ea20703050000000000a36e616c306aa0",
+    "03020105a10e1b0c434f52502e4558414d504c45a221301fa003020102a11830",
+    "161b066b72627467741b0c434f52502e4558414d504c45a330302ea003020112",
+    "a103020102a2220420808182838485868788898a8b8c8d8e8f90919293949596",
+    "9798999a9b9c9d9e9fa4233021a003020112a21a041850515253545556575859",
+    "5a5b5c5d5e5f6061626364656667a47c307aa00703050040810000a20e1b0c43",
+    "4f52502e4558414d504c45a3243022a003020102a11b30191b04636966731b11",
+    "667330312e636f72702e6578616d706c65a511180f3230333730393133303234",
+    "3830355aa611180f32303337303931333032343830355aa70602042a2b2c2da8",
+    "0b3009020112020111020117"
+);
+
+/// TGS-REP dont le ticket de service est chiffre en RC4 (kvno 7) : la
+/// cible type du kerberoasting.
+const TGS_REP_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a010800450001000402400040066496c0a82805c0a8",
+    "280a0058c73b000027100000191450182000175a0000000000d46d81d13081ce",
+    "a003020105a10302010da30e1b0c434f52502e4558414d504c45a4123010a003",
+    "020101a10930071b05616c696365a571616f306da003020105a10e1b0c434f52",
+    "502e4558414d504c45a2243022a003020102a11b30191b04636966731b116673",
+    "30312e636f72702e6578616d706c65a330302ea003020117a103020107a22204",
+    "20a0a1a2a3a4a5a6a7a8a9aaabacadaeafb0b1b2b3b4b5b6b7b8b9babbbcbdbe",
+    "bfa62b3029a003020112a2220420202122232425262728292a2b2c2d2e2f3031",
+    "32333435363738393a3b3c3d3e3f"
+);
+
+/// KRB-ERROR KDC_ERR_C_PRINCIPAL_UNKNOWN pour `mallory` (88 -> 51004).
+const UNKNOWN_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a010800450000a404034000401164e6c0a82805c0a8",
+    "280a0058c73c0090aa577e8185308182a003020105a10302011ea411180f3230",
+    "3236313031393130313131335aa505020305a4b1a603020106a70e1b0c434f52",
+    "502e4558414d504c45a8143012a003020101a10b30091b076d616c6c6f7279a9",
+    "0e1b0c434f52502e4558414d504c45aa21301fa003020102a11830161b066b72",
+    "627467741b0c434f52502e4558414d504c45"
+);
+
+/// Ethernet (14) + IPv4 (20) + UDP (8).
+const UDP_PAYLOAD_OFFSET: usize = 42;
+/// Ethernet (14) + IPv4 (20) + TCP (20).
+const TCP_PAYLOAD_OFFSET: usize = 54;
+
+fn frame(hex_fixture: &str, expected_len: usize) -> Vec<u8> {
+    let bytes = hex::decode(hex_fixture).expect("invalid test hex fixture");
+    assert_eq!(bytes.len(), expected_len, "fixture length must match frame");
+    bytes
+}
+
+fn application_label(bytes: &[u8]) -> &'static str {
+    parse(LinkType::ETHERNET, bytes)
+        .expect("frame decodes")
+        .application
+        .expect("an application layer is detected")
+        .application_protocol
+}
+
+fn udp_message(bytes: &[u8]) -> KerberosMessage<'_> {
+    KerberosMessage::try_from(&bytes[UDP_PAYLOAD_OFFSET..]).expect("valid Kerberos datagram")
+}
+
+fn tcp_message(bytes: &[u8]) -> KerberosMessage<'_> {
+    let (message, rest) = KerberosMessage::from_tcp_stream(&bytes[TCP_PAYLOAD_OFFSET..])
+        .expect("valid Kerberos TCP record");
+    assert!(rest.is_empty(), "one record per segment");
+    message
+}
+
+#[test]
+fn packet_flow_labels_kerberos_over_udp_and_tcp() {
+    for (hex_fixture, expected_len) in [
+        (AS_REQ_NOAUTH_FRAME_HEX, 233),
+        (PREAUTH_REQUIRED_FRAME_HEX, 205),
+        (AS_REQ_FRAME_HEX, 292),
+        (AS_REP_FRAME_HEX, 326),
+        (TGS_REQ_FRAME_HEX, 396),
+        (TGS_REP_FRAME_HEX, 270),
+        (UNKNOWN_FRAME_HEX, 178),
+    ] {
+        assert_eq!(
+            application_label(&frame(hex_fixture, expected_len)),
+            "Kerberos"
+        );
+    }
+}
+
+#[test]
+fn as_exchange_and_pre_authentication() {
+    let bytes = frame(AS_REQ_NOAUTH_FRAME_HEX, 233);
+    let KerberosMessage::AsReq(request) = udp_message(&bytes) else {
+        panic!("AS-REQ expected");
+    };
+    assert_eq!(request.realm, "CORP.EXAMPLE");
+    assert_eq!(
+        request.cname.as_ref().map(ToString::to_string).as_deref(),
+        Some("alice")
+    );
+    // Sans preuve de la cle du client : candidat a l'AS-REP roasting si le
+    // compte n'exige pas de pre-authentification.
+    assert!(!request.is_pre_authenticated());
+    assert_eq!(request.etypes, [18, 17, 23, 24, -135]);
+
+    let bytes = frame(PREAUTH_REQUIRED_FRAME_HEX, 205);
+    let KerberosMessage::Error(error) = udp_message(&bytes) else {
+        panic!("KRB-ERROR expected");
+    };
+    assert_eq!(error.error_code_name(), "KDC_ERR_PREAUTH_REQUIRED");
+    assert_eq!(error.sname.to_string(), "krbtgt/CORP.EXAMPLE");
+
+    let bytes = frame(AS_REQ_FRAME_HEX, 292);
+    let KerberosMessage::AsReq(request) = tcp_message(&bytes) else {
+        panic!("AS-REQ expected");
+    };
+    assert!(request.is_pre_authenticated());
+    let names: Vec<_> = request
+        .padata
+        .iter()
+        .map(|padata| padata.type_name())
+        .collect();
+    assert_eq!(names, ["PA-ENC-TIMESTAMP", "PA-PAC-REQUEST"]);
+
+    let bytes = frame(AS_REP_FRAME_HEX, 326);
+    let message = tcp_message(&bytes);
+    assert_eq!(message.message_type_name(), "AS-REP");
+    let KerberosMessage::AsRep(reply) = message else {
+        panic!("AS-REP expected");
+    };
+    assert_eq!(reply.cname.to_string(), "alice");
+    assert_eq!(reply.ticket.sname.to_string(), "krbtgt/CORP.EXAMPLE");
+    assert_eq!(reply.ticket.enc_part.kvno, Some(2));
+    assert_eq!(reply.enc_part.etype_name(), "aes256-cts-hmac-sha1-96");
+}
+
+#[test]
+fn tgs_exchange_with_rc4_service_ticket() {
+    let bytes = frame(TGS_REQ_FRAME_HEX, 396);
+    let KerberosMessage::TgsReq(request) = tcp_message(&bytes) else {
+        panic!("TGS-REQ expected");
+    };
+    assert_eq!(request.cname, None);
+    assert_eq!(
+        request.sname.as_ref().map(ToString::to_string).as_deref(),
+        Some("cifs/fs01.corp.example")
+    );
+    let tgt = request
+        .ap_request()
+        .expect("PA-TGS-REQ")
+        .expect("valid AP-REQ");
+    assert_eq!(tgt.ticket.sname.to_string(), "krbtgt/CORP.EXAMPLE");
+
+    let bytes = frame(TGS_REP_FRAME_HEX, 270);
+    let KerberosMessage::TgsRep(reply) = tcp_message(&bytes) else {
+        panic!("TGS-REP expected");
+    };
+    assert_eq!(reply.ticket.sname.type_name(), "NT-SRV-INST");
+    assert_eq!(reply.ticket.enc_part.etype_name(), "rc4-hmac");
+    assert!(reply.ticket.enc_part.is_weak());
+    assert!(!reply.enc_part.is_weak());
+}
+
+#[test]
+fn unknown_principal_error() {
+    let bytes = frame(UNKNOWN_FRAME_HEX, 178);
+    let message = udp_message(&bytes);
+    assert_eq!(message.realm(), Some("CORP.EXAMPLE"));
+    let KerberosMessage::Error(error) = message else {
+        panic!("KRB-ERROR expected");
+    };
+    assert_eq!(error.error_code_name(), "KDC_ERR_C_PRINCIPAL_UNKNOWN");
+    assert_eq!(error.crealm, Some("CORP.EXAMPLE"));
+    assert_eq!(
+        error.cname.as_ref().map(ToString::to_string).as_deref(),
+        Some("mallory")
+    );
+    assert_eq!(error.stime.to_rfc3339(), "2026-10-19T10:11:13+00:00");
+}
@@ -0,0 +1,104 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests KNXnet/IP sur trames Ethernet completes.
+//!
+//! Aucune capture KNXnet/IP publique n'est versionnee dans le depot : les
+//! trames sont synthetiques, construites d'apres KNX 03.08 (checksums
+//! IPv4/UDP corrects). Client de tunnelling 192.168.20.50, routeur IP
+//! 192.168.20.10, groupe de routage 224.0.23.12, UDP 3671.
+
+use packet_parser::parse::application::protocols::knxnet_ip::{
+    KnxnetIpBody, KnxnetIpPacket,
+    cemi::{KnxAddress, KnxGroupAddress, KnxGroupValue, KnxIndividualAddress},
+};
+use packet_parser::{LinkType, parse};
+
+/// Client -> routeur : TUNNELLING_REQUEST canal 1, sequence 0, portant un
+/// L_Data.req GroupValueWrite(1) vers 1/2/3.
+const TUNNELLING_REQUEST_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e0001020800450000310300400040118e2fc0a81432c0a8",
+    "140a0e570e57001dd14e061004200015040100001100bce000000a03010081"
+);
+
+/// Routeur -> multicast : ROUTING_INDICATION, L_Data.ind de 1.1.5 vers
+/// 1/2/3, GroupValueWrite de deux octets (DPT 9, 0x0C66).
+const ROUTING_INDICATION_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a0108004500002f0301400040116bfec0a8140ae000",
+    "170c0e570e57001b22120610053000132900bcd011050a030300800c66"
+);
+
+/// Ethernet (14) + IPv4 (20) + UDP (8).
+const UDP_PAYLOAD_OFFSET: usize = 42;
+
+fn frame(hex_fixture: &str, expected_len: usize) -> Vec<u8> {
+    let bytes = hex::decode(hex_fixture).expect("invalid test hex fixture");
+    assert_eq!(bytes.len(), expected_len, "fixture length must match frame");
+    bytes
+}
+
+fn application_label(bytes: &[u8]) -> &'static str {
+    parse(LinkType::ETHERNET, bytes)
+        .expect("synthetic frame decodes")
+        .application
+        .expect("an application layer is detected")
+        .application_protocol
+}
+
+#[test]
+fn packet_flow_labels_knxnet_ip() {
+    assert_eq!(
+        application_label(&frame(TUNNELLING_REQUEST_FRAME_HEX, 63)),
+        "KNXnet/IP"
+    );
+    assert_eq!(
+        application_label(&frame(ROUTING_INDICATION_FRAME_HEX, 61)),
+        "KNXnet/IP"
+    );
+}
+
+#[test]
+fn tunnelling_request_decodes_group_write() {
+    let bytes = frame(TUNNELLING_REQUEST_FRAME_HEX, 63);
+    let packet = KnxnetIpPacket::try_from(&bytes[UDP_PAYLOAD_OFFSET..]).expect("valid frame");
+    assert_eq!(packet.service_name(), "TUNNELLING_REQUEST");
+    let KnxnetIpBody::TunnellingRequest { header, .. } = packet.parse_body().expect("valid body")
+    else {
+        panic!("tunnelling request expected");
+    };
+    assert_eq!((header.channel_id, header.sequence), (1, 0));
+
+    let cemi = packet.cemi().expect("cEMI service").expect("valid cEMI");
+    assert_eq!(cemi.message_name(), "L_Data.req");
+    let l_data = cemi.l_data().expect("L_Data").expect("valid L_Data");
+    assert_eq!(
+        l_data.destination,
+        KnxAddress::Group(KnxGroupAddress(0x0A03))
+    );
+    assert_eq!(l_data.destination.to_string(), "1/2/3");
+    assert_eq!(l_data.apci_name(), Some("GroupValueWrite"));
+    assert_eq!(l_data.group_value(), Some(KnxGroupValue::Short(1)));
+}
+
+#[test]
+fn routing_indication_decodes_two_byte_value() {
+    let bytes = frame(ROUTING_INDICATION_FRAME_HEX, 61);
+    let packet = KnxnetIpPacket::try_from(&bytes[UDP_PAYLOAD_OFFSET..]).expect("valid frame");
+    assert_eq!(packet.service_name(), "ROUTING_INDICATION");
+    let l_data = packet
+        .cemi()
+        .expect("cEMI service")
+        .expect("valid cEMI")
+        .l_data()
+        .expect("L_Data")
+        .expect("valid L_Data");
+    assert_eq!(l_data.source, KnxIndividualAddress(0x1105));
+    assert_eq!(l_data.source.to_string(), "1.1.5");
+    assert_eq!(l_data.priority_name(), "low");
+    assert_eq!(
+        l_data.group_value(),
+        Some(KnxGroupValue::Data(&[0x0C, 0x66]))
+    );
+}
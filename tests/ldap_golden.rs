@@ -0,0 +1,238 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests LDAP : detection sur TCP 389 et 3268 et CLDAP sur UDP 389,
+//! decodage des binds, recherches et resultats d'Active Directory.
+//!
+//! Le corpus `pcaps_exemple` ne contient aucune trame LDAP : les trames sont
+//! synthetiques, construites d'apres RFC 4511 et MS-ADTS (BER, checksums
+//! IPv4/UDP/TCP corrects). Client 192.168.40.10, controleur de domaine
+//! 192.168.40.5.
+
+use packet_parser::parse::application::protocols::ldap::{
+    LdapAuthentication, LdapOperation, LdapPacket,
+};
+use packet_parser::{LinkType, parse};
+
+/// bindRequest simple de `svc-backup` en clair (TCP 52001 -> 389).
+const SIMPLE_BIND_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e00010208004500006c031040004006661cc0a8280ac0a8",
+    "2805cb21018500001b5800002af850182000f48100003042020101603d020103",
+    "042b434e3d7376632d6261636b75702c4f553d536572766963652c44433d636f",
+    "72702c44433d6578616d706c65800b57696e7465723230323621"
+);
+
+/// bindResponse invalidCredentials, sous-code Active Directory `data 52e`.
+const INVALID_CREDENTIALS_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a0108004500008e04104000400664fac0a82805c0a8",
+    "280a0185cb2100002af800001b9c50182000771400003064020101615f0a0131",
+    "0400045838303039303330383a204c6461704572723a20445349442d30433039",
+    "303434452c20636f6d6d656e743a204163636570745365637572697479436f6e",
+    "74657874206572726f722c2064617461203532652c20763435363300"
+);
+
+/// searchRequest `wholeSubtree` des comptes `adm*` actifs, avec le controle
+/// de pagination (TCP 52002 -> 389).
+const SEARCH_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e0001020800450000ff0311400040066588c0a8280ac0a8",
+    "2805cb22018500001f4000002ee050182000a8fc00003081d40201026381a804",
+    "1244433d636f72702c44433d6578616d706c650a01020a0100020203e8020100",
+    "010100a066a318040e6f626a65637443617465676f72790406706572736f6ea4",
+    "17040e73414d4163636f756e744e616d653005800361646da231a92f8116312e",
+    "322e3834302e3131333535362e312e342e3830338212757365724163636f756e",
+    "74436f6e74726f6c830132301a040e73414d4163636f756e744e616d6504086d",
+    "656d6265724f66a02430220416312e322e3834302e3131333535362e312e342e",
+    "33313904083006020203e80400"
+);
+
+/// searchResEntry de `adm-alice` et searchResDone dans le meme segment.
+const ENTRY_DONE_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a010800450000ff0411400040066488c0a82805c0a8",
+    "280a0185cb2200002ee000002017501820003ba200003081c60201026481c004",
+    "29434e3d61646d2d616c6963652c4f553d41646d696e732c44433d636f72702c",
+    "44433d6578616d706c65308192301d040e73414d4163636f756e744e616d6531",
+    "0b040961646d2d616c696365307104086d656d6265724f663165042c434e3d44",
+    "6f6d61696e2041646d696e732c434e3d55736572732c44433d636f72702c4443",
+    "3d6578616d706c650435434e3d52656d6f7465204465736b746f702055736572",
+    "732c434e3d4275696c74696e2c44433d636f72702c44433d6578616d706c6530",
+    "0c02010265070a010004000400"
+);
+
+/// Recherche des ordinateurs dotes d'un SPN sur le catalogue global
+/// (TCP 52003 -> 3268).
+const GLOBAL_CATALOG_SEARCH_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e00010208004500009103124000400665f5c0a8280ac0a8",
+    "2805cb230cc400002328000032c85018200013f200003067020103636204000a",
+    "01020a0100020100020100010100a02fa317040b6f626a656374436c61737304",
+    "08636f6d70757465728714736572766963655072696e636970616c4e616d6530",
+    "1e040b644e53486f73744e616d65040f6f7065726174696e6753797374656d"
+);
+
+/// Ping CLDAP de localisation du controleur de domaine (UDP 52004 -> 389).
+const CLDAP_PING_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e00010208004500006c031340004011660ec0a8280ac0a8",
+    "2805cb24018500583fc1304e020104634904000a01000a010002010002010001",
+    "0100a02aa3190409446e73446f6d61696e040c636f72702e6578616d706c65a3",
+    "0d04054e74566572040406000000300a04084e65746c6f676f6e"
+);
+
+/// Reponse CLDAP : attribut `Netlogon` binaire puis searchResDone.
+const CLDAP_REPLY_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a0108004500005d041240004011651ec0a82805c0a8",
+    "280a0185cb24004905e23031020104642c04003028302604084e65746c6f676f",
+    "6e311a041817000000fd0300000102030405060708090a0b0c0d0e0f10300c02",
+    "010465070a010004000400"
+);
+
+/// Ethernet (14) + IPv4 (20) + UDP (8).
+const UDP_PAYLOAD_OFFSET: usize = 42;
+/// Ethernet (14) + IPv4 (20) + TCP (20).
+const TCP_PAYLOAD_OFFSET: usize = 54;
+
+fn frame(hex_fixture: &str, expected_len: usize) -> Vec<u8> {
+    let bytes = hex::decode(hex_fixture).expect("invalid test hex fixture");
+    assert_eq!(bytes.len(), expected_len, "fixture length must match frame");
+    bytes
+}
+
+fn application_label(bytes: &[u8]) -> &'static str {
+    parse(LinkType::ETHERNET, bytes)
+        .expect("frame decodes")
+        .application
+        .expect("an application layer is detected")
+        .application_protocol
+}
+
+fn packet(bytes: &[u8], offset: usize) -> LdapPacket<'_> {
+    LdapPacket::try_from(&bytes[offset..]).expect("valid LDAP payload")
+}
+
+#[test]
+fn packet_flow_labels_ldap_global_catalog_and_cldap() {
+    for (hex_fixture, expected_len, label) in [
+        (SIMPLE_BIND_FRAME_HEX, 122, "LDAP"),
+        (INVALID_CREDENTIALS_FRAME_HEX, 156, "LDAP"),
+        (SEARCH_FRAME_HEX, 269, "LDAP"),
+        (ENTRY_DONE_FRAME_HEX, 269, "LDAP"),
+        (GLOBAL_CATALOG_SEARCH_FRAME_HEX, 159, "LDAP"),
+        (CLDAP_PING_FRAME_HEX, 122, "CLDAP"),
+        (CLDAP_REPLY_FRAME_HEX, 107, "CLDAP"),
+    ] {
+        assert_eq!(application_label(&frame(hex_fixture, expected_len)), label);
+    }
+}
+
+#[test]
+fn cleartext_bind_and_invalid_credentials() {
+    let bytes = frame(SIMPLE_BIND_FRAME_HEX, 122);
+    let request = packet(&bytes, TCP_PAYLOAD_OFFSET);
+    let LdapOperation::BindRequest(bind) = &request.messages[0].operation else {
+        panic!("bindRequest expected");
+    };
+    assert_eq!(bind.version, 3);
+    assert_eq!(bind.name, "CN=svc-backup,OU=Service,DC=corp,DC=example");
+    // Mot de passe en clair sur le reseau.
+    assert_eq!(
+        bind.authentication,
+        LdapAuthentication::Simple(b"Winter2026!")
+    );
+    assert!(!bind.is_anonymous());
+
+    let bytes = frame(INVALID_CREDENTIALS_FRAME_HEX, 156);
+    let response = packet(&bytes, TCP_PAYLOAD_OFFSET);
+    let message = &response.messages[0];
+    assert_eq!(message.message_id, 1);
+    assert_eq!(message.operation.name(), "bindResponse");
+    let result = message.operation.result().expect("LDAPResult");
+    assert_eq!(result.result_code_name(), "invalidCredentials");
+    // data 52e : mot de passe errone pour un compte existant.
+    assert!(result.diagnostic_message.contains("data 52e"));
+}
+
+#[test]
+fn paged_search_and_results() {
+    let bytes = frame(SEARCH_FRAME_HEX, 269);
+    let request = packet(&bytes, TCP_PAYLOAD_OFFSET);
+    let message = &request.messages[0];
+    assert_eq!(message.controls[0].oid, "1.2.840.113556.1.4.319");
+    assert!(!message.controls[0].critical);
+    let LdapOperation::SearchRequest(search) = &message.operation else {
+        panic!("searchRequest expected");
+    };
+    assert_eq!(search.base_object, "DC=corp,DC=example");
+    assert_eq!(search.scope_name(), "wholeSubtree");
+    assert_eq!(search.size_limit, 1000);
+    assert_eq!(
+        search.filter.to_string(),
+        "(&(objectCategory=person)(sAMAccountName=adm*)\
+         (!(userAccountControl:1.2.840.113556.1.4.803:=2)))"
+    );
+    assert_eq!(search.attributes, ["sAMAccountName", "memberOf"]);
+
+    let bytes = frame(ENTRY_DONE_FRAME_HEX, 269);
+    let response = packet(&bytes, TCP_PAYLOAD_OFFSET);
+    let names: Vec<_> = response
+        .messages
+        .iter()
+        .map(|message| message.operation.name())
+        .collect();
+    assert_eq!(names, ["searchResEntry", "searchResDone"]);
+    let LdapOperation::SearchResultEntry(entry) = &response.messages[0].operation else {
+        panic!("searchResEntry expected");
+    };
+    assert_eq!(
+        entry.object_name,
+        "CN=adm-alice,OU=Admins,DC=corp,DC=example"
+    );
+    assert_eq!(
+        entry.values("samaccountname"),
+        Some([b"adm-alice".as_slice()].as_slice())
+    );
+    assert_eq!(entry.values("memberOf").map(<[_]>::len), Some(2));
+    assert!(
+        response.messages[1]
+            .operation
+            .result()
+            .is_some_and(|result| result.is_success())
+    );
+}
+
+#[test]
+fn global_catalog_and_cldap_ping() {
+    let bytes = frame(GLOBAL_CATALOG_SEARCH_FRAME_HEX, 159);
+    let request = packet(&bytes, TCP_PAYLOAD_OFFSET);
+    let LdapOperation::SearchRequest(search) = &request.messages[0].operation else {
+        panic!("searchRequest expected");
+    };
+    // Base vide : recherche sur toute la foret.
+    assert_eq!(search.base_object, "");
+    assert_eq!(
+        search.filter.to_string(),
+        "(&(objectClass=computer)(servicePrincipalName=*))"
+    );
+
+    let bytes = frame(CLDAP_PING_FRAME_HEX, 122);
+    let ping = packet(&bytes, UDP_PAYLOAD_OFFSET);
+    let LdapOperation::SearchRequest(search) = &ping.messages[0].operation else {
+        panic!("searchRequest expected");
+    };
+    assert_eq!(search.scope_name(), "baseObject");
+    assert_eq!(
+        search.filter.to_string(),
+        "(&(DnsDomain=corp.example)(NtVer=\\06\\00\\00\\00))"
+    );
+    assert_eq!(search.filter.attributes(), ["DnsDomain", "NtVer"]);
+    assert_eq!(search.attributes, ["Netlogon"]);
+
+    let bytes = frame(CLDAP_REPLY_FRAME_HEX, 107);
+    let reply = packet(&bytes, UDP_PAYLOAD_OFFSET);
+    let LdapOperation::SearchResultEntry(entry) = &reply.messages[0].operation else {
+        panic!("searchResEntry expected");
+    };
+    let netlogon = entry.values("Netlogon").expect("Netlogon attribute")[0];
+    // NETLOGON_SAM_LOGON_RESPONSE_EX (opcode 23).
+    assert_eq!(&netlogon[..2], [0x17, 0x00]);
+    assert_eq!(reply.messages.len(), 2);
+}
@@ -0,0 +1,125 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests MMS (ISO 9506, IEC 61850-8-1) sur trames Ethernet completes.
+//!
+//! Aucune capture MMS publique n'est versionnee dans le depot : les trames
+//! sont synthetiques, empilant TPKT, COTP DT, session, presentation et ACSE
+//! d'apres IEC 61850-8-1 (checksums IPv4/TCP corrects). Client
+//! 192.168.20.50, IED 192.168.20.10 sur TCP 102.
+
+use packet_parser::parse::application::protocols::mms::{
+    MmsPacket,
+    data::{MmsAccessResult, MmsData},
+    service::{MmsObjectName, MmsService, MmsVariableAccess},
+};
+use packet_parser::{LinkType, parse};
+
+/// Client -> IED : CONNECT de session, CP de presentation, AARQ portant
+/// l'initiate-RequestPDU.
+const ASSOCIATE_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e0001020800450000c50400400040068ca6c0a81432c0a8",
+    "140ac4180066000013880000177050182000da7d00000300009d02f0800d9405",
+    "06130100160102140200023302000134020001c17e317ca003800101a2758104",
+    "00000001820400000001a422300d020101060252013004060251013011020103",
+    "060628ca2202010430040602510161433041020101a03c603aa107060528ca22",
+    "0203be2f282d020103a028a826800300fde881010582010583010aa416800101",
+    "810305f100820c03ee1c00000408000079ef18"
+);
+
+/// Client -> IED : Read de IED1LD0/LLN0$ST$Mod$stVal, invokeID 5.
+const READ_REQUEST_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e0001020800450000690401400040068d01c0a81432c0a8",
+    "140ac418006600001425000017705018200020dd00000300004102f080010001",
+    "0061343032020103a02da02b020105a426a124a0223020a01ea11c1a07494544",
+    "314c44301a114c4c4e30245354244d6f6424737456616c"
+);
+
+/// IED -> client : reponse au Read, INTEGER 1 (mode « on »).
+const READ_RESPONSE_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a010800450000480500400040068c23c0a8140ac0a8",
+    "14320066c418000017700000146650182000f73e00000300002002f080010001",
+    "0061133011020103a00ca10a020105a405a103850101"
+);
+
+/// Ethernet (14) + IPv4 (20) + TCP sans options (20).
+const TCP_PAYLOAD_OFFSET: usize = 54;
+
+fn frame(hex_fixture: &str, expected_len: usize) -> Vec<u8> {
+    let bytes = hex::decode(hex_fixture).expect("invalid test hex fixture");
+    assert_eq!(bytes.len(), expected_len, "fixture length must match frame");
+    bytes
+}
+
+fn application_label(bytes: &[u8]) -> &'static str {
+    parse(LinkType::ETHERNET, bytes)
+        .expect("synthetic frame decodes")
+        .application
+        .expect("an application layer is detected")
+        .application_protocol
+}
+
+#[test]
+fn packet_flow_labels_mms_ahead_of_cotp() {
+    assert_eq!(application_label(&frame(ASSOCIATE_FRAME_HEX, 211)), "MMS");
+    assert_eq!(
+        application_label(&frame(READ_REQUEST_FRAME_HEX, 119)),
+        "MMS"
+    );
+    assert_eq!(
+        application_label(&frame(READ_RESPONSE_FRAME_HEX, 86)),
+        "MMS"
+    );
+}
+
+#[test]
+fn association_carries_initiate_request() {
+    let bytes = frame(ASSOCIATE_FRAME_HEX, 211);
+    let packet = MmsPacket::try_from(&bytes[TCP_PAYLOAD_OFFSET..]).expect("valid MMS packet");
+    assert_eq!(packet.spdu_name(), "CONNECT");
+    assert_eq!(packet.acse_name(), Some("AARQ"));
+    assert_eq!(packet.pdu.pdu_name(), "initiate-RequestPDU");
+    let Some(Ok(MmsService::Initiate {
+        max_outstanding_calling,
+        nesting_level,
+        ..
+    })) = packet.pdu.service()
+    else {
+        panic!("initiate request expected");
+    };
+    assert_eq!((max_outstanding_calling, nesting_level), (5, Some(10)));
+}
+
+#[test]
+fn read_round_trip_names_the_data_attribute() {
+    let bytes = frame(READ_REQUEST_FRAME_HEX, 119);
+    let request = MmsPacket::try_from(&bytes[TCP_PAYLOAD_OFFSET..]).expect("valid MMS packet");
+    assert_eq!(request.spdu_name(), "DATA TRANSFER");
+    assert_eq!(request.pdu.invoke_id, Some(5));
+    assert_eq!(request.pdu.service_name(), Some("read"));
+    let Some(Ok(MmsService::Read {
+        variables: MmsVariableAccess::Variables(names),
+        ..
+    })) = request.pdu.service()
+    else {
+        panic!("read request expected");
+    };
+    assert_eq!(
+        names,
+        vec![MmsObjectName::DomainSpecific {
+            domain_id: "IED1LD0",
+            item_id: "LLN0$ST$Mod$stVal"
+        }]
+    );
+    assert_eq!(names[0].to_string(), "IED1LD0/LLN0$ST$Mod$stVal");
+
+    let bytes = frame(READ_RESPONSE_FRAME_HEX, 86);
+    let response = MmsPacket::try_from(&bytes[TCP_PAYLOAD_OFFSET..]).expect("valid MMS packet");
+    assert_eq!(response.pdu.invoke_id, Some(5));
+    let Some(Ok(MmsService::ReadResponse { results, .. })) = response.pdu.service() else {
+        panic!("read response expected");
+    };
+    assert_eq!(results, vec![MmsAccessResult::Success(MmsData::Integer(1))]);
+}
@@ -0,0 +1,184 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests du décodage typé Modbus/TCP et de la corrélation
+//! requête/réponse sur les captures de `pcaps_exemple/protocols/modbus`.
+//! Les numéros de trame et valeurs attendues ont été recoupés avec
+//! Wireshark (tshark -O mbtcp,modbus).
+
+use std::path::Path;
+
+use packet_parser::{
+    LinkType,
+    errors::application::modbus_tcp::ModbusDecodeError,
+    parse,
+    parse::application::protocols::modbus_tcp::{
+        ModbusTcpPacket,
+        transaction::{ModbusTable, ModbusTransaction, ModbusTransactionTracker, ModbusValues},
+    },
+};
+
+mod common;
+use common::{FileRead, read_capture};
+
+const MODBUS_PORT: u16 = 502;
+
+/// Payload Modbus/TCP de chaque trame, avec son numéro Wireshark et son
+/// sens (vrai pour client → serveur).
+fn modbus_payloads(name: &str) -> Vec<(usize, bool, Vec<u8>)> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("pcaps_exemple/protocols/modbus")
+        .join(name);
+    let frames: Vec<(LinkType, Vec<u8>)> = match read_capture(&path) {
+        FileRead::Frames { frames, .. } => frames,
+        FileRead::Unsupported => panic!("{}: unreadable capture", path.display()),
+    };
+    frames
+        .iter()
+        .enumerate()
+        .filter_map(|(index, (link_type, data))| {
+            let transport = parse(*link_type, data).ok()?.transport?;
+            let payload = transport.payload.filter(|payload| !payload.is_empty())?;
+            let to_server = transport.destination_port == Some(MODBUS_PORT);
+            if !to_server && transport.source_port != Some(MODBUS_PORT) {
+                return None;
+            }
+            Some((index + 1, to_server, payload.to_vec()))
+        })
+        .collect()
+}
+
+/// Rejoue une capture dans un tracker ; retourne les transactions et les
+/// erreurs de décodage par numéro de trame.
+fn replay(name: &str) -> (Vec<ModbusTransaction>, Vec<(usize, ModbusDecodeError)>) {
+    let mut tracker = ModbusTransactionTracker::new();
+    let mut transactions = Vec::new();
+    let mut errors = Vec::new();
+    for (number, to_server, payload) in modbus_payloads(name) {
+        let packet = ModbusTcpPacket::try_from(payload.as_slice()).expect("Modbus/TCP framing");
+        let pushed = if to_server {
+            tracker.push_request(&packet)
+        } else {
+            tracker.push_response(&packet)
+        };
+        if let Err(error) = pushed {
+            errors.push((number, error));
+        }
+        while let Some(transaction) = tracker.next_transaction() {
+            transactions.push(transaction);
+        }
+    }
+    (transactions, errors)
+}
+
+#[test]
+fn holding_register_reads_pair_with_their_values() {
+    let (transactions, errors) = replay("Modbus.pcap");
+    assert!(errors.is_empty(), "{errors:?}");
+    // 51 requêtes, mais aux trames 95-102 le serveur répond avec les
+    // identifiants 1 à 4 à des requêtes portant 0 : ces quatre réponses
+    // restent sans requête, comme dans Wireshark.
+    assert_eq!(transactions.len(), 47);
+    for transaction in &transactions {
+        assert_eq!(transaction.function_code, 0x03);
+        assert!(transaction.write.is_none());
+        let read = transaction.read.as_ref().expect("read range");
+        assert_eq!(read.table, ModbusTable::HoldingRegisters);
+        let Some(ModbusValues::Registers(values)) = &read.values else {
+            panic!("register values expected");
+        };
+        assert_eq!(values.len(), usize::from(read.quantity));
+    }
+
+    // Trames 1-4 : registre 1 puis registre 0 (0x41C8).
+    let first = transactions[0].read.as_ref().unwrap();
+    assert_eq!(
+        (transactions[0].transaction_identifier, first.address),
+        (0xd1, 1)
+    );
+    assert_eq!(first.values, Some(ModbusValues::Registers(vec![0x0000])));
+    let second = transactions[1].read.as_ref().unwrap();
+    assert_eq!(second.address, 0);
+    assert_eq!(second.values, Some(ModbusValues::Registers(vec![0x41c8])));
+}
+
+#[test]
+fn function_code_sweep_decodes() {
+    let (transactions, errors) = replay("MODBUS-TestDataPart2.pcap");
+
+    // Seules trois requêtes du balayage sont malformées : Write Single
+    // Register suivi de deux octets (trame 22), Mask Write Register et
+    // Read/Write Multiple Registers amputés (trames 62 et 64).
+    assert_eq!(
+        errors,
+        [
+            (
+                22,
+                ModbusDecodeError::TrailingData {
+                    field: "register value",
+                    length: 2
+                }
+            ),
+            (62, ModbusDecodeError::Truncated { field: "OR mask" }),
+            (
+                64,
+                ModbusDecodeError::Truncated {
+                    field: "write starting address"
+                }
+            ),
+        ]
+    );
+
+    // Les 125 autres fonctions du balayage (0 à 127) obtiennent une
+    // réponse, suivies de 12 lectures de bobines.
+    assert_eq!(transactions.len(), 137);
+    let exceptions = transactions[..125]
+        .iter()
+        .filter(|transaction| transaction.is_exception())
+        .count();
+    assert_eq!(exceptions, 119);
+
+    // Trames 20-21 : Write Single Coil 0 à OFF, accepté.
+    let write = &transactions[5];
+    assert_eq!(write.function_code, 0x05);
+    assert!(write.is_successful_write());
+    let access = write.write.as_ref().unwrap();
+    assert_eq!((access.table, access.address), (ModbusTable::Coils, 0));
+    assert_eq!(access.values, Some(ModbusValues::Bits(vec![false])));
+
+    // Trames 318-320 : huit bobines à partir de 0 (octet 0x15).
+    let read = transactions[125].read.as_ref().unwrap();
+    assert_eq!(
+        read.values,
+        Some(ModbusValues::Bits(vec![
+            true, false, true, false, true, false, false, false
+        ]))
+    );
+
+    // Puis recherche dichotomique de la taille de la table : 0xFFFF,
+    // 0x7FFF... refusées en Illegal Data Address jusqu'à 1023.
+    let probes: Vec<_> = transactions[127..]
+        .iter()
+        .map(|transaction| {
+            let read = transaction.read.as_ref().unwrap();
+            (read.address, transaction.exception_code)
+        })
+        .collect();
+    assert_eq!(
+        probes,
+        [
+            (65535, Some(2)),
+            (32767, Some(2)),
+            (16383, Some(2)),
+            (8191, Some(2)),
+            (4095, Some(2)),
+            (2047, Some(2)),
+            (1023, None),
+            (1535, None),
+            (1791, Some(2)),
+            (1663, Some(2)),
+        ]
+    );
+}
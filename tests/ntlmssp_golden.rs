@@ -0,0 +1,165 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests NTLMSSP : echange NEGOTIATE, CHALLENGE, AUTHENTICATE porte
+//! par un bind LDAP Sicily d'Active Directory.
+//!
+//! Le corpus `pcaps_exemple` ne contient aucune authentification NTLM : les
+//! trames sont synthetiques, construites d'apres MS-NLMP et MS-ADTS
+//! (checksums IPv4/TCP corrects). Client 192.168.40.10, controleur de
+//! domaine 192.168.40.5.
+
+use packet_parser::parse::application::protocols::{
+    ldap::{LdapOperation, LdapPacket},
+    ntlmssp::{NtlmResponseVersion, NtlmsspMessage},
+};
+use packet_parser::{LinkType, parse};
+
+/// bindRequest Sicily portant le NEGOTIATE NTLMSSP (TCP 52010 -> 389).
+const SICILY_NEGOTIATE_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e00010208004500005e032040004006661ac0a8280ac0a8",
+    "2805cb2a018500004e200000753050182000ebc400003034020105602f020103",
+    "04008a284e544c4d5353500001000000978208e2000000000000000000000000",
+    "000000000a0063450000000f"
+);
+
+/// bindResponse dont le matchedDN porte le CHALLENGE : `CORP`, `DC01`,
+/// `dc01.corp.example`, Windows Server 2019 (10.0.17763).
+const SICILY_CHALLENGE_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a010800450000ff0420400040066479c0a82805c0a8",
+    "280a0185cb2a0000753000004e565018200027c600003081d40201056181ce0a",
+    "01000481c64e544c4d53535000020000000800080038000000158288e2112233",
+    "4455667788000000000000000086008600400000000a0063450000000f43004f",
+    "00520050000200080043004f0052005000010008004400430030003100040018",
+    "0063006f00720070002e006500780061006d0070006c00650003002200640063",
+    "00300031002e0063006f00720070002e006500780061006d0070006c00650005",
+    "00180063006f00720070002e006500780061006d0070006c0065000700080000",
+    "60ed2ab25fdd01000000000400"
+);
+
+/// bindRequest Sicily portant l'AUTHENTICATE NTLMv2 de `CORP\alice` depuis
+/// `WS01`, avec MIC.
+const SICILY_AUTHENTICATE_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e0001020800450001c403214000400664b3c0a8280ac0a8",
+    "2805cb2a018500004e560000760750182000959d000030820198020106608201",
+    "9102010304008b8201884e544c4d53535000030000001800180058000000ee00",
+    "ee0070000000080008005e0100000a000a006601000008000800700100001000",
+    "100078010000158288e20a0063450000000f909192939495969798999a9b9c9d",
+    "9e9f000000000000000000000000000000000000000000000000c0c1c2c3c4c5",
+    "c6c7c8c9cacbcccdcecf01010000000000000060ed2ab25fdd01a1a2a3a4a5a6",
+    "a7a8000000000200080043004f00520050000100080044004300300031000400",
+    "180063006f00720070002e006500780061006d0070006c006500030022006400",
+    "6300300031002e0063006f00720070002e006500780061006d0070006c006500",
+    "0500180063006f00720070002e006500780061006d0070006c00650007000800",
+    "0060ed2ab25fdd01060004000200000009002c006c006400610070002f006400",
+    "6300300031002e0063006f00720070002e006500780061006d0070006c006500",
+    "000000000000000043004f005200500061006c00690063006500570053003000",
+    "3100e0e1e2e3e4e5e6e7e8e9eaebecedeeef"
+);
+
+/// Ethernet (14) + IPv4 (20) + TCP (20).
+const TCP_PAYLOAD_OFFSET: usize = 54;
+
+fn frame(hex_fixture: &str, expected_len: usize) -> Vec<u8> {
+    let bytes = hex::decode(hex_fixture).expect("invalid test hex fixture");
+    assert_eq!(bytes.len(), expected_len, "fixture length must match frame");
+    bytes
+}
+
+fn application_label(bytes: &[u8]) -> &'static str {
+    parse(LinkType::ETHERNET, bytes)
+        .expect("frame decodes")
+        .application
+        .expect("an application layer is detected")
+        .application_protocol
+}
+
+/// Jeton NTLMSSP du premier message LDAP de la trame.
+fn ntlmssp_token(bytes: &[u8]) -> &[u8] {
+    let packet = LdapPacket::try_from(&bytes[TCP_PAYLOAD_OFFSET..]).expect("valid LDAP payload");
+    let token = packet.messages[0].operation.ntlmssp_token();
+    token.expect("NTLMSSP token")
+}
+
+#[test]
+fn packet_flow_labels_sicily_binds_as_ldap() {
+    for (hex_fixture, expected_len) in [
+        (SICILY_NEGOTIATE_FRAME_HEX, 108),
+        (SICILY_CHALLENGE_FRAME_HEX, 269),
+        (SICILY_AUTHENTICATE_FRAME_HEX, 466),
+    ] {
+        assert_eq!(application_label(&frame(hex_fixture, expected_len)), "LDAP");
+    }
+}
+
+#[test]
+fn sicily_negotiate_and_challenge() {
+    let bytes = frame(SICILY_NEGOTIATE_FRAME_HEX, 108);
+    let packet = LdapPacket::try_from(&bytes[TCP_PAYLOAD_OFFSET..]).expect("valid LDAP payload");
+    let LdapOperation::BindRequest(bind) = &packet.messages[0].operation else {
+        panic!("bindRequest expected");
+    };
+    assert_eq!(bind.authentication.name(), "sicilyNegotiate");
+    let NtlmsspMessage::Negotiate(negotiate) =
+        NtlmsspMessage::try_from(ntlmssp_token(&bytes)).expect("valid NEGOTIATE")
+    else {
+        panic!("NEGOTIATE expected");
+    };
+    assert_eq!(negotiate.domain, None);
+    assert_eq!(negotiate.workstation, None);
+    assert_eq!(
+        negotiate
+            .version
+            .map(|version| version.to_string())
+            .as_deref(),
+        Some("10.0.17763")
+    );
+
+    let bytes = frame(SICILY_CHALLENGE_FRAME_HEX, 269);
+    let message = NtlmsspMessage::try_from(ntlmssp_token(&bytes)).expect("valid CHALLENGE");
+    assert_eq!(message.message_type_name(), "CHALLENGE");
+    assert!(message.flag_names().contains(&"TARGET_INFO"));
+    let NtlmsspMessage::Challenge(challenge) = message else {
+        panic!("CHALLENGE expected");
+    };
+    assert_eq!(challenge.target_name, "CORP");
+    assert_eq!(
+        challenge.server_challenge,
+        *b"\x11\x22\x33\x44\x55\x66\x77\x88"
+    );
+    assert_eq!(challenge.nb_domain_name().as_deref(), Some("CORP"));
+    assert_eq!(challenge.nb_computer_name().as_deref(), Some("DC01"));
+    assert_eq!(challenge.dns_domain_name().as_deref(), Some("corp.example"));
+    assert_eq!(
+        challenge.dns_computer_name().as_deref(),
+        Some("dc01.corp.example")
+    );
+    // FILETIME du 2026-10-19 10:11:12 UTC.
+    assert_eq!(challenge.timestamp(), Some(134_368_782_720_000_000));
+}
+
+#[test]
+fn sicily_ntlmv2_authenticate() {
+    let bytes = frame(SICILY_AUTHENTICATE_FRAME_HEX, 466);
+    let token = ntlmssp_token(&bytes);
+    let NtlmsspMessage::Authenticate(authenticate) =
+        NtlmsspMessage::try_from(token).expect("valid AUTHENTICATE")
+    else {
+        panic!("AUTHENTICATE expected");
+    };
+    assert_eq!(authenticate.account(), "CORP\\alice");
+    assert_eq!(authenticate.workstation, "WS01");
+    assert_eq!(authenticate.response_version(), NtlmResponseVersion::NtlmV2);
+    assert_eq!(
+        authenticate.target_name().as_deref(),
+        Some("ldap/dc01.corp.example")
+    );
+    assert!(authenticate.has_mic());
+    assert_eq!(
+        authenticate.mic(token),
+        Some((0x90..0xA0).collect::<Vec<u8>>().as_slice())
+    );
+    assert_eq!(authenticate.encrypted_session_key.len(), 16);
+}
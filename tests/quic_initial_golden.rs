@@ -0,0 +1,113 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests du dechiffrement des paquets Initial QUIC v1, relus depuis
+//! `pcaps_exemple/sll.pcap` (session Chrome vers
+//! `[2a00:1450:4006:818::200a]:443`, voir `quic_golden.rs`).
+//!
+//! Le ClientHello de 2241 octets est decoupe en une quarantaine de frames
+//! CRYPTO melangees, reparties sur les Initial client des trames 1712, 1713
+//! et 1714 : aucun paquet seul ne suffit. Valeurs attendues recoupees par
+//! une implementation independante (Python `cryptography`).
+
+#![cfg(feature = "decrypt")]
+
+use std::path::Path;
+
+use packet_parser::{
+    LinkType,
+    decrypt::quic::{QuicCryptoReassembler, QuicInitialKeys, decrypt_client_initial},
+    errors::application::quic::QuicDecryptError,
+    parse,
+    parse::application::protocols::{
+        fingerprint::Ja4Protocol, quic::QuicFrame, tls::handshake::TlsHandshake,
+    },
+};
+
+mod common;
+use common::{FileRead, read_capture};
+
+/// DCID du premier Initial client, d'ou derivent les cles des deux sens.
+const ORIGINAL_DCID: [u8; 8] = [0x8c, 0xcc, 0xe3, 0xb8, 0xd2, 0xb5, 0xd6, 0x13];
+
+/// Payload UDP des trames `numbers` (numerotation Wireshark, a partir de 1).
+fn udp_payloads(numbers: &[usize]) -> Vec<Vec<u8>> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("pcaps_exemple/sll.pcap");
+    let frames = match read_capture(&path) {
+        FileRead::Frames { frames, .. } => frames,
+        FileRead::Unsupported => panic!("{}: unreadable capture", path.display()),
+    };
+    numbers
+        .iter()
+        .map(|&number| {
+            let (link_type, data): &(LinkType, Vec<u8>) = &frames[number - 1];
+            let flow = parse(*link_type, data).expect("captured frame decodes");
+            flow.transport
+                .and_then(|transport| transport.payload)
+                .expect("UDP payload")
+                .to_vec()
+        })
+        .collect()
+}
+
+#[test]
+fn client_hello_spread_over_three_initials_is_reassembled() {
+    let mut reassembler = QuicCryptoReassembler::new();
+    for (payload, packet_number) in udp_payloads(&[1712, 1713, 1714]).iter().zip(1..) {
+        let plaintext = decrypt_client_initial(payload).expect("client Initial decrypts");
+        assert_eq!(plaintext.dcid, ORIGINAL_DCID);
+        assert_eq!(plaintext.packet_number, packet_number);
+        assert_eq!(plaintext.packet_length, payload.len());
+        reassembler.push_plaintext(&plaintext).unwrap();
+    }
+
+    assert_eq!(reassembler.contiguous().len(), 2241);
+    let hello = reassembler.client_hello().expect("complete ClientHello");
+    assert_eq!(hello.server_name(), Some("waa-pa.clients6.google.com"));
+    assert_eq!(hello.alpn_protocols(), Some(vec![&b"h3"[..]]));
+    assert_eq!(hello.supported_versions(), Some(vec![0x0304]));
+    assert_eq!(
+        hello.ja4(Ja4Protocol::Quic),
+        "q13d0313h3_55b375c5d22e_226f3f127bbe"
+    );
+}
+
+#[test]
+fn first_initial_alone_leaves_the_client_hello_incomplete() {
+    let payloads = udp_payloads(&[1712]);
+    let plaintext = decrypt_client_initial(&payloads[0]).unwrap();
+    let mut reassembler = QuicCryptoReassembler::new();
+    reassembler.push_plaintext(&plaintext).unwrap();
+
+    assert!(reassembler.client_hello().is_none());
+}
+
+/// Trame 1726 : Initial serveur portant le ServerHello en une seule frame
+/// CRYPTO, key_share X25519MLKEM768 (0x11ec).
+#[test]
+fn server_initial_decrypts_with_keys_from_the_client_dcid() {
+    let payloads = udp_payloads(&[1726]);
+    let keys = QuicInitialKeys::server(1, &ORIGINAL_DCID).unwrap();
+    let plaintext = keys.decrypt(&payloads[0]).expect("server Initial decrypts");
+    assert_eq!(plaintext.packet_number, 6);
+
+    let frames = plaintext.frames().unwrap();
+    let QuicFrame::Crypto(crypto) = &frames[0] else {
+        panic!("CRYPTO frame expected, got {:?}", frames[0]);
+    };
+    let hello = TlsHandshake::try_from(crypto.data)
+        .unwrap()
+        .server_hello()
+        .expect("ServerHello");
+    assert_eq!(hello.negotiated_version(), 0x0304);
+    assert_eq!(hello.key_share_group(), Some(0x11ec));
+
+    // Les cles client ne conviennent pas au sens serveur.
+    let client_keys = QuicInitialKeys::client(1, &ORIGINAL_DCID).unwrap();
+    assert_eq!(
+        client_keys.decrypt(&payloads[0]),
+        Err(QuicDecryptError::AuthenticationFailed)
+    );
+}
@@ -0,0 +1,209 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests du decodage type S7Comm (sections de donnees, Userdata/SZL,
+//! transferts de blocs) sur les captures de `pcaps_exemple/protocols/s7comm`.
+//! Les numeros de trame et valeurs attendues ont ete recoupes avec Wireshark
+//! (tshark -O s7comm).
+
+use std::path::Path;
+
+use packet_parser::{
+    LinkType, parse,
+    parse::application::protocols::s7comm::{
+        S7CommPacket,
+        message::S7Message,
+        var::{S7VarAccessKind, S7VarAccessTracker},
+    },
+};
+
+mod common;
+use common::{FileRead, read_capture};
+
+fn capture(name: &str) -> Vec<(LinkType, Vec<u8>)> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("pcaps_exemple/protocols/s7comm")
+        .join(name);
+    match read_capture(&path) {
+        FileRead::Frames { frames, .. } => frames,
+        FileRead::Unsupported => panic!("{}: unreadable capture", path.display()),
+    }
+}
+
+/// Payload TCP de chaque trame S7Comm, avec son numero Wireshark.
+fn s7_payloads(name: &str) -> Vec<(usize, Vec<u8>)> {
+    capture(name)
+        .iter()
+        .enumerate()
+        .filter_map(|(index, (link_type, data))| {
+            let flow = parse(*link_type, data).ok()?;
+            let payload = flow.transport?.payload?;
+            S7CommPacket::try_from(payload).ok()?;
+            Some((index + 1, payload.to_vec()))
+        })
+        .collect()
+}
+
+fn frame(payloads: &[(usize, Vec<u8>)], number: usize) -> S7CommPacket<'_> {
+    let (_, payload) = payloads
+        .iter()
+        .find(|(frame, _)| *frame == number)
+        .unwrap_or_else(|| panic!("frame {number} is not S7Comm"));
+    S7CommPacket::try_from(payload.as_slice()).expect("S7Comm frame")
+}
+
+#[test]
+fn every_repository_s7comm_pdu_decodes() {
+    for (name, expected) in [
+        ("s7comm_downloading_block_db1.pcap", 48),
+        ("s7comm_program_blocklist_onlineview.pcap", 70),
+        ("s7comm_reading_plc_status.pcap", 144),
+        ("s7comm_reading_setting_plc_time.pcap", 26),
+        ("s7comm_varservice_libnodavedemo.pcap", 16),
+        ("s7comm_varservice_libnodavedemo_bench.pcap", 10_006),
+    ] {
+        let payloads = s7_payloads(name);
+        assert_eq!(payloads.len(), expected, "{name}");
+        for (number, payload) in &payloads {
+            let packet = S7CommPacket::try_from(payload.as_slice()).unwrap();
+            if let Err(error) = packet.message() {
+                panic!("{name} frame {number}: {error}");
+            }
+        }
+    }
+}
+
+#[test]
+fn bench_read_and_write_vars_pair_with_their_results() {
+    let payloads = s7_payloads("s7comm_varservice_libnodavedemo_bench.pcap");
+    let mut tracker = S7VarAccessTracker::new();
+    let mut reads = 0;
+    let mut writes = Vec::new();
+    for (_, payload) in &payloads {
+        let packet = S7CommPacket::try_from(payload.as_slice()).unwrap();
+        tracker.push(&packet).expect("decodable Read/Write Var");
+        while let Some(access) = tracker.next_access() {
+            assert!(access.is_success(), "PDU {}", access.pduref);
+            match access.kind {
+                S7VarAccessKind::Read => reads += 1,
+                S7VarAccessKind::Write => writes.push(access),
+                _ => unreachable!(),
+            }
+        }
+    }
+
+    // 3000 lectures et 2002 ecritures, toutes en zone M et toutes
+    // acceptees : 999 "M 0.0 BYTE 1", 999 "M 0.0 BYTE 212" et quatre
+    // ecritures de 4 octets en M 0, 4, 8 et 12.
+    assert_eq!(reads, 3000);
+    assert_eq!(writes.len(), 2002);
+    let mut single_bytes = 0;
+    for write in &writes {
+        let item = &write.items[0];
+        let address = item.address.expect("S7ANY address");
+        assert_eq!(address.area, 0x83);
+        assert_eq!(item.value.len(), usize::from(address.count));
+        if address.count == 1 {
+            single_bytes += 1;
+        }
+    }
+    assert_eq!(single_bytes, 999);
+}
+
+#[test]
+fn szl_identification_records_decode() {
+    let payloads = s7_payloads("s7comm_reading_plc_status.pcap");
+
+    // Trame 58 : requete SZL 0x0011 index 0.
+    let S7Message::Userdata(request) = frame(&payloads, 58).message().unwrap() else {
+        panic!("Userdata expected");
+    };
+    let request = request.szl_request().expect("Read SZL request");
+    assert_eq!((request.szl_id, request.index), (0x0011, 0x0000));
+
+    // Trame 59 : identification du module, quatre enregistrements.
+    let S7Message::Userdata(response) = frame(&payloads, 59).message().unwrap() else {
+        panic!("Userdata expected");
+    };
+    let szl = response.szl_response().unwrap().expect("SZL response");
+    assert_eq!(
+        (szl.szl_id, szl.record_length, szl.record_count),
+        (0x0011, 28, 4)
+    );
+    let modules: Vec<_> = szl.module_identifications().collect();
+    assert_eq!(modules.len(), 4);
+    assert_eq!(modules[0].index, 1);
+    assert_eq!(modules[0].order_number(), Some("6ES7 151-8AB01-0AB0"));
+    assert_eq!(modules[0].module_type, 0x00c0);
+    assert_eq!(modules[1].index, 6);
+    assert_eq!(modules[2].index, 7);
+    assert_eq!(modules[2].order_number(), Some(""));
+
+    // Trame 69 : premier fragment de SZL 0x001C (dix enregistrements
+    // annonces, six complets dans ce PDU).
+    let S7Message::Userdata(response) = frame(&payloads, 69).message().unwrap() else {
+        panic!("Userdata expected");
+    };
+    assert_eq!(response.parameter.last_data_unit, Some(false));
+    let szl = response.szl_response().unwrap().expect("SZL response");
+    assert_eq!(szl.record_count, 10);
+    let names: Vec<_> = szl
+        .component_identifications()
+        .map(|component| (component.index, component.name()))
+        .collect();
+    assert_eq!(
+        names,
+        [
+            (1, Some("IM151-8-CPU")),
+            (2, Some("IM151-8 PN/DP CPU")),
+            (3, Some("")),
+            (4, Some("Original Siemens Equipment")),
+            (5, Some("S C-C6TW74882012")),
+            (7, Some("IM151-8 PN/DP CPU")),
+        ]
+    );
+}
+
+#[test]
+fn db1_download_sequence_decodes() {
+    let payloads = s7_payloads("s7comm_downloading_block_db1.pcap");
+
+    // Trame 43 : Request download de DB1.
+    let S7Message::BlockControl(request) = frame(&payloads, 43).message().unwrap() else {
+        panic!("Request download expected");
+    };
+    assert_eq!(request.function, 0x1a);
+    assert_eq!(request.filename, Some("_0A00001P"));
+    assert_eq!(request.block().unwrap().to_string(), "DB 1");
+    assert_eq!(request.load_memory_length, Some(500));
+    assert_eq!(request.mc7_code_length, Some(400));
+
+    // Trames 48 et 54 : blocs de 222 puis 56 octets, le premier annoncant
+    // une suite.
+    let S7Message::BlockControl(first) = frame(&payloads, 48).message().unwrap() else {
+        panic!("Download block expected");
+    };
+    assert_eq!(first.function, 0x1b);
+    assert!(first.more_data_following());
+    assert_eq!(first.data.map(<[u8]>::len), Some(222));
+    let S7Message::BlockControl(last) = frame(&payloads, 54).message().unwrap() else {
+        panic!("Download block expected");
+    };
+    assert!(!last.more_data_following());
+    assert_eq!(last.data.map(<[u8]>::len), Some(56));
+
+    // Trame 55 : Download ended ; trame 58 : activation par PLC Control.
+    let S7Message::BlockControl(ended) = frame(&payloads, 55).message().unwrap() else {
+        panic!("Download ended expected");
+    };
+    assert_eq!(ended.function, 0x1c);
+    assert_eq!(ended.block().unwrap().number, 1);
+    let S7Message::PlcControl(control) = frame(&payloads, 58).message().unwrap() else {
+        panic!("PLC Control expected");
+    };
+    assert_eq!(control.pi_service, "_INSE");
+    let blocks: Vec<_> = control.blocks().map(|block| block.to_string()).collect();
+    assert_eq!(blocks, ["DB 1"]);
+}
@@ -0,0 +1,268 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests SMB : detection SMB1 / SMB2 sur TCP 445 et decodage des
+//! commandes NEGOTIATE, SESSION_SETUP, TREE_CONNECT, CREATE, READ et WRITE.
+//!
+//! Les deux premieres trames sont reelles (trames 39979 et 40012 de
+//! `pcaps_exemple/The-Ultimate-PCAP.pcapng`, deja utilisees par les golden
+//! tests NetBIOS). Le corpus ne contient pas d'autre echange SMB2 : les
+//! suivantes sont synthetiques, construites d'apres MS-SMB2 et RFC 4178
+//! (checksums IPv4/TCP corrects). Client 192.168.40.10, serveur
+//! 192.168.40.2, SMB direct hosting sur TCP 445.
+
+use packet_parser::parse::application::protocols::smb::{
+    SmbPacket,
+    command::{Smb2Body, Smb2FileId},
+    security::{SmbAuthMechanism, SmbSecurityBlob},
+};
+use packet_parser::{LinkType, parse};
+
+/// Trame 39979 : Negotiate Protocol Request SMB1 (client 49958 -> 445,
+/// Ethernet + 802.1Q + IPv6).
+const SMB1_NEGOTIATE_FRAME_HEX: &str = concat!(
+    "3cfa30031230000c29c37feb8100005186dd600b0725005d06402a006020ad0b",
+    "8381fd70e2f9f0319dc42a006020ad0b83800000000000000010c32601bd7977",
+    "37d8f1717bb750180405ad42000000000045ff534d4272000000001853c80000",
+    "00000000000000000000fffffffe00000000002200024e54204c4d20302e3132",
+    "0002534d4220322e3030320002534d4220322e3f3f3f00"
+);
+
+/// Trame 40012 : Session Setup Response SMB2 finale d'une authentification
+/// NTLM (445 -> client 49960).
+const SMB2_SESSION_SETUP_FRAME_HEX: &str = concat!(
+    "3cfa30031230000c29a9e4e38100005086dd6000eaa7007d06402a006020ad0b",
+    "838000000000000000102a006020ad0b8381fd70e2f9f0319dc401bdc3283d0c",
+    "2110db9f3724501820003b8b000000000065fe534d4240000100000000000100",
+    "210011000000000000000200000000000000fffe000000000000250000500020",
+    "0000000000000000000000000000000000000900000048001d00a11b3019a003",
+    "0a0100a312041001000000ca1d85ba1649942500000000"
+);
+
+/// NEGOTIATE Request offrant SMB 2.0.2 a 3.1.1, avec contextes
+/// PREAUTH_INTEGRITY (SHA-512) et ENCRYPTION (AES-128-GCM, AES-128-CCM).
+const NEGOTIATE_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e0001020800450000da01004000400667c1c0a8280ac0a8",
+    "2802c3c001bd000003e8000007d050182000146a0000000000aefe534d424000",
+    "0100000000000000010000000000000000000000000000000000fffe00000000",
+    "0000000000000000000000000000000000000000000000000000240005000100",
+    "00007f000000101112131415161718191a1b1c1d1e1f70000000020000000202",
+    "100200030203110300000100260000000000010020000100a0a1a2a3a4a5a6a7",
+    "a8a9aaabacadaeafb0b1b2b3b4b5b6b7b8b9babbbcbdbebf0000020006000000",
+    "0000020002000100"
+);
+
+/// SESSION_SETUP Request : NegTokenInit SPNEGO offrant MS-KRB5, KRB5 et
+/// NTLMSSP, mechToken Kerberos AP-REQ.
+const SESSION_SETUP_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e0001020800450000d901014000400667c1c0a8280ac0a8",
+    "2802c3c001bd00000514000008fc501820000aa50000000000adfe534d424000",
+    "0100000000000100010000000000000000000100000000000000fffe00000000",
+    "0000000000000000000000000000000000000000000000000000190000010100",
+    "000000000000580055000000000000000000605306062b0601050502a0493047",
+    "a024302206092a864882f71201020206092a864886f712010202060a2b060104",
+    "01823702020aa21f041d601b06092a864886f71201020201006e0c300aa00302",
+    "0105a10302010e"
+);
+
+/// TREE_CONNECT Request vers `\\fs01\finance`.
+const TREE_CONNECT_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e0001020800450000900102400040066809c0a8280ac0a8",
+    "2802c3c001bd000005dc000009c450182000b5da000000000064fe534d424000",
+    "0100000000000300010000000000000000000200000000000000fffe00000000",
+    "0000110000000010000000000000000000000000000000000000090000004800",
+    "1c005c005c0066007300300031005c00660069006e0061006e0063006500"
+);
+
+/// CREATE Request ouvrant `reports\q3.csv` en lecture (FILE_OPEN).
+const CREATE_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e0001020800450000c001034000400667d8c0a8280ac0a8",
+    "2802c3c001bd0000064000000a285018200006b1000000000094fe534d424000",
+    "0100000000000500010000000000000000000300000000000000fffe00000500",
+    "0000110000000010000000000000000000000000000000000000390000000200",
+    "0000000000000000000000000000000000008900120000000000010000000100",
+    "00004000000078001c0000000000000000007200650070006f00720074007300",
+    "5c00710033002e00630073007600"
+);
+
+/// WRITE Request de 12 octets a l'offset 0 du fichier ouvert.
+const WRITE_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e0001020800450000a801044000400667efc0a8280ac0a8",
+    "2802c3c001bd000006a400000a8c501820004e2500000000007cfe534d424000",
+    "0100000000000900010000000000000000000400000000000000fffe00000500",
+    "0000110000000010000000000000000000000000000000000000310070000c00",
+    "0000000000000000000011000000000000002200000000000000000000000000",
+    "0000000000000000000051333b726576656e75653b0a"
+);
+
+/// READ Response renvoyant les 12 memes octets.
+const READ_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a01080045000088020540004006670ec0a82802c0a8",
+    "280a01bdc3c000000a8c0000070850182000c00100000000005cfe534d424000",
+    "0100000000000800010001000000000000000500000000000000fffe00000500",
+    "0000110000000010000000000000000000000000000000000000110050000c00",
+    "0000000000000000000051333b726576656e75653b0a"
+);
+
+/// Ethernet (14) + IPv4 (20) + TCP (20).
+const TCP_PAYLOAD_OFFSET: usize = 54;
+
+fn frame(hex_fixture: &str, expected_len: usize) -> Vec<u8> {
+    let bytes = hex::decode(hex_fixture).expect("invalid test hex fixture");
+    assert_eq!(bytes.len(), expected_len, "fixture length must match frame");
+    bytes
+}
+
+fn application_label(bytes: &[u8]) -> &'static str {
+    parse(LinkType::ETHERNET, bytes)
+        .expect("frame decodes")
+        .application
+        .expect("an application layer is detected")
+        .application_protocol
+}
+
+/// Corps de l'unique message SMB2 d'une trame synthetique.
+fn body(bytes: &[u8]) -> Smb2Body<'_> {
+    let packet = SmbPacket::from_nbss(&bytes[TCP_PAYLOAD_OFFSET..]).expect("valid SMB2 packet");
+    let [message] = packet.smb2_messages() else {
+        panic!("a single SMB2 message expected");
+    };
+    message.body().expect("valid SMB2 body")
+}
+
+#[test]
+fn packet_flow_labels_smb1_and_smb2() {
+    // SMB1 garde son propre label : son usage merite une alerte.
+    assert_eq!(
+        application_label(&frame(SMB1_NEGOTIATE_FRAME_HEX, 151)),
+        "SMB"
+    );
+    assert_eq!(
+        application_label(&frame(SMB2_SESSION_SETUP_FRAME_HEX, 183)),
+        "SMB2"
+    );
+    for (hex_fixture, expected_len) in [
+        (NEGOTIATE_FRAME_HEX, 232),
+        (SESSION_SETUP_FRAME_HEX, 231),
+        (TREE_CONNECT_FRAME_HEX, 158),
+        (CREATE_FRAME_HEX, 206),
+        (WRITE_FRAME_HEX, 182),
+        (READ_FRAME_HEX, 150),
+    ] {
+        assert_eq!(application_label(&frame(hex_fixture, expected_len)), "SMB2");
+    }
+}
+
+#[test]
+fn captured_smb1_negotiate_and_ntlm_session_setup() {
+    let bytes = frame(SMB1_NEGOTIATE_FRAME_HEX, 151);
+    let flow = parse(LinkType::ETHERNET, bytes.as_slice()).expect("captured frame decodes");
+    let payload = flow.transport.and_then(|transport| transport.payload);
+    let packet = SmbPacket::from_nbss(payload.expect("TCP payload")).expect("SMB1 message");
+    let SmbPacket::Smb1(message) = packet else {
+        panic!("SMB1 message expected");
+    };
+    assert_eq!(
+        message.negotiate_dialects(),
+        Some(vec!["NT LM 0.12", "SMB 2.002", "SMB 2.???"])
+    );
+    assert!(message.offers_smb2());
+
+    let bytes = frame(SMB2_SESSION_SETUP_FRAME_HEX, 183);
+    let flow = parse(LinkType::ETHERNET, bytes.as_slice()).expect("captured frame decodes");
+    let payload = flow.transport.and_then(|transport| transport.payload);
+    let packet = SmbPacket::from_nbss(payload.expect("TCP payload")).expect("SMB2 message");
+    let [message] = packet.smb2_messages() else {
+        panic!("a single SMB2 message expected");
+    };
+    assert_eq!(message.header.session_id, 0x0000_2000_5000_0025);
+    assert_eq!(message.header.status_name(), "STATUS_SUCCESS");
+    let body = message.body().expect("SESSION_SETUP Response");
+    assert!(matches!(
+        body,
+        Smb2Body::SessionSetupResponse {
+            session_flags: 0,
+            ..
+        }
+    ));
+    let blob = SmbSecurityBlob::parse(body.security_buffer().expect("security buffer"))
+        .expect("NegTokenResp");
+    assert_eq!(blob.negotiation_state_name(), Some("accept-completed"));
+    assert!(blob.mech_list_mic.is_some());
+}
+
+#[test]
+fn negotiate_and_kerberos_session_setup() {
+    let bytes = frame(NEGOTIATE_FRAME_HEX, 232);
+    let negotiate = body(&bytes);
+    assert_eq!(
+        negotiate.dialect_names(),
+        ["SMB 2.0.2", "SMB 2.1", "SMB 3.0", "SMB 3.0.2", "SMB 3.1.1"]
+    );
+    assert!(negotiate.capability_names().contains(&"ENCRYPTION"));
+    let Smb2Body::NegotiateRequest {
+        negotiate_contexts, ..
+    } = &negotiate
+    else {
+        panic!("NEGOTIATE Request expected");
+    };
+    let names: Vec<_> = negotiate_contexts
+        .iter()
+        .map(|context| context.name())
+        .collect();
+    assert_eq!(
+        names,
+        ["PREAUTH_INTEGRITY_CAPABILITIES", "ENCRYPTION_CAPABILITIES"]
+    );
+
+    let bytes = frame(SESSION_SETUP_FRAME_HEX, 231);
+    let setup = body(&bytes);
+    let blob = SmbSecurityBlob::parse(setup.security_buffer().expect("security buffer"))
+        .expect("NegTokenInit");
+    assert!(blob.spnego);
+    assert_eq!(
+        blob.offered_mechanisms,
+        [
+            SmbAuthMechanism::Kerberos,
+            SmbAuthMechanism::Kerberos,
+            SmbAuthMechanism::Ntlmssp
+        ]
+    );
+    assert_eq!(blob.mechanism(), Some(SmbAuthMechanism::Kerberos));
+}
+
+#[test]
+fn share_file_and_transfer_sizes() {
+    let bytes = frame(TREE_CONNECT_FRAME_HEX, 158);
+    let tree = body(&bytes);
+    assert!(
+        matches!(&tree, Smb2Body::TreeConnectRequest { path, .. } if path == r"\\fs01\finance")
+    );
+    assert_eq!(tree.share_name(), Some("finance"));
+
+    let bytes = frame(CREATE_FRAME_HEX, 206);
+    let create = body(&bytes);
+    assert!(matches!(&create, Smb2Body::CreateRequest { name, .. } if name == r"reports\q3.csv"));
+    assert_eq!(create.create_disposition_name(), Some("OPEN"));
+
+    let file_id = Smb2FileId {
+        persistent: 0x11,
+        volatile: 0x22,
+    };
+    let bytes = frame(WRITE_FRAME_HEX, 182);
+    assert_eq!(
+        body(&bytes),
+        Smb2Body::WriteRequest {
+            offset: 0,
+            file_id,
+            data: b"Q3;revenue;\n",
+        }
+    );
+    let bytes = frame(READ_FRAME_HEX, 150);
+    let Smb2Body::ReadResponse { data, .. } = body(&bytes) else {
+        panic!("READ Response expected");
+    };
+    assert_eq!(data.len(), 12);
+}
@@ -0,0 +1,169 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests SNMP : nommage des OID, traps v1/v2c et parametres USM sur
+//! trames Ethernet completes.
+//!
+//! Aucune capture SNMP n'est versionnee dans le depot : les trames sont
+//! synthetiques, construites d'apres RFC 1157, RFC 3416 et RFC 3414
+//! (checksums IPv4/UDP corrects). Station de supervision 192.168.30.5,
+//! agent 192.168.30.20 (moteur net-snmp au format MAC).
+
+use packet_parser::parse::application::protocols::snmp::{
+    SnmpMessage, SnmpPacket, SnmpPdu, SnmpPduType, SnmpV3Data, oid::SnmpOidRegistry,
+};
+use packet_parser::{LinkType, parse};
+
+/// Agent -> supervision : trap v1 linkDown (enterprise Siemens
+/// 1.3.6.1.4.1.4329.6), ifIndex.3 = 3.
+const TRAP_V1_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a01080045000059060040004011772ac0a81e14c0a8",
+    "1e05040000a2004592b8303b02010004067075626c6963a42e06082b06010401",
+    "a169064004c0a81e14020102020100430301e2403011300f060a2b0601020102",
+    "02010103020103"
+);
+
+/// Agent -> supervision : SNMPv2-Trap coldStart, sysName.0 = plc-hall2.
+const TRAP_V2_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a01080045000076060140004011770cc0a81e14c0a8",
+    "1e05040100a200624f2c305802010104067075626c6963a74b02014d02010002",
+    "01003040300e06082b06010201010300430210683017060a2b06010603010104",
+    "010006092b0601060301010501301506082b060102010105000409706c632d68",
+    "616c6c32"
+);
+
+/// Supervision -> agent : GetRequest SNMPv3 de decouverte (noAuthNoPriv,
+/// USM vide).
+const DISCOVERY_FRAME_HEX: &str = concat!(
+    "0050c2000a0100005e00010208004500005a0700400040117629c0a81e05c0a8",
+    "1e14c3f100a10046b1b3303c0201033011020400001001020300ffe304010402",
+    "01030410300e0400020100020100040004000400301204000400a00c02022001",
+    "0201000201003000"
+);
+
+/// Agent -> supervision : Report usmStatsUnknownEngineIDs.0 portant
+/// l'engine id de l'agent, boots 7, time 86400.
+const REPORT_FRAME_HEX: &str = concat!(
+    "00005e0001020050c2000a0108004500008307014000401175ffc0a81e14c0a8",
+    "1e0500a1c3f1006f15c230650201033011020400001001020300ffe304010002",
+    "0103041d301b040b80001f88030050c2001e1402010702030151800400040004",
+    "00302e040b80001f88030050c2001e140400a81d020220010201000201003011",
+    "300f060a2b060106030f01010400410101"
+);
+
+/// Ethernet (14) + IPv4 (20) + UDP (8).
+const UDP_PAYLOAD_OFFSET: usize = 42;
+
+fn frame(hex_fixture: &str, expected_len: usize) -> Vec<u8> {
+    let bytes = hex::decode(hex_fixture).expect("invalid test hex fixture");
+    assert_eq!(bytes.len(), expected_len, "fixture length must match frame");
+    bytes
+}
+
+fn application_label(bytes: &[u8]) -> &'static str {
+    parse(LinkType::ETHERNET, bytes)
+        .expect("synthetic frame decodes")
+        .application
+        .expect("an application layer is detected")
+        .application_protocol
+}
+
+fn snmp(bytes: &[u8]) -> SnmpPacket<'_> {
+    SnmpPacket::try_from(&bytes[UDP_PAYLOAD_OFFSET..]).expect("valid SNMP message")
+}
+
+fn community_pdu<'a>(packet: &'a SnmpPacket<'a>) -> &'a SnmpPdu<'a> {
+    let SnmpMessage::V1V2c(message) = &packet.message else {
+        panic!("v1/v2c message expected");
+    };
+    &message.pdu
+}
+
+#[test]
+fn packet_flow_labels_snmp() {
+    for (hex_fixture, len) in [
+        (TRAP_V1_FRAME_HEX, 103),
+        (TRAP_V2_FRAME_HEX, 132),
+        (DISCOVERY_FRAME_HEX, 104),
+        (REPORT_FRAME_HEX, 145),
+    ] {
+        assert_eq!(application_label(&frame(hex_fixture, len)), "SNMP");
+    }
+}
+
+#[test]
+fn v1_and_v2_traps_share_one_notification_view() {
+    let registry = SnmpOidRegistry::builtin();
+
+    let bytes = frame(TRAP_V1_FRAME_HEX, 103);
+    let packet = snmp(&bytes);
+    let pdu = community_pdu(&packet);
+    let trap = pdu.trap().expect("Trap-PDU");
+    assert_eq!(registry.format(&trap.trap_oid), "linkDown");
+    assert_eq!(trap.uptime, Some(123_456));
+    let v1 = trap.v1.expect("v1 fields");
+    assert_eq!(v1.agent_address, [192, 168, 30, 20]);
+    assert_eq!(v1.enterprise.to_string(), "1.3.6.1.4.1.4329.6");
+    assert_eq!(
+        registry.format_oid(pdu.variable_bindings()[0].object_identifier()),
+        "ifIndex.3"
+    );
+
+    let bytes = frame(TRAP_V2_FRAME_HEX, 132);
+    let packet = snmp(&bytes);
+    let pdu = community_pdu(&packet);
+    assert_eq!(pdu.pdu_type, SnmpPduType::TrapV2);
+    let trap = pdu.trap().expect("SNMPv2-Trap");
+    assert_eq!(registry.format(&trap.trap_oid), "coldStart");
+    assert_eq!(trap.uptime, Some(4200));
+    let names: Vec<_> = pdu
+        .variable_bindings()
+        .iter()
+        .map(|binding| registry.format_oid(binding.object_identifier()))
+        .collect();
+    assert_eq!(names, ["sysUpTime.0", "snmpTrapOID.0", "sysName.0"]);
+}
+
+#[test]
+fn usm_discovery_exchange() {
+    let bytes = frame(DISCOVERY_FRAME_HEX, 104);
+    let packet = snmp(&bytes);
+    let SnmpMessage::V3(request) = &packet.message else {
+        panic!("v3 message expected");
+    };
+    assert!(request.is_reportable() && !request.is_authenticated());
+    let usm = request.usm().expect("valid USM").expect("USM model");
+    assert!(usm.is_discovery());
+
+    let bytes = frame(REPORT_FRAME_HEX, 145);
+    let packet = snmp(&bytes);
+    let SnmpMessage::V3(report) = &packet.message else {
+        panic!("v3 message expected");
+    };
+    let usm = report.usm().expect("valid USM").expect("USM model");
+    assert_eq!(
+        (
+            usm.authoritative_engine_boots,
+            usm.authoritative_engine_time
+        ),
+        (7, 86_400)
+    );
+    let engine = usm.engine_id().expect("engine id");
+    assert_eq!(engine.enterprise, 8072);
+    assert_eq!(
+        engine.mac_address(),
+        Some([0x00, 0x50, 0xc2, 0x00, 0x1e, 0x14])
+    );
+
+    let SnmpV3Data::ScopedPdu(scoped) = &report.data else {
+        panic!("plaintext scoped PDU expected");
+    };
+    assert_eq!(scoped.pdu.pdu_type, SnmpPduType::Report);
+    assert_eq!(
+        SnmpOidRegistry::builtin()
+            .format_oid(scoped.pdu.variable_bindings()[0].object_identifier()),
+        "usmStatsUnknownEngineIDs.0"
+    );
+}
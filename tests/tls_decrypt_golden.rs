@@ -0,0 +1,128 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden test du dechiffrement TLS 1.2 sur
+//! `pcaps_exemple/protocols/tls/tls12-dsb.pcapng` : deux sessions curl en
+//! TLS_ECDHE_RSA_WITH_AES_128_GCM_SHA256 (trames 1 a 9 vers example.com,
+//! 10 a 17 vers example.net). Les secrets sont ceux du bloc Decryption
+//! Secrets de la capture ; les textes clairs attendus ont ete recoupes avec
+//! Python `cryptography`.
+
+#![cfg(feature = "decrypt")]
+
+use std::path::Path;
+
+use packet_parser::{
+    LinkType,
+    decrypt::tls::{TlsConnection, TlsDirection, TlsKeyLog, TlsPlaintextRecord},
+    parse,
+    parse::application::protocols::tls::TlsContentType,
+};
+
+mod common;
+use common::{FileRead, read_capture};
+
+const KEY_LOG: &str = "\
+CLIENT_RANDOM f67a28b386b31c620d76c0026fdd9888edbe6bf0f5b715b2caca158f84ae9d66 cc38e78182b9dfd74ef3103d79bbc99cfc9b4dad209ed209062b5481e63353128da7571b13cfd4d3a5ae7d0520fb346d
+CLIENT_RANDOM 1e0d63b41d7c7bb639559cfc9f06ffd5c65fe4a9df31abc5af833b0d834436f4 c7f5dda54fb417181cb26e52112afaf9e1756addd77d3c479d96a609c0d3c9bb9929c8475cafb4dbad8f72e868a43e02
+";
+
+fn capture(name: &str) -> Vec<(LinkType, Vec<u8>)> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("pcaps_exemple/protocols/tls")
+        .join(name);
+    match read_capture(&path) {
+        FileRead::Frames { frames, .. } => frames,
+        FileRead::Unsupported => panic!("{}: unreadable capture", path.display()),
+    }
+}
+
+/// Rejoue les trames `range` (numerotation Wireshark) dans une connexion et
+/// rend chaque enregistrement dechiffre avec le protocole classe par la
+/// table de dispatch.
+fn decrypt_session(
+    frames: &[(LinkType, Vec<u8>)],
+    range: std::ops::RangeInclusive<usize>,
+    key_log: &TlsKeyLog,
+) -> Vec<(TlsPlaintextRecord, Option<&'static str>)> {
+    let mut connection = TlsConnection::new(key_log);
+    let mut records = Vec::new();
+    for number in range {
+        let (link_type, data) = &frames[number - 1];
+        let flow = parse(*link_type, data).expect("captured frame decodes");
+        let transport = flow.transport.expect("TCP segment");
+        let Some(payload) = transport.payload else {
+            continue;
+        };
+        if transport.destination_port == Some(443) {
+            connection
+                .push_client(payload)
+                .expect("client records decrypt");
+        } else {
+            connection
+                .push_server(payload)
+                .expect("server records decrypt");
+        }
+        while let Some(record) = connection.next_record() {
+            let protocol = record
+                .application(&transport)
+                .map(|application| application.application_protocol);
+            records.push((record, protocol));
+        }
+    }
+    records
+}
+
+#[test]
+fn curl_sessions_decrypt_to_http() {
+    let frames = capture("tls12-dsb.pcapng");
+    let key_log = TlsKeyLog::parse(KEY_LOG).expect("key log");
+    assert_eq!(key_log.len(), 2);
+
+    for (range, host) in [(1..=9, "example.com"), (10..=17, "example.net")] {
+        let records = decrypt_session(&frames, range, &key_log);
+        let summary: Vec<_> = records
+            .iter()
+            .map(|(record, protocol)| {
+                (
+                    record.direction,
+                    record.content_type,
+                    record.data.len(),
+                    *protocol,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                (TlsDirection::Client, TlsContentType::Handshake, 16, None),
+                (TlsDirection::Server, TlsContentType::Handshake, 16, None),
+                (
+                    TlsDirection::Client,
+                    TlsContentType::ApplicationData,
+                    76,
+                    Some("HTTP")
+                ),
+                (
+                    TlsDirection::Server,
+                    TlsContentType::ApplicationData,
+                    338,
+                    Some("HTTP")
+                ),
+                (TlsDirection::Client, TlsContentType::Alert, 2, None),
+                (TlsDirection::Server, TlsContentType::Alert, 2, None),
+            ],
+            "{host}"
+        );
+
+        let request = String::from_utf8_lossy(&records[2].0.data);
+        assert!(request.starts_with("HEAD / HTTP/1.1\r\n"));
+        assert!(request.contains(&format!("Host: {host}\r\n")));
+        assert!(records[3].0.data.starts_with(b"HTTP/1.1 200 OK\r\n"));
+        // close_notify des deux cotes.
+        assert_eq!(records[4].0.data, [0x01, 0x00]);
+        assert_eq!(records[5].0.data, [0x01, 0x00]);
+    }
+}
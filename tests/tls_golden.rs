@@ -0,0 +1,232 @@
+// Copyright (c) 2026 Cyprien Avico avicocyprien@yahoo.com
+//
+// Licensed under the MIT License <LICENSE-MIT or http://opensource.org/licenses/MIT>.
+// This file may not be copied, modified, or distributed except according to those terms.
+
+//! Golden tests de la couche handshake TLS, relus depuis les captures de
+//! `pcaps_exemple/protocols/tls/`. Les valeurs attendues (SNI, ALPN,
+//! certificats) ont ete recoupees avec `openssl x509 -inform der` sur les
+//! certificats extraits des memes trames.
+//!
+//! - `tls12-dsb.pcapng` (raw IPv4, session vers example.com) : ClientHello en
+//!   trame 1 ; ServerHello, Certificate, ServerKeyExchange et ServerHelloDone
+//!   etales sur les segments TCP des trames 2 et 3, le Certificate (chaine
+//!   DigiCert a deux maillons, SAN) chevauchant les deux segments.
+//! - `tls1.2.pcapng` (Ethernet, localhost) : ServerHello, Certificate v1
+//!   auto-signe, ServerKeyExchange et ServerHelloDone dans la seule trame 6.
+//! - `tls1.3.pcapng` : ServerHello TLS 1.3 de la trame 6.
+
+use std::path::Path;
+
+use chrono::{TimeZone, Utc};
+use packet_parser::{
+    LinkType, parse,
+    parse::application::protocols::fingerprint::Ja4Protocol,
+    parse::application::protocols::tls::{
+        handshake::{TlsHandshake, TlsHandshakeReassembler, TlsHandshakeType},
+        parse_tls_records,
+        x509::X509GeneralName,
+    },
+};
+
+mod common;
+use common::{FileRead, read_capture};
+
+fn capture(name: &str) -> Vec<(LinkType, Vec<u8>)> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("pcaps_exemple/protocols/tls")
+        .join(name);
+    match read_capture(&path) {
+        FileRead::Frames { frames, .. } => frames,
+        FileRead::Unsupported => panic!("{}: unreadable capture", path.display()),
+    }
+}
+
+/// Payload TCP de la trame `number` (numerotation Wireshark, a partir de 1).
+fn tcp_payload(frames: &[(LinkType, Vec<u8>)], number: usize) -> Vec<u8> {
+    let (link_type, data) = &frames[number - 1];
+    let flow = parse(*link_type, data).expect("captured frame decodes");
+    flow.transport
+        .and_then(|transport| transport.payload)
+        .expect("TCP payload")
+        .to_vec()
+}
+
+/// Reassemble les messages handshake d'un flux TCP deja remis en ordre.
+fn handshake_messages(stream: &[u8]) -> Vec<Vec<u8>> {
+    let mut reassembler = TlsHandshakeReassembler::new();
+    let mut messages = Vec::new();
+    for record in parse_tls_records(stream) {
+        reassembler.push_record(&record).expect("bounded message");
+        while let Some(message) = reassembler.next_message().expect("bounded message") {
+            messages.push(message);
+        }
+    }
+    assert_eq!(reassembler.pending(), 0, "no message left incomplete");
+    messages
+}
+
+#[test]
+fn client_hello_reports_sni_and_alpn() {
+    let frames = capture("tls12-dsb.pcapng");
+    let payload = tcp_payload(&frames, 1);
+    let records = parse_tls_records(&payload);
+    let messages = records[0].handshake_messages();
+
+    let hello = messages[0].client_hello().expect("ClientHello");
+    assert_eq!(hello.server_name(), Some("example.com"));
+    assert_eq!(hello.alpn_protocols(), Some(vec![&b"http/1.1"[..]]));
+    assert_eq!(hello.cipher_suites.len(), 28);
+    assert_eq!(hello.supported_versions(), None);
+    assert!(!hello.has_grease());
+}
+
+#[test]
+fn certificate_chain_spanning_two_segments_decodes_to_x509() {
+    let frames = capture("tls12-dsb.pcapng");
+    let mut stream = tcp_payload(&frames, 2);
+    stream.extend_from_slice(&tcp_payload(&frames, 3));
+
+    let messages = handshake_messages(&stream);
+    let types: Vec<TlsHandshakeType> = messages
+        .iter()
+        .map(|bytes| {
+            TlsHandshake::try_from(bytes.as_slice())
+                .unwrap()
+                .handshake_type
+        })
+        .collect();
+    assert_eq!(
+        types,
+        vec![
+            TlsHandshakeType::ServerHello,
+            TlsHandshakeType::Certificate,
+            TlsHandshakeType::ServerKeyExchange,
+            TlsHandshakeType::ServerHelloDone,
+        ]
+    );
+
+    let server_hello = TlsHandshake::try_from(messages[0].as_slice())
+        .unwrap()
+        .server_hello()
+        .unwrap();
+    assert_eq!(server_hello.negotiated_version(), 0x0303);
+    assert!(!server_hello.is_hello_retry_request());
+    assert_eq!(server_hello.selected_alpn(), Some(&b"http/1.1"[..]));
+
+    let certificate = TlsHandshake::try_from(messages[1].as_slice()).unwrap();
+    assert_eq!(certificate.length, 2740);
+    let chain = certificate.certificate_chain().unwrap();
+    assert_eq!(
+        chain
+            .certificates
+            .iter()
+            .map(|c| c.len())
+            .collect::<Vec<_>>(),
+        vec![1526, 1205]
+    );
+
+    let certificates = chain.decode_certificates().expect("valid X.509 chain");
+    let leaf = &certificates[0];
+    assert_eq!(leaf.version, 3);
+    assert_eq!(
+        hex::encode(leaf.serial_number),
+        "0e64c5fbc236ade14b172aeb41c78cb0"
+    );
+    assert_eq!(
+        leaf.subject.to_string(),
+        "C=US, ST=California, L=Los Angeles, \
+         O=Internet Corporation for Assigned Names and Numbers, OU=Technology, \
+         CN=www.example.org"
+    );
+    assert_eq!(
+        leaf.issuer.to_string(),
+        "C=US, O=DigiCert Inc, OU=www.digicert.com, \
+         CN=DigiCert SHA2 High Assurance Server CA"
+    );
+    assert_eq!(
+        leaf.not_before,
+        Utc.with_ymd_and_hms(2015, 11, 3, 0, 0, 0).unwrap()
+    );
+    assert_eq!(
+        leaf.not_after,
+        Utc.with_ymd_and_hms(2018, 11, 28, 12, 0, 0).unwrap()
+    );
+    assert_eq!(
+        leaf.subject_alt_names().unwrap(),
+        [
+            "www.example.org",
+            "example.com",
+            "example.edu",
+            "example.net",
+            "example.org",
+            "www.example.com",
+            "www.example.edu",
+            "www.example.net",
+        ]
+        .map(X509GeneralName::DnsName)
+    );
+    assert_eq!(
+        leaf.signature_algorithm.name(),
+        Some("sha256WithRSAEncryption")
+    );
+    assert_eq!(leaf.public_key_algorithm.name(), Some("rsaEncryption"));
+    assert!(!leaf.is_self_issued());
+
+    // L'intermediaire signe la feuille : son sujet est l'emetteur de celle-ci.
+    assert_eq!(certificates[1].subject, leaf.issuer);
+    assert_eq!(
+        certificates[1].issuer.common_name(),
+        Some("DigiCert High Assurance EV Root CA")
+    );
+}
+
+#[test]
+fn self_signed_v1_certificate_has_no_subject_alt_name() {
+    let frames = capture("tls1.2.pcapng");
+    let payload = tcp_payload(&frames, 6);
+    let messages: Vec<_> = parse_tls_records(&payload)
+        .iter()
+        .flat_map(|record| record.handshake_messages())
+        .collect();
+    assert_eq!(messages.len(), 4);
+
+    let server_hello = messages[0].server_hello().unwrap();
+    assert_eq!(server_hello.cipher_suite, 0xc030);
+    assert_eq!(server_hello.ja3s_string(), "771,49200,65281-11-35-23");
+    assert_eq!(server_hello.ja3s(), "ec74a5c51106f0419184d0dd08fb05bc");
+
+    let chain = messages[1].certificate_chain().unwrap();
+    let certificates = chain.decode_certificates().unwrap();
+    assert_eq!(certificates.len(), 1);
+
+    let certificate = &certificates[0];
+    assert_eq!(certificate.version, 1);
+    assert_eq!(
+        certificate.subject.to_string(),
+        "C=JP, ST=Tokyo, O=TeX2e, CN=localhost"
+    );
+    assert!(certificate.is_self_issued());
+    assert!(certificate.extensions.is_empty());
+    assert_eq!(certificate.subject_alt_names(), Ok(Vec::new()));
+    assert_eq!(certificate.dns_names(), vec!["localhost"]);
+    assert!(certificate.is_valid_at(Utc.with_ymd_and_hms(2022, 1, 1, 0, 0, 0).unwrap()));
+    assert!(!certificate.is_valid_at(Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap()));
+}
+
+#[test]
+fn tls13_server_hello_negotiates_through_supported_versions() {
+    let frames = capture("tls1.3.pcapng");
+    let payload = tcp_payload(&frames, 6);
+    let records = parse_tls_records(&payload);
+    let hello = records[0].handshake_messages()[0].server_hello().unwrap();
+
+    assert_eq!(hello.legacy_version, 0x0303);
+    assert_eq!(hello.negotiated_version(), 0x0304);
+    assert_eq!(hello.cipher_suite, 0x1302);
+    assert_eq!(hello.key_share_group(), Some(0x001d));
+
+    assert_eq!(hello.ja3s_string(), "771,4866,43-51");
+    assert_eq!(hello.ja3s(), "15af977ce25de452b96affa2addb1036");
+    assert_eq!(hello.ja4s(Ja4Protocol::Tcp), "t130200_1302_a56c5b993250");
+}
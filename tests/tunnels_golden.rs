@@ -9,7 +9,7 @@
 //! regle du depot — et restent portes par l'issue.
 
 use packet_parser::parse::transport::protocols::TransportProtocol;
-use packet_parser::{LinkType, parse};
+use packet_parser::{InnerKind, LinkType, parse};
 
 /// Trame 20749 : GRE v0 sans option (proto 0x0800) portant un ping ICMP
 /// 172.23.11.56 -> 192.168.42.11.
@@ -78,6 +78,7 @@ fn gre_ipv4_tunnel_exposes_the_inner_icmp_flow() {
             .map(|application| application.application_protocol),
         Some("GRE")
     );
+    assert_eq!(flow.inner_kind(), Some(InnerKind::Encapsulation));
     let flows = flow.flatten();
     assert_eq!(flows.len(), 2, "tunnel externe + conversation interne");
 